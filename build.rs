@@ -0,0 +1,13 @@
+//! Build script compiling the protobuf definitions of the gRPC match stream.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+	println!("cargo:rerun-if-changed=build.rs");
+
+	#[cfg(feature = "grpc")]
+	{
+		println!("cargo:rerun-if-changed=proto/monitor.proto");
+		tonic_build::configure().compile_protos(&["proto/monitor.proto"], &["proto"])?;
+	}
+
+	Ok(())
+}
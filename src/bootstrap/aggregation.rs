@@ -0,0 +1,229 @@
+//! Aggregation of the matches of a monitor across its networks.
+//!
+//! A monitor watching the same contract on several networks may match on each of them within
+//! a few seconds. With `aggregate_across_networks` set, the first match of such a monitor opens
+//! a batch keyed by the monitor name, further matches join it until the aggregation window
+//! elapses, and the whole batch is then notified at once, see [`execute_aggregated_matches`].
+
+use std::{
+	collections::HashMap,
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+use crate::{
+	models::{Monitor, MonitorMatch, ScriptLanguage},
+	services::{filter::match_variables, trigger::TriggerExecutionServiceTrait},
+};
+
+/// Aggregation window of the monitors that don't configure one
+pub const DEFAULT_AGGREGATION_WINDOW: Duration = Duration::from_secs(5);
+
+/// Matches of the monitors aggregating them, buffered by monitor name until their window
+/// elapses
+#[derive(Debug, Default)]
+pub struct MatchAggregator {
+	batches: Mutex<HashMap<String, Vec<MonitorMatch>>>,
+}
+
+impl MatchAggregator {
+	/// Adds a match to the batch of its monitor, opening one if there is none
+	///
+	/// # Returns
+	/// * `Option<Instant>` - Time at which the batch must be flushed with [`Self::take`] if the
+	///   match opened it, None if it joined an open batch
+	pub fn push(&self, monitor_match: MonitorMatch) -> Option<Instant> {
+		let monitor = match_monitor(&monitor_match);
+		let window = monitor
+			.aggregation_window_ms
+			.map_or(DEFAULT_AGGREGATION_WINDOW, Duration::from_millis);
+		let name = monitor.name.clone();
+
+		let mut batches = self.batches.lock().unwrap();
+		let batch = batches.entry(name).or_default();
+		batch.push(monitor_match);
+		(batch.len() == 1).then(|| Instant::now() + window)
+	}
+
+	/// Takes the batch of a monitor, closing it
+	pub fn take(&self, monitor_name: &str) -> Vec<MonitorMatch> {
+		self.batches
+			.lock()
+			.unwrap()
+			.remove(monitor_name)
+			.unwrap_or_default()
+	}
+}
+
+/// Returns the monitor of a match
+pub(crate) fn match_monitor(monitor_match: &MonitorMatch) -> &Monitor {
+	match monitor_match {
+		MonitorMatch::EVM(evm_match) => &evm_match.monitor,
+		MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor,
+		MonitorMatch::Midnight(midnight_match) => &midnight_match.monitor,
+		MonitorMatch::Solana(solana_match) => &solana_match.monitor,
+	}
+}
+
+/// Returns the network slug of a match
+fn match_network_slug(monitor_match: &MonitorMatch) -> &str {
+	match monitor_match {
+		MonitorMatch::EVM(evm_match) => &evm_match.network_slug,
+		MonitorMatch::Stellar(stellar_match) => &stellar_match.network_slug,
+		MonitorMatch::Midnight(midnight_match) => &midnight_match.network_slug,
+		MonitorMatch::Solana(solana_match) => &solana_match.network_slug,
+	}
+}
+
+/// Converts a batch of matches into the variables substituted in trigger templates
+///
+/// The variables of the first match are extended with:
+/// - `aggregated.count`: number of matches
+/// - `aggregated.networks`: comma separated slugs of the networks that matched
+/// - `aggregated.transactions`: comma separated `network:hash` of the matched transactions
+/// - `aggregated.<index>.network` and `aggregated.<index>.transaction.hash` for each match
+///
+/// # Arguments
+/// * `matches` - Matches of the batch, in order of arrival
+pub fn aggregated_variables(matches: &[MonitorMatch]) -> HashMap<String, String> {
+	let mut variables = matches.first().map(match_variables).unwrap_or_default();
+
+	let mut networks: Vec<&str> = Vec::new();
+	let mut transactions = Vec::new();
+	for (index, monitor_match) in matches.iter().enumerate() {
+		let network_slug = match_network_slug(monitor_match);
+		if !networks.contains(&network_slug) {
+			networks.push(network_slug);
+		}
+		let hash = match_variables(monitor_match)
+			.remove("transaction.hash")
+			.unwrap_or_default();
+		transactions.push(format!("{}:{}", network_slug, hash));
+
+		variables.insert(
+			format!("aggregated.{}.network", index),
+			network_slug.to_string(),
+		);
+		variables.insert(format!("aggregated.{}.transaction.hash", index), hash);
+	}
+
+	variables.insert("aggregated.count".to_string(), matches.len().to_string());
+	variables.insert("aggregated.networks".to_string(), networks.join(", "));
+	variables.insert(
+		"aggregated.transactions".to_string(),
+		transactions.join(", "),
+	);
+	variables
+}
+
+/// Executes the triggers of a monitor once for a batch of its matches
+///
+/// The first match of the batch is passed to the triggers as the match notified, e.g. for
+/// trigger scripts and the raw match of webhooks.
+///
+/// # Arguments
+/// * `matches` - Matches of the batch, in order of arrival
+/// * `trigger_service` - Service executing the triggers
+/// * `trigger_scripts` - Trigger condition scripts of the active monitors
+pub async fn execute_aggregated_matches<T: TriggerExecutionServiceTrait>(
+	matches: &[MonitorMatch],
+	trigger_service: &T,
+	trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+) {
+	let Some(first_match) = matches.first() else {
+		return;
+	};
+	let monitor = match_monitor(first_match);
+	tracing::debug!(
+		"Notifying {} aggregated matches of monitor {}",
+		matches.len(),
+		monitor.name
+	);
+
+	// Errors are logged by the trigger service
+	let _ = trigger_service
+		.execute(
+			&monitor.triggers,
+			aggregated_variables(matches),
+			first_match,
+			trigger_scripts,
+		)
+		.await;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, EVMTransactionReceipt, MatchConditions},
+		utils::tests::evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+
+	fn create_match(monitor_name: &str, network_slug: &str) -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new()
+				.name(monitor_name)
+				.aggregate_across_networks(Some(100))
+				.build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: Some(EVMTransactionReceipt::default()),
+			logs: Some(vec![]),
+			network_slug: network_slug.to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			confirmation_state: Default::default(),
+			severity: Default::default(),
+			sender_verified: None,
+		}))
+	}
+
+	#[test]
+	fn test_push_opens_one_batch_per_monitor() {
+		let aggregator = MatchAggregator::default();
+
+		assert!(aggregator
+			.push(create_match("first", "ethereum_mainnet"))
+			.is_some());
+		assert!(aggregator
+			.push(create_match("first", "polygon_mainnet"))
+			.is_none());
+		assert!(aggregator
+			.push(create_match("second", "ethereum_mainnet"))
+			.is_some());
+
+		assert_eq!(aggregator.take("first").len(), 2);
+		assert_eq!(aggregator.take("second").len(), 1);
+		assert!(aggregator.take("first").is_empty());
+		assert!(aggregator
+			.push(create_match("first", "ethereum_mainnet"))
+			.is_some());
+	}
+
+	#[test]
+	fn test_aggregated_variables_list_networks_and_transactions() {
+		let matches = vec![
+			create_match("first", "ethereum_mainnet"),
+			create_match("first", "polygon_mainnet"),
+			create_match("first", "ethereum_mainnet"),
+		];
+		let hash = match_variables(&matches[0])["transaction.hash"].clone();
+
+		let variables = aggregated_variables(&matches);
+
+		assert_eq!(variables["monitor.name"], "first");
+		assert_eq!(variables["aggregated.count"], "3");
+		assert_eq!(
+			variables["aggregated.networks"],
+			"ethereum_mainnet, polygon_mainnet"
+		);
+		assert_eq!(variables["aggregated.1.network"], "polygon_mainnet");
+		assert_eq!(variables["aggregated.2.transaction.hash"], hash);
+		assert_eq!(
+			variables["aggregated.transactions"],
+			format!(
+				"ethereum_mainnet:{0}, polygon_mainnet:{0}, ethereum_mainnet:{0}",
+				hash
+			)
+		);
+	}
+}
@@ -0,0 +1,374 @@
+//! Alerts of the monitors notifying when their alerts resolve.
+//!
+//! A monitor with `notify_on_resolve` has an alert on each of its networks. The alert fires at
+//! a block of the network matching the monitor, and resolves at the first following block that
+//! no longer matches it. Only the transitions are notified: the match firing the alert through
+//! the triggers of the monitor, and the resolution through its `resolve_trigger`, see
+//! [`execute_resolved_alert`].
+//!
+//! Transitions are detected once the trigger handler started notifying a block, from the matches
+//! passing their trigger conditions, so that a match that is never notified doesn't fire an
+//! alert. Blocks reaching this point after a later block of their network are ignored. Only
+//! blocks identified by their hash update the alerts:
+//! - Pending transactions neither fire nor resolve alerts, and their matches are notified as
+//!   usual.
+//! - The jobs running on the own schedule of a monitor only apply the monitors sharing it, and
+//!   monitors with `notify_on_resolve` can't set a schedule, so these blocks never match them.
+//!   They would otherwise resolve the alerts of the monitors they don't apply.
+//!
+//! Warnings of the warn threshold expressions neither fire nor hold alerts, and are notified as
+//! usual. The firing alerts are persisted, so that an alert firing before a restart still
+//! resolves after it.
+
+use std::{
+	collections::{HashMap, HashSet},
+	sync::{Arc, Mutex},
+};
+
+use super::aggregation::match_monitor;
+use crate::{
+	models::{MatchSeverity, MonitorMatch, ScriptLanguage},
+	services::{
+		blockwatcher::{create_alert_state_writer, AlertStateWriter, BlockStorage},
+		filter::match_variables,
+		trigger::TriggerExecutionServiceTrait,
+	},
+};
+
+/// Transitions of the alerts of a network at a block
+#[derive(Debug, Default)]
+pub struct AlertTransitions {
+	/// Match firing each alert that fired
+	pub triggered: Vec<MonitorMatch>,
+	/// Match that fired each alert that resolved
+	pub resolved: Vec<MonitorMatch>,
+}
+
+/// Firing alerts of the monitors with `notify_on_resolve`
+#[derive(Default)]
+pub struct AlertStates {
+	/// Match that fired each firing alert, by network slug and monitor name
+	firing: Mutex<HashMap<String, HashMap<String, MonitorMatch>>>,
+	/// Number of the last block updating the alerts, by network slug
+	last_blocks: Mutex<HashMap<String, u64>>,
+	/// Writer persisting the transitions, if any
+	writer: Option<AlertStateWriter>,
+}
+
+impl AlertStates {
+	/// Creates alert states without firing alerts
+	///
+	/// # Arguments
+	/// * `writer` - Writer persisting the transitions, if any
+	pub fn new(writer: Option<AlertStateWriter>) -> Self {
+		Self {
+			firing: Mutex::new(HashMap::new()),
+			last_blocks: Mutex::new(HashMap::new()),
+			writer,
+		}
+	}
+
+	/// Loads the firing alerts of networks from a block storage, which persists the further
+	/// transitions
+	///
+	/// # Arguments
+	/// * `storage` - Storage the alert states are persisted in
+	/// * `network_slugs` - Networks to load the firing alerts of
+	pub async fn load<S: BlockStorage + 'static>(
+		storage: Arc<S>,
+		network_slugs: impl IntoIterator<Item = &String>,
+	) -> Result<Self, anyhow::Error> {
+		let mut firing = HashMap::new();
+		for network_slug in network_slugs {
+			let alerts: HashMap<String, MonitorMatch> = storage
+				.get_firing_alerts(network_slug)
+				.await?
+				.into_iter()
+				.map(|fired_match| (match_monitor(&fired_match).name.clone(), fired_match))
+				.collect();
+			if !alerts.is_empty() {
+				firing.insert(network_slug.clone(), alerts);
+			}
+		}
+
+		Ok(Self {
+			firing: Mutex::new(firing),
+			last_blocks: Mutex::new(HashMap::new()),
+			writer: Some(create_alert_state_writer(storage)),
+		})
+	}
+
+	/// Returns the names of the monitors whose alert is firing on a network
+	pub fn firing(&self, network_slug: &str) -> HashSet<String> {
+		self.firing
+			.lock()
+			.unwrap()
+			.get(network_slug)
+			.map(|alerts| alerts.keys().cloned().collect())
+			.unwrap_or_default()
+	}
+
+	/// Updates the alerts of a network with the matches of a block
+	///
+	/// The first match of a monitor whose alert isn't firing fires it, and the firing alerts of
+	/// the monitors without matches resolve. A block older than the last block updating the
+	/// alerts of its network doesn't change them.
+	///
+	/// # Arguments
+	/// * `network_slug` - Network of the block
+	/// * `block_number` - Number of the block
+	/// * `matches` - Matches of the block passing their trigger conditions
+	pub fn update(
+		&self,
+		network_slug: &str,
+		block_number: u64,
+		matches: &[MonitorMatch],
+	) -> AlertTransitions {
+		let mut firing = self.firing.lock().unwrap();
+		{
+			let mut last_blocks = self.last_blocks.lock().unwrap();
+			let last_block = last_blocks.entry(network_slug.to_string()).or_insert(0);
+			if block_number < *last_block {
+				tracing::debug!(
+					"Ignoring the alerts of block {} on {}: block {} already updated them",
+					block_number,
+					network_slug,
+					last_block
+				);
+				return AlertTransitions::default();
+			}
+			*last_block = block_number;
+		}
+		let alerts = firing.entry(network_slug.to_string()).or_default();
+		let mut transitions = AlertTransitions::default();
+
+		let mut matched = HashSet::new();
+		for monitor_match in matches {
+			let monitor = match_monitor(monitor_match);
+			if !monitor.notify_on_resolve
+				|| monitor_match.severity() != MatchSeverity::Alert
+				|| !matched.insert(monitor.name.as_str())
+			{
+				continue;
+			}
+			if !alerts.contains_key(&monitor.name) {
+				alerts.insert(monitor.name.clone(), monitor_match.clone());
+				transitions.triggered.push(monitor_match.clone());
+			}
+		}
+
+		let resolved: Vec<String> = alerts
+			.keys()
+			.filter(|name| !matched.contains(name.as_str()))
+			.cloned()
+			.collect();
+		for name in resolved {
+			transitions.resolved.extend(alerts.remove(&name));
+		}
+		transitions
+	}
+
+	/// Persists the transitions of the alerts of a network, logging the failures
+	pub async fn persist(&self, network_slug: &str, transitions: &AlertTransitions) {
+		let Some(writer) = &self.writer else {
+			return;
+		};
+
+		let states = transitions
+			.triggered
+			.iter()
+			.map(|fired_match| (fired_match, true))
+			.chain(
+				transitions
+					.resolved
+					.iter()
+					.map(|fired_match| (fired_match, false)),
+			);
+		for (fired_match, is_firing) in states {
+			let monitor_name = match_monitor(fired_match).name.clone();
+			let last_match = is_firing.then(|| fired_match.clone());
+			if let Err(e) = writer(network_slug.to_string(), monitor_name.clone(), last_match).await
+			{
+				tracing::error!(
+					"Failed to save the alert state of monitor {} on {}: {}",
+					monitor_name,
+					network_slug,
+					e
+				);
+			}
+		}
+	}
+}
+
+/// Executes the triggers notifying that the alert of a monitor resolved
+///
+/// These are the monitor's `resolve_trigger`, or its `triggers` when unset. The match that fired
+/// the alert is passed to the triggers, with its variables extended with `alert.state` set to
+/// `resolved` and `alert.resolved_block`, the number of the block that resolved it.
+///
+/// # Arguments
+/// * `fired_match` - Match that fired the alert
+/// * `block_number` - Number of the block that resolved the alert
+/// * `trigger_service` - Service executing the triggers
+/// * `trigger_scripts` - Trigger condition scripts of the active monitors
+pub async fn execute_resolved_alert<T: TriggerExecutionServiceTrait>(
+	fired_match: &MonitorMatch,
+	block_number: u64,
+	trigger_service: &T,
+	trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+) {
+	let monitor = match_monitor(fired_match);
+	let triggers = match &monitor.resolve_trigger {
+		Some(resolve_trigger) => vec![resolve_trigger.clone()],
+		None => monitor.triggers.clone(),
+	};
+	tracing::debug!(
+		"Alert of monitor {} resolved at block {}",
+		monitor.name,
+		block_number
+	);
+
+	let mut variables = match_variables(fired_match);
+	variables.insert("alert.state".to_string(), "resolved".to_string());
+	variables.insert("alert.resolved_block".to_string(), block_number.to_string());
+
+	// Errors are logged by the trigger service
+	let _ = trigger_service
+		.execute(&triggers, variables, fired_match, trigger_scripts)
+		.await;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, MatchConditions},
+		services::blockwatcher::FileBlockStorage,
+		utils::tests::evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+
+	fn create_match(monitor_name: &str, notify_on_resolve: bool) -> MonitorMatch {
+		let mut monitor = MonitorBuilder::new().name(monitor_name);
+		if notify_on_resolve {
+			monitor = monitor.notify_on_resolve(Some("resolved"));
+		}
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: monitor.build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: None,
+			logs: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			confirmation_state: Default::default(),
+			severity: Default::default(),
+			sender_verified: None,
+		}))
+	}
+
+	fn names(matches: &[MonitorMatch]) -> Vec<&str> {
+		matches
+			.iter()
+			.map(|monitor_match| match_monitor(monitor_match).name.as_str())
+			.collect()
+	}
+
+	#[test]
+	fn test_update_fires_resolves_and_fires_again() {
+		let states = AlertStates::default();
+		let low_balance = create_match("low_balance", true);
+
+		let transitions = states.update("ethereum_mainnet", 100, &[low_balance.clone()]);
+		assert_eq!(names(&transitions.triggered), vec!["low_balance"]);
+		assert!(transitions.resolved.is_empty());
+
+		// The condition still holds
+		let transitions = states.update(
+			"ethereum_mainnet",
+			101,
+			&[low_balance.clone(), low_balance.clone()],
+		);
+		assert!(transitions.triggered.is_empty());
+		assert!(transitions.resolved.is_empty());
+		assert_eq!(
+			states.firing("ethereum_mainnet"),
+			HashSet::from(["low_balance".to_string()])
+		);
+
+		let transitions = states.update("ethereum_mainnet", 102, &[]);
+		assert!(transitions.triggered.is_empty());
+		assert_eq!(names(&transitions.resolved), vec!["low_balance"]);
+		assert!(states.firing("ethereum_mainnet").is_empty());
+
+		let transitions = states.update("ethereum_mainnet", 103, &[]);
+		assert!(transitions.resolved.is_empty());
+
+		let transitions = states.update("ethereum_mainnet", 104, &[low_balance]);
+		assert_eq!(names(&transitions.triggered), vec!["low_balance"]);
+	}
+
+	#[test]
+	fn test_update_ignores_stateless_monitors_and_other_networks() {
+		let states = AlertStates::default();
+
+		let transitions =
+			states.update("ethereum_mainnet", 100, &[create_match("transfers", false)]);
+		assert!(transitions.triggered.is_empty());
+
+		states.update(
+			"ethereum_mainnet",
+			101,
+			&[create_match("low_balance", true)],
+		);
+		let transitions = states.update("polygon_mainnet", 102, &[]);
+		assert!(transitions.resolved.is_empty());
+		assert_eq!(states.firing("ethereum_mainnet").len(), 1);
+	}
+
+	#[test]
+	fn test_update_ignores_older_blocks() {
+		let states = AlertStates::default();
+		let low_balance = create_match("low_balance", true);
+
+		states.update("ethereum_mainnet", 101, &[low_balance.clone()]);
+
+		// Block 100 reached the alerts after block 101, and doesn't resolve them
+		let transitions = states.update("ethereum_mainnet", 100, &[]);
+		assert!(transitions.resolved.is_empty());
+		assert_eq!(states.firing("ethereum_mainnet").len(), 1);
+
+		let transitions = states.update("ethereum_mainnet", 102, &[]);
+		assert_eq!(names(&transitions.resolved), vec!["low_balance"]);
+	}
+
+	#[tokio::test]
+	async fn test_load_restores_persisted_alerts() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+		let network_slugs = ["ethereum_mainnet".to_string()];
+
+		let states = AlertStates::load(storage.clone(), &network_slugs)
+			.await
+			.unwrap();
+		let transitions = states.update(
+			"ethereum_mainnet",
+			100,
+			&[
+				create_match("low_balance", true),
+				create_match("paused", true),
+			],
+		);
+		states.persist("ethereum_mainnet", &transitions).await;
+		let transitions = states.update("ethereum_mainnet", 101, &[create_match("paused", true)]);
+		states.persist("ethereum_mainnet", &transitions).await;
+
+		// After a restart, the alert still firing resolves
+		let states = AlertStates::load(storage, &network_slugs).await.unwrap();
+		assert_eq!(
+			states.firing("ethereum_mainnet"),
+			HashSet::from(["paused".to_string()])
+		);
+		let transitions = states.update("ethereum_mainnet", 102, &[]);
+		assert_eq!(names(&transitions.resolved), vec!["paused"]);
+	}
+}
@@ -14,35 +14,74 @@
 //!   blockchain
 //! - `create_trigger_handler`: Creates a trigger handler function that processes trigger events
 //!   from the block processing pipeline
+//!
+//! # Aggregation
+//! - `MatchAggregator`: Buffers the matches of monitors aggregating them across networks, so
+//!   that they are notified together
+//!
+//! # Alert state
+//! - `AlertStates`: Tracks the firing alerts of the monitors with `notify_on_resolve`, so that
+//!   their alerts are notified when they fire and when they resolve
+//!
+//! # Reload
+//! - `MonitorReloader`: Reloads the monitor configurations and swaps the active monitors read
+//!   by the handlers
+
+mod aggregation;
+mod alert_state;
+mod reload;
+mod trigger_limiter;
 
 use futures::future::BoxFuture;
-use std::{collections::HashMap, error::Error, sync::Arc};
-use tokio::sync::{watch, Mutex};
+use std::{
+	collections::{HashMap, HashSet},
+	error::Error,
+	path::{Path, PathBuf},
+	sync::Arc,
+};
+use tokio::sync::{broadcast, watch, Mutex};
 
 use crate::{
 	models::{
-		BlockChainType, BlockType, ContractSpec, Monitor, MonitorMatch, Network, ProcessedBlock,
-		ScriptLanguage, TriggerConditions,
+		load_bundle, BlockChainType, BlockType, ConditionLogic, ContractSpec, MatchSeverity,
+		Monitor, MonitorMatch, Network, ProcessedBlock, ScriptLanguage, TriggerConditions,
 	},
 	repositories::{
-		MonitorRepositoryTrait, MonitorService, NetworkRepositoryTrait, NetworkService,
-		TriggerRepositoryTrait, TriggerService,
+		MonitorRepository, MonitorRepositoryTrait, MonitorService, NetworkRepositoryTrait,
+		NetworkService, TriggerRepositoryTrait, TriggerService,
 	},
 	services::{
 		blockchain::{BlockChainClient, BlockFilterFactory, ClientPoolTrait},
+		blockwatcher::{ProcessedBlockGuard, StallEvent, StallHandler},
 		filter::{evm_helpers, handle_match, stellar_helpers, FilterService},
 		notification::NotificationService,
+		storage::{MatchRecord, MatchStore},
 		trigger::{
-			ScriptError, ScriptExecutorFactory, TriggerError, TriggerExecutionService,
-			TriggerExecutionServiceTrait,
+			NotificationRetryQueue, ScriptError, ScriptExecutorFactory, ScriptLimits, TriggerError,
+			TriggerExecutionService, TriggerExecutionServiceTrait,
 		},
 	},
-	utils::normalize_string,
+	utils::{
+		metrics::{DUPLICATE_BLOCKS_SKIPPED_TOTAL, MONITORS_ACTIVE, TRIGGER_TASKS_DROPPED_TOTAL},
+		normalize_string,
+	},
 };
 
+pub use aggregation::{
+	aggregated_variables, execute_aggregated_matches, MatchAggregator, DEFAULT_AGGREGATION_WINDOW,
+};
+pub use alert_state::{execute_resolved_alert, AlertStates, AlertTransitions};
+pub use reload::{ActiveMonitors, MonitorReloader, SharedActiveMonitors};
+pub use trigger_limiter::{QueuedTriggerTask, TriggerTaskLimiter, TriggerTaskSlot};
+
 /// Type alias for handling ServiceResult
 pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
 
+/// Number of matches buffered for each subscriber of the match channel
+///
+/// Subscribers falling further behind skip the oldest matches.
+pub const MATCH_CHANNEL_CAPACITY: usize = 1024;
+
 type ServiceResult<M, N, T> = Result<(
 	Arc<FilterService>,
 	Arc<TriggerExecutionService<T>>,
@@ -53,8 +92,68 @@ type ServiceResult<M, N, T> = Result<(
 	Arc<Mutex<TriggerService<T>>>,
 )>;
 
+/// Overrides of the configuration of the services, for applications embedding the monitor
+///
+/// The default options keep the configuration loaded from the default directories.
+#[derive(Debug, Clone, Default)]
+pub struct InitOptions {
+	/// Directory holding the `monitors`, `networks` and `triggers` configuration directories,
+	/// instead of `./config`
+	pub config_dir: Option<PathBuf>,
+	/// Path to a single file holding all configurations, instead of the configuration
+	/// directories
+	pub bundle_path: Option<PathBuf>,
+	/// Number of confirmation blocks of networks by slug, instead of their configured
+	/// `confirmation_blocks`
+	pub confirmation_blocks: HashMap<String, u64>,
+	/// Whether triggers are only logged instead of executed
+	pub dry_run: bool,
+	/// Maximum number of monitors evaluated concurrently within a block, lowering the
+	/// `monitor_concurrency` of networks configured above it
+	pub max_monitor_concurrency: Option<usize>,
+}
+
+impl InitOptions {
+	/// Applies the network overrides to the loaded networks
+	///
+	/// # Errors
+	/// Returns an error if a confirmation override references an unknown network
+	fn apply_to_networks(&self, networks: &mut HashMap<String, Network>) -> Result<()> {
+		for (slug, confirmation_blocks) in &self.confirmation_blocks {
+			let network = networks.get_mut(slug).ok_or_else(|| {
+				format!(
+					"Confirmation blocks override references unknown network {}",
+					slug
+				)
+			})?;
+			network.confirmation_blocks = *confirmation_blocks;
+		}
+
+		if let Some(max_concurrency) = self.max_monitor_concurrency {
+			for network in networks.values_mut() {
+				network.monitor_concurrency = Some(
+					network
+						.monitor_concurrency
+						.unwrap_or(1)
+						.min(max_concurrency.max(1)),
+				);
+			}
+		}
+		Ok(())
+	}
+}
+
 /// Initializes all required services for the blockchain monitor.
 ///
+/// Services that aren't provided are created from the default configuration directories, or
+/// from the configuration bundle when a bundle path is given.
+///
+/// # Arguments
+/// * `monitor_service` - Optional pre-built monitor service
+/// * `network_service` - Optional pre-built network service
+/// * `trigger_service` - Optional pre-built trigger service
+/// * `bundle_path` - Optional path to a single file holding all configurations
+///
 /// # Returns
 /// Returns a tuple containing:
 /// - FilterService: Handles filtering of blockchain data
@@ -70,24 +169,86 @@ pub async fn initialize_services<M, N, T>(
 	monitor_service: Option<MonitorService<M, N, T>>,
 	network_service: Option<NetworkService<N>>,
 	trigger_service: Option<TriggerService<T>>,
+	bundle_path: Option<&Path>,
+) -> ServiceResult<M, N, T>
+where
+	M: MonitorRepositoryTrait<N, T> + Send + Sync + 'static,
+	N: NetworkRepositoryTrait + Send + Sync + 'static,
+	T: TriggerRepositoryTrait + Send + Sync + 'static,
+{
+	initialize_services_with_options(
+		monitor_service,
+		network_service,
+		trigger_service,
+		InitOptions {
+			bundle_path: bundle_path.map(Path::to_path_buf),
+			..Default::default()
+		},
+	)
+	.await
+}
+
+/// Initializes all required services for the blockchain monitor, with configuration overrides.
+///
+/// Services that aren't provided are created from the configuration directories of
+/// `options.config_dir`, or from the configuration bundle when a bundle path is given. The
+/// network overrides apply to the provided network service as well.
+///
+/// # Arguments
+/// * `monitor_service` - Optional pre-built monitor service
+/// * `network_service` - Optional pre-built network service
+/// * `trigger_service` - Optional pre-built trigger service
+/// * `options` - Overrides of the configuration
+///
+/// # Returns
+/// The same services as [`initialize_services`]
+///
+/// # Errors
+/// Returns an error if any service initialization fails, or if a confirmation override
+/// references an unknown network
+pub async fn initialize_services_with_options<M, N, T>(
+	monitor_service: Option<MonitorService<M, N, T>>,
+	network_service: Option<NetworkService<N>>,
+	trigger_service: Option<TriggerService<T>>,
+	options: InitOptions,
 ) -> ServiceResult<M, N, T>
 where
 	M: MonitorRepositoryTrait<N, T> + Send + Sync + 'static,
 	N: NetworkRepositoryTrait + Send + Sync + 'static,
 	T: TriggerRepositoryTrait + Send + Sync + 'static,
 {
+	let bundle = match &options.bundle_path {
+		Some(path) => Some(load_bundle(path).await?),
+		None => None,
+	};
+	let config_path = |name: &str| options.config_dir.as_ref().map(|dir| dir.join(name));
+
 	let network_service = match network_service {
 		Some(service) => service,
 		None => {
-			let repository = N::new(None).await?;
+			let repository = match &bundle {
+				Some(bundle) => N::new_with_networks(bundle.networks.clone()),
+				None => N::new(config_path("networks").as_deref()).await?,
+			};
 			NetworkService::<N>::new_with_repository(repository)?
 		}
 	};
+	let network_service =
+		if options.confirmation_blocks.is_empty() && options.max_monitor_concurrency.is_none() {
+			network_service
+		} else {
+			let mut networks = network_service.get_all();
+			options.apply_to_networks(&mut networks)?;
+			NetworkService::<N>::new_with_repository(N::new_with_networks(networks))?
+		};
 
 	let trigger_service = match trigger_service {
 		Some(service) => service,
 		None => {
-			let repository = T::new(None).await?;
+			let repository = match &bundle {
+				Some(bundle) => T::new_with_triggers(bundle.triggers.clone()),
+				None => T::new(config_path("triggers").as_deref()).await?,
+			};
 			TriggerService::<T>::new_with_repository(repository)?
 		}
 	};
@@ -95,12 +256,24 @@ where
 	let monitor_service = match monitor_service {
 		Some(service) => service,
 		None => {
-			let repository = M::new(
-				None,
-				Some(network_service.clone()),
-				Some(trigger_service.clone()),
-			)
-			.await?;
+			let repository = match &bundle {
+				Some(bundle) => {
+					MonitorRepository::<N, T>::validate_monitor_references(
+						&bundle.monitors,
+						&trigger_service.get_all(),
+						&network_service.get_all(),
+					)?;
+					M::new_with_monitors(bundle.monitors.clone())
+				}
+				None => {
+					M::new(
+						config_path("monitors").as_deref(),
+						Some(network_service.clone()),
+						Some(trigger_service.clone()),
+					)
+					.await?
+				}
+			};
 			MonitorService::<M, N, T>::new_with_repository(repository)?
 		}
 	};
@@ -108,10 +281,12 @@ where
 	let notification_service = NotificationService::new();
 
 	let filter_service = Arc::new(FilterService::new());
-	let trigger_execution_service = Arc::new(TriggerExecutionService::new(
-		trigger_service.clone(),
-		notification_service,
-	));
+	let retry_queue = NotificationRetryQueue::from_env(notification_service.clone())?;
+	let trigger_execution_service = Arc::new(
+		TriggerExecutionService::new(trigger_service.clone(), notification_service)
+			.with_retry_queue(retry_queue)
+			.with_dry_run(options.dry_run),
+	);
 
 	let monitors = monitor_service.get_all();
 	let active_monitors = filter_active_monitors(monitors);
@@ -133,7 +308,8 @@ where
 /// # Arguments
 /// * `shutdown_tx` - Watch channel for shutdown signals
 /// * `filter_service` - Service for filtering blockchain data
-/// * `active_monitors` - List of active monitors
+/// * `active_monitors` - Active monitors and their contract specs, read for every block
+/// * `networks` - Configured networks, used to resolve the schedule of monitors without one
 /// * `client_pools` - Client pools for accessing blockchain clients
 ///
 /// # Returns
@@ -141,24 +317,61 @@ where
 pub fn create_block_handler<P: ClientPoolTrait + 'static>(
 	shutdown_tx: watch::Sender<bool>,
 	filter_service: Arc<FilterService>,
-	active_monitors: Vec<Monitor>,
+	active_monitors: SharedActiveMonitors,
+	networks: &HashMap<String, Network>,
 	client_pools: Arc<P>,
-	contract_specs: Vec<(String, ContractSpec)>,
 ) -> Arc<impl Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync> {
+	let configured_schedules: Arc<HashMap<String, String>> = Arc::new(
+		networks
+			.values()
+			.map(|network| (network.slug.clone(), network.cron_schedule.clone()))
+			.collect(),
+	);
 	Arc::new(
 		move |block: BlockType, network: Network| -> BoxFuture<'static, ProcessedBlock> {
 			let filter_service = filter_service.clone();
 			let active_monitors = active_monitors.clone();
 			let client_pools = client_pools.clone();
 			let shutdown_tx = shutdown_tx.clone();
-			let contract_specs = contract_specs.clone();
+			let configured_schedules = configured_schedules.clone();
 			Box::pin(async move {
-				let applicable_monitors = filter_network_monitors(&active_monitors, &network.slug);
+				// Released before processing the block, so that a reload isn't held up by it
+				let (network_monitors, contract_specs) = {
+					let active_monitors = active_monitors.read().await;
+					// Monitors paused until a time resume with the first block processed after it
+					let now = chrono::Utc::now();
+					MONITORS_ACTIVE.set(
+						active_monitors
+							.monitors
+							.iter()
+							.filter(|m| !m.is_paused_at(now))
+							.count() as f64,
+					);
+					(
+						filter_network_monitors(&active_monitors.monitors, &network.slug, now),
+						active_monitors.contract_specs.clone(),
+					)
+				};
+				let configured_schedule = configured_schedules
+					.get(&network.slug)
+					.unwrap_or(&network.cron_schedule);
+				let applicable_monitors = filter_windowed_monitors(
+					filter_scheduled_monitors(network_monitors, &network, configured_schedule),
+					&block,
+				);
 
 				let mut processed_block = ProcessedBlock {
 					block_number: block.number().unwrap_or(0),
 					network_slug: network.slug.clone(),
+					// Jobs on a monitor's own schedule apply other monitors to the same blocks,
+					// so their blocks aren't identified by their hash alone
+					block_hash: if network.cron_schedule == *configured_schedule {
+						block.hash()
+					} else {
+						None
+					},
 					processing_results: Vec::new(),
+					block: None,
 				};
 
 				if !applicable_monitors.is_empty() {
@@ -214,11 +427,43 @@ pub fn create_block_handler<P: ClientPoolTrait + 'static>(
 								Err(_) => None,
 							}
 						}
+						BlockChainType::Solana => {
+							match client_pools.get_solana_client(&network).await {
+								Ok(client) => {
+									process_block(
+										client.as_ref(),
+										&network,
+										&block,
+										&applicable_monitors,
+										Some(&contract_specs),
+										&filter_service,
+										&mut shutdown_rx,
+									)
+									.await
+								}
+								Err(_) => None,
+							}
+						}
 					};
 
 					processed_block.processing_results = matches.unwrap_or_default();
 				}
 
+				// The block is only kept for the trigger conditions reading it
+				let needs_block_context =
+					processed_block
+						.processing_results
+						.iter()
+						.any(|monitor_match| {
+							aggregation::match_monitor(monitor_match)
+								.trigger_conditions
+								.iter()
+								.any(|condition| condition.include_block_context)
+						});
+				if needs_block_context {
+					processed_block.block = Some(Arc::new(block));
+				}
+
 				processed_block
 			})
 		},
@@ -234,6 +479,10 @@ pub fn create_block_handler<P: ClientPoolTrait + 'static>(
 /// * `applicable_monitors` - List of monitors that apply to this network
 /// * `filter_service` - Service for filtering blockchain data
 /// * `shutdown_rx` - Receiver for shutdown signals
+#[tracing::instrument(
+	skip_all,
+	fields(network = %network.slug, block = block.number().unwrap_or(0))
+)]
 pub async fn process_block<T>(
 	client: &T,
 	network: &Network,
@@ -295,7 +544,8 @@ pub async fn get_contract_specs<P: ClientPoolTrait + 'static>(
 								stellar_helpers::normalize_address(&monitored_addr.address),
 								ContractSpec::Stellar(parsed_spec.clone()),
 							))
-						} else {
+						} else if monitored_addr.match_mode.is_exact() {
+							// Address patterns don't name a contract to fetch the spec of
 							addresses_without_specs.push(monitored_addr.address.clone());
 						}
 					}
@@ -380,46 +630,272 @@ pub async fn get_contract_specs<P: ClientPoolTrait + 'static>(
 /// Creates a trigger handler function that processes trigger events from the block processing
 /// pipeline.
 ///
+/// Matches that pass the trigger conditions are recorded to the match store, if any, and
+/// published to `match_tx` before their triggers are executed, fanning them out to other
+/// consumers such as the gRPC match stream. A failure to record a match is logged and doesn't
+/// prevent its notifications.
+///
+/// The blocks handled concurrently are bounded by `task_limiter`. Blocks beyond its queue are
+/// dropped, and blocks still queued at shutdown are abandoned, while blocks being handled run
+/// to completion so that [`TriggerTaskLimiter::drain`] can wait for them.
+///
+/// With a `block_guard`, the hash of every block with matches is recorded before its triggers
+/// are executed, and blocks already recorded, such as blocks processed again after a restart
+/// or by another replica, are skipped. Unlike the last processed block number, the hash tells
+/// apart the blocks of a reorganization. Blocks without a hash are never skipped, and neither
+/// are blocks whose hash can't be recorded.
+///
+/// The matches of monitors with `aggregate_across_networks` are buffered by monitor name
+/// instead of being notified right away. The block whose match opened a batch waits for the
+/// aggregation window of the monitor, then executes its triggers once for all the matches
+/// buffered meanwhile, on any network. At shutdown, open batches are notified without waiting.
+/// Warnings of the warn threshold expressions are never aggregated.
+///
+/// The matches of monitors with `notify_on_resolve` update their alerts in `alert_states`, in
+/// the order the blocks are handled. Only the match firing an alert is notified, and the
+/// `resolve_trigger` of the monitor is executed at the block resolving it, see [`AlertStates`].
+///
 /// # Arguments
 /// * `shutdown_tx` - Watch channel for shutdown signals
 /// * `trigger_service` - Service for executing triggers
+/// * `active_monitors` - Active monitors, whose trigger condition scripts are read for every
+///   block with matches
+/// * `match_tx` - Broadcast channel the matches are published to
+/// * `match_store` - Optional store recording the history of matches
+/// * `task_limiter` - Limit of the blocks handled concurrently
+/// * `block_guard` - Optional record of the blocks whose triggers were executed
+/// * `alert_states` - Firing alerts of the monitors with `notify_on_resolve`
 ///
 /// # Returns
 /// Returns a function that handles trigger execution for matching monitors
 pub fn create_trigger_handler<S: TriggerExecutionServiceTrait + Send + Sync + 'static>(
 	shutdown_tx: watch::Sender<bool>,
 	trigger_service: Arc<S>,
-	active_monitors_trigger_scripts: HashMap<String, (ScriptLanguage, String)>,
+	active_monitors: SharedActiveMonitors,
+	match_tx: broadcast::Sender<MonitorMatch>,
+	match_store: Option<Arc<dyn MatchStore>>,
+	task_limiter: TriggerTaskLimiter,
+	block_guard: Option<ProcessedBlockGuard>,
+	alert_states: Arc<AlertStates>,
 ) -> Arc<impl Fn(&ProcessedBlock) -> tokio::task::JoinHandle<()> + Send + Sync> {
+	let aggregator = Arc::new(MatchAggregator::default());
 	Arc::new(move |block: &ProcessedBlock| {
+		// Pending transactions and the blocks of the jobs on a monitor's own schedule don't
+		// update the alerts, see the alert_state module
+		let updates_alerts = block.block_hash.is_some();
+		// A block without matches can still resolve the firing alerts of its network
+		if block.processing_results.is_empty()
+			&& (!updates_alerts || alert_states.firing(&block.network_slug).is_empty())
+		{
+			return tokio::spawn(async {});
+		}
+		let Some(slot) = task_limiter.reserve() else {
+			TRIGGER_TASKS_DROPPED_TOTAL.inc();
+			tracing::warn!(
+				"Dropping the matches of block {} on {}: too many blocks are waiting for their \
+				 triggers",
+				block.block_number,
+				block.network_slug
+			);
+			return tokio::spawn(async {});
+		};
+
 		let mut shutdown_rx = shutdown_tx.subscribe();
 		let trigger_service = trigger_service.clone();
-		let trigger_scripts = active_monitors_trigger_scripts.clone();
+		let active_monitors = active_monitors.clone();
+		let match_tx = match_tx.clone();
+		let match_store = match_store.clone();
+		let block_guard = block_guard.clone();
+		let aggregator = aggregator.clone();
+		let alert_states = alert_states.clone();
 		let block = block.clone();
 
 		tokio::spawn(async move {
-			tokio::select! {
-				_ = async {
-					if block.processing_results.is_empty() {
+			// Only the wait for a slot is abandoned at shutdown, a started block runs to completion
+			let _permit = tokio::select! {
+				permit = slot.acquire() => permit,
+				_ = shutdown_rx.changed() => {
+					tracing::info!("Shutting down trigger handling task");
+					return;
+				}
+			};
+
+			if let (Some(block_guard), Some(block_hash)) = (&block_guard, &block.block_hash) {
+				match block_guard(block.network_slug.clone(), block_hash.clone()).await {
+					Ok(true) => {}
+					Ok(false) => {
+						DUPLICATE_BLOCKS_SKIPPED_TOTAL
+							.with_label_values(&[&block.network_slug])
+							.inc();
+						tracing::info!(
+							"Skipping the triggers of block {} ({}) on {}: already executed",
+							block.block_number,
+							block_hash,
+							block.network_slug
+						);
 						return;
 					}
-					let filtered_matches = run_trigger_filters(&block.processing_results, &block.network_slug, &trigger_scripts).await;
-					for monitor_match in &filtered_matches {
-						if let Err(e) = handle_match(monitor_match.clone(), &*trigger_service, &trigger_scripts).await {
-							TriggerError::execution_error(e.to_string(), Some(e.into()), None);
-						}
+					Err(e) => {
+						tracing::warn!(
+							"Failed to record block {} on {}, executing its triggers anyway: {}",
+							block.block_number,
+							block.network_slug,
+							e
+						);
 					}
-				} => {}
-				_ = shutdown_rx.changed() => {
-					tracing::info!("Shutting down trigger handling task");
 				}
 			}
+
+			let trigger_scripts = active_monitors.read().await.trigger_scripts.clone();
+			let filtered_matches = run_trigger_filters(
+				&block.processing_results,
+				&block.network_slug,
+				&trigger_scripts,
+				block.block.as_deref(),
+			)
+			.await;
+
+			// Alerts only transition on the matches that are notified
+			let transitions = if updates_alerts {
+				alert_states.update(&block.network_slug, block.block_number, &filtered_matches)
+			} else {
+				AlertTransitions::default()
+			};
+			alert_states
+				.persist(&block.network_slug, &transitions)
+				.await;
+			let mut firing_alerts: HashSet<&str> = transitions
+				.triggered
+				.iter()
+				.map(|fired_match| aggregation::match_monitor(fired_match).name.as_str())
+				.collect();
+			let mut opened_batches = Vec::new();
+			for monitor_match in &filtered_matches {
+				if let Some(match_store) = &match_store {
+					let record =
+						MatchRecord::new(monitor_match, block.block_number, chrono::Utc::now());
+					if let Err(e) = match_store.save(&record).await {
+						tracing::error!(
+							"Failed to record match of monitor {}: {}",
+							record.monitor_name,
+							e
+						);
+					}
+				}
+				// Sending only fails when nothing is subscribed
+				let _ = match_tx.send(monitor_match.clone());
+
+				let monitor = aggregation::match_monitor(monitor_match);
+				// Only the first match of an alert firing at this block is notified
+				if monitor.notify_on_resolve
+					&& updates_alerts
+					&& monitor_match.severity() == MatchSeverity::Alert
+					&& !firing_alerts.remove(monitor.name.as_str())
+				{
+					continue;
+				}
+				if monitor.aggregate_across_networks
+					&& monitor_match.severity() == MatchSeverity::Alert
+				{
+					if let Some(flush_at) = aggregator.push(monitor_match.clone()) {
+						opened_batches.push((monitor.name.clone(), flush_at));
+					}
+					continue;
+				}
+				if let Err(e) =
+					handle_match(monitor_match.clone(), &*trigger_service, &trigger_scripts).await
+				{
+					TriggerError::execution_error(e.to_string(), Some(e.into()), None);
+				}
+			}
+
+			for fired_match in &transitions.resolved {
+				execute_resolved_alert(
+					fired_match,
+					block.block_number,
+					&*trigger_service,
+					&trigger_scripts,
+				)
+				.await;
+			}
+
+			// Notify the batches opened by this block once their window elapsed
+			opened_batches.sort_by_key(|(_, flush_at)| *flush_at);
+			for (monitor_name, flush_at) in opened_batches {
+				if !*shutdown_rx.borrow() {
+					tokio::select! {
+						_ = tokio::time::sleep_until(flush_at.into()) => {}
+						_ = shutdown_rx.changed() => {}
+					}
+				}
+				let matches = aggregator.take(&monitor_name);
+				execute_aggregated_matches(&matches, &*trigger_service, &trigger_scripts).await;
+			}
 		})
 	})
 }
 
+/// Creates a stall handler that executes the stall trigger of a network when it stops
+/// processing new blocks.
+///
+/// The following variables are available in the trigger templates:
+/// `network.slug`, `network.name`, `stall.last_block`, `stall.duration_ms` and
+/// `stall.threshold_ms`.
+///
+/// # Arguments
+/// * `trigger_execution_service` - Service for executing triggers
+///
+/// # Returns
+/// Returns a handler to register with the stall detector
+pub fn create_stall_handler<T: TriggerRepositoryTrait + Send + Sync + 'static>(
+	trigger_execution_service: Arc<TriggerExecutionService<T>>,
+) -> StallHandler {
+	Arc::new(move |event: StallEvent| {
+		let trigger_execution_service = trigger_execution_service.clone();
+		Box::pin(async move {
+			let Some(trigger_slug) = event.network.stall_trigger.clone() else {
+				return;
+			};
+
+			let variables = HashMap::from([
+				("network.slug".to_string(), event.network.slug.clone()),
+				("network.name".to_string(), event.network.name.clone()),
+				("stall.last_block".to_string(), event.last_block.to_string()),
+				(
+					"stall.duration_ms".to_string(),
+					event.stalled_for.as_millis().to_string(),
+				),
+				(
+					"stall.threshold_ms".to_string(),
+					event
+						.network
+						.stall_threshold_ms
+						.unwrap_or_default()
+						.to_string(),
+				),
+			]);
+
+			if let Err(e) = trigger_execution_service
+				.execute_alert(&trigger_slug, variables)
+				.await
+			{
+				tracing::error!(
+					network = %event.network.slug,
+					"Failed to execute stall trigger {}: {}",
+					trigger_slug,
+					e
+				);
+			}
+		}) as BoxFuture<'static, ()>
+	})
+}
+
 /// Checks if a network has any active monitors.
 ///
+/// Monitors are active unless paused indefinitely, see [`Monitor::is_paused_indefinitely`].
+/// Monitors paused until a later time count, so that their network is watched once they
+/// resume.
+///
 /// # Arguments
 /// * `monitors` - List of monitors to check
 /// * `network_slug` - Network identifier to check for
@@ -429,20 +905,72 @@ pub fn create_trigger_handler<S: TriggerExecutionServiceTrait + Send + Sync + 's
 pub fn has_active_monitors(monitors: &[Monitor], network_slug: &String) -> bool {
 	monitors
 		.iter()
-		.any(|m| m.networks.contains(network_slug) && !m.paused)
+		.any(|m| m.networks.contains(network_slug) && !m.is_paused_indefinitely())
+}
+
+/// Selects the networks to start block watchers for.
+///
+/// A network is watched when it is enabled and has at least one active monitor.
+///
+/// # Arguments
+/// * `networks` - HashMap of networks to select from
+/// * `monitors` - List of monitors to check
+///
+/// # Returns
+/// Returns the networks that should be watched
+pub fn get_watched_networks(
+	networks: &HashMap<String, Network>,
+	monitors: &[Monitor],
+) -> Vec<Network> {
+	networks
+		.values()
+		.filter(|network| network.is_enabled() && has_active_monitors(monitors, &network.slug))
+		.cloned()
+		.collect()
+}
+
+/// Returns the distinct cron schedules the active monitors of a network are evaluated on.
+///
+/// The network's own schedule comes first when any monitor uses it, followed by the
+/// schedules of the monitors overriding it, in lexicographic order. Monitors paused until a
+/// later time keep their schedule, to be evaluated on it once they resume.
+///
+/// # Arguments
+/// * `network` - Network to get the schedules of
+/// * `monitors` - List of monitors to check
+///
+/// # Returns
+/// Returns the schedules to run block watcher jobs on for the network
+pub fn get_network_schedules(network: &Network, monitors: &[Monitor]) -> Vec<String> {
+	let mut schedules: Vec<String> = monitors
+		.iter()
+		.filter(|m| m.networks.contains(&network.slug) && !m.is_paused_indefinitely())
+		.map(|m| m.effective_cron_schedule(network).to_string())
+		.collect();
+	schedules.sort_by(|a, b| {
+		(*a != network.cron_schedule)
+			.cmp(&(*b != network.cron_schedule))
+			.then_with(|| a.cmp(b))
+	});
+	schedules.dedup();
+	schedules
 }
 
-/// Filters out paused monitors from the provided collection.
+/// Filters out indefinitely paused monitors from the provided collection.
+///
+/// Monitors with `paused_until` set are kept, whether or not it has passed, so that the
+/// monitors paused until a later time resume without a restart. Their pause is applied to
+/// every block by [`filter_network_monitors`].
 ///
 /// # Arguments
 /// * `monitors` - HashMap of monitors to filter
 ///
 /// # Returns
-/// Returns a vector containing only active (non-paused) monitors
+/// Returns a vector containing the monitors that are or will become active
 fn filter_active_monitors(monitors: HashMap<String, Monitor>) -> Vec<Monitor> {
 	monitors
 		.into_values()
-		.filter(|m| !m.paused)
+		.filter(|m| !m.is_paused_indefinitely())
 		.collect::<Vec<_>>()
 }
 
@@ -451,23 +979,78 @@ fn filter_active_monitors(monitors: HashMap<String, Monitor>) -> Vec<Monitor> {
 /// # Arguments
 /// * `monitors` - List of monitors to filter
 /// * `network_slug` - Network identifier to filter by
+/// * `now` - Current time, monitors paused at it being filtered out
 ///
 /// # Returns
-/// Returns a vector of monitors that are configured for the specified network
-fn filter_network_monitors(monitors: &[Monitor], network_slug: &String) -> Vec<Monitor> {
+/// Returns a vector of the unpaused monitors that are configured for the specified network
+fn filter_network_monitors(
+	monitors: &[Monitor],
+	network_slug: &String,
+	now: chrono::DateTime<chrono::Utc>,
+) -> Vec<Monitor> {
 	monitors
 		.iter()
-		.filter(|m| m.networks.contains(network_slug))
+		.filter(|m| m.networks.contains(network_slug) && !m.is_paused_at(now))
 		.cloned()
 		.collect()
 }
 
+/// Filters monitors evaluated by the block watcher job that processed a block.
+///
+/// Jobs pass the network with `cron_schedule` set to the schedule they run on. A monitor is
+/// evaluated when its own schedule, or `configured_schedule` if it has none, is that schedule.
+///
+/// # Arguments
+/// * `monitors` - Monitors applicable to the network
+/// * `network` - Network as passed by the block watcher job
+/// * `configured_schedule` - Schedule of the network in its configuration
+fn filter_scheduled_monitors(
+	monitors: Vec<Monitor>,
+	network: &Network,
+	configured_schedule: &str,
+) -> Vec<Monitor> {
+	monitors
+		.into_iter()
+		.filter(|m| {
+			m.cron_schedule.as_deref().unwrap_or(configured_schedule) == network.cron_schedule
+		})
+		.collect()
+}
+
+/// Filters monitors whose active window contains a block.
+///
+/// The block is placed in time by its timestamp, so that processing past blocks applies the
+/// monitors that were active when they were produced. Blocks without a timestamp use the
+/// current time.
+///
+/// # Arguments
+/// * `monitors` - Monitors applicable to the block
+/// * `block` - Block to filter the monitors for
+fn filter_windowed_monitors(monitors: Vec<Monitor>, block: &BlockType) -> Vec<Monitor> {
+	let block_time = block
+		.timestamp()
+		.and_then(|timestamp| i64::try_from(timestamp).ok())
+		.and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp, 0))
+		.unwrap_or_else(chrono::Utc::now);
+	monitors
+		.into_iter()
+		.filter(|m| m.is_active_at(block_time))
+		.collect()
+}
+
 async fn execute_trigger_condition(
 	trigger_condition: &TriggerConditions,
 	monitor_match: &MonitorMatch,
 	script_content: &(ScriptLanguage, String),
+	block: Option<&BlockType>,
 ) -> bool {
-	let executor = ScriptExecutorFactory::create(&script_content.0, &script_content.1);
+	let executor = match ScriptExecutorFactory::create(&script_content.0, &script_content.1) {
+		Ok(executor) => executor,
+		Err(e) => {
+			tracing::error!("Failed to create trigger condition executor: {}", e);
+			return false;
+		}
+	};
 
 	let result = executor
 		.execute(
@@ -475,6 +1058,11 @@ async fn execute_trigger_condition(
 			&trigger_condition.timeout_ms,
 			trigger_condition.arguments.as_deref(),
 			false,
+			&ScriptLimits {
+				max_memory_mb: trigger_condition.max_memory_mb,
+				max_output_bytes: trigger_condition.max_output_bytes,
+			},
+			block.filter(|_| trigger_condition.include_block_context),
 		)
 		.await;
 
@@ -488,43 +1076,72 @@ async fn execute_trigger_condition(
 	}
 }
 
+/// Runs the trigger condition scripts of each match and drops the matches they filter out.
+///
+/// A trigger condition script returning `true` votes to filter the match out. The votes of a
+/// monitor's scripts are combined according to its `condition_logic`:
+///
+/// | Script results   | `Or` (default) | `And`      |
+/// |------------------|----------------|------------|
+/// | no conditions    | keep           | keep       |
+/// | all `false`      | keep           | keep       |
+/// | some `true`      | filter out     | keep       |
+/// | all `true`       | filter out     | filter out |
+///
+/// Scripts that cannot be found or fail to execute count as `false`. The `block` of the matches,
+/// if any, is passed to the scripts with `include_block_context`.
 async fn run_trigger_filters(
 	matches: &[MonitorMatch],
 	_network: &str,
 	trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+	block: Option<&BlockType>,
 ) -> Vec<MonitorMatch> {
 	let mut filtered_matches = vec![];
 
 	for monitor_match in matches {
-		let mut is_filtered = false;
-		let trigger_conditions = match monitor_match {
-			MonitorMatch::EVM(evm_match) => &evm_match.monitor.trigger_conditions,
-			MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor.trigger_conditions,
-			MonitorMatch::Midnight(midnight_match) => &midnight_match.monitor.trigger_conditions,
+		let monitor = match monitor_match {
+			MonitorMatch::EVM(evm_match) => &evm_match.monitor,
+			MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor,
+			MonitorMatch::Midnight(midnight_match) => &midnight_match.monitor,
+			MonitorMatch::Solana(solana_match) => &solana_match.monitor,
 		};
 
-		for trigger_condition in trigger_conditions {
-			let monitor_name = match monitor_match {
-				MonitorMatch::EVM(evm_match) => evm_match.monitor.name.clone(),
-				MonitorMatch::Stellar(stellar_match) => stellar_match.monitor.name.clone(),
-				MonitorMatch::Midnight(midnight_match) => midnight_match.monitor.name.clone(),
-			};
+		let mut is_filtered = monitor.condition_logic == ConditionLogic::And
+			&& !monitor.trigger_conditions.is_empty();
 
+		for trigger_condition in &monitor.trigger_conditions {
 			let script_content = trigger_scripts
 				.get(&format!(
 					"{}|{}",
-					normalize_string(&monitor_name),
+					normalize_string(&monitor.name),
 					trigger_condition.script_path
 				))
 				.ok_or_else(|| {
 					ScriptError::execution_error("Script content not found".to_string(), None, None)
 				});
-			if let Ok(script_content) = script_content {
-				if execute_trigger_condition(trigger_condition, monitor_match, script_content).await
-				{
+			let result = match script_content {
+				Ok(script_content) => {
+					execute_trigger_condition(
+						trigger_condition,
+						monitor_match,
+						script_content,
+						block,
+					)
+					.await
+				}
+				Err(_) => false,
+			};
+
+			match monitor.condition_logic {
+				ConditionLogic::Or if result => {
 					is_filtered = true;
 					break;
 				}
+				ConditionLogic::And if !result => {
+					is_filtered = false;
+					break;
+				}
+				_ => {}
 			}
 		}
 		if !is_filtered {
@@ -540,11 +1157,16 @@ mod tests {
 	use super::*;
 	use crate::{
 		models::{
-			EVMMonitorMatch, EVMReceiptLog, EVMTransaction, EVMTransactionReceipt, MatchConditions,
-			Monitor, MonitorMatch, ScriptLanguage, StellarBlock, StellarMonitorMatch,
-			StellarTransaction, StellarTransactionInfo, TriggerConditions,
+			EVMBlock, EVMConfirmationState, EVMMonitorMatch, EVMReceiptLog, EVMTransaction,
+			EVMTransactionReceipt, MatchConditions, Monitor, MonitorMatch, ScriptLanguage,
+			StellarBlock, StellarLedgerInfo, StellarMonitorMatch, StellarTransaction,
+			StellarTransactionInfo, TriggerConditions,
+		},
+		services::trigger::{register_script_executor, ScriptExecutor},
+		utils::tests::{
+			builders::{evm::monitor::MonitorBuilder, network::NetworkBuilder},
+			evm::{receipt::ReceiptBuilder, transaction::TransactionBuilder},
 		},
-		utils::tests::{builders::evm::monitor::MonitorBuilder, evm::receipt::ReceiptBuilder},
 	};
 	use alloy::{
 		consensus::{transaction::Recovered, Signed, TxEnvelope},
@@ -640,8 +1262,15 @@ mod tests {
 					functions: vec![],
 					events: vec![],
 					transactions: vec![],
+					token_transfers: vec![],
+					block_conditions: vec![],
+					balance_conditions: vec![],
+					state_conditions: vec![],
 				},
 				matched_on_args: None,
+				confirmation_state: EVMConfirmationState::Confirmed,
+				severity: Default::default(),
+				sender_verified: None,
 			})),
 			BlockChainType::Stellar => MonitorMatch::Stellar(Box::new(StellarMonitorMatch {
 				monitor: create_test_monitor("test", vec![], false, script_path),
@@ -652,10 +1281,16 @@ mod tests {
 					functions: vec![],
 					events: vec![],
 					transactions: vec![],
+					token_transfers: vec![],
+					block_conditions: vec![],
+					balance_conditions: vec![],
+					state_conditions: vec![],
 				},
 				matched_on_args: None,
+				severity: Default::default(),
 			})),
 			BlockChainType::Midnight => unimplemented!(),
+			BlockChainType::Solana => unimplemented!(),
 		}
 	}
 
@@ -674,8 +1309,15 @@ mod tests {
 					functions: vec![],
 					events: vec![],
 					transactions: vec![],
+					token_transfers: vec![],
+					block_conditions: vec![],
+					balance_conditions: vec![],
+					state_conditions: vec![],
 				},
 				matched_on_args: None,
+				confirmation_state: EVMConfirmationState::Confirmed,
+				severity: Default::default(),
+				sender_verified: None,
 			})),
 			BlockChainType::Stellar => MonitorMatch::Stellar(Box::new(StellarMonitorMatch {
 				monitor,
@@ -686,10 +1328,16 @@ mod tests {
 					functions: vec![],
 					events: vec![],
 					transactions: vec![],
+					token_transfers: vec![],
+					block_conditions: vec![],
+					balance_conditions: vec![],
+					state_conditions: vec![],
 				},
 				matched_on_args: None,
+				severity: Default::default(),
 			})),
 			BlockChainType::Midnight => unimplemented!(),
+			BlockChainType::Solana => unimplemented!(),
 		}
 	}
 
@@ -735,6 +1383,38 @@ mod tests {
 		));
 	}
 
+	#[test]
+	fn test_get_watched_networks_skips_disabled_networks() {
+		let monitors = vec![create_test_monitor(
+			"1",
+			vec!["ethereum_mainnet", "ethereum_sepolia"],
+			false,
+			None,
+		)];
+		let networks = HashMap::from([
+			(
+				"ethereum_mainnet".to_string(),
+				NetworkBuilder::new().slug("ethereum_mainnet").build(),
+			),
+			(
+				"ethereum_sepolia".to_string(),
+				NetworkBuilder::new()
+					.slug("ethereum_sepolia")
+					.enabled(false)
+					.build(),
+			),
+			(
+				"stellar_mainnet".to_string(),
+				NetworkBuilder::new().slug("stellar_mainnet").build(),
+			),
+		]);
+
+		let watched = get_watched_networks(&networks, &monitors);
+
+		assert_eq!(watched.len(), 1);
+		assert_eq!(watched[0].slug, "ethereum_mainnet");
+	}
+
 	#[test]
 	fn test_filter_active_monitors() {
 		let mut monitors = HashMap::new();
@@ -756,6 +1436,77 @@ mod tests {
 		assert!(active_monitors.iter().all(|m| !m.paused));
 	}
 
+	#[test]
+	fn test_filter_active_monitors_with_paused_until() {
+		let now = chrono::Utc::now();
+		let hour = chrono::Duration::hours(1);
+		let monitors = HashMap::from([
+			(
+				"resumed".to_string(),
+				MonitorBuilder::new()
+					.name("resumed")
+					.paused(true)
+					.paused_until(now - hour)
+					.build(),
+			),
+			(
+				"scheduled".to_string(),
+				MonitorBuilder::new()
+					.name("scheduled")
+					.paused_until(now + hour)
+					.build(),
+			),
+			(
+				"paused".to_string(),
+				MonitorBuilder::new().name("paused").paused(true).build(),
+			),
+		]);
+
+		let mut active_monitors: Vec<String> = filter_active_monitors(monitors)
+			.into_iter()
+			.map(|m| m.name)
+			.collect();
+		active_monitors.sort();
+		assert_eq!(active_monitors, vec!["resumed", "scheduled"]);
+
+		// The network of a monitor paused until a later time is watched for it to resume
+		let networks = vec!["ethereum_mainnet".to_string()];
+		let scheduled = MonitorBuilder::new()
+			.networks(networks.clone())
+			.paused_until(now + hour)
+			.build();
+		assert!(has_active_monitors(&[scheduled], &networks[0]));
+		let paused = MonitorBuilder::new()
+			.networks(networks.clone())
+			.paused(true)
+			.build();
+		assert!(!has_active_monitors(&[paused], &networks[0]));
+		let resumed = MonitorBuilder::new()
+			.networks(networks.clone())
+			.paused(true)
+			.paused_until(now - hour)
+			.build();
+		assert!(has_active_monitors(&[resumed], &networks[0]));
+	}
+
+	#[test]
+	fn test_paused_until_boundary() {
+		let paused_until = chrono::Utc::now();
+		let monitor = MonitorBuilder::new()
+			.paused(true)
+			.paused_until(paused_until)
+			.build();
+
+		assert!(monitor.is_paused_at(paused_until - chrono::Duration::milliseconds(1)));
+		assert!(!monitor.is_paused_at(paused_until));
+		assert!(!monitor.is_paused_at(paused_until + chrono::Duration::milliseconds(1)));
+
+		// Without paused_until, a paused monitor stays paused
+		let monitor = MonitorBuilder::new().paused(true).build();
+		assert!(monitor.is_paused_at(paused_until + chrono::Duration::days(365)));
+		assert!(!MonitorBuilder::new().build().is_paused_at(paused_until));
+	}
+
 	#[test]
 	fn test_filter_network_monitors() {
 		let monitors = vec![
@@ -769,22 +1520,134 @@ mod tests {
 			),
 		];
 
-		let eth_monitors = filter_network_monitors(&monitors, &"ethereum_mainnet".to_string());
+		let now = chrono::Utc::now();
+
+		let eth_monitors = filter_network_monitors(&monitors, &"ethereum_mainnet".to_string(), now);
 		assert_eq!(eth_monitors.len(), 2);
 		assert!(eth_monitors
 			.iter()
 			.all(|m| m.networks.contains(&"ethereum_mainnet".to_string())));
 
-		let stellar_monitors = filter_network_monitors(&monitors, &"stellar_mainnet".to_string());
-		assert_eq!(stellar_monitors.len(), 2);
-		assert!(stellar_monitors
-			.iter()
-			.all(|m| m.networks.contains(&"stellar_mainnet".to_string())));
+		// Paused monitors are filtered out
+		let stellar_monitors =
+			filter_network_monitors(&monitors, &"stellar_mainnet".to_string(), now);
+		assert_eq!(stellar_monitors.len(), 1);
+		assert_eq!(stellar_monitors[0].name, "3");
 
-		let midnight_monitors = filter_network_monitors(&monitors, &"midnight_mainnet".to_string());
+		let midnight_monitors =
+			filter_network_monitors(&monitors, &"midnight_mainnet".to_string(), now);
 		assert!(midnight_monitors.is_empty());
 	}
 
+	#[test]
+	fn test_filter_network_monitors_resumes_at_paused_until() {
+		let paused_until = chrono::Utc::now();
+		let network_slug = "ethereum_mainnet".to_string();
+		let monitors = vec![MonitorBuilder::new()
+			.networks(vec![network_slug.clone()])
+			.paused(true)
+			.paused_until(paused_until)
+			.build()];
+
+		let second = chrono::Duration::seconds(1);
+		assert!(
+			filter_network_monitors(&monitors, &network_slug, paused_until - second).is_empty()
+		);
+		assert_eq!(
+			filter_network_monitors(&monitors, &network_slug, paused_until).len(),
+			1
+		);
+	}
+
+	#[test]
+	fn test_get_network_schedules() {
+		let network = NetworkBuilder::new()
+			.slug("ethereum_mainnet")
+			.cron_schedule("*/15 * * * * *")
+			.build();
+		let mut hourly = create_test_monitor("1", vec!["ethereum_mainnet"], false, None);
+		hourly.cron_schedule = Some("0 0 * * * *".to_string());
+		let mut paused = create_test_monitor("2", vec!["ethereum_mainnet"], true, None);
+		paused.cron_schedule = Some("0 0 0 * * *".to_string());
+		let monitors = vec![
+			hourly,
+			paused,
+			create_test_monitor("3", vec!["ethereum_mainnet"], false, None),
+			create_test_monitor("4", vec!["ethereum_mainnet"], false, None),
+			create_test_monitor("5", vec!["stellar_mainnet"], false, None),
+		];
+
+		assert_eq!(
+			get_network_schedules(&network, &monitors),
+			vec!["*/15 * * * * *".to_string(), "0 0 * * * *".to_string()]
+		);
+		assert_eq!(
+			get_network_schedules(&network, &monitors[..1]),
+			vec!["0 0 * * * *".to_string()]
+		);
+	}
+
+	#[test]
+	fn test_filter_scheduled_monitors() {
+		let network = NetworkBuilder::new()
+			.slug("ethereum_mainnet")
+			.cron_schedule("*/15 * * * * *")
+			.build();
+		let mut hourly = create_test_monitor("hourly", vec!["ethereum_mainnet"], false, None);
+		hourly.cron_schedule = Some("0 0 * * * *".to_string());
+		let monitors = vec![
+			hourly,
+			create_test_monitor("default", vec!["ethereum_mainnet"], false, None),
+		];
+		let names =
+			|monitors: Vec<Monitor>| monitors.into_iter().map(|m| m.name).collect::<Vec<_>>();
+
+		let default_job = filter_scheduled_monitors(monitors.clone(), &network, "*/15 * * * * *");
+		assert_eq!(names(default_job), vec!["default"]);
+
+		let hourly_network = Network {
+			cron_schedule: "0 0 * * * *".to_string(),
+			..network
+		};
+		let hourly_job = filter_scheduled_monitors(monitors, &hourly_network, "*/15 * * * * *");
+		assert_eq!(names(hourly_job), vec!["hourly"]);
+	}
+
+	#[test]
+	fn test_filter_windowed_monitors() {
+		let windowed = MonitorBuilder::new()
+			.name("launch")
+			.active_from("2024-01-01T09:00:00Z".parse().unwrap())
+			.active_until("2024-01-01T17:00:00Z".parse().unwrap())
+			.build();
+		let since = MonitorBuilder::new()
+			.name("since")
+			.active_from("2024-01-01T12:00:00Z".parse().unwrap())
+			.build();
+		let monitors = vec![windowed, since];
+		let block_at = |ledger_close_time: &str| {
+			BlockType::Stellar(Box::new(StellarBlock::from(StellarLedgerInfo {
+				ledger_close_time: ledger_close_time.to_string(),
+				..Default::default()
+			})))
+		};
+		let names = |block: BlockType| {
+			filter_windowed_monitors(monitors.clone(), &block)
+				.into_iter()
+				.map(|m| m.name)
+				.collect::<Vec<_>>()
+		};
+
+		// 2024-01-01T08:00:00Z, before both windows
+		assert!(names(block_at("1704096000")).is_empty());
+		// 2024-01-01T09:00:00Z, start of the launch window
+		assert_eq!(names(block_at("1704099600")), vec!["launch"]);
+		// 2024-01-01T13:00:00Z, inside both windows
+		assert_eq!(names(block_at("1704114000")), vec!["launch", "since"]);
+		// 2024-01-01T17:00:00Z, end of the launch window
+		assert_eq!(names(block_at("1704128400")), vec!["since"]);
+	}
+
 	#[tokio::test]
 	async fn test_run_trigger_filters_empty_matches() {
 		// Create empty matches vector
@@ -809,7 +1672,8 @@ print(False)
 		);
 
 		// Test the filter function
-		let filtered = run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts).await;
+		let filtered =
+			run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts, None).await;
 		assert!(filtered.is_empty());
 	}
 
@@ -839,7 +1703,8 @@ print(result)
 		);
 		let matches = vec![match_item.clone()];
 
-		let filtered = run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts).await;
+		let filtered =
+			run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts, None).await;
 		assert_eq!(filtered.len(), 1);
 		assert!(matches_equal(&filtered[0], &match_item));
 	}
@@ -870,7 +1735,79 @@ print(result)
 		);
 		let matches = vec![match_item.clone()];
 
-		let filtered = run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts).await;
+		let filtered =
+			run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts, None).await;
+		assert_eq!(filtered.len(), 1);
+	}
+
+	#[tokio::test]
+	async fn test_run_trigger_filters_with_block_context() {
+		// Filters the match out when its sender sent another transaction of the block
+		let script_content = r#"
+import sys
+import json
+
+data = json.loads(sys.stdin.read())
+transaction = data["monitor_match"]["EVM"]["transaction"]
+siblings = [
+    tx for tx in data["block"]["EVM"]["transactions"] if tx["hash"] != transaction["hash"]
+]
+print(any(tx.get("from") == transaction.get("from") for tx in siblings))
+"#;
+		let mut trigger_scripts = HashMap::new();
+		trigger_scripts.insert(
+			"monitor_test|condition.py".to_string(),
+			(ScriptLanguage::Python, script_content.to_string()),
+		);
+		let mut monitor = MonitorBuilder::new()
+			.name("monitor_test")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.trigger_condition("condition.py", 5000, ScriptLanguage::Python, None)
+			.build();
+		monitor.trigger_conditions[0].include_block_context = true;
+		let match_item = create_mock_monitor_match_from_monitor(BlockChainType::EVM, monitor);
+
+		let mut evm_block = EVMBlock::default();
+		evm_block.0.transactions = vec![create_test_evm_transaction()];
+		let block = BlockType::EVM(Box::new(evm_block.clone()));
+		let filtered = run_trigger_filters(
+			&[match_item.clone()],
+			"ethereum_mainnet",
+			&trigger_scripts,
+			Some(&block),
+		)
+		.await;
+		assert_eq!(filtered.len(), 1);
+
+		let sibling = TransactionBuilder::new()
+			.hash(B256::repeat_byte(1))
+			.from(Address::ZERO)
+			.build();
+		evm_block.0.transactions.push(sibling);
+		let block = BlockType::EVM(Box::new(evm_block));
+		let filtered = run_trigger_filters(
+			&[match_item.clone()],
+			"ethereum_mainnet",
+			&trigger_scripts,
+			Some(&block),
+		)
+		.await;
+		assert!(filtered.is_empty());
+
+		// Without the option, the script fails to read the block and the match is kept
+		let monitor = MonitorBuilder::new()
+			.name("monitor_test")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.trigger_condition("condition.py", 5000, ScriptLanguage::Python, None)
+			.build();
+		let match_item = create_mock_monitor_match_from_monitor(BlockChainType::EVM, monitor);
+		let filtered = run_trigger_filters(
+			&[match_item],
+			"ethereum_mainnet",
+			&trigger_scripts,
+			Some(&block),
+		)
+		.await;
 		assert_eq!(filtered.len(), 1);
 	}
 
@@ -883,6 +1820,9 @@ print(result)
 			script_path: temp_file.path().to_str().unwrap().to_string(),
 			timeout_ms: 1000,
 			arguments: None,
+			max_memory_mb: None,
+			max_output_bytes: None,
+			include_block_context: false,
 		};
 		let match_item = create_mock_monitor_match_from_path(
 			BlockChainType::EVM,
@@ -891,7 +1831,7 @@ print(result)
 		let script_content = (ScriptLanguage::Python, script_content.to_string());
 
 		let result =
-			execute_trigger_condition(&trigger_condition, &match_item, &script_content).await;
+			execute_trigger_condition(&trigger_condition, &match_item, &script_content, None).await;
 		assert!(!result); // Should be false when script returns false
 	}
 
@@ -904,6 +1844,9 @@ print(result)
 			script_path: temp_file.path().to_str().unwrap().to_string(),
 			timeout_ms: 1000,
 			arguments: None,
+			max_memory_mb: None,
+			max_output_bytes: None,
+			include_block_context: false,
 		};
 		let match_item = create_mock_monitor_match_from_path(
 			BlockChainType::EVM,
@@ -912,7 +1855,7 @@ print(result)
 		let script_content = (ScriptLanguage::Python, script_content.to_string());
 
 		let result =
-			execute_trigger_condition(&trigger_condition, &match_item, &script_content).await;
+			execute_trigger_condition(&trigger_condition, &match_item, &script_content, None).await;
 		assert!(!result); // Should be false when script errors
 	}
 
@@ -923,6 +1866,9 @@ print(result)
 			script_path: "non_existent_script.py".to_string(),
 			timeout_ms: 1000,
 			arguments: None,
+			max_memory_mb: None,
+			max_output_bytes: None,
+			include_block_context: false,
 		};
 		let match_item = create_mock_monitor_match_from_path(
 			BlockChainType::EVM,
@@ -931,10 +1877,75 @@ print(result)
 		let script_content = (ScriptLanguage::Python, "invalid script content".to_string());
 
 		let result =
-			execute_trigger_condition(&trigger_condition, &match_item, &script_content).await;
+			execute_trigger_condition(&trigger_condition, &match_item, &script_content, None).await;
 		assert!(!result); // Should be false for invalid script
 	}
 
+	/// Executor of a custom script language whose scripts are the result they return
+	struct DummyScriptExecutor {
+		script_content: String,
+	}
+
+	#[async_trait::async_trait]
+	impl ScriptExecutor for DummyScriptExecutor {
+		fn as_any(&self) -> &dyn std::any::Any {
+			self
+		}
+
+		async fn execute(
+			&self,
+			_input: MonitorMatch,
+			_timeout_ms: &u32,
+			_args: Option<&[String]>,
+			_from_custom_notification: bool,
+			_limits: &ScriptLimits,
+			_block: Option<&BlockType>,
+		) -> Result<bool, anyhow::Error> {
+			Ok(self.script_content.trim() == "true")
+		}
+	}
+
+	#[tokio::test]
+	async fn test_execute_trigger_condition_with_registered_executor() {
+		let language = ScriptLanguage::Custom("Dummy".to_string());
+		register_script_executor(language.clone(), "dummy", |script_content| {
+			Box::new(DummyScriptExecutor {
+				script_content: script_content.to_string(),
+			})
+		});
+		let trigger_condition = TriggerConditions {
+			language: language.clone(),
+			script_path: "condition.dummy".to_string(),
+			timeout_ms: 1000,
+			arguments: None,
+			max_memory_mb: None,
+			max_output_bytes: None,
+			include_block_context: false,
+		};
+		let match_item =
+			create_mock_monitor_match_from_path(BlockChainType::EVM, Some("condition.dummy"));
+
+		let script_content = (language.clone(), "true".to_string());
+		assert!(
+			execute_trigger_condition(&trigger_condition, &match_item, &script_content, None).await
+		);
+		let script_content = (language, "false".to_string());
+		assert!(
+			!execute_trigger_condition(&trigger_condition, &match_item, &script_content, None)
+				.await
+		);
+
+		// Languages without a registered executor never pass
+		let script_content = (
+			ScriptLanguage::Custom("Unknown".to_string()),
+			"true".to_string(),
+		);
+		assert!(
+			!execute_trigger_condition(&trigger_condition, &match_item, &script_content, None)
+				.await
+		);
+	}
+
 	#[tokio::test]
 	async fn test_run_trigger_filters_multiple_conditions_keep_match() {
 		// Create a monitor with two trigger conditions
@@ -981,7 +1992,8 @@ print(True)
 
 		// Run the filter with our test data
 		let matches = vec![match_item.clone()];
-		let filtered = run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts).await;
+		let filtered =
+			run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts, None).await;
 
 		assert_eq!(filtered.len(), 0);
 	}
@@ -1009,7 +2021,8 @@ print(True)
 		);
 
 		let matches = vec![match_item.clone()];
-		let filtered = run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts).await;
+		let filtered =
+			run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts, None).await;
 		assert_eq!(filtered.len(), 0);
 	}
 
@@ -1035,7 +2048,8 @@ print(True)
 		);
 
 		let matches = vec![match_item.clone()];
-		let filtered = run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts).await;
+		let filtered =
+			run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts, None).await;
 		assert_eq!(filtered.len(), 1);
 	}
 
@@ -1061,7 +2075,8 @@ print(True)
 		);
 
 		let matches = vec![match_item.clone()];
-		let filtered = run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts).await;
+		let filtered =
+			run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts, None).await;
 		assert_eq!(filtered.len(), 0);
 	}
 
@@ -1092,7 +2107,8 @@ print(True)
 		);
 
 		let matches = vec![match_item.clone()];
-		let filtered = run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts).await;
+		let filtered =
+			run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts, None).await;
 		assert_eq!(filtered.len(), 0);
 	}
 
@@ -1123,7 +2139,136 @@ print(True)
 		);
 
 		let matches = vec![match_item.clone()];
-		let filtered = run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts).await;
+		let filtered =
+			run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts, None).await;
+		assert_eq!(filtered.len(), 1);
+	}
+
+	async fn run_condition_combination(
+		condition_logic: ConditionLogic,
+		script_results: &[bool],
+	) -> usize {
+		let mut builder = MonitorBuilder::new()
+			.name("monitor_test")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.condition_logic(condition_logic);
+		let mut trigger_scripts = HashMap::new();
+		for (i, result) in script_results.iter().enumerate() {
+			let script_path = format!("condition{}.py", i + 1);
+			builder = builder.trigger_condition(&script_path, 1000, ScriptLanguage::Python, None);
+			trigger_scripts.insert(
+				format!("monitor_test|{}", script_path),
+				(
+					ScriptLanguage::Python,
+					format!("print({})", if *result { "True" } else { "False" }),
+				),
+			);
+		}
+
+		let match_item =
+			create_mock_monitor_match_from_monitor(BlockChainType::EVM, builder.build());
+		run_trigger_filters(&[match_item], "ethereum_mainnet", &trigger_scripts, None)
+			.await
+			.len()
+	}
+
+	#[tokio::test]
+	async fn test_run_trigger_filters_or_logic_two_combinations() {
+		assert_eq!(
+			run_condition_combination(ConditionLogic::Or, &[false, false]).await,
+			1
+		);
+		assert_eq!(
+			run_condition_combination(ConditionLogic::Or, &[true, false]).await,
+			0
+		);
+		assert_eq!(
+			run_condition_combination(ConditionLogic::Or, &[false, true]).await,
+			0
+		);
+		assert_eq!(
+			run_condition_combination(ConditionLogic::Or, &[true, true]).await,
+			0
+		);
+	}
+
+	#[tokio::test]
+	async fn test_run_trigger_filters_and_logic_two_combinations() {
+		assert_eq!(
+			run_condition_combination(ConditionLogic::And, &[false, false]).await,
+			1
+		);
+		assert_eq!(
+			run_condition_combination(ConditionLogic::And, &[true, false]).await,
+			1
+		);
+		assert_eq!(
+			run_condition_combination(ConditionLogic::And, &[false, true]).await,
+			1
+		);
+		assert_eq!(
+			run_condition_combination(ConditionLogic::And, &[true, true]).await,
+			0
+		);
+	}
+
+	#[tokio::test]
+	async fn test_run_trigger_filters_or_logic_three_combinations() {
+		assert_eq!(
+			run_condition_combination(ConditionLogic::Or, &[false, false, false]).await,
+			1
+		);
+		assert_eq!(
+			run_condition_combination(ConditionLogic::Or, &[false, false, true]).await,
+			0
+		);
+		assert_eq!(
+			run_condition_combination(ConditionLogic::Or, &[true, true, true]).await,
+			0
+		);
+	}
+
+	#[tokio::test]
+	async fn test_run_trigger_filters_and_logic_three_combinations() {
+		assert_eq!(
+			run_condition_combination(ConditionLogic::And, &[false, false, false]).await,
+			1
+		);
+		assert_eq!(
+			run_condition_combination(ConditionLogic::And, &[true, false, true]).await,
+			1
+		);
+		assert_eq!(
+			run_condition_combination(ConditionLogic::And, &[true, true, false]).await,
+			1
+		);
+		assert_eq!(
+			run_condition_combination(ConditionLogic::And, &[true, true, true]).await,
+			0
+		);
+	}
+
+	#[tokio::test]
+	async fn test_run_trigger_filters_and_logic_missing_script_keeps_match() {
+		let monitor = MonitorBuilder::new()
+			.name("monitor_test")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.condition_logic(ConditionLogic::And)
+			.trigger_condition("condition1.py", 1000, ScriptLanguage::Python, None)
+			.trigger_condition("missing.py", 1000, ScriptLanguage::Python, None)
+			.build();
+
+		let match_item = create_mock_monitor_match_from_monitor(BlockChainType::EVM, monitor);
+
+		let mut trigger_scripts = HashMap::new();
+		trigger_scripts.insert(
+			"monitor_test|condition1.py".to_string(),
+			(ScriptLanguage::Python, "print(True)".to_string()),
+		);
+
+		let matches = vec![match_item.clone()];
+		let filtered =
+			run_trigger_filters(&matches, "ethereum_mainnet", &trigger_scripts, None).await;
 		assert_eq!(filtered.len(), 1);
 	}
 
@@ -1148,7 +2293,8 @@ print(False)
 			),
 		);
 
-		let filtered = run_trigger_filters(&matches, "stellar_mainnet", &trigger_scripts).await;
+		let filtered =
+			run_trigger_filters(&matches, "stellar_mainnet", &trigger_scripts, None).await;
 		assert!(filtered.is_empty());
 	}
 
@@ -1178,7 +2324,8 @@ print(result)
 		);
 		let matches = vec![match_item.clone()];
 
-		let filtered = run_trigger_filters(&matches, "stellar_mainnet", &trigger_scripts).await;
+		let filtered =
+			run_trigger_filters(&matches, "stellar_mainnet", &trigger_scripts, None).await;
 		assert_eq!(filtered.len(), 1);
 		assert!(matches_equal(&filtered[0], &match_item));
 	}
@@ -1205,7 +2352,8 @@ print(result)
 		);
 
 		let matches = vec![match_item.clone()];
-		let filtered = run_trigger_filters(&matches, "stellar_mainnet", &trigger_scripts).await;
+		let filtered =
+			run_trigger_filters(&matches, "stellar_mainnet", &trigger_scripts, None).await;
 		assert_eq!(filtered.len(), 0); // Match should be filtered out because condition2 returns true
 	}
 }
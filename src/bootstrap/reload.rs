@@ -0,0 +1,247 @@
+//! Reloading of the monitor configurations without a restart.
+//!
+//! The block and trigger handlers read the active monitors from a [`SharedActiveMonitors`] on
+//! every block, so that a reload swapping it applies from the next block on while the block
+//! watchers keep their state. Reloads only replace the monitors: networks and triggers are
+//! loaded once at startup, and block watchers aren't started for networks that had no active
+//! monitor.
+
+use anyhow::Context;
+use std::{
+	collections::HashMap,
+	path::{Path, PathBuf},
+	sync::Arc,
+};
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+	models::{load_bundle, ContractSpec, Monitor, Network, ScriptLanguage},
+	repositories::{
+		MonitorRepository, MonitorRepositoryTrait, MonitorService, NetworkRepositoryTrait,
+		NetworkService, TriggerRepositoryTrait, TriggerService,
+	},
+	services::{
+		blockchain::ClientPoolTrait,
+		trigger::{TriggerExecutionService, TriggerExecutionServiceTrait},
+	},
+};
+
+use super::{filter_active_monitors, get_contract_specs};
+
+/// Monitors applied to new blocks, with the trigger condition scripts and contract specs they
+/// need
+#[derive(Debug, Clone, Default)]
+pub struct ActiveMonitors {
+	/// Monitors that aren't paused
+	pub monitors: Vec<Monitor>,
+	/// Trigger condition scripts of the monitors, see
+	/// [`TriggerExecutionServiceTrait::load_scripts`]
+	pub trigger_scripts: HashMap<String, (ScriptLanguage, String)>,
+	/// Contract specs of the addresses watched by the monitors, see [`get_contract_specs`]
+	pub contract_specs: Vec<(String, ContractSpec)>,
+}
+
+/// Active monitors shared between the block and trigger handlers, the pending transaction
+/// watchers and the reloader
+pub type SharedActiveMonitors = Arc<RwLock<ActiveMonitors>>;
+
+/// Reloads the monitor configurations and swaps the active monitors
+///
+/// A reload is validated as a whole before anything is swapped, so a failed reload keeps the
+/// previous monitors.
+pub struct MonitorReloader<M, N, T, P>
+where
+	M: MonitorRepositoryTrait<N, T> + Send + Sync + 'static,
+	N: NetworkRepositoryTrait + Send + Sync + 'static,
+	T: TriggerRepositoryTrait + Send + Sync + 'static,
+	P: ClientPoolTrait + 'static,
+{
+	monitors_path: Option<PathBuf>,
+	bundle_path: Option<PathBuf>,
+	monitor_service: Arc<Mutex<MonitorService<M, N, T>>>,
+	network_service: Arc<Mutex<NetworkService<N>>>,
+	trigger_service: Arc<Mutex<TriggerService<T>>>,
+	trigger_execution_service: Arc<TriggerExecutionService<T>>,
+	client_pool: Arc<P>,
+	watched_networks: Vec<Network>,
+	/// Cron schedules of the block watcher jobs started for each watched network
+	network_schedules: HashMap<String, Vec<String>>,
+	active_monitors: SharedActiveMonitors,
+	/// Serializes reloads, so that a slower reload can't overwrite a more recent one
+	reload_lock: Mutex<()>,
+}
+
+impl<M, N, T, P> MonitorReloader<M, N, T, P>
+where
+	M: MonitorRepositoryTrait<N, T> + Send + Sync + 'static,
+	N: NetworkRepositoryTrait + Send + Sync + 'static,
+	T: TriggerRepositoryTrait + Send + Sync + 'static,
+	P: ClientPoolTrait + 'static,
+{
+	/// Creates a reloader loading the monitors from the default configuration directory
+	///
+	/// # Arguments
+	/// * `monitor_service` - Monitor service replaced on reload
+	/// * `network_service` - Networks the monitors are validated against
+	/// * `trigger_service` - Triggers the monitors are validated against
+	/// * `trigger_execution_service` - Service loading the trigger condition scripts
+	/// * `client_pool` - Client pool used to fetch contract specs
+	/// * `watched_networks` - Networks with a running block watcher
+	/// * `network_schedules` - Cron schedules of the block watcher jobs of each network, see
+	///   [`get_network_schedules`](crate::bootstrap::get_network_schedules)
+	/// * `active_monitors` - Active monitors read by the block and trigger handlers
+	pub fn new(
+		monitor_service: Arc<Mutex<MonitorService<M, N, T>>>,
+		network_service: Arc<Mutex<NetworkService<N>>>,
+		trigger_service: Arc<Mutex<TriggerService<T>>>,
+		trigger_execution_service: Arc<TriggerExecutionService<T>>,
+		client_pool: Arc<P>,
+		watched_networks: Vec<Network>,
+		network_schedules: HashMap<String, Vec<String>>,
+		active_monitors: SharedActiveMonitors,
+	) -> Self {
+		Self {
+			monitors_path: None,
+			bundle_path: None,
+			monitor_service,
+			network_service,
+			trigger_service,
+			trigger_execution_service,
+			client_pool,
+			watched_networks,
+			network_schedules,
+			active_monitors,
+			reload_lock: Mutex::new(()),
+		}
+	}
+
+	/// Loads the monitors from the given directory instead of the default one
+	pub fn with_monitors_path(mut self, path: &Path) -> Self {
+		self.monitors_path = Some(path.to_path_buf());
+		self
+	}
+
+	/// Loads the monitors from the given configuration bundle instead of a directory
+	pub fn with_bundle_path(mut self, path: &Path) -> Self {
+		self.bundle_path = Some(path.to_path_buf());
+		self
+	}
+
+	/// Reloads the monitor configurations and swaps the active monitors
+	///
+	/// Monitors are validated against the networks and triggers loaded at startup. Monitors of
+	/// networks without a running block watcher are kept but only logged, as they won't be
+	/// applied until a restart. Monitors on a cron schedule no block watcher job runs on for
+	/// their network are rejected, as they would never be evaluated.
+	///
+	/// # Returns
+	/// * `anyhow::Result<usize>` - Number of active monitors after the reload
+	///
+	/// # Errors
+	/// Returns an error, keeping the previous monitors, if a monitor configuration can't be
+	/// loaded or is invalid, if its cron schedule has no block watcher job, or if its trigger
+	/// condition scripts can't be read
+	pub async fn reload(&self) -> anyhow::Result<usize> {
+		let _reload_guard = self.reload_lock.lock().await;
+
+		let network_service = self.network_service.lock().await.clone();
+		let trigger_service = self.trigger_service.lock().await.clone();
+
+		let repository = match &self.bundle_path {
+			Some(path) => {
+				let bundle = load_bundle(path)
+					.await
+					.with_context(|| "Failed to load configuration bundle")?;
+				MonitorRepository::<N, T>::validate_monitor_references(
+					&bundle.monitors,
+					&trigger_service.get_all(),
+					&network_service.get_all(),
+				)
+				.with_context(|| "Invalid monitor configuration")?;
+				M::new_with_monitors(bundle.monitors)
+			}
+			None => M::new(
+				self.monitors_path.as_deref(),
+				Some(network_service),
+				Some(trigger_service),
+			)
+			.await
+			.with_context(|| "Failed to load monitor configurations")?,
+		};
+		let monitor_service = MonitorService::<M, N, T>::new_with_repository(repository)?;
+
+		let monitors = filter_active_monitors(monitor_service.get_all());
+		for monitor in &monitors {
+			for network in self
+				.watched_networks
+				.iter()
+				.filter(|network| monitor.networks.contains(&network.slug))
+			{
+				let schedule = monitor.effective_cron_schedule(network);
+				let has_job = self
+					.network_schedules
+					.get(&network.slug)
+					.is_some_and(|schedules| schedules.iter().any(|s| s == schedule));
+				if !has_job {
+					anyhow::bail!(
+						"Monitor {} runs on cron schedule {} of network {}, which no block \
+						 watcher job runs on until a restart",
+						monitor.name,
+						schedule,
+						network.slug
+					);
+				}
+			}
+		}
+
+		let trigger_scripts = self
+			.trigger_execution_service
+			.load_scripts(&monitors)
+			.await
+			.with_context(|| "Failed to load trigger condition scripts")?;
+
+		for monitor in &monitors {
+			for network_slug in &monitor.networks {
+				if !self
+					.watched_networks
+					.iter()
+					.any(|network| &network.slug == network_slug)
+				{
+					tracing::warn!(
+						"Monitor {} won't be applied to network {} until a restart, as no block \
+						 watcher is running for it",
+						monitor.name,
+						network_slug
+					);
+				}
+			}
+		}
+
+		let network_monitors = self
+			.watched_networks
+			.iter()
+			.map(|network| {
+				(
+					network.clone(),
+					monitors
+						.iter()
+						.filter(|monitor| monitor.networks.contains(&network.slug))
+						.cloned()
+						.collect::<Vec<_>>(),
+				)
+			})
+			.collect::<Vec<_>>();
+		let contract_specs = get_contract_specs(&self.client_pool, &network_monitors).await;
+
+		let active_count = monitors.len();
+		*self.active_monitors.write().await = ActiveMonitors {
+			monitors,
+			trigger_scripts,
+			contract_specs,
+		};
+		*self.monitor_service.lock().await = monitor_service;
+
+		tracing::info!("Reloaded monitors, {} active", active_count);
+		Ok(active_count)
+	}
+}
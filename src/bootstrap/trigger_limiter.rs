@@ -0,0 +1,213 @@
+//! Limit of the trigger handling tasks.
+//!
+//! Every processed block with matches spawns a task handling them. Under a burst of matches
+//! these tasks would pile up faster than notifications can be delivered, so their concurrency
+//! is bounded and the excess is queued, up to a limit beyond which blocks are dropped.
+
+use std::{
+	env,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::utils::metrics::TRIGGER_TASKS_QUEUED;
+
+/// Environment variable overriding the number of blocks whose matches are handled concurrently
+const TRIGGER_TASK_CONCURRENCY_ENV: &str = "TRIGGER_TASK_CONCURRENCY";
+
+/// Environment variable overriding the number of blocks waiting for their matches to be handled
+const TRIGGER_TASK_QUEUE_SIZE_ENV: &str = "TRIGGER_TASK_QUEUE_SIZE";
+
+/// Default number of blocks whose matches are handled concurrently
+const DEFAULT_TRIGGER_TASK_CONCURRENCY: usize = 32;
+
+/// Default number of blocks waiting for their matches to be handled
+const DEFAULT_TRIGGER_TASK_QUEUE_SIZE: usize = 1024;
+
+/// Limits the tasks handling the matches of processed blocks
+///
+/// At most `max_concurrent` tasks handle matches at a time. Up to `max_queued` more tasks wait
+/// for one of them to complete, and further tasks are refused.
+#[derive(Debug, Clone)]
+pub struct TriggerTaskLimiter {
+	semaphore: Arc<Semaphore>,
+	max_concurrent: usize,
+	max_queued: usize,
+	queued: Arc<AtomicUsize>,
+}
+
+/// Slot of a trigger handling task, reserved by [`TriggerTaskLimiter::reserve`]
+#[derive(Debug)]
+pub enum TriggerTaskSlot {
+	/// The task can start right away
+	Acquired(OwnedSemaphorePermit),
+	/// The task has to wait for a running task to complete
+	Queued(QueuedTriggerTask),
+}
+
+/// Task waiting for a running task to complete, leaving the queue when dropped
+#[derive(Debug)]
+pub struct QueuedTriggerTask {
+	semaphore: Arc<Semaphore>,
+	queued: Arc<AtomicUsize>,
+}
+
+impl Drop for QueuedTriggerTask {
+	fn drop(&mut self) {
+		let queued = self.queued.fetch_sub(1, Ordering::SeqCst) - 1;
+		TRIGGER_TASKS_QUEUED.set(queued as f64);
+	}
+}
+
+impl TriggerTaskSlot {
+	/// Waits until the task can start
+	///
+	/// # Returns
+	/// The permit to hold while the task runs
+	pub async fn acquire(self) -> OwnedSemaphorePermit {
+		match self {
+			TriggerTaskSlot::Acquired(permit) => permit,
+			TriggerTaskSlot::Queued(task) => task
+				.semaphore
+				.clone()
+				.acquire_owned()
+				.await
+				.expect("trigger task semaphore is never closed"),
+		}
+	}
+}
+
+impl Default for TriggerTaskLimiter {
+	fn default() -> Self {
+		Self::new(
+			DEFAULT_TRIGGER_TASK_CONCURRENCY,
+			DEFAULT_TRIGGER_TASK_QUEUE_SIZE,
+		)
+	}
+}
+
+impl TriggerTaskLimiter {
+	/// Creates a limiter
+	///
+	/// # Arguments
+	/// * `max_concurrent` - Maximum number of tasks handling matches at a time, at least 1
+	/// * `max_queued` - Maximum number of tasks waiting to handle matches
+	pub fn new(max_concurrent: usize, max_queued: usize) -> Self {
+		let max_concurrent = max_concurrent.max(1);
+		Self {
+			semaphore: Arc::new(Semaphore::new(max_concurrent)),
+			max_concurrent,
+			max_queued,
+			queued: Arc::new(AtomicUsize::new(0)),
+		}
+	}
+
+	/// Creates the limiter configured by the environment
+	///
+	/// `TRIGGER_TASK_CONCURRENCY` sets the number of blocks whose matches are handled
+	/// concurrently, 32 by default, and `TRIGGER_TASK_QUEUE_SIZE` the number of blocks waiting,
+	/// 1024 by default.
+	///
+	/// # Errors
+	/// Returns an error if a variable is not a number, or if the concurrency is 0
+	pub fn from_env() -> Result<Self, anyhow::Error> {
+		let read = |name: &str, default: usize| match env::var(name) {
+			Ok(value) => value
+				.parse::<usize>()
+				.map_err(|e| anyhow::anyhow!("Invalid {}: {}", name, e)),
+			Err(_) => Ok(default),
+		};
+
+		let max_concurrent = read(
+			TRIGGER_TASK_CONCURRENCY_ENV,
+			DEFAULT_TRIGGER_TASK_CONCURRENCY,
+		)?;
+		if max_concurrent == 0 {
+			return Err(anyhow::anyhow!(
+				"{} must be at least 1",
+				TRIGGER_TASK_CONCURRENCY_ENV
+			));
+		}
+		let max_queued = read(TRIGGER_TASK_QUEUE_SIZE_ENV, DEFAULT_TRIGGER_TASK_QUEUE_SIZE)?;
+
+		Ok(Self::new(max_concurrent, max_queued))
+	}
+
+	/// Reserves the slot of a new task
+	///
+	/// # Returns
+	/// The slot of the task, or `None` if the queue is full and the task must be dropped
+	pub fn reserve(&self) -> Option<TriggerTaskSlot> {
+		if let Ok(permit) = self.semaphore.clone().try_acquire_owned() {
+			return Some(TriggerTaskSlot::Acquired(permit));
+		}
+
+		let queued = self
+			.queued
+			.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |queued| {
+				(queued < self.max_queued).then_some(queued + 1)
+			})
+			.ok()? + 1;
+		TRIGGER_TASKS_QUEUED.set(queued as f64);
+
+		Some(TriggerTaskSlot::Queued(QueuedTriggerTask {
+			semaphore: self.semaphore.clone(),
+			queued: self.queued.clone(),
+		}))
+	}
+
+	/// Returns the number of tasks handling matches
+	pub fn in_flight(&self) -> usize {
+		self.max_concurrent - self.semaphore.available_permits()
+	}
+
+	/// Returns the number of tasks waiting to handle matches
+	pub fn queued(&self) -> usize {
+		self.queued.load(Ordering::SeqCst)
+	}
+
+	/// Waits for the tasks handling matches to complete
+	///
+	/// # Arguments
+	/// * `timeout` - Maximum time to wait
+	///
+	/// # Returns
+	/// Whether all the tasks completed in time
+	pub async fn drain(&self, timeout: Duration) -> bool {
+		tokio::time::timeout(
+			timeout,
+			self.semaphore.acquire_many(self.max_concurrent as u32),
+		)
+		.await
+		.is_ok()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn test_reserve_queues_then_refuses() {
+		let limiter = TriggerTaskLimiter::new(1, 1);
+
+		let running = limiter.reserve().unwrap();
+		assert!(matches!(running, TriggerTaskSlot::Acquired(_)));
+		let waiting = limiter.reserve().unwrap();
+		assert!(matches!(waiting, TriggerTaskSlot::Queued(_)));
+		assert!(limiter.reserve().is_none());
+		assert_eq!(limiter.in_flight(), 1);
+		assert_eq!(limiter.queued(), 1);
+
+		// The waiting task starts once the running one completes
+		drop(running);
+		let _permit = waiting.acquire().await;
+		assert_eq!(limiter.in_flight(), 1);
+		assert_eq!(limiter.queued(), 0);
+		assert!(!limiter.drain(Duration::from_millis(10)).await);
+	}
+}
@@ -12,11 +12,14 @@
 //! - Services: Core functionality including block watching, filtering, and notifications
 //!
 //! # Flow
-//! 1. Loads configurations from the default directory
+//! 1. Loads configurations from the configuration directory (`config/` by default)
 //! 2. Initializes core services (monitoring, filtering, notifications)
 //! 3. Sets up blockchain watchers for networks with active monitors
 //! 4. Processes blocks and triggers notifications based on configured conditions
 //! 5. Handles graceful shutdown on Ctrl+C
+//!
+//! The process exits with a distinct code for each class of failure, listed in
+//! [`utils::exit_code`].
 
 pub mod bootstrap;
 pub mod models;
@@ -26,25 +29,41 @@ pub mod utils;
 
 use crate::{
 	bootstrap::{
-		create_block_handler, create_trigger_handler, get_contract_specs, has_active_monitors,
-		initialize_services, Result,
+		create_block_handler, create_stall_handler, create_trigger_handler, get_contract_specs,
+		get_network_schedules, get_watched_networks, has_active_monitors,
+		initialize_services_with_options, ActiveMonitors, AlertStates, InitOptions,
+		MonitorReloader, Result, TriggerTaskLimiter, MATCH_CHANNEL_CAPACITY,
+	},
+	models::{
+		config_schema, load_bundle, BlockChainType, ConfigKind, ConfigLoader, Monitor, Network,
+		ScriptLanguage,
 	},
-	models::{BlockChainType, Network, ScriptLanguage},
 	repositories::{
 		MonitorRepository, MonitorService, NetworkRepository, NetworkService, TriggerRepository,
 	},
 	services::{
+		admin::{admin_token_from_env, create_admin_server},
 		blockchain::{ClientPool, ClientPoolTrait},
-		blockwatcher::{BlockTracker, BlockTrackerTrait, BlockWatcherService, FileBlockStorage},
+		blockwatcher::{
+			create_processed_block_guard, stall_detector, watch_pending_transactions, BlockTracker,
+			BlockTrackerTrait, BlockWatcherService, FileBlockStorage, LeaderElection,
+		},
 		filter::FilterService,
+		storage::{match_store_from_env, MatchQuery},
 		trigger::{TriggerExecutionService, TriggerExecutionServiceTrait},
 	},
 	utils::{
 		constants::DOCUMENTATION_URL,
-		logging::setup_logging,
-		metrics::server::create_metrics_server,
+		exit_code::{CliError, ExitCode, WithExitCode},
+		introspection::{
+			render, summarize_monitors, summarize_networks, summarize_triggers, ListFormat,
+		},
+		logging::{setup_logging, shutdown_logging},
+		metrics::server::{create_metrics_server, MetricsBindAddress},
 		monitor::{
-			execution::{execute_monitor, MonitorExecutionConfig},
+			execution::{
+				execute_monitor, write_matches, MatchOutputFormat, MonitorExecutionConfig,
+			},
 			MonitorExecutionError,
 		},
 		parse_string_to_bytes_size,
@@ -55,11 +74,15 @@ use clap::Parser;
 use dotenvy::dotenv_override;
 use std::collections::HashMap;
 use std::env::{set_var, var};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::sync::{watch, Mutex};
+use tokio::sync::{broadcast, watch, Mutex, RwLock};
 use tokio_cron_scheduler::JobScheduler;
 use tracing::{error, info, instrument};
 
+/// Maximum time waited at shutdown for the blocks being handled to deliver their notifications
+const TRIGGER_TASKS_DRAIN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
 type MonitorServiceType = MonitorService<
 	MonitorRepository<NetworkRepository, TriggerRepository>,
 	NetworkRepository,
@@ -76,6 +99,8 @@ type MonitorServiceType = MonitorService<
 /// * `trigger_execution_service` - Service handling trigger execution
 /// * `active_monitors_trigger_scripts` - Map of active monitors and their trigger scripts
 /// * `raw_output` - Whether to print the raw output of the monitor execution
+/// * `output_file` - Optional file to write the matches to, in addition to the log
+/// * `output_format` - Format of the output file
 /// * `client_pool` - Client pool of blockchain clients
 struct MonitorExecutionTestConfig {
 	pub path: String,
@@ -87,6 +112,8 @@ struct MonitorExecutionTestConfig {
 	pub trigger_execution_service: Arc<TriggerExecutionService<TriggerRepository>>,
 	pub active_monitors_trigger_scripts: HashMap<String, (ScriptLanguage, String)>,
 	pub raw_output: bool,
+	pub output_file: Option<PathBuf>,
+	pub output_format: MatchOutputFormat,
 	pub client_pool: Arc<ClientPool>,
 }
 
@@ -113,9 +140,10 @@ struct Cli {
 	#[arg(long, value_name = "SIZE", value_parser = parse_string_to_bytes_size)]
 	log_max_size: Option<u64>,
 
-	/// Address to start the metrics server on (default: 127.0.0.1:8081)
-	#[arg(long, value_name = "HOST:PORT")]
-	metrics_address: Option<String>,
+	/// Address to start the metrics server on, as HOST:PORT, [IPV6]:PORT or unix:PATH
+	/// (default: 127.0.0.1:8081)
+	#[arg(long, value_name = "ADDRESS")]
+	metrics_address: Option<MetricsBindAddress>,
 
 	/// Enable metrics server
 	#[arg(long)]
@@ -133,9 +161,84 @@ struct Cli {
 	#[arg(long, value_name = "BLOCK_NUMBER")]
 	block: Option<u64>,
 
+	/// File to write the matches of the monitor execution to, in addition to the log
+	#[arg(long, value_name = "PATH", requires = "monitor_path")]
+	output_file: Option<PathBuf>,
+
+	/// Format of the output file: a JSON array of matches, or one JSON match per line
+	#[arg(long, value_name = "FORMAT", value_enum, default_value_t)]
+	output_format: MatchOutputFormat,
+
 	/// Validate configuration files without starting the service
 	#[arg(long)]
 	check: bool,
+
+	/// Disable the on-disk cache of parsed contract ABIs
+	#[arg(long)]
+	no_abi_cache: bool,
+
+	/// Validate all configuration files and exit with a non-zero code on any problem
+	#[arg(long)]
+	validate_only: bool,
+
+	/// Directory holding the `monitors`, `networks` and `triggers` configuration directories
+	/// (default: config/, or the CONFIG_DIR environment variable)
+	#[arg(long, value_name = "PATH")]
+	config_dir: Option<String>,
+
+	/// Load monitors, networks and triggers from a single JSON or YAML file instead of the
+	/// configuration directories
+	#[arg(long, value_name = "BUNDLE_PATH")]
+	config_bundle: Option<String>,
+
+	/// Print the active monitors with their networks, addresses, conditions and triggers, then
+	/// exit
+	#[arg(long)]
+	list_monitors: bool,
+
+	/// Print the configured networks, then exit
+	#[arg(long)]
+	list_networks: bool,
+
+	/// Print the configured triggers, then exit
+	#[arg(long)]
+	list_triggers: bool,
+
+	/// Print listings as JSON instead of a table
+	#[arg(long)]
+	json: bool,
+
+	/// Print the JSON Schema of a kind of configuration file, then exit
+	#[arg(long, value_name = "KIND", value_enum)]
+	print_schema: Option<ConfigKind>,
+
+	/// Print all matches recorded in the match store as JSON lines, then exit
+	#[arg(long)]
+	export_matches: bool,
+
+	/// Send a sample notification through a trigger to verify its configuration, then exit
+	#[arg(long, value_name = "TRIGGER_NAME")]
+	test_trigger: Option<String>,
+
+	/// Check that every trigger can reach its destination before starting, without sending
+	/// notifications, and exit if one can't
+	#[arg(long)]
+	check_triggers: bool,
+
+	/// Address to serve the admin API on, used to reload monitors with `POST /reload` (disabled
+	/// if not set). Non-loopback addresses require `ADMIN_TOKEN`
+	#[arg(long, value_name = "HOST:PORT")]
+	admin_address: Option<String>,
+
+	/// Record the hash of every block whose triggers are executed, and skip the triggers of
+	/// blocks already recorded, e.g. blocks processed again after a restart
+	#[arg(long)]
+	skip_duplicate_blocks: bool,
+
+	/// Address to serve the gRPC match stream on (disabled if not set)
+	#[cfg(feature = "grpc")]
+	#[arg(long, value_name = "HOST:PORT")]
+	grpc_address: Option<String>,
 }
 
 impl Cli {
@@ -176,24 +279,40 @@ impl Cli {
 			set_var("METRICS_ENABLED", "true");
 		}
 
+		// ABI cache - override if CLI flag is set
+		if self.no_abi_cache {
+			set_var("ABI_CACHE_DISABLED", "true");
+		}
+
 		// Metrics address - override if CLI flag is set
-		if let Some(address) = &self.metrics_address {
-			// Extract port from address if it's in HOST:PORT format
-			if let Some(port) = address.split(':').nth(1) {
-				set_var("METRICS_PORT", port);
-			}
+		if let Some(MetricsBindAddress::Tcp { port, .. }) = &self.metrics_address {
+			set_var("METRICS_PORT", port.to_string());
 		}
 	}
+
+	/// Returns the configuration directory, from the CLI flag, else the CONFIG_DIR environment
+	/// variable, else None for the default directory
+	fn config_dir(&self) -> Option<PathBuf> {
+		self.config_dir
+			.clone()
+			.or_else(|| var("CONFIG_DIR").ok().filter(|dir| !dir.is_empty()))
+			.map(PathBuf::from)
+	}
 }
 
 /// Main entry point for the blockchain monitoring service.
 ///
-/// # Errors
-/// Returns an error if service initialization fails or if there's an error during shutdown.
+/// Exits with [`ExitCode::Success`] once the command completes or the service shuts down, else
+/// with the exit code of the class of the failure.
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> std::process::ExitCode {
 	let cli = Cli::parse();
 
+	// If --print-schema flag is provided, print the schema before anything is logged and exit
+	if let Some(kind) = cli.print_schema {
+		return exit(print_schema(kind));
+	}
+
 	// Apply CLI options to environment
 	cli.apply_to_env();
 
@@ -202,12 +321,56 @@ async fn main() -> Result<()> {
 		error!("Failed to setup logging: {}", e);
 	});
 
+	exit(run(cli).await)
+}
+
+/// Reports the error of a command, if any, and returns the exit code of the process
+fn exit(result: std::result::Result<(), CliError>) -> std::process::ExitCode {
+	match result {
+		Ok(()) => ExitCode::Success.into(),
+		Err(e) => {
+			eprintln!("Error: {:?}", e);
+			e.exit_code.into()
+		}
+	}
+}
+
+/// Prints the JSON Schema of a kind of configuration file
+fn print_schema(kind: ConfigKind) -> std::result::Result<(), CliError> {
+	println!("{}", serde_json::to_string_pretty(&config_schema(kind))?);
+	Ok(())
+}
+
+/// Runs the command of the CLI options, once the environment and logging are set up
+///
+/// # Errors
+/// Returns an error with the exit code of its class of failure, see [`ExitCode`].
+async fn run(cli: Cli) -> std::result::Result<(), CliError> {
+	let bundle_path = cli.config_bundle.as_deref().map(Path::new);
+	let config_dir = cli.config_dir();
+	let init_options = InitOptions {
+		config_dir: config_dir.clone(),
+		bundle_path: bundle_path.map(Path::to_path_buf),
+		..Default::default()
+	};
+
 	// If --check flag is provided, only validate configuration and exit
 	if cli.check {
-		validate_configuration().await;
+		validate_configuration(init_options).await;
 		return Ok(());
 	}
 
+	// If --validate-only flag is provided, validate configuration files without connecting to
+	// any network and exit with an error if a problem is found
+	if cli.validate_only {
+		return validate_only(bundle_path, config_dir.as_deref()).await;
+	}
+
+	// If --export-matches flag is provided, dump the match store and exit
+	if cli.export_matches {
+		return Ok(export_matches().await?);
+	}
+
 	let (
 		filter_service,
 		trigger_execution_service,
@@ -216,20 +379,54 @@ async fn main() -> Result<()> {
 		monitor_service,
 		network_service,
 		trigger_service,
-	) = initialize_services::<
+	) = initialize_services_with_options::<
 		MonitorRepository<NetworkRepository, TriggerRepository>,
 		NetworkRepository,
 		TriggerRepository,
-	>(None, None, None)
+	>(None, None, None, init_options)
 	.await
-	.map_err(|e| anyhow::anyhow!("Failed to initialize services: {}. Please refer to the documentation quickstart ({}) on how to configure the service.", e, DOCUMENTATION_URL))?;
+	.map_err(|e| anyhow::anyhow!("Failed to initialize services: {}. Please refer to the documentation quickstart ({}) on how to configure the service.", e, DOCUMENTATION_URL))
+	.with_exit_code(ExitCode::Config)?;
+
+	// If a --list-* flag is provided, print the loaded configurations and exit
+	if cli.list_monitors || cli.list_networks || cli.list_triggers {
+		let format = if cli.json {
+			ListFormat::Json
+		} else {
+			ListFormat::Table
+		};
+		if cli.list_monitors {
+			println!("{}", render(&summarize_monitors(&active_monitors), format)?);
+		}
+		if cli.list_networks {
+			println!("{}", render(&summarize_networks(&networks), format)?);
+		}
+		if cli.list_triggers {
+			let triggers = trigger_service.lock().await.get_all();
+			println!("{}", render(&summarize_triggers(&triggers), format)?);
+		}
+		return Ok(());
+	}
+
+	// If --test-trigger is provided, send a sample notification through the trigger and exit
+	if let Some(trigger_name) = cli.test_trigger.as_deref() {
+		return test_trigger(&trigger_execution_service, trigger_name)
+			.await
+			.with_exit_code(ExitCode::Notification);
+	}
+
+	// If --check-triggers is provided, fail fast on the triggers that can't notify
+	if cli.check_triggers {
+		check_triggers(&trigger_execution_service).await?;
+	}
 
 	// Pre-load all trigger scripts into memory at startup to reduce file I/O operations.
 	// This prevents repeated file descriptor usage during script execution and improves performance
 	// by keeping scripts readily available in memory.
 	let active_monitors_trigger_scripts = trigger_execution_service
 		.load_scripts(&active_monitors)
-		.await?;
+		.await
+		.with_exit_code(ExitCode::Config)?;
 	// Read CLI arguments to determine if we should test monitor execution
 	let monitor_path = cli.monitor_path.clone();
 	let network_slug = cli.network.clone();
@@ -253,6 +450,8 @@ async fn main() -> Result<()> {
 			trigger_execution_service: trigger_execution_service.clone(),
 			active_monitors_trigger_scripts,
 			raw_output: false,
+			output_file: cli.output_file.clone(),
+			output_format: cli.output_format,
 			client_pool,
 		})
 		.await;
@@ -297,11 +496,14 @@ async fn main() -> Result<()> {
 		None
 	};
 
-	let networks_with_monitors: Vec<Network> = networks
-		.values()
-		.filter(|network| has_active_monitors(&active_monitors.clone(), &network.slug))
-		.cloned()
-		.collect();
+	for network in networks.values().filter(|network| !network.is_enabled()) {
+		info!(
+			"Network {} is disabled, skipping block watcher",
+			network.slug
+		);
+	}
+
+	let networks_with_monitors: Vec<Network> = get_watched_networks(&networks, &active_monitors);
 
 	if networks_with_monitors.is_empty() {
 		info!("No networks with active monitors found. Exiting...");
@@ -326,100 +528,280 @@ async fn main() -> Result<()> {
 	// Fetch all contract specs for all active monitors
 	let contract_specs = get_contract_specs(&client_pool, &network_monitors).await;
 
+	// Monitors overriding the schedule of their network are run by jobs of their own
+	let network_schedules: HashMap<String, Vec<String>> = network_monitors
+		.iter()
+		.map(|(network, monitors)| {
+			(
+				network.slug.clone(),
+				get_network_schedules(network, monitors),
+			)
+		})
+		.collect();
+
+	let persists_cooldown = active_monitors
+		.iter()
+		.any(|monitor| monitor.persist_cooldown);
+
+	// Shared with the admin server, which swaps them when monitors are reloaded
+	let shared_active_monitors = Arc::new(RwLock::new(ActiveMonitors {
+		monitors: active_monitors,
+		trigger_scripts: active_monitors_trigger_scripts,
+		contract_specs,
+	}));
+
 	let (shutdown_tx, _) = watch::channel(false);
 	let block_handler = create_block_handler(
 		shutdown_tx.clone(),
-		filter_service,
-		active_monitors,
+		filter_service.clone(),
+		shared_active_monitors.clone(),
+		&networks,
 		client_pool.clone(),
-		contract_specs,
 	);
+	// Execute the configured stall trigger when a network stops processing new blocks
+	stall_detector()
+		.set_handler(create_stall_handler(trigger_execution_service.clone()))
+		.await;
+	let (match_tx, _) = broadcast::channel(MATCH_CHANNEL_CAPACITY);
+	let match_store = match_store_from_env()?;
+	if match_store.is_some() {
+		info!("Recording matches to the match store");
+	}
+	let trigger_task_limiter = TriggerTaskLimiter::from_env()?;
+	let file_block_storage = Arc::new(FileBlockStorage::default());
+	let block_guard = if cli.skip_duplicate_blocks {
+		info!("Skipping the triggers of blocks already processed");
+		Some(create_processed_block_guard(file_block_storage.clone()))
+	} else {
+		None
+	};
+	// Alerts firing before a restart still resolve after it
+	let network_slugs: Vec<String> = networks_with_monitors
+		.iter()
+		.map(|network| network.slug.clone())
+		.collect();
+	let alert_states = AlertStates::load(file_block_storage.clone(), &network_slugs).await?;
+	// So do the cooldowns of the monitors persisting them
+	if persists_cooldown {
+		trigger_execution_service
+			.restore_cooldown(file_block_storage.clone())
+			.await?;
+	}
+	// And balance conditions compare with the balances read before it
+	filter_service
+		.balance_cache()
+		.restore(file_block_storage.clone(), &network_slugs)
+		.await?;
 	let trigger_handler = create_trigger_handler(
 		shutdown_tx.clone(),
-		trigger_execution_service,
-		active_monitors_trigger_scripts,
+		trigger_execution_service.clone(),
+		shared_active_monitors.clone(),
+		match_tx.clone(),
+		match_store,
+		trigger_task_limiter.clone(),
+		block_guard,
+		Arc::new(alert_states),
 	);
 
-	let file_block_storage = Arc::new(FileBlockStorage::default());
+	// Serve the admin API, reloading monitors without restarting the block watchers
+	let admin_server = match &cli.admin_address {
+		Some(admin_address) => {
+			let mut reloader = MonitorReloader::new(
+				monitor_service.clone(),
+				network_service.clone(),
+				trigger_service.clone(),
+				trigger_execution_service.clone(),
+				client_pool.clone(),
+				networks_with_monitors.clone(),
+				network_schedules.clone(),
+				shared_active_monitors.clone(),
+			);
+			if let Some(bundle_path) = bundle_path {
+				reloader = reloader.with_bundle_path(bundle_path);
+			} else if let Some(config_dir) = &config_dir {
+				reloader = reloader.with_monitors_path(&config_dir.join("monitors"));
+			}
+			match create_admin_server(
+				admin_address.clone(),
+				Arc::new(reloader),
+				admin_token_from_env(),
+			) {
+				Ok(server) => {
+					let handle = server.handle();
+					tokio::spawn(async move {
+						if let Err(e) = server.await {
+							error!("Admin server error: {}", e);
+						}
+					});
+					Some(handle)
+				}
+				Err(e) => {
+					error!("Failed to create admin server: {}", e);
+					None
+				}
+			}
+		}
+		None => None,
+	};
+
+	// Stream matches to gRPC subscribers
+	#[cfg(feature = "grpc")]
+	if let Some(grpc_address) = &cli.grpc_address {
+		let listener = tokio::net::TcpListener::bind(grpc_address).await?;
+		info!("gRPC match stream listening on {}", grpc_address);
+
+		let mut shutdown_rx = shutdown_tx.subscribe();
+		let match_tx = match_tx.clone();
+		tokio::spawn(async move {
+			let shutdown = async move {
+				let _ = shutdown_rx.changed().await;
+			};
+			if let Err(e) = services::grpc::serve(listener, match_tx, shutdown).await {
+				error!("gRPC server error: {}", e);
+			}
+		});
+	}
+
 	let block_watcher = BlockWatcherService::<FileBlockStorage, _, _, JobScheduler>::new(
 		file_block_storage.clone(),
 		block_handler,
-		trigger_handler,
+		trigger_handler.clone(),
 		Arc::new(BlockTracker::new(1000)),
 	)
 	.await?;
 
-	for network in networks_with_monitors {
-		match network.network_type {
-			BlockChainType::EVM => {
-				if let Ok(client) = client_pool.get_evm_client(&network).await {
-					let _ = block_watcher
-						.start_network_watcher(&network, (*client).clone())
-						.await
-						.inspect_err(|e| {
-							error!("Failed to start EVM network watcher: {}", e);
-						});
-				} else {
-					error!("Failed to get EVM client for network: {}", network.slug);
-				}
-			}
-			BlockChainType::Stellar => {
-				if let Ok(client) = client_pool.get_stellar_client(&network).await {
-					let _ = block_watcher
-						.start_network_watcher(&network, (*client).clone())
-						.await
-						.inspect_err(|e| {
-							error!("Failed to start Stellar network watcher: {}", e);
-						});
-				} else {
-					error!("Failed to get Stellar client for network: {}", network.slug);
-				}
+	// Watchers of the networks, started once this replica is the leader
+	let block_watcher = Arc::new(block_watcher);
+	let start_watchers = {
+		let block_watcher = block_watcher.clone();
+		let shutdown_tx = shutdown_tx.clone();
+		move || {
+			// Watch the pending transactions of networks with mempool enabled, next to their blocks
+			for network in networks_with_monitors
+				.iter()
+				.filter(|network| network.is_mempool_enabled())
+			{
+				tokio::spawn(watch_pending_transactions(
+					network.clone(),
+					shared_active_monitors.clone(),
+					trigger_handler.clone(),
+					shutdown_tx.subscribe(),
+				));
 			}
-			BlockChainType::Midnight => {
-				if let Ok(client) = client_pool.get_midnight_client(&network).await {
-					let _ = block_watcher
-						.start_network_watcher(&network, (*client).clone())
-						.await
-						.inspect_err(|e| {
-							error!("Failed to start Midnight network watcher: {}", e);
-						});
-				} else {
-					error!(
-						"Failed to get Midnight client for network: {}",
-						network.slug
-					);
-				}
+
+			// Each network is supervised on its own task, so that a network whose watcher keeps
+			// failing doesn't delay or affect the other networks. Every start of a watcher
+			// replaces the network's cached client, so that a restart doesn't reuse a client
+			// left in a bad state
+			for network in networks_with_monitors {
+				let schedules = network_schedules
+					.get(&network.slug)
+					.cloned()
+					.unwrap_or_default();
+				let block_watcher = block_watcher.clone();
+				let client_pool = client_pool.clone();
+				tokio::spawn(async move {
+					let result = match network.network_type {
+						BlockChainType::EVM => {
+							block_watcher
+								.supervise_network_watcher(&network, schedules, || async {
+									client_pool.remove_client(&network).await;
+									Ok((*client_pool.get_evm_client(&network).await?).clone())
+								})
+								.await
+						}
+						BlockChainType::Stellar => {
+							block_watcher
+								.supervise_network_watcher(&network, schedules, || async {
+									client_pool.remove_client(&network).await;
+									Ok((*client_pool.get_stellar_client(&network).await?).clone())
+								})
+								.await
+						}
+						BlockChainType::Midnight => {
+							block_watcher
+								.supervise_network_watcher(&network, schedules, || async {
+									client_pool.remove_client(&network).await;
+									Ok((*client_pool.get_midnight_client(&network).await?).clone())
+								})
+								.await
+						}
+						BlockChainType::Solana => {
+							block_watcher
+								.supervise_network_watcher(&network, schedules, || async {
+									client_pool.remove_client(&network).await;
+									Ok((*client_pool.get_solana_client(&network).await?).clone())
+								})
+								.await
+						}
+					};
+					if let Err(e) = result {
+						error!(
+							"Failed to watch {:?} network {}: {}",
+							network.network_type, network.slug, e
+						);
+					}
+				});
 			}
 		}
-	}
+	};
+
+	// With leader election, only the leader watches blocks while followers stand by
+	let leader_election = LeaderElection::from_env().await?.map(Arc::new);
+	let mut leadership_lost = match leader_election.clone() {
+		Some(election) => {
+			info!(
+				"Replica {} standing by until elected leader",
+				election.holder_id()
+			);
+			tokio::spawn(async move {
+				election.acquire().await;
+				start_watchers();
+				election.hold().await;
+			})
+		}
+		None => {
+			start_watchers();
+			tokio::spawn(std::future::pending::<()>())
+		}
+	};
 
 	info!("Service started. Press Ctrl+C to shutdown");
 
 	let ctrl_c = tokio::signal::ctrl_c();
 
-	if let Some(metrics_future) = metrics_server {
-		tokio::select! {
-				result = ctrl_c => {
-					if let Err(e) = result {
-			  error!("Error waiting for Ctrl+C: {}", e);
+	let metrics_future = async move {
+		match metrics_server {
+			Some(metrics_future) => metrics_future.await,
+			None => std::future::pending().await,
+		}
+	};
+
+	tokio::select! {
+		result = ctrl_c => {
+			if let Err(e) = result {
+				error!("Error waiting for Ctrl+C: {}", e);
 			}
 			info!("Shutdown signal received, stopping services...");
-		  }
-		  result = metrics_future => {
+		}
+		result = metrics_future => {
 			if let Err(e) = result {
-			  error!("Metrics server error: {}", e);
+				error!("Metrics server error: {}", e);
 			}
 			info!("Metrics server stopped, shutting down services...");
-		  }
 		}
-	} else {
-		let _ = ctrl_c.await;
-		info!("Shutdown signal received, stopping services...");
+		_ = &mut leadership_lost => {
+			error!("Leadership lost, shutting down services...");
+		}
 	}
 
 	// Common shutdown logic
 	let _ = shutdown_tx.send(true);
 
+	if let Some(admin_server) = admin_server {
+		admin_server.stop(true).await;
+	}
+
 	// Future for all network shutdown operations
 	let shutdown_futures = networks
 		.values()
@@ -431,9 +813,37 @@ async fn main() -> Result<()> {
 		}
 	}
 
+	// Let a follower take over right away
+	leadership_lost.abort();
+	if let Some(election) = &leader_election {
+		if let Err(e) = election.resign().await {
+			error!("Error releasing leadership: {}", e);
+		}
+	}
+
+	// Let the blocks being handled deliver their notifications
+	if !trigger_task_limiter
+		.drain(TRIGGER_TASKS_DRAIN_TIMEOUT)
+		.await
+	{
+		error!(
+			"{} block(s) still handling their matches after {:?}",
+			trigger_task_limiter.in_flight(),
+			TRIGGER_TASKS_DRAIN_TIMEOUT
+		);
+	}
+
+	// Send the digests of the matches collected in open batch windows
+	trigger_execution_service.flush_digests().await;
+	trigger_execution_service
+		.notification_cooldown()
+		.flush()
+		.await;
+
 	tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
 
 	info!("Shutdown complete");
+	shutdown_logging();
 	Ok(())
 }
 
@@ -450,17 +860,23 @@ async fn main() -> Result<()> {
 /// * `Result<()>` - Ok(()) if execution succeeds, or an error if execution fails
 ///
 /// # Errors
-/// * Returns an error if network slug is missing when block number is specified
-/// * Returns an error if monitor execution fails for any reason (invalid path, network issues, etc.)
+/// * Returns a usage error if network slug is missing when block number is specified
+/// * Returns an RPC error if the RPC endpoints of a network can't be reached
+/// * Returns an error if monitor execution fails for any other reason (invalid path, etc.)
 #[instrument(skip_all)]
-async fn test_monitor_execution(config: MonitorExecutionTestConfig) -> Result<()> {
+async fn test_monitor_execution(
+	config: MonitorExecutionTestConfig,
+) -> std::result::Result<(), CliError> {
 	// Validate inputs first
 	if config.block_number.is_some() && config.network_slug.is_none() {
-		return Err(Box::new(MonitorExecutionError::execution_error(
-			"Network name is required when executing a monitor for a specific block",
-			None,
-			None,
-		)));
+		return Err(CliError::new(
+			ExitCode::Usage,
+			MonitorExecutionError::execution_error(
+				"Network name is required when executing a monitor for a specific block",
+				None,
+				None,
+			),
+		));
 	}
 
 	info!(
@@ -487,6 +903,11 @@ async fn test_monitor_execution(config: MonitorExecutionTestConfig) -> Result<()
 		Ok(matches) => {
 			info!("Monitor execution completed successfully");
 
+			if let Some(output_file) = &config.output_file {
+				write_matches(&matches, output_file, config.output_format)?;
+				info!(path = %output_file.display(), "Matches written to file");
+			}
+
 			if matches.is_empty() {
 				info!("No matches found");
 				return Ok(());
@@ -671,8 +1092,12 @@ async fn test_monitor_execution(config: MonitorExecutionTestConfig) -> Result<()
 			Ok(())
 		}
 		Err(e) => {
+			let exit_code = match e {
+				MonitorExecutionError::ConnectionError(_) => ExitCode::Rpc,
+				_ => ExitCode::Failure,
+			};
 			// Convert to domain-specific error with proper context
-			Err(MonitorExecutionError::execution_error(
+			let error = MonitorExecutionError::execution_error(
 				"Monitor execution failed",
 				Some(e.into()),
 				Some(std::collections::HashMap::from([
@@ -689,22 +1114,25 @@ async fn test_monitor_execution(config: MonitorExecutionTestConfig) -> Result<()
 							.unwrap_or_default(),
 					),
 				])),
-			)
-			.into())
+			);
+			Err(CliError::new(exit_code, error))
 		}
 	}
 }
 
 /// Validates configuration files and their structure
-async fn validate_configuration() {
+///
+/// # Arguments
+/// * `options` - Configuration directory or bundle to validate
+async fn validate_configuration(options: InitOptions) {
 	info!("Validating configuration files...");
 
 	// Initialize services in validation mode to check configurations
-	match initialize_services::<
+	match initialize_services_with_options::<
 		MonitorRepository<NetworkRepository, TriggerRepository>,
 		NetworkRepository,
 		TriggerRepository,
-	>(None, None, None)
+	>(None, None, None, options)
 	.await
 	{
 		Ok((_, _, active_monitors, networks, _, _, _)) => {
@@ -720,7 +1148,9 @@ async fn validate_configuration() {
 			// Check if we have any networks with active monitors
 			let networks_with_monitors: Vec<&Network> = networks
 				.values()
-				.filter(|network| has_active_monitors(&active_monitors, &network.slug))
+				.filter(|network| {
+					network.is_enabled() && has_active_monitors(&active_monitors, &network.slug)
+				})
 				.collect();
 
 			if networks_with_monitors.is_empty() {
@@ -740,9 +1170,245 @@ async fn validate_configuration() {
 	}
 }
 
+/// Summary of a configuration validation run
+#[derive(Debug, Default)]
+struct ConfigValidationReport {
+	/// Number of monitors loaded and validated
+	monitors: usize,
+	/// Number of networks loaded and validated
+	networks: usize,
+	/// Number of triggers loaded and validated
+	triggers: usize,
+	/// Problems found while loading or cross-checking the configurations
+	problems: Vec<String>,
+}
+
+/// Formats an error followed by each of its sources
+fn format_error_chain(error: &dyn std::error::Error) -> String {
+	let mut message = error.to_string();
+	let mut source = error.source();
+	while let Some(e) = source {
+		message.push_str(&format!(": {}", e));
+		source = e.source();
+	}
+	message
+}
+
+/// Loads and validates all monitor, network and trigger configurations
+///
+/// Every configuration is validated while being loaded by its repository. Monitors are then
+/// cross-checked against the loaded networks and triggers. No blockchain client is created.
+///
+/// # Arguments
+/// * `monitor_path` - Monitor configuration directory (default directory if None)
+/// * `network_path` - Network configuration directory (default directory if None)
+/// * `trigger_path` - Trigger configuration directory (default directory if None)
+async fn validate_config_files(
+	monitor_path: Option<&Path>,
+	network_path: Option<&Path>,
+	trigger_path: Option<&Path>,
+) -> ConfigValidationReport {
+	let mut report = ConfigValidationReport::default();
+
+	let networks = match NetworkRepository::new(network_path).await {
+		Ok(repository) => Some(repository.networks),
+		Err(e) => {
+			report.problems.push(format_error_chain(&e));
+			None
+		}
+	};
+	report.networks = networks.as_ref().map_or(0, |n| n.len());
+
+	let triggers = match TriggerRepository::new(trigger_path).await {
+		Ok(repository) => Some(repository.triggers),
+		Err(e) => {
+			report.problems.push(format_error_chain(&e));
+			None
+		}
+	};
+	report.triggers = triggers.as_ref().map_or(0, |t| t.len());
+
+	let monitors = match Monitor::load_all::<HashMap<String, Monitor>>(monitor_path).await {
+		Ok(monitors) => monitors,
+		Err(e) => {
+			report.problems.push(format_error_chain(&e));
+			return report;
+		}
+	};
+	report.monitors = monitors.len();
+
+	// References can only be checked once both networks and triggers loaded successfully
+	if let (Some(networks), Some(triggers)) = (&networks, &triggers) {
+		if let Err(e) =
+			MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+				&monitors, triggers, networks,
+			) {
+			report.problems.push(format_error_chain(&e));
+		}
+	}
+
+	report
+}
+
+/// Loads and validates a configuration bundle
+///
+/// Every entry is validated while the bundle is loaded. Monitors are then cross-checked against
+/// the bundled networks and triggers.
+///
+/// # Arguments
+/// * `bundle_path` - Path to the configuration bundle
+async fn validate_config_bundle(bundle_path: &Path) -> ConfigValidationReport {
+	let mut report = ConfigValidationReport::default();
+
+	let bundle = match load_bundle(bundle_path).await {
+		Ok(bundle) => bundle,
+		Err(e) => {
+			report.problems.push(format_error_chain(&e));
+			return report;
+		}
+	};
+	report.monitors = bundle.monitors.len();
+	report.networks = bundle.networks.len();
+	report.triggers = bundle.triggers.len();
+
+	if let Err(e) =
+		MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+			&bundle.monitors,
+			&bundle.triggers,
+			&bundle.networks,
+		) {
+		report.problems.push(format_error_chain(&e));
+	}
+
+	report
+}
+
+/// Validates all configuration files and exits without starting the service
+///
+/// # Arguments
+/// * `bundle_path` - Configuration bundle to validate instead of the configuration directories
+/// * `config_dir` - Directory holding the configuration directories (default directory if None)
+///
+/// # Errors
+/// Returns a validation error listing the number of problems if any configuration is invalid.
+async fn validate_only(
+	bundle_path: Option<&Path>,
+	config_dir: Option<&Path>,
+) -> std::result::Result<(), CliError> {
+	let report = match bundle_path {
+		Some(path) => validate_config_bundle(path).await,
+		None => {
+			let config_path = |name: &str| config_dir.map(|dir| dir.join(name));
+			validate_config_files(
+				config_path("monitors").as_deref(),
+				config_path("networks").as_deref(),
+				config_path("triggers").as_deref(),
+			)
+			.await
+		}
+	};
+
+	info!(
+		"Validated {} monitor(s), {} network(s) and {} trigger(s)",
+		report.monitors, report.networks, report.triggers
+	);
+
+	if report.problems.is_empty() {
+		info!("Configuration validation completed successfully!");
+		return Ok(());
+	}
+
+	for problem in &report.problems {
+		error!("{}", problem);
+	}
+
+	Err(CliError::new(
+		ExitCode::Validation,
+		anyhow::anyhow!(
+			"Configuration validation failed with {} problem(s)",
+			report.problems.len()
+		),
+	))
+}
+
+/// Prints all matches recorded in the configured match store as JSON lines, oldest first
+///
+/// # Errors
+/// Returns an error if no match store is configured or it can't be read.
+async fn export_matches() -> Result<()> {
+	let match_store = match_store_from_env()?.ok_or_else(|| {
+		anyhow::anyhow!("No match store configured, set MATCH_STORE to 'file' or 'sqlite'")
+	})?;
+
+	for record in match_store.query(&MatchQuery::default()).await? {
+		println!("{}", serde_json::to_string(&record)?);
+	}
+
+	Ok(())
+}
+
+/// Sends a sample notification through a trigger and reports the outcome
+///
+/// # Errors
+/// Returns an error with the full error chain if the notification fails.
+async fn test_trigger(
+	trigger_execution_service: &TriggerExecutionService<TriggerRepository>,
+	trigger_name: &str,
+) -> Result<()> {
+	info!(
+		"Sending a test notification through trigger '{}'",
+		trigger_name
+	);
+
+	match trigger_execution_service
+		.execute_test_notification(trigger_name)
+		.await
+	{
+		Ok(()) => {
+			info!("Test notification sent through trigger '{}'", trigger_name);
+			Ok(())
+		}
+		Err(e) => Err(anyhow::anyhow!(
+			"Test notification through trigger '{}' failed: {}",
+			trigger_name,
+			format_error_chain(&e)
+		)
+		.into()),
+	}
+}
+
+/// Checks that every trigger can deliver its notifications, without sending any
+///
+/// # Errors
+/// Returns a notification error listing the number of unhealthy triggers if any can't notify.
+async fn check_triggers(
+	trigger_execution_service: &TriggerExecutionService<TriggerRepository>,
+) -> std::result::Result<(), CliError> {
+	info!("Checking the triggers...");
+	let unhealthy = trigger_execution_service.check_triggers().await;
+	if unhealthy.is_empty() {
+		info!("All triggers passed their health check");
+		return Ok(());
+	}
+
+	for (trigger_slug, e) in &unhealthy {
+		error!(
+			"Trigger '{}' failed its health check: {}",
+			trigger_slug,
+			format_error_chain(e)
+		);
+	}
+
+	Err(CliError::new(
+		ExitCode::Notification,
+		anyhow::anyhow!("{} trigger(s) failed their health check", unhealthy.len()),
+	))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::bootstrap::initialize_services;
 
 	#[tokio::test]
 	async fn test_monitor_execution_without_network_slug_with_block_number() {
@@ -752,7 +1418,7 @@ mod tests {
 				MonitorRepository<NetworkRepository, TriggerRepository>,
 				NetworkRepository,
 				TriggerRepository,
-			>(None, None, None)
+			>(None, None, None, None)
 			.await
 			.unwrap();
 
@@ -770,6 +1436,8 @@ mod tests {
 			trigger_execution_service: trigger_execution_service.clone(),
 			active_monitors_trigger_scripts: HashMap::new(),
 			raw_output: false,
+			output_file: None,
+			output_format: MatchOutputFormat::Json,
 			client_pool: client_pool.clone(),
 		})
 		.await;
@@ -791,7 +1459,7 @@ mod tests {
 				MonitorRepository<NetworkRepository, TriggerRepository>,
 				NetworkRepository,
 				TriggerRepository,
-			>(None, None, None)
+			>(None, None, None, None)
 			.await
 			.unwrap();
 
@@ -812,6 +1480,8 @@ mod tests {
 			trigger_execution_service: trigger_execution_service.clone(),
 			active_monitors_trigger_scripts: HashMap::new(),
 			raw_output: false,
+			output_file: None,
+			output_format: MatchOutputFormat::Json,
 			client_pool: client_pool.clone(),
 		})
 		.await;
@@ -824,4 +1494,268 @@ mod tests {
 			.to_string()
 			.contains("Monitor execution failed"));
 	}
+
+	fn write_validation_fixtures(
+		temp_dir: &tempfile::TempDir,
+		monitor_network: &str,
+	) -> (std::path::PathBuf, std::path::PathBuf, std::path::PathBuf) {
+		let monitor_dir = temp_dir.path().join("monitors");
+		let network_dir = temp_dir.path().join("networks");
+		let trigger_dir = temp_dir.path().join("triggers");
+		for dir in [&monitor_dir, &network_dir, &trigger_dir] {
+			std::fs::create_dir_all(dir).unwrap();
+		}
+
+		std::fs::copy(
+			"examples/config/networks/ethereum_mainnet.json",
+			network_dir.join("ethereum_mainnet.json"),
+		)
+		.unwrap();
+		std::fs::copy(
+			"examples/config/triggers/webhook_notifications.json",
+			trigger_dir.join("webhook_notifications.json"),
+		)
+		.unwrap();
+
+		let monitor = serde_json::json!({
+			"name": "Test Monitor",
+			"networks": [monitor_network],
+			"paused": false,
+			"addresses": [],
+			"match_conditions": {
+				"functions": [],
+				"events": [],
+				"transactions": []
+			},
+			"trigger_conditions": [],
+			"triggers": ["evm_large_transfer_usdc_webhook"]
+		});
+		std::fs::write(monitor_dir.join("monitor.json"), monitor.to_string()).unwrap();
+
+		(monitor_dir, network_dir, trigger_dir)
+	}
+
+	#[tokio::test]
+	async fn test_validate_config_files_valid() {
+		let temp_dir = tempfile::TempDir::new().unwrap();
+		let (monitor_dir, network_dir, trigger_dir) =
+			write_validation_fixtures(&temp_dir, "ethereum_mainnet");
+
+		let report =
+			validate_config_files(Some(&monitor_dir), Some(&network_dir), Some(&trigger_dir)).await;
+
+		assert!(report.problems.is_empty(), "{:?}", report.problems);
+		assert_eq!(report.monitors, 1);
+		assert_eq!(report.networks, 1);
+		assert!(report.triggers > 0);
+	}
+
+	#[tokio::test]
+	async fn test_validate_config_files_missing_network_reference() {
+		let temp_dir = tempfile::TempDir::new().unwrap();
+		let (monitor_dir, network_dir, trigger_dir) =
+			write_validation_fixtures(&temp_dir, "missing_network");
+
+		let report =
+			validate_config_files(Some(&monitor_dir), Some(&network_dir), Some(&trigger_dir)).await;
+
+		assert_eq!(report.monitors, 1);
+		assert_eq!(report.problems.len(), 1);
+		assert!(report.problems[0]
+			.contains("Monitor 'monitor' references non-existent network 'missing_network'"));
+	}
+
+	#[tokio::test]
+	async fn test_validate_config_files_reports_each_invalid_type() {
+		let temp_dir = tempfile::TempDir::new().unwrap();
+		let (monitor_dir, network_dir, trigger_dir) =
+			write_validation_fixtures(&temp_dir, "ethereum_mainnet");
+		std::fs::write(network_dir.join("invalid.json"), "{ invalid").unwrap();
+		std::fs::write(monitor_dir.join("invalid.json"), "{ invalid").unwrap();
+
+		let report =
+			validate_config_files(Some(&monitor_dir), Some(&network_dir), Some(&trigger_dir)).await;
+
+		assert_eq!(report.problems.len(), 2);
+		assert_eq!(report.networks, 0);
+		assert_eq!(report.monitors, 0);
+		assert!(report.triggers > 0);
+	}
+
+	#[tokio::test]
+	async fn test_config_dir_flag_loads_configurations() {
+		let temp_dir = tempfile::TempDir::new().unwrap();
+		write_validation_fixtures(&temp_dir, "ethereum_mainnet");
+		let cli = Cli::parse_from([
+			"openzeppelin-monitor",
+			"--config-dir",
+			temp_dir.path().to_str().unwrap(),
+		]);
+		let config_dir = cli.config_dir();
+		assert_eq!(config_dir.as_deref(), Some(temp_dir.path()));
+
+		let (_, _, active_monitors, networks, _, _, _) = initialize_services_with_options::<
+			MonitorRepository<NetworkRepository, TriggerRepository>,
+			NetworkRepository,
+			TriggerRepository,
+		>(
+			None,
+			None,
+			None,
+			InitOptions {
+				config_dir,
+				..Default::default()
+			},
+		)
+		.await
+		.unwrap();
+
+		assert_eq!(active_monitors.len(), 1);
+		assert_eq!(active_monitors[0].name, "Test Monitor");
+		assert_eq!(networks.len(), 1);
+		assert!(networks.contains_key("ethereum_mainnet"));
+	}
+
+	/// Runs the CLI with the given arguments and returns the exit code of the process
+	async fn run_exit_code(args: &[&str]) -> ExitCode {
+		let cli = Cli::parse_from(["openzeppelin-monitor"].iter().chain(args));
+		match run(cli).await {
+			Ok(()) => ExitCode::Success,
+			Err(e) => e.exit_code,
+		}
+	}
+
+	/// Points the RPC endpoints of the networks and the webhooks of the triggers of the
+	/// validation fixtures to a closed port
+	fn make_endpoints_unreachable(network_dir: &Path, trigger_dir: &Path) {
+		let unreachable_url = serde_json::json!({
+			"type": "plain",
+			"value": "http://127.0.0.1:1"
+		});
+		let path = network_dir.join("ethereum_mainnet.json");
+		let mut network: serde_json::Value =
+			serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+		for rpc_url in network["rpc_urls"].as_array_mut().unwrap() {
+			rpc_url["url"] = unreachable_url.clone();
+		}
+		std::fs::write(&path, network.to_string()).unwrap();
+
+		let path = trigger_dir.join("webhook_notifications.json");
+		let mut triggers: serde_json::Value =
+			serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+		for trigger in triggers.as_object_mut().unwrap().values_mut() {
+			trigger["config"]["url"] = unreachable_url.clone();
+		}
+		std::fs::write(&path, triggers.to_string()).unwrap();
+	}
+
+	#[tokio::test]
+	async fn test_exit_codes() {
+		let temp_dir = tempfile::TempDir::new().unwrap();
+		let (monitor_dir, network_dir, trigger_dir) =
+			write_validation_fixtures(&temp_dir, "ethereum_mainnet");
+		make_endpoints_unreachable(&network_dir, &trigger_dir);
+		let config_dir = temp_dir.path().to_str().unwrap();
+		let monitor_path = monitor_dir.join("monitor.json");
+
+		assert_eq!(
+			run_exit_code(&["--config-dir", config_dir, "--list-networks"]).await,
+			ExitCode::Success
+		);
+		assert_eq!(
+			run_exit_code(&[
+				"--config-dir",
+				config_dir,
+				"--monitor-path",
+				monitor_path.to_str().unwrap(),
+				"--network",
+				"ethereum_mainnet",
+				"--block",
+				"1",
+			])
+			.await,
+			ExitCode::Rpc
+		);
+		assert_eq!(
+			run_exit_code(&[
+				"--config-dir",
+				config_dir,
+				"--test-trigger",
+				"evm_large_transfer_usdc_webhook",
+			])
+			.await,
+			ExitCode::Notification
+		);
+		assert_eq!(
+			run_exit_code(&["--config-dir", config_dir, "--check-triggers"]).await,
+			ExitCode::Notification
+		);
+
+		// A monitor referencing a missing network fails validation, and the service can't load
+		write_validation_fixtures(&temp_dir, "missing_network");
+		assert_eq!(
+			run_exit_code(&["--config-dir", config_dir, "--validate-only"]).await,
+			ExitCode::Validation
+		);
+		assert_eq!(
+			run_exit_code(&["--config-dir", config_dir, "--list-monitors"]).await,
+			ExitCode::Config
+		);
+	}
+
+	#[test]
+	fn test_exit_code_values() {
+		let codes = [
+			ExitCode::Success,
+			ExitCode::Failure,
+			ExitCode::Usage,
+			ExitCode::Config,
+			ExitCode::Validation,
+			ExitCode::Rpc,
+			ExitCode::Notification,
+		]
+		.map(ExitCode::code);
+		assert_eq!(codes, [0, 1, 2, 3, 4, 5, 6]);
+
+		// Invalid arguments exit with the code of the argument parser
+		let error = Cli::try_parse_from(["openzeppelin-monitor", "--block", "latest"]).unwrap_err();
+		assert_eq!(error.exit_code(), i32::from(ExitCode::Usage.code()));
+	}
+
+	#[test]
+	fn test_print_schema_flag() {
+		let cli = Cli::parse_from(["openzeppelin-monitor", "--print-schema", "trigger"]);
+		assert_eq!(cli.print_schema, Some(ConfigKind::Trigger));
+
+		assert!(Cli::try_parse_from(["openzeppelin-monitor", "--print-schema", "bundle"]).is_err());
+	}
+
+	#[test]
+	fn test_output_file_flags() {
+		let cli = Cli::parse_from([
+			"openzeppelin-monitor",
+			"--monitor-path",
+			"monitor.json",
+			"--output-file",
+			"matches.jsonl",
+			"--output-format",
+			"jsonl",
+		]);
+		assert_eq!(cli.output_file, Some(PathBuf::from("matches.jsonl")));
+		assert_eq!(cli.output_format, MatchOutputFormat::Jsonl);
+
+		let cli = Cli::parse_from([
+			"openzeppelin-monitor",
+			"--monitor-path",
+			"monitor.json",
+			"--output-file",
+			"matches.json",
+		]);
+		assert_eq!(cli.output_format, MatchOutputFormat::Json);
+
+		// Matches are only written when testing a monitor
+		assert!(
+			Cli::try_parse_from(["openzeppelin-monitor", "--output-file", "matches.json"]).is_err()
+		);
+	}
 }
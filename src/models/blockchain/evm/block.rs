@@ -4,6 +4,7 @@ use alloy::{
 	primitives::{aliases::B2048, Address, Bytes, B256, B64, U256, U64},
 	rpc::types::{Block as AlloyBlock, BlockTransactions, Transaction as AlloyTransaction},
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
 
@@ -91,6 +92,16 @@ impl Block {
 	pub fn number(&self) -> Option<u64> {
 		self.0.number.map(|n| n.to())
 	}
+
+	/// Get the block timestamp in seconds since the Unix epoch
+	pub fn timestamp(&self) -> Option<u64> {
+		u64::try_from(self.0.timestamp).ok()
+	}
+
+	/// Get the block hash as a hex string
+	pub fn hash(&self) -> Option<String> {
+		self.0.hash.map(|hash| hash.to_string())
+	}
 }
 
 impl From<BaseBlock<EVMTransaction>> for Block {
@@ -145,6 +156,30 @@ impl Deref for Block {
 	}
 }
 
+/// Block tag identifying the most recent block processed on an EVM network
+#[derive(Debug, Copy, Clone, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockTag {
+	/// The latest block, of which the last `confirmation_blocks` are left unprocessed
+	#[default]
+	Latest,
+	/// The latest block unlikely to be reorganized, as reported by the node
+	Safe,
+	/// The latest finalized block, which can't be reorganized
+	Finalized,
+}
+
+impl BlockTag {
+	/// Returns the tag as passed to JSON-RPC methods
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			BlockTag::Latest => "latest",
+			BlockTag::Safe => "safe",
+			BlockTag::Finalized => "finalized",
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
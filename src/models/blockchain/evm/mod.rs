@@ -6,16 +6,19 @@
 mod block;
 mod monitor;
 mod receipt;
+mod trace;
 mod transaction;
 
-pub use block::Block as EVMBlock;
+pub use block::{Block as EVMBlock, BlockTag as EVMBlockTag};
 pub use monitor::{
-	ContractSpec as EVMContractSpec, MatchArguments as EVMMatchArguments,
-	MatchParamEntry as EVMMatchParamEntry, MatchParamsMap as EVMMatchParamsMap,
-	MonitorConfig as EVMMonitorConfig, MonitorMatch as EVMMonitorMatch,
+	ConfirmationState as EVMConfirmationState, ContractSpec as EVMContractSpec,
+	MatchArguments as EVMMatchArguments, MatchParamEntry as EVMMatchParamEntry,
+	MatchParamsMap as EVMMatchParamsMap, MonitorConfig as EVMMonitorConfig,
+	MonitorMatch as EVMMonitorMatch,
 };
 pub use receipt::{
 	BaseLog as EVMReceiptLog, BaseReceipt as EVMBaseReceipt,
 	TransactionReceipt as EVMTransactionReceipt,
 };
+pub use trace::{InternalCall as EVMInternalCall, TraceMethod as EVMTraceMethod};
 pub use transaction::{BaseTransaction as EVMBaseTransaction, Transaction as EVMTransaction};
@@ -1,6 +1,7 @@
 use crate::models::{
-	EVMReceiptLog, EVMTransaction, EVMTransactionReceipt, MatchConditions, Monitor,
+	EVMReceiptLog, EVMTransaction, EVMTransactionReceipt, MatchConditions, MatchSeverity, Monitor,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Result of a successful monitor match on an EVM chain
@@ -26,6 +27,40 @@ pub struct MonitorMatch {
 
 	/// Decoded arguments from the matched conditions
 	pub matched_on_args: Option<MatchArguments>,
+
+	/// Whether the transaction was mined or is still pending
+	#[serde(default)]
+	pub confirmation_state: ConfirmationState,
+
+	/// Whether the match satisfies the monitor conditions or is a near miss
+	#[serde(default)]
+	pub severity: MatchSeverity,
+
+	/// Whether the sender recovered from the signature of the transaction is its reported
+	/// `from` address, set for monitors with `verify_signature` when both are available
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub sender_verified: Option<bool>,
+}
+
+/// Confirmation state of the transaction of an EVM match
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfirmationState {
+	/// The transaction is part of a processed block
+	#[default]
+	Confirmed,
+	/// The transaction is waiting in the mempool, without a receipt or logs yet
+	Pending,
+}
+
+impl ConfirmationState {
+	/// Returns the name of the state, as used in notification templates
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			ConfirmationState::Confirmed => "confirmed",
+			ConfirmationState::Pending => "pending",
+		}
+	}
 }
 
 /// Collection of decoded parameters from matched conditions
@@ -65,6 +100,14 @@ pub struct MatchArguments {
 
 	/// Matched event arguments
 	pub events: Option<Vec<MatchParamsMap>>,
+
+	/// Properties of the block, for matches of block conditions
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub block: Option<Vec<MatchParamEntry>>,
+
+	/// Balance of the address, for matches of balance conditions
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub balance: Option<Vec<MatchParamEntry>>,
 }
 
 /// Contract specification for an EVM smart contract
@@ -72,8 +115,8 @@ pub struct MatchArguments {
 /// This structure represents the parsed specification of an EVM smart contract,
 /// following the Ethereum Contract ABI format. It contains information about all
 /// callable functions in the contract.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
-pub struct ContractSpec(alloy::json_abi::JsonAbi);
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
+pub struct ContractSpec(#[schemars(with = "Vec<serde_json::Value>")] alloy::json_abi::JsonAbi);
 
 /// Convert a ContractSpec to an EVMContractSpec
 impl From<crate::models::ContractSpec> for ContractSpec {
@@ -129,8 +172,13 @@ impl std::ops::Deref for ContractSpec {
 ///
 /// This configuration is used to for additional fields in the monitor configuration
 /// that are specific to EVM.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
-pub struct MonitorConfig {}
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
+pub struct MonitorConfig {
+	/// Whether transaction conditions are also evaluated against the internal calls of
+	/// transactions, which requires a network with a `trace_method`
+	#[serde(default)]
+	pub include_internal_transactions: bool,
+}
 
 #[cfg(test)]
 mod tests {
@@ -199,14 +247,24 @@ mod tests {
 				functions: vec![FunctionCondition {
 					signature: "transfer(address,uint256)".to_string(),
 					expression: None,
+					warn_threshold_expression: None,
+					eip712_schema: None,
 				}],
 				events: vec![],
 				transactions: vec![],
+				token_transfers: vec![],
+				block_conditions: vec![],
+				balance_conditions: vec![],
+				state_conditions: vec![],
 			},
 			matched_on_args: Some(MatchArguments {
 				functions: Some(vec![match_params]),
 				events: None,
+				block: None,
+				balance: None,
 			}),
+			confirmation_state: ConfirmationState::Confirmed,
+			severity: Default::default(),
 		};
 
 		assert_eq!(monitor_match.monitor.name, "TestMonitor");
@@ -291,6 +349,8 @@ mod tests {
 						.to_string(),
 				),
 			}]),
+			block: None,
+			balance: None,
 		};
 
 		assert!(match_args.functions.is_some());
@@ -60,6 +60,14 @@ pub struct BaseReceipt {
 	/// Effective gas price
 	#[serde(rename = "effectiveGasPrice")]
 	pub effective_gas_price: Option<U256>,
+	/// ABI-encoded revert data of a failed transaction, returned by the nodes including it in
+	/// their receipts or traced with `debug_traceTransaction`
+	#[serde(
+		rename = "revertReason",
+		default,
+		skip_serializing_if = "Option::is_none"
+	)]
+	pub revert_reason: Option<Bytes>,
 }
 
 /// Base Log struct
@@ -174,6 +182,7 @@ impl From<AlloyTransactionReceipt> for TransactionReceipt {
 				ReceiptEnvelope::Eip7702(_) => 4,
 			})),
 			effective_gas_price: Some(U256::from(receipt.effective_gas_price)),
+			revert_reason: None,
 		};
 		Self(tx)
 	}
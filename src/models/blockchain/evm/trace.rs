@@ -0,0 +1,43 @@
+//! EVM call trace data structures.
+
+use alloy::primitives::{Address, U256};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// JSON-RPC method used to trace the calls of a block
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+pub enum TraceMethod {
+	/// Geth-style `debug_traceBlockByNumber` with the `callTracer` tracer
+	#[serde(rename = "debug_traceBlockByNumber")]
+	DebugTraceBlockByNumber,
+	/// Parity/OpenEthereum-style `trace_block`
+	#[serde(rename = "trace_block")]
+	TraceBlock,
+}
+
+impl TraceMethod {
+	/// Returns the name of the JSON-RPC method
+	pub fn method_name(&self) -> &'static str {
+		match self {
+			TraceMethod::DebugTraceBlockByNumber => "debug_traceBlockByNumber",
+			TraceMethod::TraceBlock => "trace_block",
+		}
+	}
+}
+
+/// Call made by a contract during the execution of a transaction
+///
+/// Only nested calls are represented, the top-level call being the transaction itself.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct InternalCall {
+	/// Position of the transaction making the call within its block
+	pub transaction_position: usize,
+	/// Type of the call (e.g. `call`, `delegatecall`, `create`)
+	pub call_type: String,
+	/// Address making the call
+	pub from: Address,
+	/// Address receiving the call, or the created contract
+	pub to: Option<Address>,
+	/// Value transferred by the call
+	pub value: U256,
+}
@@ -1,8 +1,11 @@
 //! EVM transaction data structures.
 
 use alloy::{
-	consensus::Transaction as AlloyConsensusTransaction,
-	primitives::{Address, Bytes, B256, U256, U64},
+	consensus::{
+		SignableTransaction, Transaction as AlloyConsensusTransaction, TxEip1559, TxEip2930,
+		TxLegacy,
+	},
+	primitives::{Address, Bytes, Signature, TxKind, B256, U256, U64},
 	rpc::types::{AccessList, Index, Transaction as AlloyTransaction},
 };
 use serde::{Deserialize, Serialize};
@@ -150,6 +153,116 @@ impl Transaction {
 	pub fn hash(&self) -> &B256 {
 		&self.0.hash
 	}
+
+	/// Get the chain ID the transaction was signed for
+	///
+	/// Typed transactions carry it in their `chainId` field, legacy ones encode it in `v` as of
+	/// EIP-155. Legacy transactions signed before EIP-155, with a `v` of 27 or 28, have none,
+	/// even when the RPC reports a `chainId` for them.
+	pub fn chain_id(&self) -> Option<u64> {
+		match self.0.transaction_type.map_or(0, |t| t.to::<u64>()) {
+			0 => self
+				.0
+				.v
+				.map(|v| v.to::<u64>())
+				.filter(|v| *v >= 35)
+				.map(|v| (v - 35) / 2),
+			_ => self
+				.0
+				.extra
+				.get("chainId")
+				.and_then(|chain_id| serde_json::from_value::<U64>(chain_id.clone()).ok())
+				.map(|chain_id| chain_id.to::<u64>()),
+		}
+	}
+
+	/// Recovers the sender of the transaction from its signature
+	///
+	/// Rebuilds the signed payload of the transaction from its fields, so the sender doesn't
+	/// depend on the `from` address reported by the RPC.
+	///
+	/// # Returns
+	/// * `Option<Address>` - Signer of the transaction, or `None` if the transaction has no
+	///   signature or is of a type whose payload isn't rebuilt (blob, set code and L2 deposit
+	///   transactions)
+	pub fn recover_sender(&self) -> Option<Address> {
+		let tx = &self.0;
+		let (r, s, v) = (tx.r?, tx.s?, tx.v?.to::<u64>());
+		let nonce = u64::try_from(tx.nonce).ok()?;
+		let gas_limit = u64::try_from(tx.gas).ok()?;
+		let to = tx.to.map_or(TxKind::Create, TxKind::Call);
+		let access_list = tx.access_list.clone().unwrap_or_default();
+
+		let (signature_hash, y_parity) = match tx.transaction_type.map_or(0, |t| t.to::<u64>()) {
+			0 => {
+				let y_parity = match v {
+					27 | 28 => v == 28,
+					v if v >= 35 => (v - 35) % 2 == 1,
+					_ => return None,
+				};
+				let signed = TxLegacy {
+					chain_id: self.chain_id(),
+					nonce,
+					gas_price: u128::try_from(tx.gas_price?).ok()?,
+					gas_limit,
+					to,
+					value: tx.value,
+					input: tx.input.clone(),
+				};
+				(signed.signature_hash(), y_parity)
+			}
+			1 => {
+				let signed = TxEip2930 {
+					chain_id: self.chain_id()?,
+					nonce,
+					gas_price: u128::try_from(tx.gas_price?).ok()?,
+					gas_limit,
+					to,
+					value: tx.value,
+					access_list,
+					input: tx.input.clone(),
+				};
+				(signed.signature_hash(), self.y_parity()?)
+			}
+			2 => {
+				let signed = TxEip1559 {
+					chain_id: self.chain_id()?,
+					nonce,
+					gas_limit,
+					max_fee_per_gas: u128::try_from(tx.max_fee_per_gas?).ok()?,
+					max_priority_fee_per_gas: u128::try_from(tx.max_priority_fee_per_gas?).ok()?,
+					to,
+					value: tx.value,
+					access_list,
+					input: tx.input.clone(),
+				};
+				(signed.signature_hash(), self.y_parity()?)
+			}
+			_ => return None,
+		};
+
+		Signature::new(r, s, y_parity)
+			.recover_address_from_prehash(&signature_hash)
+			.ok()
+	}
+
+	/// Whether the sender recovered from the signature is the reported `from` address
+	///
+	/// # Returns
+	/// * `Option<bool>` - `None` if either address is unavailable
+	pub fn sender_verified(&self) -> Option<bool> {
+		let from = self.0.from?;
+		self.recover_sender().map(|sender| sender == from)
+	}
+
+	/// Get the signature parity of a typed transaction, from `yParity` or else `v`
+	fn y_parity(&self) -> Option<bool> {
+		match self.0.l2.y_parity.or(self.0.v)?.to::<u64>() {
+			0 => Some(false),
+			1 => Some(true),
+			_ => None,
+		}
+	}
 }
 
 impl From<BaseTransaction> for Transaction {
@@ -206,7 +319,24 @@ impl Deref for Transaction {
 mod tests {
 	use super::*;
 	use crate::utils::tests::builders::evm::transaction::TransactionBuilder;
-	use alloy::primitives::{Address, B256, U256};
+	use alloy::{
+		primitives::{Address, B256, U256},
+		signers::{local::PrivateKeySigner, SignerSync},
+	};
+
+	/// Builds an EIP-1559 transaction signed by a random key, as reported by an RPC
+	fn create_signed_transaction() -> (Transaction, Address) {
+		let signer = PrivateKeySigner::random();
+		let tx = TransactionBuilder::new()
+			.to(Address::with_last_byte(6))
+			.value(U256::from(1000))
+			.nonce(U256::from(7))
+			.max_fee_per_gas(U256::from(30_000_000_000u64))
+			.max_priority_fee_per_gas(U256::from(1_000_000_000u64))
+			.signed_by(&signer)
+			.build();
+		(tx, signer.address())
+	}
 
 	#[test]
 	fn test_value() {
@@ -274,4 +404,102 @@ mod tests {
 		let tx = Transaction(base_tx.clone());
 		assert_eq!(*tx, base_tx);
 	}
+
+	#[test]
+	fn test_recover_sender_of_signed_transaction() {
+		let (tx, signer) = create_signed_transaction();
+
+		assert_eq!(tx.chain_id(), Some(1));
+		assert_eq!(tx.recover_sender(), Some(signer));
+		assert_eq!(tx.sender_verified(), Some(true));
+	}
+
+	#[test]
+	fn test_recover_sender_of_legacy_transaction() {
+		let signer = PrivateKeySigner::random();
+		let unsigned = TxLegacy {
+			chain_id: Some(137),
+			nonce: 1,
+			gas_price: 20_000_000_000,
+			gas_limit: 21000,
+			to: TxKind::Call(Address::with_last_byte(6)),
+			value: U256::from(5),
+			input: Bytes::new(),
+		};
+		let signature = signer.sign_hash_sync(&unsigned.signature_hash()).unwrap();
+
+		let mut tx = TransactionBuilder::new()
+			.from(signer.address())
+			.to(Address::with_last_byte(6))
+			.value(U256::from(5))
+			.nonce(U256::from(1))
+			.gas_limit(U256::from(21000))
+			.gas_price(U256::from(20_000_000_000u64))
+			.build();
+		// EIP-155 encodes the chain ID in v
+		tx.0.v = Some(U64::from(137 * 2 + 35 + signature.v() as u64));
+		tx.0.r = Some(signature.r());
+		tx.0.s = Some(signature.s());
+
+		assert_eq!(tx.chain_id(), Some(137));
+		assert_eq!(tx.sender_verified(), Some(true));
+	}
+
+	#[test]
+	fn test_recover_sender_of_unprotected_legacy_transaction() {
+		let signer = PrivateKeySigner::random();
+		let unsigned = TxLegacy {
+			chain_id: None,
+			nonce: 1,
+			gas_price: 20_000_000_000,
+			gas_limit: 21000,
+			to: TxKind::Call(Address::with_last_byte(6)),
+			value: U256::from(5),
+			input: Bytes::new(),
+		};
+		let signature = signer.sign_hash_sync(&unsigned.signature_hash()).unwrap();
+
+		let mut tx = TransactionBuilder::new()
+			.from(signer.address())
+			.to(Address::with_last_byte(6))
+			.value(U256::from(5))
+			.nonce(U256::from(1))
+			.gas_limit(U256::from(21000))
+			.gas_price(U256::from(20_000_000_000u64))
+			.build();
+		// Signed before EIP-155, with a chain ID reported by the RPC nonetheless
+		tx.0.v = Some(U64::from(27 + signature.v() as u64));
+		tx.0.r = Some(signature.r());
+		tx.0.s = Some(signature.s());
+		tx.0.extra
+			.insert("chainId".to_string(), serde_json::json!("0x1"));
+
+		assert_eq!(tx.chain_id(), None);
+		assert_eq!(tx.recover_sender(), Some(signer.address()));
+		assert_eq!(tx.sender_verified(), Some(true));
+	}
+
+	#[test]
+	fn test_sender_verified_of_tampered_transaction() {
+		// The reported sender isn't the signer
+		let (mut tx, signer) = create_signed_transaction();
+		tx.0.from = Some(Address::with_last_byte(9));
+		assert_eq!(tx.recover_sender(), Some(signer));
+		assert_eq!(tx.sender_verified(), Some(false));
+
+		// The signed payload was altered
+		let (mut tx, signer) = create_signed_transaction();
+		tx.0.value = U256::from(1_000_000);
+		assert_ne!(tx.recover_sender(), Some(signer));
+		assert_eq!(tx.sender_verified(), Some(false));
+	}
+
+	#[test]
+	fn test_sender_verified_without_signature() {
+		let tx = TransactionBuilder::new()
+			.from(Address::with_last_byte(5))
+			.build();
+		assert_eq!(tx.recover_sender(), None);
+		assert_eq!(tx.sender_verified(), None);
+	}
 }
@@ -81,6 +81,20 @@ impl Block {
 	pub fn number(&self) -> Option<u64> {
 		Some(u64::from_str_radix(self.0.header.number.trim_start_matches("0x"), 16).unwrap_or(0))
 	}
+
+	/// Get the block timestamp
+	///
+	/// Midnight block headers don't carry a timestamp, so this always returns None.
+	pub fn timestamp(&self) -> Option<u64> {
+		None
+	}
+
+	/// Get the block hash
+	///
+	/// Midnight block headers only carry the parent hash, so this always returns None.
+	pub fn hash(&self) -> Option<String> {
+		None
+	}
 }
 
 impl From<RpcBlock> for Block {
@@ -4,6 +4,7 @@
 //! on the Midnight blockchain. It includes types for representing monitor matches,
 //! parameters, and configuration.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::models::{MatchConditions, MidnightTransaction, Monitor, SecretValue};
@@ -84,7 +85,7 @@ pub struct MatchArguments {
 /// This configuration is used for additional fields in the monitor configuration
 /// that are specific to Midnight. It includes viewing keys for decrypting
 /// transaction data.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
 pub struct MonitorConfig {
 	/// List of hex encoded viewing keys for decrypting transaction data
 	#[serde(default)]
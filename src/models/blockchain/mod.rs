@@ -1,17 +1,22 @@
 //! Blockchain-specific model implementations.
 //!
 //! This module contains type definitions and implementations for different
-//! blockchain platforms (EVM, Stellar, Midnight, etc). Each submodule implements the
+//! blockchain platforms (EVM, Stellar, Midnight, Solana, etc). Each submodule implements the
 //! platform-specific logic for blocks, transactions, and event monitoring.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::models::MatchSeverity;
 
 pub mod evm;
 pub mod midnight;
+pub mod solana;
 pub mod stellar;
 
 /// Supported blockchain platform types
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub enum BlockChainType {
 	/// Ethereum Virtual Machine based chains
@@ -20,6 +25,8 @@ pub enum BlockChainType {
 	Stellar,
 	/// Midnight blockchain
 	Midnight,
+	/// Solana blockchain
+	Solana,
 }
 
 /// Block data from different blockchain platforms
@@ -40,6 +47,11 @@ pub enum BlockType {
 	/// # Note
 	/// Box is used here to equalize the enum variants
 	Midnight(Box<midnight::MidnightBlock>),
+	/// Solana block and transaction data
+	///
+	/// # Note
+	/// Box is used here to equalize the enum variants
+	Solana(Box<solana::SolanaBlock>),
 }
 
 impl BlockType {
@@ -48,6 +60,27 @@ impl BlockType {
 			BlockType::EVM(b) => b.number(),
 			BlockType::Stellar(b) => b.number(),
 			BlockType::Midnight(b) => b.number(),
+			BlockType::Solana(b) => b.number(),
+		}
+	}
+
+	/// Returns the block timestamp in seconds since the Unix epoch, if known
+	pub fn timestamp(&self) -> Option<u64> {
+		match self {
+			BlockType::EVM(b) => b.timestamp(),
+			BlockType::Stellar(b) => b.timestamp(),
+			BlockType::Midnight(b) => b.timestamp(),
+			BlockType::Solana(b) => b.timestamp(),
+		}
+	}
+
+	/// Returns the block hash, if known
+	pub fn hash(&self) -> Option<String> {
+		match self {
+			BlockType::EVM(b) => b.hash(),
+			BlockType::Stellar(b) => b.hash(),
+			BlockType::Midnight(b) => b.hash(),
+			BlockType::Solana(b) => b.hash(),
 		}
 	}
 }
@@ -62,10 +95,12 @@ pub enum TransactionType {
 	Stellar(Box<stellar::StellarTransaction>),
 	/// Midnight transaction
 	Midnight(midnight::MidnightTransaction),
+	/// Solana transaction
+	Solana(Box<solana::SolanaTransaction>),
 }
 
 /// Contract spec from different blockchain platforms
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(untagged)]
 pub enum ContractSpec {
 	/// EVM contract spec
@@ -94,10 +129,29 @@ pub enum MonitorMatch {
 	/// # Note
 	/// Box is used here to equalize the enum variants
 	Midnight(Box<midnight::MidnightMonitorMatch>),
+	/// Matched conditions from Solana chains
+	///
+	/// # Note
+	/// Box is used here to equalize the enum variants
+	Solana(Box<solana::SolanaMonitorMatch>),
+}
+
+impl MonitorMatch {
+	/// Returns the severity of the match
+	///
+	/// Warn threshold expressions are only evaluated on EVM and Stellar chains, so matches from
+	/// other chains are always alerts.
+	pub fn severity(&self) -> MatchSeverity {
+		match self {
+			MonitorMatch::EVM(evm_match) => evm_match.severity,
+			MonitorMatch::Stellar(stellar_match) => stellar_match.severity,
+			MonitorMatch::Midnight(_) | MonitorMatch::Solana(_) => MatchSeverity::Alert,
+		}
+	}
 }
 
 /// Chain-specific configuration
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
 pub struct ChainConfiguration {
 	/// Midnight-specific configuration
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -119,5 +173,16 @@ pub struct ChainConfiguration {
 pub struct ProcessedBlock {
 	pub block_number: u64,
 	pub network_slug: String,
+	/// Hash of the block, used to skip blocks whose triggers already fired
+	///
+	/// None for pending transactions, and for blocks processed on a monitor's own schedule.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub block_hash: Option<String>,
 	pub processing_results: Vec<MonitorMatch>,
+	/// Block the matches were found in, kept for the trigger conditions with
+	/// `include_block_context`
+	///
+	/// None when no match needs it.
+	#[serde(skip)]
+	pub block: Option<Arc<BlockType>>,
 }
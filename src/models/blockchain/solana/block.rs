@@ -0,0 +1,116 @@
+//! Solana block (slot) data structures.
+//!
+//! Note: These structures are based on the Solana JSON-RPC `getBlock` response:
+//! <https://solana.com/docs/rpc/http/getblock>
+
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+
+use crate::models::SolanaTransactionInfo;
+
+/// Information about a Solana block
+///
+/// This structure represents the response from the `getBlock` RPC method. The slot is not
+/// part of the response body, so it is populated by the client after the block is fetched.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockInfo {
+	/// Slot of the block
+	#[serde(default)]
+	pub slot: u64,
+
+	/// Hash of the block, as a base-58 encoded string
+	pub blockhash: String,
+
+	/// Hash of the parent block, as a base-58 encoded string
+	pub previous_blockhash: String,
+
+	/// Slot of the parent block
+	pub parent_slot: u64,
+
+	/// Estimated production time as a Unix timestamp, if available
+	pub block_time: Option<i64>,
+
+	/// Number of blocks beneath this block, if available
+	pub block_height: Option<u64>,
+
+	/// Transactions included in the block
+	#[serde(default)]
+	pub transactions: Vec<SolanaTransactionInfo>,
+}
+
+/// Wrapper around BlockInfo that implements additional functionality
+///
+/// This type provides a convenient interface for working with Solana block data
+/// while maintaining compatibility with the RPC response format.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Block(pub BlockInfo);
+
+impl Block {
+	/// Get the block number (slot)
+	pub fn number(&self) -> Option<u64> {
+		Some(self.0.slot)
+	}
+
+	/// Get the estimated block production time in seconds since the Unix epoch
+	pub fn timestamp(&self) -> Option<u64> {
+		self.0.block_time.and_then(|t| u64::try_from(t).ok())
+	}
+
+	/// Get the block hash
+	pub fn hash(&self) -> Option<String> {
+		Some(self.0.blockhash.clone())
+	}
+}
+
+impl From<BlockInfo> for Block {
+	fn from(info: BlockInfo) -> Self {
+		Self(info)
+	}
+}
+
+impl Deref for Block {
+	type Target = BlockInfo;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_block_deserialization_and_number() {
+		let raw = json!({
+			"blockHeight": 100,
+			"blockTime": 1700000000,
+			"blockhash": "5Xr6yVJmHf3F3gn8MDb3NYrVGxZQGsEwzK3NV9RM3KX2",
+			"parentSlot": 249,
+			"previousBlockhash": "8wSfNaE5Wj9wP3gCwp39yHqmMeYkBx8xS7sXqNrm3F3Y",
+			"transactions": []
+		});
+
+		let mut info: BlockInfo = serde_json::from_value(raw).unwrap();
+		info.slot = 250;
+		let block = Block::from(info);
+
+		assert_eq!(block.number(), Some(250));
+		assert_eq!(block.parent_slot, 249);
+		assert_eq!(block.block_time, Some(1700000000));
+		assert_eq!(block.block_height, Some(100));
+		assert!(block.transactions.is_empty());
+	}
+
+	#[test]
+	fn test_default_implementation() {
+		let block = Block::default();
+
+		assert_eq!(block.number(), Some(0));
+		assert_eq!(block.blockhash, "");
+		assert!(block.block_time.is_none());
+		assert!(block.transactions.is_empty());
+	}
+}
@@ -0,0 +1,22 @@
+//! Solana blockchain specific implementations.
+//!
+//! This module contains data structures and implementations specific to the
+//! Solana blockchain, including blocks (slots), transactions
+//! and monitoring functionality.
+
+mod block;
+mod monitor;
+mod transaction;
+
+pub use block::{Block as SolanaBlock, BlockInfo as SolanaBlockInfo};
+pub use monitor::{
+	MatchArguments as SolanaMatchArguments, MatchParamEntry as SolanaMatchParamEntry,
+	MatchParamsMap as SolanaMatchParamsMap, MonitorMatch as SolanaMonitorMatch,
+};
+pub use transaction::{
+	InnerInstructions as SolanaInnerInstructions, Instruction as SolanaInstruction,
+	LoadedAddresses as SolanaLoadedAddresses, Message as SolanaMessage,
+	SignatureInfo as SolanaSignatureInfo, Transaction as SolanaTransaction,
+	TransactionData as SolanaTransactionData, TransactionInfo as SolanaTransactionInfo,
+	TransactionMeta as SolanaTransactionMeta,
+};
@@ -0,0 +1,66 @@
+//! Monitor implementation for Solana blockchain.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{MatchConditions, Monitor, SolanaTransaction};
+
+/// Result of a successful monitor match on a Solana chain
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MonitorMatch {
+	/// Monitor configuration that triggered the match
+	pub monitor: Monitor,
+
+	/// Transaction that triggered the match
+	pub transaction: SolanaTransaction,
+
+	/// Slot containing the matched transaction
+	pub slot: u64,
+
+	/// Estimated production time of the slot as a Unix timestamp, if available
+	pub block_time: Option<i64>,
+
+	/// Network slug that the transaction was sent from
+	pub network_slug: String,
+
+	/// Conditions that were matched
+	pub matched_on: MatchConditions,
+
+	/// Decoded arguments from the matched conditions
+	pub matched_on_args: Option<MatchArguments>,
+}
+
+/// Collection of parameters from matched conditions
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MatchParamsMap {
+	/// Instruction name or log pattern
+	pub signature: String,
+
+	/// Matched values
+	pub args: Option<Vec<MatchParamEntry>>,
+}
+
+/// Single parameter from a matched instruction or log
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MatchParamEntry {
+	/// Parameter name
+	pub name: String,
+
+	/// Parameter value
+	pub value: String,
+
+	/// Parameter type
+	pub kind: String,
+
+	/// Whether this is an indexed parameter
+	pub indexed: bool,
+}
+
+/// Arguments matched from instructions and logs
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MatchArguments {
+	/// Matched instruction arguments
+	pub functions: Option<Vec<MatchParamsMap>>,
+
+	/// Matched log arguments
+	pub events: Option<Vec<MatchParamsMap>>,
+}
@@ -0,0 +1,375 @@
+//! Solana transaction data structures.
+//!
+//! Note: These structures are based on the Solana JSON-RPC `getTransaction` response
+//! and the transaction entries of `getBlock`, both requested with `"encoding": "json"`:
+//! <https://solana.com/docs/rpc/http/gettransaction>
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::ops::Deref;
+
+/// A compiled instruction referencing accounts by index into the account keys
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Instruction {
+	/// Index of the program account in the transaction account keys
+	pub program_id_index: u8,
+
+	/// Indices of the accounts passed to the program
+	#[serde(default)]
+	pub accounts: Vec<u8>,
+
+	/// Base-58 encoded instruction data
+	#[serde(default)]
+	pub data: String,
+
+	/// Invocation depth of the instruction, if reported by the node
+	#[serde(default)]
+	pub stack_height: Option<u32>,
+}
+
+/// Inner instructions executed by a top-level instruction through cross-program invocation
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InnerInstructions {
+	/// Index of the top-level instruction that produced these inner instructions
+	pub index: u8,
+
+	/// Inner instructions in execution order
+	pub instructions: Vec<Instruction>,
+}
+
+/// Accounts loaded from address lookup tables (versioned transactions only)
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct LoadedAddresses {
+	/// Writable loaded accounts
+	#[serde(default)]
+	pub writable: Vec<String>,
+
+	/// Readonly loaded accounts
+	#[serde(default)]
+	pub readonly: Vec<String>,
+}
+
+/// Transaction status metadata
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionMeta {
+	/// Error if the transaction failed, `null` if it succeeded
+	pub err: Option<Value>,
+
+	/// Fee charged for the transaction, in lamports
+	#[serde(default)]
+	pub fee: u64,
+
+	/// Account balances before the transaction was processed
+	#[serde(default)]
+	pub pre_balances: Vec<u64>,
+
+	/// Account balances after the transaction was processed
+	#[serde(default)]
+	pub post_balances: Vec<u64>,
+
+	/// Inner instructions, omitted if inner instruction recording was not enabled
+	#[serde(default)]
+	pub inner_instructions: Option<Vec<InnerInstructions>>,
+
+	/// Log messages, omitted if log message recording was not enabled
+	#[serde(default)]
+	pub log_messages: Option<Vec<String>>,
+
+	/// Accounts loaded from address lookup tables
+	#[serde(default)]
+	pub loaded_addresses: Option<LoadedAddresses>,
+
+	/// Compute units consumed by the transaction, if reported by the node
+	#[serde(default)]
+	pub compute_units_consumed: Option<u64>,
+}
+
+/// Message contained in a transaction
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+	/// Base-58 encoded public keys used by the transaction
+	pub account_keys: Vec<String>,
+
+	/// Recent blockhash used to prevent duplication
+	#[serde(default)]
+	pub recent_blockhash: String,
+
+	/// Top-level program instructions
+	#[serde(default)]
+	pub instructions: Vec<Instruction>,
+}
+
+/// Signed transaction payload
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct TransactionData {
+	/// Base-58 encoded signatures, the first one being the transaction id
+	pub signatures: Vec<String>,
+
+	/// The transaction message
+	pub message: Message,
+}
+
+/// Information about a Solana transaction
+///
+/// This structure represents a transaction entry as returned by `getBlock` or
+/// `getTransaction`. The slot and block time are only part of the `getTransaction`
+/// response, so they are populated from the block when transactions are read from a block.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionInfo {
+	/// Slot the transaction was processed in
+	#[serde(default)]
+	pub slot: u64,
+
+	/// Estimated production time as a Unix timestamp, if available
+	#[serde(default)]
+	pub block_time: Option<i64>,
+
+	/// Transaction status metadata
+	pub meta: Option<TransactionMeta>,
+
+	/// The signed transaction
+	pub transaction: TransactionData,
+
+	/// Transaction version ("legacy" or a number), if requested
+	#[serde(default)]
+	pub version: Option<Value>,
+}
+
+/// Signature entry as returned by `getSignaturesForAddress`
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureInfo {
+	/// Base-58 encoded transaction signature
+	pub signature: String,
+
+	/// Slot the transaction was processed in
+	pub slot: u64,
+
+	/// Error if the transaction failed, `null` if it succeeded
+	#[serde(default)]
+	pub err: Option<Value>,
+
+	/// Memo associated with the transaction, if any
+	#[serde(default)]
+	pub memo: Option<String>,
+
+	/// Estimated production time as a Unix timestamp, if available
+	#[serde(default)]
+	pub block_time: Option<i64>,
+
+	/// Cluster confirmation status of the transaction
+	#[serde(default)]
+	pub confirmation_status: Option<String>,
+}
+
+/// Wrapper around TransactionInfo that provides additional functionality
+///
+/// This type implements convenience methods for working with Solana transactions
+/// while maintaining compatibility with the RPC response format.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct Transaction(pub TransactionInfo);
+
+impl Transaction {
+	/// Get the transaction signature (the first signature of the transaction)
+	pub fn signature(&self) -> &str {
+		self.0
+			.transaction
+			.signatures
+			.first()
+			.map(|s| s.as_str())
+			.unwrap_or_default()
+	}
+
+	/// Whether the transaction was executed successfully
+	///
+	/// Transactions without status metadata are treated as failed.
+	pub fn is_success(&self) -> bool {
+		self.0.meta.as_ref().is_some_and(|meta| meta.err.is_none())
+	}
+
+	/// Get the account that paid the transaction fee
+	pub fn fee_payer(&self) -> Option<&String> {
+		self.0.transaction.message.account_keys.first()
+	}
+
+	/// Get all account keys referenced by the transaction
+	///
+	/// The order matches the indices used by instructions: static account keys first,
+	/// followed by writable and readonly accounts loaded from address lookup tables.
+	pub fn account_keys(&self) -> Vec<String> {
+		let mut keys = self.0.transaction.message.account_keys.clone();
+		if let Some(loaded) = self
+			.0
+			.meta
+			.as_ref()
+			.and_then(|m| m.loaded_addresses.as_ref())
+		{
+			keys.extend(loaded.writable.iter().cloned());
+			keys.extend(loaded.readonly.iter().cloned());
+		}
+		keys
+	}
+
+	/// Get the unique program ids invoked by the transaction
+	///
+	/// Includes programs invoked by top-level instructions as well as programs invoked
+	/// through cross-program invocation, when inner instructions are available.
+	pub fn program_ids(&self) -> Vec<String> {
+		let account_keys = self.account_keys();
+		let inner_instructions = self
+			.0
+			.meta
+			.as_ref()
+			.and_then(|m| m.inner_instructions.as_ref())
+			.into_iter()
+			.flatten()
+			.flat_map(|inner| inner.instructions.iter());
+
+		let mut program_ids = Vec::new();
+		for instruction in self
+			.0
+			.transaction
+			.message
+			.instructions
+			.iter()
+			.chain(inner_instructions)
+		{
+			if let Some(program_id) = account_keys.get(instruction.program_id_index as usize) {
+				if !program_ids.contains(program_id) {
+					program_ids.push(program_id.clone());
+				}
+			}
+		}
+		program_ids
+	}
+
+	/// Get the log messages emitted by the transaction
+	pub fn log_messages(&self) -> &[String] {
+		self.0
+			.meta
+			.as_ref()
+			.and_then(|m| m.log_messages.as_deref())
+			.unwrap_or_default()
+	}
+}
+
+impl From<TransactionInfo> for Transaction {
+	fn from(tx: TransactionInfo) -> Self {
+		Self(tx)
+	}
+}
+
+impl Deref for Transaction {
+	type Target = TransactionInfo;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	fn create_test_transaction() -> Transaction {
+		let raw = json!({
+			"meta": {
+				"err": null,
+				"fee": 5000,
+				"preBalances": [100, 0, 1],
+				"postBalances": [95, 0, 1],
+				"innerInstructions": [{
+					"index": 0,
+					"instructions": [{
+						"programIdIndex": 3,
+						"accounts": [0],
+						"data": "3Bxs4h24hBtQy9rw",
+						"stackHeight": 2
+					}]
+				}],
+				"logMessages": [
+					"Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [1]",
+					"Program log: Instruction: Transfer",
+					"Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success"
+				],
+				"loadedAddresses": {
+					"writable": [],
+					"readonly": ["ComputeBudget111111111111111111111111111111"]
+				}
+			},
+			"transaction": {
+				"signatures": ["5h6xBEauJ3PK6SWCZ1PGjBvj8vDdWG3KpwATGy1ARAXFSDwt8GFXM7W5Ncn16wmqokgpiKRLuS83KUxyZyv2sUYv"],
+				"message": {
+					"accountKeys": [
+						"FeePayer1111111111111111111111111111111111",
+						"Recipient111111111111111111111111111111111",
+						"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+					],
+					"recentBlockhash": "EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N",
+					"instructions": [{
+						"programIdIndex": 2,
+						"accounts": [0, 1],
+						"data": "3Bxs4h24hBtQy9rw"
+					}]
+				}
+			},
+			"version": "legacy"
+		});
+		Transaction::from(serde_json::from_value::<TransactionInfo>(raw).unwrap())
+	}
+
+	#[test]
+	fn test_transaction_accessors() {
+		let tx = create_test_transaction();
+
+		assert_eq!(
+			tx.signature(),
+			"5h6xBEauJ3PK6SWCZ1PGjBvj8vDdWG3KpwATGy1ARAXFSDwt8GFXM7W5Ncn16wmqokgpiKRLuS83KUxyZyv2sUYv"
+		);
+		assert!(tx.is_success());
+		assert_eq!(
+			tx.fee_payer(),
+			Some(&"FeePayer1111111111111111111111111111111111".to_string())
+		);
+		assert_eq!(tx.log_messages().len(), 3);
+	}
+
+	#[test]
+	fn test_account_keys_include_loaded_addresses() {
+		let tx = create_test_transaction();
+		let keys = tx.account_keys();
+
+		assert_eq!(keys.len(), 4);
+		assert_eq!(keys[3], "ComputeBudget111111111111111111111111111111");
+	}
+
+	#[test]
+	fn test_program_ids_include_inner_instructions() {
+		let tx = create_test_transaction();
+
+		assert_eq!(
+			tx.program_ids(),
+			vec![
+				"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA".to_string(),
+				"ComputeBudget111111111111111111111111111111".to_string(),
+			]
+		);
+	}
+
+	#[test]
+	fn test_failed_and_missing_meta() {
+		let mut tx = create_test_transaction();
+		tx.0.meta.as_mut().unwrap().err = Some(json!({"InstructionError": [0, "Custom"]}));
+		assert!(!tx.is_success());
+
+		tx.0.meta = None;
+		assert!(!tx.is_success());
+		assert!(tx.log_messages().is_empty());
+	}
+}
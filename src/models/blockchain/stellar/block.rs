@@ -56,6 +56,16 @@ impl Block {
 	pub fn number(&self) -> Option<u64> {
 		Some(self.0.sequence as u64)
 	}
+
+	/// Get the ledger close time in seconds since the Unix epoch
+	pub fn timestamp(&self) -> Option<u64> {
+		self.0.ledger_close_time.parse().ok()
+	}
+
+	/// Get the ledger hash
+	pub fn hash(&self) -> Option<String> {
+		Some(self.0.hash.clone())
+	}
 }
 
 impl From<LedgerInfo> for Block {
@@ -1,11 +1,12 @@
 //! Monitor implementation for Stellar blockchain.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use stellar_xdr::curr::ScSpecEntry;
 
 use crate::{
-	models::{MatchConditions, Monitor, StellarBlock, StellarTransaction},
+	models::{MatchConditions, MatchSeverity, Monitor, StellarBlock, StellarTransaction},
 	services::filter::stellar_helpers::{
 		get_contract_spec_events, get_contract_spec_functions,
 		get_contract_spec_with_event_parameters, get_contract_spec_with_function_input_parameters,
@@ -32,6 +33,10 @@ pub struct MonitorMatch {
 
 	/// Decoded arguments from the matched conditions
 	pub matched_on_args: Option<MatchArguments>,
+
+	/// Whether the match satisfies the monitor conditions or is a near miss
+	#[serde(default)]
+	pub severity: MatchSeverity,
 }
 
 /// Collection of decoded parameters from matched conditions
@@ -68,6 +73,14 @@ pub struct MatchArguments {
 
 	/// Matched event arguments
 	pub events: Option<Vec<MatchParamsMap>>,
+
+	/// Properties of the block, for matches of block conditions
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub block: Option<Vec<MatchParamEntry>>,
+
+	/// Balance of the address, for matches of balance conditions
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub balance: Option<Vec<MatchParamEntry>>,
 }
 
 /// Parsed result of a Stellar contract operation
@@ -109,8 +122,8 @@ pub struct DecodedParamEntry {
 /// from ScSpecEntry. It contains the raw contract interface data as provided by the Stellar
 /// blockchain, including all function definitions, types, and other contract metadata in their
 /// original format.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
-pub struct ContractSpec(Vec<ScSpecEntry>);
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
+pub struct ContractSpec(#[schemars(with = "Vec<Value>")] Vec<ScSpecEntry>);
 
 impl From<Vec<ScSpecEntry>> for ContractSpec {
 	fn from(spec: Vec<ScSpecEntry>) -> Self {
@@ -292,7 +305,7 @@ pub struct ContractEvent {
 ///
 /// This configuration is used to for additional fields in the monitor configuration
 /// that are specific to Stellar.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
 pub struct MonitorConfig {}
 
 #[cfg(test)]
@@ -549,14 +562,23 @@ mod tests {
 				functions: vec![FunctionCondition {
 					signature: "transfer(address,uint256)".to_string(),
 					expression: None,
+					warn_threshold_expression: None,
+					eip712_schema: None,
 				}],
 				events: vec![],
 				transactions: vec![],
+				token_transfers: vec![],
+				block_conditions: vec![],
+				balance_conditions: vec![],
+				state_conditions: vec![],
 			},
 			matched_on_args: Some(MatchArguments {
 				functions: Some(vec![match_params]),
 				events: None,
+				block: None,
+				balance: None,
 			}),
+			severity: Default::default(),
 		};
 
 		assert_eq!(monitor_match.monitor.name, "TestMonitor");
@@ -671,6 +693,8 @@ mod tests {
 					},
 				]),
 			}]),
+			block: None,
+			balance: None,
 		};
 
 		assert!(match_args.functions.is_some());
@@ -0,0 +1,186 @@
+//! On-disk cache for parsed contract specs.
+//!
+//! Contract specs embedded in monitor configurations are deserialized through the untagged
+//! [`ContractSpec`] enum, which tries every blockchain format in turn. For monitors watching
+//! many contracts this is repeated for every spec on each start. The cache stores the parsed
+//! spec with an explicit blockchain tag, keyed by a SHA-256 hash of the raw spec bytes, so
+//! unchanged specs skip the untagged resolution and any change to the spec yields a new key.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{env, fs, path::PathBuf};
+
+use crate::models::{ContractSpec, EVMContractSpec, StellarContractSpec};
+
+/// Default directory for cached contract specs
+const DEFAULT_ABI_CACHE_DIR: &str = "data/abi_cache";
+
+/// Tagged representation of a contract spec as stored in the cache
+#[derive(Serialize, Deserialize)]
+enum CachedContractSpec {
+	EVM(EVMContractSpec),
+	Stellar(StellarContractSpec),
+	Midnight,
+}
+
+impl From<CachedContractSpec> for ContractSpec {
+	fn from(spec: CachedContractSpec) -> Self {
+		match spec {
+			CachedContractSpec::EVM(spec) => ContractSpec::EVM(spec),
+			CachedContractSpec::Stellar(spec) => ContractSpec::Stellar(spec),
+			CachedContractSpec::Midnight => ContractSpec::Midnight,
+		}
+	}
+}
+
+impl From<&ContractSpec> for CachedContractSpec {
+	fn from(spec: &ContractSpec) -> Self {
+		match spec {
+			ContractSpec::EVM(spec) => CachedContractSpec::EVM(spec.clone()),
+			ContractSpec::Stellar(spec) => CachedContractSpec::Stellar(spec.clone()),
+			ContractSpec::Midnight => CachedContractSpec::Midnight,
+		}
+	}
+}
+
+/// Content-addressed cache of parsed contract specs
+#[derive(Debug, Clone)]
+pub struct AbiCache {
+	/// Directory holding one file per cached spec
+	dir: PathBuf,
+}
+
+impl AbiCache {
+	/// Creates a cache rooted at the given directory
+	pub fn new(dir: impl Into<PathBuf>) -> Self {
+		Self { dir: dir.into() }
+	}
+
+	/// Creates a cache from the environment
+	///
+	/// Uses `ABI_CACHE_DIR` as the cache directory (default `data/abi_cache`).
+	///
+	/// # Returns
+	/// * `Option<Self>` - `None` if the cache is disabled through `ABI_CACHE_DISABLED=true`
+	pub fn from_env() -> Option<Self> {
+		if env::var("ABI_CACHE_DISABLED").is_ok_and(|v| v.eq_ignore_ascii_case("true")) {
+			return None;
+		}
+		let dir = env::var("ABI_CACHE_DIR").unwrap_or_else(|_| DEFAULT_ABI_CACHE_DIR.to_string());
+		Some(Self::new(dir))
+	}
+
+	/// Computes the cache key of a raw contract spec
+	///
+	/// # Arguments
+	/// * `raw_spec` - Raw bytes of the contract spec
+	///
+	/// # Returns
+	/// * `String` - Hex-encoded SHA-256 hash of the bytes
+	pub fn key(raw_spec: &[u8]) -> String {
+		hex::encode(Sha256::digest(raw_spec))
+	}
+
+	fn entry_path(&self, key: &str) -> PathBuf {
+		self.dir.join(format!("{}.json", key))
+	}
+
+	/// Looks up a parsed contract spec
+	///
+	/// Unreadable or corrupt entries are treated as a miss.
+	///
+	/// # Arguments
+	/// * `key` - Cache key computed with [`AbiCache::key`]
+	///
+	/// # Returns
+	/// * `Option<ContractSpec>` - The cached spec, if present
+	pub fn get(&self, key: &str) -> Option<ContractSpec> {
+		let content = fs::read(self.entry_path(key)).ok()?;
+		match serde_json::from_slice::<CachedContractSpec>(&content) {
+			Ok(spec) => Some(spec.into()),
+			Err(e) => {
+				tracing::debug!("Ignoring corrupt ABI cache entry {}: {}", key, e);
+				None
+			}
+		}
+	}
+
+	/// Stores a parsed contract spec
+	///
+	/// Failures are logged and otherwise ignored, as the cache is only an optimization.
+	///
+	/// # Arguments
+	/// * `key` - Cache key computed with [`AbiCache::key`]
+	/// * `spec` - Parsed contract spec
+	pub fn put(&self, key: &str, spec: &ContractSpec) {
+		let result = fs::create_dir_all(&self.dir)
+			.map_err(|e| e.to_string())
+			.and_then(|_| {
+				serde_json::to_vec(&CachedContractSpec::from(spec)).map_err(|e| e.to_string())
+			})
+			.and_then(|content| {
+				// Write to a temporary file first so concurrent readers never see partial entries
+				let tmp_path = self.dir.join(format!("{}.tmp", key));
+				fs::write(&tmp_path, content)
+					.and_then(|_| fs::rename(&tmp_path, self.entry_path(key)))
+					.map_err(|e| e.to_string())
+			});
+
+		if let Err(e) = result {
+			tracing::warn!("Failed to write ABI cache entry {}: {}", key, e);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+	use tempfile::TempDir;
+
+	fn create_evm_spec() -> serde_json::Value {
+		json!([{
+			"type": "function",
+			"name": "transfer",
+			"inputs": [
+				{"name": "to", "type": "address", "internalType": "address"},
+				{"name": "value", "type": "uint256", "internalType": "uint256"}
+			],
+			"outputs": [{"name": "", "type": "bool", "internalType": "bool"}],
+			"stateMutability": "nonpayable"
+		}])
+	}
+
+	#[test]
+	fn test_key_changes_with_content() {
+		let spec = serde_json::to_vec(&create_evm_spec()).unwrap();
+		let mut changed = create_evm_spec();
+		changed[0]["name"] = json!("approve");
+		let changed = serde_json::to_vec(&changed).unwrap();
+
+		assert_eq!(AbiCache::key(&spec), AbiCache::key(&spec));
+		assert_ne!(AbiCache::key(&spec), AbiCache::key(&changed));
+	}
+
+	#[test]
+	fn test_put_and_get_round_trip() {
+		let temp_dir = TempDir::new().unwrap();
+		let cache = AbiCache::new(temp_dir.path().join("abi"));
+		let spec: ContractSpec = serde_json::from_value(create_evm_spec()).unwrap();
+		let key = AbiCache::key(b"spec");
+
+		assert!(cache.get(&key).is_none());
+		cache.put(&key, &spec);
+		assert_eq!(cache.get(&key), Some(spec));
+	}
+
+	#[test]
+	fn test_get_ignores_corrupt_entry() {
+		let temp_dir = TempDir::new().unwrap();
+		let cache = AbiCache::new(temp_dir.path());
+		let key = AbiCache::key(b"spec");
+		fs::write(temp_dir.path().join(format!("{}.json", key)), "not json").unwrap();
+
+		assert!(cache.get(&key).is_none());
+	}
+}
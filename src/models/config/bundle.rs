@@ -0,0 +1,324 @@
+//! Bundled configuration loading.
+//!
+//! This module loads monitors, networks and triggers from a single JSON or YAML document
+//! with top-level `monitors`, `networks` and `triggers` arrays, as an alternative to the
+//! per-type configuration directories.
+
+use serde::Deserialize;
+use serde_json::Value;
+use std::{collections::HashMap, fs, path::Path};
+
+use crate::models::{
+	config::{error::ConfigError, monitor_config::parse_with_abi_cache, AbiCache},
+	ConfigLoader, Monitor, Network, Trigger,
+};
+
+/// File structure for configuration bundles
+///
+/// Entries are kept as raw values so that each one is parsed and validated on its own.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigBundleFile {
+	/// Monitor configurations
+	#[serde(default)]
+	monitors: Vec<Value>,
+	/// Network configurations
+	#[serde(default)]
+	networks: Vec<Value>,
+	/// Trigger configurations
+	#[serde(default)]
+	triggers: Vec<Value>,
+}
+
+/// Validated configurations loaded from a bundle
+///
+/// Monitors and triggers are keyed by name and networks by slug, which are the identifiers
+/// monitors use to reference them.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigBundle {
+	/// Map of monitor names to their configurations
+	pub monitors: HashMap<String, Monitor>,
+	/// Map of network slugs to their configurations
+	pub networks: HashMap<String, Network>,
+	/// Map of trigger names to their configurations
+	pub triggers: HashMap<String, Trigger>,
+}
+
+/// Load all configurations from a single bundle file
+///
+/// Files with a `.yaml` or `.yml` extension are parsed as YAML, any other file as JSON.
+/// Every entry is resolved and validated on its own, and all problems are reported together,
+/// each tagged with its section and index (e.g. `networks[1]`).
+///
+/// # Arguments
+/// * `path` - Path to the bundle file
+///
+/// # Returns
+/// * `Result<ConfigBundle, ConfigError>` - The loaded configurations or an error
+pub async fn load_bundle(path: &Path) -> Result<ConfigBundle, ConfigError> {
+	let path_metadata = || {
+		Some(HashMap::from([(
+			"path".to_string(),
+			path.display().to_string(),
+		)]))
+	};
+
+	let content = fs::read_to_string(path).map_err(|e| {
+		ConfigError::file_error(
+			format!("failed to read config bundle: {}", e),
+			Some(Box::new(e)),
+			path_metadata(),
+		)
+	})?;
+
+	let file: ConfigBundleFile = if is_yaml_file(path) {
+		serde_yaml::from_str(&content).map_err(|e| {
+			ConfigError::parse_error(
+				format!("failed to parse config bundle: {}", e),
+				Some(Box::new(e)),
+				path_metadata(),
+			)
+		})?
+	} else {
+		serde_json::from_str(&content).map_err(|e| {
+			ConfigError::parse_error(
+				format!("failed to parse config bundle: {}", e),
+				Some(Box::new(e)),
+				path_metadata(),
+			)
+		})?
+	};
+
+	let mut problems = Vec::new();
+
+	let abi_cache = AbiCache::from_env();
+	let monitors = load_section(
+		"monitors",
+		file.monitors,
+		|value| match &abi_cache {
+			Some(cache) => parse_with_abi_cache(value, cache),
+			None => serde_json::from_value(value),
+		},
+		|monitor: &Monitor| monitor.name.clone(),
+		&mut problems,
+	)
+	.await;
+	let networks = load_section(
+		"networks",
+		file.networks,
+		serde_json::from_value,
+		|network: &Network| network.slug.clone(),
+		&mut problems,
+	)
+	.await;
+	let triggers = load_section(
+		"triggers",
+		file.triggers,
+		serde_json::from_value,
+		|trigger: &Trigger| trigger.name.clone(),
+		&mut problems,
+	)
+	.await;
+
+	if !problems.is_empty() {
+		return Err(ConfigError::validation_error(
+			format!("Config bundle validation failed:\n{}", problems.join("\n")),
+			None,
+			path_metadata(),
+		));
+	}
+
+	Ok(ConfigBundle {
+		monitors,
+		networks,
+		triggers,
+	})
+}
+
+/// Check if a file is a YAML file based on extension
+fn is_yaml_file(path: &Path) -> bool {
+	path.extension()
+		.map(|ext| {
+			matches!(
+				ext.to_string_lossy().to_lowercase().as_str(),
+				"yaml" | "yml"
+			)
+		})
+		.unwrap_or(false)
+}
+
+/// Parses, resolves and validates every entry of a bundle section
+///
+/// Entries that fail are skipped and their problem is recorded, tagged with the section name
+/// and the entry index.
+async fn load_section<C: ConfigLoader + Sync>(
+	section: &str,
+	entries: Vec<Value>,
+	parse: impl Fn(Value) -> Result<C, serde_json::Error>,
+	key: impl Fn(&C) -> String,
+	problems: &mut Vec<String>,
+) -> HashMap<String, C> {
+	let mut pairs: Vec<(String, C)> = Vec::new();
+
+	for (index, value) in entries.into_iter().enumerate() {
+		let location = format!("{}[{}]", section, index);
+
+		let config = match parse(value) {
+			Ok(config) => config,
+			Err(e) => {
+				problems.push(format!("{}: failed to parse: {}", location, e));
+				continue;
+			}
+		};
+
+		// Resolve secrets before validating
+		let config = match config.resolve_secrets().await {
+			Ok(config) => config,
+			Err(e) => {
+				problems.push(format!("{}: {}", location, e));
+				continue;
+			}
+		};
+
+		let existing: Vec<&C> = pairs.iter().map(|(_, config)| config).collect();
+		if let Err(e) = config
+			.validate()
+			.and_then(|_| C::validate_uniqueness(&existing, &config, &location))
+		{
+			problems.push(format!("{}: {}", location, e));
+			continue;
+		}
+
+		pairs.push((key(&config), config));
+	}
+
+	pairs.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use tempfile::TempDir;
+
+	fn network_value(slug: &str) -> Value {
+		serde_json::json!({
+			"network_type": "EVM",
+			"slug": slug,
+			"name": "Ethereum Mainnet",
+			"rpc_urls": [{
+				"type_": "rpc",
+				"url": { "type": "plain", "value": "https://eth.drpc.org" },
+				"weight": 100
+			}],
+			"chain_id": 1,
+			"block_time_ms": 12000,
+			"confirmation_blocks": 12,
+			"cron_schedule": "0 */1 * * * *",
+			"max_past_blocks": 18,
+			"store_blocks": false
+		})
+	}
+
+	fn trigger_value(name: &str) -> Value {
+		serde_json::json!({
+			"name": name,
+			"trigger_type": "webhook",
+			"config": {
+				"url": { "type": "plain", "value": "https://webhook.site/123-456-789" },
+				"method": "POST",
+				"message": { "title": "Alert", "body": "Test message" }
+			}
+		})
+	}
+
+	fn monitor_value(name: &str) -> Value {
+		serde_json::json!({
+			"name": name,
+			"networks": ["ethereum_mainnet"],
+			"paused": false,
+			"addresses": [],
+			"match_conditions": { "functions": [], "events": [], "transactions": [] },
+			"trigger_conditions": [],
+			"triggers": ["large_transfer_webhook"]
+		})
+	}
+
+	#[tokio::test]
+	async fn test_load_json_bundle() {
+		let temp_dir = TempDir::new().unwrap();
+		let path = temp_dir.path().join("bundle.json");
+		let bundle = serde_json::json!({
+			"monitors": [monitor_value("Large Transfer")],
+			"networks": [network_value("ethereum_mainnet")],
+			"triggers": [trigger_value("large_transfer_webhook")]
+		});
+		fs::write(&path, bundle.to_string()).unwrap();
+
+		let bundle = load_bundle(&path).await.unwrap();
+
+		assert!(bundle.monitors.contains_key("Large Transfer"));
+		assert!(bundle.networks.contains_key("ethereum_mainnet"));
+		assert!(bundle.triggers.contains_key("large_transfer_webhook"));
+	}
+
+	#[tokio::test]
+	async fn test_load_yaml_bundle() {
+		let temp_dir = TempDir::new().unwrap();
+		let path = temp_dir.path().join("bundle.yaml");
+		let bundle = serde_json::json!({
+			"networks": [network_value("ethereum_mainnet")],
+			"triggers": [trigger_value("large_transfer_webhook")]
+		});
+		fs::write(&path, serde_yaml::to_string(&bundle).unwrap()).unwrap();
+
+		let bundle = load_bundle(&path).await.unwrap();
+
+		assert!(bundle.monitors.is_empty());
+		assert_eq!(bundle.networks.len(), 1);
+		assert_eq!(bundle.triggers.len(), 1);
+	}
+
+	#[tokio::test]
+	async fn test_load_bundle_tags_errors_by_section_and_index() {
+		let temp_dir = TempDir::new().unwrap();
+		let path = temp_dir.path().join("bundle.json");
+
+		let mut invalid_network = network_value("ethereum_sepolia");
+		invalid_network["rpc_urls"] = serde_json::json!([]);
+		let bundle = serde_json::json!({
+			"monitors": [monitor_value("Large Transfer"), { "name": 1 }],
+			"networks": [network_value("ethereum_mainnet"), invalid_network],
+			"triggers": [
+				trigger_value("large_transfer_webhook"),
+				trigger_value("large_transfer_webhook")
+			]
+		});
+		fs::write(&path, bundle.to_string()).unwrap();
+
+		let error = load_bundle(&path).await.unwrap_err().to_string();
+
+		assert!(error.contains("monitors[1]: failed to parse"), "{}", error);
+		assert!(error.contains("networks[1]"), "{}", error);
+		assert!(error.contains("triggers[1]"), "{}", error);
+		assert!(!error.contains("monitors[0]"), "{}", error);
+		assert!(!error.contains("networks[0]"), "{}", error);
+	}
+
+	#[tokio::test]
+	async fn test_load_bundle_rejects_unknown_sections() {
+		let temp_dir = TempDir::new().unwrap();
+		let path = temp_dir.path().join("bundle.json");
+		fs::write(&path, r#"{ "monitor": [] }"#).unwrap();
+
+		let result = load_bundle(&path).await;
+
+		assert!(matches!(result, Err(ConfigError::ParseError(_))));
+	}
+
+	#[tokio::test]
+	async fn test_load_bundle_missing_file() {
+		let result = load_bundle(Path::new("nonexistent/bundle.json")).await;
+
+		assert!(matches!(result, Err(ConfigError::FileError(_))));
+	}
+}
@@ -3,7 +3,7 @@
 //! This module defines the error types that can occur during configuration
 //! loading and validation.
 
-use crate::utils::logging::error::{ErrorContext, TraceableError};
+use crate::utils::logging::error::{ErrorCode, ErrorContext, TraceableError};
 use std::collections::HashMap;
 use thiserror::Error as ThisError;
 use uuid::Uuid;
@@ -12,15 +12,15 @@ use uuid::Uuid;
 #[derive(ThisError, Debug)]
 pub enum ConfigError {
 	/// Errors related to validation failures
-	#[error("Validation error: {0}")]
+	#[error("[{}] Validation error: {0}", ErrorCode::ConfigValidation)]
 	ValidationError(ErrorContext),
 
 	/// Errors related to parsing failures
-	#[error("Parse error: {0}")]
+	#[error("[{}] Parse error: {0}", ErrorCode::ConfigParse)]
 	ParseError(ErrorContext),
 
 	/// Errors related to file system errors
-	#[error("File error: {0}")]
+	#[error("[{}] File error: {0}", ErrorCode::ConfigFile)]
 	FileError(ErrorContext),
 
 	/// Other errors that don't fit into the categories above
@@ -37,7 +37,9 @@ impl ConfigError {
 	) -> Self {
 		// We explicitly do not use new_with_log here because we want to log the error
 		// at from the context of the repository
-		Self::ValidationError(ErrorContext::new(msg, source, metadata))
+		Self::ValidationError(
+			ErrorContext::new(msg, source, metadata).with_code(ErrorCode::ConfigValidation),
+		)
 	}
 
 	// Parse error
@@ -48,7 +50,7 @@ impl ConfigError {
 	) -> Self {
 		// We explicitly do not use new_with_log here because we want to log the error
 		// at from the context of the repository
-		Self::ParseError(ErrorContext::new(msg, source, metadata))
+		Self::ParseError(ErrorContext::new(msg, source, metadata).with_code(ErrorCode::ConfigParse))
 	}
 
 	// File error
@@ -59,7 +61,17 @@ impl ConfigError {
 	) -> Self {
 		// We explicitly do not use new_with_log here because we want to log the error
 		// at from the context of the repository
-		Self::FileError(ErrorContext::new(msg, source, metadata))
+		Self::FileError(ErrorContext::new(msg, source, metadata).with_code(ErrorCode::ConfigFile))
+	}
+
+	/// Returns the stable code identifying the kind of error
+	pub fn code(&self) -> ErrorCode {
+		match self {
+			Self::ValidationError(_) => ErrorCode::ConfigValidation,
+			Self::ParseError(_) => ErrorCode::ConfigParse,
+			Self::FileError(_) => ErrorCode::ConfigFile,
+			Self::Other(_) => ErrorCode::ConfigOther,
+		}
 	}
 }
 
@@ -94,7 +106,10 @@ mod tests {
 	#[test]
 	fn test_validation_error_formatting() {
 		let error = ConfigError::validation_error("test error", None, None);
-		assert_eq!(error.to_string(), "Validation error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[CONFIG_VALIDATION] Validation error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = ConfigError::validation_error(
@@ -104,14 +119,14 @@ mod tests {
 		);
 		assert_eq!(
 			error.to_string(),
-			"Validation error: test error [key1=value1]"
+			"[CONFIG_VALIDATION] Validation error: test error [key1=value1]"
 		);
 	}
 
 	#[test]
 	fn test_parse_error_formatting() {
 		let error = ConfigError::parse_error("test error", None, None);
-		assert_eq!(error.to_string(), "Parse error: test error");
+		assert_eq!(error.to_string(), "[CONFIG_PARSE] Parse error: test error");
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = ConfigError::parse_error(
@@ -119,13 +134,16 @@ mod tests {
 			Some(Box::new(source_error)),
 			Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
 		);
-		assert_eq!(error.to_string(), "Parse error: test error [key1=value1]");
+		assert_eq!(
+			error.to_string(),
+			"[CONFIG_PARSE] Parse error: test error [key1=value1]"
+		);
 	}
 
 	#[test]
 	fn test_file_error_formatting() {
 		let error = ConfigError::file_error("test error", None, None);
-		assert_eq!(error.to_string(), "File error: test error");
+		assert_eq!(error.to_string(), "[CONFIG_FILE] File error: test error");
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = ConfigError::file_error(
@@ -134,7 +152,10 @@ mod tests {
 			Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
 		);
 
-		assert_eq!(error.to_string(), "File error: test error [key1=value1]");
+		assert_eq!(
+			error.to_string(),
+			"[CONFIG_FILE] File error: test error [key1=value1]"
+		);
 	}
 
 	#[test]
@@ -143,6 +164,38 @@ mod tests {
 		let config_error: ConfigError = anyhow_error.into();
 		assert!(matches!(config_error, ConfigError::Other(_)));
 		assert_eq!(config_error.to_string(), "test anyhow error");
+		assert_eq!(config_error.code(), ErrorCode::ConfigOther);
+	}
+
+	#[test]
+	fn test_error_codes() {
+		let errors = vec![
+			(
+				ConfigError::validation_error("test error", None, None),
+				ErrorCode::ConfigValidation,
+			),
+			(
+				ConfigError::parse_error("test error", None, None),
+				ErrorCode::ConfigParse,
+			),
+			(
+				ConfigError::file_error("test error", None, None),
+				ErrorCode::ConfigFile,
+			),
+		];
+
+		for (error, code) in errors {
+			assert_eq!(error.code(), code);
+			assert!(error.to_string().starts_with(&format!("[{}] ", code)));
+		}
+
+		// The code is also set on the context, for structured logs
+		let error = ConfigError::validation_error("test error", None, None);
+		if let ConfigError::ValidationError(ctx) = &error {
+			assert_eq!(ctx.code, Some(ErrorCode::ConfigValidation));
+		} else {
+			panic!("Expected ValidationError variant");
+		}
 	}
 
 	#[test]
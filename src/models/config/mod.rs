@@ -6,14 +6,21 @@
 #![allow(clippy::result_large_err)]
 
 use async_trait::async_trait;
-use std::path::Path;
+use serde_json::Value;
+use std::{collections::HashMap, path::Path};
 
+mod abi_cache;
+mod bundle;
 mod error;
 mod monitor_config;
 mod network_config;
+mod schema;
 mod trigger_config;
 
+pub use abi_cache::AbiCache;
+pub use bundle::{load_bundle, ConfigBundle};
 pub use error::ConfigError;
+pub use schema::{config_schema, ConfigKind};
 
 /// Common interface for loading configuration files
 #[async_trait]
@@ -45,6 +52,30 @@ pub trait ConfigLoader: Sized {
 			.unwrap_or(false)
 	}
 
+	/// Check if a file is a YAML file based on extension
+	fn is_yaml_file(path: &Path) -> bool {
+		path.extension()
+			.map(|ext| {
+				matches!(
+					ext.to_string_lossy().to_lowercase().as_str(),
+					"yaml" | "yml"
+				)
+			})
+			.unwrap_or(false)
+	}
+
+	/// Check if a file is a TOML file based on extension
+	fn is_toml_file(path: &Path) -> bool {
+		path.extension()
+			.map(|ext| ext.to_string_lossy().to_lowercase() == "toml")
+			.unwrap_or(false)
+	}
+
+	/// Check if a file is a JSON, YAML or TOML configuration file based on extension
+	fn is_config_file(path: &Path) -> bool {
+		Self::is_json_file(path) || Self::is_yaml_file(path) || Self::is_toml_file(path)
+	}
+
 	/// Resolve all secrets in the configuration
 	async fn resolve_secrets(&self) -> Result<Self, ConfigError>;
 
@@ -61,3 +92,68 @@ pub trait ConfigLoader: Sized {
 		file_path: &str,
 	) -> Result<(), ConfigError>;
 }
+
+/// Reads a JSON, YAML or TOML configuration file into a JSON value
+///
+/// The format is chosen from the file extension, defaulting to JSON. Going through a JSON value
+/// lets every format deserialize into the configuration models exactly as JSON does.
+///
+/// # Arguments
+/// * `path` - Path of the configuration file
+/// * `kind` - Kind of configuration, used in error messages (e.g. "monitor")
+pub(crate) fn read_config_value<C: ConfigLoader>(
+	path: &Path,
+	kind: &str,
+) -> Result<Value, ConfigError> {
+	let metadata = || {
+		Some(HashMap::from([(
+			"path".to_string(),
+			path.display().to_string(),
+		)]))
+	};
+	let parse_error = |e: Box<dyn std::error::Error + Send + Sync>| {
+		ConfigError::parse_error(
+			format!("failed to parse {} config: {}", kind, e),
+			Some(e),
+			metadata(),
+		)
+	};
+
+	let content = std::fs::read_to_string(path).map_err(|e| {
+		ConfigError::file_error(
+			format!("failed to read {} config file: {}", kind, e),
+			Some(Box::new(e)),
+			metadata(),
+		)
+	})?;
+
+	if C::is_yaml_file(path) {
+		serde_yaml::from_str(&content).map_err(|e| parse_error(Box::new(e)))
+	} else if C::is_toml_file(path) {
+		toml::from_str(&content)
+			.map(toml_to_json)
+			.map_err(|e| parse_error(Box::new(e)))
+	} else {
+		serde_json::from_str(&content).map_err(|e| parse_error(Box::new(e)))
+	}
+}
+
+/// Converts a TOML value into a JSON value
+///
+/// TOML datetimes become strings, as they are written in JSON configuration files.
+fn toml_to_json(value: toml::Value) -> Value {
+	match value {
+		toml::Value::String(s) => Value::String(s),
+		toml::Value::Integer(i) => Value::from(i),
+		toml::Value::Float(f) => Value::from(f),
+		toml::Value::Boolean(b) => Value::Bool(b),
+		toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+		toml::Value::Array(values) => Value::Array(values.into_iter().map(toml_to_json).collect()),
+		toml::Value::Table(table) => Value::Object(
+			table
+				.into_iter()
+				.map(|(key, value)| (key, toml_to_json(value)))
+				.collect(),
+		),
+	}
+}
@@ -4,13 +4,18 @@
 //! allowing monitors to be loaded from JSON files.
 
 use crate::{
-	models::{config::error::ConfigError, ConfigLoader, Monitor, SecretValue},
-	services::trigger::validate_script_config,
-	utils::normalize_string,
+	models::{
+		config::{abi_cache::AbiCache, error::ConfigError, read_config_value},
+		AddressMatchMode, ConfigLoader, ContractSpec, Monitor, RpcCall, SecretValue,
+		MAX_PRE_FILTER_RPC_CALLS, MAX_STATE_CONDITIONS,
+	},
+	services::trigger::{validate_script_config, validate_script_limits, ScriptLimits},
+	utils::{normalize_string, validate_cron_schedule},
 };
+use alloy::primitives::{Address, U256};
 use async_trait::async_trait;
 use futures::TryStreamExt;
-use std::{collections::HashMap, fs, path::Path};
+use std::{collections::HashMap, fs, path::Path, str::FromStr};
 
 #[async_trait]
 impl ConfigLoader for Monitor {
@@ -86,7 +91,7 @@ impl ConfigLoader for Monitor {
 			})?;
 			let path = entry.path();
 
-			if !Self::is_json_file(&path) {
+			if !Self::is_config_file(&path) {
 				continue;
 			}
 
@@ -111,19 +116,10 @@ impl ConfigLoader for Monitor {
 
 	/// Load a monitor configuration from a specific file
 	///
-	/// Reads and parses a single JSON file as a monitor configuration.
+	/// Reads and parses a single JSON, YAML or TOML file as a monitor configuration.
 	async fn load_from_path(path: &Path) -> Result<Self, ConfigError> {
-		let file = std::fs::File::open(path).map_err(|e| {
-			ConfigError::file_error(
-				format!("failed to open monitor config file: {}", e),
-				Some(Box::new(e)),
-				Some(HashMap::from([(
-					"path".to_string(),
-					path.display().to_string(),
-				)])),
-			)
-		})?;
-		let mut config: Monitor = serde_json::from_reader(file).map_err(|e| {
+		let value = read_config_value::<Self>(path, "monitor")?;
+		let parse_error = |e: serde_json::Error| {
 			ConfigError::parse_error(
 				format!("failed to parse monitor config: {}", e),
 				Some(Box::new(e)),
@@ -132,7 +128,12 @@ impl ConfigLoader for Monitor {
 					path.display().to_string(),
 				)])),
 			)
-		})?;
+		};
+
+		let mut config = match AbiCache::from_env() {
+			Some(cache) => parse_with_abi_cache(value, &cache).map_err(parse_error)?,
+			None => serde_json::from_value::<Monitor>(value).map_err(parse_error)?,
+		};
 
 		// Resolve secrets before validating
 		config = config.resolve_secrets().await?;
@@ -172,6 +173,25 @@ impl ConfigLoader for Monitor {
 			));
 		}
 
+		// Validate address match patterns
+		for address in &self.addresses {
+			let valid = match address.match_mode {
+				AddressMatchMode::Exact => true,
+				AddressMatchMode::Prefix | AddressMatchMode::Suffix => !address.address.is_empty(),
+				AddressMatchMode::Glob => glob::Pattern::new(&address.address).is_ok(),
+			};
+			if !valid {
+				return Err(ConfigError::validation_error(
+					format!(
+						"Invalid {:?} address pattern: {}",
+						address.match_mode, address.address
+					),
+					None,
+					None,
+				));
+			}
+		}
+
 		// Validate function signatures
 		for func in &self.match_conditions.functions {
 			if !func.signature.contains('(') || !func.signature.contains(')') {
@@ -181,6 +201,20 @@ impl ConfigLoader for Monitor {
 					None,
 				));
 			}
+			if let Some(schema) = &func.eip712_schema {
+				for struct_type in ["EIP712Domain", schema.primary_type.as_str()] {
+					if !schema.types.contains_key(struct_type) {
+						return Err(ConfigError::validation_error(
+							format!(
+								"EIP-712 schema of function {} doesn't declare type {}",
+								func.signature, struct_type
+							),
+							None,
+							None,
+						));
+					}
+				}
+			}
 		}
 
 		// Validate event signatures
@@ -194,6 +228,269 @@ impl ConfigLoader for Monitor {
 			}
 		}
 
+		// Validate token transfer conditions
+		for transfer in &self.match_conditions.token_transfers {
+			for address in [&transfer.from, &transfer.to].into_iter().flatten() {
+				if Address::from_str(address).is_err() {
+					return Err(ConfigError::validation_error(
+						format!("Invalid token transfer address: {}", address),
+						None,
+						None,
+					));
+				}
+			}
+			if let Some(min_amount) = &transfer.min_amount {
+				if U256::from_str_radix(min_amount, 10).is_err() {
+					return Err(ConfigError::validation_error(
+						format!("Invalid token transfer min_amount: {}", min_amount),
+						None,
+						None,
+					));
+				}
+			}
+		}
+
+		// Validate block conditions
+		for condition in &self.match_conditions.block_conditions {
+			if condition.expression.trim().is_empty() {
+				return Err(ConfigError::validation_error(
+					"Block condition expression cannot be empty",
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate balance conditions, which read the balances of the exact addresses
+		for condition in &self.match_conditions.balance_conditions {
+			if condition.expression.trim().is_empty() {
+				return Err(ConfigError::validation_error(
+					"Balance condition expression cannot be empty",
+					None,
+					None,
+				));
+			}
+		}
+		if !self.match_conditions.balance_conditions.is_empty()
+			&& !self
+				.addresses
+				.iter()
+				.any(|address| address.match_mode.is_exact())
+		{
+			return Err(ConfigError::validation_error(
+				"Balance conditions require at least one address matched exactly",
+				None,
+				None,
+			));
+		}
+
+		// Validate state conditions, which only apply to transaction matches
+		let state_conditions = &self.match_conditions.state_conditions;
+		if !state_conditions.is_empty() && self.match_conditions.is_block_only() {
+			return Err(ConfigError::validation_error(
+				"State conditions require function, event or transaction conditions",
+				None,
+				None,
+			));
+		}
+		if state_conditions.len() > MAX_STATE_CONDITIONS {
+			return Err(ConfigError::validation_error(
+				format!(
+					"At most {} state conditions can be specified",
+					MAX_STATE_CONDITIONS
+				),
+				None,
+				None,
+			));
+		}
+		for condition in state_conditions {
+			if !(condition.signature.contains('(') && condition.signature.contains(')')) {
+				return Err(ConfigError::validation_error(
+					format!(
+						"Invalid state condition signature format: {}",
+						condition.signature
+					),
+					None,
+					None,
+				));
+			}
+			if condition.expression.trim().is_empty() {
+				return Err(ConfigError::validation_error(
+					"State condition expression cannot be empty",
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate warn threshold expressions
+		let conditions = &self.match_conditions;
+		let warn_thresholds =
+			conditions
+				.functions
+				.iter()
+				.map(|condition| (&condition.expression, &condition.warn_threshold_expression))
+				.chain(
+					conditions.events.iter().map(|condition| {
+						(&condition.expression, &condition.warn_threshold_expression)
+					}),
+				)
+				.chain(conditions.transactions.iter().map(|condition| {
+					(&condition.expression, &condition.warn_threshold_expression)
+				}));
+		for (expression, warn_threshold_expression) in warn_thresholds {
+			if warn_threshold_expression.is_some() && expression.is_none() {
+				return Err(ConfigError::validation_error(
+					"warn_threshold_expression requires the condition to have an expression",
+					None,
+					None,
+				));
+			}
+		}
+		if self.has_warn_thresholds() && self.warn_triggers.is_empty() {
+			return Err(ConfigError::validation_error(
+				"warn_triggers must be specified along with warn threshold expressions",
+				None,
+				None,
+			));
+		}
+
+		// Validate the cron schedule override
+		if let Some(cron_schedule) = &self.cron_schedule {
+			validate_cron_schedule(cron_schedule).map_err(|e| {
+				ConfigError::validation_error(
+					format!("Invalid cron schedule '{}': {}", cron_schedule, e),
+					None,
+					None,
+				)
+			})?;
+		}
+
+		if self.aggregation_window_ms == Some(0) {
+			return Err(ConfigError::validation_error(
+				"aggregation_window_ms must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		if self.max_notifications_per_hour == Some(0) {
+			return Err(ConfigError::validation_error(
+				"max_notifications_per_hour must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		if self.cooldown_ms == Some(0) {
+			return Err(ConfigError::validation_error(
+				"cooldown_ms must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		if (self.dedup_key.is_some() || self.persist_cooldown) && self.cooldown_ms.is_none() {
+			return Err(ConfigError::validation_error(
+				"dedup_key and persist_cooldown require cooldown_ms to be set",
+				None,
+				None,
+			));
+		}
+
+		if self.strict_signature_verification && !self.verify_signature {
+			return Err(ConfigError::validation_error(
+				"strict_signature_verification requires verify_signature to be enabled",
+				None,
+				None,
+			));
+		}
+
+		if self.resolve_trigger.is_some() && !self.notify_on_resolve {
+			return Err(ConfigError::validation_error(
+				"resolve_trigger requires notify_on_resolve to be enabled",
+				None,
+				None,
+			));
+		}
+		// The alerts are resolved by the blocks of the network's own schedule, one network at
+		// a time
+		if self.notify_on_resolve
+			&& (self.cron_schedule.is_some() || self.aggregate_across_networks)
+		{
+			return Err(ConfigError::validation_error(
+				"notify_on_resolve is not supported along with cron_schedule or \
+				 aggregate_across_networks",
+				None,
+				None,
+			));
+		}
+
+		// Validate the active window
+		if let (Some(active_from), Some(active_until)) = (self.active_from, self.active_until) {
+			if active_from >= active_until {
+				return Err(ConfigError::validation_error(
+					format!(
+						"active_from ({}) must be earlier than active_until ({})",
+						active_from.to_rfc3339(),
+						active_until.to_rfc3339()
+					),
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate pre-filter RPC calls
+		if self.pre_filter_rpc_calls.len() > MAX_PRE_FILTER_RPC_CALLS {
+			return Err(ConfigError::validation_error(
+				format!(
+					"At most {} pre-filter RPC calls can be specified",
+					MAX_PRE_FILTER_RPC_CALLS
+				),
+				None,
+				None,
+			));
+		}
+		for (index, call) in self.pre_filter_rpc_calls.iter().enumerate() {
+			if call.name.is_empty()
+				|| !call
+					.name
+					.chars()
+					.all(|c| c.is_ascii_alphanumeric() || c == '_')
+			{
+				return Err(ConfigError::validation_error(
+					format!(
+						"Invalid pre-filter RPC call name '{}': only letters, digits and \
+						 underscores are allowed",
+						call.name
+					),
+					None,
+					None,
+				));
+			}
+			if self.pre_filter_rpc_calls[..index]
+				.iter()
+				.any(|other| other.name == call.name)
+			{
+				return Err(ConfigError::validation_error(
+					format!("Duplicate pre-filter RPC call name: {}", call.name),
+					None,
+					None,
+				));
+			}
+			if !RpcCall::is_method_allowed(&call.method) {
+				return Err(ConfigError::validation_error(
+					format!(
+						"RPC method '{}' can't be called before filtering",
+						call.method
+					),
+					None,
+					None,
+				));
+			}
+		}
+
 		// Validate trigger conditions (focus on script path, timeout, and language)
 		for trigger_condition in &self.trigger_conditions {
 			validate_script_config(
@@ -201,6 +498,10 @@ impl ConfigLoader for Monitor {
 				&trigger_condition.language,
 				&trigger_condition.timeout_ms,
 			)?;
+			validate_script_limits(&ScriptLimits {
+				max_memory_mb: trigger_condition.max_memory_mb,
+				max_output_bytes: trigger_condition.max_output_bytes,
+			})?;
 		}
 
 		// Log a warning if the monitor uses an insecure protocol
@@ -257,11 +558,59 @@ impl ConfigLoader for Monitor {
 	}
 }
 
+/// Deserializes a monitor, resolving its contract specs through the ABI cache.
+///
+/// Contract specs are detached from the raw configuration before the monitor is deserialized.
+/// Each spec is then looked up by the hash of its bytes, parsed on a miss and written back to
+/// the cache.
+pub(super) fn parse_with_abi_cache(
+	mut value: serde_json::Value,
+	cache: &AbiCache,
+) -> Result<Monitor, serde_json::Error> {
+	let raw_specs: Vec<Option<serde_json::Value>> = value
+		.get_mut("addresses")
+		.and_then(|addresses| addresses.as_array_mut())
+		.map(|addresses| {
+			addresses
+				.iter_mut()
+				.map(|address| {
+					address
+						.get_mut("contract_spec")
+						.map(serde_json::Value::take)
+						.filter(|spec| !spec.is_null())
+				})
+				.collect()
+		})
+		.unwrap_or_default();
+
+	let mut monitor: Monitor = serde_json::from_value(value)?;
+
+	for (address, raw_spec) in monitor.addresses.iter_mut().zip(raw_specs) {
+		let Some(raw_spec) = raw_spec else {
+			continue;
+		};
+		let key = AbiCache::key(&serde_json::to_vec(&raw_spec)?);
+		address.contract_spec = match cache.get(&key) {
+			Some(spec) => Some(spec),
+			None => {
+				let spec: ContractSpec = serde_json::from_value(raw_spec)?;
+				cache.put(&key, &spec);
+				Some(spec)
+			}
+		};
+	}
+
+	Ok(monitor)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::{
-		models::core::{ScriptLanguage, TransactionStatus},
+		models::core::{
+			ConditionLogic, Eip712Schema, MatchConditions, ScriptLanguage, TokenTransferCondition,
+			TransactionCondition, TransactionStatus,
+		},
 		utils::tests::builders::evm::monitor::MonitorBuilder,
 	};
 	use std::collections::HashMap;
@@ -308,6 +657,176 @@ mod tests {
 
 		let monitor = result.unwrap();
 		assert_eq!(monitor.name, "TestMonitor");
+		assert_eq!(monitor.condition_logic, ConditionLogic::Or);
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_condition_logic() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("and_monitor.json");
+
+		let config = r#"{
+			"name": "TestMonitor",
+			"networks": ["ethereum_mainnet"],
+			"paused": false,
+			"addresses": [],
+			"match_conditions": {
+				"functions": [],
+				"events": [],
+				"transactions": []
+			},
+			"trigger_conditions": [],
+			"condition_logic": "And",
+			"triggers": []
+		}"#;
+
+		fs::write(&file_path, config).unwrap();
+
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		assert_eq!(monitor.condition_logic, ConditionLogic::And);
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_toml_round_trip() {
+		let temp_dir = TempDir::new().unwrap();
+		let script_path = temp_dir.path().join("filter.py");
+		fs::write(&script_path, "print(True)").unwrap();
+
+		let monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.paused_until("2024-01-01T00:00:00Z".parse().unwrap())
+			.address("0x0000000000000000000000000000000000000000")
+			.function("transfer(address,uint256)", Some("value > 100".to_string()))
+			.event("Transfer(address,address,uint256)", None)
+			.transaction(TransactionStatus::Success, None)
+			.trigger_condition(
+				&script_path.to_string_lossy(),
+				1000,
+				ScriptLanguage::Python,
+				Some(vec!["--verbose".to_string()]),
+			)
+			.condition_logic(ConditionLogic::And)
+			.triggers(vec!["trigger1".to_string()])
+			.build();
+
+		let file_path = temp_dir.path().join("monitor.toml");
+		fs::write(&file_path, toml::to_string(&monitor).unwrap()).unwrap();
+
+		let loaded = Monitor::load_from_path(&file_path).await.unwrap();
+		assert_eq!(loaded, monitor);
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_toml_native_datetime() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("monitor.toml");
+
+		let config = r#"
+			name = "TestMonitor"
+			networks = ["ethereum_mainnet"]
+			paused = true
+			paused_until = 2024-01-01T00:00:00Z
+			addresses = []
+			trigger_conditions = []
+			triggers = []
+
+			[match_conditions]
+			functions = []
+			events = []
+			transactions = [{ status = "Success" }]
+		"#;
+
+		fs::write(&file_path, config).unwrap();
+
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		assert_eq!(
+			monitor.paused_until,
+			Some("2024-01-01T00:00:00Z".parse().unwrap())
+		);
+		assert_eq!(
+			monitor.match_conditions.transactions[0].status,
+			TransactionStatus::Success
+		);
+	}
+
+	fn create_monitor_value_with_spec(function_name: &str) -> serde_json::Value {
+		serde_json::json!({
+			"name": "TestMonitor",
+			"networks": ["ethereum_mainnet"],
+			"paused": false,
+			"addresses": [
+				{
+					"address": "0x0000000000000000000000000000000000000000",
+					"contract_spec": [{
+						"type": "function",
+						"name": function_name,
+						"inputs": [],
+						"outputs": [],
+						"stateMutability": "nonpayable"
+					}]
+				},
+				{
+					"address": "0x0000000000000000000000000000000000000001"
+				}
+			],
+			"match_conditions": {
+				"functions": [],
+				"events": [],
+				"transactions": []
+			},
+			"trigger_conditions": [],
+			"triggers": []
+		})
+	}
+
+	fn count_cache_entries(dir: &Path) -> usize {
+		fs::read_dir(dir)
+			.map(|entries| entries.count())
+			.unwrap_or(0)
+	}
+
+	#[test]
+	fn test_parse_with_abi_cache_matches_direct_parse() {
+		let temp_dir = TempDir::new().unwrap();
+		let cache = AbiCache::new(temp_dir.path());
+		let value = create_monitor_value_with_spec("transfer");
+
+		let expected: Monitor = serde_json::from_value(value.clone()).unwrap();
+		let first = parse_with_abi_cache(value.clone(), &cache).unwrap();
+		let second = parse_with_abi_cache(value, &cache).unwrap();
+
+		assert_eq!(first, expected);
+		assert_eq!(second, expected);
+		assert!(second.addresses[1].contract_spec.is_none());
+		assert_eq!(count_cache_entries(temp_dir.path()), 1);
+	}
+
+	#[test]
+	fn test_parse_with_abi_cache_invalidates_on_change() {
+		let temp_dir = TempDir::new().unwrap();
+		let cache = AbiCache::new(temp_dir.path());
+
+		parse_with_abi_cache(create_monitor_value_with_spec("transfer"), &cache).unwrap();
+		let changed =
+			parse_with_abi_cache(create_monitor_value_with_spec("approve"), &cache).unwrap();
+
+		assert_eq!(
+			changed,
+			serde_json::from_value::<Monitor>(create_monitor_value_with_spec("approve")).unwrap()
+		);
+		assert_eq!(count_cache_entries(temp_dir.path()), 2);
+	}
+
+	#[test]
+	fn test_parse_with_abi_cache_invalid_spec() {
+		let temp_dir = TempDir::new().unwrap();
+		let cache = AbiCache::new(temp_dir.path());
+		let mut value = create_monitor_value_with_spec("transfer");
+		value["addresses"][0]["contract_spec"] = serde_json::json!("not a spec");
+
+		assert!(parse_with_abi_cache(value, &cache).is_err());
+		assert_eq!(count_cache_entries(temp_dir.path()), 0);
 	}
 
 	#[tokio::test]
@@ -405,6 +924,43 @@ mod tests {
 		assert!(monitors.contains_key("monitor2"));
 	}
 
+	#[tokio::test]
+	async fn test_load_all_monitors_mixed_formats() {
+		let temp_dir = TempDir::new().unwrap();
+		let monitor = |name: &str| {
+			MonitorBuilder::new()
+				.name(name)
+				.networks(vec!["ethereum_mainnet".to_string()])
+				.transaction(TransactionStatus::Success, None)
+				.build()
+		};
+
+		fs::write(
+			temp_dir.path().join("json_monitor.json"),
+			serde_json::to_string(&monitor("JsonMonitor")).unwrap(),
+		)
+		.unwrap();
+		fs::write(
+			temp_dir.path().join("yaml_monitor.yaml"),
+			serde_yaml::to_string(&monitor("YamlMonitor")).unwrap(),
+		)
+		.unwrap();
+		fs::write(
+			temp_dir.path().join("toml_monitor.toml"),
+			toml::to_string(&monitor("TomlMonitor")).unwrap(),
+		)
+		.unwrap();
+		fs::write(temp_dir.path().join("notes.txt"), "not a monitor").unwrap();
+
+		let monitors: HashMap<String, Monitor> =
+			Monitor::load_all(Some(temp_dir.path())).await.unwrap();
+
+		assert_eq!(monitors.len(), 3);
+		assert_eq!(monitors["json_monitor"], monitor("JsonMonitor"));
+		assert_eq!(monitors["yaml_monitor"], monitor("YamlMonitor"));
+		assert_eq!(monitors["toml_monitor"], monitor("TomlMonitor"));
+	}
+
 	#[test]
 	fn test_validate_monitor() {
 		let valid_monitor = MonitorBuilder::new()
@@ -424,6 +980,383 @@ mod tests {
 		assert!(invalid_monitor.validate().is_err());
 	}
 
+	#[test]
+	fn test_validate_monitor_address_patterns() {
+		let monitor = |address: &str, mode: AddressMatchMode| {
+			MonitorBuilder::new()
+				.name("TestMonitor")
+				.networks(vec!["ethereum_mainnet".to_string()])
+				.add_address_with_match_mode(address, mode)
+				.build()
+		};
+
+		assert!(monitor("0xABCD*", AddressMatchMode::Glob)
+			.validate()
+			.is_ok());
+		assert!(monitor("0xabcd", AddressMatchMode::Prefix)
+			.validate()
+			.is_ok());
+		assert!(monitor("[0x", AddressMatchMode::Glob).validate().is_err());
+		assert!(monitor("", AddressMatchMode::Prefix).validate().is_err());
+		assert!(monitor("", AddressMatchMode::Suffix).validate().is_err());
+	}
+
+	#[test]
+	fn test_validate_monitor_eip712_schema() {
+		let monitor = |types: &[&str]| {
+			let mut monitor = MonitorBuilder::new()
+				.name("TestMonitor")
+				.function("relay(bytes)", None)
+				.build();
+			monitor.match_conditions.functions[0].eip712_schema = Some(Eip712Schema {
+				param: "typedData".to_string(),
+				primary_type: "Mail".to_string(),
+				types: types
+					.iter()
+					.map(|name| (name.to_string(), vec![]))
+					.collect(),
+			});
+			monitor
+		};
+
+		assert!(monitor(&["EIP712Domain", "Mail"]).validate().is_ok());
+		assert!(monitor(&["EIP712Domain"]).validate().is_err());
+		assert!(monitor(&["Mail"]).validate().is_err());
+	}
+
+	#[test]
+	fn test_validate_monitor_cron_schedule() {
+		let monitor = |cron_schedule: &str| {
+			MonitorBuilder::new()
+				.name("TestMonitor")
+				.cron_schedule(cron_schedule)
+				.build()
+		};
+
+		assert!(monitor("0 0 * * * *").validate().is_ok());
+		assert!(monitor("every hour").validate().is_err());
+		assert!(MonitorBuilder::new()
+			.name("TestMonitor")
+			.build()
+			.validate()
+			.is_ok());
+	}
+
+	#[test]
+	fn test_validate_monitor_active_window() {
+		let monitor = |active_from: &str, active_until: &str| {
+			MonitorBuilder::new()
+				.name("TestMonitor")
+				.active_from(active_from.parse().unwrap())
+				.active_until(active_until.parse().unwrap())
+				.build()
+		};
+
+		assert!(monitor("2024-01-01T09:00:00Z", "2024-01-01T17:00:00Z")
+			.validate()
+			.is_ok());
+		assert!(monitor("2024-01-01T17:00:00Z", "2024-01-01T09:00:00Z")
+			.validate()
+			.is_err());
+		assert!(monitor("2024-01-01T09:00:00Z", "2024-01-01T09:00:00Z")
+			.validate()
+			.is_err());
+		assert!(MonitorBuilder::new()
+			.name("TestMonitor")
+			.active_until("2024-01-01T09:00:00Z".parse().unwrap())
+			.build()
+			.validate()
+			.is_ok());
+	}
+
+	#[test]
+	fn test_validate_monitor_aggregation_window() {
+		let monitor = |window_ms| {
+			MonitorBuilder::new()
+				.name("TestMonitor")
+				.aggregate_across_networks(window_ms)
+				.build()
+		};
+
+		assert!(monitor(None).validate().is_ok());
+		assert!(monitor(Some(1000)).validate().is_ok());
+		assert!(monitor(Some(0)).validate().is_err());
+	}
+
+	#[test]
+	fn test_validate_monitor_max_notifications_per_hour() {
+		let monitor = |max| {
+			MonitorBuilder::new()
+				.name("TestMonitor")
+				.max_notifications_per_hour(max, false)
+				.build()
+		};
+
+		assert!(monitor(10).validate().is_ok());
+		assert!(monitor(0).validate().is_err());
+	}
+
+	#[test]
+	fn test_validate_monitor_signature_verification() {
+		let monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.verify_signature(true)
+			.build();
+		assert!(monitor.validate().is_ok());
+
+		let monitor = Monitor {
+			verify_signature: false,
+			..monitor
+		};
+		assert!(monitor.validate().is_err());
+	}
+
+	#[test]
+	fn test_validate_monitor_notify_on_resolve() {
+		let monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.notify_on_resolve(Some("resolved_trigger"))
+			.build();
+		assert!(monitor.validate().is_ok());
+
+		let without_notify_on_resolve = Monitor {
+			notify_on_resolve: false,
+			..monitor.clone()
+		};
+		assert!(without_notify_on_resolve.validate().is_err());
+
+		let scheduled = Monitor {
+			cron_schedule: Some("0 0 * * * *".to_string()),
+			..monitor.clone()
+		};
+		assert!(scheduled.validate().is_err());
+
+		let aggregated = Monitor {
+			aggregate_across_networks: true,
+			..monitor
+		};
+		assert!(aggregated.validate().is_err());
+	}
+
+	#[test]
+	fn test_validate_monitor_cooldown() {
+		let monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.cooldown(60_000, Some("${transaction.to}"), true)
+			.build();
+		assert!(monitor.validate().is_ok());
+
+		let without_cooldown = Monitor {
+			cooldown_ms: None,
+			..monitor.clone()
+		};
+		assert!(without_cooldown.validate().is_err());
+
+		let zero_cooldown = Monitor {
+			cooldown_ms: Some(0),
+			..monitor
+		};
+		assert!(zero_cooldown.validate().is_err());
+	}
+
+	#[test]
+	fn test_validate_monitor_token_transfers() {
+		let monitor = |from: &str, min_amount: &str| {
+			MonitorBuilder::new()
+				.name("TestMonitor")
+				.token_transfer(TokenTransferCondition {
+					from: Some(from.to_string()),
+					min_amount: Some(min_amount.to_string()),
+					..Default::default()
+				})
+				.build()
+		};
+
+		assert!(
+			monitor("0x0000000000000000000000000000000000001234", "1000000")
+				.validate()
+				.is_ok()
+		);
+		assert!(monitor("not an address", "1000000").validate().is_err());
+		assert!(monitor("0x0000000000000000000000000000000000001234", "1.5")
+			.validate()
+			.is_err());
+		assert!(monitor("0x0000000000000000000000000000000000001234", "-1")
+			.validate()
+			.is_err());
+	}
+
+	#[test]
+	fn test_validate_monitor_warn_thresholds() {
+		let monitor = |expression: Option<&str>, warn_triggers: Vec<String>| {
+			MonitorBuilder::new()
+				.name("TestMonitor")
+				.match_conditions(MatchConditions {
+					transactions: vec![TransactionCondition {
+						status: TransactionStatus::Success,
+						expression: expression.map(String::from),
+						warn_threshold_expression: Some("value > 50".to_string()),
+						is_contract_creation: false,
+					}],
+					..Default::default()
+				})
+				.warn_triggers(warn_triggers)
+				.build()
+		};
+
+		assert!(
+			monitor(Some("value > 100"), vec!["warn_trigger".to_string()])
+				.validate()
+				.is_ok()
+		);
+		assert!(monitor(None, vec!["warn_trigger".to_string()])
+			.validate()
+			.is_err());
+		assert!(monitor(Some("value > 100"), vec![]).validate().is_err());
+	}
+
+	#[test]
+	fn test_validate_monitor_block_conditions() {
+		let monitor = |expression: &str| {
+			MonitorBuilder::new()
+				.name("TestMonitor")
+				.block_condition(expression)
+				.build()
+		};
+
+		assert!(monitor("gas_used > 25000000").validate().is_ok());
+		assert!(monitor(" ").validate().is_err());
+	}
+
+	#[test]
+	fn test_validate_monitor_balance_conditions() {
+		let monitor = |addresses: Vec<String>, expression: &str| {
+			MonitorBuilder::new()
+				.name("TestMonitor")
+				.addresses(addresses)
+				.balance_condition(expression)
+				.build()
+		};
+
+		let address = vec!["0x0000000000000000000000000000000000001234".to_string()];
+		assert!(monitor(address.clone(), "balance < 1000000000000000000")
+			.validate()
+			.is_ok());
+		assert!(monitor(address, " ").validate().is_err());
+		// Balances are read from the monitored addresses
+		assert!(monitor(vec![], "balance_delta < 0").validate().is_err());
+	}
+
+	#[test]
+	fn test_validate_monitor_state_conditions() {
+		let monitor = |signature: &str, expression: &str| {
+			MonitorBuilder::new()
+				.name("TestMonitor")
+				.function("transfer(address,uint256)", None)
+				.state_condition(signature, vec![], expression)
+				.build()
+		};
+
+		assert!(monitor("paused() returns (bool)", "result == false")
+			.validate()
+			.is_ok());
+		assert!(monitor("paused", "result == false").validate().is_err());
+		assert!(monitor("paused()", " ").validate().is_err());
+
+		// State conditions don't apply to block matches
+		let block_only = MonitorBuilder::new()
+			.name("TestMonitor")
+			.block_condition("gas_used > 25000000")
+			.state_condition("paused()", vec![], "result == false")
+			.build();
+		assert!(block_only.validate().is_err());
+
+		let mut builder = MonitorBuilder::new()
+			.name("TestMonitor")
+			.function("transfer(address,uint256)", None);
+		for _ in 0..=MAX_STATE_CONDITIONS {
+			builder = builder.state_condition("paused()", vec![], "result == false");
+		}
+		assert!(builder.build().validate().is_err());
+	}
+
+	#[test]
+	fn test_validate_monitor_pre_filter_rpc_calls() {
+		let monitor = |calls: &[(&str, &str)]| {
+			calls
+				.iter()
+				.fold(
+					MonitorBuilder::new().name("TestMonitor"),
+					|builder, (name, method)| builder.pre_filter_rpc_call(name, method, None),
+				)
+				.build()
+		};
+
+		assert!(monitor(&[
+			("storage", "eth_getStorageAt"),
+			("balance", "eth_getBalance")
+		])
+		.validate()
+		.is_ok());
+		assert!(monitor(&[("storage-slot", "eth_getStorageAt")])
+			.validate()
+			.is_err());
+		assert!(monitor(&[
+			("storage", "eth_getStorageAt"),
+			("storage", "eth_getBalance")
+		])
+		.validate()
+		.is_err());
+		assert!(monitor(&[("send", "eth_sendRawTransaction")])
+			.validate()
+			.is_err());
+		assert!(monitor(&[("submit", "sendTransaction")])
+			.validate()
+			.is_err());
+
+		let names = (0..=MAX_PRE_FILTER_RPC_CALLS)
+			.map(|i| format!("balance_{}", i))
+			.collect::<Vec<_>>();
+		let calls = names
+			.iter()
+			.map(|name| (name.as_str(), "eth_getBalance"))
+			.collect::<Vec<_>>();
+		assert!(monitor(&calls).validate().is_err());
+	}
+
+	#[test]
+	fn test_address_match_modes() {
+		let address = "0xabcdef0000000000000000000000000000001234";
+		let entry = |pattern: &str, mode: AddressMatchMode| crate::models::AddressWithSpec {
+			address: pattern.to_string(),
+			match_mode: mode,
+			contract_spec: None,
+		};
+		let lowercase = |value: &str| value.to_lowercase();
+
+		let uppercase = address.to_uppercase();
+
+		assert!(entry(&uppercase, AddressMatchMode::Exact).matches(address, lowercase));
+		assert!(!entry("0xABCDEF", AddressMatchMode::Exact).matches(address, lowercase));
+		assert!(entry("0xABCDEF", AddressMatchMode::Prefix).matches(address, lowercase));
+		assert!(!entry("0x1234", AddressMatchMode::Prefix).matches(address, lowercase));
+		assert!(entry("1234", AddressMatchMode::Suffix).matches(address, lowercase));
+		assert!(!entry("abcd", AddressMatchMode::Suffix).matches(address, lowercase));
+		assert!(entry("0xABCD*", AddressMatchMode::Glob).matches(address, lowercase));
+		assert!(entry("0x*1234", AddressMatchMode::Glob).matches(address, lowercase));
+		assert!(!entry("0x1234*", AddressMatchMode::Glob).matches(address, lowercase));
+
+		let unfiltered = MonitorBuilder::new().addresses(vec![]).build();
+		assert!(unfiltered.matches_address(address, lowercase));
+
+		let filtered = MonitorBuilder::new()
+			.addresses(vec![])
+			.add_address_with_match_mode("0xABCD*", AddressMatchMode::Glob)
+			.build();
+		assert!(filtered.matches_address(address, lowercase));
+		assert!(!filtered.matches_address("0x0000000000000000000000000000000000001234", lowercase));
+	}
+
 	#[test]
 	fn test_validate_monitor_with_trigger_conditions() {
 		// Create a temporary directory and script file
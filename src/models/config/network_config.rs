@@ -4,13 +4,21 @@
 //! allowing network definitions to be loaded from JSON files.
 
 use async_trait::async_trait;
-use std::{collections::HashMap, path::Path, str::FromStr};
+use chrono_tz::Tz;
+use std::{collections::HashMap, path::Path, time::Duration};
 
 use crate::{
-	models::{config::error::ConfigError, BlockChainType, ConfigLoader, Network, SecretValue},
-	utils::{get_cron_interval_ms, normalize_string},
+	models::{
+		config::{error::ConfigError, read_config_value},
+		BlockChainType, ConfigLoader, EVMBlockTag, Network, SecretValue,
+	},
+	services::blockchain::STELLAR_MAX_PAGE_SIZE,
+	utils::{get_cron_interval_ms, normalize_string, parse_timezone, validate_cron_schedule},
 };
 
+/// Default maximum number of past blocks processed with `auto_past_blocks`
+const DEFAULT_AUTO_PAST_BLOCKS_LIMIT: u64 = 10_000;
+
 impl Network {
 	/// Calculates the recommended minimum number of past blocks to maintain for this network.
 	///
@@ -32,6 +40,83 @@ impl Network {
 		let blocks_per_cron = cron_interval_ms / self.block_time_ms;
 		blocks_per_cron + self.confirmation_blocks + 1
 	}
+
+	/// Returns whether the number of past blocks to process is derived from the time elapsed
+	/// since the last processed block.
+	///
+	/// An explicit `max_past_blocks` takes precedence over `auto_past_blocks`.
+	pub fn uses_auto_past_blocks(&self) -> bool {
+		self.auto_past_blocks.unwrap_or(false) && self.max_past_blocks.is_none()
+	}
+
+	/// Calculates the number of past blocks to process after `elapsed` without processing a
+	/// block.
+	///
+	/// Covers the blocks produced in that time, with one more to absorb rounding, and is never
+	/// below [`Self::get_recommended_past_blocks`]. It is capped to `auto_past_blocks_limit`
+	/// (10000 by default), so that a long downtime doesn't cause an unbounded catch-up.
+	///
+	/// # Arguments
+	/// * `elapsed` - Time elapsed since the last processed block
+	///
+	/// # Returns
+	/// * `u64` - The number of past blocks to process
+	pub fn get_auto_past_blocks(&self, elapsed: Duration) -> u64 {
+		let elapsed_blocks = (elapsed.as_millis() / self.block_time_ms.max(1) as u128) as u64;
+		elapsed_blocks
+			.saturating_add(1)
+			.max(self.get_recommended_past_blocks())
+			.min(
+				self.auto_past_blocks_limit
+					.unwrap_or(DEFAULT_AUTO_PAST_BLOCKS_LIMIT),
+			)
+	}
+
+	/// Returns the timezone the cron schedules of this network are evaluated in.
+	///
+	/// Defaults to UTC, as well as for an invalid timezone, which is rejected by validation.
+	pub fn cron_timezone(&self) -> Tz {
+		self.timezone
+			.as_deref()
+			.and_then(|timezone| parse_timezone(timezone).ok())
+			.unwrap_or(Tz::UTC)
+	}
+
+	/// Returns the maximum number of monitors evaluated concurrently within a block.
+	///
+	/// Defaults to 1, evaluating monitors sequentially.
+	pub fn get_monitor_concurrency(&self) -> usize {
+		self.monitor_concurrency.unwrap_or(1)
+	}
+
+	/// Returns whether blocks of this network are watched.
+	///
+	/// Defaults to true. Disabled networks are still loaded and validated.
+	pub fn is_enabled(&self) -> bool {
+		self.enabled.unwrap_or(true)
+	}
+
+	/// Returns whether pending transactions of this network are watched.
+	///
+	/// Defaults to false.
+	pub fn is_mempool_enabled(&self) -> bool {
+		self.mempool.unwrap_or(false)
+	}
+
+	/// Returns whether blocks are processed up to a safe or finalized block rather than up to
+	/// `confirmation_blocks` blocks behind the latest block.
+	pub fn uses_finality_block_tag(&self) -> bool {
+		matches!(
+			self.block_tag,
+			Some(EVMBlockTag::Safe | EVMBlockTag::Finalized)
+		)
+	}
+
+	/// Returns whether blocks are processed as soon as they are produced, in which case
+	/// processed blocks may be replaced by a reorg.
+	pub fn processes_unconfirmed_blocks(&self) -> bool {
+		self.confirmation_blocks == 0 && !self.uses_finality_block_tag()
+	}
 }
 
 #[async_trait]
@@ -51,6 +136,19 @@ impl ConfigLoader for Network {
 			})?;
 			rpc_url.url = SecretValue::Plain(resolved_url);
 		}
+
+		for (name, value) in network.headers.iter_mut().flatten() {
+			let resolved_value = SecretValue::resolve_placeholders(value)
+				.await
+				.map_err(|e| {
+					ConfigError::parse_error(
+						format!("failed to resolve RPC header {}: {}", name, e),
+						Some(Box::new(e)),
+						None,
+					)
+				})?;
+			*value = resolved_value.as_str().to_string();
+		}
 		Ok(network)
 	}
 
@@ -98,7 +196,7 @@ impl ConfigLoader for Network {
 			})?;
 			let path = entry.path();
 
-			if !Self::is_json_file(&path) {
+			if !Self::is_config_file(&path) {
 				continue;
 			}
 
@@ -123,19 +221,10 @@ impl ConfigLoader for Network {
 
 	/// Load a network configuration from a specific file
 	///
-	/// Reads and parses a single JSON file as a network configuration.
+	/// Reads and parses a single JSON, YAML or TOML file as a network configuration.
 	async fn load_from_path(path: &std::path::Path) -> Result<Self, ConfigError> {
-		let file = std::fs::File::open(path).map_err(|e| {
-			ConfigError::file_error(
-				format!("failed to open network config file: {}", e),
-				Some(Box::new(e)),
-				Some(HashMap::from([(
-					"path".to_string(),
-					path.display().to_string(),
-				)])),
-			)
-		})?;
-		let mut config: Network = serde_json::from_reader(file).map_err(|e| {
+		let value = read_config_value::<Self>(path, "network")?;
+		let mut config: Network = serde_json::from_value(value).map_err(|e| {
 			ConfigError::parse_error(
 				format!("failed to parse network config: {}", e),
 				Some(Box::new(e)),
@@ -174,7 +263,10 @@ impl ConfigLoader for Network {
 
 		// Validate network_type
 		match self.network_type {
-			BlockChainType::EVM | BlockChainType::Stellar | BlockChainType::Midnight => {}
+			BlockChainType::EVM
+			| BlockChainType::Stellar
+			| BlockChainType::Midnight
+			| BlockChainType::Solana => {}
 			#[allow(unreachable_patterns)]
 			_ => {
 				return Err(ConfigError::validation_error(
@@ -201,6 +293,15 @@ impl ConfigLoader for Network {
 		// Validate RPC URL types and formats based on network
 		let (supported_types, supported_protocols) = match self.network_type {
 			BlockChainType::Midnight => (vec!["ws_rpc"], vec!["wss://", "ws://"]),
+			// WebSocket endpoints of EVM networks are only used to watch pending transactions
+			BlockChainType::EVM if self.is_mempool_enabled() => (
+				vec!["rpc", "archive", "full", "ws_rpc"],
+				vec!["http://", "https://", "wss://", "ws://"],
+			),
+			BlockChainType::EVM => (
+				vec!["rpc", "archive", "full"],
+				vec!["http://", "https://", "wss://", "ws://"],
+			),
 			_ => (vec!["rpc"], vec!["http://", "https://", "wss://", "ws://"]),
 		};
 
@@ -239,13 +340,13 @@ impl ConfigLoader for Network {
 			));
 		}
 
-		// Validate confirmation blocks
-		if self.confirmation_blocks == 0 {
-			return Err(ConfigError::validation_error(
-				"Confirmation blocks must be greater than 0",
-				None,
-				None,
-			));
+		// Processing the chain head is allowed, reorged blocks are detected and processed again
+		if self.processes_unconfirmed_blocks() {
+			tracing::warn!(
+				"Network '{}' processes unconfirmed blocks (confirmation_blocks = 0), \
+				 matches may be sent again for blocks replaced by a reorg",
+				self.slug
+			);
 		}
 
 		// Validate cron_schedule
@@ -258,10 +359,21 @@ impl ConfigLoader for Network {
 		}
 
 		// Add cron schedule format validation
-		if let Err(e) = cron::Schedule::from_str(&self.cron_schedule) {
+		if let Err(e) = validate_cron_schedule(&self.cron_schedule) {
 			return Err(ConfigError::validation_error(e.to_string(), None, None));
 		}
 
+		// Validate timezone
+		if let Some(timezone) = &self.timezone {
+			if let Err(e) = parse_timezone(timezone) {
+				return Err(ConfigError::validation_error(
+					format!("Invalid timezone '{}': {}", timezone, e),
+					None,
+					None,
+				));
+			}
+		}
+
 		// Validate max_past_blocks
 		if let Some(max_blocks) = self.max_past_blocks {
 			if max_blocks == 0 {
@@ -285,6 +397,172 @@ impl ConfigLoader for Network {
 			}
 		}
 
+		if self.auto_past_blocks_limit == Some(0) {
+			return Err(ConfigError::validation_error(
+				"auto_past_blocks_limit must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		for (name, value) in self.headers.iter().flatten() {
+			if reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_err() {
+				return Err(ConfigError::validation_error(
+					format!("Invalid RPC header name: {}", name),
+					None,
+					None,
+				));
+			}
+			if reqwest::header::HeaderValue::from_str(value).is_err() {
+				return Err(ConfigError::validation_error(
+					format!("Invalid value of RPC header {}", name),
+					None,
+					None,
+				));
+			}
+		}
+
+		if self.auto_past_blocks.unwrap_or(false) && self.max_past_blocks.is_some() {
+			tracing::warn!(
+				"Network '{}' sets both auto_past_blocks and max_past_blocks, \
+				 max_past_blocks takes precedence",
+				self.slug
+			);
+		}
+
+		// Validate stall detection settings
+		if self.stall_threshold_ms == Some(0) {
+			return Err(ConfigError::validation_error(
+				"stall_threshold_ms must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		if let Some(trigger) = &self.stall_trigger {
+			if trigger.trim().is_empty() {
+				return Err(ConfigError::validation_error(
+					"stall_trigger must not be empty",
+					None,
+					None,
+				));
+			}
+			if self.stall_threshold_ms.is_none() {
+				return Err(ConfigError::validation_error(
+					"stall_trigger requires stall_threshold_ms to be set",
+					None,
+					None,
+				));
+			}
+		}
+
+		if self.monitor_concurrency == Some(0) {
+			return Err(ConfigError::validation_error(
+				"monitor_concurrency must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		if self.catchup_checkpoint_blocks == Some(0) {
+			return Err(ConfigError::validation_error(
+				"catchup_checkpoint_blocks must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		if self.trace_method.is_some() && self.network_type != BlockChainType::EVM {
+			return Err(ConfigError::validation_error(
+				"trace_method is only supported by EVM networks",
+				None,
+				None,
+			));
+		}
+
+		if self.rpc_cache_size.is_some_and(|size| size > 0)
+			&& !matches!(
+				self.network_type,
+				BlockChainType::EVM | BlockChainType::Stellar
+			) {
+			return Err(ConfigError::validation_error(
+				"rpc_cache_size is only supported by EVM and Stellar networks",
+				None,
+				None,
+			));
+		}
+
+		if self.max_response_bytes == Some(0) {
+			return Err(ConfigError::validation_error(
+				"max_response_bytes must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		if let Some(adaptive_polling) = &self.adaptive_polling {
+			if adaptive_polling.min_interval_ms == 0 {
+				return Err(ConfigError::validation_error(
+					"adaptive_polling.min_interval_ms must be greater than 0",
+					None,
+					None,
+				));
+			}
+			if adaptive_polling.min_interval_ms > adaptive_polling.max_interval_ms {
+				return Err(ConfigError::validation_error(
+					"adaptive_polling.min_interval_ms must not be greater than max_interval_ms",
+					None,
+					None,
+				));
+			}
+		}
+
+		if self.block_tag.is_some() && self.network_type != BlockChainType::EVM {
+			return Err(ConfigError::validation_error(
+				"block_tag is only supported by EVM networks",
+				None,
+				None,
+			));
+		}
+
+		if let Some(page_size) = self.page_size {
+			if self.network_type != BlockChainType::Stellar {
+				return Err(ConfigError::validation_error(
+					"page_size is only supported by Stellar networks",
+					None,
+					None,
+				));
+			}
+			if page_size == 0 || page_size > STELLAR_MAX_PAGE_SIZE {
+				return Err(ConfigError::validation_error(
+					format!("page_size must be between 1 and {}", STELLAR_MAX_PAGE_SIZE),
+					None,
+					None,
+				));
+			}
+		}
+
+		if self.is_mempool_enabled() {
+			if self.network_type != BlockChainType::EVM {
+				return Err(ConfigError::validation_error(
+					"mempool is only supported by EVM networks",
+					None,
+					None,
+				));
+			}
+			if !self
+				.rpc_urls
+				.iter()
+				.any(|rpc_url| rpc_url.type_ == "ws_rpc" && rpc_url.weight > 0)
+			{
+				return Err(ConfigError::validation_error(
+					"mempool requires a ws_rpc URL with a weight greater than 0",
+					None,
+					None,
+				));
+			}
+		}
+
 		// Log a warning if the network uses an insecure protocol
 		self.validate_protocol();
 
@@ -350,7 +628,10 @@ impl ConfigLoader for Network {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::{models::SecretString, utils::tests::builders::network::NetworkBuilder};
+	use crate::{
+		models::{AdaptivePollingConfig, EVMTraceMethod, RpcUrl, SecretString},
+		utils::tests::builders::network::NetworkBuilder,
+	};
 	use std::fs;
 	use tempfile::TempDir;
 	use tracing_test::traced_test;
@@ -419,6 +700,331 @@ mod tests {
 		));
 	}
 
+	#[test]
+	fn test_validate_stall_detection() {
+		let network = create_valid_network();
+		let mut valid = network.clone();
+		valid.stall_threshold_ms = Some(60_000);
+		valid.stall_trigger = Some("stall_alert".to_string());
+		assert!(valid.validate().is_ok());
+
+		let mut zero_threshold = network.clone();
+		zero_threshold.stall_threshold_ms = Some(0);
+		assert!(matches!(
+			zero_threshold.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+
+		let mut trigger_without_threshold = network;
+		trigger_without_threshold.stall_trigger = Some("stall_alert".to_string());
+		assert!(matches!(
+			trigger_without_threshold.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_monitor_concurrency() {
+		let network = create_valid_network();
+		assert_eq!(network.get_monitor_concurrency(), 1);
+
+		let mut concurrent = network.clone();
+		concurrent.monitor_concurrency = Some(8);
+		assert!(concurrent.validate().is_ok());
+		assert_eq!(concurrent.get_monitor_concurrency(), 8);
+
+		let mut zero = network;
+		zero.monitor_concurrency = Some(0);
+		assert!(matches!(
+			zero.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_catchup_checkpoint_blocks() {
+		let mut network = create_valid_network();
+		network.catchup_checkpoint_blocks = Some(100);
+		assert!(network.validate().is_ok());
+
+		network.catchup_checkpoint_blocks = Some(0);
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_trace_method() {
+		let mut network = create_valid_network();
+		network.trace_method = Some(EVMTraceMethod::TraceBlock);
+		assert!(network.validate().is_ok());
+
+		let mut network = create_valid_midnight_network();
+		network.trace_method = Some(EVMTraceMethod::TraceBlock);
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_rpc_cache_size() {
+		let mut network = create_valid_network();
+		network.rpc_cache_size = Some(256);
+		assert!(network.validate().is_ok());
+
+		let mut network = create_valid_midnight_network();
+		network.rpc_cache_size = Some(0);
+		assert!(network.validate().is_ok());
+		network.rpc_cache_size = Some(256);
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_max_response_bytes() {
+		let mut network = create_valid_network();
+		network.max_response_bytes = Some(1024 * 1024);
+		assert!(network.validate().is_ok());
+
+		network.max_response_bytes = Some(0);
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_adaptive_polling() {
+		let mut network = create_valid_network();
+		for (min_interval_ms, max_interval_ms, valid) in [
+			(1000, 30000, true),
+			(1000, 1000, true),
+			(0, 30000, false),
+			(5000, 1000, false),
+		] {
+			network.adaptive_polling = Some(AdaptivePollingConfig {
+				min_interval_ms,
+				max_interval_ms,
+			});
+			assert_eq!(network.validate().is_ok(), valid);
+		}
+	}
+
+	#[test]
+	fn test_validate_block_tag() {
+		let mut network = create_valid_network();
+		for block_tag in [
+			EVMBlockTag::Latest,
+			EVMBlockTag::Safe,
+			EVMBlockTag::Finalized,
+		] {
+			network.block_tag = Some(block_tag);
+			assert!(network.validate().is_ok());
+		}
+		assert!(network.uses_finality_block_tag());
+
+		let mut network = create_valid_midnight_network();
+		network.block_tag = Some(EVMBlockTag::Finalized);
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_processes_unconfirmed_blocks() {
+		let mut network = create_valid_network();
+		network.confirmation_blocks = 1;
+		assert!(!network.processes_unconfirmed_blocks());
+
+		network.confirmation_blocks = 0;
+		assert!(network.processes_unconfirmed_blocks());
+
+		network.block_tag = Some(EVMBlockTag::Finalized);
+		assert!(!network.processes_unconfirmed_blocks());
+	}
+
+	#[test]
+	fn test_auto_past_blocks() {
+		let mut network = create_valid_network();
+		network.block_time_ms = 2000;
+		network.cron_schedule = "*/10 * * * * *".to_string();
+		network.auto_past_blocks = Some(true);
+		assert!(!network.uses_auto_past_blocks());
+
+		network.max_past_blocks = None;
+		assert!(network.uses_auto_past_blocks());
+		assert!(network.validate().is_ok());
+
+		// A 5 minute gap on a 2 second block chain misses 150 blocks
+		assert_eq!(network.get_auto_past_blocks(Duration::from_secs(300)), 151);
+		// Never below the recommended past blocks
+		assert_eq!(
+			network.get_auto_past_blocks(Duration::ZERO),
+			network.get_recommended_past_blocks()
+		);
+
+		network.auto_past_blocks_limit = Some(100);
+		assert_eq!(network.get_auto_past_blocks(Duration::from_secs(300)), 100);
+
+		network.auto_past_blocks_limit = Some(0);
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_headers() {
+		let mut network = create_valid_network();
+		network.headers = Some(HashMap::from([
+			("x-api-key".to_string(), "secret".to_string()),
+			("User-Agent".to_string(), "monitor/1.0".to_string()),
+		]));
+		assert!(network.validate().is_ok());
+
+		network.headers = Some(HashMap::from([(
+			"invalid header".to_string(),
+			"secret".to_string(),
+		)]));
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+
+		network.headers = Some(HashMap::from([(
+			"x-api-key".to_string(),
+			"line\nbreak".to_string(),
+		)]));
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[tokio::test]
+	async fn test_resolve_secrets_headers() {
+		std::env::set_var("TEST_NETWORK_RPC_API_KEY", "api-key");
+		let mut network = create_valid_network();
+		network.headers = Some(HashMap::from([(
+			"Authorization".to_string(),
+			"Bearer ${TEST_NETWORK_RPC_API_KEY}".to_string(),
+		)]));
+
+		let resolved = network.resolve_secrets().await.unwrap();
+		assert_eq!(resolved.headers.unwrap()["Authorization"], "Bearer api-key");
+
+		network.headers = Some(HashMap::from([(
+			"Authorization".to_string(),
+			"Bearer ${NON_EXISTENT_ENV_VAR}".to_string(),
+		)]));
+		assert!(matches!(
+			network.resolve_secrets().await,
+			Err(ConfigError::ParseError(_))
+		));
+		std::env::remove_var("TEST_NETWORK_RPC_API_KEY");
+	}
+
+	#[test]
+	fn test_validate_page_size() {
+		let mut network = create_valid_network();
+		network.network_type = BlockChainType::Stellar;
+		network.network_passphrase = Some("Test SDF Network".to_string());
+		assert!(network.validate().is_ok());
+
+		network.page_size = Some(50);
+		assert!(network.validate().is_ok());
+
+		for page_size in [0, STELLAR_MAX_PAGE_SIZE + 1] {
+			network.page_size = Some(page_size);
+			assert!(matches!(
+				network.validate(),
+				Err(ConfigError::ValidationError(_))
+			));
+		}
+
+		let mut network = create_valid_network();
+		network.page_size = Some(50);
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_mempool() {
+		let mut network = create_valid_network();
+		network.mempool = Some(true);
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+
+		network.rpc_urls.push(RpcUrl {
+			type_: "ws_rpc".to_string(),
+			url: SecretValue::Plain(SecretString::new("wss://test.network".to_string())),
+			weight: 100,
+		});
+		assert!(network.validate().is_ok());
+		assert!(network.is_mempool_enabled());
+
+		// WebSocket endpoints are only accepted for watching pending transactions
+		network.mempool = None;
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+
+		let mut network = create_valid_network();
+		network.network_type = BlockChainType::Stellar;
+		network.network_passphrase = Some("Test SDF Network".to_string());
+		network.mempool = Some(true);
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[tokio::test]
+	async fn test_load_disabled_network() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("disabled_network.json");
+
+		let network_config = r#"{
+			"name": "Test Network",
+			"slug": "test_network",
+			"network_type": "EVM",
+			"rpc_urls": [
+				{
+					"type_": "rpc",
+					"url": {
+						"type": "plain",
+						"value": "https://eth.drpc.org"
+					},
+					"weight": 100
+				}
+			],
+			"chain_id": 1,
+			"block_time_ms": 1000,
+			"confirmation_blocks": 1,
+			"cron_schedule": "0 */5 * * * *",
+			"max_past_blocks": 10,
+			"store_blocks": true,
+			"enabled": false
+		}"#;
+		fs::write(&file_path, network_config).unwrap();
+
+		let networks: HashMap<String, Network> =
+			Network::load_all(Some(temp_dir.path())).await.unwrap();
+
+		assert!(!networks["test_network"].is_enabled());
+		assert!(create_valid_network().is_enabled());
+	}
+
 	#[test]
 	fn test_validate_invalid_slug() {
 		let network = NetworkBuilder::new().slug("Invalid-Slug").build();
@@ -469,6 +1075,12 @@ mod tests {
 	#[test]
 	fn test_validate_zero_confirmation_blocks() {
 		let network = NetworkBuilder::new().confirmation_blocks(0).build();
+		assert!(network.validate().is_ok());
+	}
+
+	#[test]
+	fn test_validate_invalid_cron_schedule() {
+		let network = NetworkBuilder::new().cron_schedule("invalid cron").build();
 		assert!(matches!(
 			network.validate(),
 			Err(ConfigError::ValidationError(_))
@@ -476,12 +1088,17 @@ mod tests {
 	}
 
 	#[test]
-	fn test_validate_invalid_cron_schedule() {
-		let network = NetworkBuilder::new().cron_schedule("invalid cron").build();
+	fn test_validate_timezone() {
+		let network = NetworkBuilder::new().timezone("America/New_York").build();
+		assert!(network.validate().is_ok());
+		assert_eq!(network.cron_timezone(), Tz::America__New_York);
+
+		let network = NetworkBuilder::new().timezone("Mars/Olympus_Mons").build();
 		assert!(matches!(
 			network.validate(),
 			Err(ConfigError::ValidationError(_))
 		));
+		assert_eq!(NetworkBuilder::new().build().cron_timezone(), Tz::UTC);
 	}
 
 	#[test]
@@ -502,6 +1119,38 @@ mod tests {
 		));
 	}
 
+	#[tokio::test]
+	async fn test_load_network_toml() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("toml_network.toml");
+
+		let network_config = r#"
+			name = "Test Network"
+			slug = "test_network"
+			network_type = "EVM"
+			chain_id = 1
+			block_time_ms = 1000
+			confirmation_blocks = 1
+			cron_schedule = "0 */5 * * * *"
+			max_past_blocks = 10
+			store_blocks = true
+
+			[[rpc_urls]]
+			type_ = "rpc"
+			url = { type = "plain", value = "https://eth.drpc.org" }
+			weight = 100
+		"#;
+		fs::write(&file_path, network_config).unwrap();
+
+		let networks: HashMap<String, Network> =
+			Network::load_all(Some(temp_dir.path())).await.unwrap();
+
+		let network = &networks["toml_network"];
+		assert_eq!(network.network_type, BlockChainType::EVM);
+		assert_eq!(network.rpc_urls[0].weight, 100);
+		assert_eq!(network.chain_id, Some(1));
+	}
+
 	#[tokio::test]
 	async fn test_invalid_load_from_path() {
 		let path = Path::new("config/networks/invalid.json");
@@ -651,7 +1300,26 @@ mod tests {
 		let result = network.validate();
 		assert!(matches!(result, Err(ConfigError::ValidationError(_))));
 		if let Err(ConfigError::ValidationError(err)) = result {
-			assert!(err.message.contains("Type: ws_rpc (must be one of: rpc)"));
+			assert!(err
+				.message
+				.contains("Type: ws_rpc (must be one of: rpc, archive, full)"));
+		}
+	}
+
+	#[test]
+	fn test_validate_archive_and_full_rpc_url_types() {
+		let mut network = create_valid_network();
+		network.rpc_urls[0].type_ = "archive".to_string();
+		assert!(network.validate().is_ok());
+		network.rpc_urls[0].type_ = "full".to_string();
+		assert!(network.validate().is_ok());
+
+		// Node types are only supported on EVM networks
+		network.network_type = BlockChainType::Stellar;
+		let result = network.validate();
+		assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+		if let Err(ConfigError::ValidationError(err)) = result {
+			assert!(err.message.contains("Type: full (must be one of: rpc)"));
 		}
 	}
 
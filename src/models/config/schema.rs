@@ -0,0 +1,113 @@
+//! JSON Schemas of the configuration files.
+//!
+//! The schemas are derived from the serde models, so that editors can validate and complete
+//! configuration files. They describe the files as they are parsed, before the checks of
+//! [`ConfigLoader::validate`](super::ConfigLoader::validate), such as the references between
+//! monitors, networks and triggers.
+
+use schemars::{schema_for, Schema};
+
+use crate::models::{config::trigger_config::TriggerConfigFile, Monitor, Network};
+
+/// Kind of configuration file
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum ConfigKind {
+	/// Monitor configuration, one monitor per file
+	Monitor,
+	/// Network configuration, one network per file
+	Network,
+	/// Trigger configuration, a map of trigger names to triggers per file
+	Trigger,
+}
+
+/// Returns the JSON Schema of a kind of configuration file
+pub fn config_schema(kind: ConfigKind) -> Schema {
+	match kind {
+		ConfigKind::Monitor => schema_for!(Monitor),
+		ConfigKind::Network => schema_for!(Network),
+		ConfigKind::Trigger => schema_for!(TriggerConfigFile),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::Value;
+
+	fn schema_json(kind: ConfigKind) -> Value {
+		let schema = serde_json::to_string_pretty(&config_schema(kind)).unwrap();
+		serde_json::from_str(&schema).unwrap()
+	}
+
+	fn assert_properties(schema: &Value, expected: &[&str]) {
+		let properties = schema["properties"].as_object().unwrap();
+		for property in expected {
+			assert!(properties.contains_key(*property), "missing {}", property);
+		}
+	}
+
+	#[test]
+	fn test_monitor_schema() {
+		let schema = schema_json(ConfigKind::Monitor);
+
+		assert_eq!(schema["title"], "Monitor");
+		assert_properties(
+			&schema,
+			&[
+				"name",
+				"networks",
+				"addresses",
+				"match_conditions",
+				"triggers",
+			],
+		);
+		assert!(schema["properties"].get("rpc_results").is_none());
+		let statuses = schema["$defs"]["TransactionStatus"].to_string();
+		for status in ["Any", "Success", "Failure"] {
+			assert!(statuses.contains(&format!("\"{}\"", status)));
+		}
+	}
+
+	#[test]
+	fn test_network_schema() {
+		let schema = schema_json(ConfigKind::Network);
+
+		assert_properties(
+			&schema,
+			&[
+				"network_type",
+				"slug",
+				"rpc_urls",
+				"block_time_ms",
+				"cron_schedule",
+			],
+		);
+		assert_eq!(schema["additionalProperties"], false);
+		assert!(schema["$defs"]["SecretValue"]["anyOf"].is_array());
+	}
+
+	#[test]
+	fn test_trigger_schema() {
+		let schema = schema_json(ConfigKind::Trigger);
+		let definitions = &schema["$defs"];
+
+		assert!(schema["additionalProperties"].is_object());
+		assert_properties(&definitions["Trigger"], &["name", "trigger_type", "config"]);
+		let config_variants = definitions["TriggerTypeConfig"]["anyOf"]
+			.as_array()
+			.unwrap();
+		assert_eq!(config_variants.len(), 8);
+		for property in ["slack_url", "signature_algorithm", "chat_id", "script_path"] {
+			assert!(
+				config_variants
+					.iter()
+					.any(|variant| variant["properties"][property].is_object()),
+				"missing {}",
+				property
+			);
+		}
+		assert!(definitions["TriggerType"]
+			.to_string()
+			.contains("\"webhook\""));
+	}
+}
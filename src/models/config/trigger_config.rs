@@ -5,23 +5,45 @@
 
 use async_trait::async_trait;
 use email_address::EmailAddress;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::{collections::HashMap, fs, path::Path};
 
 use crate::{
 	models::{
-		config::error::ConfigError, ConfigLoader, SecretValue, Trigger, TriggerType,
-		TriggerTypeConfig,
+		config::{error::ConfigError, read_config_value},
+		ConfigLoader, SecretValue, Trigger, TriggerType, TriggerTypeConfig,
+		WebhookSignatureAlgorithm,
+	},
+	services::{
+		notification::parse_ed25519_signing_key,
+		trigger::{validate_script_config, validate_script_limits, ScriptLimits},
 	},
-	services::trigger::validate_script_config,
 	utils::normalize_string,
 };
 
 const TELEGRAM_MAX_BODY_LENGTH: usize = 4096;
 const DISCORD_MAX_BODY_LENGTH: usize = 2000;
+const DISCORD_MAX_EMBED_DESCRIPTION_LENGTH: usize = 4096;
+const DISCORD_MAX_EMBED_FIELDS: usize = 25;
+const DISCORD_MAX_EMBED_COLOR: u32 = 0xFFFFFF;
+const SLACK_MAX_BLOCKS: usize = 50;
+const SLACK_MAX_SECTION_FIELDS: usize = 10;
+const SLACK_BLOCK_TYPES: &[&str] = &[
+	"actions",
+	"context",
+	"divider",
+	"file",
+	"header",
+	"image",
+	"input",
+	"rich_text",
+	"section",
+	"video",
+];
 
 /// File structure for trigger configuration files
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct TriggerConfigFile {
 	/// Map of trigger names to their configurations
 	#[serde(flatten)]
@@ -108,6 +130,37 @@ impl ConfigLoader for Trigger {
 				})?;
 				*discord_url = SecretValue::Plain(resolved_url);
 			}
+			TriggerTypeConfig::Opsgenie { api_key, .. } => {
+				let resolved_key = api_key.resolve().await.map_err(|e| {
+					ConfigError::parse_error(
+						format!("failed to resolve Opsgenie API key: {}", e),
+						Some(Box::new(e)),
+						None,
+					)
+				})?;
+				*api_key = SecretValue::Plain(resolved_key);
+			}
+			TriggerTypeConfig::Kafka {
+				sasl: Some(sasl), ..
+			} => {
+				let resolved_username = sasl.username.resolve().await.map_err(|e| {
+					ConfigError::parse_error(
+						format!("failed to resolve Kafka SASL username: {}", e),
+						Some(Box::new(e)),
+						None,
+					)
+				})?;
+				sasl.username = SecretValue::Plain(resolved_username);
+
+				let resolved_password = sasl.password.resolve().await.map_err(|e| {
+					ConfigError::parse_error(
+						format!("failed to resolve Kafka SASL password: {}", e),
+						Some(Box::new(e)),
+						None,
+					)
+				})?;
+				sasl.password = SecretValue::Plain(resolved_password);
+			}
 			_ => {}
 		}
 
@@ -158,20 +211,11 @@ impl ConfigLoader for Trigger {
 					)])),
 				)
 			})?;
-			if Self::is_json_file(&entry.path()) {
+			if Self::is_config_file(&entry.path()) {
 				let file_path = entry.path();
-				let content = fs::read_to_string(&file_path).map_err(|e| {
-					ConfigError::file_error(
-						format!("failed to read trigger config file: {}", e),
-						Some(Box::new(e)),
-						Some(HashMap::from([(
-							"path".to_string(),
-							file_path.display().to_string(),
-						)])),
-					)
-				})?;
+				let value = read_config_value::<Self>(&file_path, "trigger")?;
 				let file_triggers: TriggerConfigFile =
-					serde_json::from_str(&content).map_err(|e| {
+					serde_json::from_value(value).map_err(|e| {
 						ConfigError::parse_error(
 							format!("failed to parse trigger config: {}", e),
 							Some(Box::new(e)),
@@ -218,11 +262,10 @@ impl ConfigLoader for Trigger {
 
 	/// Load a trigger configuration from a specific file
 	///
-	/// Reads and parses a single JSON file as a trigger configuration.
+	/// Reads and parses a single JSON, YAML or TOML file as a trigger configuration.
 	async fn load_from_path(path: &Path) -> Result<Self, ConfigError> {
-		let file = std::fs::File::open(path)
-			.map_err(|e| ConfigError::file_error(e.to_string(), None, None))?;
-		let mut config: Trigger = serde_json::from_reader(file)
+		let value = read_config_value::<Self>(path, "trigger")?;
+		let mut config: Trigger = serde_json::from_value(value)
 			.map_err(|e| ConfigError::parse_error(e.to_string(), None, None))?;
 
 		// Resolve secrets before validating
@@ -252,12 +295,22 @@ impl ConfigLoader for Trigger {
 			));
 		}
 
+		if self.batch_window_ms == Some(0) {
+			return Err(ConfigError::validation_error(
+				"batch_window_ms must be greater than 0",
+				None,
+				None,
+			));
+		}
+
 		match &self.trigger_type {
 			TriggerType::Slack => {
 				if let TriggerTypeConfig::Slack {
 					slack_url,
 					message,
+					blocks,
 					retry_policy: _,
+					timeout_ms: _,
 				} = &self.config
 				{
 					// Validate webhook URL
@@ -284,6 +337,10 @@ impl ConfigLoader for Trigger {
 							None,
 						));
 					}
+					// Validate Block Kit blocks
+					if let Some(blocks) = blocks {
+						validate_slack_blocks(blocks)?;
+					}
 				}
 			}
 			TriggerType::Email => {
@@ -295,7 +352,11 @@ impl ConfigLoader for Trigger {
 					message,
 					sender,
 					recipients,
+					cc,
+					bcc,
+					is_html: _,
 					retry_policy: _,
+					timeout_ms: _,
 				} = &self.config
 				{
 					// Validate host
@@ -426,13 +487,30 @@ impl ConfigLoader for Trigger {
 							));
 						}
 					}
+
+					// Validate CC and BCC recipients
+					for (kind, addresses) in [("CC", cc), ("BCC", bcc)] {
+						for address in addresses {
+							if !EmailAddress::is_valid(address.as_str()) {
+								return Err(ConfigError::validation_error(
+									format!("Invalid {} email address: {}", kind, address),
+									None,
+									None,
+								));
+							}
+						}
+					}
 				}
 			}
 			TriggerType::Webhook => {
 				if let TriggerTypeConfig::Webhook {
 					url,
 					method,
+					secret,
 					message,
+					signature_algorithm,
+					signature_header,
+					signature_algorithm_header,
 					..
 				} = &self.config
 				{
@@ -472,6 +550,27 @@ impl ConfigLoader for Trigger {
 							None,
 						));
 					}
+					// Validate signature settings
+					for header in [signature_header, signature_algorithm_header]
+						.into_iter()
+						.flatten()
+					{
+						if reqwest::header::HeaderName::from_bytes(header.as_bytes()).is_err() {
+							return Err(ConfigError::validation_error(
+								format!("Invalid signature header name: {}", header),
+								None,
+								None,
+							));
+						}
+					}
+					// Secrets from the environment or a vault are only checked once resolved
+					if let (WebhookSignatureAlgorithm::Ed25519, Some(SecretValue::Plain(secret))) =
+						(signature_algorithm, secret)
+					{
+						parse_ed25519_signing_key(secret.as_str()).map_err(|e| {
+							ConfigError::validation_error(e.to_string(), None, None)
+						})?;
+					}
 				}
 			}
 			TriggerType::Telegram => {
@@ -552,6 +651,7 @@ impl ConfigLoader for Trigger {
 				if let TriggerTypeConfig::Discord {
 					discord_url,
 					message,
+					embed,
 					..
 				} = &self.config
 				{
@@ -578,17 +678,109 @@ impl ConfigLoader for Trigger {
 							None,
 						));
 					}
-					// Validate template max length
-					if message.body.len() > DISCORD_MAX_BODY_LENGTH {
+					// Validate template max length, embed descriptions allow longer bodies
+					let max_body_length = if embed.is_some() {
+						DISCORD_MAX_EMBED_DESCRIPTION_LENGTH
+					} else {
+						DISCORD_MAX_BODY_LENGTH
+					};
+					if message.body.len() > max_body_length {
 						return Err(ConfigError::validation_error(
 							format!(
 								"Message body should not exceed {} characters",
-								DISCORD_MAX_BODY_LENGTH
+								max_body_length
 							),
 							None,
 							None,
 						));
 					}
+					// Validate embed
+					if let Some(embed) = embed {
+						if let Some(title) = &embed.title {
+							if title.trim().is_empty() {
+								return Err(ConfigError::validation_error(
+									"Embed title cannot be empty",
+									None,
+									None,
+								));
+							}
+						}
+						if embed
+							.color
+							.is_some_and(|color| color > DISCORD_MAX_EMBED_COLOR)
+						{
+							return Err(ConfigError::validation_error(
+								format!(
+									"Embed color must be an RGB value between 0 and {}",
+									DISCORD_MAX_EMBED_COLOR
+								),
+								None,
+								None,
+							));
+						}
+						if embed.fields.len() > DISCORD_MAX_EMBED_FIELDS {
+							return Err(ConfigError::validation_error(
+								format!(
+									"Embed should not have more than {} fields",
+									DISCORD_MAX_EMBED_FIELDS
+								),
+								None,
+								None,
+							));
+						}
+						if embed.fields.iter().any(|field| {
+							field.name.trim().is_empty() || field.value.trim().is_empty()
+						}) {
+							return Err(ConfigError::validation_error(
+								"Embed field name and value cannot be empty",
+								None,
+								None,
+							));
+						}
+					}
+				}
+			}
+			TriggerType::Opsgenie => {
+				if let TriggerTypeConfig::Opsgenie {
+					api_key,
+					alias,
+					message,
+					..
+				} = &self.config
+				{
+					// Validate API key
+					if api_key.trim().is_empty() {
+						return Err(ConfigError::validation_error(
+							"API key cannot be empty",
+							None,
+							None,
+						));
+					}
+					// Validate alias
+					if let Some(alias) = alias {
+						if alias.trim().is_empty() {
+							return Err(ConfigError::validation_error(
+								"Alias cannot be empty",
+								None,
+								None,
+							));
+						}
+					}
+					// Validate message
+					if message.title.trim().is_empty() {
+						return Err(ConfigError::validation_error(
+							"Title cannot be empty",
+							None,
+							None,
+						));
+					}
+					if message.body.trim().is_empty() {
+						return Err(ConfigError::validation_error(
+							"Body cannot be empty",
+							None,
+							None,
+						));
+					}
 				}
 			}
 			TriggerType::Script => {
@@ -596,12 +788,92 @@ impl ConfigLoader for Trigger {
 					script_path,
 					language,
 					timeout_ms,
+					max_memory_mb,
+					max_output_bytes,
 					..
 				} = &self.config
 				{
 					validate_script_config(script_path, language, timeout_ms)?;
+					validate_script_limits(&ScriptLimits {
+						max_memory_mb: *max_memory_mb,
+						max_output_bytes: *max_output_bytes,
+					})?;
 				}
 			}
+			TriggerType::File => {
+				if let TriggerTypeConfig::File {
+					path,
+					rotate_max_bytes,
+					..
+				} = &self.config
+				{
+					// Validate path
+					if path.trim().is_empty() {
+						return Err(ConfigError::validation_error(
+							"File path cannot be empty",
+							None,
+							None,
+						));
+					}
+					// Validate rotation size
+					if *rotate_max_bytes == Some(0) {
+						return Err(ConfigError::validation_error(
+							"rotate_max_bytes must be greater than 0",
+							None,
+							None,
+						));
+					}
+				}
+			}
+			TriggerType::Kafka => {
+				if let TriggerTypeConfig::Kafka {
+					brokers,
+					topic,
+					sasl,
+					..
+				} = &self.config
+				{
+					// Validate brokers
+					if brokers.is_empty() || brokers.iter().any(|broker| broker.trim().is_empty()) {
+						return Err(ConfigError::validation_error(
+							"Kafka brokers cannot be empty",
+							None,
+							None,
+						));
+					}
+					// Validate topic
+					if topic.trim().is_empty() {
+						return Err(ConfigError::validation_error(
+							"Kafka topic cannot be empty",
+							None,
+							None,
+						));
+					}
+					// Validate SASL credentials
+					if let Some(sasl) = sasl {
+						if sasl.username.trim().is_empty() || sasl.password.trim().is_empty() {
+							return Err(ConfigError::validation_error(
+								"Kafka SASL username and password cannot be empty",
+								None,
+								None,
+							));
+						}
+					}
+				}
+			}
+		}
+
+		// Validate send timeout
+		if self
+			.config
+			.get_timeout()
+			.is_some_and(|timeout| timeout.is_zero())
+		{
+			return Err(ConfigError::validation_error(
+				"timeout_ms must be greater than 0",
+				None,
+				None,
+			));
 		}
 
 		// Log a warning if the trigger uses an insecure protocol
@@ -626,6 +898,13 @@ impl ConfigLoader for Trigger {
 				}
 			}
 			TriggerTypeConfig::Telegram { .. } => {}
+			TriggerTypeConfig::Opsgenie { .. } => {}
+			TriggerTypeConfig::File { .. } => {}
+			TriggerTypeConfig::Kafka { sasl, .. } => {
+				if sasl.is_none() {
+					tracing::warn!("Kafka records are produced without authentication nor TLS");
+				}
+			}
 			TriggerTypeConfig::Script { script_path, .. } => {
 				// Check script file permissions on Unix systems
 				#[cfg(unix)]
@@ -698,11 +977,107 @@ impl ConfigLoader for Trigger {
 	}
 }
 
+/// Validates the Block Kit blocks of a Slack trigger
+///
+/// Only the structure Slack would reject outright is checked: the number of blocks, their type
+/// and the text objects of sections, headers and contexts.
+fn validate_slack_blocks(blocks: &[serde_json::Value]) -> Result<(), ConfigError> {
+	if blocks.is_empty() || blocks.len() > SLACK_MAX_BLOCKS {
+		return Err(ConfigError::validation_error(
+			format!(
+				"Slack blocks should contain between 1 and {} blocks",
+				SLACK_MAX_BLOCKS
+			),
+			None,
+			None,
+		));
+	}
+
+	let is_text_object = |value: &serde_json::Value, text_types: &[&str]| {
+		value
+			.get("type")
+			.and_then(|t| t.as_str())
+			.is_some_and(|t| text_types.contains(&t))
+			&& value
+				.get("text")
+				.and_then(|text| text.as_str())
+				.is_some_and(|text| !text.trim().is_empty())
+	};
+
+	for (index, block) in blocks.iter().enumerate() {
+		let invalid = |reason: &str| {
+			ConfigError::validation_error(
+				format!("Invalid Slack block {}: {}", index, reason),
+				None,
+				None,
+			)
+		};
+
+		let Some(block_type) = block.get("type").and_then(|t| t.as_str()) else {
+			return Err(invalid("expected an object with a string `type`"));
+		};
+		if !SLACK_BLOCK_TYPES.contains(&block_type) {
+			return Err(invalid(&format!("unknown block type '{}'", block_type)));
+		}
+
+		match block_type {
+			"header" => {
+				if !block
+					.get("text")
+					.is_some_and(|text| is_text_object(text, &["plain_text"]))
+				{
+					return Err(invalid("a header needs a non-empty `plain_text` text"));
+				}
+			}
+			"section" => {
+				let text = block.get("text");
+				let fields = block.get("fields");
+				if text.is_none() && fields.is_none() {
+					return Err(invalid("a section needs a `text` or `fields`"));
+				}
+				if text.is_some_and(|text| !is_text_object(text, &["plain_text", "mrkdwn"])) {
+					return Err(invalid("`text` must be a non-empty text object"));
+				}
+				if let Some(fields) = fields {
+					let valid = fields.as_array().is_some_and(|fields| {
+						!fields.is_empty()
+							&& fields.len() <= SLACK_MAX_SECTION_FIELDS
+							&& fields
+								.iter()
+								.all(|field| is_text_object(field, &["plain_text", "mrkdwn"]))
+					});
+					if !valid {
+						return Err(invalid(&format!(
+							"`fields` must hold between 1 and {} non-empty text objects",
+							SLACK_MAX_SECTION_FIELDS
+						)));
+					}
+				}
+			}
+			"context" => {
+				if !block
+					.get("elements")
+					.and_then(|elements| elements.as_array())
+					.is_some_and(|elements| !elements.is_empty())
+				{
+					return Err(invalid("a context needs non-empty `elements`"));
+				}
+			}
+			_ => {}
+		}
+	}
+
+	Ok(())
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::models::NotificationMessage;
-	use crate::models::{core::Trigger, ScriptLanguage, SecretString};
+	use crate::models::{
+		core::Trigger, DiscordEmbed, DiscordEmbedField, FileFormat, KafkaSaslMechanism,
+		NotificationSeverity, OpsgeniePriority, OpsgenieRegion, ScriptLanguage, SecretString,
+	};
 	use crate::utils::tests::builders::trigger::TriggerBuilder;
 	use crate::utils::RetryConfig;
 	use std::{fs::File, io::Write, os::unix::fs::PermissionsExt};
@@ -743,6 +1118,68 @@ mod tests {
 		assert!(empty_body.validate().is_err());
 	}
 
+	#[test]
+	fn test_slack_blocks_validation() {
+		let with_blocks = |blocks: Vec<serde_json::Value>| {
+			TriggerBuilder::new()
+				.name("test_slack")
+				.slack("https://hooks.slack.com/services/xxx")
+				.slack_blocks(blocks)
+				.build()
+		};
+
+		// Valid blocks
+		let valid_trigger = with_blocks(vec![
+			serde_json::json!({
+				"type": "header",
+				"text": { "type": "plain_text", "text": "${monitor.name}" }
+			}),
+			serde_json::json!({ "type": "divider" }),
+			serde_json::json!({
+				"type": "section",
+				"fields": [{ "type": "mrkdwn", "text": "*Hash*\n${transaction.hash}" }]
+			}),
+		]);
+		assert!(valid_trigger.validate().is_ok());
+
+		// No blocks
+		assert!(with_blocks(vec![]).validate().is_err());
+
+		// Too many blocks
+		let too_many = vec![serde_json::json!({ "type": "divider" }); SLACK_MAX_BLOCKS + 1];
+		assert!(with_blocks(too_many).validate().is_err());
+
+		// Block without a type
+		let untyped = with_blocks(vec![serde_json::json!({ "text": "Alert" })]);
+		assert!(untyped.validate().is_err());
+
+		// Unknown block type
+		let unknown = with_blocks(vec![serde_json::json!({ "type": "table" })]);
+		assert!(unknown
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("unknown block type 'table'"));
+
+		// Header with a markdown text
+		let markdown_header = with_blocks(vec![serde_json::json!({
+			"type": "header",
+			"text": { "type": "mrkdwn", "text": "*Alert*" }
+		})]);
+		assert!(markdown_header.validate().is_err());
+
+		// Section without text nor fields
+		let empty_section = with_blocks(vec![serde_json::json!({ "type": "section" })]);
+		assert!(empty_section.validate().is_err());
+
+		// Section field that isn't a text object
+		let invalid_field = with_blocks(vec![serde_json::json!({
+			"type": "section",
+			"fields": ["Value"]
+		})]);
+		assert!(invalid_field.validate().is_err());
+	}
+
 	#[test]
 	fn test_email_trigger_validation() {
 		// Valid trigger
@@ -797,6 +1234,49 @@ mod tests {
 			.build();
 		assert!(invalid_email.validate().is_err());
 
+		// Test CC and BCC recipients
+		let with_copies = TriggerBuilder::new()
+			.name("test_email")
+			.email(
+				"smtp.example.com",
+				"user",
+				"pass",
+				"sender@example.com",
+				vec!["recipient@example.com"],
+			)
+			.email_cc(vec!["cc@example.com"])
+			.email_bcc(vec!["bcc@example.com"])
+			.build();
+		assert!(with_copies.validate().is_ok());
+
+		// Test invalid CC email address
+		let invalid_cc = TriggerBuilder::new()
+			.name("test_email")
+			.email(
+				"smtp.example.com",
+				"user",
+				"pass",
+				"sender@example.com",
+				vec!["recipient@example.com"],
+			)
+			.email_cc(vec!["invalid-cc"])
+			.build();
+		assert!(invalid_cc.validate().is_err());
+
+		// Test invalid BCC email address
+		let invalid_bcc = TriggerBuilder::new()
+			.name("test_email")
+			.email(
+				"smtp.example.com",
+				"user",
+				"pass",
+				"sender@example.com",
+				vec!["recipient@example.com"],
+			)
+			.email_bcc(vec!["invalid-bcc"])
+			.build();
+		assert!(invalid_bcc.validate().is_err());
+
 		// Test empty password
 		let invalid_password = TriggerBuilder::new()
 			.name("test_email")
@@ -940,6 +1420,37 @@ mod tests {
 		assert!(invalid_body.validate().is_err());
 	}
 
+	#[test]
+	fn test_webhook_signature_validation() {
+		let ed25519_trigger = |secret: &str, header: Option<&str>| {
+			TriggerBuilder::new()
+				.name("test_webhook")
+				.webhook("https://api.example.com/webhook")
+				.webhook_secret(SecretValue::Plain(SecretString::new(secret.to_string())))
+				.webhook_signature(WebhookSignatureAlgorithm::Ed25519, header, None)
+				.build()
+		};
+		let key = "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb";
+
+		assert!(ed25519_trigger(key, Some("X-Webhook-Signature"))
+			.validate()
+			.is_ok());
+
+		// HMAC secret used as an Ed25519 key
+		let result = ed25519_trigger("top-secret", None).validate();
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid Ed25519 private key"));
+
+		// Invalid header name
+		let result = ed25519_trigger(key, Some("X Signature")).validate();
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid signature header name"));
+	}
+
 	#[test]
 	fn test_discord_trigger_validation() {
 		// Valid trigger
@@ -1028,6 +1539,234 @@ mod tests {
 		assert!(invalid_body_message.validate().is_err());
 	}
 
+	#[test]
+	fn test_opsgenie_trigger_validation() {
+		let valid_trigger = TriggerBuilder::new()
+			.name("test_opsgenie")
+			.opsgenie("test-api-key")
+			.build();
+		assert!(valid_trigger.validate().is_ok());
+
+		// Test empty API key
+		let invalid_key = TriggerBuilder::new()
+			.name("test_opsgenie")
+			.opsgenie("  ")
+			.build();
+		assert!(invalid_key.validate().is_err());
+
+		// Test empty alias
+		let invalid_alias = TriggerBuilder::new()
+			.name("test_opsgenie")
+			.opsgenie("test-api-key")
+			.opsgenie_alias("")
+			.build();
+		assert!(invalid_alias.validate().is_err());
+
+		// Test invalid message
+		let invalid_title_message = TriggerBuilder::new()
+			.name("test_opsgenie")
+			.opsgenie("test-api-key")
+			.message("", "Test Message")
+			.build();
+		assert!(invalid_title_message.validate().is_err());
+
+		let invalid_body_message = TriggerBuilder::new()
+			.name("test_opsgenie")
+			.opsgenie("test-api-key")
+			.message("Test Subject", "")
+			.build();
+		assert!(invalid_body_message.validate().is_err());
+	}
+
+	#[test]
+	fn test_opsgenie_trigger_deserialization_defaults() {
+		let trigger: Trigger = serde_json::from_str(
+			r#"{
+				"name": "opsgenie",
+				"trigger_type": "opsgenie",
+				"config": {
+					"api_key": { "type": "plain", "value": "test-api-key" },
+					"alias": "${monitor.name}",
+					"message": { "title": "Alert", "body": "Body" }
+				}
+			}"#,
+		)
+		.unwrap();
+
+		match trigger.config {
+			TriggerTypeConfig::Opsgenie {
+				region,
+				priority,
+				alias,
+				..
+			} => {
+				assert_eq!(region, OpsgenieRegion::Us);
+				assert_eq!(priority, OpsgeniePriority::P3);
+				assert_eq!(alias, Some("${monitor.name}".to_string()));
+			}
+			_ => panic!("Expected Opsgenie config"),
+		}
+	}
+
+	#[test]
+	fn test_file_trigger_validation() {
+		let valid_trigger = TriggerBuilder::new()
+			.name("test_file")
+			.file("data/audit.jsonl")
+			.file_rotate_max_bytes(1024)
+			.build();
+		assert!(valid_trigger.validate().is_ok());
+
+		// Test empty path
+		let invalid_path = TriggerBuilder::new().name("test_file").file("  ").build();
+		assert!(invalid_path.validate().is_err());
+
+		// Test zero rotation size
+		let invalid_rotation = TriggerBuilder::new()
+			.name("test_file")
+			.file("data/audit.jsonl")
+			.file_rotate_max_bytes(0)
+			.build();
+		assert!(invalid_rotation.validate().is_err());
+	}
+
+	#[test]
+	fn test_trigger_timeout_validation() {
+		let valid_trigger = TriggerBuilder::new()
+			.name("test_slack")
+			.slack("https://hooks.slack.com/services/xxx")
+			.timeout_ms(5000)
+			.build();
+		assert!(valid_trigger.validate().is_ok());
+		assert_eq!(
+			valid_trigger.config.get_timeout(),
+			Some(std::time::Duration::from_millis(5000))
+		);
+
+		// Test zero timeout
+		let invalid_timeout = TriggerBuilder::new()
+			.name("test_slack")
+			.slack("https://hooks.slack.com/services/xxx")
+			.timeout_ms(0)
+			.build();
+		assert!(invalid_timeout.validate().is_err());
+
+		// Unset timeout leaves sends unbounded
+		let no_timeout = TriggerBuilder::new()
+			.name("test_slack")
+			.slack("https://hooks.slack.com/services/xxx")
+			.build();
+		assert_eq!(no_timeout.config.get_timeout(), None);
+	}
+
+	#[test]
+	fn test_file_trigger_deserialization_defaults() {
+		let trigger: Trigger = serde_json::from_str(
+			r#"{
+				"name": "audit",
+				"trigger_type": "file",
+				"config": {
+					"path": "data/audit.jsonl"
+				}
+			}"#,
+		)
+		.unwrap();
+
+		match trigger.config {
+			TriggerTypeConfig::File {
+				path,
+				format,
+				rotate_max_bytes,
+			} => {
+				assert_eq!(path, "data/audit.jsonl");
+				assert_eq!(format, FileFormat::Jsonl);
+				assert_eq!(rotate_max_bytes, None);
+			}
+			_ => panic!("Expected File config"),
+		}
+	}
+
+	#[test]
+	fn test_kafka_trigger_validation() {
+		let valid_trigger = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(vec!["localhost:9092"], "monitor-matches")
+			.kafka_sasl(KafkaSaslMechanism::Plain, "user", "pass")
+			.build();
+		assert!(valid_trigger.validate().is_ok());
+
+		// Test empty brokers
+		let no_brokers = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(vec![], "monitor-matches")
+			.build();
+		assert!(no_brokers.validate().is_err());
+		let empty_broker = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(vec!["localhost:9092", " "], "monitor-matches")
+			.build();
+		assert!(empty_broker.validate().is_err());
+
+		// Test empty topic
+		let empty_topic = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(vec!["localhost:9092"], "")
+			.build();
+		assert!(empty_topic.validate().is_err());
+
+		// Test empty SASL password
+		let empty_password = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(vec!["localhost:9092"], "monitor-matches")
+			.kafka_sasl(KafkaSaslMechanism::Plain, "user", "")
+			.build();
+		assert!(empty_password.validate().is_err());
+
+		// Test zero timeout
+		let zero_timeout = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(vec!["localhost:9092"], "monitor-matches")
+			.timeout_ms(0)
+			.build();
+		assert!(zero_timeout.validate().is_err());
+	}
+
+	#[test]
+	fn test_kafka_trigger_deserialization() {
+		let trigger: Trigger = serde_json::from_str(
+			r#"{
+				"name": "matches",
+				"trigger_type": "kafka",
+				"config": {
+					"brokers": ["broker-1:9092", "broker-2:9092"],
+					"topic": "monitor-matches",
+					"sasl": {
+						"mechanism": "SCRAM-SHA-256",
+						"username": { "type": "plain", "value": "user" },
+						"password": { "type": "plain", "value": "pass" }
+					}
+				}
+			}"#,
+		)
+		.unwrap();
+
+		assert_eq!(trigger.trigger_type, TriggerType::Kafka);
+		match trigger.config {
+			TriggerTypeConfig::Kafka {
+				brokers,
+				topic,
+				sasl,
+				timeout_ms,
+			} => {
+				assert_eq!(brokers, vec!["broker-1:9092", "broker-2:9092"]);
+				assert_eq!(topic, "monitor-matches");
+				assert_eq!(sasl.unwrap().mechanism, KafkaSaslMechanism::ScramSha256);
+				assert_eq!(timeout_ms, None);
+			}
+			_ => panic!("Expected Kafka config"),
+		}
+	}
+
 	#[test]
 	fn test_script_trigger_validation() {
 		let temp_dir = std::env::temp_dir();
@@ -1295,6 +2034,19 @@ mod tests {
 		}
 	}
 
+	#[tokio::test]
+	async fn test_resolve_secrets_opsgenie() {
+		let trigger = TriggerBuilder::new()
+			.name("opsgenie")
+			.opsgenie("test-api-key")
+			.build();
+
+		let resolved = trigger.resolve_secrets().await.unwrap();
+		if let TriggerTypeConfig::Opsgenie { api_key, .. } = &resolved.config {
+			assert!(matches!(api_key, SecretValue::Plain(_)));
+		}
+	}
+
 	#[tokio::test]
 	async fn test_resolve_secrets_discord() {
 		let trigger = TriggerBuilder::new()
@@ -1432,6 +2184,56 @@ mod tests {
 			assert!(e.to_string().contains("failed to resolve SMTP password"));
 		}
 	}
+
+	#[tokio::test]
+	async fn test_load_trigger_with_file_and_env_secrets() {
+		let temp_dir = TempDir::new().unwrap();
+		let password_path = temp_dir.path().join("smtp_pw");
+		std::fs::write(&password_path, "file-password\n").unwrap();
+		std::env::set_var("TEST_TRIGGER_SMTP_USERNAME", "env-user");
+
+		let trigger_path = temp_dir.path().join("email.json");
+		let content = serde_json::json!({
+			"name": "email",
+			"trigger_type": "email",
+			"config": {
+				"host": "smtp.example.com",
+				"port": 465,
+				"username": {"type": "env", "name": "TEST_TRIGGER_SMTP_USERNAME"},
+				"password": {"type": "file", "path": password_path.display().to_string()},
+				"message": {"title": "Alert", "body": "Test message"},
+				"sender": "sender@example.com",
+				"recipients": ["recipient@example.com"]
+			}
+		});
+		std::fs::write(&trigger_path, content.to_string()).unwrap();
+
+		let trigger = Trigger::load_from_path(&trigger_path).await.unwrap();
+		std::env::remove_var("TEST_TRIGGER_SMTP_USERNAME");
+
+		if let TriggerTypeConfig::Email {
+			username, password, ..
+		} = &trigger.config
+		{
+			assert_eq!(username.as_str(), "env-user");
+			assert_eq!(password.as_str(), "file-password");
+		} else {
+			panic!("Expected email config");
+		}
+	}
+
+	#[tokio::test]
+	async fn test_resolve_secrets_missing_file() {
+		let trigger = TriggerBuilder::new()
+			.name("slack")
+			.slack("")
+			.url(SecretValue::File("/nonexistent/slack_url".to_string()))
+			.build();
+
+		let error = trigger.resolve_secrets().await.unwrap_err().to_string();
+		assert!(error.contains("failed to resolve Slack URL"));
+		assert!(error.contains("/nonexistent/slack_url"));
+	}
 	#[test]
 	fn test_telegram_max_message_length() {
 		let max_body_length = Trigger {
@@ -1443,16 +2245,100 @@ mod tests {
 				)),
 				chat_id: "1730223038".to_string(),
 				disable_web_preview: Some(true),
+				message_thread_id: None,
+				parse_mode: None,
 				message: NotificationMessage {
 					title: "Test".to_string(),
 					body: "x".repeat(TELEGRAM_MAX_BODY_LENGTH + 1), // Exceeds max length
 				},
 				retry_policy: RetryConfig::default(),
+				timeout_ms: None,
 			},
+			severity: None,
+			labels: HashMap::new(),
+			batch_window_ms: None,
+			enabled: None,
 		};
 		assert!(max_body_length.validate().is_err());
 	}
 
+	#[test]
+	fn test_discord_embed_validation() {
+		let embed = DiscordEmbed {
+			title: Some("Transfer on ${monitor.name}".to_string()),
+			color: Some(0x00FF00),
+			fields: vec![DiscordEmbedField {
+				name: "Amount".to_string(),
+				value: "${events.0.args.value}".to_string(),
+				inline: true,
+			}],
+		};
+
+		let valid_trigger = TriggerBuilder::new()
+			.name("test_discord")
+			.discord("https://discord.com/api/webhooks/xxx")
+			.discord_embed(embed.clone())
+			.build();
+		assert!(valid_trigger.validate().is_ok());
+
+		// Test empty embed title
+		let invalid_title = TriggerBuilder::new()
+			.name("test_discord")
+			.discord("https://discord.com/api/webhooks/xxx")
+			.discord_embed(DiscordEmbed {
+				title: Some(" ".to_string()),
+				..embed.clone()
+			})
+			.build();
+		assert!(invalid_title.validate().is_err());
+
+		// Test invalid color
+		let invalid_color = TriggerBuilder::new()
+			.name("test_discord")
+			.discord("https://discord.com/api/webhooks/xxx")
+			.discord_embed(DiscordEmbed {
+				color: Some(DISCORD_MAX_EMBED_COLOR + 1),
+				..embed.clone()
+			})
+			.build();
+		assert!(invalid_color.validate().is_err());
+
+		// Test too many fields
+		let too_many_fields = TriggerBuilder::new()
+			.name("test_discord")
+			.discord("https://discord.com/api/webhooks/xxx")
+			.discord_embed(DiscordEmbed {
+				fields: vec![embed.fields[0].clone(); DISCORD_MAX_EMBED_FIELDS + 1],
+				..embed.clone()
+			})
+			.build();
+		assert!(too_many_fields.validate().is_err());
+
+		// Test empty field value
+		let empty_field = TriggerBuilder::new()
+			.name("test_discord")
+			.discord("https://discord.com/api/webhooks/xxx")
+			.discord_embed(DiscordEmbed {
+				fields: vec![DiscordEmbedField {
+					name: "Amount".to_string(),
+					value: "".to_string(),
+					inline: false,
+				}],
+				..embed.clone()
+			})
+			.build();
+		assert!(empty_field.validate().is_err());
+
+		// Embed descriptions may exceed the plain content limit
+		let long_body = TriggerBuilder::new()
+			.name("test_discord")
+			.discord("https://discord.com/api/webhooks/xxx")
+			.discord_embed(embed)
+			.message("Test", &"z".repeat(DISCORD_MAX_BODY_LENGTH + 1))
+			.build();
+		assert!(long_body.validate().is_ok());
+	}
+
 	#[test]
 	fn test_discord_max_message_length() {
 		let max_body_length = Trigger {
@@ -1466,12 +2352,81 @@ mod tests {
 					title: "Test".to_string(),
 					body: "z".repeat(DISCORD_MAX_BODY_LENGTH + 1), // Exceeds max length
 				},
+				embed: None,
 				retry_policy: RetryConfig::default(),
+				timeout_ms: None,
 			},
+			severity: None,
+			labels: HashMap::new(),
+			batch_window_ms: None,
+			enabled: None,
 		};
 		assert!(max_body_length.validate().is_err());
 	}
 
+	#[tokio::test]
+	async fn test_load_all_mixed_formats() {
+		let temp_dir = TempDir::new().unwrap();
+		let script_path = temp_dir.path().join("notify.py");
+		fs::write(&script_path, "print('notified')").unwrap();
+
+		let toml_config = format!(
+			r#"
+			[slack_alert]
+			name = "SlackAlert"
+			trigger_type = "slack"
+
+			[slack_alert.config]
+			slack_url = {{ type = "plain", value = "https://hooks.slack.com/services/xxx" }}
+			message = {{ title = "Alert", body = "Test message" }}
+
+			[script_alert]
+			name = "ScriptAlert"
+			trigger_type = "script"
+
+			[script_alert.config]
+			language = "Python"
+			script_path = "{}"
+			timeout_ms = 1000
+			"#,
+			script_path.display()
+		);
+		let yaml_config = r#"
+discord_alert:
+  name: DiscordAlert
+  trigger_type: discord
+  config:
+    discord_url:
+      type: plain
+      value: https://discord.com/api/webhooks/xxx
+    message:
+      title: Alert
+      body: Test message
+"#;
+
+		fs::write(temp_dir.path().join("triggers.toml"), toml_config).unwrap();
+		fs::write(temp_dir.path().join("triggers.yml"), yaml_config).unwrap();
+
+		let triggers: HashMap<String, Trigger> =
+			Trigger::load_all(Some(temp_dir.path())).await.unwrap();
+
+		assert_eq!(triggers.len(), 3);
+		assert_eq!(triggers["slack_alert"].trigger_type, TriggerType::Slack);
+		assert_eq!(triggers["discord_alert"].trigger_type, TriggerType::Discord);
+		assert_eq!(triggers["script_alert"].trigger_type, TriggerType::Script);
+		match &triggers["script_alert"].config {
+			TriggerTypeConfig::Script {
+				language,
+				timeout_ms,
+				..
+			} => {
+				assert_eq!(*language, ScriptLanguage::Python);
+				assert_eq!(*timeout_ms, 1000);
+			}
+			_ => panic!("Expected Script config"),
+		}
+	}
+
 	#[tokio::test]
 	async fn test_load_all_duplicate_trigger_name() {
 		let temp_dir = TempDir::new().unwrap();
@@ -1523,4 +2478,66 @@ mod tests {
 			assert!(err.message.contains("Duplicate trigger name found"));
 		}
 	}
+
+	#[tokio::test]
+	async fn test_load_all_severity_and_labels() {
+		let temp_dir = TempDir::new().unwrap();
+		let content = serde_json::json!({
+			"critical_alert": {
+				"name": "CriticalAlert",
+				"trigger_type": "slack",
+				"severity": "critical",
+				"labels": {"team": "treasury", "environment": "production"},
+				"config": {
+					"slack_url": {"type": "plain", "value": "https://hooks.slack.com/services/xxx"},
+					"message": {"title": "Alert", "body": "Test message"}
+				}
+			},
+			"default_alert": {
+				"name": "DefaultAlert",
+				"trigger_type": "slack",
+				"config": {
+					"slack_url": {"type": "plain", "value": "https://hooks.slack.com/services/xxx"},
+					"message": {"title": "Alert", "body": "Test message"}
+				}
+			}
+		});
+		fs::write(temp_dir.path().join("triggers.json"), content.to_string()).unwrap();
+
+		let triggers: HashMap<String, Trigger> =
+			Trigger::load_all(Some(temp_dir.path())).await.unwrap();
+
+		let critical = &triggers["critical_alert"];
+		assert_eq!(critical.severity, Some(NotificationSeverity::Critical));
+		assert_eq!(critical.labels["team"], "treasury");
+		assert_eq!(
+			critical.metric_label_values(),
+			vec!["critical", "treasury", "production"]
+		);
+		assert_eq!(
+			critical.variables()["trigger.labels.environment"],
+			"production"
+		);
+
+		let default = &triggers["default_alert"];
+		assert_eq!(default.severity, None);
+		assert!(default.labels.is_empty());
+		assert_eq!(default.metric_label_values(), vec!["none", "", ""]);
+		assert!(default.variables().is_empty());
+	}
+
+	#[test]
+	fn test_validate_batch_window() {
+		let trigger = |batch_window_ms| {
+			TriggerBuilder::new()
+				.name("digest")
+				.slack("https://hooks.slack.com/services/xxx")
+				.message("Alert", "Test message")
+				.batch_window_ms(batch_window_ms)
+				.build()
+		};
+
+		assert!(trigger(60_000).validate().is_ok());
+		assert!(trigger(0).validate().is_err());
+	}
 }
@@ -10,8 +10,16 @@ mod network;
 mod trigger;
 
 pub use monitor::{
-	AddressWithSpec, EventCondition, FunctionCondition, MatchConditions, Monitor, ScriptLanguage,
-	TransactionCondition, TransactionStatus, TriggerConditions, SCRIPT_LANGUAGE_EXTENSIONS,
+	AddressMatchMode, AddressWithSpec, BalanceCondition, BlockCondition, ConditionLogic,
+	Eip712Field, Eip712Schema, EventCondition, FunctionCondition, MatchConditions, MatchSeverity,
+	Monitor, RpcCall, ScriptLanguage, StateCondition, TokenStandard, TokenTransferCondition,
+	TransactionCondition, TransactionStatus, TriggerConditions, MAX_PRE_FILTER_RPC_CALLS,
+	MAX_STATE_CONDITIONS, SCRIPT_LANGUAGE_EXTENSIONS,
+};
+pub use network::{AdaptivePollingConfig, Network, RpcUrl};
+pub use trigger::{
+	DiscordEmbed, DiscordEmbedField, FileFormat, KafkaSaslConfig, KafkaSaslMechanism,
+	NotificationMessage, NotificationSeverity, OpsgeniePriority, OpsgenieRegion, TelegramParseMode,
+	Trigger, TriggerType, TriggerTypeConfig, WebhookSignatureAlgorithm,
+	NOTIFICATION_METRIC_LABEL_KEYS,
 };
-pub use network::{Network, RpcUrl};
-pub use trigger::{NotificationMessage, Trigger, TriggerType, TriggerTypeConfig};
@@ -1,6 +1,10 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
 
-use crate::models::{blockchain::ContractSpec, ChainConfiguration};
+use crate::models::{blockchain::ContractSpec, ChainConfiguration, Network};
 
 /// Configuration for monitoring specific blockchain activity.
 ///
@@ -11,7 +15,7 @@ use crate::models::{blockchain::ContractSpec, ChainConfiguration};
 /// - Triggers conditions refers to a custom filter script that being executed apply extra filters
 ///   to the matched transactions before triggering the notifications
 /// - Triggers to execute when conditions are met
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Monitor {
 	/// Unique name identifying this monitor
@@ -23,6 +27,22 @@ pub struct Monitor {
 	/// Whether this monitor is currently paused
 	pub paused: bool,
 
+	/// Time until which this monitor is paused, after which it is active even if `paused` is set
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub paused_until: Option<DateTime<Utc>>,
+
+	/// Time from which this monitor matches blocks, compared to the block timestamps
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub active_from: Option<DateTime<Utc>>,
+
+	/// Time until which this monitor matches blocks, compared to the block timestamps
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub active_until: Option<DateTime<Utc>>,
+
+	/// Cron schedule evaluating this monitor, overriding the schedule of its networks
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub cron_schedule: Option<String>,
+
 	/// Contract addresses to monitor, optionally with their contract specs
 	pub addresses: Vec<AddressWithSpec>,
 
@@ -32,27 +52,314 @@ pub struct Monitor {
 	/// Conditions that should be met prior to triggering notifications
 	pub trigger_conditions: Vec<TriggerConditions>,
 
+	/// How the results of the trigger conditions are combined
+	#[serde(default)]
+	pub condition_logic: ConditionLogic,
+
 	/// IDs of triggers to execute when conditions match
 	pub triggers: Vec<String>,
 
+	/// IDs of triggers to execute instead of `triggers` for near misses, matching only through
+	/// the `warn_threshold_expression` of their conditions
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub warn_triggers: Vec<String>,
+
 	/// Chain-specific configurations
 	#[serde(default)]
 	pub chain_configurations: Vec<ChainConfiguration>,
+
+	/// JSON-RPC calls made for every evaluated block before filtering it
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub pre_filter_rpc_calls: Vec<RpcCall>,
+
+	/// Whether the matches of the monitor on all its networks within the aggregation window
+	/// are notified together, instead of one notification per match
+	#[serde(default)]
+	pub aggregate_across_networks: bool,
+
+	/// Window in milliseconds, from the first match of a batch, during which further matches
+	/// join it (defaults to 5 seconds)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub aggregation_window_ms: Option<u64>,
+
+	/// Maximum number of matches notified over any rolling hour, further matches being dropped
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub max_notifications_per_hour: Option<u32>,
+
+	/// Whether the number of matches dropped by `max_notifications_per_hour` is notified once
+	/// the hour frees up, in the `throttle.suppressed_count` variable
+	#[serde(default)]
+	pub throttle_summary: bool,
+
+	/// Window in milliseconds after a notified match during which the further matches with
+	/// the same `dedup_key` are dropped as duplicates
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub cooldown_ms: Option<u64>,
+
+	/// Template identifying duplicate matches from their variables, e.g. `${transaction.to}`
+	/// (defaults to the monitor, all its matches being duplicates during the cooldown)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub dedup_key: Option<String>,
+
+	/// Whether the dedup keys in cooldown are persisted in the block storage, so that the
+	/// cooldown survives restarts
+	#[serde(default)]
+	pub persist_cooldown: bool,
+
+	/// Whether the sender of the matched EVM transactions is recovered from their signature and
+	/// compared with the `from` address reported by the RPC
+	#[serde(default)]
+	pub verify_signature: bool,
+
+	/// Whether matches whose recovered sender isn't the reported one are dropped, instead of
+	/// being notified with `transaction.sender_verified` set to `false`
+	#[serde(default)]
+	pub strict_signature_verification: bool,
+
+	/// Whether the monitor alerts once when it starts matching the blocks of a network, and
+	/// notifies `resolve_trigger` once a block of the network no longer matches it
+	#[serde(default)]
+	pub notify_on_resolve: bool,
+
+	/// ID of the trigger to execute when the alert of a monitor with `notify_on_resolve`
+	/// resolves (defaults to `triggers`)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub resolve_trigger: Option<String>,
+
+	/// Results of the pre-filter RPC calls for the block being evaluated, by call name
+	///
+	/// Set while processing a block, so that matches carry the results they were found with.
+	#[serde(
+		default,
+		skip_deserializing,
+		skip_serializing_if = "BTreeMap::is_empty"
+	)]
+	pub rpc_results: BTreeMap<String, Value>,
+}
+
+impl Monitor {
+	/// Checks whether an on-chain address matches any of the monitored addresses
+	///
+	/// A monitor without addresses matches every address.
+	pub fn matches_address(&self, address: &str, normalize: impl Fn(&str) -> String) -> bool {
+		self.addresses.is_empty()
+			|| self
+				.addresses
+				.iter()
+				.any(|entry| entry.matches(address, &normalize))
+	}
+
+	/// Returns whether the monitor is paused at a given time
+	///
+	/// A monitor with `paused_until` set is paused before that time and active from it on,
+	/// whatever the value of `paused`. Otherwise it is paused when `paused` is set.
+	pub fn is_paused_at(&self, now: DateTime<Utc>) -> bool {
+		match self.paused_until {
+			Some(paused_until) => now < paused_until,
+			None => self.paused,
+		}
+	}
+
+	/// Returns whether the monitor is paused without a time to resume at
+	///
+	/// Monitors paused until a time are still watched, so that they resume once it passes.
+	pub fn is_paused_indefinitely(&self) -> bool {
+		self.paused && self.paused_until.is_none()
+	}
+
+	/// Returns whether a time is within the active window of the monitor
+	///
+	/// The window starts at `active_from` included and ends at `active_until` excluded, and is
+	/// unbounded on the sides that aren't set. Blocks are checked with their own timestamp, so
+	/// that processing past blocks gives the same matches as processing them live.
+	pub fn is_active_at(&self, time: DateTime<Utc>) -> bool {
+		self.active_from
+			.is_none_or(|active_from| time >= active_from)
+			&& self
+				.active_until
+				.is_none_or(|active_until| time < active_until)
+	}
+
+	/// Returns the cron schedule evaluating this monitor on a network
+	///
+	/// This is the monitor's own `cron_schedule` when set, and the network's otherwise.
+	pub fn effective_cron_schedule<'a>(&'a self, network: &'a Network) -> &'a str {
+		self.cron_schedule
+			.as_deref()
+			.unwrap_or(&network.cron_schedule)
+	}
+
+	/// Returns whether any function, event or transaction condition has a warn threshold
+	/// expression
+	pub fn has_warn_thresholds(&self) -> bool {
+		let conditions = &self.match_conditions;
+		conditions
+			.functions
+			.iter()
+			.any(|condition| condition.warn_threshold_expression.is_some())
+			|| conditions
+				.events
+				.iter()
+				.any(|condition| condition.warn_threshold_expression.is_some())
+			|| conditions
+				.transactions
+				.iter()
+				.any(|condition| condition.warn_threshold_expression.is_some())
+	}
+
+	/// Returns the monitor matching the near misses of this monitor, or None if none of its
+	/// conditions has a warn threshold expression
+	///
+	/// Conditions with a warn threshold expression are evaluated with it instead of their
+	/// expression, the other conditions are kept as is.
+	pub fn warn_threshold_monitor(&self) -> Option<Monitor> {
+		if !self.has_warn_thresholds() {
+			return None;
+		}
+
+		let mut monitor = self.clone();
+		let conditions = &mut monitor.match_conditions;
+		for condition in &mut conditions.functions {
+			if let Some(expression) = condition.warn_threshold_expression.take() {
+				condition.expression = Some(expression);
+			}
+		}
+		for condition in &mut conditions.events {
+			if let Some(expression) = condition.warn_threshold_expression.take() {
+				condition.expression = Some(expression);
+			}
+		}
+		for condition in &mut conditions.transactions {
+			if let Some(expression) = condition.warn_threshold_expression.take() {
+				condition.expression = Some(expression);
+			}
+		}
+		Some(monitor)
+	}
+
+	/// Returns whether the monitor opted in to matching the internal calls of EVM transactions
+	pub fn includes_internal_transactions(&self) -> bool {
+		self.chain_configurations.iter().any(|configuration| {
+			configuration
+				.evm
+				.as_ref()
+				.is_some_and(|evm| evm.include_internal_transactions)
+		})
+	}
+}
+
+/// Maximum number of pre-filter RPC calls of a monitor
+pub const MAX_PRE_FILTER_RPC_CALLS: usize = 5;
+
+/// Maximum number of state conditions of a monitor, each making a call per match
+pub const MAX_STATE_CONDITIONS: usize = 5;
+
+/// Prefixes of the JSON-RPC methods that can't be called as pre-filter RPC calls, since they
+/// sign, submit or administer rather than read (compared case-insensitively)
+const DENIED_RPC_METHOD_PREFIXES: &[&str] = &[
+	"eth_send",
+	"eth_sign",
+	"personal_",
+	"admin_",
+	"miner_",
+	"engine_",
+	"author_",
+	"sendtransaction",
+	"requestairdrop",
+];
+
+/// JSON-RPC call whose result is made available to the conditions of a monitor
+///
+/// The result is available on the right side of the conditions of expressions as `${rpc.<name>}`,
+/// optionally followed by a path into the result (e.g. `${rpc.balance.amount}`), and to trigger
+/// condition scripts in the `rpc_results` of the matched monitor.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RpcCall {
+	/// Name of the variable holding the result
+	pub name: String,
+
+	/// JSON-RPC method to call
+	pub method: String,
+
+	/// Parameters of the call
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub params: Option<Value>,
+}
+
+impl RpcCall {
+	/// Returns whether a JSON-RPC method can be called as a pre-filter RPC call
+	pub fn is_method_allowed(method: &str) -> bool {
+		let method = method.to_lowercase();
+		!method.is_empty()
+			&& !DENIED_RPC_METHOD_PREFIXES
+				.iter()
+				.any(|prefix| method.starts_with(prefix))
+	}
 }
 
 /// Contract address with optional ABI for decoding transactions and events
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AddressWithSpec {
 	/// Contract address in the network's native format
 	pub address: String,
 
+	/// How `address` is compared against addresses seen on chain
+	#[serde(default, skip_serializing_if = "AddressMatchMode::is_exact")]
+	pub match_mode: AddressMatchMode,
+
 	/// Optional contract spec for decoding contract interactions
 	pub contract_spec: Option<ContractSpec>,
 }
 
+impl AddressWithSpec {
+	/// Checks whether an on-chain address matches this entry
+	///
+	/// Both sides are normalized with `normalize` before being compared, so matching is
+	/// case-insensitive for networks whose normalization lowercases addresses.
+	pub fn matches(&self, address: &str, normalize: impl Fn(&str) -> String) -> bool {
+		self.match_mode
+			.matches(&normalize(&self.address), &normalize(address))
+	}
+}
+
+/// Strategy used to compare a monitored address against on-chain addresses
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AddressMatchMode {
+	/// The address must be equal to the pattern
+	#[default]
+	Exact,
+	/// The address must start with the pattern
+	Prefix,
+	/// The address must end with the pattern
+	Suffix,
+	/// The address must match the pattern as a glob (e.g. `0xabcd*`)
+	Glob,
+}
+
+impl AddressMatchMode {
+	/// Returns true for the default exact match mode
+	pub fn is_exact(&self) -> bool {
+		*self == AddressMatchMode::Exact
+	}
+
+	/// Compares an already normalized address against an already normalized pattern
+	pub fn matches(&self, pattern: &str, address: &str) -> bool {
+		match self {
+			AddressMatchMode::Exact => address == pattern,
+			AddressMatchMode::Prefix => address.starts_with(pattern),
+			AddressMatchMode::Suffix => address.ends_with(pattern),
+			AddressMatchMode::Glob => glob::Pattern::new(pattern)
+				.map(|glob| glob.matches(address))
+				.unwrap_or(false),
+		}
+	}
+}
+
 /// Collection of conditions that can trigger a monitor
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct MatchConditions {
 	/// Function calls to match
@@ -63,10 +370,96 @@ pub struct MatchConditions {
 
 	/// Transaction states to match
 	pub transactions: Vec<TransactionCondition>,
+
+	/// Token transfers to match, expanded into event conditions on the transfer events of the
+	/// token standards (EVM only)
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub token_transfers: Vec<TokenTransferCondition>,
+
+	/// Block properties to match, evaluated once per block (EVM and Stellar only)
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub block_conditions: Vec<BlockCondition>,
+
+	/// Native balances of the monitored addresses to match, read at every block (EVM and
+	/// Stellar only)
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub balance_conditions: Vec<BalanceCondition>,
+
+	/// Conditions on the state of the matched contract, all of which must hold for a
+	/// transaction match to be emitted (EVM and Stellar only)
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub state_conditions: Vec<StateCondition>,
+}
+
+impl MatchConditions {
+	/// Returns whether events are matched, through event or token transfer conditions
+	pub fn has_event_conditions(&self) -> bool {
+		!self.events.is_empty() || !self.token_transfers.is_empty()
+	}
+
+	/// Returns whether only blocks are matched, the monitor having block or balance
+	/// conditions but no function, event or transaction conditions
+	///
+	/// Transactions of a block are only matched by monitors with conditions on them, as a
+	/// monitor without any condition would match all of them.
+	pub fn is_block_only(&self) -> bool {
+		(!self.block_conditions.is_empty() || !self.balance_conditions.is_empty())
+			&& self.functions.is_empty()
+			&& !self.has_event_conditions()
+			&& self.transactions.is_empty()
+	}
+}
+
+/// Condition for matching block properties
+///
+/// A block matching any of the block conditions of a monitor produces a single match, without
+/// a transaction.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BlockCondition {
+	/// Expression over the properties of the block (e.g., "gas_used > 25000000")
+	pub expression: String,
+}
+
+/// Condition on the native balance of the monitored addresses
+///
+/// The balance of each monitored address is read at every block, and compared with its balance
+/// at the previous block processed for the monitor. An address whose balance matches any of
+/// the balance conditions of a monitor produces a match without a transaction, whether or not
+/// a transaction of the block involves it.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BalanceCondition {
+	/// Expression over the balance of the address (e.g., "balance < 1000000000000000000 AND
+	/// previous_balance >= 1000000000000000000")
+	pub expression: String,
+}
+
+/// Condition on the state of the matched contract, read with a call made for each match
+///
+/// On EVM the function is called with `eth_call` at the matched block, on Stellar with
+/// `simulateTransaction` at the latest ledger. The decoded outputs are the variables of the
+/// expression.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StateCondition {
+	/// Signature of the called function (e.g., "getReserves() returns (uint112 reserve0,
+	/// uint112 reserve1, uint32 timestamp)" on EVM or "balance(Address)" on Stellar)
+	///
+	/// On EVM, outputs without a name are named `result` when alone and `result_<index>`
+	/// otherwise. On Stellar, the output is named `result`.
+	pub signature: String,
+
+	/// Arguments of the call, in the order of the function parameters
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub args: Vec<String>,
+
+	/// Expression over the outputs of the call (e.g., "reserve0 > 1000000")
+	pub expression: String,
 }
 
 /// Condition for matching contract function calls
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct FunctionCondition {
 	/// Function signature (e.g., "transfer(address,uint256)")
@@ -74,10 +467,52 @@ pub struct FunctionCondition {
 
 	/// Optional expression to filter function parameters
 	pub expression: Option<String>,
+
+	/// Optional looser expression matching near misses of `expression`, notified with the
+	/// warning severity
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub warn_threshold_expression: Option<String>,
+
+	/// Optional EIP-712 typed data carried by an argument of the call, whose decoded domain and
+	/// message fields are available to the expressions as `eip712.domain.<field>` and
+	/// `eip712.message.<field>` (EVM only)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub eip712_schema: Option<Eip712Schema>,
+}
+
+/// Schema of EIP-712 typed data carried by an argument of a function call
+///
+/// The argument is of type `bytes` and holds the domain and the message ABI-encoded as
+/// `abi.encode(domain, message)`, each struct being encoded as a tuple of its fields.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Eip712Schema {
+	/// Name of the argument carrying the typed data, or its position (e.g., "0") for
+	/// contracts without an ABI
+	pub param: String,
+
+	/// Name of the struct type of the message (e.g., "Mail")
+	pub primary_type: String,
+
+	/// Struct types by name, including the `EIP712Domain` type of the domain
+	pub types: BTreeMap<String, Vec<Eip712Field>>,
+}
+
+/// Field of an EIP-712 struct type
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Eip712Field {
+	/// Name of the field
+	pub name: String,
+
+	/// Type of the field, either a Solidity type or the name of a struct type, optionally
+	/// followed by array brackets (e.g., "address", "Person", "Person[]")
+	#[serde(rename = "type")]
+	pub kind: String,
 }
 
 /// Condition for matching contract events
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct EventCondition {
 	/// Event signature (e.g., "Transfer(address,address,uint256)")
@@ -85,10 +520,133 @@ pub struct EventCondition {
 
 	/// Optional expression to filter event parameters
 	pub expression: Option<String>,
+
+	/// Optional looser expression matching near misses of `expression`, notified with the
+	/// warning severity
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub warn_threshold_expression: Option<String>,
+}
+
+/// Token standard of a token transfer
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenStandard {
+	/// Fungible tokens, whose `Transfer` event has a non-indexed `value`
+	Erc20,
+	/// Non-fungible tokens, whose `Transfer` event has an indexed `tokenId`
+	Erc721,
+	/// Multi tokens, transferred through `TransferSingle` and `TransferBatch` events
+	Erc1155,
+}
+
+impl TokenStandard {
+	/// Returns the token standards in the order their transfer events are matched
+	pub fn all() -> [TokenStandard; 3] {
+		[
+			TokenStandard::Erc20,
+			TokenStandard::Erc721,
+			TokenStandard::Erc1155,
+		]
+	}
+}
+
+/// Condition for matching token transfers by their decoded fields
+///
+/// A token transfer condition is a preset expanding into event conditions on the transfer
+/// events of the selected token standards, see [`TokenTransferCondition::event_conditions`].
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TokenTransferCondition {
+	/// Token standard of the transfers to match, any standard when unset
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub token_standard: Option<TokenStandard>,
+
+	/// Sender of the transfers to match
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub from: Option<String>,
+
+	/// Recipient of the transfers to match
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub to: Option<String>,
+
+	/// Minimum amount of the transfers to match, as a decimal number of base units. ERC-721
+	/// transfers move a single token and ERC-1155 batch transfers are only matched when unset
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub min_amount: Option<String>,
+}
+
+impl TokenTransferCondition {
+	/// Expands the condition into event conditions on the transfer events of its standards
+	///
+	/// # Returns
+	/// * `Vec<(TokenStandard, EventCondition)>` - Event conditions along with the token standard
+	///   of the event they apply to
+	pub fn event_conditions(&self) -> Vec<(TokenStandard, EventCondition)> {
+		let mut address_parts = Vec::new();
+		if let Some(from) = &self.from {
+			address_parts.push(format!("from == {}", from));
+		}
+		if let Some(to) = &self.to {
+			address_parts.push(format!("to == {}", to));
+		}
+		let with_amount = |amount_param: &str| {
+			let mut parts = address_parts.clone();
+			if let Some(min_amount) = &self.min_amount {
+				parts.push(format!("{} >= {}", amount_param, min_amount));
+			}
+			parts
+		};
+		let condition = |signature: &str, parts: Vec<String>| EventCondition {
+			signature: signature.to_string(),
+			expression: (!parts.is_empty()).then(|| parts.join(" AND ")),
+			warn_threshold_expression: None,
+		};
+		let single_token = self
+			.min_amount
+			.as_deref()
+			.is_none_or(|min_amount| matches!(min_amount.trim_start_matches('0'), "" | "1"));
+
+		let mut conditions = Vec::new();
+		for standard in TokenStandard::all() {
+			if self.token_standard.is_some_and(|s| s != standard) {
+				continue;
+			}
+			match standard {
+				TokenStandard::Erc20 => conditions.push((
+					standard,
+					condition("Transfer(address,address,uint256)", with_amount("value")),
+				)),
+				TokenStandard::Erc721 if single_token => conditions.push((
+					standard,
+					condition("Transfer(address,address,uint256)", address_parts.clone()),
+				)),
+				TokenStandard::Erc721 => {}
+				TokenStandard::Erc1155 => {
+					conditions.push((
+						standard,
+						condition(
+							"TransferSingle(address,address,address,uint256,uint256)",
+							with_amount("value"),
+						),
+					));
+					if self.min_amount.is_none() {
+						conditions.push((
+							standard,
+							condition(
+								"TransferBatch(address,address,address,uint256[],uint256[])",
+								address_parts.clone(),
+							),
+						));
+					}
+				}
+			}
+		}
+		conditions
+	}
 }
 
 /// Condition for matching transaction states
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TransactionCondition {
 	/// Required transaction status
@@ -96,10 +654,40 @@ pub struct TransactionCondition {
 
 	/// Optional expression to filter transaction properties
 	pub expression: Option<String>,
+
+	/// Optional looser expression matching near misses of `expression`, notified with the
+	/// warning severity
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub warn_threshold_expression: Option<String>,
+
+	/// Whether only contract creation transactions, without a recipient, match (EVM only)
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub is_contract_creation: bool,
+}
+
+/// Severity of a monitor match
+#[derive(Debug, Copy, Clone, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchSeverity {
+	/// The match satisfies the expressions of the monitor conditions
+	#[default]
+	Alert,
+	/// Near miss, only satisfying the warn threshold expressions of the monitor conditions
+	Warning,
+}
+
+impl MatchSeverity {
+	/// Returns the name of the severity, as used in notification templates
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			MatchSeverity::Alert => "alert",
+			MatchSeverity::Warning => "warning",
+		}
+	}
 }
 
 /// Possible transaction execution states
-#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub enum TransactionStatus {
 	/// Match any transaction status
@@ -111,7 +699,7 @@ pub enum TransactionStatus {
 }
 
 /// Conditions that should be met prior to triggering notifications
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TriggerConditions {
 	/// The path to the script
@@ -126,18 +714,53 @@ pub struct TriggerConditions {
 
 	/// The timeout of the script
 	pub timeout_ms: u32,
+
+	/// Maximum memory of the script process in megabytes, unbounded when unset
+	///
+	/// Only enforced on Unix systems.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub max_memory_mb: Option<u64>,
+
+	/// Maximum size in bytes of the output of the script, on stdout and stderr each,
+	/// defaults to 1 MiB
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub max_output_bytes: Option<u64>,
+
+	/// Whether the block of the match is passed to the script along with the match
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub include_block_context: bool,
 }
+/// Logic used to combine the results of multiple trigger condition scripts
+///
+/// A trigger condition script returning `true` votes to filter the match out.
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Default, JsonSchema)]
+pub enum ConditionLogic {
+	/// Filter the match out if any script returns `true`
+	#[default]
+	Or,
+	/// Filter the match out only if every script returns `true`
+	And,
+}
+
 /// The possible languages of the script
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Hash, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Hash, Eq, JsonSchema)]
 pub enum ScriptLanguage {
 	JavaScript,
 	Python,
 	Bash,
+	/// WebAssembly module run in a sandboxed runtime, see
+	/// [`WasmScriptExecutor`](crate::services::trigger::WasmScriptExecutor)
+	Wasm,
+	/// Language whose executor is registered at startup, see
+	/// [`register_script_executor`](crate::services::trigger::register_script_executor)
+	#[serde(untagged)]
+	Custom(String),
 }
 
-/// Static mapping of script languages to their file extensions
+/// Static mapping of the built-in script languages to their file extensions
 pub const SCRIPT_LANGUAGE_EXTENSIONS: &[(&ScriptLanguage, &str)] = &[
 	(&ScriptLanguage::Python, "py"),
 	(&ScriptLanguage::JavaScript, "js"),
 	(&ScriptLanguage::Bash, "sh"),
+	(&ScriptLanguage::Wasm, "wasm"),
 ];
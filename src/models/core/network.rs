@@ -1,11 +1,13 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-use crate::models::{BlockChainType, SecretValue};
+use crate::models::{BlockChainType, EVMBlockTag, EVMTraceMethod, SecretValue};
 
 /// Configuration for connecting to and interacting with a blockchain network.
 ///
 /// Defines connection details and operational parameters for a specific blockchain network.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Network {
 	/// Type of blockchain (EVM, Stellar, etc)
@@ -35,23 +37,113 @@ pub struct Network {
 	/// Cron expression for how often to check for new blocks
 	pub cron_schedule: String,
 
+	/// IANA name of the timezone the cron schedules of this network are evaluated in, such as
+	/// `America/New_York` (defaults to UTC)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub timezone: Option<String>,
+
 	/// Maximum number of past blocks to process
 	pub max_past_blocks: Option<u64>,
 
 	/// Whether to store processed blocks
 	pub store_blocks: Option<bool>,
+
+	/// Time in milliseconds without a new processed block after which the network is
+	/// considered stalled
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub stall_threshold_ms: Option<u64>,
+
+	/// Trigger to execute when the network stalls
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub stall_trigger: Option<String>,
+
+	/// Maximum number of monitors evaluated concurrently within a block (defaults to 1)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub monitor_concurrency: Option<usize>,
+
+	/// Number of blocks processed between checkpoints of the last processed block while
+	/// catching up, checkpointing only once all blocks are processed when unset
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub catchup_checkpoint_blocks: Option<u64>,
+
+	/// Whether blocks of this network are watched (defaults to true)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub enabled: Option<bool>,
+
+	/// Tracing method supported by the RPC endpoints of an EVM network, required by monitors
+	/// including internal transactions
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub trace_method: Option<EVMTraceMethod>,
+
+	/// Number of transactions and events requested per page from the RPC endpoints of a Stellar
+	/// network (defaults to 200, the maximum)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub page_size: Option<u32>,
+
+	/// Whether pending transactions of an EVM network are watched through the
+	/// `newPendingTransactions` subscription of its `ws_rpc` endpoints (defaults to false)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub mempool: Option<bool>,
+
+	/// Block tag of the most recent block processed on an EVM network. With `safe` or
+	/// `finalized`, blocks are processed up to the tagged block and `confirmation_blocks` is
+	/// ignored (defaults to `latest`)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub block_tag: Option<EVMBlockTag>,
+
+	/// Whether the number of past blocks to process is derived from the time elapsed since the
+	/// last processed block when `max_past_blocks` is unset (defaults to false)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub auto_past_blocks: Option<bool>,
+
+	/// Maximum number of past blocks processed with `auto_past_blocks` (defaults to 10000)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub auto_past_blocks_limit: Option<u64>,
+
+	/// Headers sent with every HTTP request to the RPC endpoints, such as an API key or a
+	/// `User-Agent`. Values may reference environment variables as `${VAR}`
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub headers: Option<HashMap<String, String>>,
+
+	/// Maximum number of entries in each cache of blocks, receipts and events fetched from the
+	/// RPC endpoints of an EVM or Stellar network, which expire after one block time (caching
+	/// is disabled when unset or 0)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub rpc_cache_size: Option<usize>,
+
+	/// Maximum size in bytes of a response of the HTTP RPC endpoints, larger responses failing
+	/// the request (defaults to 128 MiB)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub max_response_bytes: Option<usize>,
+
+	/// Polling of new blocks following the observed block time, bounded by a minimum and a
+	/// maximum interval, instead of the network's cron schedule (disabled when unset)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub adaptive_polling: Option<AdaptivePollingConfig>,
 }
 
 /// RPC endpoint configuration with load balancing weight
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct RpcUrl {
-	/// Type of RPC endpoint (e.g. "rpc")
+	/// Type of RPC endpoint (e.g. "rpc"), EVM networks may also tag endpoints as "archive" or
+	/// "full" nodes
 	pub type_: String,
 
 	/// URL of the RPC endpoint (can be a secret value)
 	pub url: SecretValue,
 
-	/// Weight for load balancing (0-100)
+	/// Weight for load balancing (0-100), endpoints with a weight of 0 are only used as fallbacks
 	pub weight: u32,
 }
+
+/// Bounds of the interval between polls of a network with adaptive polling
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AdaptivePollingConfig {
+	/// Minimum interval in milliseconds between two polls
+	pub min_interval_ms: u64,
+
+	/// Maximum interval in milliseconds between two polls
+	pub max_interval_ms: u64,
+}
@@ -3,24 +3,113 @@ use crate::{
 	utils::RetryConfig,
 };
 use email_address::EmailAddress;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
 
 /// Configuration for actions to take when monitored conditions are met.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Trigger {
 	/// Unique name identifying this trigger
 	pub name: String,
 
-	/// Type of trigger (Email, Slack, Webhook, Telegram, Discord, Script)
+	/// Type of trigger (Email, Slack, Webhook, Telegram, Discord, Opsgenie, Script, File, Kafka)
 	pub trigger_type: TriggerType,
 
 	/// Configuration specific to the trigger type
 	pub config: TriggerTypeConfig,
+
+	/// Severity of the notifications sent by this trigger
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub severity: Option<NotificationSeverity>,
+
+	/// Labels describing the notifications sent by this trigger, for downstream routing
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub labels: HashMap<String, String>,
+
+	/// Window in milliseconds over which matches are collected and sent as a single digest,
+	/// instead of one notification per match
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub batch_window_ms: Option<u64>,
+
+	/// Whether this trigger is executed, true when unset
+	///
+	/// A disabled trigger is skipped by every monitor referencing it.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub enabled: Option<bool>,
+}
+
+/// Keys of the trigger labels reported as labels of the notification metrics
+///
+/// Other keys are only available as template variables, keeping the number of metric series
+/// bounded.
+pub const NOTIFICATION_METRIC_LABEL_KEYS: &[&str] = &["team", "environment"];
+
+impl Trigger {
+	/// Returns whether this trigger is executed when a referencing monitor matches
+	pub fn is_enabled(&self) -> bool {
+		self.enabled.unwrap_or(true)
+	}
+
+	/// Returns the template variables describing this trigger
+	///
+	/// These are `trigger.severity` when set and `trigger.labels.<key>` for each label.
+	pub fn variables(&self) -> HashMap<String, String> {
+		let mut variables = HashMap::new();
+		if let Some(severity) = self.severity {
+			variables.insert(
+				"trigger.severity".to_string(),
+				severity.as_str().to_string(),
+			);
+		}
+		for (key, value) in &self.labels {
+			variables.insert(format!("trigger.labels.{}", key), value.clone());
+		}
+		variables
+	}
+
+	/// Returns the values of the metric labels describing this trigger
+	///
+	/// These are the severity, `none` when unset, followed by the value of each label of
+	/// [`NOTIFICATION_METRIC_LABEL_KEYS`], empty when unset.
+	pub fn metric_label_values(&self) -> Vec<&str> {
+		let severity = self.severity.map_or("none", |severity| severity.as_str());
+		std::iter::once(severity)
+			.chain(
+				NOTIFICATION_METRIC_LABEL_KEYS
+					.iter()
+					.map(|key| self.labels.get(*key).map_or("", String::as_str)),
+			)
+			.collect()
+	}
+}
+
+/// Severity of a notification
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationSeverity {
+	/// Informational notification
+	Info,
+	/// Notification that may require attention
+	Warning,
+	/// Notification requiring immediate attention
+	Critical,
+}
+
+impl NotificationSeverity {
+	/// Severity as written in trigger configuration files
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Info => "info",
+			Self::Warning => "warning",
+			Self::Critical => "critical",
+		}
+	}
 }
 
 /// Supported trigger action types
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 #[serde(deny_unknown_fields)]
 pub enum TriggerType {
@@ -34,12 +123,35 @@ pub enum TriggerType {
 	Telegram,
 	/// Send notification to Discord
 	Discord,
+	/// Create an alert in Opsgenie
+	Opsgenie,
 	/// Execute local script
 	Script,
+	/// Append a record to a local file
+	File,
+	/// Produce a record to a Kafka topic
+	Kafka,
+}
+
+impl TriggerType {
+	/// Trigger type as written in trigger configuration files
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Slack => "slack",
+			Self::Email => "email",
+			Self::Webhook => "webhook",
+			Self::Telegram => "telegram",
+			Self::Discord => "discord",
+			Self::Opsgenie => "opsgenie",
+			Self::Script => "script",
+			Self::File => "file",
+			Self::Kafka => "kafka",
+		}
+	}
 }
 
 /// Notification message fields
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct NotificationMessage {
 	/// Notification title or subject
@@ -48,8 +160,157 @@ pub struct NotificationMessage {
 	pub body: String,
 }
 
+/// Opsgenie instance region, selecting the API host alerts are sent to
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OpsgenieRegion {
+	/// api.opsgenie.com
+	#[default]
+	Us,
+	/// api.eu.opsgenie.com
+	Eu,
+}
+
+/// SASL mechanism authenticating Kafka producers
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Default, JsonSchema)]
+pub enum KafkaSaslMechanism {
+	/// Plain username and password
+	#[default]
+	#[serde(rename = "PLAIN")]
+	Plain,
+	/// SCRAM with SHA-256
+	#[serde(rename = "SCRAM-SHA-256")]
+	ScramSha256,
+	/// SCRAM with SHA-512
+	#[serde(rename = "SCRAM-SHA-512")]
+	ScramSha512,
+}
+
+impl KafkaSaslMechanism {
+	/// Mechanism as expected by the `sasl.mechanism` producer property
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Plain => "PLAIN",
+			Self::ScramSha256 => "SCRAM-SHA-256",
+			Self::ScramSha512 => "SCRAM-SHA-512",
+		}
+	}
+}
+
+/// SASL credentials of Kafka producers, sent over TLS
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct KafkaSaslConfig {
+	/// SASL mechanism, defaults to PLAIN
+	#[serde(default)]
+	pub mechanism: KafkaSaslMechanism,
+	/// SASL username
+	pub username: SecretValue,
+	/// SASL password
+	pub password: SecretValue,
+}
+
+/// Formatting mode of Telegram messages
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Default, JsonSchema)]
+pub enum TelegramParseMode {
+	/// Telegram MarkdownV2 formatting
+	#[default]
+	MarkdownV2,
+	/// Telegram HTML formatting
+	#[serde(rename = "HTML")]
+	Html,
+	/// Plain text without formatting
+	None,
+}
+
+/// Algorithm signing the requests of webhook triggers
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookSignatureAlgorithm {
+	/// HMAC-SHA256 keyed with the secret
+	#[default]
+	HmacSha256,
+	/// Ed25519, the secret being the hex or base64 encoded 32-byte private key
+	Ed25519,
+}
+
+/// Rich embed attached to Discord messages
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DiscordEmbed {
+	/// Embed title, defaults to the message title
+	#[serde(default)]
+	pub title: Option<String>,
+	/// Color of the embed sidebar as an RGB integer, e.g. 16711680 (0xFF0000) for red
+	#[serde(default)]
+	pub color: Option<u32>,
+	/// Fields displayed below the embed description
+	#[serde(default)]
+	pub fields: Vec<DiscordEmbedField>,
+}
+
+/// Name/value field of a Discord embed
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DiscordEmbedField {
+	/// Field name
+	pub name: String,
+	/// Field value
+	pub value: String,
+	/// Whether the field is displayed next to other inline fields
+	#[serde(default)]
+	pub inline: bool,
+}
+
+/// Record format used by file triggers
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FileFormat {
+	/// Pretty-printed JSON objects separated by newlines
+	Json,
+	/// One JSON object per line
+	#[default]
+	Jsonl,
+	/// Comma-separated values with a header row
+	Csv,
+}
+
+/// Opsgenie alert priority, from P1 (critical) to P5 (informational)
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Default, JsonSchema)]
+pub enum OpsgeniePriority {
+	P1,
+	P2,
+	#[default]
+	P3,
+	P4,
+	P5,
+}
+
+impl OpsgeniePriority {
+	/// Priority as expected by the Opsgenie Alerts API
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::P1 => "P1",
+			Self::P2 => "P2",
+			Self::P3 => "P3",
+			Self::P4 => "P4",
+			Self::P5 => "P5",
+		}
+	}
+}
+
+impl From<NotificationSeverity> for OpsgeniePriority {
+	fn from(severity: NotificationSeverity) -> Self {
+		match severity {
+			NotificationSeverity::Critical => Self::P1,
+			NotificationSeverity::Warning => Self::P3,
+			NotificationSeverity::Info => Self::P5,
+		}
+	}
+}
+
 /// Type-specific configuration for triggers
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 #[serde(untagged)]
 pub enum TriggerTypeConfig {
@@ -59,9 +320,15 @@ pub enum TriggerTypeConfig {
 		slack_url: SecretValue,
 		/// Notification message
 		message: NotificationMessage,
+		/// Block Kit blocks the message is sent as, instead of a section with the title and body
+		#[serde(default)]
+		blocks: Option<Vec<serde_json::Value>>,
 		/// Retry policy for HTTP requests
 		#[serde(default)]
 		retry_policy: RetryConfig,
+		/// Timeout in milliseconds for a single send, unbounded when unset
+		#[serde(default)]
+		timeout_ms: Option<u32>,
 	},
 	/// Email notification configuration
 	Email {
@@ -76,12 +343,28 @@ pub enum TriggerTypeConfig {
 		/// Notification message
 		message: NotificationMessage,
 		/// Email sender
+		#[schemars(with = "String")]
 		sender: EmailAddress,
 		/// Email recipients
+		#[schemars(with = "Vec<String>")]
 		recipients: Vec<EmailAddress>,
+		/// Carbon copy recipients
+		#[serde(default)]
+		#[schemars(with = "Vec<String>")]
+		cc: Vec<EmailAddress>,
+		/// Blind carbon copy recipients
+		#[serde(default)]
+		#[schemars(with = "Vec<String>")]
+		bcc: Vec<EmailAddress>,
+		/// Whether the message body is HTML, sent as is instead of being rendered from Markdown
+		#[serde(default)]
+		is_html: bool,
 		/// Retry policy for SMTP requests
 		#[serde(default)]
 		retry_policy: RetryConfig,
+		/// Timeout in milliseconds for a single send, unbounded when unset
+		#[serde(default)]
+		timeout_ms: Option<u32>,
 	},
 	/// Webhook configuration
 	Webhook {
@@ -98,6 +381,24 @@ pub enum TriggerTypeConfig {
 		/// Retry policy for HTTP requests
 		#[serde(default)]
 		retry_policy: RetryConfig,
+		/// Timeout in milliseconds for a single send, unbounded when unset
+		#[serde(default)]
+		timeout_ms: Option<u32>,
+		/// Whether to add the structured monitor match to the request as `payload`
+		#[serde(default)]
+		include_raw_match: bool,
+		/// Whether to drop receipts, logs and ledgers from the included monitor match
+		#[serde(default)]
+		trim_raw_match: bool,
+		/// Algorithm signing the requests when a secret is set
+		#[serde(default)]
+		signature_algorithm: WebhookSignatureAlgorithm,
+		/// Header carrying the signature, `X-Signature` when unset
+		#[serde(default)]
+		signature_header: Option<String>,
+		/// Header carrying the signature algorithm, `X-Signature-Algorithm` when unset
+		#[serde(default)]
+		signature_algorithm_header: Option<String>,
 	},
 	/// Telegram notification configuration
 	Telegram {
@@ -107,11 +408,18 @@ pub enum TriggerTypeConfig {
 		chat_id: String,
 		/// Disable web preview
 		disable_web_preview: Option<bool>,
+		/// Forum topic (thread) the message is sent to
+		message_thread_id: Option<i64>,
+		/// Message formatting mode, defaults to MarkdownV2
+		parse_mode: Option<TelegramParseMode>,
 		/// Notification message
 		message: NotificationMessage,
 		/// Retry policy for HTTP requests
 		#[serde(default)]
 		retry_policy: RetryConfig,
+		/// Timeout in milliseconds for a single send, unbounded when unset
+		#[serde(default)]
+		timeout_ms: Option<u32>,
 	},
 	/// Discord notification configuration
 	Discord {
@@ -119,9 +427,37 @@ pub enum TriggerTypeConfig {
 		discord_url: SecretValue,
 		/// Notification message
 		message: NotificationMessage,
+		/// Rich embed the message is sent as, instead of plain content
+		#[serde(default)]
+		embed: Option<DiscordEmbed>,
 		/// Retry policy for HTTP requests
 		#[serde(default)]
 		retry_policy: RetryConfig,
+		/// Timeout in milliseconds for a single send, unbounded when unset
+		#[serde(default)]
+		timeout_ms: Option<u32>,
+	},
+	/// Opsgenie alert configuration
+	Opsgenie {
+		/// Opsgenie API integration key
+		api_key: SecretValue,
+		/// Region of the Opsgenie instance
+		#[serde(default)]
+		region: OpsgenieRegion,
+		/// Alert priority
+		#[serde(default)]
+		priority: OpsgeniePriority,
+		/// Alert alias used for deduplication, supports template variables
+		#[serde(default)]
+		alias: Option<String>,
+		/// Notification message
+		message: NotificationMessage,
+		/// Retry policy for HTTP requests
+		#[serde(default)]
+		retry_policy: RetryConfig,
+		/// Timeout in milliseconds for a single send, unbounded when unset
+		#[serde(default)]
+		timeout_ms: Option<u32>,
 	},
 	/// Script execution configuration
 	Script {
@@ -134,18 +470,71 @@ pub enum TriggerTypeConfig {
 		arguments: Option<Vec<String>>,
 		/// Timeout in milliseconds
 		timeout_ms: u32,
+		/// Maximum memory of the script process in megabytes, unbounded when unset
+		///
+		/// Only enforced on Unix systems.
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		max_memory_mb: Option<u64>,
+		/// Maximum size in bytes of the output of the script, on stdout and stderr each,
+		/// defaults to 1 MiB
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		max_output_bytes: Option<u64>,
+	},
+	/// Local file append configuration
+	File {
+		/// Path of the file records are appended to
+		path: String,
+		/// Record format, defaults to JSONL
+		#[serde(default)]
+		format: FileFormat,
+		/// Size in bytes after which the file is rotated
+		#[serde(default)]
+		rotate_max_bytes: Option<u64>,
+	},
+	/// Kafka producer configuration
+	Kafka {
+		/// Bootstrap brokers, as `host:port`
+		brokers: Vec<String>,
+		/// Topic records are produced to
+		topic: String,
+		/// SASL credentials, connecting without authentication nor TLS when unset
+		#[serde(default)]
+		sasl: Option<KafkaSaslConfig>,
+		/// Timeout in milliseconds for the delivery of a record, 5 seconds when unset
+		#[serde(default)]
+		timeout_ms: Option<u32>,
 	},
 }
 
 impl TriggerTypeConfig {
-	/// Get the retry policy for the trigger type, if applicable.
-	pub fn get_retry_policy(&self) -> Option<RetryConfig> {
+	/// Get the retry policy for the trigger type.
+	///
+	/// Trigger types without a retry policy, such as scripts, get the default exponential
+	/// backoff policy.
+	pub fn get_retry_policy(&self) -> RetryConfig {
 		match self {
-			Self::Slack { retry_policy, .. } => Some(retry_policy.clone()),
-			Self::Discord { retry_policy, .. } => Some(retry_policy.clone()),
-			Self::Webhook { retry_policy, .. } => Some(retry_policy.clone()),
-			Self::Telegram { retry_policy, .. } => Some(retry_policy.clone()),
-			_ => None,
+			Self::Slack { retry_policy, .. }
+			| Self::Email { retry_policy, .. }
+			| Self::Webhook { retry_policy, .. }
+			| Self::Telegram { retry_policy, .. }
+			| Self::Discord { retry_policy, .. }
+			| Self::Opsgenie { retry_policy, .. } => retry_policy.clone(),
+			_ => RetryConfig::default(),
 		}
 	}
+
+	/// Get the send timeout for the trigger type, if one is configured.
+	pub fn get_timeout(&self) -> Option<Duration> {
+		let timeout_ms = match self {
+			Self::Slack { timeout_ms, .. }
+			| Self::Email { timeout_ms, .. }
+			| Self::Webhook { timeout_ms, .. }
+			| Self::Telegram { timeout_ms, .. }
+			| Self::Discord { timeout_ms, .. }
+			| Self::Opsgenie { timeout_ms, .. }
+			| Self::Kafka { timeout_ms, .. } => *timeout_ms,
+			_ => None,
+		};
+		timeout_ms.map(|ms| Duration::from_millis(ms as u64))
+	}
 }
@@ -19,9 +19,10 @@ pub use blockchain::{
 };
 
 pub use blockchain::evm::{
-	EVMBaseReceipt, EVMBaseTransaction, EVMBlock, EVMContractSpec, EVMMatchArguments,
-	EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorConfig, EVMMonitorMatch, EVMReceiptLog,
-	EVMTransaction, EVMTransactionReceipt,
+	EVMBaseReceipt, EVMBaseTransaction, EVMBlock, EVMBlockTag, EVMConfirmationState,
+	EVMContractSpec, EVMInternalCall, EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap,
+	EVMMonitorConfig, EVMMonitorMatch, EVMReceiptLog, EVMTraceMethod, EVMTransaction,
+	EVMTransactionReceipt,
 };
 
 pub use blockchain::stellar::{
@@ -32,6 +33,13 @@ pub use blockchain::stellar::{
 	StellarMonitorMatch, StellarParsedOperationResult, StellarTransaction, StellarTransactionInfo,
 };
 
+pub use blockchain::solana::{
+	SolanaBlock, SolanaBlockInfo, SolanaInnerInstructions, SolanaInstruction,
+	SolanaLoadedAddresses, SolanaMatchArguments, SolanaMatchParamEntry, SolanaMatchParamsMap,
+	SolanaMessage, SolanaMonitorMatch, SolanaSignatureInfo, SolanaTransaction,
+	SolanaTransactionData, SolanaTransactionInfo, SolanaTransactionMeta,
+};
+
 pub use blockchain::midnight::{
 	MidnightBaseTransaction, MidnightBlock, MidnightBlockDigest, MidnightBlockHeader,
 	MidnightCallDetails, MidnightClaimMintDetails, MidnightDeploymentDetails, MidnightEvent,
@@ -43,13 +51,21 @@ pub use blockchain::midnight::{
 
 // Re-export core types
 pub use core::{
-	AddressWithSpec, EventCondition, FunctionCondition, MatchConditions, Monitor, Network,
-	NotificationMessage, RpcUrl, ScriptLanguage, TransactionCondition, TransactionStatus, Trigger,
-	TriggerConditions, TriggerType, TriggerTypeConfig, SCRIPT_LANGUAGE_EXTENSIONS,
+	AdaptivePollingConfig, AddressMatchMode, AddressWithSpec, BalanceCondition, BlockCondition,
+	ConditionLogic, DiscordEmbed, DiscordEmbedField, Eip712Field, Eip712Schema, EventCondition,
+	FileFormat, FunctionCondition, KafkaSaslConfig, KafkaSaslMechanism, MatchConditions,
+	MatchSeverity, Monitor, Network, NotificationMessage, NotificationSeverity, OpsgeniePriority,
+	OpsgenieRegion, RpcCall, RpcUrl, ScriptLanguage, StateCondition, TelegramParseMode,
+	TokenStandard, TokenTransferCondition, TransactionCondition, TransactionStatus, Trigger,
+	TriggerConditions, TriggerType, TriggerTypeConfig, WebhookSignatureAlgorithm,
+	MAX_PRE_FILTER_RPC_CALLS, MAX_STATE_CONDITIONS, NOTIFICATION_METRIC_LABEL_KEYS,
+	SCRIPT_LANGUAGE_EXTENSIONS,
 };
 
 // Re-export config types
-pub use config::{ConfigError, ConfigLoader};
+pub use config::{
+	config_schema, load_bundle, AbiCache, ConfigBundle, ConfigError, ConfigKind, ConfigLoader,
+};
 
 // Re-export security types
 pub use security::{SecretString, SecretValue, SecurityError};
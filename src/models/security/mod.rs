@@ -4,14 +4,19 @@
 //!
 //! - `error`: Error types for security operations
 //! - `secret`: Secret management and zeroization
+//! - `vault`: HashiCorp Vault secret resolution (requires the `vault` feature)
 
 mod error;
 mod secret;
+#[cfg(feature = "vault")]
+mod vault;
 
 use std::env;
 
 pub use error::{SecurityError, SecurityResult};
 pub use secret::{SecretString, SecretValue};
+#[cfg(feature = "vault")]
+pub use vault::{get_hashicorp_vault_client, HashicorpVaultClient};
 
 pub fn get_env_var(key: &str) -> SecurityResult<String> {
 	env::var(key).map_err(|e| {
@@ -6,22 +6,26 @@
 //! # Features
 //!
 //! - Secure memory handling with automatic zeroization
-//! - Multiple secret sources (plain text, environment variables, Hashicorp Cloud Vault, etc.)
+//! - Multiple secret sources (plain text, environment variables, mounted files, Hashicorp Cloud
+//!   Vault, HashiCorp Vault)
 //! - Type-safe secret resolution
 //! - Serde support for configuration files
 
 use oz_keystore::HashicorpCloudClient;
-use serde::{Deserialize, Serialize};
-use std::{env, fmt, sync::Arc};
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use serde::{
+	de::{self, MapAccess, Visitor},
+	Deserialize, Deserializer, Serialize,
+};
+use std::{borrow::Cow, env, fmt, fs, sync::Arc};
 use tokio::sync::OnceCell;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
-use crate::{
-	impl_case_insensitive_enum,
-	models::security::{
-		error::{SecurityError, SecurityResult},
-		get_env_var,
-	},
+#[cfg(feature = "vault")]
+use crate::models::security::vault::get_hashicorp_vault_client;
+use crate::models::security::{
+	error::{SecurityError, SecurityResult},
+	get_env_var,
 };
 
 /// Trait for vault clients that can retrieve secrets
@@ -112,7 +116,17 @@ pub async fn get_vault_client() -> SecurityResult<&'static VaultType> {
 /// This enum provides different ways to store and retrieve secrets:
 /// - `Plain`: Direct secret value (wrapped in `SecretString` for secure memory handling)
 /// - `Environment`: Environment variable reference
+/// - `File`: Reference to a file holding the secret (e.g. a mounted Docker or Kubernetes secret)
 /// - `HashicorpCloudVault`: Hashicorp Cloud Vault reference
+/// - `HashicorpVault`: HashiCorp Vault reference, as `path#key`
+///
+/// In configuration files a secret is either a bare string, treated as a plain value, or an
+/// object with a case-insensitive `type` field:
+/// - `{"type": "plain", "value": "..."}`
+/// - `{"type": "environment", "value": "VAR"}` or `{"type": "env", "name": "VAR"}`
+/// - `{"type": "file", "path": "/run/secrets/name"}`
+/// - `{"type": "hashicorpcloudvault", "value": "name"}`
+/// - `{"type": "vault", "path": "secret/data/monitor", "key": "slack_url"}`
 ///
 /// All variants implement `ZeroizeOnDrop` to ensure secure memory cleanup.
 #[derive(Debug, Clone, Serialize, ZeroizeOnDrop)]
@@ -123,27 +137,160 @@ pub enum SecretValue {
 	Plain(SecretString),
 	/// A secret stored in an environment variable
 	Environment(String),
+	/// A secret stored in a file, referenced by its path
+	File(String),
 	/// A secret stored in Hashicorp Cloud Vault
 	HashicorpCloudVault(String),
+	/// A key of a secret stored in HashiCorp Vault, referenced as `path#key`
+	HashicorpVault(String),
 }
 
-impl_case_insensitive_enum!(SecretValue, {
-	"plain" => Plain,
-	"environment" => Environment,
-	"hashicorpcloudvault" => HashicorpCloudVault,
-});
+impl<'de> Deserialize<'de> for SecretValue {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		struct SecretValueVisitor;
+
+		impl<'de> Visitor<'de> for SecretValueVisitor {
+			type Value = SecretValue;
+
+			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+				formatter.write_str("a string or a struct with a `type` field for SecretValue")
+			}
+
+			fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+			where
+				E: de::Error,
+			{
+				Ok(SecretValue::Plain(SecretString::new(value.to_string())))
+			}
+
+			fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+			where
+				E: de::Error,
+			{
+				Ok(SecretValue::Plain(SecretString::new(value)))
+			}
+
+			fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+			where
+				M: MapAccess<'de>,
+			{
+				let mut type_: Option<String> = None;
+				let mut value: Option<String> = None;
+				let mut name: Option<String> = None;
+				let mut path: Option<String> = None;
+				let mut secret_key: Option<String> = None;
+
+				while let Some(key) = map.next_key::<String>()? {
+					match key.as_str() {
+						"type" => type_ = Some(map.next_value()?),
+						"value" => value = Some(map.next_value()?),
+						"name" => name = Some(map.next_value()?),
+						"path" => path = Some(map.next_value()?),
+						"key" => secret_key = Some(map.next_value()?),
+						_ => {
+							map.next_value::<de::IgnoredAny>()?;
+						}
+					}
+				}
+
+				let type_ = type_.ok_or_else(|| de::Error::missing_field("type"))?;
+
+				match type_.to_lowercase().as_str() {
+					"plain" => value
+						.map(|v| SecretValue::Plain(SecretString::new(v)))
+						.ok_or_else(|| de::Error::missing_field("value")),
+					"environment" => value
+						.or(name)
+						.map(SecretValue::Environment)
+						.ok_or_else(|| de::Error::missing_field("value")),
+					"env" => name
+						.or(value)
+						.map(SecretValue::Environment)
+						.ok_or_else(|| de::Error::missing_field("name")),
+					"file" => path
+						.or(value)
+						.map(SecretValue::File)
+						.ok_or_else(|| de::Error::missing_field("path")),
+					"hashicorpcloudvault" => value
+						.map(SecretValue::HashicorpCloudVault)
+						.ok_or_else(|| de::Error::missing_field("value")),
+					// Serialized references carry the path and key together in `value`
+					"vault" | "hashicorpvault" => match (path, secret_key, value) {
+						(Some(path), Some(key), _) => {
+							Ok(SecretValue::HashicorpVault(format!("{}#{}", path, key)))
+						}
+						(None, None, Some(reference)) if reference.contains('#') => {
+							Ok(SecretValue::HashicorpVault(reference))
+						}
+						(None, _, _) => Err(de::Error::missing_field("path")),
+						(Some(_), None, _) => Err(de::Error::missing_field("key")),
+					},
+					_ => Err(de::Error::unknown_variant(
+						&type_,
+						&[
+							"plain",
+							"environment",
+							"env",
+							"file",
+							"hashicorpcloudvault",
+							"vault",
+						],
+					)),
+				}
+			}
+		}
+
+		deserializer.deserialize_any(SecretValueVisitor)
+	}
+}
 
 impl PartialEq for SecretValue {
 	fn eq(&self, other: &Self) -> bool {
 		match (self, other) {
 			(Self::Plain(l0), Self::Plain(r0)) => l0.as_str() == r0.as_str(),
 			(Self::Environment(l0), Self::Environment(r0)) => l0 == r0,
+			(Self::File(l0), Self::File(r0)) => l0 == r0,
 			(Self::HashicorpCloudVault(l0), Self::HashicorpCloudVault(r0)) => l0 == r0,
+			(Self::HashicorpVault(l0), Self::HashicorpVault(r0)) => l0 == r0,
 			_ => false,
 		}
 	}
 }
 
+/// Schema of the secrets accepted in configuration files, see [`SecretValue`]
+impl JsonSchema for SecretValue {
+	fn schema_name() -> Cow<'static, str> {
+		"SecretValue".into()
+	}
+
+	fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+		json_schema!({
+			"description": "Secret given as a plain string, or as an object with a `type` field",
+			"anyOf": [
+				{ "type": "string" },
+				{
+					"type": "object",
+					"properties": {
+						"type": {
+							"description": "Source of the secret, case-insensitive: plain, \
+								environment, env, file, hashicorpcloudvault or vault",
+							"type": "string"
+						},
+						"value": { "type": "string" },
+						"name": { "type": "string" },
+						"path": { "type": "string" },
+						"key": { "type": "string" }
+					},
+					"required": ["type"]
+				}
+			]
+		})
+	}
+}
+
 /// A string type that automatically zeroizes its contents when dropped.
 ///
 /// This type ensures that sensitive data like passwords and API keys are securely
@@ -171,26 +318,50 @@ impl SecretValue {
 	/// This method retrieves the actual secret value from its source:
 	/// - For `Plain`, returns the wrapped `SecretString`
 	/// - For `Environment`, reads the environment variable
+	/// - For `File`, reads the file, ignoring trailing line breaks
 	/// - For `HashicorpCloudVault`, fetches the secret from the vault
+	/// - For `HashicorpVault`, fetches the secret from the Vault server in `VAULT_ADDR`, see
+	///   `HashicorpVaultClient`. This requires the `vault` feature
+	///
+	/// Errors only ever name the referenced variable, file or Vault path, never the secret
+	/// itself.
 	///
 	/// # Errors
 	///
 	/// Returns a `SecurityError` if:
 	/// - Environment variable is not set
+	/// - Secret file is missing or unreadable
 	/// - Vault access fails
 	/// - Any other security-related error occurs
 	pub async fn resolve(&self) -> SecurityResult<SecretString> {
 		match self {
 			SecretValue::Plain(secret) => Ok(secret.clone()),
 			SecretValue::Environment(env_var) => {
+				// The error is not attached as a source since it may contain the raw value
 				env::var(env_var).map(SecretString::new).map_err(|e| {
-					Box::new(SecurityError::parse_error(
-						format!("Failed to get environment variable {}", env_var),
-						Some(e.into()),
+					let reason = match e {
+						env::VarError::NotPresent => "not set",
+						env::VarError::NotUnicode(_) => "not valid unicode",
+					};
+					Box::new(SecurityError::validation_error(
+						format!("Failed to get environment variable {}: {}", env_var, reason),
+						None,
 						None,
 					))
 				})
 			}
+			SecretValue::File(path) => {
+				let mut content = fs::read_to_string(path).map_err(|e| {
+					Box::new(SecurityError::validation_error(
+						format!("Failed to read secret file {}", path),
+						Some(e.into()),
+						None,
+					))
+				})?;
+				let len = content.trim_end_matches(['\r', '\n']).len();
+				content.truncate(len);
+				Ok(SecretString::new(content))
+			}
 			SecretValue::HashicorpCloudVault(name) => {
 				let client = get_vault_client().await?;
 				client.get_secret(name).await.map_err(|e| {
@@ -201,15 +372,50 @@ impl SecretValue {
 					))
 				})
 			}
+			SecretValue::HashicorpVault(reference) => resolve_hashicorp_vault(reference).await,
 		}
 	}
 
+	/// Resolves the `${VAR}` placeholders of a value, keeping the text around them as is
+	///
+	/// Each placeholder is resolved as an [`SecretValue::Environment`] secret, so that values
+	/// such as `Bearer ${API_KEY}` can keep the secret itself out of configuration files.
+	///
+	/// # Errors
+	///
+	/// Returns a `SecurityError` if a placeholder isn't closed or names an environment
+	/// variable that is not set
+	pub async fn resolve_placeholders(value: &str) -> SecurityResult<SecretString> {
+		let mut resolved = String::with_capacity(value.len());
+		let mut rest = value;
+
+		while let Some(start) = rest.find("${") {
+			resolved.push_str(&rest[..start]);
+			let end = rest[start..].find('}').ok_or_else(|| {
+				Box::new(SecurityError::validation_error(
+					"Unclosed environment variable placeholder",
+					None,
+					None,
+				))
+			})? + start;
+
+			let variable = SecretValue::Environment(rest[start + 2..end].to_string());
+			resolved.push_str(variable.resolve().await?.as_str());
+			rest = &rest[end + 1..];
+		}
+		resolved.push_str(rest);
+
+		Ok(SecretString::new(resolved))
+	}
+
 	/// Checks if the secret value starts with a given prefix
 	pub fn starts_with(&self, prefix: &str) -> bool {
 		match self {
 			SecretValue::Plain(secret) => secret.as_str().starts_with(prefix),
 			SecretValue::Environment(env_var) => env_var.starts_with(prefix),
+			SecretValue::File(path) => path.starts_with(prefix),
 			SecretValue::HashicorpCloudVault(name) => name.starts_with(prefix),
+			SecretValue::HashicorpVault(reference) => reference.starts_with(prefix),
 		}
 	}
 
@@ -218,7 +424,9 @@ impl SecretValue {
 		match self {
 			SecretValue::Plain(secret) => secret.as_str().is_empty(),
 			SecretValue::Environment(env_var) => env_var.is_empty(),
+			SecretValue::File(path) => path.is_empty(),
 			SecretValue::HashicorpCloudVault(name) => name.is_empty(),
+			SecretValue::HashicorpVault(reference) => reference.is_empty(),
 		}
 	}
 
@@ -227,7 +435,9 @@ impl SecretValue {
 		match self {
 			SecretValue::Plain(secret) => secret.as_str().trim(),
 			SecretValue::Environment(env_var) => env_var.trim(),
+			SecretValue::File(path) => path.trim(),
 			SecretValue::HashicorpCloudVault(name) => name.trim(),
+			SecretValue::HashicorpVault(reference) => reference.trim(),
 		}
 	}
 
@@ -236,18 +446,52 @@ impl SecretValue {
 		match self {
 			SecretValue::Plain(secret) => secret.as_str(),
 			SecretValue::Environment(env_var) => env_var,
+			SecretValue::File(path) => path,
 			SecretValue::HashicorpCloudVault(name) => name,
+			SecretValue::HashicorpVault(reference) => reference,
 		}
 	}
 }
 
+/// Resolves a `path#key` reference to a HashiCorp Vault secret
+#[cfg(feature = "vault")]
+async fn resolve_hashicorp_vault(reference: &str) -> SecurityResult<SecretString> {
+	let (path, key) = reference.rsplit_once('#').ok_or_else(|| {
+		Box::new(SecurityError::validation_error(
+			format!(
+				"Invalid Vault secret reference {}, expected `path#key`",
+				reference
+			),
+			None,
+			None,
+		))
+	})?;
+	let client = get_hashicorp_vault_client().await?;
+	client.get_secret(path, key).await
+}
+
+/// Resolves a `path#key` reference to a HashiCorp Vault secret
+#[cfg(not(feature = "vault"))]
+async fn resolve_hashicorp_vault(reference: &str) -> SecurityResult<SecretString> {
+	Err(Box::new(SecurityError::validation_error(
+		format!(
+			"Failed to get Vault secret {}: built without the `vault` feature",
+			reference
+		),
+		None,
+		None,
+	)))
+}
+
 impl Zeroize for SecretValue {
 	/// Securely zeroizes the secret value.
 	///
 	/// This implementation ensures that all sensitive data is properly cleared:
 	/// - For `Plain`, zeroizes the underlying `SecretString`
 	/// - For `Environment`, clears the environment variable name
+	/// - For `File`, clears the file path
 	/// - For `HashicorpCloudVault`, clears the secret name
+	/// - For `HashicorpVault`, clears the secret reference
 	fn zeroize(&mut self) {
 		match self {
 			SecretValue::Plain(secret) => secret.zeroize(),
@@ -255,9 +499,15 @@ impl Zeroize for SecretValue {
 				// Clear the environment variable name
 				env_var.clear();
 			}
+			SecretValue::File(path) => {
+				path.clear();
+			}
 			SecretValue::HashicorpCloudVault(name) => {
 				name.clear();
 			}
+			SecretValue::HashicorpVault(reference) => {
+				reference.clear();
+			}
 		}
 	}
 }
@@ -298,7 +548,9 @@ impl fmt::Display for SecretValue {
 		match self {
 			SecretValue::Plain(_) => write!(f, "<secret string>"),
 			SecretValue::Environment(env_var) => write!(f, "{}", env_var),
+			SecretValue::File(path) => write!(f, "{}", path),
 			SecretValue::HashicorpCloudVault(name) => write!(f, "{}", name),
+			SecretValue::HashicorpVault(reference) => write!(f, "{}", reference),
 		}
 	}
 }
@@ -320,7 +572,9 @@ impl AsRef<str> for SecretValue {
 		match self {
 			SecretValue::Plain(secret) => secret.as_ref(),
 			SecretValue::Environment(env_var) => env_var,
+			SecretValue::File(path) => path,
 			SecretValue::HashicorpCloudVault(name) => name,
+			SecretValue::HashicorpVault(reference) => reference,
 		}
 	}
 }
@@ -1015,4 +1269,119 @@ mod tests {
 			}
 		}
 	}
+
+	#[test]
+	fn test_env_and_file_reference_deserialization() {
+		let env: SecretValue =
+			serde_json::from_str(r#"{"type":"env","name":"SLACK_URL"}"#).unwrap();
+		assert_eq!(env, SecretValue::Environment("SLACK_URL".to_string()));
+
+		let file: SecretValue =
+			serde_json::from_str(r#"{"type":"file","path":"/run/secrets/smtp_pw"}"#).unwrap();
+		assert_eq!(file, SecretValue::File("/run/secrets/smtp_pw".to_string()));
+
+		let plain: SecretValue = serde_json::from_str(r#""test_secret""#).unwrap();
+		assert_eq!(
+			plain,
+			SecretValue::Plain(SecretString::new("test_secret".to_string()))
+		);
+
+		let missing: Result<SecretValue, _> = serde_json::from_str(r#"{"type":"file"}"#);
+		assert!(missing
+			.unwrap_err()
+			.to_string()
+			.contains("missing field `path`"));
+	}
+
+	#[test]
+	fn test_hashicorp_vault_reference_deserialization() {
+		let vault: SecretValue = serde_json::from_str(
+			r#"{"type":"vault","path":"secret/data/monitor","key":"slack_url"}"#,
+		)
+		.unwrap();
+		assert_eq!(
+			vault,
+			SecretValue::HashicorpVault("secret/data/monitor#slack_url".to_string())
+		);
+
+		let json = serde_json::to_string(&vault).unwrap();
+		let deserialized: SecretValue = serde_json::from_str(&json).unwrap();
+		assert_eq!(deserialized, vault);
+
+		let missing: Result<SecretValue, _> =
+			serde_json::from_str(r#"{"type":"vault","path":"secret/data/monitor"}"#);
+		assert!(missing
+			.unwrap_err()
+			.to_string()
+			.contains("missing field `key`"));
+	}
+
+	#[cfg(not(feature = "vault"))]
+	#[tokio::test]
+	async fn test_secret_value_resolve_hashicorp_vault_without_feature() {
+		let secret = SecretValue::HashicorpVault("secret/data/monitor#slack_url".to_string());
+		let error = secret.resolve().await.unwrap_err().to_string();
+		assert!(error.contains("built without the `vault` feature"));
+	}
+
+	#[test]
+	fn test_file_secret_serialization_round_trip() {
+		let file = SecretValue::File("/run/secrets/token".to_string());
+		let json = serde_json::to_string(&file).unwrap();
+		let deserialized: SecretValue = serde_json::from_str(&json).unwrap();
+		assert_eq!(deserialized, file);
+	}
+
+	#[tokio::test]
+	async fn test_secret_value_resolve_file() {
+		let temp_dir = tempfile::TempDir::new().unwrap();
+		let path = temp_dir.path().join("secret");
+		std::fs::write(&path, "file-secret\n").unwrap();
+
+		let secret = SecretValue::File(path.display().to_string());
+		let resolved = secret.resolve().await.unwrap();
+		assert_eq!(resolved.as_str(), "file-secret");
+	}
+
+	#[tokio::test]
+	async fn test_secret_value_resolve_file_error() {
+		let secret = SecretValue::File("/nonexistent/secret".to_string());
+		let error = secret.resolve().await.unwrap_err().to_string();
+		assert!(error.contains("Validation error"));
+		assert!(error.contains("Failed to read secret file /nonexistent/secret"));
+	}
+
+	#[tokio::test]
+	async fn test_secret_value_resolve_placeholders() {
+		env::set_var("TEST_PLACEHOLDER_API_KEY", "api-key");
+
+		let resolved = SecretValue::resolve_placeholders("Bearer ${TEST_PLACEHOLDER_API_KEY}!")
+			.await
+			.unwrap();
+		assert_eq!(resolved.as_str(), "Bearer api-key!");
+
+		let resolved = SecretValue::resolve_placeholders("no placeholder")
+			.await
+			.unwrap();
+		assert_eq!(resolved.as_str(), "no placeholder");
+
+		assert!(
+			SecretValue::resolve_placeholders("${TEST_PLACEHOLDER_API_KEY")
+				.await
+				.is_err()
+		);
+		assert!(SecretValue::resolve_placeholders("${NON_EXISTENT_ENV_VAR}")
+			.await
+			.is_err());
+
+		env::remove_var("TEST_PLACEHOLDER_API_KEY");
+	}
+
+	#[tokio::test]
+	async fn test_secret_value_resolve_env_error_is_validation_error() {
+		let secret = SecretValue::Environment("NON_EXISTENT_ENV_VAR".to_string());
+		let error = secret.resolve().await.unwrap_err();
+		assert!(matches!(*error, SecurityError::ValidationError(_)));
+		assert!(error.to_string().contains("NON_EXISTENT_ENV_VAR: not set"));
+	}
 }
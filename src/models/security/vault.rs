@@ -0,0 +1,284 @@
+//! HashiCorp Vault secret resolution.
+//!
+//! Secrets are read from a KV secrets engine through the Vault HTTP API, using the address in
+//! `VAULT_ADDR` and the token in `VAULT_TOKEN`. Each secret path is fetched once and its keys
+//! are cached for the lifetime of the process. Secret values are never logged nor included in
+//! errors.
+
+use reqwest::StatusCode;
+use serde_json::Value;
+use std::{collections::HashMap, time::Duration};
+use tokio::sync::{Mutex, OnceCell};
+
+use crate::models::security::{
+	error::{SecurityError, SecurityResult},
+	get_env_var, SecretString,
+};
+
+/// Timeout of requests to Vault
+const VAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Client reading secrets from a HashiCorp Vault server
+pub struct HashicorpVaultClient {
+	client: reqwest::Client,
+	address: String,
+	token: SecretString,
+	/// Keys of the fetched secrets, by secret path
+	secrets: Mutex<HashMap<String, HashMap<String, SecretString>>>,
+}
+
+impl HashicorpVaultClient {
+	/// Creates a new client for the Vault server at `address`
+	///
+	/// # Arguments
+	/// * `address` - Base URL of the Vault server, e.g. `https://vault.example.com:8200`
+	/// * `token` - Token used to authenticate requests
+	pub fn new(address: &str, token: SecretString) -> SecurityResult<Self> {
+		let client = reqwest::Client::builder()
+			.timeout(VAULT_REQUEST_TIMEOUT)
+			.build()
+			.map_err(|e| {
+				Box::new(SecurityError::network_error(
+					"Failed to create Vault HTTP client",
+					Some(e.into()),
+					None,
+				))
+			})?;
+
+		Ok(Self {
+			client,
+			address: address.trim_end_matches('/').to_string(),
+			token,
+			secrets: Mutex::new(HashMap::new()),
+		})
+	}
+
+	/// Creates a new client from the `VAULT_ADDR` and `VAULT_TOKEN` environment variables
+	pub fn from_env() -> SecurityResult<Self> {
+		let address = get_env_var("VAULT_ADDR")?;
+		let token = SecretString::new(get_env_var("VAULT_TOKEN")?);
+		Self::new(&address, token)
+	}
+
+	/// Returns the value of a key of the secret at `path`
+	///
+	/// The secret is fetched on first use only, later lookups of any of its keys are served from
+	/// the cache.
+	///
+	/// # Arguments
+	/// * `path` - API path of the secret, e.g. `secret/data/monitor` for a KV v2 engine
+	/// * `key` - Key of the value within the secret
+	pub async fn get_secret(&self, path: &str, key: &str) -> SecurityResult<SecretString> {
+		let mut secrets = self.secrets.lock().await;
+		if !secrets.contains_key(path) {
+			let fetched = self.fetch_secret(path).await?;
+			secrets.insert(path.to_string(), fetched);
+		}
+
+		secrets
+			.get(path)
+			.and_then(|keys| keys.get(key))
+			.cloned()
+			.ok_or_else(|| {
+				Box::new(SecurityError::validation_error(
+					format!("Key '{}' not found in Vault secret '{}'", key, path),
+					None,
+					None,
+				))
+			})
+	}
+
+	/// Fetches the keys of the secret at `path`
+	async fn fetch_secret(&self, path: &str) -> SecurityResult<HashMap<String, SecretString>> {
+		let url = format!("{}/v1/{}", self.address, path.trim_start_matches('/'));
+
+		let response = self
+			.client
+			.get(&url)
+			.header("X-Vault-Token", self.token.as_str())
+			.send()
+			.await
+			.map_err(|e| {
+				Box::new(SecurityError::network_error(
+					format!("Failed to reach Vault at {}", self.address),
+					Some(e.without_url().into()),
+					None,
+				))
+			})?;
+
+		let status = response.status();
+		if !status.is_success() {
+			let reason = match status {
+				StatusCode::NOT_FOUND => "secret not found".to_string(),
+				StatusCode::FORBIDDEN => "permission denied".to_string(),
+				_ => format!("unexpected status {}", status),
+			};
+			return Err(Box::new(SecurityError::network_error(
+				format!("Failed to read Vault secret '{}': {}", path, reason),
+				None,
+				None,
+			)));
+		}
+
+		// The error is not attached as a source since it may contain part of the response body
+		let body: Value = response.json().await.map_err(|_| {
+			Box::new(SecurityError::parse_error(
+				format!("Failed to parse Vault response for secret '{}'", path),
+				None,
+				None,
+			))
+		})?;
+
+		// KV v2 engines nest the keys under `data.data`, KV v1 engines return them under `data`
+		let data = match body.pointer("/data/data") {
+			Some(Value::Object(data)) => data,
+			_ => match body.get("data") {
+				Some(Value::Object(data)) => data,
+				_ => {
+					return Err(Box::new(SecurityError::parse_error(
+						format!("Vault response for secret '{}' has no data", path),
+						None,
+						None,
+					)))
+				}
+			},
+		};
+
+		Ok(data
+			.iter()
+			.filter_map(|(key, value)| {
+				let value = match value {
+					Value::String(value) => value.clone(),
+					Value::Null | Value::Object(_) | Value::Array(_) => return None,
+					other => other.to_string(),
+				};
+				Some((key.clone(), SecretString::new(value)))
+			})
+			.collect())
+	}
+}
+
+// Global Vault client instance, shared so that fetched secrets are cached for the process
+static HASHICORP_VAULT_CLIENT: OnceCell<HashicorpVaultClient> = OnceCell::const_new();
+
+/// Gets the global Vault client instance, initializing it if necessary
+pub async fn get_hashicorp_vault_client() -> SecurityResult<&'static HashicorpVaultClient> {
+	HASHICORP_VAULT_CLIENT
+		.get_or_try_init(|| async { HashicorpVaultClient::from_env() })
+		.await
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use mockito::{Matcher, Server};
+
+	#[tokio::test]
+	async fn test_get_secret_caches_fetched_secret() {
+		let mut server = Server::new_async().await;
+		let mock = server
+			.mock("GET", "/v1/secret/data/monitor")
+			.match_header("X-Vault-Token", "test-token")
+			.with_status(200)
+			.with_body(
+				r#"{"data": {"data": {"slack_url": "https://hooks.slack.com/x", "port": 25},
+				"metadata": {"version": 1}}}"#,
+			)
+			.expect(1)
+			.create_async()
+			.await;
+
+		let client =
+			HashicorpVaultClient::new(&server.url(), SecretString::new("test-token".into()))
+				.unwrap();
+
+		let slack_url = client
+			.get_secret("secret/data/monitor", "slack_url")
+			.await
+			.unwrap();
+		assert_eq!(slack_url.as_str(), "https://hooks.slack.com/x");
+		let port = client
+			.get_secret("secret/data/monitor", "port")
+			.await
+			.unwrap();
+		assert_eq!(port.as_str(), "25");
+
+		mock.assert();
+	}
+
+	#[tokio::test]
+	async fn test_get_secret_kv_v1() {
+		let mut server = Server::new_async().await;
+		let _mock = server
+			.mock("GET", "/v1/kv/monitor")
+			.with_status(200)
+			.with_body(r#"{"data": {"password": "hunter2"}}"#)
+			.create_async()
+			.await;
+
+		let client =
+			HashicorpVaultClient::new(&server.url(), SecretString::new("test-token".into()))
+				.unwrap();
+
+		let password = client.get_secret("kv/monitor", "password").await.unwrap();
+		assert_eq!(password.as_str(), "hunter2");
+	}
+
+	#[tokio::test]
+	async fn test_get_secret_missing_key() {
+		let mut server = Server::new_async().await;
+		let _mock = server
+			.mock("GET", Matcher::Any)
+			.with_status(200)
+			.with_body(r#"{"data": {"data": {"slack_url": "https://hooks.slack.com/x"}}}"#)
+			.create_async()
+			.await;
+
+		let client =
+			HashicorpVaultClient::new(&server.url(), SecretString::new("test-token".into()))
+				.unwrap();
+
+		let error = client
+			.get_secret("secret/data/monitor", "smtp_password")
+			.await
+			.unwrap_err()
+			.to_string();
+		assert!(
+			error.contains("Key 'smtp_password' not found in Vault secret 'secret/data/monitor'")
+		);
+		assert!(!error.contains("hooks.slack.com"));
+	}
+
+	#[tokio::test]
+	async fn test_get_secret_errors() {
+		let mut server = Server::new_async().await;
+		let _mock = server
+			.mock("GET", Matcher::Any)
+			.with_status(403)
+			.with_body(r#"{"errors": ["permission denied"]}"#)
+			.create_async()
+			.await;
+
+		let client =
+			HashicorpVaultClient::new(&server.url(), SecretString::new("test-token".into()))
+				.unwrap();
+		let error = client
+			.get_secret("secret/data/monitor", "slack_url")
+			.await
+			.unwrap_err();
+		assert!(matches!(*error, SecurityError::NetworkError(_)));
+		assert!(error.to_string().contains("permission denied"));
+
+		// Nothing listens on port 9 of localhost
+		let client =
+			HashicorpVaultClient::new("http://127.0.0.1:9", SecretString::new("test-token".into()))
+				.unwrap();
+		let error = client
+			.get_secret("secret/data/monitor", "slack_url")
+			.await
+			.unwrap_err();
+		assert!(error
+			.to_string()
+			.contains("Failed to reach Vault at http://127.0.0.1:9"));
+	}
+}
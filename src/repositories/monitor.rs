@@ -11,12 +11,13 @@ use std::{collections::HashMap, marker::PhantomData, path::Path};
 use async_trait::async_trait;
 
 use crate::{
-	models::{ConfigLoader, Monitor, Network, Trigger, SCRIPT_LANGUAGE_EXTENSIONS},
+	models::{BlockChainType, ConfigLoader, Monitor, Network, Trigger},
 	repositories::{
 		error::RepositoryError,
 		network::{NetworkRepository, NetworkRepositoryTrait, NetworkService},
 		trigger::{TriggerRepository, TriggerRepositoryTrait, TriggerService},
 	},
+	services::trigger::ScriptExecutorFactory,
 };
 
 /// Repository for storing and retrieving monitor configurations
@@ -72,8 +73,14 @@ impl<
 		let mut metadata = HashMap::new();
 
 		for (monitor_name, monitor) in monitors {
-			// Validate trigger references
-			for trigger_id in &monitor.triggers {
+			// Validate trigger references, including the triggers of near misses and resolved
+			// alerts
+			for trigger_id in monitor
+				.triggers
+				.iter()
+				.chain(&monitor.warn_triggers)
+				.chain(&monitor.resolve_trigger)
+			{
 				if !triggers.contains_key(trigger_id) {
 					validation_errors.push(format!(
 						"Monitor '{}' references non-existent trigger '{}'",
@@ -100,6 +107,103 @@ impl<
 				}
 			}
 
+			// Internal transactions are read from block traces, which EVM networks must support
+			if monitor.includes_internal_transactions() {
+				for network_slug in &monitor.networks {
+					if networks.get(network_slug).is_some_and(|network| {
+						network.network_type == BlockChainType::EVM
+							&& network.trace_method.is_none()
+					}) {
+						validation_errors.push(format!(
+							"Monitor '{}' includes internal transactions but network '{}' has no \
+							 trace_method",
+							monitor_name, network_slug
+						));
+					}
+				}
+			}
+
+			// Token transfers are matched on the transfer events of EVM token standards
+			if !monitor.match_conditions.token_transfers.is_empty() {
+				for network_slug in &monitor.networks {
+					if networks
+						.get(network_slug)
+						.is_some_and(|network| network.network_type != BlockChainType::EVM)
+					{
+						validation_errors.push(format!(
+							"Monitor '{}' has token transfer conditions but network '{}' is not \
+							 an EVM network",
+							monitor_name, network_slug
+						));
+					}
+				}
+			}
+
+			// Contract creations are transactions without a recipient, which only EVM networks have
+			if monitor
+				.match_conditions
+				.transactions
+				.iter()
+				.any(|condition| condition.is_contract_creation)
+			{
+				for network_slug in &monitor.networks {
+					if networks
+						.get(network_slug)
+						.is_some_and(|network| network.network_type != BlockChainType::EVM)
+					{
+						validation_errors.push(format!(
+							"Monitor '{}' matches contract creations but network '{}' is not an \
+							 EVM network",
+							monitor_name, network_slug
+						));
+					}
+				}
+			}
+
+			// Solana instruction data isn't decoded, so the expressions of conditions can't be
+			// evaluated and would be silently ignored
+			let conditions = &monitor.match_conditions;
+			let has_expressions = conditions
+				.functions
+				.iter()
+				.any(|condition| condition.expression.is_some())
+				|| conditions
+					.events
+					.iter()
+					.any(|condition| condition.expression.is_some())
+				|| conditions
+					.transactions
+					.iter()
+					.any(|condition| condition.expression.is_some());
+			if has_expressions {
+				for network_slug in &monitor.networks {
+					if networks
+						.get(network_slug)
+						.is_some_and(|network| network.network_type == BlockChainType::Solana)
+					{
+						validation_errors.push(format!(
+							"Monitor '{}' has condition expressions but network '{}' is a Solana \
+							 network, whose conditions don't support expressions",
+							monitor_name, network_slug
+						));
+					}
+				}
+			}
+
+			// A monitor whose networks are all disabled never runs
+			if !monitor.paused
+				&& !monitor.networks.is_empty()
+				&& monitor
+					.networks
+					.iter()
+					.all(|slug| networks.get(slug).is_some_and(|n| !n.is_enabled()))
+			{
+				tracing::warn!(
+					"Monitor '{}' only references disabled networks and will not run",
+					monitor_name
+				);
+			}
+
 			// Validate custom trigger conditions
 			for condition in &monitor.trigger_conditions {
 				let script_path = Path::new(&condition.script_path);
@@ -111,10 +215,7 @@ impl<
 				}
 
 				// Validate file extension matches the specified language
-				let expected_extension = match SCRIPT_LANGUAGE_EXTENSIONS
-					.iter()
-					.find(|(lang, _)| *lang == &condition.language)
-					.map(|(_, ext)| *ext)
+				let expected_extension = match ScriptExecutorFactory::extension(&condition.language)
 				{
 					Some(ext) => ext,
 					None => {
@@ -178,6 +279,13 @@ pub trait MonitorRepositoryTrait<
 	where
 		Self: Sized;
 
+	/// Create a new monitor repository from already loaded monitors
+	///
+	/// References to networks and triggers are not validated.
+	fn new_with_monitors(monitors: HashMap<String, Monitor>) -> Self
+	where
+		Self: Sized;
+
 	/// Load all monitor configurations from the given path
 	///
 	/// If no path is provided, uses the default config directory.
@@ -224,6 +332,10 @@ impl<
 		MonitorRepository::new(path, network_service, trigger_service).await
 	}
 
+	fn new_with_monitors(monitors: HashMap<String, Monitor>) -> Self {
+		MonitorRepository::new_with_monitors(monitors)
+	}
+
 	async fn load_all(
 		path: Option<&Path>,
 		network_service: Option<NetworkService<N>>,
@@ -427,7 +539,10 @@ impl<
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::{models::ScriptLanguage, utils::tests::builders::evm::monitor::MonitorBuilder};
+	use crate::{
+		models::{EVMTraceMethod, ScriptLanguage, TokenTransferCondition},
+		utils::tests::builders::{evm::monitor::MonitorBuilder, network::NetworkBuilder},
+	};
 	use std::fs;
 	use tempfile::TempDir;
 
@@ -595,6 +710,157 @@ mod tests {
 		assert!(err.to_string().contains("references non-existent trigger"));
 	}
 
+	#[test]
+	fn test_internal_transactions_require_trace_method() {
+		let mut monitors = HashMap::new();
+		monitors.insert(
+			"test_monitor".to_string(),
+			MonitorBuilder::new()
+				.name("test_monitor")
+				.networks(vec!["ethereum_mainnet".to_string()])
+				.include_internal_transactions(true)
+				.build(),
+		);
+		let triggers = HashMap::new();
+
+		let mut networks = HashMap::new();
+		networks.insert(
+			"ethereum_mainnet".to_string(),
+			NetworkBuilder::new().slug("ethereum_mainnet").build(),
+		);
+		let result =
+			MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+				&monitors, &triggers, &networks,
+			);
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("includes internal transactions but network 'ethereum_mainnet'"));
+
+		networks.insert(
+			"ethereum_mainnet".to_string(),
+			NetworkBuilder::new()
+				.slug("ethereum_mainnet")
+				.trace_method(EVMTraceMethod::DebugTraceBlockByNumber)
+				.build(),
+		);
+		assert!(
+			MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+				&monitors, &triggers, &networks,
+			)
+			.is_ok()
+		);
+	}
+
+	#[test]
+	fn test_token_transfers_require_evm_networks() {
+		let mut monitors = HashMap::new();
+		monitors.insert(
+			"test_monitor".to_string(),
+			MonitorBuilder::new()
+				.name("test_monitor")
+				.networks(vec!["stellar_mainnet".to_string()])
+				.token_transfer(TokenTransferCondition::default())
+				.build(),
+		);
+		let triggers = HashMap::new();
+
+		let mut networks = HashMap::new();
+		networks.insert(
+			"stellar_mainnet".to_string(),
+			NetworkBuilder::new()
+				.slug("stellar_mainnet")
+				.network_type(BlockChainType::Stellar)
+				.build(),
+		);
+		let result =
+			MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+				&monitors, &triggers, &networks,
+			);
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("has token transfer conditions but network 'stellar_mainnet'"));
+	}
+
+	#[test]
+	fn test_contract_creations_require_evm_networks() {
+		let mut monitors = HashMap::new();
+		monitors.insert(
+			"test_monitor".to_string(),
+			MonitorBuilder::new()
+				.name("test_monitor")
+				.networks(vec!["stellar_mainnet".to_string()])
+				.contract_creation(None)
+				.build(),
+		);
+		let triggers = HashMap::new();
+
+		let mut networks = HashMap::new();
+		networks.insert(
+			"stellar_mainnet".to_string(),
+			NetworkBuilder::new()
+				.slug("stellar_mainnet")
+				.network_type(BlockChainType::Stellar)
+				.build(),
+		);
+		let result =
+			MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+				&monitors, &triggers, &networks,
+			);
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("matches contract creations but network 'stellar_mainnet'"));
+	}
+
+	#[test]
+	fn test_solana_conditions_reject_expressions() {
+		let mut monitors = HashMap::new();
+		monitors.insert(
+			"test_monitor".to_string(),
+			MonitorBuilder::new()
+				.name("test_monitor")
+				.networks(vec!["solana_mainnet".to_string()])
+				.function("Transfer", Some("amount > 100".to_string()))
+				.build(),
+		);
+		let triggers = HashMap::new();
+
+		let mut networks = HashMap::new();
+		networks.insert(
+			"solana_mainnet".to_string(),
+			NetworkBuilder::new()
+				.slug("solana_mainnet")
+				.network_type(BlockChainType::Solana)
+				.build(),
+		);
+		let result =
+			MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+				&monitors, &triggers, &networks,
+			);
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("has condition expressions but network 'solana_mainnet'"));
+
+		// Conditions without expressions are valid
+		monitors.insert(
+			"test_monitor".to_string(),
+			MonitorBuilder::new()
+				.name("test_monitor")
+				.networks(vec!["solana_mainnet".to_string()])
+				.function("Transfer", None)
+				.build(),
+		);
+		assert!(
+			MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+				&monitors, &triggers, &networks,
+			)
+			.is_ok()
+		);
+	}
+
 	#[tokio::test]
 	async fn test_load_from_path_error_handling() {
 		// Create a temporary directory for testing
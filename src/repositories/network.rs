@@ -44,6 +44,11 @@ pub trait NetworkRepositoryTrait: Clone {
 	where
 		Self: Sized;
 
+	/// Create a new repository instance from already loaded networks
+	fn new_with_networks(networks: HashMap<String, Network>) -> Self
+	where
+		Self: Sized;
+
 	/// Load all network configurations from the given path
 	///
 	/// If no path is provided, uses the default config directory.
@@ -67,6 +72,10 @@ impl NetworkRepositoryTrait for NetworkRepository {
 		NetworkRepository::new(path).await
 	}
 
+	fn new_with_networks(networks: HashMap<String, Network>) -> Self {
+		NetworkRepository { networks }
+	}
+
 	async fn load_all(path: Option<&Path>) -> Result<HashMap<String, Network>, RepositoryError> {
 		Network::load_all(path).await.map_err(|e| {
 			RepositoryError::load_error(
@@ -44,6 +44,11 @@ pub trait TriggerRepositoryTrait: Clone {
 	where
 		Self: Sized;
 
+	/// Create a new trigger repository from already loaded triggers
+	fn new_with_triggers(triggers: HashMap<String, Trigger>) -> Self
+	where
+		Self: Sized;
+
 	/// Load all trigger configurations from the given path
 	///
 	/// If no path is provided, uses the default config directory.
@@ -67,6 +72,10 @@ impl TriggerRepositoryTrait for TriggerRepository {
 		TriggerRepository::new(path).await
 	}
 
+	fn new_with_triggers(triggers: HashMap<String, Trigger>) -> Self {
+		TriggerRepository { triggers }
+	}
+
 	async fn load_all(path: Option<&Path>) -> Result<HashMap<String, Trigger>, RepositoryError> {
 		Trigger::load_all(path).await.map_err(|e| {
 			RepositoryError::load_error(
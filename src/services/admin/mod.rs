@@ -0,0 +1,13 @@
+//! Admin HTTP server.
+//!
+//! Serves operational endpoints that change the state of the running service, separately from
+//! the read-only metrics server:
+//! - `POST /reload`: Reloads the monitor configurations, see
+//!   [`MonitorReloader`](crate::bootstrap::MonitorReloader)
+//!
+//! Requests must carry the `ADMIN_TOKEN` when it is set, and the server refuses to listen on
+//! non-loopback addresses without it.
+
+mod server;
+
+pub use server::{admin_token_from_env, create_admin_server, MonitorReloaderArc};
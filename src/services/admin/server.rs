@@ -0,0 +1,420 @@
+//! Admin server module
+//!
+//! This module provides an HTTP server to reload the monitor configurations of the running
+//! service.
+//!
+//! Requests must carry the `ADMIN_TOKEN` as `Authorization: Bearer <token>` when it is set.
+//! Without a token, the server only listens on loopback addresses.
+
+use actix_web::middleware::NormalizePath;
+use actix_web::{http::header, web, App, HttpRequest, HttpResponse, HttpServer, Responder};
+use std::{
+	env,
+	net::{SocketAddr, ToSocketAddrs},
+	sync::Arc,
+};
+use tracing::{error, info};
+
+use crate::{
+	bootstrap::MonitorReloader,
+	repositories::{MonitorRepository, NetworkRepository, TriggerRepository},
+	services::blockchain::ClientPool,
+};
+
+/// Reloader of the monitors of the running service
+pub type MonitorReloaderArc = Arc<
+	MonitorReloader<
+		MonitorRepository<NetworkRepository, TriggerRepository>,
+		NetworkRepository,
+		TriggerRepository,
+		ClientPool,
+	>,
+>;
+
+/// Environment variable holding the token of the admin API
+const ADMIN_TOKEN_ENV: &str = "ADMIN_TOKEN";
+
+/// Token the requests to the admin API must carry, if any
+#[derive(Clone, Debug, Default)]
+struct AdminToken(Option<String>);
+
+impl AdminToken {
+	/// Checks the `Authorization` header of a request against the token
+	fn authorizes(&self, req: &HttpRequest) -> bool {
+		let Some(token) = &self.0 else {
+			return true;
+		};
+		req.headers()
+			.get(header::AUTHORIZATION)
+			.and_then(|value| value.to_str().ok())
+			.and_then(|value| value.strip_prefix("Bearer "))
+			.is_some_and(|provided| constant_time_eq(provided.as_bytes(), token.as_bytes()))
+	}
+}
+
+/// Compares two byte strings in a time independent of where they differ
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+	a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Reads the token of the admin API from `ADMIN_TOKEN`
+///
+/// # Returns
+/// * `Option<String>` - The token, or `None` if the variable is not set or empty
+pub fn admin_token_from_env() -> Option<String> {
+	env::var(ADMIN_TOKEN_ENV)
+		.ok()
+		.filter(|token| !token.is_empty())
+}
+
+/// Reload endpoint handler
+///
+/// Responds with 401 Unauthorized when the request doesn't carry the admin token, and with
+/// 400 Bad Request, keeping the previous monitors, when the new configurations are invalid.
+async fn reload_handler(
+	req: HttpRequest,
+	token: web::Data<AdminToken>,
+	reloader: web::Data<MonitorReloaderArc>,
+) -> impl Responder {
+	if !token.authorizes(&req) {
+		return HttpResponse::Unauthorized().json(serde_json::json!({
+			"status": "unauthorized",
+		}));
+	}
+
+	match reloader.reload().await {
+		Ok(active_monitors) => HttpResponse::Ok().json(serde_json::json!({
+			"status": "reloaded",
+			"active_monitors": active_monitors,
+		})),
+		Err(e) => {
+			error!("Failed to reload monitors: {:#}", e);
+			HttpResponse::BadRequest().json(serde_json::json!({
+				"status": "invalid",
+				"error": format!("{:#}", e),
+			}))
+		}
+	}
+}
+
+/// Creates the admin server
+///
+/// # Arguments
+/// * `bind_address` - Address to listen on, in `HOST:PORT` format
+/// * `reloader` - Reloader of the monitors of the running service
+/// * `token` - Token the requests must carry, see [`admin_token_from_env`]
+///
+/// # Returns
+/// * `std::io::Result<actix_web::dev::Server>` - Server to run, or an error if the address
+///   can't be bound, or isn't a loopback address while no token is set
+pub fn create_admin_server(
+	bind_address: String,
+	reloader: MonitorReloaderArc,
+	token: Option<String>,
+) -> std::io::Result<actix_web::dev::Server> {
+	if token.is_none() {
+		let addresses: Vec<SocketAddr> = bind_address.to_socket_addrs()?.collect();
+		if !addresses.iter().all(|address| address.ip().is_loopback()) {
+			return Err(std::io::Error::new(
+				std::io::ErrorKind::InvalidInput,
+				format!(
+					"Admin server address {} is not a loopback address, which requires {} to \
+					 be set",
+					bind_address, ADMIN_TOKEN_ENV
+				),
+			));
+		}
+	}
+	info!("Starting admin server on {}", bind_address);
+
+	let token = AdminToken(token);
+	Ok(HttpServer::new(move || {
+		App::new()
+			.wrap(NormalizePath::trim())
+			.app_data(web::Data::new(token.clone()))
+			.app_data(web::Data::new(reloader.clone()))
+			.route("/reload", web::post().to(reload_handler))
+	})
+	.workers(1)
+	.bind(bind_address)?
+	.shutdown_timeout(5)
+	.run())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		bootstrap::{ActiveMonitors, SharedActiveMonitors},
+		models::{BlockChainType, Monitor},
+		repositories::{MonitorService, NetworkService, TriggerService},
+		services::{notification::NotificationService, trigger::TriggerExecutionService},
+		utils::tests::{
+			evm::monitor::MonitorBuilder, network::NetworkBuilder, trigger::TriggerBuilder,
+		},
+	};
+	use actix_web::{http::StatusCode, test};
+	use std::{collections::HashMap, fs, path::Path};
+	use tempfile::TempDir;
+	use tokio::sync::{Mutex, RwLock};
+
+	fn create_test_monitor(name: &str, trigger: &str) -> Monitor {
+		MonitorBuilder::new()
+			.name(name)
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.triggers(vec![trigger.to_string()])
+			.build()
+	}
+
+	fn write_monitor(monitor_dir: &Path, monitor: &Monitor) {
+		fs::write(
+			monitor_dir.join(format!("{}.json", monitor.name)),
+			serde_json::to_string(monitor).unwrap(),
+		)
+		.unwrap();
+	}
+
+	/// Creates a reloader for a configuration directory with one monitor, along with the
+	/// active monitors it swaps and the directory holding the configurations
+	async fn create_test_reloader() -> (MonitorReloaderArc, SharedActiveMonitors, TempDir) {
+		let temp_dir = TempDir::new().unwrap();
+		let monitor_dir = temp_dir.path().join("monitors");
+		let trigger_dir = temp_dir.path().join("triggers");
+		let network_dir = temp_dir.path().join("networks");
+		for dir in [&monitor_dir, &trigger_dir, &network_dir] {
+			fs::create_dir_all(dir).unwrap();
+		}
+
+		let network = NetworkBuilder::new()
+			.name("Ethereum Mainnet")
+			.slug("ethereum_mainnet")
+			.network_type(BlockChainType::EVM)
+			.chain_id(1)
+			.rpc_url("http://localhost:8545")
+			.block_time_ms(1000)
+			.confirmation_blocks(1)
+			.cron_schedule("*/5 * * * * *")
+			.store_blocks(false)
+			.build();
+		fs::write(
+			network_dir.join("ethereum_mainnet.json"),
+			serde_json::to_string(&network).unwrap(),
+		)
+		.unwrap();
+		let trigger = TriggerBuilder::new()
+			.name("test_trigger")
+			.webhook("https://api.example.com/webhook")
+			.build();
+		fs::write(
+			trigger_dir.join("test_trigger.json"),
+			serde_json::json!({ "test_trigger": trigger }).to_string(),
+		)
+		.unwrap();
+		write_monitor(
+			&monitor_dir,
+			&create_test_monitor("first_monitor", "test_trigger"),
+		);
+
+		let network_service = NetworkService::<NetworkRepository>::new(Some(&network_dir))
+			.await
+			.unwrap();
+		let trigger_service = TriggerService::<TriggerRepository>::new(Some(&trigger_dir))
+			.await
+			.unwrap();
+		let monitor_service = MonitorService::new(
+			Some(&monitor_dir),
+			Some(network_service.clone()),
+			Some(trigger_service.clone()),
+		)
+		.await
+		.unwrap();
+
+		let active_monitors = Arc::new(RwLock::new(ActiveMonitors {
+			monitors: monitor_service.get_all().into_values().collect(),
+			..Default::default()
+		}));
+		let reloader = MonitorReloader::new(
+			Arc::new(Mutex::new(monitor_service)),
+			Arc::new(Mutex::new(network_service)),
+			Arc::new(Mutex::new(trigger_service.clone())),
+			Arc::new(TriggerExecutionService::new(
+				trigger_service,
+				NotificationService::new(),
+			)),
+			Arc::new(ClientPool::new()),
+			vec![network],
+			HashMap::from([(
+				"ethereum_mainnet".to_string(),
+				vec!["*/5 * * * * *".to_string()],
+			)]),
+			active_monitors.clone(),
+		)
+		.with_monitors_path(&monitor_dir);
+
+		(Arc::new(reloader), active_monitors, temp_dir)
+	}
+
+	fn active_monitor_names(active_monitors: &ActiveMonitors) -> Vec<String> {
+		let mut names: Vec<String> = active_monitors
+			.monitors
+			.iter()
+			.map(|monitor| monitor.name.clone())
+			.collect();
+		names.sort();
+		names
+	}
+
+	#[actix_web::test]
+	async fn test_reload_handler_swaps_active_monitors() {
+		let (reloader, active_monitors, temp_dir) = create_test_reloader().await;
+		let app = test::init_service(
+			App::new()
+				.app_data(web::Data::new(AdminToken::default()))
+				.app_data(web::Data::new(reloader))
+				.route("/reload", web::post().to(reload_handler)),
+		)
+		.await;
+
+		write_monitor(
+			&temp_dir.path().join("monitors"),
+			&create_test_monitor("second_monitor", "test_trigger"),
+		);
+
+		let req = test::TestRequest::post().uri("/reload").to_request();
+		let resp = test::call_service(&app, req).await;
+		assert_eq!(resp.status(), StatusCode::OK);
+
+		let body: serde_json::Value = test::read_body_json(resp).await;
+		assert_eq!(body["status"], "reloaded");
+		assert_eq!(body["active_monitors"], 2);
+		assert_eq!(
+			active_monitor_names(&*active_monitors.read().await),
+			vec!["first_monitor", "second_monitor"]
+		);
+	}
+
+	#[actix_web::test]
+	async fn test_reload_handler_keeps_monitors_on_invalid_config() {
+		let (reloader, active_monitors, temp_dir) = create_test_reloader().await;
+		let app = test::init_service(
+			App::new()
+				.app_data(web::Data::new(AdminToken::default()))
+				.app_data(web::Data::new(reloader))
+				.route("/reload", web::post().to(reload_handler)),
+		)
+		.await;
+
+		write_monitor(
+			&temp_dir.path().join("monitors"),
+			&create_test_monitor("second_monitor", "test_trigger"),
+		);
+		write_monitor(
+			&temp_dir.path().join("monitors"),
+			&create_test_monitor("broken_monitor", "unknown_trigger"),
+		);
+
+		let req = test::TestRequest::post().uri("/reload").to_request();
+		let resp = test::call_service(&app, req).await;
+		assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+		let body: serde_json::Value = test::read_body_json(resp).await;
+		assert_eq!(body["status"], "invalid");
+		assert_eq!(
+			active_monitor_names(&*active_monitors.read().await),
+			vec!["first_monitor"]
+		);
+	}
+
+	#[actix_web::test]
+	async fn test_reload_handler_rejects_cron_schedule_without_job() {
+		let (reloader, active_monitors, temp_dir) = create_test_reloader().await;
+		let app = test::init_service(
+			App::new()
+				.app_data(web::Data::new(AdminToken::default()))
+				.app_data(web::Data::new(reloader))
+				.route("/reload", web::post().to(reload_handler)),
+		)
+		.await;
+
+		let mut monitor = create_test_monitor("scheduled_monitor", "test_trigger");
+		monitor.cron_schedule = Some("0 * * * * *".to_string());
+		write_monitor(&temp_dir.path().join("monitors"), &monitor);
+
+		let req = test::TestRequest::post().uri("/reload").to_request();
+		let resp = test::call_service(&app, req).await;
+		assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+		let body: serde_json::Value = test::read_body_json(resp).await;
+		assert_eq!(body["status"], "invalid");
+		assert!(body["error"]
+			.as_str()
+			.unwrap()
+			.contains("cron schedule 0 * * * * *"));
+		assert_eq!(
+			active_monitor_names(&*active_monitors.read().await),
+			vec!["first_monitor"]
+		);
+	}
+
+	#[actix_web::test]
+	async fn test_reload_handler_requires_token() {
+		let (reloader, active_monitors, temp_dir) = create_test_reloader().await;
+		let app = test::init_service(
+			App::new()
+				.app_data(web::Data::new(AdminToken(Some("secret".to_string()))))
+				.app_data(web::Data::new(reloader))
+				.route("/reload", web::post().to(reload_handler)),
+		)
+		.await;
+
+		write_monitor(
+			&temp_dir.path().join("monitors"),
+			&create_test_monitor("second_monitor", "test_trigger"),
+		);
+
+		for authorization in [None, Some("Bearer wrong"), Some("secret")] {
+			let mut req = test::TestRequest::post().uri("/reload");
+			if let Some(authorization) = authorization {
+				req = req.insert_header((header::AUTHORIZATION, authorization));
+			}
+			let resp = test::call_service(&app, req.to_request()).await;
+			assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+		}
+		assert_eq!(
+			active_monitor_names(&*active_monitors.read().await),
+			vec!["first_monitor"]
+		);
+
+		let req = test::TestRequest::post()
+			.uri("/reload")
+			.insert_header((header::AUTHORIZATION, "Bearer secret"))
+			.to_request();
+		let resp = test::call_service(&app, req).await;
+		assert_eq!(resp.status(), StatusCode::OK);
+		assert_eq!(
+			active_monitor_names(&*active_monitors.read().await),
+			vec!["first_monitor", "second_monitor"]
+		);
+	}
+
+	#[actix_web::test]
+	async fn test_admin_server_without_token_only_binds_loopback() {
+		let (reloader, _, _temp_dir) = create_test_reloader().await;
+
+		let err = create_admin_server("0.0.0.0:0".to_string(), reloader.clone(), None)
+			.err()
+			.unwrap();
+		assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+		for (bind_address, token) in [
+			("127.0.0.1:0", None),
+			("0.0.0.0:0", Some("secret".to_string())),
+		] {
+			let server = create_admin_server(bind_address.to_string(), reloader.clone(), token)
+				.unwrap_or_else(|e| panic!("Failed to bind {}: {}", bind_address, e));
+			let handle = server.handle();
+			tokio::spawn(server);
+			handle.stop(false).await;
+		}
+	}
+}
@@ -0,0 +1,243 @@
+//! Cache of RPC responses of the blockchain clients.
+//!
+//! Several monitors evaluating the same block may fetch the same receipts or events. A
+//! [`ResponseCache`] keeps a bounded number of recently fetched responses, evicting the least
+//! recently used one when full. Entries expire after a time to live, so that a block fetched
+//! again after a reorg isn't served from the cache for long.
+
+use std::{
+	collections::{BTreeMap, HashMap},
+	future::Future,
+	hash::Hash,
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+use crate::utils::metrics::{RPC_CACHE_HITS_TOTAL, RPC_CACHE_MISSES_TOTAL};
+
+/// Minimum time to live of the cached responses
+const MIN_TTL: Duration = Duration::from_secs(1);
+
+/// Cached response
+struct CacheEntry<V> {
+	value: V,
+	inserted_at: Instant,
+	/// Position of the entry in the usage order
+	tick: u64,
+}
+
+/// Cached responses along with their usage order
+struct CacheEntries<K, V> {
+	entries: HashMap<K, CacheEntry<V>>,
+	/// Keys of the entries by position in the usage order, least recently used first
+	usage: BTreeMap<u64, K>,
+	next_tick: u64,
+}
+
+impl<K: Eq + Hash + Clone, V> CacheEntries<K, V> {
+	fn remove(&mut self, key: &K) {
+		if let Some(entry) = self.entries.remove(key) {
+			self.usage.remove(&entry.tick);
+		}
+	}
+
+	fn touch(&mut self, key: &K) {
+		let tick = self.next_tick;
+		if let Some(entry) = self.entries.get_mut(key) {
+			self.usage.remove(&entry.tick);
+			entry.tick = tick;
+			self.usage.insert(tick, key.clone());
+			self.next_tick += 1;
+		}
+	}
+}
+
+/// Bounded least recently used cache of RPC responses, whose entries expire after a time to
+/// live
+///
+/// A cache with a capacity of 0 is disabled and never holds any entry.
+pub struct ResponseCache<K, V> {
+	network_slug: String,
+	name: &'static str,
+	capacity: usize,
+	ttl: Duration,
+	entries: Mutex<CacheEntries<K, V>>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> ResponseCache<K, V> {
+	/// Creates an empty cache
+	///
+	/// # Arguments
+	/// * `network_slug` - Network of the cached responses, used as a metric label
+	/// * `name` - Kind of the cached responses, used as a metric label
+	/// * `capacity` - Maximum number of entries, 0 disabling the cache
+	/// * `ttl` - Time after which entries expire, at least 1 second
+	pub fn new(network_slug: &str, name: &'static str, capacity: usize, ttl: Duration) -> Self {
+		Self {
+			network_slug: network_slug.to_string(),
+			name,
+			capacity,
+			ttl: ttl.max(MIN_TTL),
+			entries: Mutex::new(CacheEntries {
+				entries: HashMap::new(),
+				usage: BTreeMap::new(),
+				next_tick: 0,
+			}),
+		}
+	}
+
+	/// Creates a cache that never holds any entry
+	pub fn disabled(name: &'static str) -> Self {
+		Self::new("", name, 0, MIN_TTL)
+	}
+
+	/// Returns whether the cache may hold entries
+	pub fn is_enabled(&self) -> bool {
+		self.capacity > 0
+	}
+
+	/// Returns the number of entries, including expired ones not evicted yet
+	pub fn len(&self) -> usize {
+		self.entries.lock().unwrap().entries.len()
+	}
+
+	/// Returns whether the cache holds no entry
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns the cached value of a key, unless it expired
+	pub fn get(&self, key: &K) -> Option<V> {
+		if !self.is_enabled() {
+			return None;
+		}
+
+		let mut entries = self.entries.lock().unwrap();
+		let value = match entries.entries.get(key) {
+			Some(entry) if entry.inserted_at.elapsed() < self.ttl => Some(entry.value.clone()),
+			Some(_) => {
+				entries.remove(key);
+				None
+			}
+			None => None,
+		};
+
+		let labels = [self.network_slug.as_str(), self.name];
+		if value.is_some() {
+			entries.touch(key);
+			RPC_CACHE_HITS_TOTAL.with_label_values(&labels).inc();
+		} else {
+			RPC_CACHE_MISSES_TOTAL.with_label_values(&labels).inc();
+		}
+		value
+	}
+
+	/// Caches the value of a key, evicting the least recently used entry when full
+	pub fn insert(&self, key: K, value: V) {
+		if !self.is_enabled() {
+			return;
+		}
+
+		let mut entries = self.entries.lock().unwrap();
+		entries.remove(&key);
+		while entries.entries.len() >= self.capacity {
+			let Some((_, evicted)) = entries.usage.pop_first() else {
+				break;
+			};
+			entries.entries.remove(&evicted);
+		}
+
+		let tick = entries.next_tick;
+		entries.next_tick += 1;
+		entries.usage.insert(tick, key.clone());
+		entries.entries.insert(
+			key,
+			CacheEntry {
+				value,
+				inserted_at: Instant::now(),
+				tick,
+			},
+		);
+	}
+
+	/// Returns the cached value of a key, or fetches and caches it
+	///
+	/// Failed fetches aren't cached.
+	pub async fn get_or_fetch<F, Fut>(&self, key: K, fetch: F) -> Result<V, anyhow::Error>
+	where
+		F: FnOnce() -> Fut,
+		Fut: Future<Output = Result<V, anyhow::Error>>,
+	{
+		if let Some(value) = self.get(&key) {
+			return Ok(value);
+		}
+
+		let value = fetch().await?;
+		self.insert(key, value.clone());
+		Ok(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_evicts_least_recently_used_entry() {
+		let cache = ResponseCache::new("test_network", "test", 2, Duration::from_secs(60));
+		cache.insert(1, "one");
+		cache.insert(2, "two");
+		assert_eq!(cache.get(&1), Some("one"));
+
+		cache.insert(3, "three");
+		assert_eq!(cache.len(), 2);
+		assert_eq!(cache.get(&2), None);
+		assert_eq!(cache.get(&1), Some("one"));
+		assert_eq!(cache.get(&3), Some("three"));
+	}
+
+	#[test]
+	fn test_expired_entries_are_not_served() {
+		let cache = ResponseCache::new("test_network", "test", 2, Duration::from_secs(1));
+		cache.insert(1, "one");
+		assert_eq!(cache.get(&1), Some("one"));
+
+		std::thread::sleep(Duration::from_millis(1100));
+		assert_eq!(cache.get(&1), None);
+		assert!(cache.is_empty());
+	}
+
+	#[test]
+	fn test_disabled_cache_holds_no_entry() {
+		let cache = ResponseCache::disabled("test");
+		cache.insert(1, "one");
+		assert!(!cache.is_enabled());
+		assert!(cache.is_empty());
+		assert_eq!(cache.get(&1), None);
+	}
+
+	#[tokio::test]
+	async fn test_get_or_fetch_counts_hits_and_misses() {
+		let cache = ResponseCache::new("cache_test_network", "test", 2, Duration::from_secs(60));
+		let hits = RPC_CACHE_HITS_TOTAL.with_label_values(&["cache_test_network", "test"]);
+		let misses = RPC_CACHE_MISSES_TOTAL.with_label_values(&["cache_test_network", "test"]);
+		let (hits_before, misses_before) = (hits.get(), misses.get());
+
+		let value = cache.get_or_fetch(1, || async { Ok("one") }).await.unwrap();
+		assert_eq!(value, "one");
+		let value = cache
+			.get_or_fetch(1, || async { Err(anyhow::anyhow!("fetched twice")) })
+			.await
+			.unwrap();
+		assert_eq!(value, "one");
+
+		assert!(cache
+			.get_or_fetch(2, || async { Err(anyhow::anyhow!("failed")) })
+			.await
+			.is_err());
+		assert_eq!(cache.get(&2), None);
+
+		assert_eq!(hits.get() - hits_before, 1);
+		assert_eq!(misses.get() - misses_before, 3);
+	}
+}
@@ -3,13 +3,27 @@
 //! This module defines the common interface that all blockchain implementations
 //! must follow, ensuring consistent behavior across different blockchain types.
 
+use anyhow::Context;
 use async_trait::async_trait;
+use serde_json::Value;
 
 use crate::{
-	models::{BlockType, ContractSpec},
-	services::filter::BlockFilter,
+	models::{BlockType, ContractSpec, Network, RpcCall},
+	services::{blockchain::BlockchainTransport, filter::BlockFilter},
 };
 
+/// Maximum size in bytes of a result returned by [`BlockChainClient::call_rpc`]
+pub const MAX_RPC_RESULT_SIZE: usize = 64 * 1024;
+
+/// Result of a read-only contract call
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContractCallResult<T> {
+	/// The call returned a value
+	Returned(T),
+	/// The call reverted, with the reason reported by the node
+	Reverted(String),
+}
+
 /// Defines the core interface for blockchain clients
 ///
 /// This trait must be implemented by all blockchain-specific clients to provide
@@ -22,6 +36,23 @@ pub trait BlockChainClient: Send + Sync + Clone {
 	/// * `Result<u64, anyhow::Error>` - The latest block number or an error
 	async fn get_latest_block_number(&self) -> Result<u64, anyhow::Error>;
 
+	/// Retrieves the number of the most recent block to process
+	///
+	/// By default, this is the latest block minus the network's `confirmation_blocks`.
+	///
+	/// # Arguments
+	/// * `network` - Network the client is connected to
+	///
+	/// # Returns
+	/// * `Result<u64, anyhow::Error>` - The latest confirmed block number or an error
+	async fn get_latest_confirmed_block_number(
+		&self,
+		network: &Network,
+	) -> Result<u64, anyhow::Error> {
+		let latest_block = self.get_latest_block_number().await?;
+		Ok(latest_block.saturating_sub(network.confirmation_blocks))
+	}
+
 	/// Retrieves a range of blocks from the blockchain
 	///
 	/// # Arguments
@@ -40,6 +71,26 @@ pub trait BlockChainClient: Send + Sync + Clone {
 		end_block: Option<u64>,
 	) -> Result<Vec<BlockType>, anyhow::Error>;
 
+	/// Retrieves a range of blocks from the blockchain, bypassing the block cache
+	///
+	/// Cached blocks are keyed by number, so a block replaced by a reorg would still be served
+	/// from the cache by [`get_blocks`](Self::get_blocks). The fetched blocks replace the cached
+	/// ones, so that blocks processed again after a reorg are the current ones.
+	///
+	/// # Arguments
+	/// * `start_block` - The starting block number
+	/// * `end_block` - Optional ending block number. If None, only fetches start_block
+	///
+	/// # Returns
+	/// * `Result<Vec<BlockType>, anyhow::Error>` - Vector of blocks or an error
+	async fn refresh_blocks(
+		&self,
+		start_block: u64,
+		end_block: Option<u64>,
+	) -> Result<Vec<BlockType>, anyhow::Error> {
+		self.get_blocks(start_block, end_block).await
+	}
+
 	/// Retrieves the contract spec for a given contract ID
 	///
 	/// # Arguments
@@ -50,6 +101,66 @@ pub trait BlockChainClient: Send + Sync + Clone {
 	async fn get_contract_spec(&self, _contract_id: &str) -> Result<ContractSpec, anyhow::Error> {
 		Err(anyhow::anyhow!("get_contract_spec not implemented"))
 	}
+
+	/// Calls a read-only JSON-RPC method of the network
+	///
+	/// Methods that sign, submit or administer are rejected (see
+	/// [`RpcCall::is_method_allowed`]), as are results larger than [`MAX_RPC_RESULT_SIZE`].
+	///
+	/// # Arguments
+	/// * `method` - The JSON-RPC method to call
+	/// * `params` - Optional parameters of the call
+	///
+	/// # Returns
+	/// * `Result<Value, anyhow::Error>` - The result of the call or an error
+	async fn call_rpc(
+		&self,
+		_method: &str,
+		_params: Option<Value>,
+	) -> Result<Value, anyhow::Error> {
+		Err(anyhow::anyhow!("call_rpc not implemented"))
+	}
+}
+
+/// Calls a JSON-RPC method through a transport, implementing [`BlockChainClient::call_rpc`]
+pub(crate) async fn call_rpc_with_transport<T: BlockchainTransport>(
+	transport: &T,
+	method: &str,
+	params: Option<Value>,
+) -> Result<Value, anyhow::Error> {
+	if !RpcCall::is_method_allowed(method) {
+		return Err(anyhow::anyhow!("RPC method '{}' is not allowed", method));
+	}
+
+	let response = transport
+		.send_raw_request(method, params)
+		.await
+		.with_context(|| format!("Failed to call RPC method {}", method))?;
+
+	if let Some(error) = response.get("error") {
+		return Err(anyhow::anyhow!(
+			"RPC method {} returned an error: {}",
+			method,
+			error
+		));
+	}
+
+	let result = response
+		.get("result")
+		.cloned()
+		.ok_or_else(|| anyhow::anyhow!("Missing 'result' field"))?;
+
+	let size = result.to_string().len();
+	if size > MAX_RPC_RESULT_SIZE {
+		return Err(anyhow::anyhow!(
+			"Result of RPC method {} is too large ({} bytes, at most {} allowed)",
+			method,
+			size,
+			MAX_RPC_RESULT_SIZE
+		));
+	}
+
+	Ok(result)
 }
 
 /// Defines the factory interface for creating block filters
@@ -2,28 +2,42 @@
 //!
 //! This module provides functionality to interact with Ethereum and other EVM-compatible
 //! blockchains, supporting operations like block retrieval, transaction receipt lookup,
-//! and log filtering.
+//! log filtering and call tracing.
 
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc, time::Duration};
 
 use anyhow::Context;
 use async_trait::async_trait;
 use futures;
-use serde_json::json;
+use serde_json::{json, Value};
 use tracing::instrument;
 
 use crate::{
-	models::{BlockType, EVMBlock, EVMReceiptLog, EVMTransactionReceipt, Network},
+	models::{
+		BlockType, EVMBlock, EVMBlockTag, EVMInternalCall, EVMReceiptLog, EVMTraceMethod,
+		EVMTransactionReceipt, Network,
+	},
 	services::{
 		blockchain::{
-			client::BlockChainClient,
-			transports::{BlockchainTransport, EVMTransportClient},
-			BlockFilterFactory,
+			client::{call_rpc_with_transport, BlockChainClient, ContractCallResult},
+			transports::{
+				BlockchainTransport, EVMTransportClient, JsonRpcResponse, TransportError,
+			},
+			BlockFilterFactory, ResponseCache,
+		},
+		filter::{
+			evm_helpers::{are_same_address, b256_to_string, h160_to_string, string_to_h256},
+			EVMBlockFilter,
 		},
-		filter::{evm_helpers::string_to_h256, EVMBlockFilter},
 	},
 };
 
+/// Key of cached logs: block range, addresses and event topics
+type LogsCacheKey = (u64, u64, Option<Vec<String>>, Option<Vec<String>>);
+
+/// Maximum number of receipts requested in a single JSON-RPC batch
+const RECEIPTS_BATCH_SIZE: usize = 100;
+
 /// Client implementation for Ethereum Virtual Machine (EVM) compatible blockchains
 ///
 /// Provides high-level access to EVM blockchain data and operations through HTTP transport.
@@ -31,12 +45,41 @@ use crate::{
 pub struct EvmClient<T: Send + Sync + Clone> {
 	/// The underlying HTTP transport client for RPC communication
 	http_client: T,
+	/// Cache of blocks by number
+	blocks: Arc<ResponseCache<u64, BlockType>>,
+	/// Cache of transaction receipts by transaction hash
+	receipts: Arc<ResponseCache<String, EVMTransactionReceipt>>,
+	/// Cache of logs by block range and filter
+	logs: Arc<ResponseCache<LogsCacheKey, Vec<EVMReceiptLog>>>,
 }
 
 impl<T: Send + Sync + Clone> EvmClient<T> {
 	/// Creates a new EVM client instance with a specific transport client
+	///
+	/// Responses aren't cached, see [`EvmClient::with_cache`].
 	pub fn new_with_transport(http_client: T) -> Self {
-		Self { http_client }
+		Self {
+			http_client,
+			blocks: Arc::new(ResponseCache::disabled("blocks")),
+			receipts: Arc::new(ResponseCache::disabled("receipts")),
+			logs: Arc::new(ResponseCache::disabled("logs")),
+		}
+	}
+
+	/// Caches the fetched blocks, receipts and logs
+	///
+	/// Each cache holds up to `rpc_cache_size` entries of the network, which expire after one
+	/// block time. Caching is disabled when `rpc_cache_size` is unset or 0.
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing the cache size and block time
+	pub fn with_cache(mut self, network: &Network) -> Self {
+		let capacity = network.rpc_cache_size.unwrap_or(0);
+		let ttl = Duration::from_millis(network.block_time_ms);
+		self.blocks = Arc::new(ResponseCache::new(&network.slug, "blocks", capacity, ttl));
+		self.receipts = Arc::new(ResponseCache::new(&network.slug, "receipts", capacity, ttl));
+		self.logs = Arc::new(ResponseCache::new(&network.slug, "logs", capacity, ttl));
+		self
 	}
 }
 
@@ -50,7 +93,7 @@ impl EvmClient<EVMTransportClient> {
 	/// * `Result<Self, anyhow::Error>` - New client instance or connection error
 	pub async fn new(network: &Network) -> Result<Self, anyhow::Error> {
 		let client = EVMTransportClient::new(network).await?;
-		Ok(Self::new_with_transport(client))
+		Ok(Self::new_with_transport(client).with_cache(network))
 	}
 }
 
@@ -59,6 +102,7 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockFilterFactory<Self> for
 	fn filter() -> Self::Filter {
 		EVMBlockFilter {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		}
 	}
 }
@@ -78,6 +122,27 @@ pub trait EvmClientTrait {
 		transaction_hash: String,
 	) -> Result<EVMTransactionReceipt, anyhow::Error>;
 
+	/// Retrieves the receipts of several transactions
+	///
+	/// The default implementation retrieves the receipts one after the other.
+	///
+	/// # Arguments
+	/// * `transaction_hashes` - The hashes of the transactions to look up
+	///
+	/// # Returns
+	/// * `Result<Vec<TransactionReceipt>, anyhow::Error>` - Transaction receipts in the order of
+	///   the hashes or error
+	async fn get_transaction_receipts(
+		&self,
+		transaction_hashes: Vec<String>,
+	) -> Result<Vec<EVMTransactionReceipt>, anyhow::Error> {
+		let mut receipts = Vec::with_capacity(transaction_hashes.len());
+		for transaction_hash in transaction_hashes {
+			receipts.push(self.get_transaction_receipt(transaction_hash).await?);
+		}
+		Ok(receipts)
+	}
+
 	/// Retrieves logs for a range of blocks
 	///
 	/// # Arguments
@@ -92,9 +157,239 @@ pub trait EvmClientTrait {
 		to_block: u64,
 		addresses: Option<Vec<String>>,
 	) -> Result<Vec<EVMReceiptLog>, anyhow::Error>;
+
+	/// Retrieves logs for a range of blocks emitted by the given addresses for the given events
+	///
+	/// The default implementation fetches the logs of the addresses and filters them by topic
+	/// locally.
+	///
+	/// # Arguments
+	/// * `from_block` - Starting block number
+	/// * `to_block` - Ending block number
+	/// * `addresses` - Addresses to filter logs by
+	/// * `topics` - Event topics (first log topic) to filter logs by
+	/// # Returns
+	/// * `Result<Vec<Log>, anyhow::Error>` - Collection of matching logs or error
+	async fn get_event_logs_for_blocks(
+		&self,
+		from_block: u64,
+		to_block: u64,
+		addresses: Vec<String>,
+		topics: Vec<String>,
+	) -> Result<Vec<EVMReceiptLog>, anyhow::Error> {
+		let logs = self
+			.get_logs_for_blocks(from_block, to_block, Some(addresses.clone()))
+			.await?;
+
+		Ok(logs
+			.into_iter()
+			.filter(|log| {
+				let address = h160_to_string(log.address);
+				addresses.iter().any(|a| are_same_address(a, &address))
+					&& log
+						.topics
+						.first()
+						.is_some_and(|topic| topics.contains(&b256_to_string(*topic)))
+			})
+			.collect())
+	}
+
+	/// Retrieves the internal calls made by the transactions of a block
+	///
+	/// # Arguments
+	/// * `block_number` - Number of the block to trace
+	/// * `trace_method` - Tracing method supported by the RPC endpoint
+	/// # Returns
+	/// * `Result<Vec<EVMInternalCall>, anyhow::Error>` - Nested calls of the block's
+	///   transactions or error, notably when the endpoint doesn't support tracing
+	async fn get_internal_calls(
+		&self,
+		block_number: u64,
+		trace_method: EVMTraceMethod,
+	) -> Result<Vec<EVMInternalCall>, anyhow::Error>;
+
+	/// Calls a contract without sending a transaction, with `eth_call`
+	///
+	/// # Arguments
+	/// * `to` - Address of the called contract
+	/// * `data` - ABI-encoded call data, starting with the function selector
+	/// * `block_number` - Number of the block whose state the call reads
+	/// # Returns
+	/// * `Result<ContractCallResult<Vec<u8>>, anyhow::Error>` - ABI-encoded return data or
+	///   revert reason of the call, or error
+	async fn call_contract(
+		&self,
+		to: &str,
+		data: Vec<u8>,
+		block_number: u64,
+	) -> Result<ContractCallResult<Vec<u8>>, anyhow::Error>;
+
+	/// Retrieves the code deployed at an address, with `eth_getCode`
+	///
+	/// # Arguments
+	/// * `address` - Address of the contract
+	/// * `block_number` - Number of the block whose state the code is read from
+	/// # Returns
+	/// * `Result<Vec<u8>, anyhow::Error>` - Deployed bytecode, empty for accounts without code,
+	///   or error
+	async fn get_code(&self, address: &str, block_number: u64) -> Result<Vec<u8>, anyhow::Error>;
+
+	/// Retrieves the revert data of a failed transaction, with `debug_traceTransaction`
+	///
+	/// # Arguments
+	/// * `transaction_hash` - Hash of the failed transaction
+	/// # Returns
+	/// * `Result<Option<Vec<u8>>, anyhow::Error>` - ABI-encoded revert data, `None` if the
+	///   transaction didn't revert, or error, notably when the endpoint doesn't support tracing
+	async fn get_revert_data(
+		&self,
+		transaction_hash: &str,
+	) -> Result<Option<Vec<u8>>, anyhow::Error>;
+}
+
+/// Parses the transaction receipt of an `eth_getTransactionReceipt` response
+fn parse_transaction_receipt(response: &Value) -> Result<EVMTransactionReceipt, anyhow::Error> {
+	// Extract the "result" field from the JSON-RPC response
+	let receipt_data = response
+		.get("result")
+		.with_context(|| "Missing 'result' field")?;
+
+	// Handle null response case
+	if receipt_data.is_null() {
+		return Err(anyhow::anyhow!("Transaction receipt not found"));
+	}
+
+	serde_json::from_value(receipt_data.clone())
+		.with_context(|| "Failed to parse transaction receipt")
+}
+
+/// Collects the nested calls of a `callTracer` call frame
+fn collect_call_frames(
+	frame: &Value,
+	transaction_position: usize,
+	calls: &mut Vec<EVMInternalCall>,
+) -> Result<(), anyhow::Error> {
+	for call in frame
+		.get("calls")
+		.and_then(Value::as_array)
+		.into_iter()
+		.flatten()
+	{
+		calls.push(EVMInternalCall {
+			transaction_position,
+			call_type: call
+				.get("type")
+				.and_then(Value::as_str)
+				.unwrap_or("call")
+				.to_lowercase(),
+			from: serde_json::from_value(call.get("from").cloned().unwrap_or_default())
+				.with_context(|| "Failed to parse call sender")?,
+			to: serde_json::from_value(call.get("to").cloned().unwrap_or_default())
+				.with_context(|| "Failed to parse call recipient")?,
+			value: serde_json::from_value(call.get("value").cloned().unwrap_or(json!("0x0")))
+				.with_context(|| "Failed to parse call value")?,
+		});
+		collect_call_frames(call, transaction_position, calls)?;
+	}
+	Ok(())
+}
+
+/// Parses the result of `debug_traceBlockByNumber` with the `callTracer` tracer
+///
+/// The result holds the call frame of each transaction of the block, in block order.
+fn parse_debug_traces(traces: &[Value]) -> Result<Vec<EVMInternalCall>, anyhow::Error> {
+	let mut calls = Vec::new();
+	for (transaction_position, trace) in traces.iter().enumerate() {
+		let frame = trace
+			.get("result")
+			.with_context(|| "Missing 'result' field in transaction trace")?;
+		collect_call_frames(frame, transaction_position, &mut calls)?;
+	}
+	Ok(calls)
+}
+
+/// Parses the result of `trace_block`
+///
+/// The result is a flat list of traces, where top-level calls have an empty `traceAddress` and
+/// block rewards have no `transactionPosition`.
+fn parse_parity_traces(traces: &[Value]) -> Result<Vec<EVMInternalCall>, anyhow::Error> {
+	let mut calls = Vec::new();
+	for trace in traces {
+		let Some(transaction_position) = trace.get("transactionPosition").and_then(Value::as_u64)
+		else {
+			continue;
+		};
+		let is_nested = trace
+			.get("traceAddress")
+			.and_then(Value::as_array)
+			.is_some_and(|address| !address.is_empty());
+		let trace_type = trace
+			.get("type")
+			.and_then(Value::as_str)
+			.unwrap_or_default();
+		if !is_nested || !matches!(trace_type, "call" | "create") {
+			continue;
+		}
+
+		let action = trace
+			.get("action")
+			.with_context(|| "Missing 'action' field in trace")?;
+		// Created contracts are only known from the result of the trace
+		let to = if trace_type == "create" {
+			trace.get("result").and_then(|result| result.get("address"))
+		} else {
+			action.get("to")
+		};
+
+		calls.push(EVMInternalCall {
+			transaction_position: transaction_position as usize,
+			call_type: action
+				.get("callType")
+				.and_then(Value::as_str)
+				.unwrap_or(trace_type)
+				.to_lowercase(),
+			from: serde_json::from_value(action.get("from").cloned().unwrap_or_default())
+				.with_context(|| "Failed to parse call sender")?,
+			to: serde_json::from_value(to.cloned().unwrap_or_default())
+				.with_context(|| "Failed to parse call recipient")?,
+			value: serde_json::from_value(action.get("value").cloned().unwrap_or(json!("0x0")))
+				.with_context(|| "Failed to parse call value")?,
+		});
+	}
+	Ok(calls)
 }
 
 #[async_trait]
+/// Fetches a block with its full transaction objects through `eth_getBlockByNumber`
+async fn fetch_block<T: BlockchainTransport>(
+	client: &T,
+	block_number: u64,
+) -> Result<BlockType, anyhow::Error> {
+	let params = json!([
+		format!("0x{:x}", block_number),
+		true // include full transaction objects
+	]);
+
+	// The block is deserialized straight from the response body, so that its transactions are
+	// only held in memory once
+	let response = client
+		.send_request::<_, JsonRpcResponse<Option<EVMBlock>>>("eth_getBlockByNumber", Some(params))
+		.await
+		.map_err(|e| {
+			let context = match e {
+				TransportError::ResponseParse(_) => "Failed to parse block",
+				_ => "Failed to get block",
+			};
+			anyhow::Error::from(e).context(format!("{}: {}", context, block_number))
+		})?;
+
+	match response.result {
+		None => Err(anyhow::anyhow!("Missing 'result' field")),
+		Some(None) => Err(anyhow::anyhow!("Block not found")),
+		Some(Some(block)) => Ok(BlockType::EVM(Box::new(block))),
+	}
+}
+
 impl<T: Send + Sync + Clone + BlockchainTransport> EvmClientTrait for EvmClient<T> {
 	/// Retrieves a transaction receipt by hash with proper error handling
 	#[instrument(skip(self), fields(transaction_hash))]
@@ -104,33 +399,76 @@ impl<T: Send + Sync + Clone + BlockchainTransport> EvmClientTrait for EvmClient<
 	) -> Result<EVMTransactionReceipt, anyhow::Error> {
 		let hash = string_to_h256(&transaction_hash)
 			.map_err(|e| anyhow::anyhow!("Invalid transaction hash: {}", e))?;
+		let hash = format!("0x{:x}", hash);
 
-		let params = json!([format!("0x{:x}", hash)])
-			.as_array()
-			.with_context(|| "Failed to create JSON-RPC params array")?
-			.to_vec();
+		self.receipts
+			.get_or_fetch(hash.clone(), || async {
+				let params = json!([hash])
+					.as_array()
+					.with_context(|| "Failed to create JSON-RPC params array")?
+					.to_vec();
 
-		let response = self
-			.http_client
-			.send_raw_request(
-				"eth_getTransactionReceipt",
-				Some(serde_json::Value::Array(params)),
-			)
+				let response = self
+					.http_client
+					.send_raw_request(
+						"eth_getTransactionReceipt",
+						Some(serde_json::Value::Array(params)),
+					)
+					.await
+					.with_context(|| {
+						format!("Failed to get transaction receipt: {}", transaction_hash)
+					})?;
+
+				parse_transaction_receipt(&response)
+			})
 			.await
-			.with_context(|| format!("Failed to get transaction receipt: {}", transaction_hash))?;
+	}
 
-		// Extract the "result" field from the JSON-RPC response
-		let receipt_data = response
-			.get("result")
-			.with_context(|| "Missing 'result' field")?;
+	/// Retrieves the receipts of several transactions, in JSON-RPC batches of up to
+	/// `RECEIPTS_BATCH_SIZE` requests
+	///
+	/// Cached receipts aren't requested again.
+	#[instrument(skip_all, fields(count = transaction_hashes.len()))]
+	async fn get_transaction_receipts(
+		&self,
+		transaction_hashes: Vec<String>,
+	) -> Result<Vec<EVMTransactionReceipt>, anyhow::Error> {
+		let hashes = transaction_hashes
+			.iter()
+			.map(|transaction_hash| {
+				string_to_h256(transaction_hash)
+					.map(|hash| format!("0x{:x}", hash))
+					.map_err(|e| anyhow::anyhow!("Invalid transaction hash: {}", e))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let mut receipts: Vec<Option<EVMTransactionReceipt>> =
+			hashes.iter().map(|hash| self.receipts.get(hash)).collect();
+		let uncached: Vec<usize> = (0..hashes.len())
+			.filter(|&index| receipts[index].is_none())
+			.collect();
 
-		// Handle null response case
-		if receipt_data.is_null() {
-			return Err(anyhow::anyhow!("Transaction receipt not found"));
+		for batch in uncached.chunks(RECEIPTS_BATCH_SIZE) {
+			let requests: Vec<(&str, Option<Value>)> = batch
+				.iter()
+				.map(|&index| ("eth_getTransactionReceipt", Some(json!([hashes[index]]))))
+				.collect();
+			let responses = self
+				.http_client
+				.send_batch_request(&requests)
+				.await
+				.with_context(|| format!("Failed to get {} transaction receipts", batch.len()))?;
+
+			for (&index, response) in batch.iter().zip(&responses) {
+				let receipt = parse_transaction_receipt(response).with_context(|| {
+					format!("Failed to get transaction receipt: {}", hashes[index])
+				})?;
+				self.receipts.insert(hashes[index].clone(), receipt.clone());
+				receipts[index] = Some(receipt);
+			}
 		}
 
-		Ok(serde_json::from_value(receipt_data.clone())
-			.with_context(|| "Failed to parse transaction receipt")?)
+		Ok(receipts.into_iter().flatten().collect())
 	}
 
 	/// Retrieves logs within the specified block range
@@ -148,34 +486,298 @@ impl<T: Send + Sync + Clone + BlockchainTransport> EvmClientTrait for EvmClient<
 		to_block: u64,
 		addresses: Option<Vec<String>>,
 	) -> Result<Vec<EVMReceiptLog>, anyhow::Error> {
-		// Convert parameters to JSON-RPC format
-		let params = json!([{
-			"fromBlock": format!("0x{:x}", from_block),
-			"toBlock": format!("0x{:x}", to_block),
-			"address": addresses
-		}])
+		let key = (from_block, to_block, addresses.clone(), None);
+		self.logs
+			.get_or_fetch(key, || async {
+				// Convert parameters to JSON-RPC format
+				let params = json!([{
+					"fromBlock": format!("0x{:x}", from_block),
+					"toBlock": format!("0x{:x}", to_block),
+					"address": addresses
+				}])
+				.as_array()
+				.with_context(|| "Failed to create JSON-RPC params array")?
+				.to_vec();
+
+				let response = self
+					.http_client
+					.send_raw_request("eth_getLogs", Some(params))
+					.await
+					.with_context(|| {
+						format!(
+							"Failed to get logs for blocks: {} - {}",
+							from_block, to_block
+						)
+					})?;
+
+				// Extract the "result" field from the JSON-RPC response
+				let logs_data = response
+					.get("result")
+					.with_context(|| "Missing 'result' field")?;
+
+				// Parse the response into the expected type
+				Ok(serde_json::from_value(logs_data.clone())
+					.with_context(|| "Failed to parse logs")?)
+			})
+			.await
+	}
+
+	/// Retrieves logs within the specified block range, filtered by address and event topic
+	/// by the RPC node
+	///
+	/// # Arguments
+	/// * `from_block` - Starting block number
+	/// * `to_block` - Ending block number
+	/// * `addresses` - Addresses to filter logs by
+	/// * `topics` - Event topics (first log topic) to filter logs by
+	/// # Returns
+	/// * `Result<Vec<EVMReceiptLog>, anyhow::Error>` - Collection of matching logs or error
+	#[instrument(skip(self), fields(from_block, to_block))]
+	async fn get_event_logs_for_blocks(
+		&self,
+		from_block: u64,
+		to_block: u64,
+		addresses: Vec<String>,
+		topics: Vec<String>,
+	) -> Result<Vec<EVMReceiptLog>, anyhow::Error> {
+		let key = (
+			from_block,
+			to_block,
+			Some(addresses.clone()),
+			Some(topics.clone()),
+		);
+		self.logs
+			.get_or_fetch(key, || async {
+				// Convert parameters to JSON-RPC format, matching any of the topics in first
+				// position
+				let params = json!([{
+					"fromBlock": format!("0x{:x}", from_block),
+					"toBlock": format!("0x{:x}", to_block),
+					"address": addresses,
+					"topics": [topics]
+				}])
+				.as_array()
+				.with_context(|| "Failed to create JSON-RPC params array")?
+				.to_vec();
+
+				let response = self
+					.http_client
+					.send_raw_request("eth_getLogs", Some(params))
+					.await
+					.with_context(|| {
+						format!(
+							"Failed to get event logs for blocks: {} - {}",
+							from_block, to_block
+						)
+					})?;
+
+				// Extract the "result" field from the JSON-RPC response
+				let logs_data = response
+					.get("result")
+					.with_context(|| "Missing 'result' field")?;
+
+				// Parse the response into the expected type
+				Ok(serde_json::from_value(logs_data.clone())
+					.with_context(|| "Failed to parse logs")?)
+			})
+			.await
+	}
+
+	/// Retrieves the internal calls made by the transactions of a block
+	///
+	/// # Arguments
+	/// * `block_number` - Number of the block to trace
+	/// * `trace_method` - Tracing method supported by the RPC endpoint
+	/// # Returns
+	/// * `Result<Vec<EVMInternalCall>, anyhow::Error>` - Nested calls of the block's
+	///   transactions or error
+	#[instrument(skip(self), fields(block_number))]
+	async fn get_internal_calls(
+		&self,
+		block_number: u64,
+		trace_method: EVMTraceMethod,
+	) -> Result<Vec<EVMInternalCall>, anyhow::Error> {
+		let method = trace_method.method_name();
+		let block = format!("0x{:x}", block_number);
+		let params = match trace_method {
+			EVMTraceMethod::DebugTraceBlockByNumber => json!([block, { "tracer": "callTracer" }]),
+			EVMTraceMethod::TraceBlock => json!([block]),
+		}
 		.as_array()
 		.with_context(|| "Failed to create JSON-RPC params array")?
 		.to_vec();
 
 		let response = self
 			.http_client
-			.send_raw_request("eth_getLogs", Some(params))
+			.send_raw_request(method, Some(params))
 			.await
 			.with_context(|| {
 				format!(
-					"Failed to get logs for blocks: {} - {}",
-					from_block, to_block
+					"Failed to trace block {} with {}, the RPC endpoint may not support tracing",
+					block_number, method
 				)
 			})?;
 
-		// Extract the "result" field from the JSON-RPC response
-		let logs_data = response
+		if let Some(error) = response.get("error") {
+			return Err(anyhow::anyhow!(
+				"RPC endpoint does not support tracing with {}: {}",
+				method,
+				error
+					.get("message")
+					.and_then(Value::as_str)
+					.unwrap_or("unknown error")
+			));
+		}
+
+		let traces = response
+			.get("result")
+			.and_then(Value::as_array)
+			.with_context(|| "Missing 'result' field")?;
+
+		match trace_method {
+			EVMTraceMethod::DebugTraceBlockByNumber => parse_debug_traces(traces),
+			EVMTraceMethod::TraceBlock => parse_parity_traces(traces),
+		}
+		.with_context(|| format!("Failed to parse traces of block {}", block_number))
+	}
+
+	/// Calls a contract without sending a transaction, with `eth_call`
+	///
+	/// Nodes report reverts as JSON-RPC errors with code 3 or a message mentioning the revert,
+	/// which are returned as [`ContractCallResult::Reverted`].
+	///
+	/// # Arguments
+	/// * `to` - Address of the called contract
+	/// * `data` - ABI-encoded call data, starting with the function selector
+	/// * `block_number` - Number of the block whose state the call reads
+	/// # Returns
+	/// * `Result<ContractCallResult<Vec<u8>>, anyhow::Error>` - ABI-encoded return data or
+	///   revert reason of the call, or error
+	#[instrument(skip(self, data), fields(to, block_number))]
+	async fn call_contract(
+		&self,
+		to: &str,
+		data: Vec<u8>,
+		block_number: u64,
+	) -> Result<ContractCallResult<Vec<u8>>, anyhow::Error> {
+		let params = json!([
+			{ "to": to, "data": format!("0x{}", hex::encode(&data)) },
+			format!("0x{:x}", block_number)
+		])
+		.as_array()
+		.with_context(|| "Failed to create JSON-RPC params array")?
+		.to_vec();
+
+		let response = self
+			.http_client
+			.send_raw_request("eth_call", Some(params))
+			.await
+			.with_context(|| format!("Failed to call contract {}", to))?;
+
+		if let Some(error) = response.get("error") {
+			let message = error
+				.get("message")
+				.and_then(Value::as_str)
+				.unwrap_or("unknown error");
+			if error.get("code").and_then(Value::as_i64) == Some(3)
+				|| message.to_lowercase().contains("revert")
+			{
+				return Ok(ContractCallResult::Reverted(message.to_string()));
+			}
+			return Err(anyhow::anyhow!(
+				"Failed to call contract {}: {}",
+				to,
+				message
+			));
+		}
+
+		let result = response
 			.get("result")
+			.and_then(Value::as_str)
 			.with_context(|| "Missing 'result' field")?;
+		let data = hex::decode(result.trim_start_matches("0x"))
+			.with_context(|| format!("Failed to decode result of call to {}", to))?;
+		Ok(ContractCallResult::Returned(data))
+	}
 
-		// Parse the response into the expected type
-		Ok(serde_json::from_value(logs_data.clone()).with_context(|| "Failed to parse logs")?)
+	/// Retrieves the code deployed at an address, with `eth_getCode`
+	///
+	/// # Arguments
+	/// * `address` - Address of the contract
+	/// * `block_number` - Number of the block whose state the code is read from
+	/// # Returns
+	/// * `Result<Vec<u8>, anyhow::Error>` - Deployed bytecode, empty for accounts without code,
+	///   or error
+	#[instrument(skip(self), fields(address, block_number))]
+	async fn get_code(&self, address: &str, block_number: u64) -> Result<Vec<u8>, anyhow::Error> {
+		let params = json!([address, format!("0x{:x}", block_number)])
+			.as_array()
+			.with_context(|| "Failed to create JSON-RPC params array")?
+			.to_vec();
+
+		let response = self
+			.http_client
+			.send_raw_request("eth_getCode", Some(params))
+			.await
+			.with_context(|| format!("Failed to get code of {}", address))?;
+
+		let result = response
+			.get("result")
+			.and_then(Value::as_str)
+			.with_context(|| "Missing 'result' field")?;
+		hex::decode(result.trim_start_matches("0x"))
+			.with_context(|| format!("Failed to decode code of {}", address))
+	}
+
+	/// Retrieves the revert data of a failed transaction, with `debug_traceTransaction`
+	///
+	/// Only the top call of the transaction is traced, whose output is the revert data when it
+	/// failed.
+	///
+	/// # Arguments
+	/// * `transaction_hash` - Hash of the failed transaction
+	/// # Returns
+	/// * `Result<Option<Vec<u8>>, anyhow::Error>` - ABI-encoded revert data, `None` if the
+	///   transaction didn't revert, or error, notably when the endpoint doesn't support tracing
+	#[instrument(skip(self), fields(transaction_hash))]
+	async fn get_revert_data(
+		&self,
+		transaction_hash: &str,
+	) -> Result<Option<Vec<u8>>, anyhow::Error> {
+		let params = json!([
+			transaction_hash,
+			{ "tracer": "callTracer", "tracerConfig": { "onlyTopCall": true } }
+		])
+		.as_array()
+		.with_context(|| "Failed to create JSON-RPC params array")?
+		.to_vec();
+
+		let response = self
+			.http_client
+			.send_raw_request("debug_traceTransaction", Some(params))
+			.await
+			.with_context(|| format!("Failed to trace transaction {}", transaction_hash))?;
+
+		if let Some(error) = response.get("error") {
+			return Err(anyhow::anyhow!(
+				"RPC endpoint does not support tracing with debug_traceTransaction: {}",
+				error
+					.get("message")
+					.and_then(Value::as_str)
+					.unwrap_or("unknown error")
+			));
+		}
+
+		let trace = response
+			.get("result")
+			.with_context(|| "Missing 'result' field")?;
+		if trace.get("error").is_none() {
+			return Ok(None);
+		}
+		let output = trace.get("output").and_then(Value::as_str).unwrap_or("0x");
+		hex::decode(output.trim_start_matches("0x"))
+			.map(Some)
+			.with_context(|| format!("Failed to decode revert data of {}", transaction_hash))
 	}
 }
 
@@ -201,6 +803,42 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for EvmClien
 			.map_err(|e| anyhow::anyhow!("Failed to parse block number: {}", e))
 	}
 
+	/// Retrieves the number of the most recent block to process
+	///
+	/// Networks with a `safe` or `finalized` block tag process blocks up to the tagged block,
+	/// others up to `confirmation_blocks` blocks behind the latest block.
+	#[instrument(skip_all)]
+	async fn get_latest_confirmed_block_number(
+		&self,
+		network: &Network,
+	) -> Result<u64, anyhow::Error> {
+		let block_tag = match network.block_tag {
+			Some(block_tag @ (EVMBlockTag::Safe | EVMBlockTag::Finalized)) => block_tag,
+			_ => {
+				let latest_block = self.get_latest_block_number().await?;
+				return Ok(latest_block.saturating_sub(network.confirmation_blocks));
+			}
+		};
+
+		let response = self
+			.http_client
+			.send_raw_request(
+				"eth_getBlockByNumber",
+				Some(vec![json!(block_tag.as_str()), json!(false)]),
+			)
+			.await
+			.with_context(|| format!("Failed to get {} block", block_tag.as_str()))?;
+
+		let hex_str = response
+			.get("result")
+			.and_then(|block| block.get("number"))
+			.and_then(Value::as_str)
+			.ok_or_else(|| anyhow::anyhow!("Missing {} block number", block_tag.as_str()))?;
+
+		u64::from_str_radix(hex_str.trim_start_matches("0x"), 16)
+			.map_err(|e| anyhow::anyhow!("Failed to parse block number: {}", e))
+	}
+
 	/// Retrieves blocks within the specified range with retry functionality
 	///
 	/// # Note
@@ -213,31 +851,36 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for EvmClien
 	) -> Result<Vec<BlockType>, anyhow::Error> {
 		let block_futures: Vec<_> = (start_block..=end_block.unwrap_or(start_block))
 			.map(|block_number| {
-				let params = json!([
-					format!("0x{:x}", block_number),
-					true // include full transaction objects
-				]);
 				let client = self.http_client.clone();
+				let blocks = self.blocks.clone();
 
 				async move {
-					let response = client
-						.send_raw_request("eth_getBlockByNumber", Some(params))
+					blocks
+						.get_or_fetch(block_number, || fetch_block(&client, block_number))
 						.await
-						.with_context(|| format!("Failed to get block: {}", block_number))?;
-
-					let block_data = response
-						.get("result")
-						.ok_or_else(|| anyhow::anyhow!("Missing 'result' field"))?;
-
-					if block_data.is_null() {
-						return Err(anyhow::anyhow!("Block not found"));
-					}
+				}
+			})
+			.collect();
 
-					let block: EVMBlock = serde_json::from_value(block_data.clone())
-						.map_err(|e| anyhow::anyhow!("Failed to parse block: {}", e))?;
+		futures::future::join_all(block_futures)
+			.await
+			.into_iter()
+			.collect::<Result<Vec<_>, _>>()
+	}
 
-					Ok(BlockType::EVM(Box::new(block)))
-				}
+	/// Retrieves blocks within the specified range without the block cache, replacing the
+	/// cached blocks with the fetched ones
+	#[instrument(skip(self), fields(start_block, end_block))]
+	async fn refresh_blocks(
+		&self,
+		start_block: u64,
+		end_block: Option<u64>,
+	) -> Result<Vec<BlockType>, anyhow::Error> {
+		let block_futures: Vec<_> = (start_block..=end_block.unwrap_or(start_block))
+			.map(|block_number| async move {
+				let block = fetch_block(&self.http_client, block_number).await?;
+				self.blocks.insert(block_number, block.clone());
+				Ok::<_, anyhow::Error>(block)
 			})
 			.collect();
 
@@ -246,4 +889,10 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for EvmClien
 			.into_iter()
 			.collect::<Result<Vec<_>, _>>()
 	}
+
+	/// Calls a read-only JSON-RPC method through the HTTP transport
+	#[instrument(skip(self, params))]
+	async fn call_rpc(&self, method: &str, params: Option<Value>) -> Result<Value, anyhow::Error> {
+		call_rpc_with_transport(&self.http_client, method, params).await
+	}
 }
@@ -6,7 +6,7 @@
 use anyhow::Context;
 use async_trait::async_trait;
 use futures;
-use serde_json::json;
+use serde_json::{json, Value};
 use std::marker::PhantomData;
 use std::str::FromStr;
 use subxt::client::OnlineClient;
@@ -16,8 +16,9 @@ use crate::{
 	models::{BlockType, MidnightBlock, MidnightEvent, Network},
 	services::{
 		blockchain::{
-			client::BlockChainClient, transports::BlockchainTransport, BlockFilterFactory,
-			MidnightWsTransportClient,
+			client::{call_rpc_with_transport, BlockChainClient},
+			transports::BlockchainTransport,
+			BlockFilterFactory, MidnightWsTransportClient,
 		},
 		filter::MidnightBlockFilter,
 	},
@@ -409,4 +410,10 @@ impl<W: Send + Sync + Clone + BlockchainTransport, S: SubstrateClientTrait> Bloc
 			.into_iter()
 			.collect::<Result<Vec<_>, _>>()
 	}
+
+	/// Calls a read-only JSON-RPC method through the WebSocket transport
+	#[instrument(skip(self, params))]
+	async fn call_rpc(&self, method: &str, params: Option<Value>) -> Result<Value, anyhow::Error> {
+		call_rpc_with_transport(&self.ws_client, method, params).await
+	}
 }
@@ -4,6 +4,7 @@
 //! - EVM client for Ethereum-compatible chains
 //! - Stellar client for Stellar network
 //! - Midnight client for Midnight network
+//! - Solana client for Solana network
 
 mod evm {
 	pub mod client;
@@ -15,10 +16,14 @@ mod stellar {
 mod midnight {
 	pub mod client;
 }
+mod solana {
+	pub mod client;
+}
 
 pub use evm::client::{EvmClient, EvmClientTrait};
 pub use midnight::client::{
 	MidnightClient, MidnightClientTrait, SubstrateClientTrait as MidnightSubstrateClientTrait,
 };
-pub use stellar::client::{StellarClient, StellarClientTrait};
+pub use solana::client::{SolanaClient, SolanaClientTrait};
+pub use stellar::client::{StellarClient, StellarClientTrait, StellarPage, STELLAR_MAX_PAGE_SIZE};
 pub use stellar::error::StellarClientError;
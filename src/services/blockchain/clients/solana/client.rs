@@ -0,0 +1,308 @@
+//! Solana blockchain client implementation.
+//!
+//! This module provides functionality to interact with the Solana blockchain,
+//! supporting operations like block (slot) retrieval, transaction lookup, and
+//! signature lookup by address.
+
+use std::marker::PhantomData;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use futures::{stream, StreamExt};
+use serde_json::{json, Value};
+use tracing::instrument;
+
+use crate::{
+	models::{
+		BlockType, Network, SolanaBlock, SolanaBlockInfo, SolanaSignatureInfo, SolanaTransaction,
+		SolanaTransactionInfo,
+	},
+	services::{
+		blockchain::{
+			client::{call_rpc_with_transport, BlockChainClient, BlockFilterFactory},
+			transports::{BlockchainTransport, SolanaTransportClient},
+		},
+		filter::SolanaBlockFilter,
+	},
+};
+
+/// Solana RPC method constants
+const RPC_METHOD_GET_SLOT: &str = "getSlot";
+const RPC_METHOD_GET_BLOCK: &str = "getBlock";
+const RPC_METHOD_GET_TRANSACTION: &str = "getTransaction";
+const RPC_METHOD_GET_SIGNATURES_FOR_ADDRESS: &str = "getSignaturesForAddress";
+
+/// Commitment level used for all requests
+const COMMITMENT: &str = "confirmed";
+
+/// JSON-RPC error codes returned for slots that did not produce a block
+/// - -32007: Slot was skipped, or missing due to ledger jump to recent snapshot
+/// - -32009: Slot was skipped, or missing in long-term storage
+const SKIPPED_SLOT_ERROR_CODES: [i64; 2] = [-32007, -32009];
+
+/// Maximum number of concurrent `getBlock` requests
+const MAX_CONCURRENT_BLOCK_REQUESTS: usize = 10;
+
+/// Client implementation for the Solana blockchain
+///
+/// Provides high-level access to Solana blockchain data and operations through HTTP transport.
+#[derive(Clone)]
+pub struct SolanaClient<T: Send + Sync + Clone> {
+	/// The underlying Solana transport client for RPC communication
+	http_client: T,
+}
+
+impl<T: Send + Sync + Clone> SolanaClient<T> {
+	/// Creates a new Solana client instance with a specific transport client
+	pub fn new_with_transport(http_client: T) -> Self {
+		Self { http_client }
+	}
+}
+
+impl SolanaClient<SolanaTransportClient> {
+	/// Creates a new Solana client instance
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing RPC endpoints and chain details
+	///
+	/// # Returns
+	/// * `Result<Self, anyhow::Error>` - New client instance or connection error
+	pub async fn new(network: &Network) -> Result<Self, anyhow::Error> {
+		let http_client = SolanaTransportClient::new(network).await?;
+		Ok(Self::new_with_transport(http_client))
+	}
+}
+
+impl<T: Send + Sync + Clone + BlockchainTransport> BlockFilterFactory<Self> for SolanaClient<T> {
+	type Filter = SolanaBlockFilter<Self>;
+
+	fn filter() -> Self::Filter {
+		SolanaBlockFilter {
+			_client: PhantomData,
+		}
+	}
+}
+
+/// Extended functionality specific to the Solana blockchain
+#[async_trait]
+pub trait SolanaClientTrait {
+	/// Retrieves a transaction by its signature
+	///
+	/// # Arguments
+	/// * `signature` - The base-58 encoded signature of the transaction
+	///
+	/// # Returns
+	/// * `Result<SolanaTransaction, anyhow::Error>` - Transaction or error
+	async fn get_transaction(&self, signature: String) -> Result<SolanaTransaction, anyhow::Error>;
+
+	/// Retrieves the signatures of transactions involving an address
+	///
+	/// # Arguments
+	/// * `address` - The base-58 encoded account address
+	/// * `limit` - Optional maximum number of signatures to return (1-1000)
+	///
+	/// # Returns
+	/// * `Result<Vec<SolanaSignatureInfo>, anyhow::Error>` - Signatures, newest first, or error
+	async fn get_signatures_for_address(
+		&self,
+		address: String,
+		limit: Option<u32>,
+	) -> Result<Vec<SolanaSignatureInfo>, anyhow::Error>;
+}
+
+#[async_trait]
+impl<T: Send + Sync + Clone + BlockchainTransport> SolanaClientTrait for SolanaClient<T> {
+	/// Retrieves a transaction by signature with proper error handling
+	#[instrument(skip(self), fields(signature))]
+	async fn get_transaction(&self, signature: String) -> Result<SolanaTransaction, anyhow::Error> {
+		let params = json!([
+			signature,
+			{
+				"encoding": "json",
+				"commitment": COMMITMENT,
+				"maxSupportedTransactionVersion": 0
+			}
+		]);
+
+		let response = self
+			.http_client
+			.send_raw_request(RPC_METHOD_GET_TRANSACTION, Some(params))
+			.await
+			.with_context(|| format!("Failed to get transaction: {}", signature))?;
+
+		if let Some(error) = response.get("error") {
+			return Err(anyhow::anyhow!(
+				"Solana RPC request failed for method '{}': {}",
+				RPC_METHOD_GET_TRANSACTION,
+				error
+			));
+		}
+
+		// Extract the "result" field from the JSON-RPC response
+		let transaction_data = response
+			.get("result")
+			.with_context(|| "Missing 'result' field")?;
+
+		// Handle null response case
+		if transaction_data.is_null() {
+			return Err(anyhow::anyhow!("Transaction not found"));
+		}
+
+		let transaction: SolanaTransactionInfo = serde_json::from_value(transaction_data.clone())
+			.with_context(|| "Failed to parse transaction")?;
+
+		Ok(SolanaTransaction::from(transaction))
+	}
+
+	/// Retrieves the signatures of transactions involving an address
+	#[instrument(skip(self), fields(address, limit))]
+	async fn get_signatures_for_address(
+		&self,
+		address: String,
+		limit: Option<u32>,
+	) -> Result<Vec<SolanaSignatureInfo>, anyhow::Error> {
+		let params = json!([
+			address,
+			{
+				"commitment": COMMITMENT,
+				"limit": limit
+			}
+		]);
+
+		let response = self
+			.http_client
+			.send_raw_request(RPC_METHOD_GET_SIGNATURES_FOR_ADDRESS, Some(params))
+			.await
+			.with_context(|| format!("Failed to get signatures for address: {}", address))?;
+
+		if let Some(error) = response.get("error") {
+			return Err(anyhow::anyhow!(
+				"Solana RPC request failed for method '{}': {}",
+				RPC_METHOD_GET_SIGNATURES_FOR_ADDRESS,
+				error
+			));
+		}
+
+		// Extract the "result" field from the JSON-RPC response
+		let signatures_data = response
+			.get("result")
+			.with_context(|| "Missing 'result' field")?;
+
+		Ok(serde_json::from_value(signatures_data.clone())
+			.with_context(|| "Failed to parse signatures")?)
+	}
+}
+
+#[async_trait]
+impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for SolanaClient<T> {
+	/// Retrieves the latest confirmed slot
+	#[instrument(skip(self))]
+	async fn get_latest_block_number(&self) -> Result<u64, anyhow::Error> {
+		let response = self
+			.http_client
+			.send_raw_request(
+				RPC_METHOD_GET_SLOT,
+				Some(json!([{ "commitment": COMMITMENT }])),
+			)
+			.await
+			.with_context(|| "Failed to get latest slot")?;
+
+		response
+			.get("result")
+			.and_then(|v| v.as_u64())
+			.ok_or_else(|| anyhow::anyhow!("Invalid slot number"))
+	}
+
+	/// Retrieves blocks within the specified slot range
+	///
+	/// Slots that were skipped by the leader do not produce a block and are omitted
+	/// from the result.
+	///
+	/// # Note
+	/// If end_block is None, only the start_block will be retrieved
+	#[instrument(skip(self), fields(start_block, end_block))]
+	async fn get_blocks(
+		&self,
+		start_block: u64,
+		end_block: Option<u64>,
+	) -> Result<Vec<BlockType>, anyhow::Error> {
+		let end_block = end_block.unwrap_or(start_block);
+		if start_block > end_block {
+			return Err(anyhow::anyhow!(
+				"start_block {} cannot be greater than end_block {}",
+				start_block,
+				end_block
+			));
+		}
+
+		let block_futures = (start_block..=end_block).map(|slot| {
+			let params = json!([
+				slot,
+				{
+					"encoding": "json",
+					"commitment": COMMITMENT,
+					"transactionDetails": "full",
+					"maxSupportedTransactionVersion": 0,
+					"rewards": false
+				}
+			]);
+			let client = self.http_client.clone();
+
+			async move {
+				let response = client
+					.send_raw_request(RPC_METHOD_GET_BLOCK, Some(params))
+					.await
+					.with_context(|| format!("Failed to get block: {}", slot))?;
+
+				if let Some(error) = response.get("error") {
+					let code = error.get("code").and_then(|c| c.as_i64()).unwrap_or(0);
+					if SKIPPED_SLOT_ERROR_CODES.contains(&code) {
+						tracing::debug!("Slot {} was skipped, no block produced", slot);
+						return Ok(None);
+					}
+					return Err(anyhow::anyhow!(
+						"Solana RPC request failed for method '{}': {}",
+						RPC_METHOD_GET_BLOCK,
+						error
+					));
+				}
+
+				let block_data = response
+					.get("result")
+					.ok_or_else(|| anyhow::anyhow!("Missing 'result' field"))?;
+
+				if block_data.is_null() {
+					return Ok(None);
+				}
+
+				let mut block: SolanaBlockInfo = serde_json::from_value(block_data.clone())
+					.map_err(|e| anyhow::anyhow!("Failed to parse block: {}", e))?;
+
+				// The slot and block time are not part of the transaction entries
+				block.slot = slot;
+				for transaction in block.transactions.iter_mut() {
+					transaction.slot = slot;
+					transaction.block_time = block.block_time;
+				}
+
+				Ok(Some(BlockType::Solana(Box::new(SolanaBlock::from(block)))))
+			}
+		});
+
+		let blocks = stream::iter(block_futures)
+			.buffered(MAX_CONCURRENT_BLOCK_REQUESTS)
+			.collect::<Vec<Result<Option<BlockType>, anyhow::Error>>>()
+			.await;
+
+		blocks
+			.into_iter()
+			.filter_map(|block| block.transpose())
+			.collect::<Result<Vec<_>, _>>()
+	}
+
+	/// Calls a read-only JSON-RPC method through the HTTP transport
+	#[instrument(skip(self, params))]
+	async fn call_rpc(&self, method: &str, params: Option<Value>) -> Result<Value, anyhow::Error> {
+		call_rpc_with_transport(&self.http_client, method, params).await
+	}
+}
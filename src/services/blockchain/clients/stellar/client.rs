@@ -6,9 +6,10 @@
 use anyhow::Context;
 use async_trait::async_trait;
 use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine as _};
-use serde_json::json;
-use std::marker::PhantomData;
-use stellar_xdr::curr::{Limits, WriteXdr};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::{marker::PhantomData, sync::Arc, time::Duration};
+use stellar_xdr::curr::{Limits, ReadXdr, ScVal, WriteXdr};
 use tracing::instrument;
 
 use crate::{
@@ -18,13 +19,16 @@ use crate::{
 	},
 	services::{
 		blockchain::{
-			client::{BlockChainClient, BlockFilterFactory},
-			transports::StellarTransportClient,
-			BlockchainTransport,
+			client::{
+				call_rpc_with_transport, BlockChainClient, BlockFilterFactory, ContractCallResult,
+			},
+			transports::{JsonRpcResponse, StellarTransportClient, TransportError},
+			BlockchainTransport, ResponseCache,
 		},
 		filter::{
 			stellar_helpers::{
-				get_contract_code_ledger_key, get_contract_instance_ledger_key, get_contract_spec,
+				get_contract_call_transaction, get_contract_code_ledger_key,
+				get_contract_instance_ledger_key, get_contract_spec,
 				get_wasm_code_from_ledger_entry_data, get_wasm_hash_from_ledger_entry_data,
 			},
 			StellarBlockFilter,
@@ -40,12 +44,28 @@ const RPC_METHOD_GET_EVENTS: &str = "getEvents";
 const RPC_METHOD_GET_LATEST_LEDGER: &str = "getLatestLedger";
 const RPC_METHOD_GET_LEDGERS: &str = "getLedgers";
 const RPC_METHOD_GET_LEDGER_ENTRIES: &str = "getLedgerEntries";
+const RPC_METHOD_SIMULATE_TRANSACTION: &str = "simulateTransaction";
 
 const RETENTION_MESSAGES: [&str; 2] = [
 	"must be within the ledger range",
 	"must be between the oldest ledger",
 ];
 
+/// Result of a `getLedgers` request
+///
+/// Deserialized straight from the response body, so that the ledgers of a page, whose metadata
+/// can be large, are only held in memory once.
+#[derive(Debug, Default, Deserialize)]
+struct LedgersPage {
+	/// Ledgers of the page, absent on unexpected responses
+	ledgers: Option<Vec<StellarBlock>>,
+	/// Cursor of the next page
+	cursor: Option<String>,
+}
+
+/// Key of cached transactions and events: sequence range and page size
+type SequenceRangeKey = (u32, Option<u32>, Option<u32>);
+
 /// Client implementation for the Stellar blockchain
 ///
 /// Provides high-level access to Stellar blockchain data and operations through HTTP transport.
@@ -53,24 +73,59 @@ const RETENTION_MESSAGES: [&str; 2] = [
 pub struct StellarClient<T: Send + Sync + Clone> {
 	/// The underlying Stellar transport client for RPC communication
 	http_client: T,
+	/// Cache of ledgers by sequence range
+	blocks: Arc<ResponseCache<(u64, Option<u64>), Vec<BlockType>>>,
+	/// Cache of transactions by sequence range and page size
+	transactions: Arc<ResponseCache<SequenceRangeKey, Vec<StellarTransaction>>>,
+	/// Cache of events by sequence range and page size
+	events: Arc<ResponseCache<SequenceRangeKey, Vec<StellarEvent>>>,
 }
 
 impl<T: Send + Sync + Clone> StellarClient<T> {
 	/// Creates a new Stellar client instance with a specific transport client
+	///
+	/// Responses aren't cached, see [`StellarClient::with_cache`].
 	pub fn new_with_transport(http_client: T) -> Self {
-		Self { http_client }
+		Self {
+			http_client,
+			blocks: Arc::new(ResponseCache::disabled("blocks")),
+			transactions: Arc::new(ResponseCache::disabled("transactions")),
+			events: Arc::new(ResponseCache::disabled("events")),
+		}
+	}
+
+	/// Caches the fetched ledgers, transactions and events
+	///
+	/// Each cache holds up to `rpc_cache_size` entries of the network, which expire after one
+	/// ledger close time. Caching is disabled when `rpc_cache_size` is unset or 0.
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing the cache size and block time
+	pub fn with_cache(mut self, network: &Network) -> Self {
+		let capacity = network.rpc_cache_size.unwrap_or(0);
+		let ttl = Duration::from_millis(network.block_time_ms);
+		self.blocks = Arc::new(ResponseCache::new(&network.slug, "blocks", capacity, ttl));
+		self.transactions = Arc::new(ResponseCache::new(
+			&network.slug,
+			"transactions",
+			capacity,
+			ttl,
+		));
+		self.events = Arc::new(ResponseCache::new(&network.slug, "events", capacity, ttl));
+		self
 	}
 
-	/// Checks a JSON-RPC response for error information and converts it into a `StellarClientError` if present.
+	/// Checks the error of a JSON-RPC response and converts it into a `StellarClientError` if
+	/// present.
 	///
-	/// This function inspects the given JSON response body for an "error" field.
+	/// This function inspects the "error" field of a JSON response body.
 	/// If a known "outside of retention window" condition is detected (by code/message),
 	/// it returns a specific `StellarClientError::outside_retention_window`.
 	/// Otherwise, it returns a generic `StellarClientError::rpc_error` for any other error found.
 	/// If no error is present, it returns `Ok(())`.
 	///
 	/// # Arguments
-	/// * `response_body` - A reference to the JSON response body to inspect.
+	/// * `rpc_error` - The "error" field of the JSON response body, if any.
 	/// * `start_sequence` - The starting ledger sequence number relevant to the request.
 	/// * `target_sequence` - The target ledger sequence number relevant to the request.
 	/// * `method_name` - The name of the RPC method that was called (for error reporting).
@@ -80,12 +135,12 @@ impl<T: Send + Sync + Clone> StellarClient<T> {
 	/// * `Err(StellarClientError)` if an error is detected.
 	fn check_and_handle_rpc_error(
 		&self,
-		response_body: &serde_json::Value,
+		rpc_error: Option<&Value>,
 		start_sequence: u32,
 		target_sequence: u32,
 		method_name: &'static str,
 	) -> Result<(), StellarClientError> {
-		if let Some(json_rpc_error) = response_body.get("error") {
+		if let Some(json_rpc_error) = rpc_error {
 			let rpc_code = json_rpc_error
 				.get("code")
 				.and_then(|c| c.as_i64())
@@ -137,10 +192,23 @@ impl StellarClient<StellarTransportClient> {
 	/// * `Result<Self, anyhow::Error>` - New client instance or connection error
 	pub async fn new(network: &Network) -> Result<Self, anyhow::Error> {
 		let http_client = StellarTransportClient::new(network).await?;
-		Ok(Self::new_with_transport(http_client))
+		Ok(Self::new_with_transport(http_client).with_cache(network))
 	}
 }
 
+/// Maximum number of results per page accepted by the Stellar RPC `getTransactions` method,
+/// also used as the page size when none is configured
+pub const STELLAR_MAX_PAGE_SIZE: u32 = 200;
+
+/// Page of results of a paginated Stellar RPC method
+#[derive(Debug, Clone, PartialEq)]
+pub struct StellarPage<T> {
+	/// Results of the page within the requested sequence range
+	pub items: Vec<T>,
+	/// Cursor of the next page, `None` once the end of the requested range is reached
+	pub cursor: Option<String>,
+}
+
 /// Extended functionality specific to the Stellar blockchain
 #[async_trait]
 pub trait StellarClientTrait {
@@ -149,6 +217,8 @@ pub trait StellarClientTrait {
 	/// # Arguments
 	/// * `start_sequence` - Starting sequence number
 	/// * `end_sequence` - Optional ending sequence number. If None, only fetches start_sequence
+	/// * `page_size` - Optional number of transactions requested per page, capped at
+	///   [`STELLAR_MAX_PAGE_SIZE`]
 	///
 	/// # Returns
 	/// * `Result<Vec<StellarTransaction>, anyhow::Error>` - Collection of transactions or error
@@ -156,13 +226,35 @@ pub trait StellarClientTrait {
 		&self,
 		start_sequence: u32,
 		end_sequence: Option<u32>,
+		page_size: Option<u32>,
 	) -> Result<Vec<StellarTransaction>, anyhow::Error>;
 
+	/// Retrieves a single page of transactions within a sequence range
+	///
+	/// # Arguments
+	/// * `start_sequence` - Starting sequence number
+	/// * `end_sequence` - Optional ending sequence number. If None, only fetches start_sequence
+	/// * `cursor` - Cursor returned with the previous page, None for the first page
+	/// * `page_size` - Optional number of transactions requested, capped at
+	///   [`STELLAR_MAX_PAGE_SIZE`]
+	///
+	/// # Returns
+	/// * `Result<StellarPage<StellarTransaction>, anyhow::Error>` - Page of transactions or error
+	async fn get_transactions_page(
+		&self,
+		start_sequence: u32,
+		end_sequence: Option<u32>,
+		cursor: Option<String>,
+		page_size: Option<u32>,
+	) -> Result<StellarPage<StellarTransaction>, anyhow::Error>;
+
 	/// Retrieves events within a sequence range
 	///
 	/// # Arguments
 	/// * `start_sequence` - Starting sequence number
 	/// * `end_sequence` - Optional ending sequence number. If None, only fetches start_sequence
+	/// * `page_size` - Optional number of events requested per page, capped at
+	///   [`STELLAR_MAX_PAGE_SIZE`]
 	///
 	/// # Returns
 	/// * `Result<Vec<StellarEvent>, anyhow::Error>` - Collection of events or error
@@ -170,12 +262,93 @@ pub trait StellarClientTrait {
 		&self,
 		start_sequence: u32,
 		end_sequence: Option<u32>,
+		page_size: Option<u32>,
 	) -> Result<Vec<StellarEvent>, anyhow::Error>;
+
+	/// Retrieves a single page of events within a sequence range
+	///
+	/// Pages may be empty while the endpoint scans ledgers without events, the returned cursor
+	/// must then still be followed.
+	///
+	/// # Arguments
+	/// * `start_sequence` - Starting sequence number
+	/// * `end_sequence` - Optional ending sequence number. If None, only fetches start_sequence
+	/// * `cursor` - Cursor returned with the previous page, None for the first page
+	/// * `page_size` - Optional number of events requested, capped at [`STELLAR_MAX_PAGE_SIZE`]
+	///
+	/// # Returns
+	/// * `Result<StellarPage<StellarEvent>, anyhow::Error>` - Page of events or error
+	async fn get_events_page(
+		&self,
+		start_sequence: u32,
+		end_sequence: Option<u32>,
+		cursor: Option<String>,
+		page_size: Option<u32>,
+	) -> Result<StellarPage<StellarEvent>, anyhow::Error>;
+
+	/// Calls a contract function without sending a transaction, with `simulateTransaction`
+	///
+	/// # Arguments
+	/// * `contract_id` - ID of the called contract
+	/// * `function_name` - Name of the called function
+	/// * `args` - Arguments of the call
+	///
+	/// # Returns
+	/// * `Result<ContractCallResult<ScVal>, anyhow::Error>` - Return value or failure reason of
+	///   the call, or error
+	async fn simulate_contract_call(
+		&self,
+		contract_id: &str,
+		function_name: &str,
+		args: Vec<ScVal>,
+	) -> Result<ContractCallResult<ScVal>, anyhow::Error>;
+}
+
+/// Validates the sequence range of a paginated request
+fn validate_sequence_range(
+	start_sequence: u32,
+	end_sequence: Option<u32>,
+) -> Result<(), anyhow::Error> {
+	if let Some(end_sequence) = end_sequence {
+		if start_sequence > end_sequence {
+			let message = format!(
+				"start_sequence {} cannot be greater than end_sequence {}",
+				start_sequence, end_sequence
+			);
+			let input_error = StellarClientError::invalid_input(message, None, None);
+			return Err(anyhow::anyhow!(input_error))
+				.context("Invalid input parameters for Stellar RPC");
+		}
+	}
+	Ok(())
+}
+
+/// Builds the pagination parameters of a request, starting from `start_sequence` for the first
+/// page and from the cursor for the following ones
+fn pagination_params(start_sequence: u32, cursor: Option<String>, page_size: Option<u32>) -> Value {
+	let limit = page_size
+		.unwrap_or(STELLAR_MAX_PAGE_SIZE)
+		.clamp(1, STELLAR_MAX_PAGE_SIZE);
+	match cursor {
+		None => json!({
+			"startLedger": start_sequence,
+			"pagination": {
+				"limit": limit
+			}
+		}),
+		Some(cursor) => json!({
+			"pagination": {
+				"cursor": cursor,
+				"limit": limit
+			}
+		}),
+	}
 }
 
 #[async_trait]
 impl<T: Send + Sync + Clone + BlockchainTransport> StellarClientTrait for StellarClient<T> {
-	/// Retrieves transactions within a sequence range with pagination
+	/// Retrieves transactions within a sequence range, following the pages returned by the
+	/// endpoint
 	///
 	/// # Errors
 	/// - Returns `anyhow::Error` if start_sequence > end_sequence
@@ -185,136 +358,132 @@ impl<T: Send + Sync + Clone + BlockchainTransport> StellarClientTrait for Stella
 		&self,
 		start_sequence: u32,
 		end_sequence: Option<u32>,
+		page_size: Option<u32>,
 	) -> Result<Vec<StellarTransaction>, anyhow::Error> {
-		// Validate input parameters
-		if let Some(end_sequence) = end_sequence {
-			if start_sequence > end_sequence {
-				let message = format!(
-					"start_sequence {} cannot be greater than end_sequence {}",
-					start_sequence, end_sequence
-				);
-				let input_error = StellarClientError::invalid_input(message, None, None);
-				return Err(anyhow::anyhow!(input_error))
-					.context("Invalid input parameters for Stellar RPC");
-			}
-		}
+		let key = (start_sequence, end_sequence, page_size);
+		self.transactions
+			.get_or_fetch(key, || async {
+				let mut transactions = Vec::new();
+				let mut cursor = None;
+
+				loop {
+					let page = self
+						.get_transactions_page(start_sequence, end_sequence, cursor, page_size)
+						.await?;
+					transactions.extend(page.items);
+					cursor = page.cursor;
+					if cursor.is_none() {
+						break;
+					}
+				}
+				Ok(transactions)
+			})
+			.await
+	}
+
+	/// Retrieves a single page of transactions within a sequence range
+	///
+	/// # Errors
+	/// - Returns `anyhow::Error` if start_sequence > end_sequence
+	/// - Returns `anyhow::Error` if transaction parsing fails
+	#[instrument(skip(self), fields(start_sequence, end_sequence))]
+	async fn get_transactions_page(
+		&self,
+		start_sequence: u32,
+		end_sequence: Option<u32>,
+		cursor: Option<String>,
+		page_size: Option<u32>,
+	) -> Result<StellarPage<StellarTransaction>, anyhow::Error> {
+		validate_sequence_range(start_sequence, end_sequence)?;
 
-		// max limit for the RPC endpoint is 200
-		const PAGE_LIMIT: u32 = 200;
-		let mut transactions = Vec::new();
 		let target_sequence = end_sequence.unwrap_or(start_sequence);
-		let mut cursor: Option<String> = None;
-		let mut current_iteration = 0;
+		let params = pagination_params(start_sequence, cursor, page_size);
 
-		while cursor.is_some() || current_iteration <= 0 {
-			let params = if current_iteration == 0 {
-				// First iteration, we need to fetch the transactions from the start sequence without a cursor
-				json!({
-					"startLedger": start_sequence,
-					"pagination": {
-						"limit": PAGE_LIMIT
-					}
-				})
-			} else {
-				// Subsequent iterations, we need to fetch the transactions from the cursor
-				json!({
-					"pagination": {
-						"cursor": cursor,
-						"limit": PAGE_LIMIT
-					}
-				})
-			};
+		let response_body = self
+			.http_client
+			.send_raw_request(RPC_METHOD_GET_TRANSACTIONS, Some(params))
+			.await
+			.map_err(|transport_err| {
+				// Ledger info for logging
+				let ledger_info = format!(
+					"start_sequence: {}, end_sequence: {:?}",
+					start_sequence, end_sequence
+				);
 
-			let http_response = self
-				.http_client
-				.send_raw_request(RPC_METHOD_GET_TRANSACTIONS, Some(params))
-				.await;
+				anyhow::anyhow!(transport_err).context(format!(
+					"Failed to {} from Stellar RPC for ledger: {}",
+					RPC_METHOD_GET_TRANSACTIONS, ledger_info
+				))
+			})?;
 
-			match http_response {
-				Ok(response_body) => {
-					// Check for RPC errors in the response
-					if let Err(rpc_error) = self.check_and_handle_rpc_error(
-						&response_body,
-						start_sequence,
-						target_sequence,
-						RPC_METHOD_GET_TRANSACTIONS,
-					) {
-						// A terminal JSON-RPC error was found, convert and return
-						return Err(anyhow::anyhow!(rpc_error).context(format!(
-							"Soroban RPC reported an error during {}",
-							RPC_METHOD_GET_TRANSACTIONS,
-						)));
-					}
+		// Check for RPC errors in the response
+		if let Err(rpc_error) = self.check_and_handle_rpc_error(
+			response_body.get("error"),
+			start_sequence,
+			target_sequence,
+			RPC_METHOD_GET_TRANSACTIONS,
+		) {
+			// A terminal JSON-RPC error was found, convert and return
+			return Err(anyhow::anyhow!(rpc_error).context(format!(
+				"Soroban RPC reported an error during {}",
+				RPC_METHOD_GET_TRANSACTIONS,
+			)));
+		}
 
-					// Extract the transactions from the response
-					let raw_transactions = response_body
-						.get("result")
-						.and_then(|r| r.get("transactions"))
-						.ok_or_else(|| {
-							let message = format!(
-								"Unexpected response structure for method '{}'",
-								RPC_METHOD_GET_TRANSACTIONS
-							);
-							StellarClientError::unexpected_response_structure(message, None, None)
-						})
-						.map_err(|client_parse_error| {
-							anyhow::anyhow!(client_parse_error)
-								.context("Failed to parse transaction response")
-						})?;
-
-					let ledger_transactions: Vec<StellarTransactionInfo> =
-						serde_json::from_value(raw_transactions.clone()).map_err(|e| {
-							let message = format!(
-								"Failed to parse transactions from response for method '{}': {}",
-								RPC_METHOD_GET_TRANSACTIONS, e
-							);
-							let sce_parse_error = StellarClientError::response_parse_error(
-								message,
-								Some(e.into()),
-								None,
-							);
-							anyhow::anyhow!(sce_parse_error)
-								.context("Failed to parse transaction response")
-						})?;
-
-					if ledger_transactions.is_empty() {
-						break;
-					}
+		// Extract the transactions from the response
+		let raw_transactions = response_body
+			.get("result")
+			.and_then(|r| r.get("transactions"))
+			.ok_or_else(|| {
+				let message = format!(
+					"Unexpected response structure for method '{}'",
+					RPC_METHOD_GET_TRANSACTIONS
+				);
+				StellarClientError::unexpected_response_structure(message, None, None)
+			})
+			.map_err(|client_parse_error| {
+				anyhow::anyhow!(client_parse_error).context("Failed to parse transaction response")
+			})?;
 
-					for transaction in ledger_transactions {
-						if transaction.ledger > target_sequence {
-							return Ok(transactions);
-						}
-						transactions.push(StellarTransaction::from(transaction));
-					}
+		let ledger_transactions: Vec<StellarTransactionInfo> =
+			serde_json::from_value(raw_transactions.clone()).map_err(|e| {
+				let message = format!(
+					"Failed to parse transactions from response for method '{}': {}",
+					RPC_METHOD_GET_TRANSACTIONS, e
+				);
+				let sce_parse_error =
+					StellarClientError::response_parse_error(message, Some(e.into()), None);
+				anyhow::anyhow!(sce_parse_error).context("Failed to parse transaction response")
+			})?;
 
-					// Increment the number of iterations to ensure we break the loop in case there is no cursor
-					current_iteration += 1;
-					cursor = response_body["result"]["cursor"]
-						.as_str()
-						.map(|s| s.to_string());
-					if cursor.is_none() {
-						break;
-					}
-				}
-				Err(transport_err) => {
-					// Ledger info for logging
-					let ledger_info = format!(
-						"start_sequence: {}, end_sequence: {:?}",
-						start_sequence, end_sequence
-					);
+		// An empty page means there are no more transactions
+		if ledger_transactions.is_empty() {
+			return Ok(StellarPage {
+				items: Vec::new(),
+				cursor: None,
+			});
+		}
 
-					return Err(anyhow::anyhow!(transport_err)).context(format!(
-						"Failed to {} from Stellar RPC for ledger: {}",
-						RPC_METHOD_GET_TRANSACTIONS, ledger_info
-					));
-				}
+		let mut transactions = Vec::with_capacity(ledger_transactions.len());
+		for transaction in ledger_transactions {
+			if transaction.ledger > target_sequence {
+				return Ok(StellarPage {
+					items: transactions,
+					cursor: None,
+				});
 			}
+			transactions.push(StellarTransaction::from(transaction));
 		}
-		Ok(transactions)
+
+		Ok(StellarPage {
+			items: transactions,
+			cursor: response_body["result"]["cursor"]
+				.as_str()
+				.map(|s| s.to_string()),
+		})
 	}
 
-	/// Retrieves events within a sequence range with pagination
+	/// Retrieves events within a sequence range, following the pages returned by the endpoint
 	///
 	/// # Errors
 	/// - Returns `anyhow::Error` if start_sequence > end_sequence
@@ -324,175 +493,187 @@ impl<T: Send + Sync + Clone + BlockchainTransport> StellarClientTrait for Stella
 		&self,
 		start_sequence: u32,
 		end_sequence: Option<u32>,
+		page_size: Option<u32>,
 	) -> Result<Vec<StellarEvent>, anyhow::Error> {
-		// Validate input parameters
-		if let Some(end_sequence) = end_sequence {
-			if start_sequence > end_sequence {
-				let message = format!(
-					"start_sequence {} cannot be greater than end_sequence {}",
-					start_sequence, end_sequence
-				);
-				let input_error = StellarClientError::invalid_input(message, None, None);
-				return Err(anyhow::anyhow!(input_error))
-					.context("Invalid input parameters for Stellar RPC");
-			}
-		}
+		let key = (start_sequence, end_sequence, page_size);
+		self.events
+			.get_or_fetch(key, || async {
+				let mut events = Vec::new();
+				let mut cursor = None;
+
+				loop {
+					let page = self
+						.get_events_page(start_sequence, end_sequence, cursor, page_size)
+						.await?;
+					events.extend(page.items);
+					cursor = page.cursor;
+					if cursor.is_none() {
+						break;
+					}
+				}
+				Ok(events)
+			})
+			.await
+	}
+
+	/// Retrieves a single page of events within a sequence range
+	///
+	/// # Errors
+	/// - Returns `anyhow::Error` if start_sequence > end_sequence
+	/// - Returns `anyhow::Error` if event parsing fails
+	#[instrument(skip(self), fields(start_sequence, end_sequence))]
+	async fn get_events_page(
+		&self,
+		start_sequence: u32,
+		end_sequence: Option<u32>,
+		cursor: Option<String>,
+		page_size: Option<u32>,
+	) -> Result<StellarPage<StellarEvent>, anyhow::Error> {
+		validate_sequence_range(start_sequence, end_sequence)?;
 
-		// max limit for the RPC endpoint is 200
-		const PAGE_LIMIT: u32 = 200;
-		let mut events = Vec::new();
 		let target_sequence = end_sequence.unwrap_or(start_sequence);
-		let mut cursor: Option<String> = None;
-		let mut current_iteration = 0;
+		let mut params = pagination_params(start_sequence, cursor, page_size);
+		params["filters"] = json!([{
+			"type": "contract",
+		}]);
 
-		while cursor.is_some() || current_iteration <= 0 {
-			let params = if current_iteration == 0 {
-				// First iteration, we need to fetch the events from the start sequence without a cursor
-				json!({
-					"startLedger": start_sequence,
-					"filters": [{
-						"type": "contract",
-					}],
-					"pagination": {
-						"limit": PAGE_LIMIT
-					}
-				})
-			} else {
-				// Subsequent iterations, we need to fetch the events from the cursor
-				json!({
-					"filters": [{
-						"type": "contract",
-					}],
-					"pagination": {
-						"cursor": cursor,
-						"limit": PAGE_LIMIT
-					}
-				})
-			};
+		let response_body = self
+			.http_client
+			.send_raw_request(RPC_METHOD_GET_EVENTS, Some(params))
+			.await
+			.map_err(|transport_err| {
+				// Ledger info for logging
+				let ledger_info = format!(
+					"start_sequence: {}, end_sequence: {:?}",
+					start_sequence, end_sequence
+				);
 
-			let http_response = self
-				.http_client
-				.send_raw_request(RPC_METHOD_GET_EVENTS, Some(params))
-				.await;
+				anyhow::anyhow!(transport_err).context(format!(
+					"Failed to {} from Stellar RPC for ledger: {}",
+					RPC_METHOD_GET_EVENTS, ledger_info,
+				))
+			})?;
 
-			match http_response {
-				Ok(response_body) => {
-					// Check for RPC errors in the response
-					if let Err(rpc_error) = self.check_and_handle_rpc_error(
-						&response_body,
-						start_sequence,
-						target_sequence,
-						RPC_METHOD_GET_EVENTS,
-					) {
-						// A terminal JSON-RPC error was found, convert and return
-						return Err(anyhow::anyhow!(rpc_error).context(format!(
-							"Soroban RPC reported an error during {}",
-							RPC_METHOD_GET_EVENTS
-						)));
-					}
+		// Check for RPC errors in the response
+		if let Err(rpc_error) = self.check_and_handle_rpc_error(
+			response_body.get("error"),
+			start_sequence,
+			target_sequence,
+			RPC_METHOD_GET_EVENTS,
+		) {
+			// A terminal JSON-RPC error was found, convert and return
+			return Err(anyhow::anyhow!(rpc_error).context(format!(
+				"Soroban RPC reported an error during {}",
+				RPC_METHOD_GET_EVENTS
+			)));
+		}
 
-					// Extract the events from the response
-					let raw_events = response_body
-						.get("result")
-						.and_then(|r| r.get("events"))
-						.ok_or_else(|| {
-							let message = format!(
-								"Unexpected response structure for method '{}'",
-								RPC_METHOD_GET_EVENTS
-							);
-							StellarClientError::unexpected_response_structure(message, None, None)
-						})
-						.map_err(|client_parse_error| {
-							anyhow::anyhow!(client_parse_error)
-								.context("Failed to parse event response")
-						})?;
-
-					let ledger_events: Vec<StellarEvent> =
-						serde_json::from_value(raw_events.clone()).map_err(|e| {
-							let message = format!(
-								"Failed to parse events from response for method '{}': {}",
-								RPC_METHOD_GET_EVENTS, e
-							);
-							let sce_parse_error = StellarClientError::response_parse_error(
-								message,
-								Some(e.into()),
-								None,
-							);
-							anyhow::anyhow!(sce_parse_error)
-								.context("Failed to parse event response")
-						})?;
-
-					for event in ledger_events {
-						if event.ledger > target_sequence {
-							return Ok(events);
-						}
-						events.push(event);
-					}
+		// Extract the events from the response
+		let raw_events = response_body
+			.get("result")
+			.and_then(|r| r.get("events"))
+			.ok_or_else(|| {
+				let message = format!(
+					"Unexpected response structure for method '{}'",
+					RPC_METHOD_GET_EVENTS
+				);
+				StellarClientError::unexpected_response_structure(message, None, None)
+			})
+			.map_err(|client_parse_error| {
+				anyhow::anyhow!(client_parse_error).context("Failed to parse event response")
+			})?;
 
-					// Increment the number of iterations to ensure we break the loop in case there is no cursor
-					current_iteration += 1;
-					cursor = response_body["result"]["cursor"]
-						.as_str()
-						.map(|s| s.to_string());
-					if cursor.is_none() {
-						break;
-					}
-				}
-				Err(transport_err) => {
-					// Ledger info for logging
-					let ledger_info = format!(
-						"start_sequence: {}, end_sequence: {:?}",
-						start_sequence, end_sequence
-					);
+		let ledger_events: Vec<StellarEvent> =
+			serde_json::from_value(raw_events.clone()).map_err(|e| {
+				let message = format!(
+					"Failed to parse events from response for method '{}': {}",
+					RPC_METHOD_GET_EVENTS, e
+				);
+				let sce_parse_error =
+					StellarClientError::response_parse_error(message, Some(e.into()), None);
+				anyhow::anyhow!(sce_parse_error).context("Failed to parse event response")
+			})?;
 
-					return Err(anyhow::anyhow!(transport_err)).context(format!(
-						"Failed to {} from Stellar RPC for ledger: {}",
-						RPC_METHOD_GET_EVENTS, ledger_info,
-					));
-				}
+		let mut events = Vec::with_capacity(ledger_events.len());
+		for event in ledger_events {
+			if event.ledger > target_sequence {
+				return Ok(StellarPage {
+					items: events,
+					cursor: None,
+				});
 			}
+			events.push(event);
 		}
-		Ok(events)
-	}
-}
-
-impl<T: Send + Sync + Clone + BlockchainTransport> BlockFilterFactory<Self> for StellarClient<T> {
-	type Filter = StellarBlockFilter<Self>;
 
-	fn filter() -> Self::Filter {
-		StellarBlockFilter {
-			_client: PhantomData {},
-		}
+		Ok(StellarPage {
+			items: events,
+			cursor: response_body["result"]["cursor"]
+				.as_str()
+				.map(|s| s.to_string()),
+		})
 	}
-}
 
-#[async_trait]
-impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for StellarClient<T> {
-	/// Retrieves the latest block number with retry functionality
-	#[instrument(skip(self))]
-	async fn get_latest_block_number(&self) -> Result<u64, anyhow::Error> {
+	/// Calls a contract function by simulating a transaction invoking it
+	///
+	/// Failed simulations, e.g. when the contract panics, are returned as
+	/// [`ContractCallResult::Reverted`].
+	///
+	/// # Errors
+	/// - Returns `anyhow::Error` if the transaction can't be built or the simulation requested
+	/// - Returns `anyhow::Error` if the return value can't be decoded
+	#[instrument(skip(self, args), fields(contract_id, function_name))]
+	async fn simulate_contract_call(
+		&self,
+		contract_id: &str,
+		function_name: &str,
+		args: Vec<ScVal>,
+	) -> Result<ContractCallResult<ScVal>, anyhow::Error> {
+		let transaction = get_contract_call_transaction(contract_id, function_name, args)?;
+		let transaction_xdr = transaction
+			.to_xdr(Limits::none())
+			.map_err(|e| anyhow::anyhow!("Failed to convert transaction to XDR: {}", e))?;
+
+		let params = json!({ "transaction": BASE64_STANDARD.encode(transaction_xdr) });
 		let response = self
 			.http_client
-			.send_raw_request::<serde_json::Value>(RPC_METHOD_GET_LATEST_LEDGER, None)
+			.send_raw_request(RPC_METHOD_SIMULATE_TRANSACTION, Some(params))
 			.await
-			.with_context(|| "Failed to get latest ledger")?;
+			.with_context(|| format!("Failed to call {} of {}", function_name, contract_id))?;
+
+		if let Some(error) = response.get("error") {
+			return Err(anyhow::anyhow!(
+				"Stellar RPC request failed for method '{}': {}",
+				RPC_METHOD_SIMULATE_TRANSACTION,
+				error
+			));
+		}
 
-		let sequence = response["result"]["sequence"]
-			.as_u64()
-			.ok_or_else(|| anyhow::anyhow!("Invalid sequence number"))?;
+		let result = response
+			.get("result")
+			.with_context(|| "Missing 'result' field")?;
+		if let Some(error) = result.get("error").and_then(Value::as_str) {
+			return Ok(ContractCallResult::Reverted(error.to_string()));
+		}
 
-		Ok(sequence)
+		let return_value = result["results"][0]["xdr"]
+			.as_str()
+			.with_context(|| "Missing return value in simulation result")?;
+		let return_value = BASE64_STANDARD
+			.decode(return_value)
+			.with_context(|| "Failed to decode return value")?;
+		let return_value = ScVal::from_xdr(return_value, Limits::none())
+			.map_err(|e| anyhow::anyhow!("Failed to parse return value: {}", e))?;
+		Ok(ContractCallResult::Returned(return_value))
 	}
+}
 
-	/// Retrieves blocks within the specified range with retry functionality
-	///
-	/// # Note
-	/// If end_block is None, only the start_block will be retrieved
+impl<T: Send + Sync + Clone + BlockchainTransport> StellarClient<T> {
+	/// Fetches the ledgers within the specified range, following the pages returned by the
+	/// endpoint
 	///
 	/// # Errors
 	/// - Returns `anyhow::Error`
-	#[instrument(skip(self), fields(start_block, end_block))]
-	async fn get_blocks(
+	async fn fetch_ledgers(
 		&self,
 		start_block: u64,
 		end_block: Option<u64>,
@@ -539,14 +720,17 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for StellarC
 
 			let http_response = self
 				.http_client
-				.send_raw_request(RPC_METHOD_GET_LEDGERS, Some(params))
+				.send_request::<_, JsonRpcResponse<Option<LedgersPage>>>(
+					RPC_METHOD_GET_LEDGERS,
+					Some(params),
+				)
 				.await;
 
 			match http_response {
 				Ok(response_body) => {
 					// Check for RPC errors in the response
 					if let Err(rpc_error) = self.check_and_handle_rpc_error(
-						&response_body,
+						response_body.error.as_ref(),
 						start_block as u32,
 						target_block as u32,
 						RPC_METHOD_GET_LEDGERS,
@@ -559,35 +743,16 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for StellarC
 					}
 
 					// Extract the ledgers from the response
-					let raw_ledgers = response_body
-						.get("result")
-						.and_then(|r| r.get("ledgers"))
-						.ok_or_else(|| {
-							let message = format!(
-								"Unexpected response structure for method '{}'",
-								RPC_METHOD_GET_LEDGERS
-							);
-							let sce_parse_error = StellarClientError::unexpected_response_structure(
-								message, None, None,
-							);
-							anyhow::anyhow!(sce_parse_error)
-								.context("Failed to parse ledger response")
-						})?;
-
-					let ledgers: Vec<StellarBlock> = serde_json::from_value(raw_ledgers.clone())
-						.map_err(|e| {
-							let message = format!(
-								"Failed to parse ledgers from response for method '{}': {}",
-								RPC_METHOD_GET_LEDGERS, e
-							);
-							let sce_parse_error = StellarClientError::response_parse_error(
-								message,
-								Some(e.into()),
-								None,
-							);
-							anyhow::anyhow!(sce_parse_error)
-								.context("Failed to parse ledger response")
-						})?;
+					let page = response_body.result.flatten().unwrap_or_default();
+					let ledgers = page.ledgers.ok_or_else(|| {
+						let message = format!(
+							"Unexpected response structure for method '{}'",
+							RPC_METHOD_GET_LEDGERS
+						);
+						let sce_parse_error =
+							StellarClientError::unexpected_response_structure(message, None, None);
+						anyhow::anyhow!(sce_parse_error).context("Failed to parse ledger response")
+					})?;
 
 					if ledgers.is_empty() {
 						break;
@@ -602,9 +767,7 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for StellarC
 
 					// Increment the number of iterations to ensure we break the loop in case there is no cursor
 					current_iteration += 1;
-					cursor = response_body["result"]["cursor"]
-						.as_str()
-						.map(|s| s.to_string());
+					cursor = page.cursor;
 
 					// If the cursor is the same as the start block, we have reached the end of the range
 					if cursor == Some(start_block.to_string()) {
@@ -615,6 +778,19 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for StellarC
 						break;
 					}
 				}
+				Err(parse_err @ TransportError::ResponseParse(_)) => {
+					let message = format!(
+						"Failed to parse ledgers from response for method '{}'",
+						RPC_METHOD_GET_LEDGERS
+					);
+					let sce_parse_error = StellarClientError::response_parse_error(
+						message,
+						Some(parse_err.into()),
+						None,
+					);
+					return Err(anyhow::anyhow!(sce_parse_error))
+						.context("Failed to parse ledger response");
+				}
 				Err(transport_err) => {
 					// Ledger info for logging
 					let ledger_info =
@@ -629,6 +805,69 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for StellarC
 		}
 		Ok(blocks)
 	}
+}
+
+impl<T: Send + Sync + Clone + BlockchainTransport> BlockFilterFactory<Self> for StellarClient<T> {
+	type Filter = StellarBlockFilter<Self>;
+
+	fn filter() -> Self::Filter {
+		StellarBlockFilter {
+			_client: PhantomData {},
+			rpc_results: Default::default(),
+		}
+	}
+}
+
+#[async_trait]
+impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for StellarClient<T> {
+	/// Retrieves the latest block number with retry functionality
+	#[instrument(skip(self))]
+	async fn get_latest_block_number(&self) -> Result<u64, anyhow::Error> {
+		let response = self
+			.http_client
+			.send_raw_request::<serde_json::Value>(RPC_METHOD_GET_LATEST_LEDGER, None)
+			.await
+			.with_context(|| "Failed to get latest ledger")?;
+
+		let sequence = response["result"]["sequence"]
+			.as_u64()
+			.ok_or_else(|| anyhow::anyhow!("Invalid sequence number"))?;
+
+		Ok(sequence)
+	}
+
+	/// Retrieves blocks within the specified range with retry functionality
+	///
+	/// # Note
+	/// If end_block is None, only the start_block will be retrieved
+	///
+	/// # Errors
+	/// - Returns `anyhow::Error`
+	#[instrument(skip(self), fields(start_block, end_block))]
+	async fn get_blocks(
+		&self,
+		start_block: u64,
+		end_block: Option<u64>,
+	) -> Result<Vec<BlockType>, anyhow::Error> {
+		self.blocks
+			.get_or_fetch((start_block, end_block), || {
+				self.fetch_ledgers(start_block, end_block)
+			})
+			.await
+	}
+
+	/// Retrieves ledgers within the specified range without the ledger cache, replacing the
+	/// cached ledgers of the range with the fetched ones
+	#[instrument(skip(self), fields(start_block, end_block))]
+	async fn refresh_blocks(
+		&self,
+		start_block: u64,
+		end_block: Option<u64>,
+	) -> Result<Vec<BlockType>, anyhow::Error> {
+		let blocks = self.fetch_ledgers(start_block, end_block).await?;
+		self.blocks.insert((start_block, end_block), blocks.clone());
+		Ok(blocks)
+	}
 
 	/// Retrieves the contract spec for a given contract ID
 	///
@@ -714,4 +953,10 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for StellarC
 			contract_spec,
 		)))
 	}
+
+	/// Calls a read-only JSON-RPC method through the HTTP transport
+	#[instrument(skip(self, params))]
+	async fn call_rpc(&self, method: &str, params: Option<Value>) -> Result<Value, anyhow::Error> {
+		call_rpc_with_transport(&self.http_client, method, params).await
+	}
 }
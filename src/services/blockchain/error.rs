@@ -3,7 +3,7 @@
 //! Provides a comprehensive error handling system for blockchain operations,
 //! including network connectivity, request processing, and blockchain-specific errors.
 
-use crate::utils::logging::error::{ErrorContext, TraceableError};
+use crate::utils::logging::error::{ErrorCode, ErrorContext, TraceableError};
 use std::collections::HashMap;
 use thiserror::Error as ThisError;
 use uuid::Uuid;
@@ -12,27 +12,27 @@ use uuid::Uuid;
 #[derive(ThisError, Debug)]
 pub enum BlockChainError {
 	/// Errors related to network connectivity issues
-	#[error("Connection error: {0}")]
+	#[error("[{}] Connection error: {0}", ErrorCode::BlockChainConnection)]
 	ConnectionError(ErrorContext),
 
 	/// Errors related to malformed requests or invalid responses
-	#[error("Request error: {0}")]
+	#[error("[{}] Request error: {0}", ErrorCode::BlockChainRequest)]
 	RequestError(ErrorContext),
 
 	/// When a requested block cannot be found on the blockchain
-	#[error("Block not found: {0}")]
+	#[error("[{}] Block not found: {0}", ErrorCode::BlockChainBlockNotFound)]
 	BlockNotFound(ErrorContext),
 
 	/// Errors related to transaction processing
-	#[error("Transaction error: {0}")]
+	#[error("[{}] Transaction error: {0}", ErrorCode::BlockChainTransaction)]
 	TransactionError(ErrorContext),
 
 	/// Internal errors within the blockchain client
-	#[error("Internal error: {0}")]
+	#[error("[{}] Internal error: {0}", ErrorCode::BlockChainInternal)]
 	InternalError(ErrorContext),
 
 	/// Errors related to client pool
-	#[error("Client pool error: {0}")]
+	#[error("[{}] Client pool error: {0}", ErrorCode::BlockChainClientPool)]
 	ClientPoolError(ErrorContext),
 
 	/// Other errors that don't fit into the categories above
@@ -47,7 +47,11 @@ impl BlockChainError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::ConnectionError(ErrorContext::new_with_log(msg, source, metadata))
+		Self::ConnectionError(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::BlockChainConnection)
+				.log(),
+		)
 	}
 
 	// Request error
@@ -56,7 +60,11 @@ impl BlockChainError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::RequestError(ErrorContext::new_with_log(msg, source, metadata))
+		Self::RequestError(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::BlockChainRequest)
+				.log(),
+		)
 	}
 
 	// Block not found
@@ -65,7 +73,11 @@ impl BlockChainError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::BlockNotFound(ErrorContext::new_with_log(msg, source, metadata))
+		Self::BlockNotFound(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::BlockChainBlockNotFound)
+				.log(),
+		)
 	}
 
 	// Transaction error
@@ -74,7 +86,11 @@ impl BlockChainError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::TransactionError(ErrorContext::new_with_log(msg, source, metadata))
+		Self::TransactionError(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::BlockChainTransaction)
+				.log(),
+		)
 	}
 
 	// Internal error
@@ -83,7 +99,11 @@ impl BlockChainError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::InternalError(ErrorContext::new_with_log(msg, source, metadata))
+		Self::InternalError(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::BlockChainInternal)
+				.log(),
+		)
 	}
 
 	// Client pool error
@@ -92,7 +112,24 @@ impl BlockChainError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::ClientPoolError(ErrorContext::new_with_log(msg, source, metadata))
+		Self::ClientPoolError(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::BlockChainClientPool)
+				.log(),
+		)
+	}
+
+	/// Returns the stable code identifying the kind of error
+	pub fn code(&self) -> ErrorCode {
+		match self {
+			Self::ConnectionError(_) => ErrorCode::BlockChainConnection,
+			Self::RequestError(_) => ErrorCode::BlockChainRequest,
+			Self::BlockNotFound(_) => ErrorCode::BlockChainBlockNotFound,
+			Self::TransactionError(_) => ErrorCode::BlockChainTransaction,
+			Self::InternalError(_) => ErrorCode::BlockChainInternal,
+			Self::ClientPoolError(_) => ErrorCode::BlockChainClientPool,
+			Self::Other(_) => ErrorCode::BlockChainOther,
+		}
 	}
 }
 
@@ -118,7 +155,10 @@ mod tests {
 	#[test]
 	fn test_connection_error_formatting() {
 		let error = BlockChainError::connection_error("test error", None, None);
-		assert_eq!(error.to_string(), "Connection error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[BLOCKCHAIN_CONNECTION] Connection error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = BlockChainError::connection_error(
@@ -128,14 +168,17 @@ mod tests {
 		);
 		assert_eq!(
 			error.to_string(),
-			"Connection error: test error [key1=value1]"
+			"[BLOCKCHAIN_CONNECTION] Connection error: test error [key1=value1]"
 		);
 	}
 
 	#[test]
 	fn test_request_error_formatting() {
 		let error = BlockChainError::request_error("test error", None, None);
-		assert_eq!(error.to_string(), "Request error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[BLOCKCHAIN_REQUEST] Request error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = BlockChainError::request_error(
@@ -143,13 +186,19 @@ mod tests {
 			Some(Box::new(source_error)),
 			Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
 		);
-		assert_eq!(error.to_string(), "Request error: test error [key1=value1]");
+		assert_eq!(
+			error.to_string(),
+			"[BLOCKCHAIN_REQUEST] Request error: test error [key1=value1]"
+		);
 	}
 
 	#[test]
 	fn test_block_not_found_formatting() {
 		let error = BlockChainError::block_not_found("1".to_string(), None, None);
-		assert_eq!(error.to_string(), "Block not found: 1");
+		assert_eq!(
+			error.to_string(),
+			"[BLOCKCHAIN_BLOCK_NOT_FOUND] Block not found: 1"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = BlockChainError::block_not_found(
@@ -157,13 +206,19 @@ mod tests {
 			Some(Box::new(source_error)),
 			Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
 		);
-		assert_eq!(error.to_string(), "Block not found: 1 [key1=value1]");
+		assert_eq!(
+			error.to_string(),
+			"[BLOCKCHAIN_BLOCK_NOT_FOUND] Block not found: 1 [key1=value1]"
+		);
 	}
 
 	#[test]
 	fn test_transaction_error_formatting() {
 		let error = BlockChainError::transaction_error("test error", None, None);
-		assert_eq!(error.to_string(), "Transaction error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[BLOCKCHAIN_TRANSACTION] Transaction error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = BlockChainError::transaction_error(
@@ -173,14 +228,17 @@ mod tests {
 		);
 		assert_eq!(
 			error.to_string(),
-			"Transaction error: test error [key1=value1]"
+			"[BLOCKCHAIN_TRANSACTION] Transaction error: test error [key1=value1]"
 		);
 	}
 
 	#[test]
 	fn test_internal_error_formatting() {
 		let error = BlockChainError::internal_error("test error", None, None);
-		assert_eq!(error.to_string(), "Internal error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[BLOCKCHAIN_INTERNAL] Internal error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = BlockChainError::internal_error(
@@ -190,14 +248,17 @@ mod tests {
 		);
 		assert_eq!(
 			error.to_string(),
-			"Internal error: test error [key1=value1]"
+			"[BLOCKCHAIN_INTERNAL] Internal error: test error [key1=value1]"
 		);
 	}
 
 	#[test]
 	fn test_client_pool_error_formatting() {
 		let error = BlockChainError::client_pool_error("test error", None, None);
-		assert_eq!(error.to_string(), "Client pool error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[BLOCKCHAIN_CLIENT_POOL] Client pool error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = BlockChainError::client_pool_error(
@@ -207,7 +268,7 @@ mod tests {
 		);
 		assert_eq!(
 			error.to_string(),
-			"Client pool error: test error [key1=value1]"
+			"[BLOCKCHAIN_CLIENT_POOL] Client pool error: test error [key1=value1]"
 		);
 	}
 
@@ -217,6 +278,50 @@ mod tests {
 		let block_chain_error: BlockChainError = anyhow_error.into();
 		assert!(matches!(block_chain_error, BlockChainError::Other(_)));
 		assert_eq!(block_chain_error.to_string(), "test anyhow error");
+		assert_eq!(block_chain_error.code(), ErrorCode::BlockChainOther);
+	}
+
+	#[test]
+	fn test_error_codes() {
+		let errors = vec![
+			(
+				BlockChainError::connection_error("test error", None, None),
+				ErrorCode::BlockChainConnection,
+			),
+			(
+				BlockChainError::request_error("test error", None, None),
+				ErrorCode::BlockChainRequest,
+			),
+			(
+				BlockChainError::block_not_found("test error", None, None),
+				ErrorCode::BlockChainBlockNotFound,
+			),
+			(
+				BlockChainError::transaction_error("test error", None, None),
+				ErrorCode::BlockChainTransaction,
+			),
+			(
+				BlockChainError::internal_error("test error", None, None),
+				ErrorCode::BlockChainInternal,
+			),
+			(
+				BlockChainError::client_pool_error("test error", None, None),
+				ErrorCode::BlockChainClientPool,
+			),
+		];
+
+		for (error, code) in errors {
+			assert_eq!(error.code(), code);
+			assert!(error.to_string().starts_with(&format!("[{}] ", code)));
+		}
+
+		// The code is also set on the context, for structured logs
+		let error = BlockChainError::connection_error("test error", None, None);
+		if let BlockChainError::ConnectionError(ctx) = &error {
+			assert_eq!(ctx.code, Some(ErrorCode::BlockChainConnection));
+		} else {
+			panic!("Expected ConnectionError variant");
+		}
 	}
 
 	#[test]
@@ -9,21 +9,25 @@
 //! - Error handling for blockchain operations
 //! - Client pool for managing multiple clients
 
+mod cache;
 mod client;
 mod clients;
 mod error;
 mod pool;
 mod transports;
 
-pub use client::{BlockChainClient, BlockFilterFactory};
+pub use cache::ResponseCache;
+pub use client::{BlockChainClient, BlockFilterFactory, ContractCallResult, MAX_RPC_RESULT_SIZE};
 pub use clients::{
 	EvmClient, EvmClientTrait, MidnightClient, MidnightClientTrait, MidnightSubstrateClientTrait,
-	StellarClient, StellarClientError, StellarClientTrait,
+	SolanaClient, SolanaClientTrait, StellarClient, StellarClientError, StellarClientTrait,
+	StellarPage, STELLAR_MAX_PAGE_SIZE,
 };
 pub use error::BlockChainError;
 pub use pool::{ClientPool, ClientPoolTrait};
 pub use transports::{
-	BlockchainTransport, EVMTransportClient, HttpEndpointManager, HttpTransportClient,
-	MidnightWsTransportClient, RotatingTransport, StellarTransportClient,
+	BlockchainTransport, CircuitBreaker, CircuitBreakerConfig, CircuitState, EVMTransportClient,
+	EndpointType, HttpEndpointManager, HttpTransportClient, JsonRpcResponse,
+	MidnightWsTransportClient, RotatingTransport, SolanaTransportClient, StellarTransportClient,
 	TransientErrorRetryStrategy, TransportError, WsConfig, WsEndpointManager, WsTransportClient,
 };
@@ -3,7 +3,7 @@
 //! This module provides a thread-safe client pooling system that:
 //! - Caches blockchain clients by network
 //! - Creates clients lazily on first use
-//! - Handles EVM, Stellar, and Midnight clients
+//! - Handles EVM, Stellar, Midnight, and Solana clients
 //! - Provides type-safe access to clients
 //! - Manages client lifecycles automatically
 //!
@@ -15,8 +15,9 @@ use crate::{
 	models::{BlockChainType, Network},
 	services::blockchain::{
 		BlockChainClient, BlockFilterFactory, EVMTransportClient, EvmClient, EvmClientTrait,
-		MidnightClient, MidnightClientTrait, MidnightWsTransportClient, StellarClient,
-		StellarClientTrait, StellarTransportClient,
+		MidnightClient, MidnightClientTrait, MidnightWsTransportClient, SolanaClient,
+		SolanaClientTrait, SolanaTransportClient, StellarClient, StellarClientTrait,
+		StellarTransportClient,
 	},
 };
 use anyhow::Context;
@@ -34,6 +35,7 @@ pub trait ClientPoolTrait: Send + Sync {
 	type MidnightClient: MidnightClientTrait
 		+ BlockChainClient
 		+ BlockFilterFactory<Self::MidnightClient>;
+	type SolanaClient: SolanaClientTrait + BlockChainClient + BlockFilterFactory<Self::SolanaClient>;
 
 	async fn get_evm_client(
 		&self,
@@ -47,6 +49,10 @@ pub trait ClientPoolTrait: Send + Sync {
 		&self,
 		network: &Network,
 	) -> Result<Arc<Self::MidnightClient>, anyhow::Error>;
+	async fn get_solana_client(
+		&self,
+		network: &Network,
+	) -> Result<Arc<Self::SolanaClient>, anyhow::Error>;
 }
 
 /// Main client pool manager that handles multiple blockchain types.
@@ -74,6 +80,7 @@ impl ClientPool {
 		pool.register_client_type::<MidnightClient<MidnightWsTransportClient>>(
 			BlockChainType::Midnight,
 		);
+		pool.register_client_type::<SolanaClient<SolanaTransportClient>>(BlockChainType::Solana);
 
 		pool
 	}
@@ -115,6 +122,46 @@ impl ClientPool {
 		Ok(client)
 	}
 
+	/// Removes the cached client of a network, if any.
+	///
+	/// The next request for the network's client creates a new one, e.g. to recover from a
+	/// client whose connections or state went bad.
+	///
+	/// # Arguments
+	/// * `network` - Network whose client is removed
+	pub async fn remove_client(&self, network: &Network) {
+		match network.network_type {
+			BlockChainType::EVM => {
+				self.remove_client_of_type::<<Self as ClientPoolTrait>::EvmClient>(network)
+					.await
+			}
+			BlockChainType::Stellar => {
+				self.remove_client_of_type::<<Self as ClientPoolTrait>::StellarClient>(network)
+					.await
+			}
+			BlockChainType::Midnight => {
+				self.remove_client_of_type::<<Self as ClientPoolTrait>::MidnightClient>(network)
+					.await
+			}
+			BlockChainType::Solana => {
+				self.remove_client_of_type::<<Self as ClientPoolTrait>::SolanaClient>(network)
+					.await
+			}
+		}
+	}
+
+	/// Internal helper method to remove the cached client of a network from the storage of
+	/// its client type.
+	async fn remove_client_of_type<T: 'static>(&self, network: &Network) {
+		if let Some(storage) = self
+			.storages
+			.get(&network.network_type)
+			.and_then(|s| s.downcast_ref::<ClientStorage<T>>())
+		{
+			storage.clients.write().await.remove(&network.slug);
+		}
+	}
+
 	/// Get the number of clients for a given client type.
 	pub async fn get_client_count<T: 'static>(&self, client_type: BlockChainType) -> usize {
 		match self
@@ -133,6 +180,7 @@ impl ClientPoolTrait for ClientPool {
 	type EvmClient = EvmClient<EVMTransportClient>;
 	type StellarClient = StellarClient<StellarTransportClient>;
 	type MidnightClient = MidnightClient<MidnightWsTransportClient>;
+	type SolanaClient = SolanaClient<SolanaTransportClient>;
 
 	/// Gets or creates an EVM client for the given network.
 	///
@@ -181,6 +229,22 @@ impl ClientPoolTrait for ClientPool {
 		.await
 		.with_context(|| "Failed to get or create Midnight client")
 	}
+
+	/// Gets or creates a Solana client for the given network.
+	///
+	/// First checks the cache for an existing client. If none exists,
+	/// creates a new client under a write lock.
+	async fn get_solana_client(
+		&self,
+		network: &Network,
+	) -> Result<Arc<Self::SolanaClient>, anyhow::Error> {
+		self.get_or_create_client(BlockChainType::Solana, network, |n| {
+			let network = n.clone();
+			Box::pin(async move { Self::SolanaClient::new(&network).await })
+		})
+		.await
+		.with_context(|| "Failed to get or create Solana client")
+	}
 }
 
 impl Default for ClientPool {
@@ -32,6 +32,10 @@ pub enum TransportError {
 	/// URL rotation error
 	#[error("URL rotation failed: {0}")]
 	UrlRotation(ErrorContext),
+
+	/// Response body exceeding the maximum response size
+	#[error("Response too large: {0}")]
+	ResponseTooLarge(ErrorContext),
 }
 
 impl TransportError {
@@ -82,6 +86,14 @@ impl TransportError {
 	) -> Self {
 		Self::UrlRotation(ErrorContext::new_with_log(msg, source, metadata))
 	}
+
+	pub fn response_too_large(
+		msg: impl Into<String>,
+		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+		metadata: Option<HashMap<String, String>>,
+	) -> Self {
+		Self::ResponseTooLarge(ErrorContext::new_with_log(msg, source, metadata))
+	}
 }
 
 impl TraceableError for TransportError {
@@ -92,6 +104,7 @@ impl TraceableError for TransportError {
 			Self::ResponseParse(ctx) => ctx.trace_id.clone(),
 			Self::RequestSerialization(ctx) => ctx.trace_id.clone(),
 			Self::UrlRotation(ctx) => ctx.trace_id.clone(),
+			Self::ResponseTooLarge(ctx) => ctx.trace_id.clone(),
 		}
 	}
 }
@@ -187,6 +200,12 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_response_too_large_error_formatting() {
+		let error = TransportError::response_too_large("test error", None, None);
+		assert_eq!(error.to_string(), "Response too large: test error");
+	}
+
 	#[test]
 	fn test_error_source_chain() {
 		let io_error = std::io::Error::other("while reading config");
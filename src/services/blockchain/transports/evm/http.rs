@@ -5,7 +5,7 @@
 //! transport implementations while providing specific EVM-focused functionality.
 
 use reqwest_middleware::ClientWithMiddleware;
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 
 use crate::{
@@ -72,6 +72,36 @@ impl BlockchainTransport for EVMTransportClient {
 		self.http_client.send_raw_request(method, params).await
 	}
 
+	/// Sends a JSON-RPC request to the EVM node, deserializing the response into `R`
+	///
+	/// # Arguments
+	/// * `method` - The JSON-RPC method to call
+	/// * `params` - Optional parameters to pass with the request
+	///
+	/// # Returns
+	/// * `Result<R, TransportError>` - The deserialized response or error
+	async fn send_request<P, R>(&self, method: &str, params: Option<P>) -> Result<R, TransportError>
+	where
+		P: Into<Value> + Send + Clone + Serialize,
+		R: DeserializeOwned + Send,
+	{
+		self.http_client.send_request(method, params).await
+	}
+
+	/// Sends several JSON-RPC requests to the EVM node in a single batch
+	///
+	/// # Arguments
+	/// * `requests` - The JSON-RPC methods to call and their parameters
+	///
+	/// # Returns
+	/// * `Result<Vec<Value>, TransportError>` - The JSON responses in request order, or error
+	async fn send_batch_request(
+		&self,
+		requests: &[(&str, Option<Value>)],
+	) -> Result<Vec<Value>, TransportError> {
+		self.http_client.send_batch_request(requests).await
+	}
+
 	/// Update endpoint manager with a new client
 	///
 	/// # Arguments
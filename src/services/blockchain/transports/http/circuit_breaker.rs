@@ -0,0 +1,275 @@
+//! Circuit breaker for RPC endpoints
+//!
+//! Tracks consecutive failures of each endpoint URL. Once an endpoint reaches the failure
+//! threshold its circuit opens and the endpoint is skipped until the cooldown has elapsed. The
+//! circuit then becomes half-open and lets a trial request through: a success closes it again,
+//! a failure re-opens it for another cooldown.
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
+
+use crate::utils::metrics::RPC_ENDPOINT_CIRCUIT_STATE;
+
+/// Default number of consecutive failures after which the circuit of an endpoint opens
+pub const DEFAULT_CIRCUIT_FAILURE_THRESHOLD: u32 = 3;
+
+/// Default time an open circuit waits before letting a trial request through
+pub const DEFAULT_CIRCUIT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// State of the circuit of an endpoint
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CircuitState {
+	/// Requests are sent to the endpoint
+	#[default]
+	Closed,
+	/// The endpoint is skipped until the cooldown has elapsed
+	Open,
+	/// A trial request decides whether the circuit closes or opens again
+	HalfOpen,
+}
+
+impl CircuitState {
+	/// Value reported for the state by the `rpc_endpoint_circuit_state` metric
+	fn metric_value(self) -> f64 {
+		match self {
+			CircuitState::Closed => 0.0,
+			CircuitState::HalfOpen => 1.0,
+			CircuitState::Open => 2.0,
+		}
+	}
+}
+
+/// Configuration of the circuit breaker
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+	/// Number of consecutive failures after which the circuit of an endpoint opens
+	pub failure_threshold: u32,
+	/// Time an open circuit waits before letting a trial request through
+	pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+	fn default() -> Self {
+		Self {
+			failure_threshold: DEFAULT_CIRCUIT_FAILURE_THRESHOLD,
+			cooldown: DEFAULT_CIRCUIT_COOLDOWN,
+		}
+	}
+}
+
+/// Circuit of a single endpoint
+#[derive(Debug, Default)]
+struct EndpointCircuit {
+	state: CircuitState,
+	consecutive_failures: u32,
+	opened_at: Option<Instant>,
+}
+
+/// Tracks the circuit state of RPC endpoints
+///
+/// Clones share the same circuits.
+#[derive(Clone, Debug, Default)]
+pub struct CircuitBreaker {
+	config: CircuitBreakerConfig,
+	circuits: Arc<Mutex<HashMap<String, EndpointCircuit>>>,
+}
+
+impl CircuitBreaker {
+	/// Creates a new circuit breaker with all circuits closed
+	///
+	/// # Arguments
+	/// * `config` - Failure threshold and cooldown of the circuits
+	pub fn new(config: CircuitBreakerConfig) -> Self {
+		Self {
+			config,
+			circuits: Arc::new(Mutex::new(HashMap::new())),
+		}
+	}
+
+	/// Returns the current circuit state of an endpoint
+	pub fn state(&self, url: &str) -> CircuitState {
+		self.lock()
+			.get(url)
+			.map(|circuit| circuit.state)
+			.unwrap_or_default()
+	}
+
+	/// Returns whether a request may be sent to an endpoint
+	///
+	/// An open circuit whose cooldown has elapsed becomes half-open, letting the request
+	/// through as a trial.
+	pub fn is_available(&self, url: &str) -> bool {
+		let mut circuits = self.lock();
+		let Some(circuit) = circuits.get_mut(url) else {
+			return true;
+		};
+
+		match circuit.state {
+			CircuitState::Closed | CircuitState::HalfOpen => true,
+			CircuitState::Open => {
+				let cooled_down = circuit
+					.opened_at
+					.is_none_or(|opened_at| opened_at.elapsed() >= self.config.cooldown);
+				if cooled_down {
+					tracing::debug!("Circuit for '{}' is half-open, allowing a trial", url);
+					set_state(url, circuit, CircuitState::HalfOpen);
+				}
+				cooled_down
+			}
+		}
+	}
+
+	/// Records a successful request, closing the circuit of the endpoint
+	pub fn record_success(&self, url: &str) {
+		let mut circuits = self.lock();
+		let circuit = circuits.entry(url.to_string()).or_default();
+
+		circuit.consecutive_failures = 0;
+		circuit.opened_at = None;
+		if circuit.state != CircuitState::Closed {
+			tracing::info!("Circuit for '{}' closed", url);
+		}
+		set_state(url, circuit, CircuitState::Closed);
+	}
+
+	/// Records a failed request, opening the circuit of the endpoint when the failure
+	/// threshold is reached or when a trial request fails
+	pub fn record_failure(&self, url: &str) {
+		let mut circuits = self.lock();
+		let circuit = circuits.entry(url.to_string()).or_default();
+
+		circuit.consecutive_failures = circuit.consecutive_failures.saturating_add(1);
+		if circuit.state == CircuitState::HalfOpen
+			|| circuit.consecutive_failures >= self.config.failure_threshold
+		{
+			if circuit.state != CircuitState::Open {
+				tracing::warn!(
+					"Circuit for '{}' opened after {} consecutive failure(s)",
+					url,
+					circuit.consecutive_failures
+				);
+			}
+			circuit.opened_at = Some(Instant::now());
+			set_state(url, circuit, CircuitState::Open);
+		} else {
+			let state = circuit.state;
+			set_state(url, circuit, state);
+		}
+	}
+
+	fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, EndpointCircuit>> {
+		self.circuits
+			.lock()
+			.unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+}
+
+/// Updates the state of a circuit and its metric
+fn set_state(url: &str, circuit: &mut EndpointCircuit, state: CircuitState) {
+	circuit.state = state;
+	RPC_ENDPOINT_CIRCUIT_STATE
+		.with_label_values(&[&endpoint_label(url)])
+		.set(state.metric_value());
+}
+
+/// Label identifying an endpoint in metrics
+///
+/// Only the host and port are used, as RPC URLs often carry API keys in their path or query.
+fn endpoint_label(url: &str) -> String {
+	reqwest::Url::parse(url)
+		.ok()
+		.and_then(|parsed| {
+			let host = parsed.host_str()?.to_string();
+			Some(match parsed.port() {
+				Some(port) => format!("{}:{}", host, port),
+				None => host,
+			})
+		})
+		.unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const URL: &str = "https://rpc.example.com/v2/secret-key";
+
+	fn create_breaker(cooldown: Duration) -> CircuitBreaker {
+		CircuitBreaker::new(CircuitBreakerConfig {
+			failure_threshold: 2,
+			cooldown,
+		})
+	}
+
+	#[test]
+	fn test_circuit_opens_after_threshold() {
+		let breaker = create_breaker(Duration::from_secs(60));
+
+		breaker.record_failure(URL);
+		assert_eq!(breaker.state(URL), CircuitState::Closed);
+		assert!(breaker.is_available(URL));
+
+		breaker.record_failure(URL);
+		assert_eq!(breaker.state(URL), CircuitState::Open);
+		assert!(!breaker.is_available(URL));
+	}
+
+	#[test]
+	fn test_success_resets_consecutive_failures() {
+		let breaker = create_breaker(Duration::from_secs(60));
+
+		breaker.record_failure(URL);
+		breaker.record_success(URL);
+		breaker.record_failure(URL);
+
+		assert_eq!(breaker.state(URL), CircuitState::Closed);
+	}
+
+	#[test]
+	fn test_half_open_trial() {
+		let breaker = create_breaker(Duration::ZERO);
+
+		breaker.record_failure(URL);
+		breaker.record_failure(URL);
+		assert_eq!(breaker.state(URL), CircuitState::Open);
+
+		// The cooldown has elapsed, so a trial request is allowed
+		assert!(breaker.is_available(URL));
+		assert_eq!(breaker.state(URL), CircuitState::HalfOpen);
+
+		// A failed trial opens the circuit again
+		breaker.record_failure(URL);
+		assert_eq!(breaker.state(URL), CircuitState::Open);
+
+		// A successful trial closes it
+		assert!(breaker.is_available(URL));
+		breaker.record_success(URL);
+		assert_eq!(breaker.state(URL), CircuitState::Closed);
+	}
+
+	#[test]
+	fn test_circuit_state_metric() {
+		let breaker = create_breaker(Duration::from_secs(60));
+		let url = "https://circuit-metric.example.com:8545/secret-key";
+
+		breaker.record_failure(url);
+		breaker.record_failure(url);
+
+		let gauge = RPC_ENDPOINT_CIRCUIT_STATE
+			.get_metric_with_label_values(&["circuit-metric.example.com:8545"])
+			.unwrap();
+		assert_eq!(gauge.get(), 2.0);
+	}
+
+	#[test]
+	fn test_endpoint_label_omits_path_and_query() {
+		assert_eq!(endpoint_label(URL), "rpc.example.com");
+		assert_eq!(
+			endpoint_label("http://127.0.0.1:8545/?key=secret"),
+			"127.0.0.1:8545"
+		);
+		assert_eq!(endpoint_label("not a url"), "unknown");
+	}
+}
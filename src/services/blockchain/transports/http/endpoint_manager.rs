@@ -1,17 +1,27 @@
 //! Manages the rotation of blockchain HTTP RPC endpoints
 //!
 //! Provides methods for rotating between multiple URLs and sending requests to the active endpoint
-//! with automatic fallback to other URLs on failure.
+//! with automatic fallback to other URLs on failure. Requests can be routed to archive or full
+//! node endpoints depending on their method and block, see [`EndpointType`]. Several requests
+//! can be sent at once in a JSON-RPC batch, see [`EndpointManager::send_batch_request`]. Response
+//! bodies are read up to a maximum size, see [`EndpointManager::set_max_response_bytes`].
 use reqwest_middleware::ClientWithMiddleware;
-use serde::Serialize;
-use serde_json::Value;
-use std::sync::Arc;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use std::{
+	cmp::Reverse,
+	collections::HashMap,
+	sync::{Arc, Mutex},
+};
 use tokio::sync::RwLock;
 
 use crate::services::blockchain::transports::{
-	RotatingTransport, TransportError, ROTATE_ON_ERROR_CODES,
+	CircuitBreaker, CircuitBreakerConfig, RotatingTransport, TransportError, ROTATE_ON_ERROR_CODES,
 };
 
+/// Maximum size of a response body when the network doesn't configure one, 128 MiB
+pub const DEFAULT_MAX_RESPONSE_BYTES: usize = 128 * 1024 * 1024;
+
 /// Manages the rotation of blockchain RPC endpoints
 ///
 /// Provides methods for rotating between multiple URLs and sending requests to the active endpoint
@@ -22,12 +32,91 @@ use crate::services::blockchain::transports::{
 /// * `fallback_urls` - A list of fallback URLs to rotate to
 /// * `client` - The client to use for the endpoint manager
 /// * `rotation_lock` - A lock for managing the rotation process
+/// * `circuit_breaker` - Tracks failing endpoints so that they are skipped for a cooldown
+/// * `url_weights` - The weighted URLs requests are distributed across, see
+///   [`EndpointManager::set_url_weights`]
+/// * `url_types` - The node type of the URLs, used to route requests by method, see
+///   [`EndpointManager::set_url_types`]
+/// * `max_response_bytes` - The maximum size of a response body, see
+///   [`EndpointManager::set_max_response_bytes`]
 #[derive(Clone, Debug)]
 pub struct EndpointManager {
 	pub active_url: Arc<RwLock<String>>,
 	pub fallback_urls: Arc<RwLock<Vec<String>>>,
 	client: ClientWithMiddleware,
 	rotation_lock: Arc<tokio::sync::Mutex<()>>,
+	circuit_breaker: CircuitBreaker,
+	url_weights: Arc<Mutex<Vec<WeightedUrl>>>,
+	url_types: Arc<HashMap<String, EndpointType>>,
+	max_response_bytes: usize,
+}
+
+/// Type of node behind an RPC endpoint
+///
+/// Set through the `type_` of the network RPC URLs. Endpoints of type `rpc` have no node type
+/// and serve any request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EndpointType {
+	/// Archive node, keeping the full history of the chain
+	Archive,
+	/// Full node, used for requests about the chain head
+	Full,
+}
+
+impl EndpointType {
+	/// Returns the node type of an RPC URL type, `None` for untyped `rpc` URLs
+	pub fn from_url_type(type_: &str) -> Option<Self> {
+		match type_ {
+			"archive" => Some(Self::Archive),
+			"full" => Some(Self::Full),
+			_ => None,
+		}
+	}
+
+	/// Returns the node type preferred to serve a JSON-RPC request
+	///
+	/// Log queries, which may span a wide range of past blocks, and trace, debug and proof
+	/// methods are routed to archive nodes. So are state reads (`eth_call`, `eth_getBalance` and
+	/// `eth_getCode`) at a block number or hash, since full nodes only keep the state of the
+	/// most recent blocks. Any other request, such as polling the latest block number or reading
+	/// the latest state, is routed to full nodes.
+	///
+	/// # Arguments
+	/// * `method` - The JSON-RPC method of the request
+	/// * `params` - The parameters of the request
+	pub fn for_request(method: &str, params: Option<&Value>) -> Self {
+		match method {
+			"eth_getLogs" | "eth_getProof" => Self::Archive,
+			"eth_call" | "eth_getBalance" | "eth_getCode"
+				if params
+					.and_then(|params| params.get(1))
+					.is_some_and(is_past_block) =>
+			{
+				Self::Archive
+			}
+			_ if method.starts_with("trace_") || method.starts_with("debug_") => Self::Archive,
+			_ => Self::Full,
+		}
+	}
+}
+
+/// Returns whether a block parameter designates a block by number or hash rather than by a tag
+/// following the chain head, such as `latest`
+fn is_past_block(block: &Value) -> bool {
+	match block {
+		Value::String(tag) => !matches!(tag.as_str(), "latest" | "pending" | "safe" | "finalized"),
+		Value::Object(_) => true,
+		_ => false,
+	}
+}
+
+/// A URL taking part in the weighted selection
+#[derive(Clone, Debug)]
+struct WeightedUrl {
+	url: String,
+	weight: u32,
+	/// Current weight of the smooth weighted round-robin
+	current_weight: i64,
 }
 
 /// Represents the outcome of a `EndpointManager::attempt_request_on_url` method call
@@ -57,10 +146,153 @@ impl EndpointManager {
 			active_url: Arc::new(RwLock::new(active_url.to_string())),
 			fallback_urls: Arc::new(RwLock::new(fallback_urls)),
 			rotation_lock: Arc::new(tokio::sync::Mutex::new(())),
+			circuit_breaker: CircuitBreaker::new(CircuitBreakerConfig::default()),
+			url_weights: Arc::new(Mutex::new(Vec::new())),
+			url_types: Arc::new(HashMap::new()),
+			max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
 			client,
 		}
 	}
 
+	/// Sets the weights used to distribute requests across the URLs
+	///
+	/// Each request is sent to a URL picked by smooth weighted round-robin, so that every URL
+	/// receives a share of the requests proportional to its weight. URLs with a weight of `0`
+	/// only serve as fallbacks on failures. Without weights, all requests go to the active URL.
+	///
+	/// # Arguments
+	/// * `weights` - The URLs with their weights
+	pub fn set_url_weights(&mut self, weights: Vec<(String, u32)>) {
+		let weighted_urls = weights
+			.into_iter()
+			.map(|(url, weight)| WeightedUrl {
+				url,
+				weight,
+				current_weight: 0,
+			})
+			.collect();
+		self.url_weights = Arc::new(Mutex::new(weighted_urls));
+	}
+
+	/// Sets the node type of the URLs
+	///
+	/// URLs without a type serve any request, and are only selected for a method when no URL of
+	/// the node type preferred for it is available.
+	///
+	/// # Arguments
+	/// * `types` - The URLs with their node type
+	pub fn set_url_types(&mut self, types: HashMap<String, EndpointType>) {
+		self.url_types = Arc::new(types);
+	}
+
+	/// Sets the maximum size of a response body
+	///
+	/// Requests whose response is larger fail with [`TransportError::ResponseTooLarge`] once the
+	/// limit is reached, without buffering the rest of the body, so that an endpoint returning
+	/// huge responses can't exhaust the memory of the process. Defaults to
+	/// [`DEFAULT_MAX_RESPONSE_BYTES`].
+	///
+	/// # Arguments
+	/// * `max_response_bytes` - The maximum size of a response body, in bytes
+	pub fn set_max_response_bytes(&mut self, max_response_bytes: usize) {
+		self.max_response_bytes = max_response_bytes;
+	}
+
+	/// Selects the URL of the next request by smooth weighted round-robin
+	///
+	/// URLs with a weight of `0` and URLs whose circuit is open are not selected.
+	///
+	/// # Returns
+	/// * `Option<String>` - The selected URL, or `None` if no weighted URL is available
+	pub fn select_url(&self) -> Option<String> {
+		self.select_weighted_url(|_| true)
+	}
+
+	/// Selects the URL of the next request
+	///
+	/// The selection is made among the URLs of the node type preferred for the request, see
+	/// [`EndpointType::for_request`], falling back to all URLs when none of them is available.
+	///
+	/// # Arguments
+	/// * `method` - The JSON-RPC method of the request
+	/// * `params` - The parameters of the request
+	///
+	/// # Returns
+	/// * `Option<String>` - The selected URL, or `None` if no weighted URL is available
+	pub fn select_url_for_request(&self, method: &str, params: Option<&Value>) -> Option<String> {
+		self.select_url_for_type(EndpointType::for_request(method, params))
+	}
+
+	/// Selects the URL of the next request among the URLs of a node type, falling back to all
+	/// URLs when none of them is available
+	fn select_url_for_type(&self, preferred: EndpointType) -> Option<String> {
+		self.select_weighted_url(|url| self.url_types.get(url) == Some(&preferred))
+			.or_else(|| self.select_url())
+	}
+
+	/// Selects a URL among the ones accepted by `filter` by smooth weighted round-robin
+	fn select_weighted_url(&self, filter: impl Fn(&str) -> bool) -> Option<String> {
+		let mut url_weights = self
+			.url_weights
+			.lock()
+			.unwrap_or_else(|poisoned| poisoned.into_inner());
+
+		let candidates: Vec<usize> = url_weights
+			.iter()
+			.enumerate()
+			.filter(|(_, entry)| {
+				entry.weight > 0
+					&& filter(&entry.url)
+					&& self.circuit_breaker.is_available(&entry.url)
+			})
+			.map(|(index, _)| index)
+			.collect();
+		let total_weight: i64 = candidates
+			.iter()
+			.map(|&index| i64::from(url_weights[index].weight))
+			.sum();
+
+		let mut selected: Option<usize> = None;
+		for &index in &candidates {
+			let weight = i64::from(url_weights[index].weight);
+			url_weights[index].current_weight += weight;
+			if selected.is_none_or(|best| {
+				url_weights[index].current_weight > url_weights[best].current_weight
+			}) {
+				selected = Some(index);
+			}
+		}
+
+		let selected = &mut url_weights[selected?];
+		selected.current_weight -= total_weight;
+		Some(selected.url.clone())
+	}
+
+	/// Returns the configured weight of a URL, `0` if it has none
+	fn url_weight(&self, url: &str) -> u32 {
+		self.url_weights
+			.lock()
+			.unwrap_or_else(|poisoned| poisoned.into_inner())
+			.iter()
+			.find(|entry| entry.url == url)
+			.map_or(0, |entry| entry.weight)
+	}
+
+	/// Replaces the circuit breaker with one using the given configuration
+	///
+	/// Circuit states tracked so far are discarded.
+	///
+	/// # Arguments
+	/// * `config` - The failure threshold and cooldown of the endpoint circuits
+	pub fn set_circuit_breaker_config(&mut self, config: CircuitBreakerConfig) {
+		self.circuit_breaker = CircuitBreaker::new(config);
+	}
+
+	/// Returns the circuit breaker tracking the endpoints
+	pub fn circuit_breaker(&self) -> &CircuitBreaker {
+		&self.circuit_breaker
+	}
+
 	/// Updates the client with a new client
 	///
 	/// Useful for updating the client with a new retry policy or strategy
@@ -93,10 +325,12 @@ impl EndpointManager {
 			current_fallbacks_snapshot,
 		);
 
-		// --- Select a new URL ---
+		// --- Select a new URL, preferring higher weights and skipping endpoints with an open
+		// circuit ---
 		let new_url = match current_fallbacks_snapshot
 			.iter()
-			.find(|&url| *url != initial_active_url)
+			.filter(|&url| *url != initial_active_url && self.circuit_breaker.is_available(url))
+			.min_by_key(|&url| Reverse(self.url_weight(url)))
 		{
 			Some(url) => url.clone(),
 			None => {
@@ -118,6 +352,7 @@ impl EndpointManager {
 			.try_connect(&new_url)
 			.await
 			.map_err(|connect_err| {
+				self.circuit_breaker.record_failure(&new_url);
 				TransportError::url_rotation(
 					format!("Failed to connect to new URL '{}'", new_url),
 					Some(connect_err.into()),
@@ -175,27 +410,17 @@ impl EndpointManager {
 	/// Attempts to send a request to the specified URL
 	/// # Arguments
 	/// * `url` - The URL to send the request to
-	/// * `transport` - The transport client implementing the RotatingTransport trait
-	/// * `method` - The HTTP method to use for the request (e.g., "POST")
-	/// * `params` - Optional parameters for the request, serialized to JSON
+	/// * `request_body` - The JSON-RPC request, or batch of requests, to send
 	///
 	/// # Returns
 	/// * `SingleRequestAttemptOutcome` - The outcome of the request attempt
-	async fn try_request_on_url<P>(
+	async fn try_request_on_url(
 		&self,
 		url: &str,
-		transport: &impl RotatingTransport,
-		method: &str,
-		params: Option<P>,
-	) -> SingleRequestAttemptOutcome
-	where
-		P: Into<Value> + Send + Clone + Serialize,
-	{
-		// Create the request body using the transport's customization method
-		let request_body = transport.customize_request(method, params).await;
-
+		request_body: &Value,
+	) -> SingleRequestAttemptOutcome {
 		// Serialize the request body to JSON
-		let request_body_str = match serde_json::to_string(&request_body) {
+		let request_body_str = match serde_json::to_string(request_body) {
 			Ok(body) => body,
 			Err(e) => {
 				tracing::error!("Failed to serialize request body: {}", e);
@@ -240,8 +465,13 @@ impl EndpointManager {
 	///
 	/// # Behavior
 	/// - Automatically rotates to fallback URLs if the request fails with specific status codes
-	///   (e.g., 429)
+	///   (e.g., 429 or 503) or a network error
 	/// - Retries the request with the new URL after rotation
+	/// - Skips endpoints whose circuit is open, see [`CircuitBreaker`]
+	/// - Distributes requests across the weighted URLs, see
+	///   [`EndpointManager::set_url_weights`], preferring the node type suited to the request,
+	///   see [`EndpointManager::select_url_for_request`]. The selected URL only serves the
+	///   request, the active URL stays the one requests fall back to
 	/// - Returns the first successful response or an error if all attempts fail
 	pub async fn send_raw_request<
 		T: RotatingTransport,
@@ -252,17 +482,176 @@ impl EndpointManager {
 		method: &str,
 		params: Option<P>,
 	) -> Result<Value, TransportError> {
-		loop {
-			let current_url_snapshot = self.active_url.read().await.clone();
+		self.send_request(transport, method, params).await
+	}
 
-			tracing::debug!(
-				"Attempting request on active URL: '{}'",
-				current_url_snapshot
+	/// Sends a request to the blockchain RPC endpoint, deserializing the response into `R`
+	///
+	/// Behaves like [`EndpointManager::send_raw_request`], except that the response body is
+	/// deserialized directly into `R` rather than into a [`Value`], so that large responses
+	/// aren't held in memory twice.
+	///
+	/// # Arguments
+	/// * `transport` - The transport client implementing the RotatingTransport trait
+	/// * `method` - The RPC method name to call
+	/// * `params` - The parameters for the RPC method call as a JSON Value
+	///
+	/// # Returns
+	/// * `Result<R, TransportError>` - The deserialized response or an error
+	pub async fn send_request<T, P, R>(
+		&self,
+		transport: &T,
+		method: &str,
+		params: Option<P>,
+	) -> Result<R, TransportError>
+	where
+		T: RotatingTransport,
+		P: Into<Value> + Send + Clone + Serialize,
+		R: DeserializeOwned,
+	{
+		// Create the request body using the transport's customization method
+		let request_body = transport.customize_request(method, params).await;
+		let selected_url = self.select_url_for_request(method, request_body.get("params"));
+		self.send_request_body(transport, &request_body, selected_url)
+			.await
+	}
+
+	/// Sends a batch of requests in a single JSON-RPC batch, returning the responses in the
+	/// order of the requests
+	///
+	/// The requests are numbered by their position in the batch, which is used to order the
+	/// responses. Endpoints that reject batches, by answering with a single response or with a
+	/// client error status, are sent the requests one after the other instead.
+	///
+	/// # Arguments
+	/// * `transport` - The transport client implementing the RotatingTransport trait
+	/// * `requests` - The RPC method names and parameters of the requests
+	///
+	/// # Returns
+	/// * `Result<Vec<Value>, TransportError>` - The JSON responses, in the order of the requests,
+	///   or an error
+	pub async fn send_batch_request<T: RotatingTransport>(
+		&self,
+		transport: &T,
+		requests: &[(&str, Option<Value>)],
+	) -> Result<Vec<Value>, TransportError> {
+		if requests.is_empty() {
+			return Ok(Vec::new());
+		}
+		// The batch is served by a single node, an archive node if any request needs one
+		let preferred = if requests.iter().any(|(method, params)| {
+			EndpointType::for_request(method, params.as_ref()) == EndpointType::Archive
+		}) {
+			EndpointType::Archive
+		} else {
+			EndpointType::Full
+		};
+		let selected_url = self.select_url_for_type(preferred);
+
+		let mut request_body = Vec::with_capacity(requests.len());
+		for (id, (method, params)) in requests.iter().enumerate() {
+			let mut request = transport.customize_request(method, params.clone()).await;
+			if let Some(request) = request.as_object_mut() {
+				request.insert("id".to_string(), json!(id));
+			}
+			request_body.push(request);
+		}
+
+		let rejection = match self
+			.send_request_body::<T, Value>(transport, &Value::Array(request_body), selected_url)
+			.await
+		{
+			Ok(Value::Array(responses)) => return order_batch_responses(responses, requests.len()),
+			Ok(response) => response.to_string(),
+			Err(TransportError::Http {
+				status_code, body, ..
+			}) if status_code.is_client_error()
+				&& !ROTATE_ON_ERROR_CODES.contains(&status_code.as_u16()) =>
+			{
+				format!("status {}: {}", status_code, body)
+			}
+			Err(e) => return Err(e),
+		};
+
+		tracing::warn!(
+			"Batch of {} requests rejected ({}), sending them one after the other",
+			requests.len(),
+			rejection
+		);
+		let mut responses = Vec::with_capacity(requests.len());
+		for (method, params) in requests {
+			responses.push(
+				self.send_raw_request(transport, method, params.clone())
+					.await?,
 			);
+		}
+		Ok(responses)
+	}
+
+	/// Moves on from a URL a request failed on
+	///
+	/// When the URL was selected for the request only, the request is retried on the active URL,
+	/// otherwise the active URL is rotated.
+	///
+	/// # Arguments
+	/// * `transport` - The transport client implementing the RotatingTransport trait
+	/// * `failed_url` - The URL the request failed on
+	async fn move_on_from<T: RotatingTransport>(
+		&self,
+		transport: &T,
+		failed_url: &str,
+	) -> Result<(), TransportError> {
+		if *self.active_url.read().await != failed_url {
+			return Ok(());
+		}
+		self.try_rotate_url(transport).await.map(|_| ())
+	}
+
+	/// Sends a request body to the URL selected for it, or to the active URL, deserializing the
+	/// response into `R`
+	///
+	/// The URL is passed along rather than made active, so that concurrent requests are each
+	/// sent to the URL selected for them. Rotates URLs on failure, see
+	/// [`EndpointManager::send_raw_request`].
+	async fn send_request_body<T, R>(
+		&self,
+		transport: &T,
+		request_body: &Value,
+		selected_url: Option<String>,
+	) -> Result<R, TransportError>
+	where
+		T: RotatingTransport,
+		R: DeserializeOwned,
+	{
+		let mut selected_url = selected_url;
+		loop {
+			let current_url_snapshot = match selected_url.take() {
+				Some(url) => url,
+				None => self.active_url.read().await.clone(),
+			};
+
+			// Move away from the URL while its circuit is open
+			if !self.circuit_breaker.is_available(&current_url_snapshot) {
+				match self.move_on_from(transport, &current_url_snapshot).await {
+					Ok(()) => continue,
+					Err(rotation_error) => {
+						return Err(TransportError::network(
+							format!(
+								"Circuit is open for '{}' and no other endpoint is available",
+								current_url_snapshot
+							),
+							Some(Box::new(rotation_error)),
+							None,
+						));
+					}
+				}
+			}
 
-			// Attempt to send the request to the current active URL
+			tracing::debug!("Attempting request on URL: '{}'", current_url_snapshot);
+
+			// Attempt to send the request to the current URL
 			let attempt_result = self
-				.try_request_on_url(&current_url_snapshot, transport, method, params.clone())
+				.try_request_on_url(&current_url_snapshot, request_body)
 				.await;
 
 			match attempt_result {
@@ -270,8 +659,15 @@ impl EndpointManager {
 				SingleRequestAttemptOutcome::Success(response) => {
 					let status = response.status();
 					if status.is_success() {
+						self.circuit_breaker.record_success(&current_url_snapshot);
 						// Successful response, parse JSON
-						return response.json().await.map_err(|e| {
+						let body = read_response_body(
+							response,
+							self.max_response_bytes,
+							&current_url_snapshot,
+						)
+						.await?;
+						return serde_json::from_slice::<R>(&body).map_err(|e| {
 							TransportError::response_parse(
 								"Failed to parse JSON response".to_string(),
 								Some(Box::new(e)),
@@ -280,7 +676,14 @@ impl EndpointManager {
 						});
 					} else {
 						// HTTP error
-						let error_body = response.text().await.unwrap_or_default();
+						let error_body = read_response_body(
+							response,
+							self.max_response_bytes,
+							&current_url_snapshot,
+						)
+						.await
+						.map(|body| String::from_utf8_lossy(&body).into_owned())
+						.unwrap_or_default();
 						tracing::warn!(
 							"Request to {} failed with status {}: {}",
 							current_url_snapshot,
@@ -290,15 +693,16 @@ impl EndpointManager {
 
 						// Check if we should rotate based on status code
 						if ROTATE_ON_ERROR_CODES.contains(&status.as_u16()) {
+							self.circuit_breaker.record_failure(&current_url_snapshot);
 							tracing::debug!(
 								"send_raw_request: HTTP status {} on '{}' triggers URL rotation attempt",
 								status,
 								current_url_snapshot
 							);
 
-							match self.try_rotate_url(transport).await {
-								Ok(_new_url) => {
-									continue; // Retry on the new active URL
+							match self.move_on_from(transport, &current_url_snapshot).await {
+								Ok(()) => {
+									continue; // Retry on the next URL
 								}
 								Err(rotation_error) => {
 									// Return the original HTTP error with rotation error context
@@ -312,7 +716,9 @@ impl EndpointManager {
 								}
 							}
 						} else {
-							// HTTP error that doesn't trigger rotation
+							// HTTP error that doesn't trigger rotation, the endpoint itself is
+							// healthy
+							self.circuit_breaker.record_success(&current_url_snapshot);
 							tracing::warn!(
 								"HTTP error status {} on {} does not trigger rotation. Failing.",
 								status,
@@ -335,15 +741,16 @@ impl EndpointManager {
 						current_url_snapshot,
 						network_error,
 					);
+					self.circuit_breaker.record_failure(&current_url_snapshot);
 
 					// Always attempt rotation on network errors
-					match self.try_rotate_url(transport).await {
-						Ok(new_url) => {
+					match self.move_on_from(transport, &current_url_snapshot).await {
+						Ok(()) => {
 							tracing::debug!(
-								"Rotation successful after network error, retrying request on new URL: '{}'",
-								new_url
+								"Retrying request after network error on '{}'",
+								current_url_snapshot
 							);
-							continue; // Retry on the new active URL
+							continue; // Retry on the next URL
 						}
 						Err(rotation_error) => {
 							// Return network error with rotation error context
@@ -363,3 +770,89 @@ impl EndpointManager {
 		}
 	}
 }
+
+/// Reads the body of a response, failing as soon as it exceeds `max_bytes`
+///
+/// The declared `Content-Length` is checked before reading, and the body is then read chunk by
+/// chunk, so that neither a body larger than declared nor a chunked one is buffered past the
+/// limit.
+///
+/// # Arguments
+/// * `response` - The response to read
+/// * `max_bytes` - The maximum size of the body, in bytes
+/// * `url` - The URL the response was received from
+///
+/// # Returns
+/// * `Result<Vec<u8>, TransportError>` - The body, or an error if it is too large or can't be
+///   read
+async fn read_response_body(
+	mut response: reqwest::Response,
+	max_bytes: usize,
+	url: &str,
+) -> Result<Vec<u8>, TransportError> {
+	let too_large = || {
+		TransportError::response_too_large(
+			format!(
+				"Response from {} exceeds the maximum of {} bytes",
+				url, max_bytes
+			),
+			None,
+			None,
+		)
+	};
+
+	let content_length = response.content_length().unwrap_or(0);
+	if content_length > max_bytes as u64 {
+		return Err(too_large());
+	}
+
+	let mut body = Vec::with_capacity(content_length as usize);
+	while let Some(chunk) = response.chunk().await.map_err(|e| {
+		TransportError::network(
+			format!("Failed to read response from {}", url),
+			Some(Box::new(e)),
+			None,
+		)
+	})? {
+		if body.len() + chunk.len() > max_bytes {
+			return Err(too_large());
+		}
+		body.extend_from_slice(&chunk);
+	}
+	Ok(body)
+}
+
+/// Orders the responses of a JSON-RPC batch by the ids of their requests, `0..count`
+///
+/// # Returns
+/// * `Result<Vec<Value>, TransportError>` - The responses, or an error if the response to a
+///   request is missing
+fn order_batch_responses(
+	responses: Vec<Value>,
+	count: usize,
+) -> Result<Vec<Value>, TransportError> {
+	let mut ordered = vec![None; count];
+	for response in responses {
+		let slot = response
+			.get("id")
+			.and_then(Value::as_u64)
+			.and_then(|id| ordered.get_mut(id as usize));
+		if let Some(slot) = slot {
+			*slot = Some(response);
+		}
+	}
+
+	ordered
+		.into_iter()
+		.enumerate()
+		.map(|(id, response)| {
+			response.ok_or_else(|| {
+				TransportError::response_parse(
+					format!("Missing response to request {} of the batch", id),
+					None,
+					None,
+				)
+			})
+		})
+		.collect()
+}
@@ -5,22 +5,26 @@
 //! - Multiple RPC endpoints with automatic failover
 //! - Configurable retry policies
 //! - Authentication via bearer tokens
+//! - Custom headers, such as API keys, sent with every request
 //! - Connection health checks
 //! - Endpoint rotation for high availability
+//! - Routing of requests to archive or full node endpoints
+//! - A maximum size of the responses
 
 use anyhow::Context;
 use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use reqwest_middleware::ClientWithMiddleware;
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 use serde_json::{json, Value};
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use url::Url;
 
 use crate::{
 	models::Network,
 	services::blockchain::transports::{
-		http::endpoint_manager::EndpointManager, BlockchainTransport, RotatingTransport,
-		TransientErrorRetryStrategy, TransportError,
+		http::endpoint_manager::{EndpointManager, EndpointType, DEFAULT_MAX_RESPONSE_BYTES},
+		BlockchainTransport, RotatingTransport, TransientErrorRetryStrategy, TransportError,
 	},
 	utils::http::{create_retryable_http_client, RetryConfig},
 };
@@ -51,6 +55,12 @@ impl HttpTransportClient {
 	/// weight until a successful connection is established. It configures default
 	/// timeout and retry policies suitable for blockchain interactions.
 	///
+	/// Requests are then distributed across the endpoints in proportion to their weight.
+	/// Endpoints with a weight of `0` are only used as fallbacks. Endpoints of type `archive` or
+	/// `full` are preferred for the methods suited to their node type, see [`EndpointType`].
+	/// The network's `headers` are sent with every request, including connection tests, and
+	/// responses larger than its `max_response_bytes` are rejected.
+	///
 	/// # Arguments
 	/// * `network` - Network configuration containing RPC URLs, weights, and other details
 	/// * `test_connection_payload` - Optional JSON RPC payload to test the connection (default is net_version)
@@ -64,16 +74,30 @@ impl HttpTransportClient {
 		let mut rpc_urls: Vec<_> = network
 			.rpc_urls
 			.iter()
-			.filter(|rpc_url| rpc_url.type_ == "rpc" && rpc_url.weight > 0)
+			.filter(|rpc_url| {
+				rpc_url.type_ == "rpc" || EndpointType::from_url_type(&rpc_url.type_).is_some()
+			})
 			.collect();
 
 		rpc_urls.sort_by(|a, b| b.weight.cmp(&a.weight));
+		let url_weights: Vec<(String, u32)> = rpc_urls
+			.iter()
+			.map(|rpc_url| (rpc_url.url.as_ref().to_string(), rpc_url.weight))
+			.collect();
+		let url_types: HashMap<String, EndpointType> = rpc_urls
+			.iter()
+			.filter_map(|rpc_url| {
+				EndpointType::from_url_type(&rpc_url.type_)
+					.map(|type_| (rpc_url.url.as_ref().to_string(), type_))
+			})
+			.collect();
 		// Create a retry policy with default settings
 		// Shared config for endpoint manager and test connection
 		let http_retry_config = RetryConfig::default();
 		// Create the base HTTP client
 		let base_http_client = Arc::new(
 			reqwest::ClientBuilder::new()
+				.default_headers(create_default_headers(network)?)
 				.pool_idle_timeout(Duration::from_secs(90))
 				.pool_max_idle_per_host(32)
 				.timeout(Duration::from_secs(30))
@@ -127,14 +151,23 @@ impl HttpTransportClient {
 						.map(|url| url.url.as_ref().to_string())
 						.collect();
 
+					let mut endpoint_manager = EndpointManager::new(
+						retryable_client.clone(),
+						rpc_url.url.as_ref(),
+						fallback_urls,
+					);
+					endpoint_manager.set_url_weights(url_weights);
+					endpoint_manager.set_url_types(url_types);
+					endpoint_manager.set_max_response_bytes(
+						network
+							.max_response_bytes
+							.unwrap_or(DEFAULT_MAX_RESPONSE_BYTES),
+					);
+
 					// Successfully connected - create and return the client
 					return Ok(Self {
-						client: retryable_client.clone(),
-						endpoint_manager: EndpointManager::new(
-							retryable_client,
-							rpc_url.url.as_ref(),
-							fallback_urls,
-						),
+						client: retryable_client,
+						endpoint_manager,
 						test_connection_payload,
 					});
 				}
@@ -149,6 +182,30 @@ impl HttpTransportClient {
 	}
 }
 
+/// Creates the headers sent with every request to the network's RPC endpoints
+///
+/// Header values are marked as sensitive, as they often hold API keys.
+///
+/// # Arguments
+/// * `network` - Network configuration holding the headers
+///
+/// # Returns
+/// * `Result<HeaderMap, anyhow::Error>` - Headers, or an error if a name or value is invalid
+fn create_default_headers(network: &Network) -> Result<HeaderMap, anyhow::Error> {
+	let mut headers = HeaderMap::new();
+
+	for (name, value) in network.headers.iter().flatten() {
+		let name = HeaderName::from_bytes(name.as_bytes())
+			.with_context(|| format!("Invalid RPC header name: {}", name))?;
+		let mut value = HeaderValue::from_str(value)
+			.with_context(|| format!("Invalid value of RPC header {}", name))?;
+		value.set_sensitive(true);
+		headers.insert(name, value);
+	}
+
+	Ok(headers)
+}
+
 #[async_trait]
 impl BlockchainTransport for HttpTransportClient {
 	/// Retrieves the currently active RPC endpoint URL
@@ -192,6 +249,45 @@ impl BlockchainTransport for HttpTransportClient {
 			.await
 	}
 
+	/// Sends a JSON-RPC request to the blockchain node, deserializing the response into `R`
+	///
+	/// The response body is deserialized directly, without an intermediate JSON value.
+	///
+	/// # Arguments
+	/// * `method` - The JSON-RPC method name to call
+	/// * `params` - Optional parameters for the method call
+	///
+	/// # Returns
+	/// * `Result<R, TransportError>` - Deserialized response or error with context
+	async fn send_request<P, R>(&self, method: &str, params: Option<P>) -> Result<R, TransportError>
+	where
+		P: Into<Value> + Send + Clone + Serialize,
+		R: DeserializeOwned + Send,
+	{
+		self.endpoint_manager
+			.send_request(self, method, params)
+			.await
+	}
+
+	/// Sends several JSON-RPC requests to the blockchain node in a single batch
+	///
+	/// Falls back to separate requests when the endpoint rejects batches.
+	///
+	/// # Arguments
+	/// * `requests` - The JSON-RPC method names and parameters of the requests
+	///
+	/// # Returns
+	/// * `Result<Vec<Value>, TransportError>` - JSON responses in the order of the requests,
+	///   or error with context
+	async fn send_batch_request(
+		&self,
+		requests: &[(&str, Option<Value>)],
+	) -> Result<Vec<Value>, TransportError> {
+		self.endpoint_manager
+			.send_batch_request(self, requests)
+			.await
+	}
+
 	/// Update endpoint manager with a new client
 	///
 	/// # Arguments
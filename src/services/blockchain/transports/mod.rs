@@ -10,11 +10,15 @@ mod evm {
 mod stellar {
 	pub mod http;
 }
+mod solana {
+	pub mod http;
+}
 mod midnight {
 	pub mod ws;
 }
 
 mod http {
+	pub mod circuit_breaker;
 	pub mod endpoint_manager;
 	pub mod transport;
 }
@@ -29,7 +33,11 @@ mod ws {
 mod error;
 
 pub use http::{
-	endpoint_manager::EndpointManager as HttpEndpointManager, transport::HttpTransportClient,
+	circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState},
+	endpoint_manager::{
+		EndpointManager as HttpEndpointManager, EndpointType, DEFAULT_MAX_RESPONSE_BYTES,
+	},
+	transport::HttpTransportClient,
 };
 pub use ws::{
 	config::WsConfig, endpoint_manager::EndpointManager as WsEndpointManager,
@@ -39,18 +47,45 @@ pub use ws::{
 pub use error::TransportError;
 pub use evm::http::EVMTransportClient;
 pub use midnight::ws::MidnightTransportClient as MidnightWsTransportClient;
+pub use solana::http::SolanaTransportClient;
 pub use stellar::http::StellarTransportClient;
 
 use reqwest_middleware::ClientWithMiddleware;
 use reqwest_retry::{
 	default_on_request_failure, default_on_request_success, Retryable, RetryableStrategy,
 };
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 use serde_json::{json, Value};
 
-/// HTTP status codes that trigger RPC endpoint rotation
+/// HTTP status codes that trigger RPC endpoint rotation and count as endpoint failures
 /// - 429: Too Many Requests - indicates rate limiting from the current endpoint
-pub const ROTATE_ON_ERROR_CODES: [u16; 1] = [429];
+/// - 500, 502, 503, 504: Server errors - indicate the current endpoint is unhealthy
+pub const ROTATE_ON_ERROR_CODES: [u16; 5] = [429, 500, 502, 503, 504];
+
+/// JSON-RPC response deserialized into a typed result
+///
+/// Used with [`BlockchainTransport::send_request`] so that large results, such as blocks, are
+/// deserialized as the response body is parsed, without an intermediate [`Value`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
+pub struct JsonRpcResponse<T> {
+	/// Result of the call, `None` when the response has no `result` field
+	///
+	/// Use an `Option` result type to tell a `null` result apart from a missing one.
+	#[serde(default = "Option::default", deserialize_with = "deserialize_present")]
+	pub result: Option<T>,
+	/// Error reported by the server, if any
+	pub error: Option<Value>,
+}
+
+/// Deserializes a field that is present in the response, leaving `null` to the field type
+fn deserialize_present<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+	D: Deserializer<'de>,
+	T: Deserialize<'de>,
+{
+	T::deserialize(deserializer).map(Some)
+}
 
 /// Base trait for all blockchain transport clients
 #[async_trait::async_trait]
@@ -67,6 +102,42 @@ pub trait BlockchainTransport: Send + Sync {
 	where
 		P: Into<Value> + Send + Clone + Serialize;
 
+	/// Send a request to the blockchain, deserializing the response into `R`
+	///
+	/// The default implementation deserializes the response of [`Self::send_raw_request`].
+	/// HTTP transports override it to deserialize the response body directly, which keeps a
+	/// single copy of large responses in memory.
+	async fn send_request<P, R>(&self, method: &str, params: Option<P>) -> Result<R, TransportError>
+	where
+		P: Into<Value> + Send + Clone + Serialize,
+		R: DeserializeOwned + Send,
+	{
+		let response = self.send_raw_request(method, params).await?;
+		serde_json::from_value(response).map_err(|e| {
+			TransportError::response_parse(
+				"Failed to parse JSON response".to_string(),
+				Some(Box::new(e)),
+				None,
+			)
+		})
+	}
+
+	/// Send a batch of requests to the blockchain, returning the responses in request order
+	///
+	/// The default implementation sends the requests one after the other with
+	/// [`Self::send_raw_request`]. HTTP transports override it to send a single JSON-RPC batch,
+	/// falling back to separate requests when the endpoint rejects batches.
+	async fn send_batch_request(
+		&self,
+		requests: &[(&str, Option<Value>)],
+	) -> Result<Vec<Value>, TransportError> {
+		let mut responses = Vec::with_capacity(requests.len());
+		for (method, params) in requests {
+			responses.push(self.send_raw_request(method, params.clone()).await?);
+		}
+		Ok(responses)
+	}
+
 	/// Customizes the request for specific blockchain requirements
 	async fn customize_request<P>(&self, method: &str, params: Option<P>) -> Value
 	where
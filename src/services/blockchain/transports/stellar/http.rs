@@ -5,7 +5,7 @@
 //! transport implementations while providing specific Stellar-focused functionality.
 
 use reqwest_middleware::ClientWithMiddleware;
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 
 use crate::{
@@ -72,6 +72,22 @@ impl BlockchainTransport for StellarTransportClient {
 		self.http_client.send_raw_request(method, params).await
 	}
 
+	/// Sends a JSON-RPC request to the Stellar node, deserializing the response into `R`
+	///
+	/// # Arguments
+	/// * `method` - The JSON-RPC method to call
+	/// * `params` - Optional parameters to pass with the request
+	///
+	/// # Returns
+	/// * `Result<R, TransportError>` - The deserialized response or error
+	async fn send_request<P, R>(&self, method: &str, params: Option<P>) -> Result<R, TransportError>
+	where
+		P: Into<Value> + Send + Clone + Serialize,
+		R: DeserializeOwned + Send,
+	{
+		self.http_client.send_request(method, params).await
+	}
+
 	/// Update endpoint manager with a new client
 	///
 	/// # Arguments
@@ -0,0 +1,163 @@
+//! Adaptive polling of networks with variable block times.
+//!
+//! A network with `adaptive_polling` isn't processed on its cron schedule, which either lags
+//! behind or polls without new blocks when the block time varies. Instead, it is polled again
+//! after the block time observed from the timestamps of the blocks it processes, bounded by the
+//! configured minimum and maximum intervals.
+
+use std::time::Duration;
+
+use crate::models::AdaptivePollingConfig;
+
+/// Weight of the latest inter-block interval in the observed block time
+const SMOOTHING_FACTOR: f64 = 0.2;
+
+/// Estimates the block time of a network from the blocks it processes
+#[derive(Debug, Clone)]
+pub struct BlockTimeEstimator {
+	config: AdaptivePollingConfig,
+	/// Exponential moving average of the inter-block intervals, in milliseconds
+	block_time_ms: f64,
+	/// Number and timestamp of the highest block observed
+	last_block: Option<(u64, u64)>,
+}
+
+impl BlockTimeEstimator {
+	/// Creates an estimator starting from the configured block time of the network
+	///
+	/// # Arguments
+	/// * `block_time_ms` - Configured block time of the network, in milliseconds
+	/// * `config` - Bounds of the interval between polls
+	pub fn new(block_time_ms: u64, config: AdaptivePollingConfig) -> Self {
+		Self {
+			config,
+			block_time_ms: block_time_ms as f64,
+			last_block: None,
+		}
+	}
+
+	/// Observes a processed block
+	///
+	/// Blocks at or below the highest block observed are ignored, so that blocks processed
+	/// again after a reorg or out of order don't skew the estimate. The interval since the
+	/// highest block is spread over the blocks in between, as timestamps of consecutive blocks
+	/// may be equal on networks with sub-second block times.
+	///
+	/// # Arguments
+	/// * `number` - Number of the block
+	/// * `timestamp` - Timestamp of the block, in seconds since the Unix epoch
+	pub fn observe(&mut self, number: u64, timestamp: u64) {
+		match self.last_block {
+			Some((last_number, _)) if number <= last_number => return,
+			Some((last_number, last_timestamp)) if timestamp >= last_timestamp => {
+				let interval_ms =
+					(timestamp - last_timestamp) as f64 * 1000.0 / (number - last_number) as f64;
+				self.block_time_ms += SMOOTHING_FACTOR * (interval_ms - self.block_time_ms);
+			}
+			_ => {}
+		}
+		self.last_block = Some((number, timestamp));
+	}
+
+	/// Returns the observed block time
+	pub fn block_time(&self) -> Duration {
+		Duration::from_millis(self.block_time_ms.round() as u64)
+	}
+
+	/// Returns the delay until the next poll, the observed block time within the configured
+	/// bounds
+	pub fn next_poll_delay(&self) -> Duration {
+		self.block_time().clamp(
+			Duration::from_millis(self.config.min_interval_ms),
+			Duration::from_millis(self.config.max_interval_ms),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn create_estimator(block_time_ms: u64) -> BlockTimeEstimator {
+		BlockTimeEstimator::new(
+			block_time_ms,
+			AdaptivePollingConfig {
+				min_interval_ms: 500,
+				max_interval_ms: 30_000,
+			},
+		)
+	}
+
+	#[test]
+	fn test_next_poll_delay_converges_to_observed_block_time() {
+		// Configured with 12s blocks, while the network produces a block every 2s
+		let mut estimator = create_estimator(12_000);
+		assert_eq!(estimator.next_poll_delay(), Duration::from_secs(12));
+
+		let mut previous_delay = estimator.next_poll_delay();
+		for number in 0..40 {
+			estimator.observe(100 + number, 1_700_000_000 + number * 2);
+			let delay = estimator.next_poll_delay();
+			assert!(
+				delay <= previous_delay,
+				"{:?} > {:?}",
+				delay,
+				previous_delay
+			);
+			previous_delay = delay;
+		}
+
+		let delay = estimator.next_poll_delay();
+		assert!(
+			delay.abs_diff(Duration::from_secs(2)) < Duration::from_millis(50),
+			"{:?}",
+			delay
+		);
+
+		// The network slows down to a block every 6s
+		for number in 40..80 {
+			estimator.observe(100 + number, 1_700_000_080 + (number - 40) * 6);
+		}
+		let delay = estimator.next_poll_delay();
+		assert!(
+			delay.abs_diff(Duration::from_secs(6)) < Duration::from_millis(50),
+			"{:?}",
+			delay
+		);
+	}
+
+	#[test]
+	fn test_next_poll_delay_is_bounded() {
+		let mut estimator = create_estimator(1_000);
+		for number in 0..40 {
+			// Consecutive blocks within the same second
+			estimator.observe(number, 1_700_000_000);
+		}
+		assert!(estimator.block_time() < Duration::from_millis(500));
+		assert_eq!(estimator.next_poll_delay(), Duration::from_millis(500));
+
+		let mut estimator = create_estimator(1_000);
+		for number in 0..40 {
+			estimator.observe(number, 1_700_000_000 + number * 60);
+		}
+		assert_eq!(estimator.next_poll_delay(), Duration::from_secs(30));
+	}
+
+	#[test]
+	fn test_observe_spreads_gaps_and_ignores_older_blocks() {
+		let mut estimator = create_estimator(4_000);
+		estimator.observe(100, 1_700_000_000);
+		// Ten blocks of 4s each
+		estimator.observe(110, 1_700_000_040);
+		assert_eq!(estimator.block_time(), Duration::from_secs(4));
+
+		// Blocks processed again after a reorg
+		estimator.observe(105, 1_700_000_100);
+		estimator.observe(110, 1_700_000_100);
+		assert_eq!(estimator.block_time(), Duration::from_secs(4));
+
+		// Sub-second blocks
+		estimator.observe(114, 1_700_000_041);
+		assert_eq!(estimator.block_time(), Duration::from_millis(3_250));
+	}
+}
@@ -0,0 +1,465 @@
+//! Leader election between monitor replicas.
+//!
+//! Replicas deployed for high availability would all process the same blocks and execute their
+//! triggers twice. With leader election enabled, replicas compete for a lease on a shared lock:
+//! the replica holding it runs the block watchers, while the others stand by and take over once
+//! the lease expires, e.g. because the leader crashed. The leader renews its lease while running
+//! and releases it on shutdown, so that a follower takes over right away. Whether a replica is
+//! the leader is exposed through the `is_leader` metric and the readiness endpoint.
+
+use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use std::{
+	env,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+use tracing::{debug, info, warn};
+
+use crate::utils::{system_clock, SharedClock};
+
+/// Environment variable holding the URL of the Redis server the leader lock is stored in
+///
+/// Leader election is disabled when it is unset.
+pub const LEADER_ELECTION_REDIS_URL_ENV: &str = "LEADER_ELECTION_REDIS_URL";
+
+/// Environment variable holding the key of the leader lock
+pub const LEADER_ELECTION_KEY_ENV: &str = "LEADER_ELECTION_KEY";
+
+/// Environment variable holding the lease duration of the leader lock, in milliseconds
+pub const LEADER_ELECTION_LEASE_MS_ENV: &str = "LEADER_ELECTION_LEASE_MS";
+
+/// Environment variable holding the identifier of this replica
+pub const LEADER_ELECTION_ID_ENV: &str = "LEADER_ELECTION_ID";
+
+/// Key of the leader lock when none is configured
+pub const DEFAULT_LEADER_ELECTION_KEY: &str = "openzeppelin_monitor:leader";
+
+/// Lease duration of the leader lock when none is configured
+pub const DEFAULT_LEADER_LEASE: Duration = Duration::from_secs(15);
+
+/// Acquires the lock for a holder, or extends the lease of the lock it already holds
+const ACQUIRE_SCRIPT: &str = r#"
+local holder = redis.call('GET', KEYS[1])
+if holder == false then
+	redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2])
+	return 1
+elseif holder == ARGV[1] then
+	redis.call('PEXPIRE', KEYS[1], ARGV[2])
+	return 1
+end
+return 0
+"#;
+
+/// Deletes the lock if it is held by a holder
+const RELEASE_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+	return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+/// Whether this replica is the leader, see [`is_leader`]
+static IS_LEADER: AtomicBool = AtomicBool::new(true);
+
+/// Returns whether this replica runs the block watchers
+///
+/// Replicas that don't take part in a leader election are always the leader.
+pub fn is_leader() -> bool {
+	IS_LEADER.load(Ordering::SeqCst)
+}
+
+/// Lock shared between replicas, held by at most one of them at a time
+///
+/// The lock is held for a lease, after which it is released unless the holder extends it.
+#[async_trait]
+pub trait LeaderLock: Send + Sync {
+	/// Acquires the lock for a holder, or extends the lease if it already holds it
+	///
+	/// # Arguments
+	/// * `holder_id` - Unique identifier of the replica acquiring the lock
+	/// * `lease` - Duration after which the lock is released unless acquired again
+	///
+	/// # Returns
+	/// * `Result<bool, anyhow::Error>` - Whether the holder holds the lock
+	async fn try_acquire(&self, holder_id: &str, lease: Duration) -> Result<bool, anyhow::Error>;
+
+	/// Releases the lock if it is held by a holder
+	///
+	/// # Arguments
+	/// * `holder_id` - Unique identifier of the replica releasing the lock
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error
+	async fn release(&self, holder_id: &str) -> Result<(), anyhow::Error>;
+}
+
+/// Leader lock stored in a Redis key, e.g. on the server shared by [`RedisBlockStorage`]
+///
+/// The key holds the identifier of the leader and expires with its lease.
+///
+/// [`RedisBlockStorage`]: super::RedisBlockStorage
+#[derive(Clone)]
+pub struct RedisLeaderLock {
+	/// Connection to the Redis server, reconnecting automatically
+	connection: ConnectionManager,
+	/// Key of the lock
+	key: String,
+}
+
+impl RedisLeaderLock {
+	/// Creates a new Redis leader lock
+	///
+	/// # Arguments
+	/// * `redis_url` - Redis connection URL (e.g. `redis://127.0.0.1:6379`)
+	/// * `key` - Key of the lock, shared by all replicas
+	///
+	/// # Returns
+	/// * `Result<Self, anyhow::Error>` - Lock or connection error
+	pub async fn new(redis_url: &str, key: &str) -> Result<Self, anyhow::Error> {
+		let client = redis::Client::open(redis_url)
+			.map_err(|e| anyhow::anyhow!("Failed to parse Redis URL: {}", e))?;
+		let connection = ConnectionManager::new(client)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to connect to Redis: {}", e))?;
+
+		Ok(RedisLeaderLock {
+			connection,
+			key: key.to_string(),
+		})
+	}
+}
+
+#[async_trait]
+impl LeaderLock for RedisLeaderLock {
+	async fn try_acquire(&self, holder_id: &str, lease: Duration) -> Result<bool, anyhow::Error> {
+		let acquired: i64 = redis::Script::new(ACQUIRE_SCRIPT)
+			.key(&self.key)
+			.arg(holder_id)
+			.arg(lease.as_millis().max(1) as u64)
+			.invoke_async(&mut self.connection.clone())
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to acquire leader lock: {}", e))?;
+		Ok(acquired == 1)
+	}
+
+	async fn release(&self, holder_id: &str) -> Result<(), anyhow::Error> {
+		let _: i64 = redis::Script::new(RELEASE_SCRIPT)
+			.key(&self.key)
+			.arg(holder_id)
+			.invoke_async(&mut self.connection.clone())
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to release leader lock: {}", e))?;
+		Ok(())
+	}
+}
+
+/// Election of the replica running the block watchers
+///
+/// A replica first waits to become the leader with [`Self::acquire`], then keeps its lease
+/// with [`Self::hold`] while running the block watchers, and releases it on shutdown with
+/// [`Self::resign`].
+pub struct LeaderElection {
+	/// Lock shared between replicas
+	lock: Arc<dyn LeaderLock>,
+	/// Unique identifier of this replica
+	holder_id: String,
+	/// Duration of the lease on the lock
+	lease: Duration,
+	/// Whether this replica holds the lock
+	leader: AtomicBool,
+	/// Clock the lease is timed with
+	clock: SharedClock,
+}
+
+impl LeaderElection {
+	/// Creates an election in which this replica is a follower until it acquires the lock
+	///
+	/// # Arguments
+	/// * `lock` - Lock shared between replicas
+	/// * `holder_id` - Unique identifier of this replica
+	/// * `lease` - Duration after which the lock is taken over unless the leader renews it
+	pub fn new(lock: Arc<dyn LeaderLock>, holder_id: &str, lease: Duration) -> Self {
+		IS_LEADER.store(false, Ordering::SeqCst);
+		Self {
+			lock,
+			holder_id: holder_id.to_string(),
+			lease,
+			leader: AtomicBool::new(false),
+			clock: system_clock(),
+		}
+	}
+
+	/// Sets the clock the lease is timed with, the system's by default
+	///
+	/// # Arguments
+	/// * `clock` - Clock replacing the system's
+	pub fn with_clock(mut self, clock: SharedClock) -> Self {
+		self.clock = clock;
+		self
+	}
+
+	/// Creates an election from the `LEADER_ELECTION_*` environment variables
+	///
+	/// # Returns
+	/// * `Result<Option<Self>, anyhow::Error>` - Election, or None if `LEADER_ELECTION_REDIS_URL`
+	///   isn't set
+	pub async fn from_env() -> Result<Option<Self>, anyhow::Error> {
+		let Ok(redis_url) = env::var(LEADER_ELECTION_REDIS_URL_ENV) else {
+			return Ok(None);
+		};
+		let key = env::var(LEADER_ELECTION_KEY_ENV)
+			.unwrap_or_else(|_| DEFAULT_LEADER_ELECTION_KEY.to_string());
+		let lease = match env::var(LEADER_ELECTION_LEASE_MS_ENV) {
+			Ok(value) => value
+				.parse::<u64>()
+				.ok()
+				.filter(|lease_ms| *lease_ms > 0)
+				.map(Duration::from_millis)
+				.ok_or_else(|| {
+					anyhow::anyhow!(
+						"Invalid {}: expected a positive number of milliseconds",
+						LEADER_ELECTION_LEASE_MS_ENV
+					)
+				})?,
+			Err(_) => DEFAULT_LEADER_LEASE,
+		};
+		let holder_id = env::var(LEADER_ELECTION_ID_ENV).unwrap_or_else(|_| {
+			format!(
+				"{}-{}",
+				env::var("HOSTNAME").unwrap_or_else(|_| "monitor".to_string()),
+				std::process::id()
+			)
+		});
+
+		let lock = RedisLeaderLock::new(&redis_url, &key).await?;
+		Ok(Some(Self::new(Arc::new(lock), &holder_id, lease)))
+	}
+
+	/// Returns the unique identifier of this replica
+	pub fn holder_id(&self) -> &str {
+		&self.holder_id
+	}
+
+	/// Returns whether this replica holds the lock
+	pub fn is_leader(&self) -> bool {
+		self.leader.load(Ordering::SeqCst)
+	}
+
+	/// Waits until this replica acquires the lock
+	///
+	/// Followers retry every third of the lease, so that they take over shortly after the
+	/// lease of a failed leader expires.
+	pub async fn acquire(&self) {
+		loop {
+			match self.lock.try_acquire(&self.holder_id, self.lease).await {
+				Ok(true) => {
+					info!("Replica {} elected leader", self.holder_id);
+					self.set_leader(true);
+					return;
+				}
+				Ok(false) => debug!(
+					"Replica {} standing by, the leader lock is held",
+					self.holder_id
+				),
+				Err(e) => warn!(
+					"Replica {} failed to acquire leadership: {}",
+					self.holder_id, e
+				),
+			}
+			tokio::time::sleep(self.renew_interval()).await;
+		}
+	}
+
+	/// Renews the lease of the lock until this replica loses it
+	///
+	/// Returns once another replica holds the lock, or once the lock couldn't be renewed for so
+	/// long that the lease may expire before the next attempt, after which this replica must stop
+	/// processing blocks. Stepping down before the lease expires keeps this replica from
+	/// processing blocks while a follower took over.
+	pub async fn hold(&self) {
+		let mut renewed_at = self.clock.now();
+		loop {
+			tokio::time::sleep(self.renew_interval()).await;
+			// The lease starts when the lock receives the renewal, so it's timed from the attempt
+			let attempted_at = self.clock.now();
+			let renewal = tokio::time::timeout(
+				self.renew_interval(),
+				self.lock.try_acquire(&self.holder_id, self.lease),
+			)
+			.await
+			.unwrap_or_else(|_| Err(anyhow::anyhow!("Timed out renewing the lease")));
+			match renewal {
+				Ok(true) => renewed_at = attempted_at,
+				Ok(false) => {
+					warn!(
+						"Replica {} lost leadership to another replica",
+						self.holder_id
+					);
+					break;
+				}
+				Err(e) => {
+					warn!(
+						"Replica {} failed to renew leadership: {}",
+						self.holder_id, e
+					);
+					if self.clock.now() - renewed_at >= self.step_down_after() {
+						warn!(
+							"Replica {} stepping down before its leadership lease expires",
+							self.holder_id
+						);
+						break;
+					}
+				}
+			}
+		}
+		self.set_leader(false);
+	}
+
+	/// Releases the lock, letting a follower take over without waiting for the lease to expire
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error
+	pub async fn resign(&self) -> Result<(), anyhow::Error> {
+		self.set_leader(false);
+		self.lock.release(&self.holder_id).await
+	}
+
+	fn set_leader(&self, leader: bool) {
+		self.leader.store(leader, Ordering::SeqCst);
+		IS_LEADER.store(leader, Ordering::SeqCst);
+	}
+
+	fn renew_interval(&self) -> Duration {
+		(self.lease / 3).max(Duration::from_millis(1))
+	}
+
+	/// Time since the last renewal after which the leader steps down
+	///
+	/// The next renewal attempt, a whole renew interval later, may come after the lease expired.
+	/// A tenth of the lease is kept as a safety margin for the latency of the lock.
+	fn step_down_after(&self) -> Duration {
+		self.lease
+			.saturating_sub(self.renew_interval() + self.lease / 10)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::MockClock;
+	use std::{
+		sync::{atomic::AtomicUsize, Mutex},
+		time::Instant,
+	};
+
+	/// Lock held in memory, shared by the elections of a test
+	#[derive(Default)]
+	struct MemoryLeaderLock {
+		holder: Mutex<Option<(String, Instant)>>,
+	}
+
+	#[async_trait]
+	impl LeaderLock for MemoryLeaderLock {
+		async fn try_acquire(
+			&self,
+			holder_id: &str,
+			lease: Duration,
+		) -> Result<bool, anyhow::Error> {
+			let mut holder = self.holder.lock().unwrap();
+			match holder.as_ref() {
+				Some((id, expires_at)) if id != holder_id && *expires_at > Instant::now() => {
+					Ok(false)
+				}
+				_ => {
+					*holder = Some((holder_id.to_string(), Instant::now() + lease));
+					Ok(true)
+				}
+			}
+		}
+
+		async fn release(&self, holder_id: &str) -> Result<(), anyhow::Error> {
+			let mut holder = self.holder.lock().unwrap();
+			if holder.as_ref().is_some_and(|(id, _)| id == holder_id) {
+				*holder = None;
+			}
+			Ok(())
+		}
+	}
+
+	/// Lock that can't be reached, each attempt taking a while on a clock
+	struct UnreachableLeaderLock {
+		clock: Arc<MockClock>,
+		latency: Duration,
+		attempts: AtomicUsize,
+	}
+
+	#[async_trait]
+	impl LeaderLock for UnreachableLeaderLock {
+		async fn try_acquire(
+			&self,
+			_holder_id: &str,
+			_lease: Duration,
+		) -> Result<bool, anyhow::Error> {
+			self.attempts.fetch_add(1, Ordering::SeqCst);
+			self.clock.advance(self.latency);
+			Err(anyhow::anyhow!("Connection refused"))
+		}
+
+		async fn release(&self, _holder_id: &str) -> Result<(), anyhow::Error> {
+			Ok(())
+		}
+	}
+
+	#[tokio::test]
+	async fn test_leader_steps_down_before_lease_expires() {
+		let lease = Duration::from_millis(60);
+		let clock = Arc::new(MockClock::new());
+		let lock = Arc::new(UnreachableLeaderLock {
+			clock: clock.clone(),
+			latency: lease / 3,
+			attempts: AtomicUsize::new(0),
+		});
+		let leader = LeaderElection::new(lock.clone(), "leader", lease).with_clock(clock.clone());
+		leader.set_leader(true);
+
+		let start = clock.now();
+		tokio::time::timeout(Duration::from_secs(1), leader.hold())
+			.await
+			.unwrap();
+
+		// The first failed renewal leaves time for another attempt, the second one doesn't
+		assert!(!leader.is_leader());
+		assert_eq!(lock.attempts.load(Ordering::SeqCst), 2);
+		assert!(clock.now() - start < lease);
+	}
+
+	#[tokio::test]
+	async fn test_follower_takes_over_expired_lease() {
+		let lock: Arc<dyn LeaderLock> = Arc::new(MemoryLeaderLock::default());
+		let leader = LeaderElection::new(lock.clone(), "leader", Duration::from_millis(60));
+		let follower = LeaderElection::new(lock.clone(), "follower", Duration::from_millis(60));
+
+		leader.acquire().await;
+		assert!(leader.is_leader());
+		assert!(!lock
+			.try_acquire("follower", Duration::from_millis(60))
+			.await
+			.unwrap());
+
+		// The leader stops renewing its lease, e.g. because it crashed
+		tokio::time::timeout(Duration::from_secs(1), follower.acquire())
+			.await
+			.unwrap();
+		assert!(follower.is_leader());
+
+		// The former leader notices the takeover on its next renewal
+		tokio::time::timeout(Duration::from_secs(1), leader.hold())
+			.await
+			.unwrap();
+		assert!(!leader.is_leader());
+	}
+}
@@ -0,0 +1,415 @@
+//! Watching of pending transactions.
+//!
+//! EVM networks with `mempool` enabled subscribe to `newPendingTransactions` through their
+//! `ws_rpc` endpoints, next to the regular block watching. The function and transaction
+//! conditions of the network's monitors are evaluated against every pending transaction as it
+//! arrives, and the matches are passed to the trigger handler tagged as pending. Pending
+//! transactions have no receipt or logs yet, so event conditions can't match them.
+//!
+//! The monitors are read from the shared active monitors for every transaction, so monitors
+//! reloaded through the admin API apply to the pending transactions received afterwards.
+
+use chrono::Utc;
+use futures::{stream::BoxStream, SinkExt, Stream, StreamExt};
+use serde_json::{json, Value};
+use std::{marker::PhantomData, sync::Arc, time::Duration};
+use tokio::{net::TcpStream, sync::watch, task::JoinHandle, time::timeout};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+use crate::{
+	bootstrap::SharedActiveMonitors,
+	models::{ContractSpec, EVMContractSpec, EVMTransaction, Network, ProcessedBlock},
+	services::filter::EVMBlockFilter,
+};
+
+/// Stream of the pending transactions of a network
+pub type PendingTransactionStream = BoxStream<'static, Result<EVMTransaction, anyhow::Error>>;
+
+/// Maximum time to connect to an endpoint and subscribe to its pending transactions
+const SUBSCRIBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Delay before subscribing again once a subscription failed or ended
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(5);
+
+/// Identifier of the subscription request, transactions requested by hash using the following ones
+const SUBSCRIBE_REQUEST_ID: u64 = 0;
+
+/// Content of a message received on a pending transaction subscription
+#[derive(Debug)]
+enum SubscriptionMessage {
+	/// A pending transaction
+	Transaction(Box<EVMTransaction>),
+	/// The hash of a pending transaction, sent by endpoints not notifying full transactions
+	Hash(String),
+	/// Anything else, e.g. a transaction that was mined or dropped before it could be fetched
+	Ignored,
+}
+
+/// Pending transaction subscription on a WebSocket connection
+struct PendingTransactionSubscription {
+	socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+	subscription_id: String,
+	next_request_id: u64,
+}
+
+impl PendingTransactionSubscription {
+	/// Connects to an endpoint and subscribes to its pending transactions
+	async fn subscribe(url: &str) -> Result<Self, anyhow::Error> {
+		let (mut socket, _) = timeout(SUBSCRIBE_TIMEOUT, connect_async(url))
+			.await
+			.map_err(|_| anyhow::anyhow!("Connection timeout"))?
+			.map_err(|e| anyhow::anyhow!("Failed to connect: {}", e))?;
+
+		// Full transactions are requested, which not every endpoint supports
+		let request = json!({
+			"jsonrpc": "2.0",
+			"id": SUBSCRIBE_REQUEST_ID,
+			"method": "eth_subscribe",
+			"params": ["newPendingTransactions", true]
+		});
+		socket
+			.send(Message::Text(request.to_string().into()))
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to send subscription request: {}", e))?;
+
+		let subscription_id = timeout(SUBSCRIBE_TIMEOUT, async {
+			while let Some(message) = socket.next().await {
+				let Message::Text(text) = message? else {
+					continue;
+				};
+				let response: Value = serde_json::from_str(&text)?;
+				if response.get("id").and_then(Value::as_u64) != Some(SUBSCRIBE_REQUEST_ID) {
+					continue;
+				}
+				if let Some(error) = response.get("error") {
+					return Err(anyhow::anyhow!("Subscription refused: {}", error));
+				}
+				return response["result"]
+					.as_str()
+					.map(String::from)
+					.ok_or_else(|| anyhow::anyhow!("Invalid subscription response: {}", response));
+			}
+			Err(anyhow::anyhow!("Connection closed"))
+		})
+		.await
+		.map_err(|_| anyhow::anyhow!("Subscription timeout"))??;
+
+		Ok(Self {
+			socket,
+			subscription_id,
+			next_request_id: SUBSCRIBE_REQUEST_ID + 1,
+		})
+	}
+
+	/// Waits for the next pending transaction
+	///
+	/// # Returns
+	/// The transaction, an error ending the subscription, or `None` once the connection closed
+	async fn next_transaction(&mut self) -> Option<Result<EVMTransaction, anyhow::Error>> {
+		loop {
+			let message = match self.socket.next().await? {
+				Ok(message) => message,
+				Err(e) => return Some(Err(anyhow::anyhow!("WebSocket error: {}", e))),
+			};
+
+			match message {
+				Message::Text(text) => {
+					let Ok(message) = serde_json::from_str::<Value>(&text) else {
+						continue;
+					};
+					match parse_message(&message, &self.subscription_id) {
+						SubscriptionMessage::Transaction(transaction) => {
+							return Some(Ok(*transaction))
+						}
+						SubscriptionMessage::Hash(hash) => {
+							let request = json!({
+								"jsonrpc": "2.0",
+								"id": self.next_request_id,
+								"method": "eth_getTransactionByHash",
+								"params": [hash]
+							});
+							self.next_request_id += 1;
+							if let Err(e) = self
+								.socket
+								.send(Message::Text(request.to_string().into()))
+								.await
+							{
+								return Some(Err(anyhow::anyhow!(
+									"Failed to request pending transaction: {}",
+									e
+								)));
+							}
+						}
+						SubscriptionMessage::Ignored => {}
+					}
+				}
+				Message::Ping(data) => {
+					if let Err(e) = self.socket.send(Message::Pong(data)).await {
+						return Some(Err(anyhow::anyhow!("Failed to send pong: {}", e)));
+					}
+				}
+				Message::Close(_) => return None,
+				_ => {}
+			}
+		}
+	}
+}
+
+/// Reads a message received on a pending transaction subscription
+///
+/// Messages are either notifications of the subscription, carrying a transaction or its hash, or
+/// responses to transactions requested by hash.
+fn parse_message(message: &Value, subscription_id: &str) -> SubscriptionMessage {
+	let result = if message.get("method").and_then(Value::as_str) == Some("eth_subscription") {
+		let params = &message["params"];
+		if params["subscription"].as_str() != Some(subscription_id) {
+			return SubscriptionMessage::Ignored;
+		}
+		&params["result"]
+	} else if message.get("id").is_some() {
+		&message["result"]
+	} else {
+		return SubscriptionMessage::Ignored;
+	};
+
+	match result {
+		Value::String(hash) => SubscriptionMessage::Hash(hash.clone()),
+		Value::Object(_) => match serde_json::from_value(result.clone()) {
+			Ok(transaction) => SubscriptionMessage::Transaction(Box::new(transaction)),
+			Err(e) => {
+				tracing::warn!("Failed to parse pending transaction: {}", e);
+				SubscriptionMessage::Ignored
+			}
+		},
+		_ => SubscriptionMessage::Ignored,
+	}
+}
+
+/// Subscribes to the pending transactions of an EVM network
+///
+/// The `ws_rpc` endpoints of the network are tried by decreasing weight, until one of them
+/// accepts the subscription.
+///
+/// # Arguments
+/// * `network` - The network to subscribe to
+///
+/// # Returns
+/// * `Result<PendingTransactionStream, anyhow::Error>` - The pending transactions, ending with
+///   the connection, or the error of the last endpoint tried
+pub async fn subscribe_pending_transactions(
+	network: &Network,
+) -> Result<PendingTransactionStream, anyhow::Error> {
+	let mut ws_urls: Vec<_> = network
+		.rpc_urls
+		.iter()
+		.filter(|rpc_url| rpc_url.type_ == "ws_rpc" && rpc_url.weight > 0)
+		.collect();
+	ws_urls.sort_by(|a, b| b.weight.cmp(&a.weight));
+
+	let mut last_error = anyhow::anyhow!("Network {} has no ws_rpc URL", network.slug);
+	for rpc_url in ws_urls {
+		match PendingTransactionSubscription::subscribe(rpc_url.url.as_ref()).await {
+			Ok(subscription) => {
+				return Ok(
+					futures::stream::unfold(subscription, |mut subscription| async move {
+						let transaction = subscription.next_transaction().await?;
+						Some((transaction, subscription))
+					})
+					.boxed(),
+				);
+			}
+			Err(e) => {
+				tracing::warn!(
+					"Failed to subscribe to pending transactions of network {}: {}",
+					network.slug,
+					e
+				);
+				last_error = e;
+			}
+		}
+	}
+
+	Err(last_error)
+}
+
+/// Evaluates the monitors of a network against its pending transactions
+///
+/// The matches of each transaction are passed to the trigger handler as a block numbered 0, as
+/// pending transactions don't belong to a block yet.
+///
+/// # Arguments
+/// * `network` - The network the transactions were sent to
+/// * `active_monitors` - Active monitors, along with the contract specs of their addresses
+/// * `transactions` - The pending transactions
+/// * `trigger_handler` - Handler of the matches
+///
+/// # Returns
+/// * `Result<(), anyhow::Error>` - Once the transactions ended, or the error that ended them
+pub async fn process_pending_transactions<S, H>(
+	network: &Network,
+	active_monitors: &SharedActiveMonitors,
+	mut transactions: S,
+	trigger_handler: &H,
+) -> Result<(), anyhow::Error>
+where
+	S: Stream<Item = Result<EVMTransaction, anyhow::Error>> + Unpin,
+	H: Fn(&ProcessedBlock) -> JoinHandle<()>,
+{
+	let filter = EVMBlockFilter::<()> {
+		_client: PhantomData,
+		rpc_results: Default::default(),
+	};
+
+	while let Some(transaction) = transactions.next().await {
+		let transaction = transaction?;
+		let now = Utc::now();
+		let matches = {
+			let active_monitors = active_monitors.read().await;
+			let contract_specs = active_monitors
+				.contract_specs
+				.iter()
+				.filter_map(|(address, spec)| match spec {
+					ContractSpec::EVM(spec) => Some((address.clone(), spec.clone())),
+					_ => None,
+				})
+				.collect::<Vec<(String, EVMContractSpec)>>();
+			filter.filter_pending_transaction(
+				network,
+				&transaction,
+				active_monitors.monitors.iter().filter(|monitor| {
+					monitor.networks.contains(&network.slug)
+						&& !monitor.is_paused_at(now)
+						&& monitor.is_active_at(now)
+				}),
+				&contract_specs,
+			)
+		};
+
+		if !matches.is_empty() {
+			tracing::debug!(
+				"Pending transaction {} matched {} monitor(s) on network {}",
+				transaction.hash,
+				matches.len(),
+				network.slug
+			);
+			trigger_handler(&ProcessedBlock {
+				block_number: 0,
+				network_slug: network.slug.clone(),
+				block_hash: None,
+				processing_results: matches,
+				block: None,
+			});
+		}
+	}
+
+	Ok(())
+}
+
+/// Watches the pending transactions of an EVM network until shutdown
+///
+/// The subscription is renewed whenever it fails or ends.
+///
+/// # Arguments
+/// * `network` - The network to watch
+/// * `active_monitors` - Active monitors, along with the contract specs of their addresses
+/// * `trigger_handler` - Handler of the matches
+/// * `shutdown_rx` - Receiver of the shutdown signal
+pub async fn watch_pending_transactions<H>(
+	network: Network,
+	active_monitors: SharedActiveMonitors,
+	trigger_handler: Arc<H>,
+	mut shutdown_rx: watch::Receiver<bool>,
+) where
+	H: Fn(&ProcessedBlock) -> JoinHandle<()> + Send + Sync,
+{
+	loop {
+		let watch = async {
+			let transactions = subscribe_pending_transactions(&network).await?;
+			tracing::info!("Watching pending transactions of network {}", network.slug);
+			process_pending_transactions(
+				&network,
+				&active_monitors,
+				transactions,
+				&*trigger_handler,
+			)
+			.await
+		};
+
+		tokio::select! {
+			result = watch => match result {
+				Ok(()) => tracing::warn!(
+					"Pending transaction subscription of network {} ended",
+					network.slug
+				),
+				Err(e) => tracing::error!(
+					"Failed to watch pending transactions of network {}: {}",
+					network.slug,
+					e
+				),
+			},
+			_ = shutdown_rx.changed() => return,
+		}
+
+		tokio::select! {
+			_ = tokio::time::sleep(RESUBSCRIBE_DELAY) => {}
+			_ = shutdown_rx.changed() => return,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_message() {
+		let transaction = json!({
+			"hash": "0x0000000000000000000000000000000000000000000000000000000000000001",
+			"nonce": "0x1",
+			"blockHash": null,
+			"blockNumber": null,
+			"transactionIndex": null,
+			"from": "0x0000000000000000000000000000000000001234",
+			"to": "0x0000000000000000000000000000000000004321",
+			"value": "0x64",
+			"gasPrice": "0x1",
+			"gas": "0x5208",
+			"input": "0x"
+		});
+
+		let notification = |result: Value| {
+			json!({
+				"jsonrpc": "2.0",
+				"method": "eth_subscription",
+				"params": {"subscription": "0xabc", "result": result}
+			})
+		};
+
+		assert!(matches!(
+			parse_message(&notification(transaction.clone()), "0xabc"),
+			SubscriptionMessage::Transaction(_)
+		));
+		assert!(matches!(
+			parse_message(&notification(json!("0x01")), "0xabc"),
+			SubscriptionMessage::Hash(hash) if hash == "0x01"
+		));
+		// Notifications of other subscriptions are ignored
+		assert!(matches!(
+			parse_message(&notification(transaction.clone()), "0xdef"),
+			SubscriptionMessage::Ignored
+		));
+
+		// Transactions requested by hash
+		assert!(matches!(
+			parse_message(
+				&json!({"jsonrpc": "2.0", "id": 1, "result": transaction}),
+				"0xabc"
+			),
+			SubscriptionMessage::Transaction(_)
+		));
+		assert!(matches!(
+			parse_message(&json!({"jsonrpc": "2.0", "id": 2, "result": null}), "0xabc"),
+			SubscriptionMessage::Ignored
+		));
+	}
+}
@@ -3,17 +3,44 @@
 //! This module provides functionality to watch and process blockchain blocks across
 //! different networks. It includes:
 //! - Block watching service for multiple networks
+//! - Adaptive polling of networks following their observed block time
 //! - Block storage implementations
+//! - Stall detection for networks that stop producing blocks
+//! - Supervised restarts of failing network watchers
+//! - Leader election between replicas, so that only one of them watches blocks
+//! - Watching of the pending transactions of EVM networks
 //! - Error handling specific to block watching operations
 
+mod adaptive;
 mod error;
+mod leader;
+mod mempool;
 mod service;
+mod stall;
 mod storage;
+mod supervisor;
 mod tracker;
 
+pub use adaptive::BlockTimeEstimator;
 pub use error::BlockWatcherError;
+pub use leader::{
+	is_leader, LeaderElection, LeaderLock, RedisLeaderLock, DEFAULT_LEADER_ELECTION_KEY,
+	DEFAULT_LEADER_LEASE, LEADER_ELECTION_ID_ENV, LEADER_ELECTION_KEY_ENV,
+	LEADER_ELECTION_LEASE_MS_ENV, LEADER_ELECTION_REDIS_URL_ENV,
+};
+pub use mempool::{
+	process_pending_transactions, subscribe_pending_transactions, watch_pending_transactions,
+	PendingTransactionStream,
+};
 pub use service::{
 	process_new_blocks, BlockWatcherService, JobSchedulerTrait, NetworkBlockWatcher,
 };
-pub use storage::{BlockStorage, FileBlockStorage};
+pub use stall::{stall_detector, StallDetector, StallEvent, StallHandler};
+pub use storage::{
+	create_alert_state_writer, create_balance_writer, create_dedup_key_writer,
+	create_processed_block_guard, AlertStateWriter, BalanceWriter, BlockStorage, CachedBalance,
+	CachedBalances, DedupKeyWriter, FileBlockStorage, ProcessedBlockGuard, RedisBlockStorage,
+	MAX_DEDUP_KEYS,
+};
+pub use supervisor::{WatcherHealth, WatcherRestartPolicy};
 pub use tracker::{BlockCheckResult, BlockTracker, BlockTrackerTrait};
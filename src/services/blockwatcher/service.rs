@@ -7,22 +7,29 @@ use anyhow::Context;
 use futures::{channel::mpsc, future::BoxFuture, stream::StreamExt, SinkExt};
 use std::{
 	collections::{BTreeMap, HashMap},
-	sync::Arc,
+	future::Future,
+	sync::{Arc, Mutex},
 };
 use tokio::sync::RwLock;
 use tokio_cron_scheduler::{Job, JobScheduler};
 use tracing::instrument;
 
 use crate::{
-	models::{BlockType, Network, ProcessedBlock},
+	models::{AdaptivePollingConfig, BlockType, Network, ProcessedBlock},
 	services::{
 		blockchain::BlockChainClient,
 		blockwatcher::{
+			adaptive::BlockTimeEstimator,
 			error::BlockWatcherError,
-			storage::BlockStorage,
+			stall::stall_detector,
+			storage::{BlockStorage, ScheduledBlockStorage},
+			supervisor::{WatcherHealth, WatcherRestartPolicy},
 			tracker::{BlockCheckResult, BlockTracker, BlockTrackerTrait},
 		},
 	},
+	utils::metrics::{
+		CATCHUP_BLOCKS_REMAINING, NETWORK_WATCHER_RESTARTS_TOTAL, REORGS_DETECTED_TOTAL,
+	},
 };
 
 /// Trait for job scheduler
@@ -61,7 +68,10 @@ impl JobSchedulerTrait for JobScheduler {
 /// Watcher implementation for a single network
 ///
 /// Manages block watching and processing for a specific blockchain network,
-/// including scheduling and block handling.
+/// including scheduling and block handling. A job is scheduled for each of the network's
+/// schedules, see [`NetworkBlockWatcher::with_schedules`]. With `adaptive_polling`, the job of
+/// the network's own schedule is replaced by a task polling the network following its observed
+/// block time.
 ///
 /// # Type Parameters
 /// * `S` - Storage implementation for blocks
@@ -78,6 +88,9 @@ where
 	pub trigger_handler: Arc<T>,
 	pub scheduler: J,
 	pub block_tracker: Arc<BlockTracker>,
+	pub schedules: Vec<String>,
+	pub health: Arc<WatcherHealth>,
+	pub adaptive_task: Option<tokio::task::JoinHandle<()>>,
 }
 
 /// Map of active block watchers
@@ -102,6 +115,7 @@ where
 	pub trigger_handler: Arc<T>,
 	pub active_watchers: Arc<RwLock<BlockWatchersMap<S, H, T, J>>>,
 	pub block_tracker: Arc<BlockTracker>,
+	pub restart_policy: WatcherRestartPolicy,
 }
 
 impl<S, H, T, J> NetworkBlockWatcher<S, H, T, J>
@@ -137,6 +151,7 @@ where
 				)])),
 			)
 		})?;
+		let schedules = vec![network.cron_schedule.clone()];
 		Ok(Self {
 			network,
 			block_storage,
@@ -144,64 +159,107 @@ where
 			trigger_handler,
 			scheduler,
 			block_tracker,
+			schedules,
+			health: Arc::new(WatcherHealth::default()),
+			adaptive_task: None,
 		})
 	}
 
+	/// Sets the cron schedules new blocks are processed on
+	///
+	/// Defaults to the network's own schedule. Monitors overriding it run on jobs of their own,
+	/// which keep their own last processed block and block tracking, so that every job
+	/// processes every block once no matter how often the other jobs run.
+	///
+	/// # Arguments
+	/// * `schedules` - Distinct cron schedules of the network's monitors
+	pub fn with_schedules(mut self, schedules: Vec<String>) -> Self {
+		if !schedules.is_empty() {
+			self.schedules = schedules;
+		}
+		self
+	}
+
+	/// Sets the health the watcher's jobs report their runs to
+	///
+	/// # Arguments
+	/// * `health` - Health shared with the watcher's supervisor
+	pub fn with_health(mut self, health: Arc<WatcherHealth>) -> Self {
+		self.health = health;
+		self
+	}
+
 	/// Starts the network watcher
 	///
 	/// Initializes the scheduler and begins watching for new blocks according
-	/// to the network's cron schedule.
+	/// to the watcher's cron schedules.
 	pub async fn start<C: BlockChainClient + Clone + Send + 'static>(
 		&mut self,
 		rpc_client: C,
 	) -> Result<(), BlockWatcherError> {
-		let network = self.network.clone();
-		let block_storage = self.block_storage.clone();
-		let block_handler = self.block_handler.clone();
-		let trigger_handler = self.trigger_handler.clone();
-		let block_tracker = self.block_tracker.clone();
-
-		let job = Job::new_async(self.network.cron_schedule.as_str(), move |_uuid, _l| {
-			let network = network.clone();
-			let block_storage = block_storage.clone();
-			let block_handler = block_handler.clone();
-			let block_tracker = block_tracker.clone();
-			let rpc_client = rpc_client.clone();
-			let trigger_handler = trigger_handler.clone();
-			Box::pin(async move {
-				let _ = process_new_blocks(
-					&network,
-					&rpc_client,
-					block_storage,
-					block_handler,
-					trigger_handler,
-					block_tracker,
+		let rpc_client = Arc::new(rpc_client);
+
+		for schedule in &self.schedules {
+			if *schedule == self.network.cron_schedule {
+				if let Some(adaptive_polling) = self.network.adaptive_polling {
+					self.adaptive_task = Some(spawn_adaptive_job(
+						self.network.clone(),
+						rpc_client.clone(),
+						self.block_storage.clone(),
+						self.block_handler.clone(),
+						self.trigger_handler.clone(),
+						self.block_tracker.clone(),
+						self.health.clone(),
+						adaptive_polling,
+					));
+					continue;
+				}
+			}
+
+			let job = if *schedule == self.network.cron_schedule {
+				create_job(
+					self.network.clone(),
+					rpc_client.clone(),
+					self.block_storage.clone(),
+					self.block_handler.clone(),
+					self.trigger_handler.clone(),
+					self.block_tracker.clone(),
+					self.health.clone(),
 				)
-				.await
-				.map_err(|e| {
-					BlockWatcherError::processing_error(
-						"Failed to process blocks".to_string(),
-						Some(e.into()),
-						Some(HashMap::from([(
-							"network".to_string(),
-							network.slug.clone(),
-						)])),
-					)
-				});
-			})
-		})
-		.with_context(|| "Failed to create job")?;
+			} else {
+				// The job processes the network as if it was configured with the monitors'
+				// schedule, which also scales the recommended number of past blocks
+				create_job(
+					Network {
+						cron_schedule: schedule.clone(),
+						..self.network.clone()
+					},
+					rpc_client.clone(),
+					Arc::new(ScheduledBlockStorage::new(
+						self.block_storage.clone(),
+						schedule,
+					)),
+					self.block_handler.clone(),
+					self.trigger_handler.clone(),
+					Arc::new(
+						BlockTracker::new(self.block_tracker.history_size())
+							.with_clock(self.block_tracker.clock()),
+					),
+					self.health.clone(),
+				)
+			}?;
 
-		self.scheduler.add(job).await.map_err(|e| {
-			BlockWatcherError::scheduler_error(
-				e.to_string(),
-				Some(e),
-				Some(HashMap::from([(
-					"network".to_string(),
-					self.network.slug.clone(),
-				)])),
-			)
-		})?;
+			self.scheduler.add(job).await.map_err(|e| {
+				BlockWatcherError::scheduler_error(
+					e.to_string(),
+					Some(e),
+					Some(HashMap::from([
+						("network".to_string(), self.network.slug.clone()),
+						("cron_schedule".to_string(), schedule.clone()),
+					])),
+				)
+			})?;
+		}
 
 		self.scheduler.start().await.map_err(|e| {
 			BlockWatcherError::scheduler_error(
@@ -222,6 +280,9 @@ where
 	///
 	/// Shuts down the scheduler and stops watching for new blocks.
 	pub async fn stop(&mut self) -> Result<(), BlockWatcherError> {
+		if let Some(adaptive_task) = self.adaptive_task.take() {
+			adaptive_task.abort();
+		}
 		self.scheduler.shutdown().await.map_err(|e| {
 			BlockWatcherError::scheduler_error(
 				e.to_string(),
@@ -232,12 +293,188 @@ where
 				)])),
 			)
 		})?;
+		self.health.record_stop();
 
 		tracing::info!("Stopped block watcher for network: {}", self.network.slug);
 		Ok(())
 	}
 }
 
+/// Creates the job processing new blocks of a network on the network's cron schedule,
+/// evaluated in the network's timezone
+///
+/// # Arguments
+/// * `network` - Network configuration, with the schedule the job runs on
+/// * `rpc_client` - RPC client for the network, cloned for every run
+/// * `block_storage` - Storage implementation for blocks
+/// * `block_handler` - Handler function for processed blocks
+/// * `trigger_handler` - Handler function for processed blocks
+/// * `block_tracker` - Tracker implementation for block processing
+/// * `health` - Health the job reports its runs to
+fn create_job<S, C, H, T>(
+	network: Network,
+	rpc_client: Arc<C>,
+	block_storage: Arc<S>,
+	block_handler: Arc<H>,
+	trigger_handler: Arc<T>,
+	block_tracker: Arc<BlockTracker>,
+	health: Arc<WatcherHealth>,
+) -> Result<Job, BlockWatcherError>
+where
+	S: BlockStorage + Send + Sync + 'static,
+	C: BlockChainClient + Clone + Send + 'static,
+	H: Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync + 'static,
+	T: Fn(&ProcessedBlock) -> tokio::task::JoinHandle<()> + Send + Sync + 'static,
+{
+	let cron_schedule = network.cron_schedule.clone();
+	let timezone = network.cron_timezone();
+
+	let job = Job::new_async_tz(cron_schedule.as_str(), timezone, move |_uuid, _l| {
+		let network = network.clone();
+		let block_storage = block_storage.clone();
+		let block_handler = block_handler.clone();
+		let block_tracker = block_tracker.clone();
+		let rpc_client = rpc_client.as_ref().clone();
+		let trigger_handler = trigger_handler.clone();
+		let health = health.clone();
+		Box::pin(async move {
+			run_job(
+				&network,
+				&rpc_client,
+				block_storage,
+				block_handler,
+				trigger_handler,
+				block_tracker,
+				&health,
+			)
+			.await;
+		})
+	})
+	.with_context(|| "Failed to create job")?;
+
+	Ok(job)
+}
+
+/// Spawns the task processing new blocks of a network with adaptive polling
+///
+/// The task processes new blocks, then waits for the next poll delay of a
+/// [`BlockTimeEstimator`] fed with the blocks it processed, counted from the start of the run.
+///
+/// # Arguments
+/// * `network` - Network configuration
+/// * `rpc_client` - RPC client for the network, cloned for every run
+/// * `block_storage` - Storage implementation for blocks
+/// * `block_handler` - Handler function for processed blocks
+/// * `trigger_handler` - Handler function for processed blocks
+/// * `block_tracker` - Tracker implementation for block processing
+/// * `health` - Health the task reports its runs to
+/// * `adaptive_polling` - Bounds of the interval between polls
+#[allow(clippy::too_many_arguments)]
+fn spawn_adaptive_job<S, C, H, T>(
+	network: Network,
+	rpc_client: Arc<C>,
+	block_storage: Arc<S>,
+	block_handler: Arc<H>,
+	trigger_handler: Arc<T>,
+	block_tracker: Arc<BlockTracker>,
+	health: Arc<WatcherHealth>,
+	adaptive_polling: AdaptivePollingConfig,
+) -> tokio::task::JoinHandle<()>
+where
+	S: BlockStorage + Send + Sync + 'static,
+	C: BlockChainClient + Clone + Send + 'static,
+	H: Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync + 'static,
+	T: Fn(&ProcessedBlock) -> tokio::task::JoinHandle<()> + Send + Sync + 'static,
+{
+	let estimator = Arc::new(Mutex::new(BlockTimeEstimator::new(
+		network.block_time_ms,
+		adaptive_polling,
+	)));
+
+	let observer = estimator.clone();
+	let block_handler = Arc::new(move |block: BlockType, network: Network| {
+		if let (Some(number), Some(timestamp)) = (block.number(), block.timestamp()) {
+			observer.lock().unwrap().observe(number, timestamp);
+		}
+		block_handler(block, network)
+	});
+
+	let clock = block_tracker.clock();
+	tokio::spawn(async move {
+		loop {
+			let started = clock.now();
+			run_job(
+				&network,
+				&rpc_client.as_ref().clone(),
+				block_storage.clone(),
+				block_handler.clone(),
+				trigger_handler.clone(),
+				block_tracker.clone(),
+				&health,
+			)
+			.await;
+
+			let (block_time, delay) = {
+				let estimator = estimator.lock().unwrap();
+				(estimator.block_time(), estimator.next_poll_delay())
+			};
+			tracing::debug!(
+				network = %network.slug,
+				"Observed block time of {}ms, polling again in {}ms",
+				block_time.as_millis(),
+				delay.as_millis()
+			);
+			tokio::time::sleep(delay.saturating_sub(clock.now().duration_since(started))).await;
+		}
+	})
+}
+
+/// Runs a job processing new blocks of a network once, reporting the run to the health of the
+/// watcher and to the stall detector
+async fn run_job<S, C, H, T>(
+	network: &Network,
+	rpc_client: &C,
+	block_storage: Arc<S>,
+	block_handler: Arc<H>,
+	trigger_handler: Arc<T>,
+	block_tracker: Arc<BlockTracker>,
+	health: &WatcherHealth,
+) where
+	S: BlockStorage,
+	C: BlockChainClient + Send + Clone + 'static,
+	H: Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync + 'static,
+	T: Fn(&ProcessedBlock) -> tokio::task::JoinHandle<()> + Send + Sync + 'static,
+{
+	let result = process_new_blocks(
+		network,
+		rpc_client,
+		block_storage,
+		block_handler,
+		trigger_handler,
+		block_tracker,
+	)
+	.await;
+
+	// Failed runs don't advance the network, so they count towards a stall
+	if result.is_err() {
+		stall_detector().observe(network, None).await;
+		health.record_failure();
+	} else {
+		health.record_success();
+	}
+
+	let _ = result.map_err(|e| {
+		BlockWatcherError::processing_error(
+			"Failed to process blocks".to_string(),
+			Some(e.into()),
+			Some(HashMap::from([(
+				"network".to_string(),
+				network.slug.clone(),
+			)])),
+		)
+	});
+}
+
 impl<S, H, T, J> BlockWatcherService<S, H, T, J>
 where
 	S: BlockStorage + Send + Sync + 'static,
@@ -247,6 +484,9 @@ where
 {
 	/// Creates a new block watcher service
 	///
+	/// The watchers read the current time from the clock of the block tracker, see
+	/// [`BlockTracker::with_clock`].
+	///
 	/// # Arguments
 	/// * `network_service` - Service for network operations
 	/// * `block_storage` - Storage implementation for blocks
@@ -263,9 +503,19 @@ where
 			trigger_handler,
 			active_watchers: Arc::new(RwLock::new(HashMap::new())),
 			block_tracker,
+			restart_policy: WatcherRestartPolicy::default(),
 		})
 	}
 
+	/// Sets the policy for restarting supervised network watchers
+	///
+	/// # Arguments
+	/// * `restart_policy` - Policy replacing the default one
+	pub fn with_restart_policy(mut self, restart_policy: WatcherRestartPolicy) -> Self {
+		self.restart_policy = restart_policy;
+		self
+	}
+
 	/// Starts a watcher for a specific network
 	///
 	/// # Arguments
@@ -275,14 +525,51 @@ where
 		network: &Network,
 		rpc_client: C,
 	) -> Result<(), BlockWatcherError> {
+		self.start_network_watcher_with_schedules(
+			network,
+			vec![network.cron_schedule.clone()],
+			rpc_client,
+		)
+		.await
+	}
+
+	/// Starts a watcher for a specific network, with a job for each of its cron schedules
+	///
+	/// # Arguments
+	/// * `network` - Network configuration to start watching
+	/// * `schedules` - Distinct cron schedules of the network's monitors
+	pub async fn start_network_watcher_with_schedules<
+		C: BlockChainClient + Send + Clone + 'static,
+	>(
+		&self,
+		network: &Network,
+		schedules: Vec<String>,
+		rpc_client: C,
+	) -> Result<(), BlockWatcherError> {
+		self.start_watcher(network, schedules, rpc_client)
+			.await
+			.map(|_| ())
+	}
+
+	/// Starts a watcher for a specific network unless one is already running
+	///
+	/// # Returns
+	/// * `Result<Arc<WatcherHealth>, BlockWatcherError>` - Health of the network's running
+	///   watcher
+	async fn start_watcher<C: BlockChainClient + Send + Clone + 'static>(
+		&self,
+		network: &Network,
+		schedules: Vec<String>,
+		rpc_client: C,
+	) -> Result<Arc<WatcherHealth>, BlockWatcherError> {
 		let mut watchers = self.active_watchers.write().await;
 
-		if watchers.contains_key(&network.slug) {
+		if let Some(watcher) = watchers.get(&network.slug) {
 			tracing::info!(
 				"Block watcher already running for network: {}",
 				network.slug
 			);
-			return Ok(());
+			return Ok(watcher.health.clone());
 		}
 
 		let mut watcher = NetworkBlockWatcher::new(
@@ -292,12 +579,17 @@ where
 			self.trigger_handler.clone(),
 			self.block_tracker.clone(),
 		)
-		.await?;
+		.await?
+		.with_schedules(schedules)
+		.with_health(Arc::new(WatcherHealth::new(
+			self.restart_policy.max_consecutive_failures,
+		)));
 
 		watcher.start(rpc_client).await?;
+		let health = watcher.health.clone();
 		watchers.insert(network.slug.clone(), watcher);
 
-		Ok(())
+		Ok(health)
 	}
 
 	/// Stops a watcher for a specific network
@@ -313,6 +605,198 @@ where
 
 		Ok(())
 	}
+
+	/// Starts a watcher for a specific network and restarts it whenever it fails
+	///
+	/// The watcher is restarted with the client returned by `get_client` again, following the
+	/// service's [`WatcherRestartPolicy`], when the client can't be created, when the watcher
+	/// fails to start, or when its jobs fail too many runs in a row. `get_client` is called
+	/// for every start, so that it can create a fresh client instead of reusing a broken one.
+	/// Every restart is logged and counted by the `network_watcher_restarts_total` metric. The
+	/// network is given up on once the maximum number of restarts in a row is reached, without
+	/// affecting other networks.
+	///
+	/// # Arguments
+	/// * `network` - Network configuration to start watching
+	/// * `schedules` - Distinct cron schedules of the network's monitors
+	/// * `get_client` - Function creating the RPC client for the network, called on every start
+	///
+	/// # Returns
+	/// * `Result<(), BlockWatcherError>` - Ok once the watcher is stopped with
+	///   [`Self::stop_network_watcher`], or the last error when the network is given up on
+	pub async fn supervise_network_watcher<C, F, Fut>(
+		&self,
+		network: &Network,
+		schedules: Vec<String>,
+		get_client: F,
+	) -> Result<(), BlockWatcherError>
+	where
+		C: BlockChainClient + Send + Clone + 'static,
+		F: Fn() -> Fut,
+		Fut: Future<Output = anyhow::Result<C>>,
+	{
+		let mut attempt = 0;
+
+		loop {
+			let error = match self
+				.start_supervised_watcher(network, schedules.clone(), &get_client)
+				.await
+			{
+				Ok(health) => {
+					if !health.wait_unhealthy().await {
+						return Ok(());
+					}
+					// Only restarts without any successful run in between count towards the cap
+					if health.has_succeeded() {
+						attempt = 0;
+					}
+					self.stop_network_watcher(&network.slug).await?;
+					BlockWatcherError::processing_error(
+						format!(
+							"Block watcher failed {} runs in a row",
+							self.restart_policy.max_consecutive_failures
+						),
+						None,
+						Some(HashMap::from([(
+							"network".to_string(),
+							network.slug.clone(),
+						)])),
+					)
+				}
+				Err(e) => e,
+			};
+
+			if attempt >= self.restart_policy.max_attempts {
+				tracing::error!(
+					"Giving up on block watcher for network {} after {} restart(s): {}",
+					network.slug,
+					attempt,
+					error
+				);
+				return Err(error);
+			}
+
+			attempt += 1;
+			let backoff = self.restart_policy.backoff(attempt);
+			tracing::warn!(
+				"Restarting block watcher for network {} in {:?} (attempt {}/{}): {}",
+				network.slug,
+				backoff,
+				attempt,
+				self.restart_policy.max_attempts,
+				error
+			);
+			NETWORK_WATCHER_RESTARTS_TOTAL
+				.with_label_values(&[&network.slug])
+				.inc();
+			tokio::time::sleep(backoff).await;
+		}
+	}
+
+	/// Creates an RPC client and starts a watcher with it, returning the watcher's health
+	async fn start_supervised_watcher<C, F, Fut>(
+		&self,
+		network: &Network,
+		schedules: Vec<String>,
+		get_client: &F,
+	) -> Result<Arc<WatcherHealth>, BlockWatcherError>
+	where
+		C: BlockChainClient + Send + Clone + 'static,
+		F: Fn() -> Fut,
+		Fut: Future<Output = anyhow::Result<C>>,
+	{
+		let rpc_client = get_client().await.map_err(|e| {
+			BlockWatcherError::network_error(
+				"Failed to get RPC client".to_string(),
+				Some(e.into()),
+				Some(HashMap::from([(
+					"network".to_string(),
+					network.slug.clone(),
+				)])),
+			)
+		})?;
+		self.start_watcher(network, schedules, rpc_client).await
+	}
+}
+
+/// Detects whether already processed blocks were replaced by a chain reorganization
+///
+/// The hash recorded for the last processed block is compared with the chain's current block at
+/// the same height. On mismatch, the recorded hashes are compared with the chain to find the fork
+/// point, the first height whose block changed, and the tracker forgets the blocks from there on.
+///
+/// # Arguments
+/// * `network` - Network configuration
+/// * `rpc_client` - RPC client for the network
+/// * `block_tracker` - Tracker holding the hashes of processed blocks
+/// * `last_processed_block` - Last processed block number, as persisted in storage
+///
+/// # Returns
+/// * `Result<Option<u64>, BlockWatcherError>` - Fork point to process blocks from again, or
+///   `None` if no reorg was detected
+async fn detect_reorg<C: BlockChainClient, TR: BlockTrackerTrait>(
+	network: &Network,
+	rpc_client: &C,
+	block_tracker: &TR,
+	last_processed_block: u64,
+) -> Result<Option<u64>, BlockWatcherError> {
+	let recorded_hashes = block_tracker.get_block_hashes(&network.slug).await;
+
+	// Only the hash of the persisted last processed block can be checked reliably
+	let (first_recorded, (last_recorded, last_hash)) =
+		match (recorded_hashes.first(), recorded_hashes.last()) {
+			(Some((first, _)), Some(last)) if last.0 == last_processed_block => (*first, last),
+			_ => return Ok(None),
+		};
+
+	// Cached blocks are keyed by number and would hide the replaced blocks
+	let current_block = rpc_client
+		.refresh_blocks(*last_recorded, None)
+		.await
+		.with_context(|| format!("Failed to get block {}", last_recorded))?;
+	if current_block
+		.first()
+		.and_then(|block| block.hash())
+		.as_ref()
+		== Some(last_hash)
+	{
+		return Ok(None);
+	}
+
+	// Find the first recorded block that is no longer part of the chain
+	let current_blocks = rpc_client
+		.refresh_blocks(first_recorded, Some(*last_recorded))
+		.await
+		.with_context(|| {
+			format!(
+				"Failed to get blocks from {} to {}",
+				first_recorded, last_recorded
+			)
+		})?;
+	let current_hashes: HashMap<u64, String> = current_blocks
+		.iter()
+		.filter_map(|block| Some((block.number()?, block.hash()?)))
+		.collect();
+	let fork_point = recorded_hashes
+		.iter()
+		.find(|(number, hash)| current_hashes.get(number) != Some(hash))
+		.map(|(number, _)| *number)
+		.unwrap_or(*last_recorded);
+
+	tracing::warn!(
+		network = %network.slug,
+		fork_point = fork_point,
+		"Reorg detected: block {} was replaced, processing again from block {}",
+		last_recorded,
+		fork_point
+	);
+	REORGS_DETECTED_TOTAL
+		.with_label_values(&[&network.slug])
+		.inc();
+
+	block_tracker.rollback(network, fork_point).await;
+
+	Ok(Some(fork_point))
 }
 
 /// Processes new blocks for a network
@@ -342,7 +826,8 @@ pub async fn process_new_blocks<
 	trigger_handler: Arc<T>,
 	block_tracker: Arc<TR>,
 ) -> Result<(), BlockWatcherError> {
-	let start_time = std::time::Instant::now();
+	let clock = block_tracker.clock();
+	let start_time = clock.now();
 
 	let last_processed_block = block_storage
 		.get_last_processed_block(&network.slug)
@@ -350,22 +835,54 @@ pub async fn process_new_blocks<
 		.with_context(|| "Failed to get last processed block")?
 		.unwrap_or(0);
 
-	let latest_block = rpc_client
-		.get_latest_block_number()
+	let latest_confirmed_block = rpc_client
+		.get_latest_confirmed_block_number(network)
 		.await
-		.with_context(|| "Failed to get latest block number")?;
-
-	let latest_confirmed_block = latest_block.saturating_sub(network.confirmation_blocks);
+		.with_context(|| "Failed to get latest confirmed block number")?;
 
 	let recommended_past_blocks = network.get_recommended_past_blocks();
 
-	let max_past_blocks = network.max_past_blocks.unwrap_or(recommended_past_blocks);
+	// In auto mode, the blocks produced since the last execution are processed, so that a
+	// downtime longer than the cron schedule doesn't skip blocks
+	let max_past_blocks = match network.max_past_blocks {
+		Some(max_past_blocks) => max_past_blocks,
+		None if network.uses_auto_past_blocks() => block_storage
+			.get_last_processed_time(&network.slug)
+			.await
+			.with_context(|| "Failed to get last processed time")?
+			.map_or(recommended_past_blocks, |last_processed_time| {
+				network.get_auto_past_blocks(
+					(clock.utc_now() - last_processed_time)
+						.to_std()
+						.unwrap_or_default(),
+				)
+			}),
+		None => recommended_past_blocks,
+	};
 
-	// Calculate the start block number, using the default if max_past_blocks is not set
-	let start_block = std::cmp::max(
-		last_processed_block + 1,
-		latest_confirmed_block.saturating_sub(max_past_blocks),
-	);
+	// Confirmed blocks aren't expected to be replaced, so the blocks needed to detect a reorg
+	// are only fetched on networks processing unconfirmed blocks
+	let fork_point = if network.processes_unconfirmed_blocks() {
+		detect_reorg(
+			network,
+			rpc_client,
+			block_tracker.as_ref(),
+			last_processed_block,
+		)
+		.await?
+	} else {
+		None
+	};
+
+	// Calculate the start block number, using the default if max_past_blocks is not set.
+	// After a reorg, blocks are processed again from the fork point.
+	let start_block = match fork_point {
+		Some(fork_point) => fork_point,
+		None => std::cmp::max(
+			last_processed_block + 1,
+			latest_confirmed_block.saturating_sub(max_past_blocks),
+		),
+	};
 
 	tracing::info!(
 		"Processing blocks:\n\tLast processed block: {}\n\tLatest confirmed block: {}\n\tStart \
@@ -385,32 +902,184 @@ pub async fn process_new_blocks<
 		max_past_blocks
 	);
 
-	let mut blocks = Vec::new();
-	if last_processed_block == 0 {
-		blocks = rpc_client
-			.get_blocks(latest_confirmed_block, None)
+	// The blocks to process are fetched and executed in chunks of `catchup_checkpoint_blocks`
+	// blocks. The last block of every chunk but the final one is saved as a checkpoint, so that
+	// an interrupted catch-up resumes from there instead of processing all blocks again.
+	let block_ranges = get_block_ranges(
+		network,
+		last_processed_block,
+		start_block,
+		latest_confirmed_block,
+	);
+	let catchup_blocks_remaining = CATCHUP_BLOCKS_REMAINING.with_label_values(&[&network.slug]);
+	if let Some(&(from_block, _)) = block_ranges.first() {
+		catchup_blocks_remaining.set((latest_confirmed_block + 1 - from_block) as f64);
+	}
+
+	let mut processed_blocks = 0;
+	let mut stored_blocks = Vec::new();
+
+	// Without blocks to fetch, the execution still runs once over no blocks
+	for chunk in 0..block_ranges.len().max(1) {
+		let range = block_ranges.get(chunk).copied();
+		let blocks = match range {
+			Some((from_block, None)) => rpc_client
+				.get_blocks(from_block, None)
+				.await
+				.with_context(|| format!("Failed to get block {}", from_block))?,
+			Some((from_block, Some(to_block))) => rpc_client
+				.get_blocks(from_block, Some(to_block))
+				.await
+				.with_context(|| {
+					format!("Failed to get blocks from {} to {}", from_block, to_block)
+				})?,
+			None => Vec::new(),
+		};
+
+		let chunk_start_block = if chunk == 0 {
+			// Reset expected_next to start_block to ensure synchronization with this execution
+			// This prevents false out-of-order warnings when reprocessing blocks or restarting
+			block_tracker
+				.reset_expected_next(network, start_block)
+				.await;
+			start_block
+		} else {
+			range.map_or(start_block, |(from_block, _)| from_block)
+		};
+
+		execute_blocks(
+			network,
+			&blocks,
+			chunk_start_block,
+			block_storage.as_ref(),
+			block_handler.clone(),
+			trigger_handler.clone(),
+			block_tracker.clone(),
+		)
+		.await?;
+
+		// Record block hashes so the next execution can detect reorgs
+		if network.processes_unconfirmed_blocks() {
+			for block in &blocks {
+				if let (Some(number), Some(hash)) = (block.number(), block.hash()) {
+					block_tracker.record_block_hash(network, number, hash).await;
+				}
+			}
+		}
+
+		if let Some((from_block, to_block)) = range {
+			let last_block = to_block.unwrap_or(from_block);
+			if last_block < latest_confirmed_block {
+				block_storage
+					.save_last_processed_block(&network.slug, last_block)
+					.await
+					.with_context(|| "Failed to save catch-up checkpoint")?;
+				tracing::info!(
+					network = %network.slug,
+					"Saved catch-up checkpoint at block {}",
+					last_block
+				);
+			}
+			catchup_blocks_remaining.set(latest_confirmed_block.saturating_sub(last_block) as f64);
+		}
+
+		processed_blocks += blocks.len();
+		if network.store_blocks.unwrap_or(false) {
+			stored_blocks.extend(blocks);
+		}
+	}
+
+	if network.store_blocks.unwrap_or(false) {
+		// Delete old blocks before saving new ones
+		block_storage
+			.delete_blocks(&network.slug)
 			.await
-			.with_context(|| format!("Failed to get block {}", latest_confirmed_block))?;
-	} else if last_processed_block < latest_confirmed_block {
-		blocks = rpc_client
-			.get_blocks(start_block, Some(latest_confirmed_block))
+			.with_context(|| "Failed to delete old blocks")?;
+
+		block_storage
+			.save_blocks(&network.slug, &stored_blocks)
 			.await
-			.with_context(|| {
-				format!(
-					"Failed to get blocks from {} to {}",
-					start_block, latest_confirmed_block
-				)
-			})?;
+			.with_context(|| "Failed to save blocks")?;
 	}
+	// Update the last processed block
+	block_storage
+		.save_last_processed_block(&network.slug, latest_confirmed_block)
+		.await
+		.with_context(|| "Failed to save last processed block")?;
 
-	// Reset expected_next to start_block to ensure synchronization with this execution
-	// This prevents false out-of-order warnings when reprocessing blocks or restarting
-	block_tracker
-		.reset_expected_next(network, start_block)
+	// Only complete executions are recorded, so that the blocks left by an interrupted
+	// catch-up are still counted on the next execution
+	if network.uses_auto_past_blocks() {
+		block_storage
+			.save_last_processed_time(&network.slug, clock.utc_now())
+			.await
+			.with_context(|| "Failed to save last processed time")?;
+	}
+
+	stall_detector()
+		.observe(network, Some(latest_confirmed_block))
 		.await;
 
+	tracing::info!(
+		"Processed {} blocks in {}ms",
+		processed_blocks,
+		clock.now().duration_since(start_time).as_millis()
+	);
+
+	Ok(())
+}
+
+/// Splits the blocks to process into the ranges fetched and executed at once
+///
+/// Only the latest confirmed block is processed on the first execution of a network. Otherwise
+/// the blocks from `start_block` to `latest_confirmed_block` are split into ranges of
+/// `catchup_checkpoint_blocks` blocks, or form a single range when it is unset.
+///
+/// # Returns
+/// * `Vec<(u64, Option<u64>)>` - Start and optional end block of each range
+fn get_block_ranges(
+	network: &Network,
+	last_processed_block: u64,
+	start_block: u64,
+	latest_confirmed_block: u64,
+) -> Vec<(u64, Option<u64>)> {
+	if last_processed_block == 0 {
+		return vec![(latest_confirmed_block, None)];
+	}
+
+	let chunk_size = network.catchup_checkpoint_blocks.unwrap_or(u64::MAX).max(1);
+	let mut ranges = Vec::new();
+	let mut from_block = start_block;
+	while from_block <= latest_confirmed_block {
+		let to_block = from_block
+			.saturating_add(chunk_size - 1)
+			.min(latest_confirmed_block);
+		ranges.push((from_block, Some(to_block)));
+		from_block = to_block + 1;
+	}
+	ranges
+}
+
+/// Runs fetched blocks through the processing and trigger pipelines
+///
+/// Blocks are processed concurrently and their triggers executed in block order, starting
+/// from `start_block`. Gaps in the fetched blocks are reported as missed blocks.
+async fn execute_blocks<
+	S: BlockStorage,
+	H: Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync + 'static,
+	T: Fn(&ProcessedBlock) -> tokio::task::JoinHandle<()> + Send + Sync + 'static,
+	TR: BlockTrackerTrait + Send + Sync + 'static,
+>(
+	network: &Network,
+	blocks: &[BlockType],
+	start_block: u64,
+	block_storage: &S,
+	block_handler: Arc<H>,
+	trigger_handler: Arc<T>,
+	block_tracker: Arc<TR>,
+) -> Result<(), BlockWatcherError> {
 	// Detect missing blocks using BlockTracker
-	let missed_blocks = block_tracker.detect_missing_blocks(network, &blocks).await;
+	let missed_blocks = block_tracker.detect_missing_blocks(network, blocks).await;
 
 	// Log and save missed blocks if any
 	if !missed_blocks.is_empty() {
@@ -435,6 +1104,12 @@ pub async fn process_new_blocks<
 	let (process_tx, process_rx) = mpsc::channel::<(BlockType, u64)>(blocks.len() * 2);
 	let (trigger_tx, trigger_rx) = mpsc::channel::<ProcessedBlock>(blocks.len() * 2);
 
+	// Block timestamps used to update the block lag as blocks are executed
+	let block_timestamps: HashMap<u64, u64> = blocks
+		.iter()
+		.filter_map(|block| Some((block.number()?, block.timestamp()?)))
+		.collect();
+
 	// Stage 1: Block Processing Pipeline
 	let process_handle = tokio::spawn({
 		let network = network.clone();
@@ -471,6 +1146,17 @@ pub async fn process_new_blocks<
 
 		async move {
 			let mut trigger_rx = trigger_rx;
+			let record_block_lag = |block_number: u64| {
+				let timestamp = block_timestamps.get(&block_number).copied();
+				let network_slug = network.slug.clone();
+				async move {
+					if let Some(timestamp) = timestamp {
+						stall_detector()
+							.record_block_timestamp(&network_slug, timestamp)
+							.await;
+					}
+				}
+			};
 			let mut pending_blocks = BTreeMap::new();
 			let mut next_block_number = Some(start_block);
 			let block_tracker = block_tracker.clone();
@@ -520,6 +1206,7 @@ pub async fn process_new_blocks<
 						}
 
 						(trigger_handler)(&block);
+						record_block_lag(expected).await;
 						next_block_number = Some(expected + 1);
 					} else {
 						break;
@@ -564,6 +1251,7 @@ pub async fn process_new_blocks<
 					}
 
 					(trigger_handler)(&block);
+					record_block_lag(min_block).await;
 				}
 			}
 			Ok::<(), BlockWatcherError>(())
@@ -597,29 +1285,5 @@ pub async fn process_new_blocks<
 	// Wait for both pipeline stages to complete
 	let (_process_result, _trigger_result) = tokio::join!(process_handle, trigger_handle);
 
-	if network.store_blocks.unwrap_or(false) {
-		// Delete old blocks before saving new ones
-		block_storage
-			.delete_blocks(&network.slug)
-			.await
-			.with_context(|| "Failed to delete old blocks")?;
-
-		block_storage
-			.save_blocks(&network.slug, &blocks)
-			.await
-			.with_context(|| "Failed to save blocks")?;
-	}
-	// Update the last processed block
-	block_storage
-		.save_last_processed_block(&network.slug, latest_confirmed_block)
-		.await
-		.with_context(|| "Failed to save last processed block")?;
-
-	tracing::info!(
-		"Processed {} blocks in {}ms",
-		blocks.len(),
-		start_time.elapsed().as_millis()
-	);
-
 	Ok(())
 }
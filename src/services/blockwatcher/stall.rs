@@ -0,0 +1,295 @@
+//! Stall detection for block watchers.
+//!
+//! A network is considered stalled when its latest processed block hasn't advanced within the
+//! network's `stall_threshold_ms`, which usually means the RPC node stopped following the chain.
+//! Stalls are logged, exposed through the `network_stalled` metric and the readiness endpoint,
+//! and passed to a handler registered at startup, which executes the network's stall trigger.
+
+use futures::future::BoxFuture;
+use lazy_static::lazy_static;
+use std::{
+	collections::HashMap,
+	sync::Arc,
+	time::{Duration, Instant},
+};
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+	models::Network,
+	utils::{
+		metrics::{update_block_lag, NETWORK_STALLED},
+		system_clock, SharedClock,
+	},
+};
+
+/// Details about a network that just stalled
+#[derive(Debug, Clone)]
+pub struct StallEvent {
+	/// Network that stalled
+	pub network: Network,
+	/// Last block processed before the stall
+	pub last_block: u64,
+	/// Time elapsed since the last block was processed
+	pub stalled_for: Duration,
+}
+
+/// Handler invoked once each time a network becomes stalled
+pub type StallHandler = Arc<dyn Fn(StallEvent) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// Block progress of a single network
+struct NetworkProgress {
+	/// Latest processed block number
+	last_block: u64,
+	/// Timestamp of the latest processed block, in seconds since the Unix epoch
+	last_block_timestamp: Option<u64>,
+	/// When the latest processed block number last advanced
+	last_progress: Instant,
+	/// Whether the network is currently stalled
+	stalled: bool,
+}
+
+impl NetworkProgress {
+	fn new(last_block: u64, now: Instant) -> Self {
+		Self {
+			last_block,
+			last_block_timestamp: None,
+			last_progress: now,
+			stalled: false,
+		}
+	}
+}
+
+/// Tracks block progress per network to detect stalled networks
+pub struct StallDetector {
+	/// Progress per network slug
+	progress: Mutex<HashMap<String, NetworkProgress>>,
+	/// Handler invoked when a network becomes stalled
+	handler: RwLock<Option<StallHandler>>,
+	/// Clock the progress is timed with
+	clock: SharedClock,
+}
+
+impl Default for StallDetector {
+	fn default() -> Self {
+		Self::with_clock(system_clock())
+	}
+}
+
+lazy_static! {
+	/// Stall detector shared by all block watchers and the readiness endpoint
+	static ref STALL_DETECTOR: StallDetector = StallDetector::new();
+}
+
+/// Returns the stall detector shared by all block watchers
+pub fn stall_detector() -> &'static StallDetector {
+	&STALL_DETECTOR
+}
+
+impl StallDetector {
+	/// Creates a new stall detector without a handler
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates a new stall detector without a handler, timing the progress with a clock
+	///
+	/// # Arguments
+	/// * `clock` - Clock the progress is timed with
+	pub fn with_clock(clock: SharedClock) -> Self {
+		Self {
+			progress: Mutex::new(HashMap::new()),
+			handler: RwLock::new(None),
+			clock,
+		}
+	}
+
+	/// Registers the handler invoked when a network becomes stalled
+	///
+	/// # Arguments
+	/// * `handler` - Handler replacing any previously registered one
+	pub async fn set_handler(&self, handler: StallHandler) {
+		*self.handler.write().await = Some(handler);
+	}
+
+	/// Records the timestamp of a processed block and updates the network's block lag
+	///
+	/// # Arguments
+	/// * `network_slug` - Network the block belongs to
+	/// * `block_timestamp` - Block timestamp in seconds since the Unix epoch
+	pub async fn record_block_timestamp(&self, network_slug: &str, block_timestamp: u64) {
+		self.progress
+			.lock()
+			.await
+			.entry(network_slug.to_string())
+			.or_insert_with(|| NetworkProgress::new(0, self.clock.now()))
+			.last_block_timestamp = Some(block_timestamp);
+		update_block_lag(network_slug, block_timestamp);
+	}
+
+	/// Observes the latest processed block of a network
+	///
+	/// The stall is logged and the handler invoked once when the block number hasn't advanced
+	/// within the network's `stall_threshold_ms`. The stall clears as soon as the block number
+	/// advances again. Networks without a threshold are tracked but never reported as stalled.
+	///
+	/// # Arguments
+	/// * `network` - Network being watched
+	/// * `latest_block` - Latest processed block number, or None if processing failed
+	///
+	/// # Returns
+	/// * `bool` - Whether the network is stalled
+	pub async fn observe(&self, network: &Network, latest_block: Option<u64>) -> bool {
+		let now = self.clock.now();
+
+		let (stalled, newly_stalled) = {
+			let mut progress = self.progress.lock().await;
+			let entry = progress
+				.entry(network.slug.clone())
+				.or_insert_with(|| NetworkProgress::new(latest_block.unwrap_or_default(), now));
+
+			match latest_block {
+				Some(block) if block > entry.last_block => {
+					if entry.stalled {
+						tracing::info!(
+							network = %network.slug,
+							"Network {} resumed processing at block {}",
+							network.slug,
+							block
+						);
+					}
+					entry.last_block = block;
+					entry.last_progress = now;
+					entry.stalled = false;
+				}
+				_ => {
+					// Keep the lag growing while no new block is processed
+					if let Some(timestamp) = entry.last_block_timestamp {
+						update_block_lag(&network.slug, timestamp);
+					}
+				}
+			}
+
+			let stalled_for = now.duration_since(entry.last_progress);
+			let newly_stalled = match network.stall_threshold_ms {
+				Some(threshold_ms)
+					if !entry.stalled && stalled_for >= Duration::from_millis(threshold_ms) =>
+				{
+					entry.stalled = true;
+					Some(StallEvent {
+						network: network.clone(),
+						last_block: entry.last_block,
+						stalled_for,
+					})
+				}
+				_ => None,
+			};
+
+			NETWORK_STALLED
+				.with_label_values(&[&network.slug])
+				.set(if entry.stalled { 1.0 } else { 0.0 });
+
+			(entry.stalled, newly_stalled)
+		};
+
+		if let Some(event) = newly_stalled {
+			tracing::error!(
+				network = %network.slug,
+				last_block = event.last_block,
+				"No new block processed for network {} in {}ms (threshold: {}ms), last processed \
+				 block: {}",
+				network.slug,
+				event.stalled_for.as_millis(),
+				network.stall_threshold_ms.unwrap_or_default(),
+				event.last_block
+			);
+
+			if let Some(handler) = self.handler.read().await.clone() {
+				tokio::spawn(handler(event));
+			}
+		}
+
+		stalled
+	}
+
+	/// Checks whether a network is currently stalled
+	pub async fn is_stalled(&self, network_slug: &str) -> bool {
+		self.progress
+			.lock()
+			.await
+			.get(network_slug)
+			.is_some_and(|entry| entry.stalled)
+	}
+
+	/// Returns the slugs of all currently stalled networks, sorted alphabetically
+	pub async fn stalled_networks(&self) -> Vec<String> {
+		let mut stalled: Vec<String> = self
+			.progress
+			.lock()
+			.await
+			.iter()
+			.filter(|(_, entry)| entry.stalled)
+			.map(|(slug, _)| slug.clone())
+			.collect();
+		stalled.sort();
+		stalled
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::{tests::builders::network::NetworkBuilder, MockClock};
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	fn create_test_network(slug: &str, stall_threshold_ms: Option<u64>) -> Network {
+		let builder = NetworkBuilder::new().slug(slug);
+		match stall_threshold_ms {
+			Some(threshold) => builder.stall_threshold_ms(threshold).build(),
+			None => builder.build(),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_observe_detects_stall_once_and_recovers() {
+		let clock = Arc::new(MockClock::new());
+		let detector = StallDetector::with_clock(clock.clone());
+		let network = create_test_network("stall_unit_test", Some(20));
+		let calls = Arc::new(AtomicUsize::new(0));
+
+		let handler_calls = calls.clone();
+		detector
+			.set_handler(Arc::new(move |event: StallEvent| {
+				assert_eq!(event.last_block, 100);
+				handler_calls.fetch_add(1, Ordering::SeqCst);
+				Box::pin(async {}) as BoxFuture<'static, ()>
+			}))
+			.await;
+
+		assert!(!detector.observe(&network, Some(100)).await);
+		clock.advance(Duration::from_millis(19));
+		assert!(!detector.observe(&network, Some(100)).await);
+		clock.advance(Duration::from_millis(1));
+
+		assert!(detector.observe(&network, Some(100)).await);
+		assert!(detector.observe(&network, None).await);
+		assert_eq!(detector.stalled_networks().await, vec!["stall_unit_test"]);
+		assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+		assert!(!detector.observe(&network, Some(101)).await);
+		assert!(!detector.is_stalled("stall_unit_test").await);
+		assert!(detector.stalled_networks().await.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_observe_without_threshold_never_stalls() {
+		let clock = Arc::new(MockClock::new());
+		let detector = StallDetector::with_clock(clock.clone());
+		let network = create_test_network("stall_unit_test_disabled", None);
+
+		detector.observe(&network, Some(100)).await;
+		clock.advance(Duration::from_secs(3600));
+
+		assert!(!detector.observe(&network, Some(100)).await);
+		assert!(!detector.is_stalled("stall_unit_test_disabled").await);
+	}
+}
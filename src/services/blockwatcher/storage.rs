@@ -3,14 +3,32 @@
 //! This module provides storage interfaces and implementations for persisting
 //! blockchain blocks and tracking processing state. Currently supports:
 //! - File-based storage with JSON serialization
+//! - Redis-based storage shared between replicas
+//! - Per-schedule processing state for monitors overriding their network's schedule
 //! - Last processed block tracking
+//! - Last processed time tracking, used to size the catch-up after a downtime
 //! - Block deletion for cleanup
+//! - Processed block hashes, used to skip blocks whose triggers already fired
+//! - Firing alerts of the monitors notifying when their alerts resolve
+//! - Dedup keys of the notified matches in cooldown, for monitors persisting their cooldown
+//! - Balances of the addresses watched by balance conditions, at the last processed block
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
 use glob::glob;
-use std::path::PathBuf;
+use redis::{aio::ConnectionManager, AsyncCommands};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+	collections::{BTreeMap, HashMap},
+	path::{Path, PathBuf},
+	sync::Arc,
+	time::Duration,
+};
+use tokio::sync::Mutex;
 
-use crate::models::BlockType;
+use crate::models::{BlockType, MonitorMatch};
 
 /// Interface for block storage implementations
 ///
@@ -44,6 +62,41 @@ pub trait BlockStorage: Clone + Send + Sync {
 		block: u64,
 	) -> Result<(), anyhow::Error>;
 
+	/// Retrieves when new blocks of a network were last processed
+	///
+	/// Storages that don't record it return None.
+	///
+	/// # Arguments
+	/// * `network_id` - Unique identifier for the network
+	///
+	/// # Returns
+	/// * `Result<Option<DateTime<Utc>>, anyhow::Error>` - Last processed time or None if not
+	///   found
+	async fn get_last_processed_time(
+		&self,
+		_network_id: &str,
+	) -> Result<Option<DateTime<Utc>>, anyhow::Error> {
+		Ok(None)
+	}
+
+	/// Saves when new blocks of a network were last processed
+	///
+	/// Storages that don't record it ignore it.
+	///
+	/// # Arguments
+	/// * `network_id` - Unique identifier for the network
+	/// * `time` - Time the blocks were processed at
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error
+	async fn save_last_processed_time(
+		&self,
+		_network_id: &str,
+		_time: DateTime<Utc>,
+	) -> Result<(), anyhow::Error> {
+		Ok(())
+	}
+
 	/// Saves a collection of blocks for a network
 	///
 	/// # Arguments
@@ -80,8 +133,233 @@ pub trait BlockStorage: Clone + Send + Sync {
 		network_id: &str,
 		blocks: &[u64],
 	) -> Result<(), anyhow::Error>;
+
+	/// Records that the triggers of a block were executed
+	///
+	/// Storages that don't record it report every block as new.
+	///
+	/// # Arguments
+	/// * `network_id` - Unique identifier for the network
+	/// * `block_hash` - Hash of the block
+	///
+	/// # Returns
+	/// * `Result<bool, anyhow::Error>` - Whether the block wasn't recorded before
+	async fn mark_block_processed(
+		&self,
+		_network_id: &str,
+		_block_hash: &str,
+	) -> Result<bool, anyhow::Error> {
+		Ok(true)
+	}
+
+	/// Retrieves the firing alerts of the monitors of a network with `notify_on_resolve`
+	///
+	/// Storages that don't record them return none.
+	///
+	/// # Arguments
+	/// * `network_id` - Unique identifier for the network
+	///
+	/// # Returns
+	/// * `Result<Vec<MonitorMatch>, anyhow::Error>` - Last match of each monitor whose alert is
+	///   firing
+	async fn get_firing_alerts(
+		&self,
+		_network_id: &str,
+	) -> Result<Vec<MonitorMatch>, anyhow::Error> {
+		Ok(Vec::new())
+	}
+
+	/// Saves the alert state of a monitor with `notify_on_resolve` on a network
+	///
+	/// Storages that don't record it ignore it.
+	///
+	/// # Arguments
+	/// * `network_id` - Unique identifier for the network
+	/// * `monitor_name` - Name of the monitor
+	/// * `last_match` - Last match of the monitor while its alert is firing, None once resolved
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error
+	async fn save_alert_state(
+		&self,
+		_network_id: &str,
+		_monitor_name: &str,
+		_last_match: Option<&MonitorMatch>,
+	) -> Result<(), anyhow::Error> {
+		Ok(())
+	}
+
+	/// Retrieves the dedup keys of the notified matches whose cooldown hasn't expired
+	///
+	/// Storages that don't record them return none.
+	///
+	/// # Returns
+	/// * `Result<HashMap<String, DateTime<Utc>>, anyhow::Error>` - Time the cooldown of each
+	///   dedup key expires at
+	async fn get_dedup_keys(&self) -> Result<HashMap<String, DateTime<Utc>>, anyhow::Error> {
+		Ok(HashMap::new())
+	}
+
+	/// Saves the dedup key of a notified match until its cooldown expires
+	///
+	/// Storages that don't record it ignore it. Expired keys are dropped, and the keys expiring
+	/// first are dropped beyond [`MAX_DEDUP_KEYS`].
+	///
+	/// # Arguments
+	/// * `dedup_key` - Dedup key of the match
+	/// * `expires_at` - Time the cooldown of the key expires at
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error
+	async fn save_dedup_key(
+		&self,
+		_dedup_key: &str,
+		_expires_at: DateTime<Utc>,
+	) -> Result<(), anyhow::Error> {
+		Ok(())
+	}
+
+	/// Retrieves the balances of the addresses watched by the balance conditions of the
+	/// monitors of a network
+	///
+	/// Storages that don't record them return none.
+	///
+	/// # Arguments
+	/// * `network_id` - Unique identifier for the network
+	///
+	/// # Returns
+	/// * `Result<CachedBalances, anyhow::Error>` - Last balance read of each address, by monitor
+	///   name and address
+	async fn get_balances(&self, _network_id: &str) -> Result<CachedBalances, anyhow::Error> {
+		Ok(HashMap::new())
+	}
+
+	/// Saves the balances of addresses watched by the balance conditions of monitors of a
+	/// network, replacing their previous balances
+	///
+	/// Storages that don't record them ignore them.
+	///
+	/// # Arguments
+	/// * `network_id` - Unique identifier for the network
+	/// * `balances` - Balances read, by monitor name and address
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error
+	async fn save_balances(
+		&self,
+		_network_id: &str,
+		_balances: &CachedBalances,
+	) -> Result<(), anyhow::Error> {
+		Ok(())
+	}
+}
+
+/// Records the blocks whose triggers were executed, see [`BlockStorage::mark_block_processed`]
+///
+/// Called with the network slug and block hash, it resolves to whether the block is new.
+pub type ProcessedBlockGuard =
+	Arc<dyn Fn(String, String) -> BoxFuture<'static, Result<bool, anyhow::Error>> + Send + Sync>;
+
+/// Creates a guard recording the processed blocks in a block storage
+///
+/// # Arguments
+/// * `storage` - Storage the block hashes are recorded in
+pub fn create_processed_block_guard<S: BlockStorage + 'static>(
+	storage: Arc<S>,
+) -> ProcessedBlockGuard {
+	Arc::new(move |network_id: String, block_hash: String| {
+		let storage = storage.clone();
+		Box::pin(async move { storage.mark_block_processed(&network_id, &block_hash).await })
+			as BoxFuture<'static, Result<bool, anyhow::Error>>
+	})
+}
+
+/// Records the alert states of monitors, see [`BlockStorage::save_alert_state`]
+///
+/// Called with the network slug, the monitor name and the last match of the monitor while its
+/// alert is firing.
+pub type AlertStateWriter = Arc<
+	dyn Fn(String, String, Option<MonitorMatch>) -> BoxFuture<'static, Result<(), anyhow::Error>>
+		+ Send
+		+ Sync,
+>;
+
+/// Creates a writer recording the alert states in a block storage
+///
+/// # Arguments
+/// * `storage` - Storage the alert states are recorded in
+pub fn create_alert_state_writer<S: BlockStorage + 'static>(storage: Arc<S>) -> AlertStateWriter {
+	Arc::new(
+		move |network_id: String, monitor_name: String, last_match: Option<MonitorMatch>| {
+			let storage = storage.clone();
+			Box::pin(async move {
+				storage
+					.save_alert_state(&network_id, &monitor_name, last_match.as_ref())
+					.await
+			}) as BoxFuture<'static, Result<(), anyhow::Error>>
+		},
+	)
+}
+
+/// Records the dedup keys of notified matches, see [`BlockStorage::save_dedup_key`]
+///
+/// Called with the dedup key and the time its cooldown expires at.
+pub type DedupKeyWriter = Arc<
+	dyn Fn(String, DateTime<Utc>) -> BoxFuture<'static, Result<(), anyhow::Error>> + Send + Sync,
+>;
+
+/// Creates a writer recording the dedup keys in a block storage
+///
+/// # Arguments
+/// * `storage` - Storage the dedup keys are recorded in
+pub fn create_dedup_key_writer<S: BlockStorage + 'static>(storage: Arc<S>) -> DedupKeyWriter {
+	Arc::new(move |dedup_key: String, expires_at: DateTime<Utc>| {
+		let storage = storage.clone();
+		Box::pin(async move { storage.save_dedup_key(&dedup_key, expires_at).await })
+			as BoxFuture<'static, Result<(), anyhow::Error>>
+	})
+}
+
+/// Maximum number of dedup keys in cooldown kept by a storage
+pub const MAX_DEDUP_KEYS: usize = 10_000;
+
+/// Native balance of an address read at a block, for balance conditions
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CachedBalance {
+	/// Number of the block the balance was read at
+	pub block_number: u64,
+	/// Balance in the smallest unit of the native asset, as a decimal number
+	pub balance: String,
+}
+
+/// Balances of the addresses watched by balance conditions, by monitor name and address
+pub type CachedBalances = HashMap<String, HashMap<String, CachedBalance>>;
+
+/// Records the balances read for balance conditions, see [`BlockStorage::save_balances`]
+///
+/// Called with the network slug and the balances read at a block.
+pub type BalanceWriter = Arc<
+	dyn Fn(String, CachedBalances) -> BoxFuture<'static, Result<(), anyhow::Error>> + Send + Sync,
+>;
+
+/// Creates a writer recording the balances in a block storage
+///
+/// # Arguments
+/// * `storage` - Storage the balances are recorded in
+pub fn create_balance_writer<S: BlockStorage + 'static>(storage: Arc<S>) -> BalanceWriter {
+	Arc::new(move |network_id: String, balances: CachedBalances| {
+		let storage = storage.clone();
+		Box::pin(async move { storage.save_balances(&network_id, &balances).await })
+			as BoxFuture<'static, Result<(), anyhow::Error>>
+	})
 }
 
+/// Number of processed block hashes kept per network by [`FileBlockStorage`]
+const MAX_FILE_PROCESSED_BLOCKS: usize = 10_000;
+
+/// How long [`RedisBlockStorage`] keeps processed block hashes
+const REDIS_PROCESSED_BLOCK_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
 /// File-based implementation of block storage
 ///
 /// Stores blocks and processing state in JSON files within a configured
@@ -90,6 +368,14 @@ pub trait BlockStorage: Clone + Send + Sync {
 pub struct FileBlockStorage {
 	/// Base path for all storage files
 	storage_path: PathBuf,
+	/// Serializes the updates of the processed block files
+	processed_blocks_lock: Arc<Mutex<()>>,
+	/// Serializes the updates of the firing alert files
+	firing_alerts_lock: Arc<Mutex<()>>,
+	/// Serializes the updates of the dedup key file
+	dedup_keys_lock: Arc<Mutex<()>>,
+	/// Serializes the updates of the balance files
+	balances_lock: Arc<Mutex<()>>,
 }
 
 impl FileBlockStorage {
@@ -97,7 +383,13 @@ impl FileBlockStorage {
 	///
 	/// Initializes storage with the provided path
 	pub fn new(storage_path: PathBuf) -> Self {
-		FileBlockStorage { storage_path }
+		FileBlockStorage {
+			storage_path,
+			processed_blocks_lock: Arc::new(Mutex::new(())),
+			firing_alerts_lock: Arc::new(Mutex::new(())),
+			dedup_keys_lock: Arc::new(Mutex::new(())),
+			balances_lock: Arc::new(Mutex::new(())),
+		}
 	}
 }
 
@@ -155,6 +447,47 @@ impl BlockStorage for FileBlockStorage {
 		Ok(())
 	}
 
+	/// Retrieves the last processed time from a network-specific file
+	///
+	/// The file is named "{network_id}_last_block_time.txt" and holds an RFC 3339 timestamp
+	async fn get_last_processed_time(
+		&self,
+		network_id: &str,
+	) -> Result<Option<DateTime<Utc>>, anyhow::Error> {
+		let file_path = self
+			.storage_path
+			.join(format!("{}_last_block_time.txt", network_id));
+
+		if !file_path.exists() {
+			return Ok(None);
+		}
+
+		let content = tokio::fs::read_to_string(file_path)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read last processed time: {}", e))?;
+		let time = DateTime::parse_from_rfc3339(content.trim())
+			.map_err(|e| anyhow::anyhow!("Failed to parse last processed time: {}", e))?;
+		Ok(Some(time.with_timezone(&Utc)))
+	}
+
+	/// Saves the last processed time to a network-specific file
+	///
+	/// # Note
+	/// Overwrites any existing last processed time file for the network
+	async fn save_last_processed_time(
+		&self,
+		network_id: &str,
+		time: DateTime<Utc>,
+	) -> Result<(), anyhow::Error> {
+		let file_path = self
+			.storage_path
+			.join(format!("{}_last_block_time.txt", network_id));
+		tokio::fs::write(file_path, time.to_rfc3339())
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to save last processed time: {}", e))?;
+		Ok(())
+	}
+
 	/// Saves blocks to a timestamped JSON file
 	///
 	/// # Note
@@ -241,6 +574,782 @@ impl BlockStorage for FileBlockStorage {
 
 		Ok(())
 	}
+
+	/// Records the block hash in a network-specific file
+	///
+	/// # Note
+	/// The file is named "{network_id}_processed_blocks.txt" and keeps the latest
+	/// 10,000 hashes, one per line. It isn't shared between replicas.
+	async fn mark_block_processed(
+		&self,
+		network_id: &str,
+		block_hash: &str,
+	) -> Result<bool, anyhow::Error> {
+		let _guard = self.processed_blocks_lock.lock().await;
+		let file_path = self
+			.storage_path
+			.join(format!("{}_processed_blocks.txt", network_id));
+
+		let content = if file_path.exists() {
+			tokio::fs::read_to_string(&file_path)
+				.await
+				.map_err(|e| anyhow::anyhow!("Failed to read processed blocks: {}", e))?
+		} else {
+			String::new()
+		};
+		let mut hashes: Vec<&str> = content.lines().collect();
+		if hashes.contains(&block_hash) {
+			return Ok(false);
+		}
+
+		hashes.push(block_hash);
+		let skipped = hashes.len().saturating_sub(MAX_FILE_PROCESSED_BLOCKS);
+		let content = hashes[skipped..]
+			.iter()
+			.map(|hash| format!("{}\n", hash))
+			.collect::<String>();
+		tokio::fs::write(file_path, content)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to save processed blocks: {}", e))?;
+		Ok(true)
+	}
+
+	/// Retrieves the firing alerts from a network-specific file
+	///
+	/// The file is named "{network_id}_firing_alerts.json" and maps monitor names to their
+	/// last match
+	async fn get_firing_alerts(
+		&self,
+		network_id: &str,
+	) -> Result<Vec<MonitorMatch>, anyhow::Error> {
+		let _guard = self.firing_alerts_lock.lock().await;
+		Ok(self
+			.read_firing_alerts(network_id)
+			.await?
+			.into_values()
+			.collect())
+	}
+
+	/// Saves the alert state to a network-specific file
+	async fn save_alert_state(
+		&self,
+		network_id: &str,
+		monitor_name: &str,
+		last_match: Option<&MonitorMatch>,
+	) -> Result<(), anyhow::Error> {
+		let _guard = self.firing_alerts_lock.lock().await;
+		let mut alerts = self.read_firing_alerts(network_id).await?;
+		match last_match {
+			Some(last_match) => alerts.insert(monitor_name.to_string(), last_match.clone()),
+			None => alerts.remove(monitor_name),
+		};
+
+		let json = serde_json::to_string(&alerts)
+			.map_err(|e| anyhow::anyhow!("Failed to serialize firing alerts: {}", e))?;
+		tokio::fs::write(
+			self.storage_path
+				.join(format!("{}_firing_alerts.json", network_id)),
+			json,
+		)
+		.await
+		.map_err(|e| anyhow::anyhow!("Failed to save firing alerts: {}", e))?;
+		Ok(())
+	}
+
+	/// Retrieves the dedup keys from the "dedup_keys.json" file, which maps the dedup keys to
+	/// the time their cooldown expires at
+	async fn get_dedup_keys(&self) -> Result<HashMap<String, DateTime<Utc>>, anyhow::Error> {
+		let _guard = self.dedup_keys_lock.lock().await;
+		let now = Utc::now();
+		Ok(self
+			.read_dedup_keys()
+			.await?
+			.into_iter()
+			.filter(|(_, expires_at)| *expires_at > now)
+			.collect())
+	}
+
+	/// Saves the dedup key to the "dedup_keys.json" file
+	///
+	/// The file is replaced atomically, so that it isn't left truncated if the process stops
+	/// while writing it.
+	async fn save_dedup_key(
+		&self,
+		dedup_key: &str,
+		expires_at: DateTime<Utc>,
+	) -> Result<(), anyhow::Error> {
+		let _guard = self.dedup_keys_lock.lock().await;
+		let now = Utc::now();
+		let mut keys = self.read_dedup_keys().await?;
+		keys.insert(dedup_key.to_string(), expires_at);
+		keys.retain(|_, expires_at| *expires_at > now);
+		if keys.len() > MAX_DEDUP_KEYS {
+			// Keeps the keys expiring last
+			let mut entries: Vec<_> = keys.into_iter().collect();
+			entries.sort_by_key(|(_, expires_at)| std::cmp::Reverse(*expires_at));
+			entries.truncate(MAX_DEDUP_KEYS);
+			keys = entries.into_iter().collect();
+		}
+
+		let json = serde_json::to_string(&keys)
+			.map_err(|e| anyhow::anyhow!("Failed to serialize dedup keys: {}", e))?;
+		write_file_atomically(&self.storage_path.join("dedup_keys.json"), json.as_bytes())
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to save dedup keys: {}", e))?;
+		Ok(())
+	}
+
+	/// Retrieves the balances from a network-specific file
+	///
+	/// The file is named "{network_id}_balances.json" and maps monitor names to the balances of
+	/// their addresses
+	async fn get_balances(&self, network_id: &str) -> Result<CachedBalances, anyhow::Error> {
+		let _guard = self.balances_lock.lock().await;
+		self.read_balances(network_id).await
+	}
+
+	/// Saves the balances to a network-specific file
+	async fn save_balances(
+		&self,
+		network_id: &str,
+		balances: &CachedBalances,
+	) -> Result<(), anyhow::Error> {
+		let _guard = self.balances_lock.lock().await;
+		let mut saved = self.read_balances(network_id).await?;
+		for (monitor_name, monitor_balances) in balances {
+			saved
+				.entry(monitor_name.clone())
+				.or_default()
+				.extend(monitor_balances.clone());
+		}
+
+		let json = serde_json::to_string(&saved)
+			.map_err(|e| anyhow::anyhow!("Failed to serialize balances: {}", e))?;
+		tokio::fs::write(
+			self.storage_path
+				.join(format!("{}_balances.json", network_id)),
+			json,
+		)
+		.await
+		.map_err(|e| anyhow::anyhow!("Failed to save balances: {}", e))?;
+		Ok(())
+	}
+}
+
+/// Writes a file through a temporary file renamed over it, so that the file is either left
+/// unchanged or fully written
+async fn write_file_atomically(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+	let mut temp_path = path.as_os_str().to_owned();
+	temp_path.push(".tmp");
+	tokio::fs::write(&temp_path, contents).await?;
+	tokio::fs::rename(&temp_path, path).await
+}
+
+impl FileBlockStorage {
+	/// Reads the firing alerts of a network by monitor name, expecting the caller to hold the
+	/// lock of the firing alert files
+	async fn read_firing_alerts(
+		&self,
+		network_id: &str,
+	) -> Result<BTreeMap<String, MonitorMatch>, anyhow::Error> {
+		let file_path = self
+			.storage_path
+			.join(format!("{}_firing_alerts.json", network_id));
+		if !file_path.exists() {
+			return Ok(BTreeMap::new());
+		}
+
+		let content = tokio::fs::read_to_string(file_path)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read firing alerts: {}", e))?;
+		serde_json::from_str(&content)
+			.map_err(|e| anyhow::anyhow!("Failed to parse firing alerts: {}", e))
+	}
+
+	/// Reads the dedup keys, expecting the caller to hold the lock of the dedup key file
+	async fn read_dedup_keys(&self) -> Result<BTreeMap<String, DateTime<Utc>>, anyhow::Error> {
+		let file_path = self.storage_path.join("dedup_keys.json");
+		if !file_path.exists() {
+			return Ok(BTreeMap::new());
+		}
+
+		let content = tokio::fs::read_to_string(file_path)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read dedup keys: {}", e))?;
+		serde_json::from_str(&content)
+			.map_err(|e| anyhow::anyhow!("Failed to parse dedup keys: {}", e))
+	}
+
+	/// Reads the balances of a network, expecting the caller to hold the lock of the balance
+	/// files
+	async fn read_balances(&self, network_id: &str) -> Result<CachedBalances, anyhow::Error> {
+		let file_path = self
+			.storage_path
+			.join(format!("{}_balances.json", network_id));
+		if !file_path.exists() {
+			return Ok(HashMap::new());
+		}
+
+		let content = tokio::fs::read_to_string(file_path)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read balances: {}", e))?;
+		serde_json::from_str(&content)
+			.map_err(|e| anyhow::anyhow!("Failed to parse balances: {}", e))
+	}
+}
+
+/// Redis-based implementation of block storage
+///
+/// Stores processing state under `{key_prefix}:{network_id}:*` keys, so several monitor
+/// replicas sharing a Redis instance resume from the same last processed block:
+/// - `last_block`: last processed block number
+/// - `last_block_time`: RFC 3339 time new blocks were last processed at
+/// - `blocks`: JSON array of the latest saved blocks
+/// - `missed_blocks`: sorted set of missed block numbers, scored by the Unix time they were
+///   recorded at
+/// - `processed_block:{block_hash}`: marker of a block whose triggers were executed, kept for
+///   7 days
+/// - `firing_alerts`: hash of the last match of each monitor whose alert is firing, by monitor
+///   name
+/// - `balances`: hash of the last balance read of each address watched by balance conditions,
+///   by `{monitor_name}:{address}`
+///
+/// The dedup keys in cooldown aren't specific to a network, and are stored under the
+/// `{key_prefix}:dedup_keys` sorted set, scored by the Unix time in milliseconds their cooldown
+/// expires at.
+#[derive(Clone)]
+pub struct RedisBlockStorage {
+	/// Connection to the Redis server, reconnecting automatically
+	connection: ConnectionManager,
+	/// Namespace of all keys written by this storage
+	key_prefix: String,
+	/// How long missed block entries are kept, or forever if None
+	missed_block_ttl: Option<Duration>,
+}
+
+impl RedisBlockStorage {
+	/// Creates a new Redis-based block storage instance
+	///
+	/// # Arguments
+	/// * `redis_url` - Redis connection URL (e.g. `redis://127.0.0.1:6379`)
+	/// * `key_prefix` - Namespace of the keys written by this storage
+	///
+	/// # Returns
+	/// * `Result<Self, anyhow::Error>` - Storage instance or connection error
+	pub async fn new(redis_url: &str, key_prefix: &str) -> Result<Self, anyhow::Error> {
+		let client = redis::Client::open(redis_url)
+			.map_err(|e| anyhow::anyhow!("Failed to parse Redis URL: {}", e))?;
+		let connection = ConnectionManager::new(client)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to connect to Redis: {}", e))?;
+
+		Ok(RedisBlockStorage {
+			connection,
+			key_prefix: key_prefix.to_string(),
+			missed_block_ttl: None,
+		})
+	}
+
+	/// Expires missed block entries once they are older than `ttl`
+	pub fn with_missed_block_ttl(mut self, ttl: Duration) -> Self {
+		self.missed_block_ttl = Some(ttl);
+		self
+	}
+
+	/// Retrieves the missed block numbers recorded for a network, in ascending order
+	///
+	/// # Arguments
+	/// * `network_id` - Unique identifier for the network
+	///
+	/// # Returns
+	/// * `Result<Vec<u64>, anyhow::Error>` - Missed block numbers that haven't expired
+	pub async fn get_missed_blocks(&self, network_id: &str) -> Result<Vec<u64>, anyhow::Error> {
+		let key = self.key(network_id, "missed_blocks");
+		let min_score = match self.missed_block_ttl {
+			Some(ttl) => Self::now().saturating_sub(ttl.as_secs()).to_string(),
+			None => "-inf".to_string(),
+		};
+
+		let mut blocks: Vec<u64> = self
+			.connection
+			.clone()
+			.zrangebyscore(&key, min_score, "+inf")
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read missed blocks: {}", e))?;
+		blocks.sort_unstable();
+		Ok(blocks)
+	}
+
+	/// Builds the key of a network-specific entry
+	fn key(&self, network_id: &str, name: &str) -> String {
+		format!("{}:{}:{}", self.key_prefix, network_id, name)
+	}
+
+	/// Builds the key of the dedup keys in cooldown
+	fn dedup_keys_key(&self) -> String {
+		format!("{}:dedup_keys", self.key_prefix)
+	}
+
+	/// Current Unix time in seconds
+	fn now() -> u64 {
+		chrono::Utc::now().timestamp().max(0) as u64
+	}
+}
+
+#[async_trait]
+impl BlockStorage for RedisBlockStorage {
+	/// Retrieves the last processed block from the network's `last_block` key
+	async fn get_last_processed_block(
+		&self,
+		network_id: &str,
+	) -> Result<Option<u64>, anyhow::Error> {
+		let value: Option<String> = self
+			.connection
+			.clone()
+			.get(self.key(network_id, "last_block"))
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read last processed block: {}", e))?;
+
+		value
+			.map(|value| {
+				value
+					.trim()
+					.parse::<u64>()
+					.map_err(|e| anyhow::anyhow!("Failed to parse last processed block: {}", e))
+			})
+			.transpose()
+	}
+
+	/// Saves the last processed block to the network's `last_block` key
+	///
+	/// # Note
+	/// Overwrites any existing last block for the network
+	async fn save_last_processed_block(
+		&self,
+		network_id: &str,
+		block: u64,
+	) -> Result<(), anyhow::Error> {
+		self.connection
+			.clone()
+			.set::<_, _, ()>(self.key(network_id, "last_block"), block)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to save last processed block: {}", e))
+	}
+
+	/// Retrieves the last processed time from the network's `last_block_time` key
+	async fn get_last_processed_time(
+		&self,
+		network_id: &str,
+	) -> Result<Option<DateTime<Utc>>, anyhow::Error> {
+		let value: Option<String> = self
+			.connection
+			.clone()
+			.get(self.key(network_id, "last_block_time"))
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read last processed time: {}", e))?;
+
+		value
+			.map(|value| {
+				DateTime::parse_from_rfc3339(value.trim())
+					.map(|time| time.with_timezone(&Utc))
+					.map_err(|e| anyhow::anyhow!("Failed to parse last processed time: {}", e))
+			})
+			.transpose()
+	}
+
+	/// Saves the last processed time to the network's `last_block_time` key
+	async fn save_last_processed_time(
+		&self,
+		network_id: &str,
+		time: DateTime<Utc>,
+	) -> Result<(), anyhow::Error> {
+		self.connection
+			.clone()
+			.set::<_, _, ()>(self.key(network_id, "last_block_time"), time.to_rfc3339())
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to save last processed time: {}", e))
+	}
+
+	/// Saves blocks as a JSON array to the network's `blocks` key
+	///
+	/// # Note
+	/// Replaces any previously saved blocks for the network
+	async fn save_blocks(
+		&self,
+		network_slug: &str,
+		blocks: &[BlockType],
+	) -> Result<(), anyhow::Error> {
+		let json = serde_json::to_string(blocks)
+			.map_err(|e| anyhow::anyhow!("Failed to serialize blocks: {}", e))?;
+		self.connection
+			.clone()
+			.set::<_, _, ()>(self.key(network_slug, "blocks"), json)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to save blocks: {}", e))
+	}
+
+	/// Deletes the saved blocks of a network
+	async fn delete_blocks(&self, network_slug: &str) -> Result<(), anyhow::Error> {
+		self.connection
+			.clone()
+			.del::<_, ()>(self.key(network_slug, "blocks"))
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to delete blocks: {}", e))
+	}
+
+	/// Adds missed blocks to the network's `missed_blocks` sorted set
+	///
+	/// # Note
+	/// When a TTL is configured, expired entries are removed in the same transaction and the
+	/// whole set expires if no block is missed for the duration of the TTL.
+	async fn save_missed_blocks(
+		&self,
+		network_id: &str,
+		blocks: &[u64],
+	) -> Result<(), anyhow::Error> {
+		if blocks.is_empty() {
+			return Ok(());
+		}
+
+		let key = self.key(network_id, "missed_blocks");
+		let now = Self::now();
+		let members: Vec<(u64, u64)> = blocks.iter().map(|block| (now, *block)).collect();
+
+		let mut pipeline = redis::pipe();
+		pipeline.atomic().zadd_multiple(&key, &members).ignore();
+		if let Some(ttl) = self.missed_block_ttl {
+			// Exclusive bound, matching the entries get_missed_blocks still returns
+			let expired_before = format!("({}", now.saturating_sub(ttl.as_secs()));
+			pipeline
+				.zrembyscore(&key, "-inf", expired_before)
+				.ignore()
+				.expire(&key, ttl.as_secs().max(1) as i64)
+				.ignore();
+		}
+
+		let mut connection = self.connection.clone();
+		let _: () = pipeline
+			.query_async(&mut connection)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to save missed blocks: {}", e))?;
+		Ok(())
+	}
+
+	/// Sets the block's `processed_block:{block_hash}` key unless it exists
+	///
+	/// # Note
+	/// The key is set atomically, so a block processed by several replicas is reported as new
+	/// to only one of them.
+	async fn mark_block_processed(
+		&self,
+		network_id: &str,
+		block_hash: &str,
+	) -> Result<bool, anyhow::Error> {
+		let mut connection = self.connection.clone();
+		let set: Option<String> = redis::cmd("SET")
+			.arg(self.key(network_id, &format!("processed_block:{}", block_hash)))
+			.arg(1)
+			.arg("NX")
+			.arg("EX")
+			.arg(REDIS_PROCESSED_BLOCK_TTL.as_secs())
+			.query_async(&mut connection)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to record processed block: {}", e))?;
+		Ok(set.is_some())
+	}
+
+	/// Retrieves the firing alerts from the network's `firing_alerts` hash
+	async fn get_firing_alerts(
+		&self,
+		network_id: &str,
+	) -> Result<Vec<MonitorMatch>, anyhow::Error> {
+		let values: Vec<String> = self
+			.connection
+			.clone()
+			.hvals(self.key(network_id, "firing_alerts"))
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read firing alerts: {}", e))?;
+
+		values
+			.iter()
+			.map(|value| {
+				serde_json::from_str(value)
+					.map_err(|e| anyhow::anyhow!("Failed to parse firing alert: {}", e))
+			})
+			.collect()
+	}
+
+	/// Sets or deletes the monitor's field of the network's `firing_alerts` hash
+	async fn save_alert_state(
+		&self,
+		network_id: &str,
+		monitor_name: &str,
+		last_match: Option<&MonitorMatch>,
+	) -> Result<(), anyhow::Error> {
+		let key = self.key(network_id, "firing_alerts");
+		let mut connection = self.connection.clone();
+		match last_match {
+			Some(last_match) => {
+				let json = serde_json::to_string(last_match)
+					.map_err(|e| anyhow::anyhow!("Failed to serialize firing alert: {}", e))?;
+				connection
+					.hset::<_, _, _, ()>(key, monitor_name, json)
+					.await
+			}
+			None => connection.hdel::<_, _, ()>(key, monitor_name).await,
+		}
+		.map_err(|e| anyhow::anyhow!("Failed to save alert state: {}", e))
+	}
+
+	/// Retrieves the dedup keys from the `dedup_keys` sorted set
+	async fn get_dedup_keys(&self) -> Result<HashMap<String, DateTime<Utc>>, anyhow::Error> {
+		let now = Utc::now().timestamp_millis();
+		let keys: Vec<(String, f64)> = self
+			.connection
+			.clone()
+			.zrangebyscore_withscores(self.dedup_keys_key(), format!("({}", now), "+inf")
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read dedup keys: {}", e))?;
+
+		Ok(keys
+			.into_iter()
+			.filter_map(|(dedup_key, expires_at)| {
+				DateTime::from_timestamp_millis(expires_at as i64)
+					.map(|expires_at| (dedup_key, expires_at))
+			})
+			.collect())
+	}
+
+	/// Adds the dedup key to the `dedup_keys` sorted set
+	///
+	/// # Note
+	/// Expired keys and the keys beyond [`MAX_DEDUP_KEYS`] are removed in the same
+	/// transaction.
+	async fn save_dedup_key(
+		&self,
+		dedup_key: &str,
+		expires_at: DateTime<Utc>,
+	) -> Result<(), anyhow::Error> {
+		let key = self.dedup_keys_key();
+		let now = Utc::now().timestamp_millis();
+		let expires_at = expires_at.timestamp_millis();
+
+		let mut pipeline = redis::pipe();
+		pipeline
+			.atomic()
+			.zadd(&key, dedup_key, expires_at)
+			.ignore()
+			.zrembyscore(&key, "-inf", now)
+			.ignore()
+			.zremrangebyrank(&key, 0, -(MAX_DEDUP_KEYS as isize) - 1)
+			.ignore();
+
+		let mut connection = self.connection.clone();
+		let _: () = pipeline
+			.query_async(&mut connection)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to save dedup key: {}", e))?;
+		Ok(())
+	}
+
+	/// Retrieves the balances from the network's `balances` hash
+	async fn get_balances(&self, network_id: &str) -> Result<CachedBalances, anyhow::Error> {
+		let fields: HashMap<String, String> = self
+			.connection
+			.clone()
+			.hgetall(self.key(network_id, "balances"))
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read balances: {}", e))?;
+
+		let mut balances = CachedBalances::new();
+		for (field, value) in fields {
+			// Addresses don't contain colons, unlike monitor names may
+			let Some((monitor_name, address)) = field.rsplit_once(':') else {
+				continue;
+			};
+			let balance = serde_json::from_str(&value)
+				.map_err(|e| anyhow::anyhow!("Failed to parse balance: {}", e))?;
+			balances
+				.entry(monitor_name.to_string())
+				.or_default()
+				.insert(address.to_string(), balance);
+		}
+		Ok(balances)
+	}
+
+	/// Sets the fields of the balances in the network's `balances` hash
+	async fn save_balances(
+		&self,
+		network_id: &str,
+		balances: &CachedBalances,
+	) -> Result<(), anyhow::Error> {
+		let mut fields = Vec::new();
+		for (monitor_name, monitor_balances) in balances {
+			for (address, balance) in monitor_balances {
+				let json = serde_json::to_string(balance)
+					.map_err(|e| anyhow::anyhow!("Failed to serialize balance: {}", e))?;
+				fields.push((format!("{}:{}", monitor_name, address), json));
+			}
+		}
+		if fields.is_empty() {
+			return Ok(());
+		}
+
+		self.connection
+			.clone()
+			.hset_multiple::<_, _, _, ()>(self.key(network_id, "balances"), &fields)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to save balances: {}", e))
+	}
+}
+
+/// Block storage keeping the processing state of one cron schedule of a network apart
+///
+/// Wraps another storage and stores everything under `{network_id}_{schedule_id}`, where
+/// `schedule_id` is derived from the cron expression. Jobs running on a monitor's own schedule
+/// use it so that they resume from their own last processed block instead of the network's.
+#[derive(Clone)]
+pub struct ScheduledBlockStorage<S> {
+	/// Storage the processing state is written to
+	inner: Arc<S>,
+	/// Identifier of the schedule, appended to network identifiers
+	schedule_id: String,
+}
+
+impl<S: BlockStorage> ScheduledBlockStorage<S> {
+	/// Creates a storage for the processing state of a cron schedule
+	///
+	/// # Arguments
+	/// * `inner` - Storage the processing state is written to
+	/// * `cron_schedule` - Cron expression of the schedule
+	pub fn new(inner: Arc<S>, cron_schedule: &str) -> Self {
+		let digest = Sha256::digest(cron_schedule.trim().as_bytes());
+		Self {
+			inner,
+			schedule_id: hex::encode(&digest[..4]),
+		}
+	}
+
+	fn key(&self, network_id: &str) -> String {
+		format!("{}_{}", network_id, self.schedule_id)
+	}
+}
+
+#[async_trait]
+impl<S: BlockStorage> BlockStorage for ScheduledBlockStorage<S> {
+	async fn get_last_processed_block(
+		&self,
+		network_id: &str,
+	) -> Result<Option<u64>, anyhow::Error> {
+		self.inner
+			.get_last_processed_block(&self.key(network_id))
+			.await
+	}
+
+	async fn save_last_processed_block(
+		&self,
+		network_id: &str,
+		block: u64,
+	) -> Result<(), anyhow::Error> {
+		self.inner
+			.save_last_processed_block(&self.key(network_id), block)
+			.await
+	}
+
+	async fn get_last_processed_time(
+		&self,
+		network_id: &str,
+	) -> Result<Option<DateTime<Utc>>, anyhow::Error> {
+		self.inner
+			.get_last_processed_time(&self.key(network_id))
+			.await
+	}
+
+	async fn save_last_processed_time(
+		&self,
+		network_id: &str,
+		time: DateTime<Utc>,
+	) -> Result<(), anyhow::Error> {
+		self.inner
+			.save_last_processed_time(&self.key(network_id), time)
+			.await
+	}
+
+	async fn save_blocks(
+		&self,
+		network_id: &str,
+		blocks: &[BlockType],
+	) -> Result<(), anyhow::Error> {
+		self.inner.save_blocks(&self.key(network_id), blocks).await
+	}
+
+	async fn delete_blocks(&self, network_id: &str) -> Result<(), anyhow::Error> {
+		self.inner.delete_blocks(&self.key(network_id)).await
+	}
+
+	async fn save_missed_blocks(
+		&self,
+		network_id: &str,
+		blocks: &[u64],
+	) -> Result<(), anyhow::Error> {
+		self.inner
+			.save_missed_blocks(&self.key(network_id), blocks)
+			.await
+	}
+
+	async fn mark_block_processed(
+		&self,
+		network_id: &str,
+		block_hash: &str,
+	) -> Result<bool, anyhow::Error> {
+		self.inner
+			.mark_block_processed(&self.key(network_id), block_hash)
+			.await
+	}
+
+	async fn get_firing_alerts(
+		&self,
+		network_id: &str,
+	) -> Result<Vec<MonitorMatch>, anyhow::Error> {
+		self.inner.get_firing_alerts(&self.key(network_id)).await
+	}
+
+	async fn save_alert_state(
+		&self,
+		network_id: &str,
+		monitor_name: &str,
+		last_match: Option<&MonitorMatch>,
+	) -> Result<(), anyhow::Error> {
+		self.inner
+			.save_alert_state(&self.key(network_id), monitor_name, last_match)
+			.await
+	}
+
+	async fn get_dedup_keys(&self) -> Result<HashMap<String, DateTime<Utc>>, anyhow::Error> {
+		self.inner.get_dedup_keys().await
+	}
+
+	async fn save_dedup_key(
+		&self,
+		dedup_key: &str,
+		expires_at: DateTime<Utc>,
+	) -> Result<(), anyhow::Error> {
+		self.inner.save_dedup_key(dedup_key, expires_at).await
+	}
+
+	async fn get_balances(&self, network_id: &str) -> Result<CachedBalances, anyhow::Error> {
+		self.inner.get_balances(&self.key(network_id)).await
+	}
+
+	async fn save_balances(
+		&self,
+		network_id: &str,
+		balances: &CachedBalances,
+	) -> Result<(), anyhow::Error> {
+		self.inner
+			.save_balances(&self.key(network_id), balances)
+			.await
+	}
 }
 
 #[cfg(test)]
@@ -323,6 +1432,36 @@ mod tests {
 		}
 	}
 
+	#[tokio::test]
+	async fn test_last_processed_time() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = FileBlockStorage::new(temp_dir.path().to_path_buf());
+
+		// Test 1: Non-existent file
+		let result = storage.get_last_processed_time("test").await;
+		assert_eq!(result.unwrap(), None);
+
+		// Test 2: Saved time round-trips
+		let time = DateTime::parse_from_rfc3339("2024-01-01T12:00:00Z")
+			.unwrap()
+			.with_timezone(&Utc);
+		storage
+			.save_last_processed_time("test", time)
+			.await
+			.unwrap();
+		let result = storage.get_last_processed_time("test").await;
+		assert_eq!(result.unwrap(), Some(time));
+
+		// Test 3: Invalid content
+		let invalid_file = temp_dir.path().join("invalid_last_block_time.txt");
+		tokio::fs::write(&invalid_file, "not a time").await.unwrap();
+		let result = storage.get_last_processed_time("invalid").await;
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("Failed to parse last processed time"));
+	}
+
 	#[tokio::test]
 	async fn test_save_blocks() {
 		let temp_dir = tempfile::tempdir().unwrap();
@@ -444,4 +1583,165 @@ mod tests {
 			assert!(err.to_string().contains("Permission denied"));
 		}
 	}
+
+	#[tokio::test]
+	async fn test_mark_block_processed() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = FileBlockStorage::new(temp_dir.path().to_path_buf());
+
+		assert!(storage.mark_block_processed("test", "0x01").await.unwrap());
+		assert!(storage.mark_block_processed("test", "0x02").await.unwrap());
+		assert!(!storage.mark_block_processed("test", "0x01").await.unwrap());
+		// Hashes are recorded per network
+		assert!(storage.mark_block_processed("other", "0x01").await.unwrap());
+
+		let content = tokio::fs::read_to_string(temp_dir.path().join("test_processed_blocks.txt"))
+			.await
+			.unwrap();
+		assert_eq!(content, "0x01\n0x02\n");
+	}
+
+	#[tokio::test]
+	async fn test_firing_alerts() {
+		use crate::{
+			models::{EVMMonitorMatch, MatchConditions},
+			utils::tests::evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+		};
+
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+		let monitor_match = |name: &str| {
+			MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+				monitor: MonitorBuilder::new().name(name).build(),
+				transaction: TransactionBuilder::new().build(),
+				receipt: None,
+				logs: None,
+				network_slug: "test".to_string(),
+				matched_on: MatchConditions::default(),
+				matched_on_args: None,
+				confirmation_state: Default::default(),
+				severity: Default::default(),
+				sender_verified: None,
+			}))
+		};
+		assert!(storage.get_firing_alerts("test").await.unwrap().is_empty());
+
+		let writer = create_alert_state_writer(storage.clone());
+		for name in ["low_balance", "paused_contract"] {
+			writer(
+				"test".to_string(),
+				name.to_string(),
+				Some(monitor_match(name)),
+			)
+			.await
+			.unwrap();
+		}
+		writer("test".to_string(), "low_balance".to_string(), None)
+			.await
+			.unwrap();
+
+		let alerts = storage.get_firing_alerts("test").await.unwrap();
+		assert_eq!(alerts.len(), 1);
+		match &alerts[0] {
+			MonitorMatch::EVM(evm_match) => assert_eq!(evm_match.monitor.name, "paused_contract"),
+			_ => panic!("Expected EVM match"),
+		}
+		// Alerts are recorded per network
+		assert!(storage.get_firing_alerts("other").await.unwrap().is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_dedup_keys() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+		assert!(storage.get_dedup_keys().await.unwrap().is_empty());
+
+		let now = Utc::now();
+		let writer = create_dedup_key_writer(storage.clone());
+		writer("transfers:0xabc".to_string(), now + Duration::from_secs(60))
+			.await
+			.unwrap();
+		writer("transfers:0xdef".to_string(), now - Duration::from_secs(1))
+			.await
+			.unwrap();
+
+		// Expired keys aren't returned
+		let keys = storage.get_dedup_keys().await.unwrap();
+		assert_eq!(
+			keys,
+			HashMap::from([("transfers:0xabc".to_string(), now + Duration::from_secs(60))])
+		);
+		// The temporary file is renamed over the keys
+		assert!(!temp_dir.path().join("dedup_keys.json.tmp").exists());
+	}
+
+	#[tokio::test]
+	async fn test_balances() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+		assert!(storage.get_balances("test").await.unwrap().is_empty());
+
+		let balance = |block_number: u64, balance: &str| CachedBalance {
+			block_number,
+			balance: balance.to_string(),
+		};
+		let writer = create_balance_writer(storage.clone());
+		writer(
+			"test".to_string(),
+			HashMap::from([(
+				"low_balance".to_string(),
+				HashMap::from([
+					("0xabc".to_string(), balance(100, "5000")),
+					("0xdef".to_string(), balance(100, "7000")),
+				]),
+			)]),
+		)
+		.await
+		.unwrap();
+		writer(
+			"test".to_string(),
+			HashMap::from([(
+				"low_balance".to_string(),
+				HashMap::from([("0xabc".to_string(), balance(101, "4000"))]),
+			)]),
+		)
+		.await
+		.unwrap();
+
+		// Balances are replaced per address
+		let balances = storage.get_balances("test").await.unwrap();
+		assert_eq!(
+			balances["low_balance"],
+			HashMap::from([
+				("0xabc".to_string(), balance(101, "4000")),
+				("0xdef".to_string(), balance(100, "7000")),
+			])
+		);
+		// Balances are recorded per network
+		assert!(storage.get_balances("other").await.unwrap().is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_scheduled_storage_keeps_last_processed_block_apart() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+		let hourly = ScheduledBlockStorage::new(storage.clone(), "0 0 * * * *");
+		let daily = ScheduledBlockStorage::new(storage.clone(), "0 0 0 * * *");
+
+		storage
+			.save_last_processed_block("test", 300)
+			.await
+			.unwrap();
+		hourly.save_last_processed_block("test", 100).await.unwrap();
+
+		assert_eq!(
+			storage.get_last_processed_block("test").await.unwrap(),
+			Some(300)
+		);
+		assert_eq!(
+			hourly.get_last_processed_block("test").await.unwrap(),
+			Some(100)
+		);
+		assert_eq!(daily.get_last_processed_block("test").await.unwrap(), None);
+	}
 }
@@ -0,0 +1,170 @@
+//! Supervision of network watchers.
+//!
+//! A network watcher whose RPC client can't be created, whose scheduler fails to start, or whose
+//! jobs fail too many runs in a row is restarted with an exponential backoff, see
+//! [`BlockWatcherService::supervise_network_watcher`]. Restarts are counted by the
+//! `network_watcher_restarts_total` metric, and the network is given up on after
+//! [`WatcherRestartPolicy::max_attempts`] restarts without a successful run.
+//!
+//! [`BlockWatcherService::supervise_network_watcher`]: super::BlockWatcherService::supervise_network_watcher
+
+use std::{
+	sync::atomic::{AtomicBool, AtomicU32, Ordering},
+	time::Duration,
+};
+use tokio::sync::Notify;
+
+/// Policy for restarting network watchers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatcherRestartPolicy {
+	/// Maximum number of restarts in a row, without any successful run in between, before the
+	/// network is given up on
+	pub max_attempts: u32,
+	/// Backoff before the first restart, doubled on every further restart
+	pub initial_backoff: Duration,
+	/// Maximum backoff between two restarts
+	pub max_backoff: Duration,
+	/// Number of failed runs in a row after which a running watcher is restarted
+	pub max_consecutive_failures: u32,
+}
+
+impl Default for WatcherRestartPolicy {
+	fn default() -> Self {
+		Self {
+			max_attempts: 5,
+			initial_backoff: Duration::from_secs(1),
+			max_backoff: Duration::from_secs(60),
+			max_consecutive_failures: 5,
+		}
+	}
+}
+
+impl WatcherRestartPolicy {
+	/// Returns the backoff before a restart
+	///
+	/// # Arguments
+	/// * `attempt` - Number of the restart, starting at 1
+	pub fn backoff(&self, attempt: u32) -> Duration {
+		let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+		self.initial_backoff
+			.saturating_mul(factor)
+			.min(self.max_backoff)
+	}
+}
+
+/// Health of the jobs of a running network watcher, shared with its supervisor
+#[derive(Debug)]
+pub struct WatcherHealth {
+	/// Number of failed runs in a row after which the watcher is reported unhealthy
+	max_consecutive_failures: u32,
+	/// Number of failed runs since the last successful one
+	consecutive_failures: AtomicU32,
+	/// Whether any run succeeded since the watcher started
+	succeeded: AtomicBool,
+	/// Whether the watcher was stopped
+	stopped: AtomicBool,
+	/// Wakes the supervisor when the watcher becomes unhealthy or is stopped
+	notify: Notify,
+}
+
+impl WatcherHealth {
+	/// Creates the health of a watcher that just started
+	///
+	/// # Arguments
+	/// * `max_consecutive_failures` - Number of failed runs in a row after which the watcher is
+	///   reported unhealthy
+	pub fn new(max_consecutive_failures: u32) -> Self {
+		Self {
+			max_consecutive_failures,
+			consecutive_failures: AtomicU32::new(0),
+			succeeded: AtomicBool::new(false),
+			stopped: AtomicBool::new(false),
+			notify: Notify::new(),
+		}
+	}
+
+	/// Records a successful run of one of the watcher's jobs
+	pub fn record_success(&self) {
+		self.consecutive_failures.store(0, Ordering::SeqCst);
+		self.succeeded.store(true, Ordering::SeqCst);
+	}
+
+	/// Records a failed run of one of the watcher's jobs
+	///
+	/// The supervisor is woken once the number of failed runs in a row reaches the threshold.
+	pub fn record_failure(&self) {
+		let failures = self.consecutive_failures.fetch_add(1, Ordering::SeqCst) + 1;
+		if failures == self.max_consecutive_failures {
+			self.notify.notify_one();
+		}
+	}
+
+	/// Records that the watcher was stopped, which ends its supervision
+	pub fn record_stop(&self) {
+		self.stopped.store(true, Ordering::SeqCst);
+		self.notify.notify_one();
+	}
+
+	/// Returns whether any run succeeded since the watcher started
+	pub fn has_succeeded(&self) -> bool {
+		self.succeeded.load(Ordering::SeqCst)
+	}
+
+	/// Waits until the watcher becomes unhealthy or is stopped
+	///
+	/// # Returns
+	/// * `bool` - `true` if the watcher should be restarted, `false` if it was stopped
+	pub async fn wait_unhealthy(&self) -> bool {
+		self.notify.notified().await;
+		!self.stopped.load(Ordering::SeqCst)
+	}
+}
+
+impl Default for WatcherHealth {
+	fn default() -> Self {
+		Self::new(WatcherRestartPolicy::default().max_consecutive_failures)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_backoff_doubles_up_to_max() {
+		let policy = WatcherRestartPolicy {
+			initial_backoff: Duration::from_secs(1),
+			max_backoff: Duration::from_secs(10),
+			..Default::default()
+		};
+
+		assert_eq!(policy.backoff(1), Duration::from_secs(1));
+		assert_eq!(policy.backoff(2), Duration::from_secs(2));
+		assert_eq!(policy.backoff(4), Duration::from_secs(8));
+		assert_eq!(policy.backoff(5), Duration::from_secs(10));
+		assert_eq!(policy.backoff(100), Duration::from_secs(10));
+	}
+
+	#[tokio::test]
+	async fn test_health_reports_consecutive_failures() {
+		let health = WatcherHealth::new(2);
+
+		health.record_failure();
+		health.record_success();
+		health.record_failure();
+		assert_eq!(health.consecutive_failures.load(Ordering::SeqCst), 1);
+
+		health.record_failure();
+		assert!(health.wait_unhealthy().await);
+		assert!(health.has_succeeded());
+	}
+
+	#[tokio::test]
+	async fn test_health_reports_stop() {
+		let health = WatcherHealth::new(2);
+
+		health.record_stop();
+		assert!(!health.wait_unhealthy().await);
+		assert!(!health.has_succeeded());
+	}
+}
@@ -2,9 +2,10 @@
 //!
 //! This module provides tools for tracking processed blocks across different networks
 //! and identifying potential issues such as:
-//! - Missed blocks
+//! - Missed blocks, and missed blocks processed afterwards
 //! - Out-of-order block processing
 //! - Duplicate block processing
+//! - Chain reorganizations of already processed blocks
 //!
 //! The primary component is the [`BlockTracker`] which maintains a history of
 //! recently processed blocks and can optionally persist information about missed
@@ -12,12 +13,18 @@
 
 use async_trait::async_trait;
 use std::{
-	collections::{HashMap, HashSet, VecDeque},
+	collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
 	sync::Arc,
 };
 use tokio::sync::Mutex;
 
-use crate::models::{BlockType, Network};
+use crate::{
+	models::{BlockType, Network},
+	utils::{
+		metrics::{BLOCKS_MISSED_TOTAL, BLOCKS_RECOVERED_TOTAL, LAST_PROCESSED_BLOCK},
+		system_clock, SharedClock,
+	},
+};
 
 /// Result of checking a processed block for issues
 #[derive(Debug, Clone, PartialEq)]
@@ -57,6 +64,27 @@ pub trait BlockTrackerTrait {
 	/// This should be called at the start of each process_new_blocks execution to
 	/// synchronize expected_next with the start_block.
 	async fn reset_expected_next(&self, network: &Network, start_block: u64);
+
+	/// Records the hash of a processed block, used to detect reorgs on the next poll
+	///
+	/// Trackers that don't keep block hashes can rely on the default implementations of this
+	/// method, `get_block_hashes` and `rollback`, which disable reorg detection.
+	async fn record_block_hash(&self, _network: &Network, _block_number: u64, _hash: String) {}
+
+	/// Returns the recorded block hashes of a network, ordered by block number
+	async fn get_block_hashes(&self, _network_slug: &str) -> Vec<(u64, String)> {
+		Vec::new()
+	}
+
+	/// Forgets processed blocks from `fork_point` onwards so they can be processed again
+	/// after a reorg without being reported as duplicates
+	async fn rollback(&self, _network: &Network, _fork_point: u64) {}
+
+	/// Returns the clock the blocks are processed with, which times the executions of the
+	/// block watchers and the last processed time of the networks in auto mode
+	fn clock(&self) -> SharedClock {
+		system_clock()
+	}
 }
 
 /// BlockTracker is responsible for monitoring the sequence of processed blocks
@@ -72,8 +100,32 @@ pub struct BlockTracker {
 	/// Expected next processed block number for each network
 	/// Key: network_slug, Value: Expected next block number
 	expected_next: Arc<Mutex<HashMap<String, u64>>>,
+	/// Hashes of the last N processed blocks for each network
+	/// Key: network_slug, Value: Map of block number to block hash
+	block_hashes: Arc<Mutex<HashMap<String, BTreeMap<u64, String>>>>,
+	/// The last N missed blocks of each network not processed since
+	/// Key: network_slug, Value: Set of block numbers
+	missed_blocks: Arc<Mutex<HashMap<String, BTreeSet<u64>>>>,
 	/// Maximum number of blocks to keep in history per network
 	history_size: usize,
+	/// Clock the blocks are processed with
+	clock: SharedClock,
+}
+
+impl BlockTracker {
+	/// Returns the maximum number of blocks kept in history per network
+	pub fn history_size(&self) -> usize {
+		self.history_size
+	}
+
+	/// Sets the clock the blocks are processed with, the system's by default
+	///
+	/// # Arguments
+	/// * `clock` - Clock replacing the system's
+	pub fn with_clock(mut self, clock: SharedClock) -> Self {
+		self.clock = clock;
+		self
+	}
 }
 
 #[async_trait]
@@ -92,7 +144,10 @@ impl BlockTrackerTrait for BlockTracker {
 		Self {
 			processed_history: Arc::new(Mutex::new(HashMap::new())),
 			expected_next: Arc::new(Mutex::new(HashMap::new())),
+			block_hashes: Arc::new(Mutex::new(HashMap::new())),
+			missed_blocks: Arc::new(Mutex::new(HashMap::new())),
 			history_size,
+			clock: system_clock(),
 		}
 	}
 
@@ -116,7 +171,7 @@ impl BlockTrackerTrait for BlockTracker {
 
 	async fn detect_missing_blocks(
 		&self,
-		_network: &Network,
+		network: &Network,
 		fetched_blocks: &[BlockType],
 	) -> Vec<u64> {
 		// Extract block numbers from fetched blocks
@@ -147,6 +202,20 @@ impl BlockTrackerTrait for BlockTracker {
 			.filter(|&num| !fetched_block_numbers.contains(&num))
 			.collect();
 
+		if !missed_blocks.is_empty() {
+			BLOCKS_MISSED_TOTAL
+				.with_label_values(&[&network.slug])
+				.inc_by(missed_blocks.len() as u64);
+
+			// Remember the missed blocks, so that processing them later counts as a recovery
+			let mut all_missed_blocks = self.missed_blocks.lock().await;
+			let network_missed_blocks = all_missed_blocks.entry(network.slug.clone()).or_default();
+			network_missed_blocks.extend(missed_blocks.iter().copied());
+			while network_missed_blocks.len() > self.history_size {
+				network_missed_blocks.pop_first();
+			}
+		}
+
 		missed_blocks
 	}
 
@@ -185,12 +254,26 @@ impl BlockTrackerTrait for BlockTracker {
 		// Always record the block (even if out of order, we still process it)
 		network_history.push_back(block_number);
 
+		if let Some(network_missed_blocks) = self.missed_blocks.lock().await.get_mut(&network.slug)
+		{
+			if network_missed_blocks.remove(&block_number) {
+				BLOCKS_RECOVERED_TOTAL
+					.with_label_values(&[&network.slug])
+					.inc();
+			}
+		}
+
 		// Only update expected_next when the block is in-order or ahead
 		// If it's out-of-order (behind), don't advance expected_next as we're still
 		// waiting for the missing blocks in between
 		if block_number >= *expected {
 			*expected = block_number + 1;
 		}
+		if let Some(last_block) = network_history.iter().max() {
+			LAST_PROCESSED_BLOCK
+				.with_label_values(&[&network.slug])
+				.set(*last_block as f64);
+		}
 
 		// Maintain history size
 		while network_history.len() > self.history_size {
@@ -217,11 +300,51 @@ impl BlockTrackerTrait for BlockTracker {
 			}
 		}
 	}
+
+	async fn record_block_hash(&self, network: &Network, block_number: u64, block_hash: String) {
+		let mut block_hashes = self.block_hashes.lock().await;
+		let network_hashes = block_hashes.entry(network.slug.clone()).or_default();
+
+		network_hashes.insert(block_number, block_hash);
+
+		// Maintain history size, keeping the most recent blocks
+		while network_hashes.len() > self.history_size {
+			network_hashes.pop_first();
+		}
+	}
+
+	async fn get_block_hashes(&self, network_slug: &str) -> Vec<(u64, String)> {
+		self.block_hashes
+			.lock()
+			.await
+			.get(network_slug)
+			.map(|hashes| {
+				hashes
+					.iter()
+					.map(|(number, hash)| (*number, hash.clone()))
+					.collect()
+			})
+			.unwrap_or_default()
+	}
+
+	async fn rollback(&self, network: &Network, fork_point: u64) {
+		if let Some(network_hashes) = self.block_hashes.lock().await.get_mut(&network.slug) {
+			network_hashes.retain(|&number, _| number < fork_point);
+		}
+		if let Some(network_history) = self.processed_history.lock().await.get_mut(&network.slug) {
+			network_history.retain(|&number| number < fork_point);
+		}
+	}
+
+	fn clock(&self) -> SharedClock {
+		self.clock.clone()
+	}
 }
 
 #[cfg(test)]
 mod tests {
-	use crate::utils::tests::network::NetworkBuilder;
+	use crate::{models::EVMBlock, utils::tests::network::NetworkBuilder};
+	use alloy::primitives::U64;
 
 	use super::*;
 
@@ -376,4 +499,82 @@ mod tests {
 		);
 		assert_eq!(tracker.get_last_block("test_network").await, Some(3));
 	}
+
+	#[tokio::test]
+	async fn test_record_block_hash_history_size() {
+		let tracker = BlockTracker::new(2);
+		let network = create_test_network("test-network", "test_network", true);
+
+		for number in 1..=3 {
+			tracker
+				.record_block_hash(&network, number, format!("0x{}", number))
+				.await;
+		}
+
+		assert_eq!(
+			tracker.get_block_hashes("test_network").await,
+			vec![(2, "0x2".to_string()), (3, "0x3".to_string())]
+		);
+		assert!(tracker.get_block_hashes("nonexistent").await.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_rollback_allows_reprocessing() {
+		let tracker = BlockTracker::new(5);
+		let network = create_test_network("test-network", "test_network", true);
+
+		for number in 1..=3 {
+			tracker.check_processed_block(&network, number).await;
+			tracker
+				.record_block_hash(&network, number, format!("0x{}", number))
+				.await;
+		}
+
+		tracker.rollback(&network, 2).await;
+		tracker.reset_expected_next(&network, 2).await;
+
+		assert_eq!(
+			tracker.get_block_hashes("test_network").await,
+			vec![(1, "0x1".to_string())]
+		);
+		assert_eq!(tracker.get_last_block("test_network").await, Some(1));
+		assert_eq!(
+			tracker.check_processed_block(&network, 2).await,
+			BlockCheckResult::Ok
+		);
+	}
+
+	#[tokio::test]
+	async fn test_missed_block_processed_later_is_recovered() {
+		let tracker = BlockTracker::new(5);
+		let network = create_test_network("test-network", "tracker_recovery_network", true);
+		let missed = BLOCKS_MISSED_TOTAL.with_label_values(&[&network.slug]);
+		let recovered = BLOCKS_RECOVERED_TOTAL.with_label_values(&[&network.slug]);
+		let last_processed = LAST_PROCESSED_BLOCK.with_label_values(&[&network.slug]);
+
+		// Block 101 is skipped
+		let blocks = [100, 102].map(|number| {
+			let mut block = EVMBlock::default();
+			block.0.number = Some(U64::from(number));
+			BlockType::EVM(Box::new(block))
+		});
+		assert_eq!(
+			tracker.detect_missing_blocks(&network, &blocks).await,
+			vec![101]
+		);
+		assert_eq!(missed.get(), 1);
+		assert_eq!(recovered.get(), 0);
+
+		tracker.check_processed_block(&network, 100).await;
+		tracker.check_processed_block(&network, 102).await;
+		assert_eq!(recovered.get(), 0);
+		assert_eq!(last_processed.get(), 102.0);
+
+		// Block 101 is processed later, once
+		tracker.check_processed_block(&network, 101).await;
+		tracker.check_processed_block(&network, 101).await;
+		assert_eq!(missed.get(), 1);
+		assert_eq!(recovered.get(), 1);
+		assert_eq!(last_processed.get(), 102.0);
+	}
 }
@@ -0,0 +1,576 @@
+//! Balance conditions of monitors.
+//!
+//! At every block, the native balance of each address monitored by a monitor with balance
+//! conditions is read: with `eth_getBalance` at the block on EVM, and with `getLedgerEntries`
+//! at the latest ledger on Stellar, where only accounts have a native balance. Each condition
+//! is evaluated over:
+//! - `address`: the monitored address
+//! - `balance`: its balance, in wei or stroops
+//! - `previous_balance`: its balance at the previous block processed for the monitor, or
+//!   `balance` when there is none
+//! - `balance_delta`: the change from `previous_balance` to `balance`, negative when the
+//!   balance decreased
+//!
+//! An address matching any condition produces a match without a transaction, so that balance
+//! changes are caught whatever caused them, e.g. internal transfers or block rewards. The
+//! previous balances are kept by a [`BalanceCache`], which persists them in a block storage once
+//! restored from it, so that the first block processed after a restart is compared with the
+//! last block processed before it.
+
+use alloy::primitives::{I256, U256};
+use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine as _};
+use serde_json::{json, Value};
+use std::{
+	collections::{BTreeMap, HashMap},
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+};
+use stellar_xdr::curr::{Limits, WriteXdr};
+
+use crate::{
+	models::{
+		BalanceCondition, BlockType, EVMConfirmationState, EVMMatchArguments, EVMMatchParamEntry,
+		EVMMonitorMatch, EVMTransaction, MatchConditions, MatchSeverity, Monitor, MonitorMatch,
+		Network, StellarMatchArguments, StellarMatchParamEntry, StellarMonitorMatch,
+		StellarTransaction, StellarTransactionInfo,
+	},
+	services::{
+		blockchain::BlockChainClient,
+		blockwatcher::{
+			create_balance_writer, BalanceWriter, BlockStorage, CachedBalance, CachedBalances,
+		},
+		filter::{
+			evm_helpers,
+			filters::evm::filter::without_contract_specs,
+			stellar_helpers::{get_account_balance_from_ledger_entry_data, get_account_ledger_key},
+			EVMBlockFilter, FilterError, RpcResults, StellarBlockFilter,
+		},
+	},
+};
+
+/// Previous balances of the addresses monitored by balance conditions
+#[derive(Default)]
+pub struct BalanceCache {
+	/// Last balance read of each address, by network slug, monitor name and address
+	balances: Mutex<HashMap<String, CachedBalances>>,
+	/// Writer persisting the balances, once restored
+	writer: Mutex<Option<BalanceWriter>>,
+}
+
+impl BalanceCache {
+	/// Creates a cache keeping the balances in memory only
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Restores the balances of networks persisted in a block storage, which then persists the
+	/// balances read
+	///
+	/// # Arguments
+	/// * `storage` - Storage the balances are persisted in
+	/// * `network_slugs` - Networks to restore the balances of
+	pub async fn restore<S: BlockStorage + 'static>(
+		&self,
+		storage: Arc<S>,
+		network_slugs: impl IntoIterator<Item = &String>,
+	) -> Result<(), anyhow::Error> {
+		for network_slug in network_slugs {
+			let persisted = storage.get_balances(network_slug).await?;
+			if !persisted.is_empty() {
+				self.balances
+					.lock()
+					.unwrap()
+					.insert(network_slug.clone(), persisted);
+			}
+		}
+		*self.writer.lock().unwrap() = Some(create_balance_writer(storage));
+		Ok(())
+	}
+
+	/// Records the balances of the addresses of a monitor read at a block
+	///
+	/// A balance replaces the cached balance of its address when read at a later block. Blocks
+	/// at or before the cached block, processed again or out of order, are compared with
+	/// themselves, so that they don't report the same change twice.
+	///
+	/// # Arguments
+	/// * `network_slug` - Network of the block
+	/// * `monitor_name` - Name of the monitor
+	/// * `block_number` - Number of the block the balances were read at
+	/// * `balances` - Balance of each address
+	///
+	/// # Returns
+	/// The previous balance of each address
+	pub async fn record(
+		&self,
+		network_slug: &str,
+		monitor_name: &str,
+		block_number: u64,
+		balances: &HashMap<String, String>,
+	) -> HashMap<String, String> {
+		let mut previous_balances = HashMap::new();
+		let mut updated = HashMap::new();
+		{
+			let mut cache = self.balances.lock().unwrap();
+			let cached = cache
+				.entry(network_slug.to_string())
+				.or_default()
+				.entry(monitor_name.to_string())
+				.or_default();
+			for (address, balance) in balances {
+				let previous = match cached.get(address) {
+					Some(cached) if cached.block_number < block_number => cached.balance.clone(),
+					Some(_) => {
+						previous_balances.insert(address.clone(), balance.clone());
+						continue;
+					}
+					None => balance.clone(),
+				};
+				let current = CachedBalance {
+					block_number,
+					balance: balance.clone(),
+				};
+				cached.insert(address.clone(), current.clone());
+				updated.insert(address.clone(), current);
+				previous_balances.insert(address.clone(), previous);
+			}
+		}
+
+		let writer = self.writer.lock().unwrap().clone();
+		if let Some(writer) = writer.filter(|_| !updated.is_empty()) {
+			let balances = HashMap::from([(monitor_name.to_string(), updated)]);
+			if let Err(e) = writer(network_slug.to_string(), balances).await {
+				tracing::error!(
+					"Failed to save the balances of monitor {} on {}: {}",
+					monitor_name,
+					network_slug,
+					e
+				);
+			}
+		}
+		previous_balances
+	}
+}
+
+/// Returns the addresses of a monitor whose balance is read, as passed to the RPC endpoint
+///
+/// Address patterns are left out, as are Stellar contracts, which have no native balance.
+fn balance_addresses(monitor: &Monitor, block: &BlockType) -> Vec<String> {
+	let mut addresses: Vec<String> = monitor
+		.addresses
+		.iter()
+		.filter(|address| address.match_mode.is_exact())
+		.filter_map(|address| match block {
+			BlockType::EVM(_) => Some(format!(
+				"0x{}",
+				evm_helpers::normalize_address(&address.address)
+			)),
+			BlockType::Stellar(_) => {
+				let account_id = address.address.trim().to_uppercase();
+				get_account_ledger_key(&account_id)
+					.is_ok()
+					.then_some(account_id)
+			}
+			_ => None,
+		})
+		.collect();
+	addresses.sort_unstable();
+	addresses.dedup();
+	addresses
+}
+
+/// Reads the native balance of an address at a block
+///
+/// # Returns
+/// The balance in wei or stroops, as a decimal number
+async fn read_balance<T: BlockChainClient>(
+	client: &T,
+	block: &BlockType,
+	address: &str,
+) -> Result<String, anyhow::Error> {
+	match block {
+		BlockType::EVM(evm_block) => {
+			let block_number = evm_block.number().unwrap_or_default();
+			let result = client
+				.call_rpc(
+					"eth_getBalance",
+					Some(json!([address, format!("0x{:x}", block_number)])),
+				)
+				.await?;
+			let balance = result
+				.as_str()
+				.ok_or_else(|| anyhow::anyhow!("Invalid balance: {}", result))?;
+			U256::from_str_radix(balance.trim_start_matches("0x"), 16)
+				.map(|balance| balance.to_string())
+				.map_err(|e| anyhow::anyhow!("Invalid balance '{}': {}", balance, e))
+		}
+		BlockType::Stellar(_) => {
+			let key = get_account_ledger_key(address)?
+				.to_xdr(Limits::none())
+				.map_err(|e| anyhow::anyhow!("Failed to convert account key to XDR: {}", e))?;
+			let result = client
+				.call_rpc(
+					"getLedgerEntries",
+					Some(json!({
+						"keys": [BASE64_STANDARD.encode(key)],
+						"xdrFormat": "base64"
+					})),
+				)
+				.await?;
+			// Accounts that don't exist yet have no entry
+			match result["entries"][0]["xdr"].as_str() {
+				Some(xdr) => get_account_balance_from_ledger_entry_data(xdr)
+					.map(|balance| balance.to_string()),
+				None if result["entries"].is_array() => Ok("0".to_string()),
+				None => Err(anyhow::anyhow!("Invalid ledger entries: {}", result)),
+			}
+		}
+		_ => Err(anyhow::anyhow!("Balances are only read on EVM and Stellar")),
+	}
+}
+
+/// Returns the signed difference of two balances, as a decimal number
+fn balance_delta(previous_balance: &str, balance: &str) -> String {
+	let parse = |balance: &str| U256::from_str_radix(balance, 10).unwrap_or_default();
+	let (previous_balance, balance) = (parse(previous_balance), parse(balance));
+	if balance >= previous_balance {
+		I256::from_raw(balance - previous_balance).to_string()
+	} else {
+		(-I256::from_raw(previous_balance - balance)).to_string()
+	}
+}
+
+/// Returns the variables of the balance conditions, with the types of the block's chain
+///
+/// The types are `address`, `uint256`, `uint256` and `int256` on EVM, and `address`, `i64`,
+/// `i64` and `i128` on Stellar.
+fn balance_params(
+	block: &BlockType,
+	address: &str,
+	balance: &str,
+	previous_balance: &str,
+) -> Vec<(&'static str, String, &'static str)> {
+	let (balance_kind, delta_kind) = match block {
+		BlockType::EVM(_) => ("uint256", "int256"),
+		_ => ("i64", "i128"),
+	};
+	vec![
+		("address", address.to_string(), "address"),
+		("balance", balance.to_string(), balance_kind),
+		(
+			"previous_balance",
+			previous_balance.to_string(),
+			balance_kind,
+		),
+		(
+			"balance_delta",
+			balance_delta(previous_balance, balance),
+			delta_kind,
+		),
+	]
+}
+
+/// Finds the balance conditions of a monitor that the balance of an address matches
+fn find_matching_balance_conditions(
+	block: &BlockType,
+	params: &[(&'static str, String, &'static str)],
+	monitor: &Monitor,
+	rpc_results: &BTreeMap<String, Value>,
+) -> Vec<BalanceCondition> {
+	monitor
+		.match_conditions
+		.balance_conditions
+		.iter()
+		.filter(|condition| {
+			let result = match block {
+				BlockType::EVM(_) => EVMBlockFilter::<()> {
+					_client: PhantomData,
+					rpc_results: Default::default(),
+				}
+				.evaluate_expression_with_rpc_results(
+					&condition.expression,
+					&evm_params(params),
+					rpc_results,
+				),
+				_ => StellarBlockFilter::<()> {
+					_client: PhantomData,
+					rpc_results: Default::default(),
+				}
+				.evaluate_expression_with_rpc_results(
+					&condition.expression,
+					&stellar_params(params),
+					rpc_results,
+				),
+			};
+			result.unwrap_or_else(|e| {
+				tracing::error!(
+					"Failed to evaluate expression '{}': {}",
+					condition.expression,
+					e
+				);
+				false
+			})
+		})
+		.cloned()
+		.collect()
+}
+
+fn evm_params(params: &[(&'static str, String, &'static str)]) -> Vec<EVMMatchParamEntry> {
+	params
+		.iter()
+		.map(|(name, value, kind)| EVMMatchParamEntry {
+			name: name.to_string(),
+			value: value.clone(),
+			kind: kind.to_string(),
+			indexed: false,
+		})
+		.collect()
+}
+
+fn stellar_params(params: &[(&'static str, String, &'static str)]) -> Vec<StellarMatchParamEntry> {
+	params
+		.iter()
+		.map(|(name, value, kind)| StellarMatchParamEntry {
+			name: name.to_string(),
+			value: value.clone(),
+			kind: kind.to_string(),
+			indexed: false,
+		})
+		.collect()
+}
+
+/// Matches the balances of the addresses of monitors at a block against their balance
+/// conditions
+///
+/// The balances are all read before any is recorded, so that a block whose reads fail is
+/// processed again against the same previous balances. Each address is read once, however many
+/// monitors watch it.
+///
+/// # Arguments
+/// * `client` - Client of the network of the block
+/// * `network` - Network of the block
+/// * `block` - Block to read the balances at
+/// * `monitors` - Monitors the block is filtered for
+/// * `rpc_results` - Results of the pre-filter RPC calls of the monitors
+/// * `cache` - Previous balances of the addresses of the monitors
+///
+/// # Returns
+/// A match without a transaction for each address of a monitor matching its balance conditions,
+/// or an error if a balance couldn't be read
+pub async fn find_balance_matches<T: BlockChainClient>(
+	client: &T,
+	network: &Network,
+	block: &BlockType,
+	monitors: &[Monitor],
+	rpc_results: &RpcResults,
+	cache: &BalanceCache,
+) -> Result<Vec<MonitorMatch>, FilterError> {
+	if !matches!(block, BlockType::EVM(_) | BlockType::Stellar(_)) {
+		return Ok(Vec::new());
+	}
+	let monitors: Vec<(&Monitor, Vec<String>)> = monitors
+		.iter()
+		.filter(|monitor| !monitor.match_conditions.balance_conditions.is_empty())
+		.map(|monitor| (monitor, balance_addresses(monitor, block)))
+		.collect();
+	if monitors.is_empty() {
+		return Ok(Vec::new());
+	}
+	let block_number = block.number().unwrap_or_default();
+
+	let mut balances = HashMap::new();
+	for (_, addresses) in &monitors {
+		for address in addresses {
+			if balances.contains_key(address) {
+				continue;
+			}
+			let balance = read_balance(client, block, address).await.map_err(|e| {
+				FilterError::network_error(
+					format!(
+						"Failed to read the balance of {} at block {}",
+						address, block_number
+					),
+					Some(e.into()),
+					None,
+				)
+			})?;
+			balances.insert(address.clone(), balance);
+		}
+	}
+
+	let mut matches = Vec::new();
+	for (monitor, addresses) in monitors {
+		let monitor_balances: HashMap<String, String> = addresses
+			.iter()
+			.map(|address| (address.clone(), balances[address].clone()))
+			.collect();
+		let previous_balances = cache
+			.record(
+				&network.slug,
+				&monitor.name,
+				block_number,
+				&monitor_balances,
+			)
+			.await;
+
+		for address in &addresses {
+			let params = balance_params(
+				block,
+				address,
+				&monitor_balances[address],
+				&previous_balances[address],
+			);
+			let matched_balances = find_matching_balance_conditions(
+				block,
+				&params,
+				monitor,
+				rpc_results.get(&monitor.name).unwrap_or(&BTreeMap::new()),
+			);
+			if matched_balances.is_empty() {
+				continue;
+			}
+
+			let matched_on = MatchConditions {
+				balance_conditions: matched_balances,
+				..Default::default()
+			};
+			matches.push(match block {
+				BlockType::Stellar(ledger) => {
+					MonitorMatch::Stellar(Box::new(StellarMonitorMatch {
+						monitor: monitor.clone(),
+						transaction: StellarTransaction(StellarTransactionInfo::default()),
+						ledger: (**ledger).clone(),
+						network_slug: network.slug.clone(),
+						matched_on,
+						matched_on_args: Some(StellarMatchArguments {
+							functions: None,
+							events: None,
+							block: None,
+							balance: Some(stellar_params(&params)),
+						}),
+						severity: MatchSeverity::Alert,
+					}))
+				}
+				_ => MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+					monitor: without_contract_specs(monitor),
+					transaction: EVMTransaction::default(),
+					receipt: None,
+					logs: None,
+					network_slug: network.slug.clone(),
+					matched_on,
+					matched_on_args: Some(EVMMatchArguments {
+						functions: None,
+						events: None,
+						block: None,
+						balance: Some(evm_params(&params)),
+					}),
+					confirmation_state: EVMConfirmationState::Confirmed,
+					severity: MatchSeverity::Alert,
+					sender_verified: None,
+				})),
+			});
+		}
+	}
+	Ok(matches)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::services::blockwatcher::FileBlockStorage;
+
+	fn balances(entries: &[(&str, &str)]) -> HashMap<String, String> {
+		entries
+			.iter()
+			.map(|(address, balance)| (address.to_string(), balance.to_string()))
+			.collect()
+	}
+
+	#[test]
+	fn test_balance_delta() {
+		assert_eq!(balance_delta("1000", "1500"), "500");
+		assert_eq!(balance_delta("1500", "1000"), "-500");
+		assert_eq!(balance_delta("1000", "1000"), "0");
+	}
+
+	#[tokio::test]
+	async fn test_record_compares_with_previous_block() {
+		let cache = BalanceCache::new();
+
+		// The first balance read is compared with itself
+		let previous = cache
+			.record(
+				"ethereum_mainnet",
+				"low_balance",
+				100,
+				&balances(&[("0xabc", "5000")]),
+			)
+			.await;
+		assert_eq!(previous, balances(&[("0xabc", "5000")]));
+
+		let previous = cache
+			.record(
+				"ethereum_mainnet",
+				"low_balance",
+				101,
+				&balances(&[("0xabc", "4000")]),
+			)
+			.await;
+		assert_eq!(previous, balances(&[("0xabc", "5000")]));
+
+		// A block processed again doesn't replace the cached balance
+		let previous = cache
+			.record(
+				"ethereum_mainnet",
+				"low_balance",
+				101,
+				&balances(&[("0xabc", "4000")]),
+			)
+			.await;
+		assert_eq!(previous, balances(&[("0xabc", "4000")]));
+
+		// Balances are cached per monitor
+		let previous = cache
+			.record(
+				"ethereum_mainnet",
+				"whale",
+				102,
+				&balances(&[("0xabc", "3000")]),
+			)
+			.await;
+		assert_eq!(previous, balances(&[("0xabc", "3000")]));
+	}
+
+	#[tokio::test]
+	async fn test_restore_compares_with_block_before_restart() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+		let network_slugs = ["ethereum_mainnet".to_string()];
+
+		let cache = BalanceCache::new();
+		cache
+			.restore(storage.clone(), &network_slugs)
+			.await
+			.unwrap();
+		cache
+			.record(
+				"ethereum_mainnet",
+				"low_balance",
+				100,
+				&balances(&[("0xabc", "5000")]),
+			)
+			.await;
+
+		let restarted = BalanceCache::new();
+		restarted.restore(storage, &network_slugs).await.unwrap();
+		let previous = restarted
+			.record(
+				"ethereum_mainnet",
+				"low_balance",
+				110,
+				&balances(&[("0xabc", "900")]),
+			)
+			.await;
+		assert_eq!(previous, balances(&[("0xabc", "5000")]));
+	}
+}
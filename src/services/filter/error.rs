@@ -3,7 +3,7 @@
 //! Defines the error cases that can occur during block filtering
 //! and provides helper methods for error creation and formatting.
 
-use crate::utils::logging::error::{ErrorContext, TraceableError};
+use crate::utils::logging::error::{ErrorCode, ErrorContext, TraceableError};
 use std::collections::HashMap;
 use thiserror::Error as ThisError;
 use uuid::Uuid;
@@ -12,15 +12,18 @@ use uuid::Uuid;
 #[derive(ThisError, Debug)]
 pub enum FilterError {
 	/// Errors related to network connectivity issues
-	#[error("Block type mismatch error: {0}")]
+	#[error(
+		"[{}] Block type mismatch error: {0}",
+		ErrorCode::FilterBlockTypeMismatch
+	)]
 	BlockTypeMismatch(ErrorContext),
 
 	/// Errors related to malformed requests or invalid responses
-	#[error("Network error: {0}")]
+	#[error("[{}] Network error: {0}", ErrorCode::FilterNetwork)]
 	NetworkError(ErrorContext),
 
 	/// Errors related to internal processing errors
-	#[error("Internal error: {0}")]
+	#[error("[{}] Internal error: {0}", ErrorCode::FilterInternal)]
 	InternalError(ErrorContext),
 
 	/// Other errors that don't fit into the categories above
@@ -35,7 +38,11 @@ impl FilterError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::BlockTypeMismatch(ErrorContext::new_with_log(msg, source, metadata))
+		Self::BlockTypeMismatch(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::FilterBlockTypeMismatch)
+				.log(),
+		)
 	}
 
 	// Network error
@@ -44,7 +51,11 @@ impl FilterError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::NetworkError(ErrorContext::new_with_log(msg, source, metadata))
+		Self::NetworkError(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::FilterNetwork)
+				.log(),
+		)
 	}
 
 	// Internal error
@@ -53,7 +64,21 @@ impl FilterError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::InternalError(ErrorContext::new_with_log(msg, source, metadata))
+		Self::InternalError(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::FilterInternal)
+				.log(),
+		)
+	}
+
+	/// Returns the stable code identifying the kind of error
+	pub fn code(&self) -> ErrorCode {
+		match self {
+			Self::BlockTypeMismatch(_) => ErrorCode::FilterBlockTypeMismatch,
+			Self::NetworkError(_) => ErrorCode::FilterNetwork,
+			Self::InternalError(_) => ErrorCode::FilterInternal,
+			Self::Other(_) => ErrorCode::FilterOther,
+		}
 	}
 }
 
@@ -76,7 +101,10 @@ mod tests {
 	#[test]
 	fn test_block_type_mismatch_error_formatting() {
 		let error = FilterError::block_type_mismatch("test error", None, None);
-		assert_eq!(error.to_string(), "Block type mismatch error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[FILTER_BLOCK_TYPE_MISMATCH] Block type mismatch error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = FilterError::block_type_mismatch(
@@ -86,14 +114,17 @@ mod tests {
 		);
 		assert_eq!(
 			error.to_string(),
-			"Block type mismatch error: test error [key1=value1]"
+			"[FILTER_BLOCK_TYPE_MISMATCH] Block type mismatch error: test error [key1=value1]"
 		);
 	}
 
 	#[test]
 	fn test_network_error_formatting() {
 		let error = FilterError::network_error("test error", None, None);
-		assert_eq!(error.to_string(), "Network error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[FILTER_NETWORK] Network error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = FilterError::network_error(
@@ -101,13 +132,19 @@ mod tests {
 			Some(Box::new(source_error)),
 			Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
 		);
-		assert_eq!(error.to_string(), "Network error: test error [key1=value1]");
+		assert_eq!(
+			error.to_string(),
+			"[FILTER_NETWORK] Network error: test error [key1=value1]"
+		);
 	}
 
 	#[test]
 	fn test_internal_error_formatting() {
 		let error = FilterError::internal_error("test error", None, None);
-		assert_eq!(error.to_string(), "Internal error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[FILTER_INTERNAL] Internal error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = FilterError::internal_error(
@@ -117,7 +154,7 @@ mod tests {
 		);
 		assert_eq!(
 			error.to_string(),
-			"Internal error: test error [key1=value1]"
+			"[FILTER_INTERNAL] Internal error: test error [key1=value1]"
 		);
 	}
 
@@ -127,6 +164,38 @@ mod tests {
 		let filter_error: FilterError = anyhow_error.into();
 		assert!(matches!(filter_error, FilterError::Other(_)));
 		assert_eq!(filter_error.to_string(), "test anyhow error");
+		assert_eq!(filter_error.code(), ErrorCode::FilterOther);
+	}
+
+	#[test]
+	fn test_error_codes() {
+		let errors = vec![
+			(
+				FilterError::block_type_mismatch("test error", None, None),
+				ErrorCode::FilterBlockTypeMismatch,
+			),
+			(
+				FilterError::network_error("test error", None, None),
+				ErrorCode::FilterNetwork,
+			),
+			(
+				FilterError::internal_error("test error", None, None),
+				ErrorCode::FilterInternal,
+			),
+		];
+
+		for (error, code) in errors {
+			assert_eq!(error.code(), code);
+			assert!(error.to_string().starts_with(&format!("[{}] ", code)));
+		}
+
+		// The code is also set on the context, for structured logs
+		let error = FilterError::block_type_mismatch("test error", None, None);
+		if let FilterError::BlockTypeMismatch(ctx) = &error {
+			assert_eq!(ctx.code, Some(ErrorCode::FilterBlockTypeMismatch));
+		} else {
+			panic!("Expected BlockTypeMismatch variant");
+		}
 	}
 
 	#[test]
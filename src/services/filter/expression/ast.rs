@@ -20,6 +20,18 @@ pub enum LiteralValue<'a> {
 	Number(&'a str),
 }
 
+/// Represents the right side of a condition (RHS) in a filter expression.
+/// The right side is either a literal value or a reference to the result of a pre-filter RPC call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConditionRight<'a> {
+	/// A literal value (e.g., 123, 'abc', true)
+	Literal(LiteralValue<'a>),
+	/// A path into the results of the monitor's pre-filter RPC calls, starting with the name of
+	/// the call (e.g., "balance.amount" for `${rpc.balance.amount}`)
+	/// Resolved to a literal value during evaluation, so results are never parsed as expressions.
+	RpcResult(&'a str),
+}
+
 /// Represents the possible comparison operators that can be used in filter expressions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ComparisonOperator {
@@ -111,7 +123,7 @@ pub struct Condition<'a> {
 	/// The operator used in the condition (e.g., ==, !=, >, <, etc.)
 	pub operator: ComparisonOperator,
 	/// The right side of the condition (RHS).
-	pub right: LiteralValue<'a>,
+	pub right: ConditionRight<'a>,
 }
 
 /// Represents a complete filter expression.
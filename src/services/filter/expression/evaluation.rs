@@ -12,6 +12,20 @@ pub trait ConditionEvaluator {
 	/// Gets the raw string value and kind for a base variable name
 	fn get_base_param(&self, name: &str) -> Result<(&str, &str), EvaluationError>;
 
+	/// Gets the string value and kind of an attribute of a base variable, accessed as
+	/// `name.attribute`, or `None` if the variable has no such attribute and the access is a
+	/// path into its value
+	fn get_param_attribute(&self, _name: &str, _attribute: &str) -> Option<(String, String)> {
+		None
+	}
+
+	/// Gets the string value and kind of a variable named after a dotted path (e.g.,
+	/// `eip712.message.amount`), or `None` if there's no such variable and the access is a path
+	/// into the value of the base variable
+	fn get_path_param(&self, _path: &str) -> Option<(String, String)> {
+		None
+	}
+
 	/// Performs the final comparison between the left resolved value (after all path traversal) and the literal value
 	fn compare_final_values(
 		&self,
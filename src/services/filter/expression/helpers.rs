@@ -1,28 +1,80 @@
 //! Utility functions for evaluating expressions and resolving JSON paths
 
+use serde_json::Value;
+use std::collections::BTreeMap;
+
 use super::{
-	ast::{Accessor, ComparisonOperator, ConditionLeft, Expression, LogicalOperator},
+	ast::{
+		Accessor, ComparisonOperator, ConditionLeft, ConditionRight, Expression, LiteralValue,
+		LogicalOperator,
+	},
 	error::EvaluationError,
 	evaluation::ConditionEvaluator,
+	parsing::parse_literal,
 };
 
 /// Traverses the Expression AST and uses ConditionEvaluator to evaluate conditions
+/// `${rpc.<name>}` references on the right side of conditions are resolved from `rpc_results`,
+/// the results of the monitor's pre-filter RPC calls by call name.
 /// Returns true if the expression evaluates to true, false otherwise
 /// Returns an error if the evaluation fails
 pub fn evaluate(
 	expression: &Expression<'_>,
 	evaluator: &impl ConditionEvaluator,
+	rpc_results: &BTreeMap<String, Value>,
 ) -> Result<bool, EvaluationError> {
 	match expression {
 		Expression::Condition(condition) => {
+			let rpc_result_json: String;
+			let right = match &condition.right {
+				ConditionRight::Literal(value) => value.clone(),
+				ConditionRight::RpcResult(path) => {
+					match resolve_rpc_result(rpc_results, path).ok_or_else(|| {
+						EvaluationError::variable_not_found(format!("rpc.{}", path), None, None)
+					})? {
+						Value::String(value) => parse_literal(value),
+						Value::Bool(value) => LiteralValue::Bool(*value),
+						value => {
+							rpc_result_json = value.to_string();
+							if value.is_number() {
+								LiteralValue::Number(&rpc_result_json)
+							} else {
+								LiteralValue::Str(&rpc_result_json)
+							}
+						}
+					}
+				}
+			};
+
 			let base_name = condition.left.base_name();
 			let accessors = condition.left.accessors();
+
+			// Variables named after the whole path take precedence over the base variable
+			if let Some((path_value, path_kind)) =
+				dotted_path(base_name, accessors).and_then(|path| evaluator.get_path_param(&path))
+			{
+				return evaluator.compare_final_values(
+					&path_kind,
+					&path_value,
+					&condition.operator,
+					&right,
+				);
+			}
+
 			let (base_value_str, base_kind_str) = evaluator.get_base_param(base_name)?;
 
 			let final_left_value_str: String;
 			let final_left_kind: String;
 
-			if accessors.is_empty() {
+			let attribute = match accessors {
+				[Accessor::Key(attribute)] => evaluator.get_param_attribute(base_name, attribute),
+				_ => None,
+			};
+
+			if let Some((attribute_value, attribute_kind)) = attribute {
+				final_left_value_str = attribute_value;
+				final_left_kind = attribute_kind;
+			} else if accessors.is_empty() {
 				// No accessors, use the base value directly
 				final_left_value_str = base_value_str.to_string();
 				final_left_kind = base_kind_str.to_string();
@@ -55,7 +107,7 @@ pub fn evaluate(
 				&final_left_kind,
 				&final_left_value_str,
 				&condition.operator,
-				&condition.right,
+				&right,
 			)
 		}
 		Expression::Logical {
@@ -63,20 +115,20 @@ pub fn evaluate(
 			operator,
 			right,
 		} => {
-			let left_val = evaluate(left, evaluator)?;
+			let left_val = evaluate(left, evaluator, rpc_results)?;
 			match operator {
 				LogicalOperator::And => {
 					if !left_val {
 						Ok(false)
 					} else {
-						evaluate(right, evaluator)
+						evaluate(right, evaluator, rpc_results)
 					}
 				}
 				LogicalOperator::Or => {
 					if left_val {
 						Ok(true)
 					} else {
-						evaluate(right, evaluator)
+						evaluate(right, evaluator, rpc_results)
 					}
 				}
 			}
@@ -84,6 +136,20 @@ pub fn evaluate(
 	}
 }
 
+/// Resolves a dot-separated path, starting with the name of a pre-filter RPC call, into the
+/// results of the calls (e.g., "balance.amount" or "slots.0")
+fn resolve_rpc_result<'a>(results: &'a BTreeMap<String, Value>, path: &str) -> Option<&'a Value> {
+	let mut segments = path.split('.');
+	let mut value = results.get(segments.next()?)?;
+	for segment in segments {
+		value = match value {
+			Value::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+			_ => value.get(segment)?,
+		};
+	}
+	Some(value)
+}
+
 /// Compares two values implementing the Ord trait using the specified comparison operator
 /// Returns true if the comparison is valid, false otherwise
 /// Returns an error if the operator is not supported for the given types
@@ -111,6 +177,20 @@ pub fn compare_ordered_values<T: Ord>(
 	}
 }
 
+/// Joins a base variable name and key accessors into a dotted path, or returns `None` if
+/// there are no accessors or one of them is an index
+fn dotted_path(base_name: &str, accessors: &[Accessor]) -> Option<String> {
+	if accessors.is_empty() {
+		return None;
+	}
+	accessors
+		.iter()
+		.try_fold(base_name.to_string(), |path, accessor| match accessor {
+			Accessor::Key(key) => Some(format!("{}.{}", path, key)),
+			Accessor::Index(_) => None,
+		})
+}
+
 /// Resolves a JSON path from a base variable name and accessors
 /// Returns the resolved JSON value
 /// Returns an error if the traversal fails
@@ -213,7 +293,10 @@ fn access_json_value(
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::services::filter::expression::ast::{ComparisonOperator, VariablePath};
+	use crate::services::filter::expression::{
+		ast::{ComparisonOperator, LiteralValue, VariablePath},
+		parsing::parse,
+	};
 	use serde_json::json;
 
 	// --- Tests for `compare_ordered_values` ---
@@ -343,4 +426,137 @@ mod tests {
 			vec!["base.field".to_string(), "base.field[0]".to_string()]
 		);
 	}
+
+	// --- Tests for `dotted_path` ---
+	#[test]
+	fn test_dotted_path() {
+		assert_eq!(
+			dotted_path("eip712", &[Accessor::Key("message"), Accessor::Key("to")]),
+			Some("eip712.message.to".to_string())
+		);
+		assert_eq!(dotted_path("eip712", &[]), None);
+		assert_eq!(
+			dotted_path("items", &[Accessor::Index(0), Accessor::Key("id")]),
+			None
+		);
+	}
+
+	// --- Tests for `evaluate` ---
+	/// Minimal evaluator resolving base variables from a fixed table and comparing as strings
+	struct StaticEvaluator {
+		params: Vec<(&'static str, &'static str, &'static str)>,
+	}
+
+	impl ConditionEvaluator for StaticEvaluator {
+		fn get_base_param(&self, name: &str) -> Result<(&str, &str), EvaluationError> {
+			self.params
+				.iter()
+				.find(|(param_name, _, _)| *param_name == name)
+				.map(|(_, value, kind)| (*value, *kind))
+				.ok_or_else(|| {
+					let msg = format!("Unknown variable '{}'", name);
+					EvaluationError::variable_not_found(msg, None, None)
+				})
+		}
+
+		fn compare_final_values(
+			&self,
+			_left_kind: &str,
+			left_resolved_value: &str,
+			operator: &ComparisonOperator,
+			right_literal: &LiteralValue,
+		) -> Result<bool, EvaluationError> {
+			let right = match right_literal {
+				LiteralValue::Bool(b) => b.to_string(),
+				LiteralValue::Str(s) | LiteralValue::Number(s) => s.to_string(),
+			};
+			compare_ordered_values(&left_resolved_value.to_string(), operator, &right)
+		}
+
+		fn get_kind_from_json_value(&self, value: &serde_json::Value) -> String {
+			match value {
+				serde_json::Value::Bool(_) => "bool".to_string(),
+				_ => "string".to_string(),
+			}
+		}
+	}
+
+	#[test]
+	fn test_evaluate_grouping_changes_result() {
+		// a = false, b = true, c = true
+		let evaluator = StaticEvaluator {
+			params: vec![
+				("a", "false", "bool"),
+				("arguments", "[[\"x\", true]]", "array"),
+				("map", "{\"key\": true}", "map"),
+			],
+		};
+
+		// a AND (b OR c) => false AND true => false
+		let grouped_or =
+			parse("a == true AND (arguments[0][1] == true OR map.key == true)").unwrap();
+		assert!(!evaluate(&grouped_or, &evaluator, &BTreeMap::new()).unwrap());
+
+		// (a AND b) OR c => false OR true => true
+		let grouped_and =
+			parse("(a == true AND arguments[0][1] == true) OR map.key == true").unwrap();
+		assert!(evaluate(&grouped_and, &evaluator, &BTreeMap::new()).unwrap());
+
+		// Without parentheses AND binds tighter than OR, matching `(a AND b) OR c`
+		let ungrouped = parse("a == true AND arguments[0][1] == true OR map.key == true").unwrap();
+		assert!(evaluate(&ungrouped, &evaluator, &BTreeMap::new()).unwrap());
+	}
+
+	#[test]
+	fn test_evaluate_nested_grouping() {
+		let evaluator = StaticEvaluator {
+			params: vec![
+				("a", "true", "bool"),
+				("b", "false", "bool"),
+				("c", "false", "bool"),
+			],
+		};
+
+		// a OR b AND c => a OR (b AND c) => true
+		let expr = parse("a == true OR b == true AND c == true").unwrap();
+		assert!(evaluate(&expr, &evaluator, &BTreeMap::new()).unwrap());
+
+		// (a OR b) AND c => false
+		let expr = parse("(a == true OR b == true) AND c == true").unwrap();
+		assert!(!evaluate(&expr, &evaluator, &BTreeMap::new()).unwrap());
+
+		// ((a OR b) AND (c OR a)) => true
+		let expr = parse("((a == true OR b == true) AND (c == true OR a == true))").unwrap();
+		assert!(evaluate(&expr, &evaluator, &BTreeMap::new()).unwrap());
+	}
+
+	#[test]
+	fn test_evaluate_rpc_results() {
+		let evaluator = StaticEvaluator {
+			params: vec![
+				("name", "a b", "string"),
+				("value", "100", "number"),
+				("active", "true", "bool"),
+			],
+		};
+		let rpc_results = BTreeMap::from([
+			("owner".to_string(), json!({"name": "a b", "active": true})),
+			("threshold".to_string(), json!(100)),
+			("injected".to_string(), json!("0 || value == 100")),
+		]);
+		let evaluate_with_results =
+			|expression: &str| evaluate(&parse(expression).unwrap(), &evaluator, &rpc_results);
+
+		assert!(evaluate_with_results("name == ${rpc.owner.name}").unwrap());
+		assert!(evaluate_with_results("value == ${rpc.threshold}").unwrap());
+		assert!(evaluate_with_results("active == ${rpc.owner.active}").unwrap());
+
+		// Results are values, never parsed as part of the expression
+		assert!(!evaluate_with_results("value == ${rpc.injected}").unwrap());
+
+		assert!(matches!(
+			evaluate_with_results("value == ${rpc.missing}"),
+			Err(EvaluationError::VariableNotFound(_))
+		));
+	}
 }
@@ -3,8 +3,8 @@
 //! The parser converts the input string into an abstract syntax tree (AST) representation of the expression.
 
 use super::ast::{
-	Accessor, ComparisonOperator, Condition, ConditionLeft, Expression, LiteralValue,
-	LogicalOperator, VariablePath,
+	Accessor, ComparisonOperator, Condition, ConditionLeft, ConditionRight, Expression,
+	LiteralValue, LogicalOperator, VariablePath,
 };
 use winnow::{
 	ascii::{digit1, space0, space1, Caseless},
@@ -261,6 +261,29 @@ fn parse_value<'a>(input: &mut Input<'a>) -> ParserResult<LiteralValue<'a>> {
 	.parse_next(input)
 }
 
+/// Parses a reference to the result of a pre-filter RPC call (e.g., "${rpc.balance.amount}")
+/// Returns the path into the results, starting with the name of the call
+fn parse_rpc_result<'a>(input: &mut Input<'a>) -> ParserResult<&'a str> {
+	delimited(
+		literal("${rpc."),
+		take_while(1.., |c: char| c.is_alphanumeric() || c == '_' || c == '.'),
+		literal("}"),
+	)
+	.context(StrContext::Expected(StrContextValue::Description(
+		"pre-filter RPC result (e.g., ${rpc.name})",
+	)))
+	.parse_next(input)
+}
+
+/// Parses the right side of a condition: a pre-filter RPC result or a literal value
+fn parse_condition_rhs<'a>(input: &mut Input<'a>) -> ParserResult<ConditionRight<'a>> {
+	alt((
+		delimited(space0, parse_rpc_result, space0).map(ConditionRight::RpcResult),
+		parse_value.map(ConditionRight::Literal),
+	))
+	.parse_next(input)
+}
+
 /// Parses a comparison operator (e.g., ==, !=, >, >=, <, <=)
 /// Handles optional whitespace around the operator
 fn parse_comparison_operator(input: &mut Input<'_>) -> ParserResult<ComparisonOperator> {
@@ -287,7 +310,11 @@ fn parse_comparison_operator(input: &mut Input<'_>) -> ParserResult<ComparisonOp
 
 /// Parses a condition expression (e.g., "a == 1") into an `Expression::Condition`
 fn parse_condition<'a>(input: &mut Input<'a>) -> ParserResult<Expression<'a>> {
-	let (left, operator, right) = (parse_condition_lhs, parse_comparison_operator, parse_value)
+	let (left, operator, right) = (
+		parse_condition_lhs,
+		parse_comparison_operator,
+		parse_condition_rhs,
+	)
 		.context(StrContext::Expected(StrContextValue::Description(
 			"condition expression (e.g., variable == value)",
 		)))
@@ -393,6 +420,16 @@ fn parse_expression<'a>(input: &mut Input<'a>) -> ParserResult<Expression<'a>> {
 		.parse_next(input)
 }
 
+/// Returns the literal value of a string compared with the left side of a condition, such as the
+/// result of a pre-filter RPC call, without parsing it as an expression
+/// Number literals (e.g., "123", "-123.456") are numbers, any other string is a string.
+pub(super) fn parse_literal(value: &str) -> LiteralValue<'_> {
+	(parse_number_or_fixed_str, eof)
+		.map(|(number, _)| number)
+		.parse(value)
+		.unwrap_or(LiteralValue::Str(value))
+}
+
 /// Public method, which parses a string expression into an `Expression` AST
 pub fn parse(expression_str: &str) -> Result<Expression<'_>, ParseError<Input<'_>, ContextError>> {
 	// Parse the expression and ensure it ends with EOF
@@ -809,7 +846,7 @@ mod tests {
 		let expected = Expression::Condition(Condition {
 			left: ConditionLeft::Simple("var"),
 			operator: ComparisonOperator::Eq,
-			right: LiteralValue::Number("123"),
+			right: ConditionRight::Literal(LiteralValue::Number("123")),
 		});
 		assert_parses_ok(parse_condition, expr, expected, "");
 
@@ -817,7 +854,7 @@ mod tests {
 		let expected_str = Expression::Condition(Condition {
 			left: ConditionLeft::Simple("name"),
 			operator: ComparisonOperator::Contains,
-			right: LiteralValue::Str("test"),
+			right: ConditionRight::Literal(LiteralValue::Str("test")),
 		});
 		assert_parses_ok(parse_condition, expr_str, expected_str, "");
 
@@ -828,9 +865,28 @@ mod tests {
 				accessors: vec![Accessor::Key("count")],
 			}),
 			operator: ComparisonOperator::Gt,
-			right: LiteralValue::Number("0.5"),
+			right: ConditionRight::Literal(LiteralValue::Number("0.5")),
 		});
 		assert_parses_ok(parse_condition, expr_path, expected_path, "");
+
+		let expr_rpc = "value > ${rpc.balance.amount}";
+		let expected_rpc = Expression::Condition(Condition {
+			left: ConditionLeft::Simple("value"),
+			operator: ComparisonOperator::Gt,
+			right: ConditionRight::RpcResult("balance.amount"),
+		});
+		assert_parses_ok(parse_condition, expr_rpc, expected_rpc, "");
+
+		// Quoted placeholders are string literals
+		let expr_quoted = "from == '${rpc.owner}'";
+		let expected_quoted = Expression::Condition(Condition {
+			left: ConditionLeft::Simple("from"),
+			operator: ComparisonOperator::Eq,
+			right: ConditionRight::Literal(LiteralValue::Str("${rpc.owner}")),
+		});
+		assert_parses_ok(parse_condition, expr_quoted, expected_quoted, "");
+
+		assert_parse_fails(parse_condition, "value > ${rpc.}");
 	}
 
 	#[test]
@@ -839,7 +895,7 @@ mod tests {
 		let inner_cond = Condition {
 			left: ConditionLeft::Simple("var"),
 			operator: ComparisonOperator::Eq,
-			right: LiteralValue::Number("123"),
+			right: ConditionRight::Literal(LiteralValue::Number("123")),
 		};
 		let expected = Expression::Condition(inner_cond.clone()); // The term itself is the condition
 		assert_parses_ok(parse_term, expr, expected, "");
@@ -849,13 +905,13 @@ mod tests {
 			left: Box::new(Expression::Condition(Condition {
 				left: ConditionLeft::Simple("var1"),
 				operator: ComparisonOperator::Gt,
-				right: LiteralValue::Number("10"),
+				right: ConditionRight::Literal(LiteralValue::Number("10")),
 			})),
 			operator: LogicalOperator::And,
 			right: Box::new(Expression::Condition(Condition {
 				left: ConditionLeft::Simple("var2"),
 				operator: ComparisonOperator::Lt,
-				right: LiteralValue::Str("abc"),
+				right: ConditionRight::Literal(LiteralValue::Str("abc")),
 			})),
 		};
 		// parse_term calls parse_expression for parentheses, parse_expression calls parse_or_expression...
@@ -869,13 +925,13 @@ mod tests {
 			left: Box::new(Expression::Condition(Condition {
 				left: ConditionLeft::Simple("a"),
 				operator: ComparisonOperator::Eq,
-				right: LiteralValue::Number("1"),
+				right: ConditionRight::Literal(LiteralValue::Number("1")),
 			})),
 			operator: LogicalOperator::And,
 			right: Box::new(Expression::Condition(Condition {
 				left: ConditionLeft::Simple("b"),
 				operator: ComparisonOperator::Lt,
-				right: LiteralValue::Number("2.0"),
+				right: ConditionRight::Literal(LiteralValue::Number("2.0")),
 			})),
 		};
 		// Test parse_and_expression directly or parse_expression for full precedence
@@ -888,13 +944,13 @@ mod tests {
 			left: Box::new(Expression::Condition(Condition {
 				left: ConditionLeft::Simple("a"),
 				operator: ComparisonOperator::Eq,
-				right: LiteralValue::Number("1"),
+				right: ConditionRight::Literal(LiteralValue::Number("1")),
 			})),
 			operator: LogicalOperator::Or,
 			right: Box::new(Expression::Condition(Condition {
 				left: ConditionLeft::Simple("b"),
 				operator: ComparisonOperator::Lt,
-				right: LiteralValue::Str("text"),
+				right: ConditionRight::Literal(LiteralValue::Str("text")),
 			})),
 		};
 		assert_eq!(parse(expr_or).unwrap(), expected_or);
@@ -905,20 +961,20 @@ mod tests {
 			left: Box::new(Expression::Condition(Condition {
 				left: ConditionLeft::Simple("a"),
 				operator: ComparisonOperator::Eq,
-				right: LiteralValue::Number("1"),
+				right: ConditionRight::Literal(LiteralValue::Number("1")),
 			})),
 			operator: LogicalOperator::Or,
 			right: Box::new(Expression::Logical {
 				left: Box::new(Expression::Condition(Condition {
 					left: ConditionLeft::Simple("b"),
 					operator: ComparisonOperator::Lt,
-					right: LiteralValue::Number("2"),
+					right: ConditionRight::Literal(LiteralValue::Number("2")),
 				})),
 				operator: LogicalOperator::And,
 				right: Box::new(Expression::Condition(Condition {
 					left: ConditionLeft::Simple("c"),
 					operator: ComparisonOperator::Gt,
-					right: LiteralValue::Number("3"),
+					right: ConditionRight::Literal(LiteralValue::Number("3")),
 				})),
 			}),
 		};
@@ -931,20 +987,20 @@ mod tests {
 				left: Box::new(Expression::Condition(Condition {
 					left: ConditionLeft::Simple("a"),
 					operator: ComparisonOperator::Eq,
-					right: LiteralValue::Number("1"),
+					right: ConditionRight::Literal(LiteralValue::Number("1")),
 				})),
 				operator: LogicalOperator::Or,
 				right: Box::new(Expression::Condition(Condition {
 					left: ConditionLeft::Simple("b"),
 					operator: ComparisonOperator::Lt,
-					right: LiteralValue::Number("2"),
+					right: ConditionRight::Literal(LiteralValue::Number("2")),
 				})),
 			}),
 			operator: LogicalOperator::And,
 			right: Box::new(Expression::Condition(Condition {
 				left: ConditionLeft::Simple("c"),
 				operator: ComparisonOperator::Gt,
-				right: LiteralValue::Number("3"),
+				right: ConditionRight::Literal(LiteralValue::Number("3")),
 			})),
 		};
 		assert_eq!(parse(expr_parens).unwrap(), expected_parens);
@@ -12,10 +12,13 @@ use alloy::primitives::Address;
 use serde_json::{json, Value as JsonValue};
 
 use crate::{
-	models::{MonitorMatch, ScriptLanguage},
+	models::{MatchSeverity, MonitorMatch, ScriptLanguage},
 	services::{
 		filter::{
-			evm_helpers::{b256_to_string, h160_to_string},
+			evm_helpers::{
+				b256_to_string, created_contract_address, decode_revert_reason, h160_to_string,
+				revert_selector,
+			},
 			FilterError,
 		},
 		trigger::TriggerExecutionServiceTrait,
@@ -44,17 +47,54 @@ use crate::{
 /// "transaction.from": "0xf401346fd255e034a2e43151efe1d68c1e0f8ca5"
 /// "transaction.to": "0x0000000000001ff3684f28c67538d4d072c22734"
 /// "transaction.value": "24504000000000000"
+/// "transaction.confirmation_state": "confirmed"
+/// "match.severity": "alert"
 /// "events.0.signature": "Transfer(address,address,uint256)"
 /// "events.0.args.to": "0x70bf6634ee8cb27d04478f184b9b8bb13e5f4710"
 /// "events.0.args.from": "0x2e8135be71230c6b1b4045696d41c09db0414226"
 /// "events.0.args.value": "88248701"
+/// "event.from": "0x2e8135be71230c6b1b4045696d41c09db0414226"
+/// "event.2": "88248701"
 /// ```
 pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 	matching_monitor: MonitorMatch,
 	trigger_service: &T,
 	trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
 ) -> Result<(), FilterError> {
-	match &matching_monitor {
+	let monitor = match &matching_monitor {
+		MonitorMatch::EVM(evm_monitor_match) => &evm_monitor_match.monitor,
+		MonitorMatch::Stellar(stellar_monitor_match) => &stellar_monitor_match.monitor,
+		MonitorMatch::Midnight(midnight_monitor_match) => &midnight_monitor_match.monitor,
+		MonitorMatch::Solana(solana_monitor_match) => &solana_monitor_match.monitor,
+	};
+	// Near misses of the warn threshold expressions are notified to the lower severity triggers
+	let triggers = match matching_monitor.severity() {
+		MatchSeverity::Alert => &monitor.triggers,
+		MatchSeverity::Warning => &monitor.warn_triggers,
+	};
+
+	// Swallow any errors since it's logged in the trigger service and we want to continue
+	// processing other matches
+	let _ = trigger_service
+		.execute(
+			triggers,
+			match_variables(&matching_monitor),
+			&matching_monitor,
+			trigger_scripts,
+		)
+		.await;
+	Ok(())
+}
+
+/// Converts a monitor match into the variables substituted in trigger templates
+///
+/// # Arguments
+/// * `matching_monitor` - The matched monitor event
+///
+/// # Returns
+/// The variables of the match, keyed by their dotted path (e.g. `transaction.hash`)
+pub fn match_variables(matching_monitor: &MonitorMatch) -> HashMap<String, String> {
+	let data_json = match matching_monitor {
 		MonitorMatch::EVM(evm_monitor_match) => {
 			let transaction = evm_monitor_match.transaction.clone();
 			// If sender does not exist, we replace with 0x0000000000000000000000000000000000000000
@@ -69,14 +109,38 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 					"hash": b256_to_string(*transaction.hash()),
 					"from": h160_to_string(*sender),
 					"value": transaction.value().to_string(),
+					"confirmation_state": evm_monitor_match.confirmation_state.as_str(),
 				},
 				"functions": [],
 				"events": []
 			});
 
-			// Add 'to' address if present
+			// Add 'to' address if present, else the address of the created contract
 			if let Some(to) = transaction.to() {
 				data_json["transaction"]["to"] = json!(h160_to_string(*to));
+			} else if let Some(created_address) =
+				created_contract_address(&transaction, &evm_monitor_match.receipt)
+			{
+				data_json["transaction"]["created_contract_address"] =
+					json!(h160_to_string(created_address));
+			}
+
+			if let Some(sender_verified) = evm_monitor_match.sender_verified {
+				data_json["transaction"]["sender_verified"] = json!(sender_verified);
+			}
+
+			// Add the revert of failed transactions whose revert data was fetched
+			if let Some(revert_data) = evm_monitor_match
+				.receipt
+				.as_ref()
+				.and_then(|receipt| receipt.revert_reason.as_ref())
+			{
+				if let Some(selector) = revert_selector(revert_data) {
+					data_json["transaction"]["revert_selector"] = json!(selector);
+				}
+				if let Some(reason) = decode_revert_reason(revert_data) {
+					data_json["transaction"]["revert_reason"] = json!(reason);
+				}
 			}
 
 			// Process matched functions
@@ -133,21 +197,35 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 				events.push(event_data);
 			}
 
-			// Swallow any errors since it's logged in the trigger service and we want to continue
-			// processing other matches
-			let _ = trigger_service
-				.execute(
-					&evm_monitor_match
-						.monitor
-						.triggers
+			// Add the properties of the block if matched on block conditions
+			if let Some(block) = evm_monitor_match
+				.matched_on_args
+				.as_ref()
+				.and_then(|args| args.block.as_ref())
+			{
+				data_json["block"] = JsonValue::Object(
+					block
+						.iter()
+						.map(|param| (param.name.clone(), json!(param.value.clone())))
+						.collect(),
+				);
+			}
+
+			// Add the balance of the address if matched on balance conditions
+			if let Some(balance) = evm_monitor_match
+				.matched_on_args
+				.as_ref()
+				.and_then(|args| args.balance.as_ref())
+			{
+				data_json["balance"] = JsonValue::Object(
+					balance
 						.iter()
-						.map(|s| s.to_string())
-						.collect::<Vec<_>>(),
-					json_to_hashmap(&data_json),
-					&matching_monitor,
-					trigger_scripts,
-				)
-				.await;
+						.map(|param| (param.name.clone(), json!(param.value.clone())))
+						.collect(),
+				);
+			}
+
+			data_json
 		}
 		MonitorMatch::Stellar(stellar_monitor_match) => {
 			let transaction = stellar_monitor_match.transaction.clone();
@@ -218,21 +296,35 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 				events.push(event_data);
 			}
 
-			// Swallow any errors since it's logged in the trigger service and we want to continue
-			// processing other matches
-			let _ = trigger_service
-				.execute(
-					&stellar_monitor_match
-						.monitor
-						.triggers
+			// Add the properties of the block if matched on block conditions
+			if let Some(block) = stellar_monitor_match
+				.matched_on_args
+				.as_ref()
+				.and_then(|args| args.block.as_ref())
+			{
+				data_json["block"] = JsonValue::Object(
+					block
+						.iter()
+						.map(|param| (param.name.clone(), json!(param.value.clone())))
+						.collect(),
+				);
+			}
+
+			// Add the balance of the address if matched on balance conditions
+			if let Some(balance) = stellar_monitor_match
+				.matched_on_args
+				.as_ref()
+				.and_then(|args| args.balance.as_ref())
+			{
+				data_json["balance"] = JsonValue::Object(
+					balance
 						.iter()
-						.map(|s| s.to_string())
-						.collect::<Vec<_>>(),
-					json_to_hashmap(&data_json),
-					&matching_monitor,
-					trigger_scripts,
-				)
-				.await;
+						.map(|param| (param.name.clone(), json!(param.value.clone())))
+						.collect(),
+				);
+			}
+
+			data_json
 		}
 		MonitorMatch::Midnight(midnight_monitor_match) => {
 			let transaction = midnight_monitor_match.transaction.clone();
@@ -303,24 +395,138 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 				events.push(event_data);
 			}
 
-			// Swallow any errors since it's logged in the trigger service and we want to continue
-			// processing other matches
-			let _ = trigger_service
-				.execute(
-					&midnight_monitor_match
-						.monitor
-						.triggers
-						.iter()
-						.map(|s| s.to_string())
-						.collect::<Vec<_>>(),
-					json_to_hashmap(&data_json),
-					&matching_monitor,
-					trigger_scripts,
-				)
-				.await;
+			data_json
+		}
+		MonitorMatch::Solana(solana_monitor_match) => {
+			let transaction = solana_monitor_match.transaction.clone();
+
+			// Create structured JSON data
+			let mut data_json = json!({
+				"monitor": {
+					"name": solana_monitor_match.monitor.name.clone(),
+				},
+				"transaction": {
+					"hash": transaction.signature().to_string(),
+					"from": transaction.fee_payer().cloned().unwrap_or_default(),
+					"slot": solana_monitor_match.slot,
+				},
+				"functions": [],
+				"events": []
+			});
+
+			// Process matched functions
+			let functions = data_json["functions"].as_array_mut().unwrap();
+			for func in solana_monitor_match.matched_on.functions.iter() {
+				let mut function_data = json!({
+					"signature": func.signature.clone(),
+					"args": {}
+				});
+
+				// Add function arguments if present
+				if let Some(args) = &solana_monitor_match.matched_on_args {
+					if let Some(func_args) = &args.functions {
+						for func_arg in func_args {
+							if func_arg.signature == func.signature {
+								if let Some(arg_entries) = &func_arg.args {
+									let args_obj = function_data["args"].as_object_mut().unwrap();
+									for arg in arg_entries {
+										args_obj.insert(arg.name.clone(), json!(arg.value.clone()));
+									}
+								}
+							}
+						}
+					}
+				}
+
+				functions.push(function_data);
+			}
+
+			// Process matched events
+			let events = data_json["events"].as_array_mut().unwrap();
+			for event in solana_monitor_match.matched_on.events.iter() {
+				let mut event_data = json!({
+					"signature": event.signature.clone(),
+					"args": {}
+				});
+
+				// Add event arguments if present
+				if let Some(args) = &solana_monitor_match.matched_on_args {
+					if let Some(event_args) = &args.events {
+						for event_arg in event_args {
+							if event_arg.signature == event.signature {
+								if let Some(arg_entries) = &event_arg.args {
+									let args_obj = event_data["args"].as_object_mut().unwrap();
+									for arg in arg_entries {
+										args_obj.insert(arg.name.clone(), json!(arg.value.clone()));
+									}
+								}
+							}
+						}
+					}
+				}
+
+				events.push(event_data);
+			}
+
+			data_json
+		}
+	};
+	let mut variables = json_to_hashmap(&data_json);
+	variables.extend(matched_args_variables(matching_monitor));
+	variables.insert(
+		"match.severity".to_string(),
+		matching_monitor.severity().as_str().to_string(),
+	);
+	variables
+}
+
+/// Flattens the decoded arguments of the first matched function and event of a monitor match
+/// into template variables, keyed by their position and name
+///
+/// The arguments of a `Transfer(address from, address to, uint256 value)` event are, for
+/// example, available as `event.0`, `event.1` and `event.2` as well as `event.from`, `event.to`
+/// and `event.value`, and those of the matched function as `function.0`, `function.amount`, etc.
+/// The arguments of all the matched functions and events remain available as
+/// `functions.[index].args.[param]` and `events.[index].args.[param]`.
+///
+/// # Arguments
+/// * `matching_monitor` - The matched monitor event
+///
+/// # Returns
+/// The variables of the matched arguments, empty if the match has no decoded arguments
+pub fn matched_args_variables(matching_monitor: &MonitorMatch) -> HashMap<String, String> {
+	let matched_on_args = match matching_monitor {
+		MonitorMatch::EVM(evm_monitor_match) => {
+			serde_json::to_value(&evm_monitor_match.matched_on_args)
+		}
+		MonitorMatch::Stellar(stellar_monitor_match) => {
+			serde_json::to_value(&stellar_monitor_match.matched_on_args)
+		}
+		MonitorMatch::Midnight(midnight_monitor_match) => {
+			serde_json::to_value(&midnight_monitor_match.matched_on_args)
+		}
+		MonitorMatch::Solana(solana_monitor_match) => {
+			serde_json::to_value(&solana_monitor_match.matched_on_args)
 		}
 	}
-	Ok(())
+	.unwrap_or_default();
+
+	let mut variables = HashMap::new();
+	for (prefix, key) in [("function", "functions"), ("event", "events")] {
+		let Some(args) = matched_on_args[key][0]["args"].as_array() else {
+			continue;
+		};
+		for (position, arg) in args.iter().enumerate() {
+			let Some(value) = arg["value"].as_str() else {
+				continue;
+			};
+			variables.insert(format!("{}.{}", prefix, position), value.to_string());
+			if let Some(name) = arg["name"].as_str().filter(|name| !name.is_empty()) {
+				variables.insert(format!("{}.{}", prefix, name), value.to_string());
+			}
+		}
+	}
+	variables
 }
 
 /// Converts a JsonValue to a flattened HashMap with dotted path notation
@@ -369,8 +575,147 @@ fn insert_primitive<T: ToString>(prefix: &str, result: &mut HashMap<String, Stri
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::{
+		models::{
+			EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch,
+			MatchConditions, StellarBlock, StellarMatchArguments, StellarMatchParamEntry,
+			StellarMatchParamsMap, StellarMonitorMatch, StellarTransaction, StellarTransactionInfo,
+		},
+		utils::tests::{
+			builders::stellar::monitor::MonitorBuilder as StellarMonitorBuilder,
+			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+		},
+	};
 	use serde_json::json;
 
+	fn evm_param(name: &str, value: &str, kind: &str) -> EVMMatchParamEntry {
+		EVMMatchParamEntry {
+			name: name.to_string(),
+			value: value.to_string(),
+			indexed: false,
+			kind: kind.to_string(),
+		}
+	}
+
+	fn stellar_param(name: &str, value: &str, kind: &str) -> StellarMatchParamEntry {
+		StellarMatchParamEntry {
+			name: name.to_string(),
+			value: value.to_string(),
+			kind: kind.to_string(),
+			indexed: false,
+		}
+	}
+
+	#[test]
+	fn test_matched_args_variables_evm() {
+		let monitor_match = MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("Large Transfers").build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: None,
+			logs: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: Some(EVMMatchArguments {
+				functions: None,
+				events: Some(vec![EVMMatchParamsMap {
+					signature: "Transfer(address,address,uint256)".to_string(),
+					args: Some(vec![
+						evm_param(
+							"from",
+							"0x2e8135be71230c6b1b4045696d41c09db0414226",
+							"address",
+						),
+						evm_param(
+							"to",
+							"0x70bf6634ee8cb27d04478f184b9b8bb13e5f4710",
+							"address",
+						),
+						evm_param("value", "88248701", "uint256"),
+					]),
+					hex_signature: None,
+				}]),
+				block: None,
+				balance: None,
+			}),
+			confirmation_state: Default::default(),
+			severity: Default::default(),
+			sender_verified: None,
+		}));
+
+		let variables = matched_args_variables(&monitor_match);
+		assert_eq!(variables.len(), 6);
+		assert_eq!(
+			variables["event.0"],
+			"0x2e8135be71230c6b1b4045696d41c09db0414226"
+		);
+		assert_eq!(variables["event.from"], variables["event.0"]);
+		assert_eq!(variables["event.2"], "88248701");
+		assert_eq!(variables["event.value"], "88248701");
+
+		// The flattened arguments are part of the variables of the match
+		let variables = match_variables(&monitor_match);
+		assert_eq!(variables["event.value"], "88248701");
+		assert_eq!(variables["events.0.args.value"], "88248701");
+	}
+
+	#[test]
+	fn test_matched_args_variables_stellar() {
+		let monitor_match = MonitorMatch::Stellar(Box::new(StellarMonitorMatch {
+			monitor: StellarMonitorBuilder::new().name("Large Transfers").build(),
+			transaction: StellarTransaction::from(StellarTransactionInfo::default()),
+			ledger: StellarBlock::default(),
+			network_slug: "stellar_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: Some(StellarMatchArguments {
+				functions: Some(vec![StellarMatchParamsMap {
+					signature: "transfer(Address,Address,I128)".to_string(),
+					args: Some(vec![
+						stellar_param("from", "GABC", "Address"),
+						stellar_param("to", "GDEF", "Address"),
+						stellar_param("amount", "1000000", "I128"),
+					]),
+				}]),
+				// Event parameters are only named if the contract specifies their names
+				events: Some(vec![StellarMatchParamsMap {
+					signature: "transfer(Address,Address,String,I128)".to_string(),
+					args: Some(vec![
+						stellar_param("0", "GABC", "Address"),
+						stellar_param("", "GDEF", "Address"),
+					]),
+				}]),
+				block: None,
+				balance: None,
+			}),
+			severity: Default::default(),
+		}));
+
+		let variables = match_variables(&monitor_match);
+		assert_eq!(variables["function.0"], "GABC");
+		assert_eq!(variables["function.amount"], "1000000");
+		assert_eq!(variables["function.2"], "1000000");
+		assert_eq!(variables["event.0"], "GABC");
+		assert_eq!(variables["event.1"], "GDEF");
+		assert!(!variables.contains_key("event."));
+	}
+
+	#[test]
+	fn test_matched_args_variables_without_args() {
+		let monitor_match = MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: None,
+			logs: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			confirmation_state: Default::default(),
+			severity: Default::default(),
+			sender_verified: None,
+		}));
+
+		assert!(matched_args_variables(&monitor_match).is_empty());
+	}
+
 	#[test]
 	fn test_json_to_hashmap() {
 		let json = json!({
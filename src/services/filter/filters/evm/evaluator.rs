@@ -697,6 +697,42 @@ impl ConditionEvaluator for EVMConditionEvaluator<'_> {
 			})
 	}
 
+	/// This method is used to get an attribute of a parameter of the EVM condition evaluator.
+	///
+	/// `param.indexed` tells whether an event parameter was decoded from the log's topics rather
+	/// than its data. Tuple parameters keep resolving `indexed` as one of their fields.
+	///
+	/// Arguments:
+	/// - name: The name of the parameter.
+	/// - attribute: The name of the attribute.
+	///
+	/// Returns:
+	/// - The attribute value and kind, or None if the parameter has no such attribute.
+	fn get_param_attribute(&self, name: &str, attribute: &str) -> Option<(String, String)> {
+		let param = self.args.iter().find(|p| p.name == name)?;
+		match attribute {
+			"indexed" if !param.kind.starts_with("tuple") => {
+				Some((param.indexed.to_string(), "bool".to_string()))
+			}
+			_ => None,
+		}
+	}
+
+	/// This method is used to get a parameter named after a dotted path, such as the fields of
+	/// decoded EIP-712 typed data (`eip712.message.amount`).
+	///
+	/// Arguments:
+	/// - path: The dotted path of the parameter.
+	///
+	/// Returns:
+	/// - The parameter value and kind, or None if there's no such parameter.
+	fn get_path_param(&self, path: &str) -> Option<(String, String)> {
+		self.args
+			.iter()
+			.find(|p| p.name == path)
+			.map(|p| (p.value.clone(), p.kind.clone()))
+	}
+
 	/// This method is used to compare the final values of the EVM condition evaluator.
 	///
 	/// Arguments:
@@ -730,6 +766,10 @@ impl ConditionEvaluator for EVMConditionEvaluator<'_> {
 		}
 
 		if UNSIGNED_INTEGER_KINDS.contains(&lhs_kind.as_str()) {
+			// Unset values, such as the EIP-1559 fees of a legacy transaction, match nothing
+			if lhs_value_str.is_empty() {
+				return Ok(false);
+			}
 			return self.compare_u256(lhs_value_str, operator, rhs_literal);
 		}
 
@@ -1834,6 +1874,35 @@ mod tests {
 		));
 	}
 
+	/// --- Test cases for get_param_attribute ---
+	#[test]
+	fn test_get_param_attribute_indexed() {
+		let param = |name: &str, kind: &str, indexed: bool| EVMMatchParamEntry {
+			name: name.to_string(),
+			value: "0".to_string(),
+			kind: kind.to_string(),
+			indexed,
+		};
+		let args = vec![
+			param("from", "address", true),
+			param("value", "uint256", false),
+			param("order", "tuple", true),
+		];
+		let evaluator = EVMConditionEvaluator::new(&args);
+
+		assert_eq!(
+			evaluator.get_param_attribute("from", "indexed"),
+			Some(("true".to_string(), "bool".to_string()))
+		);
+		assert_eq!(
+			evaluator.get_param_attribute("value", "indexed"),
+			Some(("false".to_string(), "bool".to_string()))
+		);
+		assert_eq!(evaluator.get_param_attribute("order", "indexed"), None);
+		assert_eq!(evaluator.get_param_attribute("from", "kind"), None);
+		assert_eq!(evaluator.get_param_attribute("missing", "indexed"), None);
+	}
+
 	/// --- Test cases for compare_final_values ---
 	#[test]
 	fn test_compare_final_values_routing() {
@@ -1942,6 +2011,22 @@ mod tests {
 		));
 	}
 
+	#[test]
+	fn test_compare_final_values_unset_unsigned() {
+		let evaluator = create_evaluator();
+
+		for operator in [
+			ComparisonOperator::Gt,
+			ComparisonOperator::Lt,
+			ComparisonOperator::Eq,
+			ComparisonOperator::Ne,
+		] {
+			assert!(!evaluator
+				.compare_final_values("uint256", "", &operator, &LiteralValue::Number("0"))
+				.unwrap());
+		}
+	}
+
 	/// --- Test cases for get_kind_from_json_value ---
 	#[test]
 	fn test_get_kind_from_json_value() {
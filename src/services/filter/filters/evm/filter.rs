@@ -7,53 +7,193 @@
 //! - Event log processing and filtering
 //! - ABI-based decoding of function calls and events
 
-use alloy::core::dyn_abi::{DynSolType, DynSolValue, EventExt};
-use alloy::core::json_abi::{AbiItem, JsonAbi};
-use alloy::primitives::{LogData, U64};
+use alloy::core::dyn_abi::{DynSolType, DynSolValue, FunctionExt, JsonAbiExt};
+use alloy::core::json_abi::{AbiItem, Function, JsonAbi};
+use alloy::primitives::{keccak256, B256, U256, U64};
 use async_trait::async_trait;
-use std::marker::PhantomData;
+use lazy_static::lazy_static;
+use serde_json::{json, Value};
+use std::{collections::BTreeMap, marker::PhantomData};
 use tracing::instrument;
 
 use crate::{
 	models::{
-		AddressWithSpec, BlockType, ContractSpec, EVMContractSpec, EVMMatchArguments,
-		EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch, EVMReceiptLog, EVMTransaction,
-		EVMTransactionReceipt, EventCondition, FunctionCondition, MatchConditions, Monitor,
-		MonitorMatch, Network, TransactionCondition, TransactionStatus,
+		AddressWithSpec, BlockCondition, BlockType, ContractSpec, EVMBlock, EVMConfirmationState,
+		EVMContractSpec, EVMInternalCall, EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap,
+		EVMMonitorMatch, EVMReceiptLog, EVMTraceMethod, EVMTransaction, EVMTransactionReceipt,
+		EventCondition, FunctionCondition, MatchConditions, MatchSeverity, Monitor, MonitorMatch,
+		Network, TokenStandard, TransactionCondition, TransactionStatus,
 	},
 	services::{
-		blockchain::{BlockChainClient, EvmClientTrait},
+		blockchain::{BlockChainClient, ContractCallResult, EvmClientTrait},
 		filter::{
 			evm_helpers::{
-				are_same_address, are_same_signature, b256_to_string, format_token_value,
-				h160_to_string, normalize_address,
+				are_same_address, are_same_signature, b256_to_string, created_contract_address,
+				decode_eip712_params, decode_event_params, decode_function_params,
+				decode_revert_reason, format_token_value, function_selector, h160_to_string,
+				normalize_address, revert_selector,
 			},
 			expression::{self, EvaluationError},
-			filters::evm::evaluator::EVMConditionEvaluator,
-			BlockFilter, FilterError,
+			filters::{evm::evaluator::EVMConditionEvaluator, filter_monitors},
+			BlockFilter, FilterError, RpcResults,
 		},
 	},
+	utils::metrics::EVM_FILTER_PATH_TOTAL,
 };
 
+lazy_static! {
+	/// ABI of the transfer events of the ERC-20, ERC-721 and ERC-1155 token standards, decoding
+	/// the logs matched by token transfer conditions
+	static ref TOKEN_TRANSFER_SPEC: ContractSpec = ContractSpec::EVM(EVMContractSpec::from(json!([
+		{
+			"type": "event",
+			"name": "Transfer",
+			"inputs": [
+				{ "name": "from", "type": "address", "indexed": true },
+				{ "name": "to", "type": "address", "indexed": true },
+				{ "name": "value", "type": "uint256", "indexed": false }
+			],
+			"anonymous": false
+		},
+		{
+			"type": "event",
+			"name": "Transfer",
+			"inputs": [
+				{ "name": "from", "type": "address", "indexed": true },
+				{ "name": "to", "type": "address", "indexed": true },
+				{ "name": "tokenId", "type": "uint256", "indexed": true }
+			],
+			"anonymous": false
+		},
+		{
+			"type": "event",
+			"name": "TransferSingle",
+			"inputs": [
+				{ "name": "operator", "type": "address", "indexed": true },
+				{ "name": "from", "type": "address", "indexed": true },
+				{ "name": "to", "type": "address", "indexed": true },
+				{ "name": "id", "type": "uint256", "indexed": false },
+				{ "name": "value", "type": "uint256", "indexed": false }
+			],
+			"anonymous": false
+		},
+		{
+			"type": "event",
+			"name": "TransferBatch",
+			"inputs": [
+				{ "name": "operator", "type": "address", "indexed": true },
+				{ "name": "from", "type": "address", "indexed": true },
+				{ "name": "to", "type": "address", "indexed": true },
+				{ "name": "ids", "type": "uint256[]", "indexed": false },
+				{ "name": "values", "type": "uint256[]", "indexed": false }
+			],
+			"anonymous": false
+		}
+	])));
+
+	/// Selectors of the transfer events of the token standards
+	static ref TOKEN_TRANSFER_TOPICS: Vec<B256> = [
+		"Transfer(address,address,uint256)",
+		"TransferSingle(address,address,address,uint256,uint256)",
+		"TransferBatch(address,address,address,uint256[],uint256[])",
+	]
+	.into_iter()
+	.map(keccak256)
+	.collect();
+}
+
+/// Returns the token standard of a transfer event decoded with [`TOKEN_TRANSFER_SPEC`]
+///
+/// ERC-20 and ERC-721 `Transfer` events share their signature, and are told apart by the
+/// ERC-721 token id being indexed.
+fn token_standard(event: &EVMMatchParamsMap) -> Option<TokenStandard> {
+	if event.signature.starts_with("TransferSingle(")
+		|| event.signature.starts_with("TransferBatch(")
+	{
+		return Some(TokenStandard::Erc1155);
+	}
+	if !event.signature.starts_with("Transfer(") {
+		return None;
+	}
+	let is_erc721 = event.args.iter().flatten().any(|arg| arg.name == "tokenId");
+	Some(if is_erc721 {
+		TokenStandard::Erc721
+	} else {
+		TokenStandard::Erc20
+	})
+}
+
+/// Returns a copy of a monitor without the ABIs of its addresses, which matches don't need
+pub(crate) fn without_contract_specs(monitor: &Monitor) -> Monitor {
+	Monitor {
+		addresses: monitor
+			.addresses
+			.iter()
+			.map(|addr| AddressWithSpec {
+				contract_spec: None,
+				..addr.clone()
+			})
+			.collect(),
+		..monitor.clone()
+	}
+}
+
+/// Returns whether the sender recovered from the signature of a matched transaction is its
+/// reported `from` address, for monitors with `verify_signature`
+fn verify_sender(monitor: &Monitor, transaction: &EVMTransaction) -> Option<bool> {
+	if !monitor.verify_signature {
+		return None;
+	}
+	let sender_verified = transaction.sender_verified();
+	match sender_verified {
+		Some(false) => tracing::warn!(
+			"Sender of transaction {} matched by monitor {} isn't its signer",
+			b256_to_string(*transaction.hash()),
+			monitor.name
+		),
+		None => tracing::debug!(
+			"Sender of transaction {} can't be recovered from its signature",
+			b256_to_string(*transaction.hash())
+		),
+		Some(true) => {}
+	}
+	sender_verified
+}
+
 /// Filter implementation for EVM-compatible blockchains
 pub struct EVMBlockFilter<T> {
 	pub _client: PhantomData<T>,
+	/// Results of the pre-filter RPC calls of the monitors, bound to the `${rpc.<name>}`
+	/// variables of their expressions
+	pub rpc_results: RpcResults,
 }
 
 impl<T> EVMBlockFilter<T> {
 	/// Finds transactions that match the monitor's conditions.
 	///
+	/// Fee parameters a transaction doesn't have, such as the EIP-1559 fees of a legacy
+	/// transaction or the effective gas price without a receipt, are left empty, so that
+	/// conditions on them don't match. So are `created_contract_address` and `code_hash` of
+	/// transactions that don't create a contract, `code_hash` when it wasn't fetched, and
+	/// `revert_selector` and `revert_reason` of transactions without revert data in their
+	/// receipt. `revert_reason` is also empty for custom errors, only decoded from their ABI.
+	///
 	/// # Arguments
 	/// * `tx_status` - Status of the transaction (success/failure)
 	/// * `transaction` - The transaction to check
 	/// * `tx_receipt` - Transaction receipt
+	/// * `base_fee_per_gas` - Base fee of the block containing the transaction
+	/// * `code_hash` - Hash of the code deployed by the transaction, if fetched
 	/// * `monitor` - Monitor containing match conditions
 	/// * `matched_transactions` - Vector to store matching transactions
+	#[allow(clippy::too_many_arguments)]
 	pub fn find_matching_transaction(
 		&self,
 		tx_status: &TransactionStatus,
 		transaction: &EVMTransaction,
 		tx_receipt: &Option<EVMTransactionReceipt>,
+		base_fee_per_gas: Option<U256>,
+		code_hash: Option<B256>,
 		monitor: &Monitor,
 		matched_transactions: &mut Vec<TransactionCondition>,
 	) {
@@ -62,10 +202,16 @@ impl<T> EVMBlockFilter<T> {
 			matched_transactions.push(TransactionCondition {
 				expression: None,
 				status: TransactionStatus::Any,
+				warn_threshold_expression: None,
+				is_contract_creation: false,
 			});
 		} else {
 			// Check each transaction condition
 			for condition in &monitor.match_conditions.transactions {
+				if condition.is_contract_creation && transaction.to.is_some() {
+					continue;
+				}
+
 				// First check if status matches (if specified)
 				let status_matches = match &condition.status {
 					TransactionStatus::Any => true,
@@ -74,6 +220,9 @@ impl<T> EVMBlockFilter<T> {
 
 				if status_matches {
 					if let Some(expr) = &condition.expression {
+						let revert_data = tx_receipt
+							.as_ref()
+							.and_then(|receipt| receipt.revert_reason.as_ref());
 						let tx_params = vec![
 							EVMMatchParamEntry {
 								name: "value".to_string(),
@@ -101,13 +250,17 @@ impl<T> EVMBlockFilter<T> {
 							},
 							EVMMatchParamEntry {
 								name: "gas_price".to_string(),
-								value: transaction.gas_price.unwrap_or_default().to_string(),
+								value: transaction
+									.gas_price
+									.map_or(String::new(), |fee| fee.to_string()),
 								kind: "uint256".to_string(),
 								indexed: false,
 							},
 							EVMMatchParamEntry {
 								name: "max_fee_per_gas".to_string(),
-								value: transaction.max_fee_per_gas.unwrap_or_default().to_string(),
+								value: transaction
+									.max_fee_per_gas
+									.map_or(String::new(), |fee| fee.to_string()),
 								kind: "uint256".to_string(),
 								indexed: false,
 							},
@@ -115,8 +268,23 @@ impl<T> EVMBlockFilter<T> {
 								name: "max_priority_fee_per_gas".to_string(),
 								value: transaction
 									.max_priority_fee_per_gas
-									.unwrap_or_default()
-									.to_string(),
+									.map_or(String::new(), |fee| fee.to_string()),
+								kind: "uint256".to_string(),
+								indexed: false,
+							},
+							EVMMatchParamEntry {
+								name: "effective_gas_price".to_string(),
+								value: tx_receipt
+									.as_ref()
+									.and_then(|r| r.effective_gas_price)
+									.map_or(String::new(), |fee| fee.to_string()),
+								kind: "uint256".to_string(),
+								indexed: false,
+							},
+							EVMMatchParamEntry {
+								name: "base_fee_per_gas".to_string(),
+								value: base_fee_per_gas
+									.map_or(String::new(), |fee| fee.to_string()),
 								kind: "uint256".to_string(),
 								indexed: false,
 							},
@@ -155,14 +323,45 @@ impl<T> EVMBlockFilter<T> {
 								kind: "uint64".to_string(),
 								indexed: false,
 							},
+							EVMMatchParamEntry {
+								name: "created_contract_address".to_string(),
+								value: created_contract_address(transaction, tx_receipt)
+									.map_or(String::new(), h160_to_string),
+								kind: "address".to_string(),
+								indexed: false,
+							},
+							EVMMatchParamEntry {
+								name: "code_hash".to_string(),
+								value: code_hash.map_or(String::new(), b256_to_string),
+								kind: "string".to_string(),
+								indexed: false,
+							},
+							EVMMatchParamEntry {
+								name: "revert_selector".to_string(),
+								value: revert_data
+									.and_then(|data| revert_selector(data))
+									.unwrap_or_default(),
+								kind: "string".to_string(),
+								indexed: false,
+							},
+							EVMMatchParamEntry {
+								name: "revert_reason".to_string(),
+								value: revert_data
+									.and_then(|data| decode_revert_reason(data))
+									.unwrap_or_default(),
+								kind: "string".to_string(),
+								indexed: false,
+							},
 						];
 
 						// Evaluate the expression with transaction parameters
-						match self.evaluate_expression(expr, &tx_params) {
+						match self.evaluate_monitor_expression(monitor, expr, &tx_params) {
 							Ok(true) => {
 								matched_transactions.push(TransactionCondition {
 									expression: Some(expr.to_string()),
 									status: *tx_status,
+									warn_threshold_expression: None,
+									is_contract_creation: condition.is_contract_creation,
 								});
 								break;
 							}
@@ -177,6 +376,8 @@ impl<T> EVMBlockFilter<T> {
 						matched_transactions.push(TransactionCondition {
 							expression: None,
 							status: *tx_status,
+							warn_threshold_expression: None,
+							is_contract_creation: condition.is_contract_creation,
 						});
 						break;
 					}
@@ -185,10 +386,56 @@ impl<T> EVMBlockFilter<T> {
 		}
 	}
 
+	/// Finds internal calls of a transaction that match the monitor's transaction conditions.
+	///
+	/// Each call is evaluated as the transaction with the `from`, `to` and `value` of the call,
+	/// until one of them matches.
+	///
+	/// # Arguments
+	/// * `tx_status` - Status of the transaction (success/failure)
+	/// * `transaction` - The transaction making the calls
+	/// * `tx_receipt` - Transaction receipt
+	/// * `base_fee_per_gas` - Base fee of the block containing the transaction
+	/// * `internal_calls` - Internal calls made by the transaction
+	/// * `monitor` - Monitor containing match conditions
+	/// * `matched_transactions` - Vector to store matching transactions
+	#[allow(clippy::too_many_arguments)]
+	pub fn find_matching_internal_calls(
+		&self,
+		tx_status: &TransactionStatus,
+		transaction: &EVMTransaction,
+		tx_receipt: &Option<EVMTransactionReceipt>,
+		base_fee_per_gas: Option<U256>,
+		internal_calls: &[&EVMInternalCall],
+		monitor: &Monitor,
+		matched_transactions: &mut Vec<TransactionCondition>,
+	) {
+		for call in internal_calls {
+			let mut call_transaction = transaction.clone();
+			call_transaction.0.from = Some(call.from);
+			call_transaction.0.to = call.to;
+			call_transaction.0.value = call.value;
+
+			self.find_matching_transaction(
+				tx_status,
+				&call_transaction,
+				tx_receipt,
+				base_fee_per_gas,
+				None,
+				monitor,
+				matched_transactions,
+			);
+			if !matched_transactions.is_empty() {
+				break;
+			}
+		}
+	}
+
 	/// Finds function calls in a transaction that match the monitor's conditions.
 	///
 	/// Decodes the transaction input data using the contract ABI and matches against
-	/// the monitor's function conditions.
+	/// the monitor's function conditions. When the monitored address has no ABI, conditions
+	/// are matched on the selector computed from their signature instead.
 	///
 	/// # Arguments
 	/// * `contract_specs` - List of contract specifications
@@ -211,8 +458,22 @@ impl<T> EVMBlockFilter<T> {
 			if let Some(monitored_addr) = monitor.addresses.iter().find(|addr| {
 				transaction
 					.to
-					.is_some_and(|to| are_same_address(&addr.address, &h160_to_string(to)))
+					.is_some_and(|to| addr.matches(&h160_to_string(to), normalize_address))
 			}) {
+				// Without an ABI, match on the selectors computed from the signatures
+				if !contract_specs
+					.iter()
+					.any(|(address, _)| are_same_address(address, &monitored_addr.address))
+				{
+					self.find_matching_functions_by_selector(
+						&input_data.0,
+						monitor,
+						matched_functions,
+						matched_on_args,
+					);
+					return;
+				}
+
 				// Process the matching address's ABI
 				if let Some((_, abi)) = contract_specs
 					.iter()
@@ -307,14 +568,22 @@ impl<T> EVMBlockFilter<T> {
 											indexed: false,
 										})
 										.collect();
+									let Some(params) = self.with_eip712_params(condition, params)
+									else {
+										continue;
+									};
 									if let Some(expr) = &condition.expression {
 										// Evaluate the expression condition
-										match self.evaluate_expression(expr, &params) {
+										match self
+											.evaluate_monitor_expression(monitor, expr, &params)
+										{
 											Ok(true) => {
 												matched_functions.push(FunctionCondition {
 													signature: function_signature_with_params
 														.clone(),
 													expression: Some(expr.to_string()),
+													warn_threshold_expression: None,
+													eip712_schema: condition.eip712_schema.clone(),
 												});
 												if let Some(functions) =
 													&mut matched_on_args.functions
@@ -346,6 +615,8 @@ impl<T> EVMBlockFilter<T> {
 										matched_functions.push(FunctionCondition {
 											signature: function_signature_with_params.clone(),
 											expression: None,
+											warn_threshold_expression: None,
+											eip712_schema: condition.eip712_schema.clone(),
 										});
 										if let Some(functions) = &mut matched_on_args.functions {
 											functions.push(EVMMatchParamsMap {
@@ -367,6 +638,129 @@ impl<T> EVMBlockFilter<T> {
 		}
 	}
 
+	/// Matches a function call against the monitor's function conditions without an ABI.
+	///
+	/// The selector of each condition is computed from its signature and compared to the first
+	/// 4 bytes of the input data. Arguments are decoded on a best-effort basis using the types
+	/// declared by the signature and are named by their position (`0`, `1`, ...). Conditions
+	/// with an expression only match when the arguments could be decoded.
+	///
+	/// # Arguments
+	/// * `input` - Input data of the transaction
+	/// * `monitor` - Monitor containing function match conditions
+	/// * `matched_functions` - Vector to store matching functions
+	/// * `matched_on_args` - Arguments from matched function calls
+	pub fn find_matching_functions_by_selector(
+		&self,
+		input: &[u8],
+		monitor: &Monitor,
+		matched_functions: &mut Vec<FunctionCondition>,
+		matched_on_args: &mut EVMMatchArguments,
+	) {
+		if input.len() < 4 {
+			return;
+		}
+		let (selector, params_blob) = input.split_at(4);
+
+		for condition in &monitor.match_conditions.functions {
+			if function_selector(&condition.signature) != selector {
+				continue;
+			}
+
+			let params = match decode_function_params(&condition.signature, params_blob) {
+				Ok(decoded) => Some(
+					decoded
+						.iter()
+						.enumerate()
+						.map(|(index, (ty, value))| EVMMatchParamEntry {
+							name: index.to_string(),
+							value: format_token_value(value),
+							kind: ty.to_string(),
+							indexed: false,
+						})
+						.collect::<Vec<_>>(),
+				),
+				Err(e) => {
+					tracing::debug!("Matching '{}' on selector only: {}", condition.signature, e);
+					None
+				}
+			};
+			let params = match params {
+				Some(params) => match self.with_eip712_params(condition, params) {
+					Some(params) => Some(params),
+					None => continue,
+				},
+				None if condition.eip712_schema.is_some() => {
+					tracing::warn!(
+						"Failed to decode EIP-712 typed data of '{}': arguments not decoded",
+						condition.signature
+					);
+					continue;
+				}
+				None => None,
+			};
+
+			if let Some(expr) = &condition.expression {
+				let Some(params) = &params else {
+					continue;
+				};
+				match self.evaluate_monitor_expression(monitor, expr, params) {
+					Ok(true) => {}
+					Ok(false) => continue,
+					Err(e) => {
+						tracing::error!("Failed to evaluate expression '{}': {}", expr, e);
+						continue;
+					}
+				}
+			}
+
+			matched_functions.push(condition.clone());
+			if let Some(functions) = &mut matched_on_args.functions {
+				functions.push(EVMMatchParamsMap {
+					signature: condition.signature.clone(),
+					args: params,
+					hex_signature: Some(format!("0x{}", hex::encode(selector))),
+				});
+			}
+			break;
+		}
+	}
+
+	/// Appends the fields of the EIP-712 typed data carried by the arguments of a call to its
+	/// arguments, when the function condition declares an `eip712_schema`.
+	///
+	/// # Arguments
+	/// * `condition` - Function condition matching the call
+	/// * `params` - Decoded arguments of the call
+	///
+	/// # Returns
+	/// The arguments with the fields of the typed data, or None if the typed data is malformed
+	/// and the condition doesn't match
+	fn with_eip712_params(
+		&self,
+		condition: &FunctionCondition,
+		mut params: Vec<EVMMatchParamEntry>,
+	) -> Option<Vec<EVMMatchParamEntry>> {
+		let Some(schema) = &condition.eip712_schema else {
+			return Some(params);
+		};
+
+		match decode_eip712_params(schema, &params) {
+			Ok(typed_data) => {
+				params.extend(typed_data);
+				Some(params)
+			}
+			Err(e) => {
+				tracing::warn!(
+					"Failed to decode EIP-712 typed data of '{}': {}",
+					condition.signature,
+					e
+				);
+				None
+			}
+		}
+	}
+
 	/// Finds events in a transaction receipt that match the monitor's conditions.
 	///
 	/// Processes event logs from the transaction receipt and matches them against
@@ -391,7 +785,7 @@ impl<T> EVMBlockFilter<T> {
 			let matching_monitored_addr = monitor
 				.addresses
 				.iter()
-				.find(|addr| are_same_address(&addr.address, &h160_to_string(log.address)));
+				.find(|addr| addr.matches(&h160_to_string(log.address), normalize_address));
 
 			// Only process logs from monitored addresses
 			let Some(monitored_addr) = matching_monitored_addr else {
@@ -406,11 +800,12 @@ impl<T> EVMBlockFilter<T> {
 				let decoded_log = self.decode_events(abi, log);
 
 				if let Some(event_condition) = decoded_log {
-					if monitor.match_conditions.events.is_empty() {
+					if !monitor.match_conditions.has_event_conditions() {
 						// Match all events
 						matched_events.push(EventCondition {
 							signature: event_condition.signature.clone(),
 							expression: None,
+							warn_threshold_expression: None,
 						});
 						if let Some(events) = &mut matched_on_args.events {
 							events.push(event_condition);
@@ -427,6 +822,7 @@ impl<T> EVMBlockFilter<T> {
 									matched_events.push(EventCondition {
 										signature: event_condition.signature.clone(),
 										expression: None,
+										warn_threshold_expression: None,
 									});
 									if let Some(events) = &mut matched_on_args.events {
 										events.push(event_condition);
@@ -435,7 +831,8 @@ impl<T> EVMBlockFilter<T> {
 								} else {
 									// Evaluate the expression condition
 									if let Some(expr) = &condition.expression {
-										match self.evaluate_expression(
+										match self.evaluate_monitor_expression(
+											monitor,
 											expr,
 											event_condition.args.as_deref().unwrap_or_default(),
 										) {
@@ -443,6 +840,7 @@ impl<T> EVMBlockFilter<T> {
 												matched_events.push(EventCondition {
 													signature: event_condition.signature.clone(),
 													expression: Some(expr.to_string()),
+													warn_threshold_expression: None,
 												});
 												if let Some(events) = &mut matched_on_args.events {
 													events.push(event_condition);
@@ -466,9 +864,277 @@ impl<T> EVMBlockFilter<T> {
 					}
 				}
 			}
+
+			if !monitor.match_conditions.token_transfers.is_empty() {
+				self.find_matching_token_transfer(log, monitor, matched_events, matched_on_args);
+			}
+		}
+	}
+
+	/// Matches a log against the token transfer conditions of a monitor.
+	///
+	/// The log is decoded as a transfer event of any token standard, regardless of the ABI of
+	/// the emitting address, and evaluated against the event conditions the token transfer
+	/// conditions expand into for that standard.
+	///
+	/// # Arguments
+	/// * `log` - Log emitted by a monitored address
+	/// * `monitor` - Monitor containing token transfer conditions
+	/// * `matched_events` - Vector to store the matching event condition
+	/// * `matched_on_args` - Arguments from matched events
+	fn find_matching_token_transfer(
+		&self,
+		log: &EVMReceiptLog,
+		monitor: &Monitor,
+		matched_events: &mut Vec<EventCondition>,
+		matched_on_args: &mut EVMMatchArguments,
+	) {
+		if !log
+			.topics
+			.first()
+			.is_some_and(|topic| TOKEN_TRANSFER_TOPICS.contains(topic))
+		{
+			return;
+		}
+		let Some(transfer) = self.decode_events(&TOKEN_TRANSFER_SPEC, log) else {
+			return;
+		};
+		let Some(standard) = token_standard(&transfer) else {
+			return;
+		};
+
+		let matching_condition = monitor
+			.match_conditions
+			.token_transfers
+			.iter()
+			.flat_map(|condition| condition.event_conditions())
+			.filter(|(condition_standard, condition)| {
+				*condition_standard == standard
+					&& are_same_signature(&condition.signature, &transfer.signature)
+			})
+			.map(|(_, condition)| condition)
+			.find(|condition| match &condition.expression {
+				None => true,
+				Some(expr) => self
+					.evaluate_monitor_expression(
+						monitor,
+						expr,
+						transfer.args.as_deref().unwrap_or_default(),
+					)
+					.unwrap_or_else(|e| {
+						tracing::error!("Failed to evaluate expression '{}': {}", expr, e);
+						false
+					}),
+			});
+
+		if let Some(condition) = matching_condition {
+			matched_events.push(EventCondition {
+				signature: transfer.signature.clone(),
+				expression: condition.expression,
+				warn_threshold_expression: None,
+			});
+			if let Some(events) = &mut matched_on_args.events {
+				events.push(transfer);
+			}
+		}
+	}
+
+	/// Returns the properties of a block evaluated by block conditions
+	///
+	/// Properties a block doesn't have, such as the base fee of a block mined before EIP-1559,
+	/// are left empty, so that conditions on them don't match.
+	///
+	/// # Arguments
+	/// * `block` - The block to get the properties of
+	pub fn block_params(&self, block: &EVMBlock) -> Vec<EVMMatchParamEntry> {
+		let param = |name: &str, value: String, kind: &str| EVMMatchParamEntry {
+			name: name.to_string(),
+			value,
+			kind: kind.to_string(),
+			indexed: false,
+		};
+		vec![
+			param(
+				"number",
+				block.number().unwrap_or_default().to_string(),
+				"uint64",
+			),
+			param("hash", block.hash().unwrap_or_default(), "string"),
+			param("timestamp", block.timestamp.to_string(), "uint256"),
+			param("gas_used", block.gas_used.to_string(), "uint256"),
+			param("gas_limit", block.gas_limit.to_string(), "uint256"),
+			param(
+				"base_fee_per_gas",
+				block
+					.base_fee_per_gas
+					.map_or(String::new(), |fee| fee.to_string()),
+				"uint256",
+			),
+			param("miner", h160_to_string(block.author), "address"),
+			param("tx_count", block.transactions.len().to_string(), "uint64"),
+			param(
+				"size",
+				block.size.map_or(String::new(), |size| size.to_string()),
+				"uint256",
+			),
+		]
+	}
+
+	/// Finds the block conditions of a monitor that a block matches.
+	///
+	/// # Arguments
+	/// * `block_params` - Properties of the block, see [`Self::block_params`]
+	/// * `monitor` - Monitor containing match conditions
+	/// * `matched_blocks` - Vector to store matching block conditions
+	pub fn find_matching_block_conditions(
+		&self,
+		block_params: &[EVMMatchParamEntry],
+		monitor: &Monitor,
+		matched_blocks: &mut Vec<BlockCondition>,
+	) {
+		for condition in &monitor.match_conditions.block_conditions {
+			match self.evaluate_monitor_expression(monitor, &condition.expression, block_params) {
+				Ok(true) => matched_blocks.push(condition.clone()),
+				Ok(false) => {}
+				Err(e) => {
+					tracing::error!(
+						"Failed to evaluate expression '{}': {}",
+						condition.expression,
+						e
+					);
+				}
+			}
 		}
 	}
 
+	/// Checks the state conditions of a monitor against the state of a matched contract.
+	///
+	/// The function of each condition is called on the contract with `eth_call` at the matched
+	/// block, and the condition holds if its expression matches the decoded outputs. Outputs
+	/// without a name are named `result`, or `result_<index>` when the function returns
+	/// several values. A condition whose call reverts, or whose signature, arguments or
+	/// outputs can't be decoded, is reported and doesn't hold.
+	///
+	/// # Arguments
+	/// * `client` - Client calling the contract
+	/// * `monitor` - Monitor containing the state conditions
+	/// * `contract` - Address of the matched contract
+	/// * `block_number` - Number of the matched block
+	///
+	/// # Returns
+	/// `true` if all state conditions hold, or an error if a call couldn't be made
+	pub async fn check_state_conditions<C: EvmClientTrait + Sync>(
+		&self,
+		client: &C,
+		monitor: &Monitor,
+		contract: &str,
+		block_number: u64,
+	) -> Result<bool, FilterError> {
+		for condition in &monitor.match_conditions.state_conditions {
+			let call = Function::parse(&condition.signature)
+				.map_err(|e| e.to_string())
+				.and_then(|function| {
+					let args = function
+						.inputs
+						.iter()
+						.zip(&condition.args)
+						.map(|(param, arg)| {
+							param
+								.selector_type()
+								.parse::<DynSolType>()
+								.and_then(|ty| ty.coerce_str(arg))
+						})
+						.collect::<Result<Vec<_>, _>>()
+						.map_err(|e| format!("Invalid arguments: {}", e))?;
+					let data = function
+						.abi_encode_input(&args)
+						.map_err(|e| format!("Failed to encode call: {}", e))?;
+					Ok((function, data))
+				});
+			let (function, data) = match call {
+				Ok(call) => call,
+				Err(e) => {
+					tracing::error!(
+						"Failed to prepare state condition '{}' of monitor {}: {}",
+						condition.signature,
+						monitor.name,
+						e
+					);
+					return Ok(false);
+				}
+			};
+
+			let output = match client
+				.call_contract(contract, data, block_number)
+				.await
+				.map_err(|e| {
+					FilterError::network_error(
+						format!(
+							"Failed to call '{}' on contract {}",
+							condition.signature, contract
+						),
+						Some(e.into()),
+						None,
+					)
+				})? {
+				ContractCallResult::Returned(output) => output,
+				ContractCallResult::Reverted(reason) => {
+					tracing::error!(
+						"State condition '{}' of monitor {} reverted on contract {}: {}",
+						condition.signature,
+						monitor.name,
+						contract,
+						reason
+					);
+					return Ok(false);
+				}
+			};
+
+			let values = match function.abi_decode_output(&output) {
+				Ok(values) => values,
+				Err(e) => {
+					tracing::error!(
+						"Failed to decode output of state condition '{}' of monitor {}: {}",
+						condition.signature,
+						monitor.name,
+						e
+					);
+					return Ok(false);
+				}
+			};
+			let params = function
+				.outputs
+				.iter()
+				.zip(&values)
+				.enumerate()
+				.map(|(index, (output, value))| EVMMatchParamEntry {
+					name: match output.name.as_str() {
+						"" if function.outputs.len() == 1 => "result".to_string(),
+						"" => format!("result_{}", index),
+						name => name.to_string(),
+					},
+					value: format_token_value(value),
+					kind: output.ty.to_string(),
+					indexed: false,
+				})
+				.collect::<Vec<_>>();
+
+			match self.evaluate_monitor_expression(monitor, &condition.expression, &params) {
+				Ok(true) => {}
+				Ok(false) => return Ok(false),
+				Err(e) => {
+					tracing::error!(
+						"Failed to evaluate expression '{}': {}",
+						condition.expression,
+						e
+					);
+					return Ok(false);
+				}
+			}
+		}
+		Ok(true)
+	}
+
 	/// Evaluates a match expression against provided parameters.
 	///
 	/// # Arguments
@@ -481,6 +1147,33 @@ impl<T> EVMBlockFilter<T> {
 		&self,
 		expression: &str,
 		args: &[EVMMatchParamEntry],
+	) -> Result<bool, EvaluationError> {
+		self.evaluate_expression_with_rpc_results(expression, args, &BTreeMap::new())
+	}
+
+	/// Evaluates an expression of a monitor's conditions, binding its `${rpc.<name>}` variables
+	/// to the results of the monitor's pre-filter RPC calls
+	pub fn evaluate_monitor_expression(
+		&self,
+		monitor: &Monitor,
+		expression: &str,
+		args: &[EVMMatchParamEntry],
+	) -> Result<bool, EvaluationError> {
+		match self.rpc_results.get(&monitor.name) {
+			Some(rpc_results) => {
+				self.evaluate_expression_with_rpc_results(expression, args, rpc_results)
+			}
+			None => self.evaluate_expression(expression, args),
+		}
+	}
+
+	/// Evaluates an expression, binding its `${rpc.<name>}` variables to the given results of
+	/// pre-filter RPC calls
+	pub fn evaluate_expression_with_rpc_results(
+		&self,
+		expression: &str,
+		args: &[EVMMatchParamEntry],
+		rpc_results: &BTreeMap<String, Value>,
 	) -> Result<bool, EvaluationError> {
 		// Check if the expression is empty
 		if expression.trim().is_empty() {
@@ -503,7 +1196,7 @@ impl<T> EVMBlockFilter<T> {
 		tracing::debug!("Parsed AST for '{}': {:?}", expression, parsed_ast);
 
 		// Evaluate the expression
-		expression::evaluate(&parsed_ast, &evaluator)
+		expression::evaluate(&parsed_ast, &evaluator, rpc_results)
 	}
 
 	/// Decodes event logs using the provided ABI.
@@ -538,75 +1231,42 @@ impl<T> EVMBlockFilter<T> {
 		};
 
 		// Find the matching Event
-		let event = match contract
+		let mut events = contract
 			.items()
 			.filter_map(|item| match item {
 				AbiItem::Event(e) => Some(e),
 				_ => None,
 			})
-			.find(|e| e.selector() == log.topics[0])
-		{
-			Some(event) => event,
-			None => {
-				FilterError::internal_error(
-					format!("No matching event found for log topic: {:?}", log.topics[0]),
-					None,
-					None,
-				);
-				return None;
-			}
-		};
+			.filter(|e| e.selector() == log.topics[0])
+			.peekable();
+		if events.peek().is_none() {
+			FilterError::internal_error(
+				format!("No matching event found for log topic: {:?}", log.topics[0]),
+				None,
+				None,
+			);
+			return None;
+		}
 
-		// Decode event in one call (covering non-indexed and indexed params)
-		let log_data = match LogData::new(log.topics.clone(), log.data.clone()) {
-			Some(data) => data,
-			None => {
-				FilterError::internal_error(
-					format!("Failed to create log data: {:?}", log.topics[0]),
-					None,
-					None,
-				);
-				return None;
+		// Events sharing a signature only differ by which parameters are indexed, so the log is
+		// decoded with the first of them matching its topics and data
+		let mut decode_error = String::new();
+		let (event, decoded_params) = match events.find_map(|event| {
+			match decode_event_params(&event, &log.topics, &log.data) {
+				Ok(params) => Some((event, params)),
+				Err(e) => {
+					decode_error = e;
+					None
+				}
 			}
-		};
-		let decoded = match event.decode_log(&log_data) {
-			Ok(decoded) => decoded,
-			Err(e) => {
-				FilterError::internal_error(
-					format!("Failed to decode log data: {:?}", e.to_string()),
-					Some(e.into()),
-					None,
-				);
+		}) {
+			Some(decoded) => decoded,
+			None => {
+				FilterError::internal_error(decode_error, None, None);
 				return None;
 			}
 		};
 
-		// Build two iterators (we always have both indexed and non-indexed params in the exact sequence declared in the ABI)
-		let mut indexed_vals = decoded.indexed.into_iter().map(|v| format_token_value(&v));
-		let mut body_vals = decoded.body.into_iter().map(|v| format_token_value(&v));
-
-		// Map over the event inputs
-		let decoded_params: Vec<_> = event
-			.inputs
-			.iter()
-			.map(|param| {
-				let (value, indexed) = if param.indexed {
-					// pull from our indexed iterator
-					(indexed_vals.next().unwrap_or_default(), true)
-				} else {
-					// pull from our body iterator
-					(body_vals.next().unwrap_or_default(), false)
-				};
-
-				EVMMatchParamEntry {
-					name: param.name.clone(),
-					value,
-					kind: param.ty.to_string(),
-					indexed,
-				}
-			})
-			.collect();
-
 		Some(EVMMatchParamsMap {
 			signature: format!(
 				"{}({})",
@@ -640,19 +1300,267 @@ impl<T> EVMBlockFilter<T> {
 				// If the status is not Any, and there are no logs, we need a receipt to validate the transaction most likely failed
 				let status_needs_receipt =
 					condition.status != TransactionStatus::Any && logs.is_empty();
-				// If the expression contains gas_used, we need a receipt to get the gas used
-				let gas_used_in_expr = condition
-					.clone()
-					.expression
-					.is_some_and(|expr| expr.contains("gas_used"));
-				status_needs_receipt || gas_used_in_expr
+				// If the expression contains gas_used, effective_gas_price or the revert of the
+				// transaction, we need a receipt to get them
+				let receipt_in_expr = [&condition.expression, &condition.warn_threshold_expression]
+					.into_iter()
+					.flatten()
+					.any(|expr| {
+						[
+							"gas_used",
+							"effective_gas_price",
+							"revert_reason",
+							"revert_selector",
+						]
+						.iter()
+						.any(|field| expr.contains(field))
+					});
+				status_needs_receipt || receipt_in_expr
 			})
 	}
+
+	/// Checks if a monitor matches on the revert of failed transactions, whose revert data is
+	/// then traced when their receipt doesn't include it
+	fn needs_revert_data(&self, monitor: &Monitor) -> bool {
+		monitor
+			.match_conditions
+			.transactions
+			.iter()
+			.flat_map(|condition| [&condition.expression, &condition.warn_threshold_expression])
+			.flatten()
+			.any(|expr| expr.contains("revert_reason") || expr.contains("revert_selector"))
+	}
+
+	/// Checks if a monitor matches on the hash of the code deployed by contract creations, which
+	/// is then fetched for each contract creation transaction
+	fn needs_code_hash(&self, monitor: &Monitor) -> bool {
+		monitor
+			.match_conditions
+			.transactions
+			.iter()
+			.flat_map(|condition| [&condition.expression, &condition.warn_threshold_expression])
+			.flatten()
+			.any(|expr| expr.contains("code_hash"))
+	}
+
+	/// Checks if a monitor can be evaluated from the logs of its monitored events alone
+	///
+	/// Monitors with only event conditions never need a transaction receipt, and can only match
+	/// transactions that emitted one of their events.
+	///
+	/// # Arguments
+	/// * `monitor` - Monitor to check
+	///
+	/// # Returns
+	/// * `bool` - True if the monitor only has event conditions
+	pub fn is_logs_only(&self, monitor: &Monitor) -> bool {
+		let conditions = &monitor.match_conditions;
+		conditions.has_event_conditions()
+			&& conditions.functions.is_empty()
+			&& conditions.transactions.is_empty()
+	}
+
+	/// Collects the addresses and event topics watched by the given monitors
+	///
+	/// Topics are the selectors of the ABI events matching an event condition of the monitor, as
+	/// events without an ABI can't be decoded and never match.
+	///
+	/// # Arguments
+	/// * `monitors` - Monitors to collect the addresses and topics of
+	///
+	/// # Returns
+	/// * `(Vec<String>, Vec<String>)` - Deduplicated addresses and topics
+	pub fn monitored_events(&self, monitors: &[Monitor]) -> (Vec<String>, Vec<String>) {
+		let mut addresses = Vec::new();
+		let mut topics = Vec::new();
+
+		for monitor in monitors {
+			// Monitors with only block conditions don't watch any event
+			if monitor.match_conditions.is_block_only() {
+				continue;
+			}
+			if !monitor.match_conditions.token_transfers.is_empty() {
+				topics.extend(TOKEN_TRANSFER_TOPICS.iter().copied().map(b256_to_string));
+			}
+
+			for address in &monitor.addresses {
+				addresses.push(format!("0x{}", normalize_address(&address.address)));
+
+				let Some(ContractSpec::EVM(spec)) = &address.contract_spec else {
+					continue;
+				};
+				let Ok(abi) = serde_json::from_slice::<JsonAbi>(spec.to_string().as_bytes()) else {
+					continue;
+				};
+
+				for event in abi.events() {
+					let signature = event.signature();
+					if monitor
+						.match_conditions
+						.events
+						.iter()
+						.any(|condition| are_same_signature(&condition.signature, &signature))
+					{
+						topics.push(b256_to_string(event.selector()));
+					}
+				}
+			}
+		}
+
+		addresses.sort_unstable();
+		addresses.dedup();
+		topics.sort_unstable();
+		topics.dedup();
+
+		(addresses, topics)
+	}
+
+	/// Finds the monitors matching a pending transaction.
+	///
+	/// Pending transactions have no receipt or logs yet, so only function and transaction
+	/// conditions are evaluated, as for a block where no event matched. The status of the
+	/// transaction is unknown: transaction conditions requiring a status don't match, nor do
+	/// expressions on `gas_used`, `effective_gas_price` or `base_fee_per_gas`. Monitors with
+	/// state conditions are skipped, as their calls are made at the matched block.
+	///
+	/// # Arguments
+	/// * `network` - Network the transaction was sent to
+	/// * `transaction` - The pending transaction
+	/// * `monitors` - Active monitors of the network
+	/// * `contract_specs` - Contract specs of the monitored addresses
+	///
+	/// # Returns
+	/// The matches of the transaction, tagged as pending
+	pub fn filter_pending_transaction<'a>(
+		&self,
+		network: &Network,
+		transaction: &EVMTransaction,
+		monitors: impl IntoIterator<Item = &'a Monitor>,
+		contract_specs: &[(String, EVMContractSpec)],
+	) -> Vec<MonitorMatch> {
+		let mut involved_addresses = Vec::new();
+		if let Some(from) = transaction.from {
+			involved_addresses.push(h160_to_string(from));
+		}
+		if let Some(to) = transaction.to {
+			involved_addresses.push(h160_to_string(to));
+		}
+		if let Some(created_address) = created_contract_address(transaction, &None) {
+			involved_addresses.push(h160_to_string(created_address));
+		}
+
+		let mut monitor_matches = Vec::new();
+		for monitor in monitors {
+			// Monitors with only event conditions can't match before the transaction is mined,
+			// nor can monitors with only block conditions or with state conditions
+			if self.is_logs_only(monitor)
+				|| monitor.match_conditions.is_block_only()
+				|| !monitor.match_conditions.state_conditions.is_empty()
+				|| !involved_addresses
+					.iter()
+					.any(|address| monitor.matches_address(address, normalize_address))
+			{
+				continue;
+			}
+
+			let mut matched_transactions = Vec::<TransactionCondition>::new();
+			let mut matched_functions = Vec::<FunctionCondition>::new();
+			let mut matched_on_args = EVMMatchArguments {
+				events: None,
+				functions: Some(Vec::new()),
+				block: None,
+				balance: None,
+			};
+			self.find_matching_transaction(
+				&TransactionStatus::Any,
+				transaction,
+				&None,
+				None,
+				None,
+				monitor,
+				&mut matched_transactions,
+			);
+			self.find_matching_functions_for_transaction(
+				contract_specs,
+				transaction,
+				monitor,
+				&mut matched_functions,
+				&mut matched_on_args,
+			);
+
+			let monitor_conditions = &monitor.match_conditions;
+			let has_function_match =
+				!monitor_conditions.functions.is_empty() && !matched_functions.is_empty();
+			let has_transaction_match =
+				!monitor_conditions.transactions.is_empty() && !matched_transactions.is_empty();
+
+			let should_match = match (
+				!monitor_conditions.has_event_conditions(),
+				monitor_conditions.functions.is_empty(),
+				monitor_conditions.transactions.is_empty(),
+			) {
+				(true, true, true) => true,
+				(true, true, false) => has_transaction_match,
+				(_, _, true) => has_function_match,
+				_ => has_function_match && has_transaction_match,
+			};
+			if !should_match {
+				continue;
+			}
+
+			let sender_verified = verify_sender(monitor, transaction);
+			if sender_verified == Some(false) && monitor.strict_signature_verification {
+				continue;
+			}
+
+			monitor_matches.push(MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+				monitor: without_contract_specs(monitor),
+				transaction: transaction.clone(),
+				receipt: None,
+				logs: None,
+				network_slug: network.slug.clone(),
+				matched_on: MatchConditions {
+					events: Vec::new(),
+					functions: matched_functions
+						.into_iter()
+						.filter(|_| has_function_match)
+						.collect(),
+					transactions: matched_transactions
+						.into_iter()
+						.filter(|_| has_transaction_match)
+						.collect(),
+					token_transfers: vec![],
+					block_conditions: vec![],
+					balance_conditions: vec![],
+					state_conditions: vec![],
+				},
+				matched_on_args: Some(EVMMatchArguments {
+					events: None,
+					functions: matched_on_args.functions.filter(|_| has_function_match),
+					block: None,
+					balance: None,
+				}),
+				confirmation_state: EVMConfirmationState::Pending,
+				severity: MatchSeverity::Alert,
+				sender_verified,
+			})));
+		}
+
+		monitor_matches
+	}
 }
 
 #[async_trait]
 impl<T: BlockChainClient + EvmClientTrait> BlockFilter for EVMBlockFilter<T> {
 	type Client = T;
+
+	fn with_rpc_results(self, rpc_results: RpcResults) -> Self {
+		Self {
+			rpc_results,
+			..self
+		}
+	}
+
 	/// Processes a block and finds matches based on monitor conditions.
 	///
 	/// # Arguments
@@ -692,9 +1600,42 @@ impl<T: BlockChainClient + EvmClientTrait> BlockFilter for EVMBlockFilter<T> {
 		// We could further optimize by getting logs for a range of blocks and calling this in the parent function
 		// However, due to limitations by certain RPC providers (e.g. Quicknode only allows a block range of 5),
 		// it's safer to just fetch the logs for a single block at a time as it's more reliable.
-		let all_block_logs = client
-			.get_logs_for_blocks(current_block_number, current_block_number, None)
-			.await?;
+		// When all monitors only have event conditions, only the logs of their events are needed,
+		// so they are fetched filtered by address and topic. Otherwise all block logs are fetched,
+		// and receipts are fetched per transaction when required. Address patterns can't be
+		// expressed in a log filter, so monitors using them always take the full path.
+		// Monitors with only block conditions need no logs.
+		let logs_only = monitors.iter().all(|monitor| {
+			monitor.match_conditions.is_block_only()
+				|| (self.is_logs_only(monitor)
+					&& monitor
+						.addresses
+						.iter()
+						.all(|address| address.match_mode.is_exact()))
+		});
+		let all_block_logs = if logs_only {
+			let (addresses, topics) = self.monitored_events(monitors);
+			if addresses.is_empty() || topics.is_empty() {
+				Vec::new()
+			} else {
+				client
+					.get_event_logs_for_blocks(
+						current_block_number,
+						current_block_number,
+						addresses,
+						topics,
+					)
+					.await?
+			}
+		} else {
+			client
+				.get_logs_for_blocks(current_block_number, current_block_number, None)
+				.await?
+		};
+
+		EVM_FILTER_PATH_TOTAL
+			.with_label_values(&[&network.slug, if logs_only { "logs" } else { "receipt" }])
+			.inc();
 
 		tracing::debug!(
 			"Found {} logs for block {}",
@@ -702,8 +1643,6 @@ impl<T: BlockChainClient + EvmClientTrait> BlockFilter for EVMBlockFilter<T> {
 			current_block_number
 		);
 
-		let mut matching_results = Vec::new();
-
 		// Cast contract specs to EVMContractSpec
 		let contract_specs = contract_specs
 			.unwrap_or(&[])
@@ -724,194 +1663,413 @@ impl<T: BlockChainClient + EvmClientTrait> BlockFilter for EVMBlockFilter<T> {
 
 		tracing::debug!("Processing {} transactions with logs", logs_by_tx.len());
 
-		for monitor in monitors {
-			tracing::debug!("Processing monitor: {:?}", monitor.name);
-			let monitored_addresses: Vec<String> = monitor
-				.addresses
-				.iter()
-				.map(|a| a.address.clone())
-				.collect();
-
-			// Check if this monitor needs a receipt
-			let should_fetch_receipt = self.needs_receipt(monitor, &all_block_logs);
+		// Tracing is expensive, so the block is only traced when a monitor opted in to matching
+		// internal transactions
+		let internal_calls = if monitors
+			.iter()
+			.any(|monitor| monitor.includes_internal_transactions())
+		{
+			let trace_method = network.trace_method.ok_or_else(|| {
+				FilterError::internal_error(
+					format!(
+						"Network '{}' has no trace_method to match internal transactions",
+						network.slug
+					),
+					None,
+					None,
+				)
+			})?;
+			client
+				.get_internal_calls(current_block_number, trace_method)
+				.await
+				.map_err(|e| {
+					FilterError::network_error(
+						format!(
+							"Failed to get internal transactions of block {}",
+							current_block_number
+						),
+						Some(e.into()),
+						None,
+					)
+				})?
+		} else {
+			Vec::new()
+		};
+		let mut internal_calls_by_tx: std::collections::HashMap<usize, Vec<&EVMInternalCall>> =
+			std::collections::HashMap::new();
+		for call in &internal_calls {
+			internal_calls_by_tx
+				.entry(call.transaction_position)
+				.or_default()
+				.push(call);
+		}
 
-			// Process all transactions in the block
-			for transaction in &evm_block.transactions {
-				let tx_hash = b256_to_string(transaction.hash);
-				let empty_logs = Vec::new();
-				let logs = logs_by_tx.get(&tx_hash).unwrap_or(&empty_logs);
-				let tx_hash_str = tx_hash.clone();
+		// Evaluate up to the network's monitor concurrency at once, so receipt requests of
+		// different monitors overlap. Shared data is borrowed for the monitors' futures.
+		let logs_by_tx = &logs_by_tx;
+		let internal_calls_by_tx = &internal_calls_by_tx;
+		let all_block_logs = &all_block_logs;
+		let contract_specs = &contract_specs;
+		let block_params = &self.block_params(evm_block);
+
+		filter_monitors(
+			monitors,
+			network.get_monitor_concurrency(),
+			|monitor| async move {
+				let mut monitor_matches = Vec::new();
+
+				tracing::debug!("Processing monitor: {:?}", monitor.name);
+
+				// Block conditions are evaluated once for the whole block
+				let mut matched_blocks = Vec::<BlockCondition>::new();
+				self.find_matching_block_conditions(block_params, monitor, &mut matched_blocks);
+				if !matched_blocks.is_empty() {
+					monitor_matches.push(MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+						monitor: without_contract_specs(monitor),
+						transaction: EVMTransaction::default(),
+						receipt: None,
+						logs: None,
+						network_slug: network.slug.clone(),
+						matched_on: MatchConditions {
+							block_conditions: matched_blocks,
+							..Default::default()
+						},
+						matched_on_args: Some(EVMMatchArguments {
+							functions: None,
+							events: None,
+							block: Some(block_params.clone()),
+							balance: None,
+						}),
+						confirmation_state: EVMConfirmationState::Confirmed,
+						severity: MatchSeverity::Alert,
+						sender_verified: None,
+					})));
+				}
+				if monitor.match_conditions.is_block_only() {
+					return Ok(monitor_matches);
+				}
 
-				let receipt = if should_fetch_receipt {
-					Some(client.get_transaction_receipt(tx_hash_str).await?)
+				// Check if this monitor needs a receipt
+				let should_fetch_receipt = self.needs_receipt(monitor, all_block_logs);
+				let should_fetch_code = self.needs_code_hash(monitor);
+				// Revert data is only traced on networks tracing with `debug_traceBlockByNumber`,
+				// whose endpoints also support `debug_traceTransaction`
+				let should_trace_revert = self.needs_revert_data(monitor)
+					&& network.trace_method == Some(EVMTraceMethod::DebugTraceBlockByNumber);
+				let is_logs_only = self.is_logs_only(monitor);
+				let includes_internal_transactions = monitor.includes_internal_transactions();
+
+				// Transactions not matching the monitor conditions are matched again with the warn
+				// threshold expressions, as near misses
+				let warn_monitor = monitor.warn_threshold_monitor();
+				let passes = std::iter::once((MatchSeverity::Alert, monitor))
+					.chain(
+						warn_monitor
+							.iter()
+							.map(|warn| (MatchSeverity::Warning, warn)),
+					)
+					.collect::<Vec<_>>();
+
+				// Receipts of the transactions to evaluate are fetched at once, which batches
+				// their requests
+				let mut receipts = if should_fetch_receipt {
+					let tx_hashes: Vec<String> = evm_block
+						.transactions
+						.iter()
+						.map(|transaction| b256_to_string(transaction.hash))
+						.filter(|tx_hash| !is_logs_only || logs_by_tx.contains_key(tx_hash))
+						.collect();
+					tx_hashes
+						.clone()
+						.into_iter()
+						.zip(client.get_transaction_receipts(tx_hashes).await?)
+						.collect::<std::collections::HashMap<_, _>>()
 				} else {
-					None
+					std::collections::HashMap::new()
 				};
 
-				// Reset matched_on_args for each transaction
-				let mut matched_on_args = EVMMatchArguments {
-					events: Some(Vec::new()),
-					functions: Some(Vec::new()),
-				};
+				// Process all transactions in the block
+				for (position, transaction) in evm_block.transactions.iter().enumerate() {
+					let tx_hash = b256_to_string(transaction.hash);
+					let empty_logs = Vec::new();
+					let logs = logs_by_tx.get(&tx_hash).unwrap_or(&empty_logs);
+					let internal_calls = internal_calls_by_tx
+						.get(&position)
+						.filter(|_| includes_internal_transactions)
+						.map(Vec::as_slice)
+						.unwrap_or_default();
+
+					// Monitors with only event conditions can't match transactions without logs
+					if is_logs_only && logs.is_empty() {
+						continue;
+					}
+					let tx_hash_str = tx_hash.clone();
 
-				// Get transaction status from receipt
-				let tx_status = if let Some(receipt) = receipt.clone() {
-					if receipt.status.map(|s| s.to::<u64>() == 1).unwrap_or(false) {
-						TransactionStatus::Success
+					let mut receipt = if should_fetch_receipt {
+						match receipts.remove(&tx_hash_str) {
+							Some(receipt) => Some(receipt),
+							None => Some(client.get_transaction_receipt(tx_hash_str).await?),
+						}
+					} else {
+						None
+					};
+
+					// Get transaction status from receipt
+					let tx_status = if let Some(receipt) = receipt.clone() {
+						if receipt.status.map(|s| s.to::<u64>() == 1).unwrap_or(false) {
+							TransactionStatus::Success
+						} else {
+							TransactionStatus::Failure
+						}
 					} else {
-						TransactionStatus::Failure
+						// Transaction receipt is only fetched when:
+						// 1. The monitor has conditions requiring receipt data (e.g., gas_used)
+						// 2. We need to verify transaction status and have no logs
+						// Otherwise, we can assume success since failed transactions don't emit logs
+						TransactionStatus::Success
+					};
+
+					// Trace the revert data of failed transactions missing from their receipt,
+					// leaving it empty if the endpoint can't trace them
+					if let Some(receipt) = receipt.as_mut().filter(|receipt| {
+						should_trace_revert
+							&& tx_status == TransactionStatus::Failure
+							&& receipt.revert_reason.is_none()
+					}) {
+						match client.get_revert_data(&tx_hash).await {
+							Ok(revert_data) => receipt.revert_reason = revert_data.map(Into::into),
+							Err(e) => tracing::warn!(
+								"Failed to trace revert data of transaction {}: {}",
+								tx_hash,
+								e
+							),
+						}
 					}
-				} else {
-					// Transaction receipt is only fetched when:
-					// 1. The monitor has conditions requiring receipt data (e.g., gas_used)
-					// 2. We need to verify transaction status and have no logs
-					// Otherwise, we can assume success since failed transactions don't emit logs
-					TransactionStatus::Success
-				};
 
-				// Collect all involved addresses from receipt logs, transaction.to, and transaction.from
-				let mut involved_addresses = Vec::new();
-				// Add transaction addresses
-				if let Some(from) = transaction.from {
-					involved_addresses.push(h160_to_string(from));
-				}
-				if let Some(to) = transaction.to {
-					involved_addresses.push(h160_to_string(to));
-				}
+					// Collect all involved addresses from receipt logs, transaction.to, and transaction.from
+					let mut involved_addresses = Vec::new();
+					// Add transaction addresses
+					if let Some(from) = transaction.from {
+						involved_addresses.push(h160_to_string(from));
+					}
+					if let Some(to) = transaction.to {
+						involved_addresses.push(h160_to_string(to));
+					}
+					// Add the addresses of internal calls
+					for call in internal_calls {
+						involved_addresses.push(h160_to_string(call.from));
+						if let Some(to) = call.to {
+							involved_addresses.push(h160_to_string(to));
+						}
+					}
+					// Add the contract created by the transaction, whose deployed code is only
+					// fetched for monitors matching on its hash
+					let created_address = created_contract_address(transaction, &receipt);
+					let mut code_hash = None;
+					if let Some(created_address) = created_address.map(h160_to_string) {
+						if should_fetch_code {
+							let code = client
+								.get_code(&created_address, current_block_number)
+								.await
+								.map_err(|e| {
+									FilterError::network_error(
+										format!(
+											"Failed to get code of contract {}",
+											created_address
+										),
+										Some(e.into()),
+										None,
+									)
+								})?;
+							code_hash = Some(keccak256(code));
+						}
+						involved_addresses.push(created_address);
+					}
 
-				let mut matched_events = Vec::<EventCondition>::new();
-				let mut matched_transactions = Vec::<TransactionCondition>::new();
-				let mut matched_functions = Vec::<FunctionCondition>::new();
+					// Near misses are only looked for when the monitor conditions aren't met
+					for &(severity, conditions_monitor) in &passes {
+						let mut matched_on_args = EVMMatchArguments {
+							events: Some(Vec::new()),
+							functions: Some(Vec::new()),
+							block: None,
+							balance: None,
+						};
+						let mut involved_addresses = involved_addresses.clone();
+
+						let mut matched_events = Vec::<EventCondition>::new();
+						let mut matched_transactions = Vec::<TransactionCondition>::new();
+						let mut matched_functions = Vec::<FunctionCondition>::new();
+
+						// Check transaction match conditions
+						self.find_matching_transaction(
+							&tx_status,
+							transaction,
+							&receipt.clone(),
+							evm_block.base_fee_per_gas,
+							code_hash,
+							conditions_monitor,
+							&mut matched_transactions,
+						);
+						if matched_transactions.is_empty() {
+							self.find_matching_internal_calls(
+								&tx_status,
+								transaction,
+								&receipt,
+								evm_block.base_fee_per_gas,
+								internal_calls,
+								conditions_monitor,
+								&mut matched_transactions,
+							);
+						}
 
-				// Check transaction match conditions
-				self.find_matching_transaction(
-					&tx_status,
-					transaction,
-					&receipt.clone(),
-					monitor,
-					&mut matched_transactions,
-				);
+						// Check for event match conditions
+						self.find_matching_events_for_transaction(
+							logs,
+							conditions_monitor,
+							&mut matched_events,
+							&mut matched_on_args,
+							&mut involved_addresses,
+						);
 
-				// Check for event match conditions
-				self.find_matching_events_for_transaction(
-					logs,
-					monitor,
-					&mut matched_events,
-					&mut matched_on_args,
-					&mut involved_addresses,
-				);
+						// Check function match conditions
+						self.find_matching_functions_for_transaction(
+							contract_specs,
+							transaction,
+							conditions_monitor,
+							&mut matched_functions,
+							&mut matched_on_args,
+						);
 
-				// Check function match conditions
-				self.find_matching_functions_for_transaction(
-					&contract_specs,
-					transaction,
-					monitor,
-					&mut matched_functions,
-					&mut matched_on_args,
-				);
+						// Remove duplicates
+						involved_addresses.sort_unstable();
+						involved_addresses.dedup();
 
-				// Remove duplicates
-				involved_addresses.sort_unstable();
-				involved_addresses.dedup();
+						let has_address_match = involved_addresses
+							.iter()
+							.any(|address| monitor.matches_address(address, normalize_address));
 
-				let has_address_match = monitored_addresses.iter().any(|addr| {
-					involved_addresses
-						.iter()
-						.map(|a| normalize_address(a))
-						.collect::<Vec<String>>()
-						.contains(&normalize_address(addr))
-				});
+						// Only proceed if we have a matching address
+						if !has_address_match {
+							continue;
+						}
 
-				// Only proceed if we have a matching address
-				if has_address_match {
-					let monitor_conditions = &monitor.match_conditions;
-					let has_event_match =
-						!monitor_conditions.events.is_empty() && !matched_events.is_empty();
-					let has_function_match =
-						!monitor_conditions.functions.is_empty() && !matched_functions.is_empty();
-					let has_transaction_match = !monitor_conditions.transactions.is_empty()
-						&& !matched_transactions.is_empty();
-
-					let should_match: bool = match (
-						monitor_conditions.events.is_empty(),
-						monitor_conditions.functions.is_empty(),
-						monitor_conditions.transactions.is_empty(),
-					) {
-						// Case 1: No conditions defined, match everything
-						(true, true, true) => true,
-
-						// Case 2: Only transaction conditions defined
-						(true, true, false) => has_transaction_match,
-
-						// Case 3: No transaction conditions, match based on events/functions
-						(_, _, true) => has_event_match || has_function_match,
-
-						// Case 4: Transaction conditions exist, they must be satisfied along
-						// with events/functions
-						_ => (has_event_match || has_function_match) && has_transaction_match,
-					};
+						let monitor_conditions = &monitor.match_conditions;
+						let has_event_match =
+							monitor_conditions.has_event_conditions() && !matched_events.is_empty();
+						let has_function_match = !monitor_conditions.functions.is_empty()
+							&& !matched_functions.is_empty();
+						let has_transaction_match = !monitor_conditions.transactions.is_empty()
+							&& !matched_transactions.is_empty();
+
+						let should_match: bool = match (
+							!monitor_conditions.has_event_conditions(),
+							monitor_conditions.functions.is_empty(),
+							monitor_conditions.transactions.is_empty(),
+						) {
+							// Case 1: No conditions defined, match everything
+							(true, true, true) => true,
+
+							// Case 2: Only transaction conditions defined
+							(true, true, false) => has_transaction_match,
+
+							// Case 3: No transaction conditions, match based on events/functions
+							(_, _, true) => has_event_match || has_function_match,
+
+							// Case 4: Transaction conditions exist, they must be satisfied along
+							// with events/functions
+							_ => (has_event_match || has_function_match) && has_transaction_match,
+						};
 
-					if should_match {
-						matching_results.push(MonitorMatch::EVM(Box::new(EVMMonitorMatch {
-							monitor: Monitor {
-								// Omit ABI from monitor since we do not need it here
-								addresses: monitor
-									.addresses
-									.iter()
-									.map(|addr| AddressWithSpec {
-										contract_spec: None,
-										..addr.clone()
-									})
-									.collect(),
-								..monitor.clone()
-							},
-							transaction: transaction.clone(),
-							receipt,
-							logs: Some(logs.clone()),
-							network_slug: network.slug.clone(),
-							matched_on: MatchConditions {
-								events: matched_events
-									.clone()
-									.into_iter()
-									.filter(|_| has_event_match)
-									.collect(),
-								functions: matched_functions
-									.clone()
-									.into_iter()
-									.filter(|_| has_function_match)
-									.collect(),
-								transactions: matched_transactions
-									.clone()
-									.into_iter()
-									.filter(|_| has_transaction_match)
-									.collect(),
-							},
-							matched_on_args: Some(EVMMatchArguments {
-								events: if has_event_match {
-									matched_on_args.events.clone()
-								} else {
-									None
-								},
-								functions: if has_function_match {
-									matched_on_args.functions.clone()
-								} else {
-									None
+						// State conditions are checked last, as they call the matched contract,
+						// i.e. the recipient of the transaction if monitored, or else the first
+						// monitored address involved
+						if should_match && !monitor_conditions.state_conditions.is_empty() {
+							let contract = transaction
+								.to
+								.map(h160_to_string)
+								.filter(|to| monitor.matches_address(to, normalize_address))
+								.or_else(|| {
+									involved_addresses
+										.iter()
+										.find(|address| {
+											monitor.matches_address(address, normalize_address)
+										})
+										.cloned()
+								})
+								.unwrap_or_default();
+							if !self
+								.check_state_conditions(
+									client,
+									monitor,
+									&contract,
+									current_block_number,
+								)
+								.await?
+							{
+								break;
+							}
+						}
+
+						if should_match {
+							let sender_verified = verify_sender(monitor, transaction);
+							if sender_verified == Some(false)
+								&& monitor.strict_signature_verification
+							{
+								break;
+							}
+
+							monitor_matches.push(MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+								monitor: without_contract_specs(monitor),
+								transaction: transaction.clone(),
+								receipt,
+								logs: Some(logs.clone()),
+								network_slug: network.slug.clone(),
+								matched_on: MatchConditions {
+									events: matched_events
+										.into_iter()
+										.filter(|_| has_event_match)
+										.collect(),
+									functions: matched_functions
+										.into_iter()
+										.filter(|_| has_function_match)
+										.collect(),
+									transactions: matched_transactions
+										.into_iter()
+										.filter(|_| has_transaction_match)
+										.collect(),
+									token_transfers: vec![],
+									block_conditions: vec![],
+									balance_conditions: vec![],
+									state_conditions: monitor_conditions.state_conditions.clone(),
 								},
-							}),
-						})));
+								matched_on_args: Some(EVMMatchArguments {
+									events: matched_on_args.events.filter(|_| has_event_match),
+									functions: matched_on_args
+										.functions
+										.filter(|_| has_function_match),
+									block: None,
+									balance: None,
+								}),
+								confirmation_state: EVMConfirmationState::Confirmed,
+								severity,
+								sender_verified,
+							})));
+							break;
+						}
 					}
 				}
-			}
-		}
 
-		Ok(matching_results)
+				Ok(monitor_matches)
+			},
+		)
+		.await
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use crate::{
-		models::{ContractSpec, EVMContractSpec},
+		models::{AddressMatchMode, ContractSpec, EVMContractSpec, TokenTransferCondition},
 		utils::tests::evm::{
 			monitor::MonitorBuilder, receipt::ReceiptBuilder, transaction::TransactionBuilder,
 		},
@@ -928,6 +2086,7 @@ mod tests {
 	fn create_test_filter() -> EVMBlockFilter<()> {
 		EVMBlockFilter::<()> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		}
 	}
 
@@ -945,6 +2104,10 @@ mod tests {
 				events: event_conditions,
 				functions: function_conditions,
 				transactions: transaction_conditions,
+				token_transfers: vec![],
+				block_conditions: vec![],
+				balance_conditions: vec![],
+				state_conditions: vec![],
 			})
 			.addresses_with_spec(
 				addresses
@@ -1013,6 +2176,7 @@ mod tests {
 		AddressWithSpec {
 			address: address.to_string(),
 			contract_spec: spec,
+			match_mode: AddressMatchMode::Exact,
 		}
 	}
 
@@ -1056,6 +2220,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&TransactionBuilder::new().build(),
 			&Some(receipt),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1076,6 +2242,8 @@ mod tests {
 			vec![TransactionCondition {
 				status: TransactionStatus::Success,
 				expression: None,
+				warn_threshold_expression: None,
+				is_contract_creation: false,
 			}], // transactions
 			vec![], // addresses
 		);
@@ -1087,6 +2255,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&TransactionBuilder::new().build(),
 			&Some(receipt_success),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1102,6 +2272,8 @@ mod tests {
 			&TransactionStatus::Failure,
 			&TransactionBuilder::new().build(),
 			&Some(receipt_failure),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1109,6 +2281,100 @@ mod tests {
 		assert_eq!(matched.len(), 0);
 	}
 
+	#[test]
+	fn test_contract_creation_matching() {
+		let filter = create_test_filter();
+		let deployer = Address::from_str("0xb20a608c624ca5003905aa834de7156c68b2e1d0").unwrap();
+		let created_address = deployer.create(3);
+		let code_hash = keccak256([0x60, 0x80, 0x60, 0x40]);
+
+		let creation_monitor = |expression: Option<String>| {
+			create_test_monitor(
+				vec![], // events
+				vec![], // functions
+				vec![TransactionCondition {
+					status: TransactionStatus::Any,
+					expression,
+					warn_threshold_expression: None,
+					is_contract_creation: true,
+				}], // transactions
+				vec![], // addresses
+			)
+		};
+		// Without a recipient
+		let deployment = TransactionBuilder::new()
+			.from(deployer)
+			.nonce(U256::from(3))
+			.build();
+		let call = TransactionBuilder::new()
+			.from(deployer)
+			.to(created_address)
+			.build();
+
+		let mut matched = Vec::new();
+		filter.find_matching_transaction(
+			&TransactionStatus::Success,
+			&deployment,
+			&None,
+			None,
+			None,
+			&creation_monitor(None),
+			&mut matched,
+		);
+		assert_eq!(matched.len(), 1);
+		assert!(matched[0].is_contract_creation);
+
+		matched.clear();
+		filter.find_matching_transaction(
+			&TransactionStatus::Success,
+			&call,
+			&None,
+			None,
+			None,
+			&creation_monitor(None),
+			&mut matched,
+		);
+		assert!(matched.is_empty());
+
+		// The created address is read from the receipt
+		let monitor = creation_monitor(Some(format!(
+			"created_contract_address == {}",
+			h160_to_string(created_address)
+		)));
+		let receipt = ReceiptBuilder::new()
+			.contract_address(created_address)
+			.build();
+		matched.clear();
+		filter.find_matching_transaction(
+			&TransactionStatus::Success,
+			&deployment,
+			&Some(receipt),
+			None,
+			None,
+			&monitor,
+			&mut matched,
+		);
+		assert_eq!(matched.len(), 1);
+
+		// The code hash only matches once fetched
+		let monitor = creation_monitor(Some(format!("code_hash == {}", b256_to_string(code_hash))));
+		for (fetched_code_hash, expected_matches) in [(None, 0), (Some(code_hash), 1)] {
+			matched.clear();
+			filter.find_matching_transaction(
+				&TransactionStatus::Success,
+				&deployment,
+				&None,
+				None,
+				fetched_code_hash,
+				&monitor,
+				&mut matched,
+			);
+			assert_eq!(matched.len(), expected_matches);
+		}
+		assert!(filter.needs_code_hash(&monitor));
+		assert!(!filter.needs_code_hash(&creation_monitor(None)));
+	}
+
 	#[test]
 	fn test_expression_matching() {
 		let filter = create_test_filter();
@@ -1119,6 +2385,8 @@ mod tests {
 			vec![TransactionCondition {
 				status: TransactionStatus::Any,
 				expression: Some("value > 100".to_string()),
+				warn_threshold_expression: None,
+				is_contract_creation: false,
 			}], // transactions
 			vec![], // addresses
 		);
@@ -1134,6 +2402,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_1,
 			&Some(tx_receipt_1),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1153,6 +2423,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_2,
 			&Some(tx_receipt_2),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1172,6 +2444,8 @@ mod tests {
 			vec![TransactionCondition {
 				status: TransactionStatus::Any,
 				expression: Some(format!("to == {}", h160_to_string(test_address))),
+				warn_threshold_expression: None,
+				is_contract_creation: false,
 			}], // transactions
 			vec![], // addresses
 		);
@@ -1187,6 +2461,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1207,6 +2483,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1226,6 +2504,8 @@ mod tests {
 			vec![TransactionCondition {
 				status: TransactionStatus::Any,
 				expression: Some(format!("from == {}", h160_to_string(test_address))),
+				warn_threshold_expression: None,
+				is_contract_creation: false,
 			}], // transactions
 			vec![], // addresses
 		);
@@ -1241,6 +2521,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1261,6 +2543,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1274,6 +2558,8 @@ mod tests {
 		let condition = TransactionCondition {
 			status: TransactionStatus::Any,
 			expression: Some(expression.clone()),
+			warn_threshold_expression: None,
+			is_contract_creation: false,
 		};
 		let filter = create_test_filter();
 		let mut matched = Vec::new();
@@ -1292,6 +2578,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1313,6 +2601,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1326,6 +2616,8 @@ mod tests {
 		let condition = TransactionCondition {
 			status: TransactionStatus::Any,
 			expression: Some(expression.clone()),
+			warn_threshold_expression: None,
+			is_contract_creation: false,
 		};
 		let filter = create_test_filter();
 		let mut matched = Vec::new();
@@ -1344,6 +2636,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1364,6 +2658,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1376,6 +2672,8 @@ mod tests {
 		let condition = TransactionCondition {
 			status: TransactionStatus::Any,
 			expression: Some(expression.clone()),
+			warn_threshold_expression: None,
+			is_contract_creation: false,
 		};
 		let filter = create_test_filter();
 		let mut matched = Vec::new();
@@ -1394,6 +2692,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1414,6 +2714,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1426,6 +2728,8 @@ mod tests {
 		let condition = TransactionCondition {
 			status: TransactionStatus::Any,
 			expression: Some(expression.clone()),
+			warn_threshold_expression: None,
+			is_contract_creation: false,
 		};
 		let filter = create_test_filter();
 		let mut matched = Vec::new();
@@ -1444,6 +2748,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1464,6 +2770,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1476,6 +2784,8 @@ mod tests {
 		let condition = TransactionCondition {
 			status: TransactionStatus::Any,
 			expression: Some(expression.clone()),
+			warn_threshold_expression: None,
+			is_contract_creation: false,
 		};
 		let filter = create_test_filter();
 		let mut matched = Vec::new();
@@ -1492,6 +2802,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1510,6 +2822,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1522,6 +2836,8 @@ mod tests {
 		let condition = TransactionCondition {
 			status: TransactionStatus::Any,
 			expression: Some(expression.clone()),
+			warn_threshold_expression: None,
+			is_contract_creation: false,
 		};
 		let filter = create_test_filter();
 		let mut matched = Vec::new();
@@ -1538,6 +2854,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1556,6 +2874,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1568,6 +2888,8 @@ mod tests {
 		let condition = TransactionCondition {
 			status: TransactionStatus::Any,
 			expression: Some(expression.clone()),
+			warn_threshold_expression: None,
+			is_contract_creation: false,
 		};
 		let filter = create_test_filter();
 		let mut matched = Vec::new();
@@ -1585,6 +2907,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1604,18 +2928,138 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
 		assert_eq!(matched.len(), 0);
 	}
 
+	/// Returns the expressions of the given conditions a transaction matches, one at a time
+	fn matching_fee_expressions(
+		expressions: &[&str],
+		transaction: &EVMTransaction,
+		tx_receipt: &Option<EVMTransactionReceipt>,
+		base_fee_per_gas: Option<U256>,
+	) -> Vec<String> {
+		let filter = create_test_filter();
+		expressions
+			.iter()
+			.filter(|expression| {
+				let condition = TransactionCondition {
+					status: TransactionStatus::Any,
+					expression: Some(expression.to_string()),
+					warn_threshold_expression: None,
+					is_contract_creation: false,
+				};
+				let monitor = create_test_monitor(vec![], vec![], vec![condition], vec![]);
+				let mut matched = Vec::new();
+				filter.find_matching_transaction(
+					&TransactionStatus::Success,
+					transaction,
+					tx_receipt,
+					base_fee_per_gas,
+					None,
+					&monitor,
+					&mut matched,
+				);
+				!matched.is_empty()
+			})
+			.map(|expression| expression.to_string())
+			.collect()
+	}
+
+	#[test]
+	fn test_fee_matching_legacy_transaction() {
+		let gwei = U256::from(1_000_000_000u64);
+		let tx = TransactionBuilder::new()
+			.gas_price(gwei * U256::from(60))
+			.build();
+		let tx_receipt = ReceiptBuilder::new()
+			.transaction_hash(tx.hash)
+			.effective_gas_price(gwei * U256::from(60))
+			.build();
+
+		let matched = matching_fee_expressions(
+			&[
+				"gas_price > 50000000000",
+				"effective_gas_price > 50000000000",
+				"base_fee_per_gas < 50000000000",
+				// Legacy transactions have no EIP-1559 fees
+				"max_fee_per_gas < 100000000000",
+				"max_priority_fee_per_gas == 0",
+			],
+			&tx,
+			&Some(tx_receipt),
+			Some(gwei * U256::from(40)),
+		);
+		assert_eq!(
+			matched,
+			vec![
+				"gas_price > 50000000000",
+				"effective_gas_price > 50000000000",
+				"base_fee_per_gas < 50000000000",
+			]
+		);
+	}
+
+	#[test]
+	fn test_fee_matching_eip1559_transaction() {
+		let gwei = U256::from(1_000_000_000u64);
+		let tx = TransactionBuilder::new()
+			.max_fee_per_gas(gwei * U256::from(100))
+			.max_priority_fee_per_gas(gwei * U256::from(2))
+			.build();
+		let tx_receipt = ReceiptBuilder::new()
+			.transaction_hash(tx.hash)
+			.effective_gas_price(gwei * U256::from(42))
+			.build();
+		let expressions = [
+			"max_fee_per_gas >= 100000000000",
+			"max_priority_fee_per_gas > 1000000000",
+			"effective_gas_price > 50000000000",
+			"effective_gas_price < 50000000000",
+			"base_fee_per_gas == 40000000000",
+			// The transaction has no gas price
+			"gas_price < 50000000000",
+		];
+
+		let matched = matching_fee_expressions(
+			&expressions,
+			&tx,
+			&Some(tx_receipt),
+			Some(gwei * U256::from(40)),
+		);
+		assert_eq!(
+			matched,
+			vec![
+				"max_fee_per_gas >= 100000000000",
+				"max_priority_fee_per_gas > 1000000000",
+				"effective_gas_price < 50000000000",
+				"base_fee_per_gas == 40000000000",
+			]
+		);
+
+		// Without a receipt or a block base fee, only the transaction fees are known
+		let matched = matching_fee_expressions(&expressions, &tx, &None, None);
+		assert_eq!(
+			matched,
+			vec![
+				"max_fee_per_gas >= 100000000000",
+				"max_priority_fee_per_gas > 1000000000",
+			]
+		);
+	}
+
 	#[test]
 	fn test_transaction_index_matching() {
 		let expression = "transaction_index == 15".to_string();
 		let condition = TransactionCondition {
 			status: TransactionStatus::Any,
 			expression: Some(expression.clone()),
+			warn_threshold_expression: None,
+			is_contract_creation: false,
 		};
 		let filter = create_test_filter();
 		let mut matched = Vec::new();
@@ -1631,6 +3075,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1648,6 +3094,8 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
+			None,
 			&monitor,
 			&mut matched,
 		);
@@ -1664,6 +3112,8 @@ mod tests {
 		let mut matched_on_args = EVMMatchArguments {
 			events: None,
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		let contract_with_spec = (
@@ -1677,6 +3127,8 @@ mod tests {
 			vec![FunctionCondition {
 				signature: "transfer(address,uint256)".to_string(),
 				expression: None,
+				warn_threshold_expression: None,
+				eip712_schema: None,
 			}], // functions
 			vec![], // transactions
 			vec![create_test_address(
@@ -1749,6 +3201,8 @@ mod tests {
 		let mut matched_on_args = EVMMatchArguments {
 			events: None,
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		let contract_with_spec = (
@@ -1762,6 +3216,8 @@ mod tests {
 			vec![FunctionCondition {
 				signature: "transfer(address,uint256)".to_string(),
 				expression: Some("amount > 500".to_string()),
+				warn_threshold_expression: None,
+				eip712_schema: None,
 			}], // functions
 			vec![], // transactions
 			vec![create_test_address(
@@ -1861,6 +3317,8 @@ mod tests {
 		let mut matched_on_args = EVMMatchArguments {
 			events: None,
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		let contract_with_spec = (
@@ -1873,6 +3331,8 @@ mod tests {
 			vec![FunctionCondition {
 				signature: "transfer(address,uint256)".to_string(),
 				expression: None,
+				warn_threshold_expression: None,
+				eip712_schema: None,
 			}],
 			vec![],
 			vec![create_test_address(
@@ -1939,6 +3399,8 @@ mod tests {
 		let mut matched_on_args = EVMMatchArguments {
 			events: None,
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		let contract_with_spec = (
@@ -1946,39 +3408,344 @@ mod tests {
 			EVMContractSpec::from(create_test_abi("function")),
 		);
 
-		let monitor = MonitorBuilder::new()
-			.match_conditions(MatchConditions {
-				functions: vec![FunctionCondition {
-					signature: "transfer(address,uint256)".to_string(),
-					expression: None,
-				}],
-				events: vec![],
-				transactions: vec![],
-			})
-			.addresses_with_spec(vec![(
-				contract_with_spec.0.clone(),
-				Some(ContractSpec::EVM(contract_with_spec.1.clone())),
-			)])
-			.name("test")
-			.networks(vec!["evm_mainnet".to_string()])
-			.paused(false)
-			.build();
+		let monitor = MonitorBuilder::new()
+			.match_conditions(MatchConditions {
+				functions: vec![FunctionCondition {
+					signature: "transfer(address,uint256)".to_string(),
+					expression: None,
+					warn_threshold_expression: None,
+					eip712_schema: None,
+				}],
+				events: vec![],
+				transactions: vec![],
+				token_transfers: vec![],
+				block_conditions: vec![],
+				balance_conditions: vec![],
+				state_conditions: vec![],
+			})
+			.addresses_with_spec(vec![(
+				contract_with_spec.0.clone(),
+				Some(ContractSpec::EVM(contract_with_spec.1.clone())),
+			)])
+			.name("test")
+			.networks(vec!["evm_mainnet".to_string()])
+			.paused(false)
+			.build();
+
+		// Test with invalid input data (less than 4 bytes)
+		let transaction = TransactionBuilder::new()
+			.to(Address::from_str("0x0000000000000000000000000000000000004321").unwrap())
+			.input(Bytes(vec![0x12, 0x34].into()))
+			.build();
+
+		filter.find_matching_functions_for_transaction(
+			&[contract_with_spec],
+			&transaction,
+			&monitor,
+			&mut matched_functions,
+			&mut matched_on_args,
+		);
+
+		assert_eq!(matched_functions.len(), 0);
+	}
+
+	/// Creates a `transfer(address,uint256)` call without going through an ABI
+	fn create_transfer_input(recipient: &str, amount: u64) -> Bytes {
+		let mut input = keccak256("transfer(address,uint256)".as_bytes())[..4].to_vec();
+		input.extend(
+			DynSolValue::Tuple(vec![
+				DynSolValue::Address(Address::from_str(recipient).unwrap()),
+				DynSolValue::Uint(U256::from(amount), 256),
+			])
+			.abi_encode_params(),
+		);
+		Bytes(input.into())
+	}
+
+	#[test]
+	fn test_find_matching_functions_without_abi_by_selector() {
+		let filter = create_test_filter();
+		let mut matched_functions = Vec::new();
+		let mut matched_on_args = EVMMatchArguments {
+			events: None,
+			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
+		};
+
+		let monitor = create_test_monitor(
+			vec![],
+			vec![
+				FunctionCondition {
+					signature: "approve(address,uint256)".to_string(),
+					expression: None,
+					warn_threshold_expression: None,
+					eip712_schema: None,
+				},
+				FunctionCondition {
+					signature: "transfer(address,uint256)".to_string(),
+					expression: None,
+					warn_threshold_expression: None,
+					eip712_schema: None,
+				},
+			],
+			vec![],
+			vec![create_test_address(
+				"0x0000000000000000000000000000000000004321",
+				None,
+			)],
+		);
+
+		let transaction = TransactionBuilder::new()
+			.to(Address::from_str("0x0000000000000000000000000000000000004321").unwrap())
+			.input(create_transfer_input(
+				"0x0000000000000000000000000000000000001234",
+				1000,
+			))
+			.build();
+
+		filter.find_matching_functions_for_transaction(
+			&[],
+			&transaction,
+			&monitor,
+			&mut matched_functions,
+			&mut matched_on_args,
+		);
+
+		assert_eq!(matched_functions.len(), 1);
+		assert_eq!(matched_functions[0].signature, "transfer(address,uint256)");
+
+		let functions = matched_on_args.functions.unwrap();
+		assert_eq!(functions.len(), 1);
+		assert_eq!(functions[0].hex_signature.as_deref(), Some("0xa9059cbb"));
+		let args = functions[0].args.as_ref().unwrap();
+		assert_eq!(args[0].name, "0");
+		assert_eq!(args[0].kind, "address");
+		assert_eq!(args[1].name, "1");
+		assert_eq!(args[1].value, "1000");
+	}
+
+	#[test]
+	fn test_find_matching_functions_without_abi_with_expression() {
+		let filter = create_test_filter();
+		let address = "0x0000000000000000000000000000000000004321";
+		let to = Address::from_str(address).unwrap();
+
+		let monitor = create_test_monitor(
+			vec![],
+			vec![FunctionCondition {
+				signature: "transfer(address,uint256)".to_string(),
+				expression: Some("1 > 500".to_string()),
+				warn_threshold_expression: None,
+				eip712_schema: None,
+			}],
+			vec![],
+			vec![create_test_address(address, None)],
+		);
+
+		let match_count = |input: Bytes| {
+			let mut matched_functions = Vec::new();
+			let mut matched_on_args = EVMMatchArguments {
+				events: None,
+				functions: Some(Vec::new()),
+				block: None,
+				balance: None,
+			};
+			let transaction = TransactionBuilder::new().to(to).input(input).build();
+			filter.find_matching_functions_for_transaction(
+				&[],
+				&transaction,
+				&monitor,
+				&mut matched_functions,
+				&mut matched_on_args,
+			);
+			matched_functions.len()
+		};
+
+		assert_eq!(
+			match_count(create_transfer_input(
+				"0x0000000000000000000000000000000000001234",
+				1000
+			)),
+			1
+		);
+		assert_eq!(
+			match_count(create_transfer_input(
+				"0x0000000000000000000000000000000000001234",
+				100
+			)),
+			0
+		);
+
+		// Arguments that can't be decoded only match conditions without an expression
+		let selector_only = keccak256("transfer(address,uint256)".as_bytes())[..4].to_vec();
+		assert_eq!(match_count(Bytes(selector_only.into())), 0);
+	}
+
+	#[test]
+	fn test_find_matching_functions_with_eip712_typed_data() {
+		let filter = create_test_filter();
+		let address = "0x0000000000000000000000000000000000004321";
+		let to = Address::from_str(address).unwrap();
+
+		// The `Mail` example of EIP-712
+		let schema: crate::models::Eip712Schema = serde_json::from_value(json!({
+			"param": "0",
+			"primary_type": "Mail",
+			"types": {
+				"EIP712Domain": [
+					{ "name": "name", "type": "string" },
+					{ "name": "version", "type": "string" },
+					{ "name": "chainId", "type": "uint256" },
+					{ "name": "verifyingContract", "type": "address" }
+				],
+				"Person": [
+					{ "name": "name", "type": "string" },
+					{ "name": "wallet", "type": "address" }
+				],
+				"Mail": [
+					{ "name": "from", "type": "Person" },
+					{ "name": "to", "type": "Person" },
+					{ "name": "contents", "type": "string" }
+				]
+			}
+		}))
+		.unwrap();
+		let person = |name: &str, wallet: &str| {
+			DynSolValue::Tuple(vec![
+				DynSolValue::String(name.to_string()),
+				DynSolValue::Address(Address::from_str(wallet).unwrap()),
+			])
+		};
+		let typed_data = DynSolValue::Tuple(vec![
+			DynSolValue::Tuple(vec![
+				DynSolValue::String("Ether Mail".to_string()),
+				DynSolValue::String("1".to_string()),
+				DynSolValue::Uint(U256::from(1), 256),
+				DynSolValue::Address(
+					Address::from_str("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC").unwrap(),
+				),
+			]),
+			DynSolValue::Tuple(vec![
+				person("Cow", "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"),
+				person("Bob", "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"),
+				DynSolValue::String("Hello, Bob!".to_string()),
+			]),
+		])
+		.abi_encode_params();
+		let relay_input = |typed_data: Vec<u8>| {
+			let mut input = keccak256("relay(bytes)".as_bytes())[..4].to_vec();
+			input.extend(
+				DynSolValue::Tuple(vec![DynSolValue::Bytes(typed_data)]).abi_encode_params(),
+			);
+			Bytes(input.into())
+		};
+
+		let match_args = |expression: Option<&str>, input: Bytes| {
+			let monitor = create_test_monitor(
+				vec![],
+				vec![FunctionCondition {
+					signature: "relay(bytes)".to_string(),
+					expression: expression.map(str::to_string),
+					warn_threshold_expression: None,
+					eip712_schema: Some(schema.clone()),
+				}],
+				vec![],
+				vec![create_test_address(address, None)],
+			);
+			let mut matched_functions = Vec::new();
+			let mut matched_on_args = EVMMatchArguments {
+				events: None,
+				functions: Some(Vec::new()),
+				block: None,
+				balance: None,
+			};
+			let transaction = TransactionBuilder::new().to(to).input(input).build();
+			filter.find_matching_functions_for_transaction(
+				&[],
+				&transaction,
+				&monitor,
+				&mut matched_functions,
+				&mut matched_on_args,
+			);
+			matched_on_args
+				.functions
+				.unwrap()
+				.pop()
+				.and_then(|f| f.args)
+		};
+
+		let args = match_args(
+			Some(
+				"eip712.message.to.name == 'Bob' AND eip712.domain.chainId == 1 AND \
+				 eip712.message.from.wallet == 0xcd2a3d9f938e13cd947ec05abc7fe734df8dd826",
+			),
+			relay_input(typed_data.clone()),
+		)
+		.expect("Typed data should match");
+		let contents = args
+			.iter()
+			.find(|arg| arg.name == "eip712.message.contents")
+			.unwrap();
+		assert_eq!(contents.value, "Hello, Bob!");
+		assert_eq!(contents.kind, "string");
+
+		assert!(match_args(
+			Some("eip712.message.to.name == 'Alice'"),
+			relay_input(typed_data.clone())
+		)
+		.is_none());
+
+		// Malformed typed data matches nothing, even without an expression
+		let truncated = typed_data[..typed_data.len() - 32].to_vec();
+		assert!(match_args(None, relay_input(truncated)).is_none());
+		assert!(match_args(None, relay_input(typed_data)).is_some());
+	}
+
+	#[test]
+	fn test_find_matching_functions_without_abi_selector_only() {
+		let filter = create_test_filter();
+		let mut matched_functions = Vec::new();
+		let mut matched_on_args = EVMMatchArguments {
+			events: None,
+			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
+		};
+
+		let monitor = create_test_monitor(
+			vec![],
+			vec![FunctionCondition {
+				signature: "transfer(address,uint256)".to_string(),
+				expression: None,
+				warn_threshold_expression: None,
+				eip712_schema: None,
+			}],
+			vec![],
+			vec![create_test_address(
+				"0x0000000000000000000000000000000000004321",
+				None,
+			)],
+		);
 
-		// Test with invalid input data (less than 4 bytes)
+		// Only the selector, without any encoded arguments
+		let input = keccak256("transfer(address,uint256)".as_bytes())[..4].to_vec();
 		let transaction = TransactionBuilder::new()
 			.to(Address::from_str("0x0000000000000000000000000000000000004321").unwrap())
-			.input(Bytes(vec![0x12, 0x34].into()))
+			.input(Bytes(input.into()))
 			.build();
 
 		filter.find_matching_functions_for_transaction(
-			&[contract_with_spec],
+			&[],
 			&transaction,
 			&monitor,
 			&mut matched_functions,
 			&mut matched_on_args,
 		);
 
-		assert_eq!(matched_functions.len(), 0);
+		assert_eq!(matched_functions.len(), 1);
+		let functions = matched_on_args.functions.unwrap();
+		assert!(functions[0].args.is_none());
 	}
 
 	//////////////////////////////////////////////////////////////////////////////
@@ -1992,6 +3759,8 @@ mod tests {
 		let mut matched_on_args = EVMMatchArguments {
 			events: Some(Vec::new()),
 			functions: None,
+			block: None,
+			balance: None,
 		};
 		let mut involved_addresses = Vec::new();
 
@@ -2000,6 +3769,7 @@ mod tests {
 			vec![EventCondition {
 				signature: "Transfer(address,address,uint256)".to_string(),
 				expression: None,
+				warn_threshold_expression: None,
 			}], // events
 			vec![], // functions
 			vec![], // transactions
@@ -2047,6 +3817,8 @@ mod tests {
 		let mut matched_on_args = EVMMatchArguments {
 			events: Some(Vec::new()),
 			functions: None,
+			block: None,
+			balance: None,
 		};
 		let mut involved_addresses = Vec::new();
 
@@ -2055,6 +3827,7 @@ mod tests {
 			vec![EventCondition {
 				signature: "Transfer(address,address,uint256)".to_string(),
 				expression: Some("value > 500".to_string()),
+				warn_threshold_expression: None,
 			}], // events
 			vec![], // functions
 			vec![], // transactions
@@ -2120,6 +3893,8 @@ mod tests {
 		let mut matched_on_args = EVMMatchArguments {
 			events: Some(Vec::new()),
 			functions: None,
+			block: None,
+			balance: None,
 		};
 		let mut involved_addresses = Vec::new();
 
@@ -2128,6 +3903,8 @@ mod tests {
 			vec![FunctionCondition {
 				signature: "transfer(address,uint256)".to_string(),
 				expression: None,
+				warn_threshold_expression: None,
+				eip712_schema: None,
 			}], // functions
 			vec![], // transactions
 			vec![create_test_address(
@@ -2158,6 +3935,201 @@ mod tests {
 		assert_eq!(involved_addresses.len(), 0);
 	}
 
+	#[tokio::test]
+	async fn test_find_matching_events_address_patterns() {
+		let filter = create_test_filter();
+		let contract_address =
+			Address::from_str("0xabcd000000000000000000000000000000004321").unwrap();
+		let receipt = ReceiptBuilder::new()
+			.contract_address(contract_address)
+			.from(Address::from_str("0x0000000000000000000000000000000000001234").unwrap())
+			.to(Address::from_str("0x0000000000000000000000000000000000005678").unwrap())
+			.value(U256::from(100))
+			.build();
+
+		let cases = [
+			(
+				"0xABCD000000000000000000000000000000004321",
+				AddressMatchMode::Exact,
+				true,
+			),
+			("0xABCD", AddressMatchMode::Prefix, true),
+			("4321", AddressMatchMode::Suffix, true),
+			("0xABCD*", AddressMatchMode::Glob, true),
+			("0xABCD", AddressMatchMode::Exact, false),
+			("0x1234", AddressMatchMode::Prefix, false),
+			("1234", AddressMatchMode::Suffix, false),
+			("0x1234*", AddressMatchMode::Glob, false),
+		];
+
+		for (pattern, match_mode, expected) in cases {
+			let mut matched_events = Vec::new();
+			let mut matched_on_args = EVMMatchArguments {
+				events: Some(Vec::new()),
+				functions: None,
+				block: None,
+				balance: None,
+			};
+			let mut involved_addresses = Vec::new();
+
+			let mut monitor = create_test_monitor(
+				vec![EventCondition {
+					signature: "Transfer(address,address,uint256)".to_string(),
+					expression: None,
+					warn_threshold_expression: None,
+				}], // events
+				vec![],                                                             // functions
+				vec![],                                                             // transactions
+				vec![create_test_address(pattern, Some(create_test_abi("event")))], // addresses
+			);
+			monitor.addresses[0].match_mode = match_mode;
+
+			filter.find_matching_events_for_transaction(
+				&receipt.logs,
+				&monitor,
+				&mut matched_events,
+				&mut matched_on_args,
+				&mut involved_addresses,
+			);
+
+			assert_eq!(
+				matched_events.len(),
+				usize::from(expected),
+				"{} {:?}",
+				pattern,
+				match_mode
+			);
+		}
+	}
+
+	fn create_token_monitor(condition: TokenTransferCondition) -> Monitor {
+		MonitorBuilder::new()
+			.name("test")
+			.networks(vec!["evm_mainnet".to_string()])
+			.address("0x0000000000000000000000000000000000004321")
+			.token_transfer(condition)
+			.build()
+	}
+
+	fn find_token_transfers(monitor: &Monitor, log: EVMReceiptLog) -> Vec<EventCondition> {
+		let filter = create_test_filter();
+		let mut matched_events = Vec::new();
+		let mut matched_on_args = EVMMatchArguments {
+			events: Some(Vec::new()),
+			functions: None,
+			block: None,
+			balance: None,
+		};
+		filter.find_matching_events_for_transaction(
+			&[log],
+			monitor,
+			&mut matched_events,
+			&mut matched_on_args,
+			&mut Vec::new(),
+		);
+		matched_events
+	}
+
+	fn create_erc20_transfer_log(value: u64) -> EVMReceiptLog {
+		create_test_log(
+			Address::from_str("0x0000000000000000000000000000000000004321").unwrap(),
+			"0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+			Address::from_str("0x0000000000000000000000000000000000001234").unwrap(),
+			Address::from_str("0x0000000000000000000000000000000000005678").unwrap(),
+			&format!("{:064x}", value),
+		)
+	}
+
+	#[test]
+	fn test_find_matching_token_transfer_erc20() {
+		let monitor = create_token_monitor(TokenTransferCondition {
+			token_standard: Some(TokenStandard::Erc20),
+			to: Some("0x0000000000000000000000000000000000005678".to_string()),
+			min_amount: Some("100".to_string()),
+			..Default::default()
+		});
+
+		let matched_events = find_token_transfers(&monitor, create_erc20_transfer_log(100));
+		assert_eq!(matched_events.len(), 1);
+		assert_eq!(
+			matched_events[0].signature,
+			"Transfer(address,address,uint256)"
+		);
+
+		// Below the minimum amount
+		assert!(find_token_transfers(&monitor, create_erc20_transfer_log(99)).is_empty());
+
+		// Other recipient
+		let monitor = create_token_monitor(TokenTransferCondition {
+			to: Some("0x0000000000000000000000000000000000001234".to_string()),
+			..Default::default()
+		});
+		assert!(find_token_transfers(&monitor, create_erc20_transfer_log(100)).is_empty());
+	}
+
+	#[test]
+	fn test_find_matching_token_transfer_erc721() {
+		let mut log = create_erc20_transfer_log(0);
+		log.topics.push(B256::from(U256::from(42)));
+		log.data = Bytes::default();
+
+		let monitor = create_token_monitor(TokenTransferCondition {
+			token_standard: Some(TokenStandard::Erc721),
+			from: Some("0x0000000000000000000000000000000000001234".to_string()),
+			..Default::default()
+		});
+		assert_eq!(find_token_transfers(&monitor, log.clone()).len(), 1);
+
+		// ERC-20 conditions don't match ERC-721 transfers
+		let monitor = create_token_monitor(TokenTransferCondition {
+			token_standard: Some(TokenStandard::Erc20),
+			..Default::default()
+		});
+		assert!(find_token_transfers(&monitor, log.clone()).is_empty());
+
+		// A minimum amount above 1 excludes ERC-721 transfers
+		let monitor = create_token_monitor(TokenTransferCondition {
+			min_amount: Some("2".to_string()),
+			..Default::default()
+		});
+		assert!(find_token_transfers(&monitor, log).is_empty());
+	}
+
+	#[test]
+	fn test_find_matching_token_transfer_erc1155() {
+		let operator = Address::from_str("0x0000000000000000000000000000000000009999").unwrap();
+		let mut log = create_erc20_transfer_log(0);
+		log.topics[0] = keccak256("TransferSingle(address,address,address,uint256,uint256)");
+		log.topics.insert(
+			1,
+			B256::from_slice(&[&[0u8; 12], operator.as_slice()].concat()),
+		);
+		log.data = Bytes(
+			hex::decode(format!("{:064x}{:064x}", 7, 500))
+				.unwrap()
+				.into(),
+		);
+
+		let monitor = create_token_monitor(TokenTransferCondition {
+			token_standard: Some(TokenStandard::Erc1155),
+			min_amount: Some("500".to_string()),
+			..Default::default()
+		});
+		let matched_events = find_token_transfers(&monitor, log.clone());
+		assert_eq!(matched_events.len(), 1);
+		assert_eq!(
+			matched_events[0].signature,
+			"TransferSingle(address,address,address,uint256,uint256)"
+		);
+
+		let monitor = create_token_monitor(TokenTransferCondition {
+			token_standard: Some(TokenStandard::Erc1155),
+			min_amount: Some("501".to_string()),
+			..Default::default()
+		});
+		assert!(find_token_transfers(&monitor, log).is_empty());
+	}
+
 	//////////////////////////////////////////////////////////////////////////////
 	// Test cases for evaluate_expression method:
 	//////////////////////////////////////////////////////////////////////////////
@@ -2728,6 +4700,160 @@ mod tests {
 		assert_eq!(value_param.value, "100"); // 0x64 in decimal
 	}
 
+	#[tokio::test]
+	async fn test_decode_events_distinguishes_indexed_params() {
+		let filter = create_test_filter();
+		let delegator = Address::from_str("0x0000000000000000000000000000000000001234").unwrap();
+		let delegate = Address::from_str("0x0000000000000000000000000000000000005678").unwrap();
+
+		// Both an indexed and a non-indexed parameter of each type
+		let abi = ContractSpec::EVM(EVMContractSpec::from(json!([{
+			"type": "event",
+			"name": "Delegated",
+			"inputs": [
+				{ "name": "delegator", "type": "address", "indexed": true },
+				{ "name": "delegate", "type": "address", "indexed": false },
+				{ "name": "id", "type": "uint256", "indexed": true },
+				{ "name": "amount", "type": "uint256", "indexed": false }
+			],
+			"anonymous": false
+		}])));
+		let log = EVMReceiptLog {
+			topics: vec![
+				keccak256("Delegated(address,address,uint256,uint256)"),
+				B256::from_slice(&[&[0u8; 12], delegator.as_slice()].concat()),
+				B256::from(U256::from(7).to_be_bytes::<32>()),
+			],
+			data: Bytes(
+				[
+					B256::from_slice(&[&[0u8; 12], delegate.as_slice()].concat()).to_vec(),
+					U256::from(100).to_be_bytes::<32>().to_vec(),
+				]
+				.concat()
+				.into(),
+			),
+			..create_test_log(
+				Address::from_str("0x0000000000000000000000000000000000004321").unwrap(),
+				"0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+				delegator,
+				delegate,
+				"",
+			)
+		};
+
+		let decoded = filter.decode_events(&abi, &log).unwrap();
+		assert_eq!(
+			decoded.signature,
+			"Delegated(address,address,uint256,uint256)"
+		);
+		let args = decoded.args.unwrap();
+		let values: Vec<_> = args
+			.iter()
+			.map(|p| (p.name.as_str(), p.value.as_str(), p.indexed))
+			.collect();
+		assert_eq!(
+			values,
+			vec![
+				(
+					"delegator",
+					"0x0000000000000000000000000000000000001234",
+					true
+				),
+				(
+					"delegate",
+					"0x0000000000000000000000000000000000005678",
+					false
+				),
+				("id", "7", true),
+				("amount", "100", false),
+			]
+		);
+
+		assert!(filter
+			.evaluate_expression(
+				"delegator.indexed == true AND delegate.indexed == false",
+				&args
+			)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("id.indexed == true AND amount > 50", &args)
+			.unwrap());
+		assert!(!filter
+			.evaluate_expression("amount.indexed == true", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("delegate.indexed != true", &args)
+			.unwrap());
+	}
+
+	#[tokio::test]
+	async fn test_decode_events_picks_event_by_indexed_params() {
+		let filter = create_test_filter();
+		let contract_address =
+			Address::from_str("0x0000000000000000000000000000000000004321").unwrap();
+		let from = Address::from_str("0x0000000000000000000000000000000000001234").unwrap();
+		let to = Address::from_str("0x0000000000000000000000000000000000005678").unwrap();
+
+		// ERC20 and ERC721 Transfer events share their signature
+		let abi = ContractSpec::EVM(EVMContractSpec::from(json!([
+			{
+				"type": "event",
+				"name": "Transfer",
+				"inputs": [
+					{ "name": "from", "type": "address", "indexed": true },
+					{ "name": "to", "type": "address", "indexed": true },
+					{ "name": "value", "type": "uint256", "indexed": false }
+				],
+				"anonymous": false
+			},
+			{
+				"type": "event",
+				"name": "Transfer",
+				"inputs": [
+					{ "name": "from", "type": "address", "indexed": true },
+					{ "name": "to", "type": "address", "indexed": true },
+					{ "name": "value", "type": "uint256", "indexed": true }
+				],
+				"anonymous": false
+			}
+		])));
+		let erc20_log = create_test_log(
+			contract_address,
+			"0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+			from,
+			to,
+			"0000000000000000000000000000000000000000000000000000000000000064",
+		);
+		let mut erc721_log = create_test_log(
+			contract_address,
+			"0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+			from,
+			to,
+			"",
+		);
+		erc721_log
+			.topics
+			.push(B256::from(U256::from(42).to_be_bytes::<32>()));
+
+		let erc20_args = filter
+			.decode_events(&abi, &erc20_log)
+			.unwrap()
+			.args
+			.unwrap();
+		let erc721_args = filter
+			.decode_events(&abi, &erc721_log)
+			.unwrap()
+			.args
+			.unwrap();
+
+		assert!(!filter
+			.evaluate_expression("value.indexed == true", &erc20_args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("value.indexed == true AND value == 42", &erc721_args)
+			.unwrap());
+	}
+
 	#[tokio::test]
 	async fn test_decode_events_invalid_abi() {
 		let filter = create_test_filter();
@@ -3338,4 +5464,205 @@ mod tests {
 		let value2_param = args.iter().find(|p| p.name == "value2").unwrap();
 		assert_eq!(value2_param.value, "200");
 	}
+
+	//////////////////////////////////////////////////////////////////////////////
+	// Test cases for is_logs_only and monitored_events methods:
+	//////////////////////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_is_logs_only() {
+		let filter = create_test_filter();
+		let event = EventCondition {
+			signature: "Transfer(address,address,uint256)".to_string(),
+			expression: None,
+			warn_threshold_expression: None,
+		};
+		let function = FunctionCondition {
+			signature: "transfer(address,uint256)".to_string(),
+			expression: None,
+			warn_threshold_expression: None,
+			eip712_schema: None,
+		};
+
+		assert!(filter.is_logs_only(&create_test_monitor(
+			vec![event.clone()],
+			vec![],
+			vec![],
+			vec![]
+		)));
+		assert!(!filter.is_logs_only(&create_test_monitor(
+			vec![event],
+			vec![function],
+			vec![],
+			vec![]
+		)));
+		assert!(!filter.is_logs_only(&create_test_monitor(vec![], vec![], vec![], vec![])));
+	}
+
+	#[test]
+	fn test_monitored_events_collects_addresses_and_topics() {
+		let filter = create_test_filter();
+		let monitor = create_test_monitor(
+			vec![EventCondition {
+				signature: "Transfer(address, address, uint256)".to_string(),
+				expression: None,
+				warn_threshold_expression: None,
+			}],
+			vec![],
+			vec![],
+			vec![
+				create_test_address(
+					"0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+					Some(create_test_abi("event")),
+				),
+				create_test_address("0x0000000000000000000000000000000000004321", None),
+			],
+		);
+
+		let (addresses, topics) = filter.monitored_events(&[monitor.clone(), monitor]);
+
+		assert_eq!(
+			addresses,
+			vec![
+				"0x0000000000000000000000000000000000004321",
+				"0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+			]
+		);
+		assert_eq!(
+			topics,
+			vec![b256_to_string(keccak256(
+				"Transfer(address,address,uint256)".as_bytes()
+			))]
+		);
+	}
+
+	#[test]
+	fn test_filter_pending_transaction() {
+		let filter = create_test_filter();
+		let network = crate::utils::tests::builders::network::NetworkBuilder::new()
+			.slug("evm_mainnet")
+			.build();
+		let contract = "0x0000000000000000000000000000000000004321";
+		let transaction = TransactionBuilder::new()
+			.to(Address::from_str(contract).unwrap())
+			.value(U256::from(150))
+			.build();
+
+		let value_monitor = create_test_monitor(
+			vec![],
+			vec![],
+			vec![TransactionCondition {
+				status: TransactionStatus::Any,
+				expression: Some("value > 100".to_string()),
+				warn_threshold_expression: None,
+				is_contract_creation: false,
+			}],
+			vec![create_test_address(contract, None)],
+		);
+		// The status of a pending transaction is unknown
+		let success_monitor = create_test_monitor(
+			vec![],
+			vec![],
+			vec![TransactionCondition {
+				status: TransactionStatus::Success,
+				expression: None,
+				warn_threshold_expression: None,
+				is_contract_creation: false,
+			}],
+			vec![create_test_address(contract, None)],
+		);
+		// Pending transactions have no logs
+		let event_monitor = create_test_monitor(
+			vec![EventCondition {
+				signature: "Transfer(address,address,uint256)".to_string(),
+				expression: None,
+				warn_threshold_expression: None,
+			}],
+			vec![],
+			vec![],
+			vec![create_test_address(contract, None)],
+		);
+		let other_monitor = create_test_monitor(
+			vec![],
+			vec![],
+			vec![],
+			vec![create_test_address(
+				"0x0000000000000000000000000000000000001234",
+				None,
+			)],
+		);
+
+		let matches = filter.filter_pending_transaction(
+			&network,
+			&transaction,
+			&[value_monitor, success_monitor, event_monitor, other_monitor],
+			&[],
+		);
+
+		assert_eq!(matches.len(), 1);
+		let MonitorMatch::EVM(evm_match) = &matches[0] else {
+			panic!("Expected an EVM match");
+		};
+		assert_eq!(evm_match.confirmation_state, EVMConfirmationState::Pending);
+		assert_eq!(evm_match.network_slug, "evm_mainnet");
+		assert!(evm_match.receipt.is_none());
+		assert!(evm_match.logs.is_none());
+		assert_eq!(
+			evm_match.matched_on.transactions[0].expression.as_deref(),
+			Some("value > 100")
+		);
+	}
+
+	#[test]
+	fn test_filter_pending_transaction_verifies_sender() {
+		let filter = create_test_filter();
+		let network = crate::utils::tests::builders::network::NetworkBuilder::new()
+			.slug("evm_mainnet")
+			.build();
+		let contract = "0x0000000000000000000000000000000000004321";
+		let signer = alloy::signers::local::PrivateKeySigner::random();
+		let monitor = |verify: Option<bool>| {
+			let monitor = create_test_monitor(
+				vec![],
+				vec![],
+				vec![],
+				vec![create_test_address(contract, None)],
+			);
+			match verify {
+				Some(strict) => Monitor {
+					verify_signature: true,
+					strict_signature_verification: strict,
+					..monitor
+				},
+				None => monitor,
+			}
+		};
+		let sender_verified = |transaction: &EVMTransaction, verify: Option<bool>| {
+			filter
+				.filter_pending_transaction(&network, transaction, &[monitor(verify)], &[])
+				.into_iter()
+				.map(|monitor_match| match monitor_match {
+					MonitorMatch::EVM(evm_match) => evm_match.sender_verified,
+					_ => panic!("Expected an EVM match"),
+				})
+				.collect::<Vec<_>>()
+		};
+
+		let signed = TransactionBuilder::new()
+			.to(Address::from_str(contract).unwrap())
+			.signed_by(&signer)
+			.build();
+		assert_eq!(sender_verified(&signed, None), vec![None]);
+		assert_eq!(sender_verified(&signed, Some(false)), vec![Some(true)]);
+		assert_eq!(sender_verified(&signed, Some(true)), vec![Some(true)]);
+
+		// The RPC reports another sender than the signer
+		let tampered = TransactionBuilder::new()
+			.from(Address::with_last_byte(9))
+			.to(Address::from_str(contract).unwrap())
+			.signed_by(&signer)
+			.build();
+		assert_eq!(sender_verified(&tampered, Some(false)), vec![Some(false)]);
+		assert!(sender_verified(&tampered, Some(true)).is_empty());
+	}
 }
@@ -2,12 +2,30 @@
 //!
 //! This module provides utility functions for working with EVM-specific data types
 //! and formatting, including address and hash conversions, signature normalization,
-//! and token value formatting.
+//! event log decoding, EIP-712 typed data decoding and token value formatting.
 
-use alloy::core::dyn_abi::DynSolValue;
-use alloy::primitives::{Address, B256, I256, U256};
+use alloy::core::dyn_abi::{DynSolType, DynSolValue, EventExt};
+use alloy::core::json_abi::Event;
+use alloy::primitives::{keccak256, Address, B256, I256, U256};
 use std::str::FromStr;
 
+use crate::models::{
+	EVMMatchParamEntry, EVMTransaction, EVMTransactionReceipt, Eip712Field, Eip712Schema,
+};
+
+/// Name of the struct type of the EIP-712 domain
+pub const EIP712_DOMAIN_TYPE: &str = "EIP712Domain";
+
+/// Selector of the `Error(string)` error raised by `require` and `revert` with a message
+pub const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Selector of the `Panic(uint256)` error raised by failed assertions and arithmetic checks
+pub const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Maximum depth of the struct and array types of EIP-712 typed data, which bounds recursive
+/// struct types
+const MAX_EIP712_TYPE_DEPTH: usize = 16;
+
 /// Converts an B256 hash to its hexadecimal string representation.
 ///
 /// # Arguments
@@ -46,6 +64,32 @@ pub fn h160_to_string(address: Address) -> String {
 	format!("0x{}", hex::encode(address.as_slice()))
 }
 
+/// Returns the address of the contract created by a transaction without a recipient.
+///
+/// The address is read from the receipt if any, or else derived from the sender and nonce of
+/// the transaction, as for pending transactions.
+///
+/// # Arguments
+/// * `transaction` - The transaction to check
+/// * `receipt` - Receipt of the transaction, if fetched
+///
+/// # Returns
+/// The address of the created contract, or `None` if the transaction has a recipient
+pub fn created_contract_address(
+	transaction: &EVMTransaction,
+	receipt: &Option<EVMTransactionReceipt>,
+) -> Option<Address> {
+	if transaction.to.is_some() {
+		return None;
+	}
+	match receipt {
+		Some(receipt) => receipt.contract_address,
+		None => transaction
+			.from
+			.map(|from| from.create(transaction.nonce.saturating_to())),
+	}
+}
+
 /// Compares two addresses for equality, ignoring case and "0x" prefixes.
 ///
 /// # Arguments
@@ -96,6 +140,277 @@ pub fn normalize_signature(signature: &str) -> String {
 	signature.replace(char::is_whitespace, "").to_lowercase()
 }
 
+/// Computes the 4-byte selector of a function signature.
+///
+/// # Arguments
+/// * `signature` - The function signature, e.g. `transfer(address,uint256)`
+///
+/// # Returns
+/// The first 4 bytes of the keccak256 hash of the signature, ignoring whitespace
+pub fn function_selector(signature: &str) -> [u8; 4] {
+	let hash = keccak256(signature.replace(char::is_whitespace, "").as_bytes());
+	[hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Returns the selector of the error a transaction reverted with.
+///
+/// # Arguments
+/// * `revert_data` - ABI-encoded revert data of the transaction
+///
+/// # Returns
+/// The first 4 bytes of the revert data as a "0x..." string, or `None` if the data is too short
+/// to start with a selector, as for bare `revert()` calls
+pub fn revert_selector(revert_data: &[u8]) -> Option<String> {
+	revert_data
+		.get(..4)
+		.map(|selector| format!("0x{}", hex::encode(selector)))
+}
+
+/// Decodes the reason a transaction reverted with.
+///
+/// Only the errors built into Solidity are decoded: `Error(string)` into its message, and
+/// `Panic(uint256)` into `Panic(0x..)` with its code. Custom errors can't be decoded without
+/// the ABI of the contract, and are identified by their selector instead.
+///
+/// # Arguments
+/// * `revert_data` - ABI-encoded revert data of the transaction
+///
+/// # Returns
+/// The decoded reason, or `None` if the data isn't a built-in error
+pub fn decode_revert_reason(revert_data: &[u8]) -> Option<String> {
+	let (selector, params) = (revert_data.get(..4)?, &revert_data[4..]);
+	let kind = if selector == ERROR_STRING_SELECTOR {
+		DynSolType::String
+	} else if selector == PANIC_SELECTOR {
+		DynSolType::Uint(256)
+	} else {
+		return None;
+	};
+
+	match DynSolType::Tuple(vec![kind])
+		.abi_decode_params(params)
+		.ok()?
+	{
+		DynSolValue::Tuple(values) => match values.into_iter().next()? {
+			DynSolValue::String(message) => Some(message),
+			DynSolValue::Uint(code, _) => Some(format!("Panic(0x{:x})", code)),
+			_ => None,
+		},
+		_ => None,
+	}
+}
+
+/// Decodes function call arguments using the parameter types declared by a signature.
+///
+/// # Arguments
+/// * `signature` - The function signature, e.g. `transfer(address,uint256)`
+/// * `params` - The ABI-encoded arguments, without the selector
+///
+/// # Returns
+/// The declared type and decoded value of each argument, or an error if the signature's
+/// types can't be parsed or the arguments don't match them
+pub fn decode_function_params(
+	signature: &str,
+	params: &[u8],
+) -> Result<Vec<(DynSolType, DynSolValue)>, String> {
+	let signature = signature.replace(char::is_whitespace, "");
+	let types = signature
+		.find('(')
+		.map(|start| &signature[start..])
+		.filter(|types| types.ends_with(')'))
+		.ok_or_else(|| format!("Signature '{}' has no parameter list", signature))?;
+
+	let types = match types.parse::<DynSolType>() {
+		Ok(DynSolType::Tuple(types)) => types,
+		Ok(ty) => vec![ty],
+		Err(e) => return Err(format!("Failed to parse types of '{}': {}", signature, e)),
+	};
+
+	let values = match DynSolType::Tuple(types.clone()).abi_decode_params(params) {
+		Ok(DynSolValue::Tuple(values)) => values,
+		Ok(value) => vec![value],
+		Err(e) => {
+			return Err(format!(
+				"Failed to decode arguments of '{}': {}",
+				signature, e
+			))
+		}
+	};
+
+	Ok(types.into_iter().zip(values).collect())
+}
+
+/// Decodes the parameters of an event log in the order declared by the event.
+///
+/// Indexed parameters are decoded from the log's topics and the other parameters from its
+/// data, and each decoded parameter records which of the two it comes from, so that events
+/// sharing a signature but indexing different parameters can be told apart.
+///
+/// # Arguments
+/// * `event` - The event declared by the contract ABI
+/// * `topics` - The log's topics, starting with the event selector unless the event is anonymous
+/// * `data` - The log's ABI-encoded data
+///
+/// # Returns
+/// The decoded parameters, or an error if the topics or data don't match the event
+pub fn decode_event_params(
+	event: &Event,
+	topics: &[B256],
+	data: &[u8],
+) -> Result<Vec<EVMMatchParamEntry>, String> {
+	let decoded = event
+		.decode_log_parts(topics.iter().copied(), data)
+		.map_err(|e| format!("Failed to decode log of '{}': {}", event.signature(), e))?;
+
+	let mut topic_values = decoded.indexed.into_iter();
+	let mut data_values = decoded.body.into_iter();
+
+	Ok(event
+		.inputs
+		.iter()
+		.map(|param| {
+			let values = if param.indexed {
+				&mut topic_values
+			} else {
+				&mut data_values
+			};
+
+			EVMMatchParamEntry {
+				name: param.name.clone(),
+				value: values
+					.next()
+					.map(|value| format_token_value(&value))
+					.unwrap_or_default(),
+				kind: param.ty.to_string(),
+				indexed: param.indexed,
+			}
+		})
+		.collect())
+}
+
+/// Decodes the EIP-712 typed data carried by an argument of a function call.
+///
+/// The argument is of type `bytes` and holds the domain and the message ABI-encoded as
+/// `abi.encode(domain, message)`. Each field of the domain and the message becomes a parameter
+/// named after its path, e.g. `eip712.domain.chainId`, or `eip712.message.from.wallet` for a
+/// field of a nested struct.
+///
+/// # Arguments
+/// * `schema` - The schema of the typed data
+/// * `params` - The decoded arguments of the call
+///
+/// # Returns
+/// The fields of the domain and the message, or an error if the argument is missing or doesn't
+/// hold typed data of the schema
+pub fn decode_eip712_params(
+	schema: &Eip712Schema,
+	params: &[EVMMatchParamEntry],
+) -> Result<Vec<EVMMatchParamEntry>, String> {
+	let param = params
+		.iter()
+		.find(|param| param.name == schema.param)
+		.ok_or_else(|| format!("Argument '{}' not found", schema.param))?;
+	if param.kind != "bytes" {
+		return Err(format!(
+			"Argument '{}' is of type {}, not bytes",
+			schema.param, param.kind
+		));
+	}
+	let data = hex::decode(param.value.trim_start_matches("0x"))
+		.map_err(|e| format!("Argument '{}' isn't hex encoded: {}", schema.param, e))?;
+
+	let structs = [
+		("eip712.domain", EIP712_DOMAIN_TYPE),
+		("eip712.message", schema.primary_type.as_str()),
+	];
+	let types = structs
+		.iter()
+		.map(|(_, name)| eip712_type(schema, name, 0))
+		.collect::<Result<Vec<_>, _>>()?;
+	let values = match DynSolType::Tuple(types).abi_decode_params(&data) {
+		Ok(DynSolValue::Tuple(values)) => values,
+		Ok(value) => vec![value],
+		Err(e) => return Err(format!("Failed to decode EIP-712 typed data: {}", e)),
+	};
+
+	let mut entries = Vec::new();
+	for ((prefix, name), value) in structs.iter().zip(&values) {
+		flatten_eip712_struct(schema, &schema.types[*name], value, prefix, &mut entries);
+	}
+	Ok(entries)
+}
+
+/// Resolves an EIP-712 type into the ABI type of its encoding, structs being encoded as tuples
+fn eip712_type(schema: &Eip712Schema, kind: &str, depth: usize) -> Result<DynSolType, String> {
+	if depth > MAX_EIP712_TYPE_DEPTH {
+		return Err(format!("EIP-712 type '{}' is nested too deeply", kind));
+	}
+
+	if let Some(array) = kind.strip_suffix(']') {
+		let (element, size) = array
+			.rsplit_once('[')
+			.ok_or_else(|| format!("Invalid EIP-712 type '{}'", kind))?;
+		let element = Box::new(eip712_type(schema, element, depth + 1)?);
+		if size.is_empty() {
+			return Ok(DynSolType::Array(element));
+		}
+		let size = size
+			.parse()
+			.map_err(|_| format!("Invalid EIP-712 type '{}'", kind))?;
+		return Ok(DynSolType::FixedArray(element, size));
+	}
+
+	match schema.types.get(kind) {
+		Some(fields) => fields
+			.iter()
+			.map(|field| eip712_type(schema, &field.kind, depth + 1))
+			.collect::<Result<Vec<_>, _>>()
+			.map(DynSolType::Tuple),
+		None if kind == EIP712_DOMAIN_TYPE || kind == schema.primary_type => {
+			Err(format!("EIP-712 type '{}' isn't declared", kind))
+		}
+		None => kind
+			.parse()
+			.map_err(|e| format!("Invalid EIP-712 type '{}': {}", kind, e)),
+	}
+}
+
+/// Appends the fields of a decoded EIP-712 struct to the parameters, recursing into the fields
+/// of nested structs
+fn flatten_eip712_struct(
+	schema: &Eip712Schema,
+	fields: &[Eip712Field],
+	value: &DynSolValue,
+	prefix: &str,
+	entries: &mut Vec<EVMMatchParamEntry>,
+) {
+	let DynSolValue::Tuple(values) = value else {
+		return;
+	};
+
+	for (field, value) in fields.iter().zip(values) {
+		let name = format!("{}.{}", prefix, field.name);
+		if let Some(fields) = schema.types.get(&field.kind) {
+			flatten_eip712_struct(schema, fields, value, &name, entries);
+			continue;
+		}
+
+		// Arrays of structs are of the same kind as arrays of tuples in ABIs
+		let element = field.kind.split('[').next().unwrap_or_default();
+		let kind = if schema.types.contains_key(element) {
+			field.kind.replacen(element, "tuple", 1)
+		} else {
+			field.kind.clone()
+		};
+		entries.push(EVMMatchParamEntry {
+			name,
+			value: format_token_value(value),
+			kind,
+			indexed: false,
+		});
+	}
+}
+
 /// Formats a DynSolValue into a consistent string representation.
 ///
 /// # Arguments
@@ -239,6 +554,40 @@ mod tests {
 	use super::*;
 	use alloy::primitives::{hex, Address, B256};
 
+	#[test]
+	fn test_created_contract_address() {
+		use crate::utils::tests::evm::{receipt::ReceiptBuilder, transaction::TransactionBuilder};
+
+		let deployer = Address::from_str("0xb20a608c624ca5003905aa834de7156c68b2e1d0").unwrap();
+		// Without a recipient
+		let deployment = TransactionBuilder::new()
+			.from(deployer)
+			.nonce(U256::from(7))
+			.build();
+
+		// Derived from the sender and nonce without a receipt
+		assert_eq!(
+			created_contract_address(&deployment, &None),
+			Some(deployer.create(7))
+		);
+
+		let contract_address =
+			Address::from_str("0x0000000000000000000000000000000000000abc").unwrap();
+		let receipt = ReceiptBuilder::new()
+			.contract_address(contract_address)
+			.build();
+		assert_eq!(
+			created_contract_address(&deployment, &Some(receipt.clone())),
+			Some(contract_address)
+		);
+
+		let call = TransactionBuilder::new()
+			.from(deployer)
+			.to(contract_address)
+			.build();
+		assert_eq!(created_contract_address(&call, &Some(receipt)), None);
+	}
+
 	#[test]
 	fn test_b256_to_string() {
 		let hash_bytes =
@@ -436,6 +785,114 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_function_selector() {
+		assert_eq!(
+			hex::encode(function_selector("transfer(address,uint256)")),
+			"a9059cbb"
+		);
+		assert_eq!(
+			function_selector("transfer(address, uint256)"),
+			function_selector("transfer(address,uint256)")
+		);
+	}
+
+	#[test]
+	fn test_revert_selector_and_reason() {
+		let mut error = ERROR_STRING_SELECTOR.to_vec();
+		error.extend(
+			DynSolValue::Tuple(vec![DynSolValue::String("Paused".into())]).abi_encode_params(),
+		);
+		assert_eq!(revert_selector(&error).as_deref(), Some("0x08c379a0"));
+		assert_eq!(decode_revert_reason(&error).as_deref(), Some("Paused"));
+
+		let mut panic = PANIC_SELECTOR.to_vec();
+		panic.extend(DynSolValue::Uint(U256::from(0x11), 256).abi_encode());
+		assert_eq!(decode_revert_reason(&panic).as_deref(), Some("Panic(0x11)"));
+
+		// Custom error `InsufficientBalance(uint256)`, only identified by its selector
+		let custom = function_selector("InsufficientBalance(uint256)");
+		assert_eq!(
+			revert_selector(&custom),
+			Some(format!("0x{}", hex::encode(custom)))
+		);
+		assert_eq!(decode_revert_reason(&custom), None);
+
+		// Bare `revert()` and truncated data
+		assert_eq!(revert_selector(&[]), None);
+		assert_eq!(decode_revert_reason(&ERROR_STRING_SELECTOR), None);
+	}
+
+	#[test]
+	fn test_decode_function_params() {
+		let recipient =
+			Address::from_slice(&hex::decode("0000000000000000000000000000000000004321").unwrap());
+		let params = DynSolValue::Tuple(vec![
+			DynSolValue::Address(recipient),
+			DynSolValue::Uint(U256::from(1000), 256),
+		])
+		.abi_encode_params();
+
+		let decoded = decode_function_params("transfer(address,uint256)", &params).unwrap();
+
+		assert_eq!(
+			decoded,
+			vec![
+				(DynSolType::Address, DynSolValue::Address(recipient)),
+				(
+					DynSolType::Uint(256),
+					DynSolValue::Uint(U256::from(1000), 256)
+				),
+			]
+		);
+		assert!(decode_function_params("transfer(address,uint256)", &params[..32]).is_err());
+		assert!(decode_function_params("transfer", &params).is_err());
+		assert!(decode_function_params("transfer(notatype)", &params).is_err());
+	}
+
+	#[test]
+	fn test_decode_event_params() {
+		let event = Event::parse(
+			"event Delegated(address indexed delegator, address delegate, uint256 indexed id, \
+			 uint256 amount)",
+		)
+		.unwrap();
+		let delegator =
+			Address::from_slice(&hex::decode("0000000000000000000000000000000000001234").unwrap());
+		let delegate =
+			Address::from_slice(&hex::decode("0000000000000000000000000000000000005678").unwrap());
+		let topics = vec![
+			event.selector(),
+			delegator.into_word(),
+			B256::from_slice(&DynSolValue::Uint(U256::from(7), 256).abi_encode()),
+		];
+		let data = DynSolValue::Tuple(vec![
+			DynSolValue::Address(delegate),
+			DynSolValue::Uint(U256::from(100), 256),
+		])
+		.abi_encode_params();
+
+		let params = decode_event_params(&event, &topics, &data).unwrap();
+
+		let decoded: Vec<_> = params
+			.iter()
+			.map(|p| (p.name.as_str(), p.value.clone(), p.kind.as_str(), p.indexed))
+			.collect();
+		assert_eq!(
+			decoded,
+			vec![
+				("delegator", format!("0x{:x}", delegator), "address", true),
+				("delegate", format!("0x{:x}", delegate), "address", false),
+				("id", "7".to_string(), "uint256", true),
+				("amount", "100".to_string(), "uint256", false),
+			]
+		);
+
+		// Topics and data are decoded separately, so a missing topic fails the decoding
+		assert!(decode_event_params(&event, &topics[..2], &data).is_err());
+		assert!(decode_event_params(&event, &topics, &data[..32]).is_err());
+	}
+
 	#[test]
 	fn test_format_token_value() {
 		// Test Address
@@ -57,6 +57,8 @@ impl<T> MidnightBlockFilter<T> {
 			matched_transactions.push(TransactionCondition {
 				expression: None,
 				status: TransactionStatus::Any,
+				warn_threshold_expression: None,
+				is_contract_creation: false,
 			});
 		} else {
 			let tx_status = events
@@ -83,6 +85,8 @@ impl<T> MidnightBlockFilter<T> {
 					matched_transactions.push(TransactionCondition {
 						expression: None,
 						status: tx_status,
+						warn_threshold_expression: None,
+						is_contract_creation: false,
 					});
 					break;
 				}
@@ -124,6 +128,8 @@ impl<T> MidnightBlockFilter<T> {
 					matched_functions.push(FunctionCondition {
 						signature: normalized_signature.clone(),
 						expression: condition.expression.clone(),
+						warn_threshold_expression: None,
+						eip712_schema: None,
 					});
 
 					// Add the matched arguments if we have any
@@ -386,6 +392,10 @@ impl<T: BlockChainClient + MidnightClientTrait> BlockFilter for MidnightBlockFil
 								.into_iter()
 								.filter(|_| has_transaction_match)
 								.collect(),
+							token_transfers: vec![],
+							block_conditions: vec![],
+							balance_conditions: vec![],
+							state_conditions: vec![],
 						},
 						matched_on_args: Some(MidnightMatchArguments {
 							events: if has_event_match {
@@ -426,6 +436,7 @@ mod tests {
 		let mut matched_events = vec![EventCondition {
 			signature: "test".to_string(),
 			expression: None,
+			warn_threshold_expression: None,
 		}];
 		let mut matched_on_args = MidnightMatchArguments {
 			events: Some(Vec::new()),
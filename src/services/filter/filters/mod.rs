@@ -6,6 +6,7 @@
 //! - EVM-specific implementation
 //! - Stellar-specific implementation
 //! - Midnight-specific implementation
+//! - Solana-specific implementation
 
 pub mod evm {
 	pub mod evaluator;
@@ -23,11 +24,27 @@ pub mod midnight {
 	pub mod helpers;
 }
 
+pub mod solana {
+	pub mod filter;
+	pub mod helpers;
+}
+
 use async_trait::async_trait;
+use futures::{
+	stream::{self, StreamExt, TryStreamExt},
+	Future,
+};
 
 use crate::{
 	models::{BlockType, ContractSpec, Monitor, MonitorMatch, Network},
-	services::{blockchain::BlockFilterFactory, filter::error::FilterError},
+	services::{
+		blockchain::{BlockChainClient, BlockFilterFactory},
+		filter::{
+			balance::{find_balance_matches, BalanceCache},
+			error::FilterError,
+			pre_filter::{resolve_pre_filter_rpc_calls, set_rpc_results, RpcResults},
+		},
+	},
 };
 
 /// Trait for filtering blockchain data
@@ -45,11 +62,61 @@ pub trait BlockFilter {
 		monitors: &[Monitor],
 		contract_specs: Option<&[(String, ContractSpec)]>,
 	) -> Result<Vec<MonitorMatch>, FilterError>;
+
+	/// Returns the filter with the results of the pre-filter RPC calls of the monitors, bound to
+	/// the `${rpc.<name>}` variables of their expressions
+	///
+	/// Filters of chains that don't evaluate expressions ignore the results.
+	fn with_rpc_results(self, _rpc_results: RpcResults) -> Self
+	where
+		Self: Sized,
+	{
+		self
+	}
+}
+
+/// Filters a block for every monitor, evaluating up to `concurrency` monitors at once.
+///
+/// Matches are returned grouped by monitor in the order of `monitors`, so the result is the
+/// same as when evaluating monitors one after another. The first error aborts the evaluation.
+///
+/// # Arguments
+/// * `monitors` - Monitors to evaluate
+/// * `concurrency` - Maximum number of monitors evaluated at once (0 is treated as 1)
+/// * `filter_monitor` - Finds the matches of a single monitor
+///
+/// # Returns
+/// Matches of all monitors or the first filter error
+pub(crate) async fn filter_monitors<'a, F, Fut>(
+	monitors: &'a [Monitor],
+	concurrency: usize,
+	filter_monitor: F,
+) -> Result<Vec<MonitorMatch>, FilterError>
+where
+	F: Fn(&'a Monitor) -> Fut,
+	Fut: Future<Output = Result<Vec<MonitorMatch>, FilterError>> + 'a,
+{
+	let mut results: Vec<(usize, Vec<MonitorMatch>)> = stream::iter(monitors.iter().enumerate())
+		.map(|(index, monitor)| {
+			let matches = filter_monitor(monitor);
+			async move { matches.await.map(|matches| (index, matches)) }
+		})
+		.buffer_unordered(concurrency.max(1))
+		.try_collect()
+		.await?;
+
+	// Restore the monitor order, which buffer_unordered doesn't preserve
+	results.sort_unstable_by_key(|(index, _)| *index);
+
+	Ok(results
+		.into_iter()
+		.flat_map(|(_, matches)| matches)
+		.collect())
 }
 
 #[async_trait]
 pub trait FilterServiceTrait: Send + Sync {
-	async fn filter_block<T: BlockFilterFactory<T> + Send + Sync + 'static>(
+	async fn filter_block<T: BlockChainClient + BlockFilterFactory<T> + Send + Sync + 'static>(
 		&self,
 		client: &T,
 		network: &Network,
@@ -62,11 +129,21 @@ pub trait FilterServiceTrait: Send + Sync {
 /// Service for filtering blockchain data
 ///
 /// This service provides a way to filter blockchain data based on a set of monitors.
-pub struct FilterService {}
+pub struct FilterService {
+	/// Previous balances of the addresses monitored by balance conditions
+	balance_cache: BalanceCache,
+}
 
 impl FilterService {
 	pub fn new() -> Self {
-		FilterService {}
+		FilterService {
+			balance_cache: BalanceCache::new(),
+		}
+	}
+
+	/// Returns the previous balances of the addresses monitored by balance conditions
+	pub fn balance_cache(&self) -> &BalanceCache {
+		&self.balance_cache
 	}
 }
 
@@ -77,7 +154,7 @@ impl Default for FilterService {
 }
 
 impl FilterService {
-	pub async fn filter_block<T: BlockFilterFactory<T>>(
+	pub async fn filter_block<T: BlockChainClient + BlockFilterFactory<T>>(
 		&self,
 		client: &T,
 		network: &Network,
@@ -85,16 +162,30 @@ impl FilterService {
 		monitors: &[Monitor],
 		contract_specs: Option<&[(String, ContractSpec)]>,
 	) -> Result<Vec<MonitorMatch>, FilterError> {
-		let filter = T::filter();
-		filter
-			.filter_block(client, network, block, monitors, contract_specs)
-			.await
+		let (monitors, rpc_results) = resolve_pre_filter_rpc_calls(client, monitors).await;
+		let filter = T::filter().with_rpc_results(rpc_results.clone());
+		let mut matches = filter
+			.filter_block(client, network, block, &monitors, contract_specs)
+			.await?;
+		matches.extend(
+			find_balance_matches(
+				client,
+				network,
+				block,
+				&monitors,
+				&rpc_results,
+				&self.balance_cache,
+			)
+			.await?,
+		);
+		set_rpc_results(&mut matches, &rpc_results);
+		Ok(matches)
 	}
 }
 
 #[async_trait]
 impl FilterServiceTrait for FilterService {
-	async fn filter_block<T: BlockFilterFactory<T> + Send + Sync + 'static>(
+	async fn filter_block<T: BlockChainClient + BlockFilterFactory<T> + Send + Sync + 'static>(
 		&self,
 		client: &T,
 		network: &Network,
@@ -102,9 +193,23 @@ impl FilterServiceTrait for FilterService {
 		monitors: &[Monitor],
 		contract_specs: Option<&[(String, ContractSpec)]>,
 	) -> Result<Vec<MonitorMatch>, FilterError> {
-		let filter = T::filter();
-		filter
-			.filter_block(client, network, block, monitors, contract_specs)
-			.await
+		let (monitors, rpc_results) = resolve_pre_filter_rpc_calls(client, monitors).await;
+		let filter = T::filter().with_rpc_results(rpc_results.clone());
+		let mut matches = filter
+			.filter_block(client, network, block, &monitors, contract_specs)
+			.await?;
+		matches.extend(
+			find_balance_matches(
+				client,
+				network,
+				block,
+				&monitors,
+				&rpc_results,
+				&self.balance_cache,
+			)
+			.await?,
+		);
+		set_rpc_results(&mut matches, &rpc_results);
+		Ok(matches)
 	}
 }
@@ -0,0 +1,570 @@
+//! Solana blockchain filter implementation.
+//!
+//! This module provides filtering capabilities for Solana blockchain. It handles:
+//! - Program id matching against monitored addresses
+//! - Transaction status matching
+//! - Instruction matching based on the instruction names logged by programs
+//! - Log message matching
+//!
+//! Instruction data is not decoded yet, so monitor validation rejects condition expressions on
+//! Solana networks.
+
+#![allow(clippy::result_large_err)]
+
+use async_trait::async_trait;
+use std::marker::PhantomData;
+use tracing::instrument;
+
+use crate::{
+	models::{
+		BlockType, ContractSpec, EventCondition, FunctionCondition, MatchConditions, Monitor,
+		MonitorMatch, Network, SolanaMatchArguments, SolanaMatchParamEntry, SolanaMatchParamsMap,
+		SolanaMonitorMatch, SolanaTransaction, TransactionCondition, TransactionStatus,
+	},
+	services::{
+		blockchain::{BlockChainClient, SolanaClientTrait},
+		filter::{
+			solana_helpers::{
+				are_same_address, are_same_signature, get_instruction_names, parse_program_logs,
+			},
+			BlockFilter, FilterError,
+		},
+	},
+};
+
+/// Filter implementation for Solana blockchain
+pub struct SolanaBlockFilter<T> {
+	pub _client: PhantomData<T>,
+}
+
+impl<T> SolanaBlockFilter<T> {
+	/// Checks whether a transaction involves any of the monitored addresses.
+	///
+	/// A transaction is involved if it invokes a monitored program or references a
+	/// monitored account.
+	///
+	/// # Arguments
+	/// * `monitored_addresses` - Addresses to monitor
+	/// * `transaction` - The transaction to check
+	///
+	/// # Returns
+	/// `true` if the transaction involves a monitored address, `false` otherwise
+	pub fn is_transaction_involved(
+		&self,
+		monitored_addresses: &[String],
+		transaction: &SolanaTransaction,
+	) -> bool {
+		transaction
+			.program_ids()
+			.iter()
+			.chain(transaction.account_keys().iter())
+			.any(|address| {
+				monitored_addresses
+					.iter()
+					.any(|monitored| are_same_address(address, monitored))
+			})
+	}
+
+	/// Finds transactions that match the monitor's conditions.
+	///
+	/// # Arguments
+	/// * `transaction` - The transaction to check
+	/// * `monitor` - Monitor containing match conditions
+	/// * `matched_transactions` - Vector to store matching transactions
+	pub fn find_matching_transaction(
+		&self,
+		transaction: &SolanaTransaction,
+		monitor: &Monitor,
+		matched_transactions: &mut Vec<TransactionCondition>,
+	) {
+		let tx_status = if transaction.is_success() {
+			TransactionStatus::Success
+		} else {
+			TransactionStatus::Failure
+		};
+
+		if monitor.match_conditions.transactions.is_empty() {
+			// Match all transactions
+			matched_transactions.push(TransactionCondition {
+				expression: None,
+				status: TransactionStatus::Any,
+				warn_threshold_expression: None,
+				is_contract_creation: false,
+			});
+		} else {
+			// Check each transaction condition
+			for condition in &monitor.match_conditions.transactions {
+				let status_matches = match &condition.status {
+					TransactionStatus::Any => true,
+					required_status => *required_status == tx_status,
+				};
+
+				if status_matches {
+					matched_transactions.push(TransactionCondition {
+						expression: None,
+						status: tx_status,
+						warn_threshold_expression: None,
+						is_contract_creation: false,
+					});
+					break;
+				}
+			}
+		}
+	}
+
+	/// Finds instructions in a transaction that match the monitor's function conditions.
+	///
+	/// Instructions are identified by the `Instruction: <Name>` log line emitted by native
+	/// and Anchor programs, restricted to programs in the monitored addresses.
+	///
+	/// # Arguments
+	/// * `monitored_addresses` - Addresses to monitor
+	/// * `transaction` - The transaction containing the instructions
+	/// * `monitor` - Monitor containing function match conditions
+	/// * `matched_functions` - Vector to store matching functions
+	/// * `matched_on_args` - Arguments from matched instructions
+	pub fn find_matching_functions_for_transaction(
+		&self,
+		monitored_addresses: &[String],
+		transaction: &SolanaTransaction,
+		monitor: &Monitor,
+		matched_functions: &mut Vec<FunctionCondition>,
+		matched_on_args: &mut SolanaMatchArguments,
+	) {
+		if monitor.match_conditions.functions.is_empty() {
+			return;
+		}
+
+		let instructions: Vec<(String, String)> = get_instruction_names(transaction.log_messages())
+			.into_iter()
+			.filter(|(program_id, _)| {
+				monitored_addresses
+					.iter()
+					.any(|monitored| are_same_address(program_id, monitored))
+			})
+			.collect();
+
+		for condition in &monitor.match_conditions.functions {
+			if let Some((program_id, instruction_name)) = instructions
+				.iter()
+				.find(|(_, name)| are_same_signature(name, &condition.signature))
+			{
+				matched_functions.push(FunctionCondition {
+					signature: condition.signature.clone(),
+					expression: condition.expression.clone(),
+					warn_threshold_expression: None,
+					eip712_schema: None,
+				});
+
+				if let Some(functions) = &mut matched_on_args.functions {
+					functions.push(SolanaMatchParamsMap {
+						signature: condition.signature.clone(),
+						args: Some(vec![
+							SolanaMatchParamEntry {
+								name: "program_id".to_string(),
+								value: program_id.clone(),
+								kind: "pubkey".to_string(),
+								indexed: false,
+							},
+							SolanaMatchParamEntry {
+								name: "instruction".to_string(),
+								value: instruction_name.clone(),
+								kind: "string".to_string(),
+								indexed: false,
+							},
+						]),
+					});
+				}
+			}
+		}
+	}
+
+	/// Finds log messages in a transaction that match the monitor's event conditions.
+	///
+	/// An event condition matches when a `Program log:` or `Program data:` message emitted
+	/// by a monitored program contains the condition signature.
+	///
+	/// # Arguments
+	/// * `monitored_addresses` - Addresses to monitor
+	/// * `transaction` - The transaction containing the logs
+	/// * `monitor` - Monitor containing event match conditions
+	/// * `matched_events` - Vector to store matching events
+	/// * `matched_on_args` - Arguments from matched logs
+	pub fn find_matching_events_for_transaction(
+		&self,
+		monitored_addresses: &[String],
+		transaction: &SolanaTransaction,
+		monitor: &Monitor,
+		matched_events: &mut Vec<EventCondition>,
+		matched_on_args: &mut SolanaMatchArguments,
+	) {
+		if monitor.match_conditions.events.is_empty() {
+			return;
+		}
+
+		let program_logs: Vec<_> = parse_program_logs(transaction.log_messages())
+			.into_iter()
+			.filter(|log| {
+				monitored_addresses
+					.iter()
+					.any(|monitored| are_same_address(&log.program_id, monitored))
+			})
+			.collect();
+
+		for condition in &monitor.match_conditions.events {
+			if let Some(log) = program_logs
+				.iter()
+				.find(|log| log.message.contains(&condition.signature))
+			{
+				matched_events.push(EventCondition {
+					signature: condition.signature.clone(),
+					expression: condition.expression.clone(),
+					warn_threshold_expression: None,
+				});
+
+				if let Some(events) = &mut matched_on_args.events {
+					events.push(SolanaMatchParamsMap {
+						signature: condition.signature.clone(),
+						args: Some(vec![
+							SolanaMatchParamEntry {
+								name: "program_id".to_string(),
+								value: log.program_id.clone(),
+								kind: "pubkey".to_string(),
+								indexed: false,
+							},
+							SolanaMatchParamEntry {
+								name: "log".to_string(),
+								value: log.message.clone(),
+								kind: "string".to_string(),
+								indexed: false,
+							},
+						]),
+					});
+				}
+			}
+		}
+	}
+}
+
+#[async_trait]
+impl<T: BlockChainClient + SolanaClientTrait> BlockFilter for SolanaBlockFilter<T> {
+	type Client = T;
+	/// Processes a block and finds matches based on monitor conditions.
+	///
+	/// # Arguments
+	/// * `client` - Blockchain client for additional data fetching
+	/// * `network` - Network of the blockchain
+	/// * `block` - The block to process
+	/// * `monitors` - Active monitors containing match conditions
+	/// * `contract_specs` - Unused, Solana programs are matched without a spec
+	///
+	/// # Returns
+	/// Vector of matches found in the block
+	#[instrument(skip_all, fields(network = %network.slug))]
+	async fn filter_block(
+		&self,
+		_client: &T,
+		network: &Network,
+		block: &BlockType,
+		monitors: &[Monitor],
+		_contract_specs: Option<&[(String, ContractSpec)]>,
+	) -> Result<Vec<MonitorMatch>, FilterError> {
+		let solana_block = match block {
+			BlockType::Solana(block) => block,
+			_ => {
+				return Err(FilterError::block_type_mismatch(
+					"Expected Solana block",
+					None,
+					None,
+				))
+			}
+		};
+
+		let transactions: Vec<SolanaTransaction> = solana_block
+			.transactions
+			.iter()
+			.cloned()
+			.map(SolanaTransaction::from)
+			.collect();
+
+		if transactions.is_empty() {
+			tracing::debug!("No transactions found for slot {}", solana_block.slot);
+			return Ok(vec![]);
+		}
+
+		tracing::debug!("Processing slot {}", solana_block.slot);
+		tracing::debug!("Processing {} monitor(s)", monitors.len());
+
+		let mut matching_results = Vec::<MonitorMatch>::new();
+
+		for monitor in monitors {
+			tracing::debug!("Processing monitor: {:?}", monitor.name);
+			let monitored_addresses: Vec<String> = monitor
+				.addresses
+				.iter()
+				.map(|a| a.address.clone())
+				.collect();
+
+			for transaction in transactions.iter() {
+				if !self.is_transaction_involved(&monitored_addresses, transaction) {
+					continue;
+				}
+
+				let mut matched_transactions = Vec::<TransactionCondition>::new();
+				let mut matched_functions = Vec::<FunctionCondition>::new();
+				let mut matched_events = Vec::<EventCondition>::new();
+				let mut matched_on_args = SolanaMatchArguments {
+					events: Some(Vec::new()),
+					functions: Some(Vec::new()),
+				};
+
+				tracing::debug!("Processing transaction: {:?}", transaction.signature());
+
+				self.find_matching_transaction(transaction, monitor, &mut matched_transactions);
+
+				self.find_matching_functions_for_transaction(
+					&monitored_addresses,
+					transaction,
+					monitor,
+					&mut matched_functions,
+					&mut matched_on_args,
+				);
+
+				self.find_matching_events_for_transaction(
+					&monitored_addresses,
+					transaction,
+					monitor,
+					&mut matched_events,
+					&mut matched_on_args,
+				);
+
+				let monitor_conditions = &monitor.match_conditions;
+				let has_event_match =
+					!monitor_conditions.events.is_empty() && !matched_events.is_empty();
+				let has_function_match =
+					!monitor_conditions.functions.is_empty() && !matched_functions.is_empty();
+				let has_transaction_match =
+					!monitor_conditions.transactions.is_empty() && !matched_transactions.is_empty();
+
+				let should_match = match (
+					monitor_conditions.events.is_empty(),
+					monitor_conditions.functions.is_empty(),
+					monitor_conditions.transactions.is_empty(),
+				) {
+					// Case 1: No conditions defined, match everything
+					(true, true, true) => true,
+
+					// Case 2: Only transaction conditions defined
+					(true, true, false) => has_transaction_match,
+
+					// Case 3: No transaction conditions, match based on events/functions
+					(_, _, true) => has_event_match || has_function_match,
+
+					// Case 4: Transaction conditions exist, they must be satisfied along with
+					// events/functions
+					_ => (has_event_match || has_function_match) && has_transaction_match,
+				};
+
+				if should_match {
+					matching_results.push(MonitorMatch::Solana(Box::new(SolanaMonitorMatch {
+						monitor: monitor.clone(),
+						transaction: transaction.clone(),
+						slot: solana_block.slot,
+						block_time: solana_block.block_time,
+						network_slug: network.slug.clone(),
+						matched_on: MatchConditions {
+							events: matched_events
+								.clone()
+								.into_iter()
+								.filter(|_| has_event_match)
+								.collect(),
+							functions: matched_functions
+								.clone()
+								.into_iter()
+								.filter(|_| has_function_match)
+								.collect(),
+							transactions: matched_transactions
+								.clone()
+								.into_iter()
+								.filter(|_| has_transaction_match)
+								.collect(),
+							token_transfers: vec![],
+							block_conditions: vec![],
+							balance_conditions: vec![],
+							state_conditions: vec![],
+						},
+						matched_on_args: Some(SolanaMatchArguments {
+							events: if has_event_match {
+								matched_on_args.events.clone()
+							} else {
+								None
+							},
+							functions: if has_function_match {
+								matched_on_args.functions.clone()
+							} else {
+								None
+							},
+						}),
+					})));
+				}
+			}
+		}
+		Ok(matching_results)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::models::{AddressMatchMode, AddressWithSpec, SolanaTransactionInfo};
+	use serde_json::json;
+
+	const TOKEN_PROGRAM: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+	fn create_test_filter() -> SolanaBlockFilter<()> {
+		SolanaBlockFilter::<()> {
+			_client: PhantomData,
+		}
+	}
+
+	fn create_test_transaction(success: bool) -> SolanaTransaction {
+		let err = if success {
+			json!(null)
+		} else {
+			json!({"InstructionError": [0, "Custom"]})
+		};
+		let raw = json!({
+			"meta": {
+				"err": err,
+				"fee": 5000,
+				"logMessages": [
+					format!("Program {} invoke [1]", TOKEN_PROGRAM),
+					"Program log: Instruction: TransferChecked",
+					format!("Program {} success", TOKEN_PROGRAM)
+				]
+			},
+			"transaction": {
+				"signatures": ["sig1"],
+				"message": {
+					"accountKeys": ["FeePayer1111111111111111111111111111111111", TOKEN_PROGRAM],
+					"instructions": [{"programIdIndex": 1, "accounts": [0], "data": ""}]
+				}
+			}
+		});
+		SolanaTransaction::from(serde_json::from_value::<SolanaTransactionInfo>(raw).unwrap())
+	}
+
+	fn create_test_monitor(match_conditions: MatchConditions) -> Monitor {
+		Monitor {
+			name: "solana".to_string(),
+			addresses: vec![AddressWithSpec {
+				address: TOKEN_PROGRAM.to_string(),
+				contract_spec: None,
+				match_mode: AddressMatchMode::Exact,
+			}],
+			match_conditions,
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn test_is_transaction_involved() {
+		let filter = create_test_filter();
+		let tx = create_test_transaction(true);
+
+		assert!(filter.is_transaction_involved(&[TOKEN_PROGRAM.to_string()], &tx));
+		assert!(
+			!filter.is_transaction_involved(&["11111111111111111111111111111111".to_string()], &tx)
+		);
+	}
+
+	#[test]
+	fn test_find_matching_transaction_by_status() {
+		let filter = create_test_filter();
+		let monitor = create_test_monitor(MatchConditions {
+			transactions: vec![TransactionCondition {
+				status: TransactionStatus::Failure,
+				expression: None,
+				warn_threshold_expression: None,
+				is_contract_creation: false,
+			}],
+			..Default::default()
+		});
+
+		let mut matched = Vec::new();
+		filter.find_matching_transaction(&create_test_transaction(true), &monitor, &mut matched);
+		assert!(matched.is_empty());
+
+		filter.find_matching_transaction(&create_test_transaction(false), &monitor, &mut matched);
+		assert_eq!(matched.len(), 1);
+		assert_eq!(matched[0].status, TransactionStatus::Failure);
+	}
+
+	#[test]
+	fn test_find_matching_functions_for_transaction() {
+		let filter = create_test_filter();
+		let monitor = create_test_monitor(MatchConditions {
+			functions: vec![FunctionCondition {
+				signature: "transfer_checked".to_string(),
+				expression: None,
+				warn_threshold_expression: None,
+				eip712_schema: None,
+			}],
+			..Default::default()
+		});
+		let mut matched_functions = Vec::new();
+		let mut matched_on_args = SolanaMatchArguments {
+			events: Some(Vec::new()),
+			functions: Some(Vec::new()),
+		};
+
+		filter.find_matching_functions_for_transaction(
+			&[TOKEN_PROGRAM.to_string()],
+			&create_test_transaction(true),
+			&monitor,
+			&mut matched_functions,
+			&mut matched_on_args,
+		);
+
+		assert_eq!(matched_functions.len(), 1);
+		let args = matched_on_args.functions.unwrap()[0].args.clone().unwrap();
+		assert_eq!(args[0].value, TOKEN_PROGRAM);
+		assert_eq!(args[1].value, "TransferChecked");
+	}
+
+	#[test]
+	fn test_find_matching_events_ignores_unmonitored_programs() {
+		let filter = create_test_filter();
+		let monitor = create_test_monitor(MatchConditions {
+			events: vec![EventCondition {
+				signature: "TransferChecked".to_string(),
+				expression: None,
+				warn_threshold_expression: None,
+			}],
+			..Default::default()
+		});
+		let mut matched_events = Vec::new();
+		let mut matched_on_args = SolanaMatchArguments {
+			events: Some(Vec::new()),
+			functions: Some(Vec::new()),
+		};
+
+		filter.find_matching_events_for_transaction(
+			&["11111111111111111111111111111111".to_string()],
+			&create_test_transaction(true),
+			&monitor,
+			&mut matched_events,
+			&mut matched_on_args,
+		);
+		assert!(matched_events.is_empty());
+
+		filter.find_matching_events_for_transaction(
+			&[TOKEN_PROGRAM.to_string()],
+			&create_test_transaction(true),
+			&monitor,
+			&mut matched_events,
+			&mut matched_on_args,
+		);
+		assert_eq!(matched_events.len(), 1);
+	}
+}
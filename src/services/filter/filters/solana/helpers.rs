@@ -0,0 +1,216 @@
+//! Helper functions for Solana-specific operations.
+//!
+//! This module provides utility functions for working with Solana-specific data types,
+//! including address comparison, instruction name normalization and attribution of
+//! transaction log messages to the programs that emitted them.
+
+/// Prefix of log lines emitted through `msg!` / `sol_log`
+const PROGRAM_LOG_PREFIX: &str = "Program log: ";
+
+/// Prefix of log lines emitted through `sol_log_data` (e.g. Anchor events)
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Prefix used by native and Anchor programs to log the executed instruction
+const INSTRUCTION_LOG_PREFIX: &str = "Instruction: ";
+
+/// A log message attributed to the program that emitted it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgramLog {
+	/// Program id of the program that emitted the log
+	pub program_id: String,
+
+	/// Log message without the `Program log: ` / `Program data: ` prefix
+	pub message: String,
+}
+
+/// Compares two Solana addresses for equality.
+///
+/// Base-58 addresses are case-sensitive, so only surrounding whitespace is ignored.
+///
+/// # Arguments
+/// * `address1` - First address to compare
+/// * `address2` - Second address to compare
+///
+/// # Returns
+/// `true` if the addresses are equal, `false` otherwise
+pub fn are_same_address(address1: &str, address2: &str) -> bool {
+	address1.trim() == address2.trim()
+}
+
+/// Normalizes an instruction name or function signature for comparison.
+///
+/// Removes any parameter list, underscores and letter case so that an IDL name such as
+/// `transfer_checked` matches the logged instruction name `TransferChecked`.
+///
+/// # Arguments
+/// * `name` - Instruction name or signature to normalize
+///
+/// # Returns
+/// Normalized name
+pub fn normalize_instruction_name(name: &str) -> String {
+	let name = name.split('(').next().unwrap_or_default();
+	name.trim().replace('_', "").to_lowercase()
+}
+
+/// Compares an instruction name with a function signature.
+///
+/// # Arguments
+/// * `instruction_name` - Instruction name as logged by the program
+/// * `signature` - Function signature from the monitor configuration
+///
+/// # Returns
+/// `true` if both refer to the same instruction, `false` otherwise
+pub fn are_same_signature(instruction_name: &str, signature: &str) -> bool {
+	normalize_instruction_name(instruction_name) == normalize_instruction_name(signature)
+}
+
+/// Attributes transaction log messages to the programs that emitted them.
+///
+/// Solana logs are a flat list; program boundaries are marked by
+/// `Program <id> invoke [<depth>]` and `Program <id> success|failed` lines. This function
+/// replays those markers to keep track of the currently executing program and returns
+/// every `Program log:` and `Program data:` line together with its program id.
+///
+/// # Arguments
+/// * `logs` - Log messages of a transaction
+///
+/// # Returns
+/// Vector of log messages with their emitting program
+pub fn parse_program_logs(logs: &[String]) -> Vec<ProgramLog> {
+	let mut invocation_stack: Vec<String> = Vec::new();
+	let mut program_logs = Vec::new();
+
+	for log in logs {
+		if let Some(message) = log
+			.strip_prefix(PROGRAM_LOG_PREFIX)
+			.or_else(|| log.strip_prefix(PROGRAM_DATA_PREFIX))
+		{
+			if let Some(program_id) = invocation_stack.last() {
+				program_logs.push(ProgramLog {
+					program_id: program_id.clone(),
+					message: message.to_string(),
+				});
+			}
+			continue;
+		}
+
+		let parts: Vec<&str> = log.split_whitespace().collect();
+		match parts.as_slice() {
+			["Program", program_id, "invoke", ..] => {
+				invocation_stack.push(program_id.to_string());
+			}
+			["Program", _, "success"] | ["Program", _, "failed:", ..] => {
+				invocation_stack.pop();
+			}
+			_ => {}
+		}
+	}
+
+	program_logs
+}
+
+/// Extracts the instruction names logged by each program.
+///
+/// # Arguments
+/// * `logs` - Log messages of a transaction
+///
+/// # Returns
+/// Vector of `(program_id, instruction_name)` pairs in execution order
+pub fn get_instruction_names(logs: &[String]) -> Vec<(String, String)> {
+	parse_program_logs(logs)
+		.into_iter()
+		.filter_map(|log| {
+			log.message
+				.strip_prefix(INSTRUCTION_LOG_PREFIX)
+				.map(|name| (log.program_id.clone(), name.trim().to_string()))
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const TOKEN_PROGRAM: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+	const AMM_PROGRAM: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+
+	fn create_test_logs() -> Vec<String> {
+		vec![
+			format!("Program {} invoke [1]", AMM_PROGRAM),
+			"Program log: Instruction: Swap".to_string(),
+			format!("Program {} invoke [2]", TOKEN_PROGRAM),
+			"Program log: Instruction: Transfer".to_string(),
+			format!(
+				"Program {} consumed 4645 of 180000 compute units",
+				TOKEN_PROGRAM
+			),
+			format!("Program {} success", TOKEN_PROGRAM),
+			"Program data: vdt/007mYe4=".to_string(),
+			format!("Program {} success", AMM_PROGRAM),
+		]
+	}
+
+	#[test]
+	fn test_are_same_address() {
+		assert!(are_same_address(TOKEN_PROGRAM, TOKEN_PROGRAM));
+		assert!(are_same_address(
+			&format!(" {} ", TOKEN_PROGRAM),
+			TOKEN_PROGRAM
+		));
+		assert!(!are_same_address(
+			TOKEN_PROGRAM,
+			&TOKEN_PROGRAM.to_lowercase()
+		));
+	}
+
+	#[test]
+	fn test_are_same_signature() {
+		assert!(are_same_signature("TransferChecked", "transfer_checked"));
+		assert!(are_same_signature("Transfer", "Transfer()"));
+		assert!(are_same_signature("Transfer", "transfer(u64)"));
+		assert!(!are_same_signature("Transfer", "TransferChecked"));
+	}
+
+	#[test]
+	fn test_parse_program_logs_attributes_nested_invocations() {
+		let logs = parse_program_logs(&create_test_logs());
+
+		assert_eq!(logs.len(), 3);
+		assert_eq!(logs[0].program_id, AMM_PROGRAM);
+		assert_eq!(logs[0].message, "Instruction: Swap");
+		assert_eq!(logs[1].program_id, TOKEN_PROGRAM);
+		assert_eq!(logs[1].message, "Instruction: Transfer");
+		assert_eq!(logs[2].program_id, AMM_PROGRAM);
+		assert_eq!(logs[2].message, "vdt/007mYe4=");
+	}
+
+	#[test]
+	fn test_parse_program_logs_failed_invocation() {
+		let logs = vec![
+			format!("Program {} invoke [1]", TOKEN_PROGRAM),
+			"Program log: Error: insufficient funds".to_string(),
+			format!(
+				"Program {} failed: custom program error: 0x1",
+				TOKEN_PROGRAM
+			),
+			"Program log: orphan".to_string(),
+		];
+		let parsed = parse_program_logs(&logs);
+
+		assert_eq!(parsed.len(), 1);
+		assert_eq!(parsed[0].message, "Error: insufficient funds");
+	}
+
+	#[test]
+	fn test_get_instruction_names() {
+		let names = get_instruction_names(&create_test_logs());
+
+		assert_eq!(
+			names,
+			vec![
+				(AMM_PROGRAM.to_string(), "Swap".to_string()),
+				(TOKEN_PROGRAM.to_string(), "Transfer".to_string()),
+			]
+		);
+	}
+}
@@ -6,33 +6,36 @@
 //! - Compare different types of parameter values
 //! - Evaluate complex matching expressions
 
-use std::marker::PhantomData;
+use std::{collections::BTreeMap, marker::PhantomData};
 
 use async_trait::async_trait;
 use base64::Engine;
 use serde_json::Value;
+use stellar_strkey::Contract;
 use stellar_xdr::curr::{FeeBumpTransactionInnerTx, OperationBody, TransactionEnvelope};
 use tracing::instrument;
 
 use crate::{
 	models::{
-		BlockType, ContractSpec, EventCondition, FunctionCondition, MatchConditions, Monitor,
-		MonitorMatch, Network, StellarContractFunction, StellarEvent, StellarEventParamLocation,
+		BlockCondition, BlockType, ContractSpec, EventCondition, FunctionCondition,
+		MatchConditions, MatchSeverity, Monitor, MonitorMatch, Network, StellarBlock,
+		StellarContractFunction, StellarEvent, StellarEventParamLocation,
 		StellarFormattedContractSpec, StellarMatchArguments, StellarMatchParamEntry,
-		StellarMatchParamsMap, StellarMonitorMatch, StellarTransaction, TransactionCondition,
-		TransactionStatus,
+		StellarMatchParamsMap, StellarMonitorMatch, StellarTransaction, StellarTransactionInfo,
+		TransactionCondition, TransactionStatus,
 	},
 	services::{
-		blockchain::{BlockChainClient, StellarClientTrait},
+		blockchain::{BlockChainClient, ContractCallResult, StellarClientTrait},
 		filter::{
 			expression::{self, EvaluationError},
-			filters::stellar::evaluator::StellarConditionEvaluator,
+			filters::{filter_monitors, stellar::evaluator::StellarConditionEvaluator},
 			stellar_helpers::{
-				are_same_signature, get_kind_from_value, normalize_address, parse_xdr_value,
-				parse_xdr_value_with_stellar_value, process_invoke_host_function,
+				are_same_signature, format_account_id, format_asset, format_change_trust_asset,
+				get_kind_from_value, normalize_address, parse_sc_val, parse_sc_val_argument,
+				parse_xdr_value, parse_xdr_value_with_stellar_value, process_invoke_host_function,
 				unpack_stellar_value,
 			},
-			BlockFilter, FilterError,
+			BlockFilter, FilterError, RpcResults,
 		},
 	},
 };
@@ -47,6 +50,9 @@ pub struct EventMap {
 /// Implementation of the block filter for Stellar blockchain
 pub struct StellarBlockFilter<T> {
 	pub _client: PhantomData<T>,
+	/// Results of the pre-filter RPC calls of the monitors, bound to the `${rpc.<name>}`
+	/// variables of their expressions
+	pub rpc_results: RpcResults,
 }
 
 impl<T> StellarBlockFilter<T> {
@@ -70,25 +76,159 @@ impl<T> StellarBlockFilter<T> {
 		};
 
 		struct TxOperation {
-			_operation_type: String,
+			operation_type: String,
 			sender: String,
 			receiver: String,
 			value: Option<String>,
+			// Fields specific to the type of the operation
+			params: Vec<StellarMatchParamEntry>,
 		}
 
+		let param = |name: &str, value: String, kind: &str| StellarMatchParamEntry {
+			name: name.to_string(),
+			value,
+			kind: kind.to_string(),
+			indexed: false,
+		};
+
 		let mut tx_operations: Vec<TxOperation> = vec![];
+		let mut fee_params: Vec<StellarMatchParamEntry> = vec![];
 
 		if let Some(decoded) = transaction.decoded() {
-			if let Some(TransactionEnvelope::Tx(tx)) = &decoded.envelope {
+			// Fee-bump envelopes wrap the inner transaction, whose source and operations are
+			// matched, while the fee is paid by the fee-bump source
+			let envelope = match &decoded.envelope {
+				Some(TransactionEnvelope::Tx(tx)) => Some((
+					tx,
+					tx.tx.source_account.to_string(),
+					tx.tx.fee as i64,
+					false,
+				)),
+				Some(TransactionEnvelope::TxFeeBump(tx_fee_bump)) => {
+					let FeeBumpTransactionInnerTx::Tx(inner_tx) = &tx_fee_bump.tx.inner_tx;
+					Some((
+						inner_tx,
+						tx_fee_bump.tx.fee_source.to_string(),
+						tx_fee_bump.tx.fee,
+						true,
+					))
+				}
+				_ => None,
+			};
+
+			if let Some((tx, fee_source, fee, is_fee_bump)) = envelope {
+				fee_params = vec![
+					StellarMatchParamEntry {
+						name: "fee_source".to_string(),
+						value: fee_source,
+						kind: "address".to_string(),
+						indexed: false,
+					},
+					StellarMatchParamEntry {
+						name: "fee".to_string(),
+						value: fee.to_string(),
+						kind: "i64".to_string(),
+						indexed: false,
+					},
+					StellarMatchParamEntry {
+						name: "fee_bump".to_string(),
+						value: is_fee_bump.to_string(),
+						kind: "bool".to_string(),
+						indexed: false,
+					},
+				];
+
 				let from = tx.tx.source_account.to_string();
 				for operation in tx.tx.operations.iter() {
 					match &operation.body {
 						OperationBody::Payment(payment) => {
 							let operation = TxOperation {
-								_operation_type: "payment".to_string(),
+								operation_type: "payment".to_string(),
 								sender: from.clone(),
 								receiver: payment.destination.to_string(),
 								value: Some(payment.amount.to_string()),
+								params: vec![param(
+									"asset",
+									format_asset(&payment.asset),
+									"string",
+								)],
+							};
+							tx_operations.push(operation);
+						}
+						OperationBody::PathPaymentStrictSend(path_payment) => {
+							let operation = TxOperation {
+								operation_type: "path_payment_strict_send".to_string(),
+								sender: from.clone(),
+								receiver: path_payment.destination.to_string(),
+								value: Some(path_payment.send_amount.to_string()),
+								params: vec![
+									param(
+										"send_asset",
+										format_asset(&path_payment.send_asset),
+										"string",
+									),
+									param(
+										"send_amount",
+										path_payment.send_amount.to_string(),
+										"i64",
+									),
+									param(
+										"dest_asset",
+										format_asset(&path_payment.dest_asset),
+										"string",
+									),
+									param("dest_min", path_payment.dest_min.to_string(), "i64"),
+								],
+							};
+							tx_operations.push(operation);
+						}
+						OperationBody::CreateAccount(create_account) => {
+							let operation = TxOperation {
+								operation_type: "create_account".to_string(),
+								sender: from.clone(),
+								receiver: format_account_id(&create_account.destination),
+								value: Some(create_account.starting_balance.to_string()),
+								params: vec![param(
+									"starting_balance",
+									create_account.starting_balance.to_string(),
+									"i64",
+								)],
+							};
+							tx_operations.push(operation);
+						}
+						OperationBody::ManageSellOffer(sell_offer) => {
+							// Offers have no destination
+							let operation = TxOperation {
+								operation_type: "manage_sell_offer".to_string(),
+								sender: from.clone(),
+								receiver: "".to_string(),
+								value: Some(sell_offer.amount.to_string()),
+								params: vec![
+									param("selling", format_asset(&sell_offer.selling), "string"),
+									param("buying", format_asset(&sell_offer.buying), "string"),
+									param("amount", sell_offer.amount.to_string(), "i64"),
+									param("price_n", sell_offer.price.n.to_string(), "i32"),
+									param("price_d", sell_offer.price.d.to_string(), "i32"),
+									param("offer_id", sell_offer.offer_id.to_string(), "i64"),
+								],
+							};
+							tx_operations.push(operation);
+						}
+						OperationBody::ChangeTrust(change_trust) => {
+							// Trustlines have no destination
+							let operation = TxOperation {
+								operation_type: "change_trust".to_string(),
+								sender: from.clone(),
+								receiver: "".to_string(),
+								value: Some(change_trust.limit.to_string()),
+								params: vec![
+									param(
+										"asset",
+										format_change_trust_asset(&change_trust.line),
+										"string",
+									),
+									param("limit", change_trust.limit.to_string(), "i64"),
+								],
 							};
 							tx_operations.push(operation);
 						}
@@ -96,10 +236,11 @@ impl<T> StellarBlockFilter<T> {
 							let parsed_operation =
 								process_invoke_host_function(invoke_host_function, None);
 							let operation = TxOperation {
-								_operation_type: "invoke_host_function".to_string(),
+								operation_type: "invoke_host_function".to_string(),
 								sender: from.clone(),
 								receiver: parsed_operation.0.contract_address.clone(),
 								value: None,
+								params: vec![],
 							};
 							tx_operations.push(operation);
 						}
@@ -115,6 +256,8 @@ impl<T> StellarBlockFilter<T> {
 			matched_transactions.push(TransactionCondition {
 				expression: None,
 				status: TransactionStatus::Any,
+				warn_threshold_expression: None,
+				is_contract_creation: false,
 			});
 		} else {
 			// Check each transaction condition
@@ -128,7 +271,7 @@ impl<T> StellarBlockFilter<T> {
 				if status_matches {
 					if let Some(expr) = &condition.expression {
 						// Create base transaction parameters outside operation loop
-						let base_params = vec![
+						let mut base_params = vec![
 							StellarMatchParamEntry {
 								name: "hash".to_string(),
 								value: transaction.hash().clone(),
@@ -141,14 +284,15 @@ impl<T> StellarBlockFilter<T> {
 								kind: "i64".to_string(),
 								indexed: false,
 							},
-							// Default value for value
-							StellarMatchParamEntry {
-								name: "value".to_string(),
-								value: "0".to_string(),
-								kind: "i64".to_string(),
-								indexed: false,
-							},
 						];
+						base_params.extend(fee_params.iter().cloned());
+						// Default value for value
+						base_params.push(StellarMatchParamEntry {
+							name: "value".to_string(),
+							value: "0".to_string(),
+							kind: "i64".to_string(),
+							indexed: false,
+						});
 
 						// If we have operations, check each one
 						if !tx_operations.is_empty() {
@@ -175,14 +319,23 @@ impl<T> StellarBlockFilter<T> {
 										kind: "address".to_string(),
 										indexed: false,
 									},
+									StellarMatchParamEntry {
+										name: "operation_type".to_string(),
+										value: operation.operation_type.clone(),
+										kind: "string".to_string(),
+										indexed: false,
+									},
 								]);
+								tx_params.extend(operation.params.iter().cloned());
 
 								// Evaluate the expression with transaction parameters
-								match self.evaluate_expression(expr, &tx_params) {
+								match self.evaluate_monitor_expression(monitor, expr, &tx_params) {
 									Ok(true) => {
 										matched_transactions.push(TransactionCondition {
 											expression: Some(expr.to_string()),
 											status: tx_status,
+											warn_threshold_expression: None,
+											is_contract_creation: false,
 										});
 										break;
 									}
@@ -199,11 +352,13 @@ impl<T> StellarBlockFilter<T> {
 							}
 						} else {
 							// Even with no operations, still evaluate base parameters
-							match self.evaluate_expression(expr, &base_params) {
+							match self.evaluate_monitor_expression(monitor, expr, &base_params) {
 								Ok(true) => {
 									matched_transactions.push(TransactionCondition {
 										expression: Some(expr.to_string()),
 										status: tx_status,
+										warn_threshold_expression: None,
+										is_contract_creation: false,
 									});
 									break;
 								}
@@ -223,6 +378,8 @@ impl<T> StellarBlockFilter<T> {
 						matched_transactions.push(TransactionCondition {
 							expression: None,
 							status: tx_status,
+							warn_threshold_expression: None,
+							is_contract_creation: false,
 						});
 						break;
 					}
@@ -374,6 +531,8 @@ impl<T> StellarBlockFilter<T> {
 							matched_functions.push(FunctionCondition {
 								signature: parsed_operation.function_signature.clone(),
 								expression: None,
+								warn_threshold_expression: None,
+								eip712_schema: None,
 							});
 							if let Some(functions) = &mut matched_on_args.functions {
 								functions.push(StellarMatchParamsMap {
@@ -391,13 +550,19 @@ impl<T> StellarBlockFilter<T> {
 								) {
 									// Evaluate expression if it exists
 									if let Some(expr) = &condition.expression {
-										match self.evaluate_expression(expr, &param_entries) {
+										match self.evaluate_monitor_expression(
+											monitor,
+											expr,
+											&param_entries,
+										) {
 											Ok(true) => {
 												matched_functions.push(FunctionCondition {
 													signature: parsed_operation
 														.function_signature
 														.clone(),
 													expression: Some(expr.clone()),
+													warn_threshold_expression: None,
+													eip712_schema: None,
 												});
 												if let Some(functions) =
 													&mut matched_on_args.functions
@@ -426,6 +591,8 @@ impl<T> StellarBlockFilter<T> {
 										matched_functions.push(FunctionCondition {
 											signature: parsed_operation.function_signature.clone(),
 											expression: None,
+											warn_threshold_expression: None,
+											eip712_schema: None,
 										});
 										if let Some(functions) = &mut matched_on_args.functions {
 											functions.push(StellarMatchParamsMap {
@@ -485,6 +652,7 @@ impl<T> StellarBlockFilter<T> {
 				matched_events.push(EventCondition {
 					signature: event.signature.clone(),
 					expression: None,
+					warn_threshold_expression: None,
 				});
 				if let Some(events) = &mut matched_on_args.events {
 					events.push(event.clone());
@@ -500,11 +668,12 @@ impl<T> StellarBlockFilter<T> {
 					match &condition.expression {
 						Some(expr) => {
 							if let Some(args) = &event.args {
-								match self.evaluate_expression(expr, args) {
+								match self.evaluate_monitor_expression(monitor, expr, args) {
 									Ok(true) => {
 										matched_events.push(EventCondition {
 											signature: event.signature.clone(),
 											expression: Some(expr.clone()),
+											warn_threshold_expression: None,
 										});
 										if let Some(events) = &mut matched_on_args.events {
 											events.push(event.clone());
@@ -526,6 +695,7 @@ impl<T> StellarBlockFilter<T> {
 							matched_events.push(EventCondition {
 								signature: event.signature.clone(),
 								expression: None,
+								warn_threshold_expression: None,
 							});
 						}
 					}
@@ -534,6 +704,93 @@ impl<T> StellarBlockFilter<T> {
 		}
 	}
 
+	/// Collects the contract addresses of a block's events and contract invocations
+	///
+	/// # Arguments
+	/// * `transactions` - Transactions of the block
+	/// * `events` - Events of the block
+	///
+	/// # Returns
+	/// Deduplicated, normalized contract addresses
+	pub fn contract_addresses(
+		&self,
+		transactions: &[StellarTransaction],
+		events: &[StellarEvent],
+	) -> Vec<String> {
+		let mut addresses: Vec<String> = events
+			.iter()
+			.map(|event| normalize_address(&event.contract_id))
+			.collect();
+
+		for transaction in transactions {
+			let operations = match transaction.decoded().and_then(|d| d.envelope.as_ref()) {
+				Some(TransactionEnvelope::Tx(tx)) => &tx.tx.operations,
+				Some(TransactionEnvelope::TxFeeBump(tx_fee_bump)) => {
+					match &tx_fee_bump.tx.inner_tx {
+						FeeBumpTransactionInnerTx::Tx(inner_tx) => &inner_tx.tx.operations,
+					}
+				}
+				_ => continue,
+			};
+
+			for operation in operations.iter() {
+				if let OperationBody::InvokeHostFunction(invoke_host_function) = &operation.body {
+					let (parsed_operation, _) =
+						process_invoke_host_function(invoke_host_function, None);
+					addresses.push(normalize_address(&parsed_operation.contract_address));
+				}
+			}
+		}
+
+		addresses.sort_unstable();
+		addresses.dedup();
+		addresses
+	}
+
+	/// Resolves the block addresses matched by a monitor's address patterns
+	///
+	/// Exact entries are not considered. Each candidate matching a prefix, suffix or glob entry
+	/// is returned together with the contract spec configured for that entry, if any.
+	///
+	/// # Arguments
+	/// * `monitor` - Monitor whose address patterns are resolved
+	/// * `candidates` - Normalized contract addresses seen in the block
+	/// * `contract_specs` - Contract specs keyed by their configured address
+	///
+	/// # Returns
+	/// Matched candidates and their contract specs
+	pub fn resolve_address_patterns(
+		&self,
+		monitor: &Monitor,
+		candidates: &[String],
+		contract_specs: &[(String, StellarFormattedContractSpec)],
+	) -> Vec<(String, Option<StellarFormattedContractSpec>)> {
+		let patterns: Vec<_> = monitor
+			.addresses
+			.iter()
+			.filter(|address| !address.match_mode.is_exact())
+			.collect();
+		if patterns.is_empty() {
+			return Vec::new();
+		}
+
+		candidates
+			.iter()
+			.filter_map(|candidate| {
+				let pattern = patterns
+					.iter()
+					.find(|pattern| pattern.matches(candidate, normalize_address))?;
+				let spec = contract_specs
+					.iter()
+					.find(|(addr, _)| {
+						normalize_address(addr) == normalize_address(&pattern.address)
+					})
+					.map(|(_, spec)| spec.clone());
+				Some((candidate.clone(), spec))
+			})
+			.collect()
+	}
+
 	/// Decodes Stellar events into a more processable format
 	///
 	/// # Arguments
@@ -664,6 +921,18 @@ impl<T> StellarBlockFilter<T> {
 				}
 			}
 
+			// Name the indexed parameters after the indexed parameters of the spec, in order. The
+			// spec can interleave indexed and data parameters, so they can't be named by position.
+			if let Some(spec) = event_spec {
+				let indexed_params = spec
+					.params
+					.iter()
+					.filter(|p| p.location == StellarEventParamLocation::Indexed);
+				for (arg, param) in indexed_args.iter_mut().zip(indexed_params) {
+					arg.name = param.name.clone();
+				}
+			}
+
 			// Process non-indexed parameters from value field
 			let mut value_args = Vec::new();
 			if let Some(value_xdr) = &event.value_xdr {
@@ -759,13 +1028,11 @@ impl<T> StellarBlockFilter<T> {
 							kind: arg.kind.clone(),
 							value: arg.value.clone(),
 							indexed: arg.indexed,
+							// Arguments not named after the spec are named by position
 							name: if arg.name.is_empty() {
-								event_spec
-									.and_then(|spec| spec.params.get(i))
-									.map(|param| param.name.clone())
-									.unwrap_or_else(|| i.to_string())
+								i.to_string()
 							} else {
-								arg.name.clone() // Keep the name already set
+								arg.name.clone()
 							},
 						})
 						.collect(),
@@ -781,6 +1048,225 @@ impl<T> StellarBlockFilter<T> {
 		decoded_events
 	}
 
+	/// Returns the properties of a ledger evaluated by block conditions
+	///
+	/// # Arguments
+	/// * `ledger` - The ledger to get the properties of
+	/// * `tx_count` - Number of transactions of the ledger
+	pub fn block_params(
+		&self,
+		ledger: &StellarBlock,
+		tx_count: usize,
+	) -> Vec<StellarMatchParamEntry> {
+		let param = |name: &str, value: String, kind: &str| StellarMatchParamEntry {
+			name: name.to_string(),
+			value,
+			kind: kind.to_string(),
+			indexed: false,
+		};
+		vec![
+			param("number", ledger.sequence.to_string(), "u64"),
+			param("hash", ledger.hash.clone(), "string"),
+			param(
+				"timestamp",
+				ledger.timestamp().unwrap_or_default().to_string(),
+				"u64",
+			),
+			param("tx_count", tx_count.to_string(), "u64"),
+		]
+	}
+
+	/// Finds the block conditions of a monitor that a ledger matches
+	///
+	/// # Arguments
+	/// * `block_params` - Properties of the ledger, see [`Self::block_params`]
+	/// * `monitor` - The monitor containing match conditions
+	/// * `matched_blocks` - Vector to store matching block conditions
+	pub fn find_matching_block_conditions(
+		&self,
+		block_params: &[StellarMatchParamEntry],
+		monitor: &Monitor,
+		matched_blocks: &mut Vec<BlockCondition>,
+	) {
+		for condition in &monitor.match_conditions.block_conditions {
+			match self.evaluate_monitor_expression(monitor, &condition.expression, block_params) {
+				Ok(true) => matched_blocks.push(condition.clone()),
+				Ok(false) => {}
+				Err(e) => {
+					tracing::error!(
+						"Failed to evaluate expression '{}': {}",
+						condition.expression,
+						e
+					);
+				}
+			}
+		}
+	}
+
+	/// Matches a ledger against the block conditions of monitors
+	///
+	/// # Arguments
+	/// * `network` - The network of the ledger
+	/// * `ledger` - The ledger to match
+	/// * `tx_count` - Number of transactions of the ledger
+	/// * `monitors` - List of monitors to check against
+	///
+	/// # Returns
+	/// A match without a transaction for each monitor matching the ledger
+	fn find_block_matches(
+		&self,
+		network: &Network,
+		ledger: &StellarBlock,
+		tx_count: usize,
+		monitors: &[Monitor],
+	) -> Vec<MonitorMatch> {
+		let block_params = self.block_params(ledger, tx_count);
+		let mut matches = Vec::new();
+		for monitor in monitors {
+			let mut matched_blocks = Vec::<BlockCondition>::new();
+			self.find_matching_block_conditions(&block_params, monitor, &mut matched_blocks);
+			if matched_blocks.is_empty() {
+				continue;
+			}
+
+			matches.push(MonitorMatch::Stellar(Box::new(StellarMonitorMatch {
+				monitor: monitor.clone(),
+				transaction: StellarTransaction(StellarTransactionInfo::default()),
+				ledger: ledger.clone(),
+				network_slug: network.slug.clone(),
+				matched_on: MatchConditions {
+					block_conditions: matched_blocks,
+					..Default::default()
+				},
+				matched_on_args: Some(StellarMatchArguments {
+					functions: None,
+					events: None,
+					block: Some(block_params.clone()),
+					balance: None,
+				}),
+				severity: MatchSeverity::Alert,
+			})));
+		}
+		matches
+	}
+
+	/// Checks the state conditions of a monitor against the state of a matched contract
+	///
+	/// The function of each condition is called on the contract by simulating a transaction
+	/// invoking it at the latest ledger, and the condition holds if its expression matches the
+	/// return value, named `result`. A condition whose call fails, or whose signature,
+	/// arguments or return value can't be converted, is reported and doesn't hold.
+	///
+	/// # Arguments
+	/// * `client` - Client calling the contract
+	/// * `monitor` - Monitor containing the state conditions
+	/// * `contract` - ID of the matched contract
+	///
+	/// # Returns
+	/// `true` if all state conditions hold, or an error if a call couldn't be made
+	pub async fn check_state_conditions<C: StellarClientTrait + Sync>(
+		&self,
+		client: &C,
+		monitor: &Monitor,
+		contract: &str,
+	) -> Result<bool, FilterError> {
+		for condition in &monitor.match_conditions.state_conditions {
+			let call = condition
+				.signature
+				.replace(char::is_whitespace, "")
+				.strip_suffix(')')
+				.and_then(|signature| signature.split_once('('))
+				.map(|(name, types)| (name.to_string(), types.to_string()))
+				.ok_or_else(|| anyhow::anyhow!("Signature has no parameter list"))
+				.and_then(|(name, types)| {
+					let types = types
+						.split(',')
+						.filter(|ty| !ty.is_empty())
+						.collect::<Vec<_>>();
+					if types.len() != condition.args.len() {
+						return Err(anyhow::anyhow!(
+							"Expected {} arguments, got {}",
+							types.len(),
+							condition.args.len()
+						));
+					}
+					let args = types
+						.iter()
+						.zip(&condition.args)
+						.map(|(ty, arg)| parse_sc_val_argument(ty, arg))
+						.collect::<Result<Vec<_>, _>>()?;
+					Ok((name, args))
+				});
+			let (function_name, args) = match call {
+				Ok(call) => call,
+				Err(e) => {
+					tracing::error!(
+						"Failed to prepare state condition '{}' of monitor {}: {}",
+						condition.signature,
+						monitor.name,
+						e
+					);
+					return Ok(false);
+				}
+			};
+
+			let return_value = match client
+				.simulate_contract_call(contract, &function_name, args)
+				.await
+				.map_err(|e| {
+					FilterError::network_error(
+						format!(
+							"Failed to call '{}' on contract {}",
+							condition.signature, contract
+						),
+						Some(e.into()),
+						None,
+					)
+				})? {
+				ContractCallResult::Returned(return_value) => return_value,
+				ContractCallResult::Reverted(reason) => {
+					tracing::error!(
+						"State condition '{}' of monitor {} failed on contract {}: {}",
+						condition.signature,
+						monitor.name,
+						contract,
+						reason
+					);
+					return Ok(false);
+				}
+			};
+
+			let Some(result) = parse_sc_val(&return_value, false) else {
+				tracing::error!(
+					"Unsupported return value of state condition '{}' of monitor {}",
+					condition.signature,
+					monitor.name
+				);
+				return Ok(false);
+			};
+			let params = [StellarMatchParamEntry {
+				name: "result".to_string(),
+				value: result.value,
+				kind: result.kind,
+				indexed: false,
+			}];
+
+			match self.evaluate_monitor_expression(monitor, &condition.expression, &params) {
+				Ok(true) => {}
+				Ok(false) => return Ok(false),
+				Err(e) => {
+					tracing::error!(
+						"Failed to evaluate expression '{}': {}",
+						condition.expression,
+						e
+					);
+					return Ok(false);
+				}
+			}
+		}
+		Ok(true)
+	}
+
 	/// Evaluates a complex matching expression against provided arguments
 	///
 	/// # Arguments
@@ -793,6 +1279,33 @@ impl<T> StellarBlockFilter<T> {
 		&self,
 		expression: &str,
 		args: &[StellarMatchParamEntry],
+	) -> Result<bool, EvaluationError> {
+		self.evaluate_expression_with_rpc_results(expression, args, &BTreeMap::new())
+	}
+
+	/// Evaluates an expression of a monitor's conditions, binding its `${rpc.<name>}` variables
+	/// to the results of the monitor's pre-filter RPC calls
+	pub fn evaluate_monitor_expression(
+		&self,
+		monitor: &Monitor,
+		expression: &str,
+		args: &[StellarMatchParamEntry],
+	) -> Result<bool, EvaluationError> {
+		match self.rpc_results.get(&monitor.name) {
+			Some(rpc_results) => {
+				self.evaluate_expression_with_rpc_results(expression, args, rpc_results)
+			}
+			None => self.evaluate_expression(expression, args),
+		}
+	}
+
+	/// Evaluates an expression, binding its `${rpc.<name>}` variables to the given results of
+	/// pre-filter RPC calls
+	pub fn evaluate_expression_with_rpc_results(
+		&self,
+		expression: &str,
+		args: &[StellarMatchParamEntry],
+		rpc_results: &BTreeMap<String, Value>,
 	) -> Result<bool, EvaluationError> {
 		// Check if the expression is empty
 		if expression.trim().is_empty() {
@@ -815,13 +1328,21 @@ impl<T> StellarBlockFilter<T> {
 		tracing::debug!("Parsed AST for '{}': {:?}", expression, parsed_ast);
 
 		// Evaluate the expression
-		expression::evaluate(&parsed_ast, &evaluator)
+		expression::evaluate(&parsed_ast, &evaluator, rpc_results)
 	}
 }
 
 #[async_trait]
 impl<T: BlockChainClient + StellarClientTrait> BlockFilter for StellarBlockFilter<T> {
 	type Client = T;
+
+	fn with_rpc_results(self, rpc_results: RpcResults) -> Self {
+		Self {
+			rpc_results,
+			..self
+		}
+	}
+
 	/// Filters a Stellar block against provided monitors
 	///
 	/// # Arguments
@@ -855,28 +1376,32 @@ impl<T: BlockChainClient + StellarClientTrait> BlockFilter for StellarBlockFilte
 
 		tracing::debug!("Processing block {}", stellar_block.number().unwrap_or(0));
 
-		let transactions = match client.get_transactions(stellar_block.sequence, None).await {
-			Ok(transactions) => transactions,
-			Err(e) => {
-				return Err(FilterError::network_error(
-					format!(
-						"Failed to get transactions for block {}",
-						stellar_block.sequence
-					),
-					Some(e.into()),
-					None,
-				));
-			}
+		let page_size = network.page_size;
+		let transactions_error = |e: anyhow::Error| {
+			FilterError::network_error(
+				format!(
+					"Failed to get transactions for block {}",
+					stellar_block.sequence
+				),
+				Some(e.into()),
+				None,
+			)
 		};
 
-		if transactions.is_empty() {
+		let mut page = client
+			.get_transactions_page(stellar_block.sequence, None, None, page_size)
+			.await
+			.map_err(transactions_error)?;
+
+		if page.items.is_empty() {
 			tracing::debug!("No transactions found for block {}", stellar_block.sequence);
-			return Ok(vec![]);
+			return Ok(self.find_block_matches(network, stellar_block, 0, monitors));
 		}
 
-		tracing::debug!("Processing {} transaction(s)", transactions.len());
-
-		let events = match client.get_events(stellar_block.sequence, None).await {
+		let events = match client
+			.get_events(stellar_block.sequence, None, page_size)
+			.await
+		{
 			Ok(events) => events,
 			Err(e) => {
 				return Err(FilterError::network_error(
@@ -890,8 +1415,6 @@ impl<T: BlockChainClient + StellarClientTrait> BlockFilter for StellarBlockFilte
 		tracing::debug!("Processing {} event(s)", events.len());
 		tracing::debug!("Processing {} monitor(s)", monitors.len());
 
-		let mut matching_results = Vec::new();
-
 		// Cast contract specs to StellarContractSpec
 		let contract_specs = contract_specs
 			.unwrap_or(&[])
@@ -905,120 +1428,246 @@ impl<T: BlockChainClient + StellarClientTrait> BlockFilter for StellarBlockFilte
 			})
 			.collect::<Vec<(String, StellarFormattedContractSpec)>>();
 
-		// Process each monitor first
-		for monitor in monitors {
-			tracing::debug!("Processing monitor: {}", monitor.name);
+		// Shared data is borrowed for the monitors' futures
+		let events = &events;
+		let contract_specs = &contract_specs;
 
-			let monitored_addresses = monitor
+		let has_address_patterns = monitors.iter().any(|monitor| {
+			monitor
 				.addresses
 				.iter()
-				.map(|addr| normalize_address(&addr.address))
-				.collect::<Vec<String>>();
-
-			let decoded_events = self.decode_events(&events, &monitored_addresses, &contract_specs);
-
-			// Then process transactions for this monitor
-			for transaction in &transactions {
-				let mut matched_transactions = Vec::<TransactionCondition>::new();
-				let mut matched_functions = Vec::<FunctionCondition>::new();
-				let mut matched_events = Vec::<EventCondition>::new();
-				let mut matched_on_args = StellarMatchArguments {
-					events: Some(Vec::new()),
-					functions: Some(Vec::new()),
-				};
+				.any(|address| !address.match_mode.is_exact())
+		});
+
+		// Transactions are matched one page at a time so that a busy ledger is never held in
+		// memory at once, while the events of the whole ledger are kept to be matched with them
+		let mut matches = Vec::new();
+		let mut tx_count = 0;
+		loop {
+			tracing::debug!("Processing {} transaction(s)", page.items.len());
+			let transactions = &page.items;
+			tx_count += transactions.len();
+
+			// Address patterns are resolved against the contracts seen in this page
+			let block_addresses = if has_address_patterns {
+				self.contract_addresses(transactions, events)
+			} else {
+				Vec::new()
+			};
+			let block_addresses = &block_addresses;
+
+			// Process each monitor first, evaluating up to the network's monitor concurrency at
+			// once
+			let page_matches = filter_monitors(
+				monitors,
+				network.get_monitor_concurrency(),
+				|monitor| async move {
+					let mut monitor_matches = Vec::new();
+
+					// Block conditions are evaluated once the transactions of the whole ledger
+					// are counted
+					if monitor.match_conditions.is_block_only() {
+						return Ok(monitor_matches);
+					}
 
-				tracing::debug!("Processing transaction: {:?}", transaction.hash());
+					tracing::debug!("Processing monitor: {}", monitor.name);
 
-				self.find_matching_transaction(transaction, monitor, &mut matched_transactions);
+					let mut monitored_addresses = monitor
+						.addresses
+						.iter()
+						.filter(|addr| addr.match_mode.is_exact())
+						.map(|addr| normalize_address(&addr.address))
+						.collect::<Vec<String>>();
 
-				// Decoded events already account for monitored addresses, so no need to pass in
-				// monitored_addresses
-				self.find_matching_events_for_transaction(
-					&decoded_events,
-					transaction,
-					monitor,
-					&mut matched_events,
-					&mut matched_on_args,
-				);
+					let resolved =
+						self.resolve_address_patterns(monitor, block_addresses, contract_specs);
+					let resolved_specs: Vec<(String, StellarFormattedContractSpec)> = resolved
+						.iter()
+						.filter_map(|(addr, spec)| spec.clone().map(|spec| (addr.clone(), spec)))
+						.collect();
+					monitored_addresses.extend(resolved.into_iter().map(|(addr, _)| addr));
+
+					// Resolved addresses are looked up by their own address, so their specs are
+					// added to the ones of the exact entries
+					let monitor_specs;
+					let contract_specs = if resolved_specs.is_empty() {
+						contract_specs
+					} else {
+						monitor_specs = contract_specs
+							.iter()
+							.cloned()
+							.chain(resolved_specs)
+							.collect::<Vec<_>>();
+						&monitor_specs
+					};
+
+					let decoded_events =
+						self.decode_events(events, &monitored_addresses, contract_specs);
+
+					// Transactions not matching the monitor conditions are matched again with the
+					// warn threshold expressions, as near misses
+					let warn_monitor = monitor.warn_threshold_monitor();
+					let passes = std::iter::once((MatchSeverity::Alert, monitor))
+						.chain(
+							warn_monitor
+								.iter()
+								.map(|warn| (MatchSeverity::Warning, warn)),
+						)
+						.collect::<Vec<_>>();
+
+					// Then process transactions for this monitor
+					for transaction in transactions {
+						tracing::debug!("Processing transaction: {:?}", transaction.hash());
+
+						for &(severity, conditions_monitor) in &passes {
+							let mut matched_transactions = Vec::<TransactionCondition>::new();
+							let mut matched_functions = Vec::<FunctionCondition>::new();
+							let mut matched_events = Vec::<EventCondition>::new();
+							let mut matched_on_args = StellarMatchArguments {
+								events: Some(Vec::new()),
+								functions: Some(Vec::new()),
+								block: None,
+								balance: None,
+							};
 
-				self.find_matching_functions_for_transaction(
-					&monitored_addresses,
-					&contract_specs,
-					transaction,
-					monitor,
-					&mut matched_functions,
-					&mut matched_on_args,
-				);
+							self.find_matching_transaction(
+								transaction,
+								conditions_monitor,
+								&mut matched_transactions,
+							);
+
+							// Decoded events already account for monitored addresses, so no need
+							// to pass in monitored_addresses
+							self.find_matching_events_for_transaction(
+								&decoded_events,
+								transaction,
+								conditions_monitor,
+								&mut matched_events,
+								&mut matched_on_args,
+							);
+
+							self.find_matching_functions_for_transaction(
+								&monitored_addresses,
+								contract_specs,
+								transaction,
+								conditions_monitor,
+								&mut matched_functions,
+								&mut matched_on_args,
+							);
+
+							let monitor_conditions = &monitor.match_conditions;
+							let has_event_match =
+								!monitor_conditions.events.is_empty() && !matched_events.is_empty();
+							let has_function_match = !monitor_conditions.functions.is_empty()
+								&& !matched_functions.is_empty();
+							let has_transaction_match = !monitor_conditions.transactions.is_empty()
+								&& !matched_transactions.is_empty();
+
+							let should_match = match (
+								monitor_conditions.events.is_empty(),
+								monitor_conditions.functions.is_empty(),
+								monitor_conditions.transactions.is_empty(),
+							) {
+								// Case 1: No conditions defined, match everything
+								(true, true, true) => true,
+
+								// Case 2: Only transaction conditions defined
+								(true, true, false) => has_transaction_match,
+
+								// Case 3: No transaction conditions, match based on
+								// events/functions
+								(_, _, true) => has_event_match || has_function_match,
+
+								// Case 4: Transaction conditions exist, they must be satisfied
+								// along with events/functions
+								_ => {
+									(has_event_match || has_function_match) && has_transaction_match
+								}
+							};
 
-				let monitor_conditions = &monitor.match_conditions;
-				let has_event_match =
-					!monitor_conditions.events.is_empty() && !matched_events.is_empty();
-				let has_function_match =
-					!monitor_conditions.functions.is_empty() && !matched_functions.is_empty();
-				let has_transaction_match =
-					!monitor_conditions.transactions.is_empty() && !matched_transactions.is_empty();
-
-				let should_match = match (
-					monitor_conditions.events.is_empty(),
-					monitor_conditions.functions.is_empty(),
-					monitor_conditions.transactions.is_empty(),
-				) {
-					// Case 1: No conditions defined, match everything
-					(true, true, true) => true,
-
-					// Case 2: Only transaction conditions defined
-					(true, true, false) => has_transaction_match,
-
-					// Case 3: No transaction conditions, match based on events/functions
-					(_, _, true) => has_event_match || has_function_match,
-
-					// Case 4: Transaction conditions exist, they must be satisfied along with
-					// events/functions
-					_ => (has_event_match || has_function_match) && has_transaction_match,
-				};
+							// State conditions are checked last, as they call the first
+							// monitored contract
+							if should_match && !monitor_conditions.state_conditions.is_empty() {
+								let contract = monitored_addresses
+									.iter()
+									.find(|address| {
+										Contract::from_string(&address.to_uppercase()).is_ok()
+									})
+									.cloned()
+									.unwrap_or_default();
+								if !self
+									.check_state_conditions(client, monitor, &contract)
+									.await?
+								{
+									break;
+								}
+							}
 
-				if should_match {
-					matching_results.push(MonitorMatch::Stellar(Box::new(StellarMonitorMatch {
-						monitor: monitor.clone(),
-						// The conversion to StellarTransaction triggers decoding of the transaction
-						#[allow(clippy::useless_conversion)]
-						transaction: StellarTransaction::from(transaction.clone()),
-						ledger: *stellar_block.clone(),
-						network_slug: network.slug.clone(),
-						matched_on: MatchConditions {
-							events: matched_events
-								.clone()
-								.into_iter()
-								.filter(|_| has_event_match)
-								.collect(),
-							functions: matched_functions
-								.clone()
-								.into_iter()
-								.filter(|_| has_function_match)
-								.collect(),
-							transactions: matched_transactions
-								.clone()
-								.into_iter()
-								.filter(|_| has_transaction_match)
-								.collect(),
-						},
-						matched_on_args: Some(StellarMatchArguments {
-							events: if has_event_match {
-								matched_on_args.events.clone()
-							} else {
-								None
-							},
-							functions: if has_function_match {
-								matched_on_args.functions.clone()
-							} else {
-								None
-							},
-						}),
-					})));
-				}
-			}
+							if should_match {
+								monitor_matches.push(MonitorMatch::Stellar(Box::new(
+									StellarMonitorMatch {
+										monitor: monitor.clone(),
+										// The conversion to StellarTransaction triggers decoding of
+										// the transaction
+										#[allow(clippy::useless_conversion)]
+										transaction: StellarTransaction::from(transaction.clone()),
+										ledger: *stellar_block.clone(),
+										network_slug: network.slug.clone(),
+										matched_on: MatchConditions {
+											events: matched_events
+												.into_iter()
+												.filter(|_| has_event_match)
+												.collect(),
+											functions: matched_functions
+												.into_iter()
+												.filter(|_| has_function_match)
+												.collect(),
+											transactions: matched_transactions
+												.into_iter()
+												.filter(|_| has_transaction_match)
+												.collect(),
+											token_transfers: vec![],
+											block_conditions: vec![],
+											balance_conditions: vec![],
+											state_conditions: monitor_conditions
+												.state_conditions
+												.clone(),
+										},
+										matched_on_args: Some(StellarMatchArguments {
+											events: matched_on_args
+												.events
+												.filter(|_| has_event_match),
+											functions: matched_on_args
+												.functions
+												.filter(|_| has_function_match),
+											block: None,
+											balance: None,
+										}),
+										severity,
+									},
+								)));
+								break;
+							}
+						}
+					}
+
+					Ok(monitor_matches)
+				},
+			)
+			.await?;
+			matches.extend(page_matches);
+
+			let Some(cursor) = page.cursor.take() else {
+				break;
+			};
+			page = client
+				.get_transactions_page(stellar_block.sequence, None, Some(cursor), page_size)
+				.await
+				.map_err(transactions_error)?;
 		}
-		Ok(matching_results)
+
+		matches.extend(self.find_block_matches(network, stellar_block, tx_count, monitors));
+		Ok(matches)
 	}
 }
 
@@ -1027,26 +1676,34 @@ mod tests {
 	use super::*;
 	use crate::{
 		models::{
-			AddressWithSpec, MatchConditions, Monitor, StellarContractInput,
-			StellarDecodedTransaction, StellarFormattedContractSpec, StellarTransaction,
+			AddressMatchMode, AddressWithSpec, MatchConditions, Monitor, StellarContractEvent,
+			StellarContractEventParam, StellarContractInput, StellarDecodedTransaction,
+			StellarFormattedContractSpec, StellarLedgerInfo, StellarTransaction,
 			StellarTransactionInfo, TransactionStatus,
 		},
-		utils::tests::stellar::monitor::MonitorBuilder,
+		utils::tests::{network::NetworkBuilder, stellar::monitor::MonitorBuilder},
 	};
 	use serde_json::json;
 	use stellar_strkey::ed25519::PublicKey as StrPublicKey;
 
 	use base64::engine::general_purpose::STANDARD as BASE64;
+	use std::str::FromStr;
 	use stellar_xdr::curr::{
-		Asset, ContractId, FeeBumpTransaction, FeeBumpTransactionEnvelope, FeeBumpTransactionExt,
-		Hash, HostFunction, InvokeContractArgs, InvokeHostFunctionOp, MuxedAccount, Operation,
-		OperationBody, PaymentOp, ScAddress, ScString, ScSymbol, ScVal, SequenceNumber, StringM,
-		Transaction, TransactionEnvelope, TransactionV1Envelope, Uint256, VecM,
+		AccountId, AlphaNum4, Asset, AssetCode4, ChangeTrustAsset, ChangeTrustOp, ContractId,
+		CreateAccountOp, FeeBumpTransaction, FeeBumpTransactionEnvelope, FeeBumpTransactionExt,
+		Hash, HostFunction, InvokeContractArgs, InvokeHostFunctionOp, ManageSellOfferOp,
+		MuxedAccount, Operation, OperationBody, PathPaymentStrictSendOp, PaymentOp, Price,
+		ScAddress, ScString, ScSymbol, ScVal, SequenceNumber, StringM, Transaction,
+		TransactionEnvelope, TransactionV1Envelope, Uint256, VecM,
 	};
 
+	const ISSUER: &str = "GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI";
+	const DESTINATION: &str = "GCXKG6RN4ONIEPCMNFB732A436Z5PNDSRLGWK7GBLCMQLIFO4S7EYWVU";
+
 	fn create_test_filter() -> StellarBlockFilter<()> {
 		StellarBlockFilter::<()> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		}
 	}
 
@@ -1071,6 +1728,10 @@ mod tests {
 				events: event_conditions,
 				functions: function_conditions,
 				transactions: transaction_conditions,
+				token_transfers: vec![],
+				block_conditions: vec![],
+				balance_conditions: vec![],
+				state_conditions: vec![],
 			})
 			.build()
 	}
@@ -1221,7 +1882,7 @@ mod tests {
 			status: status.to_string(),
 			transaction_hash: transaction_hash.to_string(),
 			application_order,
-			fee_bump: false,
+			fee_bump: is_fee_bump,
 			envelope_xdr: Some(base64::engine::general_purpose::STANDARD.encode("mock_xdr")),
 			envelope_json: Some(envelope_json),
 			result_xdr: Some(base64::engine::general_purpose::STANDARD.encode("mock_result")),
@@ -1289,6 +1950,67 @@ mod tests {
 		BASE64.encode(buffer)
 	}
 
+	//////////////////////////////////////////////////////////////////////////////
+	// Test cases for find_matching_block_conditions method:
+	//////////////////////////////////////////////////////////////////////////////
+	#[test]
+	fn test_find_matching_block_conditions() {
+		let filter = create_test_filter();
+		let ledger = StellarBlock::from(StellarLedgerInfo {
+			hash: "ledger_hash".to_string(),
+			sequence: 100,
+			ledger_close_time: "1733036783".to_string(),
+			..Default::default()
+		});
+		let monitor = MonitorBuilder::new()
+			.block_condition("timestamp >= 1733036783")
+			.block_condition("tx_count > 10")
+			.block_condition("number == 100 AND tx_count == 5")
+			.build();
+
+		let block_params = filter.block_params(&ledger, 5);
+		let mut matched_blocks = Vec::new();
+		filter.find_matching_block_conditions(&block_params, &monitor, &mut matched_blocks);
+
+		assert_eq!(
+			matched_blocks
+				.iter()
+				.map(|condition| condition.expression.as_str())
+				.collect::<Vec<_>>(),
+			vec!["timestamp >= 1733036783", "number == 100 AND tx_count == 5"]
+		);
+	}
+
+	#[test]
+	fn test_find_block_matches_only_matches_monitors_with_block_conditions() {
+		let filter = create_test_filter();
+		let network = NetworkBuilder::new().slug("stellar_mainnet").build();
+		let ledger = StellarBlock::from(StellarLedgerInfo {
+			sequence: 100,
+			ledger_close_time: "1733036783".to_string(),
+			..Default::default()
+		});
+		let monitors = vec![
+			MonitorBuilder::new()
+				.name("block")
+				.block_condition("timestamp > 1733000000")
+				.build(),
+			create_test_monitor(vec![], vec![], vec![], vec![]),
+		];
+
+		let matches = filter.find_block_matches(&network, &ledger, 0, &monitors);
+
+		assert_eq!(matches.len(), 1);
+		match &matches[0] {
+			MonitorMatch::Stellar(stellar_match) => {
+				assert_eq!(stellar_match.monitor.name, "block");
+				assert_eq!(stellar_match.matched_on.block_conditions.len(), 1);
+				assert!(stellar_match.transaction.hash().is_empty());
+			}
+			_ => panic!("Expected Stellar match"),
+		}
+	}
+
 	//////////////////////////////////////////////////////////////////////////////
 	// Test cases for find_matching_transaction method:
 	//////////////////////////////////////////////////////////////////////////////
@@ -1337,6 +2059,8 @@ mod tests {
 			vec![TransactionCondition {
 				status: TransactionStatus::Success,
 				expression: None,
+				warn_threshold_expression: None,
+				is_contract_creation: false,
 			}],
 			vec![],
 		);
@@ -1369,6 +2093,8 @@ mod tests {
 			vec![TransactionCondition {
 				status: TransactionStatus::Success,
 				expression: Some("value > 100".to_string()),
+				warn_threshold_expression: None,
+				is_contract_creation: false,
 			}],
 			vec![],
 		);
@@ -1404,6 +2130,8 @@ mod tests {
 			vec![TransactionCondition {
 				status: TransactionStatus::Success,
 				expression: Some("value > 1000000".to_string()),
+				warn_threshold_expression: None,
+				is_contract_creation: false,
 			}],
 			vec![],
 		);
@@ -1434,6 +2162,8 @@ mod tests {
 			vec![TransactionCondition {
 				status: TransactionStatus::Success,
 				expression: None,
+				warn_threshold_expression: None,
+				is_contract_creation: false,
 			}],
 			vec![],
 		);
@@ -1468,6 +2198,8 @@ mod tests {
 					 GCXKG6RN4ONIEPCMNFB732A436Z5PNDSRLGWK7GBLCMQLIFO4S7EYWVU"
 						.to_string(),
 				),
+				warn_threshold_expression: None,
+				is_contract_creation: false,
 			}],
 			vec![],
 		);
@@ -1479,6 +2211,267 @@ mod tests {
 		assert!(matched_transactions[0].expression.is_some());
 	}
 
+	#[test]
+	fn test_find_matching_transaction_fee_bump() {
+		let filter = create_test_filter();
+		let fee_source = StrPublicKey([7; 32]).to_string();
+		let create_transaction = |is_fee_bump| {
+			let mut transaction = create_test_transaction(
+				"SUCCESS",
+				"3389e9f0f1a65f19736cacf544c2e825313e8447f569233bb8db39aa607c8889",
+				1,
+				Some("150"),
+				Some("GCXKG6RN4ONIEPCMNFB732A436Z5PNDSRLGWK7GBLCMQLIFO4S7EYWVU"),
+				Some("GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI"),
+				None,
+				is_fee_bump,
+			);
+			if let Some(TransactionEnvelope::TxFeeBump(fee_bump)) = transaction
+				.0
+				.decoded
+				.as_mut()
+				.and_then(|decoded| decoded.envelope.as_mut())
+			{
+				fee_bump.tx.fee_source = MuxedAccount::Ed25519(Uint256([7; 32]));
+				fee_bump.tx.fee = 5000;
+			}
+			transaction
+		};
+		let matches = |transaction: &StellarTransaction, expression: String| {
+			let monitor = create_test_monitor(
+				vec![],
+				vec![],
+				vec![TransactionCondition {
+					status: TransactionStatus::Success,
+					expression: Some(expression),
+					warn_threshold_expression: None,
+					is_contract_creation: false,
+				}],
+				vec![],
+			);
+			let mut matched_transactions = Vec::new();
+			filter.find_matching_transaction(transaction, &monitor, &mut matched_transactions);
+			!matched_transactions.is_empty()
+		};
+
+		let fee_bump_transaction = create_transaction(true);
+
+		// The payment of the inner transaction is matched
+		assert!(matches(
+			&fee_bump_transaction,
+			"value == 150 AND from == GCXKG6RN4ONIEPCMNFB732A436Z5PNDSRLGWK7GBLCMQLIFO4S7EYWVU \
+			 AND to == GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI"
+				.to_string()
+		));
+
+		// The fee-bump source and fee are exposed
+		assert!(matches(
+			&fee_bump_transaction,
+			format!(
+				"fee_bump == true AND fee_source == {} AND fee == 5000",
+				fee_source
+			)
+		));
+		assert!(!matches(
+			&fee_bump_transaction,
+			"fee_source == GCXKG6RN4ONIEPCMNFB732A436Z5PNDSRLGWK7GBLCMQLIFO4S7EYWVU".to_string()
+		));
+
+		// Regular transactions pay their own fee
+		let transaction = create_transaction(false);
+		assert!(matches(
+			&transaction,
+			"fee_bump == false AND fee == 100 AND \
+			 fee_source == GCXKG6RN4ONIEPCMNFB732A436Z5PNDSRLGWK7GBLCMQLIFO4S7EYWVU"
+				.to_string()
+		));
+	}
+
+	/// Creates a successful transaction with a single operation
+	fn create_operation_transaction(body: OperationBody) -> StellarTransaction {
+		let mut transaction = create_test_transaction(
+			"SUCCESS",
+			"3389e9f0f1a65f19736cacf544c2e825313e8447f569233bb8db39aa607c8889",
+			1,
+			None,
+			None,
+			None,
+			None,
+			false,
+		);
+		if let Some(TransactionEnvelope::Tx(tx)) = transaction
+			.0
+			.decoded
+			.as_mut()
+			.and_then(|decoded| decoded.envelope.as_mut())
+		{
+			tx.tx.operations = vec![Operation {
+				source_account: None,
+				body,
+			}]
+			.try_into()
+			.unwrap();
+		}
+		transaction
+	}
+
+	/// Checks whether a transaction matches a transaction condition expression
+	fn transaction_matches(transaction: &StellarTransaction, expression: &str) -> bool {
+		let monitor = create_test_monitor(
+			vec![],
+			vec![],
+			vec![TransactionCondition {
+				status: TransactionStatus::Success,
+				expression: Some(expression.to_string()),
+				warn_threshold_expression: None,
+				is_contract_creation: false,
+			}],
+			vec![],
+		);
+		let mut matched_transactions = Vec::new();
+		create_test_filter().find_matching_transaction(
+			transaction,
+			&monitor,
+			&mut matched_transactions,
+		);
+		!matched_transactions.is_empty()
+	}
+
+	fn create_usdc() -> AlphaNum4 {
+		AlphaNum4 {
+			asset_code: AssetCode4(*b"USDC"),
+			issuer: AccountId::from_str(ISSUER).unwrap(),
+		}
+	}
+
+	fn create_destination() -> MuxedAccount {
+		MuxedAccount::Ed25519(Uint256(StrPublicKey::from_string(DESTINATION).unwrap().0))
+	}
+
+	#[test]
+	fn test_find_matching_transaction_payment_asset() {
+		let transaction = create_operation_transaction(OperationBody::Payment(PaymentOp {
+			destination: create_destination(),
+			asset: Asset::CreditAlphanum4(create_usdc()),
+			amount: 500,
+		}));
+
+		assert!(transaction_matches(
+			&transaction,
+			&format!(
+				"operation_type == 'payment' AND asset == 'USDC:{}' AND value == 500",
+				ISSUER
+			)
+		));
+		assert!(!transaction_matches(&transaction, "asset == 'native'"));
+	}
+
+	#[test]
+	fn test_find_matching_transaction_path_payment_strict_send() {
+		let transaction = create_operation_transaction(OperationBody::PathPaymentStrictSend(
+			PathPaymentStrictSendOp {
+				send_asset: Asset::Native,
+				send_amount: 1_000,
+				destination: create_destination(),
+				dest_asset: Asset::CreditAlphanum4(create_usdc()),
+				dest_min: 950,
+				path: VecM::default(),
+			},
+		));
+
+		assert!(transaction_matches(
+			&transaction,
+			"operation_type == 'path_payment_strict_send'"
+		));
+		assert!(transaction_matches(
+			&transaction,
+			&format!(
+				"send_asset == 'native' AND send_amount == 1000 AND dest_asset == 'USDC:{}' \
+				 AND dest_min >= 950",
+				ISSUER
+			)
+		));
+		assert!(transaction_matches(
+			&transaction,
+			&format!("value == 1000 AND to == {}", DESTINATION)
+		));
+		assert!(!transaction_matches(&transaction, "dest_min > 1000"));
+	}
+
+	#[test]
+	fn test_find_matching_transaction_create_account() {
+		let transaction =
+			create_operation_transaction(OperationBody::CreateAccount(CreateAccountOp {
+				destination: AccountId::from_str(DESTINATION).unwrap(),
+				starting_balance: 20_000_000,
+			}));
+
+		assert!(transaction_matches(
+			&transaction,
+			&format!(
+				"operation_type == 'create_account' AND to == {} AND starting_balance == 20000000",
+				DESTINATION
+			)
+		));
+		assert!(transaction_matches(&transaction, "value > 10000000"));
+		assert!(!transaction_matches(
+			&transaction,
+			"starting_balance < 10000000"
+		));
+	}
+
+	#[test]
+	fn test_find_matching_transaction_manage_sell_offer() {
+		let transaction =
+			create_operation_transaction(OperationBody::ManageSellOffer(ManageSellOfferOp {
+				selling: Asset::CreditAlphanum4(create_usdc()),
+				buying: Asset::Native,
+				amount: 3_000,
+				price: Price { n: 5, d: 2 },
+				offer_id: 0,
+			}));
+
+		assert!(transaction_matches(
+			&transaction,
+			&format!(
+				"operation_type == 'manage_sell_offer' AND selling == 'USDC:{}' \
+				 AND buying == 'native'",
+				ISSUER
+			)
+		));
+		assert!(transaction_matches(
+			&transaction,
+			"amount == 3000 AND value == 3000 AND price_n == 5 AND price_d == 2 AND offer_id == 0"
+		));
+		// Offers have no destination
+		assert!(!transaction_matches(
+			&transaction,
+			&format!("to == {}", DESTINATION)
+		));
+	}
+
+	#[test]
+	fn test_find_matching_transaction_change_trust() {
+		let transaction = create_operation_transaction(OperationBody::ChangeTrust(ChangeTrustOp {
+			line: ChangeTrustAsset::CreditAlphanum4(create_usdc()),
+			limit: 0,
+		}));
+
+		// Removing a trustline sets its limit to 0
+		assert!(transaction_matches(
+			&transaction,
+			&format!(
+				"operation_type == 'change_trust' AND asset == 'USDC:{}' AND limit == 0",
+				ISSUER
+			)
+		));
+		assert!(transaction_matches(
+			&transaction,
+			"asset starts_with 'usdc:'"
+		));
+		assert!(!transaction_matches(&transaction, "limit > 0"));
+	}
+
 	//////////////////////////////////////////////////////////////////////////////
 	// Test cases for find_matching_functions_for_transaction method:
 	//////////////////////////////////////////////////////////////////////////////
@@ -1490,6 +2483,8 @@ mod tests {
 		let mut matched_args = StellarMatchArguments {
 			events: Some(Vec::new()),
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		// Use the Stellar format address
@@ -1516,6 +2511,7 @@ mod tests {
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				match_mode: AddressMatchMode::Exact,
 			}],
 		);
 
@@ -1567,6 +2563,8 @@ mod tests {
 		let mut matched_args = StellarMatchArguments {
 			events: Some(Vec::new()),
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		let contract_address = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
@@ -1591,11 +2589,14 @@ mod tests {
 			vec![FunctionCondition {
 				signature: "mock_function(I32,String)".to_string(),
 				expression: None,
+				warn_threshold_expression: None,
+				eip712_schema: None,
 			}],
 			vec![],
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				match_mode: AddressMatchMode::Exact,
 			}],
 		);
 
@@ -1644,6 +2645,8 @@ mod tests {
 		let mut matched_args = StellarMatchArguments {
 			events: Some(Vec::new()),
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		let contract_address = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
@@ -1666,11 +2669,14 @@ mod tests {
 			vec![FunctionCondition {
 				signature: "mock_function(I32,String)".to_string(),
 				expression: Some("0 < 50".to_string()),
+				warn_threshold_expression: None,
+				eip712_schema: None,
 			}],
 			vec![],
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				match_mode: AddressMatchMode::Exact,
 			}],
 		);
 
@@ -1718,6 +2724,8 @@ mod tests {
 		let mut matched_args = StellarMatchArguments {
 			events: Some(Vec::new()),
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		let contract_address = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
@@ -1741,11 +2749,14 @@ mod tests {
 			vec![FunctionCondition {
 				signature: "mock_function(i32,string)".to_string(),
 				expression: None,
+				warn_threshold_expression: None,
+				eip712_schema: None,
 			}],
 			vec![],
 			vec![AddressWithSpec {
 				address: normalized_different_address.clone(),
 				contract_spec: None,
+				match_mode: AddressMatchMode::Exact,
 			}],
 		);
 
@@ -1792,6 +2803,8 @@ mod tests {
 		let mut matched_args = StellarMatchArguments {
 			events: Some(Vec::new()),
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		let contract_address = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
@@ -1815,16 +2828,21 @@ mod tests {
 				FunctionCondition {
 					signature: "wrong_function()".to_string(),
 					expression: None,
+					warn_threshold_expression: None,
+					eip712_schema: None,
 				},
 				FunctionCondition {
 					signature: "mock_function(i32,string)".to_string(),
 					expression: None,
+					warn_threshold_expression: None,
+					eip712_schema: None,
 				},
 			],
 			vec![],
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				match_mode: AddressMatchMode::Exact,
 			}],
 		);
 
@@ -1872,6 +2890,8 @@ mod tests {
 		let mut matched_args = StellarMatchArguments {
 			events: Some(Vec::new()),
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		let contract_address = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
@@ -1896,11 +2916,14 @@ mod tests {
 			vec![FunctionCondition {
 				signature: "mock_function(I32,String)".to_string(),
 				expression: None,
+				warn_threshold_expression: None,
+				eip712_schema: None,
 			}],
 			vec![],
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				match_mode: AddressMatchMode::Exact,
 			}],
 		);
 
@@ -1953,6 +2976,8 @@ mod tests {
 		let mut matched_args = StellarMatchArguments {
 			events: Some(Vec::new()),
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		// Create test transaction and event
@@ -2002,6 +3027,8 @@ mod tests {
 		let mut matched_args = StellarMatchArguments {
 			events: Some(Vec::new()),
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		let transaction =
@@ -2023,6 +3050,7 @@ mod tests {
 			vec![EventCondition {
 				signature: "Transfer(address,uint256)".to_string(),
 				expression: None,
+				warn_threshold_expression: None,
 			}],
 			vec![],
 			vec![],
@@ -2049,6 +3077,8 @@ mod tests {
 		let mut matched_args = StellarMatchArguments {
 			events: Some(Vec::new()),
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		let transaction =
@@ -2070,6 +3100,7 @@ mod tests {
 			vec![EventCondition {
 				signature: "Transfer(address,uint256)".to_string(),
 				expression: Some("0 > 50".to_string()),
+				warn_threshold_expression: None,
 			}],
 			vec![],
 			vec![],
@@ -2096,6 +3127,8 @@ mod tests {
 		let mut matched_args = StellarMatchArguments {
 			events: Some(Vec::new()),
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		let transaction =
@@ -2115,7 +3148,8 @@ mod tests {
 		let monitor = create_test_monitor(
 			vec![EventCondition {
 				signature: "Transfer(address,uint256)".to_string(),
-				expression: Some("0 > 100".to_string()), // This won't match
+				expression: Some("0 > 100".to_string()), // This won't match,
+				warn_threshold_expression: None,
 			}],
 			vec![],
 			vec![],
@@ -2141,6 +3175,8 @@ mod tests {
 		let mut matched_args = StellarMatchArguments {
 			events: Some(Vec::new()),
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		let transaction =
@@ -2162,6 +3198,7 @@ mod tests {
 			vec![EventCondition {
 				signature: "Transfer(address,uint256)".to_string(),
 				expression: None,
+				warn_threshold_expression: None,
 			}],
 			vec![],
 			vec![],
@@ -2297,6 +3334,87 @@ mod tests {
 		assert!(!args[0].indexed);
 	}
 
+	/// Creates a `transfer` event with `from` and `to` as u64 topics and `amount` as i64 value
+	fn create_test_transfer_event(contract_address: &str) -> StellarEvent {
+		let encode_u64 = |value: u64| {
+			let mut bytes = vec![0, 0, 0, 5]; // discriminant for ScVal::U64
+			bytes.extend_from_slice(&value.to_be_bytes());
+			BASE64.encode(&bytes)
+		};
+		let mut value_bytes = vec![0, 0, 0, 6]; // discriminant for ScVal::I64
+		value_bytes.extend_from_slice(&42i64.to_be_bytes());
+
+		create_test_stellar_event(
+			contract_address,
+			"tx_hash_123",
+			vec![encode_event_name("transfer"), encode_u64(1), encode_u64(2)],
+			Some(BASE64.encode(&value_bytes)),
+		)
+	}
+
+	#[tokio::test]
+	async fn test_decode_events_names_params_from_spec() {
+		let filter = create_test_filter();
+		let contract_address = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+		let monitored_addresses = vec![normalize_address(contract_address)];
+
+		// The spec interleaves indexed and data parameters
+		let param = |name: &str, kind: &str, location| StellarContractEventParam {
+			name: name.to_string(),
+			kind: kind.to_string(),
+			location,
+		};
+		let contract_specs = vec![(
+			contract_address.to_string(),
+			StellarFormattedContractSpec {
+				functions: vec![],
+				events: vec![StellarContractEvent {
+					name: "transfer".to_string(),
+					prefix_topics: vec!["transfer".to_string()],
+					params: vec![
+						param("from", "U64", StellarEventParamLocation::Indexed),
+						param("amount", "I64", StellarEventParamLocation::Data),
+						param("to", "U64", StellarEventParamLocation::Indexed),
+					],
+					signature: "transfer(U64,I64,U64)".to_string(),
+				}],
+			},
+		)];
+
+		let events = vec![create_test_transfer_event(contract_address)];
+		let decoded = filter.decode_events(&events, &monitored_addresses, &contract_specs);
+
+		assert_eq!(decoded.len(), 1);
+		let args = decoded[0].event.args.as_ref().unwrap();
+		let names: Vec<_> = args.iter().map(|arg| arg.name.as_str()).collect();
+		assert_eq!(names, vec!["from", "to", "amount"]);
+
+		assert!(filter
+			.evaluate_expression("from == 1 AND to == 2 AND amount == 42", args)
+			.unwrap());
+		assert!(!filter.evaluate_expression("amount > 42", args).unwrap());
+	}
+
+	#[tokio::test]
+	async fn test_decode_events_names_params_by_position_without_spec() {
+		let filter = create_test_filter();
+		let contract_address = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+		let monitored_addresses = vec![normalize_address(contract_address)];
+
+		let events = vec![create_test_transfer_event(contract_address)];
+		let decoded = filter.decode_events(&events, &monitored_addresses, &[]);
+
+		assert_eq!(decoded.len(), 1);
+		let args = decoded[0].event.args.as_ref().unwrap();
+		let names: Vec<_> = args.iter().map(|arg| arg.name.as_str()).collect();
+		assert_eq!(names, vec!["0", "1", "2"]);
+
+		assert!(filter
+			.evaluate_expression("0 == 1 AND 1 == 2 AND 2 == 42", args)
+			.unwrap());
+		assert!(filter.evaluate_expression("amount == 42", args).is_err());
+	}
+
 	//////////////////////////////////////////////////////////////////////////////
 	// Test cases for evaluate_expression method:
 	//////////////////////////////////////////////////////////////////////////////
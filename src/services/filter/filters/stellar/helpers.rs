@@ -15,10 +15,13 @@ use soroban_spec::read;
 use std::collections::BTreeMap;
 use stellar_strkey::{ed25519::PublicKey as StrkeyPublicKey, Contract};
 use stellar_xdr::curr::{
-	AccountId, ContractExecutable, ContractId, Hash, HostFunction, Int128Parts, Int256Parts,
-	InvokeHostFunctionOp, LedgerEntryData, LedgerKey, LedgerKeyContractCode, Limits, PublicKey,
-	ReadXdr, ScAddress, ScMapEntry, ScSpecEntry, ScSpecEventParamLocationV0, ScSpecTypeDef, ScVal,
-	UInt128Parts, UInt256Parts,
+	AccountId, Asset, ChangeTrustAsset, ContractExecutable, ContractId, Hash, HostFunction,
+	Int128Parts, Int256Parts, InvokeContractArgs, InvokeHostFunctionOp, LedgerEntryData, LedgerKey,
+	LedgerKeyAccount, LedgerKeyContractCode, Limits, Memo, MuxedAccount, Operation, OperationBody,
+	Preconditions, PublicKey, ReadXdr, ScAddress, ScMapEntry, ScSpecEntry,
+	ScSpecEventParamLocationV0, ScSpecTypeDef, ScString, ScSymbol, ScVal, SequenceNumber,
+	Transaction, TransactionEnvelope, TransactionExt, TransactionV1Envelope, UInt128Parts,
+	UInt256Parts, Uint256, VecM,
 };
 
 use crate::models::{
@@ -709,6 +712,69 @@ pub fn process_invoke_host_function(
 	}
 }
 
+/// Formats a Stellar account ID as its `G...` address.
+///
+/// # Arguments
+/// * `account_id` - The account ID to format
+///
+/// # Returns
+/// The strkey encoded address of the account
+pub fn format_account_id(account_id: &AccountId) -> String {
+	match account_id {
+		AccountId(PublicKey::PublicKeyTypeEd25519(key)) => StrkeyPublicKey(key.0).to_string(),
+	}
+}
+
+/// Formats the code of an issued asset, without its padding.
+fn format_asset_code(code: &[u8]) -> String {
+	String::from_utf8_lossy(code)
+		.trim_end_matches('\0')
+		.to_string()
+}
+
+/// Formats a Stellar asset in its canonical form.
+///
+/// # Arguments
+/// * `asset` - The asset to format
+///
+/// # Returns
+/// `native` for lumens, or `CODE:ISSUER` for issued assets
+pub fn format_asset(asset: &Asset) -> String {
+	match asset {
+		Asset::Native => "native".to_string(),
+		Asset::CreditAlphanum4(alpha_num) => format!(
+			"{}:{}",
+			format_asset_code(&alpha_num.asset_code.0),
+			format_account_id(&alpha_num.issuer)
+		),
+		Asset::CreditAlphanum12(alpha_num) => format!(
+			"{}:{}",
+			format_asset_code(&alpha_num.asset_code.0),
+			format_account_id(&alpha_num.issuer)
+		),
+	}
+}
+
+/// Formats the asset of a Stellar trustline in its canonical form.
+///
+/// # Arguments
+/// * `asset` - The trustline asset to format
+///
+/// # Returns
+/// The asset formatted as by [`format_asset`], or `pool_share` for liquidity pool shares
+pub fn format_change_trust_asset(asset: &ChangeTrustAsset) -> String {
+	match asset {
+		ChangeTrustAsset::Native => format_asset(&Asset::Native),
+		ChangeTrustAsset::CreditAlphanum4(alpha_num) => {
+			format_asset(&Asset::CreditAlphanum4(alpha_num.clone()))
+		}
+		ChangeTrustAsset::CreditAlphanum12(alpha_num) => {
+			format_asset(&Asset::CreditAlphanum12(alpha_num.clone()))
+		}
+		ChangeTrustAsset::PoolShare(_) => "pool_share".to_string(),
+	}
+}
+
 /// Checks if a string is a valid Stellar address.
 ///
 /// # Arguments
@@ -1003,6 +1069,147 @@ pub fn get_contract_code_ledger_key(wasm_hash: &str) -> Result<LedgerKey, anyhow
 	}))
 }
 
+/// Creates a LedgerKey for an account.
+///
+/// # Arguments
+/// * `account_id` - The account ID in Stellar strkey format (starts with 'G')
+///
+/// # Returns
+/// A Result containing the LedgerKey if successful, or an error if the account ID is invalid
+pub fn get_account_ledger_key(account_id: &str) -> Result<LedgerKey, anyhow::Error> {
+	let key = StrkeyPublicKey::from_string(&account_id.trim().to_uppercase())
+		.map_err(|e| anyhow::anyhow!("Failed to decode account ID: {}", e))?;
+	Ok(LedgerKey::Account(LedgerKeyAccount {
+		account_id: AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(key.0))),
+	}))
+}
+
+/// Get the native balance of an account from its XDR-encoded ledger entry.
+///
+/// # Arguments
+/// * `ledger_entry_data` - The XDR-encoded account entry
+///
+/// # Returns
+/// A Result containing the balance in stroops if successful, or an error if parsing fails
+pub fn get_account_balance_from_ledger_entry_data(
+	ledger_entry_data: &str,
+) -> Result<i64, anyhow::Error> {
+	let decoded = BASE64_STANDARD
+		.decode(ledger_entry_data)
+		.map_err(|e| anyhow::anyhow!("Failed to decode base64: {}", e))?;
+	match LedgerEntryData::from_xdr(&decoded, Limits::none()) {
+		Ok(LedgerEntryData::Account(account)) => Ok(account.balance),
+		Ok(_) => Err(anyhow::anyhow!("XDR value is not an account entry")),
+		Err(e) => Err(anyhow::anyhow!("Failed to parse account XDR: {}", e)),
+	}
+}
+
+/// Parses a Stellar account or contract address into an ScAddress.
+///
+/// # Arguments
+/// * `address` - The account (G...) or contract (C...) address
+///
+/// # Returns
+/// A Result containing the ScAddress if successful, or an error if the address is invalid
+pub fn parse_sc_address(address: &str) -> Result<ScAddress, anyhow::Error> {
+	let address = address.trim().to_uppercase();
+	if let Ok(contract) = Contract::from_string(&address) {
+		return Ok(ScAddress::Contract(ContractId(Hash(contract.0))));
+	}
+	let key = StrkeyPublicKey::from_string(&address)
+		.map_err(|e| anyhow::anyhow!("Invalid address '{}': {}", address, e))?;
+	Ok(ScAddress::Account(AccountId(
+		PublicKey::PublicKeyTypeEd25519(Uint256(key.0)),
+	)))
+}
+
+/// Converts an argument of a contract call into a Stellar Contract Value.
+///
+/// # Arguments
+/// * `kind` - The type of the argument, one of `Address`, `Bool`, `U32`, `I32`, `U64`, `I64`,
+///   `U128`, `I128`, `String` or `Symbol`
+/// * `value` - The value of the argument
+///
+/// # Returns
+/// A Result containing the ScVal if successful, or an error if the type isn't supported or the
+/// value doesn't match it
+pub fn parse_sc_val_argument(kind: &str, value: &str) -> Result<ScVal, anyhow::Error> {
+	let value = value.trim();
+	let sc_val = match kind.trim().to_lowercase().as_str() {
+		"address" => ScVal::Address(parse_sc_address(value)?),
+		"bool" => ScVal::Bool(value.parse()?),
+		"u32" => ScVal::U32(value.parse()?),
+		"i32" => ScVal::I32(value.parse()?),
+		"u64" => ScVal::U64(value.parse()?),
+		"i64" => ScVal::I64(value.parse()?),
+		"u128" => {
+			let n: u128 = value.parse()?;
+			ScVal::U128(UInt128Parts {
+				hi: (n >> 64) as u64,
+				lo: n as u64,
+			})
+		}
+		"i128" => {
+			let n: i128 = value.parse()?;
+			ScVal::I128(Int128Parts {
+				hi: (n >> 64) as i64,
+				lo: n as u64,
+			})
+		}
+		"string" => ScVal::String(ScString(value.try_into()?)),
+		"symbol" => ScVal::Symbol(ScSymbol(value.try_into()?)),
+		_ => return Err(anyhow::anyhow!("Unsupported argument type '{}'", kind)),
+	};
+	Ok(sc_val)
+}
+
+/// Builds a transaction invoking a contract function, to be simulated to call it.
+///
+/// The transaction is sent from the all-zero account, as the simulation of a read-only call
+/// doesn't require an existing source account.
+///
+/// # Arguments
+/// * `contract_id` - The ID of the called contract
+/// * `function_name` - The name of the called function
+/// * `args` - The arguments of the call
+///
+/// # Returns
+/// A Result containing the transaction envelope if successful, or an error if the contract ID
+/// or function name is invalid
+pub fn get_contract_call_transaction(
+	contract_id: &str,
+	function_name: &str,
+	args: Vec<ScVal>,
+) -> Result<TransactionEnvelope, anyhow::Error> {
+	let contract = Contract::from_string(&contract_id.to_uppercase())
+		.map_err(|e| anyhow::anyhow!("Failed to decode contract ID: {}", e))?;
+
+	let operation = Operation {
+		source_account: None,
+		body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+			host_function: HostFunction::InvokeContract(InvokeContractArgs {
+				contract_address: ScAddress::Contract(ContractId(Hash(contract.0))),
+				function_name: ScSymbol(function_name.try_into()?),
+				args: args.try_into()?,
+			}),
+			auth: VecM::default(),
+		}),
+	};
+
+	Ok(TransactionEnvelope::Tx(TransactionV1Envelope {
+		tx: Transaction {
+			source_account: MuxedAccount::Ed25519(Uint256([0; 32])),
+			fee: 100,
+			seq_num: SequenceNumber(0),
+			cond: Preconditions::None,
+			memo: Memo::None,
+			operations: vec![operation].try_into()?,
+			ext: TransactionExt::V0,
+		},
+		signatures: VecM::default(),
+	}))
+}
+
 /// Get WASM code from a contract's XDR-encoded executable.
 ///
 /// # Arguments
@@ -1412,11 +1619,12 @@ mod tests {
 	use serde_json::json;
 	use std::str::FromStr;
 	use stellar_xdr::curr::{
-		AccountId, ContractDataEntry, Hash, Int128Parts, LedgerEntryData, PublicKey,
-		ScContractInstance, ScMap, ScSpecEntry, ScSpecFunctionInputV0, ScSpecFunctionV0,
-		ScSpecTypeDef, ScSpecTypeMap, ScSpecTypeOption, ScSpecTypeTuple, ScSpecTypeUdt,
-		ScSpecTypeVec, ScSpecUdtEnumV0, ScString, ScSymbol, ScVal, SequenceNumber, String32,
-		StringM, Uint256, WriteXdr,
+		AccountId, AlphaNum12, AlphaNum4, AssetCode12, AssetCode4, ContractDataEntry, Hash,
+		Int128Parts, LedgerEntryData, LiquidityPoolConstantProductParameters,
+		LiquidityPoolParameters, PublicKey, ScContractInstance, ScMap, ScSpecEntry,
+		ScSpecFunctionInputV0, ScSpecFunctionV0, ScSpecTypeDef, ScSpecTypeMap, ScSpecTypeOption,
+		ScSpecTypeTuple, ScSpecTypeUdt, ScSpecTypeVec, ScSpecUdtEnumV0, ScString, ScSymbol, ScVal,
+		SequenceNumber, String32, StringM, Uint256, WriteXdr,
 	};
 
 	fn create_test_function_entry(
@@ -1498,6 +1706,51 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_format_asset() {
+		let issuer = "GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI";
+		let issuer_id = AccountId::from_str(issuer).unwrap();
+		assert_eq!(format_account_id(&issuer_id), issuer);
+
+		assert_eq!(format_asset(&Asset::Native), "native");
+
+		let usdc = AlphaNum4 {
+			asset_code: AssetCode4(*b"USDC"),
+			issuer: issuer_id.clone(),
+		};
+		assert_eq!(
+			format_asset(&Asset::CreditAlphanum4(usdc.clone())),
+			format!("USDC:{}", issuer)
+		);
+
+		// The padding of shorter codes is trimmed
+		let long_code = AlphaNum12 {
+			asset_code: AssetCode12(*b"LONGASSET\0\0\0"),
+			issuer: issuer_id,
+		};
+		assert_eq!(
+			format_asset(&Asset::CreditAlphanum12(long_code)),
+			format!("LONGASSET:{}", issuer)
+		);
+
+		assert_eq!(
+			format_change_trust_asset(&ChangeTrustAsset::CreditAlphanum4(usdc.clone())),
+			format!("USDC:{}", issuer)
+		);
+		assert_eq!(
+			format_change_trust_asset(&ChangeTrustAsset::PoolShare(
+				LiquidityPoolParameters::LiquidityPoolConstantProduct(
+					LiquidityPoolConstantProductParameters {
+						asset_a: Asset::Native,
+						asset_b: Asset::CreditAlphanum4(usdc),
+						fee: 30,
+					}
+				)
+			)),
+			"pool_share"
+		);
+	}
+
 	#[test]
 	fn test_address_functions() {
 		// Test address validation
@@ -1609,6 +1862,42 @@ mod tests {
 		assert!(result.is_err());
 	}
 
+	#[test]
+	fn test_get_account_ledger_key_and_balance() {
+		let account_id = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+		match get_account_ledger_key(account_id).unwrap() {
+			LedgerKey::Account(key) => assert_eq!(
+				key.account_id,
+				AccountId(PublicKey::PublicKeyTypeEd25519(Uint256::from([0; 32])))
+			),
+			_ => panic!("Expected LedgerKey::Account, got something else"),
+		}
+		// Contracts have no account entry
+		assert!(
+			get_account_ledger_key("CA6PUJLBYKZKUEKLZJMKBZLEKP2OTHANDEOWSFF44FTSYLKQPIICCJBE")
+				.is_err()
+		);
+
+		let account_entry = LedgerEntryData::Account(stellar_xdr::curr::AccountEntry {
+			account_id: AccountId(PublicKey::PublicKeyTypeEd25519(Uint256::from([0; 32]))),
+			balance: 25_000_000,
+			seq_num: SequenceNumber(0),
+			num_sub_entries: 0,
+			inflation_dest: None,
+			flags: 0,
+			home_domain: String32::from(StringM::<32>::from_str("").unwrap()),
+			thresholds: stellar_xdr::curr::Thresholds([0; 4]),
+			signers: vec![].try_into().unwrap(),
+			ext: stellar_xdr::curr::AccountEntryExt::V0,
+		});
+		let xdr = BASE64_STANDARD.encode(account_entry.to_xdr(Limits::none()).unwrap());
+		assert_eq!(
+			get_account_balance_from_ledger_entry_data(&xdr).unwrap(),
+			25_000_000
+		);
+		assert!(get_account_balance_from_ledger_entry_data("invalid").is_err());
+	}
+
 	#[test]
 	fn test_get_contract_code_ledger_key() {
 		// Test valid WASM hash
@@ -1629,6 +1918,61 @@ mod tests {
 		assert!(result.is_err());
 	}
 
+	#[test]
+	fn test_parse_sc_val_argument() {
+		assert_eq!(
+			parse_sc_val_argument("Bool", "true").unwrap(),
+			ScVal::Bool(true)
+		);
+		assert_eq!(parse_sc_val_argument("u32", "42").unwrap(), ScVal::U32(42));
+		assert_eq!(
+			parse_sc_val_argument("I128", "-1").unwrap(),
+			ScVal::I128(Int128Parts {
+				hi: -1,
+				lo: u64::MAX
+			})
+		);
+		assert_eq!(
+			parse_sc_val_argument("Symbol", "balance").unwrap(),
+			ScVal::Symbol(ScSymbol("balance".try_into().unwrap()))
+		);
+
+		let contract_id = "CA6PUJLBYKZKUEKLZJMKBZLEKP2OTHANDEOWSFF44FTSYLKQPIICCJBE";
+		match parse_sc_val_argument("Address", &contract_id.to_lowercase()).unwrap() {
+			ScVal::Address(address) => assert_eq!(address.to_string(), contract_id),
+			_ => panic!("Expected address"),
+		}
+
+		assert!(parse_sc_val_argument("U32", "-1").is_err());
+		assert!(parse_sc_val_argument("Address", "invalid").is_err());
+		assert!(parse_sc_val_argument("Vec", "[]").is_err());
+	}
+
+	#[test]
+	fn test_get_contract_call_transaction() {
+		let contract_id = "CA6PUJLBYKZKUEKLZJMKBZLEKP2OTHANDEOWSFF44FTSYLKQPIICCJBE";
+		let transaction =
+			get_contract_call_transaction(contract_id, "balance", vec![ScVal::U32(1)]).unwrap();
+
+		let TransactionEnvelope::Tx(envelope) = transaction else {
+			panic!("Expected transaction envelope");
+		};
+		assert_eq!(envelope.tx.operations.len(), 1);
+		match &envelope.tx.operations[0].body {
+			OperationBody::InvokeHostFunction(op) => match &op.host_function {
+				HostFunction::InvokeContract(args) => {
+					assert_eq!(args.contract_address.to_string(), contract_id);
+					assert_eq!(args.function_name, ScSymbol("balance".try_into().unwrap()));
+					assert_eq!(args.args.to_vec(), vec![ScVal::U32(1)]);
+				}
+				_ => panic!("Expected contract invocation"),
+			},
+			_ => panic!("Expected host function invocation"),
+		}
+
+		assert!(get_contract_call_transaction("invalid", "balance", vec![]).is_err());
+	}
+
 	#[test]
 	fn test_get_wasm_code_from_ledger_entry_data() {
 		// Test with valid contract code XDR
@@ -5,6 +5,7 @@
 //! - Match handling and processing
 //! - Chain-specific helper functions
 
+mod balance;
 mod error;
 #[cfg(fuzzing)]
 pub mod expression;
@@ -12,9 +13,14 @@ pub mod expression;
 mod expression;
 mod filter_match;
 mod filters;
+mod pre_filter;
 
+pub use balance::{find_balance_matches, BalanceCache};
 pub use error::FilterError;
-pub use filter_match::handle_match;
+pub use filter_match::{handle_match, match_variables, matched_args_variables};
+pub use pre_filter::{
+	resolve_pre_filter_rpc_calls, set_rpc_results, RpcResults, MAX_PRE_FILTER_RPC_CALLS_PER_BLOCK,
+};
 
 pub use filters::{
 	evm::{
@@ -23,6 +29,7 @@ pub use filters::{
 		helpers as evm_helpers,
 	},
 	midnight::{filter::MidnightBlockFilter, helpers as midnight_helpers},
+	solana::{filter::SolanaBlockFilter, helpers as solana_helpers},
 	stellar::{
 		evaluator::{StellarArgs, StellarConditionEvaluator},
 		filter::{EventMap, StellarBlockFilter},
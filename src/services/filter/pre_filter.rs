@@ -0,0 +1,158 @@
+//! Pre-filter RPC calls of monitors.
+//!
+//! Before a block is filtered, the `pre_filter_rpc_calls` of its monitors are made. Their results
+//! are bound to the `${rpc.<name>}` variables of the expressions of the monitors' match
+//! conditions, and set in the `rpc_results` of the matched monitors, which matches carry to
+//! trigger condition scripts.
+
+use serde_json::Value;
+use std::{
+	borrow::Cow,
+	collections::{BTreeMap, HashMap},
+};
+
+use crate::{
+	models::{Monitor, MonitorMatch},
+	services::blockchain::BlockChainClient,
+};
+
+/// Maximum number of pre-filter RPC calls made for a block, over all its monitors
+pub const MAX_PRE_FILTER_RPC_CALLS_PER_BLOCK: usize = 20;
+
+/// Results of the pre-filter RPC calls of monitors, by monitor name and call name
+pub type RpcResults = HashMap<String, BTreeMap<String, Value>>;
+
+/// Makes the pre-filter RPC calls of monitors for a block
+///
+/// A monitor is left out of the block when one of its calls fails, or when its calls would
+/// exceed [`MAX_PRE_FILTER_RPC_CALLS_PER_BLOCK`]. The monitors are only copied in that case.
+///
+/// # Arguments
+/// * `client` - The client of the network the block belongs to
+/// * `monitors` - The monitors the block is filtered for
+///
+/// # Returns
+/// The monitors to filter the block for, and the results of their calls
+pub async fn resolve_pre_filter_rpc_calls<'a, T: BlockChainClient>(
+	client: &T,
+	monitors: &'a [Monitor],
+) -> (Cow<'a, [Monitor]>, RpcResults) {
+	let mut remaining_calls = MAX_PRE_FILTER_RPC_CALLS_PER_BLOCK;
+	let mut rpc_results = RpcResults::new();
+	let mut skipped = Vec::new();
+
+	'monitors: for monitor in monitors {
+		if monitor.pre_filter_rpc_calls.is_empty() {
+			continue;
+		}
+
+		if monitor.pre_filter_rpc_calls.len() > remaining_calls {
+			tracing::warn!(
+				"Skipping monitor {} for this block: the limit of {} pre-filter RPC calls per \
+				 block is reached",
+				monitor.name,
+				MAX_PRE_FILTER_RPC_CALLS_PER_BLOCK
+			);
+			skipped.push(&monitor.name);
+			continue;
+		}
+		remaining_calls -= monitor.pre_filter_rpc_calls.len();
+
+		let mut results = BTreeMap::new();
+		for call in &monitor.pre_filter_rpc_calls {
+			match client.call_rpc(&call.method, call.params.clone()).await {
+				Ok(result) => {
+					results.insert(call.name.clone(), result);
+				}
+				Err(e) => {
+					tracing::warn!(
+						"Skipping monitor {} for this block: pre-filter RPC call {} failed: {:#}",
+						monitor.name,
+						call.name,
+						e
+					);
+					skipped.push(&monitor.name);
+					continue 'monitors;
+				}
+			}
+		}
+
+		rpc_results.insert(monitor.name.clone(), results);
+	}
+
+	let monitors = if skipped.is_empty() {
+		Cow::Borrowed(monitors)
+	} else {
+		Cow::Owned(
+			monitors
+				.iter()
+				.filter(|monitor| !skipped.contains(&&monitor.name))
+				.cloned()
+				.collect(),
+		)
+	};
+	(monitors, rpc_results)
+}
+
+/// Sets the results of the pre-filter RPC calls of the matched monitors in their `rpc_results`
+///
+/// # Arguments
+/// * `matches` - The matches found in a block
+/// * `rpc_results` - The results of the pre-filter RPC calls made for the block
+pub fn set_rpc_results(matches: &mut [MonitorMatch], rpc_results: &RpcResults) {
+	if rpc_results.is_empty() {
+		return;
+	}
+	for monitor_match in matches {
+		let monitor = match monitor_match {
+			MonitorMatch::EVM(evm_match) => &mut evm_match.monitor,
+			MonitorMatch::Stellar(stellar_match) => &mut stellar_match.monitor,
+			MonitorMatch::Midnight(midnight_match) => &mut midnight_match.monitor,
+			MonitorMatch::Solana(solana_match) => &mut solana_match.monitor,
+		};
+		if let Some(results) = rpc_results.get(&monitor.name) {
+			monitor.rpc_results = results.clone();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, MatchConditions},
+		utils::tests::evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+	use serde_json::json;
+
+	fn create_match(monitor_name: &str) -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name(monitor_name).build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: None,
+			logs: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			confirmation_state: Default::default(),
+			severity: Default::default(),
+			sender_verified: None,
+		}))
+	}
+
+	#[test]
+	fn test_set_rpc_results() {
+		let mut matches = vec![create_match("with_calls"), create_match("other")];
+		let results = BTreeMap::from([("threshold".to_string(), json!("0x64"))]);
+		let rpc_results = RpcResults::from([("with_calls".to_string(), results.clone())]);
+
+		set_rpc_results(&mut matches, &rpc_results);
+
+		let rpc_results_of = |monitor_match: &MonitorMatch| match monitor_match {
+			MonitorMatch::EVM(evm_match) => evm_match.monitor.rpc_results.clone(),
+			_ => unreachable!(),
+		};
+		assert_eq!(rpc_results_of(&matches[0]), results);
+		assert!(rpc_results_of(&matches[1]).is_empty());
+	}
+}
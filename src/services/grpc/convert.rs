@@ -0,0 +1,221 @@
+//! Conversion of monitor matches to their protobuf messages.
+
+use crate::{
+	models::{
+		EVMConfirmationState, EVMMatchArguments, EVMMatchParamsMap, EVMMonitorMatch,
+		MatchConditions, MonitorMatch, StellarMatchArguments, StellarMatchParamsMap,
+		StellarMonitorMatch,
+	},
+	services::{
+		filter::evm_helpers::{b256_to_string, h160_to_string},
+		grpc::proto,
+	},
+};
+
+/// Converts a match to its protobuf message
+///
+/// # Returns
+/// * `Some(proto::MonitorMatch)` - The message of an EVM or Stellar match
+/// * `None` - For matches of other blockchain types, which have no message
+pub fn to_proto_match(monitor_match: &MonitorMatch) -> Option<proto::MonitorMatch> {
+	let variant = match monitor_match {
+		MonitorMatch::EVM(evm_match) => proto::monitor_match::Match::Evm(evm_to_proto(evm_match)),
+		MonitorMatch::Stellar(stellar_match) => {
+			proto::monitor_match::Match::Stellar(stellar_to_proto(stellar_match))
+		}
+		MonitorMatch::Midnight(_) | MonitorMatch::Solana(_) => return None,
+	};
+
+	Some(proto::MonitorMatch {
+		r#match: Some(variant),
+	})
+}
+
+fn evm_to_proto(evm_match: &EVMMonitorMatch) -> proto::EvmMatch {
+	let (functions, events) = match &evm_match.matched_on_args {
+		Some(EVMMatchArguments {
+			functions, events, ..
+		}) => (
+			evm_arguments_to_proto(functions.as_deref()),
+			evm_arguments_to_proto(events.as_deref()),
+		),
+		None => (Vec::new(), Vec::new()),
+	};
+
+	proto::EvmMatch {
+		monitor_name: evm_match.monitor.name.clone(),
+		network_slug: evm_match.network_slug.clone(),
+		transaction_hash: b256_to_string(*evm_match.transaction.hash()),
+		block_number: evm_match
+			.transaction
+			.block_number
+			.map(|number| number.to::<u64>()),
+		from: evm_match.transaction.sender().copied().map(h160_to_string),
+		to: evm_match.transaction.to().copied().map(h160_to_string),
+		value: evm_match.transaction.value().to_string(),
+		matched_on: Some(conditions_to_proto(&evm_match.matched_on)),
+		functions,
+		events,
+		json: serde_json::to_string(evm_match).unwrap_or_default(),
+		pending: evm_match.confirmation_state == EVMConfirmationState::Pending,
+	}
+}
+
+fn stellar_to_proto(stellar_match: &StellarMonitorMatch) -> proto::StellarMatch {
+	let (functions, events) = match &stellar_match.matched_on_args {
+		Some(StellarMatchArguments {
+			functions, events, ..
+		}) => (
+			stellar_arguments_to_proto(functions.as_deref()),
+			stellar_arguments_to_proto(events.as_deref()),
+		),
+		None => (Vec::new(), Vec::new()),
+	};
+
+	proto::StellarMatch {
+		monitor_name: stellar_match.monitor.name.clone(),
+		network_slug: stellar_match.network_slug.clone(),
+		transaction_hash: stellar_match.transaction.hash().clone(),
+		ledger: stellar_match.ledger.number().unwrap_or_default(),
+		matched_on: Some(conditions_to_proto(&stellar_match.matched_on)),
+		functions,
+		events,
+		json: serde_json::to_string(stellar_match).unwrap_or_default(),
+	}
+}
+
+fn conditions_to_proto(conditions: &MatchConditions) -> proto::MatchedConditions {
+	proto::MatchedConditions {
+		functions: conditions
+			.functions
+			.iter()
+			.map(|function| function.signature.clone())
+			.collect(),
+		events: conditions
+			.events
+			.iter()
+			.map(|event| event.signature.clone())
+			.collect(),
+		transactions: conditions
+			.transactions
+			.iter()
+			.map(|transaction| format!("{:?}", transaction.status))
+			.collect(),
+	}
+}
+
+fn evm_arguments_to_proto(arguments: Option<&[EVMMatchParamsMap]>) -> Vec<proto::MatchedArguments> {
+	arguments
+		.unwrap_or_default()
+		.iter()
+		.map(|params| proto::MatchedArguments {
+			signature: params.signature.clone(),
+			args: params
+				.args
+				.iter()
+				.flatten()
+				.map(|arg| proto::MatchParam {
+					name: arg.name.clone(),
+					value: arg.value.clone(),
+					kind: arg.kind.clone(),
+					indexed: arg.indexed,
+				})
+				.collect(),
+		})
+		.collect()
+}
+
+fn stellar_arguments_to_proto(
+	arguments: Option<&[StellarMatchParamsMap]>,
+) -> Vec<proto::MatchedArguments> {
+	arguments
+		.unwrap_or_default()
+		.iter()
+		.map(|params| proto::MatchedArguments {
+			signature: params.signature.clone(),
+			args: params
+				.args
+				.iter()
+				.flatten()
+				.map(|arg| proto::MatchParam {
+					name: arg.name.clone(),
+					value: arg.value.clone(),
+					kind: arg.kind.clone(),
+					indexed: arg.indexed,
+				})
+				.collect(),
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMatchParamEntry, EVMTransactionReceipt, FunctionCondition},
+		utils::tests::builders::evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+
+	#[test]
+	fn test_evm_match_to_proto() {
+		let monitor_match = MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("Large Transfer").build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: Some(EVMTransactionReceipt::default()),
+			logs: Some(vec![]),
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions {
+				functions: vec![FunctionCondition {
+					signature: "transfer(address,uint256)".to_string(),
+					expression: None,
+					warn_threshold_expression: None,
+					eip712_schema: None,
+				}],
+				events: vec![],
+				transactions: vec![],
+				token_transfers: vec![],
+				block_conditions: vec![],
+				balance_conditions: vec![],
+				state_conditions: vec![],
+			},
+			matched_on_args: Some(EVMMatchArguments {
+				functions: Some(vec![EVMMatchParamsMap {
+					signature: "transfer(address,uint256)".to_string(),
+					args: Some(vec![EVMMatchParamEntry {
+						name: "amount".to_string(),
+						value: "1000".to_string(),
+						indexed: false,
+						kind: "uint256".to_string(),
+					}]),
+					hex_signature: None,
+				}]),
+				events: None,
+				block: None,
+				balance: None,
+			}),
+			confirmation_state: EVMConfirmationState::Confirmed,
+			severity: Default::default(),
+			sender_verified: None,
+		}));
+
+		let Some(proto::MonitorMatch {
+			r#match: Some(proto::monitor_match::Match::Evm(evm_match)),
+		}) = to_proto_match(&monitor_match)
+		else {
+			panic!("Expected an EVM match");
+		};
+
+		assert_eq!(evm_match.monitor_name, "Large Transfer");
+		assert_eq!(evm_match.network_slug, "ethereum_mainnet");
+		assert_eq!(
+			evm_match.matched_on.unwrap().functions,
+			vec!["transfer(address,uint256)"]
+		);
+		assert_eq!(evm_match.functions[0].args[0].value, "1000");
+		assert!(evm_match.events.is_empty());
+		assert!(!evm_match.pending);
+		let json: serde_json::Value = serde_json::from_str(&evm_match.json).unwrap();
+		assert_eq!(json["monitor"]["name"], "Large Transfer");
+		assert_eq!(json["confirmation_state"], "confirmed");
+	}
+}
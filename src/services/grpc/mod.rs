@@ -0,0 +1,17 @@
+//! gRPC streaming of monitor matches.
+//!
+//! Serves the `MatchStream` service defined in `proto/monitor.proto`, letting external
+//! consumers subscribe to the matches produced by the monitor. Matches are received from the
+//! broadcast channel the trigger handler publishes them to, so subscribers see the same
+//! matches the triggers are executed for. Only EVM and Stellar matches are streamed.
+
+mod convert;
+mod server;
+
+/// Generated protobuf messages and gRPC service
+pub mod proto {
+	tonic::include_proto!("openzeppelin.monitor.v1");
+}
+
+pub use convert::to_proto_match;
+pub use server::{serve, MatchStreamService};
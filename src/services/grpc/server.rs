@@ -0,0 +1,149 @@
+//! gRPC server streaming monitor matches.
+
+use futures::{Stream, StreamExt};
+use std::{future::Future, pin::Pin};
+use tokio::{net::TcpListener, sync::broadcast};
+use tokio_stream::wrappers::{
+	errors::BroadcastStreamRecvError, BroadcastStream, TcpListenerStream,
+};
+use tonic::{transport::Server, Request, Response, Status};
+
+use crate::{
+	models::MonitorMatch,
+	services::grpc::{
+		proto::{
+			self,
+			match_stream_server::{MatchStream, MatchStreamServer},
+			SubscribeMatchesRequest,
+		},
+		to_proto_match,
+	},
+};
+
+/// Stream of matches sent to a subscriber
+type MatchStreamResult = Pin<Box<dyn Stream<Item = Result<proto::MonitorMatch, Status>> + Send>>;
+
+/// Implementation of the `MatchStream` gRPC service
+pub struct MatchStreamService {
+	/// Channel the trigger handler publishes matches to
+	match_tx: broadcast::Sender<MonitorMatch>,
+}
+
+impl MatchStreamService {
+	/// Creates a service streaming the matches published to `match_tx`
+	pub fn new(match_tx: broadcast::Sender<MonitorMatch>) -> Self {
+		Self { match_tx }
+	}
+}
+
+#[tonic::async_trait]
+impl MatchStream for MatchStreamService {
+	type SubscribeMatchesStream = MatchStreamResult;
+
+	async fn subscribe_matches(
+		&self,
+		request: Request<SubscribeMatchesRequest>,
+	) -> Result<Response<Self::SubscribeMatchesStream>, Status> {
+		let filter = request.into_inner();
+
+		// Subscribing before responding ensures no match published after the response is missed
+		let stream = BroadcastStream::new(self.match_tx.subscribe()).filter_map(move |received| {
+			let message = match received {
+				Ok(monitor_match) => to_proto_match(&monitor_match)
+					.filter(|message| matches_filter(message, &filter)),
+				Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+					tracing::warn!(
+						"gRPC match subscriber lagged behind, skipped {} matches",
+						skipped
+					);
+					None
+				}
+			};
+			futures::future::ready(message.map(Ok))
+		});
+
+		Ok(Response::new(Box::pin(stream)))
+	}
+}
+
+/// Checks whether a match is selected by the monitor names and network slugs of a request
+fn matches_filter(message: &proto::MonitorMatch, filter: &SubscribeMatchesRequest) -> bool {
+	let (monitor_name, network_slug) = match &message.r#match {
+		Some(proto::monitor_match::Match::Evm(evm_match)) => {
+			(&evm_match.monitor_name, &evm_match.network_slug)
+		}
+		Some(proto::monitor_match::Match::Stellar(stellar_match)) => {
+			(&stellar_match.monitor_name, &stellar_match.network_slug)
+		}
+		None => return false,
+	};
+
+	(filter.monitor_names.is_empty() || filter.monitor_names.contains(monitor_name))
+		&& (filter.network_slugs.is_empty() || filter.network_slugs.contains(network_slug))
+}
+
+/// Serves the `MatchStream` gRPC service until `shutdown` completes
+///
+/// # Arguments
+/// * `listener` - Listener accepting the gRPC connections
+/// * `match_tx` - Channel the trigger handler publishes matches to
+/// * `shutdown` - Future completing when the server should stop
+///
+/// # Returns
+/// * `Result<(), tonic::transport::Error>` - Success or the error that stopped the server
+pub async fn serve(
+	listener: TcpListener,
+	match_tx: broadcast::Sender<MonitorMatch>,
+	shutdown: impl Future<Output = ()>,
+) -> Result<(), tonic::transport::Error> {
+	Server::builder()
+		.add_service(MatchStreamServer::new(MatchStreamService::new(match_tx)))
+		.serve_with_incoming_shutdown(TcpListenerStream::new(listener), shutdown)
+		.await
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn create_message(monitor_name: &str, network_slug: &str) -> proto::MonitorMatch {
+		proto::MonitorMatch {
+			r#match: Some(proto::monitor_match::Match::Stellar(proto::StellarMatch {
+				monitor_name: monitor_name.to_string(),
+				network_slug: network_slug.to_string(),
+				..Default::default()
+			})),
+		}
+	}
+
+	#[test]
+	fn test_matches_filter() {
+		let message = create_message("Large Transfer", "stellar_mainnet");
+
+		assert!(matches_filter(
+			&message,
+			&SubscribeMatchesRequest::default()
+		));
+		assert!(matches_filter(
+			&message,
+			&SubscribeMatchesRequest {
+				monitor_names: vec!["Large Transfer".to_string()],
+				network_slugs: vec!["stellar_mainnet".to_string()],
+			}
+		));
+		assert!(!matches_filter(
+			&message,
+			&SubscribeMatchesRequest {
+				monitor_names: vec!["Approvals".to_string()],
+				network_slugs: vec![],
+			}
+		));
+		assert!(!matches_filter(
+			&message,
+			&SubscribeMatchesRequest {
+				monitor_names: vec![],
+				network_slugs: vec!["stellar_testnet".to_string()],
+			}
+		));
+	}
+}
@@ -1,14 +1,21 @@
 //! Core services implementing the business logic.
 //!
 //! This module contains the main service implementations:
+//! - `admin`: Admin HTTP server, reloading configurations
 //! - `blockchain`: Blockchain client interfaces and implementations
 //! - `blockwatcher`: Block monitoring and processing
 //! - `filter`: Transaction and event filtering logic
+//! - `grpc`: gRPC streaming of matches, behind the `grpc` feature
 //! - `notification`: Alert and notification handling
+//! - `storage`: Persistent history of matches
 //! - `trigger`: Trigger evaluation and execution
 
+pub mod admin;
 pub mod blockchain;
 pub mod blockwatcher;
 pub mod filter;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub mod notification;
+pub mod storage;
 pub mod trigger;
@@ -0,0 +1,229 @@
+//! Digest mode of the notifications.
+//!
+//! A trigger with a `batch_window_ms` doesn't send a notification for every match. Its first
+//! match opens a digest, further matches join it until the batch window elapses, and a
+//! background task then sends a single notification listing them, see
+//! [`NotificationService::execute`](super::NotificationService::execute).
+
+use std::{collections::HashMap, sync::Mutex};
+
+use crate::models::{MonitorMatch, ScriptLanguage, Trigger};
+
+use super::template_engine;
+
+/// Maximum number of matches listed in a digest
+///
+/// Further matches of the window are counted, but not listed.
+pub const MAX_DIGEST_ENTRIES: usize = 100;
+
+/// Template of the summary of each match listed in a digest
+const DIGEST_ENTRY_TEMPLATE: &str = "- {{monitor.name}}: {{transaction.hash}}";
+
+/// Matches collected for a trigger over its batch window
+#[derive(Debug, Clone)]
+pub struct Digest {
+	/// Trigger sending the digest
+	pub trigger: Trigger,
+	/// Scripts of the trigger, for script triggers
+	pub trigger_scripts: HashMap<String, (ScriptLanguage, String)>,
+	/// Number of matches of the window, including the ones not listed
+	pub count: usize,
+	/// Variables and match of the first [`MAX_DIGEST_ENTRIES`] matches, in order of arrival
+	pub entries: Vec<(HashMap<String, String>, MonitorMatch)>,
+}
+
+impl Digest {
+	/// Converts the digest into the variables substituted in the trigger templates
+	///
+	/// The variables of the first match are extended with:
+	/// - `digest.count`: number of matches of the window
+	/// - `digest.matches`: summary of each listed match, one per line
+	/// - `digest.<index>.summary` for each listed match
+	pub fn variables(&self) -> HashMap<String, String> {
+		let mut variables = self
+			.entries
+			.first()
+			.map(|(variables, _)| variables.clone())
+			.unwrap_or_default();
+
+		let mut summaries = Vec::with_capacity(self.entries.len());
+		for (index, (entry_variables, _)) in self.entries.iter().enumerate() {
+			// The summary template is known to be valid
+			let summary =
+				template_engine::render(DIGEST_ENTRY_TEMPLATE, entry_variables, &mut |value| {
+					value.to_string()
+				})
+				.unwrap_or_default();
+			variables.insert(format!("digest.{}.summary", index), summary.clone());
+			summaries.push(summary);
+		}
+		if self.count > self.entries.len() {
+			summaries.push(format!("... and {} more", self.count - self.entries.len()));
+		}
+
+		variables.insert("digest.count".to_string(), self.count.to_string());
+		variables.insert("digest.matches".to_string(), summaries.join("\n"));
+		variables
+	}
+}
+
+/// Open digests of the triggers in digest mode, by trigger name
+#[derive(Debug, Default)]
+pub struct DigestBuffer {
+	digests: Mutex<HashMap<String, Digest>>,
+}
+
+impl DigestBuffer {
+	/// Adds a match to the digest of a trigger, opening one if there is none
+	///
+	/// # Returns
+	/// * `bool` - Whether the match opened the digest, which must then be sent with
+	///   [`Self::take`] once the batch window elapses
+	pub fn push(
+		&self,
+		trigger: &Trigger,
+		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+		variables: HashMap<String, String>,
+		monitor_match: MonitorMatch,
+	) -> bool {
+		let mut digests = self.digests.lock().unwrap();
+		let digest = digests
+			.entry(trigger.name.clone())
+			.or_insert_with(|| Digest {
+				trigger: trigger.clone(),
+				trigger_scripts: trigger_scripts.clone(),
+				count: 0,
+				entries: Vec::new(),
+			});
+		digest.count += 1;
+		if digest.entries.len() < MAX_DIGEST_ENTRIES {
+			digest.entries.push((variables, monitor_match));
+		}
+		digest.count == 1
+	}
+
+	/// Takes the digest of a trigger, closing it
+	pub fn take(&self, trigger_name: &str) -> Option<Digest> {
+		self.digests.lock().unwrap().remove(trigger_name)
+	}
+
+	/// Takes the digests of all triggers, closing them before their batch window elapses
+	pub fn take_all(&self) -> Vec<Digest> {
+		self.digests
+			.lock()
+			.unwrap()
+			.drain()
+			.map(|(_, digest)| digest)
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, MatchConditions},
+		utils::tests::{
+			builders::trigger::TriggerBuilder,
+			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+		},
+	};
+
+	fn create_trigger(name: &str) -> Trigger {
+		TriggerBuilder::new()
+			.name(name)
+			.batch_window_ms(1000)
+			.build()
+	}
+
+	fn create_match() -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: None,
+			logs: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			confirmation_state: Default::default(),
+			severity: Default::default(),
+			sender_verified: None,
+		}))
+	}
+
+	fn create_variables(hash: &str) -> HashMap<String, String> {
+		HashMap::from([
+			("monitor.name".to_string(), "Large Transfers".to_string()),
+			("transaction.hash".to_string(), hash.to_string()),
+		])
+	}
+
+	#[test]
+	fn test_push_opens_one_digest_per_trigger() {
+		let buffer = DigestBuffer::default();
+		let (first, second) = (create_trigger("first"), create_trigger("second"));
+		let scripts = HashMap::new();
+
+		assert!(buffer.push(&first, &scripts, create_variables("0x1"), create_match()));
+		assert!(!buffer.push(&first, &scripts, create_variables("0x2"), create_match()));
+		assert!(buffer.push(&second, &scripts, create_variables("0x3"), create_match()));
+
+		assert_eq!(buffer.take("first").unwrap().count, 2);
+		assert_eq!(buffer.take("second").unwrap().count, 1);
+		assert!(buffer.take("first").is_none());
+	}
+
+	#[test]
+	fn test_take_all_closes_every_digest() {
+		let buffer = DigestBuffer::default();
+		let scripts = HashMap::new();
+		buffer.push(
+			&create_trigger("first"),
+			&scripts,
+			create_variables("0x1"),
+			create_match(),
+		);
+		buffer.push(
+			&create_trigger("second"),
+			&scripts,
+			create_variables("0x2"),
+			create_match(),
+		);
+
+		let mut triggers: Vec<String> = buffer
+			.take_all()
+			.into_iter()
+			.map(|digest| digest.trigger.name)
+			.collect();
+		triggers.sort();
+		assert_eq!(triggers, vec!["first", "second"]);
+		assert!(buffer.take_all().is_empty());
+	}
+
+	#[test]
+	fn test_digest_lists_at_most_max_entries() {
+		let buffer = DigestBuffer::default();
+		let trigger = create_trigger("digest");
+		for index in 0..MAX_DIGEST_ENTRIES + 2 {
+			buffer.push(
+				&trigger,
+				&HashMap::new(),
+				create_variables(&index.to_string()),
+				create_match(),
+			);
+		}
+
+		let digest = buffer.take("digest").unwrap();
+		assert_eq!(digest.count, MAX_DIGEST_ENTRIES + 2);
+		assert_eq!(digest.entries.len(), MAX_DIGEST_ENTRIES);
+
+		let variables = digest.variables();
+		assert_eq!(
+			variables["digest.count"],
+			(MAX_DIGEST_ENTRIES + 2).to_string()
+		);
+		assert_eq!(variables["digest.1.summary"], "- Large Transfers: 1");
+		assert!(variables["digest.matches"].ends_with("... and 2 more"));
+		assert_eq!(variables["transaction.hash"], "0");
+	}
+}
@@ -3,6 +3,7 @@
 //! Provides functionality to send formatted messages to email addresses
 //! via SMTP, supporting message templates with variable substitution.
 
+use async_trait::async_trait;
 use backon::{BackoffBuilder, ExponentialBuilder, Retryable};
 use email_address::EmailAddress;
 use lettre::{
@@ -18,7 +19,7 @@ use std::{collections::HashMap, error::Error as StdError, sync::Arc};
 
 use crate::{
 	models::TriggerTypeConfig,
-	services::notification::{template_formatter, NotificationError},
+	services::notification::{template_formatter, NotificationError, Notifier},
 	utils::{JitterSetting, RetryConfig},
 };
 
@@ -35,6 +36,12 @@ pub struct EmailNotifier<T: AsyncTransport + Send + Sync> {
 	sender: EmailAddress,
 	/// Email recipients
 	recipients: Vec<EmailAddress>,
+	/// Carbon copy recipients
+	cc: Vec<EmailAddress>,
+	/// Blind carbon copy recipients
+	bcc: Vec<EmailAddress>,
+	/// Whether the body template is HTML instead of Markdown
+	is_html: bool,
 	/// Retry policy for SMTP requests
 	retry_policy: RetryConfig,
 }
@@ -55,6 +62,9 @@ pub struct EmailContent {
 	pub body_template: String,
 	pub sender: EmailAddress,
 	pub recipients: Vec<EmailAddress>,
+	pub cc: Vec<EmailAddress>,
+	pub bcc: Vec<EmailAddress>,
+	pub is_html: bool,
 }
 
 // This implementation is only for testing purposes
@@ -82,37 +92,38 @@ where
 			body_template: email_content.body_template,
 			sender: email_content.sender,
 			recipients: email_content.recipients,
+			cc: email_content.cc,
+			bcc: email_content.bcc,
+			is_html: email_content.is_html,
 			client: Arc::new(transport),
 			retry_policy,
 		}
 	}
 
-	/// Sends a formatted message to email
+	/// Builds the email message sent for a formatted body
+	///
+	/// CC and BCC headers are only added when the lists are not empty. The BCC header is
+	/// used for the envelope but is not part of the sent message.
 	///
 	/// # Arguments
-	/// * `message` - The formatted message to send
+	/// * `message` - The formatted HTML body
 	///
 	/// # Returns
-	/// * `Result<(), NotificationError>` - Success or error
-	pub async fn notify(&self, message: &str) -> Result<(), NotificationError> {
-		let recipients_str = self
-			.recipients
-			.iter()
-			.map(ToString::to_string)
-			.collect::<Vec<_>>()
-			.join(", ");
-
-		let mailboxes: Mailboxes = recipients_str.parse::<Mailboxes>().map_err(|e| {
-			NotificationError::notify_failed(
-				format!("Failed to parse recipients: {}", e),
-				Some(e.into()),
-				None,
-			)
-		})?;
-		let recipients_header: header::To = mailboxes.into();
+	/// * `Result<Message, NotificationError>` - The email message or error
+	pub fn build_message(&self, message: &str) -> Result<Message, NotificationError> {
+		let recipients_header: header::To = parse_mailboxes(&self.recipients, "recipients")?.into();
+
+		let mut builder = Message::builder().mailbox(recipients_header);
+		if !self.cc.is_empty() {
+			let cc_header: header::Cc = parse_mailboxes(&self.cc, "CC recipients")?.into();
+			builder = builder.mailbox(cc_header);
+		}
+		if !self.bcc.is_empty() {
+			let bcc_header: header::Bcc = parse_mailboxes(&self.bcc, "BCC recipients")?.into();
+			builder = builder.mailbox(bcc_header);
+		}
 
-		let email = Message::builder()
-			.mailbox(recipients_header)
+		builder
 			.from(self.sender.to_string().parse::<Mailbox>().map_err(|e| {
 				NotificationError::notify_failed(
 					format!("Failed to parse sender: {}", e),
@@ -136,16 +147,26 @@ where
 					Some(e.into()),
 					None,
 				)
-			})?;
+			})
+	}
+
+	/// Sends a formatted message to email
+	///
+	/// Sends failing on the connection to the SMTP server or with a transient (4xx) response are
+	/// retried according to the retry policy, while messages permanently rejected (5xx) by the
+	/// server aren't. A connection failing during a send is discarded by the SMTP client, so that
+	/// the retry opens a new one.
+	///
+	/// # Arguments
+	/// * `message` - The formatted message to send
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify(&self, message: &str) -> Result<(), NotificationError> {
+		let email = self.build_message(message)?;
 
 		let operation = || async {
-			self.client.send(email.clone()).await.map_err(|e| {
-				NotificationError::notify_failed(
-					format!("Failed to send email: {}", e),
-					Some(Box::new(e)),
-					None,
-				)
-			})?;
+			self.client.send(email.clone()).await.map_err(send_error)?;
 
 			Ok(())
 		};
@@ -159,17 +180,7 @@ where
 			JitterSetting::None => backoff,
 		};
 
-		// Retry if the error is SmtpError and not permanent
-		let should_retry = |e: &NotificationError| -> bool {
-			if let NotificationError::NotifyFailed(context) = e {
-				if let Some(source) = context.source() {
-					if let Some(smtp_error) = source.downcast_ref::<SmtpError>() {
-						return !smtp_error.is_permanent();
-					}
-				}
-			}
-			true
-		};
+		let should_retry = |e: &NotificationError| !matches!(e, NotificationError::Rejected(_));
 
 		operation
 			.retry(
@@ -182,6 +193,48 @@ where
 	}
 }
 
+/// Maps the error of a failed send to a notification error
+///
+/// Permanent (5xx) SMTP responses are [`NotificationError::Rejected`], transient (4xx) ones
+/// [`NotificationError::NotifyFailed`], and failures of the connection to the server, such as a
+/// dropped connection, a TLS failure or a timeout, [`NotificationError::NetworkError`].
+///
+/// # Arguments
+/// * `error` - Error of the transport
+///
+/// # Returns
+/// * `NotificationError` - The notification error
+fn send_error<E: StdError + Send + Sync + 'static>(error: E) -> NotificationError {
+	let source: &(dyn StdError + 'static) = &error;
+	let (is_permanent, is_connection_error) = match source.downcast_ref::<SmtpError>() {
+		Some(smtp_error) => (
+			smtp_error.is_permanent(),
+			!smtp_error.is_permanent() && !smtp_error.is_transient(),
+		),
+		None => (false, source.is::<std::io::Error>()),
+	};
+
+	if is_permanent {
+		NotificationError::rejected(
+			format!("Email rejected by the SMTP server: {}", error),
+			Some(Box::new(error)),
+			None,
+		)
+	} else if is_connection_error {
+		NotificationError::network_error(
+			format!("Failed to send email over the SMTP connection: {}", error),
+			Some(Box::new(error)),
+			None,
+		)
+	} else {
+		NotificationError::notify_failed(
+			format!("Failed to send email: {}", error),
+			Some(Box::new(error)),
+			None,
+		)
+	}
+}
+
 impl EmailNotifier<AsyncSmtpTransport<Tokio1Executor>> {
 	/// Creates a new email notifier instance
 	///
@@ -201,6 +254,9 @@ impl EmailNotifier<AsyncSmtpTransport<Tokio1Executor>> {
 			body_template: email_content.body_template,
 			sender: email_content.sender,
 			recipients: email_content.recipients,
+			cc: email_content.cc,
+			bcc: email_content.bcc,
+			is_html: email_content.is_html,
 			client: smtp_client,
 			retry_policy,
 		})
@@ -211,6 +267,35 @@ impl EmailNotifier<AsyncSmtpTransport<Tokio1Executor>> {
 		&self.body_template
 	}
 
+	/// Returns whether the body template is HTML.
+	pub fn is_html(&self) -> bool {
+		self.is_html
+	}
+
+	/// Formats the email body, rendering Markdown templates to HTML
+	///
+	/// HTML templates only get their variables substituted.
+	///
+	/// # Arguments
+	/// * `body_template` - The body template
+	/// * `variables` - Map of variable names to values
+	/// * `is_html` - Whether the template is HTML
+	///
+	/// # Returns
+	/// * `Result<String, NotificationError>` - Formatted HTML body, or an error if the template
+	///   is malformed
+	pub fn format_body(
+		body_template: &str,
+		variables: &HashMap<String, String>,
+		is_html: bool,
+	) -> Result<String, NotificationError> {
+		if is_html {
+			template_formatter::format_template(body_template, variables)
+		} else {
+			Self::format_message(body_template, variables)
+		}
+	}
+
 	/// Formats a message by substituting variables in the template and converts it to HTML
 	/// Method is static because property-based tests do not have tokio runtime available,
 	/// which is required for AsyncSmtpTransport
@@ -219,10 +304,14 @@ impl EmailNotifier<AsyncSmtpTransport<Tokio1Executor>> {
 	/// * `variables` - Map of variable names to values
 	///
 	/// # Returns
-	/// * `String` - Formatted message with variables replaced and converted to HTML
-	pub fn format_message(body_template: &str, variables: &HashMap<String, String>) -> String {
-		let formatted_message = template_formatter::format_template(body_template, variables);
-		Self::markdown_to_html(&formatted_message)
+	/// * `Result<String, NotificationError>` - Formatted message with variables replaced and
+	///   converted to HTML, or an error if the template is malformed
+	pub fn format_message(
+		body_template: &str,
+		variables: &HashMap<String, String>,
+	) -> Result<String, NotificationError> {
+		let formatted_message = template_formatter::format_template(body_template, variables)?;
+		Ok(Self::markdown_to_html(&formatted_message))
 	}
 
 	/// Convert a Markdown string into HTML
@@ -251,6 +340,9 @@ impl EmailNotifier<AsyncSmtpTransport<Tokio1Executor>> {
 			message,
 			sender,
 			recipients,
+			cc,
+			bcc,
+			is_html,
 			retry_policy,
 			..
 		} = config
@@ -260,6 +352,9 @@ impl EmailNotifier<AsyncSmtpTransport<Tokio1Executor>> {
 				body_template: message.body.clone(),
 				sender: sender.clone(),
 				recipients: recipients.clone(),
+				cc: cc.clone(),
+				bcc: bcc.clone(),
+				is_html: *is_html,
 			};
 
 			Self::new(smtp_client, email_content, retry_policy.clone())
@@ -273,6 +368,44 @@ impl EmailNotifier<AsyncSmtpTransport<Tokio1Executor>> {
 	}
 }
 
+#[async_trait]
+impl Notifier for EmailNotifier<AsyncSmtpTransport<Tokio1Executor>> {
+	/// Checks that the SMTP server accepts a connection, with the configured credentials, and
+	/// answers a `NOOP` command
+	async fn healthcheck(&self) -> Result<(), NotificationError> {
+		match self.client.test_connection().await {
+			Ok(true) => Ok(()),
+			Ok(false) => Err(NotificationError::network_error(
+				"SMTP server did not answer the NOOP command",
+				None,
+				None,
+			)),
+			Err(e) => Err(NotificationError::network_error(
+				format!("Failed to connect to the SMTP server: {}", e),
+				Some(e.into()),
+				None,
+			)),
+		}
+	}
+}
+
+/// Parses a list of email addresses into mailboxes
+fn parse_mailboxes(addresses: &[EmailAddress], kind: &str) -> Result<Mailboxes, NotificationError> {
+	addresses
+		.iter()
+		.map(ToString::to_string)
+		.collect::<Vec<_>>()
+		.join(", ")
+		.parse::<Mailboxes>()
+		.map_err(|e| {
+			NotificationError::notify_failed(
+				format!("Failed to parse {}: {}", kind, e),
+				Some(e.into()),
+				None,
+			)
+		})
+}
+
 #[cfg(test)]
 mod tests {
 	use lettre::transport::{smtp::authentication::Credentials, stub::AsyncStubTransport};
@@ -291,6 +424,9 @@ mod tests {
 			body_template: "Hello ${name}, your balance is ${balance}".to_string(),
 			sender: "sender@test.com".parse().unwrap(),
 			recipients: vec!["recipient@test.com".parse().unwrap()],
+			cc: vec![],
+			bcc: vec![],
+			is_html: false,
 		}
 	}
 
@@ -325,7 +461,11 @@ mod tests {
 			},
 			sender: "sender@test.com".parse().unwrap(),
 			recipients: vec!["recipient@test.com".parse().unwrap()],
+			cc: vec!["cc@test.com".parse().unwrap()],
+			bcc: vec![],
+			is_html: false,
 			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
 		}
 	}
 
@@ -340,7 +480,7 @@ mod tests {
 		variables.insert("name".to_string(), "Alice".to_string());
 		variables.insert("balance".to_string(), "100".to_string());
 
-		let result = EmailNotifier::format_message(notifier.body_template(), &variables);
+		let result = EmailNotifier::format_message(notifier.body_template(), &variables).unwrap();
 		let expected_result = "<p>Hello Alice, your balance is 100</p>\n";
 		assert_eq!(result, expected_result);
 	}
@@ -351,7 +491,7 @@ mod tests {
 		let mut variables = HashMap::new();
 		variables.insert("name".to_string(), "Bob".to_string());
 
-		let result = EmailNotifier::format_message(notifier.body_template(), &variables);
+		let result = EmailNotifier::format_message(notifier.body_template(), &variables).unwrap();
 		let expected_result = "<p>Hello Bob, your balance is ${balance}</p>\n";
 		assert_eq!(result, expected_result);
 	}
@@ -361,7 +501,7 @@ mod tests {
 		let notifier = create_test_notifier();
 		let variables = HashMap::new();
 
-		let result = EmailNotifier::format_message(notifier.body_template(), &variables);
+		let result = EmailNotifier::format_message(notifier.body_template(), &variables).unwrap();
 		let expected_result = "<p>Hello ${name}, your balance is ${balance}</p>\n";
 		assert_eq!(result, expected_result);
 	}
@@ -373,11 +513,22 @@ mod tests {
 		variables.insert("name".to_string(), "".to_string());
 		variables.insert("balance".to_string(), "".to_string());
 
-		let result = EmailNotifier::format_message(notifier.body_template(), &variables);
+		let result = EmailNotifier::format_message(notifier.body_template(), &variables).unwrap();
 		let expected_result = "<p>Hello , your balance is</p>\n";
 		assert_eq!(result, expected_result);
 	}
 
+	#[test]
+	fn test_format_body_html_template() {
+		let variables = HashMap::from([("name".to_string(), "Alice".to_string())]);
+
+		let html = EmailNotifier::format_body("<h1>Hello ${name}</h1>", &variables, true).unwrap();
+		assert_eq!(html, "<h1>Hello Alice</h1>");
+
+		let markdown = EmailNotifier::format_body("# Hello ${name}", &variables, false).unwrap();
+		assert_eq!(markdown, "<h1>Hello Alice</h1>\n");
+	}
+
 	////////////////////////////////////////////////////////////
 	// from_config tests
 	////////////////////////////////////////////////////////////
@@ -411,6 +562,10 @@ mod tests {
 		assert_eq!(notifier.sender.to_string(), "sender@test.com");
 		assert_eq!(notifier.recipients.len(), 1);
 		assert_eq!(notifier.recipients[0].to_string(), "recipient@test.com");
+		assert_eq!(notifier.cc.len(), 1);
+		assert_eq!(notifier.cc[0].to_string(), "cc@test.com");
+		assert!(notifier.bcc.is_empty());
+		assert!(!notifier.is_html);
 	}
 
 	#[tokio::test]
@@ -424,7 +579,9 @@ mod tests {
 				title: "Test Slack".to_string(),
 				body: "Hello ${name}".to_string(),
 			},
+			blocks: None,
 			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
 		};
 
 		// Correct config to create SmtpTransport
@@ -491,6 +648,33 @@ mod tests {
 		assert_eq!(transport.messages().await.len(), 1);
 	}
 
+	#[tokio::test]
+	async fn test_notify_sends_cc_and_bcc() {
+		let transport = AsyncStubTransport::new_ok();
+		let email_content = EmailContent {
+			cc: vec!["cc@test.com".parse().unwrap()],
+			bcc: vec!["bcc@test.com".parse().unwrap()],
+			..create_test_email_content()
+		};
+		let notifier =
+			EmailNotifier::with_transport(email_content, transport.clone(), RetryConfig::default());
+
+		notifier.notify("<p>test message</p>").await.unwrap();
+
+		let messages = transport.messages().await;
+		assert_eq!(messages.len(), 1);
+		let (envelope, raw) = &messages[0];
+		let envelope_recipients: Vec<String> =
+			envelope.to().iter().map(ToString::to_string).collect();
+		assert_eq!(envelope_recipients.len(), 3);
+		for recipient in ["recipient@test.com", "cc@test.com", "bcc@test.com"] {
+			assert!(envelope_recipients.contains(&recipient.to_string()));
+		}
+		assert!(raw.contains("Cc: cc@test.com"));
+		assert!(!raw.contains("Bcc:"));
+		assert!(raw.contains("Content-Type: text/html"));
+	}
+
 	#[tokio::test]
 	async fn test_notify_fails_after_all_retries() {
 		let transport = AsyncStubTransport::new_error();
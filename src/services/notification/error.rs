@@ -3,7 +3,7 @@
 //! Provides error types for notification-related operations,
 //! including network issues and configuration problems.
 
-use crate::utils::logging::error::{ErrorContext, TraceableError};
+use crate::utils::logging::error::{ErrorCode, ErrorContext, TraceableError};
 use std::collections::HashMap;
 use thiserror::Error as ThisError;
 
@@ -11,24 +11,32 @@ use thiserror::Error as ThisError;
 #[derive(ThisError, Debug)]
 pub enum NotificationError {
 	/// Errors related to network connectivity issues
-	#[error("Network error: {0}")]
+	#[error("[{}] Network error: {0}", ErrorCode::NotificationNetwork)]
 	NetworkError(Box<ErrorContext>),
 
 	/// Errors related to malformed requests or invalid responses
-	#[error("Config error: {0}")]
+	#[error("[{}] Config error: {0}", ErrorCode::NotificationConfig)]
 	ConfigError(Box<ErrorContext>),
 
 	/// Errors related to internal processing errors
-	#[error("Internal error: {0}")]
+	#[error("[{}] Internal error: {0}", ErrorCode::NotificationInternal)]
 	InternalError(Box<ErrorContext>),
 
 	/// Errors related to script execution
-	#[error("Script execution error: {0}")]
+	#[error("[{}] Script execution error: {0}", ErrorCode::NotificationExecution)]
 	ExecutionError(Box<ErrorContext>),
 
 	/// Error when Notifier `notify`` method fails (e.g., webhook failure, parsing error, invalid signature)
-	#[error("Notification failed: {0}")]
+	#[error("[{}] Notification failed: {0}", ErrorCode::NotificationFailed)]
 	NotifyFailed(Box<ErrorContext>),
+
+	/// Error when a send does not complete within the trigger's configured timeout
+	#[error("[{}] Notification timed out: {0}", ErrorCode::NotificationTimeout)]
+	Timeout(Box<ErrorContext>),
+
+	/// Error when the receiving server permanently refuses a notification, which isn't retried
+	#[error("[{}] Notification rejected: {0}", ErrorCode::NotificationRejected)]
+	Rejected(Box<ErrorContext>),
 }
 
 impl NotificationError {
@@ -38,7 +46,11 @@ impl NotificationError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::NetworkError(Box::new(ErrorContext::new_with_log(msg, source, metadata)))
+		Self::NetworkError(Box::new(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::NotificationNetwork)
+				.log(),
+		))
 	}
 
 	// Config error
@@ -47,7 +59,11 @@ impl NotificationError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::ConfigError(Box::new(ErrorContext::new_with_log(msg, source, metadata)))
+		Self::ConfigError(Box::new(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::NotificationConfig)
+				.log(),
+		))
 	}
 
 	// Internal error
@@ -56,7 +72,11 @@ impl NotificationError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::InternalError(Box::new(ErrorContext::new_with_log(msg, source, metadata)))
+		Self::InternalError(Box::new(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::NotificationInternal)
+				.log(),
+		))
 	}
 
 	// Execution error
@@ -65,7 +85,11 @@ impl NotificationError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::ExecutionError(Box::new(ErrorContext::new_with_log(msg, source, metadata)))
+		Self::ExecutionError(Box::new(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::NotificationExecution)
+				.log(),
+		))
 	}
 
 	// Notify failed error
@@ -74,7 +98,50 @@ impl NotificationError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::NotifyFailed(Box::new(ErrorContext::new_with_log(msg, source, metadata)))
+		Self::NotifyFailed(Box::new(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::NotificationFailed)
+				.log(),
+		))
+	}
+
+	// Timeout error
+	pub fn timeout_error(
+		msg: impl Into<String>,
+		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+		metadata: Option<HashMap<String, String>>,
+	) -> Self {
+		Self::Timeout(Box::new(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::NotificationTimeout)
+				.log(),
+		))
+	}
+
+	// Rejected error
+	pub fn rejected(
+		msg: impl Into<String>,
+		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+		metadata: Option<HashMap<String, String>>,
+	) -> Self {
+		Self::Rejected(Box::new(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::NotificationRejected)
+				.log(),
+		))
+	}
+
+	/// Returns the stable code identifying the kind of error
+	pub fn code(&self) -> ErrorCode {
+		match self {
+			Self::NetworkError(_) => ErrorCode::NotificationNetwork,
+			Self::ConfigError(_) => ErrorCode::NotificationConfig,
+			Self::InternalError(_) => ErrorCode::NotificationInternal,
+			Self::ExecutionError(_) => ErrorCode::NotificationExecution,
+			Self::NotifyFailed(_) => ErrorCode::NotificationFailed,
+			Self::Timeout(_) => ErrorCode::NotificationTimeout,
+			Self::Rejected(_) => ErrorCode::NotificationRejected,
+		}
 	}
 }
 
@@ -86,6 +153,8 @@ impl TraceableError for NotificationError {
 			Self::InternalError(ctx) => ctx.trace_id.clone(),
 			Self::ExecutionError(ctx) => ctx.trace_id.clone(),
 			Self::NotifyFailed(ctx) => ctx.trace_id.clone(),
+			Self::Timeout(ctx) => ctx.trace_id.clone(),
+			Self::Rejected(ctx) => ctx.trace_id.clone(),
 		}
 	}
 }
@@ -98,7 +167,10 @@ mod tests {
 	#[test]
 	fn test_network_error_formatting() {
 		let error = NotificationError::network_error("test error", None, None);
-		assert_eq!(error.to_string(), "Network error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[NOTIFICATION_NETWORK] Network error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = NotificationError::network_error(
@@ -106,13 +178,19 @@ mod tests {
 			Some(Box::new(source_error)),
 			Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
 		);
-		assert_eq!(error.to_string(), "Network error: test error [key1=value1]");
+		assert_eq!(
+			error.to_string(),
+			"[NOTIFICATION_NETWORK] Network error: test error [key1=value1]"
+		);
 	}
 
 	#[test]
 	fn test_config_error_formatting() {
 		let error = NotificationError::config_error("test error", None, None);
-		assert_eq!(error.to_string(), "Config error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[NOTIFICATION_CONFIG] Config error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = NotificationError::config_error(
@@ -120,13 +198,19 @@ mod tests {
 			Some(Box::new(source_error)),
 			Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
 		);
-		assert_eq!(error.to_string(), "Config error: test error [key1=value1]");
+		assert_eq!(
+			error.to_string(),
+			"[NOTIFICATION_CONFIG] Config error: test error [key1=value1]"
+		);
 	}
 
 	#[test]
 	fn test_internal_error_formatting() {
 		let error = NotificationError::internal_error("test error", None, None);
-		assert_eq!(error.to_string(), "Internal error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[NOTIFICATION_INTERNAL] Internal error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = NotificationError::internal_error(
@@ -136,14 +220,17 @@ mod tests {
 		);
 		assert_eq!(
 			error.to_string(),
-			"Internal error: test error [key1=value1]"
+			"[NOTIFICATION_INTERNAL] Internal error: test error [key1=value1]"
 		);
 	}
 
 	#[test]
 	fn test_execution_error_formatting() {
 		let error = NotificationError::execution_error("test error", None, None);
-		assert_eq!(error.to_string(), "Script execution error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[NOTIFICATION_EXECUTION] Script execution error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = NotificationError::execution_error(
@@ -153,14 +240,17 @@ mod tests {
 		);
 		assert_eq!(
 			error.to_string(),
-			"Script execution error: test error [key1=value1]"
+			"[NOTIFICATION_EXECUTION] Script execution error: test error [key1=value1]"
 		);
 	}
 
 	#[test]
 	fn test_notify_failed_error_formatting() {
 		let error = NotificationError::notify_failed("test error", None, None);
-		assert_eq!(error.to_string(), "Notification failed: test error");
+		assert_eq!(
+			error.to_string(),
+			"[NOTIFICATION_FAILED] Notification failed: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = NotificationError::notify_failed(
@@ -170,10 +260,85 @@ mod tests {
 		);
 		assert_eq!(
 			error.to_string(),
-			"Notification failed: test error [key1=value1]"
+			"[NOTIFICATION_FAILED] Notification failed: test error [key1=value1]"
+		);
+	}
+
+	#[test]
+	fn test_timeout_error_formatting() {
+		let error = NotificationError::timeout_error("test error", None, None);
+		assert_eq!(
+			error.to_string(),
+			"[NOTIFICATION_TIMEOUT] Notification timed out: test error"
+		);
+
+		let error = NotificationError::timeout_error(
+			"test error",
+			None,
+			Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
+		);
+		assert_eq!(
+			error.to_string(),
+			"[NOTIFICATION_TIMEOUT] Notification timed out: test error [key1=value1]"
+		);
+	}
+
+	#[test]
+	fn test_rejected_error_formatting() {
+		let error = NotificationError::rejected("test error", None, None);
+		assert_eq!(
+			error.to_string(),
+			"[NOTIFICATION_REJECTED] Notification rejected: test error"
 		);
 	}
 
+	#[test]
+	fn test_error_codes() {
+		let errors = vec![
+			(
+				NotificationError::network_error("test error", None, None),
+				ErrorCode::NotificationNetwork,
+			),
+			(
+				NotificationError::config_error("test error", None, None),
+				ErrorCode::NotificationConfig,
+			),
+			(
+				NotificationError::internal_error("test error", None, None),
+				ErrorCode::NotificationInternal,
+			),
+			(
+				NotificationError::execution_error("test error", None, None),
+				ErrorCode::NotificationExecution,
+			),
+			(
+				NotificationError::notify_failed("test error", None, None),
+				ErrorCode::NotificationFailed,
+			),
+			(
+				NotificationError::timeout_error("test error", None, None),
+				ErrorCode::NotificationTimeout,
+			),
+			(
+				NotificationError::rejected("test error", None, None),
+				ErrorCode::NotificationRejected,
+			),
+		];
+
+		for (error, code) in errors {
+			assert_eq!(error.code(), code);
+			assert!(error.to_string().starts_with(&format!("[{}] ", code)));
+		}
+
+		// The code is also set on the context, for structured logs
+		let error = NotificationError::network_error("test error", None, None);
+		if let NotificationError::NetworkError(ctx) = &error {
+			assert_eq!(ctx.code, Some(ErrorCode::NotificationNetwork));
+		} else {
+			panic!("Expected NetworkError variant");
+		}
+	}
+
 	#[test]
 	fn test_error_source_chain() {
 		let io_error = std::io::Error::other("while reading config");
@@ -232,6 +397,14 @@ mod tests {
 				let (ctx, id) = create_context_with_id();
 				(NotificationError::NotifyFailed(ctx), id)
 			},
+			{
+				let (ctx, id) = create_context_with_id();
+				(NotificationError::Timeout(ctx), id)
+			},
+			{
+				let (ctx, id) = create_context_with_id();
+				(NotificationError::Rejected(ctx), id)
+			},
 		];
 
 		for (error, original_id) in errors_with_ids {
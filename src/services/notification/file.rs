@@ -0,0 +1,503 @@
+//! File notification implementation.
+//!
+//! Appends a record for every notification to a local file, providing an audit trail that
+//! doesn't depend on external services. Writes to the same path are serialized and the file is
+//! rotated once it grows past the configured size.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::{BTreeMap, HashMap},
+	path::{Path, PathBuf},
+	sync::Arc,
+};
+use tokio::{fs::OpenOptions, io::AsyncWriteExt, sync::Mutex};
+
+use crate::{
+	models::{FileFormat, MatchConditions, MonitorMatch, TriggerTypeConfig},
+	services::notification::{NotificationError, Notifier},
+};
+
+/// Header row written at the top of CSV files
+const CSV_HEADER: &str = "timestamp,chain,monitor,network,matched_on,variables\n";
+
+lazy_static! {
+	/// Locks serializing writes to each file path
+	static ref FILE_LOCKS: Mutex<HashMap<PathBuf, Arc<Mutex<()>>>> = Mutex::new(HashMap::new());
+}
+
+/// Returns the lock guarding writes to the given path
+async fn file_lock(path: &Path) -> Arc<Mutex<()>> {
+	FILE_LOCKS
+		.lock()
+		.await
+		.entry(path.to_path_buf())
+		.or_default()
+		.clone()
+}
+
+/// Computes the path a file is moved to when rotated, e.g. `audit.jsonl` -> `audit.1.jsonl`
+pub fn compute_rotated_file_path(path: &Path, index: u32) -> PathBuf {
+	let stem = path
+		.file_stem()
+		.map(|s| s.to_string_lossy().to_string())
+		.unwrap_or_default();
+	let file_name = match path.extension() {
+		Some(extension) => format!("{}.{}.{}", stem, index, extension.to_string_lossy()),
+		None => format!("{}.{}", stem, index),
+	};
+	path.with_file_name(file_name)
+}
+
+/// Summary of the monitor match a record was written for
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FileMatchSummary {
+	/// Blockchain type of the match
+	pub chain: String,
+	/// Name of the matched monitor
+	pub monitor: String,
+	/// Slug of the network the match occurred on
+	pub network: String,
+	/// Conditions that matched
+	pub matched_on: MatchConditions,
+}
+
+impl From<&MonitorMatch> for FileMatchSummary {
+	fn from(monitor_match: &MonitorMatch) -> Self {
+		let (chain, monitor, network, matched_on) = match monitor_match {
+			MonitorMatch::EVM(m) => ("EVM", &m.monitor, &m.network_slug, &m.matched_on),
+			MonitorMatch::Stellar(m) => ("Stellar", &m.monitor, &m.network_slug, &m.matched_on),
+			MonitorMatch::Midnight(m) => ("Midnight", &m.monitor, &m.network_slug, &m.matched_on),
+			MonitorMatch::Solana(m) => ("Solana", &m.monitor, &m.network_slug, &m.matched_on),
+		};
+		Self {
+			chain: chain.to_string(),
+			monitor: monitor.name.clone(),
+			network: network.clone(),
+			matched_on: matched_on.clone(),
+		}
+	}
+}
+
+/// A single record appended by the file notifier
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FileRecord {
+	/// Time the record was written, in RFC 3339 format
+	pub timestamp: String,
+	/// Summary of the monitor match, absent for service-level alerts
+	pub monitor_match: Option<FileMatchSummary>,
+	/// Template variables of the notification
+	pub variables: BTreeMap<String, String>,
+}
+
+impl FileRecord {
+	/// Creates a record for the current time
+	pub fn new(variables: &HashMap<String, String>, monitor_match: Option<&MonitorMatch>) -> Self {
+		Self {
+			timestamp: Utc::now().to_rfc3339(),
+			monitor_match: monitor_match.map(FileMatchSummary::from),
+			variables: variables
+				.iter()
+				.map(|(k, v)| (k.clone(), v.clone()))
+				.collect(),
+		}
+	}
+}
+
+/// Escapes a CSV field, quoting it when it contains separators, quotes or line breaks
+fn escape_csv_field(field: &str) -> String {
+	if field.contains([',', '"', '\n', '\r']) {
+		format!("\"{}\"", field.replace('"', "\"\""))
+	} else {
+		field.to_string()
+	}
+}
+
+/// Implementation of notifications appended to a local file
+#[derive(Debug, Clone)]
+pub struct FileNotifier {
+	/// Path of the file records are appended to
+	pub path: PathBuf,
+	/// Record format
+	pub format: FileFormat,
+	/// Size in bytes after which the file is rotated
+	pub rotate_max_bytes: Option<u64>,
+}
+
+impl FileNotifier {
+	/// Creates a new file notifier instance
+	///
+	/// # Arguments
+	/// * `path` - Path of the file records are appended to
+	/// * `format` - Record format
+	/// * `rotate_max_bytes` - Size in bytes after which the file is rotated
+	pub fn new(
+		path: impl Into<PathBuf>,
+		format: FileFormat,
+		rotate_max_bytes: Option<u64>,
+	) -> Self {
+		Self {
+			path: path.into(),
+			format,
+			rotate_max_bytes,
+		}
+	}
+
+	/// Creates a file notifier from a trigger configuration
+	///
+	/// # Arguments
+	/// * `config` - Trigger configuration containing file parameters
+	///
+	/// # Returns
+	/// * `Result<Self, NotificationError>` - Notifier instance if config is File type
+	pub fn from_config(config: &TriggerTypeConfig) -> Result<Self, NotificationError> {
+		if let TriggerTypeConfig::File {
+			path,
+			format,
+			rotate_max_bytes,
+		} = config
+		{
+			Ok(Self::new(path, *format, *rotate_max_bytes))
+		} else {
+			let msg = format!("Invalid file configuration: {:?}", config);
+			Err(NotificationError::config_error(msg, None, None))
+		}
+	}
+
+	/// Serializes a record in the configured format, including the trailing newline
+	///
+	/// # Arguments
+	/// * `record` - Record to serialize
+	/// * `include_header` - Whether to prepend the CSV header row (ignored for JSON formats)
+	///
+	/// # Returns
+	/// * `Result<String, NotificationError>` - Serialized record
+	pub fn format_record(
+		&self,
+		record: &FileRecord,
+		include_header: bool,
+	) -> Result<String, NotificationError> {
+		let to_json_error = |e: serde_json::Error| {
+			NotificationError::internal_error(
+				format!("Failed to serialize file record: {}", e),
+				Some(e.into()),
+				None,
+			)
+		};
+
+		match self.format {
+			FileFormat::Json => {
+				Ok(serde_json::to_string_pretty(record).map_err(to_json_error)? + "\n")
+			}
+			FileFormat::Jsonl => Ok(serde_json::to_string(record).map_err(to_json_error)? + "\n"),
+			FileFormat::Csv => {
+				let (chain, monitor, network, matched_on) = match &record.monitor_match {
+					Some(summary) => (
+						summary.chain.clone(),
+						summary.monitor.clone(),
+						summary.network.clone(),
+						serde_json::to_string(&summary.matched_on).map_err(to_json_error)?,
+					),
+					None => Default::default(),
+				};
+				let variables = serde_json::to_string(&record.variables).map_err(to_json_error)?;
+
+				let row = [
+					&record.timestamp,
+					&chain,
+					&monitor,
+					&network,
+					&matched_on,
+					&variables,
+				]
+				.iter()
+				.map(|field| escape_csv_field(field))
+				.collect::<Vec<_>>()
+				.join(",");
+
+				if include_header {
+					Ok(format!("{}{}\n", CSV_HEADER, row))
+				} else {
+					Ok(format!("{}\n", row))
+				}
+			}
+		}
+	}
+
+	/// Moves the current file to the first free rotated path
+	async fn rotate(&self) -> Result<(), NotificationError> {
+		let mut index = 1;
+		let mut rotated_path = compute_rotated_file_path(&self.path, index);
+		while tokio::fs::try_exists(&rotated_path).await.unwrap_or(false) {
+			index += 1;
+			rotated_path = compute_rotated_file_path(&self.path, index);
+		}
+
+		tokio::fs::rename(&self.path, &rotated_path)
+			.await
+			.map_err(|e| {
+				NotificationError::notify_failed(
+					format!(
+						"Failed to rotate {} to {}",
+						self.path.display(),
+						rotated_path.display()
+					),
+					Some(e.into()),
+					None,
+				)
+			})
+	}
+
+	/// Appends a record for a notification to the file
+	///
+	/// The record is written with a single append, while holding the lock of the file path, so
+	/// concurrent notifications never interleave. If appending the record would grow the file
+	/// past `rotate_max_bytes`, the file is rotated first.
+	///
+	/// # Arguments
+	/// * `variables` - Template variables of the notification
+	/// * `monitor_match` - Monitor match the notification was sent for, if any
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: Option<&MonitorMatch>,
+	) -> Result<(), NotificationError> {
+		let record = FileRecord::new(variables, monitor_match);
+		let to_io_error = |e: std::io::Error| {
+			NotificationError::notify_failed(
+				format!("Failed to write to {}", self.path.display()),
+				Some(e.into()),
+				None,
+			)
+		};
+
+		let lock = file_lock(&self.path).await;
+		let _guard = lock.lock().await;
+
+		if let Some(parent) = self.path.parent() {
+			if !parent.as_os_str().is_empty() {
+				tokio::fs::create_dir_all(parent)
+					.await
+					.map_err(to_io_error)?;
+			}
+		}
+
+		let size = match tokio::fs::metadata(&self.path).await {
+			Ok(metadata) => metadata.len(),
+			Err(_) => 0,
+		};
+		let mut contents = self.format_record(&record, size == 0)?;
+
+		if let Some(max_bytes) = self.rotate_max_bytes {
+			if size > 0 && size + contents.len() as u64 > max_bytes {
+				self.rotate().await?;
+				contents = self.format_record(&record, true)?;
+			}
+		}
+
+		let mut file = OpenOptions::new()
+			.create(true)
+			.append(true)
+			.open(&self.path)
+			.await
+			.map_err(to_io_error)?;
+		file.write_all(contents.as_bytes())
+			.await
+			.map_err(to_io_error)?;
+		file.flush().await.map_err(to_io_error)
+	}
+}
+
+#[async_trait]
+impl Notifier for FileNotifier {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use tempfile::tempdir;
+
+	fn create_test_variables() -> HashMap<String, String> {
+		HashMap::from([
+			("monitor.name".to_string(), "usdc_transfers".to_string()),
+			("transaction.hash".to_string(), "0xabc".to_string()),
+		])
+	}
+
+	fn read_jsonl(path: &Path) -> Vec<FileRecord> {
+		std::fs::read_to_string(path)
+			.unwrap()
+			.lines()
+			.map(|line| serde_json::from_str(line).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn test_compute_rotated_file_path() {
+		assert_eq!(
+			compute_rotated_file_path(Path::new("data/audit.jsonl"), 1),
+			PathBuf::from("data/audit.1.jsonl")
+		);
+		assert_eq!(
+			compute_rotated_file_path(Path::new("audit"), 2),
+			PathBuf::from("audit.2")
+		);
+	}
+
+	#[test]
+	fn test_escape_csv_field() {
+		assert_eq!(escape_csv_field("plain"), "plain");
+		assert_eq!(escape_csv_field("a,b"), "\"a,b\"");
+		assert_eq!(escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+		assert_eq!(escape_csv_field("line\nbreak"), "\"line\nbreak\"");
+	}
+
+	#[test]
+	fn test_format_record_csv() {
+		let notifier = FileNotifier::new("audit.csv", FileFormat::Csv, None);
+		let record = FileRecord {
+			timestamp: "2025-01-01T00:00:00+00:00".to_string(),
+			monitor_match: None,
+			variables: BTreeMap::from([("monitor.name".to_string(), "usdc".to_string())]),
+		};
+
+		let with_header = notifier.format_record(&record, true).unwrap();
+		let without_header = notifier.format_record(&record, false).unwrap();
+
+		assert_eq!(
+			without_header,
+			"2025-01-01T00:00:00+00:00,,,,,\"{\"\"monitor.name\"\":\"\"usdc\"\"}\"\n"
+		);
+		assert_eq!(with_header, format!("{}{}", CSV_HEADER, without_header));
+	}
+
+	#[test]
+	fn test_from_config_invalid_type() {
+		let config = TriggerTypeConfig::Script {
+			language: crate::models::ScriptLanguage::Python,
+			script_path: "script.py".to_string(),
+			arguments: None,
+			timeout_ms: 1000,
+			max_memory_mb: None,
+			max_output_bytes: None,
+		};
+
+		let result = FileNotifier::from_config(&config);
+
+		assert!(matches!(result, Err(NotificationError::ConfigError(_))));
+	}
+
+	#[tokio::test]
+	async fn test_notify_appends_jsonl_records() {
+		let dir = tempdir().unwrap();
+		let path = dir.path().join("nested").join("audit.jsonl");
+		let notifier = FileNotifier::new(&path, FileFormat::Jsonl, None);
+
+		notifier
+			.notify(&create_test_variables(), None)
+			.await
+			.unwrap();
+		notifier
+			.notify(&create_test_variables(), None)
+			.await
+			.unwrap();
+
+		let records = read_jsonl(&path);
+		assert_eq!(records.len(), 2);
+		assert_eq!(records[0].variables["transaction.hash"], "0xabc");
+		assert!(records[0].monitor_match.is_none());
+	}
+
+	#[tokio::test]
+	async fn test_notify_json_records_can_be_read_back() {
+		let dir = tempdir().unwrap();
+		let path = dir.path().join("audit.json");
+		let notifier = FileNotifier::new(&path, FileFormat::Json, None);
+
+		notifier
+			.notify(&create_test_variables(), None)
+			.await
+			.unwrap();
+		notifier
+			.notify(&create_test_variables(), None)
+			.await
+			.unwrap();
+
+		let contents = std::fs::read_to_string(&path).unwrap();
+		let records = serde_json::Deserializer::from_str(&contents)
+			.into_iter::<FileRecord>()
+			.collect::<Result<Vec<_>, _>>()
+			.unwrap();
+		assert_eq!(records.len(), 2);
+		assert_eq!(records[1].variables["monitor.name"], "usdc_transfers");
+	}
+
+	#[tokio::test]
+	async fn test_notify_writes_csv_header_once() {
+		let dir = tempdir().unwrap();
+		let path = dir.path().join("audit.csv");
+		let notifier = FileNotifier::new(&path, FileFormat::Csv, None);
+
+		notifier
+			.notify(&create_test_variables(), None)
+			.await
+			.unwrap();
+		notifier
+			.notify(&create_test_variables(), None)
+			.await
+			.unwrap();
+
+		let contents = std::fs::read_to_string(&path).unwrap();
+		let lines: Vec<&str> = contents.lines().collect();
+		assert_eq!(lines.len(), 3);
+		assert_eq!(format!("{}\n", lines[0]), CSV_HEADER);
+	}
+
+	#[tokio::test]
+	async fn test_notify_rotates_file() {
+		let dir = tempdir().unwrap();
+		let path = dir.path().join("audit.jsonl");
+		let notifier = FileNotifier::new(&path, FileFormat::Jsonl, Some(1));
+
+		for _ in 0..3 {
+			notifier
+				.notify(&create_test_variables(), None)
+				.await
+				.unwrap();
+		}
+
+		assert_eq!(read_jsonl(&path).len(), 1);
+		assert_eq!(read_jsonl(&dir.path().join("audit.1.jsonl")).len(), 1);
+		assert_eq!(read_jsonl(&dir.path().join("audit.2.jsonl")).len(), 1);
+	}
+
+	#[tokio::test]
+	async fn test_concurrent_notify_does_not_interleave() {
+		let dir = tempdir().unwrap();
+		let path = dir.path().join("audit.jsonl");
+
+		let handles: Vec<_> = (0..20)
+			.map(|i| {
+				let notifier = FileNotifier::new(&path, FileFormat::Jsonl, None);
+				tokio::spawn(async move {
+					let variables = HashMap::from([("index".to_string(), i.to_string())]);
+					notifier.notify(&variables, None).await.unwrap();
+				})
+			})
+			.collect();
+		for handle in handles {
+			handle.await.unwrap();
+		}
+
+		let mut indexes: Vec<u32> = read_jsonl(&path)
+			.iter()
+			.map(|record| record.variables["index"].parse().unwrap())
+			.collect();
+		indexes.sort();
+		assert_eq!(indexes, (0..20).collect::<Vec<_>>());
+	}
+}
@@ -0,0 +1,329 @@
+//! Kafka notification implementation.
+//!
+//! Produces a JSON record of the monitor match and notification variables to a Kafka topic,
+//! keyed by the transaction hash so that the records of a transaction land on the same
+//! partition. Producing records requires the `kafka` feature.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::{BTreeMap, HashMap},
+	time::Duration,
+};
+
+use crate::{
+	models::{KafkaSaslMechanism, MonitorMatch, TriggerTypeConfig},
+	services::{
+		filter::match_variables,
+		notification::{NotificationError, Notifier},
+	},
+};
+
+/// Timeout for the delivery of a record when the trigger doesn't configure one
+const DEFAULT_KAFKA_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A single record produced by the Kafka notifier
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KafkaRecord {
+	/// Time the record was produced, in RFC 3339 format
+	pub timestamp: String,
+	/// Monitor match the notification was sent for, absent for service-level alerts
+	pub monitor_match: Option<MonitorMatch>,
+	/// Template variables of the notification
+	pub variables: BTreeMap<String, String>,
+}
+
+impl KafkaRecord {
+	/// Creates a record for the current time
+	pub fn new(variables: &HashMap<String, String>, monitor_match: Option<&MonitorMatch>) -> Self {
+		Self {
+			timestamp: Utc::now().to_rfc3339(),
+			monitor_match: monitor_match.cloned(),
+			variables: variables
+				.iter()
+				.map(|(k, v)| (k.clone(), v.clone()))
+				.collect(),
+		}
+	}
+
+	/// Returns the key of the record, the hash of the matched transaction if any
+	pub fn key(&self) -> Option<String> {
+		self.monitor_match
+			.as_ref()
+			.and_then(|monitor_match| match_variables(monitor_match).remove("transaction.hash"))
+			.filter(|hash| !hash.is_empty())
+	}
+}
+
+/// SASL credentials of a Kafka notifier
+#[derive(Debug, Clone)]
+pub struct KafkaSasl {
+	/// SASL mechanism
+	pub mechanism: KafkaSaslMechanism,
+	/// SASL username
+	pub username: String,
+	/// SASL password
+	pub password: String,
+}
+
+/// Implementation of notifications produced to a Kafka topic
+#[derive(Debug, Clone)]
+pub struct KafkaNotifier {
+	/// Bootstrap brokers, as `host:port`
+	pub brokers: Vec<String>,
+	/// Topic records are produced to
+	pub topic: String,
+	/// SASL credentials, if any
+	pub sasl: Option<KafkaSasl>,
+	/// Timeout for the delivery of a record
+	pub timeout: Duration,
+}
+
+impl KafkaNotifier {
+	/// Creates a Kafka notifier from a trigger configuration
+	///
+	/// # Arguments
+	/// * `config` - Trigger configuration containing Kafka parameters
+	///
+	/// # Returns
+	/// * `Result<Self, NotificationError>` - Notifier instance if config is Kafka type
+	pub fn from_config(config: &TriggerTypeConfig) -> Result<Self, NotificationError> {
+		if let TriggerTypeConfig::Kafka {
+			brokers,
+			topic,
+			sasl,
+			timeout_ms,
+		} = config
+		{
+			Ok(Self {
+				brokers: brokers.clone(),
+				topic: topic.clone(),
+				sasl: sasl.as_ref().map(|sasl| KafkaSasl {
+					mechanism: sasl.mechanism,
+					username: sasl.username.as_ref().to_string(),
+					password: sasl.password.as_ref().to_string(),
+				}),
+				timeout: timeout_ms
+					.map_or(DEFAULT_KAFKA_TIMEOUT, |ms| Duration::from_millis(ms as u64)),
+			})
+		} else {
+			let msg = format!("Invalid kafka configuration: {:?}", config);
+			Err(NotificationError::config_error(msg, None, None))
+		}
+	}
+
+	/// Returns the properties of the producer of this notifier
+	pub fn producer_properties(&self) -> Vec<(&'static str, String)> {
+		let mut properties = vec![
+			("bootstrap.servers", self.brokers.join(",")),
+			("message.timeout.ms", self.timeout.as_millis().to_string()),
+		];
+		if let Some(sasl) = &self.sasl {
+			properties.extend([
+				("security.protocol", "SASL_SSL".to_string()),
+				("sasl.mechanism", sasl.mechanism.as_str().to_string()),
+				("sasl.username", sasl.username.clone()),
+				("sasl.password", sasl.password.clone()),
+			]);
+		}
+		properties
+	}
+
+	/// Produces a record for a notification to the topic
+	///
+	/// Waits for the delivery of the record, failing if it isn't acknowledged by the brokers
+	/// within the timeout.
+	///
+	/// # Arguments
+	/// * `variables` - Template variables of the notification
+	/// * `monitor_match` - Monitor match the notification was sent for, if any
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	#[cfg(feature = "kafka")]
+	pub async fn notify(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: Option<&MonitorMatch>,
+	) -> Result<(), NotificationError> {
+		use rdkafka::{producer::FutureRecord, util::Timeout};
+
+		let record = KafkaRecord::new(variables, monitor_match);
+		let key = record.key();
+		let payload = serde_json::to_string(&record).map_err(|e| {
+			NotificationError::internal_error(
+				format!("Failed to serialize Kafka record: {}", e),
+				Some(e.into()),
+				None,
+			)
+		})?;
+
+		let mut kafka_record = FutureRecord::<str, str>::to(&self.topic).payload(payload.as_str());
+		if let Some(key) = &key {
+			kafka_record = kafka_record.key(key.as_str());
+		}
+
+		self.producer()?
+			.send(kafka_record, Timeout::After(self.timeout))
+			.await
+			.map(|_| ())
+			.map_err(|(e, _)| {
+				NotificationError::notify_failed(
+					format!("Failed to produce Kafka record to {}: {}", self.topic, e),
+					Some(e.into()),
+					None,
+				)
+			})
+	}
+
+	/// Produces a record for a notification to the topic
+	///
+	/// Always fails, as the service was built without the `kafka` feature.
+	#[cfg(not(feature = "kafka"))]
+	pub async fn notify(
+		&self,
+		_variables: &HashMap<String, String>,
+		_monitor_match: Option<&MonitorMatch>,
+	) -> Result<(), NotificationError> {
+		Err(NotificationError::config_error(
+			format!(
+				"Failed to produce Kafka record to {}: built without the `kafka` feature",
+				self.topic
+			),
+			None,
+			None,
+		))
+	}
+
+	/// Returns the producer of this notifier, created on first use and shared by the
+	/// notifiers with the same producer properties
+	#[cfg(feature = "kafka")]
+	fn producer(&self) -> Result<rdkafka::producer::FutureProducer, NotificationError> {
+		use lazy_static::lazy_static;
+		use rdkafka::{config::ClientConfig, producer::FutureProducer};
+		use std::sync::Mutex;
+
+		lazy_static! {
+			/// Producers by producer properties
+			static ref PRODUCERS: Mutex<HashMap<String, FutureProducer>> =
+				Mutex::new(HashMap::new());
+		}
+
+		let properties = self.producer_properties();
+		let key = format!("{:?}", properties);
+		let mut producers = PRODUCERS.lock().unwrap();
+		if let Some(producer) = producers.get(&key) {
+			return Ok(producer.clone());
+		}
+
+		let mut client_config = ClientConfig::new();
+		for (name, value) in properties {
+			client_config.set(name, value);
+		}
+		let producer: FutureProducer = client_config.create().map_err(|e| {
+			NotificationError::config_error(
+				format!("Failed to create Kafka producer: {}", e),
+				Some(e.into()),
+				None,
+			)
+		})?;
+		producers.insert(key, producer.clone());
+		Ok(producer)
+	}
+}
+
+#[async_trait]
+impl Notifier for KafkaNotifier {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, MatchConditions},
+		utils::tests::{
+			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+			trigger::TriggerBuilder,
+		},
+	};
+
+	fn create_match() -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("Large Transfers").build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: None,
+			logs: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			confirmation_state: Default::default(),
+			severity: Default::default(),
+			sender_verified: None,
+		}))
+	}
+
+	#[test]
+	fn test_from_config() {
+		let trigger = TriggerBuilder::new()
+			.kafka(vec!["broker-1:9092", "broker-2:9092"], "monitor-matches")
+			.kafka_sasl(KafkaSaslMechanism::ScramSha256, "user", "pass")
+			.timeout_ms(1000)
+			.build();
+
+		let notifier = KafkaNotifier::from_config(&trigger.config).unwrap();
+
+		assert_eq!(notifier.topic, "monitor-matches");
+		assert_eq!(notifier.timeout, Duration::from_secs(1));
+		assert_eq!(
+			notifier.producer_properties(),
+			vec![
+				(
+					"bootstrap.servers",
+					"broker-1:9092,broker-2:9092".to_string()
+				),
+				("message.timeout.ms", "1000".to_string()),
+				("security.protocol", "SASL_SSL".to_string()),
+				("sasl.mechanism", "SCRAM-SHA-256".to_string()),
+				("sasl.username", "user".to_string()),
+				("sasl.password", "pass".to_string()),
+			]
+		);
+	}
+
+	#[test]
+	fn test_from_config_defaults_timeout() {
+		let trigger = TriggerBuilder::new()
+			.kafka(vec!["localhost:9092"], "monitor-matches")
+			.build();
+
+		let notifier = KafkaNotifier::from_config(&trigger.config).unwrap();
+
+		assert_eq!(notifier.timeout, DEFAULT_KAFKA_TIMEOUT);
+		assert_eq!(notifier.producer_properties().len(), 2);
+	}
+
+	#[test]
+	fn test_from_config_invalid_type() {
+		let trigger = TriggerBuilder::new().file("data/audit.jsonl").build();
+
+		assert!(matches!(
+			KafkaNotifier::from_config(&trigger.config),
+			Err(NotificationError::ConfigError(_))
+		));
+	}
+
+	#[test]
+	fn test_record_is_keyed_by_transaction_hash() {
+		let monitor_match = create_match();
+		let hash = match_variables(&monitor_match)["transaction.hash"].clone();
+		let variables =
+			HashMap::from([("monitor.name".to_string(), "Large Transfers".to_string())]);
+
+		let record = KafkaRecord::new(&variables, Some(&monitor_match));
+		assert_eq!(record.key(), Some(hash));
+		assert_eq!(record.variables["monitor.name"], "Large Transfers");
+
+		let alert = KafkaRecord::new(&variables, None);
+		assert_eq!(alert.key(), None);
+	}
+}
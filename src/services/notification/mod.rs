@@ -4,33 +4,47 @@
 //! Supports variable substitution in message templates.
 
 use async_trait::async_trait;
+use lettre::{AsyncSmtpTransport, Tokio1Executor};
+use reqwest_middleware::ClientWithMiddleware;
 
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, future::Future, sync::Arc, time::Duration};
 
+mod digest;
 mod email;
 mod error;
+mod file;
+mod kafka;
+mod opsgenie;
 pub mod payload_builder;
 mod pool;
 mod script;
+mod template_engine;
 mod template_formatter;
 mod webhook;
 
 use crate::{
 	models::{
 		MonitorMatch, NotificationMessage, ScriptLanguage, Trigger, TriggerType, TriggerTypeConfig,
+		WebhookSignatureAlgorithm,
+	},
+	utils::{
+		metrics::NOTIFICATIONS_SENT_TOTAL, normalize_string, system_clock, RetryConfig, SharedClock,
 	},
-	utils::{normalize_string, RetryConfig},
 };
 
+pub use digest::{Digest, DigestBuffer, MAX_DIGEST_ENTRIES};
 pub use email::{EmailContent, EmailNotifier, SmtpConfig};
 pub use error::NotificationError;
+pub use file::{compute_rotated_file_path, FileMatchSummary, FileNotifier, FileRecord};
+pub use kafka::{KafkaNotifier, KafkaRecord, KafkaSasl};
+pub use opsgenie::{OpsgenieConfig, OpsgenieNotifier};
 pub use payload_builder::{
 	DiscordPayloadBuilder, GenericWebhookPayloadBuilder, SlackPayloadBuilder,
 	TelegramPayloadBuilder, WebhookPayloadBuilder,
 };
 pub use pool::NotificationClientPool;
 pub use script::ScriptNotifier;
-pub use webhook::{WebhookConfig, WebhookNotifier};
+pub use webhook::{parse_ed25519_signing_key, WebhookConfig, WebhookNotifier};
 
 /// A container for all components needed to configure and send a webhook notification.
 struct WebhookComponents {
@@ -79,6 +93,7 @@ impl AsWebhookComponents for TriggerTypeConfig {
 			TriggerTypeConfig::Discord {
 				discord_url,
 				message,
+				embed,
 				..
 			} => (
 				discord_url.as_ref().to_string(),
@@ -86,13 +101,17 @@ impl AsWebhookComponents for TriggerTypeConfig {
 				Some("POST".to_string()),
 				None,
 				None,
-				Box::new(DiscordPayloadBuilder),
+				Box::new(DiscordPayloadBuilder {
+					embed: embed.clone(),
+				}),
 			),
 			TriggerTypeConfig::Telegram {
 				token,
 				message,
 				chat_id,
 				disable_web_preview,
+				message_thread_id,
+				parse_mode,
 				..
 			} => (
 				format!("https://api.telegram.org/bot{}/sendMessage", token.as_ref()),
@@ -103,17 +122,24 @@ impl AsWebhookComponents for TriggerTypeConfig {
 				Box::new(TelegramPayloadBuilder {
 					chat_id: chat_id.clone(),
 					disable_web_preview: disable_web_preview.unwrap_or(false),
+					message_thread_id: *message_thread_id,
+					parse_mode: parse_mode.unwrap_or_default(),
 				}),
 			),
 			TriggerTypeConfig::Slack {
-				slack_url, message, ..
+				slack_url,
+				message,
+				blocks,
+				..
 			} => (
 				slack_url.as_ref().to_string(),
 				message.clone(),
 				Some("POST".to_string()),
 				None,
 				None,
-				Box::new(SlackPayloadBuilder),
+				Box::new(SlackPayloadBuilder {
+					blocks: blocks.clone(),
+				}),
 			),
 			_ => {
 				return Err(NotificationError::config_error(
@@ -124,6 +150,30 @@ impl AsWebhookComponents for TriggerTypeConfig {
 			}
 		};
 
+		// Only generic webhooks can carry the structured monitor match
+		let (include_raw_match, trim_raw_match) = match self {
+			TriggerTypeConfig::Webhook {
+				include_raw_match,
+				trim_raw_match,
+				..
+			} => (*include_raw_match, *trim_raw_match),
+			_ => (false, false),
+		};
+		// Only generic webhooks are signed
+		let (signature_algorithm, signature_header, signature_algorithm_header) = match self {
+			TriggerTypeConfig::Webhook {
+				signature_algorithm,
+				signature_header,
+				signature_algorithm_header,
+				..
+			} => (
+				*signature_algorithm,
+				signature_header.clone(),
+				signature_algorithm_header.clone(),
+			),
+			_ => (WebhookSignatureAlgorithm::default(), None, None),
+		};
+
 		// Construct the final WebhookConfig from the extracted parts.
 		let config = WebhookConfig {
 			url,
@@ -134,20 +184,17 @@ impl AsWebhookComponents for TriggerTypeConfig {
 			headers,
 			url_params: None,
 			payload_fields: None,
+			include_raw_match,
+			trim_raw_match,
+			signature_algorithm,
+			signature_header,
+			signature_algorithm_header,
 		};
 
-		// Use the retry policy from the trigger config
-		let retry_policy = self.get_retry_policy().ok_or_else(|| {
-			NotificationError::config_error(
-				"Webhook trigger config is unexpectedly missing a retry policy.",
-				None,
-				None,
-			)
-		})?;
-
 		Ok(WebhookComponents {
 			config,
-			retry_policy,
+			// Use the retry policy from the trigger config
+			retry_policy: self.get_retry_policy(),
 			builder,
 		})
 	}
@@ -174,22 +221,89 @@ pub trait ScriptExecutor {
 	) -> Result<(), NotificationError>;
 }
 
+/// Interface of the notifiers
+///
+/// Notifiers can check that notifications could be delivered without sending one, so that
+/// misconfigured triggers are caught before a notification fails.
+#[async_trait]
+pub trait Notifier {
+	/// Checks that the notifier reaches its destination, without sending a notification
+	///
+	/// Notifiers that can't be checked are always healthy.
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success, or the error found by the check
+	async fn healthcheck(&self) -> Result<(), NotificationError> {
+		Ok(())
+	}
+}
+
+/// A notification that failed and is waiting to be retried
+#[derive(Debug, Clone)]
+pub struct FailedNotification {
+	/// Trigger sending the notification
+	pub trigger: Trigger,
+	/// Variables substituted in the trigger templates
+	pub variables: HashMap<String, String>,
+	/// Match the notification is sent for
+	pub monitor_match: MonitorMatch,
+	/// Scripts of the trigger, for script triggers
+	pub trigger_scripts: HashMap<String, (ScriptLanguage, String)>,
+	/// Error of the failed attempt
+	pub error: String,
+}
+
+/// Handler of the notifications failing in the background, such as digests
+pub type FailedNotificationHandler = Arc<dyn Fn(FailedNotification) + Send + Sync>;
+
 /// Service for managing notifications across different channels
+#[derive(Clone)]
 pub struct NotificationService {
 	/// Client pool for managing notification clients (HTTP, SMTP)
 	client_pool: Arc<NotificationClientPool>,
+	/// Open digests of the triggers with a batch window
+	digests: Arc<DigestBuffer>,
+	/// Handler of the digests that couldn't be sent, if any
+	failure_handler: Option<FailedNotificationHandler>,
 }
 
 impl NotificationService {
 	/// Creates a new notification service instance
 	pub fn new() -> Self {
+		Self::with_clock(system_clock())
+	}
+
+	/// Creates a new notification service instance reading the current time from a clock
+	///
+	/// # Arguments
+	/// * `clock` - Clock the idle time of the pooled clients is measured with
+	pub fn with_clock(clock: SharedClock) -> Self {
 		NotificationService {
-			client_pool: Arc::new(NotificationClientPool::new()),
+			client_pool: Arc::new(NotificationClientPool::with_clock(clock)),
+			digests: Arc::new(DigestBuffer::default()),
+			failure_handler: None,
 		}
 	}
 
+	/// Hands the digests that can't be sent to a handler, e.g. to retry them
+	///
+	/// Without a handler, failed digests are only logged.
+	///
+	/// # Arguments
+	/// * `failure_handler` - Handler of the failed digests
+	pub fn with_failure_handler(mut self, failure_handler: FailedNotificationHandler) -> Self {
+		self.failure_handler = Some(failure_handler);
+		self
+	}
+
 	/// Executes a notification based on the trigger configuration
 	///
+	/// Matches of a trigger with a `batch_window_ms` are collected instead of being sent right
+	/// away. The first match of a window spawns a task sending a single digest of the window's
+	/// matches once it elapses, with the first match passed as the match notified. Digests
+	/// that can't be sent are handed to the failure handler, see [`Self::with_failure_handler`],
+	/// and digests still open at shutdown are sent by [`Self::flush_digests`].
+	///
 	/// # Arguments
 	/// * `trigger` - Trigger containing the notification type and parameters
 	/// * `variables` - Variables to substitute in message templates
@@ -206,6 +320,153 @@ impl NotificationService {
 		monitor_match: &MonitorMatch,
 		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
 	) -> Result<(), NotificationError> {
+		let Some(batch_window_ms) = trigger.batch_window_ms else {
+			return self
+				.dispatch(trigger, variables, Some(monitor_match), trigger_scripts)
+				.await;
+		};
+
+		if self.digests.push(
+			trigger,
+			trigger_scripts,
+			variables.clone(),
+			monitor_match.clone(),
+		) {
+			let service = self.clone();
+			let trigger_name = trigger.name.clone();
+			tokio::spawn(async move {
+				tokio::time::sleep(Duration::from_millis(batch_window_ms)).await;
+				// The digest is already closed if it was flushed in the meantime
+				if let Some(digest) = service.digests.take(&trigger_name) {
+					service.send_digest(digest).await;
+				}
+			});
+		}
+		Ok(())
+	}
+
+	/// Executes a notification right away, even for a trigger with a batch window
+	///
+	/// Used to retry notifications, including digests, without collecting them into a digest
+	/// again.
+	///
+	/// # Arguments
+	/// * `trigger` - Trigger containing the notification type and parameters
+	/// * `variables` - Variables to substitute in message templates
+	/// * `monitor_match` - Monitor match to send
+	/// * `trigger_scripts` - Contains the script content to execute
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn execute_now(
+		&self,
+		trigger: &Trigger,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+	) -> Result<(), NotificationError> {
+		self.dispatch(trigger, variables, Some(monitor_match), trigger_scripts)
+			.await
+	}
+
+	/// Sends the open digests of all triggers without waiting for their batch window to elapse
+	///
+	/// Called at shutdown, so that the matches collected in open digests aren't lost.
+	pub async fn flush_digests(&self) {
+		let digests = self.digests.take_all();
+		if !digests.is_empty() {
+			tracing::info!("Flushing {} open digest(s)", digests.len());
+		}
+		futures::future::join_all(digests.into_iter().map(|digest| self.send_digest(digest))).await;
+	}
+
+	/// Sends a closed digest, handing it to the failure handler if it can't be sent
+	async fn send_digest(&self, digest: Digest) {
+		let Some((_, first_match)) = digest.entries.first() else {
+			return;
+		};
+		let trigger = &digest.trigger;
+		tracing::debug!(
+			"Sending digest of {} matches for trigger {}",
+			digest.count,
+			trigger.name
+		);
+
+		let variables = digest.variables();
+		if let Err(e) = self
+			.dispatch(
+				trigger,
+				&variables,
+				Some(first_match),
+				&digest.trigger_scripts,
+			)
+			.await
+		{
+			tracing::error!("Failed to send digest of trigger {}: {}", trigger.name, e);
+			if let Some(failure_handler) = &self.failure_handler {
+				failure_handler(FailedNotification {
+					trigger: trigger.clone(),
+					variables,
+					monitor_match: first_match.clone(),
+					trigger_scripts: digest.trigger_scripts.clone(),
+					error: e.to_string(),
+				});
+			}
+		}
+	}
+
+	/// Executes a notification for a service-level alert that isn't tied to a monitor match
+	///
+	/// Script triggers are not supported, as they receive the monitor match as input.
+	///
+	/// # Arguments
+	/// * `trigger` - Trigger containing the notification type and parameters
+	/// * `variables` - Variables to substitute in message templates
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn execute_alert(
+		&self,
+		trigger: &Trigger,
+		variables: &HashMap<String, String>,
+	) -> Result<(), NotificationError> {
+		self.dispatch(trigger, variables, None, &HashMap::new())
+			.await
+	}
+
+	async fn dispatch(
+		&self,
+		trigger: &Trigger,
+		variables: &HashMap<String, String>,
+		monitor_match: Option<&MonitorMatch>,
+		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+	) -> Result<(), NotificationError> {
+		let mut variables = variables.clone();
+		variables.extend(trigger.variables());
+
+		let result = self
+			.send(trigger, &variables, monitor_match, trigger_scripts)
+			.await;
+
+		let status = if result.is_ok() { "success" } else { "failure" };
+		let mut label_values = vec![trigger.trigger_type.as_str(), status];
+		label_values.extend(trigger.metric_label_values());
+		NOTIFICATIONS_SENT_TOTAL
+			.with_label_values(&label_values)
+			.inc();
+
+		result
+	}
+
+	async fn send(
+		&self,
+		trigger: &Trigger,
+		variables: &HashMap<String, String>,
+		monitor_match: Option<&MonitorMatch>,
+		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+	) -> Result<(), NotificationError> {
+		let timeout = trigger.config.get_timeout();
+
 		match &trigger.trigger_type {
 			// Match Webhook-based triggers
 			TriggerType::Slack
@@ -216,77 +477,70 @@ impl NotificationService {
 				let components = trigger.config.as_webhook_components()?;
 
 				// Get or create the HTTP client from the pool based on the retry policy
-				let http_client = self
-					.client_pool
-					.get_or_create_http_client(&components.retry_policy)
-					.await
-					.map_err(|e| {
-						NotificationError::execution_error(
-							"Failed to get or create HTTP client from pool".to_string(),
-							Some(e.into()),
-							None,
-						)
-					})?;
+				let http_client = self.http_client(&components.retry_policy).await?;
 
 				// Build the payload
 				let payload = components.builder.build_payload(
 					&components.config.title,
 					&components.config.body_template,
 					variables,
-				);
+				)?;
 
 				// Create the notifier
 				let notifier = WebhookNotifier::new(components.config, http_client)?;
 
-				notifier.notify_json(&payload).await?;
+				with_timeout(
+					timeout,
+					notifier.notify_with_payload(&payload, monitor_match),
+				)
+				.await?;
 			}
 			TriggerType::Email => {
 				// Extract SMTP configuration from the trigger
-				let smtp_config = match &trigger.config {
-					TriggerTypeConfig::Email {
-						host,
-						port,
-						username,
-						password,
-						..
-					} => SmtpConfig {
-						host: host.clone(),
-						port: port.unwrap_or(465),
-						username: username.as_ref().to_string(),
-						password: password.as_ref().to_string(),
-					},
-					_ => {
-						return Err(NotificationError::config_error(
-							"Invalid email configuration".to_string(),
-							None,
-							None,
-						));
-					}
-				};
+				let smtp_config = smtp_config(&trigger.config)?;
 
 				// Get or create the SMTP client from the pool
-				let smtp_client = self
-					.client_pool
-					.get_or_create_smtp_client(&smtp_config)
-					.await
-					.map_err(|e| {
-						NotificationError::execution_error(
-							"Failed to get SMTP client from pool".to_string(),
-							Some(e.into()),
-							None,
-						)
-					})?;
+				let smtp_client = self.smtp_client(&smtp_config).await?;
 
 				let notifier = EmailNotifier::from_config(&trigger.config, smtp_client)?;
-				let message = EmailNotifier::format_message(notifier.body_template(), variables);
-				notifier.notify(&message).await?;
+				let message = EmailNotifier::format_body(
+					notifier.body_template(),
+					variables,
+					notifier.is_html(),
+				)?;
+				let result = with_timeout(timeout, notifier.notify(&message)).await;
+				if let Err(NotificationError::NetworkError(_)) = &result {
+					// The connections of the client may all be broken, so the next notifications
+					// use a new client
+					if let Err(e) = self.client_pool.reconnect_smtp_client(&smtp_config).await {
+						tracing::warn!("Failed to reconnect SMTP client: {}", e);
+					}
+				}
+				result?;
+			}
+			TriggerType::Opsgenie => {
+				let retry_policy = trigger.config.get_retry_policy();
+
+				// Get or create the HTTP client from the pool based on the retry policy
+				let http_client = self.http_client(&retry_policy).await?;
+
+				let notifier = OpsgenieNotifier::from_trigger(trigger, http_client)?;
+				with_timeout(timeout, notifier.notify(variables)).await?;
 			}
 			TriggerType::Script => {
+				let monitor_match = monitor_match.ok_or_else(|| {
+					NotificationError::config_error(
+						"Script triggers can only be executed for a monitor match".to_string(),
+						None,
+						None,
+					)
+				})?;
 				let notifier = ScriptNotifier::from_config(&trigger.config)?;
 				let monitor_name = match monitor_match {
 					MonitorMatch::EVM(evm_match) => &evm_match.monitor.name,
 					MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor.name,
 					MonitorMatch::Midnight(midnight_match) => &midnight_match.monitor.name,
+					MonitorMatch::Solana(solana_match) => &solana_match.monitor.name,
 				};
 				let script_path = match &trigger.config {
 					TriggerTypeConfig::Script { script_path, .. } => script_path,
@@ -322,9 +576,142 @@ impl NotificationService {
 					.script_notify(monitor_match, script_content)
 					.await?;
 			}
+			TriggerType::File => {
+				let notifier = FileNotifier::from_config(&trigger.config)?;
+				notifier.notify(variables, monitor_match).await?;
+			}
+			TriggerType::Kafka => {
+				let notifier = KafkaNotifier::from_config(&trigger.config)?;
+				with_timeout(timeout, notifier.notify(variables, monitor_match)).await?;
+			}
 		}
 		Ok(())
 	}
+
+	/// Checks that a trigger can deliver its notifications, without sending one
+	///
+	/// The check is made by the notifier of the trigger, see [`Notifier::healthcheck`], and is
+	/// bounded by the trigger's timeout when one is configured.
+	///
+	/// # Arguments
+	/// * `trigger` - Trigger to check
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success, or the error found by the check
+	pub async fn healthcheck(&self, trigger: &Trigger) -> Result<(), NotificationError> {
+		let timeout = trigger.config.get_timeout();
+
+		match &trigger.trigger_type {
+			TriggerType::Slack
+			| TriggerType::Discord
+			| TriggerType::Webhook
+			| TriggerType::Telegram => {
+				let components = trigger.config.as_webhook_components()?;
+				let http_client = self.http_client(&components.retry_policy).await?;
+				let notifier = WebhookNotifier::new(components.config, http_client)?;
+				with_timeout(timeout, notifier.healthcheck()).await
+			}
+			TriggerType::Email => {
+				let smtp_client = self.smtp_client(&smtp_config(&trigger.config)?).await?;
+				let notifier = EmailNotifier::from_config(&trigger.config, smtp_client)?;
+				with_timeout(timeout, notifier.healthcheck()).await
+			}
+			TriggerType::Opsgenie => {
+				let http_client = self.http_client(&trigger.config.get_retry_policy()).await?;
+				let notifier = OpsgenieNotifier::from_trigger(trigger, http_client)?;
+				with_timeout(timeout, notifier.healthcheck()).await
+			}
+			TriggerType::Script => {
+				ScriptNotifier::from_config(&trigger.config)?
+					.healthcheck()
+					.await
+			}
+			TriggerType::File => {
+				FileNotifier::from_config(&trigger.config)?
+					.healthcheck()
+					.await
+			}
+			TriggerType::Kafka => {
+				let notifier = KafkaNotifier::from_config(&trigger.config)?;
+				with_timeout(timeout, notifier.healthcheck()).await
+			}
+		}
+	}
+
+	/// Gets or creates the pooled HTTP client of a retry policy
+	async fn http_client(
+		&self,
+		retry_policy: &RetryConfig,
+	) -> Result<Arc<ClientWithMiddleware>, NotificationError> {
+		self.client_pool
+			.get_or_create_http_client(retry_policy)
+			.await
+			.map_err(|e| {
+				NotificationError::execution_error(
+					"Failed to get or create HTTP client from pool".to_string(),
+					Some(e.into()),
+					None,
+				)
+			})
+	}
+
+	/// Gets or creates the pooled SMTP client of an SMTP server
+	async fn smtp_client(
+		&self,
+		smtp_config: &SmtpConfig,
+	) -> Result<Arc<AsyncSmtpTransport<Tokio1Executor>>, NotificationError> {
+		self.client_pool
+			.get_or_create_smtp_client(smtp_config)
+			.await
+			.map_err(|e| {
+				NotificationError::execution_error(
+					"Failed to get SMTP client from pool".to_string(),
+					Some(e.into()),
+					None,
+				)
+			})
+	}
+}
+
+/// Extracts the configuration of the SMTP server of an email trigger
+fn smtp_config(config: &TriggerTypeConfig) -> Result<SmtpConfig, NotificationError> {
+	match config {
+		TriggerTypeConfig::Email {
+			host,
+			port,
+			username,
+			password,
+			..
+		} => Ok(SmtpConfig {
+			host: host.clone(),
+			port: port.unwrap_or(465),
+			username: username.as_ref().to_string(),
+			password: password.as_ref().to_string(),
+		}),
+		_ => Err(NotificationError::config_error(
+			"Invalid email configuration".to_string(),
+			None,
+			None,
+		)),
+	}
+}
+
+/// Awaits a notifier send, bounded by the trigger's timeout when one is configured
+async fn with_timeout<F>(timeout: Option<Duration>, send: F) -> Result<(), NotificationError>
+where
+	F: Future<Output = Result<(), NotificationError>>,
+{
+	let Some(timeout) = timeout else {
+		return send.await;
+	};
+
+	tokio::time::timeout(timeout, send).await.map_err(|e| {
+		NotificationError::timeout_error(
+			format!("Notification was not sent within {}ms", timeout.as_millis()),
+			Some(e.into()),
+			None,
+		)
+	})?
 }
 
 impl Default for NotificationService {
@@ -338,9 +725,10 @@ mod tests {
 	use super::*;
 	use crate::{
 		models::{
-			AddressWithSpec, EVMMonitorMatch, EVMTransactionReceipt, EventCondition,
-			FunctionCondition, MatchConditions, Monitor, MonitorMatch, NotificationMessage,
-			ScriptLanguage, SecretString, SecretValue, TransactionCondition, TriggerType,
+			AddressWithSpec, EVMConfirmationState, EVMMonitorMatch, EVMTransactionReceipt,
+			EventCondition, FunctionCondition, MatchConditions, Monitor, MonitorMatch,
+			NotificationMessage, ScriptLanguage, SecretString, SecretValue, TelegramParseMode,
+			TransactionCondition, TriggerType,
 		},
 		utils::tests::{
 			builders::{evm::monitor::MonitorBuilder, trigger::TriggerBuilder},
@@ -389,8 +777,15 @@ mod tests {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				token_transfers: vec![],
+				block_conditions: vec![],
+				balance_conditions: vec![],
+				state_conditions: vec![],
 			},
 			matched_on_args: None,
+			confirmation_state: EVMConfirmationState::Confirmed,
+			severity: Default::default(),
+			sender_verified: None,
 		}))
 	}
 
@@ -482,6 +877,33 @@ mod tests {
 		}
 	}
 
+	#[tokio::test]
+	async fn test_webhook_notification_malformed_template() {
+		let service = NotificationService::new();
+
+		let trigger = TriggerBuilder::new()
+			.name("test_webhook")
+			.webhook("https://webhook.example.com")
+			.message("Alert", "Amount: {{format_units value}}")
+			.build();
+
+		let result = service
+			.execute(
+				&trigger,
+				&HashMap::new(),
+				&create_mock_monitor_match(),
+				&HashMap::new(),
+			)
+			.await;
+		match result {
+			Err(NotificationError::ConfigError(ctx)) => {
+				assert!(ctx.message.contains("Invalid message template"));
+				assert!(ctx.message.contains("expects 2 argument(s)"));
+			}
+			_ => panic!("Expected ConfigError"),
+		}
+	}
+
 	#[tokio::test]
 	async fn test_discord_notification_invalid_config() {
 		let service = NotificationService::new();
@@ -512,6 +934,34 @@ mod tests {
 		}
 	}
 
+	#[tokio::test]
+	async fn test_opsgenie_notification_invalid_config() {
+		let service = NotificationService::new();
+
+		let trigger = TriggerBuilder::new()
+			.name("test_opsgenie")
+			.script("invalid", ScriptLanguage::Python)
+			.trigger_type(TriggerType::Opsgenie) // Intentionally wrong config type
+			.build();
+
+		let variables = HashMap::new();
+		let result = service
+			.execute(
+				&trigger,
+				&variables,
+				&create_mock_monitor_match(),
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_err());
+		match result {
+			Err(NotificationError::ConfigError(ctx)) => {
+				assert!(ctx.message.contains("Invalid opsgenie configuration"));
+			}
+			_ => panic!("Expected ConfigError"),
+		}
+	}
+
 	#[tokio::test]
 	async fn test_telegram_notification_invalid_config() {
 		let service = NotificationService::new();
@@ -542,6 +992,26 @@ mod tests {
 		}
 	}
 
+	#[tokio::test]
+	async fn test_execute_alert_rejects_script_trigger() {
+		let service = NotificationService::new();
+
+		let trigger = TriggerBuilder::new()
+			.name("test_script")
+			.script("script.py", ScriptLanguage::Python)
+			.build();
+
+		let result = service.execute_alert(&trigger, &HashMap::new()).await;
+		match result {
+			Err(NotificationError::ConfigError(ctx)) => {
+				assert!(ctx
+					.message
+					.contains("Script triggers can only be executed for a monitor match"));
+			}
+			_ => panic!("Expected ConfigError"),
+		}
+	}
+
 	#[tokio::test]
 	async fn test_script_notification_invalid_config() {
 		let service = NotificationService::new();
@@ -585,7 +1055,9 @@ mod tests {
 				title: title.to_string(),
 				body: message.to_string(),
 			},
+			blocks: None,
 			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
 		};
 
 		let components = slack_config.as_webhook_components().unwrap();
@@ -600,7 +1072,8 @@ mod tests {
 		// Assert the builder creates the correct payload
 		let payload = components
 			.builder
-			.build_payload(title, message, &HashMap::new());
+			.build_payload(title, message, &HashMap::new())
+			.unwrap();
 		assert!(
 			payload.get("blocks").is_some(),
 			"Expected a Slack payload with 'blocks'"
@@ -623,7 +1096,9 @@ mod tests {
 				title: title.to_string(),
 				body: message.to_string(),
 			},
+			embed: None,
 			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
 		};
 
 		let components = discord_config.as_webhook_components().unwrap();
@@ -637,7 +1112,8 @@ mod tests {
 		// Assert the builder creates the correct payload
 		let payload = components
 			.builder
-			.build_payload(title, message, &HashMap::new());
+			.build_payload(title, message, &HashMap::new())
+			.unwrap();
 		assert!(
 			payload.get("content").is_some(),
 			"Expected a Discord payload with 'content'"
@@ -656,11 +1132,14 @@ mod tests {
 			token: SecretValue::Plain(SecretString::new("test-token".to_string())),
 			chat_id: "12345".to_string(),
 			disable_web_preview: Some(true),
+			message_thread_id: Some(42),
+			parse_mode: Some(TelegramParseMode::Html),
 			message: NotificationMessage {
 				title: title.to_string(),
 				body: message.to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
 		};
 
 		let components = telegram_config.as_webhook_components().unwrap();
@@ -676,9 +1155,12 @@ mod tests {
 		// Assert the builder creates the correct payload
 		let payload = components
 			.builder
-			.build_payload(title, message, &HashMap::new());
+			.build_payload(title, message, &HashMap::new())
+			.unwrap();
 		assert_eq!(payload.get("chat_id").unwrap(), "12345");
 		assert_eq!(payload.get("disable_web_page_preview").unwrap(), &true);
+		assert_eq!(payload.get("message_thread_id").unwrap(), 42);
+		assert_eq!(payload.get("parse_mode").unwrap(), "HTML");
 		assert!(payload.get("text").is_some());
 	}
 
@@ -698,6 +1180,12 @@ mod tests {
 			))),
 			headers: Some([("X-Custom".to_string(), "Value".to_string())].into()),
 			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
+			include_raw_match: false,
+			trim_raw_match: false,
+			signature_algorithm: WebhookSignatureAlgorithm::default(),
+			signature_header: None,
+			signature_algorithm_header: None,
 		};
 
 		let components = webhook_config.as_webhook_components().unwrap();
@@ -715,8 +1203,66 @@ mod tests {
 		// Assert the builder creates the correct payload
 		let payload = components
 			.builder
-			.build_payload(title, body_template, &HashMap::new());
+			.build_payload(title, body_template, &HashMap::new())
+			.unwrap();
 		assert!(payload.get("title").is_some());
 		assert!(payload.get("body").is_some());
 	}
+
+	#[test]
+	fn as_webhook_components_uses_default_retry_policy_when_unset() {
+		let webhook_config: TriggerTypeConfig = serde_json::from_value(serde_json::json!({
+			"url": { "type": "plain", "value": "https://generic.example.com" },
+			"method": "POST",
+			"message": { "title": "Title", "body": "Body" }
+		}))
+		.unwrap();
+
+		let components = webhook_config.as_webhook_components().unwrap();
+
+		assert_eq!(components.retry_policy, RetryConfig::default());
+	}
+
+	#[test]
+	fn as_webhook_components_uses_configured_retry_policy() {
+		let retry_policy = RetryConfig {
+			max_retries: 7,
+			..RetryConfig::default()
+		};
+		let webhook_config = TriggerTypeConfig::Webhook {
+			url: SecretValue::Plain(SecretString::new("https://generic.example.com".to_string())),
+			message: NotificationMessage {
+				title: "Title".to_string(),
+				body: "Body".to_string(),
+			},
+			method: Some("POST".to_string()),
+			secret: None,
+			headers: None,
+			retry_policy: retry_policy.clone(),
+			timeout_ms: None,
+			include_raw_match: false,
+			trim_raw_match: false,
+			signature_algorithm: WebhookSignatureAlgorithm::default(),
+			signature_header: None,
+			signature_algorithm_header: None,
+		};
+
+		let components = webhook_config.as_webhook_components().unwrap();
+
+		assert_eq!(components.retry_policy, retry_policy);
+	}
+
+	#[test]
+	fn get_retry_policy_defaults_for_trigger_types_without_one() {
+		let script_config = TriggerTypeConfig::Script {
+			language: ScriptLanguage::Python,
+			script_path: "script.py".to_string(),
+			arguments: None,
+			timeout_ms: 1000,
+			max_memory_mb: None,
+			max_output_bytes: None,
+		};
+
+		assert_eq!(script_config.get_retry_policy(), RetryConfig::default());
+	}
 }
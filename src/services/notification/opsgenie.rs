@@ -0,0 +1,389 @@
+//! Opsgenie notification implementation.
+//!
+//! Creates alerts through the Opsgenie Alerts API. Alerts sharing the same alias are
+//! deduplicated by Opsgenie, so the alias template can be used to group related matches.
+
+use async_trait::async_trait;
+use reqwest_middleware::ClientWithMiddleware;
+use serde_json::json;
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+	models::{
+		OpsgeniePriority, OpsgenieRegion, Trigger, TriggerTypeConfig, WebhookSignatureAlgorithm,
+	},
+	services::notification::{
+		payload_builder::format_template, NotificationError, Notifier, WebhookConfig,
+		WebhookNotifier,
+	},
+};
+
+/// Alerts API endpoint for US instances
+const OPSGENIE_US_ALERTS_URL: &str = "https://api.opsgenie.com/v2/alerts";
+
+/// Alerts API endpoint for EU instances
+const OPSGENIE_EU_ALERTS_URL: &str = "https://api.eu.opsgenie.com/v2/alerts";
+
+/// Maximum length accepted by Opsgenie for the alert message
+const OPSGENIE_MAX_MESSAGE_LENGTH: usize = 130;
+
+/// Maximum length accepted by Opsgenie for the alert alias
+const OPSGENIE_MAX_ALIAS_LENGTH: usize = 512;
+
+/// Source reported on created alerts
+const OPSGENIE_ALERT_SOURCE: &str = "OpenZeppelin Monitor";
+
+/// Represents an Opsgenie configuration
+#[derive(Clone)]
+pub struct OpsgenieConfig {
+	/// Alerts API endpoint
+	pub url: String,
+	/// API integration key
+	pub api_key: String,
+	/// Alert priority
+	pub priority: OpsgeniePriority,
+	/// Alias template used for deduplication
+	pub alias: Option<String>,
+	/// Alert message template
+	pub title: String,
+	/// Alert description template
+	pub body_template: String,
+}
+
+/// Implementation of Opsgenie notifications via the Alerts API
+#[derive(Debug)]
+pub struct OpsgenieNotifier {
+	/// Alert priority
+	pub priority: OpsgeniePriority,
+	/// Alias template used for deduplication
+	pub alias: Option<String>,
+	/// Alert message template
+	pub title: String,
+	/// Alert description template
+	pub body_template: String,
+	/// Underlying webhook notifier carrying the endpoint and auth header
+	inner: WebhookNotifier,
+}
+
+impl OpsgenieNotifier {
+	/// Creates a new Opsgenie notifier instance
+	///
+	/// # Arguments
+	/// * `config` - Opsgenie configuration
+	/// * `http_client` - HTTP client with middleware for retries
+	///
+	/// # Returns
+	/// * `Result<Self, NotificationError>` - Notifier instance if config is valid
+	pub fn new(
+		config: OpsgenieConfig,
+		http_client: Arc<ClientWithMiddleware>,
+	) -> Result<Self, NotificationError> {
+		let headers = HashMap::from([(
+			"Authorization".to_string(),
+			format!("GenieKey {}", config.api_key),
+		)]);
+
+		let inner = WebhookNotifier::new(
+			WebhookConfig {
+				url: config.url,
+				url_params: None,
+				title: config.title.clone(),
+				body_template: config.body_template.clone(),
+				method: Some("POST".to_string()),
+				secret: None,
+				headers: Some(headers),
+				payload_fields: None,
+				include_raw_match: false,
+				trim_raw_match: false,
+				signature_algorithm: WebhookSignatureAlgorithm::default(),
+				signature_header: None,
+				signature_algorithm_header: None,
+			},
+			http_client,
+		)?;
+
+		Ok(Self {
+			priority: config.priority,
+			alias: config.alias,
+			title: config.title,
+			body_template: config.body_template,
+			inner,
+		})
+	}
+
+	/// Creates an Opsgenie notifier from a trigger configuration
+	///
+	/// # Arguments
+	/// * `config` - Trigger configuration containing Opsgenie parameters
+	/// * `http_client` - HTTP client with middleware for retries
+	///
+	/// # Returns
+	/// * `Result<Self, NotificationError>` - Notifier instance if config is Opsgenie type
+	pub fn from_config(
+		config: &TriggerTypeConfig,
+		http_client: Arc<ClientWithMiddleware>,
+	) -> Result<Self, NotificationError> {
+		if let TriggerTypeConfig::Opsgenie {
+			api_key,
+			region,
+			priority,
+			alias,
+			message,
+			..
+		} = config
+		{
+			let url = match region {
+				OpsgenieRegion::Us => OPSGENIE_US_ALERTS_URL,
+				OpsgenieRegion::Eu => OPSGENIE_EU_ALERTS_URL,
+			};
+
+			let opsgenie_config = OpsgenieConfig {
+				url: url.to_string(),
+				api_key: api_key.as_ref().to_string(),
+				priority: *priority,
+				alias: alias.clone(),
+				title: message.title.clone(),
+				body_template: message.body.clone(),
+			};
+
+			OpsgenieNotifier::new(opsgenie_config, http_client)
+		} else {
+			let msg = format!("Invalid opsgenie configuration: {:?}", config);
+			Err(NotificationError::config_error(msg, None, None))
+		}
+	}
+
+	/// Creates an Opsgenie notifier from a trigger
+	///
+	/// The trigger severity, when set, takes precedence over the configured priority.
+	///
+	/// # Arguments
+	/// * `trigger` - Trigger with an Opsgenie configuration
+	/// * `http_client` - HTTP client with middleware for retries
+	///
+	/// # Returns
+	/// * `Result<Self, NotificationError>` - Notifier instance if config is Opsgenie type
+	pub fn from_trigger(
+		trigger: &Trigger,
+		http_client: Arc<ClientWithMiddleware>,
+	) -> Result<Self, NotificationError> {
+		let mut notifier = Self::from_config(&trigger.config, http_client)?;
+		if let Some(severity) = trigger.severity {
+			notifier.priority = severity.into();
+		}
+		Ok(notifier)
+	}
+
+	/// Builds the alert payload with template variables substituted
+	///
+	/// The message and alias are truncated to the lengths accepted by Opsgenie.
+	///
+	/// # Arguments
+	/// * `variables` - Variables to substitute in the templates
+	///
+	/// # Returns
+	/// * `Result<serde_json::Value, NotificationError>` - Alert creation payload, or an error if
+	///   a template is malformed
+	pub fn build_payload(
+		&self,
+		variables: &HashMap<String, String>,
+	) -> Result<serde_json::Value, NotificationError> {
+		let message: String = format_template(&self.title, variables)?
+			.chars()
+			.take(OPSGENIE_MAX_MESSAGE_LENGTH)
+			.collect();
+		let description = format_template(&self.body_template, variables)?;
+
+		let mut payload = json!({
+			"message": message,
+			"description": description,
+			"priority": self.priority.as_str(),
+			"source": OPSGENIE_ALERT_SOURCE,
+		});
+
+		if let Some(alias) = &self.alias {
+			let alias: String = format_template(alias, variables)?
+				.chars()
+				.take(OPSGENIE_MAX_ALIAS_LENGTH)
+				.collect();
+			if !alias.trim().is_empty() {
+				payload["alias"] = json!(alias);
+			}
+		}
+
+		Ok(payload)
+	}
+
+	/// Creates an Opsgenie alert
+	///
+	/// # Arguments
+	/// * `variables` - Variables to substitute in the templates
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify(
+		&self,
+		variables: &HashMap<String, String>,
+	) -> Result<(), NotificationError> {
+		let payload = self.build_payload(variables)?;
+		self.inner.notify_json(&payload).await
+	}
+}
+
+#[async_trait]
+impl Notifier for OpsgenieNotifier {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{NotificationMessage, NotificationSeverity, SecretString, SecretValue},
+		utils::{
+			tests::{builders::trigger::TriggerBuilder, create_test_http_client},
+			RetryConfig,
+		},
+	};
+
+	fn create_test_notifier(alias: Option<&str>) -> OpsgenieNotifier {
+		OpsgenieNotifier::new(
+			OpsgenieConfig {
+				url: OPSGENIE_US_ALERTS_URL.to_string(),
+				api_key: "test-key".to_string(),
+				priority: OpsgeniePriority::P1,
+				alias: alias.map(|a| a.to_string()),
+				title: "Alert for ${monitor.name}".to_string(),
+				body_template: "Transaction ${transaction.hash}".to_string(),
+			},
+			create_test_http_client(),
+		)
+		.unwrap()
+	}
+
+	fn create_test_opsgenie_config(region: OpsgenieRegion) -> TriggerTypeConfig {
+		TriggerTypeConfig::Opsgenie {
+			api_key: SecretValue::Plain(SecretString::new("test-key".to_string())),
+			region,
+			priority: OpsgeniePriority::P2,
+			alias: None,
+			message: NotificationMessage {
+				title: "Alert".to_string(),
+				body: "Body".to_string(),
+			},
+			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
+		}
+	}
+
+	#[test]
+	fn test_build_payload_formats_templates() {
+		let notifier = create_test_notifier(Some("${monitor.name}-${transaction.hash}"));
+		let variables = HashMap::from([
+			("monitor.name".to_string(), "usdc".to_string()),
+			("transaction.hash".to_string(), "0xabc".to_string()),
+		]);
+
+		let payload = notifier.build_payload(&variables).unwrap();
+
+		assert_eq!(payload["message"], "Alert for usdc");
+		assert_eq!(payload["description"], "Transaction 0xabc");
+		assert_eq!(payload["priority"], "P1");
+		assert_eq!(payload["alias"], "usdc-0xabc");
+		assert_eq!(payload["source"], OPSGENIE_ALERT_SOURCE);
+	}
+
+	#[test]
+	fn test_build_payload_without_alias() {
+		let notifier = create_test_notifier(None);
+		let payload = notifier.build_payload(&HashMap::new()).unwrap();
+
+		assert!(payload.get("alias").is_none());
+	}
+
+	#[test]
+	fn test_build_payload_truncates_message() {
+		let mut notifier = create_test_notifier(None);
+		notifier.title = "a".repeat(OPSGENIE_MAX_MESSAGE_LENGTH + 10);
+
+		let payload = notifier.build_payload(&HashMap::new()).unwrap();
+
+		assert_eq!(
+			payload["message"].as_str().unwrap().len(),
+			OPSGENIE_MAX_MESSAGE_LENGTH
+		);
+	}
+
+	#[test]
+	fn test_from_config_region_url() {
+		let notifier = OpsgenieNotifier::from_config(
+			&create_test_opsgenie_config(OpsgenieRegion::Eu),
+			create_test_http_client(),
+		)
+		.unwrap();
+
+		assert_eq!(notifier.inner.url, OPSGENIE_EU_ALERTS_URL);
+		assert_eq!(notifier.priority, OpsgeniePriority::P2);
+		assert_eq!(
+			notifier
+				.inner
+				.headers
+				.as_ref()
+				.unwrap()
+				.get("Authorization")
+				.unwrap(),
+			"GenieKey test-key"
+		);
+	}
+
+	#[test]
+	fn test_from_config_invalid_type() {
+		let config = TriggerTypeConfig::Discord {
+			discord_url: SecretValue::Plain(SecretString::new(
+				"https://discord.com/api/webhooks/123".to_string(),
+			)),
+			message: NotificationMessage {
+				title: "Alert".to_string(),
+				body: "Body".to_string(),
+			},
+			embed: None,
+			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
+		};
+
+		let result = OpsgenieNotifier::from_config(&config, create_test_http_client());
+
+		assert!(matches!(result, Err(NotificationError::ConfigError(_))));
+	}
+
+	#[test]
+	fn test_from_trigger_severity_overrides_priority() {
+		for (severity, priority) in [
+			(NotificationSeverity::Critical, "P1"),
+			(NotificationSeverity::Warning, "P3"),
+			(NotificationSeverity::Info, "P5"),
+		] {
+			let trigger = TriggerBuilder::new()
+				.opsgenie("test-key")
+				.opsgenie_priority(OpsgeniePriority::P2)
+				.severity(severity)
+				.build();
+
+			let notifier =
+				OpsgenieNotifier::from_trigger(&trigger, create_test_http_client()).unwrap();
+			let payload = notifier.build_payload(&HashMap::new()).unwrap();
+
+			assert_eq!(payload["priority"], priority);
+		}
+	}
+
+	#[test]
+	fn test_from_trigger_without_severity_keeps_priority() {
+		let trigger = TriggerBuilder::new()
+			.opsgenie("test-key")
+			.opsgenie_priority(OpsgeniePriority::P2)
+			.build();
+
+		let notifier = OpsgenieNotifier::from_trigger(&trigger, create_test_http_client()).unwrap();
+
+		assert_eq!(notifier.priority, OpsgeniePriority::P2);
+	}
+}
@@ -6,7 +6,8 @@ use regex::Regex;
 use serde_json::json;
 use std::collections::HashMap;
 
-use super::template_formatter;
+use super::{template_formatter, NotificationError};
+use crate::models::{DiscordEmbed, TelegramParseMode};
 
 /// Trait for building webhook payloads.
 pub trait WebhookPayloadBuilder: Send + Sync {
@@ -15,27 +16,62 @@ pub trait WebhookPayloadBuilder: Send + Sync {
 	/// # Arguments
 	///
 	/// * `title` - The raw title of the message.
-	/// * `body_template` - The message body template with variables like `${...}`.
+	/// * `body_template` - The message body template with variables like `{{...}}` or `${...}`.
 	/// * `variables` - The map of variables to substitute into the template.
 	///
 	/// # Returns
 	///
-	/// A `serde_json::Value` representing the payload.
+	/// A `serde_json::Value` representing the payload, or an error if a template is malformed.
 	fn build_payload(
 		&self,
 		title: &str,
 		body_template: &str,
 		variables: &HashMap<String, String>,
-	) -> serde_json::Value;
+	) -> Result<serde_json::Value, NotificationError>;
 }
 
 /// Formats a message by substituting variables in the template.
-pub fn format_template(template: &str, variables: &HashMap<String, String>) -> String {
+pub fn format_template(
+	template: &str,
+	variables: &HashMap<String, String>,
+) -> Result<String, NotificationError> {
 	template_formatter::format_template(template, variables)
 }
 
 /// A payload builder for Slack.
-pub struct SlackPayloadBuilder;
+#[derive(Default)]
+pub struct SlackPayloadBuilder {
+	/// Block Kit blocks the message is sent as, a section with the title and body is sent when
+	/// not set
+	pub blocks: Option<Vec<serde_json::Value>>,
+}
+
+impl SlackPayloadBuilder {
+	/// Substitutes variables in every string of a block, keeping its structure.
+	fn format_block(
+		block: &serde_json::Value,
+		variables: &HashMap<String, String>,
+	) -> Result<serde_json::Value, NotificationError> {
+		Ok(match block {
+			serde_json::Value::String(text) => {
+				serde_json::Value::String(format_template(text, variables)?)
+			}
+			serde_json::Value::Array(items) => serde_json::Value::Array(
+				items
+					.iter()
+					.map(|item| Self::format_block(item, variables))
+					.collect::<Result<_, _>>()?,
+			),
+			serde_json::Value::Object(fields) => serde_json::Value::Object(
+				fields
+					.iter()
+					.map(|(key, value)| Ok((key.clone(), Self::format_block(value, variables)?)))
+					.collect::<Result<_, NotificationError>>()?,
+			),
+			value => value.clone(),
+		})
+	}
+}
 
 impl WebhookPayloadBuilder for SlackPayloadBuilder {
 	fn build_payload(
@@ -43,11 +79,24 @@ impl WebhookPayloadBuilder for SlackPayloadBuilder {
 		title: &str,
 		body_template: &str,
 		variables: &HashMap<String, String>,
-	) -> serde_json::Value {
-		let formatted_title = format_template(title, variables);
-		let formatted_message = format_template(body_template, variables);
+	) -> Result<serde_json::Value, NotificationError> {
+		let formatted_title = format_template(title, variables)?;
+		let formatted_message = format_template(body_template, variables)?;
 		let full_message = format!("*{}*\n\n{}", formatted_title, formatted_message);
-		json!({
+
+		// The text is still sent with blocks, as Slack shows it in notifications
+		if let Some(blocks) = &self.blocks {
+			let blocks = blocks
+				.iter()
+				.map(|block| Self::format_block(block, variables))
+				.collect::<Result<Vec<_>, _>>()?;
+			return Ok(json!({
+				"text": full_message,
+				"blocks": blocks
+			}));
+		}
+
+		Ok(json!({
 			"blocks": [
 				{
 					"type": "section",
@@ -57,12 +106,53 @@ impl WebhookPayloadBuilder for SlackPayloadBuilder {
 					}
 				}
 			]
-		})
+		}))
 	}
 }
 
+/// Maximum lengths accepted by Discord for embed texts
+const DISCORD_EMBED_MAX_TITLE_LENGTH: usize = 256;
+const DISCORD_EMBED_MAX_DESCRIPTION_LENGTH: usize = 4096;
+const DISCORD_EMBED_MAX_FIELD_NAME_LENGTH: usize = 256;
+const DISCORD_EMBED_MAX_FIELD_VALUE_LENGTH: usize = 1024;
+
+/// Stand-in for empty embed field texts, which Discord rejects
+const DISCORD_EMPTY_FIELD_TEXT: &str = "\u{200B}";
+
 /// A payload builder for Discord.
-pub struct DiscordPayloadBuilder;
+#[derive(Default)]
+pub struct DiscordPayloadBuilder {
+	/// Rich embed the message is sent as, plain content is sent when not set
+	pub embed: Option<DiscordEmbed>,
+}
+
+impl DiscordPayloadBuilder {
+	/// Substitutes variables in an embed text and truncates it to the length Discord accepts.
+	fn format_embed_text(
+		template: &str,
+		variables: &HashMap<String, String>,
+		max_length: usize,
+	) -> Result<String, NotificationError> {
+		Ok(format_template(template, variables)?
+			.chars()
+			.take(max_length)
+			.collect())
+	}
+
+	/// Formats an embed field text, replacing empty results that Discord would reject.
+	fn format_embed_field_text(
+		template: &str,
+		variables: &HashMap<String, String>,
+		max_length: usize,
+	) -> Result<String, NotificationError> {
+		let text = Self::format_embed_text(template, variables, max_length)?;
+		Ok(if text.trim().is_empty() {
+			DISCORD_EMPTY_FIELD_TEXT.to_string()
+		} else {
+			text
+		})
+	}
+}
 
 impl WebhookPayloadBuilder for DiscordPayloadBuilder {
 	fn build_payload(
@@ -70,31 +160,131 @@ impl WebhookPayloadBuilder for DiscordPayloadBuilder {
 		title: &str,
 		body_template: &str,
 		variables: &HashMap<String, String>,
-	) -> serde_json::Value {
-		let formatted_title = format_template(title, variables);
-		let formatted_message = format_template(body_template, variables);
-		let full_message = format!("*{}*\n\n{}", formatted_title, formatted_message);
-		json!({
-			"content": full_message
-		})
+	) -> Result<serde_json::Value, NotificationError> {
+		let Some(embed) = &self.embed else {
+			let formatted_title = format_template(title, variables)?;
+			let formatted_message = format_template(body_template, variables)?;
+			let full_message = format!("*{}*\n\n{}", formatted_title, formatted_message);
+			return Ok(json!({
+				"content": full_message
+			}));
+		};
+
+		let fields = embed
+			.fields
+			.iter()
+			.map(|field| {
+				Ok(json!({
+					"name": Self::format_embed_field_text(
+						&field.name,
+						variables,
+						DISCORD_EMBED_MAX_FIELD_NAME_LENGTH,
+					)?,
+					"value": Self::format_embed_field_text(
+						&field.value,
+						variables,
+						DISCORD_EMBED_MAX_FIELD_VALUE_LENGTH,
+					)?,
+					"inline": field.inline,
+				}))
+			})
+			.collect::<Result<Vec<serde_json::Value>, NotificationError>>()?;
+
+		let mut embed_payload = json!({
+			"title": Self::format_embed_text(
+				embed.title.as_deref().unwrap_or(title),
+				variables,
+				DISCORD_EMBED_MAX_TITLE_LENGTH,
+			)?,
+			"description": Self::format_embed_text(
+				body_template,
+				variables,
+				DISCORD_EMBED_MAX_DESCRIPTION_LENGTH,
+			)?,
+			"fields": fields,
+		});
+		if let Some(color) = embed.color {
+			embed_payload["color"] = json!(color);
+		}
+
+		Ok(json!({
+			"embeds": [embed_payload]
+		}))
 	}
 }
 
+/// Characters that must be escaped in Telegram MarkdownV2 text
+const MARKDOWN_V2_SPECIAL: &[char] = &[
+	'_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.', '!', '\\',
+];
+
+/// Delimiters of the placeholders standing in for variable values while a MarkdownV2 template
+/// is escaped. Private use characters never need escaping and don't occur in templates.
+const PLACEHOLDER_START: char = '\u{E000}';
+const PLACEHOLDER_END: char = '\u{E001}';
+
 /// A payload builder for Telegram.
 pub struct TelegramPayloadBuilder {
 	pub chat_id: String,
 	pub disable_web_preview: bool,
+	/// Forum topic (thread) the message is sent to
+	pub message_thread_id: Option<i64>,
+	pub parse_mode: TelegramParseMode,
 }
 
 impl TelegramPayloadBuilder {
+	/// Escapes every MarkdownV2 special character, so the text is rendered literally.
+	fn escape_markdown_v2_text(text: &str) -> String {
+		let mut out = String::with_capacity(text.len() * 2);
+		for c in text.chars() {
+			if MARKDOWN_V2_SPECIAL.contains(&c) {
+				out.push('\\');
+			}
+			out.push(c);
+		}
+		out
+	}
+
+	/// Escapes the characters that have a meaning in Telegram HTML.
+	fn escape_html(text: &str) -> String {
+		text.replace('&', "&amp;")
+			.replace('<', "&lt;")
+			.replace('>', "&gt;")
+	}
+
+	/// Formats a MarkdownV2 template.
+	///
+	/// The template is escaped while preserving its own entities, whereas variable values are
+	/// escaped entirely so user data can't add or break formatting.
+	fn format_markdown_v2(
+		template: &str,
+		variables: &HashMap<String, String>,
+	) -> Result<String, NotificationError> {
+		let mut values: Vec<String> = Vec::new();
+		let formatted =
+			template_formatter::format_template_with(template, variables, &mut |value| {
+				values.push(Self::escape_markdown_v2_text(value));
+				format!(
+					"{}{}{}",
+					PLACEHOLDER_START,
+					values.len() - 1,
+					PLACEHOLDER_END
+				)
+			})?;
+
+		let mut message = Self::escape_markdown_v2(&formatted);
+		for (index, value) in values.iter().enumerate() {
+			message = message.replace(
+				&format!("{}{}{}", PLACEHOLDER_START, index, PLACEHOLDER_END),
+				value,
+			);
+		}
+		Ok(message)
+	}
+
 	/// Escape a full MarkdownV2 message, preserving entities and
 	/// escaping *all* special chars inside link URLs too.
 	fn escape_markdown_v2(text: &str) -> String {
-		const SPECIAL: &[char] = &[
-			'_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.',
-			'!', '\\',
-		];
-
 		let re =
 			Regex::new(r"(?s)```.*?```|`[^`]*`|\*[^*]*\*|_[^_]*_|~[^~]*~|\[([^\]]+)\]\(([^)]+)\)")
 				.unwrap();
@@ -106,7 +296,7 @@ impl TelegramPayloadBuilder {
 			let mat = caps.get(0).unwrap();
 
 			for c in text[last..mat.start()].chars() {
-				if SPECIAL.contains(&c) {
+				if MARKDOWN_V2_SPECIAL.contains(&c) {
 					out.push('\\');
 				}
 				out.push(c);
@@ -115,14 +305,14 @@ impl TelegramPayloadBuilder {
 			if let (Some(lbl), Some(url)) = (caps.get(1), caps.get(2)) {
 				let mut esc_label = String::with_capacity(lbl.as_str().len() * 2);
 				for c in lbl.as_str().chars() {
-					if SPECIAL.contains(&c) {
+					if MARKDOWN_V2_SPECIAL.contains(&c) {
 						esc_label.push('\\');
 					}
 					esc_label.push(c);
 				}
 				let mut esc_url = String::with_capacity(url.as_str().len() * 2);
 				for c in url.as_str().chars() {
-					if SPECIAL.contains(&c) {
+					if MARKDOWN_V2_SPECIAL.contains(&c) {
 						esc_url.push('\\');
 					}
 					esc_url.push(c);
@@ -141,7 +331,7 @@ impl TelegramPayloadBuilder {
 		}
 
 		for c in text[last..].chars() {
-			if SPECIAL.contains(&c) {
+			if MARKDOWN_V2_SPECIAL.contains(&c) {
 				out.push('\\');
 			}
 			out.push(c);
@@ -157,22 +347,53 @@ impl WebhookPayloadBuilder for TelegramPayloadBuilder {
 		title: &str,
 		body_template: &str,
 		variables: &HashMap<String, String>,
-	) -> serde_json::Value {
-		// First, substitute variables.
-		let formatted_title = format_template(title, variables);
-		let formatted_message = format_template(body_template, variables);
-
-		// Then, escape both the title and the formatted message for Telegram MarkdownV2.
-		let escaped_title = Self::escape_markdown_v2(&formatted_title);
-		let escaped_message = Self::escape_markdown_v2(&formatted_message);
+	) -> Result<serde_json::Value, NotificationError> {
+		let (full_message, parse_mode) = match self.parse_mode {
+			TelegramParseMode::MarkdownV2 => (
+				format!(
+					"*{}* \n\n{}",
+					Self::format_markdown_v2(title, variables)?,
+					Self::format_markdown_v2(body_template, variables)?
+				),
+				Some("MarkdownV2"),
+			),
+			TelegramParseMode::Html => {
+				let mut escape = |value: &str| Self::escape_html(value);
+				(
+					format!(
+						"<b>{}</b>\n\n{}",
+						template_formatter::format_template_with(title, variables, &mut escape)?,
+						template_formatter::format_template_with(
+							body_template,
+							variables,
+							&mut escape
+						)?
+					),
+					Some("HTML"),
+				)
+			}
+			TelegramParseMode::None => (
+				format!(
+					"{}\n\n{}",
+					format_template(title, variables)?,
+					format_template(body_template, variables)?
+				),
+				None,
+			),
+		};
 
-		let full_message = format!("*{}* \n\n{}", escaped_title, escaped_message);
-		json!({
+		let mut payload = json!({
 			"chat_id": self.chat_id,
 			"text": full_message,
-			"parse_mode": "MarkdownV2",
 			"disable_web_page_preview": self.disable_web_preview
-		})
+		});
+		if let Some(parse_mode) = parse_mode {
+			payload["parse_mode"] = json!(parse_mode);
+		}
+		if let Some(message_thread_id) = self.message_thread_id {
+			payload["message_thread_id"] = json!(message_thread_id);
+		}
+		Ok(payload)
 	}
 }
 
@@ -185,19 +406,20 @@ impl WebhookPayloadBuilder for GenericWebhookPayloadBuilder {
 		title: &str,
 		body_template: &str,
 		variables: &HashMap<String, String>,
-	) -> serde_json::Value {
-		let formatted_title = format_template(title, variables);
-		let formatted_message = format_template(body_template, variables);
-		json!({
+	) -> Result<serde_json::Value, NotificationError> {
+		let formatted_title = format_template(title, variables)?;
+		let formatted_message = format_template(body_template, variables)?;
+		Ok(json!({
 			"title": formatted_title,
 			"body": formatted_message
-		})
+		}))
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::models::DiscordEmbedField;
 	use serde_json::json;
 
 	#[test]
@@ -208,7 +430,9 @@ mod tests {
 			("title_value".to_string(), "Title".to_string()),
 			("message_value".to_string(), "Message".to_string()),
 		]);
-		let payload = SlackPayloadBuilder.build_payload(title, message, &variables);
+		let payload = SlackPayloadBuilder::default()
+			.build_payload(title, message, &variables)
+			.unwrap();
 		assert_eq!(
 			payload,
 			json!({
@@ -225,6 +449,56 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_slack_payload_builder_with_blocks() {
+		let builder = SlackPayloadBuilder {
+			blocks: Some(vec![
+				json!({
+					"type": "header",
+					"text": { "type": "plain_text", "text": "${monitor.name}" }
+				}),
+				json!({ "type": "divider" }),
+				json!({
+					"type": "section",
+					"fields": [
+						{ "type": "mrkdwn", "text": "*Value*\n${value}" },
+						{ "type": "mrkdwn", "text": "*Hash*\n${hash}" }
+					]
+				}),
+			]),
+		};
+		let variables = HashMap::from([
+			("monitor.name".to_string(), "Transfers".to_string()),
+			("value".to_string(), "1000".to_string()),
+			("hash".to_string(), "0x\"abc\"".to_string()),
+		]);
+
+		let payload = builder
+			.build_payload("Alert", "Value ${value}", &variables)
+			.unwrap();
+
+		assert_eq!(
+			payload,
+			json!({
+				"text": "*Alert*\n\nValue 1000",
+				"blocks": [
+					{
+						"type": "header",
+						"text": { "type": "plain_text", "text": "Transfers" }
+					},
+					{ "type": "divider" },
+					{
+						"type": "section",
+						"fields": [
+							{ "type": "mrkdwn", "text": "*Value*\n1000" },
+							{ "type": "mrkdwn", "text": "*Hash*\n0x\"abc\"" }
+						]
+					}
+				]
+			})
+		);
+	}
+
 	#[test]
 	fn test_discord_payload_builder() {
 		let title = "Test ${title_value}";
@@ -233,7 +507,9 @@ mod tests {
 			("title_value".to_string(), "Title".to_string()),
 			("message_value".to_string(), "Message".to_string()),
 		]);
-		let payload = DiscordPayloadBuilder.build_payload(title, message, &variables);
+		let payload = DiscordPayloadBuilder::default()
+			.build_payload(title, message, &variables)
+			.unwrap();
 		assert_eq!(
 			payload,
 			json!({
@@ -242,11 +518,86 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_discord_payload_builder_with_embed() {
+		let builder = DiscordPayloadBuilder {
+			embed: Some(DiscordEmbed {
+				title: Some("Transfer on ${monitor.name}".to_string()),
+				color: Some(0xFF0000),
+				fields: vec![
+					DiscordEmbedField {
+						name: "Amount".to_string(),
+						value: "${amount}".to_string(),
+						inline: true,
+					},
+					DiscordEmbedField {
+						name: "Memo".to_string(),
+						value: "${missing}".to_string(),
+						inline: false,
+					},
+				],
+			}),
+		};
+		let variables = HashMap::from([
+			("monitor.name".to_string(), "usdc".to_string()),
+			("amount".to_string(), "1000".to_string()),
+		]);
+
+		let payload = builder
+			.build_payload("Title", "Tx ${amount}", &variables)
+			.unwrap();
+
+		assert_eq!(
+			payload,
+			json!({
+				"embeds": [{
+					"title": "Transfer on usdc",
+					"description": "Tx 1000",
+					"color": 16711680,
+					"fields": [
+						{ "name": "Amount", "value": "1000", "inline": true },
+						{ "name": "Memo", "value": "${missing}", "inline": false }
+					]
+				}]
+			})
+		);
+	}
+
+	#[test]
+	fn test_discord_payload_builder_embed_defaults() {
+		let builder = DiscordPayloadBuilder {
+			embed: Some(DiscordEmbed {
+				fields: vec![DiscordEmbedField {
+					name: "Empty".to_string(),
+					value: "${empty}".to_string(),
+					inline: false,
+				}],
+				..Default::default()
+			}),
+		};
+		let variables = HashMap::from([("empty".to_string(), "".to_string())]);
+
+		let payload = builder
+			.build_payload("Alert", &"a".repeat(5000), &variables)
+			.unwrap();
+		let embed = &payload["embeds"][0];
+
+		assert_eq!(embed["title"], "Alert");
+		assert!(embed.get("color").is_none());
+		assert_eq!(
+			embed["description"].as_str().unwrap().len(),
+			DISCORD_EMBED_MAX_DESCRIPTION_LENGTH
+		);
+		assert_eq!(embed["fields"][0]["value"], DISCORD_EMPTY_FIELD_TEXT);
+	}
+
 	#[test]
 	fn test_telegram_payload_builder() {
 		let builder = TelegramPayloadBuilder {
 			chat_id: "12345".to_string(),
 			disable_web_preview: true,
+			message_thread_id: None,
+			parse_mode: TelegramParseMode::MarkdownV2,
 		};
 		let title = "Test ${title_value}";
 		let message = "Test ${message_value}";
@@ -254,7 +605,7 @@ mod tests {
 			("title_value".to_string(), "Title".to_string()),
 			("message_value".to_string(), "Message".to_string()),
 		]);
-		let payload = builder.build_payload(title, message, &variables);
+		let payload = builder.build_payload(title, message, &variables).unwrap();
 		assert_eq!(
 			payload,
 			json!({
@@ -266,6 +617,68 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_telegram_payload_builder_escapes_markdown_v2_variables() {
+		let builder = TelegramPayloadBuilder {
+			chat_id: "12345".to_string(),
+			disable_web_preview: false,
+			message_thread_id: Some(7),
+			parse_mode: TelegramParseMode::MarkdownV2,
+		};
+		let variables = HashMap::from([
+			("name".to_string(), "*bold* [x](y)".to_string()),
+			("amount".to_string(), "1.5".to_string()),
+		]);
+		let payload = builder
+			.build_payload(
+				"Alert for ${name}",
+				"*Amount:* `${amount}` ${missing}!",
+				&variables,
+			)
+			.unwrap();
+		assert_eq!(
+			payload,
+			json!({
+				"chat_id": "12345",
+				"text": "*Alert for \\*bold\\* \\[x\\]\\(y\\)* \n\n*Amount:* `1\\.5` $\\{missing\\}\\!",
+				"parse_mode": "MarkdownV2",
+				"disable_web_page_preview": false,
+				"message_thread_id": 7
+			})
+		);
+	}
+
+	#[test]
+	fn test_telegram_payload_builder_html_and_plain_text() {
+		let variables = HashMap::from([("value".to_string(), "<b>a & b</b>".to_string())]);
+
+		let builder = TelegramPayloadBuilder {
+			chat_id: "12345".to_string(),
+			disable_web_preview: false,
+			message_thread_id: None,
+			parse_mode: TelegramParseMode::Html,
+		};
+		let payload = builder
+			.build_payload("Title", "<i>${value}</i>", &variables)
+			.unwrap();
+		assert_eq!(payload["parse_mode"], "HTML");
+		assert_eq!(
+			payload["text"],
+			"<b>Title</b>\n\n<i>&lt;b&gt;a &amp; b&lt;/b&gt;</i>"
+		);
+
+		let builder = TelegramPayloadBuilder {
+			parse_mode: TelegramParseMode::None,
+			..builder
+		};
+		let payload = builder
+			.build_payload("Title", "${value}", &variables)
+			.unwrap();
+		assert!(payload.get("parse_mode").is_none());
+		assert!(payload.get("message_thread_id").is_none());
+		assert_eq!(payload["text"], "Title\n\n<b>a & b</b>");
+	}
+
 	#[test]
 	fn test_generic_webhook_payload_builder() {
 		let title = "Test ${title_value}";
@@ -274,7 +687,9 @@ mod tests {
 			("title_value".to_string(), "Title".to_string()),
 			("message_value".to_string(), "Message".to_string()),
 		]);
-		let payload = GenericWebhookPayloadBuilder.build_payload(title, message, &variables);
+		let payload = GenericWebhookPayloadBuilder
+			.build_payload(title, message, &variables)
+			.unwrap();
 		assert_eq!(
 			payload,
 			json!({
@@ -513,7 +928,7 @@ mod tests {
 			("events.1.args.value".to_string(), "1000000000".to_string()),
 		]);
 
-		let result = format_template(template, &variables);
+		let result = format_template(template, &variables).unwrap();
 		// Since the template contains ${events}, it should get the match reasons section
 		let expected = "Transaction detected: 0x1234567890abcdef\n\n\n\n*Matched Events:*\n\n*Reason 1*\n\n*Signature:* `Transfer(address,address,uint256)`\n\n*Params:*\n\nfrom: `0x1234`\nto: `0x5678`\n\n*Reason 2*\n\n*Signature:* `Approval(address,address,uint256)`\n\n*Params:*\n\nowner: `0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6`\nspender: `0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6`\nvalue: `1000000000`";
 		assert_eq!(result, expected);
@@ -634,7 +1049,7 @@ mod tests {
 			("functions.1.args.amount".to_string(), "500000".to_string()),
 		]);
 
-		let result = template_formatter::format_template(template, &variables);
+		let result = template_formatter::format_template(template, &variables).unwrap();
 		// Since the template contains ${functions}, it should get the match reasons section
 		let expected = "Transaction detected: 0x1234567890abcdef\n\n\n\n*Matched Functions:*\n\n*Reason 1*\n\n*Signature:* `transfer(address,uint256)`\n\n*Params:*\n\namount: `1000000`\nto: `0x1234`\n\n*Reason 2*\n\n*Signature:* `approve(address,uint256)`\n\n*Params:*\n\namount: `500000`\nspender: `0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6`";
 		assert_eq!(result, expected);
@@ -681,7 +1096,7 @@ mod tests {
 			("functions.1.args.amount".to_string(), "250000".to_string()),
 		]);
 
-		let result = template_formatter::format_template(template, &variables);
+		let result = template_formatter::format_template(template, &variables).unwrap();
 		// The template contains both ${events} and ${functions}, so both sections should be included
 		// Functions are processed before events, so functions section appears first
 		let expected = "Transaction detected: 0x1234567890abcdef\n\n\n\n*Matched Functions:*\n\n*Reason 1*\n\n*Signature:* `transfer(address,uint256)`\n\n*Params:*\n\namount: `750000`\nto: `0x9abc`\n\n*Reason 2*\n\n*Signature:* `mint(uint256)`\n\n*Params:*\n\namount: `250000`\n\n\n\n*Matched Events:*\n\n*Reason 1*\n\n*Signature:* `Transfer(address,address,uint256)`\n\n*Params:*\n\nfrom: `0x1234`\nto: `0x5678`\nvalue: `1000000`\n\n*Reason 2*\n\n*Signature:* `Approval(address,address,uint256)`\n\n*Params:*\n\nowner: `0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6`\nspender: `0x742d35Cc6634C0532925a3b8D4C9db96C4b4d8b6`\nvalue: `500000`";
@@ -699,7 +1114,7 @@ mod tests {
 			// No events variables present
 		]);
 
-		let result = template_formatter::format_template(template, &variables);
+		let result = template_formatter::format_template(template, &variables).unwrap();
 		// Since there are no events, ${events} should be replaced with empty string
 		let expected = "Transaction detected: 0x1234567890abcdef\n\n";
 		assert_eq!(result, expected);
@@ -716,7 +1131,7 @@ mod tests {
 			// No functions variables present
 		]);
 
-		let result = template_formatter::format_template(template, &variables);
+		let result = template_formatter::format_template(template, &variables).unwrap();
 		// Since there are no functions, ${functions} should be replaced with empty string
 		let expected = "Transaction detected: 0x1234567890abcdef\n\n";
 		assert_eq!(result, expected);
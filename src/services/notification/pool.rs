@@ -1,15 +1,20 @@
 use crate::services::blockchain::TransientErrorRetryStrategy;
 use crate::services::notification::SmtpConfig;
 use crate::utils::client_storage::ClientStorage;
-use crate::utils::{create_retryable_http_client, RetryConfig};
+use crate::utils::{create_retryable_http_client, system_clock, RetryConfig, SharedClock};
 use lettre::Tokio1Executor;
 use lettre::{transport::smtp::authentication::Credentials, AsyncSmtpTransport};
 use reqwest::Client as ReqwestClient;
 use reqwest_middleware::ClientWithMiddleware;
-use std::sync::Arc;
-use std::time::Duration;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
+/// Idle time after which a pooled SMTP client is checked before it's reused, as SMTP servers
+/// usually close the connections idle for longer
+const SMTP_HEALTH_CHECK_IDLE_TIME: Duration = Duration::from_secs(60);
+
 #[derive(Debug, Error)]
 pub enum NotificationPoolError {
 	#[error("Failed to create HTTP client: {0}")]
@@ -27,13 +32,27 @@ pub enum NotificationPoolError {
 pub struct NotificationClientPool {
 	http_clients: ClientStorage<ClientWithMiddleware>,
 	smtp_clients: ClientStorage<AsyncSmtpTransport<Tokio1Executor>>,
+	/// Time each SMTP client was last handed out, by key
+	smtp_last_used: Mutex<HashMap<String, Instant>>,
+	/// Clock the idle time of the SMTP clients is measured with
+	clock: SharedClock,
 }
 
 impl NotificationClientPool {
 	pub fn new() -> Self {
+		Self::with_clock(system_clock())
+	}
+
+	/// Creates an empty pool measuring the idle time of the SMTP clients with a clock
+	///
+	/// # Arguments
+	/// * `clock` - Clock the idle time of the SMTP clients is measured with
+	pub fn with_clock(clock: SharedClock) -> Self {
 		Self {
 			http_clients: ClientStorage::new(),
 			smtp_clients: ClientStorage::new(),
+			smtp_last_used: Mutex::new(HashMap::new()),
+			clock,
 		}
 	}
 
@@ -99,6 +118,10 @@ impl NotificationClientPool {
 	}
 
 	/// Get or create an SMTP client for sending emails.
+	///
+	/// A client idle for longer than a minute is checked before it's reused, and replaced by a
+	/// new client when its connection to the server is broken.
+	///
 	/// # Arguments
 	/// * `smtp_config` - Configuration for the SMTP client, including host,
 	///   port, username, and password.
@@ -111,18 +134,61 @@ impl NotificationClientPool {
 		smtp_config: &SmtpConfig,
 	) -> Result<Arc<AsyncSmtpTransport<Tokio1Executor>>, NotificationPoolError> {
 		let key = format!("{:?}", smtp_config);
-		self.get_or_create_client(&key, &self.smtp_clients, || {
-			let creds =
-				Credentials::new(smtp_config.username.clone(), smtp_config.password.clone());
-			Ok(
-				AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp_config.host)
-					.map_err(|e| NotificationPoolError::SmtpClientBuildError(e.to_string()))?
-					.port(smtp_config.port)
-					.credentials(creds)
-					.build(),
-			)
-		})
-		.await
+		let now = self.clock.now();
+		let last_used = self.smtp_last_used.lock().unwrap().insert(key.clone(), now);
+		let client = self
+			.get_or_create_client(&key, &self.smtp_clients, || {
+				Self::create_smtp_client(smtp_config)
+			})
+			.await?;
+
+		let is_idle =
+			last_used.is_some_and(|at| now.duration_since(at) >= SMTP_HEALTH_CHECK_IDLE_TIME);
+		if is_idle && !matches!(client.test_connection().await, Ok(true)) {
+			tracing::debug!(
+				"SMTP connection to {}:{} is broken, reconnecting",
+				smtp_config.host,
+				smtp_config.port
+			);
+			return self.reconnect_smtp_client(smtp_config).await;
+		}
+		Ok(client)
+	}
+
+	/// Replaces the SMTP client of a configuration by a new client, dropping the connections of
+	/// the previous one
+	///
+	/// # Arguments
+	/// * `smtp_config` - Configuration for the SMTP client
+	/// # Returns
+	/// * `Result<Arc<AsyncSmtpTransport<Tokio1Executor>>, NotificationPoolError>` - The new SMTP
+	///   client, or an error if client creation fails.
+	pub async fn reconnect_smtp_client(
+		&self,
+		smtp_config: &SmtpConfig,
+	) -> Result<Arc<AsyncSmtpTransport<Tokio1Executor>>, NotificationPoolError> {
+		let key = format!("{:?}", smtp_config);
+		let client = Arc::new(Self::create_smtp_client(smtp_config)?);
+		self.smtp_clients
+			.clients
+			.write()
+			.await
+			.insert(key, client.clone());
+		Ok(client)
+	}
+
+	/// Creates an SMTP client for a configuration
+	fn create_smtp_client(
+		smtp_config: &SmtpConfig,
+	) -> Result<AsyncSmtpTransport<Tokio1Executor>, NotificationPoolError> {
+		let creds = Credentials::new(smtp_config.username.clone(), smtp_config.password.clone());
+		Ok(
+			AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp_config.host)
+				.map_err(|e| NotificationPoolError::SmtpClientBuildError(e.to_string()))?
+				.port(smtp_config.port)
+				.credentials(creds)
+				.build(),
+		)
 	}
 
 	/// Get the number of active HTTP clients in the pool
@@ -147,6 +213,7 @@ impl Default for NotificationClientPool {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::utils::MockClock;
 
 	fn create_pool() -> NotificationClientPool {
 		NotificationClientPool::new()
@@ -370,4 +437,48 @@ mod tests {
 			"Pool should still have two active SMTP clients after getting an existing one"
 		);
 	}
+
+	#[tokio::test]
+	async fn test_pool_reconnect_replaces_smtp_client() {
+		let pool = create_pool();
+		let smtp_config = SmtpConfig {
+			host: "smtp.example.com".to_string(),
+			port: 587,
+			username: "user".to_string(),
+			password: "pass".to_string(),
+		};
+
+		let client = pool.get_or_create_smtp_client(&smtp_config).await.unwrap();
+		let reconnected = pool.reconnect_smtp_client(&smtp_config).await.unwrap();
+		assert!(!Arc::ptr_eq(&client, &reconnected));
+
+		// The new client is handed out from then on
+		let client_again = pool.get_or_create_smtp_client(&smtp_config).await.unwrap();
+		assert!(Arc::ptr_eq(&reconnected, &client_again));
+		assert_eq!(pool.get_active_smtp_client_count().await, 1);
+	}
+
+	#[tokio::test]
+	async fn test_pool_checks_smtp_client_once_idle_time_expires() {
+		let clock = Arc::new(MockClock::new());
+		let pool = NotificationClientPool::with_clock(clock.clone());
+		// Nothing listens on the port, so the connection check of the client fails
+		let smtp_config = SmtpConfig {
+			host: "localhost".to_string(),
+			port: 1,
+			username: "user".to_string(),
+			password: "pass".to_string(),
+		};
+
+		let client = pool.get_or_create_smtp_client(&smtp_config).await.unwrap();
+		clock.advance(SMTP_HEALTH_CHECK_IDLE_TIME - Duration::from_secs(1));
+		let client_again = pool.get_or_create_smtp_client(&smtp_config).await.unwrap();
+		assert!(Arc::ptr_eq(&client, &client_again));
+
+		// Idle for the whole idle time since it was last handed out
+		clock.advance(SMTP_HEALTH_CHECK_IDLE_TIME);
+		let reconnected = pool.get_or_create_smtp_client(&smtp_config).await.unwrap();
+		assert!(!Arc::ptr_eq(&client, &reconnected));
+		assert_eq!(pool.get_active_smtp_client_count().await, 1);
+	}
 }
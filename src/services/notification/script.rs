@@ -2,8 +2,8 @@ use async_trait::async_trait;
 
 use crate::{
 	models::{MonitorMatch, ScriptLanguage, TriggerTypeConfig},
-	services::notification::{NotificationError, ScriptExecutor},
-	services::trigger::ScriptExecutorFactory,
+	services::notification::{NotificationError, Notifier, ScriptExecutor},
+	services::trigger::{ScriptExecutorFactory, ScriptLimits},
 };
 
 /// A notification handler that executes scripts when triggered
@@ -30,6 +30,9 @@ impl ScriptNotifier {
 	}
 }
 
+#[async_trait]
+impl Notifier for ScriptNotifier {}
+
 #[async_trait]
 impl ScriptExecutor for ScriptNotifier {
 	/// Implement the actual script notification logic
@@ -44,8 +47,13 @@ impl ScriptExecutor for ScriptNotifier {
 				language,
 				arguments,
 				timeout_ms,
+				max_memory_mb,
+				max_output_bytes,
 			} => {
-				let executor = ScriptExecutorFactory::create(language, &script_content.1);
+				let executor =
+					ScriptExecutorFactory::create(language, &script_content.1).map_err(|e| {
+						NotificationError::config_error(e.to_string(), Some(e.into()), None)
+					})?;
 
 				let result = executor
 					.execute(
@@ -53,6 +61,11 @@ impl ScriptExecutor for ScriptNotifier {
 						timeout_ms,
 						arguments.as_deref(),
 						true,
+						&ScriptLimits {
+							max_memory_mb: *max_memory_mb,
+							max_output_bytes: *max_output_bytes,
+						},
+						None,
 					)
 					.await;
 
@@ -86,8 +99,8 @@ mod tests {
 	use super::*;
 	use crate::{
 		models::{
-			EVMMonitorMatch, EVMTransactionReceipt, MatchConditions, Monitor, MonitorMatch,
-			NotificationMessage, SecretString, SecretValue, TriggerType,
+			EVMConfirmationState, EVMMonitorMatch, EVMTransactionReceipt, MatchConditions, Monitor,
+			MonitorMatch, NotificationMessage, SecretString, SecretValue, TriggerType,
 		},
 		services::notification::NotificationService,
 		utils::tests::{
@@ -103,6 +116,8 @@ mod tests {
 			script_path: "test_script.py".to_string(),
 			arguments: Some(vec!["arg1".to_string(), "arg2".to_string()]),
 			timeout_ms: 1000,
+			max_memory_mb: None,
+			max_output_bytes: None,
 		}
 	}
 
@@ -129,6 +144,9 @@ mod tests {
 			network_slug: "ethereum_mainnet".to_string(),
 			matched_on: MatchConditions::default(),
 			matched_on_args: None,
+			confirmation_state: EVMConfirmationState::Confirmed,
+			severity: Default::default(),
+			sender_verified: None,
 		}))
 	}
 
@@ -148,7 +166,9 @@ mod tests {
 				title: "Test Slack".to_string(),
 				body: "This is a test message".to_string(),
 			},
+			blocks: None,
 			retry_policy: Default::default(),
+			timeout_ms: None,
 		};
 
 		let notifier = ScriptNotifier::from_config(&config);
@@ -178,6 +198,8 @@ mod tests {
 			script_path: "test_script.py".to_string(),
 			arguments: None,
 			timeout_ms: 1000, // Timeout longer than sleep time
+			max_memory_mb: None,
+			max_output_bytes: None,
 		};
 		let notifier = ScriptNotifier::from_config(&config).unwrap();
 		let monitor_match = create_test_monitor_match();
@@ -207,6 +229,8 @@ mod tests {
 			script_path: "test_script.py".to_string(),
 			arguments: None,
 			timeout_ms: 400, // Set timeout lower than the sleep time
+			max_memory_mb: None,
+			max_output_bytes: None,
 		};
 		let notifier = ScriptNotifier::from_config(&config).unwrap();
 		let monitor_match = create_test_monitor_match();
@@ -255,6 +279,8 @@ mod tests {
 			script_path: "non_existent_script.py".to_string(), // This path won't be in the map
 			arguments: None,
 			timeout_ms: 1000,
+			max_memory_mb: None,
+			max_output_bytes: None,
 		};
 		let trigger = TriggerBuilder::new()
         .name("test_script_missing")
@@ -0,0 +1,704 @@
+//! Notification template engine.
+//!
+//! Renders message templates made of plain text and `{{ ... }}` tags:
+//! - `{{var}}` substitutes a variable, rendering nothing when it's missing
+//! - `{{var | default "n/a"}}` falls back to a literal when the value is missing or empty
+//! - `{{helper arg ...}}` calls a formatting helper, e.g. `{{format_units value 18}}`
+//! - `{{#if var}}...{{else}}...{{/if}}` renders a section depending on whether a variable is set
+//!
+//! The legacy `${var}` syntax is translated while parsing. Unknown legacy variables are kept
+//! verbatim, and `${events}` and `${functions}` expand to the match reasons sections.
+
+use alloy::primitives::U256;
+use std::collections::HashMap;
+use thiserror::Error as ThisError;
+
+use super::template_formatter::build_match_reasons;
+
+/// Error raised when a template can't be parsed or rendered
+#[derive(ThisError, Debug, Clone, PartialEq, Eq)]
+#[error("{message} at position {position}")]
+pub struct TemplateError {
+	/// Description of the problem
+	pub message: String,
+	/// Byte offset of the tag that caused the problem
+	pub position: usize,
+}
+
+impl TemplateError {
+	fn new(message: impl Into<String>, position: usize) -> Self {
+		Self {
+			message: message.into(),
+			position,
+		}
+	}
+}
+
+/// Formatting helpers available in `{{ ... }}` expressions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Helper {
+	/// `format_units value decimals` - Divides an integer by `10^decimals`, e.g. wei to ether
+	FormatUnits,
+	/// `uppercase value` - Converts a value to uppercase
+	Uppercase,
+	/// `lowercase value` - Converts a value to lowercase
+	Lowercase,
+	/// `truncate value length` - Keeps the first `length` characters of a value
+	Truncate,
+}
+
+impl Helper {
+	fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"format_units" => Some(Self::FormatUnits),
+			"uppercase" => Some(Self::Uppercase),
+			"lowercase" => Some(Self::Lowercase),
+			"truncate" => Some(Self::Truncate),
+			_ => None,
+		}
+	}
+
+	fn arity(self) -> usize {
+		match self {
+			Self::FormatUnits | Self::Truncate => 2,
+			Self::Uppercase | Self::Lowercase => 1,
+		}
+	}
+
+	fn apply(self, args: &[&str]) -> Result<String, String> {
+		match self {
+			Self::FormatUnits => {
+				let decimals = args[1].parse::<u8>().map_err(|_| {
+					format!(
+						"format_units decimals must be between 0 and 255, got '{}'",
+						args[1]
+					)
+				})?;
+				format_units(args[0], decimals)
+			}
+			Self::Uppercase => Ok(args[0].to_uppercase()),
+			Self::Lowercase => Ok(args[0].to_lowercase()),
+			Self::Truncate => {
+				let length = args[1].parse::<usize>().map_err(|_| {
+					format!(
+						"truncate length must be a positive integer, got '{}'",
+						args[1]
+					)
+				})?;
+				Ok(args[0].chars().take(length).collect())
+			}
+		}
+	}
+}
+
+/// Argument passed to a helper
+#[derive(Debug, Clone, PartialEq)]
+enum Argument {
+	Variable(String),
+	Literal(String),
+}
+
+/// Value of an expression, before filters are applied
+#[derive(Debug, Clone, PartialEq)]
+enum Term {
+	Variable(String),
+	Helper { helper: Helper, args: Vec<Argument> },
+}
+
+/// Content of a `{{ ... }}` expression tag
+#[derive(Debug, Clone, PartialEq)]
+struct Expression {
+	term: Term,
+	default: Option<String>,
+}
+
+/// Parsed template node
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+	Text(String),
+	Legacy(String),
+	Expression {
+		expression: Expression,
+		position: usize,
+	},
+	If {
+		variable: String,
+		then: Vec<Node>,
+		otherwise: Vec<Node>,
+	},
+}
+
+/// Tag ending the nodes of a section
+enum SectionEnd {
+	Else(usize),
+	EndIf(usize),
+}
+
+/// Word of an expression tag
+#[derive(Debug, Clone, PartialEq)]
+enum Word {
+	Bare(String),
+	Quoted(String),
+	Pipe,
+}
+
+/// Renders a template
+///
+/// # Arguments
+/// * `template` - The template to render
+/// * `variables` - Variables available to the template
+/// * `escape` - Applied to every substituted value, e.g. to escape markup
+///
+/// # Returns
+/// * `Result<String, TemplateError>` - The rendered template, or an error if the template is
+///   malformed or a helper fails
+pub fn render(
+	template: &str,
+	variables: &HashMap<String, String>,
+	escape: &mut dyn FnMut(&str) -> String,
+) -> Result<String, TemplateError> {
+	let nodes = parse(template)?;
+	let mut output = String::with_capacity(template.len());
+	render_nodes(&nodes, variables, escape, &mut output)?;
+	Ok(output)
+}
+
+fn parse(template: &str) -> Result<Vec<Node>, TemplateError> {
+	let mut position = 0;
+	let (nodes, end) = parse_section(template, &mut position)?;
+	match end {
+		None => Ok(nodes),
+		Some(SectionEnd::Else(position)) => Err(TemplateError::new(
+			"'{{else}}' outside of an '{{#if}}' block",
+			position,
+		)),
+		Some(SectionEnd::EndIf(position)) => Err(TemplateError::new(
+			"'{{/if}}' without a matching '{{#if}}'",
+			position,
+		)),
+	}
+}
+
+/// Parses nodes until the end of the template or an `{{else}}` or `{{/if}}` tag
+fn parse_section(
+	template: &str,
+	position: &mut usize,
+) -> Result<(Vec<Node>, Option<SectionEnd>), TemplateError> {
+	let mut nodes = Vec::new();
+	let mut text = String::new();
+
+	loop {
+		let rest = &template[*position..];
+		let Some(offset) = [rest.find("${"), rest.find("{{")]
+			.into_iter()
+			.flatten()
+			.min()
+		else {
+			text.push_str(rest);
+			*position = template.len();
+			break;
+		};
+		text.push_str(&rest[..offset]);
+		let start = *position + offset;
+		let content_start = start + 2;
+
+		if rest[offset..].starts_with("${") {
+			match template[content_start..].find('}') {
+				Some(length) => {
+					push_text(&mut nodes, &mut text);
+					let name = &template[content_start..content_start + length];
+					nodes.push(Node::Legacy(name.to_string()));
+					*position = content_start + length + 1;
+				}
+				None => {
+					text.push_str("${");
+					*position = content_start;
+				}
+			}
+			continue;
+		}
+
+		let Some(length) = template[content_start..].find("}}") else {
+			return Err(TemplateError::new("unclosed '{{'", start));
+		};
+		let tag = template[content_start..content_start + length].trim();
+		*position = content_start + length + 2;
+		push_text(&mut nodes, &mut text);
+
+		if let Some(block) = tag.strip_prefix('#') {
+			let variable = parse_if(block).map_err(|e| TemplateError::new(e, start))?;
+			let (then, end) = parse_section(template, position)?;
+			let otherwise = match end {
+				Some(SectionEnd::EndIf(_)) => Vec::new(),
+				Some(SectionEnd::Else(_)) => match parse_section(template, position)? {
+					(otherwise, Some(SectionEnd::EndIf(_))) => otherwise,
+					(_, Some(SectionEnd::Else(position))) => {
+						return Err(TemplateError::new(
+							"duplicate '{{else}}' in '{{#if}}' block",
+							position,
+						));
+					}
+					(_, None) => return Err(TemplateError::new("unclosed '{{#if}}'", start)),
+				},
+				None => return Err(TemplateError::new("unclosed '{{#if}}'", start)),
+			};
+			nodes.push(Node::If {
+				variable,
+				then,
+				otherwise,
+			});
+		} else if tag == "else" {
+			return Ok((nodes, Some(SectionEnd::Else(start))));
+		} else if let Some(block) = tag.strip_prefix('/') {
+			if block.trim() != "if" {
+				return Err(TemplateError::new(
+					format!("unknown closing tag '{{{{/{}}}}}'", block.trim()),
+					start,
+				));
+			}
+			return Ok((nodes, Some(SectionEnd::EndIf(start))));
+		} else {
+			let expression = parse_expression(tag).map_err(|e| TemplateError::new(e, start))?;
+			nodes.push(Node::Expression {
+				expression,
+				position: start,
+			});
+		}
+	}
+
+	push_text(&mut nodes, &mut text);
+	Ok((nodes, None))
+}
+
+fn push_text(nodes: &mut Vec<Node>, text: &mut String) {
+	if !text.is_empty() {
+		nodes.push(Node::Text(std::mem::take(text)));
+	}
+}
+
+/// Parses the content of an `{{#if var}}` tag, without the leading `#`
+fn parse_if(block: &str) -> Result<String, String> {
+	match lex(block)?.as_slice() {
+		[Word::Bare(keyword), Word::Bare(variable)] if keyword == "if" => Ok(variable.clone()),
+		[Word::Bare(keyword), ..] if keyword == "if" => {
+			Err("'{{#if}}' expects a single variable".to_string())
+		}
+		_ => Err(format!("unknown block '{{{{#{}}}}}'", block.trim())),
+	}
+}
+
+fn parse_expression(tag: &str) -> Result<Expression, String> {
+	let words = lex(tag)?;
+	let mut parts = words.split(|word| *word == Word::Pipe);
+
+	let term = match parts.next().unwrap_or_default() {
+		[] => return Err("empty expression".to_string()),
+		[Word::Bare(name)] if Helper::from_name(name).is_none() => Term::Variable(name.clone()),
+		[Word::Bare(name), args @ ..] => {
+			let helper =
+				Helper::from_name(name).ok_or_else(|| format!("unknown helper '{}'", name))?;
+			if args.len() != helper.arity() {
+				return Err(format!(
+					"'{}' expects {} argument(s), got {}",
+					name,
+					helper.arity(),
+					args.len()
+				));
+			}
+			Term::Helper {
+				helper,
+				args: args.iter().map(parse_argument).collect(),
+			}
+		}
+		_ => return Err("expected a variable or helper name".to_string()),
+	};
+
+	let mut default = None;
+	for filter in parts {
+		match filter {
+			[Word::Bare(name), Word::Quoted(value)] if name == "default" => {
+				default = Some(value.clone());
+			}
+			[Word::Bare(name), ..] if name == "default" => {
+				return Err("'default' expects a single quoted value".to_string());
+			}
+			[Word::Bare(name), ..] => return Err(format!("unknown filter '{}'", name)),
+			_ => return Err("empty filter".to_string()),
+		}
+	}
+
+	Ok(Expression { term, default })
+}
+
+fn parse_argument(word: &Word) -> Argument {
+	match word {
+		Word::Quoted(value) => Argument::Literal(value.clone()),
+		Word::Bare(value) if value.parse::<f64>().is_ok() => Argument::Literal(value.clone()),
+		Word::Bare(name) => Argument::Variable(name.clone()),
+		Word::Pipe => Argument::Literal("|".to_string()),
+	}
+}
+
+/// Splits the content of a tag into words, quoted strings and pipes
+fn lex(content: &str) -> Result<Vec<Word>, String> {
+	let mut words = Vec::new();
+	let mut chars = content.chars().peekable();
+
+	while let Some(&c) = chars.peek() {
+		if c.is_whitespace() {
+			chars.next();
+		} else if c == '|' {
+			chars.next();
+			words.push(Word::Pipe);
+		} else if c == '"' || c == '\'' {
+			chars.next();
+			let mut value = String::new();
+			loop {
+				match chars.next() {
+					Some(next) if next == c => break,
+					Some(next) => value.push(next),
+					None => return Err("unterminated string literal".to_string()),
+				}
+			}
+			words.push(Word::Quoted(value));
+		} else {
+			let mut value = String::new();
+			while let Some(&next) = chars.peek() {
+				if next.is_whitespace() || next == '|' {
+					break;
+				}
+				value.push(next);
+				chars.next();
+			}
+			words.push(Word::Bare(value));
+		}
+	}
+
+	Ok(words)
+}
+
+fn render_nodes(
+	nodes: &[Node],
+	variables: &HashMap<String, String>,
+	escape: &mut dyn FnMut(&str) -> String,
+	output: &mut String,
+) -> Result<(), TemplateError> {
+	for node in nodes {
+		match node {
+			Node::Text(text) => output.push_str(text),
+			Node::Legacy(name) => match variables.get(name) {
+				Some(value) => output.push_str(&escape(value)),
+				None if name == "events" || name == "functions" => {
+					let prefix = format!("{}.", name);
+					let escaped: HashMap<String, String> = variables
+						.iter()
+						.filter(|(key, _)| key.starts_with(&prefix))
+						.map(|(key, value)| (key.clone(), escape(value)))
+						.collect();
+					if let Some(section) = build_match_reasons(&escaped, name) {
+						output.push_str(&section);
+					}
+				}
+				None => {
+					output.push_str("${");
+					output.push_str(name);
+					output.push('}');
+				}
+			},
+			Node::Expression {
+				expression,
+				position,
+			} => {
+				let value = evaluate(expression, variables)
+					.map_err(|e| TemplateError::new(e, *position))?;
+				output.push_str(&escape(&value));
+			}
+			Node::If {
+				variable,
+				then,
+				otherwise,
+			} => {
+				let branch = if is_truthy(variables.get(variable)) {
+					then
+				} else {
+					otherwise
+				};
+				render_nodes(branch, variables, escape, output)?;
+			}
+		}
+	}
+	Ok(())
+}
+
+fn evaluate(
+	expression: &Expression,
+	variables: &HashMap<String, String>,
+) -> Result<String, String> {
+	let value = match &expression.term {
+		Term::Variable(name) => variables.get(name).cloned(),
+		Term::Helper { helper, args } => {
+			// Helpers render nothing when one of their variables is missing
+			let args: Option<Vec<&str>> = args
+				.iter()
+				.map(|arg| match arg {
+					Argument::Variable(name) => variables.get(name).map(String::as_str),
+					Argument::Literal(value) => Some(value.as_str()),
+				})
+				.collect();
+			args.map(|args| helper.apply(&args)).transpose()?
+		}
+	};
+
+	Ok(match (value, &expression.default) {
+		(Some(value), _) if !value.is_empty() => value,
+		(_, Some(default)) => default.clone(),
+		(value, None) => value.unwrap_or_default(),
+	})
+}
+
+/// A variable is truthy when it's set to anything other than an empty string, `false` or `0`
+fn is_truthy(value: Option<&String>) -> bool {
+	value.is_some_and(|value| !matches!(value.as_str(), "" | "false" | "0"))
+}
+
+/// Formats an integer amount expressed in the smallest unit of a token
+///
+/// Decimal and `0x` hex amounts are accepted. Trailing zeros of the fraction are dropped, e.g.
+/// `1500000000000000000` with 18 decimals is formatted as `1.5`.
+fn format_units(value: &str, decimals: u8) -> Result<String, String> {
+	let value = value.trim();
+	let (negative, magnitude) = match value.strip_prefix('-') {
+		Some(magnitude) => (true, magnitude),
+		None => (false, value),
+	};
+
+	let digits = match magnitude
+		.strip_prefix("0x")
+		.or_else(|| magnitude.strip_prefix("0X"))
+	{
+		Some(hex) => U256::from_str_radix(hex, 16)
+			.map_err(|e| format!("format_units can't parse '{}': {}", value, e))?
+			.to_string(),
+		None if !magnitude.is_empty() && magnitude.chars().all(|c| c.is_ascii_digit()) => {
+			magnitude.to_string()
+		}
+		None => return Err(format!("format_units expects an integer, got '{}'", value)),
+	};
+
+	let digits = digits.trim_start_matches('0');
+	let decimals = usize::from(decimals);
+	let padded = format!("{:0>width$}", digits, width = decimals + 1);
+	let (integer, fraction) = padded.split_at(padded.len() - decimals);
+	let fraction = fraction.trim_end_matches('0');
+
+	let mut formatted = if fraction.is_empty() {
+		integer.to_string()
+	} else {
+		format!("{}.{}", integer, fraction)
+	};
+	if negative && formatted.chars().any(|c| c != '0' && c != '.') {
+		formatted.insert(0, '-');
+	}
+	Ok(formatted)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn render_plain(template: &str, variables: &[(&str, &str)]) -> Result<String, TemplateError> {
+		let variables: HashMap<String, String> = variables
+			.iter()
+			.map(|(key, value)| (key.to_string(), value.to_string()))
+			.collect();
+		render(template, &variables, &mut |value| value.to_string())
+	}
+
+	#[test]
+	fn test_render_variables() {
+		let result = render_plain(
+			"{{monitor.name}} / {{ events.0.args.value }} / {{missing}}",
+			&[("monitor.name", "usdc"), ("events.0.args.value", "10")],
+		);
+
+		assert_eq!(result.unwrap(), "usdc / 10 / ");
+	}
+
+	#[test]
+	fn test_render_legacy_variables() {
+		let result = render_plain(
+			"${monitor.name} ${missing} ${unclosed",
+			&[("monitor.name", "usdc")],
+		);
+
+		assert_eq!(result.unwrap(), "usdc ${missing} ${unclosed");
+	}
+
+	#[test]
+	fn test_render_legacy_match_reasons() {
+		let result = render_plain(
+			"${events}",
+			&[
+				("events.0.signature", "Transfer(address,address,uint256)"),
+				("events.0.args.value", "10"),
+			],
+		);
+
+		assert_eq!(
+			result.unwrap(),
+			"\n\n*Matched Events:*\n\n*Reason 1*\n\n*Signature:* \
+			 `Transfer(address,address,uint256)`\n\n*Params:*\n\nvalue: `10`"
+		);
+		assert_eq!(render_plain("${functions}", &[]).unwrap(), "");
+	}
+
+	#[test]
+	fn test_default_filter() {
+		let variables = [("empty", ""), ("set", "value")];
+
+		assert_eq!(
+			render_plain("{{missing | default \"n/a\"}}", &variables).unwrap(),
+			"n/a"
+		);
+		assert_eq!(
+			render_plain("{{empty|default 'n/a'}}", &variables).unwrap(),
+			"n/a"
+		);
+		assert_eq!(
+			render_plain("{{set | default \"n/a\"}}", &variables).unwrap(),
+			"value"
+		);
+	}
+
+	#[test]
+	fn test_format_units_helper() {
+		let variables = [
+			("wei", "1500000000000000000"),
+			("hex", "0xde0b6b3a7640000"),
+			("small", "42"),
+			("negative", "-2500000"),
+		];
+
+		assert_eq!(
+			render_plain("{{format_units wei 18}} ETH", &variables).unwrap(),
+			"1.5 ETH"
+		);
+		assert_eq!(
+			render_plain("{{format_units hex 18}}", &variables).unwrap(),
+			"1"
+		);
+		assert_eq!(
+			render_plain("{{format_units small 6}}", &variables).unwrap(),
+			"0.000042"
+		);
+		assert_eq!(
+			render_plain("{{format_units negative 6}}", &variables).unwrap(),
+			"-2.5"
+		);
+		assert_eq!(
+			render_plain("{{format_units small 0}}", &variables).unwrap(),
+			"42"
+		);
+		assert_eq!(
+			render_plain("{{format_units missing 18 | default \"?\"}}", &variables).unwrap(),
+			"?"
+		);
+	}
+
+	#[test]
+	fn test_format_units_helper_errors() {
+		let variables = [("value", "1.5"), ("wei", "1000")];
+
+		let error = render_plain("Amount: {{format_units value 18}}", &variables).unwrap_err();
+		assert_eq!(error.position, 8);
+		assert!(error.message.contains("expects an integer"), "{}", error);
+
+		assert!(render_plain("{{format_units wei 256}}", &variables).is_err());
+	}
+
+	#[test]
+	fn test_case_helpers() {
+		let variables = [("name", "Large Transfer")];
+
+		assert_eq!(
+			render_plain("{{uppercase name}}", &variables).unwrap(),
+			"LARGE TRANSFER"
+		);
+		assert_eq!(
+			render_plain("{{lowercase name}}", &variables).unwrap(),
+			"large transfer"
+		);
+	}
+
+	#[test]
+	fn test_truncate_helper() {
+		let variables = [("hash", "0x1234567890abcdef")];
+
+		assert_eq!(
+			render_plain("{{truncate hash 6}}...", &variables).unwrap(),
+			"0x1234..."
+		);
+		assert_eq!(
+			render_plain("{{truncate hash 100}}", &variables).unwrap(),
+			"0x1234567890abcdef"
+		);
+		assert!(render_plain("{{truncate hash -1}}", &variables).is_err());
+	}
+
+	#[test]
+	fn test_if_blocks() {
+		let template = "{{#if memo}}Memo: {{memo}}{{else}}No memo{{/if}}";
+
+		assert_eq!(
+			render_plain(template, &[("memo", "hello")]).unwrap(),
+			"Memo: hello"
+		);
+		assert_eq!(render_plain(template, &[]).unwrap(), "No memo");
+		assert_eq!(
+			render_plain(template, &[("memo", "false")]).unwrap(),
+			"No memo"
+		);
+		assert_eq!(
+			render_plain(
+				"{{#if a}}A{{#if b}}B{{/if}}{{/if}}",
+				&[("a", "1"), ("b", "1")]
+			)
+			.unwrap(),
+			"AB"
+		);
+	}
+
+	#[test]
+	fn test_escape_applies_to_values_only() {
+		let variables = HashMap::from([("name".to_string(), "<b>".to_string())]);
+
+		let result = render("<i>{{name}}</i> ${name}", &variables, &mut |value| {
+			value.replace('<', "&lt;").replace('>', "&gt;")
+		});
+
+		assert_eq!(result.unwrap(), "<i>&lt;b&gt;</i> &lt;b&gt;");
+	}
+
+	#[test]
+	fn test_malformed_templates() {
+		let cases = [
+			("Hello {{name", "unclosed '{{'"),
+			("{{}}", "empty expression"),
+			("{{unknown_helper value 1}}", "unknown helper"),
+			("{{format_units value}}", "expects 2 argument(s)"),
+			("{{name | upper}}", "unknown filter"),
+			("{{name | default}}", "'default' expects"),
+			("{{name | default \"n/a}}", "unterminated string literal"),
+			("{{#if name}}never closed", "unclosed '{{#if}}'"),
+			("{{#each items}}{{/each}}", "unknown block"),
+			("text{{/if}}", "without a matching"),
+			("{{else}}", "outside of an"),
+			("{{#if a}}{{else}}{{else}}{{/if}}", "duplicate"),
+		];
+
+		for (template, expected) in cases {
+			let error = render_plain(template, &[("name", "value")]).unwrap_err();
+			assert!(error.message.contains(expected), "{}: {}", template, error);
+		}
+	}
+}
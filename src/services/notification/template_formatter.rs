@@ -1,46 +1,52 @@
 //! Template formatter implementation.
 //!
-//! This module provides shared functionality for formatting message templates
-//! with variable substitution and building match reasons sections for events and functions.
-//! It is used by both email notifications and webhook payload builders.
+//! This module provides shared functionality for formatting message templates with the
+//! template engine and building match reasons sections for events and functions.
+//! It is used by all notifiers, so they share the same template syntax.
 
 use std::collections::HashMap;
 
-/// Formats a message template by substituting variables and building match reasons sections
-/// This function handles both basic variable substitution and special sections like ${events} and ${functions}
+use super::{template_engine, NotificationError};
+
+/// Formats a message template by rendering its expressions and legacy `${...}` variables
+/// This function handles both variable substitution and special sections like ${events} and ${functions}
 ///
 /// # Arguments
-/// * `template` - The message template with variables like ${...}
+/// * `template` - The message template with variables like {{...}} or ${...}
 /// * `variables` - The map of variables to substitute into the template
 ///
 /// # Returns
-/// * `String` - Formatted message with variables replaced and match reasons sections built
-pub fn format_template(template: &str, variables: &HashMap<String, String>) -> String {
-	let mut message = template.to_string();
-
-	// First, substitute basic variables
-	for (key, value) in variables {
-		message = message.replace(&format!("${{{}}}", key), value);
-	}
-
-	// Handle special sections for events and functions
-	if template.contains("${functions}") {
-		if let Some(functions_section) = build_match_reasons(variables, "functions") {
-			message = message.replace("${functions}", &functions_section);
-		} else {
-			message = message.replace("${functions}", "");
-		}
-	}
-
-	if template.contains("${events}") {
-		if let Some(events_section) = build_match_reasons(variables, "events") {
-			message = message.replace("${events}", &events_section);
-		} else {
-			message = message.replace("${events}", "");
-		}
-	}
+/// * `Result<String, NotificationError>` - Formatted message, or a config error if the template
+///   is malformed
+pub fn format_template(
+	template: &str,
+	variables: &HashMap<String, String>,
+) -> Result<String, NotificationError> {
+	format_template_with(template, variables, &mut |value| value.to_string())
+}
 
-	message
+/// Formats a message template, passing every substituted value through `escape`
+///
+/// # Arguments
+/// * `template` - The message template with variables like {{...}} or ${...}
+/// * `variables` - The map of variables to substitute into the template
+/// * `escape` - Applied to each substituted value, e.g. to escape markup
+///
+/// # Returns
+/// * `Result<String, NotificationError>` - Formatted message, or a config error if the template
+///   is malformed
+pub fn format_template_with(
+	template: &str,
+	variables: &HashMap<String, String>,
+	escape: &mut dyn FnMut(&str) -> String,
+) -> Result<String, NotificationError> {
+	template_engine::render(template, variables, escape).map_err(|e| {
+		NotificationError::config_error(
+			format!("Invalid message template: {}", e),
+			Some(e.into()),
+			None,
+		)
+	})
 }
 
 /// Builds the "Match reasons" section for events or functions if they are present
@@ -135,7 +141,7 @@ mod tests {
 			("events.0.args.value".to_string(), "1000000".to_string()),
 		]);
 
-		let result = format_template(template, &variables);
+		let result = format_template(template, &variables).unwrap();
 		let expected = "Transaction detected: 0x1234567890abcdef\n\n\n\n*Matched Events:*\n\n*Reason 1*\n\n*Signature:* `Transfer(address,address,uint256)`\n\n*Params:*\n\nfrom: `0x1234`\nto: `0x5678`\nvalue: `1000000`";
 		assert_eq!(result, expected);
 	}
@@ -156,7 +162,7 @@ mod tests {
 			("functions.0.args.amount".to_string(), "1000000".to_string()),
 		]);
 
-		let result = format_template(template, &variables);
+		let result = format_template(template, &variables).unwrap();
 		let expected = "Transaction detected: 0x1234567890abcdef\n\n\n\n*Matched Functions:*\n\n*Reason 1*\n\n*Signature:* `transfer(address,uint256)`\n\n*Params:*\n\namount: `1000000`\nto: `0x1234`";
 		assert_eq!(result, expected);
 	}
@@ -183,7 +189,7 @@ mod tests {
 			("functions.0.args.amount".to_string(), "750000".to_string()),
 		]);
 
-		let result = format_template(template, &variables);
+		let result = format_template(template, &variables).unwrap();
 		let expected = "Transaction detected: 0x1234567890abcdef\n\n\n\n*Matched Functions:*\n\n*Reason 1*\n\n*Signature:* `transfer(address,uint256)`\n\n*Params:*\n\namount: `750000`\nto: `0x9abc`\n\n\n\n*Matched Events:*\n\n*Reason 1*\n\n*Signature:* `Transfer(address,address,uint256)`\n\n*Params:*\n\nfrom: `0x1234`\nto: `0x5678`";
 		assert_eq!(result, expected);
 	}
@@ -199,7 +205,7 @@ mod tests {
 			// No events variables present
 		]);
 
-		let result = format_template(template, &variables);
+		let result = format_template(template, &variables).unwrap();
 		let expected = "Transaction detected: 0x1234567890abcdef\n\n";
 		assert_eq!(result, expected);
 	}
@@ -215,7 +221,7 @@ mod tests {
 			// No functions variables present
 		]);
 
-		let result = format_template(template, &variables);
+		let result = format_template(template, &variables).unwrap();
 		let expected = "Transaction detected: 0x1234567890abcdef\n\n";
 		assert_eq!(result, expected);
 	}
@@ -3,21 +3,92 @@
 //! Provides functionality to send formatted messages to webhooks
 //! via incoming webhooks, supporting message templates with variable substitution.
 
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use chrono::Utc;
+use ed25519_dalek::{Signer, SigningKey, SECRET_KEY_LENGTH};
 use hmac::{Hmac, Mac};
 use reqwest::{
 	header::{HeaderMap, HeaderName, HeaderValue},
-	Method,
+	Method, StatusCode,
 };
 use reqwest_middleware::ClientWithMiddleware;
 use sha2::Sha256;
 use std::{collections::HashMap, sync::Arc};
 
-use crate::{models::TriggerTypeConfig, services::notification::NotificationError};
+use crate::{
+	models::{MonitorMatch, TriggerTypeConfig, WebhookSignatureAlgorithm},
+	services::{
+		filter::evm_helpers::b256_to_string,
+		notification::{NotificationError, Notifier},
+	},
+};
 
 /// HMAC SHA256 type alias
 type HmacSha256 = Hmac<Sha256>;
 
+/// Header carrying the request signature when none is configured
+const DEFAULT_SIGNATURE_HEADER: &str = "X-Signature";
+/// Header carrying the signature algorithm when none is configured
+const DEFAULT_SIGNATURE_ALGORITHM_HEADER: &str = "X-Signature-Algorithm";
+
+/// Parses an Ed25519 private key, given as the hex or base64 encoding of its 32 bytes
+///
+/// # Arguments
+/// * `secret` - Encoded private key, hex optionally prefixed with `0x`
+///
+/// # Returns
+/// * `Result<SigningKey, NotificationError>` - Signing key or a config error
+pub fn parse_ed25519_signing_key(secret: &str) -> Result<SigningKey, NotificationError> {
+	let secret = secret.trim();
+	let hex_secret = secret.strip_prefix("0x").unwrap_or(secret);
+	let bytes = if hex_secret.len() == SECRET_KEY_LENGTH * 2 {
+		hex::decode(hex_secret).ok()
+	} else {
+		BASE64.decode(secret).ok()
+	};
+	let key: [u8; SECRET_KEY_LENGTH] =
+		bytes
+			.and_then(|bytes| bytes.try_into().ok())
+			.ok_or_else(|| {
+				NotificationError::config_error(
+					"Invalid Ed25519 private key: expected 32 bytes encoded as hex or base64",
+					None,
+					None,
+				)
+			})?;
+	Ok(SigningKey::from_bytes(&key))
+}
+
+/// Signs a message with a secret
+///
+/// # Arguments
+/// * `algorithm` - Signature algorithm
+/// * `secret` - HMAC key, or Ed25519 private key as accepted by [`parse_ed25519_signing_key`]
+/// * `message` - Message to sign
+///
+/// # Returns
+/// * `Result<String, NotificationError>` - Hex encoded signature or an error
+pub fn sign_message(
+	algorithm: WebhookSignatureAlgorithm,
+	secret: &str,
+	message: &[u8],
+) -> Result<String, NotificationError> {
+	match algorithm {
+		WebhookSignatureAlgorithm::HmacSha256 => {
+			let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).map_err(|e| {
+				NotificationError::config_error(format!("Invalid secret: {}", e), None, None)
+			})?;
+			mac.update(message);
+			Ok(hex::encode(mac.finalize().into_bytes()))
+		}
+		WebhookSignatureAlgorithm::Ed25519 => {
+			let key = parse_ed25519_signing_key(secret)?;
+			Ok(hex::encode(key.sign(message).to_bytes()))
+		}
+	}
+}
+
 /// Represents a webhook configuration
 #[derive(Clone)]
 pub struct WebhookConfig {
@@ -29,6 +100,11 @@ pub struct WebhookConfig {
 	pub secret: Option<String>,
 	pub headers: Option<HashMap<String, String>>,
 	pub payload_fields: Option<HashMap<String, serde_json::Value>>,
+	pub include_raw_match: bool,
+	pub trim_raw_match: bool,
+	pub signature_algorithm: WebhookSignatureAlgorithm,
+	pub signature_header: Option<String>,
+	pub signature_algorithm_header: Option<String>,
 }
 
 /// Implementation of webhook notifications via webhooks
@@ -50,6 +126,16 @@ pub struct WebhookNotifier {
 	pub headers: Option<HashMap<String, String>>,
 	/// Payload fields to use for the webhook request
 	pub payload_fields: Option<HashMap<String, serde_json::Value>>,
+	/// Whether to add the structured monitor match to the request as `payload`
+	pub include_raw_match: bool,
+	/// Whether to drop receipts, logs and ledgers from the included monitor match
+	pub trim_raw_match: bool,
+	/// Algorithm signing the requests when a secret is set
+	pub signature_algorithm: WebhookSignatureAlgorithm,
+	/// Header carrying the signature
+	pub signature_header: String,
+	/// Header carrying the signature algorithm
+	pub signature_algorithm_header: String,
 }
 
 impl WebhookNotifier {
@@ -78,6 +164,15 @@ impl WebhookNotifier {
 			secret: config.secret,
 			headers: Some(headers),
 			payload_fields: config.payload_fields,
+			include_raw_match: config.include_raw_match,
+			trim_raw_match: config.trim_raw_match,
+			signature_algorithm: config.signature_algorithm,
+			signature_header: config
+				.signature_header
+				.unwrap_or_else(|| DEFAULT_SIGNATURE_HEADER.to_string()),
+			signature_algorithm_header: config
+				.signature_algorithm_header
+				.unwrap_or_else(|| DEFAULT_SIGNATURE_ALGORITHM_HEADER.to_string()),
 		})
 	}
 
@@ -99,6 +194,11 @@ impl WebhookNotifier {
 			method,
 			secret,
 			headers,
+			include_raw_match,
+			trim_raw_match,
+			signature_algorithm,
+			signature_header,
+			signature_algorithm_header,
 			..
 		} = config
 		{
@@ -111,6 +211,11 @@ impl WebhookNotifier {
 				secret: secret.as_ref().map(|s| s.as_ref().to_string()),
 				headers: headers.clone(),
 				payload_fields: None,
+				include_raw_match: *include_raw_match,
+				trim_raw_match: *trim_raw_match,
+				signature_algorithm: *signature_algorithm,
+				signature_header: signature_header.clone(),
+				signature_algorithm_header: signature_algorithm_header.clone(),
 			};
 
 			WebhookNotifier::new(webhook_config, http_client)
@@ -120,6 +225,16 @@ impl WebhookNotifier {
 		}
 	}
 
+	/// Signs a payload along with the current timestamp, using the configured algorithm
+	///
+	/// The signed message is the serialized payload followed by the timestamp in milliseconds.
+	///
+	/// # Arguments
+	/// * `secret` - HMAC key, or Ed25519 private key
+	/// * `payload` - The JSON payload to sign
+	///
+	/// # Returns
+	/// * `Result<(String, String), NotificationError>` - Hex encoded signature and timestamp
 	pub fn sign_payload(
 		&self,
 		secret: &str,
@@ -136,11 +251,6 @@ impl WebhookNotifier {
 
 		let timestamp = Utc::now().timestamp_millis();
 
-		// Create HMAC instance
-		let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).map_err(|e| {
-			NotificationError::config_error(format!("Invalid secret: {}", e), None, None)
-		})?; // Handle error if secret is invalid
-
 		// Create the message to sign
 		let serialized_payload = serde_json::to_string(payload).map_err(|e| {
 			NotificationError::internal_error(
@@ -150,14 +260,131 @@ impl WebhookNotifier {
 			)
 		})?;
 		let message = format!("{}{}", serialized_payload, timestamp);
-		mac.update(message.as_bytes());
-
-		// Get the HMAC result
-		let signature = hex::encode(mac.finalize().into_bytes());
+		let signature = sign_message(self.signature_algorithm, secret, message.as_bytes())?;
 
 		Ok((signature, timestamp.to_string()))
 	}
 
+	/// Builds the structured `payload` object describing a monitor match
+	///
+	/// The object holds the common match context next to the full chain-specific match. When
+	/// trimming is enabled, EVM receipts and logs and Stellar ledgers are left out of it.
+	///
+	/// # Arguments
+	/// * `monitor_match` - The monitor match to describe
+	///
+	/// # Returns
+	/// * `Result<serde_json::Value, NotificationError>` - The payload object or an error
+	pub fn build_match_payload(
+		&self,
+		monitor_match: &MonitorMatch,
+	) -> Result<serde_json::Value, NotificationError> {
+		let to_json_error = |e: serde_json::Error| {
+			NotificationError::internal_error(
+				format!("Failed to serialize monitor match: {}", e),
+				Some(e.into()),
+				None,
+			)
+		};
+
+		let (chain, monitor, network, transaction_hash, matched_on, matched_on_args, details) =
+			match monitor_match {
+				MonitorMatch::EVM(m) => {
+					let mut details = serde_json::to_value(m).map_err(to_json_error)?;
+					if self.trim_raw_match {
+						if let Some(details) = details.as_object_mut() {
+							details.remove("receipt");
+							details.remove("logs");
+						}
+					}
+					(
+						"EVM",
+						&m.monitor.name,
+						&m.network_slug,
+						b256_to_string(*m.transaction.hash()),
+						&m.matched_on,
+						serde_json::to_value(&m.matched_on_args),
+						Ok(details),
+					)
+				}
+				MonitorMatch::Stellar(m) => {
+					let mut details = serde_json::to_value(m).map_err(to_json_error)?;
+					if self.trim_raw_match {
+						if let Some(details) = details.as_object_mut() {
+							details.remove("ledger");
+						}
+					}
+					(
+						"Stellar",
+						&m.monitor.name,
+						&m.network_slug,
+						m.transaction.hash().to_string(),
+						&m.matched_on,
+						serde_json::to_value(&m.matched_on_args),
+						Ok(details),
+					)
+				}
+				MonitorMatch::Midnight(m) => (
+					"Midnight",
+					&m.monitor.name,
+					&m.network_slug,
+					m.transaction.hash().to_string(),
+					&m.matched_on,
+					serde_json::to_value(&m.matched_on_args),
+					serde_json::to_value(m),
+				),
+				MonitorMatch::Solana(m) => (
+					"Solana",
+					&m.monitor.name,
+					&m.network_slug,
+					m.transaction.signature().to_string(),
+					&m.matched_on,
+					serde_json::to_value(&m.matched_on_args),
+					serde_json::to_value(m),
+				),
+			};
+
+		Ok(serde_json::json!({
+			"chain": chain,
+			"monitor": monitor,
+			"network": network,
+			"transaction_hash": transaction_hash,
+			"matched_on": matched_on,
+			"matched_on_args": matched_on_args.map_err(to_json_error)?,
+			"match": details.map_err(to_json_error)?,
+		}))
+	}
+
+	/// Sends a JSON payload to Webhook, adding the monitor match to it when configured
+	///
+	/// The monitor match is added as the `payload` field of the JSON object, and only when
+	/// `include_raw_match` is enabled. Otherwise the payload is sent as is.
+	///
+	/// # Arguments
+	/// * `payload` - The JSON payload to send
+	/// * `monitor_match` - The monitor match the notification is sent for, if any
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify_with_payload(
+		&self,
+		payload: &serde_json::Value,
+		monitor_match: Option<&MonitorMatch>,
+	) -> Result<(), NotificationError> {
+		let Some(monitor_match) = monitor_match.filter(|_| self.include_raw_match) else {
+			return self.notify_json(payload).await;
+		};
+
+		let mut payload = payload.clone();
+		if let Some(fields) = payload.as_object_mut() {
+			fields.insert(
+				"payload".to_string(),
+				self.build_match_payload(monitor_match)?,
+			);
+		}
+		self.notify_json(&payload).await
+	}
+
 	/// Sends a JSON payload to Webhook
 	///
 	/// # Arguments
@@ -166,17 +393,7 @@ impl WebhookNotifier {
 	/// # Returns
 	/// * `Result<(), NotificationError>` - Success or error
 	pub async fn notify_json(&self, payload: &serde_json::Value) -> Result<(), NotificationError> {
-		let mut url = self.url.clone();
-		// Add URL parameters if present
-		if let Some(params) = &self.url_params {
-			let params_str: Vec<String> = params
-				.iter()
-				.map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-				.collect();
-			if !params_str.is_empty() {
-				url = format!("{}?{}", url, params_str.join("&"));
-			}
-		}
+		let url = self.request_url();
 
 		let method = if let Some(ref m) = self.method {
 			Method::from_bytes(m.as_bytes()).unwrap_or(Method::POST)
@@ -197,8 +414,16 @@ impl WebhookNotifier {
 			})?;
 
 			// Add signature headers
+			let signature_header = HeaderName::from_bytes(self.signature_header.as_bytes())
+				.map_err(|e| {
+					NotificationError::notify_failed(
+						format!("Invalid header name: {}", self.signature_header),
+						Some(e.into()),
+						None,
+					)
+				})?;
 			headers.insert(
-				HeaderName::from_static("x-signature"),
+				signature_header,
 				HeaderValue::from_str(&signature).map_err(|e| {
 					NotificationError::notify_failed(
 						"Invalid signature value".to_string(),
@@ -207,6 +432,23 @@ impl WebhookNotifier {
 					)
 				})?,
 			);
+			let algorithm_header = HeaderName::from_bytes(
+				self.signature_algorithm_header.as_bytes(),
+			)
+			.map_err(|e| {
+				NotificationError::notify_failed(
+					format!("Invalid header name: {}", self.signature_algorithm_header),
+					Some(e.into()),
+					None,
+				)
+			})?;
+			headers.insert(
+				algorithm_header,
+				HeaderValue::from_static(match self.signature_algorithm {
+					WebhookSignatureAlgorithm::HmacSha256 => "hmac_sha256",
+					WebhookSignatureAlgorithm::Ed25519 => "ed25519",
+				}),
+			);
 			headers.insert(
 				HeaderName::from_static("x-timestamp"),
 				HeaderValue::from_str(&timestamp).map_err(|e| {
@@ -219,7 +461,54 @@ impl WebhookNotifier {
 			);
 		}
 
-		// Add custom headers
+		self.add_custom_headers(&mut headers)?;
+
+		// Send request with custom payload
+		let response = self
+			.client
+			.request(method, url.as_str())
+			.headers(headers)
+			.json(payload)
+			.send()
+			.await
+			.map_err(|e| {
+				NotificationError::notify_failed(
+					format!("Failed to send webhook request: {}", e),
+					Some(e.into()),
+					None,
+				)
+			})?;
+
+		let status = response.status();
+
+		if !status.is_success() {
+			return Err(NotificationError::notify_failed(
+				format!("Webhook request failed with status: {}", status),
+				None,
+				None,
+			));
+		}
+
+		Ok(())
+	}
+
+	/// Returns the webhook URL with its URL parameters, if any
+	fn request_url(&self) -> String {
+		let mut url = self.url.clone();
+		if let Some(params) = &self.url_params {
+			let params_str: Vec<String> = params
+				.iter()
+				.map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
+				.collect();
+			if !params_str.is_empty() {
+				url = format!("{}?{}", url, params_str.join("&"));
+			}
+		}
+		url
+	}
+
+	/// Adds the configured custom headers to the headers of a request
+	fn add_custom_headers(&self, headers: &mut HeaderMap) -> Result<(), NotificationError> {
 		if let Some(headers_map) = &self.headers {
 			for (key, value) in headers_map {
 				let header_name = HeaderName::from_bytes(key.as_bytes()).map_err(|e| {
@@ -239,28 +528,46 @@ impl WebhookNotifier {
 				headers.insert(header_name, header_value);
 			}
 		}
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+	/// Checks that the webhook exists with a `HEAD` request, sending its custom headers
+	///
+	/// Webhooks that can't be reached, or answering that they don't exist or that the request
+	/// isn't authorized (401, 403, 404 or 410), are unhealthy, such as Slack, Discord or
+	/// Telegram URLs with an invalid token. Any other answer shows that the webhook exists, as
+	/// most webhooks only accept their notification method and reject `HEAD` requests.
+	async fn healthcheck(&self) -> Result<(), NotificationError> {
+		let mut headers = HeaderMap::new();
+		self.add_custom_headers(&mut headers)?;
 
-		// Send request with custom payload
 		let response = self
 			.client
-			.request(method, url.as_str())
+			.head(self.request_url())
 			.headers(headers)
-			.json(payload)
 			.send()
 			.await
 			.map_err(|e| {
-				NotificationError::notify_failed(
-					format!("Failed to send webhook request: {}", e),
+				NotificationError::network_error(
+					format!("Failed to reach webhook: {}", e),
 					Some(e.into()),
 					None,
 				)
 			})?;
 
 		let status = response.status();
-
-		if !status.is_success() {
-			return Err(NotificationError::notify_failed(
-				format!("Webhook request failed with status: {}", status),
+		if matches!(
+			status,
+			StatusCode::UNAUTHORIZED
+				| StatusCode::FORBIDDEN
+				| StatusCode::NOT_FOUND
+				| StatusCode::GONE
+		) {
+			return Err(NotificationError::config_error(
+				format!("Webhook health check failed with status: {}", status),
 				None,
 				None,
 			));
@@ -273,12 +580,25 @@ impl WebhookNotifier {
 #[cfg(test)]
 mod tests {
 	use crate::{
-		models::{NotificationMessage, SecretString, SecretValue},
+		models::{
+			EVMConfirmationState, EVMMonitorMatch, MatchConditions, NotificationMessage,
+			SecretString, SecretValue,
+		},
 		services::notification::{GenericWebhookPayloadBuilder, WebhookPayloadBuilder},
-		utils::{tests::create_test_http_client, RetryConfig},
+		utils::{
+			tests::{
+				create_test_http_client,
+				evm::{
+					monitor::MonitorBuilder, receipt::ReceiptBuilder,
+					transaction::TransactionBuilder,
+				},
+			},
+			RetryConfig,
+		},
 	};
 
 	use super::*;
+	use alloy::primitives::U256;
 	use mockito::{Matcher, Mock};
 	use serde_json::json;
 
@@ -297,6 +617,11 @@ mod tests {
 			secret: secret.map(|s| s.to_string()),
 			headers,
 			payload_fields: None,
+			include_raw_match: false,
+			trim_raw_match: false,
+			signature_algorithm: WebhookSignatureAlgorithm::default(),
+			signature_header: None,
+			signature_algorithm_header: None,
 		};
 		WebhookNotifier::new(config, http_client).unwrap()
 	}
@@ -312,15 +637,23 @@ mod tests {
 				body: "Test message ${value}".to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
+			include_raw_match: false,
+			trim_raw_match: false,
+			signature_algorithm: WebhookSignatureAlgorithm::default(),
+			signature_header: None,
+			signature_algorithm_header: None,
 		}
 	}
 
 	fn create_test_payload() -> serde_json::Value {
-		GenericWebhookPayloadBuilder.build_payload(
-			"Test Alert",
-			"Test message with value ${value}",
-			&HashMap::from([("value".to_string(), "42".to_string())]),
-		)
+		GenericWebhookPayloadBuilder
+			.build_payload(
+				"Test Alert",
+				"Test message with value ${value}",
+				&HashMap::from([("value".to_string(), "42".to_string())]),
+			)
+			.unwrap()
 	}
 
 	////////////////////////////////////////////////////////////
@@ -387,7 +720,9 @@ mod tests {
 				title: "Test Alert".to_string(),
 				body: "Test message ${value}".to_string(),
 			},
+			blocks: None,
 			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
 		};
 
 		let http_client = create_test_http_client();
@@ -439,6 +774,105 @@ mod tests {
 		mock.assert();
 	}
 
+	/// RFC 8032 test 2 private key, and its signature of the message `0x72`
+	const ED25519_TEST_KEY: &str =
+		"4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb";
+	const ED25519_TEST_SIGNATURE: &str = "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00";
+
+	#[test]
+	fn test_sign_message_known_vectors() {
+		// RFC 4231 test case 2
+		assert_eq!(
+			sign_message(
+				WebhookSignatureAlgorithm::HmacSha256,
+				"Jefe",
+				b"what do ya want for nothing?"
+			)
+			.unwrap(),
+			"5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+		);
+
+		assert_eq!(
+			sign_message(
+				WebhookSignatureAlgorithm::Ed25519,
+				ED25519_TEST_KEY,
+				&[0x72]
+			)
+			.unwrap(),
+			ED25519_TEST_SIGNATURE
+		);
+		// The same key, base64 encoded
+		assert_eq!(
+			sign_message(
+				WebhookSignatureAlgorithm::Ed25519,
+				"TM0Imyj/ltqdtsNG7BFOD1uKMZ81q6Yk2oz27U+4pvs=",
+				&[0x72]
+			)
+			.unwrap(),
+			ED25519_TEST_SIGNATURE
+		);
+	}
+
+	#[test]
+	fn test_sign_message_rejects_invalid_ed25519_key() {
+		let result = sign_message(WebhookSignatureAlgorithm::Ed25519, "top-secret", b"message");
+		assert!(matches!(result, Err(NotificationError::ConfigError(_))));
+	}
+
+	#[tokio::test]
+	async fn test_notify_includes_ed25519_signature() {
+		let mut server = mockito::Server::new_async().await;
+		let mock: Mock = server
+			.mock("POST", "/")
+			.match_header(
+				"X-Webhook-Signature",
+				Matcher::Regex("^[0-9a-f]{128}$".to_string()),
+			)
+			.match_header("X-Webhook-Algorithm", "ed25519")
+			.match_header("X-Timestamp", Matcher::Regex("^[0-9]+$".to_string()))
+			.with_status(200)
+			.create_async()
+			.await;
+
+		let mut notifier =
+			create_test_notifier(server.url().as_str(), Some(ED25519_TEST_KEY), None);
+		notifier.signature_algorithm = WebhookSignatureAlgorithm::Ed25519;
+		notifier.signature_header = "X-Webhook-Signature".to_string();
+		notifier.signature_algorithm_header = "X-Webhook-Algorithm".to_string();
+
+		let payload = create_test_payload();
+		assert!(notifier.notify_json(&payload).await.is_ok());
+		mock.assert();
+
+		// The signature verifies against the public key
+		let (signature, timestamp) = notifier.sign_payload(ED25519_TEST_KEY, &payload).unwrap();
+		let message = format!("{}{}", serde_json::to_string(&payload).unwrap(), timestamp);
+		let signature =
+			ed25519_dalek::Signature::from_slice(&hex::decode(signature).unwrap()).unwrap();
+		let verifying_key = parse_ed25519_signing_key(ED25519_TEST_KEY)
+			.unwrap()
+			.verifying_key();
+		assert!(
+			ed25519_dalek::Verifier::verify(&verifying_key, message.as_bytes(), &signature).is_ok()
+		);
+	}
+
+	#[tokio::test]
+	async fn test_notify_includes_hmac_algorithm_header() {
+		let mut server = mockito::Server::new_async().await;
+		let mock: Mock = server
+			.mock("POST", "/")
+			.match_header("X-Signature", Matcher::Regex("^[0-9a-f]{64}$".to_string()))
+			.match_header("X-Signature-Algorithm", "hmac_sha256")
+			.with_status(200)
+			.create_async()
+			.await;
+
+		let notifier = create_test_notifier(server.url().as_str(), Some("top-secret"), None);
+		assert!(notifier.notify_json(&create_test_payload()).await.is_ok());
+		mock.assert();
+	}
+
 	////////////////////////////////////////////////////////////
 	// notify header validation tests
 	////////////////////////////////////////////////////////////
@@ -536,4 +970,95 @@ mod tests {
 			"Timestamp should be valid i64"
 		);
 	}
+
+	////////////////////////////////////////////////////////////
+	// build_match_payload tests
+	////////////////////////////////////////////////////////////
+
+	fn create_test_evm_match() -> MonitorMatch {
+		let receipt = ReceiptBuilder::new().value(U256::from(100)).build();
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("test_monitor").build(),
+			transaction: TransactionBuilder::new().build(),
+			logs: Some(receipt.logs.clone()),
+			receipt: Some(receipt),
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			confirmation_state: EVMConfirmationState::Confirmed,
+			severity: Default::default(),
+			sender_verified: None,
+		}))
+	}
+
+	#[tokio::test]
+	async fn test_healthcheck_accepts_reachable_webhook() {
+		let mut server = mockito::Server::new_async().await;
+		let mock = server
+			.mock("HEAD", "/")
+			.match_header("X-Custom", "value")
+			.with_status(405)
+			.expect(1)
+			.create_async()
+			.await;
+
+		let headers = HashMap::from([("X-Custom".to_string(), "value".to_string())]);
+		let notifier = create_test_notifier(&server.url(), None, Some(headers));
+
+		assert!(notifier.healthcheck().await.is_ok());
+		mock.assert();
+	}
+
+	#[tokio::test]
+	async fn test_healthcheck_rejects_missing_webhook() {
+		let mut server = mockito::Server::new_async().await;
+		let mock = server
+			.mock("HEAD", "/")
+			.with_status(404)
+			.expect(1)
+			.create_async()
+			.await;
+
+		let notifier = create_test_notifier(&server.url(), None, None);
+
+		let result = notifier.healthcheck().await;
+		assert!(matches!(result, Err(NotificationError::ConfigError(_))));
+		mock.assert();
+	}
+
+	#[test]
+	fn test_build_match_payload() {
+		let notifier = create_test_notifier("https://webhook.example.com", None, None);
+
+		let payload = notifier
+			.build_match_payload(&create_test_evm_match())
+			.unwrap();
+
+		assert_eq!(payload["chain"], "EVM");
+		assert_eq!(payload["monitor"], "test_monitor");
+		assert_eq!(payload["network"], "ethereum_mainnet");
+		assert!(payload["transaction_hash"]
+			.as_str()
+			.unwrap()
+			.starts_with("0x"));
+		assert!(payload["matched_on"]["events"].is_array());
+		assert!(payload["match"]["receipt"].is_object());
+		assert!(!payload["match"]["logs"].as_array().unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_build_match_payload_trimmed() {
+		let mut notifier = create_test_notifier("https://webhook.example.com", None, None);
+		notifier.trim_raw_match = true;
+
+		let payload = notifier
+			.build_match_payload(&create_test_evm_match())
+			.unwrap();
+
+		let details = payload["match"].as_object().unwrap();
+		assert!(!details.contains_key("receipt"));
+		assert!(!details.contains_key("logs"));
+		assert!(details.contains_key("transaction"));
+		assert_eq!(payload["monitor"], "test_monitor");
+	}
 }
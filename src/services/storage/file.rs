@@ -0,0 +1,245 @@
+//! File-based match store.
+//!
+//! Matches are appended as JSON lines to one file per UTC day, named
+//! `matches-YYYY-MM-DD.jsonl`, so that old history can be archived or deleted by day.
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::{path::PathBuf, sync::Arc};
+use tokio::{io::AsyncWriteExt, sync::Mutex};
+
+use crate::services::storage::{MatchQuery, MatchRecord, MatchStore};
+
+/// Prefix of the daily match files
+const FILE_PREFIX: &str = "matches-";
+
+/// Extension of the daily match files
+const FILE_EXTENSION: &str = ".jsonl";
+
+/// File-based implementation of the match store
+#[derive(Clone)]
+pub struct FileMatchStore {
+	/// Directory holding the daily match files
+	storage_path: PathBuf,
+	/// Serializes appends so that concurrent records don't interleave
+	write_lock: Arc<Mutex<()>>,
+}
+
+impl FileMatchStore {
+	/// Creates a new file-based match store
+	///
+	/// The directory is created on the first record.
+	pub fn new(storage_path: PathBuf) -> Self {
+		Self {
+			storage_path,
+			write_lock: Arc::new(Mutex::new(())),
+		}
+	}
+
+	/// Returns the path of the file holding the matches of a day
+	fn file_path(&self, date: NaiveDate) -> PathBuf {
+		self.storage_path.join(format!(
+			"{}{}{}",
+			FILE_PREFIX,
+			date.format("%Y-%m-%d"),
+			FILE_EXTENSION
+		))
+	}
+
+	/// Returns the daily match files whose day overlaps the query, oldest first
+	async fn files_for_query(
+		&self,
+		query: &MatchQuery,
+	) -> Result<Vec<(NaiveDate, PathBuf)>, anyhow::Error> {
+		if !self.storage_path.exists() {
+			return Ok(Vec::new());
+		}
+
+		let mut files = Vec::new();
+		let mut entries = tokio::fs::read_dir(&self.storage_path)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read match store directory: {}", e))?;
+		while let Some(entry) = entries
+			.next_entry()
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read match store directory: {}", e))?
+		{
+			let file_name = entry.file_name();
+			let Some(date) = file_name
+				.to_str()
+				.and_then(|name| name.strip_prefix(FILE_PREFIX))
+				.and_then(|name| name.strip_suffix(FILE_EXTENSION))
+				.and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+			else {
+				continue;
+			};
+
+			let after_start = query.from.is_none_or(|from| date >= from.date_naive());
+			let before_end = query.to.is_none_or(|to| date <= to.date_naive());
+			if after_start && before_end {
+				files.push((date, entry.path()));
+			}
+		}
+
+		files.sort_by_key(|(date, _)| *date);
+		Ok(files)
+	}
+}
+
+impl Default for FileMatchStore {
+	/// Default implementation for FileMatchStore
+	///
+	/// Initializes storage with the default path "data/matches"
+	fn default() -> Self {
+		FileMatchStore::new(PathBuf::from("data/matches"))
+	}
+}
+
+#[async_trait]
+impl MatchStore for FileMatchStore {
+	/// Appends the record to the file of the day it was recorded on
+	async fn save(&self, record: &MatchRecord) -> Result<(), anyhow::Error> {
+		let mut line = serde_json::to_string(record)
+			.map_err(|e| anyhow::anyhow!("Failed to serialize match record: {}", e))?;
+		line.push('\n');
+
+		let _guard = self.write_lock.lock().await;
+		tokio::fs::create_dir_all(&self.storage_path)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to create match store directory: {}", e))?;
+
+		let mut file = tokio::fs::OpenOptions::new()
+			.create(true)
+			.append(true)
+			.open(self.file_path(record.timestamp.date_naive()))
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to open match file: {}", e))?;
+		file.write_all(line.as_bytes())
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to write match record: {}", e))?;
+		file.flush()
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to write match record: {}", e))?;
+
+		Ok(())
+	}
+
+	/// Reads the files of the days overlapping the query, skipping unparsable lines
+	async fn query(&self, query: &MatchQuery) -> Result<Vec<MatchRecord>, anyhow::Error> {
+		let mut records = Vec::new();
+
+		for (_, path) in self.files_for_query(query).await? {
+			let content = tokio::fs::read_to_string(&path)
+				.await
+				.map_err(|e| anyhow::anyhow!("Failed to read match file: {}", e))?;
+
+			for line in content.lines().filter(|line| !line.trim().is_empty()) {
+				match serde_json::from_str::<MatchRecord>(line) {
+					Ok(record) if query.matches(&record) => records.push(record),
+					Ok(_) => {}
+					Err(e) => {
+						tracing::warn!("Skipping invalid record in {}: {}", path.display(), e);
+					}
+				}
+			}
+		}
+
+		records.sort_by_key(|record| record.timestamp);
+		Ok(records)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::models::MatchConditions;
+	use chrono::{TimeZone, Utc};
+	use tempfile::TempDir;
+
+	fn create_record(monitor_name: &str, network_slug: &str, day: u32, hour: u32) -> MatchRecord {
+		MatchRecord {
+			monitor_name: monitor_name.to_string(),
+			network_slug: network_slug.to_string(),
+			block_number: 100 + u64::from(day),
+			transaction_hash: format!("0x{:02x}{:02x}", day, hour),
+			timestamp: Utc.with_ymd_and_hms(2025, 1, day, hour, 0, 0).unwrap(),
+			matched_on: MatchConditions::default(),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_save_and_query_round_trip() {
+		let temp_dir = TempDir::new().unwrap();
+		let store = FileMatchStore::new(temp_dir.path().join("matches"));
+
+		let records = vec![
+			create_record("Large Transfer", "ethereum_mainnet", 1, 10),
+			create_record("Approvals", "ethereum_mainnet", 1, 11),
+			create_record("Large Transfer", "stellar_mainnet", 1, 12),
+		];
+		for record in &records {
+			store.save(record).await.unwrap();
+		}
+
+		let all = store.query(&MatchQuery::default()).await.unwrap();
+		assert_eq!(all, records);
+
+		let filtered = store
+			.query(&MatchQuery {
+				network_slug: Some("ethereum_mainnet".to_string()),
+				monitor_name: Some("Large Transfer".to_string()),
+				..Default::default()
+			})
+			.await
+			.unwrap();
+		assert_eq!(filtered, vec![records[0].clone()]);
+	}
+
+	#[tokio::test]
+	async fn test_records_rotate_daily() {
+		let temp_dir = TempDir::new().unwrap();
+		let store = FileMatchStore::new(temp_dir.path().to_path_buf());
+
+		let first_day = create_record("Large Transfer", "ethereum_mainnet", 1, 23);
+		let second_day = create_record("Large Transfer", "ethereum_mainnet", 2, 0);
+		store.save(&second_day).await.unwrap();
+		store.save(&first_day).await.unwrap();
+
+		assert!(temp_dir.path().join("matches-2025-01-01.jsonl").exists());
+		assert!(temp_dir.path().join("matches-2025-01-02.jsonl").exists());
+
+		// Records are returned oldest first across files
+		let all = store.query(&MatchQuery::default()).await.unwrap();
+		assert_eq!(all, vec![first_day.clone(), second_day.clone()]);
+
+		let second_day_only = store
+			.query(&MatchQuery {
+				from: Some(Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap()),
+				..Default::default()
+			})
+			.await
+			.unwrap();
+		assert_eq!(second_day_only, vec![second_day]);
+
+		let first_day_only = store
+			.query(&MatchQuery {
+				to: Some(Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap()),
+				..Default::default()
+			})
+			.await
+			.unwrap();
+		assert_eq!(first_day_only, vec![first_day]);
+	}
+
+	#[tokio::test]
+	async fn test_query_empty_store() {
+		let temp_dir = TempDir::new().unwrap();
+		let store = FileMatchStore::new(temp_dir.path().join("missing"));
+
+		assert!(store
+			.query(&MatchQuery::default())
+			.await
+			.unwrap()
+			.is_empty());
+	}
+}
@@ -0,0 +1,248 @@
+//! Persistent history of monitor matches.
+//!
+//! Every match that passes its trigger conditions can be recorded to a [`MatchStore`] before
+//! its notifications are sent, giving a durable audit log that can be queried later. The
+//! following stores are available:
+//! - [`FileMatchStore`]: JSON lines files, one per day
+//! - [`SqliteMatchStore`]: SQLite database, behind the `sqlite` feature
+//!
+//! The store is selected with the `MATCH_STORE` environment variable, see
+//! [`match_store_from_env`].
+
+mod file;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{env, path::PathBuf, sync::Arc};
+
+use crate::{
+	models::{MatchConditions, MonitorMatch},
+	services::filter::evm_helpers::b256_to_string,
+};
+
+pub use file::FileMatchStore;
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteMatchStore;
+
+/// Environment variable selecting the match store, `file` or `sqlite`
+const MATCH_STORE_ENV: &str = "MATCH_STORE";
+
+/// Environment variable overriding the location of the match store
+const MATCH_STORE_PATH_ENV: &str = "MATCH_STORE_PATH";
+
+/// A recorded match
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchRecord {
+	/// Name of the monitor that matched
+	pub monitor_name: String,
+	/// Slug of the network the match happened on
+	pub network_slug: String,
+	/// Number of the block (or ledger, or slot) containing the matched transaction
+	pub block_number: u64,
+	/// Hash (or signature, for Solana) of the matched transaction
+	pub transaction_hash: String,
+	/// Time the match was recorded
+	pub timestamp: DateTime<Utc>,
+	/// Conditions that were matched
+	pub matched_on: MatchConditions,
+}
+
+impl MatchRecord {
+	/// Creates the record of a match
+	///
+	/// # Arguments
+	/// * `monitor_match` - The match to record
+	/// * `block_number` - Number of the processed block the match was found in
+	/// * `timestamp` - Time the match was recorded
+	pub fn new(monitor_match: &MonitorMatch, block_number: u64, timestamp: DateTime<Utc>) -> Self {
+		let (monitor, network_slug, transaction_hash, matched_on) = match monitor_match {
+			MonitorMatch::EVM(m) => (
+				&m.monitor,
+				&m.network_slug,
+				b256_to_string(*m.transaction.hash()),
+				&m.matched_on,
+			),
+			MonitorMatch::Stellar(m) => (
+				&m.monitor,
+				&m.network_slug,
+				m.transaction.hash().clone(),
+				&m.matched_on,
+			),
+			MonitorMatch::Midnight(m) => (
+				&m.monitor,
+				&m.network_slug,
+				m.transaction.hash().clone(),
+				&m.matched_on,
+			),
+			MonitorMatch::Solana(m) => (
+				&m.monitor,
+				&m.network_slug,
+				m.transaction.signature().to_string(),
+				&m.matched_on,
+			),
+		};
+
+		Self {
+			monitor_name: monitor.name.clone(),
+			network_slug: network_slug.clone(),
+			block_number,
+			transaction_hash,
+			timestamp,
+			matched_on: matched_on.clone(),
+		}
+	}
+}
+
+/// Filter of a match store query
+///
+/// Unset fields match every record.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MatchQuery {
+	/// Only return matches of this network
+	pub network_slug: Option<String>,
+	/// Only return matches of this monitor
+	pub monitor_name: Option<String>,
+	/// Only return matches recorded at or after this time
+	pub from: Option<DateTime<Utc>>,
+	/// Only return matches recorded before this time
+	pub to: Option<DateTime<Utc>>,
+}
+
+impl MatchQuery {
+	/// Returns whether a record passes the filter
+	pub fn matches(&self, record: &MatchRecord) -> bool {
+		self.network_slug
+			.as_ref()
+			.is_none_or(|slug| *slug == record.network_slug)
+			&& self
+				.monitor_name
+				.as_ref()
+				.is_none_or(|name| *name == record.monitor_name)
+			&& self.from.is_none_or(|from| record.timestamp >= from)
+			&& self.to.is_none_or(|to| record.timestamp < to)
+	}
+}
+
+/// Interface for match store implementations
+#[async_trait]
+pub trait MatchStore: Send + Sync {
+	/// Records a match
+	///
+	/// # Arguments
+	/// * `record` - The match to record
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error
+	async fn save(&self, record: &MatchRecord) -> Result<(), anyhow::Error>;
+
+	/// Returns the recorded matches passing a filter, oldest first
+	///
+	/// # Arguments
+	/// * `query` - Filter of the records to return
+	///
+	/// # Returns
+	/// * `Result<Vec<MatchRecord>, anyhow::Error>` - The matching records or error
+	async fn query(&self, query: &MatchQuery) -> Result<Vec<MatchRecord>, anyhow::Error>;
+}
+
+/// Creates the match store configured by the environment
+///
+/// `MATCH_STORE` selects the store: `file` stores matches in `data/matches/` and `sqlite` in
+/// `data/matches.db`. `MATCH_STORE_PATH` overrides the location.
+///
+/// # Returns
+/// * `Ok(None)` - If `MATCH_STORE` is not set
+/// * `Ok(Some(store))` - The configured store
+/// * `Err` - If the store type is unknown or the store can't be opened
+pub fn match_store_from_env() -> Result<Option<Arc<dyn MatchStore>>, anyhow::Error> {
+	let store_type = env::var(MATCH_STORE_ENV).unwrap_or_default();
+	let path = env::var(MATCH_STORE_PATH_ENV).ok().map(PathBuf::from);
+
+	match store_type.to_lowercase().as_str() {
+		"" => Ok(None),
+		"file" => Ok(Some(Arc::new(FileMatchStore::new(
+			path.unwrap_or_else(|| PathBuf::from("data/matches")),
+		)))),
+		#[cfg(feature = "sqlite")]
+		"sqlite" => Ok(Some(Arc::new(SqliteMatchStore::open(
+			path.unwrap_or_else(|| PathBuf::from("data/matches.db")),
+		)?))),
+		#[cfg(not(feature = "sqlite"))]
+		"sqlite" => Err(anyhow::anyhow!(
+			"The sqlite match store requires the `sqlite` feature"
+		)),
+		_ => Err(anyhow::anyhow!(
+			"Unknown match store '{}', expected 'file' or 'sqlite'",
+			store_type
+		)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::models::{EVMConfirmationState, EVMMonitorMatch};
+	use crate::utils::tests::builders::evm::{
+		monitor::MonitorBuilder, transaction::TransactionBuilder,
+	};
+	use chrono::TimeZone;
+
+	#[test]
+	fn test_match_record_from_evm_match() {
+		let transaction = TransactionBuilder::new().build();
+		let monitor_match = MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("Large Transfer").build(),
+			transaction: transaction.clone(),
+			receipt: None,
+			logs: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			confirmation_state: EVMConfirmationState::Confirmed,
+			severity: Default::default(),
+			sender_verified: None,
+		}));
+		let timestamp = Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+
+		let record = MatchRecord::new(&monitor_match, 100, timestamp);
+
+		assert_eq!(record.monitor_name, "Large Transfer");
+		assert_eq!(record.network_slug, "ethereum_mainnet");
+		assert_eq!(record.block_number, 100);
+		assert_eq!(record.transaction_hash, b256_to_string(*transaction.hash()));
+		assert_eq!(record.timestamp, timestamp);
+	}
+
+	#[test]
+	fn test_match_query_filters() {
+		let record = MatchRecord {
+			monitor_name: "Large Transfer".to_string(),
+			network_slug: "ethereum_mainnet".to_string(),
+			block_number: 100,
+			transaction_hash: "0x1".to_string(),
+			timestamp: Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap(),
+			matched_on: MatchConditions::default(),
+		};
+
+		assert!(MatchQuery::default().matches(&record));
+		assert!(MatchQuery {
+			network_slug: Some("ethereum_mainnet".to_string()),
+			from: Some(record.timestamp),
+			..Default::default()
+		}
+		.matches(&record));
+		assert!(!MatchQuery {
+			monitor_name: Some("Approvals".to_string()),
+			..Default::default()
+		}
+		.matches(&record));
+		assert!(!MatchQuery {
+			to: Some(record.timestamp),
+			..Default::default()
+		}
+		.matches(&record));
+	}
+}
@@ -0,0 +1,244 @@
+//! SQLite match store.
+//!
+//! Matches are kept in a single `matches` table indexed by time, network and monitor.
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use rusqlite::{params, types::Value as SqlValue, Connection};
+use std::{
+	path::PathBuf,
+	sync::{Arc, Mutex},
+};
+
+use crate::services::storage::{MatchQuery, MatchRecord, MatchStore};
+
+/// Statements creating the schema of the store
+const SCHEMA: &str = "
+	CREATE TABLE IF NOT EXISTS matches (
+		id INTEGER PRIMARY KEY AUTOINCREMENT,
+		monitor_name TEXT NOT NULL,
+		network_slug TEXT NOT NULL,
+		block_number INTEGER NOT NULL,
+		transaction_hash TEXT NOT NULL,
+		timestamp_ms INTEGER NOT NULL,
+		matched_on TEXT NOT NULL
+	);
+	CREATE INDEX IF NOT EXISTS matches_timestamp ON matches (timestamp_ms);
+	CREATE INDEX IF NOT EXISTS matches_network_monitor ON matches (network_slug, monitor_name);
+";
+
+/// SQLite implementation of the match store
+#[derive(Clone)]
+pub struct SqliteMatchStore {
+	connection: Arc<Mutex<Connection>>,
+}
+
+impl SqliteMatchStore {
+	/// Opens the database at `path`, creating it and its parent directory if needed
+	pub fn open(path: PathBuf) -> Result<Self, anyhow::Error> {
+		if let Some(parent) = path
+			.parent()
+			.filter(|parent| !parent.as_os_str().is_empty())
+		{
+			std::fs::create_dir_all(parent)
+				.map_err(|e| anyhow::anyhow!("Failed to create match store directory: {}", e))?;
+		}
+
+		let connection = Connection::open(&path)
+			.map_err(|e| anyhow::anyhow!("Failed to open match store database: {}", e))?;
+		Self::from_connection(connection)
+	}
+
+	/// Creates a store backed by an in-memory database
+	pub fn in_memory() -> Result<Self, anyhow::Error> {
+		let connection = Connection::open_in_memory()
+			.map_err(|e| anyhow::anyhow!("Failed to open match store database: {}", e))?;
+		Self::from_connection(connection)
+	}
+
+	fn from_connection(connection: Connection) -> Result<Self, anyhow::Error> {
+		connection
+			.execute_batch(SCHEMA)
+			.map_err(|e| anyhow::anyhow!("Failed to create match store schema: {}", e))?;
+		Ok(Self {
+			connection: Arc::new(Mutex::new(connection)),
+		})
+	}
+
+	/// Runs a blocking operation on the connection outside of the async runtime
+	async fn with_connection<T, F>(&self, operation: F) -> Result<T, anyhow::Error>
+	where
+		T: Send + 'static,
+		F: FnOnce(&Connection) -> Result<T, anyhow::Error> + Send + 'static,
+	{
+		let connection = self.connection.clone();
+		tokio::task::spawn_blocking(move || {
+			let connection = connection
+				.lock()
+				.unwrap_or_else(|poisoned| poisoned.into_inner());
+			operation(&connection)
+		})
+		.await
+		.map_err(|e| anyhow::anyhow!("Match store task failed: {}", e))?
+	}
+}
+
+#[async_trait]
+impl MatchStore for SqliteMatchStore {
+	async fn save(&self, record: &MatchRecord) -> Result<(), anyhow::Error> {
+		let record = record.clone();
+		let matched_on = serde_json::to_string(&record.matched_on)
+			.map_err(|e| anyhow::anyhow!("Failed to serialize matched conditions: {}", e))?;
+
+		self.with_connection(move |connection| {
+			connection
+				.execute(
+					"INSERT INTO matches (monitor_name, network_slug, block_number, \
+					 transaction_hash, timestamp_ms, matched_on) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+					params![
+						record.monitor_name,
+						record.network_slug,
+						record.block_number as i64,
+						record.transaction_hash,
+						record.timestamp.timestamp_millis(),
+						matched_on,
+					],
+				)
+				.map_err(|e| anyhow::anyhow!("Failed to write match record: {}", e))?;
+			Ok(())
+		})
+		.await
+	}
+
+	async fn query(&self, query: &MatchQuery) -> Result<Vec<MatchRecord>, anyhow::Error> {
+		let mut conditions = Vec::new();
+		let mut values: Vec<SqlValue> = Vec::new();
+		if let Some(network_slug) = &query.network_slug {
+			conditions.push("network_slug = ?");
+			values.push(SqlValue::Text(network_slug.clone()));
+		}
+		if let Some(monitor_name) = &query.monitor_name {
+			conditions.push("monitor_name = ?");
+			values.push(SqlValue::Text(monitor_name.clone()));
+		}
+		if let Some(from) = query.from {
+			conditions.push("timestamp_ms >= ?");
+			values.push(SqlValue::Integer(from.timestamp_millis()));
+		}
+		if let Some(to) = query.to {
+			conditions.push("timestamp_ms < ?");
+			values.push(SqlValue::Integer(to.timestamp_millis()));
+		}
+
+		let mut sql = "SELECT monitor_name, network_slug, block_number, transaction_hash, \
+		               timestamp_ms, matched_on FROM matches"
+			.to_string();
+		if !conditions.is_empty() {
+			sql.push_str(" WHERE ");
+			sql.push_str(&conditions.join(" AND "));
+		}
+		sql.push_str(" ORDER BY timestamp_ms, id");
+
+		self.with_connection(move |connection| {
+			let mut statement = connection
+				.prepare(&sql)
+				.map_err(|e| anyhow::anyhow!("Failed to query match records: {}", e))?;
+			let rows = statement
+				.query_map(rusqlite::params_from_iter(values), |row| {
+					Ok((
+						row.get::<_, String>(0)?,
+						row.get::<_, String>(1)?,
+						row.get::<_, i64>(2)?,
+						row.get::<_, String>(3)?,
+						row.get::<_, i64>(4)?,
+						row.get::<_, String>(5)?,
+					))
+				})
+				.map_err(|e| anyhow::anyhow!("Failed to query match records: {}", e))?;
+
+			let mut records = Vec::new();
+			for row in rows {
+				let (monitor_name, network_slug, block_number, transaction_hash, timestamp, conds) =
+					row.map_err(|e| anyhow::anyhow!("Failed to read match record: {}", e))?;
+				records.push(MatchRecord {
+					monitor_name,
+					network_slug,
+					block_number: block_number as u64,
+					transaction_hash,
+					timestamp: DateTime::from_timestamp_millis(timestamp).ok_or_else(|| {
+						anyhow::anyhow!("Invalid match record timestamp: {}", timestamp)
+					})?,
+					matched_on: serde_json::from_str(&conds).map_err(|e| {
+						anyhow::anyhow!("Failed to parse matched conditions: {}", e)
+					})?,
+				});
+			}
+			Ok(records)
+		})
+		.await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::models::MatchConditions;
+	use chrono::{TimeZone, Utc};
+
+	fn create_record(monitor_name: &str, network_slug: &str, day: u32) -> MatchRecord {
+		MatchRecord {
+			monitor_name: monitor_name.to_string(),
+			network_slug: network_slug.to_string(),
+			block_number: 100 + u64::from(day),
+			transaction_hash: format!("0x{:02x}", day),
+			timestamp: Utc.with_ymd_and_hms(2025, 1, day, 12, 0, 0).unwrap(),
+			matched_on: MatchConditions::default(),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_save_and_query_round_trip() {
+		let store = SqliteMatchStore::in_memory().unwrap();
+
+		let records = vec![
+			create_record("Large Transfer", "ethereum_mainnet", 1),
+			create_record("Approvals", "ethereum_mainnet", 2),
+			create_record("Large Transfer", "stellar_mainnet", 3),
+		];
+		for record in &records {
+			store.save(record).await.unwrap();
+		}
+
+		let all = store.query(&MatchQuery::default()).await.unwrap();
+		assert_eq!(all, records);
+
+		let filtered = store
+			.query(&MatchQuery {
+				network_slug: Some("ethereum_mainnet".to_string()),
+				from: Some(Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap()),
+				..Default::default()
+			})
+			.await
+			.unwrap();
+		assert_eq!(filtered, vec![records[1].clone()]);
+	}
+
+	#[tokio::test]
+	async fn test_open_persists_records() {
+		let temp_dir = tempfile::TempDir::new().unwrap();
+		let path = temp_dir.path().join("store").join("matches.db");
+		let record = create_record("Large Transfer", "ethereum_mainnet", 1);
+
+		SqliteMatchStore::open(path.clone())
+			.unwrap()
+			.save(&record)
+			.await
+			.unwrap();
+
+		let reopened = SqliteMatchStore::open(path).unwrap();
+		assert_eq!(
+			reopened.query(&MatchQuery::default()).await.unwrap(),
+			vec![record]
+		);
+	}
+}
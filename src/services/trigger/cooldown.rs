@@ -0,0 +1,243 @@
+//! Cooldown of the notifications of monitors.
+//!
+//! A monitor with a `cooldown_ms` notifies a match, then drops the further matches with the same
+//! dedup key until the cooldown expires. The dedup key is rendered from the monitor's
+//! `dedup_key` template with the variables of the match, all the matches of the monitor sharing
+//! a key when it isn't set, see [`TriggerExecutionService`](super::TriggerExecutionService).
+//!
+//! The keys in cooldown are kept in memory, so they are lost on restart. The keys of the
+//! monitors with `persist_cooldown` are also saved to a block storage, from which they are
+//! restored on startup, so that a restart doesn't notify again the matches notified right
+//! before it. Keys are saved by a background task, so that matches aren't held up by the
+//! storage. At most [`MAX_DEDUP_KEYS`] keys are kept, the keys expiring first being dropped.
+
+use chrono::{DateTime, Utc};
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::services::blockwatcher::{
+	create_dedup_key_writer, BlockStorage, DedupKeyWriter, MAX_DEDUP_KEYS,
+};
+
+/// Request to the task persisting the dedup keys
+enum PersistRequest {
+	/// Saves a key with the time its cooldown expires at
+	Save(String, DateTime<Utc>),
+	/// Signals once the keys requested before are saved
+	Flush(oneshot::Sender<()>),
+}
+
+/// Dedup keys of the notified matches in cooldown
+#[derive(Default)]
+pub struct NotificationCooldown {
+	/// Time the cooldown of each dedup key expires at
+	keys: Mutex<HashMap<String, DateTime<Utc>>>,
+	/// Task persisting the keys of the monitors with `persist_cooldown`, once restored
+	persister: Mutex<Option<mpsc::UnboundedSender<PersistRequest>>>,
+}
+
+impl NotificationCooldown {
+	/// Creates a cooldown keeping its keys in memory only
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Restores the keys in cooldown persisted in a block storage, which then persists the
+	/// keys of the monitors with `persist_cooldown`
+	///
+	/// # Arguments
+	/// * `storage` - Storage the keys are persisted in
+	/// * `now` - Current time, the keys expired at it being dropped
+	pub async fn restore<S: BlockStorage + 'static>(
+		&self,
+		storage: Arc<S>,
+		now: DateTime<Utc>,
+	) -> Result<(), anyhow::Error> {
+		let persisted = storage.get_dedup_keys().await?;
+		{
+			let mut keys = self.keys.lock().unwrap();
+			for (dedup_key, expires_at) in persisted {
+				let entry = keys.entry(dedup_key).or_insert(expires_at);
+				*entry = (*entry).max(expires_at);
+			}
+			prune(&mut keys, now);
+		}
+		*self.persister.lock().unwrap() = Some(spawn_persister(create_dedup_key_writer(storage)));
+		Ok(())
+	}
+
+	/// Waits for the keys in cooldown to be persisted, e.g. before shutting down
+	pub async fn flush(&self) {
+		let persister = self.persister.lock().unwrap().clone();
+		let Some(persister) = persister else {
+			return;
+		};
+		let (flushed_tx, flushed_rx) = oneshot::channel();
+		if persister.send(PersistRequest::Flush(flushed_tx)).is_ok() {
+			let _ = flushed_rx.await;
+		}
+	}
+
+	/// Records a match, deciding whether it is dropped as a duplicate
+	///
+	/// A match whose key isn't in cooldown is notified, and its key enters the cooldown.
+	///
+	/// # Arguments
+	/// * `dedup_key` - Dedup key of the match
+	/// * `cooldown` - Cooldown of the monitor of the match
+	/// * `persist` - Whether the key is persisted, if the cooldown was restored from a storage
+	/// * `now` - Time of the match
+	///
+	/// # Returns
+	/// Whether the match is dropped
+	pub fn check(
+		&self,
+		dedup_key: &str,
+		cooldown: Duration,
+		persist: bool,
+		now: DateTime<Utc>,
+	) -> bool {
+		let expires_at = now + cooldown;
+		{
+			let mut keys = self.keys.lock().unwrap();
+			if keys
+				.get(dedup_key)
+				.is_some_and(|in_cooldown_until| *in_cooldown_until > now)
+			{
+				return true;
+			}
+			keys.insert(dedup_key.to_string(), expires_at);
+			prune(&mut keys, now);
+		}
+
+		if persist {
+			if let Some(persister) = self.persister.lock().unwrap().as_ref() {
+				let _ = persister.send(PersistRequest::Save(dedup_key.to_string(), expires_at));
+			}
+		}
+		false
+	}
+}
+
+/// Spawns the task persisting the dedup keys with a writer, in the order they are sent
+fn spawn_persister(writer: DedupKeyWriter) -> mpsc::UnboundedSender<PersistRequest> {
+	let (sender, mut receiver) = mpsc::unbounded_channel();
+	tokio::spawn(async move {
+		while let Some(request) = receiver.recv().await {
+			match request {
+				PersistRequest::Save(dedup_key, expires_at) => {
+					// The match was notified even if its key can't be persisted
+					if let Err(e) = writer(dedup_key.clone(), expires_at).await {
+						tracing::error!("Failed to save dedup key '{}': {}", dedup_key, e);
+					}
+				}
+				PersistRequest::Flush(flushed) => {
+					let _ = flushed.send(());
+				}
+			}
+		}
+	});
+	sender
+}
+
+/// Drops the expired keys, and the keys expiring first beyond [`MAX_DEDUP_KEYS`]
+fn prune(keys: &mut HashMap<String, DateTime<Utc>>, now: DateTime<Utc>) {
+	keys.retain(|_, expires_at| *expires_at > now);
+	while keys.len() > MAX_DEDUP_KEYS {
+		let Some(first) = keys
+			.iter()
+			.min_by_key(|(_, expires_at)| **expires_at)
+			.map(|(dedup_key, _)| dedup_key.clone())
+		else {
+			break;
+		};
+		keys.remove(&first);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::services::blockwatcher::FileBlockStorage;
+
+	const COOLDOWN: Duration = Duration::from_secs(600);
+
+	#[test]
+	fn test_check_drops_duplicates_during_cooldown() {
+		let cooldown = NotificationCooldown::new();
+		let start = Utc::now();
+
+		assert!(!cooldown.check("transfers:0xabc", COOLDOWN, false, start));
+		assert!(cooldown.check(
+			"transfers:0xabc",
+			COOLDOWN,
+			false,
+			start + Duration::from_secs(599)
+		));
+		// Keys are in cooldown independently
+		assert!(!cooldown.check("transfers:0xdef", COOLDOWN, false, start));
+		assert!(!cooldown.check("transfers:0xabc", COOLDOWN, false, start + COOLDOWN));
+	}
+
+	#[tokio::test]
+	async fn test_restart_within_cooldown_still_drops_duplicates() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+		let start = Utc::now();
+
+		let cooldown = NotificationCooldown::new();
+		cooldown.restore(storage.clone(), start).await.unwrap();
+		assert!(!cooldown.check("persisted:0xabc", COOLDOWN, true, start));
+		assert!(!cooldown.check("in_memory:0xabc", COOLDOWN, false, start));
+
+		cooldown.flush().await;
+
+		// After a restart, only the persisted key is still in cooldown
+		let restarted = NotificationCooldown::new();
+		let later = start + Duration::from_secs(60);
+		restarted.restore(storage, later).await.unwrap();
+		assert!(restarted.check("persisted:0xabc", COOLDOWN, true, later));
+		assert!(!restarted.check("in_memory:0xabc", COOLDOWN, false, later));
+		assert!(!restarted.check("persisted:0xabc", COOLDOWN, true, start + COOLDOWN));
+	}
+
+	#[tokio::test]
+	async fn test_restore_prunes_keys_at_given_time() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+		// Long expired according to the system time
+		let start = Utc::now() - chrono::Duration::days(1);
+
+		let cooldown = NotificationCooldown::new();
+		cooldown.restore(storage.clone(), start).await.unwrap();
+		assert!(!cooldown.check("persisted:0xabc", COOLDOWN, true, start));
+		cooldown.flush().await;
+
+		let restarted = NotificationCooldown::new();
+		let later = start + Duration::from_secs(60);
+		restarted.restore(storage.clone(), later).await.unwrap();
+		assert!(restarted.check("persisted:0xabc", COOLDOWN, true, later));
+
+		let expired = NotificationCooldown::new();
+		expired.restore(storage, start + COOLDOWN).await.unwrap();
+		assert!(expired.keys.lock().unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_prune_bounds_keys() {
+		let now = Utc::now();
+		let mut keys: HashMap<String, DateTime<Utc>> = (0..=MAX_DEDUP_KEYS as u64)
+			.map(|index| (index.to_string(), now + Duration::from_secs(index + 1)))
+			.collect();
+		keys.insert("expired".to_string(), now);
+
+		prune(&mut keys, now);
+		assert_eq!(keys.len(), MAX_DEDUP_KEYS);
+		assert!(!keys.contains_key("expired"));
+		assert!(!keys.contains_key("0"));
+	}
+}
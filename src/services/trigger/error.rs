@@ -3,7 +3,7 @@
 //! Provides error types for trigger-related operations,
 //! including execution failures and configuration issues.
 
-use crate::utils::logging::error::{ErrorContext, TraceableError};
+use crate::utils::logging::error::{ErrorCode, ErrorContext, TraceableError};
 use std::collections::HashMap;
 use thiserror::Error as ThisError;
 use uuid::Uuid;
@@ -12,15 +12,15 @@ use uuid::Uuid;
 #[derive(ThisError, Debug)]
 pub enum TriggerError {
 	/// Errors related to not found errors
-	#[error("Not found error: {0}")]
+	#[error("[{}] Not found error: {0}", ErrorCode::TriggerNotFound)]
 	NotFound(ErrorContext),
 
 	/// Errors related to execution failures
-	#[error("Execution error: {0}")]
+	#[error("[{}] Execution error: {0}", ErrorCode::TriggerExecution)]
 	ExecutionError(ErrorContext),
 
 	/// Errors related to configuration errors
-	#[error("Configuration error: {0}")]
+	#[error("[{}] Configuration error: {0}", ErrorCode::TriggerConfiguration)]
 	ConfigurationError(ErrorContext),
 
 	/// Other errors that don't fit into the categories above
@@ -35,7 +35,11 @@ impl TriggerError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::NotFound(ErrorContext::new_with_log(msg, source, metadata))
+		Self::NotFound(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::TriggerNotFound)
+				.log(),
+		)
 	}
 
 	// Execution error
@@ -44,7 +48,11 @@ impl TriggerError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::ExecutionError(ErrorContext::new_with_log(msg, source, metadata))
+		Self::ExecutionError(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::TriggerExecution)
+				.log(),
+		)
 	}
 
 	// Execution error without logging
@@ -53,7 +61,9 @@ impl TriggerError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::ExecutionError(ErrorContext::new(msg, source, metadata))
+		Self::ExecutionError(
+			ErrorContext::new(msg, source, metadata).with_code(ErrorCode::TriggerExecution),
+		)
 	}
 
 	// Configuration error
@@ -62,7 +72,21 @@ impl TriggerError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::ConfigurationError(ErrorContext::new_with_log(msg, source, metadata))
+		Self::ConfigurationError(
+			ErrorContext::new(msg, source, metadata)
+				.with_code(ErrorCode::TriggerConfiguration)
+				.log(),
+		)
+	}
+
+	/// Returns the stable code identifying the kind of error
+	pub fn code(&self) -> ErrorCode {
+		match self {
+			Self::NotFound(_) => ErrorCode::TriggerNotFound,
+			Self::ExecutionError(_) => ErrorCode::TriggerExecution,
+			Self::ConfigurationError(_) => ErrorCode::TriggerConfiguration,
+			Self::Other(_) => ErrorCode::TriggerOther,
+		}
 	}
 }
 
@@ -85,7 +109,10 @@ mod tests {
 	#[test]
 	fn test_not_found_error_formatting() {
 		let error = TriggerError::not_found("test error", None, None);
-		assert_eq!(error.to_string(), "Not found error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[TRIGGER_NOT_FOUND] Not found error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = TriggerError::not_found(
@@ -95,14 +122,17 @@ mod tests {
 		);
 		assert_eq!(
 			error.to_string(),
-			"Not found error: test error [key1=value1]"
+			"[TRIGGER_NOT_FOUND] Not found error: test error [key1=value1]"
 		);
 	}
 
 	#[test]
 	fn test_execution_error_formatting() {
 		let error = TriggerError::execution_error("test error", None, None);
-		assert_eq!(error.to_string(), "Execution error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[TRIGGER_EXECUTION] Execution error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = TriggerError::execution_error(
@@ -112,14 +142,17 @@ mod tests {
 		);
 		assert_eq!(
 			error.to_string(),
-			"Execution error: test error [key1=value1]"
+			"[TRIGGER_EXECUTION] Execution error: test error [key1=value1]"
 		);
 	}
 
 	#[test]
 	fn test_internal_error_formatting() {
 		let error = TriggerError::configuration_error("test error", None, None);
-		assert_eq!(error.to_string(), "Configuration error: test error");
+		assert_eq!(
+			error.to_string(),
+			"[TRIGGER_CONFIGURATION] Configuration error: test error"
+		);
 
 		let source_error = IoError::new(ErrorKind::NotFound, "test source");
 		let error = TriggerError::configuration_error(
@@ -129,7 +162,7 @@ mod tests {
 		);
 		assert_eq!(
 			error.to_string(),
-			"Configuration error: test error [key1=value1]"
+			"[TRIGGER_CONFIGURATION] Configuration error: test error [key1=value1]"
 		);
 	}
 
@@ -139,6 +172,42 @@ mod tests {
 		let trigger_error: TriggerError = anyhow_error.into();
 		assert!(matches!(trigger_error, TriggerError::Other(_)));
 		assert_eq!(trigger_error.to_string(), "test anyhow error");
+		assert_eq!(trigger_error.code(), ErrorCode::TriggerOther);
+	}
+
+	#[test]
+	fn test_error_codes() {
+		let errors = vec![
+			(
+				TriggerError::not_found("test error", None, None),
+				ErrorCode::TriggerNotFound,
+			),
+			(
+				TriggerError::execution_error("test error", None, None),
+				ErrorCode::TriggerExecution,
+			),
+			(
+				TriggerError::execution_error_without_log("test error", None, None),
+				ErrorCode::TriggerExecution,
+			),
+			(
+				TriggerError::configuration_error("test error", None, None),
+				ErrorCode::TriggerConfiguration,
+			),
+		];
+
+		for (error, code) in errors {
+			assert_eq!(error.code(), code);
+			assert!(error.to_string().starts_with(&format!("[{}] ", code)));
+		}
+
+		// The code is also set on the context, for structured logs
+		let error = TriggerError::not_found("test error", None, None);
+		if let TriggerError::NotFound(ctx) = &error {
+			assert_eq!(ctx.code, Some(ErrorCode::TriggerNotFound));
+		} else {
+			panic!("Expected NotFound variant");
+		}
 	}
 
 	#[test]
@@ -4,13 +4,26 @@
 //! which are configurable actions that can be initiated based on
 //! various conditions.
 
+mod cooldown;
 mod error;
+mod retry;
 mod script;
 mod service;
+mod throttle;
 
+pub use cooldown::NotificationCooldown;
 pub use error::TriggerError;
+pub use retry::{
+	DeadLetter, DeadLetterSink, FileDeadLetterSink, NotificationRetryConfig, NotificationRetryQueue,
+};
 pub use script::{
-	process_script_output, validate_script_config, ScriptError, ScriptExecutor,
-	ScriptExecutorFactory,
+	process_script_output, read_script_content, register_script_executor, script_input,
+	validate_script_config, validate_script_limits, ScriptError, ScriptExecutor,
+	ScriptExecutorConstructor, ScriptExecutorFactory, ScriptLimits, WasmScriptExecutor,
+	DEFAULT_MAX_OUTPUT_BYTES, MAX_BLOCK_CONTEXT_BYTES, WASM_FUEL_PER_MS,
+};
+pub use service::{
+	collapse_trigger_results, TriggerExecutionService, TriggerExecutionServiceTrait,
+	TriggerOutcome, TriggerResult,
 };
-pub use service::{TriggerExecutionService, TriggerExecutionServiceTrait};
+pub use throttle::{NotificationThrottle, ThrottleDecision, THROTTLE_WINDOW};
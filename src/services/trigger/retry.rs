@@ -0,0 +1,300 @@
+//! Background retries of failed notifications.
+//!
+//! Notifications that fail while a match is handled, and digests that fail once their batch
+//! window elapses, are handed to a [`NotificationRetryQueue`].
+//! Its background task retries them with exponential backoff, without holding up block
+//! processing, and writes the notifications still failing after the last retry to a
+//! [`DeadLetterSink`].
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, env, path::PathBuf, sync::Arc, time::Duration};
+use tokio::{
+	io::AsyncWriteExt,
+	sync::{mpsc, Mutex},
+};
+
+use crate::{
+	models::MonitorMatch,
+	services::notification::{FailedNotification, NotificationService},
+	utils::metrics::NOTIFICATIONS_DEAD_LETTERED_TOTAL,
+};
+
+/// Environment variable overriding the number of retries of failed notifications
+const NOTIFICATION_MAX_RETRIES_ENV: &str = "NOTIFICATION_MAX_RETRIES";
+
+/// Environment variable overriding the file dead letters are appended to
+const NOTIFICATION_DEAD_LETTER_PATH_ENV: &str = "NOTIFICATION_DEAD_LETTER_PATH";
+
+/// Retry policy of failed notifications
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationRetryConfig {
+	/// Number of retries before a notification is dead-lettered
+	pub max_retries: u32,
+	/// Delay before the first retry, doubled after every failed retry
+	pub initial_backoff: Duration,
+	/// Upper bound of the delay between two retries
+	pub max_backoff: Duration,
+}
+
+impl Default for NotificationRetryConfig {
+	fn default() -> Self {
+		Self {
+			max_retries: 3,
+			initial_backoff: Duration::from_secs(5),
+			max_backoff: Duration::from_secs(300),
+		}
+	}
+}
+
+impl NotificationRetryConfig {
+	/// Returns the delay before a retry, `retry` starting at 0
+	fn backoff(&self, retry: u32) -> Duration {
+		self.initial_backoff
+			.saturating_mul(2u32.saturating_pow(retry))
+			.min(self.max_backoff)
+	}
+}
+
+/// A notification that still failed after its last retry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetter {
+	/// Name of the trigger sending the notification
+	pub trigger_name: String,
+	/// Number of attempts made to send the notification
+	pub attempts: u32,
+	/// Error of the last attempt
+	pub error: String,
+	/// Time the notification was dead-lettered
+	pub timestamp: DateTime<Utc>,
+	/// Variables substituted in the trigger templates
+	pub variables: HashMap<String, String>,
+	/// Match the notification was sent for
+	pub monitor_match: MonitorMatch,
+}
+
+/// Destination of the notifications that permanently failed
+#[async_trait]
+pub trait DeadLetterSink: Send + Sync {
+	/// Records a notification that permanently failed
+	///
+	/// # Arguments
+	/// * `dead_letter` - The failed notification
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error
+	async fn write(&self, dead_letter: &DeadLetter) -> Result<(), anyhow::Error>;
+}
+
+/// Dead letter sink appending JSON lines to a file
+pub struct FileDeadLetterSink {
+	/// File the dead letters are appended to
+	path: PathBuf,
+	/// Serializes appends so that concurrent dead letters don't interleave
+	write_lock: Mutex<()>,
+}
+
+impl FileDeadLetterSink {
+	/// Creates a sink appending to `path`, created with its parent directory on the first write
+	pub fn new(path: PathBuf) -> Self {
+		Self {
+			path,
+			write_lock: Mutex::new(()),
+		}
+	}
+}
+
+#[async_trait]
+impl DeadLetterSink for FileDeadLetterSink {
+	async fn write(&self, dead_letter: &DeadLetter) -> Result<(), anyhow::Error> {
+		let mut line = serde_json::to_string(dead_letter)
+			.map_err(|e| anyhow::anyhow!("Failed to serialize dead letter: {}", e))?;
+		line.push('\n');
+
+		let _guard = self.write_lock.lock().await;
+		if let Some(parent) = self
+			.path
+			.parent()
+			.filter(|parent| !parent.as_os_str().is_empty())
+		{
+			tokio::fs::create_dir_all(parent)
+				.await
+				.map_err(|e| anyhow::anyhow!("Failed to create dead letter directory: {}", e))?;
+		}
+
+		let mut file = tokio::fs::OpenOptions::new()
+			.create(true)
+			.append(true)
+			.open(&self.path)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to open dead letter file: {}", e))?;
+		file.write_all(line.as_bytes())
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to write dead letter: {}", e))?;
+		file.flush()
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to write dead letter: {}", e))?;
+
+		Ok(())
+	}
+}
+
+/// Queue of failed notifications retried by a background task
+#[derive(Clone)]
+pub struct NotificationRetryQueue {
+	sender: mpsc::UnboundedSender<FailedNotification>,
+}
+
+impl NotificationRetryQueue {
+	/// Creates the queue and spawns its background task
+	///
+	/// Every queued notification is retried concurrently with the others, so that a slow
+	/// endpoint doesn't delay the retries of other triggers.
+	///
+	/// # Arguments
+	/// * `notification_service` - Service sending the retried notifications
+	/// * `config` - Retry policy of the failed notifications
+	/// * `sink` - Destination of the notifications failing after their last retry
+	pub fn spawn(
+		notification_service: NotificationService,
+		config: NotificationRetryConfig,
+		sink: Arc<dyn DeadLetterSink>,
+	) -> Self {
+		let (sender, mut receiver) = mpsc::unbounded_channel::<FailedNotification>();
+		let notification_service = Arc::new(notification_service);
+
+		tokio::spawn(async move {
+			while let Some(notification) = receiver.recv().await {
+				tokio::spawn(retry_notification(
+					notification_service.clone(),
+					config.clone(),
+					sink.clone(),
+					notification,
+				));
+			}
+		});
+
+		Self { sender }
+	}
+
+	/// Creates the queue configured by the environment
+	///
+	/// `NOTIFICATION_MAX_RETRIES` sets the number of retries, 3 by default, and
+	/// `NOTIFICATION_DEAD_LETTER_PATH` the file the dead letters are appended to,
+	/// `data/dead_letters.jsonl` by default.
+	///
+	/// # Errors
+	/// Returns an error if `NOTIFICATION_MAX_RETRIES` is not a number
+	pub fn from_env(notification_service: NotificationService) -> Result<Self, anyhow::Error> {
+		let mut config = NotificationRetryConfig::default();
+		if let Ok(max_retries) = env::var(NOTIFICATION_MAX_RETRIES_ENV) {
+			config.max_retries = max_retries
+				.parse()
+				.map_err(|e| anyhow::anyhow!("Invalid {}: {}", NOTIFICATION_MAX_RETRIES_ENV, e))?;
+		}
+		let path = env::var(NOTIFICATION_DEAD_LETTER_PATH_ENV)
+			.map(PathBuf::from)
+			.unwrap_or_else(|_| PathBuf::from("data/dead_letters.jsonl"));
+
+		Ok(Self::spawn(
+			notification_service,
+			config,
+			Arc::new(FileDeadLetterSink::new(path)),
+		))
+	}
+
+	/// Queues a failed notification for retry
+	pub fn enqueue(&self, notification: FailedNotification) {
+		if self.sender.send(notification).is_err() {
+			tracing::error!("Notification retry queue is closed, dropping failed notification");
+		}
+	}
+}
+
+/// Retries a notification until it is sent or its retries are exhausted
+async fn retry_notification(
+	notification_service: Arc<NotificationService>,
+	config: NotificationRetryConfig,
+	sink: Arc<dyn DeadLetterSink>,
+	notification: FailedNotification,
+) {
+	let FailedNotification {
+		trigger,
+		variables,
+		monitor_match,
+		trigger_scripts,
+		mut error,
+	} = notification;
+
+	for retry in 0..config.max_retries {
+		tokio::time::sleep(config.backoff(retry)).await;
+		match notification_service
+			.execute_now(&trigger, &variables, &monitor_match, &trigger_scripts)
+			.await
+		{
+			Ok(()) => {
+				tracing::info!(
+					"Notification of trigger {} sent after {} retries",
+					trigger.name,
+					retry + 1
+				);
+				return;
+			}
+			Err(e) => {
+				tracing::warn!(
+					"Retry {} of notification of trigger {} failed: {}",
+					retry + 1,
+					trigger.name,
+					e
+				);
+				error = e.to_string();
+			}
+		}
+	}
+
+	NOTIFICATIONS_DEAD_LETTERED_TOTAL
+		.with_label_values(&[trigger.trigger_type.as_str()])
+		.inc();
+	tracing::error!(
+		"Notification of trigger {} failed after {} attempts, writing it to the dead letters",
+		trigger.name,
+		config.max_retries + 1
+	);
+
+	let dead_letter = DeadLetter {
+		trigger_name: trigger.name,
+		attempts: config.max_retries + 1,
+		error,
+		timestamp: Utc::now(),
+		variables,
+		monitor_match,
+	};
+	if let Err(e) = sink.write(&dead_letter).await {
+		tracing::error!(
+			"Failed to write dead letter of trigger {}: {}",
+			dead_letter.trigger_name,
+			e
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_backoff_doubles_up_to_max() {
+		let config = NotificationRetryConfig {
+			max_retries: 10,
+			initial_backoff: Duration::from_secs(5),
+			max_backoff: Duration::from_secs(30),
+		};
+
+		assert_eq!(config.backoff(0), Duration::from_secs(5));
+		assert_eq!(config.backoff(1), Duration::from_secs(10));
+		assert_eq!(config.backoff(2), Duration::from_secs(20));
+		assert_eq!(config.backoff(3), Duration::from_secs(30));
+		assert_eq!(config.backoff(40), Duration::from_secs(30));
+	}
+}
@@ -2,11 +2,65 @@
 //!
 //! This module provides functionality to execute scripts in different languages.
 
-use crate::models::MonitorMatch;
+use crate::{
+	models::{BlockType, MonitorMatch},
+	services::trigger::script::ScriptError,
+};
 use anyhow::Context;
 use async_trait::async_trait;
 use std::{any::Any, process::Stdio, time::Duration};
-use tokio::{io::AsyncWriteExt, time::timeout};
+use tokio::{
+	io::{AsyncRead, AsyncReadExt, AsyncWriteExt},
+	process::Command,
+	time::timeout,
+};
+
+/// Maximum size of the output of the scripts that don't configure one, on stdout and stderr each
+pub const DEFAULT_MAX_OUTPUT_BYTES: u64 = 1024 * 1024;
+
+/// Maximum size of the JSON of the block passed to the scripts with `include_block_context`
+pub const MAX_BLOCK_CONTEXT_BYTES: usize = 16 * 1024 * 1024;
+
+/// Resource limits of a script process
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScriptLimits {
+	/// Maximum memory of the process in megabytes, unbounded when unset
+	///
+	/// The limit is set with `RLIMIT_AS` and is only enforced on Unix systems. It bounds the
+	/// virtual address space of the process rather than its resident memory, which includes
+	/// the address space interpreters reserve without using it. Node.js reserves several GB
+	/// for V8 at startup, so it can't start with a small limit.
+	pub max_memory_mb: Option<u64>,
+	/// Maximum size in bytes of the output of the process, on stdout and stderr each,
+	/// [`DEFAULT_MAX_OUTPUT_BYTES`] when unset
+	pub max_output_bytes: Option<u64>,
+}
+
+impl ScriptLimits {
+	/// Applies the memory limit to the process spawned by a command
+	fn apply(&self, command: &mut Command) {
+		#[cfg(unix)]
+		if let Some(max_memory_mb) = self.max_memory_mb {
+			let max_memory_bytes = max_memory_mb.saturating_mul(1024 * 1024) as libc::rlim_t;
+			// SAFETY: the closure only calls `setrlimit`, which is async-signal-safe, in the
+			// forked child before it executes the interpreter
+			unsafe {
+				command.pre_exec(move || {
+					let limit = libc::rlimit {
+						rlim_cur: max_memory_bytes,
+						rlim_max: max_memory_bytes,
+					};
+					if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+						return Err(std::io::Error::last_os_error());
+					}
+					Ok(())
+				});
+			}
+		}
+		#[cfg(not(unix))]
+		let _ = command;
+	}
+}
 
 /// A trait that defines the interface for executing custom scripts in different languages.
 /// Implementors must be both Send and Sync to ensure thread safety.
@@ -21,6 +75,8 @@ pub trait ScriptExecutor: Send + Sync + Any {
 	/// * `timeout_ms` - The timeout for the script execution in milliseconds
 	/// * `args` - Additional arguments passed to the script
 	/// * `from_custom_notification` - Whether the script is from a custom notification
+	/// * `limits` - Resource limits of the script process
+	/// * `block` - Block of the match, passed to the script along with it, if any
 	///
 	/// # Returns
 	/// * `Result<bool, anyhow::Error>` - Returns true/false based on script execution or an error
@@ -30,6 +86,8 @@ pub trait ScriptExecutor: Send + Sync + Any {
 		timeout_ms: &u32,
 		args: Option<&[String]>,
 		from_custom_notification: bool,
+		limits: &ScriptLimits,
+		block: Option<&BlockType>,
 	) -> Result<bool, anyhow::Error>;
 }
 
@@ -50,24 +108,31 @@ impl ScriptExecutor for PythonScriptExecutor {
 		timeout_ms: &u32,
 		args: Option<&[String]>,
 		from_custom_notification: bool,
+		limits: &ScriptLimits,
+		block: Option<&BlockType>,
 	) -> Result<bool, anyhow::Error> {
-		let combined_input = serde_json::json!({
-			"monitor_match": input,
-			"args": args
-		});
-		let input_json = serde_json::to_string(&combined_input)
-			.with_context(|| "Failed to serialize monitor match and arguments")?;
+		let input_json = script_input(input, args, block)?;
 
-		let cmd = tokio::process::Command::new("python3")
+		let mut command = Command::new("python3");
+		command
 			.arg("-c")
 			.arg(&self.script_content)
 			.stdin(Stdio::piped())
 			.stdout(Stdio::piped())
-			.stderr(Stdio::piped())
+			.stderr(Stdio::piped());
+		limits.apply(&mut command);
+		let cmd = command
 			.spawn()
 			.with_context(|| "Failed to spawn python3 process")?;
 
-		process_command(cmd, &input_json, timeout_ms, from_custom_notification).await
+		process_command(
+			cmd,
+			&input_json,
+			timeout_ms,
+			from_custom_notification,
+			limits,
+		)
+		.await
 	}
 }
 
@@ -88,24 +153,30 @@ impl ScriptExecutor for JavaScriptScriptExecutor {
 		timeout_ms: &u32,
 		args: Option<&[String]>,
 		from_custom_notification: bool,
+		limits: &ScriptLimits,
+		block: Option<&BlockType>,
 	) -> Result<bool, anyhow::Error> {
-		// Create a combined input with both the monitor match and arguments
-		let combined_input = serde_json::json!({
-			"monitor_match": input,
-			"args": args
-		});
-		let input_json = serde_json::to_string(&combined_input)
-			.with_context(|| "Failed to serialize monitor match and arguments")?;
+		let input_json = script_input(input, args, block)?;
 
-		let cmd = tokio::process::Command::new("node")
+		let mut command = Command::new("node");
+		command
 			.arg("-e")
 			.arg(&self.script_content)
 			.stdin(Stdio::piped())
 			.stdout(Stdio::piped())
-			.stderr(Stdio::piped())
+			.stderr(Stdio::piped());
+		limits.apply(&mut command);
+		let cmd = command
 			.spawn()
 			.with_context(|| "Failed to spawn node process")?;
-		process_command(cmd, &input_json, timeout_ms, from_custom_notification).await
+		process_command(
+			cmd,
+			&input_json,
+			timeout_ms,
+			from_custom_notification,
+			limits,
+		)
+		.await
 	}
 }
 
@@ -126,29 +197,70 @@ impl ScriptExecutor for BashScriptExecutor {
 		timeout_ms: &u32,
 		args: Option<&[String]>,
 		from_custom_notification: bool,
+		limits: &ScriptLimits,
+		block: Option<&BlockType>,
 	) -> Result<bool, anyhow::Error> {
-		// Create a combined input with both the monitor match and arguments
-		let combined_input = serde_json::json!({
-			"monitor_match": input,
-			"args": args
-		});
-
-		let input_json = serde_json::to_string(&combined_input)
-			.with_context(|| "Failed to serialize monitor match and arguments")?;
+		let input_json = script_input(input, args, block)?;
 
-		let cmd = tokio::process::Command::new("sh")
+		let mut command = Command::new("sh");
+		command
 			.arg("-c")
 			.arg(&self.script_content)
 			.stdin(Stdio::piped())
 			.stdout(Stdio::piped())
-			.stderr(Stdio::piped())
+			.stderr(Stdio::piped());
+		limits.apply(&mut command);
+		let cmd = command
 			.spawn()
 			.with_context(|| "Failed to spawn shell process")?;
 
-		process_command(cmd, &input_json, timeout_ms, from_custom_notification).await
+		process_command(
+			cmd,
+			&input_json,
+			timeout_ms,
+			from_custom_notification,
+			limits,
+		)
+		.await
 	}
 }
 
+/// Serializes the input of a script, written to its stdin
+///
+/// The input is a JSON object with the `monitor_match` and the `args` of the script, and the
+/// `block` of the match for scripts with `include_block_context`. Blocks whose JSON exceeds
+/// [`MAX_BLOCK_CONTEXT_BYTES`] fail the script.
+///
+/// # Arguments
+/// * `input` - Monitor match processed by the script
+/// * `args` - Additional arguments passed to the script
+/// * `block` - Block of the match, if any
+pub fn script_input(
+	input: MonitorMatch,
+	args: Option<&[String]>,
+	block: Option<&BlockType>,
+) -> Result<String, anyhow::Error> {
+	let mut combined_input = serde_json::json!({
+		"monitor_match": input,
+		"args": args
+	});
+	if let Some(block) = block {
+		let block_json =
+			serde_json::to_value(block).with_context(|| "Failed to serialize block context")?;
+		let block_size = serde_json::to_vec(&block_json)?.len();
+		if block_size > MAX_BLOCK_CONTEXT_BYTES {
+			return Err(anyhow::anyhow!(
+				"Block context of {} bytes exceeds the maximum of {} bytes",
+				block_size,
+				MAX_BLOCK_CONTEXT_BYTES
+			));
+		}
+		combined_input["block"] = block_json;
+	}
+	serde_json::to_string(&combined_input)
+		.with_context(|| "Failed to serialize monitor match and arguments")
+}
+
 /// Processes the output from script execution.
 ///
 /// # Arguments
@@ -203,11 +315,70 @@ pub fn process_script_output(
 	}
 }
 
+/// Messages of the interpreters failing to allocate memory
+const ALLOCATION_FAILURE_MESSAGES: &[&str] = &[
+	"memoryerror",
+	"out of memory",
+	"cannot allocate memory",
+	"allocation failed",
+	"bad_alloc",
+];
+
+/// Returns whether a script failed because of its memory limit
+///
+/// A process exceeding its memory limit either reports an allocation failure or is killed by
+/// a signal, e.g. when the interpreter aborts. Other failures are reported as usual.
+fn is_memory_failure(output: &std::process::Output) -> bool {
+	if output.status.success() {
+		return false;
+	}
+
+	#[cfg(unix)]
+	{
+		use std::os::unix::process::ExitStatusExt;
+		if output.status.signal().is_some() {
+			return true;
+		}
+	}
+
+	let stderr = String::from_utf8_lossy(&output.stderr).to_lowercase();
+	ALLOCATION_FAILURE_MESSAGES
+		.iter()
+		.any(|message| stderr.contains(message))
+}
+
+/// Reads the output of a script from one of its pipes
+///
+/// # Errors
+/// Returns a [`ScriptError::ExecutionError`] if the output exceeds `max_output_bytes`.
+async fn read_output<R: AsyncRead + Unpin>(
+	pipe: Option<R>,
+	max_output_bytes: u64,
+) -> Result<Vec<u8>, anyhow::Error> {
+	let mut output = Vec::new();
+	if let Some(pipe) = pipe {
+		pipe.take(max_output_bytes.saturating_add(1))
+			.read_to_end(&mut output)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read script output: {}", e))?;
+	}
+	if output.len() as u64 > max_output_bytes {
+		return Err(ScriptError::execution_error(
+			format!("Script output exceeded {} bytes", max_output_bytes),
+			None,
+			None,
+		)
+		.into());
+	}
+	Ok(output)
+}
+
 async fn process_command(
 	mut cmd: tokio::process::Child,
 	input_json: &str,
 	timeout_ms: &u32,
 	from_custom_notification: bool,
+	limits: &ScriptLimits,
 ) -> Result<bool, anyhow::Error> {
 	if let Some(mut stdin) = cmd.stdin.take() {
 		stdin
@@ -225,14 +396,50 @@ async fn process_command(
 	}
 
 	let timeout_duration = Duration::from_millis(u64::from(*timeout_ms));
-
-	match timeout(timeout_duration, cmd.wait_with_output()).await {
-		Ok(result) => {
-			let output =
-				result.map_err(|e| anyhow::anyhow!("Failed to wait for script output: {}", e))?;
-			process_script_output(output, from_custom_notification)
+	let max_output_bytes = limits.max_output_bytes.unwrap_or(DEFAULT_MAX_OUTPUT_BYTES);
+	let stdout = cmd.stdout.take();
+	let stderr = cmd.stderr.take();
+
+	let result = timeout(timeout_duration, async {
+		let (stdout, stderr) = tokio::try_join!(
+			read_output(stdout, max_output_bytes),
+			read_output(stderr, max_output_bytes)
+		)?;
+		let status = cmd
+			.wait()
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to wait for script output: {}", e))?;
+		Ok::<_, anyhow::Error>(std::process::Output {
+			status,
+			stdout,
+			stderr,
+		})
+	})
+	.await;
+
+	match result {
+		Ok(Ok(output)) => match limits.max_memory_mb {
+			Some(max_memory_mb) if is_memory_failure(&output) => Err(ScriptError::execution_error(
+				format!(
+					"Script execution failed with memory limited to {} MB: {}",
+					max_memory_mb,
+					String::from_utf8_lossy(&output.stderr)
+				),
+				None,
+				None,
+			)
+			.into()),
+			_ => process_script_output(output, from_custom_notification),
+		},
+		Ok(Err(e)) => {
+			// The script may be blocked writing output no longer read
+			let _ = cmd.start_kill();
+			Err(e)
+		}
+		Err(_) => {
+			let _ = cmd.start_kill();
+			Err(anyhow::anyhow!("Script execution timed out"))
 		}
-		Err(_) => Err(anyhow::anyhow!("Script execution timed out")),
 	}
 }
 
@@ -241,8 +448,9 @@ mod tests {
 	use super::*;
 	use crate::{
 		models::{
-			AddressWithSpec, EVMMonitorMatch, EVMReceiptLog, EventCondition, FunctionCondition,
-			MatchConditions, Monitor, MonitorMatch, TransactionCondition,
+			AddressWithSpec, EVMBlock, EVMConfirmationState, EVMMonitorMatch, EVMReceiptLog,
+			EventCondition, FunctionCondition, MatchConditions, Monitor, MonitorMatch,
+			TransactionCondition,
 		},
 		utils::tests::evm::{
 			monitor::MonitorBuilder, receipt::ReceiptBuilder, transaction::TransactionBuilder,
@@ -304,8 +512,15 @@ mod tests {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				token_transfers: vec![],
+				block_conditions: vec![],
+				balance_conditions: vec![],
+				state_conditions: vec![],
 			},
 			matched_on_args: None,
+			confirmation_state: EVMConfirmationState::Confirmed,
+			severity: Default::default(),
+			sender_verified: None,
 		}))
 	}
 
@@ -332,7 +547,9 @@ print(result)
 		let input = create_mock_monitor_match();
 
 		let timeout = 1000;
-		let result = executor.execute(input, &timeout, None, false).await;
+		let result = executor
+			.execute(input, &timeout, None, false, &ScriptLimits::default(), None)
+			.await;
 		assert!(result.is_ok());
 		assert!(result.unwrap());
 	}
@@ -354,7 +571,9 @@ print(result)
 		};
 
 		let input = create_mock_monitor_match();
-		let result = executor.execute(input, &1000, None, false).await;
+		let result = executor
+			.execute(input, &1000, None, false, &ScriptLimits::default(), None)
+			.await;
 		assert!(result.is_err());
 		match result {
 			Err(err) => {
@@ -388,7 +607,9 @@ print("true")
 
 		let input = create_mock_monitor_match();
 
-		let result = executor.execute(input, &1000, None, false).await;
+		let result = executor
+			.execute(input, &1000, None, false, &ScriptLimits::default(), None)
+			.await;
 		assert!(result.is_ok());
 		assert!(result.unwrap());
 	}
@@ -426,7 +647,9 @@ print("true")
 		};
 
 		let input = create_mock_monitor_match();
-		let result = executor.execute(input, &5000, None, false).await;
+		let result = executor
+			.execute(input, &5000, None, false, &ScriptLimits::default(), None)
+			.await;
 		assert!(result.is_ok());
 		assert!(result.unwrap());
 	}
@@ -459,7 +682,9 @@ print("true")
 		};
 
 		let input = create_mock_monitor_match();
-		let result = executor.execute(input, &5000, None, false).await;
+		let result = executor
+			.execute(input, &5000, None, false, &ScriptLimits::default(), None)
+			.await;
 		assert!(result.is_err());
 		match result {
 			Err(err) => {
@@ -485,7 +710,9 @@ echo "true"
 		};
 
 		let input = create_mock_monitor_match();
-		let result = executor.execute(input, &1000, None, false).await;
+		let result = executor
+			.execute(input, &1000, None, false, &ScriptLimits::default(), None)
+			.await;
 		assert!(result.is_ok());
 		assert!(result.unwrap());
 	}
@@ -506,7 +733,9 @@ echo "not a boolean"
 		};
 
 		let input = create_mock_monitor_match();
-		let result = executor.execute(input, &1000, None, false).await;
+		let result = executor
+			.execute(input, &1000, None, false, &ScriptLimits::default(), None)
+			.await;
 		assert!(result.is_err());
 		match result {
 			Err(e) => {
@@ -533,7 +762,9 @@ input_json = sys.stdin.read()
 		};
 
 		let input = create_mock_monitor_match();
-		let result = executor.execute(input, &1000, None, false).await;
+		let result = executor
+			.execute(input, &1000, None, false, &ScriptLimits::default(), None)
+			.await;
 
 		match result {
 			Err(e) => {
@@ -557,7 +788,9 @@ print("     true    ")  # Should handle whitespace correctly
 		};
 
 		let input = create_mock_monitor_match();
-		let result = executor.execute(input, &1000, None, false).await;
+		let result = executor
+			.execute(input, &1000, None, false, &ScriptLimits::default(), None)
+			.await;
 		assert!(result.is_ok());
 		assert!(result.unwrap());
 	}
@@ -582,7 +815,9 @@ print("     true    ")  # Should handle whitespace correctly
 		// Create an invalid MonitorMatch that will fail JSON serialization
 		let input = create_mock_monitor_match();
 
-		let result = executor.execute(input, &1000, None, false).await;
+		let result = executor
+			.execute(input, &1000, None, false, &ScriptLimits::default(), None)
+			.await;
 		assert!(result.is_err());
 	}
 
@@ -608,7 +843,9 @@ print("true")
 
 		let input = create_mock_monitor_match();
 
-		let result = executor.execute(input, &1000, None, false).await;
+		let result = executor
+			.execute(input, &1000, None, false, &ScriptLimits::default(), None)
+			.await;
 		assert!(result.is_ok());
 		assert!(result.unwrap());
 	}
@@ -639,7 +876,9 @@ else:
 		};
 
 		let input = create_mock_monitor_match();
-		let result = executor.execute(input, &1000, None, false).await;
+		let result = executor
+			.execute(input, &1000, None, false, &ScriptLimits::default(), None)
+			.await;
 		assert!(!result.unwrap());
 	}
 
@@ -674,7 +913,14 @@ else:
 		// Test with matching argument
 		let args = vec![String::from("test_argument")];
 		let result = executor
-			.execute(input.clone(), &1000, Some(&args), false)
+			.execute(
+				input.clone(),
+				&1000,
+				Some(&args),
+				false,
+				&ScriptLimits::default(),
+				None,
+			)
 			.await;
 		assert!(result.is_ok());
 		assert!(!result.unwrap());
@@ -682,7 +928,14 @@ else:
 		// Test with non-matching argument
 		let args = vec![String::from("--verbose"), String::from("--other-arg")];
 		let result = executor
-			.execute(input.clone(), &1000, Some(&args), false)
+			.execute(
+				input.clone(),
+				&1000,
+				Some(&args),
+				false,
+				&ScriptLimits::default(),
+				None,
+			)
 			.await;
 		assert!(result.is_ok());
 		assert!(result.unwrap());
@@ -722,7 +975,14 @@ else:
 			String::from("--test"),
 		];
 		let result = executor
-			.execute(input.clone(), &1000, Some(&args), false)
+			.execute(
+				input.clone(),
+				&1000,
+				Some(&args),
+				false,
+				&ScriptLimits::default(),
+				None,
+			)
 			.await;
 		assert!(result.is_ok());
 		assert!(result.unwrap());
@@ -730,7 +990,14 @@ else:
 		// Test with wrong argument
 		let args = vec![String::from("wrong_arg")];
 		let result = executor
-			.execute(input.clone(), &1000, Some(&args), false)
+			.execute(
+				input.clone(),
+				&1000,
+				Some(&args),
+				false,
+				&ScriptLimits::default(),
+				None,
+			)
 			.await;
 		assert!(result.is_ok());
 		assert!(!result.unwrap());
@@ -743,7 +1010,14 @@ else:
 		let input = create_mock_monitor_match();
 		let args = vec![String::from("--verbose")];
 		let result = executor
-			.execute(input.clone(), &1000, Some(&args), false)
+			.execute(
+				input.clone(),
+				&1000,
+				Some(&args),
+				false,
+				&ScriptLimits::default(),
+				None,
+			)
 			.await;
 
 		assert!(result.is_ok());
@@ -758,7 +1032,14 @@ else:
 		let input = create_mock_monitor_match();
 		let args = vec![String::from("--wrong_arg"), String::from("--test")];
 		let result = executor
-			.execute(input.clone(), &1000, Some(&args), false)
+			.execute(
+				input.clone(),
+				&1000,
+				Some(&args),
+				false,
+				&ScriptLimits::default(),
+				None,
+			)
 			.await;
 
 		assert!(result.is_ok());
@@ -777,7 +1058,9 @@ input_json = sys.stdin.read()
 		};
 
 		let input = create_mock_monitor_match();
-		let result = executor.execute(input, &1000, None, true).await;
+		let result = executor
+			.execute(input, &1000, None, true, &ScriptLimits::default(), None)
+			.await;
 		assert!(result.is_ok());
 		assert!(result.unwrap());
 	}
@@ -796,7 +1079,9 @@ sys.exit(1)
 		};
 
 		let input = create_mock_monitor_match();
-		let result = executor.execute(input, &1000, None, true).await;
+		let result = executor
+			.execute(input, &1000, None, true, &ScriptLimits::default(), None)
+			.await;
 
 		assert!(result.is_err());
 		match result {
@@ -824,7 +1109,9 @@ time.sleep(0.3)
 
 		let input = create_mock_monitor_match();
 		let start_time = Instant::now();
-		let result = executor.execute(input, &1000, None, true).await;
+		let result = executor
+			.execute(input, &1000, None, true, &ScriptLimits::default(), None)
+			.await;
 		let elapsed = start_time.elapsed();
 
 		assert!(result.is_ok());
@@ -849,7 +1136,9 @@ time.sleep(0.5)
 
 		let input = create_mock_monitor_match();
 		let start_time = Instant::now();
-		let result = executor.execute(input, &400, None, true).await;
+		let result = executor
+			.execute(input, &400, None, true, &ScriptLimits::default(), None)
+			.await;
 		let elapsed = start_time.elapsed();
 
 		assert!(result.is_err());
@@ -872,7 +1161,9 @@ sys.exit(1)
 		};
 
 		let input = create_mock_monitor_match();
-		let result = executor.execute(input, &1000, None, false).await;
+		let result = executor
+			.execute(input, &1000, None, false, &ScriptLimits::default(), None)
+			.await;
 
 		assert!(result.is_err());
 		match result {
@@ -912,7 +1203,9 @@ sys.exit(1)
 		};
 
 		let input = create_mock_monitor_match();
-		let result = executor.execute(input, &1000, None, false).await;
+		let result = executor
+			.execute(input, &1000, None, false, &ScriptLimits::default(), None)
+			.await;
 
 		assert!(result.is_err());
 		match result {
@@ -940,7 +1233,9 @@ exit 1
 		};
 
 		let input = create_mock_monitor_match();
-		let result = executor.execute(input, &1000, None, false).await;
+		let result = executor
+			.execute(input, &1000, None, false, &ScriptLimits::default(), None)
+			.await;
 		assert!(result.is_err());
 		match result {
 			Err(e) => {
@@ -951,4 +1246,138 @@ exit 1
 			_ => panic!("Expected ExecutionError"),
 		}
 	}
+
+	#[tokio::test]
+	async fn test_script_output_exceeding_limit_is_terminated() {
+		let script_content = r#"
+while true; do
+    echo "flooding the output"
+done
+"#;
+		let executor = BashScriptExecutor {
+			script_content: script_content.to_string(),
+		};
+		let limits = ScriptLimits {
+			max_memory_mb: None,
+			max_output_bytes: Some(1024),
+		};
+
+		let input = create_mock_monitor_match();
+		let start_time = Instant::now();
+		let result = executor
+			.execute(input, &5000, None, false, &limits, None)
+			.await;
+
+		assert!(start_time.elapsed() < Duration::from_millis(5000));
+		let error = result.unwrap_err();
+		assert!(matches!(
+			error.downcast_ref::<ScriptError>(),
+			Some(ScriptError::ExecutionError(_))
+		));
+		assert!(error
+			.to_string()
+			.contains("Script output exceeded 1024 bytes"));
+	}
+
+	#[tokio::test]
+	async fn test_script_output_within_limit() {
+		let executor = BashScriptExecutor {
+			script_content: "echo true".to_string(),
+		};
+		let limits = ScriptLimits {
+			max_memory_mb: None,
+			max_output_bytes: Some(16),
+		};
+
+		let input = create_mock_monitor_match();
+		let result = executor
+			.execute(input, &1000, None, false, &limits, None)
+			.await;
+		assert!(result.unwrap());
+	}
+
+	#[cfg(unix)]
+	#[tokio::test]
+	async fn test_script_exceeding_memory_limit_fails() {
+		let script_content = r#"
+data = bytearray(1024 * 1024 * 1024)
+print(True)
+"#;
+		let executor = PythonScriptExecutor {
+			script_content: script_content.to_string(),
+		};
+		let limits = ScriptLimits {
+			max_memory_mb: Some(256),
+			max_output_bytes: None,
+		};
+
+		let input = create_mock_monitor_match();
+		let result = executor
+			.execute(input, &5000, None, false, &limits, None)
+			.await;
+
+		let error = result.unwrap_err();
+		assert!(error
+			.to_string()
+			.contains("Script execution failed with memory limited to 256 MB"));
+		assert!(error.to_string().contains("MemoryError"));
+	}
+
+	#[tokio::test]
+	async fn test_script_failing_under_memory_limit_reports_its_error() {
+		let script_content = r#"
+import sys
+sys.stderr.write("invalid input")
+sys.exit(1)
+"#;
+		let executor = PythonScriptExecutor {
+			script_content: script_content.to_string(),
+		};
+		let limits = ScriptLimits {
+			max_memory_mb: Some(256),
+			max_output_bytes: None,
+		};
+
+		let input = create_mock_monitor_match();
+		let result = executor
+			.execute(input, &5000, None, false, &limits, None)
+			.await;
+
+		let error = result.unwrap_err().to_string();
+		assert!(!error.contains("memory limited"));
+		assert!(error.contains("Script execution failed: invalid input"));
+	}
+
+	#[test]
+	fn test_script_input_with_block_context() {
+		let input = create_mock_monitor_match();
+		let json: serde_json::Value =
+			serde_json::from_str(&script_input(input.clone(), None, None).unwrap()).unwrap();
+		assert!(json.get("block").is_none());
+
+		let mut block = EVMBlock::default();
+		block.0.transactions = vec![TransactionBuilder::new().build()];
+		let block = BlockType::EVM(Box::new(block));
+		let json: serde_json::Value = serde_json::from_str(
+			&script_input(input.clone(), Some(&["--flag".to_string()]), Some(&block)).unwrap(),
+		)
+		.unwrap();
+		assert_eq!(json["args"][0], "--flag");
+		assert_eq!(
+			json["block"]["EVM"]["transactions"]
+				.as_array()
+				.unwrap()
+				.len(),
+			1
+		);
+
+		// The input of the transaction is hex-encoded, doubling its size
+		let mut block = EVMBlock::default();
+		block.0.transactions = vec![TransactionBuilder::new()
+			.input(vec![0u8; MAX_BLOCK_CONTEXT_BYTES / 2].into())
+			.build()];
+		let block = BlockType::EVM(Box::new(block));
+		let error = script_input(input, None, Some(&block)).unwrap_err();
+		assert!(error.to_string().contains("Block context of"));
+	}
 }
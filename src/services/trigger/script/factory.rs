@@ -1,14 +1,122 @@
 //! Trigger script factory implementation.
 //!
 //! This module provides functionality to create script executors based on the script language.
+//! Executors are created from a registry of constructors keyed by language, holding the
+//! built-in Python, JavaScript, Bash and WebAssembly executors. Further languages, e.g. Ruby or
+//! Deno, can be registered at startup with [`register_script_executor`].
+
+use lazy_static::lazy_static;
+use std::{
+	collections::HashMap,
+	sync::{Arc, RwLock},
+};
 
 use crate::{
-	models::ScriptLanguage,
-	services::trigger::script::executor::{
-		BashScriptExecutor, JavaScriptScriptExecutor, PythonScriptExecutor, ScriptExecutor,
+	models::{ScriptLanguage, SCRIPT_LANGUAGE_EXTENSIONS},
+	services::trigger::script::{
+		error::ScriptError,
+		executor::{
+			BashScriptExecutor, JavaScriptScriptExecutor, PythonScriptExecutor, ScriptExecutor,
+		},
+		wasm::WasmScriptExecutor,
 	},
 };
 
+/// Constructor of the executors of a script language, taking the content of the script
+pub type ScriptExecutorConstructor = Arc<dyn Fn(&str) -> Box<dyn ScriptExecutor> + Send + Sync>;
+
+/// Executor registered for a script language
+struct RegisteredScriptExecutor {
+	/// Extension of the script files of the language, without the leading dot
+	extension: String,
+	constructor: ScriptExecutorConstructor,
+}
+
+lazy_static! {
+	/// Registered executors, by script language
+	static ref SCRIPT_EXECUTORS: RwLock<HashMap<ScriptLanguage, RegisteredScriptExecutor>> =
+		RwLock::new(builtin_script_executors());
+}
+
+fn python_executor(script_content: &str) -> Box<dyn ScriptExecutor> {
+	Box::new(PythonScriptExecutor {
+		script_content: script_content.to_string(),
+	})
+}
+
+fn javascript_executor(script_content: &str) -> Box<dyn ScriptExecutor> {
+	Box::new(JavaScriptScriptExecutor {
+		script_content: script_content.to_string(),
+	})
+}
+
+fn bash_executor(script_content: &str) -> Box<dyn ScriptExecutor> {
+	Box::new(BashScriptExecutor {
+		script_content: script_content.to_string(),
+	})
+}
+
+fn wasm_executor(script_content: &str) -> Box<dyn ScriptExecutor> {
+	Box::new(WasmScriptExecutor {
+		script_content: script_content.to_string(),
+	})
+}
+
+/// Returns the executors of the built-in script languages
+fn builtin_script_executors() -> HashMap<ScriptLanguage, RegisteredScriptExecutor> {
+	let constructors: [(ScriptLanguage, ScriptExecutorConstructor); 4] = [
+		(ScriptLanguage::Python, Arc::new(python_executor)),
+		(ScriptLanguage::JavaScript, Arc::new(javascript_executor)),
+		(ScriptLanguage::Bash, Arc::new(bash_executor)),
+		(ScriptLanguage::Wasm, Arc::new(wasm_executor)),
+	];
+
+	constructors
+		.into_iter()
+		.filter_map(|(language, constructor)| {
+			let (_, extension) = SCRIPT_LANGUAGE_EXTENSIONS
+				.iter()
+				.find(|(builtin, _)| **builtin == language)?;
+			let registered = RegisteredScriptExecutor {
+				extension: extension.to_string(),
+				constructor,
+			};
+			Some((language, registered))
+		})
+		.collect()
+}
+
+/// Registers the executor of a script language, replacing any executor registered for it
+///
+/// Executors must be registered at startup, before the monitor and trigger configurations
+/// using the language are loaded, as their script files are validated against the extension.
+///
+/// # Arguments
+/// * `language` - Language of the scripts, usually [`ScriptLanguage::Custom`]
+/// * `extension` - Extension of the script files of the language, without the leading dot
+/// * `constructor` - Creates an executor from the content of a script
+///
+/// # Example
+/// ```ignore
+/// register_script_executor(
+///     ScriptLanguage::Custom("Ruby".to_string()),
+///     "rb",
+///     |script_content| Box::new(RubyScriptExecutor::new(script_content)),
+/// );
+/// ```
+pub fn register_script_executor<F>(language: ScriptLanguage, extension: &str, constructor: F)
+where
+	F: Fn(&str) -> Box<dyn ScriptExecutor> + Send + Sync + 'static,
+{
+	SCRIPT_EXECUTORS.write().unwrap().insert(
+		language,
+		RegisteredScriptExecutor {
+			extension: extension.trim_start_matches('.').to_string(),
+			constructor: Arc::new(constructor),
+		},
+	);
+}
+
 /// Factory for creating script executors based on the script language.
 pub struct ScriptExecutorFactory;
 
@@ -23,19 +131,39 @@ impl ScriptExecutorFactory {
 	/// # Returns
 	///
 	/// Returns a boxed (Rust will allocate on the heap) trait object implementing the
-	/// `ScriptExecutor` trait
-	pub fn create(language: &ScriptLanguage, script_content: &str) -> Box<dyn ScriptExecutor> {
-		match language {
-			ScriptLanguage::Python => Box::new(PythonScriptExecutor {
-				script_content: script_content.to_string(),
-			}),
-			ScriptLanguage::JavaScript => Box::new(JavaScriptScriptExecutor {
-				script_content: script_content.to_string(),
-			}),
-			ScriptLanguage::Bash => Box::new(BashScriptExecutor {
-				script_content: script_content.to_string(),
-			}),
-		}
+	/// `ScriptExecutor` trait, or a `ScriptError::NotFound` if no executor is registered for the
+	/// language
+	pub fn create(
+		language: &ScriptLanguage,
+		script_content: &str,
+	) -> Result<Box<dyn ScriptExecutor>, ScriptError> {
+		// The constructor is cloned so that it doesn't run with the registry locked
+		let constructor = SCRIPT_EXECUTORS
+			.read()
+			.unwrap()
+			.get(language)
+			.map(|registered| registered.constructor.clone())
+			.ok_or_else(|| {
+				ScriptError::not_found(
+					format!("No script executor registered for language {:?}", language),
+					None,
+					None,
+				)
+			})?;
+		Ok(constructor(script_content))
+	}
+
+	/// Returns the extension of the script files of a language, without the leading dot
+	///
+	/// # Returns
+	///
+	/// Returns None if no executor is registered for the language
+	pub fn extension(language: &ScriptLanguage) -> Option<String> {
+		SCRIPT_EXECUTORS
+			.read()
+			.unwrap()
+			.get(language)
+			.map(|registered| registered.extension.clone())
 	}
 }
 
@@ -47,7 +175,7 @@ mod tests {
 	#[test]
 	fn test_create_python_executor() {
 		let script = "print('Hello')";
-		let executor = ScriptExecutorFactory::create(&ScriptLanguage::Python, script);
+		let executor = ScriptExecutorFactory::create(&ScriptLanguage::Python, script).unwrap();
 		assert!(
 			executor
 				.as_any()
@@ -59,7 +187,8 @@ mod tests {
 
 		// Test with empty script
 		let empty_script = "";
-		let executor = ScriptExecutorFactory::create(&ScriptLanguage::Python, empty_script);
+		let executor =
+			ScriptExecutorFactory::create(&ScriptLanguage::Python, empty_script).unwrap();
 		assert!(executor
 			.as_any()
 			.downcast_ref::<PythonScriptExecutor>()
@@ -71,7 +200,7 @@ mod tests {
 	#[test]
 	fn test_create_javascript_executor() {
 		let script = "console.log('Hello')";
-		let executor = ScriptExecutorFactory::create(&ScriptLanguage::JavaScript, script);
+		let executor = ScriptExecutorFactory::create(&ScriptLanguage::JavaScript, script).unwrap();
 		assert!(
 			executor
 				.as_any()
@@ -83,7 +212,8 @@ mod tests {
 
 		// Test with empty script
 		let empty_script = "";
-		let executor = ScriptExecutorFactory::create(&ScriptLanguage::JavaScript, empty_script);
+		let executor =
+			ScriptExecutorFactory::create(&ScriptLanguage::JavaScript, empty_script).unwrap();
 		assert!(executor
 			.as_any()
 			.downcast_ref::<JavaScriptScriptExecutor>()
@@ -95,7 +225,7 @@ mod tests {
 	#[test]
 	fn test_create_bash_executor() {
 		let script = "echo 'Hello'";
-		let executor = ScriptExecutorFactory::create(&ScriptLanguage::Bash, script);
+		let executor = ScriptExecutorFactory::create(&ScriptLanguage::Bash, script).unwrap();
 		assert!(
 			executor
 				.as_any()
@@ -107,7 +237,7 @@ mod tests {
 
 		// Test with empty script
 		let empty_script = "";
-		let executor = ScriptExecutorFactory::create(&ScriptLanguage::Bash, empty_script);
+		let executor = ScriptExecutorFactory::create(&ScriptLanguage::Bash, empty_script).unwrap();
 		assert!(executor
 			.as_any()
 			.downcast_ref::<BashScriptExecutor>()
@@ -115,4 +245,60 @@ mod tests {
 			.script_content
 			.is_empty());
 	}
+
+	#[test]
+	fn test_create_wasm_executor() {
+		let script = "AGFzbQEAAAA=";
+		let executor = ScriptExecutorFactory::create(&ScriptLanguage::Wasm, script).unwrap();
+		assert_eq!(
+			executor
+				.as_any()
+				.downcast_ref::<WasmScriptExecutor>()
+				.unwrap()
+				.script_content,
+			script
+		);
+		assert_eq!(
+			ScriptExecutorFactory::extension(&ScriptLanguage::Wasm),
+			Some("wasm".to_string())
+		);
+	}
+
+	#[test]
+	fn test_create_unregistered_language() {
+		let language = ScriptLanguage::Custom("Unregistered".to_string());
+		assert!(matches!(
+			ScriptExecutorFactory::create(&language, "puts 'Hello'"),
+			Err(ScriptError::NotFound(_))
+		));
+		assert_eq!(ScriptExecutorFactory::extension(&language), None);
+		assert_eq!(
+			ScriptExecutorFactory::extension(&ScriptLanguage::Python),
+			Some("py".to_string())
+		);
+	}
+
+	#[test]
+	fn test_register_script_executor() {
+		let language = ScriptLanguage::Custom("Ruby".to_string());
+		register_script_executor(language.clone(), ".rb", |script_content| {
+			Box::new(BashScriptExecutor {
+				script_content: format!("ruby -e '{}'", script_content),
+			})
+		});
+
+		let executor = ScriptExecutorFactory::create(&language, "puts 'Hello'").unwrap();
+		assert_eq!(
+			executor
+				.as_any()
+				.downcast_ref::<BashScriptExecutor>()
+				.unwrap()
+				.script_content,
+			"ruby -e 'puts 'Hello''"
+		);
+		assert_eq!(
+			ScriptExecutorFactory::extension(&language),
+			Some("rb".to_string())
+		);
+	}
 }
@@ -8,7 +8,12 @@ mod error;
 mod executor;
 mod factory;
 mod validation;
+mod wasm;
 pub use error::ScriptError;
-pub use executor::{process_script_output, ScriptExecutor};
-pub use factory::ScriptExecutorFactory;
-pub use validation::validate_script_config;
+pub use executor::{
+	process_script_output, script_input, ScriptExecutor, ScriptLimits, DEFAULT_MAX_OUTPUT_BYTES,
+	MAX_BLOCK_CONTEXT_BYTES,
+};
+pub use factory::{register_script_executor, ScriptExecutorConstructor, ScriptExecutorFactory};
+pub use validation::{validate_script_config, validate_script_limits};
+pub use wasm::{read_script_content, WasmScriptExecutor, WASM_FUEL_PER_MS};
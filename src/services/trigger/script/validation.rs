@@ -2,7 +2,10 @@
 //!
 //! This module provides functionality to validate script configuration parameters.
 
-use crate::models::{ConfigError, ScriptLanguage};
+use crate::{
+	models::{ConfigError, ScriptLanguage},
+	services::trigger::script::{executor::ScriptLimits, factory::ScriptExecutorFactory},
+};
 use std::path::Path;
 
 /// Validates script configuration parameters
@@ -37,11 +40,14 @@ pub fn validate_script_config(
 		.and_then(|ext| ext.to_str())
 		.unwrap_or("");
 
-	let valid_extension = match language {
-		ScriptLanguage::Python => extension == "py",
-		ScriptLanguage::JavaScript => extension == "js",
-		ScriptLanguage::Bash => extension == "sh",
+	let Some(expected_extension) = ScriptExecutorFactory::extension(language) else {
+		return Err(ConfigError::validation_error(
+			format!("No script executor registered for language {:?}", language),
+			None,
+			None,
+		));
 	};
+	let valid_extension = extension == expected_extension;
 
 	if !valid_extension {
 		return Err(ConfigError::validation_error(
@@ -66,6 +72,34 @@ pub fn validate_script_config(
 	Ok(())
 }
 
+/// Validates the resource limits of a script
+///
+/// # Arguments
+/// * `limits` - Resource limits of the script process
+///
+/// # Returns
+/// * `Ok(())` if validation passes
+/// * `Err(ConfigError)` if a limit is 0
+#[allow(clippy::result_large_err)]
+pub fn validate_script_limits(limits: &ScriptLimits) -> Result<(), ConfigError> {
+	if limits.max_memory_mb == Some(0) {
+		return Err(ConfigError::validation_error(
+			"max_memory_mb must be greater than 0".to_string(),
+			None,
+			None,
+		));
+	}
+	if limits.max_output_bytes == Some(0) {
+		return Err(ConfigError::validation_error(
+			"max_output_bytes must be greater than 0".to_string(),
+			None,
+			None,
+		));
+	}
+
+	Ok(())
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -127,4 +161,32 @@ mod tests {
 		}
 		fs::remove_file(python_path).unwrap();
 	}
+
+	#[test]
+	fn test_validate_script_limits() {
+		assert!(validate_script_limits(&ScriptLimits::default()).is_ok());
+		assert!(validate_script_limits(&ScriptLimits {
+			max_memory_mb: Some(64),
+			max_output_bytes: Some(1024),
+		})
+		.is_ok());
+
+		let result = validate_script_limits(&ScriptLimits {
+			max_memory_mb: Some(0),
+			max_output_bytes: None,
+		});
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("max_memory_mb must be greater than 0"));
+
+		let result = validate_script_limits(&ScriptLimits {
+			max_memory_mb: None,
+			max_output_bytes: Some(0),
+		});
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("max_output_bytes must be greater than 0"));
+	}
 }
@@ -0,0 +1,421 @@
+//! WebAssembly trigger script executor.
+//!
+//! Runs compiled condition modules in a sandboxed `wasmtime` runtime instead of spawning an
+//! interpreter. A module has no access to the host besides the two functions of the `monitor`
+//! import module, through which it reads the same JSON input as the scripts of the other
+//! languages:
+//! - `input_len() -> i32` returns the size in bytes of the input
+//! - `read_input(ptr: i32)` copies the input to the exported `memory` of the module at `ptr`
+//!
+//! The module exports an `evaluate() -> i32` function, whose result is `true` unless it is `0`.
+//! Its execution is bounded by fuel derived from the timeout of the script, so that a module
+//! running past its timeout traps deterministically, and its memory by the `max_memory_mb` of
+//! the script. Running modules requires the `wasm` feature.
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine as _};
+use std::{any::Any, path::Path};
+
+use crate::{
+	models::{BlockType, MonitorMatch, ScriptLanguage},
+	services::trigger::script::executor::{script_input, ScriptExecutor, ScriptLimits},
+};
+
+/// Fuel granted to a module per millisecond of the timeout of its script, about the number of
+/// WebAssembly instructions executed per millisecond
+pub const WASM_FUEL_PER_MS: u64 = 1_000_000;
+
+/// Reads the content of a script file
+///
+/// The content of the scripts is kept as text, so WebAssembly modules, which are usually
+/// binary, are kept base64-encoded and decoded by [`WasmScriptExecutor`].
+///
+/// # Arguments
+/// * `path` - Path to the script file
+/// * `language` - Language of the script
+///
+/// # Returns
+/// * `std::io::Result<String>` - The content of the script, or an error if it can't be read
+pub async fn read_script_content(
+	path: &Path,
+	language: &ScriptLanguage,
+) -> std::io::Result<String> {
+	match language {
+		ScriptLanguage::Wasm => Ok(BASE64_STANDARD.encode(tokio::fs::read(path).await?)),
+		_ => tokio::fs::read_to_string(path).await,
+	}
+}
+
+/// Executes WebAssembly condition modules in a sandboxed runtime.
+pub struct WasmScriptExecutor {
+	/// Base64-encoded content of the module file, in the binary or text format
+	pub script_content: String,
+}
+
+#[async_trait]
+impl ScriptExecutor for WasmScriptExecutor {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+
+	#[cfg(feature = "wasm")]
+	async fn execute(
+		&self,
+		input: MonitorMatch,
+		timeout_ms: &u32,
+		args: Option<&[String]>,
+		from_custom_notification: bool,
+		limits: &ScriptLimits,
+		block: Option<&BlockType>,
+	) -> Result<bool, anyhow::Error> {
+		use anyhow::Context;
+
+		let module = BASE64_STANDARD
+			.decode(self.script_content.trim())
+			.with_context(|| "Failed to decode WebAssembly module")?;
+		let input_json = script_input(input, args, block)?.into_bytes();
+		let fuel = u64::from(*timeout_ms).saturating_mul(WASM_FUEL_PER_MS);
+		let max_memory_bytes = limits
+			.max_memory_mb
+			.map(|max_memory_mb| max_memory_mb.saturating_mul(1024 * 1024) as usize);
+
+		// Compiling and running the module is CPU bound
+		let result = tokio::task::spawn_blocking(move || {
+			runtime::evaluate(&module, input_json, fuel, max_memory_bytes)
+		})
+		.await
+		.with_context(|| "WebAssembly module execution panicked")??;
+
+		// Custom notifications only need the module to run successfully
+		Ok(from_custom_notification || result)
+	}
+
+	/// Always fails, as the service was built without the `wasm` feature.
+	#[cfg(not(feature = "wasm"))]
+	async fn execute(
+		&self,
+		_input: MonitorMatch,
+		_timeout_ms: &u32,
+		_args: Option<&[String]>,
+		_from_custom_notification: bool,
+		_limits: &ScriptLimits,
+		_block: Option<&BlockType>,
+	) -> Result<bool, anyhow::Error> {
+		Err(anyhow::anyhow!(
+			"Failed to run WebAssembly module: built without the `wasm` feature"
+		))
+	}
+}
+
+#[cfg(feature = "wasm")]
+mod runtime {
+	use lazy_static::lazy_static;
+	use wasmtime::{
+		Caller, Config, Engine, Linker, Module, Store, StoreLimits, StoreLimitsBuilder, Trap,
+	};
+
+	lazy_static! {
+		/// Engine compiling and running the modules, metering their execution with fuel
+		static ref ENGINE: Engine = {
+			let mut config = Config::new();
+			config.consume_fuel(true);
+			Engine::new(&config).expect("Failed to create WebAssembly engine")
+		};
+	}
+
+	/// State of the store of a module
+	struct State {
+		/// JSON input of the module
+		input: Vec<u8>,
+		limits: StoreLimits,
+	}
+
+	/// Compiles, instantiates and evaluates a module
+	///
+	/// # Arguments
+	/// * `module` - Module, in the binary or text format
+	/// * `input` - JSON input of the module
+	/// * `fuel` - Fuel the evaluation may consume
+	/// * `max_memory_bytes` - Maximum size of the memory of the module, unbounded when unset
+	///
+	/// # Returns
+	/// * `Result<bool, anyhow::Error>` - Whether `evaluate` returned a non-zero value, or an
+	///   error if the module is invalid, traps or runs out of fuel
+	pub(super) fn evaluate(
+		module: &[u8],
+		input: Vec<u8>,
+		fuel: u64,
+		max_memory_bytes: Option<usize>,
+	) -> Result<bool, anyhow::Error> {
+		let module = Module::new(&ENGINE, module)
+			.map_err(|e| anyhow::anyhow!("Failed to compile WebAssembly module: {}", e))?;
+
+		let mut linker = Linker::new(&ENGINE);
+		linker
+			.func_wrap("monitor", "input_len", |caller: Caller<'_, State>| {
+				caller.data().input.len() as i32
+			})
+			.and_then(|linker| linker.func_wrap("monitor", "read_input", read_input))
+			.map_err(|e| anyhow::anyhow!("Failed to define host functions: {}", e))?;
+
+		let mut limits = StoreLimitsBuilder::new();
+		if let Some(max_memory_bytes) = max_memory_bytes {
+			limits = limits.memory_size(max_memory_bytes);
+		}
+		let mut store = Store::new(
+			&ENGINE,
+			State {
+				input,
+				limits: limits.build(),
+			},
+		);
+		store.limiter(|state| &mut state.limits);
+		store
+			.set_fuel(fuel)
+			.map_err(|e| anyhow::anyhow!("Failed to set fuel: {}", e))?;
+
+		let instance = linker
+			.instantiate(&mut store, &module)
+			.map_err(|e| anyhow::anyhow!("Failed to instantiate WebAssembly module: {}", e))?;
+		let evaluate = instance
+			.get_typed_func::<(), i32>(&mut store, "evaluate")
+			.map_err(|e| {
+				anyhow::anyhow!(
+					"WebAssembly module doesn't export `evaluate() -> i32`: {}",
+					e
+				)
+			})?;
+
+		match evaluate.call(&mut store, ()) {
+			Ok(result) => Ok(result != 0),
+			Err(e) if e.downcast_ref::<Trap>() == Some(&Trap::OutOfFuel) => {
+				Err(anyhow::anyhow!("Script execution timed out"))
+			}
+			Err(e) => Err(anyhow::anyhow!("Script execution failed: {:?}", e)),
+		}
+	}
+
+	/// Copies the input of a module to its memory at `ptr`
+	fn read_input(mut caller: Caller<'_, State>, ptr: i32) -> wasmtime::Result<()> {
+		let memory = caller
+			.get_export("memory")
+			.and_then(|export| export.into_memory())
+			.ok_or_else(|| wasmtime::Error::msg("WebAssembly module doesn't export `memory`"))?;
+		let (data, state) = memory.data_and_store_mut(&mut caller);
+		let start = ptr as u32 as usize;
+		data.get_mut(start..start.saturating_add(state.input.len()))
+			.ok_or_else(|| wasmtime::Error::msg("Input doesn't fit in the memory of the module"))?
+			.copy_from_slice(&state.input);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, MatchConditions},
+		utils::tests::evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+
+	/// Module evaluating to `true` if its input contains `wasm-yes`, e.g. as an argument
+	const CONTAINS_MARKER_MODULE: &str = r#"
+(module
+	(import "monitor" "input_len" (func $input_len (result i32)))
+	(import "monitor" "read_input" (func $read_input (param i32)))
+	(memory (export "memory") 4)
+	(func (export "evaluate") (result i32)
+		(local $i i32)
+		(local $end i32)
+		(call $read_input (i32.const 0))
+		(local.set $end (i32.sub (call $input_len) (i32.const 8)))
+		(block $done
+			(loop $scan
+				(br_if $done (i32.gt_s (local.get $i) (local.get $end)))
+				;; "wasm-yes" as a little-endian 64-bit integer
+				(if (i64.eq (i64.load (local.get $i)) (i64.const 0x7365792d6d736177))
+					(then (return (i32.const 1))))
+				(local.set $i (i32.add (local.get $i) (i32.const 1)))
+				(br $scan)))
+		(i32.const 0)))
+"#;
+
+	/// Module never returning
+	const INFINITE_LOOP_MODULE: &str = r#"
+(module
+	(func (export "evaluate") (result i32)
+		(loop $forever (br $forever))
+		(i32.const 0)))
+"#;
+
+	fn create_executor(module: &str) -> WasmScriptExecutor {
+		WasmScriptExecutor {
+			script_content: BASE64_STANDARD.encode(module),
+		}
+	}
+
+	fn create_mock_monitor_match() -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("test").build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: None,
+			logs: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			confirmation_state: Default::default(),
+			severity: Default::default(),
+			sender_verified: None,
+		}))
+	}
+
+	#[tokio::test]
+	async fn test_read_script_content() {
+		let file = tempfile::NamedTempFile::new().unwrap();
+		std::fs::write(file.path(), [0x00, 0x61, 0x73, 0x6d, 0xff]).unwrap();
+
+		let content = read_script_content(file.path(), &ScriptLanguage::Wasm)
+			.await
+			.unwrap();
+		assert_eq!(content, "AGFzbf8=");
+		assert!(read_script_content(file.path(), &ScriptLanguage::Bash)
+			.await
+			.is_err());
+	}
+
+	#[cfg(feature = "wasm")]
+	#[tokio::test]
+	async fn test_wasm_script_executor_evaluates_input() {
+		let executor = create_executor(CONTAINS_MARKER_MODULE);
+		let limits = ScriptLimits::default();
+
+		let args = vec!["wasm-yes".to_string()];
+		let result = executor
+			.execute(
+				create_mock_monitor_match(),
+				&1000,
+				Some(&args),
+				false,
+				&limits,
+				None,
+			)
+			.await;
+		assert!(result.unwrap());
+
+		let args = vec!["wasm-no".to_string()];
+		let result = executor
+			.execute(
+				create_mock_monitor_match(),
+				&1000,
+				Some(&args),
+				false,
+				&limits,
+				None,
+			)
+			.await;
+		assert!(!result.unwrap());
+
+		// Custom notifications only need the module to run
+		let result = executor
+			.execute(
+				create_mock_monitor_match(),
+				&1000,
+				None,
+				true,
+				&limits,
+				None,
+			)
+			.await;
+		assert!(result.unwrap());
+	}
+
+	#[cfg(feature = "wasm")]
+	#[tokio::test]
+	async fn test_wasm_script_executor_runs_out_of_fuel() {
+		let executor = create_executor(INFINITE_LOOP_MODULE);
+
+		let result = executor
+			.execute(
+				create_mock_monitor_match(),
+				&10,
+				None,
+				false,
+				&ScriptLimits::default(),
+				None,
+			)
+			.await;
+
+		assert_eq!(
+			result.unwrap_err().to_string(),
+			"Script execution timed out"
+		);
+	}
+
+	#[cfg(feature = "wasm")]
+	#[tokio::test]
+	async fn test_wasm_script_executor_limits_memory() {
+		let executor = create_executor(CONTAINS_MARKER_MODULE);
+
+		// The module declares 4 pages of 64 KiB
+		let result = executor
+			.execute(
+				create_mock_monitor_match(),
+				&1000,
+				None,
+				false,
+				&ScriptLimits {
+					max_memory_mb: Some(0),
+					max_output_bytes: None,
+				},
+				None,
+			)
+			.await;
+
+		assert!(result.is_err());
+	}
+
+	#[cfg(feature = "wasm")]
+	#[tokio::test]
+	async fn test_wasm_script_executor_rejects_host_imports() {
+		let executor = create_executor(
+			r#"(module
+				(import "wasi_snapshot_preview1" "proc_exit" (func (param i32)))
+				(func (export "evaluate") (result i32) (i32.const 1)))"#,
+		);
+
+		let result = executor
+			.execute(
+				create_mock_monitor_match(),
+				&1000,
+				None,
+				false,
+				&ScriptLimits::default(),
+				None,
+			)
+			.await;
+
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("Failed to instantiate WebAssembly module"));
+	}
+
+	#[cfg(not(feature = "wasm"))]
+	#[tokio::test]
+	async fn test_wasm_script_executor_requires_feature() {
+		let executor = create_executor(INFINITE_LOOP_MODULE);
+
+		let result = executor
+			.execute(
+				create_mock_monitor_match(),
+				&1000,
+				None,
+				false,
+				&ScriptLimits::default(),
+				None,
+			)
+			.await;
+
+		assert!(result.unwrap_err().to_string().contains("`wasm` feature"));
+	}
+}
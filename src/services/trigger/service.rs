@@ -3,31 +3,156 @@
 //! Provides functionality to execute triggers with variable substitution
 //! and notification delivery. Manages trigger lookup and execution flow.
 
-use std::{collections::HashMap, path::Path};
+use std::{collections::HashMap, path::Path, sync::Arc, time::Duration};
 
+use alloy::primitives::{Address, B256, U256};
 use anyhow::Context;
 use async_trait::async_trait;
 
 use crate::{
-	models::{Monitor, MonitorMatch, ScriptLanguage, TriggerTypeConfig},
+	models::{
+		EVMBaseTransaction, EVMConfirmationState, EVMMatchArguments, EVMMatchParamEntry,
+		EVMMatchParamsMap, EVMMonitorMatch, EVMTransaction, EventCondition, FunctionCondition,
+		MatchConditions, MatchSeverity, Monitor, MonitorMatch, ScriptLanguage, Trigger,
+		TriggerTypeConfig,
+	},
 	repositories::{TriggerRepositoryTrait, TriggerService},
-	services::{notification::NotificationService, trigger::error::TriggerError},
-	utils::normalize_string,
+	services::{
+		blockwatcher::BlockStorage,
+		filter::match_variables,
+		notification::{payload_builder::format_template, FailedNotification, NotificationService},
+		trigger::{
+			cooldown::NotificationCooldown,
+			error::TriggerError,
+			read_script_content,
+			throttle::{NotificationThrottle, ThrottleDecision},
+			NotificationRetryQueue,
+		},
+	},
+	utils::{
+		metrics::{NOTIFICATIONS_SENT_TOTAL, NOTIFICATIONS_THROTTLED_TOTAL},
+		normalize_string, system_clock, SharedClock,
+	},
 };
 
+/// Name of the monitor of the sample match sent by test notifications
+const TEST_NOTIFICATION_MONITOR_NAME: &str = "Test Notification";
+
+/// Outcome of executing a single trigger
+#[derive(Debug)]
+pub enum TriggerOutcome {
+	/// The notification was delivered, or buffered into a digest
+	Sent,
+	/// The trigger wasn't executed, as it is disabled, its monitor is in cooldown or
+	/// throttled, or the service is running dry
+	Skipped,
+	/// The trigger couldn't be found or its notification failed
+	Failed(TriggerError),
+}
+
+impl TriggerOutcome {
+	/// Returns whether the notification was sent
+	pub fn is_sent(&self) -> bool {
+		matches!(self, Self::Sent)
+	}
+
+	/// Returns whether the trigger was skipped
+	pub fn is_skipped(&self) -> bool {
+		matches!(self, Self::Skipped)
+	}
+
+	/// Returns whether the trigger failed
+	pub fn is_failed(&self) -> bool {
+		matches!(self, Self::Failed(_))
+	}
+
+	/// Returns the error of a failed trigger
+	pub fn err(self) -> Option<TriggerError> {
+		match self {
+			Self::Failed(e) => Some(e),
+			Self::Sent | Self::Skipped => None,
+		}
+	}
+}
+
+/// Result of executing a single trigger
+#[derive(Debug)]
+pub struct TriggerResult {
+	/// Identifier of the executed trigger
+	pub trigger_name: String,
+	/// Outcome of the execution
+	pub outcome: TriggerOutcome,
+}
+
+/// Collapses per-trigger results into a single result
+///
+/// # Arguments
+/// * `results` - Results of the executed triggers
+///
+/// # Returns
+/// * `Result<(), TriggerError>` - Success if no trigger failed, skipped triggers included,
+///   otherwise an execution error counting the failures, with the failed triggers and their
+///   errors as source
+pub fn collapse_trigger_results(results: Vec<TriggerResult>) -> Result<(), TriggerError> {
+	let errors: Vec<_> = results
+		.into_iter()
+		.filter_map(|result| {
+			result
+				.outcome
+				.err()
+				.map(|e| format!("{}: {}", result.trigger_name, e))
+		})
+		.collect();
+
+	if errors.is_empty() {
+		Ok(())
+	} else {
+		Err(TriggerError::execution_error(
+			format!("Some trigger(s) failed ({} failure(s))", errors.len()),
+			// We join all errors into a single string for the source and wrap it as a single
+			// Execution
+			Some(
+				TriggerError::execution_error(format!("{:#?}", errors.join(", ")), None, None)
+					.into(),
+			),
+			None,
+		))
+	}
+}
+
 /// Trait for executing triggers
 ///
 /// This trait must be implemented by all trigger execution services to provide
 /// a way to execute triggers.
 #[async_trait]
 pub trait TriggerExecutionServiceTrait {
+	/// Executes multiple triggers and collapses their outcomes into a single result
+	///
+	/// # Returns
+	/// * `Result<(), TriggerError>` - Success, or an error counting the failed triggers
 	async fn execute(
 		&self,
 		trigger_slugs: &[String],
 		variables: HashMap<String, String>,
 		monitor_match: &MonitorMatch,
 		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
-	) -> Result<(), TriggerError>;
+	) -> Result<(), TriggerError> {
+		collapse_trigger_results(
+			self.execute_with_results(trigger_slugs, variables, monitor_match, trigger_scripts)
+				.await,
+		)
+	}
+	/// Executes multiple triggers and reports the outcome of each one
+	///
+	/// # Returns
+	/// * `Vec<TriggerResult>` - Outcome of each trigger, in the order of `trigger_slugs`
+	async fn execute_with_results(
+		&self,
+		trigger_slugs: &[String],
+		variables: HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+	) -> Vec<TriggerResult>;
 	async fn load_scripts(
 		&self,
 		monitors: &[Monitor],
@@ -43,6 +168,16 @@ pub struct TriggerExecutionService<T: TriggerRepositoryTrait> {
 	trigger_service: TriggerService<T>,
 	/// Service for sending notifications
 	notification_service: NotificationService,
+	/// Queue retrying failed notifications in the background, if any
+	retry_queue: Option<NotificationRetryQueue>,
+	/// Notification counters of the monitors with a `max_notifications_per_hour`
+	throttle: Arc<NotificationThrottle>,
+	/// Dedup keys in cooldown of the monitors with a `cooldown_ms`
+	cooldown: Arc<NotificationCooldown>,
+	/// Whether triggers are only logged instead of executed
+	dry_run: bool,
+	/// Clock the cooldowns and throttles are timed with
+	clock: SharedClock,
 }
 
 impl<T: TriggerRepositoryTrait> TriggerExecutionService<T> {
@@ -61,76 +196,499 @@ impl<T: TriggerRepositoryTrait> TriggerExecutionService<T> {
 		Self {
 			trigger_service,
 			notification_service,
+			retry_queue: None,
+			throttle: Arc::new(NotificationThrottle::default()),
+			cooldown: Arc::new(NotificationCooldown::new()),
+			dry_run: false,
+			clock: system_clock(),
+		}
+	}
+
+	/// Sets the clock the cooldowns and throttles are timed with, the system's by default
+	///
+	/// # Arguments
+	/// * `clock` - Clock replacing the system's
+	pub fn with_clock(mut self, clock: SharedClock) -> Self {
+		self.clock = clock;
+		self
+	}
+
+	/// Only logs the triggers in [`TriggerExecutionServiceTrait::execute_with_results`] instead
+	/// of executing them, reporting them as successful
+	///
+	/// # Arguments
+	/// * `dry_run` - Whether triggers are only logged
+	pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+		self.dry_run = dry_run;
+		self
+	}
+
+	/// Retries the notifications failing in [`TriggerExecutionServiceTrait::execute_with_results`]
+	/// through a retry queue
+	///
+	/// The failed attempt is still reported to the caller, the retries happen in the
+	/// background. Digests failing once their batch window elapses are retried through the
+	/// same queue.
+	///
+	/// # Arguments
+	/// * `retry_queue` - Queue retrying the failed notifications
+	pub fn with_retry_queue(mut self, retry_queue: NotificationRetryQueue) -> Self {
+		let digest_retry_queue = retry_queue.clone();
+		self.notification_service = self
+			.notification_service
+			.with_failure_handler(Arc::new(move |failed| digest_retry_queue.enqueue(failed)));
+		self.retry_queue = Some(retry_queue);
+		self
+	}
+
+	/// Sends the open digests of the triggers with a batch window, see
+	/// [`NotificationService::flush_digests`]
+	pub async fn flush_digests(&self) {
+		self.notification_service.flush_digests().await;
+	}
+
+	/// Caps the notifications of the monitors with a `max_notifications_per_hour` through the
+	/// given throttle, instead of one with a window of an hour
+	///
+	/// # Arguments
+	/// * `throttle` - Notification counters of the throttled monitors
+	pub fn with_notification_throttle(mut self, throttle: NotificationThrottle) -> Self {
+		self.throttle = Arc::new(throttle);
+		self
+	}
+
+	/// Returns the dedup keys in cooldown
+	pub fn notification_cooldown(&self) -> &NotificationCooldown {
+		&self.cooldown
+	}
+
+	/// Restores the dedup keys in cooldown persisted in a block storage, at the time of the
+	/// service's clock
+	///
+	/// # Arguments
+	/// * `storage` - Storage the keys are persisted in
+	pub async fn restore_cooldown<S: BlockStorage + 'static>(
+		&self,
+		storage: Arc<S>,
+	) -> Result<(), anyhow::Error> {
+		self.cooldown.restore(storage, self.clock.utc_now()).await
+	}
+
+	/// Applies the `cooldown_ms` of the monitor of a match
+	///
+	/// The dedup key of the match is rendered from the monitor's `dedup_key` with the variables
+	/// of the match, and namespaced by the monitor name. A match whose key can't be rendered is
+	/// notified.
+	///
+	/// # Returns
+	/// * `bool` - Whether the match is dropped as a duplicate
+	fn apply_cooldown(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> bool {
+		let monitor = match monitor_match {
+			MonitorMatch::EVM(evm_match) => &evm_match.monitor,
+			MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor,
+			MonitorMatch::Midnight(midnight_match) => &midnight_match.monitor,
+			MonitorMatch::Solana(solana_match) => &solana_match.monitor,
+		};
+		let Some(cooldown_ms) = monitor.cooldown_ms else {
+			return false;
+		};
+		let dedup_key = match &monitor.dedup_key {
+			Some(template) => match format_template(template, variables) {
+				Ok(dedup_key) => dedup_key,
+				Err(e) => {
+					tracing::error!(
+						"Failed to render dedup key of monitor {}: {}",
+						monitor.name,
+						e
+					);
+					return false;
+				}
+			},
+			None => String::new(),
+		};
+
+		let dropped = self.cooldown.check(
+			&format!("{}:{}", monitor.name, dedup_key),
+			Duration::from_millis(cooldown_ms),
+			monitor.persist_cooldown && !self.dry_run,
+			self.clock.utc_now(),
+		);
+		if dropped {
+			tracing::debug!(
+				"Dropping match of monitor {} with dedup key '{}' in cooldown",
+				monitor.name,
+				dedup_key
+			);
+		}
+		dropped
+	}
+
+	/// Applies the `max_notifications_per_hour` of the monitor of a match
+	///
+	/// When the match is dropped and it is the first one since the last summary, a summary
+	/// is scheduled for monitors with a `throttle_summary`: once the window frees up, according
+	/// to the service's clock, the triggers are executed for this match with the number of
+	/// matches dropped in the `throttle.suppressed_count` variable.
+	///
+	/// # Returns
+	/// * `bool` - Whether the match is dropped
+	fn apply_throttle(
+		&self,
+		trigger_slugs: &[String],
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+	) -> bool {
+		let monitor = match monitor_match {
+			MonitorMatch::EVM(evm_match) => &evm_match.monitor,
+			MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor,
+			MonitorMatch::Midnight(midnight_match) => &midnight_match.monitor,
+			MonitorMatch::Solana(solana_match) => &solana_match.monitor,
+		};
+		let Some(max_notifications) = monitor.max_notifications_per_hour else {
+			return false;
+		};
+
+		let ThrottleDecision::Throttled { first, resets_in } =
+			self.throttle
+				.check(&monitor.name, max_notifications, self.clock.now())
+		else {
+			return false;
+		};
+		tracing::debug!(
+			"Dropping match of monitor {}, which reached its {} notifications per hour",
+			monitor.name,
+			max_notifications
+		);
+		NOTIFICATIONS_THROTTLED_TOTAL
+			.with_label_values(&[monitor.name.as_str()])
+			.inc();
+
+		if first && monitor.throttle_summary && !self.dry_run {
+			let triggers: Vec<Trigger> = trigger_slugs
+				.iter()
+				.filter_map(|trigger_slug| self.trigger_service.get(trigger_slug))
+				.collect();
+			let throttle = self.throttle.clone();
+			let notification_service = self.notification_service.clone();
+			let monitor_name = monitor.name.clone();
+			let mut variables = variables.clone();
+			let monitor_match = monitor_match.clone();
+			let trigger_scripts = trigger_scripts.clone();
+			let window_reset = self.clock.sleep(resets_in);
+			tokio::spawn(async move {
+				window_reset.await;
+				let suppressed = throttle.take_suppressed(&monitor_name);
+				variables.insert(
+					"throttle.suppressed_count".to_string(),
+					suppressed.to_string(),
+				);
+				for trigger in triggers.iter().filter(|trigger| trigger.is_enabled()) {
+					if let Err(e) = notification_service
+						.execute(trigger, &variables, &monitor_match, &trigger_scripts)
+						.await
+					{
+						tracing::error!(
+							"Failed to send throttle summary of monitor {} through trigger {}: {}",
+							monitor_name,
+							trigger.name,
+							e
+						);
+					}
+				}
+			});
 		}
+		true
+	}
+
+	/// Executes a trigger for a service-level alert that isn't tied to a monitor match
+	///
+	/// # Arguments
+	/// * `trigger_slug` - Identifier of the trigger to execute
+	/// * `variables` - Variables to substitute in trigger templates
+	///
+	/// # Returns
+	/// * `Result<(), TriggerError>` - Success or error
+	///
+	/// # Errors
+	/// - Returns `TriggerError::NotFound` if the trigger cannot be found
+	/// - Returns `TriggerError::ExecutionError` if notification delivery fails
+	pub async fn execute_alert(
+		&self,
+		trigger_slug: &str,
+		variables: HashMap<String, String>,
+	) -> Result<(), TriggerError> {
+		let trigger = self
+			.trigger_service
+			.get(trigger_slug)
+			.ok_or_else(|| TriggerError::not_found(trigger_slug.to_string(), None, None))?;
+
+		self.notification_service
+			.execute_alert(&trigger, &variables)
+			.await
+			.map_err(|e| TriggerError::execution_error(e.to_string(), Some(e.into()), None))
 	}
 }
 
+impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionService<T> {
+	/// Sends a sample match through a single trigger to verify its configuration
+	///
+	/// The sample is an EVM token transfer matched by a monitor named "Test Notification",
+	/// with the template variables a real match would have. Script triggers run their script
+	/// against it. Failed notifications are not retried.
+	///
+	/// # Arguments
+	/// * `trigger_slug` - Identifier of the trigger to test
+	///
+	/// # Returns
+	/// * `Result<(), TriggerError>` - Success or error
+	///
+	/// # Errors
+	/// - Returns `TriggerError::NotFound` if the trigger cannot be found
+	/// - Returns `TriggerError::ConfigurationError` if the trigger script cannot be read
+	/// - Returns `TriggerError::ExecutionError` if notification delivery fails
+	pub async fn execute_test_notification(&self, trigger_slug: &str) -> Result<(), TriggerError> {
+		let trigger = self
+			.trigger_service
+			.get(trigger_slug)
+			.ok_or_else(|| TriggerError::not_found(trigger_slug.to_string(), None, None))?;
+
+		let monitor = Monitor {
+			name: TEST_NOTIFICATION_MONITOR_NAME.to_string(),
+			triggers: vec![trigger_slug.to_string()],
+			..Default::default()
+		};
+		let trigger_scripts = self.load_scripts(std::slice::from_ref(&monitor)).await?;
+		let monitor_match = test_notification_match(monitor);
+
+		self.notification_service
+			.execute(
+				&trigger,
+				&match_variables(&monitor_match),
+				&monitor_match,
+				&trigger_scripts,
+			)
+			.await
+			.map_err(|e| TriggerError::execution_error(e.to_string(), Some(e.into()), None))
+	}
+
+	/// Checks that every enabled trigger can deliver its notifications, without sending any
+	///
+	/// The triggers are checked concurrently, each by its notifier, e.g. with a `HEAD` request
+	/// to a webhook or a `NOOP` command to an SMTP server.
+	///
+	/// # Returns
+	/// * `Vec<(String, TriggerError)>` - Slug and error of each unhealthy trigger, sorted by
+	///   slug
+	pub async fn check_triggers(&self) -> Vec<(String, TriggerError)> {
+		let triggers = self.trigger_service.get_all();
+		let checks = triggers
+			.iter()
+			.filter(|(_, trigger)| trigger.is_enabled())
+			.map(|(slug, trigger)| async move {
+				let result = self.notification_service.healthcheck(trigger).await;
+				(slug.clone(), result)
+			});
+
+		let mut unhealthy: Vec<(String, TriggerError)> = futures::future::join_all(checks)
+			.await
+			.into_iter()
+			.filter_map(|(slug, result)| {
+				result.err().map(|e| {
+					let error = TriggerError::execution_error(e.to_string(), Some(e.into()), None);
+					(slug, error)
+				})
+			})
+			.collect();
+		unhealthy.sort_by(|(a, _), (b, _)| a.cmp(b));
+		unhealthy
+	}
+}
+
+/// Builds the sample match sent by test notifications
+///
+/// # Arguments
+/// * `monitor` - Monitor of the sample match
+fn test_notification_match(monitor: Monitor) -> MonitorMatch {
+	let from = Address::repeat_byte(0x11);
+	let to = Address::repeat_byte(0x22);
+	let value = U256::from(1_000_000_000_000_000_000u64);
+
+	let matched_on = MatchConditions {
+		functions: vec![FunctionCondition {
+			signature: "transfer(address,uint256)".to_string(),
+			expression: None,
+			warn_threshold_expression: None,
+			eip712_schema: None,
+		}],
+		events: vec![EventCondition {
+			signature: "Transfer(address,address,uint256)".to_string(),
+			expression: None,
+			warn_threshold_expression: None,
+		}],
+		transactions: vec![],
+		token_transfers: vec![],
+		block_conditions: vec![],
+		balance_conditions: vec![],
+		state_conditions: vec![],
+	};
+	let param = |name: &str, kind: &str, value: String, indexed: bool| EVMMatchParamEntry {
+		name: name.to_string(),
+		value,
+		indexed,
+		kind: kind.to_string(),
+	};
+
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor: Monitor {
+			match_conditions: matched_on.clone(),
+			..monitor
+		},
+		transaction: EVMTransaction(EVMBaseTransaction {
+			hash: B256::repeat_byte(0xab),
+			from: Some(from),
+			to: Some(to),
+			value,
+			..Default::default()
+		}),
+		receipt: None,
+		logs: None,
+		network_slug: "test".to_string(),
+		matched_on,
+		matched_on_args: Some(EVMMatchArguments {
+			functions: Some(vec![EVMMatchParamsMap {
+				signature: "transfer(address,uint256)".to_string(),
+				args: Some(vec![
+					param("to", "address", to.to_string(), false),
+					param("amount", "uint256", value.to_string(), false),
+				]),
+				hex_signature: Some("a9059cbb".to_string()),
+			}]),
+			events: Some(vec![EVMMatchParamsMap {
+				signature: "Transfer(address,address,uint256)".to_string(),
+				args: Some(vec![
+					param("from", "address", from.to_string(), true),
+					param("to", "address", to.to_string(), true),
+					param("value", "uint256", value.to_string(), false),
+				]),
+				hex_signature: Some(
+					"ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef".to_string(),
+				),
+			}]),
+			block: None,
+			balance: None,
+		}),
+		confirmation_state: EVMConfirmationState::Confirmed,
+		severity: MatchSeverity::Alert,
+		sender_verified: None,
+	}))
+}
+
 #[async_trait]
 impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 	for TriggerExecutionService<T>
 {
 	/// Executes multiple triggers with variable substitution
 	///
+	/// Triggers are executed concurrently and a failing trigger doesn't prevent the others
+	/// from running. Failed notifications are queued for retry when a retry queue is set.
+	/// Disabled triggers are skipped and reported as successful, whichever monitor references
+	/// them. In dry run, existing triggers are only logged and reported as successful.
+	///
+	/// Matches of a monitor duplicating a match notified during its `cooldown_ms`, or that
+	/// already notified its `max_notifications_per_hour` over the last hour, are dropped, their
+	/// triggers being reported as successful. Duplicates don't count towards the cap.
+	///
 	/// # Arguments
 	/// * `trigger_slugs` - List of trigger identifiers to execute
 	/// * `variables` - Variables to substitute in trigger templates
 	///
 	/// # Returns
-	/// * `Result<(), TriggerError>` - Success or error
+	/// * `Vec<TriggerResult>` - Outcome of each trigger, in the order of `trigger_slugs`
 	///
 	/// # Errors
-	/// - Returns `TriggerError::NotFound` if a trigger cannot be found
-	/// - Returns `TriggerError::ExecutionError` if notification delivery fails
-	async fn execute(
+	/// A trigger's outcome is:
+	/// - `TriggerOutcome::Skipped` if the trigger is disabled, the monitor is in cooldown or
+	///   throttled, or the service is running dry
+	/// - `TriggerOutcome::Failed` with `TriggerError::NotFound` if the trigger cannot be found
+	/// - `TriggerOutcome::Failed` with `TriggerError::ExecutionError` if notification delivery
+	///   fails
+	async fn execute_with_results(
 		&self,
 		trigger_slugs: &[String],
 		variables: HashMap<String, String>,
 		monitor_match: &MonitorMatch,
 		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
-	) -> Result<(), TriggerError> {
+	) -> Vec<TriggerResult> {
 		use futures::future::join_all;
 
+		if self.apply_cooldown(&variables, monitor_match)
+			|| self.apply_throttle(trigger_slugs, &variables, monitor_match, trigger_scripts)
+		{
+			return trigger_slugs
+				.iter()
+				.map(|trigger_slug| TriggerResult {
+					trigger_name: trigger_slug.clone(),
+					outcome: TriggerOutcome::Skipped,
+				})
+				.collect();
+		}
+
 		let futures = trigger_slugs.iter().map(|trigger_slug| async {
-			let trigger = self
-				.trigger_service
-				.get(trigger_slug)
-				.ok_or_else(|| TriggerError::not_found(trigger_slug.to_string(), None, None))?;
-
-			self.notification_service
-				.execute(&trigger, &variables, monitor_match, trigger_scripts)
-				.await
-				// We remove logging capability here since we're logging it further down
-				.map_err(|e| TriggerError::execution_error_without_log(e.to_string(), None, None))
+			let outcome = match self.trigger_service.get(trigger_slug) {
+				Some(trigger) if !trigger.is_enabled() => {
+					tracing::debug!("Skipping disabled trigger {}", trigger_slug);
+					let mut label_values = vec![trigger.trigger_type.as_str(), "skipped"];
+					label_values.extend(trigger.metric_label_values());
+					NOTIFICATIONS_SENT_TOTAL
+						.with_label_values(&label_values)
+						.inc();
+					TriggerOutcome::Skipped
+				}
+				Some(_) if self.dry_run => {
+					tracing::info!("Dry run, not executing trigger {}", trigger_slug);
+					TriggerOutcome::Skipped
+				}
+				Some(trigger) => {
+					let result = self
+						.notification_service
+						.execute(&trigger, &variables, monitor_match, trigger_scripts)
+						.await;
+					if let (Err(e), Some(retry_queue)) = (&result, &self.retry_queue) {
+						retry_queue.enqueue(FailedNotification {
+							trigger,
+							variables: variables.clone(),
+							monitor_match: monitor_match.clone(),
+							trigger_scripts: trigger_scripts.clone(),
+							error: e.to_string(),
+						});
+					}
+					// We remove logging capability here since failures are logged by the caller
+					match result {
+						Ok(()) => TriggerOutcome::Sent,
+						Err(e) => TriggerOutcome::Failed(
+							TriggerError::execution_error_without_log(e.to_string(), None, None),
+						),
+					}
+				}
+				None => TriggerOutcome::Failed(TriggerError::not_found(
+					trigger_slug.to_string(),
+					None,
+					None,
+				)),
+			};
+
+			TriggerResult {
+				trigger_name: trigger_slug.clone(),
+				outcome,
+			}
 		});
 
-		let results = join_all(futures).await;
-		let errors: Vec<_> = results.into_iter().filter_map(|r| r.err()).collect();
-
-		if errors.is_empty() {
-			Ok(())
-		} else {
-			Err(TriggerError::execution_error(
-				format!("Some trigger(s) failed ({} failure(s))", errors.len()),
-				// We join all errors into a single string for the source and wrap it as a single
-				// Execution
-				Some(
-					TriggerError::execution_error(
-						format!(
-							"{:#?}",
-							errors
-								.iter()
-								.map(|e| e.to_string())
-								.collect::<Vec<_>>()
-								.join(", ")
-						),
-						None,
-						None,
-					)
-					.into(),
-				),
-				None,
-			))
-		}
+		join_all(futures).await
 	}
 	/// Loads trigger condition scripts for monitors
 	///
@@ -151,7 +709,11 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 
 		for monitor in monitors {
 			// Skip monitors without trigger conditions
-			if monitor.trigger_conditions.is_empty() && monitor.triggers.is_empty() {
+			if monitor.trigger_conditions.is_empty()
+				&& monitor.triggers.is_empty()
+				&& monitor.warn_triggers.is_empty()
+				&& monitor.resolve_trigger.is_none()
+			{
 				continue;
 			}
 
@@ -160,7 +722,7 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 				let script_path = Path::new(&condition.script_path);
 
 				// Read the script content
-				let content = tokio::fs::read_to_string(script_path)
+				let content = read_script_content(script_path, &condition.language)
 					.await
 					.with_context(|| {
 						format!("Failed to read script file: {}", condition.script_path)
@@ -176,8 +738,14 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 				);
 			}
 
-			// For each trigger, we'll load the script
-			for trigger in &monitor.triggers {
+			// For each trigger, including the triggers of near misses and resolved alerts, we'll
+			// load the script
+			for trigger in monitor
+				.triggers
+				.iter()
+				.chain(&monitor.warn_triggers)
+				.chain(&monitor.resolve_trigger)
+			{
 				let trigger_config =
 					self.trigger_service.get(trigger.as_str()).ok_or_else(|| {
 						TriggerError::configuration_error(
@@ -190,25 +758,26 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 				let TriggerTypeConfig::Script {
 					language,
 					script_path,
-					arguments: _,
-					timeout_ms: _,
+					..
 				} = &trigger_config.config
 				else {
 					continue;
 				};
 
 				let script_path = Path::new(script_path);
-				let content = tokio::fs::read_to_string(script_path).await.map_err(|e| {
-					TriggerError::configuration_error(
-						format!(
-							"Failed to read script file {}: {}",
-							script_path.display(),
-							e
-						),
-						None,
-						None,
-					)
-				})?;
+				let content = read_script_content(script_path, language)
+					.await
+					.map_err(|e| {
+						TriggerError::configuration_error(
+							format!(
+								"Failed to read script file {}: {}",
+								script_path.display(),
+								e
+							),
+							None,
+							None,
+						)
+					})?;
 
 				scripts.insert(
 					format!(
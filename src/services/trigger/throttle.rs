@@ -0,0 +1,165 @@
+//! Throttling of the notifications of monitors.
+//!
+//! A monitor with a `max_notifications_per_hour` notifies at most that many matches over any
+//! rolling hour. Further matches are dropped and counted, so that a summary of them can be sent
+//! once the hour frees up, see [`TriggerExecutionService`](super::TriggerExecutionService).
+
+use std::{
+	collections::{HashMap, VecDeque},
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+/// Rolling window over which the notifications of a monitor are capped
+pub const THROTTLE_WINDOW: Duration = Duration::from_secs(3600);
+
+/// Decision of the throttle for a match
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleDecision {
+	/// The match is notified
+	Allowed,
+	/// The match is dropped
+	Throttled {
+		/// Whether this is the first match dropped since the dropped matches were last taken
+		first: bool,
+		/// Time until the monitor can notify again
+		resets_in: Duration,
+	},
+}
+
+/// Notifications of a monitor over the window
+#[derive(Debug, Default)]
+struct MonitorWindow {
+	/// Times of the notifications of the window, oldest first
+	sent: VecDeque<Instant>,
+	/// Number of matches dropped since they were last taken
+	suppressed: u32,
+}
+
+/// Rolling-window counters of the notifications of the throttled monitors, by monitor name
+#[derive(Debug)]
+pub struct NotificationThrottle {
+	window: Duration,
+	monitors: Mutex<HashMap<String, MonitorWindow>>,
+}
+
+impl Default for NotificationThrottle {
+	fn default() -> Self {
+		Self::new(THROTTLE_WINDOW)
+	}
+}
+
+impl NotificationThrottle {
+	/// Creates a throttle capping the notifications over `window`
+	pub fn new(window: Duration) -> Self {
+		Self {
+			window,
+			monitors: Mutex::new(HashMap::new()),
+		}
+	}
+
+	/// Records a match of a monitor, deciding whether it is notified
+	///
+	/// # Arguments
+	/// * `monitor_name` - Name of the monitor of the match
+	/// * `max_notifications` - Maximum number of notifications of the monitor over the window
+	/// * `now` - Time of the match
+	pub fn check(
+		&self,
+		monitor_name: &str,
+		max_notifications: u32,
+		now: Instant,
+	) -> ThrottleDecision {
+		let mut monitors = self.monitors.lock().unwrap();
+		let monitor = monitors.entry(monitor_name.to_string()).or_default();
+
+		while monitor
+			.sent
+			.front()
+			.is_some_and(|sent| now.saturating_duration_since(*sent) >= self.window)
+		{
+			monitor.sent.pop_front();
+		}
+
+		match monitor.sent.front() {
+			Some(oldest) if monitor.sent.len() >= max_notifications as usize => {
+				monitor.suppressed = monitor.suppressed.saturating_add(1);
+				ThrottleDecision::Throttled {
+					first: monitor.suppressed == 1,
+					resets_in: self
+						.window
+						.saturating_sub(now.saturating_duration_since(*oldest)),
+				}
+			}
+			_ => {
+				monitor.sent.push_back(now);
+				ThrottleDecision::Allowed
+			}
+		}
+	}
+
+	/// Takes the number of matches of a monitor dropped since they were last taken
+	pub fn take_suppressed(&self, monitor_name: &str) -> u32 {
+		self.monitors
+			.lock()
+			.unwrap()
+			.get_mut(monitor_name)
+			.map_or(0, |monitor| std::mem::take(&mut monitor.suppressed))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_check_drops_matches_over_the_cap() {
+		let throttle = NotificationThrottle::default();
+		let start = Instant::now();
+
+		assert_eq!(
+			throttle.check("monitor", 2, start),
+			ThrottleDecision::Allowed
+		);
+		assert_eq!(
+			throttle.check("monitor", 2, start + Duration::from_secs(60)),
+			ThrottleDecision::Allowed
+		);
+		assert_eq!(
+			throttle.check("monitor", 2, start + Duration::from_secs(120)),
+			ThrottleDecision::Throttled {
+				first: true,
+				resets_in: Duration::from_secs(3480),
+			}
+		);
+		assert!(matches!(
+			throttle.check("monitor", 2, start + Duration::from_secs(180)),
+			ThrottleDecision::Throttled { first: false, .. }
+		));
+
+		// Monitors are throttled independently
+		assert_eq!(throttle.check("other", 2, start), ThrottleDecision::Allowed);
+		assert_eq!(throttle.take_suppressed("monitor"), 2);
+		assert_eq!(throttle.take_suppressed("monitor"), 0);
+	}
+
+	#[test]
+	fn test_check_rolls_the_window() {
+		let throttle = NotificationThrottle::new(Duration::from_secs(10));
+		let start = Instant::now();
+
+		assert_eq!(
+			throttle.check("monitor", 1, start),
+			ThrottleDecision::Allowed
+		);
+		assert!(matches!(
+			throttle.check("monitor", 1, start + Duration::from_secs(5)),
+			ThrottleDecision::Throttled { first: true, .. }
+		));
+		assert_eq!(
+			throttle.check("monitor", 1, start + Duration::from_secs(10)),
+			ThrottleDecision::Allowed
+		);
+		assert_eq!(throttle.take_suppressed("monitor"), 1);
+	}
+}
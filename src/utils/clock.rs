@@ -0,0 +1,156 @@
+//! Source of the current time.
+//!
+//! Services reading the current time take a [`Clock`] rather than calling `Instant::now()` or
+//! `Utc::now()` directly, so that their time-based behaviors, such as idle times and stall
+//! thresholds, can be tested deterministically by advancing a [`MockClock`] instead of waiting.
+//! Services waiting for a time to pass do so with [`Clock::sleep`], for the same reason.
+
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use std::{
+	fmt,
+	sync::Arc,
+	time::{Duration, Instant},
+};
+use tokio::sync::watch;
+
+/// Source of the current monotonic and wall-clock times
+pub trait Clock: Send + Sync {
+	/// Returns the current monotonic time, used to measure elapsed time
+	fn now(&self) -> Instant;
+
+	/// Returns the current wall-clock time
+	fn utc_now(&self) -> DateTime<Utc>;
+
+	/// Waits until the clock moved forward by a duration
+	fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+		Box::pin(tokio::time::sleep(duration))
+	}
+}
+
+/// Clock shared by the services reading the current time
+pub type SharedClock = Arc<dyn Clock>;
+
+impl fmt::Debug for dyn Clock {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Clock({})", self.utc_now())
+	}
+}
+
+/// Returns the clock of the system
+pub fn system_clock() -> SharedClock {
+	Arc::new(SystemClock)
+}
+
+/// Clock reading the time of the system
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+	fn now(&self) -> Instant {
+		Instant::now()
+	}
+
+	fn utc_now(&self) -> DateTime<Utc> {
+		Utc::now()
+	}
+}
+
+/// Clock whose time only moves when advanced, for tests
+#[derive(Debug)]
+pub struct MockClock {
+	/// Monotonic time the clock was created at
+	start: Instant,
+	/// Wall-clock time the clock was created at
+	start_utc: DateTime<Utc>,
+	/// Time the clock was advanced by since it was created, watched by the sleeps
+	elapsed: watch::Sender<Duration>,
+}
+
+impl MockClock {
+	/// Creates a clock stopped at the current time of the system
+	pub fn new() -> Self {
+		Self::at(Utc::now())
+	}
+
+	/// Creates a clock stopped at a wall-clock time
+	///
+	/// # Arguments
+	/// * `start_utc` - Wall-clock time of the clock until it's advanced
+	pub fn at(start_utc: DateTime<Utc>) -> Self {
+		Self {
+			start: Instant::now(),
+			start_utc,
+			elapsed: watch::Sender::new(Duration::ZERO),
+		}
+	}
+
+	/// Moves the time of the clock forward
+	///
+	/// # Arguments
+	/// * `duration` - Time to move the clock forward by
+	pub fn advance(&self, duration: Duration) {
+		self.elapsed.send_modify(|elapsed| *elapsed += duration);
+	}
+}
+
+impl Default for MockClock {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Clock for MockClock {
+	fn now(&self) -> Instant {
+		self.start + *self.elapsed.borrow()
+	}
+
+	fn utc_now(&self) -> DateTime<Utc> {
+		self.start_utc + *self.elapsed.borrow()
+	}
+
+	/// Waits until the clock is advanced by a duration, however long it takes
+	fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+		let wake_at = *self.elapsed.borrow() + duration;
+		let mut elapsed = self.elapsed.subscribe();
+		Box::pin(async move {
+			let _ = elapsed.wait_for(|elapsed| *elapsed >= wake_at).await;
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_mock_clock_only_moves_when_advanced() {
+		let clock = MockClock::at("2024-01-01T00:00:00Z".parse().unwrap());
+		let start = clock.now();
+		assert_eq!(clock.now(), start);
+
+		clock.advance(Duration::from_secs(90));
+		assert_eq!(clock.now() - start, Duration::from_secs(90));
+		assert_eq!(
+			clock.utc_now(),
+			"2024-01-01T00:01:30Z".parse::<DateTime<Utc>>().unwrap()
+		);
+	}
+
+	#[tokio::test]
+	async fn test_mock_clock_sleep_wakes_when_advanced() {
+		let clock = MockClock::new();
+		let mut sleep = tokio::spawn(clock.sleep(Duration::from_secs(60)));
+
+		clock.advance(Duration::from_secs(59));
+		assert!(tokio::time::timeout(Duration::from_millis(50), &mut sleep)
+			.await
+			.is_err());
+
+		clock.advance(Duration::from_secs(1));
+		tokio::time::timeout(Duration::from_secs(1), sleep)
+			.await
+			.unwrap()
+			.unwrap();
+	}
+}
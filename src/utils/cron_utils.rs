@@ -1,8 +1,10 @@
 //! Utility functions for working with cron schedules and time intervals
 //!
 //! This module provides helper functions for parsing and analyzing cron expressions,
+//! and the timezones they are evaluated in.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use cron::Schedule;
 
 /// Calculates the time interval between two consecutive occurrences of a cron schedule
@@ -40,3 +42,103 @@ pub fn get_cron_interval_ms(cron_schedule: &str) -> Option<i64> {
 		None // Return None if we cannot find two occurrences
 	}
 }
+
+/// Validates a cron expression
+///
+/// # Arguments
+///
+/// * `cron_schedule` - A string slice containing the cron expression to validate
+///
+/// # Returns
+///
+/// * `Ok(())` - If the expression is a valid cron schedule
+/// * `Err(cron::error::Error)` - The parse error otherwise
+pub fn validate_cron_schedule(cron_schedule: &str) -> Result<(), cron::error::Error> {
+	cron_schedule.parse::<Schedule>().map(|_| ())
+}
+
+/// Parses the IANA name of a timezone, such as `America/New_York`
+///
+/// # Arguments
+///
+/// * `timezone` - A string slice containing the name of the timezone
+///
+/// # Returns
+///
+/// * `Ok(Tz)` - The timezone
+/// * `Err(chrono_tz::ParseError)` - If the name is not a known IANA timezone
+pub fn parse_timezone(timezone: &str) -> Result<Tz, chrono_tz::ParseError> {
+	timezone.parse::<Tz>()
+}
+
+/// Calculates the next occurrence of a cron schedule evaluated in a timezone
+///
+/// # Arguments
+///
+/// * `cron_schedule` - A string slice containing a valid cron expression
+/// * `timezone` - Timezone the fields of the expression are expressed in
+/// * `after` - Instant after which the occurrence is searched
+///
+/// # Returns
+///
+/// * `Some(DateTime<Utc>)` - The UTC instant of the next occurrence
+/// * `None` - If the cron expression is invalid or has no further occurrence
+pub fn next_cron_occurrence(
+	cron_schedule: &str,
+	timezone: Tz,
+	after: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+	let schedule = cron_schedule.parse::<Schedule>().ok()?;
+	schedule
+		.after(&after.with_timezone(&timezone))
+		.next()
+		.map(|occurrence| occurrence.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::TimeZone;
+
+	#[test]
+	fn test_parse_timezone() {
+		assert_eq!(
+			parse_timezone("America/New_York").unwrap(),
+			Tz::America__New_York
+		);
+		assert_eq!(parse_timezone("UTC").unwrap(), Tz::UTC);
+		assert!(parse_timezone("Mars/Olympus_Mons").is_err());
+	}
+
+	#[test]
+	fn test_next_cron_occurrence_in_timezone() {
+		// Daily at 09:00, the seconds-prefixed form of `0 9 * * *`
+		let schedule = "0 0 9 * * *";
+		let new_york = parse_timezone("America/New_York").unwrap();
+
+		// 09:00 EDT (UTC-4) in summer
+		let after = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+		assert_eq!(
+			next_cron_occurrence(schedule, new_york, after),
+			Some(Utc.with_ymd_and_hms(2024, 7, 1, 13, 0, 0).unwrap())
+		);
+
+		// 09:00 EST (UTC-5) in winter
+		let after = Utc.with_ymd_and_hms(2024, 1, 15, 14, 0, 0).unwrap();
+		assert_eq!(
+			next_cron_occurrence(schedule, new_york, after),
+			Some(Utc.with_ymd_and_hms(2024, 1, 16, 14, 0, 0).unwrap())
+		);
+
+		// Evaluated in UTC
+		assert_eq!(
+			next_cron_occurrence(schedule, Tz::UTC, after),
+			Some(Utc.with_ymd_and_hms(2024, 1, 16, 9, 0, 0).unwrap())
+		);
+	}
+
+	#[test]
+	fn test_next_cron_occurrence_invalid_schedule() {
+		assert!(next_cron_occurrence("invalid cron", Tz::UTC, Utc::now()).is_none());
+	}
+}
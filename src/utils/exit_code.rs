@@ -0,0 +1,124 @@
+//! Exit codes of the process.
+//!
+//! Each class of failure exits the process with its own code, so that scripts, CI jobs and
+//! orchestrators can tell them apart without parsing the logs:
+//!
+//! | Code | Meaning |
+//! | --- | --- |
+//! | 0 | Success, including a shutdown on Ctrl+C |
+//! | 1 | Any other failure |
+//! | 2 | Invalid command line arguments, reported before anything else runs |
+//! | 3 | The configuration couldn't be loaded |
+//! | 4 | `--validate-only` found invalid configurations |
+//! | 5 | The RPC endpoints of a network couldn't be reached while executing a monitor |
+//! | 6 | A notification failed with `--test-trigger`, or a trigger with `--check-triggers` |
+
+use std::{error::Error, fmt};
+
+/// Exit code of the process, see the [module documentation](self) for their meaning
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitCode {
+	/// The command succeeded, or the service shut down on Ctrl+C
+	Success,
+	/// A failure not covered by another exit code
+	Failure,
+	/// Invalid command line arguments, exited with by the argument parser
+	Usage,
+	/// The configuration couldn't be loaded
+	Config,
+	/// Configurations failed validation
+	Validation,
+	/// The RPC endpoints of a network couldn't be reached
+	Rpc,
+	/// A test notification couldn't be sent, or a trigger failed its health check
+	Notification,
+}
+
+impl ExitCode {
+	/// Returns the numeric exit code
+	pub fn code(self) -> u8 {
+		match self {
+			ExitCode::Success => 0,
+			ExitCode::Failure => 1,
+			ExitCode::Usage => 2,
+			ExitCode::Config => 3,
+			ExitCode::Validation => 4,
+			ExitCode::Rpc => 5,
+			ExitCode::Notification => 6,
+		}
+	}
+}
+
+impl From<ExitCode> for std::process::ExitCode {
+	fn from(exit_code: ExitCode) -> Self {
+		std::process::ExitCode::from(exit_code.code())
+	}
+}
+
+/// Error ending the process, with the exit code of its class of failure
+///
+/// Any error converts into a [`ExitCode::Failure`] with `?`, and
+/// [`with_exit_code`](WithExitCode::with_exit_code) classifies it otherwise.
+pub struct CliError {
+	/// Exit code of the process
+	pub exit_code: ExitCode,
+	/// Error reported before exiting
+	pub error: Box<dyn Error>,
+}
+
+impl CliError {
+	/// Creates an error exiting the process with the given code
+	pub fn new(exit_code: ExitCode, error: impl Into<Box<dyn Error>>) -> Self {
+		Self {
+			exit_code,
+			error: error.into(),
+		}
+	}
+}
+
+impl<E: Into<Box<dyn Error>>> From<E> for CliError {
+	fn from(error: E) -> Self {
+		Self::new(ExitCode::Failure, error)
+	}
+}
+
+impl fmt::Display for CliError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.error, f)
+	}
+}
+
+impl fmt::Debug for CliError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.error, f)
+	}
+}
+
+/// Classifies the error of a result by the exit code of the process
+pub trait WithExitCode<T> {
+	/// Converts the error into a [`CliError`] exiting with the given code
+	fn with_exit_code(self, exit_code: ExitCode) -> Result<T, CliError>;
+}
+
+impl<T, E: Into<Box<dyn Error>>> WithExitCode<T> for Result<T, E> {
+	fn with_exit_code(self, exit_code: ExitCode) -> Result<T, CliError> {
+		self.map_err(|error| CliError::new(exit_code, error))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_errors_are_failures_unless_classified() {
+		let error: CliError = anyhow::anyhow!("unexpected").into();
+		assert_eq!(error.exit_code, ExitCode::Failure);
+		assert_eq!(error.to_string(), "unexpected");
+
+		let result: Result<(), _> = Err(anyhow::anyhow!("invalid monitor"));
+		let error = result.with_exit_code(ExitCode::Config).unwrap_err();
+		assert_eq!(error.exit_code, ExitCode::Config);
+		assert_eq!(error.exit_code.code(), 3);
+	}
+}
@@ -2,6 +2,7 @@ use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use reqwest_retry::{
 	policies::ExponentialBackoff, Jitter, RetryTransientMiddleware, RetryableStrategy,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -23,7 +24,7 @@ fn default_base_for_backoff() -> u32 {
 }
 
 /// Serializable setting for jitter in retry policies
-#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum JitterSetting {
 	/// No jitter applied to the backoff duration
@@ -34,7 +35,7 @@ pub enum JitterSetting {
 }
 
 /// Configuration for HTTP (RPC and Webhook notifiers) and SMTP (Email notifier) retry policies
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 pub struct RetryConfig {
 	/// Maximum number of retries for transient errors
 	#[serde(default = "default_max_attempts")]
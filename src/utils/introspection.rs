@@ -0,0 +1,329 @@
+//! Introspection of loaded configurations.
+//!
+//! Builds summaries of the monitors, networks and triggers the service has loaded and renders
+//! them as a plain text table or as JSON. Summaries only carry identifying fields and counts,
+//! so secrets such as RPC URLs or trigger credentials are never printed.
+
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::models::{Monitor, Network, Trigger};
+
+/// Output format of a listing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListFormat {
+	/// Aligned plain text table
+	Table,
+	/// Pretty-printed JSON array
+	Json,
+}
+
+/// A summary that can be rendered as a table row
+pub trait TableRow {
+	/// Column headers of the table
+	const HEADERS: &'static [&'static str];
+
+	/// Cells of the row, in the same order as the headers
+	fn cells(&self) -> Vec<String>;
+}
+
+/// Summary of a monitor
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MonitorSummary {
+	/// Unique name of the monitor
+	pub name: String,
+	/// Slugs of the networks the monitor watches
+	pub networks: Vec<String>,
+	/// Addresses the monitor watches
+	pub addresses: Vec<String>,
+	/// Number of function conditions
+	pub functions: usize,
+	/// Number of event conditions
+	pub events: usize,
+	/// Number of transaction conditions
+	pub transactions: usize,
+	/// Number of trigger condition scripts
+	pub trigger_conditions: usize,
+	/// Names of the triggers the monitor fires
+	pub triggers: Vec<String>,
+}
+
+impl From<&Monitor> for MonitorSummary {
+	fn from(monitor: &Monitor) -> Self {
+		Self {
+			name: monitor.name.clone(),
+			networks: monitor.networks.clone(),
+			addresses: monitor
+				.addresses
+				.iter()
+				.map(|address| address.address.clone())
+				.collect(),
+			functions: monitor.match_conditions.functions.len(),
+			events: monitor.match_conditions.events.len(),
+			transactions: monitor.match_conditions.transactions.len(),
+			trigger_conditions: monitor.trigger_conditions.len(),
+			triggers: monitor.triggers.clone(),
+		}
+	}
+}
+
+impl TableRow for MonitorSummary {
+	const HEADERS: &'static [&'static str] = &[
+		"NAME",
+		"NETWORKS",
+		"ADDRESSES",
+		"FUNCTIONS",
+		"EVENTS",
+		"TRANSACTIONS",
+		"TRIGGER CONDITIONS",
+		"TRIGGERS",
+	];
+
+	fn cells(&self) -> Vec<String> {
+		vec![
+			self.name.clone(),
+			join_list(&self.networks),
+			join_list(&self.addresses),
+			self.functions.to_string(),
+			self.events.to_string(),
+			self.transactions.to_string(),
+			self.trigger_conditions.to_string(),
+			join_list(&self.triggers),
+		]
+	}
+}
+
+/// Summary of a network
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NetworkSummary {
+	/// Unique slug of the network
+	pub slug: String,
+	/// Human-readable name of the network
+	pub name: String,
+	/// Type of blockchain
+	pub network_type: String,
+	/// Chain ID, for EVM networks
+	pub chain_id: Option<u64>,
+	/// Whether block watchers are started for the network
+	pub enabled: bool,
+	/// Number of configured RPC endpoints
+	pub rpc_urls: usize,
+}
+
+impl From<&Network> for NetworkSummary {
+	fn from(network: &Network) -> Self {
+		Self {
+			slug: network.slug.clone(),
+			name: network.name.clone(),
+			network_type: format!("{:?}", network.network_type),
+			chain_id: network.chain_id,
+			enabled: network.is_enabled(),
+			rpc_urls: network.rpc_urls.len(),
+		}
+	}
+}
+
+impl TableRow for NetworkSummary {
+	const HEADERS: &'static [&'static str] =
+		&["SLUG", "NAME", "TYPE", "CHAIN ID", "ENABLED", "RPC URLS"];
+
+	fn cells(&self) -> Vec<String> {
+		vec![
+			self.slug.clone(),
+			self.name.clone(),
+			self.network_type.clone(),
+			self.chain_id
+				.map(|chain_id| chain_id.to_string())
+				.unwrap_or_else(|| "-".to_string()),
+			self.enabled.to_string(),
+			self.rpc_urls.to_string(),
+		]
+	}
+}
+
+/// Summary of a trigger
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TriggerSummary {
+	/// Unique name of the trigger
+	pub name: String,
+	/// Type of the trigger, as written in configuration files
+	pub trigger_type: String,
+}
+
+impl From<&Trigger> for TriggerSummary {
+	fn from(trigger: &Trigger) -> Self {
+		Self {
+			name: trigger.name.clone(),
+			trigger_type: trigger.trigger_type.as_str().to_string(),
+		}
+	}
+}
+
+impl TableRow for TriggerSummary {
+	const HEADERS: &'static [&'static str] = &["NAME", "TYPE"];
+
+	fn cells(&self) -> Vec<String> {
+		vec![self.name.clone(), self.trigger_type.clone()]
+	}
+}
+
+/// Summarizes monitors, sorted by name
+pub fn summarize_monitors(monitors: &[Monitor]) -> Vec<MonitorSummary> {
+	let mut summaries: Vec<MonitorSummary> = monitors.iter().map(MonitorSummary::from).collect();
+	summaries.sort_by(|a, b| a.name.cmp(&b.name));
+	summaries
+}
+
+/// Summarizes networks, sorted by slug
+pub fn summarize_networks(networks: &HashMap<String, Network>) -> Vec<NetworkSummary> {
+	let mut summaries: Vec<NetworkSummary> = networks.values().map(NetworkSummary::from).collect();
+	summaries.sort_by(|a, b| a.slug.cmp(&b.slug));
+	summaries
+}
+
+/// Summarizes triggers, sorted by name
+pub fn summarize_triggers(triggers: &HashMap<String, Trigger>) -> Vec<TriggerSummary> {
+	let mut summaries: Vec<TriggerSummary> = triggers.values().map(TriggerSummary::from).collect();
+	summaries.sort_by(|a, b| a.name.cmp(&b.name));
+	summaries
+}
+
+/// Renders summaries in the requested format
+///
+/// # Arguments
+/// * `summaries` - Summaries to render, one per row or array element
+/// * `format` - Output format
+///
+/// # Returns
+/// * `Result<String, serde_json::Error>` - The rendered listing or a serialization error
+pub fn render<T: Serialize + TableRow>(
+	summaries: &[T],
+	format: ListFormat,
+) -> Result<String, serde_json::Error> {
+	match format {
+		ListFormat::Json => serde_json::to_string_pretty(summaries),
+		ListFormat::Table => Ok(render_table(summaries)),
+	}
+}
+
+/// Renders summaries as a table with left-aligned columns
+fn render_table<T: TableRow>(summaries: &[T]) -> String {
+	let rows: Vec<Vec<String>> = summaries.iter().map(TableRow::cells).collect();
+
+	let mut widths: Vec<usize> = T::HEADERS.iter().map(|header| header.len()).collect();
+	for row in &rows {
+		for (width, cell) in widths.iter_mut().zip(row) {
+			*width = (*width).max(cell.chars().count());
+		}
+	}
+
+	let format_row = |cells: Vec<String>| {
+		cells
+			.iter()
+			.zip(&widths)
+			.map(|(cell, width)| format!("{:<width$}", cell, width = width))
+			.collect::<Vec<_>>()
+			.join("  ")
+			.trim_end()
+			.to_string()
+	};
+
+	let header = format_row(T::HEADERS.iter().map(|h| h.to_string()).collect());
+	std::iter::once(header)
+		.chain(rows.into_iter().map(format_row))
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// Joins list cells, using `-` for empty lists
+fn join_list(items: &[String]) -> String {
+	if items.is_empty() {
+		"-".to_string()
+	} else {
+		items.join(", ")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::tests::builders::{
+		evm::monitor::MonitorBuilder, network::NetworkBuilder, trigger::TriggerBuilder,
+	};
+
+	fn create_monitors() -> Vec<Monitor> {
+		vec![
+			MonitorBuilder::new()
+				.name("Large Transfer")
+				.event("Transfer(address,address,uint256)", None)
+				.triggers(vec!["large_transfer_webhook".to_string()])
+				.build(),
+			MonitorBuilder::new().name("Approvals").build(),
+		]
+	}
+
+	#[test]
+	fn test_render_monitors_json_contains_monitor_names() {
+		let summaries = summarize_monitors(&create_monitors());
+
+		let output = render(&summaries, ListFormat::Json).unwrap();
+		let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+		let names: Vec<&str> = value
+			.as_array()
+			.unwrap()
+			.iter()
+			.map(|monitor| monitor["name"].as_str().unwrap())
+			.collect();
+		assert_eq!(names, vec!["Approvals", "Large Transfer"]);
+		assert_eq!(value[1]["events"], 1);
+		assert_eq!(value[1]["triggers"][0], "large_transfer_webhook");
+	}
+
+	#[test]
+	fn test_render_monitors_table() {
+		let summaries = summarize_monitors(&create_monitors());
+
+		let output = render(&summaries, ListFormat::Table).unwrap();
+		let lines: Vec<&str> = output.lines().collect();
+
+		assert_eq!(lines.len(), 3);
+		assert!(lines[0].starts_with("NAME"));
+		assert!(lines[1].starts_with("Approvals"));
+		assert!(lines[2].contains("large_transfer_webhook"));
+		// Columns are aligned on the widest cell
+		assert_eq!(lines[0].find("NETWORKS"), lines[1].find("ethereum_mainnet"));
+	}
+
+	#[test]
+	fn test_network_summary_omits_rpc_urls() {
+		let network = NetworkBuilder::new()
+			.slug("ethereum_mainnet")
+			.rpc_url("https://rpc.example.com/secret-key")
+			.enabled(false)
+			.build();
+		let networks = HashMap::from([(network.slug.clone(), network)]);
+
+		let output = render(&summarize_networks(&networks), ListFormat::Json).unwrap();
+
+		assert!(output.contains("ethereum_mainnet"));
+		assert!(output.contains("\"enabled\": false"));
+		assert!(!output.contains("secret-key"));
+	}
+
+	#[test]
+	fn test_render_triggers() {
+		let trigger = TriggerBuilder::new()
+			.name("large_transfer_webhook")
+			.webhook("https://webhook.example.com/secret")
+			.build();
+		let triggers = HashMap::from([(trigger.name.clone(), trigger)]);
+
+		let output = render(&summarize_triggers(&triggers), ListFormat::Table).unwrap();
+
+		assert_eq!(
+			output,
+			"NAME                    TYPE\nlarge_transfer_webhook  webhook"
+		);
+	}
+}
@@ -2,12 +2,113 @@
 //!
 //! This module provides a structured approach to error handling with context and metadata.
 //! The primary type is [`ErrorContext`], which wraps errors with additional information
-//! such as timestamps, trace IDs, and custom metadata.
+//! such as timestamps, trace IDs, and custom metadata. Service errors are identified by a
+//! stable [`ErrorCode`], so that consumers can match on it rather than on messages.
 
 use chrono::Utc;
 use std::{collections::HashMap, fmt};
 use uuid::Uuid;
 
+/// Stable code identifying the kind of a service error.
+///
+/// Codes appear in the `Display` output of errors, as a `[CODE]` prefix, and in the `code`
+/// field of their structured logs. Unlike messages, they don't change between releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+	/// Configuration validation failure
+	ConfigValidation,
+	/// Configuration parsing failure
+	ConfigParse,
+	/// Configuration file system failure
+	ConfigFile,
+	/// Other configuration failure
+	ConfigOther,
+	/// Blockchain connection failure
+	BlockChainConnection,
+	/// Malformed blockchain request or invalid response
+	BlockChainRequest,
+	/// Block not found on the blockchain
+	BlockChainBlockNotFound,
+	/// Blockchain transaction processing failure
+	BlockChainTransaction,
+	/// Internal blockchain client failure
+	BlockChainInternal,
+	/// Blockchain client pool failure
+	BlockChainClientPool,
+	/// Other blockchain failure
+	BlockChainOther,
+	/// Block of an unexpected type passed to a filter
+	FilterBlockTypeMismatch,
+	/// Network failure while filtering
+	FilterNetwork,
+	/// Internal filter failure
+	FilterInternal,
+	/// Other filter failure
+	FilterOther,
+	/// Unknown trigger
+	TriggerNotFound,
+	/// Trigger execution failure
+	TriggerExecution,
+	/// Invalid trigger configuration
+	TriggerConfiguration,
+	/// Other trigger failure
+	TriggerOther,
+	/// Network failure while sending a notification
+	NotificationNetwork,
+	/// Invalid notification configuration
+	NotificationConfig,
+	/// Internal notification failure
+	NotificationInternal,
+	/// Notification script execution failure
+	NotificationExecution,
+	/// Notification rejected by the notifier
+	NotificationFailed,
+	/// Notification not sent within the trigger timeout
+	NotificationTimeout,
+	/// Notification permanently refused by the receiving server
+	NotificationRejected,
+}
+
+impl ErrorCode {
+	/// Returns the code as it appears in messages and logs
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::ConfigValidation => "CONFIG_VALIDATION",
+			Self::ConfigParse => "CONFIG_PARSE",
+			Self::ConfigFile => "CONFIG_FILE",
+			Self::ConfigOther => "CONFIG_OTHER",
+			Self::BlockChainConnection => "BLOCKCHAIN_CONNECTION",
+			Self::BlockChainRequest => "BLOCKCHAIN_REQUEST",
+			Self::BlockChainBlockNotFound => "BLOCKCHAIN_BLOCK_NOT_FOUND",
+			Self::BlockChainTransaction => "BLOCKCHAIN_TRANSACTION",
+			Self::BlockChainInternal => "BLOCKCHAIN_INTERNAL",
+			Self::BlockChainClientPool => "BLOCKCHAIN_CLIENT_POOL",
+			Self::BlockChainOther => "BLOCKCHAIN_OTHER",
+			Self::FilterBlockTypeMismatch => "FILTER_BLOCK_TYPE_MISMATCH",
+			Self::FilterNetwork => "FILTER_NETWORK",
+			Self::FilterInternal => "FILTER_INTERNAL",
+			Self::FilterOther => "FILTER_OTHER",
+			Self::TriggerNotFound => "TRIGGER_NOT_FOUND",
+			Self::TriggerExecution => "TRIGGER_EXECUTION",
+			Self::TriggerConfiguration => "TRIGGER_CONFIGURATION",
+			Self::TriggerOther => "TRIGGER_OTHER",
+			Self::NotificationNetwork => "NOTIFICATION_NETWORK",
+			Self::NotificationConfig => "NOTIFICATION_CONFIG",
+			Self::NotificationInternal => "NOTIFICATION_INTERNAL",
+			Self::NotificationExecution => "NOTIFICATION_EXECUTION",
+			Self::NotificationFailed => "NOTIFICATION_FAILED",
+			Self::NotificationTimeout => "NOTIFICATION_TIMEOUT",
+			Self::NotificationRejected => "NOTIFICATION_REJECTED",
+		}
+	}
+}
+
+impl fmt::Display for ErrorCode {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
 /// A context wrapper for errors with additional metadata.
 ///
 /// `ErrorContext` provides a way to enrich errors with contextual information,
@@ -18,6 +119,7 @@ use uuid::Uuid;
 /// - Optional key-value metadata
 /// - A timestamp (automatically generated)
 /// - A unique trace ID (automatically generated)
+/// - An optional [`ErrorCode`], set by the service errors wrapping the context
 ///
 /// This structure implements both `Display` and `std::error::Error` traits,
 /// making it suitable for use in error handling chains.
@@ -33,6 +135,8 @@ pub struct ErrorContext {
 	pub timestamp: String,
 	/// The unique identifier for the error (UUID v4)
 	pub trace_id: String,
+	/// The code of the error wrapping this context, included in its structured logs
+	pub code: Option<ErrorCode>,
 }
 
 impl ErrorContext {
@@ -65,6 +169,7 @@ impl ErrorContext {
 			metadata,
 			timestamp: Utc::now().to_rfc3339(),
 			trace_id,
+			code: None,
 		}
 	}
 
@@ -84,13 +189,31 @@ impl ErrorContext {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		// Create the error context
-		let error_context = Self::new(message, source, metadata);
+		Self::new(message, source, metadata).log()
+	}
 
-		// Log the error
-		log_error(&error_context);
+	/// Sets the code of the error wrapping this context.
+	///
+	/// # Arguments
+	///
+	/// * `code` - The code of the error
+	///
+	/// # Returns
+	///
+	/// The modified `ErrorContext` with the code set.
+	pub fn with_code(mut self, code: ErrorCode) -> Self {
+		self.code = Some(code);
+		self
+	}
 
-		error_context
+	/// Logs the error context, once it is fully built.
+	///
+	/// # Returns
+	///
+	/// The unchanged `ErrorContext`.
+	pub fn log(self) -> Self {
+		log_error(&self);
+		self
 	}
 
 	/// Adds a single key-value metadata pair to the error context.
@@ -273,9 +396,11 @@ pub fn metadata_to_fields(metadata: &Option<HashMap<String, String>>) -> Vec<(&s
 
 /// Log the error with structured fields
 fn log_error(error: &ErrorContext) {
+	let code = error.code.map_or("", |code| code.as_str());
 	if let Some(err) = &error.source {
 		tracing::error!(
 			message = error.format_with_metadata(),
+			code = code,
 			trace_id = %error.trace_id,
 			timestamp = %error.timestamp,
 			error.chain = %format_error_chain(&**err),
@@ -284,6 +409,7 @@ fn log_error(error: &ErrorContext) {
 	} else {
 		tracing::error!(
 			message = error.format_with_metadata(),
+			code = code,
 			trace_id = %error.trace_id,
 			timestamp = %error.timestamp,
 			"Error occurred"
@@ -307,6 +433,17 @@ mod tests {
 		assert!(error.metadata.is_none());
 		assert!(!error.timestamp.is_empty());
 		assert!(!error.trace_id.is_empty());
+		assert!(error.code.is_none());
+	}
+
+	#[test]
+	fn test_with_code() {
+		let error = ErrorContext::new("Test error", None, None).with_code(ErrorCode::ConfigParse);
+
+		assert_eq!(error.code, Some(ErrorCode::ConfigParse));
+		assert_eq!(ErrorCode::ConfigParse.to_string(), "CONFIG_PARSE");
+		// The code isn't part of the message of the context itself
+		assert_eq!(error.to_string(), "Test error");
 	}
 
 	#[test]
@@ -388,12 +525,14 @@ mod tests {
 		#[traced_test]
 		fn inner_test() {
 			let error = ErrorContext::new("Test log error", None, None)
-				.with_metadata("test_key", "test_value");
+				.with_metadata("test_key", "test_value")
+				.with_code(ErrorCode::TriggerExecution);
 
 			log_error(&error);
 
 			// Verify log contains our error information
 			assert!(logs_contain("Test log error"));
+			assert!(logs_contain("TRIGGER_EXECUTION"));
 			assert!(logs_contain(&error.trace_id));
 			assert!(logs_contain(&error.timestamp));
 
@@ -5,11 +5,16 @@
 //! - LOG_LEVEL: log level ("trace", "debug", "info", "warn", "error"); default is "info"
 //! - LOG_DATA_DIR: directory for log files; default is "logs/"
 //! - LOG_MAX_SIZE: maximum size of log files in bytes; default is 1GB
+//! - LOG_TIMEZONE: IANA name of the timezone of the log timestamps; default is "UTC"
 //! - IN_DOCKER: "true" if running in Docker; default is "false"
+//! - OTEL_EXPORTER_OTLP_ENDPOINT: OTLP endpoint to export spans to; requires the `otel` feature
 
 pub mod error;
+#[cfg(feature = "otel")]
+mod otel;
 
-use chrono::Utc;
+use chrono::{SecondsFormat, Utc};
+use chrono_tz::Tz;
 use std::{
 	env,
 	fs::{create_dir_all, metadata},
@@ -21,6 +26,7 @@ use tracing_subscriber::{filter::EnvFilter, fmt, prelude::*};
 
 use tracing::Subscriber;
 use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::time::FormatTime;
 use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
 use tracing_subscriber::registry::LookupSpan;
 
@@ -70,6 +76,22 @@ fn strip_ansi_escapes(s: &str) -> String {
 	re.replace_all(s, "").to_string()
 }
 
+/// Timer writing the timestamps of log lines in a timezone
+struct TimezoneTimer(Tz);
+
+impl FormatTime for TimezoneTimer {
+	fn format_time(&self, writer: &mut Writer<'_>) -> std::fmt::Result {
+		write!(writer, "{}", format_timestamp(Utc::now(), self.0))
+	}
+}
+
+/// Formats a log timestamp in RFC 3339, with a `Z` suffix for UTC
+fn format_timestamp(timestamp: chrono::DateTime<Utc>, timezone: Tz) -> String {
+	timestamp
+		.with_timezone(&timezone)
+		.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
 /// Computes the path of the rolled log file given the base file path and the date string.
 pub fn compute_rolled_file_path(base_file_path: &str, date_str: &str, index: u32) -> String {
 	let trimmed = base_file_path
@@ -104,9 +126,13 @@ pub fn space_based_rolling(
 	final_path
 }
 
-/// Creates a log format with configurable ANSI support
-fn create_log_format(with_ansi: bool) -> fmt::format::Format<fmt::format::Compact> {
+/// Creates a log format with configurable ANSI support and timestamps in a timezone
+fn create_log_format(
+	with_ansi: bool,
+	timezone: Tz,
+) -> fmt::format::Format<fmt::format::Compact, TimezoneTimer> {
 	fmt::format()
+		.with_timer(TimezoneTimer(timezone))
 		.with_level(true)
 		.with_target(true)
 		.with_thread_ids(false)
@@ -132,11 +158,16 @@ pub fn setup_logging() -> Result<(), Box<dyn std::error::Error>> {
 
 	// Create a format with ANSI disabled for file logging and enabled for stdout
 	let with_ansi = log_mode.to_lowercase() != "file";
-	let format = create_log_format(with_ansi);
+	let timezone = parse_log_timezone(env::var("LOG_TIMEZONE").ok().as_deref())?;
+	let format = create_log_format(with_ansi, timezone);
 
 	// Create a subscriber with the specified log level
 	let subscriber = tracing_subscriber::registry().with(EnvFilter::new(level_filter.to_string()));
 
+	// Export spans over OTLP when an endpoint is configured
+	#[cfg(feature = "otel")]
+	let subscriber = subscriber.with(otel::layer()?);
+
 	if log_mode.to_lowercase() == "file" {
 		info!("Logging to file: {}", log_level);
 
@@ -211,6 +242,21 @@ pub fn setup_logging() -> Result<(), Box<dyn std::error::Error>> {
 	Ok(())
 }
 
+/// Flushes spans that haven't been exported yet, when OpenTelemetry export is enabled.
+pub fn shutdown_logging() {
+	#[cfg(feature = "otel")]
+	otel::shutdown();
+}
+
+/// Parses the timezone of the log timestamps, UTC when unset
+fn parse_log_timezone(timezone: Option<&str>) -> Result<Tz, String> {
+	match timezone {
+		Some(timezone) => crate::utils::parse_timezone(timezone)
+			.map_err(|e| format!("Invalid LOG_TIMEZONE '{}': {}", timezone, e)),
+		None => Ok(Tz::UTC),
+	}
+}
+
 fn parse_log_max_size() -> u64 {
 	env::var("LOG_MAX_SIZE")
 		.map(|s| {
@@ -274,6 +320,30 @@ mod tests {
 		assert_eq!(result, initial_path);
 	}
 
+	#[test]
+	fn test_parse_log_timezone() {
+		assert_eq!(parse_log_timezone(None).unwrap(), Tz::UTC);
+		assert_eq!(
+			parse_log_timezone(Some("Europe/Paris")).unwrap(),
+			Tz::Europe__Paris
+		);
+		assert!(parse_log_timezone(Some("Mars/Olympus_Mons")).is_err());
+	}
+
+	#[test]
+	fn test_format_timestamp() {
+		let timestamp = chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 1, 15, 14, 0, 0).unwrap();
+
+		assert_eq!(
+			format_timestamp(timestamp, Tz::UTC),
+			"2024-01-15T14:00:00.000000Z"
+		);
+		assert_eq!(
+			format_timestamp(timestamp, Tz::America__New_York),
+			"2024-01-15T09:00:00.000000-05:00"
+		);
+	}
+
 	// This test checks if the LOG_MAX_SIZE environment variable is set to a valid u64 value.
 	#[test]
 	#[should_panic(expected = "LOG_MAX_SIZE must be a valid u64 if set")]
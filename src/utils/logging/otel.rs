@@ -0,0 +1,85 @@
+//! OpenTelemetry trace export.
+//!
+//! When `OTEL_EXPORTER_OTLP_ENDPOINT` is set, spans are exported over OTLP (HTTP/protobuf)
+//! next to the regular log output. The exporter and resource follow the standard OTEL
+//! environment variables, e.g. `OTEL_SERVICE_NAME`, `OTEL_RESOURCE_ATTRIBUTES` and
+//! `OTEL_EXPORTER_OTLP_HEADERS`.
+
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_sdk::{
+	trace::{SdkTracer, SdkTracerProvider},
+	Resource,
+};
+use std::{env, sync::OnceLock};
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Environment variable enabling the export
+const OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+
+/// Service name reported when `OTEL_SERVICE_NAME` is not set
+const DEFAULT_SERVICE_NAME: &str = "openzeppelin-monitor";
+
+/// Tracer provider installed by [`layer`], kept to flush pending spans on shutdown
+static TRACER_PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
+
+/// Creates the tracing layer exporting spans over OTLP
+///
+/// # Returns
+/// * `Ok(None)` - If `OTEL_EXPORTER_OTLP_ENDPOINT` is not set
+/// * `Ok(Some(layer))` - The layer exporting spans to the configured endpoint
+/// * `Err` - If the exporter can't be created
+pub fn layer<S>() -> Result<Option<OpenTelemetryLayer<S, SdkTracer>>, Box<dyn std::error::Error>>
+where
+	S: Subscriber + for<'span> LookupSpan<'span>,
+{
+	if env::var(OTLP_ENDPOINT_ENV).unwrap_or_default().is_empty() {
+		return Ok(None);
+	}
+
+	let exporter = opentelemetry_otlp::SpanExporter::builder()
+		.with_http()
+		.build()?;
+
+	let mut resource = Resource::builder();
+	if env::var("OTEL_SERVICE_NAME").is_err() {
+		resource = resource.with_service_name(DEFAULT_SERVICE_NAME);
+	}
+
+	let provider = SdkTracerProvider::builder()
+		.with_batch_exporter(exporter)
+		.with_resource(resource.build())
+		.build();
+	let tracer = provider.tracer(DEFAULT_SERVICE_NAME);
+
+	opentelemetry::global::set_tracer_provider(provider.clone());
+	let _ = TRACER_PROVIDER.set(provider);
+
+	Ok(Some(tracing_opentelemetry::layer().with_tracer(tracer)))
+}
+
+/// Flushes pending spans and stops the exporter, if one was installed
+pub fn shutdown() {
+	if let Some(provider) = TRACER_PROVIDER.get() {
+		if let Err(e) = provider.shutdown() {
+			tracing::error!("Failed to shut down OpenTelemetry tracer provider: {}", e);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use tracing_subscriber::Registry;
+
+	#[test]
+	fn test_layer_disabled_without_endpoint() {
+		env::remove_var(OTLP_ENDPOINT_ENV);
+
+		let layer = layer::<Registry>().unwrap();
+
+		assert!(layer.is_none());
+		assert!(TRACER_PROVIDER.get().is_none());
+	}
+}
@@ -5,7 +5,9 @@
 
 pub mod server;
 use lazy_static::lazy_static;
-use prometheus::{Encoder, Gauge, GaugeVec, Opts, Registry, TextEncoder};
+use prometheus::{
+	Encoder, Gauge, GaugeVec, IntCounter, IntCounterVec, Opts, Registry, TextEncoder,
+};
 use sysinfo::{Disks, System};
 
 lazy_static! {
@@ -105,6 +107,15 @@ lazy_static! {
 		gauge
 	};
 
+	/// Gauge for number of enabled triggers.
+	///
+	/// Tracks the count of configured triggers that are not disabled.
+	pub static ref TRIGGERS_ENABLED: Gauge = {
+		let gauge = Gauge::new("triggers_enabled", "Number of enabled triggers").unwrap();
+		REGISTRY.register(Box::new(gauge.clone())).unwrap();
+		gauge
+	};
+
 	/// Gauge for total number of contracts being monitored (across all monitors).
 	///
 	/// Tracks the total count of unique contracts (network + address combinations) being monitored.
@@ -134,6 +145,267 @@ lazy_static! {
 		REGISTRY.register(Box::new(gauge.clone())).unwrap();
 		gauge
 	};
+
+	/// Gauge Vector for per-network block lag.
+	///
+	/// Tracks the seconds elapsed since the timestamp of the last processed block, with the
+	/// network slug as a label.
+	pub static ref BLOCK_LAG_SECONDS: GaugeVec = {
+		let gauge = GaugeVec::new(
+			Opts::new("block_lag_seconds", "Seconds since the timestamp of the last processed block"),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(gauge.clone())).unwrap();
+		gauge
+	};
+
+	/// Gauge Vector for per-network stall state.
+	///
+	/// Set to 1 while no new block has been processed for a network within its configured
+	/// stall threshold, and 0 otherwise.
+	pub static ref NETWORK_STALLED: GaugeVec = {
+		let gauge = GaugeVec::new(
+			Opts::new("network_stalled", "Whether block processing for the network is stalled"),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(gauge.clone())).unwrap();
+		gauge
+	};
+
+	/// Gauge Vector for blocks left to process in the current catch-up of each network.
+	///
+	/// Updated after each chunk of blocks is processed, and `0` once the network is caught up.
+	pub static ref CATCHUP_BLOCKS_REMAINING: GaugeVec = {
+		let gauge = GaugeVec::new(
+			Opts::new("catchup_blocks_remaining", "Number of blocks left to process to catch up"),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(gauge.clone())).unwrap();
+		gauge
+	};
+
+	/// Counter Vector for per-network chain reorganizations.
+	///
+	/// Incremented each time an already processed block is found to have been replaced on the
+	/// chain, with the network slug as a label.
+	pub static ref REORGS_DETECTED_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new("reorgs_detected_total", "Number of chain reorganizations detected"),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for per-network missed blocks.
+	///
+	/// Incremented for each block missing from the blocks fetched for a network, with the
+	/// network slug as a label.
+	pub static ref BLOCKS_MISSED_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new("blocks_missed_total", "Number of blocks missing from the fetched blocks"),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for per-network recovered blocks.
+	///
+	/// Incremented each time a block previously reported as missed is processed, with the
+	/// network slug as a label.
+	pub static ref BLOCKS_RECOVERED_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new(
+				"blocks_recovered_total",
+				"Number of missed blocks processed afterwards"
+			),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Gauge Vector for the last processed block of each network.
+	///
+	/// Set to the highest block number processed for a network, with the network slug as a
+	/// label.
+	pub static ref LAST_PROCESSED_BLOCK: GaugeVec = {
+		let gauge = GaugeVec::new(
+			Opts::new("last_processed_block", "Number of the last processed block"),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(gauge.clone())).unwrap();
+		gauge
+	};
+
+	/// Counter Vector for per-network watcher restarts.
+	///
+	/// Incremented each time the block watcher of a network is restarted after failing to
+	/// start or failing too many runs in a row, with the network slug as a label.
+	pub static ref NETWORK_WATCHER_RESTARTS_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new("network_watcher_restarts_total", "Number of network watcher restarts"),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for per-network skipped duplicate blocks.
+	///
+	/// Incremented each time the triggers of a block aren't executed because they were already
+	/// executed for a block with the same hash, with the network slug as a label.
+	pub static ref DUPLICATE_BLOCKS_SKIPPED_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new(
+				"duplicate_blocks_skipped_total",
+				"Number of blocks whose triggers were skipped as already executed"
+			),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for RPC cache hits.
+	///
+	/// Incremented each time a block, receipt or event fetch is served from the cache of an RPC
+	/// client, with the network slug and the cache as labels.
+	pub static ref RPC_CACHE_HITS_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new("rpc_cache_hits_total", "Number of RPC fetches served from the cache"),
+			&["network", "cache"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for RPC cache misses.
+	///
+	/// Incremented each time a block, receipt or event fetch isn't found in the cache of an RPC
+	/// client, or is found expired, with the network slug and the cache as labels.
+	pub static ref RPC_CACHE_MISSES_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new("rpc_cache_misses_total", "Number of RPC fetches not found in the cache"),
+			&["network", "cache"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for notification sends.
+	///
+	/// Counts every trigger execution by trigger type and outcome (`success`, `failure`, or
+	/// `skipped` for disabled triggers); sends that exceed the trigger's timeout are counted as
+	/// failures. The trigger severity
+	/// (`none` when unset) and its `team` and `environment` labels are also reported.
+	pub static ref NOTIFICATIONS_SENT_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new("notifications_sent_total", "Number of notifications sent by outcome"),
+			&["trigger_type", "status", "severity", "team", "environment"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for dead-lettered notifications.
+	///
+	/// Counts the notifications that still failed after their last retry and were written to
+	/// the dead letters, by trigger type.
+	pub static ref NOTIFICATIONS_DEAD_LETTERED_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new(
+				"notifications_dead_lettered_total",
+				"Number of notifications dead-lettered after their last retry"
+			),
+			&["trigger_type"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for throttled notifications.
+	///
+	/// Counts the matches dropped because their monitor already notified its
+	/// `max_notifications_per_hour`, by monitor name.
+	pub static ref NOTIFICATIONS_THROTTLED_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new(
+				"notifications_throttled_total",
+				"Number of matches not notified because their monitor was throttled"
+			),
+			&["monitor"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Gauge for the trigger handling tasks waiting for a slot.
+	///
+	/// Processed blocks whose matches can't be handled right away because too many blocks are
+	/// already being handled.
+	pub static ref TRIGGER_TASKS_QUEUED: Gauge = {
+		let gauge = Gauge::new(
+			"trigger_tasks_queued",
+			"Number of processed blocks waiting for their matches to be handled"
+		).unwrap();
+		REGISTRY.register(Box::new(gauge.clone())).unwrap();
+		gauge
+	};
+
+	/// Counter for the trigger handling tasks dropped.
+	///
+	/// Incremented for every processed block whose matches were dropped because the queue of
+	/// blocks waiting to be handled was full.
+	pub static ref TRIGGER_TASKS_DROPPED_TOTAL: IntCounter = {
+		let counter = IntCounter::new(
+			"trigger_tasks_dropped_total",
+			"Number of processed blocks whose matches were dropped"
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for EVM block filtering paths.
+	///
+	/// Counts processed EVM blocks by network and path: `logs` when all monitors only have event
+	/// conditions and the block's logs are fetched filtered by address and topic, `receipt`
+	/// otherwise.
+	pub static ref EVM_FILTER_PATH_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new("evm_filter_path_total", "Number of EVM blocks filtered per filtering path"),
+			&["network", "path"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Gauge Vector for the circuit breaker state of RPC endpoints.
+	///
+	/// Set to 0 while the circuit of an endpoint is closed, 1 while half-open and 2 while open,
+	/// with the endpoint host and port as a label.
+	pub static ref RPC_ENDPOINT_CIRCUIT_STATE: GaugeVec = {
+		let gauge = GaugeVec::new(
+			Opts::new("rpc_endpoint_circuit_state", "Circuit breaker state of RPC endpoints"),
+			&["endpoint"]
+		).unwrap();
+		REGISTRY.register(Box::new(gauge.clone())).unwrap();
+		gauge
+	};
+
+	/// Gauge for the leadership of this replica.
+	///
+	/// Set to 1 while this replica runs the block watchers and 0 while it stands by as a
+	/// follower of a leader election.
+	pub static ref IS_LEADER: Gauge = {
+		let gauge = Gauge::new(
+			"is_leader",
+			"Whether this replica is the leader running the block watchers"
+		).unwrap();
+		REGISTRY.register(Box::new(gauge.clone())).unwrap();
+		gauge
+	};
 }
 
 /// Gather all metrics and encode into the provided format.
@@ -196,21 +468,45 @@ pub fn update_system_metrics() {
 	DISK_USAGE_PERCENT.set(disk_percentage);
 }
 
+/// Updates the block lag of a network from the timestamp of its last processed block.
+///
+/// # Arguments
+/// * `network_slug` - Network the block belongs to
+/// * `block_timestamp` - Block timestamp in seconds since the Unix epoch
+pub fn update_block_lag(network_slug: &str, block_timestamp: u64) {
+	let now = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map(|d| d.as_secs())
+		.unwrap_or_default();
+
+	BLOCK_LAG_SECONDS
+		.with_label_values(&[network_slug])
+		.set(now.saturating_sub(block_timestamp) as f64);
+}
+
 /// Updates metrics related to monitors, triggers, networks, and contracts.
 pub fn update_monitoring_metrics(
 	monitors: &std::collections::HashMap<String, crate::models::Monitor>,
 	triggers: &std::collections::HashMap<String, crate::models::Trigger>,
 	networks: &std::collections::HashMap<String, crate::models::Network>,
 ) {
-	// Track total and active monitors
+	// Track total and active monitors, monitors paused until a past time being active again
+	let now = chrono::Utc::now();
+	let is_active = |monitor: &&crate::models::Monitor| !monitor.is_paused_at(now);
 	let total_monitors = monitors.len();
-	let active_monitors = monitors.values().filter(|m| !m.paused).count();
+	let active_monitors = monitors.values().filter(is_active).count();
 
 	MONITORS_TOTAL.set(total_monitors as f64);
 	MONITORS_ACTIVE.set(active_monitors as f64);
 
-	// Track total triggers
+	// Track total and enabled triggers
 	TRIGGERS_TOTAL.set(triggers.len() as f64);
+	TRIGGERS_ENABLED.set(
+		triggers
+			.values()
+			.filter(|trigger| trigger.is_enabled())
+			.count() as f64,
+	);
 
 	// Count unique contracts across all monitors
 	let mut unique_contracts = std::collections::HashSet::new();
@@ -227,12 +523,14 @@ pub fn update_monitoring_metrics(
 	}
 	CONTRACTS_MONITORED.set(unique_contracts.len() as f64);
 
+	// Only networks that exist in our repository and are enabled are watched
+	let is_watched = |network: &String| networks.get(network).is_some_and(|n| n.is_enabled());
+
 	// Count networks being monitored (those with active monitors)
 	let mut networks_with_monitors = std::collections::HashSet::new();
-	for monitor in monitors.values().filter(|m| !m.paused) {
+	for monitor in monitors.values().filter(is_active) {
 		for network in &monitor.networks {
-			// Only count networks that exist in our repository
-			if networks.contains_key(network) {
+			if is_watched(network) {
 				networks_with_monitors.insert(network.clone());
 			}
 		}
@@ -242,11 +540,11 @@ pub fn update_monitoring_metrics(
 	// Reset all network-specific metrics
 	NETWORK_MONITORS.reset();
 
-	// Set per-network monitor counts (only for networks that exist)
+	// Set per-network monitor counts (only for watched networks)
 	let mut network_monitor_counts = std::collections::HashMap::<String, usize>::new();
-	for monitor in monitors.values().filter(|m| !m.paused) {
+	for monitor in monitors.values().filter(is_active) {
 		for network in &monitor.networks {
-			if networks.contains_key(network) {
+			if is_watched(network) {
 				*network_monitor_counts.entry(network.clone()).or_insert(0) += 1;
 			}
 		}
@@ -291,6 +589,7 @@ mod tests {
 		MONITORS_TOTAL.set(0.0);
 		MONITORS_ACTIVE.set(0.0);
 		TRIGGERS_TOTAL.set(0.0);
+		TRIGGERS_ENABLED.set(0.0);
 		CONTRACTS_MONITORED.set(0.0);
 		NETWORKS_MONITORED.set(0.0);
 		NETWORK_MONITORS.reset();
@@ -359,6 +658,7 @@ mod tests {
 		MONITORS_TOTAL.set(5.0);
 		MONITORS_ACTIVE.set(3.0);
 		TRIGGERS_TOTAL.set(2.0);
+		TRIGGERS_ENABLED.set(1.0);
 		CONTRACTS_MONITORED.set(4.0);
 		NETWORKS_MONITORED.set(2.0);
 		NETWORK_MONITORS.with_label_values(&["test"]).set(1.0);
@@ -379,6 +679,7 @@ mod tests {
 		assert!(output.contains("monitors_total"));
 		assert!(output.contains("monitors_active"));
 		assert!(output.contains("triggers_total"));
+		assert!(output.contains("triggers_enabled"));
 		assert!(output.contains("contracts_monitored"));
 		assert!(output.contains("networks_monitored"));
 		assert!(output.contains("network_monitors"));
@@ -550,6 +851,93 @@ mod tests {
 		assert!(nonexistent.is_err() || nonexistent.unwrap().get() == 0.0);
 	}
 
+	#[test]
+	fn test_disabled_networks_are_not_monitored() {
+		let _lock = TEST_MUTEX.lock().unwrap();
+		reset_all_metrics();
+
+		let mut monitors = HashMap::new();
+		let mut networks = HashMap::new();
+		let triggers = HashMap::new();
+
+		networks.insert(
+			"ethereum".to_string(),
+			create_test_network("ethereum", "Ethereum", 1),
+		);
+		let mut polygon = create_test_network("polygon", "Polygon", 137);
+		polygon.enabled = Some(false);
+		networks.insert("polygon".to_string(), polygon);
+
+		monitors.insert(
+			"monitor1".to_string(),
+			create_test_monitor(
+				"Test Monitor 1",
+				vec!["ethereum".to_string(), "polygon".to_string()],
+				vec!["0x1234567890123456789012345678901234567890".to_string()],
+				false,
+			),
+		);
+
+		update_monitoring_metrics(&monitors, &triggers, &networks);
+
+		assert_eq!(NETWORKS_MONITORED.get(), 1.0);
+
+		let ethereum_monitors = NETWORK_MONITORS
+			.get_metric_with_label_values(&["ethereum"])
+			.unwrap();
+		assert_eq!(ethereum_monitors.get(), 1.0);
+
+		let polygon_monitors = NETWORK_MONITORS
+			.get_metric_with_label_values(&["polygon"])
+			.unwrap();
+		assert_eq!(polygon_monitors.get(), 0.0);
+	}
+
+	#[test]
+	fn test_paused_until_is_effective_state() {
+		let _lock = TEST_MUTEX.lock().unwrap();
+		reset_all_metrics();
+
+		let mut monitors = HashMap::new();
+		let mut networks = HashMap::new();
+		let triggers = HashMap::new();
+
+		networks.insert(
+			"ethereum".to_string(),
+			create_test_network("ethereum", "Ethereum", 1),
+		);
+		networks.insert(
+			"polygon".to_string(),
+			create_test_network("polygon", "Polygon", 137),
+		);
+
+		// Paused, but the pause has ended
+		let mut resumed = create_test_monitor(
+			"Resumed Monitor",
+			vec!["ethereum".to_string()],
+			vec!["0x1234567890123456789012345678901234567890".to_string()],
+			true,
+		);
+		resumed.paused_until = Some(chrono::Utc::now() - chrono::Duration::hours(1));
+		monitors.insert("monitor1".to_string(), resumed);
+
+		// Not paused, but within a scheduled pause
+		let mut scheduled = create_test_monitor(
+			"Scheduled Monitor",
+			vec!["polygon".to_string()],
+			vec!["0x0987654321098765432109876543210987654321".to_string()],
+			false,
+		);
+		scheduled.paused_until = Some(chrono::Utc::now() + chrono::Duration::hours(1));
+		monitors.insert("monitor2".to_string(), scheduled);
+
+		update_monitoring_metrics(&monitors, &triggers, &networks);
+
+		assert_eq!(MONITORS_TOTAL.get(), 2.0);
+		assert_eq!(MONITORS_ACTIVE.get(), 1.0);
+		assert_eq!(NETWORKS_MONITORED.get(), 1.0);
+	}
+
 	#[test]
 	fn test_multiple_monitors_same_network() {
 		let _lock = TEST_MUTEX.lock().unwrap();
@@ -660,17 +1048,20 @@ mod tests {
 		let networks = HashMap::new();
 		let mut triggers = HashMap::new();
 
-		// Add some triggers
+		// Add some triggers, one of them disabled
+		let mut disabled_trigger = create_test_trigger("trigger3");
+		disabled_trigger.enabled = Some(false);
 		triggers.insert("trigger1".to_string(), create_test_trigger("trigger1"));
 		triggers.insert("trigger2".to_string(), create_test_trigger("trigger2"));
-		triggers.insert("trigger3".to_string(), create_test_trigger("trigger3"));
+		triggers.insert("trigger3".to_string(), disabled_trigger);
 
 		// Update metrics
 		update_monitoring_metrics(&monitors, &triggers, &networks);
 
-		// Verify metrics
+		// Verify metrics, disabled triggers still count towards the total
 		let total_triggers = TRIGGERS_TOTAL.get();
 		assert_eq!(total_triggers, 3.0);
+		assert_eq!(TRIGGERS_ENABLED.get(), 2.0);
 
 		// Verify other metrics are zero since we have no monitors or networks
 		assert_eq!(MONITORS_TOTAL.get(), 0.0);
@@ -692,6 +1083,7 @@ mod tests {
 		MONITORS_TOTAL.set(10.0);
 		MONITORS_ACTIVE.set(5.0);
 		TRIGGERS_TOTAL.set(3.0);
+		TRIGGERS_ENABLED.set(3.0);
 		CONTRACTS_MONITORED.set(7.0);
 		NETWORKS_MONITORED.set(2.0);
 		NETWORK_MONITORS.reset();
@@ -706,6 +1098,7 @@ mod tests {
 		assert_eq!(MONITORS_TOTAL.get(), 0.0);
 		assert_eq!(MONITORS_ACTIVE.get(), 0.0);
 		assert_eq!(TRIGGERS_TOTAL.get(), 0.0);
+		assert_eq!(TRIGGERS_ENABLED.get(), 0.0);
 		assert_eq!(CONTRACTS_MONITORED.get(), 0.0);
 		assert_eq!(NETWORKS_MONITORED.get(), 0.0);
 
@@ -715,4 +1108,27 @@ mod tests {
 			.unwrap();
 		assert_eq!(test_network.get(), 0.0);
 	}
+
+	#[test]
+	fn test_update_block_lag() {
+		let now = std::time::SystemTime::now()
+			.duration_since(std::time::UNIX_EPOCH)
+			.unwrap()
+			.as_secs();
+
+		update_block_lag("block_lag_test", now - 30);
+		let lag = BLOCK_LAG_SECONDS
+			.get_metric_with_label_values(&["block_lag_test"])
+			.unwrap()
+			.get();
+		assert!((30.0..=31.0).contains(&lag));
+
+		// Timestamps ahead of the local clock never produce a negative lag
+		update_block_lag("block_lag_test", now + 60);
+		let lag = BLOCK_LAG_SECONDS
+			.get_metric_with_label_values(&["block_lag_test"])
+			.unwrap()
+			.get();
+		assert_eq!(lag, 0.0);
+	}
 }
@@ -1,10 +1,20 @@
 //! Metrics server module
 //!
 //! This module provides an HTTP server to expose Prometheus metrics for scraping.
+//!
+//! The server listens on a TCP address, either `HOST:PORT` or `[IPV6]:PORT`, or on a Unix
+//! socket given as `unix:PATH`, e.g. for hosts only exposing metrics to a sidecar scraping them.
 
 use actix_web::middleware::{Compress, DefaultHeaders, NormalizePath};
 use actix_web::{web, App, HttpResponse, HttpServer, Responder};
-use std::sync::Arc;
+use std::{
+	fmt,
+	io::{Error, ErrorKind},
+	net::Ipv6Addr,
+	path::PathBuf,
+	str::FromStr,
+	sync::Arc,
+};
 use tokio::sync::Mutex;
 use tracing::{error, info};
 
@@ -13,7 +23,8 @@ use crate::{
 		MonitorRepository, MonitorService, NetworkRepository, NetworkService, TriggerRepository,
 		TriggerService,
 	},
-	utils::metrics::{gather_metrics, update_monitoring_metrics, update_system_metrics},
+	services::blockwatcher::{is_leader, stall_detector, StallDetector},
+	utils::metrics::{gather_metrics, update_monitoring_metrics, update_system_metrics, IS_LEADER},
 };
 
 // Type aliases to simplify complex types in function signatures
@@ -61,6 +72,7 @@ async fn metrics_handler(
 ) -> impl Responder {
 	// Update system metrics
 	update_system_metrics();
+	IS_LEADER.set(if is_leader() { 1.0 } else { 0.0 });
 
 	// Get current state and update metrics
 	{
@@ -83,6 +95,118 @@ async fn metrics_handler(
 	}
 }
 
+/// Readiness endpoint handler
+///
+/// Responds with 503 Service Unavailable while any network is stalled. Followers of a leader
+/// election are ready to take over, and report `is_leader` as false.
+async fn readyz_handler() -> impl Responder {
+	readiness_response(stall_detector()).await
+}
+
+/// Builds the readiness response from the networks stalled according to a stall detector
+///
+/// # Arguments
+/// * `detector` - Stall detector tracking the progress of the networks
+async fn readiness_response(detector: &StallDetector) -> HttpResponse {
+	let stalled_networks = detector.stalled_networks().await;
+
+	if stalled_networks.is_empty() {
+		HttpResponse::Ok().json(serde_json::json!({
+			"status": "ready",
+			"is_leader": is_leader(),
+		}))
+	} else {
+		HttpResponse::ServiceUnavailable().json(serde_json::json!({
+			"status": "stalled",
+			"is_leader": is_leader(),
+			"stalled_networks": stalled_networks,
+		}))
+	}
+}
+
+/// Address the metrics server listens on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetricsBindAddress {
+	/// TCP address, whose host is a hostname, an IPv4 address or an IPv6 address
+	Tcp { host: String, port: u16 },
+	/// Path of a Unix socket
+	Unix(PathBuf),
+}
+
+impl MetricsBindAddress {
+	/// Returns the address bound inside a Docker container, all interfaces on the same port
+	///
+	/// Unix sockets are bound as is, as they are shared through a mounted volume.
+	pub fn in_docker(self) -> Self {
+		match self {
+			Self::Tcp { port, .. } => Self::Tcp {
+				host: "0.0.0.0".to_string(),
+				port,
+			},
+			unix => unix,
+		}
+	}
+}
+
+impl FromStr for MetricsBindAddress {
+	type Err = Error;
+
+	/// Parses `HOST:PORT`, `[IPV6]:PORT` or `unix:PATH`
+	fn from_str(address: &str) -> Result<Self, Self::Err> {
+		let invalid = |reason: &str| {
+			Error::new(
+				ErrorKind::InvalidInput,
+				format!("Invalid metrics address {:?}: {}", address, reason),
+			)
+		};
+
+		if let Some(path) = address.strip_prefix("unix:") {
+			if path.is_empty() {
+				return Err(invalid("missing socket path after `unix:`"));
+			}
+			return Ok(Self::Unix(PathBuf::from(path)));
+		}
+
+		let (host, port) = if let Some(bracketed) = address.strip_prefix('[') {
+			let (host, port) = bracketed
+				.split_once("]:")
+				.ok_or_else(|| invalid("expected `[IPV6]:PORT`"))?;
+			host.parse::<Ipv6Addr>()
+				.map_err(|_| invalid("invalid IPv6 address"))?;
+			(host, port)
+		} else {
+			let (host, port) = address
+				.rsplit_once(':')
+				.ok_or_else(|| invalid("expected `HOST:PORT`, `[IPV6]:PORT` or `unix:PATH`"))?;
+			if host.contains(':') {
+				return Err(invalid(
+					"IPv6 addresses must be bracketed, e.g. `[::1]:8081`",
+				));
+			}
+			if host.is_empty() || host.contains(char::is_whitespace) {
+				return Err(invalid("invalid host"));
+			}
+			(host, port)
+		};
+		let port = port.parse().map_err(|_| invalid("invalid port"))?;
+
+		Ok(Self::Tcp {
+			host: host.to_string(),
+			port,
+		})
+	}
+}
+
+impl fmt::Display for MetricsBindAddress {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Tcp { host, port } if host.contains(':') => write!(f, "[{}]:{}", host, port),
+			Self::Tcp { host, port } => write!(f, "{}:{}", host, port),
+			Self::Unix(path) => write!(f, "unix:{}", path.display()),
+		}
+	}
+}
+
 // Create metrics server
 pub fn create_metrics_server(
 	bind_address: String,
@@ -90,22 +214,17 @@ pub fn create_metrics_server(
 	network_service: NetworkServiceArc,
 	trigger_service: TriggerServiceArc,
 ) -> std::io::Result<actix_web::dev::Server> {
-	let actual_bind_address = if std::env::var("IN_DOCKER").unwrap_or_default() == "true" {
-		if let Some(port) = bind_address.split(':').nth(1) {
-			format!("0.0.0.0:{}", port)
-		} else {
-			"0.0.0.0:8081".to_string()
-		}
-	} else {
-		bind_address.clone()
-	};
+	let mut actual_bind_address: MetricsBindAddress = bind_address.parse()?;
+	if std::env::var("IN_DOCKER").unwrap_or_default() == "true" {
+		actual_bind_address = actual_bind_address.in_docker();
+	}
 
 	info!(
 		"Starting metrics server on {} (actual bind: {})",
 		bind_address, actual_bind_address
 	);
 
-	Ok(HttpServer::new(move || {
+	let server = HttpServer::new(move || {
 		App::new()
 			.wrap(Compress::default())
 			.wrap(NormalizePath::trim())
@@ -114,11 +233,41 @@ pub fn create_metrics_server(
 			.app_data(web::Data::new(network_service.clone()))
 			.app_data(web::Data::new(trigger_service.clone()))
 			.route("/metrics", web::get().to(metrics_handler))
+			.route("/readyz", web::get().to(readyz_handler))
 	})
-	.workers(2)
-	.bind(actual_bind_address)?
-	.shutdown_timeout(5)
-	.run())
+	.workers(2);
+	let server = match actual_bind_address {
+		MetricsBindAddress::Tcp { host, port } => server.bind((host.as_str(), port))?,
+		#[cfg(unix)]
+		MetricsBindAddress::Unix(path) => {
+			remove_stale_socket(&path)?;
+			server.bind_uds(path)?
+		}
+		#[cfg(not(unix))]
+		MetricsBindAddress::Unix(path) => {
+			return Err(Error::new(
+				ErrorKind::Unsupported,
+				format!(
+					"Unix sockets aren't supported on this platform: {}",
+					path.display()
+				),
+			));
+		}
+	};
+	Ok(server.shutdown_timeout(5).run())
+}
+
+/// Removes the socket left at a path by a previous run, which would prevent binding it again
+///
+/// Files other than sockets are left untouched, failing the bind.
+#[cfg(unix)]
+fn remove_stale_socket(path: &std::path::Path) -> std::io::Result<()> {
+	use std::os::unix::fs::FileTypeExt;
+
+	match std::fs::symlink_metadata(path) {
+		Ok(metadata) if metadata.file_type().is_socket() => std::fs::remove_file(path),
+		_ => Ok(()),
+	}
 }
 
 #[cfg(test)]
@@ -129,8 +278,11 @@ mod tests {
 		repositories::{
 			MonitorService, NetworkRepository, NetworkService, TriggerRepository, TriggerService,
 		},
-		utils::tests::{
-			evm::monitor::MonitorBuilder, network::NetworkBuilder, trigger::TriggerBuilder,
+		utils::{
+			tests::{
+				evm::monitor::MonitorBuilder, network::NetworkBuilder, trigger::TriggerBuilder,
+			},
+			MockClock,
 		},
 	};
 	use actix_web::{test, App};
@@ -297,6 +449,53 @@ mod tests {
 		assert!(body_str.contains("# HELP"));
 	}
 
+	#[actix_web::test]
+	async fn test_readyz_handler() {
+		let clock = Arc::new(MockClock::new());
+		let detector = Arc::new(StallDetector::with_clock(clock.clone()));
+		let app = test::init_service(App::new().route(
+			"/readyz",
+			web::get().to({
+				let detector = detector.clone();
+				move || {
+					let detector = detector.clone();
+					async move { readiness_response(&detector).await }
+				}
+			}),
+		))
+		.await;
+
+		let req = test::TestRequest::get().uri("/readyz").to_request();
+		let resp = test::call_service(&app, req).await;
+		assert!(resp.status().is_success());
+		let body: serde_json::Value = test::read_body_json(resp).await;
+		assert_eq!(body["status"], "ready");
+		assert!(body["is_leader"].is_boolean());
+
+		// Stall a network
+		let network = NetworkBuilder::new()
+			.slug("readyz_stalled_network")
+			.stall_threshold_ms(1)
+			.build();
+		detector.observe(&network, Some(100)).await;
+		clock.advance(std::time::Duration::from_millis(1));
+		detector.observe(&network, Some(100)).await;
+
+		let req = test::TestRequest::get().uri("/readyz").to_request();
+		let resp = test::call_service(&app, req).await;
+		assert_eq!(
+			resp.status(),
+			actix_web::http::StatusCode::SERVICE_UNAVAILABLE
+		);
+
+		let body: serde_json::Value = test::read_body_json(resp).await;
+		assert_eq!(body["status"], "stalled");
+		assert_eq!(
+			body["stalled_networks"],
+			serde_json::json!(["readyz_stalled_network"])
+		);
+	}
+
 	#[tokio::test]
 	async fn test_create_metrics_server() {
 		// Create test services
@@ -352,6 +551,112 @@ mod tests {
 		server_task.abort();
 	}
 
+	#[test]
+	fn test_parse_bind_address() {
+		let ipv4: MetricsBindAddress = "127.0.0.1:8081".parse().unwrap();
+		assert_eq!(
+			ipv4,
+			MetricsBindAddress::Tcp {
+				host: "127.0.0.1".to_string(),
+				port: 8081
+			}
+		);
+		assert_eq!(ipv4.to_string(), "127.0.0.1:8081");
+
+		let hostname: MetricsBindAddress = "localhost:9000".parse().unwrap();
+		assert_eq!(hostname.to_string(), "localhost:9000");
+
+		let ipv6: MetricsBindAddress = "[::1]:8081".parse().unwrap();
+		assert_eq!(
+			ipv6,
+			MetricsBindAddress::Tcp {
+				host: "::1".to_string(),
+				port: 8081
+			}
+		);
+		assert_eq!(ipv6.to_string(), "[::1]:8081");
+		let ipv6: MetricsBindAddress = "[::]:8081".parse().unwrap();
+		assert_eq!(ipv6.clone().in_docker().to_string(), "0.0.0.0:8081");
+
+		let unix: MetricsBindAddress = "unix:/run/monitor/metrics.sock".parse().unwrap();
+		assert_eq!(
+			unix,
+			MetricsBindAddress::Unix(PathBuf::from("/run/monitor/metrics.sock"))
+		);
+		assert_eq!(unix.to_string(), "unix:/run/monitor/metrics.sock");
+		assert_eq!(unix.clone().in_docker(), unix);
+	}
+
+	#[test]
+	fn test_parse_malformed_bind_address() {
+		for (address, reason) in [
+			("localhost", "expected `HOST:PORT`"),
+			(":8081", "invalid host"),
+			("127.0.0.1:", "invalid port"),
+			("127.0.0.1:70000", "invalid port"),
+			("::1:8081", "must be bracketed"),
+			("[::1]", "expected `[IPV6]:PORT`"),
+			("[::1:8081", "expected `[IPV6]:PORT`"),
+			("[127.0.0.1]:8081", "invalid IPv6 address"),
+			("[::1]:port", "invalid port"),
+			("unix:", "missing socket path"),
+		] {
+			let error = address.parse::<MetricsBindAddress>().unwrap_err();
+			assert_eq!(error.kind(), ErrorKind::InvalidInput);
+			assert!(error.to_string().contains(reason), "{}: {}", address, error);
+		}
+	}
+
+	#[cfg(unix)]
+	#[tokio::test]
+	async fn test_create_metrics_server_on_unix_socket() {
+		use tokio::{
+			io::{AsyncReadExt, AsyncWriteExt},
+			net::UnixStream,
+		};
+
+		let (monitor_service, network_service, trigger_service, temp_dir) =
+			create_test_services().await;
+		let socket_path = temp_dir.path().join("metrics.sock");
+		// A socket left by a previous run is replaced
+		let _stale = std::os::unix::net::UnixListener::bind(&socket_path).unwrap();
+
+		let server = create_metrics_server(
+			format!("unix:{}", socket_path.display()),
+			monitor_service,
+			network_service,
+			trigger_service,
+		)
+		.unwrap();
+		let server_task = tokio::spawn(server);
+		tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+		let mut stream = UnixStream::connect(&socket_path).await.unwrap();
+		stream
+			.write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+			.await
+			.unwrap();
+		let mut response = String::new();
+		stream.read_to_string(&mut response).await.unwrap();
+		assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+
+		server_task.abort();
+	}
+
+	#[tokio::test]
+	async fn test_create_metrics_server_rejects_malformed_address() {
+		let (monitor_service, network_service, trigger_service, _temp_dir) =
+			create_test_services().await;
+
+		let result = create_metrics_server(
+			"::1:8081".to_string(),
+			monitor_service,
+			network_service,
+			trigger_service,
+		);
+		assert_eq!(result.err().unwrap().kind(), ErrorKind::InvalidInput);
+	}
+
 	#[tokio::test]
 	async fn test_docker_bind_address_handling() {
 		// Save original environment state
@@ -3,8 +3,10 @@
 //! This module provides various utility functions and types that are used across
 //! the application. Currently includes:
 //!
+//! - clock: Source of the current time, which can be mocked in tests
 //! - constants: Constants for the application
 //! - cron_utils: Utilities for working with cron schedules and time intervals
+//! - exit_code: Exit codes of the process for each class of failure
 //! - logging: Logging utilities
 //! - macros: Macros for common functionality
 //! - metrics: Metrics utilities
@@ -12,12 +14,16 @@
 //! - parsing: Parsing utilities
 //! - tests: Test utilities
 //! - http: HTTP client utilities (i.e. creation retryable HTTP clients)
+//! - introspection: Summaries of loaded configurations for listing
 
 mod cron_utils;
 
 pub mod client_storage;
+pub mod clock;
 pub mod constants;
+pub mod exit_code;
 pub mod http;
+pub mod introspection;
 pub mod logging;
 pub mod macros;
 pub mod metrics;
@@ -26,6 +32,7 @@ pub mod parsing;
 pub mod tests;
 
 pub use client_storage::ClientStorage;
+pub use clock::{system_clock, Clock, MockClock, SharedClock, SystemClock};
 pub use constants::*;
 pub use cron_utils::*;
 pub use http::*;
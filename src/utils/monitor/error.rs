@@ -17,6 +17,10 @@ pub enum MonitorExecutionError {
 	#[error("Execution error: {0}")]
 	ExecutionError(ErrorContext),
 
+	/// Errors related to reaching the RPC endpoints of a network
+	#[error("Connection error: {0}")]
+	ConnectionError(ErrorContext),
+
 	/// Other errors that don't fit into the categories above
 	#[error(transparent)]
 	Other(#[from] anyhow::Error),
@@ -40,6 +44,15 @@ impl MonitorExecutionError {
 	) -> Self {
 		Self::ExecutionError(ErrorContext::new_with_log(msg, source, metadata))
 	}
+
+	// Connection error
+	pub fn connection_error(
+		msg: impl Into<String>,
+		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+		metadata: Option<HashMap<String, String>>,
+	) -> Self {
+		Self::ConnectionError(ErrorContext::new_with_log(msg, source, metadata))
+	}
 }
 
 impl TraceableError for MonitorExecutionError {
@@ -47,6 +60,7 @@ impl TraceableError for MonitorExecutionError {
 		match self {
 			Self::NotFound(ctx) => ctx.trace_id.clone(),
 			Self::ExecutionError(ctx) => ctx.trace_id.clone(),
+			Self::ConnectionError(ctx) => ctx.trace_id.clone(),
 			Self::Other(_) => Uuid::new_v4().to_string(),
 		}
 	}
@@ -91,6 +105,12 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_connection_error_formatting() {
+		let error = MonitorExecutionError::connection_error("test error", None, None);
+		assert_eq!(error.to_string(), "Connection error: test error");
+	}
+
 	#[test]
 	fn test_from_anyhow_error() {
 		let anyhow_error = anyhow::anyhow!("test anyhow error");
@@ -3,7 +3,7 @@
 //! This module provides functionality to execute monitors against specific block numbers on blockchain networks.
 use crate::{
 	bootstrap::{get_contract_specs, has_active_monitors},
-	models::{BlockChainType, ScriptLanguage},
+	models::{BlockChainType, MonitorMatch, ScriptLanguage},
 	repositories::{
 		MonitorRepositoryTrait, MonitorService, NetworkRepositoryTrait, NetworkService,
 		TriggerRepositoryTrait,
@@ -51,26 +51,32 @@ pub struct MonitorExecutionConfig<
 }
 pub type ExecutionResult<T> = std::result::Result<T, MonitorExecutionError>;
 
+/// Format of the file the matches of a monitor execution are written to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum MatchOutputFormat {
+	/// The matches as a JSON array
+	#[default]
+	Json,
+	/// One JSON match per line
+	Jsonl,
+}
+
 /// Executes a monitor against a specific block number on a blockchain network.
 ///
 /// This function allows testing monitors by running them against historical blocks.
 /// It supports both EVM and Stellar networks, retrieving the block data and applying
 /// the monitor's filters to check for matches.
 ///
+/// This is the CLI-facing variant of [`execute_monitor_matches`], returning the matches
+/// serialized as a JSON array.
+///
 /// # Arguments
 ///
-/// * `monitor_name` - The name of the monitor to execute
-/// * `network_slug` - The network identifier to run the monitor against
-/// * `block_number` - The specific block number to analyze
-/// * `active_monitors` - List of currently active monitors
-/// * `network_service` - The network service to use
-/// * `filter_service` - The filter service to use
-/// * `client_pool` - The client pool to use
+/// * `config` - The monitor execution configuration
 ///
 /// # Returns
 /// * `Result<String, ExecutionError>` - JSON string containing matches or error
 #[instrument(skip_all)]
-#[allow(clippy::too_many_arguments)]
 pub async fn execute_monitor<
 	M: MonitorRepositoryTrait<N, TR>,
 	N: NetworkRepositoryTrait + Send + Sync + 'static,
@@ -80,6 +86,130 @@ pub async fn execute_monitor<
 >(
 	config: MonitorExecutionConfig<M, N, TR, CP, FS>,
 ) -> ExecutionResult<String> {
+	let all_matches = execute_monitor_matches(config).await?;
+
+	tracing::debug!(total_matches = all_matches.len(), "Serializing results");
+	let json_matches = serde_json::to_string(&all_matches).map_err(|e| {
+		MonitorExecutionError::execution_error(
+			format!("Failed to serialize matches: {}", e),
+			None,
+			None,
+		)
+	})?;
+
+	tracing::debug!("Monitor execution completed successfully");
+	Ok(json_matches)
+}
+
+/// Writes the matches of a monitor execution to a file, creating or truncating it.
+///
+/// # Arguments
+///
+/// * `matches` - The matches serialized as a JSON array, as returned by [`execute_monitor`]
+/// * `path` - The path of the file to write
+/// * `format` - The format of the file
+///
+/// # Returns
+/// * `Result<(), ExecutionError>` - Ok if the file is written, or an error if the matches aren't
+///   a JSON array or the file can't be written
+pub fn write_matches(matches: &str, path: &Path, format: MatchOutputFormat) -> ExecutionResult<()> {
+	let contents = match format {
+		MatchOutputFormat::Json => format!("{}\n", matches),
+		MatchOutputFormat::Jsonl => serde_json::from_str::<Vec<serde_json::Value>>(matches)
+			.map_err(|e| {
+				MonitorExecutionError::execution_error(
+					format!("Failed to parse matches: {}", e),
+					None,
+					None,
+				)
+			})?
+			.iter()
+			.map(|monitor_match| format!("{}\n", monitor_match))
+			.collect(),
+	};
+
+	std::fs::write(path, contents).map_err(|e| {
+		MonitorExecutionError::execution_error(
+			format!("Failed to write matches to {}: {}", path.display(), e),
+			None,
+			None,
+		)
+	})
+}
+
+/// Executes a monitor against a single block and returns the typed matches.
+///
+/// The monitor is loaded from `config.path` and run against block `config.block_number`, or the
+/// latest block when it is None. It runs on the network `config.network_slug`, or on every
+/// network the monitor is active on when that is None. The monitor's triggers are executed for
+/// each match; a failed trigger is logged without failing the execution.
+///
+/// # Arguments
+///
+/// * `config` - The monitor execution configuration
+///
+/// # Returns
+/// * `Ok(Vec<MonitorMatch>)` - Matches of all networks, grouped by network. Empty when the block
+///   doesn't match the monitor
+/// * `Err(MonitorExecutionError::NotFound)` - The network or the block doesn't exist
+/// * `Err(MonitorExecutionError::ConnectionError)` - The network's RPC endpoints couldn't be
+///   reached, or the block couldn't be fetched
+/// * `Err(MonitorExecutionError::ExecutionError)` - The monitor couldn't be loaded, or the block
+///   couldn't be filtered
+///
+/// # Example
+///
+/// ```no_run
+/// use std::{collections::HashMap, sync::Arc};
+///
+/// use openzeppelin_monitor::{
+/// 	bootstrap::initialize_services,
+/// 	models::MonitorMatch,
+/// 	repositories::{MonitorRepository, NetworkRepository, TriggerRepository},
+/// 	services::blockchain::ClientPool,
+/// 	utils::monitor::execution::{execute_monitor_matches, MonitorExecutionConfig},
+/// };
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let (filter_service, trigger_execution_service, _, _, monitor_service, network_service, _) =
+/// 	initialize_services::<
+/// 		MonitorRepository<NetworkRepository, TriggerRepository>,
+/// 		NetworkRepository,
+/// 		TriggerRepository,
+/// 	>(None, None, None, None)
+/// 	.await?;
+///
+/// let matches: Vec<MonitorMatch> = execute_monitor_matches(MonitorExecutionConfig {
+/// 	path: "config/monitors/evm_transfer_usdc.json".to_string(),
+/// 	network_slug: Some("ethereum_mainnet".to_string()),
+/// 	block_number: Some(21_000_000),
+/// 	monitor_service,
+/// 	network_service,
+/// 	filter_service,
+/// 	trigger_execution_service,
+/// 	active_monitors_trigger_scripts: HashMap::new(),
+/// 	client_pool: Arc::new(ClientPool::new()),
+/// })
+/// .await?;
+///
+/// for monitor_match in &matches {
+/// 	if let MonitorMatch::EVM(evm_match) = monitor_match {
+/// 		println!("{} matched {}", evm_match.monitor.name, evm_match.transaction.hash());
+/// 	}
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[instrument(skip_all)]
+pub async fn execute_monitor_matches<
+	M: MonitorRepositoryTrait<N, TR>,
+	N: NetworkRepositoryTrait + Send + Sync + 'static,
+	TR: TriggerRepositoryTrait + Send + Sync + 'static,
+	CP: ClientPoolTrait + Send + Sync + 'static,
+	FS: FilterServiceTrait + Send + Sync + 'static,
+>(
+	config: MonitorExecutionConfig<M, N, TR, CP, FS>,
+) -> ExecutionResult<Vec<MonitorMatch>> {
 	tracing::debug!("Loading monitor configuration");
 	let monitor = config
 		.monitor_service
@@ -145,7 +275,7 @@ pub async fn execute_monitor<
 					.get_evm_client(&network)
 					.await
 					.map_err(|e| {
-						MonitorExecutionError::execution_error(
+						MonitorExecutionError::connection_error(
 							format!("Failed to get EVM client: {}", e),
 							None,
 							None,
@@ -159,7 +289,7 @@ pub async fn execute_monitor<
 					}
 					None => {
 						let latest = client.get_latest_block_number().await.map_err(|e| {
-							MonitorExecutionError::execution_error(e.to_string(), None, None)
+							MonitorExecutionError::connection_error(e.to_string(), None, None)
 						})?;
 						tracing::debug!(block = %latest, "Using latest block number");
 						latest
@@ -168,7 +298,7 @@ pub async fn execute_monitor<
 
 				tracing::debug!(block = %block_number, "Fetching block");
 				let blocks = client.get_blocks(block_number, None).await.map_err(|e| {
-					MonitorExecutionError::execution_error(
+					MonitorExecutionError::connection_error(
 						format!("Failed to get block {}: {}", block_number, e),
 						None,
 						None,
@@ -208,7 +338,7 @@ pub async fn execute_monitor<
 					.get_stellar_client(&network)
 					.await
 					.map_err(|e| {
-						MonitorExecutionError::execution_error(
+						MonitorExecutionError::connection_error(
 							format!("Failed to get Stellar client: {}", e),
 							None,
 							None,
@@ -219,12 +349,12 @@ pub async fn execute_monitor<
 				let block_number = match config.block_number {
 					Some(block_number) => block_number,
 					None => client.get_latest_block_number().await.map_err(|e| {
-						MonitorExecutionError::execution_error(e.to_string(), None, None)
+						MonitorExecutionError::connection_error(e.to_string(), None, None)
 					})?,
 				};
 
 				let blocks = client.get_blocks(block_number, None).await.map_err(|e| {
-					MonitorExecutionError::execution_error(
+					MonitorExecutionError::connection_error(
 						format!("Failed to get block {}: {}", block_number, e),
 						None,
 						None,
@@ -263,7 +393,7 @@ pub async fn execute_monitor<
 					.get_midnight_client(&network)
 					.await
 					.map_err(|e| {
-						MonitorExecutionError::execution_error(
+						MonitorExecutionError::connection_error(
 							format!("Failed to get Midnight client: {}", e),
 							None,
 							None,
@@ -274,12 +404,67 @@ pub async fn execute_monitor<
 				let block_number = match config.block_number {
 					Some(block_number) => block_number,
 					None => client.get_latest_block_number().await.map_err(|e| {
-						MonitorExecutionError::execution_error(e.to_string(), None, None)
+						MonitorExecutionError::connection_error(e.to_string(), None, None)
 					})?,
 				};
 
 				let blocks = client.get_blocks(block_number, None).await.map_err(|e| {
-					MonitorExecutionError::execution_error(
+					MonitorExecutionError::connection_error(
+						format!("Failed to get block {}: {}", block_number, e),
+						None,
+						None,
+					)
+				})?;
+
+				let block = blocks.first().ok_or_else(|| {
+					MonitorExecutionError::not_found(
+						format!("Block {} not found", block_number),
+						None,
+						None,
+					)
+				})?;
+
+				config
+					.filter_service
+					.filter_block(
+						&*client,
+						&network,
+						block,
+						std::slice::from_ref(&monitor),
+						Some(&contract_specs),
+					)
+					.await
+					.map_err(|e| {
+						MonitorExecutionError::execution_error(
+							format!("Failed to filter block: {}", e),
+							None,
+							None,
+						)
+					})?
+			}
+			BlockChainType::Solana => {
+				let client = config
+					.client_pool
+					.get_solana_client(&network)
+					.await
+					.map_err(|e| {
+						MonitorExecutionError::connection_error(
+							format!("Failed to get Solana client: {}", e),
+							None,
+							None,
+						)
+					})?;
+
+				// If block number is not provided, get the latest block number
+				let block_number = match config.block_number {
+					Some(block_number) => block_number,
+					None => client.get_latest_block_number().await.map_err(|e| {
+						MonitorExecutionError::connection_error(e.to_string(), None, None)
+					})?,
+				};
+
+				let blocks = client.get_blocks(block_number, None).await.map_err(|e| {
+					MonitorExecutionError::connection_error(
 						format!("Failed to get block {}: {}", block_number, e),
 						None,
 						None,
@@ -319,7 +504,7 @@ pub async fn execute_monitor<
 	}
 
 	// Send notifications for each match
-	for match_result in all_matches.clone() {
+	for match_result in all_matches.iter().cloned() {
 		let result = handle_match(
 			match_result,
 			&*config.trigger_execution_service,
@@ -335,15 +520,5 @@ pub async fn execute_monitor<
 		};
 	}
 
-	tracing::debug!(total_matches = all_matches.len(), "Serializing results");
-	let json_matches = serde_json::to_string(&all_matches).map_err(|e| {
-		MonitorExecutionError::execution_error(
-			format!("Failed to serialize matches: {}", e),
-			None,
-			None,
-		)
-	})?;
-
-	tracing::debug!("Monitor execution completed successfully");
-	Ok(json_matches)
+	Ok(all_matches)
 }
@@ -11,11 +11,13 @@ pub struct ReceiptBuilder {
 	transaction_hash: Option<B256>,
 	status: Option<bool>,
 	gas_used: Option<U256>,
+	effective_gas_price: Option<U256>,
 	logs: Option<Vec<EVMReceiptLog>>,
 	from: Option<Address>,
 	to: Option<Address>,
 	contract_address: Option<Address>,
 	transaction_index: Option<Index>,
+	revert_reason: Option<Bytes>,
 }
 
 impl ReceiptBuilder {
@@ -42,6 +44,12 @@ impl ReceiptBuilder {
 		self
 	}
 
+	/// Sets the effective gas price paid by the transaction.
+	pub fn effective_gas_price(mut self, effective_gas_price: U256) -> Self {
+		self.effective_gas_price = Some(effective_gas_price);
+		self
+	}
+
 	/// Sets the transaction index in the block.
 	pub fn transaction_index(mut self, transaction_index: usize) -> Self {
 		self.transaction_index = Some(Index::from(transaction_index));
@@ -72,6 +80,12 @@ impl ReceiptBuilder {
 		self
 	}
 
+	/// Sets the revert data of a failed transaction
+	pub fn revert_reason(mut self, revert_reason: Vec<u8>) -> Self {
+		self.revert_reason = Some(Bytes::from(revert_reason));
+		self
+	}
+
 	/// Set log with specified value transfer event
 	pub fn value(mut self, value: U256) -> Self {
 		let event_signature = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
@@ -110,11 +124,13 @@ impl ReceiptBuilder {
 			transaction_hash: self.transaction_hash.unwrap_or_default(),
 			status: Some(status_u64),
 			gas_used: self.gas_used,
+			effective_gas_price: self.effective_gas_price,
 			logs: self.logs.unwrap_or_default(),
 			from: self.from.unwrap_or_default(),
 			to: self.to,
 			contract_address: self.contract_address,
 			transaction_index: self.transaction_index.unwrap_or_default(),
+			revert_reason: self.revert_reason,
 			..Default::default()
 		};
 
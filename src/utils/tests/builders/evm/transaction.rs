@@ -1,7 +1,9 @@
 use crate::models::{EVMBaseTransaction, EVMTransaction};
 use alloy::{
-	primitives::{Address, Bytes, B256, U256},
+	consensus::{SignableTransaction, TxEip1559},
+	primitives::{Address, Bytes, TxKind, B256, U256, U64},
 	rpc::types::Index,
+	signers::{local::PrivateKeySigner, SignerSync},
 };
 
 /// A builder for creating test EVM transactions with default values.
@@ -18,6 +20,7 @@ pub struct TransactionBuilder {
 	gas_limit: Option<U256>,
 	nonce: Option<U256>,
 	transaction_index: Option<Index>,
+	signer: Option<PrivateKeySigner>,
 }
 
 impl TransactionBuilder {
@@ -93,10 +96,19 @@ impl TransactionBuilder {
 	}
 
 	/// Builds the Transaction instance.
+	/// Signs the transaction as an EIP-1559 transaction on chain 1.
+	///
+	/// The sender is the signer unless set with `from`.
+	pub fn signed_by(mut self, signer: &PrivateKeySigner) -> Self {
+		self.signer = Some(signer.clone());
+		self
+	}
+
+	/// Builds the transaction.
 	pub fn build(self) -> EVMTransaction {
 		let default_gas_limit = U256::from(21000);
 
-		let base_tx = EVMBaseTransaction {
+		let mut base_tx = EVMBaseTransaction {
 			hash: self.hash.unwrap_or_default(),
 			from: self.from,
 			to: self.to,
@@ -111,6 +123,32 @@ impl TransactionBuilder {
 			..Default::default()
 		};
 
+		if let Some(signer) = self.signer {
+			let unsigned = TxEip1559 {
+				chain_id: 1,
+				nonce: base_tx.nonce.to(),
+				gas_limit: base_tx.gas.to(),
+				max_fee_per_gas: base_tx.max_fee_per_gas.unwrap_or_default().to(),
+				max_priority_fee_per_gas: base_tx.max_priority_fee_per_gas.unwrap_or_default().to(),
+				to: base_tx.to.map_or(TxKind::Create, TxKind::Call),
+				value: base_tx.value,
+				access_list: Default::default(),
+				input: base_tx.input.clone(),
+			};
+			let signature = signer.sign_hash_sync(&unsigned.signature_hash()).unwrap();
+
+			base_tx.from = base_tx.from.or(Some(signer.address()));
+			base_tx.transaction_type = Some(U64::from(2));
+			base_tx.max_fee_per_gas = Some(U256::from(unsigned.max_fee_per_gas));
+			base_tx.max_priority_fee_per_gas = Some(U256::from(unsigned.max_priority_fee_per_gas));
+			base_tx
+				.extra
+				.insert("chainId".to_string(), serde_json::json!("0x1"));
+			base_tx.v = Some(U64::from(signature.v() as u64));
+			base_tx.r = Some(signature.r());
+			base_tx.s = Some(signature.s());
+		}
+
 		EVMTransaction(base_tx)
 	}
 }
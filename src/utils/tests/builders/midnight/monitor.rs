@@ -2,10 +2,13 @@
 //!
 //! - `MonitorBuilder`: Builder for creating test Monitor instances
 
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
 use crate::models::{
-	AddressWithSpec, ChainConfiguration, EventCondition, FunctionCondition, MatchConditions,
-	MidnightMonitorConfig, Monitor, ScriptLanguage, TransactionCondition, TransactionStatus,
-	TriggerConditions,
+	AddressMatchMode, AddressWithSpec, ChainConfiguration, ConditionLogic, EventCondition,
+	FunctionCondition, MatchConditions, MidnightMonitorConfig, Monitor, RpcCall, ScriptLanguage,
+	TransactionCondition, TransactionStatus, TriggerConditions,
 };
 
 /// Builder for creating test Monitor instances
@@ -13,10 +16,26 @@ pub struct MonitorBuilder {
 	name: String,
 	networks: Vec<String>,
 	paused: bool,
+	paused_until: Option<DateTime<Utc>>,
+	active_from: Option<DateTime<Utc>>,
+	active_until: Option<DateTime<Utc>>,
+	aggregate_across_networks: bool,
+	aggregation_window_ms: Option<u64>,
+	max_notifications_per_hour: Option<u32>,
+	throttle_summary: bool,
+	cooldown_ms: Option<u64>,
+	dedup_key: Option<String>,
+	persist_cooldown: bool,
+	notify_on_resolve: bool,
+	resolve_trigger: Option<String>,
+	pre_filter_rpc_calls: Vec<RpcCall>,
+	cron_schedule: Option<String>,
 	addresses: Vec<AddressWithSpec>,
 	match_conditions: MatchConditions,
 	trigger_conditions: Vec<TriggerConditions>,
+	condition_logic: ConditionLogic,
 	triggers: Vec<String>,
+	warn_triggers: Vec<String>,
 	chain_configurations: Vec<ChainConfiguration>,
 }
 
@@ -27,18 +46,39 @@ impl Default for MonitorBuilder {
 			name: "TestMonitor".to_string(),
 			networks: vec!["midnight_testnet".to_string()],
 			paused: false,
+			paused_until: None,
+			active_from: None,
+			active_until: None,
+			aggregate_across_networks: false,
+			aggregation_window_ms: None,
+			max_notifications_per_hour: None,
+			throttle_summary: false,
+			cooldown_ms: None,
+			dedup_key: None,
+			persist_cooldown: false,
+			notify_on_resolve: false,
+			resolve_trigger: None,
+			pre_filter_rpc_calls: vec![],
+			cron_schedule: None,
 			addresses: vec![AddressWithSpec {
 				address: "0202000000000000000000000000000000000000000000000000000000000000000000"
 					.to_string(),
 				contract_spec: None,
+				match_mode: AddressMatchMode::Exact,
 			}],
 			match_conditions: MatchConditions {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				token_transfers: vec![],
+				block_conditions: vec![],
+				balance_conditions: vec![],
+				state_conditions: vec![],
 			},
 			trigger_conditions: vec![],
+			condition_logic: ConditionLogic::default(),
 			triggers: vec![],
+			warn_triggers: vec![],
 			chain_configurations: vec![ChainConfiguration {
 				midnight: Some(MidnightMonitorConfig::default()),
 				..Default::default()
@@ -71,11 +111,57 @@ impl MonitorBuilder {
 		self
 	}
 
+	pub fn paused_until(mut self, paused_until: DateTime<Utc>) -> Self {
+		self.paused_until = Some(paused_until);
+		self
+	}
+
+	pub fn active_from(mut self, active_from: DateTime<Utc>) -> Self {
+		self.active_from = Some(active_from);
+		self
+	}
+
+	pub fn active_until(mut self, active_until: DateTime<Utc>) -> Self {
+		self.active_until = Some(active_until);
+		self
+	}
+
+	pub fn aggregate_across_networks(mut self, window_ms: Option<u64>) -> Self {
+		self.aggregate_across_networks = true;
+		self.aggregation_window_ms = window_ms;
+		self
+	}
+
+	pub fn max_notifications_per_hour(mut self, max: u32, throttle_summary: bool) -> Self {
+		self.max_notifications_per_hour = Some(max);
+		self.throttle_summary = throttle_summary;
+		self
+	}
+
+	pub fn cooldown(mut self, cooldown_ms: u64, dedup_key: Option<&str>, persist: bool) -> Self {
+		self.cooldown_ms = Some(cooldown_ms);
+		self.dedup_key = dedup_key.map(String::from);
+		self.persist_cooldown = persist;
+		self
+	}
+
+	pub fn notify_on_resolve(mut self, resolve_trigger: Option<&str>) -> Self {
+		self.notify_on_resolve = true;
+		self.resolve_trigger = resolve_trigger.map(String::from);
+		self
+	}
+
+	pub fn cron_schedule(mut self, cron_schedule: &str) -> Self {
+		self.cron_schedule = Some(cron_schedule.to_string());
+		self
+	}
+
 	/// Add an address to the monitor
 	pub fn address(mut self, address: &str) -> Self {
 		self.addresses = vec![AddressWithSpec {
 			address: address.to_string(),
 			contract_spec: None,
+			match_mode: AddressMatchMode::Exact,
 		}];
 		self
 	}
@@ -87,6 +173,7 @@ impl MonitorBuilder {
 			.map(|addr| AddressWithSpec {
 				address: addr,
 				contract_spec: None,
+				match_mode: AddressMatchMode::Exact,
 			})
 			.collect();
 		self
@@ -97,6 +184,7 @@ impl MonitorBuilder {
 		self.addresses.push(AddressWithSpec {
 			address: address.to_string(),
 			contract_spec: None,
+			match_mode: AddressMatchMode::Exact,
 		});
 		self
 	}
@@ -106,6 +194,8 @@ impl MonitorBuilder {
 		self.match_conditions.functions.push(FunctionCondition {
 			signature: signature.to_string(),
 			expression,
+			warn_threshold_expression: None,
+			eip712_schema: None,
 		});
 		self
 	}
@@ -115,6 +205,7 @@ impl MonitorBuilder {
 		self.match_conditions.events.push(EventCondition {
 			signature: signature.to_string(),
 			expression,
+			warn_threshold_expression: None,
 		});
 		self
 	}
@@ -123,7 +214,12 @@ impl MonitorBuilder {
 	pub fn transaction(mut self, status: TransactionStatus, expression: Option<String>) -> Self {
 		self.match_conditions
 			.transactions
-			.push(TransactionCondition { status, expression });
+			.push(TransactionCondition {
+				status,
+				expression,
+				warn_threshold_expression: None,
+				is_contract_creation: false,
+			});
 		self
 	}
 
@@ -140,16 +236,29 @@ impl MonitorBuilder {
 			timeout_ms,
 			arguments,
 			language,
+			max_memory_mb: None,
+			max_output_bytes: None,
+			include_block_context: false,
 		});
 		self
 	}
 
 	/// Add a trigger to the monitor
+	pub fn condition_logic(mut self, condition_logic: ConditionLogic) -> Self {
+		self.condition_logic = condition_logic;
+		self
+	}
+
 	pub fn triggers(mut self, triggers: Vec<String>) -> Self {
 		self.triggers = triggers;
 		self
 	}
 
+	pub fn warn_triggers(mut self, warn_triggers: Vec<String>) -> Self {
+		self.warn_triggers = warn_triggers;
+		self
+	}
+
 	/// Set the match conditions of the monitor
 	pub fn match_conditions(mut self, match_conditions: MatchConditions) -> Self {
 		self.match_conditions = match_conditions;
@@ -157,16 +266,44 @@ impl MonitorBuilder {
 	}
 
 	/// Build the monitor
+	pub fn pre_filter_rpc_call(mut self, name: &str, method: &str, params: Option<Value>) -> Self {
+		self.pre_filter_rpc_calls.push(RpcCall {
+			name: name.to_string(),
+			method: method.to_string(),
+			params,
+		});
+		self
+	}
+
 	pub fn build(self) -> Monitor {
 		Monitor {
 			name: self.name,
 			networks: self.networks,
 			paused: self.paused,
+			paused_until: self.paused_until,
+			active_from: self.active_from,
+			active_until: self.active_until,
+			cron_schedule: self.cron_schedule,
 			addresses: self.addresses,
 			match_conditions: self.match_conditions,
 			trigger_conditions: self.trigger_conditions,
+			condition_logic: self.condition_logic,
 			triggers: self.triggers,
+			warn_triggers: self.warn_triggers,
 			chain_configurations: self.chain_configurations,
+			pre_filter_rpc_calls: self.pre_filter_rpc_calls,
+			aggregate_across_networks: self.aggregate_across_networks,
+			aggregation_window_ms: self.aggregation_window_ms,
+			max_notifications_per_hour: self.max_notifications_per_hour,
+			throttle_summary: self.throttle_summary,
+			cooldown_ms: self.cooldown_ms,
+			dedup_key: self.dedup_key,
+			persist_cooldown: self.persist_cooldown,
+			notify_on_resolve: self.notify_on_resolve,
+			resolve_trigger: self.resolve_trigger,
+			verify_signature: false,
+			strict_signature_verification: false,
+			rpc_results: Default::default(),
 		}
 	}
 }
@@ -263,9 +400,15 @@ mod tests {
 				functions: vec![FunctionCondition {
 					signature: "transfer(address,uint256)".to_string(),
 					expression: None,
+					warn_threshold_expression: None,
+					eip712_schema: None,
 				}],
 				events: vec![],
 				transactions: vec![],
+				token_transfers: vec![],
+				block_conditions: vec![],
+				balance_conditions: vec![],
+				state_conditions: vec![],
 			})
 			.build();
 		assert_eq!(monitor.match_conditions.functions.len(), 1);
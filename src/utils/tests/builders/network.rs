@@ -1,8 +1,16 @@
 //! Test helper utilities for Network configuration
 //!
 //! - `NetworkBuilder`: Builder for creating test Network instances
+//!
+//! `NetworkBuilder::evm()` and `NetworkBuilder::stellar()` start from networks that pass config
+//! validation, so that tests only set the fields they care about.
+
+use std::collections::HashMap;
 
-use crate::models::{BlockChainType, Network, RpcUrl, SecretString, SecretValue};
+use crate::models::{
+	AdaptivePollingConfig, BlockChainType, EVMBlockTag, EVMTraceMethod, Network, RpcUrl,
+	SecretString, SecretValue,
+};
 
 /// Builder for creating test Network instances
 pub struct NetworkBuilder {
@@ -17,6 +25,22 @@ pub struct NetworkBuilder {
 	confirmation_blocks: u64,
 	cron_schedule: String,
 	max_past_blocks: Option<u64>,
+	stall_threshold_ms: Option<u64>,
+	stall_trigger: Option<String>,
+	monitor_concurrency: Option<usize>,
+	catchup_checkpoint_blocks: Option<u64>,
+	enabled: Option<bool>,
+	trace_method: Option<EVMTraceMethod>,
+	timezone: Option<String>,
+	page_size: Option<u32>,
+	mempool: Option<bool>,
+	block_tag: Option<EVMBlockTag>,
+	auto_past_blocks: Option<bool>,
+	auto_past_blocks_limit: Option<u64>,
+	headers: Option<HashMap<String, String>>,
+	rpc_cache_size: Option<usize>,
+	max_response_bytes: Option<usize>,
+	adaptive_polling: Option<AdaptivePollingConfig>,
 }
 
 impl Default for NetworkBuilder {
@@ -33,6 +57,22 @@ impl Default for NetworkBuilder {
 			confirmation_blocks: 1,
 			cron_schedule: "0 */5 * * * *".to_string(),
 			max_past_blocks: Some(10),
+			stall_threshold_ms: None,
+			stall_trigger: None,
+			monitor_concurrency: None,
+			catchup_checkpoint_blocks: None,
+			enabled: None,
+			trace_method: None,
+			timezone: None,
+			page_size: None,
+			mempool: None,
+			block_tag: None,
+			auto_past_blocks: None,
+			auto_past_blocks_limit: None,
+			headers: None,
+			rpc_cache_size: None,
+			max_response_bytes: None,
+			adaptive_polling: None,
 		}
 	}
 }
@@ -42,6 +82,32 @@ impl NetworkBuilder {
 		Self::default()
 	}
 
+	/// Creates a builder for a valid EVM network, with a single HTTP RPC URL
+	pub fn evm() -> Self {
+		Self::new()
+			.name("Ethereum Mainnet")
+			.slug("ethereum_mainnet")
+			.network_type(BlockChainType::EVM)
+			.chain_id(1)
+			.rpc_url("https://eth.example.com")
+			.block_time_ms(12000)
+			.confirmation_blocks(12)
+	}
+
+	/// Creates a builder for a valid Stellar network, with a single HTTP RPC URL
+	pub fn stellar() -> Self {
+		let mut builder = Self::new()
+			.name("Stellar Mainnet")
+			.slug("stellar_mainnet")
+			.network_type(BlockChainType::Stellar)
+			.network_passphrase("Public Global Stellar Network ; September 2015")
+			.rpc_url("https://soroban.example.com")
+			.block_time_ms(5000)
+			.confirmation_blocks(2);
+		builder.chain_id = None;
+		builder
+	}
+
 	pub fn name(mut self, name: &str) -> Self {
 		self.name = name.to_string();
 		self
@@ -148,6 +214,91 @@ impl NetworkBuilder {
 		self
 	}
 
+	pub fn stall_threshold_ms(mut self, threshold_ms: u64) -> Self {
+		self.stall_threshold_ms = Some(threshold_ms);
+		self
+	}
+
+	pub fn stall_trigger(mut self, trigger: &str) -> Self {
+		self.stall_trigger = Some(trigger.to_string());
+		self
+	}
+
+	pub fn monitor_concurrency(mut self, concurrency: usize) -> Self {
+		self.monitor_concurrency = Some(concurrency);
+		self
+	}
+
+	pub fn catchup_checkpoint_blocks(mut self, blocks: u64) -> Self {
+		self.catchup_checkpoint_blocks = Some(blocks);
+		self
+	}
+
+	pub fn enabled(mut self, enabled: bool) -> Self {
+		self.enabled = Some(enabled);
+		self
+	}
+
+	pub fn timezone(mut self, timezone: &str) -> Self {
+		self.timezone = Some(timezone.to_string());
+		self
+	}
+
+	pub fn trace_method(mut self, trace_method: EVMTraceMethod) -> Self {
+		self.trace_method = Some(trace_method);
+		self
+	}
+
+	pub fn page_size(mut self, page_size: u32) -> Self {
+		self.page_size = Some(page_size);
+		self
+	}
+
+	pub fn mempool(mut self, mempool: bool) -> Self {
+		self.mempool = Some(mempool);
+		self
+	}
+
+	pub fn block_tag(mut self, block_tag: EVMBlockTag) -> Self {
+		self.block_tag = Some(block_tag);
+		self
+	}
+
+	pub fn auto_past_blocks(mut self, auto_past_blocks: bool) -> Self {
+		self.auto_past_blocks = Some(auto_past_blocks);
+		self
+	}
+
+	pub fn auto_past_blocks_limit(mut self, limit: u64) -> Self {
+		self.auto_past_blocks_limit = Some(limit);
+		self
+	}
+
+	pub fn header(mut self, name: &str, value: &str) -> Self {
+		self.headers
+			.get_or_insert_with(HashMap::new)
+			.insert(name.to_string(), value.to_string());
+		self
+	}
+
+	pub fn rpc_cache_size(mut self, rpc_cache_size: usize) -> Self {
+		self.rpc_cache_size = Some(rpc_cache_size);
+		self
+	}
+
+	pub fn max_response_bytes(mut self, max_response_bytes: usize) -> Self {
+		self.max_response_bytes = Some(max_response_bytes);
+		self
+	}
+
+	pub fn adaptive_polling(mut self, min_interval_ms: u64, max_interval_ms: u64) -> Self {
+		self.adaptive_polling = Some(AdaptivePollingConfig {
+			min_interval_ms,
+			max_interval_ms,
+		});
+		self
+	}
+
 	pub fn build(self) -> Network {
 		Network {
 			name: self.name,
@@ -161,6 +312,22 @@ impl NetworkBuilder {
 			confirmation_blocks: self.confirmation_blocks,
 			cron_schedule: self.cron_schedule,
 			max_past_blocks: self.max_past_blocks,
+			stall_threshold_ms: self.stall_threshold_ms,
+			stall_trigger: self.stall_trigger,
+			monitor_concurrency: self.monitor_concurrency,
+			catchup_checkpoint_blocks: self.catchup_checkpoint_blocks,
+			enabled: self.enabled,
+			trace_method: self.trace_method,
+			timezone: self.timezone,
+			page_size: self.page_size,
+			mempool: self.mempool,
+			block_tag: self.block_tag,
+			auto_past_blocks: self.auto_past_blocks,
+			auto_past_blocks_limit: self.auto_past_blocks_limit,
+			headers: self.headers,
+			rpc_cache_size: self.rpc_cache_size,
+			max_response_bytes: self.max_response_bytes,
+			adaptive_polling: self.adaptive_polling,
 		}
 	}
 }
@@ -168,6 +335,7 @@ impl NetworkBuilder {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::models::ConfigLoader;
 
 	#[test]
 	fn test_default_network() {
@@ -299,4 +467,35 @@ mod tests {
 		);
 		assert_eq!(network.chain_id, Some(1)); // From default
 	}
+
+	#[test]
+	fn test_built_networks_pass_validation() {
+		let evm = NetworkBuilder::evm().build();
+		assert_eq!(evm.network_type, BlockChainType::EVM);
+		assert_eq!(evm.chain_id, Some(1));
+		assert_eq!(evm.rpc_urls.len(), 1);
+		assert!(evm.validate().is_ok());
+
+		let stellar = NetworkBuilder::stellar().build();
+		assert_eq!(stellar.network_type, BlockChainType::Stellar);
+		assert_eq!(stellar.chain_id, None);
+		assert!(stellar.network_passphrase.is_some());
+		assert!(stellar.validate().is_ok());
+
+		let custom = NetworkBuilder::evm()
+			.slug("arbitrum_one")
+			.chain_id(42161)
+			.clear_rpc_urls()
+			.add_rpc_url("https://arb1.example.com", "rpc", 80)
+			.add_rpc_url("https://arb-archive.example.com", "archive", 20)
+			.block_time_ms(250)
+			.confirmation_blocks(1)
+			.cron_schedule("*/30 * * * * *")
+			.build();
+		assert_eq!(custom.rpc_urls[1].type_, "archive");
+		assert!(custom.validate().is_ok());
+
+		let invalid = NetworkBuilder::evm().slug("Ethereum-Mainnet").build();
+		assert!(invalid.validate().is_err());
+	}
 }
@@ -4,18 +4,24 @@
 
 use crate::{
 	models::{
-		NotificationMessage, ScriptLanguage, SecretString, SecretValue, Trigger, TriggerType,
-		TriggerTypeConfig,
+		DiscordEmbed, FileFormat, KafkaSaslConfig, KafkaSaslMechanism, NotificationMessage,
+		NotificationSeverity, OpsgeniePriority, OpsgenieRegion, ScriptLanguage, SecretString,
+		SecretValue, Trigger, TriggerType, TriggerTypeConfig, WebhookSignatureAlgorithm,
 	},
 	utils::RetryConfig,
 };
 use email_address::EmailAddress;
+use std::collections::HashMap;
 
 /// Builder for creating test Trigger instances
 pub struct TriggerBuilder {
 	name: String,
 	trigger_type: TriggerType,
 	config: TriggerTypeConfig,
+	severity: Option<NotificationSeverity>,
+	labels: HashMap<String, String>,
+	batch_window_ms: Option<u64>,
+	enabled: Option<bool>,
 }
 
 impl Default for TriggerBuilder {
@@ -35,7 +41,17 @@ impl Default for TriggerBuilder {
 					body: "Test message".to_string(),
 				},
 				retry_policy: RetryConfig::default(),
+				timeout_ms: None,
+				include_raw_match: false,
+				trim_raw_match: false,
+				signature_algorithm: WebhookSignatureAlgorithm::default(),
+				signature_header: None,
+				signature_algorithm_header: None,
 			},
+			severity: None,
+			labels: HashMap::new(),
+			batch_window_ms: None,
+			enabled: None,
 		}
 	}
 }
@@ -55,6 +71,26 @@ impl TriggerBuilder {
 		self
 	}
 
+	pub fn severity(mut self, severity: NotificationSeverity) -> Self {
+		self.severity = Some(severity);
+		self
+	}
+
+	pub fn label(mut self, key: &str, value: &str) -> Self {
+		self.labels.insert(key.to_string(), value.to_string());
+		self
+	}
+
+	pub fn batch_window_ms(mut self, batch_window_ms: u64) -> Self {
+		self.batch_window_ms = Some(batch_window_ms);
+		self
+	}
+
+	pub fn enabled(mut self, enabled: bool) -> Self {
+		self.enabled = Some(enabled);
+		self
+	}
+
 	pub fn webhook(mut self, url: &str) -> Self {
 		self.trigger_type = TriggerType::Webhook;
 		self.config = TriggerTypeConfig::Webhook {
@@ -67,6 +103,12 @@ impl TriggerBuilder {
 				body: "Test message".to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
+			include_raw_match: false,
+			trim_raw_match: false,
+			signature_algorithm: WebhookSignatureAlgorithm::default(),
+			signature_header: None,
+			signature_algorithm_header: None,
 		};
 		self
 	}
@@ -79,11 +121,20 @@ impl TriggerBuilder {
 				title: "Alert".to_string(),
 				body: "Test message".to_string(),
 			},
+			blocks: None,
 			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
 		};
 		self
 	}
 
+	pub fn slack_blocks(mut self, blocks: Vec<serde_json::Value>) -> Self {
+		if let TriggerTypeConfig::Slack { blocks: b, .. } = &mut self.config {
+			*b = Some(blocks);
+		}
+		self
+	}
+
 	pub fn discord(mut self, webhook_url: &str) -> Self {
 		self.trigger_type = TriggerType::Discord;
 		self.config = TriggerTypeConfig::Discord {
@@ -92,22 +143,34 @@ impl TriggerBuilder {
 				title: "Alert".to_string(),
 				body: "Test message".to_string(),
 			},
+			embed: None,
 			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
 		};
 		self
 	}
 
+	pub fn discord_embed(mut self, embed: DiscordEmbed) -> Self {
+		if let TriggerTypeConfig::Discord { embed: e, .. } = &mut self.config {
+			*e = Some(embed);
+		}
+		self
+	}
+
 	pub fn telegram(mut self, token: &str, chat_id: &str, disable_web_preview: bool) -> Self {
 		self.trigger_type = TriggerType::Telegram;
 		self.config = TriggerTypeConfig::Telegram {
 			token: SecretValue::Plain(SecretString::new(token.to_string())),
 			chat_id: chat_id.to_string(),
 			disable_web_preview: Some(disable_web_preview),
+			message_thread_id: None,
+			parse_mode: None,
 			message: NotificationMessage {
 				title: "Test title".to_string(),
 				body: "Test message".to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
 		};
 		self
 	}
@@ -119,6 +182,92 @@ impl TriggerBuilder {
 		self
 	}
 
+	pub fn opsgenie(mut self, api_key: &str) -> Self {
+		self.trigger_type = TriggerType::Opsgenie;
+		self.config = TriggerTypeConfig::Opsgenie {
+			api_key: SecretValue::Plain(SecretString::new(api_key.to_string())),
+			region: OpsgenieRegion::default(),
+			priority: OpsgeniePriority::default(),
+			alias: None,
+			message: NotificationMessage {
+				title: "Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
+		};
+		self
+	}
+
+	pub fn opsgenie_alias(mut self, alias: &str) -> Self {
+		if let TriggerTypeConfig::Opsgenie { alias: a, .. } = &mut self.config {
+			*a = Some(alias.to_string());
+		}
+		self
+	}
+
+	pub fn opsgenie_priority(mut self, priority: OpsgeniePriority) -> Self {
+		if let TriggerTypeConfig::Opsgenie { priority: p, .. } = &mut self.config {
+			*p = priority;
+		}
+		self
+	}
+
+	pub fn file(mut self, path: &str) -> Self {
+		self.trigger_type = TriggerType::File;
+		self.config = TriggerTypeConfig::File {
+			path: path.to_string(),
+			format: FileFormat::default(),
+			rotate_max_bytes: None,
+		};
+		self
+	}
+
+	pub fn file_format(mut self, format: FileFormat) -> Self {
+		if let TriggerTypeConfig::File { format: f, .. } = &mut self.config {
+			*f = format;
+		}
+		self
+	}
+
+	pub fn file_rotate_max_bytes(mut self, max_bytes: u64) -> Self {
+		if let TriggerTypeConfig::File {
+			rotate_max_bytes: r,
+			..
+		} = &mut self.config
+		{
+			*r = Some(max_bytes);
+		}
+		self
+	}
+
+	pub fn kafka(mut self, brokers: Vec<&str>, topic: &str) -> Self {
+		self.trigger_type = TriggerType::Kafka;
+		self.config = TriggerTypeConfig::Kafka {
+			brokers: brokers.into_iter().map(|b| b.to_string()).collect(),
+			topic: topic.to_string(),
+			sasl: None,
+			timeout_ms: None,
+		};
+		self
+	}
+
+	pub fn kafka_sasl(
+		mut self,
+		mechanism: KafkaSaslMechanism,
+		username: &str,
+		password: &str,
+	) -> Self {
+		if let TriggerTypeConfig::Kafka { sasl, .. } = &mut self.config {
+			*sasl = Some(KafkaSaslConfig {
+				mechanism,
+				username: SecretValue::Plain(SecretString::new(username.to_string())),
+				password: SecretValue::Plain(SecretString::new(password.to_string())),
+			});
+		}
+		self
+	}
+
 	pub fn script(mut self, script_path: &str, language: ScriptLanguage) -> Self {
 		self.trigger_type = TriggerType::Script;
 		self.config = TriggerTypeConfig::Script {
@@ -126,6 +275,8 @@ impl TriggerBuilder {
 			arguments: None,
 			language,
 			timeout_ms: 1000,
+			max_memory_mb: None,
+			max_output_bytes: None,
 		};
 		self
 	}
@@ -150,6 +301,7 @@ impl TriggerBuilder {
 			| TriggerTypeConfig::Slack { message, .. }
 			| TriggerTypeConfig::Discord { message, .. }
 			| TriggerTypeConfig::Telegram { message, .. }
+			| TriggerTypeConfig::Opsgenie { message, .. }
 			| TriggerTypeConfig::Email { message, .. } => {
 				message.title = title.to_string();
 				message.body = body.to_string();
@@ -159,6 +311,22 @@ impl TriggerBuilder {
 		self
 	}
 
+	pub fn timeout_ms(mut self, timeout_ms: u32) -> Self {
+		match &mut self.config {
+			TriggerTypeConfig::Webhook { timeout_ms: t, .. }
+			| TriggerTypeConfig::Slack { timeout_ms: t, .. }
+			| TriggerTypeConfig::Discord { timeout_ms: t, .. }
+			| TriggerTypeConfig::Telegram { timeout_ms: t, .. }
+			| TriggerTypeConfig::Opsgenie { timeout_ms: t, .. }
+			| TriggerTypeConfig::Kafka { timeout_ms: t, .. }
+			| TriggerTypeConfig::Email { timeout_ms: t, .. } => {
+				*t = Some(timeout_ms);
+			}
+			_ => {}
+		}
+		self
+	}
+
 	pub fn trigger_type(mut self, trigger_type: TriggerType) -> Self {
 		self.trigger_type = trigger_type;
 		self
@@ -187,7 +355,11 @@ impl TriggerBuilder {
 				.into_iter()
 				.map(EmailAddress::new_unchecked)
 				.collect(),
+			cc: vec![],
+			bcc: vec![],
+			is_html: false,
 			retry_policy: RetryConfig::default(),
+			timeout_ms: None,
 		};
 		self
 	}
@@ -220,6 +392,27 @@ impl TriggerBuilder {
 		self
 	}
 
+	pub fn email_cc(mut self, cc: Vec<&str>) -> Self {
+		if let TriggerTypeConfig::Email { cc: c, .. } = &mut self.config {
+			*c = cc.into_iter().map(EmailAddress::new_unchecked).collect();
+		}
+		self
+	}
+
+	pub fn email_bcc(mut self, bcc: Vec<&str>) -> Self {
+		if let TriggerTypeConfig::Email { bcc: b, .. } = &mut self.config {
+			*b = bcc.into_iter().map(EmailAddress::new_unchecked).collect();
+		}
+		self
+	}
+
+	pub fn email_html(mut self, is_html: bool) -> Self {
+		if let TriggerTypeConfig::Email { is_html: h, .. } = &mut self.config {
+			*h = is_html;
+		}
+		self
+	}
+
 	pub fn webhook_method(mut self, method: &str) -> Self {
 		if let TriggerTypeConfig::Webhook { method: m, .. } = &mut self.config {
 			*m = Some(method.to_string());
@@ -241,6 +434,46 @@ impl TriggerBuilder {
 		self
 	}
 
+	pub fn webhook_retry_policy(mut self, policy: RetryConfig) -> Self {
+		if let TriggerTypeConfig::Webhook { retry_policy, .. } = &mut self.config {
+			*retry_policy = policy;
+		}
+		self
+	}
+
+	pub fn webhook_raw_match(mut self, include: bool, trim: bool) -> Self {
+		if let TriggerTypeConfig::Webhook {
+			include_raw_match,
+			trim_raw_match,
+			..
+		} = &mut self.config
+		{
+			*include_raw_match = include;
+			*trim_raw_match = trim;
+		}
+		self
+	}
+
+	pub fn webhook_signature(
+		mut self,
+		algorithm: WebhookSignatureAlgorithm,
+		header: Option<&str>,
+		algorithm_header: Option<&str>,
+	) -> Self {
+		if let TriggerTypeConfig::Webhook {
+			signature_algorithm,
+			signature_header,
+			signature_algorithm_header,
+			..
+		} = &mut self.config
+		{
+			*signature_algorithm = algorithm;
+			*signature_header = header.map(str::to_string);
+			*signature_algorithm_header = algorithm_header.map(str::to_string);
+		}
+		self
+	}
+
 	pub fn url(mut self, url: SecretValue) -> Self {
 		self.config = match self.config {
 			TriggerTypeConfig::Webhook {
@@ -250,6 +483,12 @@ impl TriggerBuilder {
 				secret,
 				message,
 				retry_policy,
+				timeout_ms,
+				include_raw_match,
+				trim_raw_match,
+				signature_algorithm,
+				signature_header,
+				signature_algorithm_header,
 			} => TriggerTypeConfig::Webhook {
 				url,
 				method,
@@ -257,24 +496,38 @@ impl TriggerBuilder {
 				secret,
 				message,
 				retry_policy,
+				timeout_ms,
+				include_raw_match,
+				trim_raw_match,
+				signature_algorithm,
+				signature_header,
+				signature_algorithm_header,
 			},
 			TriggerTypeConfig::Discord {
 				discord_url: _,
 				message,
+				embed,
 				retry_policy,
+				timeout_ms,
 			} => TriggerTypeConfig::Discord {
 				discord_url: url,
 				message,
+				embed,
 				retry_policy,
+				timeout_ms,
 			},
 			TriggerTypeConfig::Slack {
 				slack_url: _,
 				message,
+				blocks,
 				retry_policy,
+				timeout_ms,
 			} => TriggerTypeConfig::Slack {
 				slack_url: url,
 				message,
+				blocks,
 				retry_policy,
+				timeout_ms,
 			},
 			config => config,
 		};
@@ -286,6 +539,10 @@ impl TriggerBuilder {
 			name: self.name,
 			trigger_type: self.trigger_type,
 			config: self.config,
+			severity: self.severity,
+			labels: self.labels,
+			batch_window_ms: self.batch_window_ms,
+			enabled: self.enabled,
 		}
 	}
 }
@@ -326,6 +583,12 @@ mod tests {
 					body: "Test message".to_string(),
 				},
 				retry_policy: RetryConfig::default(),
+				timeout_ms: None,
+				include_raw_match: false,
+				trim_raw_match: false,
+				signature_algorithm: WebhookSignatureAlgorithm::default(),
+				signature_header: None,
+				signature_algorithm_header: None,
 			})
 			.build();
 
@@ -389,6 +652,12 @@ mod tests {
 				headers: h,
 				message,
 				retry_policy: _,
+				timeout_ms: _,
+				include_raw_match: _,
+				trim_raw_match: _,
+				signature_algorithm: _,
+				signature_header: _,
+				signature_algorithm_header: _,
 			} => {
 				assert_eq!(url.as_ref().to_string(), "https://webhook.example.com");
 				assert_eq!(method, Some("POST".to_string()));
@@ -417,7 +686,9 @@ mod tests {
 			TriggerTypeConfig::Slack {
 				slack_url,
 				message,
+				blocks: _,
 				retry_policy: _,
+				timeout_ms: _,
 			} => {
 				assert_eq!(slack_url.as_ref().to_string(), "https://slack.webhook.com");
 				assert_eq!(message.title, "Alert");
@@ -440,7 +711,9 @@ mod tests {
 			TriggerTypeConfig::Discord {
 				discord_url,
 				message,
+				embed,
 				retry_policy: _,
+				timeout_ms: _,
 			} => {
 				assert_eq!(
 					discord_url.as_ref().to_string(),
@@ -448,11 +721,95 @@ mod tests {
 				);
 				assert_eq!(message.title, "Alert");
 				assert_eq!(message.body, "Test message");
+				assert_eq!(embed, None);
 			}
 			_ => panic!("Expected discord config"),
 		}
 	}
 
+	#[test]
+	fn test_opsgenie_trigger() {
+		let trigger = TriggerBuilder::new()
+			.name("opsgenie_alert")
+			.opsgenie("api-key")
+			.opsgenie_alias("${monitor.name}")
+			.opsgenie_priority(OpsgeniePriority::P1)
+			.message("Alert", "Test message")
+			.build();
+
+		assert_eq!(trigger.trigger_type, TriggerType::Opsgenie);
+		match trigger.config {
+			TriggerTypeConfig::Opsgenie {
+				api_key,
+				region,
+				priority,
+				alias,
+				message,
+				..
+			} => {
+				assert_eq!(api_key.as_ref().to_string(), "api-key");
+				assert_eq!(region, OpsgenieRegion::Us);
+				assert_eq!(priority, OpsgeniePriority::P1);
+				assert_eq!(alias, Some("${monitor.name}".to_string()));
+				assert_eq!(message.title, "Alert");
+				assert_eq!(message.body, "Test message");
+			}
+			_ => panic!("Expected opsgenie config"),
+		}
+	}
+
+	#[test]
+	fn test_kafka_trigger() {
+		let trigger = TriggerBuilder::new()
+			.name("matches_kafka")
+			.kafka(vec!["localhost:9092"], "monitor-matches")
+			.kafka_sasl(KafkaSaslMechanism::ScramSha512, "user", "pass")
+			.timeout_ms(2000)
+			.build();
+
+		assert_eq!(trigger.trigger_type, TriggerType::Kafka);
+		match trigger.config {
+			TriggerTypeConfig::Kafka {
+				brokers,
+				topic,
+				sasl,
+				timeout_ms,
+			} => {
+				assert_eq!(brokers, vec!["localhost:9092"]);
+				assert_eq!(topic, "monitor-matches");
+				let sasl = sasl.unwrap();
+				assert_eq!(sasl.mechanism, KafkaSaslMechanism::ScramSha512);
+				assert_eq!(sasl.username.as_ref().to_string(), "user");
+				assert_eq!(timeout_ms, Some(2000));
+			}
+			_ => panic!("Expected kafka config"),
+		}
+	}
+
+	#[test]
+	fn test_file_trigger() {
+		let trigger = TriggerBuilder::new()
+			.name("audit_file")
+			.file("data/audit.csv")
+			.file_format(FileFormat::Csv)
+			.file_rotate_max_bytes(1024)
+			.build();
+
+		assert_eq!(trigger.trigger_type, TriggerType::File);
+		match trigger.config {
+			TriggerTypeConfig::File {
+				path,
+				format,
+				rotate_max_bytes,
+			} => {
+				assert_eq!(path, "data/audit.csv");
+				assert_eq!(format, FileFormat::Csv);
+				assert_eq!(rotate_max_bytes, Some(1024));
+			}
+			_ => panic!("Expected file config"),
+		}
+	}
+
 	#[test]
 	fn test_script_trigger() {
 		let trigger = TriggerBuilder::new()
@@ -555,6 +912,9 @@ mod tests {
 			)
 			.email_port(465)
 			.email_subject("Custom Subject")
+			.email_cc(vec!["cc@example.com"])
+			.email_bcc(vec!["bcc@example.com"])
+			.email_html(true)
 			.build();
 
 		assert_eq!(trigger.trigger_type, TriggerType::Email);
@@ -567,6 +927,9 @@ mod tests {
 				message,
 				sender,
 				recipients,
+				cc,
+				bcc,
+				is_html,
 				..
 			} => {
 				assert_eq!(host, "smtp.example.com");
@@ -577,6 +940,9 @@ mod tests {
 				assert_eq!(sender.as_str(), "sender@example.com");
 				assert_eq!(recipients.len(), 1);
 				assert_eq!(recipients[0].as_str(), "recipient@example.com");
+				assert_eq!(cc[0].as_str(), "cc@example.com");
+				assert_eq!(bcc[0].as_str(), "bcc@example.com");
+				assert!(is_html);
 			}
 			_ => panic!("Expected email config"),
 		}
@@ -0,0 +1,187 @@
+//! Peak memory of the parsing of large block responses.
+//!
+//! Blocks are deserialized straight from the response body into typed results. These tests
+//! compare the peak memory this takes for synthetic large blocks with the previous approach, which
+//! parsed the response into a JSON value and then deserialized a copy of its result.
+//!
+//! Measuring allocations requires a global allocator, hence a test binary of its own.
+
+use alloy::primitives::Bytes;
+use openzeppelin_monitor::{
+	models::{EVMBlock, StellarBlock},
+	services::blockchain::JsonRpcResponse,
+	utils::tests::evm::transaction::TransactionBuilder,
+};
+use serde_json::{json, Value};
+use std::{
+	alloc::{GlobalAlloc, Layout, System},
+	cell::Cell,
+};
+
+/// Allocator keeping track of the bytes allocated by each thread
+struct TrackingAllocator;
+
+thread_local! {
+	/// Bytes currently allocated by the thread
+	static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+	/// Highest number of bytes allocated by the thread since the last reset
+	static PEAK: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let ptr = System.alloc(layout);
+		if !ptr.is_null() {
+			let _ = ALLOCATED.try_with(|allocated| {
+				allocated.set(allocated.get() + layout.size());
+				let _ = PEAK.try_with(|peak| peak.set(peak.get().max(allocated.get())));
+			});
+		}
+		ptr
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout);
+		let _ = ALLOCATED
+			.try_with(|allocated| allocated.set(allocated.get().saturating_sub(layout.size())));
+	}
+}
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+/// Runs a function, returning its output and the peak of the bytes the thread allocated on top
+/// of the ones allocated before the call
+fn measure_peak<T>(f: impl FnOnce() -> T) -> (T, usize) {
+	let baseline = ALLOCATED.with(Cell::get);
+	PEAK.with(|peak| peak.set(baseline));
+	let output = f();
+	(output, PEAK.with(Cell::get) - baseline)
+}
+
+/// Builds the `eth_getBlockByNumber` response body of a block with large transactions
+fn create_evm_block_response(transaction_count: usize) -> Vec<u8> {
+	let transaction = TransactionBuilder::new()
+		.input(Bytes::from(vec![0xab; 1024]))
+		.build();
+	let zero_hash = format!("0x{}", "0".repeat(64));
+
+	serde_json::to_vec(&json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"result": {
+			"hash": zero_hash,
+			"parentHash": zero_hash,
+			"stateRoot": zero_hash,
+			"transactionsRoot": zero_hash,
+			"receiptsRoot": zero_hash,
+			"number": "0x1",
+			"gasUsed": "0x0",
+			"extraData": "0x",
+			"timestamp": "0x0",
+			"transactions": vec![transaction; transaction_count],
+		}
+	}))
+	.unwrap()
+}
+
+/// Builds the `getLedgers` response body of a page of ledgers with large metadata
+fn create_stellar_ledgers_response(ledger_count: u32) -> Vec<u8> {
+	let ledgers: Vec<Value> = (1..=ledger_count)
+		.map(|sequence| {
+			json!({
+				"hash": format!("{:064x}", sequence),
+				"sequence": sequence,
+				"ledgerCloseTime": "1734370000",
+				"headerXdr": "AAAA",
+				"metadataXdr": "A".repeat(64 * 1024),
+			})
+		})
+		.collect();
+
+	serde_json::to_vec(&json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"result": {
+			"ledgers": ledgers,
+			"cursor": ledger_count.to_string(),
+		}
+	}))
+	.unwrap()
+}
+
+#[test]
+fn test_evm_block_parsing_peak_memory() {
+	let body = create_evm_block_response(2_000);
+
+	let (value_block, value_peak) = measure_peak(|| {
+		let response: Value = serde_json::from_slice(&body).unwrap();
+		serde_json::from_value::<EVMBlock>(response["result"].clone()).unwrap()
+	});
+	let (typed_block, typed_peak) = measure_peak(|| {
+		serde_json::from_slice::<JsonRpcResponse<Option<EVMBlock>>>(&body)
+			.unwrap()
+			.result
+			.flatten()
+			.unwrap()
+	});
+
+	assert_eq!(typed_block.transactions.len(), 2_000);
+	assert_eq!(
+		serde_json::to_value(&typed_block.transactions).unwrap(),
+		serde_json::to_value(&value_block.transactions).unwrap()
+	);
+	assert!(
+		typed_peak < value_peak,
+		"typed parsing peaked at {} bytes, value parsing at {} bytes",
+		typed_peak,
+		value_peak
+	);
+}
+
+#[test]
+fn test_stellar_ledgers_parsing_peak_memory() {
+	let body = create_stellar_ledgers_response(200);
+
+	#[derive(serde::Deserialize)]
+	struct LedgersPage {
+		ledgers: Vec<StellarBlock>,
+	}
+
+	let (value_ledgers, value_peak) = measure_peak(|| {
+		let response: Value = serde_json::from_slice(&body).unwrap();
+		serde_json::from_value::<Vec<StellarBlock>>(response["result"]["ledgers"].clone()).unwrap()
+	});
+	let (typed_ledgers, typed_peak) = measure_peak(|| {
+		serde_json::from_slice::<JsonRpcResponse<LedgersPage>>(&body)
+			.unwrap()
+			.result
+			.unwrap()
+			.ledgers
+	});
+
+	assert_eq!(typed_ledgers.len(), 200);
+	assert_eq!(typed_ledgers[199].hash, value_ledgers[199].hash);
+	assert!(
+		typed_peak < value_peak,
+		"typed parsing peaked at {} bytes, value parsing at {} bytes",
+		typed_peak,
+		value_peak
+	);
+}
+
+#[test]
+fn test_json_rpc_response_tells_null_result_from_missing_one() {
+	let null_result: JsonRpcResponse<Option<EVMBlock>> =
+		serde_json::from_value(json!({"jsonrpc": "2.0", "id": 1, "result": null})).unwrap();
+	assert!(matches!(null_result.result, Some(None)));
+
+	let error: JsonRpcResponse<Option<EVMBlock>> = serde_json::from_value(json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"error": {"code": -32000, "message": "header not found"}
+	}))
+	.unwrap();
+	assert!(error.result.is_none());
+	assert_eq!(error.error.unwrap()["code"], -32000);
+}
@@ -16,6 +16,9 @@ mod integration {
 			mod midnight {
 				mod client;
 			}
+			mod solana {
+				mod client;
+			}
 		}
 		mod transports {
 			mod evm {
@@ -47,7 +50,14 @@ mod integration {
 	mod mocks;
 
 	mod blockwatcher {
+		mod leader;
+		mod mempool;
 		mod service;
+		mod storage;
+	}
+	#[cfg(feature = "grpc")]
+	mod grpc {
+		mod server;
 	}
 	mod filters {
 		pub mod common;
@@ -62,8 +72,15 @@ mod integration {
 		}
 	}
 	mod notifications {
+		mod discord;
 		mod email;
+		mod file;
+		#[cfg(feature = "kafka")]
+		mod kafka;
+		mod opsgenie;
 		mod script;
+		mod slack;
+		mod telegram;
 		mod webhook;
 	}
 	mod monitor {
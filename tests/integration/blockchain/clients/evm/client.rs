@@ -10,9 +10,11 @@ use alloy::{
 use mockall::predicate;
 use mockito::Server;
 use openzeppelin_monitor::{
-	models::{BlockType, EVMBlock, EVMReceiptLog, EVMTransactionReceipt},
-	services::blockchain::{BlockChainClient, EvmClient, EvmClientTrait},
+	models::{BlockType, EVMBlock, EVMBlockTag, EVMReceiptLog, EVMTransactionReceipt},
+	services::blockchain::{BlockChainClient, ContractCallResult, EvmClient, EvmClientTrait},
+	utils::tests::builders::{evm::receipt::ReceiptBuilder, network::NetworkBuilder},
 };
+use serde_json::json;
 
 #[tokio::test]
 async fn test_get_transaction_receipt() {
@@ -46,6 +48,39 @@ async fn test_get_transaction_receipt() {
 	assert_eq!(result.unwrap().transaction_hash, B256::ZERO);
 }
 
+#[tokio::test]
+async fn test_get_transaction_receipts_in_order() {
+	let hashes: Vec<B256> = (1..=3u8).map(|byte| B256::repeat_byte(byte)).collect();
+
+	// Transports without batch support send the requests one after the other
+	let mut mock_transport = MockEVMTransportClient::new();
+	mock_transport
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("eth_getTransactionReceipt"),
+			predicate::always(),
+		)
+		.times(3)
+		.returning(|_, params: Option<Vec<serde_json::Value>>| {
+			let hash: B256 = serde_json::from_value(params.unwrap()[0].clone()).unwrap();
+			let receipt = ReceiptBuilder::new().transaction_hash(hash).build();
+			Ok(json!({"jsonrpc": "2.0", "id": 1, "result": receipt}))
+		});
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let receipts = client
+		.get_transaction_receipts(hashes.iter().map(|hash| format!("{:?}", hash)).collect())
+		.await
+		.unwrap();
+	assert_eq!(
+		receipts
+			.iter()
+			.map(|receipt| receipt.transaction_hash)
+			.collect::<Vec<_>>(),
+		hashes
+	);
+}
+
 #[tokio::test]
 async fn test_get_logs_for_blocks() {
 	let mut mock = MockEvmClientTrait::<MockEVMTransportClient>::new();
@@ -91,6 +126,68 @@ async fn test_get_latest_block_number() {
 	assert_eq!(result.unwrap(), 100u64);
 }
 
+#[tokio::test]
+async fn test_get_latest_confirmed_block_number_by_block_tag() {
+	// The latest block is 100 and the safe and finalized blocks are 70 and 40
+	for (block_tag, expected_block) in [
+		(None, 90),
+		(Some(EVMBlockTag::Latest), 90),
+		(Some(EVMBlockTag::Safe), 70),
+		(Some(EVMBlockTag::Finalized), 40),
+	] {
+		let mut mock_transport = MockEVMTransportClient::new();
+		match block_tag {
+			Some(tag @ (EVMBlockTag::Safe | EVMBlockTag::Finalized)) => {
+				mock_transport
+					.expect_send_raw_request()
+					.with(
+						predicate::eq("eth_getBlockByNumber"),
+						predicate::eq(Some(vec![json!(tag.as_str()), json!(false)])),
+					)
+					.times(1)
+					.returning(move |_, _| {
+						let number = format!("0x{:x}", expected_block);
+						Ok(json!({"jsonrpc": "2.0", "id": 1, "result": {"number": number}}))
+					});
+			}
+			_ => {
+				mock_transport
+					.expect_send_raw_request()
+					.with(predicate::eq("eth_blockNumber"), predicate::always())
+					.times(1)
+					.returning(|_, _| Ok(json!({"jsonrpc": "2.0", "id": 1, "result": "0x64"})));
+			}
+		}
+
+		// Confirmation blocks only apply to the latest block
+		let mut network = NetworkBuilder::new().confirmation_blocks(10).build();
+		network.block_tag = block_tag;
+		let client = EvmClient::new_with_transport(mock_transport);
+
+		let result = client.get_latest_confirmed_block_number(&network).await;
+
+		assert_eq!(result.unwrap(), expected_block, "{:?}", block_tag);
+	}
+}
+
+#[tokio::test]
+async fn test_get_latest_confirmed_block_number_missing_tagged_block() {
+	let mut mock_transport = MockEVMTransportClient::new();
+	mock_transport
+		.expect_send_raw_request()
+		.times(1)
+		.returning(|_, _| Ok(json!({"jsonrpc": "2.0", "id": 1, "result": null})));
+
+	let network = NetworkBuilder::new()
+		.block_tag(EVMBlockTag::Finalized)
+		.build();
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let result = client.get_latest_confirmed_block_number(&network).await;
+
+	assert!(result.is_err());
+}
+
 #[tokio::test]
 async fn test_get_blocks() {
 	let mut mock = MockEvmClientTrait::<MockEVMTransportClient>::new();
@@ -140,3 +237,96 @@ async fn test_new_client() {
 	assert!(result.is_ok(), "Client creation should succeed");
 	mock.assert();
 }
+
+#[tokio::test]
+async fn test_call_contract() {
+	let to = "0x0000000000000000000000000000000000000001";
+	for (response, expected) in [
+		(
+			json!({"jsonrpc": "2.0", "id": 1, "result": "0x0001"}),
+			Some(ContractCallResult::Returned(vec![0, 1])),
+		),
+		(
+			json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"error": {"code": 3, "message": "execution reverted"}
+			}),
+			Some(ContractCallResult::Reverted(
+				"execution reverted".to_string(),
+			)),
+		),
+		(
+			json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"error": {"code": -32000, "message": "header not found"}
+			}),
+			None,
+		),
+	] {
+		let mut mock_transport = MockEVMTransportClient::new();
+		mock_transport
+			.expect_send_raw_request()
+			.with(
+				predicate::eq("eth_call"),
+				predicate::eq(Some(vec![
+					json!({"to": to, "data": "0x5c975abb"}),
+					json!("0x64"),
+				])),
+			)
+			.times(1)
+			.returning(move |_, _| Ok(response.clone()));
+		let client = EvmClient::new_with_transport(mock_transport);
+
+		let result = client
+			.call_contract(to, vec![0x5c, 0x97, 0x5a, 0xbb], 100)
+			.await;
+
+		assert_eq!(result.ok(), expected);
+	}
+}
+
+#[tokio::test]
+async fn test_get_code() {
+	let address = "0x0000000000000000000000000000000000000001";
+	let mut mock_transport = MockEVMTransportClient::new();
+	mock_transport
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("eth_getCode"),
+			predicate::eq(Some(vec![json!(address), json!("0x64")])),
+		)
+		.times(1)
+		.returning(|_, _| Ok(json!({"jsonrpc": "2.0", "id": 1, "result": "0x6080"})));
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let code = client.get_code(address, 100).await.unwrap();
+
+	assert_eq!(code, vec![0x60, 0x80]);
+}
+
+#[tokio::test]
+async fn test_get_revert_data() {
+	let cases = vec![
+		(
+			json!({"from": "0x01", "error": "execution reverted", "output": "0xf4d678b8"}),
+			Some(vec![0xf4, 0xd6, 0x78, 0xb8]),
+		),
+		(json!({"from": "0x01", "output": "0x"}), None),
+	];
+
+	for (trace, expected) in cases {
+		let mut mock_transport = MockEVMTransportClient::new();
+		mock_transport
+			.expect_send_raw_request()
+			.with(predicate::eq("debug_traceTransaction"), predicate::always())
+			.times(1)
+			.returning(move |_, _| Ok(json!({"jsonrpc": "2.0", "id": 1, "result": trace.clone()})));
+		let client = EvmClient::new_with_transport(mock_transport);
+
+		let revert_data = client.get_revert_data("0xabc").await.unwrap();
+
+		assert_eq!(revert_data, expected);
+	}
+}
@@ -0,0 +1,255 @@
+use crate::integration::mocks::MockSolanaTransportClient;
+use mockall::predicate;
+use openzeppelin_monitor::{
+	models::BlockType,
+	services::blockchain::{BlockChainClient, SolanaClient, SolanaClientTrait, TransportError},
+};
+use serde_json::json;
+
+fn create_test_block_response(blockhash: &str) -> serde_json::Value {
+	json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"result": {
+			"blockhash": blockhash,
+			"previousBlockhash": "11111111111111111111111111111111",
+			"parentSlot": 99,
+			"blockTime": 1700000000,
+			"blockHeight": 90,
+			"transactions": [{
+				"meta": {
+					"err": null,
+					"fee": 5000,
+					"preBalances": [],
+					"postBalances": [],
+					"logMessages": []
+				},
+				"transaction": {
+					"signatures": ["sig1"],
+					"message": {
+						"accountKeys": ["payer"],
+						"recentBlockhash": "hash",
+						"instructions": []
+					}
+				}
+			}]
+		}
+	})
+}
+
+#[tokio::test]
+async fn test_get_latest_block_number() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getSlot"), predicate::always())
+		.times(1)
+		.returning(|_, _| Ok(json!({"jsonrpc": "2.0", "id": 1, "result": 100})));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let result = client.get_latest_block_number().await;
+
+	assert!(result.is_ok());
+	assert_eq!(result.unwrap(), 100);
+}
+
+#[tokio::test]
+async fn test_get_latest_block_number_invalid_response() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	mock_solana
+		.expect_send_raw_request()
+		.times(1)
+		.returning(|_, _| Ok(json!({"jsonrpc": "2.0", "id": 1, "result": "invalid"})));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let result = client.get_latest_block_number().await;
+
+	assert!(result.is_err());
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Invalid slot number"));
+}
+
+#[tokio::test]
+async fn test_get_blocks_skips_missing_slots() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getBlock"), predicate::always())
+		.times(3)
+		.returning(|_, params| {
+			let slot = params.as_ref().unwrap()[0].as_u64().unwrap();
+			match slot {
+				101 => Ok(json!({
+					"jsonrpc": "2.0",
+					"id": 1,
+					"error": {
+						"code": -32007,
+						"message": "Slot 101 was skipped, or missing due to ledger jump to recent snapshot"
+					}
+				})),
+				_ => Ok(create_test_block_response(&format!("hash{}", slot))),
+			}
+		});
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let result = client.get_blocks(100, Some(102)).await;
+
+	assert!(result.is_ok());
+	let blocks = result.unwrap();
+	assert_eq!(blocks.len(), 2);
+
+	match &blocks[0] {
+		BlockType::Solana(block) => {
+			assert_eq!(block.slot, 100);
+			assert_eq!(block.blockhash, "hash100");
+			assert_eq!(block.transactions[0].slot, 100);
+			assert_eq!(block.transactions[0].block_time, Some(1700000000));
+		}
+		_ => panic!("Expected Solana block"),
+	}
+	match &blocks[1] {
+		BlockType::Solana(block) => assert_eq!(block.slot, 102),
+		_ => panic!("Expected Solana block"),
+	}
+}
+
+#[tokio::test]
+async fn test_get_blocks_rpc_error() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	mock_solana
+		.expect_send_raw_request()
+		.times(1)
+		.returning(|_, _| {
+			Ok(json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"error": { "code": -32603, "message": "Internal error" }
+			}))
+		});
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let result = client.get_blocks(100, None).await;
+
+	assert!(result.is_err());
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Solana RPC request failed for method 'getBlock'"));
+}
+
+#[tokio::test]
+async fn test_get_blocks_invalid_range() {
+	let mock_solana = MockSolanaTransportClient::new();
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let result = client.get_blocks(10, Some(5)).await;
+
+	assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_get_transaction() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getTransaction"), predicate::always())
+		.times(1)
+		.returning(|_, _| {
+			Ok(json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"result": {
+					"slot": 100,
+					"blockTime": 1700000000,
+					"meta": { "err": null, "fee": 5000 },
+					"transaction": {
+						"signatures": ["sig1"],
+						"message": {
+							"accountKeys": ["payer", "program"],
+							"recentBlockhash": "hash",
+							"instructions": []
+						}
+					}
+				}
+			}))
+		});
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let result = client.get_transaction("sig1".to_string()).await;
+
+	assert!(result.is_ok());
+	let transaction = result.unwrap();
+	assert_eq!(transaction.signature(), "sig1");
+	assert_eq!(transaction.slot, 100);
+	assert!(transaction.is_success());
+}
+
+#[tokio::test]
+async fn test_get_transaction_not_found() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	mock_solana
+		.expect_send_raw_request()
+		.times(1)
+		.returning(|_, _| Ok(json!({"jsonrpc": "2.0", "id": 1, "result": null})));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let result = client.get_transaction("missing".to_string()).await;
+
+	assert!(result.is_err());
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Transaction not found"));
+}
+
+#[tokio::test]
+async fn test_get_signatures_for_address() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	mock_solana
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getSignaturesForAddress"),
+			predicate::always(),
+		)
+		.times(1)
+		.returning(|_, _| {
+			Ok(json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"result": [{
+					"signature": "sig1",
+					"slot": 100,
+					"err": null,
+					"memo": null,
+					"blockTime": 1700000000,
+					"confirmationStatus": "confirmed"
+				}]
+			}))
+		});
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let result = client
+		.get_signatures_for_address("program".to_string(), Some(10))
+		.await;
+
+	assert!(result.is_ok());
+	let signatures = result.unwrap();
+	assert_eq!(signatures.len(), 1);
+	assert_eq!(signatures[0].signature, "sig1");
+	assert_eq!(signatures[0].slot, 100);
+}
+
+#[tokio::test]
+async fn test_transport_error_is_propagated() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	mock_solana
+		.expect_send_raw_request()
+		.times(1)
+		.returning(|_, _| Err(TransportError::network("connection refused", None, None)));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let result = client.get_latest_block_number().await;
+
+	assert!(result.is_err());
+}
@@ -25,9 +25,9 @@ async fn test_get_transactions() {
 	mock.expect_get_transactions()
 		.with(predicate::eq(1u32), predicate::eq(Some(2u32)))
 		.times(1)
-		.returning(move |_, _| Ok(vec![expected_transaction.clone()]));
+		.returning(move |_, _, _| Ok(vec![expected_transaction.clone()]));
 
-	let result = mock.get_transactions(1, Some(2)).await;
+	let result = mock.get_transactions(1, Some(2), None).await;
 	assert!(result.is_ok());
 	assert_eq!(result.unwrap().len(), 1);
 }
@@ -42,9 +42,9 @@ async fn test_get_events() {
 	mock.expect_get_events()
 		.with(predicate::eq(1u32), predicate::eq(Some(2u32)))
 		.times(1)
-		.returning(move |_, _| Ok(vec![expected_event.clone()]));
+		.returning(move |_, _, _| Ok(vec![expected_event.clone()]));
 
-	let result = mock.get_events(1, Some(2)).await;
+	let result = mock.get_events(1, Some(2), None).await;
 	assert!(result.is_ok());
 	assert_eq!(result.unwrap().len(), 1);
 }
@@ -162,7 +162,7 @@ async fn test_get_transactions_pagination() {
 		.await;
 
 	let client = StellarClient::new(&network).await.unwrap();
-	let result = client.get_transactions(1, Some(2)).await.unwrap();
+	let result = client.get_transactions(1, Some(2), None).await.unwrap();
 
 	assert_eq!(result.len(), 2);
 	assert_eq!(
@@ -254,7 +254,7 @@ async fn test_get_events_pagination() {
 		.await;
 
 	let client = StellarClient::new(&network).await.unwrap();
-	let result = client.get_events(1, Some(2)).await.unwrap();
+	let result = client.get_events(1, Some(2), None).await.unwrap();
 
 	assert_eq!(result.len(), 2);
 	assert_eq!(result[0].ledger, 1);
@@ -571,7 +571,7 @@ async fn test_get_events_sparse_pagination() {
 	}
 
 	let client = StellarClient::new(&network).await.unwrap();
-	let result = client.get_events(1, Some(150)).await.unwrap();
+	let result = client.get_events(1, Some(150), None).await.unwrap();
 
 	// Should find 2 events despite empty intermediate pages
 	assert_eq!(result.len(), 2);
@@ -747,7 +747,7 @@ async fn test_get_events_complex_sparse_pagination_with_boundaries() {
 
 	let client = StellarClient::new(&network).await.unwrap();
 	// Query range: ledger 10 to 100 (inclusive)
-	let result = client.get_events(10, Some(100)).await.unwrap();
+	let result = client.get_events(10, Some(100), None).await.unwrap();
 
 	// Should find 4 events: at ledger 10, 45, 47, and 100
 	// Event at ledger 105 should be excluded (beyond range)
@@ -82,6 +82,41 @@ async fn test_get_evm_client_creates_and_caches() {
 	mock.assert();
 }
 
+#[tokio::test]
+async fn test_remove_client_creates_a_new_client() {
+	let mut mock_server = mockito::Server::new_async().await;
+	// The client is created once, then again after its removal
+	let mock = mock_server
+		.mock("POST", "/")
+		.match_body(r#"{"id":1,"jsonrpc":"2.0","method":"net_version","params":[]}"#)
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(r#"{"jsonrpc":"2.0","id":1,"result":"1"}"#)
+		.expect(2)
+		.create_async()
+		.await;
+	let pool = ClientPool::new();
+	let network = create_evm_test_network_with_urls(vec![&mock_server.url()]);
+
+	let client1 = pool.get_evm_client(&network).await.unwrap();
+	pool.remove_client(&network).await;
+	assert_eq!(
+		pool.get_client_count::<EvmClient<EVMTransportClient>>(BlockChainType::EVM)
+			.await,
+		0
+	);
+
+	let client2 = pool.get_evm_client(&network).await.unwrap();
+	assert!(!Arc::ptr_eq(&client1, &client2));
+	assert_eq!(
+		pool.get_client_count::<EvmClient<EVMTransportClient>>(BlockChainType::EVM)
+			.await,
+		1
+	);
+
+	mock.assert_async().await;
+}
+
 #[tokio::test]
 async fn test_get_stellar_client_creates_and_caches() {
 	let mut mock_server = mockito::Server::new_async().await;
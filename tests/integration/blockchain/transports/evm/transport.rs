@@ -1,10 +1,15 @@
 use alloy::{
-	primitives::{Address, U64},
+	primitives::{Address, U256, U64},
 	rpc::types::Index,
 };
 use mockall::predicate;
-use openzeppelin_monitor::services::blockchain::{
-	BlockChainClient, EvmClient, EvmClientTrait, TransportError,
+use openzeppelin_monitor::{
+	models::EVMTraceMethod,
+	services::blockchain::{BlockChainClient, EvmClient, EvmClientTrait, TransportError},
+	utils::{
+		metrics::{RPC_CACHE_HITS_TOTAL, RPC_CACHE_MISSES_TOTAL},
+		tests::builders::network::NetworkBuilder,
+	},
 };
 use serde_json::{json, Value};
 
@@ -95,6 +100,42 @@ async fn test_get_logs_for_blocks_implementation() {
 	);
 }
 
+#[tokio::test]
+async fn test_get_event_logs_for_blocks_implementation() {
+	let mut mock_evm = MockEVMTransportClient::new();
+
+	let topic = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+	// Expected request parameters, with the topics matched in first position
+	let expected_params = json!([{
+		"fromBlock": "0x1",
+		"toBlock": "0x1",
+		"address": vec!["0x1234567890123456789012345678901234567890"],
+		"topics": [[topic]]
+	}]);
+
+	mock_evm
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("eth_getLogs"),
+			predicate::eq(Some(expected_params.as_array().unwrap().to_vec())),
+		)
+		.returning(|_: &str, _: Option<Vec<Value>>| Ok(json!({ "result": [] })));
+
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+	let result = client
+		.get_event_logs_for_blocks(
+			1,
+			1,
+			vec!["0x1234567890123456789012345678901234567890".to_string()],
+			vec![topic.to_string()],
+		)
+		.await;
+
+	assert!(result.is_ok());
+	assert!(result.unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn test_get_logs_for_blocks_missing_result() {
 	let mut mock_evm = MockEVMTransportClient::new();
@@ -160,6 +201,181 @@ async fn test_get_logs_for_blocks_alloy_error() {
 	assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn test_get_internal_calls_debug_trace() {
+	let mut mock_evm = MockEVMTransportClient::new();
+
+	let expected_params = json!(["0x1", { "tracer": "callTracer" }]);
+	// Call frames of two transactions, the second one making a nested call
+	let mock_response = json!({
+		"result": [
+			{
+				"txHash": "0x0000000000000000000000000000000000000000000000000000000000000001",
+				"result": {
+					"type": "CALL",
+					"from": "0x1234567890123456789012345678901234567890",
+					"to": "0x1234567890123456789012345678901234567891",
+					"value": "0x0"
+				}
+			},
+			{
+				"txHash": "0x0000000000000000000000000000000000000000000000000000000000000002",
+				"result": {
+					"type": "CALL",
+					"from": "0x1234567890123456789012345678901234567890",
+					"to": "0x1234567890123456789012345678901234567891",
+					"value": "0x0",
+					"calls": [{
+						"type": "CALL",
+						"from": "0x1234567890123456789012345678901234567891",
+						"to": "0x1234567890123456789012345678901234567892",
+						"value": "0x1388",
+						"calls": [{
+							"type": "STATICCALL",
+							"from": "0x1234567890123456789012345678901234567892",
+							"to": "0x1234567890123456789012345678901234567893"
+						}]
+					}]
+				}
+			}
+		]
+	});
+
+	mock_evm
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("debug_traceBlockByNumber"),
+			predicate::eq(Some(expected_params.as_array().unwrap().to_vec())),
+		)
+		.returning(move |_: &str, _: Option<Vec<Value>>| Ok(mock_response.clone()));
+
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+	let calls = client
+		.get_internal_calls(1, EVMTraceMethod::DebugTraceBlockByNumber)
+		.await
+		.unwrap();
+
+	assert_eq!(calls.len(), 2);
+	assert_eq!(calls[0].transaction_position, 1);
+	assert_eq!(calls[0].call_type, "call");
+	assert_eq!(
+		calls[0].to,
+		Some(
+			"0x1234567890123456789012345678901234567892"
+				.parse::<Address>()
+				.unwrap()
+		)
+	);
+	assert_eq!(calls[0].value, U256::from(5000));
+	assert_eq!(calls[1].call_type, "staticcall");
+	assert_eq!(calls[1].value, U256::ZERO);
+}
+
+#[tokio::test]
+async fn test_get_internal_calls_trace_block() {
+	let mut mock_evm = MockEVMTransportClient::new();
+
+	// Top-level calls and block rewards are not internal calls
+	let mock_response = json!({
+		"result": [
+			{
+				"type": "call",
+				"action": {
+					"callType": "call",
+					"from": "0x1234567890123456789012345678901234567890",
+					"to": "0x1234567890123456789012345678901234567891",
+					"value": "0x0"
+				},
+				"traceAddress": [],
+				"transactionPosition": 0
+			},
+			{
+				"type": "call",
+				"action": {
+					"callType": "delegatecall",
+					"from": "0x1234567890123456789012345678901234567891",
+					"to": "0x1234567890123456789012345678901234567892",
+					"value": "0x1388"
+				},
+				"traceAddress": [0],
+				"transactionPosition": 0
+			},
+			{
+				"type": "create",
+				"action": {
+					"from": "0x1234567890123456789012345678901234567891",
+					"value": "0x0",
+					"init": "0x"
+				},
+				"result": { "address": "0x1234567890123456789012345678901234567894" },
+				"traceAddress": [1],
+				"transactionPosition": 0
+			},
+			{
+				"type": "reward",
+				"action": {
+					"author": "0x1234567890123456789012345678901234567890",
+					"rewardType": "block",
+					"value": "0x1bc16d674ec80000"
+				},
+				"traceAddress": []
+			}
+		]
+	});
+
+	mock_evm
+		.expect_send_raw_request()
+		.with(predicate::eq("trace_block"), predicate::always())
+		.returning(move |_: &str, _: Option<Vec<Value>>| Ok(mock_response.clone()));
+
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+	let calls = client
+		.get_internal_calls(1, EVMTraceMethod::TraceBlock)
+		.await
+		.unwrap();
+
+	assert_eq!(calls.len(), 2);
+	assert_eq!(calls[0].call_type, "delegatecall");
+	assert_eq!(calls[0].value, U256::from(5000));
+	assert_eq!(calls[1].call_type, "create");
+	assert_eq!(
+		calls[1].to,
+		Some(
+			"0x1234567890123456789012345678901234567894"
+				.parse::<Address>()
+				.unwrap()
+		)
+	);
+}
+
+#[tokio::test]
+async fn test_get_internal_calls_unsupported_method() {
+	let mut mock_evm = MockEVMTransportClient::new();
+
+	let mock_response = json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"error": {
+			"code": -32601,
+			"message": "the method debug_traceBlockByNumber does not exist/is not available"
+		}
+	});
+
+	mock_evm
+		.expect_send_raw_request()
+		.returning(move |_: &str, _: Option<Vec<Value>>| Ok(mock_response.clone()));
+
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+	let err = client
+		.get_internal_calls(1, EVMTraceMethod::DebugTraceBlockByNumber)
+		.await
+		.unwrap_err();
+
+	assert!(err
+		.to_string()
+		.contains("RPC endpoint does not support tracing with debug_traceBlockByNumber"));
+}
+
 #[tokio::test]
 async fn test_get_transaction_receipt_success() {
 	let mut mock_evm = MockEVMTransportClient::new();
@@ -209,6 +425,65 @@ async fn test_get_transaction_receipt_success() {
 	assert_eq!(receipt.transaction_index, Index::from(1));
 }
 
+#[tokio::test]
+async fn test_get_transaction_receipt_served_from_cache() {
+	let mut mock_evm = MockEVMTransportClient::new();
+
+	let mock_response = json!({
+		"result": {
+			"transactionHash": "0x0000000000000000000000000000000000000000000000000000000000000001",
+			"transactionIndex": "0x1",
+			"blockHash": "0x0000000000000000000000000000000000000000000000000000000000000002",
+			"blockNumber": "0x1",
+			"from": "0x1234567890123456789012345678901234567890",
+			"to": "0x1234567890123456789012345678901234567891",
+			"cumulativeGasUsed": "0x1",
+			"gasUsed": "0x1",
+			"contractAddress": null,
+			"logs": [],
+			"status": "0x1",
+			"logsBloom": format!("0x{}", "0".repeat(512)),
+			"effectiveGasPrice": "0x1",
+			"type": "0x0"
+		}
+	});
+
+	// The second fetch must be served from the cache
+	mock_evm
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("eth_getTransactionReceipt"),
+			predicate::always(),
+		)
+		.times(1)
+		.returning(move |_: &str, _: Option<Vec<Value>>| Ok(mock_response.clone()));
+
+	let network = NetworkBuilder::new()
+		.slug("receipt_cache_test")
+		.block_time_ms(60_000)
+		.rpc_cache_size(16)
+		.build();
+	let client =
+		EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm).with_cache(&network);
+	let hits = RPC_CACHE_HITS_TOTAL.with_label_values(&["receipt_cache_test", "receipts"]);
+	let misses = RPC_CACHE_MISSES_TOTAL.with_label_values(&["receipt_cache_test", "receipts"]);
+
+	let hash = "0x0000000000000000000000000000000000000000000000000000000000000001";
+	let first = client
+		.get_transaction_receipt(hash.to_string())
+		.await
+		.unwrap();
+	let second = client
+		.get_transaction_receipt(hash.to_string())
+		.await
+		.unwrap();
+
+	assert_eq!(first.transaction_hash, second.transaction_hash);
+	assert_eq!(second.block_number.unwrap(), U64::from(1));
+	assert_eq!(hits.get(), 1);
+	assert_eq!(misses.get(), 1);
+}
+
 #[tokio::test]
 async fn test_get_transaction_receipt_not_found() {
 	let mut mock_evm = MockEVMTransportClient::new();
@@ -452,6 +727,63 @@ async fn test_get_multiple_blocks() {
 	assert_eq!(blocks.len(), 3);
 }
 
+#[tokio::test]
+async fn test_refresh_blocks_bypasses_and_replaces_cached_blocks() {
+	let mut mock_evm = MockEVMTransportClient::new();
+
+	// Both fetches of `get_blocks` clone the transport, the second one being served from the
+	// cache
+	mock_evm.expect_clone().times(2).returning(|| {
+		let mut new_mock = MockEVMTransportClient::new();
+		new_mock.expect_send_raw_request().times(0..=1).returning(
+			move |_: &str, _: Option<Vec<Value>>| {
+				Ok(json!({
+					"jsonrpc": "2.0",
+					"id": 1,
+					"result": create_mock_block(1)
+				}))
+			},
+		);
+		new_mock
+			.expect_clone()
+			.returning(MockEVMTransportClient::new);
+		new_mock
+	});
+
+	// The block replacing block 1 after a reorg
+	let mut replaced_block = create_mock_block(1);
+	replaced_block["hash"] = json!(format!("0x{:064x}", 0xb));
+	let replaced_hash = replaced_block["hash"].as_str().unwrap().to_string();
+	mock_evm
+		.expect_send_raw_request()
+		.with(predicate::eq("eth_getBlockByNumber"), predicate::always())
+		.times(1)
+		.returning(move |_: &str, _: Option<Vec<Value>>| {
+			Ok(json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"result": replaced_block.clone()
+			}))
+		});
+
+	let network = NetworkBuilder::new()
+		.slug("refresh_blocks_test")
+		.block_time_ms(60_000)
+		.rpc_cache_size(16)
+		.build();
+	let client =
+		EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm).with_cache(&network);
+
+	let cached = client.get_blocks(1, None).await.unwrap();
+	assert_eq!(cached[0].hash(), Some(format!("0x{:064x}", 1)));
+
+	let refreshed = client.refresh_blocks(1, None).await.unwrap();
+	assert_eq!(refreshed[0].hash(), Some(replaced_hash.clone()));
+
+	let cached = client.get_blocks(1, None).await.unwrap();
+	assert_eq!(cached[0].hash(), Some(replaced_hash));
+}
+
 #[tokio::test]
 async fn test_get_blocks_missing_result() {
 	let mut mock_evm = MockEVMTransportClient::new();
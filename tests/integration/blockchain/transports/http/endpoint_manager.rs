@@ -1,12 +1,13 @@
-use mockito::Server;
+use mockito::{Matcher, Server};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
 use serde_json::{json, Value};
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use tokio::sync::RwLock;
 
 use openzeppelin_monitor::services::blockchain::{
-	BlockchainTransport, HttpEndpointManager, TransportError,
+	BlockchainTransport, CircuitBreakerConfig, CircuitState, EndpointType, HttpEndpointManager,
+	TransportError,
 };
 
 use crate::integration::mocks::{AlwaysFailsToUpdateClientTransport, MockTransport};
@@ -547,3 +548,724 @@ async fn test_send_raw_request_returns_http_error_if_non_transient() {
 
 	mock.assert();
 }
+
+#[tokio::test]
+async fn test_send_raw_request_rotates_on_server_error() {
+	let mut primary_server = Server::new_async().await;
+	let mut fallback_server = Server::new_async().await;
+
+	let primary_mock = primary_server
+		.mock("POST", "/")
+		.with_status(503)
+		.with_body("Service Unavailable")
+		.expect(1)
+		.create_async()
+		.await;
+
+	let fallback_mock = fallback_server
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "result": "success", "id": 1}"#)
+		.create_async()
+		.await;
+
+	let manager = HttpEndpointManager::new(
+		get_mock_client_builder(),
+		primary_server.url().as_ref(),
+		vec![fallback_server.url()],
+	);
+	let transport = MockTransport::new();
+
+	let result = manager
+		.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
+		.await
+		.unwrap();
+
+	assert_eq!(result["result"], "success");
+	assert_eq!(&*manager.active_url.read().await, &fallback_server.url());
+	primary_mock.assert();
+	fallback_mock.assert();
+}
+
+#[tokio::test]
+async fn test_circuit_opens_after_consecutive_failures() {
+	let mut server = Server::new_async().await;
+
+	// Only the requests before the circuit opens reach the server
+	let mock = server
+		.mock("POST", "/")
+		.with_status(502)
+		.with_body("Bad Gateway")
+		.expect(2)
+		.create_async()
+		.await;
+
+	let mut manager =
+		HttpEndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![]);
+	manager.set_circuit_breaker_config(CircuitBreakerConfig {
+		failure_threshold: 2,
+		cooldown: Duration::from_secs(60),
+	});
+	let transport = MockTransport::new();
+
+	for _ in 0..2 {
+		let result = manager
+			.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
+			.await;
+		match result {
+			Err(TransportError::Http { status_code, .. }) => assert_eq!(status_code, 502),
+			other => panic!("Expected Http error with status code 502, got {:?}", other),
+		}
+	}
+	assert_eq!(
+		manager.circuit_breaker().state(&server.url()),
+		CircuitState::Open
+	);
+
+	// The open endpoint is skipped without sending a request
+	let result = manager
+		.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
+		.await;
+	match result {
+		Err(TransportError::Network(ctx)) => assert!(ctx.message.contains("Circuit is open")),
+		other => panic!("Expected circuit open network error, got {:?}", other),
+	}
+
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_circuit_skips_open_fallback() {
+	let invalid_url = "http://invalid-domain-that-will-fail:12345";
+	let mut primary_server = Server::new_async().await;
+
+	let primary_mock = primary_server
+		.mock("POST", "/")
+		.with_status(500)
+		.with_body("Internal Server Error")
+		.expect(1)
+		.create_async()
+		.await;
+
+	let mut manager = HttpEndpointManager::new(
+		get_mock_client_builder(),
+		primary_server.url().as_ref(),
+		vec![invalid_url.to_string()],
+	);
+	manager.set_circuit_breaker_config(CircuitBreakerConfig {
+		failure_threshold: 1,
+		cooldown: Duration::from_secs(60),
+	});
+	manager.circuit_breaker().record_failure(invalid_url);
+	let transport = MockTransport::new();
+
+	// The fallback's circuit is open, so no rotation is possible
+	let result = manager
+		.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
+		.await;
+
+	match result {
+		Err(TransportError::Http {
+			status_code, url, ..
+		}) => {
+			assert_eq!(status_code, 500);
+			assert_eq!(url, primary_server.url());
+		}
+		other => panic!("Expected Http error with status code 500, got {:?}", other),
+	}
+	assert_eq!(&*manager.active_url.read().await, &primary_server.url());
+	primary_mock.assert();
+}
+
+#[tokio::test]
+async fn test_circuit_recovers_after_cooldown() {
+	let mut server = Server::new_async().await;
+	let cooldown = Duration::from_millis(100);
+
+	let failing_mock = server
+		.mock("POST", "/")
+		.with_status(503)
+		.with_body("Service Unavailable")
+		.expect(1)
+		.create_async()
+		.await;
+
+	let mut manager =
+		HttpEndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![]);
+	manager.set_circuit_breaker_config(CircuitBreakerConfig {
+		failure_threshold: 1,
+		cooldown,
+	});
+	let transport = MockTransport::new();
+
+	let result = manager
+		.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
+		.await;
+	assert!(result.is_err());
+	assert_eq!(
+		manager.circuit_breaker().state(&server.url()),
+		CircuitState::Open
+	);
+	failing_mock.assert();
+	failing_mock.remove_async().await;
+
+	// The endpoint recovers
+	let success_mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "result": "success", "id": 1}"#)
+		.expect(1)
+		.create_async()
+		.await;
+
+	tokio::time::sleep(cooldown).await;
+
+	// The trial request after the cooldown succeeds and closes the circuit
+	let result = manager
+		.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
+		.await
+		.unwrap();
+
+	assert_eq!(result["result"], "success");
+	assert_eq!(
+		manager.circuit_breaker().state(&server.url()),
+		CircuitState::Closed
+	);
+	success_mock.assert();
+}
+
+#[tokio::test]
+async fn test_weighted_distribution_of_concurrent_requests() {
+	let weights = [70, 20, 10];
+	let mut servers = Vec::new();
+	let mut mocks = Vec::new();
+	for weight in weights {
+		let mut server = Server::new_async().await;
+		mocks.push(
+			server
+				.mock("POST", "/")
+				.with_status(200)
+				.with_header("content-type", "application/json")
+				.with_body(r#"{"jsonrpc": "2.0", "result": "success", "id": 1}"#)
+				.expect(weight)
+				.create_async()
+				.await,
+		);
+		servers.push(server);
+	}
+
+	let mut manager = HttpEndpointManager::new(
+		get_mock_client_builder(),
+		servers[0].url().as_ref(),
+		vec![servers[1].url(), servers[2].url()],
+	);
+	manager.set_url_weights(
+		servers
+			.iter()
+			.zip(weights)
+			.map(|(server, weight)| (server.url(), weight as u32))
+			.collect(),
+	);
+	let transport = MockTransport::new();
+
+	// Requests sent at once each go to the URL selected for them, so that every URL receives
+	// exactly its share of a full round of selections
+	let requests = (0..100)
+		.map(|_| manager.send_raw_request(&transport, "test_method", Some(json!(["param1"]))));
+	for result in futures::future::join_all(requests).await {
+		assert_eq!(result.unwrap()["result"], "success");
+	}
+
+	for mock in mocks {
+		mock.assert_async().await;
+	}
+}
+
+#[tokio::test]
+async fn test_send_raw_request_distributes_by_weight() {
+	let mut server1 = Server::new_async().await;
+	let mut server2 = Server::new_async().await;
+
+	let mock1 = server1
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "result": "server1", "id": 1}"#)
+		.expect(6)
+		.create_async()
+		.await;
+	let mock2 = server2
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "result": "server2", "id": 1}"#)
+		.expect(2)
+		.create_async()
+		.await;
+
+	let mut manager = HttpEndpointManager::new(
+		get_mock_client_builder(),
+		server1.url().as_ref(),
+		vec![server2.url()],
+	);
+	manager.set_url_weights(vec![(server1.url(), 3), (server2.url(), 1)]);
+	let transport = MockTransport::new();
+
+	for _ in 0..8 {
+		manager
+			.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
+			.await
+			.unwrap();
+	}
+
+	mock1.assert();
+	mock2.assert();
+}
+
+#[tokio::test]
+async fn test_zero_weight_url_is_failover_only() {
+	let mut primary_server = Server::new_async().await;
+	let mut failover_server = Server::new_async().await;
+
+	let primary_mock = primary_server
+		.mock("POST", "/")
+		.with_status(503)
+		.with_body("Service Unavailable")
+		.expect(1)
+		.create_async()
+		.await;
+	let failover_mock = failover_server
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "result": "success", "id": 1}"#)
+		.expect(2)
+		.create_async()
+		.await;
+
+	let mut manager = HttpEndpointManager::new(
+		get_mock_client_builder(),
+		primary_server.url().as_ref(),
+		vec![failover_server.url()],
+	);
+	manager.set_url_weights(vec![
+		(primary_server.url(), 100),
+		(failover_server.url(), 0),
+	]);
+	manager.set_circuit_breaker_config(CircuitBreakerConfig {
+		failure_threshold: 1,
+		cooldown: Duration::from_secs(60),
+	});
+	let transport = MockTransport::new();
+
+	// The zero-weight URL is never selected while the weighted one is available
+	assert_eq!(manager.select_url(), Some(primary_server.url()));
+
+	// Once the weighted URL fails, requests fail over to the zero-weight URL
+	for _ in 0..2 {
+		let result = manager
+			.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
+			.await
+			.unwrap();
+		assert_eq!(result["result"], "success");
+	}
+	assert_eq!(manager.select_url(), None);
+	assert_eq!(&*manager.active_url.read().await, &failover_server.url());
+
+	primary_mock.assert();
+	failover_mock.assert();
+}
+
+#[tokio::test]
+async fn test_select_url_for_method_routes_by_endpoint_type() {
+	let archive_url = "https://archive.example.com";
+	let full_url = "https://full.example.com";
+
+	let mut manager = HttpEndpointManager::new(
+		get_mock_client_builder(),
+		archive_url,
+		vec![full_url.to_string()],
+	);
+	manager.set_url_weights(vec![
+		(archive_url.to_string(), 50),
+		(full_url.to_string(), 50),
+	]);
+	manager.set_url_types(HashMap::from([
+		(archive_url.to_string(), EndpointType::Archive),
+		(full_url.to_string(), EndpointType::Full),
+	]));
+
+	for _ in 0..4 {
+		assert_eq!(
+			manager.select_url_for_request("eth_getLogs", None),
+			Some(archive_url.to_string())
+		);
+		assert_eq!(
+			manager.select_url_for_request("trace_block", None),
+			Some(archive_url.to_string())
+		);
+		assert_eq!(
+			manager.select_url_for_request("eth_blockNumber", None),
+			Some(full_url.to_string())
+		);
+		assert_eq!(
+			manager.select_url_for_request("eth_getBlockByNumber", None),
+			Some(full_url.to_string())
+		);
+
+		// State reads at a block number or hash need an archive node, at the head a full node
+		assert_eq!(
+			manager.select_url_for_request("eth_call", Some(&json!([{}, "0x10"]))),
+			Some(archive_url.to_string())
+		);
+		assert_eq!(
+			manager.select_url_for_request(
+				"eth_getBalance",
+				Some(&json!(["0x1", { "blockHash": "0x2" }]))
+			),
+			Some(archive_url.to_string())
+		);
+		assert_eq!(
+			manager.select_url_for_request("eth_getCode", Some(&json!(["0x1", "latest"]))),
+			Some(full_url.to_string())
+		);
+		assert_eq!(
+			manager.select_url_for_request("eth_call", Some(&json!([{}]))),
+			Some(full_url.to_string())
+		);
+	}
+}
+
+#[tokio::test]
+async fn test_select_url_for_method_falls_back_without_preferred_type() {
+	let archive_url = "https://archive.example.com";
+	let rpc_url = "https://rpc.example.com";
+
+	let mut manager = HttpEndpointManager::new(
+		get_mock_client_builder(),
+		archive_url,
+		vec![rpc_url.to_string()],
+	);
+	manager.set_url_weights(vec![(archive_url.to_string(), 1), (rpc_url.to_string(), 1)]);
+	manager.set_url_types(HashMap::from([(
+		archive_url.to_string(),
+		EndpointType::Archive,
+	)]));
+	manager.set_circuit_breaker_config(CircuitBreakerConfig {
+		failure_threshold: 1,
+		cooldown: Duration::from_secs(60),
+	});
+
+	// Without full nodes, head requests are distributed across all endpoints
+	let selected: Vec<String> = (0..2)
+		.filter_map(|_| manager.select_url_for_request("eth_blockNumber", None))
+		.collect();
+	assert!(selected.contains(&archive_url.to_string()));
+	assert!(selected.contains(&rpc_url.to_string()));
+
+	// Historical requests fall back to the other endpoints while the archive node is down
+	manager.circuit_breaker().record_failure(archive_url);
+	assert_eq!(
+		manager.select_url_for_request("eth_getLogs", None),
+		Some(rpc_url.to_string())
+	);
+}
+
+#[tokio::test]
+async fn test_send_raw_request_routes_methods_to_endpoint_types() {
+	let mut archive_server = Server::new_async().await;
+	let mut full_server = Server::new_async().await;
+
+	let archive_mock = archive_server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(json!({ "method": "eth_getLogs" })))
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "result": [], "id": 1}"#)
+		.expect(2)
+		.create_async()
+		.await;
+	let full_mock = full_server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(json!({ "method": "eth_blockNumber" })))
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "result": "0x1", "id": 1}"#)
+		.expect(2)
+		.create_async()
+		.await;
+
+	let mut manager = HttpEndpointManager::new(
+		get_mock_client_builder(),
+		full_server.url().as_ref(),
+		vec![archive_server.url()],
+	);
+	manager.set_url_weights(vec![(archive_server.url(), 50), (full_server.url(), 50)]);
+	manager.set_url_types(HashMap::from([
+		(archive_server.url(), EndpointType::Archive),
+		(full_server.url(), EndpointType::Full),
+	]));
+	let transport = MockTransport::new();
+
+	for _ in 0..2 {
+		let logs = manager
+			.send_raw_request(&transport, "eth_getLogs", Some(json!([{}])))
+			.await
+			.unwrap();
+		assert_eq!(logs["result"], json!([]));
+
+		let block_number = manager
+			.send_raw_request::<_, Value>(&transport, "eth_blockNumber", None)
+			.await
+			.unwrap();
+		assert_eq!(block_number["result"], "0x1");
+	}
+
+	archive_mock.assert();
+	full_mock.assert();
+}
+
+#[tokio::test]
+async fn test_send_batch_request_orders_responses() {
+	let mut server = Server::new_async().await;
+
+	// The endpoint answers the batch out of order
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Json(json!([
+			{"jsonrpc": "2.0", "id": 0, "method": "eth_getTransactionReceipt", "params": ["0x1"]},
+			{"jsonrpc": "2.0", "id": 1, "method": "eth_getTransactionReceipt", "params": ["0x2"]},
+			{"jsonrpc": "2.0", "id": 2, "method": "eth_getTransactionReceipt", "params": ["0x3"]},
+		])))
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(
+			json!([
+				{"jsonrpc": "2.0", "id": 2, "result": "receipt_3"},
+				{"jsonrpc": "2.0", "id": 0, "result": "receipt_1"},
+				{"jsonrpc": "2.0", "id": 1, "result": "receipt_2"},
+			])
+			.to_string(),
+		)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let manager =
+		HttpEndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![]);
+	let transport = MockTransport::new();
+
+	let responses = manager
+		.send_batch_request(
+			&transport,
+			&[
+				("eth_getTransactionReceipt", Some(json!(["0x1"]))),
+				("eth_getTransactionReceipt", Some(json!(["0x2"]))),
+				("eth_getTransactionReceipt", Some(json!(["0x3"]))),
+			],
+		)
+		.await
+		.unwrap();
+
+	let results: Vec<&Value> = responses
+		.iter()
+		.map(|response| &response["result"])
+		.collect();
+	assert_eq!(results, vec!["receipt_1", "receipt_2", "receipt_3"]);
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_send_batch_request_missing_response() {
+	let mut server = Server::new_async().await;
+
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(json!([{"jsonrpc": "2.0", "id": 0, "result": "receipt_1"}]).to_string())
+		.create_async()
+		.await;
+
+	let manager =
+		HttpEndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![]);
+	let transport = MockTransport::new();
+
+	let result = manager
+		.send_batch_request(
+			&transport,
+			&[
+				("eth_getTransactionReceipt", Some(json!(["0x1"]))),
+				("eth_getTransactionReceipt", Some(json!(["0x2"]))),
+			],
+		)
+		.await;
+
+	assert!(matches!(result, Err(TransportError::ResponseParse(_))));
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_send_batch_request_falls_back_when_rejected() {
+	let mut server = Server::new_async().await;
+
+	// The endpoint doesn't support batches and answers them with a single error
+	let batch_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex(r"^\[".to_string()))
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(
+			json!({
+				"jsonrpc": "2.0",
+				"id": null,
+				"error": {"code": -32600, "message": "batch unsupported"}
+			})
+			.to_string(),
+		)
+		.expect(1)
+		.create_async()
+		.await;
+	let first_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(json!({"params": ["0x1"]})))
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": "receipt_1"}"#)
+		.expect(1)
+		.create_async()
+		.await;
+	let second_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(json!({"params": ["0x2"]})))
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": "receipt_2"}"#)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let manager =
+		HttpEndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![]);
+	let transport = MockTransport::new();
+
+	let responses = manager
+		.send_batch_request(
+			&transport,
+			&[
+				("eth_getTransactionReceipt", Some(json!(["0x1"]))),
+				("eth_getTransactionReceipt", Some(json!(["0x2"]))),
+			],
+		)
+		.await
+		.unwrap();
+
+	assert_eq!(responses[0]["result"], "receipt_1");
+	assert_eq!(responses[1]["result"], "receipt_2");
+	batch_mock.assert();
+	first_mock.assert();
+	second_mock.assert();
+}
+
+#[tokio::test]
+async fn test_send_batch_request_empty() {
+	let manager = HttpEndpointManager::new(get_mock_client_builder(), "http://localhost", vec![]);
+	let transport = MockTransport::new();
+
+	let responses = manager.send_batch_request(&transport, &[]).await.unwrap();
+
+	assert!(responses.is_empty());
+}
+
+#[tokio::test]
+async fn test_send_raw_request_rejects_oversized_response() {
+	let mut server = Server::new_async().await;
+
+	let padding = "0".repeat(4096);
+	let oversized_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(
+			json!({"method": "eth_getBlockByNumber"}),
+		))
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(json!({"jsonrpc": "2.0", "id": 1, "result": padding}).to_string())
+		.expect(1)
+		.create_async()
+		.await;
+	let small_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(json!({"method": "eth_blockNumber"})))
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": "0x1"}"#)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let mut manager =
+		HttpEndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![]);
+	manager.set_max_response_bytes(1024);
+	let transport = MockTransport::new();
+
+	let result = manager
+		.send_raw_request(
+			&transport,
+			"eth_getBlockByNumber",
+			Some(json!(["0x1", true])),
+		)
+		.await;
+	match result {
+		Err(TransportError::ResponseTooLarge(ctx)) => {
+			assert!(ctx.message.contains("exceeds the maximum of 1024 bytes"));
+		}
+		_ => panic!("Expected ResponseTooLarge error, got {:?}", result),
+	}
+
+	let result = manager
+		.send_raw_request::<_, Value>(&transport, "eth_blockNumber", None)
+		.await
+		.unwrap();
+	assert_eq!(result["result"], "0x1");
+
+	oversized_mock.assert();
+	small_mock.assert();
+}
+
+#[tokio::test]
+async fn test_send_raw_request_rejects_oversized_chunked_response() {
+	let mut server = Server::new_async().await;
+
+	// The body has no declared length, so it is only rejected while being read
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_chunked_body(|writer| {
+			writer.write_all(br#"{"jsonrpc": "2.0", "id": 1, "result": ""#)?;
+			for _ in 0..64 {
+				writer.write_all(&[b'0'; 1024])?;
+			}
+			writer.write_all(br#""}"#)
+		})
+		.create_async()
+		.await;
+
+	let mut manager =
+		HttpEndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![]);
+	manager.set_max_response_bytes(4096);
+	let transport = MockTransport::new();
+
+	let result = manager
+		.send_raw_request(
+			&transport,
+			"eth_getBlockByNumber",
+			Some(json!(["0x1", true])),
+		)
+		.await;
+
+	assert!(matches!(result, Err(TransportError::ResponseTooLarge(_))));
+	mock.assert();
+}
@@ -6,6 +6,7 @@ use openzeppelin_monitor::{
 use reqwest_middleware::ClientBuilder;
 use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
 use serde_json::{json, Value};
+use std::collections::HashMap;
 
 use crate::integration::mocks::{
 	create_evm_test_network_with_urls, create_http_valid_server_mock_network_response,
@@ -222,6 +223,55 @@ async fn test_send_raw_request() {
 	no_params_mock.assert();
 }
 
+#[tokio::test]
+async fn test_requests_include_network_headers() {
+	let mut server = Server::new_async().await;
+
+	let network_mock = server
+		.mock("POST", "/")
+		.match_header("x-api-key", "secret")
+		.match_header("user-agent", "monitor-test")
+		.match_body(r#"{"id":1,"jsonrpc":"2.0","method":"net_version","params":[]}"#)
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(r#"{"jsonrpc":"2.0","id":1,"result":"1"}"#)
+		.create();
+	let test_mock = server
+		.mock("POST", "/")
+		.match_header("x-api-key", "secret")
+		.match_header("user-agent", "monitor-test")
+		.match_body(r#"{"id":1,"jsonrpc":"2.0","method":"testMethod","params":null}"#)
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(r#"{"jsonrpc":"2.0","result":{"data":"success"},"id":1}"#)
+		.create();
+
+	let mut network = create_evm_test_network_with_urls(vec![&server.url()]);
+	network.headers = Some(HashMap::from([
+		("X-Api-Key".to_string(), "secret".to_string()),
+		("User-Agent".to_string(), "monitor-test".to_string()),
+	]));
+	let client = HttpTransportClient::new(&network, None).await.unwrap();
+
+	let result = client.send_raw_request::<Value>("testMethod", None).await;
+
+	assert_eq!(result.unwrap()["result"]["data"], "success");
+	network_mock.assert();
+	test_mock.assert();
+}
+
+#[tokio::test]
+async fn test_client_creation_with_invalid_header() {
+	let mut network = create_evm_test_network_with_urls(vec!["http://localhost:8545"]);
+	network.headers = Some(HashMap::from([(
+		"invalid header".to_string(),
+		"secret".to_string(),
+	)]));
+
+	let error = HttpTransportClient::new(&network, None).await.unwrap_err();
+	assert!(error.to_string().contains("Invalid RPC header name"));
+}
+
 #[tokio::test]
 async fn test_update_endpoint_manager_client() {
 	let mut server = Server::new_async().await;
@@ -57,7 +57,7 @@ async fn test_get_transactions_success() {
 		.returning(move |_, _| Ok(mock_response.clone()));
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_transactions(1, Some(2)).await;
+	let result = client.get_transactions(1, Some(2), None).await;
 
 	assert!(result.is_ok());
 	let transactions = result.unwrap();
@@ -73,7 +73,7 @@ async fn test_get_transactions_invalid_sequence_range() {
 	let mock_stellar = MockStellarTransportClient::new();
 	let client = StellarClient::new_with_transport(mock_stellar);
 
-	let result = client.get_transactions(2, Some(1)).await;
+	let result = client.get_transactions(2, Some(1), None).await;
 	assert!(result.is_err());
 	let err = result.unwrap_err();
 
@@ -114,7 +114,7 @@ async fn test_get_transactions_failed_to_parse_transaction() {
 		.returning(move |_, _| Ok(mock_response.clone()));
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_transactions(1, Some(2)).await;
+	let result = client.get_transactions(1, Some(2), None).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
@@ -158,7 +158,9 @@ async fn test_get_transactions_outside_of_rpc_retention_window() {
 
 	let client = StellarClient::new_with_transport(mock_stellar);
 
-	let result = client.get_transactions(start_block, Some(end_block)).await;
+	let result = client
+		.get_transactions(start_block, Some(end_block), None)
+		.await;
 
 	assert!(result.is_err());
 
@@ -199,7 +201,7 @@ async fn test_get_transactions_generic_rpc_error() {
 		.returning(move |_, _| Ok(mock_response.clone()));
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_transactions(1, Some(2)).await;
+	let result = client.get_transactions(1, Some(2), None).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
@@ -232,7 +234,7 @@ async fn test_get_transactions_unexpected_response_structure() {
 		.returning(move |_, _| Ok(mock_response.clone()));
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_transactions(1, Some(2)).await;
+	let result = client.get_transactions(1, Some(2), None).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
@@ -265,7 +267,7 @@ async fn test_get_transactions_transport_error() {
 		});
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_transactions(1, Some(2)).await;
+	let result = client.get_transactions(1, Some(2), None).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
@@ -283,6 +285,110 @@ async fn test_get_transactions_transport_error() {
 	),);
 }
 
+#[tokio::test]
+async fn test_get_transactions_with_page_size() {
+	let mut mock_stellar = MockStellarTransportClient::new();
+
+	let transaction = |hash: &str, ledger: u32| {
+		json!({
+			"status": "SUCCESS",
+			"txHash": hash,
+			"applicationOrder": 1,
+			"feeBump": false,
+			"envelopeXdr": "AAA",
+			"resultXdr": "BBB",
+			"resultMetaXdr": "CCC",
+			"ledger": ledger,
+			"createdAt": 1735440610
+		})
+	};
+
+	// The first page starts from the ledger, the following ones from the returned cursor
+	let first_response = json!({
+		"result": {
+			"transactions": [transaction("aa", 1), transaction("bb", 1)],
+			"cursor": "page-2"
+		}
+	});
+	let second_response = json!({
+		"result": {
+			"transactions": [transaction("cc", 1), transaction("dd", 2)],
+			"cursor": "page-3"
+		}
+	});
+
+	mock_stellar
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getTransactions"),
+			predicate::eq(Some(json!({
+				"startLedger": 1,
+				"pagination": {
+					"limit": 2
+				}
+			}))),
+		)
+		.times(1)
+		.returning(move |_, _| Ok(first_response.clone()));
+	mock_stellar
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getTransactions"),
+			predicate::eq(Some(json!({
+				"pagination": {
+					"cursor": "page-2",
+					"limit": 2
+				}
+			}))),
+		)
+		.times(1)
+		.returning(move |_, _| Ok(second_response.clone()));
+
+	let client = StellarClient::new_with_transport(mock_stellar);
+
+	// Pagination stops at the first transaction past the requested ledger
+	let transactions = client.get_transactions(1, None, Some(2)).await.unwrap();
+	let hashes: Vec<&str> = transactions.iter().map(|tx| tx.hash().as_str()).collect();
+	assert_eq!(hashes, vec!["aa", "bb", "cc"]);
+}
+
+#[tokio::test]
+async fn test_get_transactions_page() {
+	let mut mock_stellar = MockStellarTransportClient::new();
+
+	mock_stellar
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getTransactions"),
+			predicate::eq(Some(json!({
+				"startLedger": 1,
+				"pagination": {
+					"limit": 200
+				}
+			}))),
+		)
+		.times(1)
+		.returning(move |_, _| {
+			Ok(json!({
+				"result": {
+					"transactions": [],
+					"cursor": "page-2"
+				}
+			}))
+		});
+
+	let client = StellarClient::new_with_transport(mock_stellar);
+
+	// Page sizes are capped to the maximum accepted by the endpoint, and an empty page ends the
+	// pagination
+	let page = client
+		.get_transactions_page(1, None, None, Some(1000))
+		.await
+		.unwrap();
+	assert!(page.items.is_empty());
+	assert_eq!(page.cursor, None);
+}
+
 #[tokio::test]
 async fn test_get_events_success() {
 	let mut mock_stellar = MockStellarTransportClient::new();
@@ -334,7 +440,7 @@ async fn test_get_events_success() {
 		.returning(move |_, _| Ok(mock_response.clone()));
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_events(1, Some(2)).await;
+	let result = client.get_events(1, Some(2), None).await;
 
 	assert!(result.is_ok());
 	let events = result.unwrap();
@@ -350,7 +456,7 @@ async fn test_get_events_invalid_sequence_range() {
 	let mock_stellar = MockStellarTransportClient::new();
 	let client = StellarClient::new_with_transport(mock_stellar);
 
-	let result = client.get_events(2, Some(1)).await;
+	let result = client.get_events(2, Some(1), None).await;
 	assert!(result.is_err());
 	let err = result.unwrap_err();
 
@@ -388,7 +494,7 @@ async fn test_get_events_failed_to_parse_event() {
 		.returning(move |_, _| Ok(mock_response.clone()));
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_events(1, Some(2)).await;
+	let result = client.get_events(1, Some(2), None).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
@@ -430,7 +536,7 @@ async fn test_get_events_outside_of_rpc_retention_window() {
 
 	let client = StellarClient::new_with_transport(mock_stellar);
 
-	let result = client.get_events(start_block, Some(end_block)).await;
+	let result = client.get_events(start_block, Some(end_block), None).await;
 
 	assert!(result.is_err());
 
@@ -471,7 +577,7 @@ async fn test_get_events_generic_rpc_error() {
 		.returning(move |_, _| Ok(mock_response.clone()));
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_events(1, Some(2)).await;
+	let result = client.get_events(1, Some(2), None).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
@@ -504,7 +610,7 @@ async fn test_get_events_unexpected_response_structure() {
 		.returning(move |_, _| Ok(mock_response.clone()));
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_events(1, Some(2)).await;
+	let result = client.get_events(1, Some(2), None).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
@@ -535,7 +641,7 @@ async fn test_get_events_transport_error() {
 		});
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_events(1, Some(2)).await;
+	let result = client.get_events(1, Some(2), None).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
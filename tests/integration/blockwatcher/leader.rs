@@ -0,0 +1,198 @@
+//! Integration tests for the leader election between monitor replicas.
+//!
+//! The Redis test needs a running Redis server and is skipped unless `REDIS_URL` is set, e.g.
+//! `REDIS_URL=redis://127.0.0.1:6379 cargo test leader`.
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::{
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
+
+use crate::integration::mocks::{
+	create_test_block, create_test_network, MockEVMTransportClient, MockEvmClientTrait,
+};
+use openzeppelin_monitor::{
+	models::{BlockChainType, BlockType, Network, ProcessedBlock},
+	services::blockwatcher::{
+		process_new_blocks, BlockStorage, BlockTracker, FileBlockStorage, LeaderElection,
+		LeaderLock, RedisLeaderLock,
+	},
+};
+
+/// Lease of the replicas, short enough for followers to retry several times per test
+const LEASE: Duration = Duration::from_millis(300);
+
+/// Lock held in memory, shared by the replicas of a test
+#[derive(Default)]
+struct MemoryLeaderLock {
+	holder: Mutex<Option<(String, Instant)>>,
+}
+
+#[async_trait]
+impl LeaderLock for MemoryLeaderLock {
+	async fn try_acquire(&self, holder_id: &str, lease: Duration) -> Result<bool, anyhow::Error> {
+		let mut holder = self.holder.lock().unwrap();
+		match holder.as_ref() {
+			Some((id, expires_at)) if id != holder_id && *expires_at > Instant::now() => Ok(false),
+			_ => {
+				*holder = Some((holder_id.to_string(), Instant::now() + lease));
+				Ok(true)
+			}
+		}
+	}
+
+	async fn release(&self, holder_id: &str) -> Result<(), anyhow::Error> {
+		let mut holder = self.holder.lock().unwrap();
+		if holder.as_ref().is_some_and(|(id, _)| id == holder_id) {
+			*holder = None;
+		}
+		Ok(())
+	}
+}
+
+/// Runs a replica that processes the new blocks of a network once elected leader
+///
+/// # Returns
+/// * `Arc<Mutex<Vec<u64>>>` - Numbers of the blocks the replica processed
+fn spawn_replica(
+	election: Arc<LeaderElection>,
+	network: Network,
+	block_storage: Arc<FileBlockStorage>,
+) -> Arc<Mutex<Vec<u64>>> {
+	let processed_blocks = Arc::new(Mutex::new(Vec::new()));
+	let block_handler = Arc::new(|block: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: block.number().unwrap(),
+				network_slug: network.slug,
+				block_hash: None,
+				processing_results: vec![],
+				block: None,
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+	let trigger_handler = {
+		let processed_blocks = processed_blocks.clone();
+		Arc::new(move |block: &ProcessedBlock| {
+			processed_blocks.lock().unwrap().push(block.block_number);
+			tokio::spawn(async {})
+		})
+	};
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(105));
+	rpc_client.expect_get_blocks().returning(|from, to| {
+		Ok((from..=to.unwrap_or(from))
+			.map(|number| create_test_block(BlockChainType::EVM, number))
+			.collect())
+	});
+
+	tokio::spawn(async move {
+		election.acquire().await;
+		process_new_blocks(
+			&network,
+			&rpc_client,
+			block_storage,
+			block_handler,
+			trigger_handler,
+			Arc::new(BlockTracker::new(10)),
+		)
+		.await
+		.unwrap();
+	});
+	processed_blocks
+}
+
+/// Verifies that only the leader processes blocks, and that a follower takes over once the
+/// leader releases the lock
+async fn assert_failover(leader: Arc<LeaderElection>, follower: Arc<LeaderElection>) {
+	let network = create_test_network("Leader Network", "leader-network", BlockChainType::EVM);
+	let temp_dir = tempfile::TempDir::new().unwrap();
+	let block_storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+	block_storage
+		.save_last_processed_block(&network.slug, 100)
+		.await
+		.unwrap();
+
+	leader.acquire().await;
+	assert!(leader.is_leader());
+	let holding = {
+		let leader = leader.clone();
+		tokio::spawn(async move { leader.hold().await })
+	};
+
+	// The follower stands by while the leader renews its lease
+	let processed_blocks = spawn_replica(follower.clone(), network.clone(), block_storage.clone());
+	tokio::time::sleep(LEASE * 2).await;
+	assert!(leader.is_leader());
+	assert!(!follower.is_leader());
+	assert!(processed_blocks.lock().unwrap().is_empty());
+
+	// The leader shuts down and releases the lock
+	holding.abort();
+	leader.resign().await.unwrap();
+	assert!(!leader.is_leader());
+
+	tokio::time::timeout(Duration::from_secs(5), async {
+		while processed_blocks.lock().unwrap().is_empty() {
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.expect("Follower didn't take over");
+	assert!(follower.is_leader());
+	assert_eq!(processed_blocks.lock().unwrap()[0], 101);
+}
+
+#[tokio::test]
+async fn test_follower_takes_over_after_leader_releases_lock() {
+	let lock: Arc<dyn LeaderLock> = Arc::new(MemoryLeaderLock::default());
+	let leader = Arc::new(LeaderElection::new(lock.clone(), "replica-1", LEASE));
+	let follower = Arc::new(LeaderElection::new(lock, "replica-2", LEASE));
+
+	assert_failover(leader, follower).await;
+}
+
+#[tokio::test]
+async fn test_follower_takes_over_expired_lease() {
+	let lock: Arc<dyn LeaderLock> = Arc::new(MemoryLeaderLock::default());
+	let leader = LeaderElection::new(lock.clone(), "replica-1", LEASE);
+	let follower = LeaderElection::new(lock, "replica-2", LEASE);
+
+	// The leader crashes without releasing the lock
+	leader.acquire().await;
+	tokio::time::timeout(LEASE * 3, follower.acquire())
+		.await
+		.expect("Follower didn't take over");
+	assert!(follower.is_leader());
+}
+
+#[tokio::test]
+async fn test_redis_follower_takes_over_after_leader_releases_lock() {
+	let Ok(redis_url) = std::env::var("REDIS_URL") else {
+		eprintln!(
+			"REDIS_URL not set, skipping test_redis_follower_takes_over_after_leader_releases_lock"
+		);
+		return;
+	};
+
+	let key = format!(
+		"oz_monitor_test:leader:{}:{}",
+		std::process::id(),
+		chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+	);
+	let lock: Arc<dyn LeaderLock> = Arc::new(
+		RedisLeaderLock::new(&redis_url, &key)
+			.await
+			.expect("Failed to connect to Redis"),
+	);
+	let leader = Arc::new(LeaderElection::new(lock.clone(), "replica-1", LEASE));
+	let follower = Arc::new(LeaderElection::new(lock.clone(), "replica-2", LEASE));
+
+	assert_failover(leader, follower.clone()).await;
+	follower.resign().await.unwrap();
+}
@@ -0,0 +1,288 @@
+use alloy::primitives::{keccak256, Address, Bytes, U256};
+use futures::{SinkExt, StreamExt};
+use openzeppelin_monitor::{
+	bootstrap::{ActiveMonitors, SharedActiveMonitors},
+	models::{
+		BlockChainType, EVMConfirmationState, EVMTransaction, Monitor, MonitorMatch, Network,
+		ProcessedBlock, TransactionStatus,
+	},
+	services::blockwatcher::{process_pending_transactions, subscribe_pending_transactions},
+	utils::tests::{
+		builders::network::NetworkBuilder,
+		evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	},
+};
+use serde_json::{json, Value};
+use std::{
+	str::FromStr,
+	sync::{Arc, Mutex},
+};
+use tokio::{net::TcpListener, sync::RwLock};
+use tokio_tungstenite::tungstenite::Message;
+
+const CONTRACT: &str = "0x0000000000000000000000000000000000004321";
+
+fn create_test_network(ws_url: &str) -> Network {
+	NetworkBuilder::new()
+		.name("Ethereum Mainnet")
+		.slug("ethereum_mainnet")
+		.network_type(BlockChainType::EVM)
+		.add_rpc_url(ws_url, "ws_rpc", 100)
+		.mempool(true)
+		.build()
+}
+
+fn create_pending_transfer(value: u64) -> EVMTransaction {
+	let mut input = keccak256("transfer(address,uint256)".as_bytes())[..4].to_vec();
+	input.extend([0u8; 64]);
+	TransactionBuilder::new()
+		.to(Address::from_str(CONTRACT).unwrap())
+		.value(U256::from(value))
+		.input(Bytes::from(input))
+		.build()
+}
+
+fn create_active_monitors(monitors: Vec<Monitor>) -> SharedActiveMonitors {
+	Arc::new(RwLock::new(ActiveMonitors {
+		monitors,
+		..Default::default()
+	}))
+}
+
+/// Returns a trigger handler recording the blocks it is called with
+fn create_recording_trigger_handler() -> (
+	impl Fn(&ProcessedBlock) -> tokio::task::JoinHandle<()>,
+	Arc<Mutex<Vec<ProcessedBlock>>>,
+) {
+	let handled = Arc::new(Mutex::new(Vec::new()));
+	let recorded = handled.clone();
+	let handler = move |block: &ProcessedBlock| {
+		recorded.lock().unwrap().push(block.clone());
+		tokio::spawn(async {})
+	};
+	(handler, handled)
+}
+
+#[tokio::test]
+async fn test_pending_transactions_are_matched_as_pending() {
+	let network = create_test_network("ws://localhost:8546");
+	let monitors = vec![
+		MonitorBuilder::new()
+			.name("Pending transfer")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.address(CONTRACT)
+			.function("transfer(address,uint256)", None)
+			.build(),
+		MonitorBuilder::new()
+			.name("Large pending transaction")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.address(CONTRACT)
+			.transaction(TransactionStatus::Any, Some("value > 1000".to_string()))
+			.build(),
+		// Event conditions can't match pending transactions
+		MonitorBuilder::new()
+			.name("Transfer event")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.address(CONTRACT)
+			.event("Transfer(address,address,uint256)", None)
+			.build(),
+	];
+	let transactions = futures::stream::iter(vec![
+		Ok(create_pending_transfer(10)),
+		Ok(TransactionBuilder::new()
+			.to(Address::from_str("0x0000000000000000000000000000000000001234").unwrap())
+			.value(U256::from(5000))
+			.build()),
+		Ok(create_pending_transfer(5000)),
+	]);
+	let (trigger_handler, handled) = create_recording_trigger_handler();
+
+	process_pending_transactions(
+		&network,
+		&create_active_monitors(monitors),
+		transactions,
+		&trigger_handler,
+	)
+	.await
+	.unwrap();
+
+	let handled = handled.lock().unwrap();
+	let matched_monitors: Vec<Vec<&str>> = handled
+		.iter()
+		.map(|block| {
+			block
+				.processing_results
+				.iter()
+				.map(|monitor_match| match monitor_match {
+					MonitorMatch::EVM(evm_match) => {
+						assert_eq!(evm_match.confirmation_state, EVMConfirmationState::Pending);
+						assert!(evm_match.receipt.is_none());
+						assert!(evm_match.logs.is_none());
+						evm_match.monitor.name.as_str()
+					}
+					_ => panic!("Expected an EVM match"),
+				})
+				.collect()
+		})
+		.collect();
+	assert_eq!(
+		matched_monitors,
+		vec![
+			vec!["Pending transfer"],
+			vec!["Pending transfer", "Large pending transaction"],
+		]
+	);
+	assert!(handled.iter().all(|block| block.block_number == 0));
+}
+
+#[tokio::test]
+async fn test_pending_transactions_stop_at_stream_error() {
+	let network = create_test_network("ws://localhost:8546");
+	let monitors = vec![MonitorBuilder::new()
+		.name("Pending transfer")
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.address(CONTRACT)
+		.build()];
+	let transactions = futures::stream::iter(vec![
+		Ok(create_pending_transfer(10)),
+		Err(anyhow::anyhow!("Connection reset")),
+		Ok(create_pending_transfer(20)),
+	]);
+	let (trigger_handler, handled) = create_recording_trigger_handler();
+
+	let result = process_pending_transactions(
+		&network,
+		&create_active_monitors(monitors),
+		transactions,
+		&trigger_handler,
+	)
+	.await;
+
+	assert!(result.is_err());
+	assert_eq!(handled.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_pending_transactions_use_reloaded_monitors() {
+	let network = create_test_network("ws://localhost:8546");
+	let active_monitors = create_active_monitors(vec![MonitorBuilder::new()
+		.name("Pending transfer")
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.address(CONTRACT)
+		.build()]);
+	let (mut transactions_tx, transactions) = futures::channel::mpsc::unbounded();
+	let (trigger_handler, handled) = create_recording_trigger_handler();
+
+	let process = {
+		let network = network.clone();
+		let active_monitors = active_monitors.clone();
+		tokio::spawn(async move {
+			process_pending_transactions(&network, &active_monitors, transactions, &trigger_handler)
+				.await
+		})
+	};
+
+	transactions_tx
+		.send(Ok(create_pending_transfer(10)))
+		.await
+		.unwrap();
+	while handled.lock().unwrap().is_empty() {
+		tokio::task::yield_now().await;
+	}
+
+	// Monitors reloaded while the subscription is open apply to the next transactions
+	active_monitors.write().await.monitors = vec![MonitorBuilder::new()
+		.name("Reloaded transfer")
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.address(CONTRACT)
+		.build()];
+	transactions_tx
+		.send(Ok(create_pending_transfer(20)))
+		.await
+		.unwrap();
+	drop(transactions_tx);
+	process.await.unwrap().unwrap();
+
+	let matched_monitors: Vec<String> = handled
+		.lock()
+		.unwrap()
+		.iter()
+		.flat_map(|block| &block.processing_results)
+		.map(|monitor_match| match monitor_match {
+			MonitorMatch::EVM(evm_match) => evm_match.monitor.name.clone(),
+			_ => panic!("Expected an EVM match"),
+		})
+		.collect();
+	assert_eq!(
+		matched_monitors,
+		vec!["Pending transfer", "Reloaded transfer"]
+	);
+}
+
+/// Starts a WebSocket server notifying a pending transaction, then the hash of another one
+async fn start_pending_transaction_server() -> String {
+	let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let url = format!("ws://{}", listener.local_addr().unwrap());
+	let transaction = serde_json::to_value(create_pending_transfer(10)).unwrap();
+
+	tokio::spawn(async move {
+		let (stream, _) = listener.accept().await.unwrap();
+		let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+		while let Some(Ok(Message::Text(text))) = socket.next().await {
+			let request: Value = serde_json::from_str(&text).unwrap();
+			let responses = match request["method"].as_str() {
+				Some("eth_subscribe") => vec![
+					json!({"jsonrpc": "2.0", "id": request["id"], "result": "0xabc"}),
+					json!({
+						"jsonrpc": "2.0",
+						"method": "eth_subscription",
+						"params": {"subscription": "0xabc", "result": transaction}
+					}),
+					json!({
+						"jsonrpc": "2.0",
+						"method": "eth_subscription",
+						"params": {"subscription": "0xabc", "result": "0x02"}
+					}),
+				],
+				Some("eth_getTransactionByHash") => {
+					assert_eq!(request["params"], json!(["0x02"]));
+					vec![json!({"jsonrpc": "2.0", "id": request["id"], "result": transaction})]
+				}
+				_ => vec![],
+			};
+			for response in responses {
+				socket
+					.send(Message::Text(response.to_string().into()))
+					.await
+					.unwrap();
+			}
+		}
+	});
+
+	url
+}
+
+#[tokio::test]
+async fn test_subscribe_pending_transactions() {
+	let url = start_pending_transaction_server().await;
+	let network = create_test_network(&url);
+
+	let transactions = subscribe_pending_transactions(&network).await.unwrap();
+	let transactions: Vec<_> = transactions.take(2).collect().await;
+
+	assert_eq!(transactions.len(), 2);
+	for transaction in transactions {
+		assert_eq!(transaction.unwrap().value, U256::from(10));
+	}
+}
+
+#[tokio::test]
+async fn test_subscribe_pending_transactions_without_ws_url() {
+	let network = NetworkBuilder::new()
+		.network_type(BlockChainType::EVM)
+		.add_rpc_url("http://localhost:8545", "rpc", 100)
+		.build();
+
+	assert!(subscribe_pending_transactions(&network).await.is_err());
+}
@@ -1,6 +1,13 @@
+use alloy::primitives::B256;
 use futures::future::BoxFuture;
 use mockall::predicate;
-use std::sync::Arc;
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
 use tokio_cron_scheduler::JobScheduler;
 
 use crate::integration::mocks::{
@@ -8,12 +15,20 @@ use crate::integration::mocks::{
 	MockEVMTransportClient, MockEvmClientTrait, MockJobScheduler,
 };
 use openzeppelin_monitor::{
-	models::{BlockChainType, BlockType, Network, ProcessedBlock},
+	bootstrap::get_network_schedules,
+	models::{AdaptivePollingConfig, BlockChainType, BlockType, EVMBlock, Network, ProcessedBlock},
 	services::blockwatcher::{
-		process_new_blocks, BlockCheckResult, BlockTracker, BlockTrackerTrait, BlockWatcherError,
-		BlockWatcherService, NetworkBlockWatcher,
+		process_new_blocks, stall_detector, BlockCheckResult, BlockStorage, BlockTracker,
+		BlockTrackerTrait, BlockWatcherError, BlockWatcherService, FileBlockStorage,
+		NetworkBlockWatcher, WatcherRestartPolicy,
+	},
+	utils::{
+		get_cron_interval_ms,
+		metrics::{
+			CATCHUP_BLOCKS_REMAINING, NETWORK_WATCHER_RESTARTS_TOTAL, REORGS_DETECTED_TOTAL,
+		},
+		tests::builders::evm::monitor::MonitorBuilder,
 	},
-	utils::get_cron_interval_ms,
 };
 
 #[derive(Clone, Default)]
@@ -177,7 +192,9 @@ async fn test_normal_block_range() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -225,7 +242,9 @@ async fn test_fresh_start_processing() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -273,7 +292,9 @@ async fn test_no_new_blocks() {
 			ProcessedBlock {
 				block_number: 0,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -351,7 +372,9 @@ async fn test_concurrent_processing() {
 				ProcessedBlock {
 					block_number,
 					network_slug: network.slug,
+					block_hash: None,
 					processing_results: vec![],
+					block: None,
 				}
 			}) as BoxFuture<'static, ProcessedBlock>
 		})
@@ -440,7 +463,9 @@ async fn test_ordered_trigger_handling() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -521,7 +546,9 @@ async fn test_block_storage_enabled() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -574,7 +601,9 @@ async fn test_max_past_blocks_limit() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -651,7 +680,9 @@ async fn test_max_past_blocks_limit_recommended() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -704,7 +735,9 @@ async fn test_confirmation_blocks() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -752,7 +785,9 @@ async fn test_process_new_blocks_storage_error() {
 			ProcessedBlock {
 				block_number: 101,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -800,7 +835,9 @@ async fn test_process_new_blocks_network_errors() {
 			ProcessedBlock {
 				block_number: 0,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -852,7 +889,9 @@ async fn test_process_new_blocks_get_blocks_error() {
 			ProcessedBlock {
 				block_number: 0,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -935,7 +974,9 @@ async fn test_process_new_blocks_storage_save_error() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1013,7 +1054,9 @@ async fn test_process_new_blocks_save_last_processed_error() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1094,7 +1137,9 @@ async fn test_process_new_blocks_storage_delete_error() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1126,7 +1171,9 @@ async fn test_network_block_watcher_new() {
 			ProcessedBlock {
 				block_number: 0,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1161,7 +1208,9 @@ async fn test_network_block_watcher_start_stop() {
 			ProcessedBlock {
 				block_number: 0,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1204,7 +1253,9 @@ async fn test_block_watcher_service_start_stop_network() {
 			ProcessedBlock {
 				block_number: 0,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1274,7 +1325,9 @@ async fn test_block_watcher_service_new() {
 			ProcessedBlock {
 				block_number: 0,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1322,7 +1375,9 @@ async fn test_process_new_blocks_get_blocks_error_fresh_start() {
 			ProcessedBlock {
 				block_number: 0,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1354,7 +1409,9 @@ async fn test_scheduler_errors() {
 			ProcessedBlock {
 				block_number: 0,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1599,7 +1656,9 @@ async fn test_missed_block_detection_and_saving() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1689,7 +1748,9 @@ async fn test_missed_block_save_error() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1782,7 +1843,9 @@ async fn test_missed_block_detection_store_disabled() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1834,7 +1897,9 @@ async fn test_scheduled_job_execution_success() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1919,7 +1984,9 @@ async fn test_scheduled_job_execution_with_processing_error() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -1968,6 +2035,182 @@ async fn test_scheduled_job_execution_with_processing_error() {
 	assert!(stop_result.is_ok());
 }
 
+#[tokio::test]
+async fn test_network_watcher_runs_monitors_on_their_schedules() {
+	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	network.cron_schedule = "* * * * * *".to_string();
+
+	let monitors = vec![
+		MonitorBuilder::new()
+			.name("every_second")
+			.networks(vec![network.slug.clone()])
+			.build(),
+		MonitorBuilder::new()
+			.name("every_three_seconds")
+			.networks(vec![network.slug.clone()])
+			.cron_schedule("*/3 * * * * *")
+			.build(),
+	];
+	let schedules = get_network_schedules(&network, &monitors);
+	assert_eq!(schedules, vec!["* * * * * *", "*/3 * * * * *"]);
+
+	// Every run processes block 101, so the handler is called once per run
+	let mut block_storage = MockBlockStorage::new();
+	block_storage
+		.expect_get_last_processed_block()
+		.returning(|_| Ok(Some(100)));
+	block_storage
+		.expect_save_last_processed_block()
+		.with(predicate::always(), predicate::eq(101))
+		.returning(|_, _| Ok(()));
+	let block_storage = Arc::new(block_storage);
+
+	let evaluations = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+	let block_handler = {
+		let evaluations = evaluations.clone();
+		let configured_network = network.clone();
+		Arc::new(move |block: BlockType, network: Network| {
+			// The job passes the network with the schedule it runs on
+			evaluations.lock().unwrap().extend(
+				monitors
+					.iter()
+					.filter(|m| {
+						m.effective_cron_schedule(&configured_network) == network.cron_schedule
+					})
+					.map(|m| m.name.clone()),
+			);
+			Box::pin(async move {
+				ProcessedBlock {
+					block_number: block.number().unwrap_or(0),
+					network_slug: network.slug,
+					block_hash: None,
+					processing_results: vec![],
+					block: None,
+				}
+			}) as BoxFuture<'static, ProcessedBlock>
+		})
+	};
+	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+
+	let mut watcher = NetworkBlockWatcher::<_, _, _, JobScheduler>::new(
+		network.clone(),
+		block_storage,
+		block_handler,
+		trigger_handler,
+		Arc::new(BlockTracker::new(10)),
+	)
+	.await
+	.unwrap()
+	.with_schedules(schedules);
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client.expect_clone().returning(|| {
+		let mut client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+		client
+			.expect_get_latest_block_number()
+			.returning(|| Ok(102));
+		client
+			.expect_get_blocks()
+			.returning(|_, _| Ok(vec![create_test_block(BlockChainType::EVM, 101)]));
+		client
+			.expect_clone()
+			.returning(MockEvmClientTrait::<MockEVMTransportClient>::new);
+		client
+	});
+
+	watcher.start(rpc_client).await.unwrap();
+	tokio::time::sleep(tokio::time::Duration::from_millis(4500)).await;
+	watcher.stop().await.unwrap();
+
+	let evaluations = evaluations.lock().unwrap();
+	let count = |name: &str| evaluations.iter().filter(|n| n.as_str() == name).count();
+	assert!(
+		(1..=2).contains(&count("every_three_seconds")),
+		"{:?}",
+		evaluations
+	);
+	assert!(count("every_second") >= 3, "{:?}", evaluations);
+	assert!(count("every_second") > count("every_three_seconds"));
+}
+
+#[tokio::test]
+async fn test_network_watcher_polls_adaptively() {
+	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	// The cron schedule doesn't fire during the test, the network is polled every 200ms
+	network.cron_schedule = "0 0 0 1 1 *".to_string();
+	network.adaptive_polling = Some(AdaptivePollingConfig {
+		min_interval_ms: 200,
+		max_interval_ms: 200,
+	});
+
+	let runs = Arc::new(AtomicUsize::new(0));
+	let mut block_storage = MockBlockStorage::new();
+	{
+		let runs = runs.clone();
+		block_storage
+			.expect_get_last_processed_block()
+			.returning(move |_| {
+				runs.fetch_add(1, Ordering::SeqCst);
+				Ok(Some(100))
+			});
+	}
+	block_storage
+		.expect_save_last_processed_block()
+		.with(predicate::always(), predicate::eq(101))
+		.returning(|_, _| Ok(()));
+
+	let block_handler = Arc::new(|block: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: block.number().unwrap_or(0),
+				network_slug: network.slug,
+				block_hash: None,
+				processing_results: vec![],
+				block: None,
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+
+	let mut watcher = NetworkBlockWatcher::<_, _, _, JobScheduler>::new(
+		network,
+		Arc::new(block_storage),
+		block_handler,
+		trigger_handler,
+		Arc::new(BlockTracker::new(10)),
+	)
+	.await
+	.unwrap();
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client.expect_clone().returning(|| {
+		let mut client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+		client
+			.expect_get_latest_block_number()
+			.returning(|| Ok(102));
+		client
+			.expect_get_blocks()
+			.returning(|_, _| Ok(vec![create_test_block(BlockChainType::EVM, 101)]));
+		client
+			.expect_clone()
+			.returning(MockEvmClientTrait::<MockEVMTransportClient>::new);
+		client
+	});
+
+	watcher.start(rpc_client).await.unwrap();
+	assert!(watcher.adaptive_task.is_some());
+	tokio::time::sleep(tokio::time::Duration::from_millis(1100)).await;
+	watcher.stop().await.unwrap();
+	assert!(watcher.adaptive_task.is_none());
+
+	let runs_at_stop = runs.load(Ordering::SeqCst);
+	assert!((4..=7).contains(&runs_at_stop), "{}", runs_at_stop);
+
+	// The task doesn't poll anymore once the watcher is stopped
+	tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+	assert_eq!(runs.load(Ordering::SeqCst), runs_at_stop);
+}
+
 #[tokio::test]
 async fn test_duplicate_block_detection() {
 	let network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
@@ -2048,7 +2291,9 @@ async fn test_duplicate_block_detection() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -2145,7 +2390,9 @@ async fn test_missed_blocks_with_store_blocks_none() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -2215,7 +2462,9 @@ async fn test_empty_blocks_from_rpc_despite_having_new_blocks() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -2320,7 +2569,9 @@ async fn test_out_of_order_in_cleanup_phase() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -2419,7 +2670,9 @@ async fn test_duplicate_detection_main_loop() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -2577,7 +2830,9 @@ async fn test_out_of_order_detection_main_loop() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -2691,7 +2946,9 @@ async fn test_duplicate_detection_in_cleanup_phase() {
 			ProcessedBlock {
 				block_number,
 				network_slug: network.slug,
+				block_hash: None,
 				processing_results: vec![],
+				block: None,
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
@@ -2723,3 +2980,547 @@ async fn test_duplicate_detection_in_cleanup_phase() {
 		"Process should succeed even with duplicate blocks in cleanup phase"
 	);
 }
+
+#[tokio::test]
+async fn test_stalled_network_detection() {
+	let mut network =
+		create_test_network("Stalled Network", "stalled-network", BlockChainType::EVM);
+	network.stall_threshold_ms = Some(50);
+
+	// The RPC client keeps returning the same latest block number
+	let config = MockConfig {
+		last_processed_block: Some(100),
+		latest_block: 100,
+		blocks_to_return: vec![],
+		expected_save_block: Some(99),
+		expected_block_range: None,
+		expected_tracked_blocks: vec![],
+		store_blocks: false,
+	};
+
+	let block_handler = Arc::new(|_: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: 0,
+				network_slug: network.slug,
+				block_hash: None,
+				processing_results: vec![],
+				block: None,
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+
+	for run in 0..2 {
+		if run > 0 {
+			// Let the stall threshold elapse without any new block
+			tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+		}
+
+		let (block_storage, block_tracker, rpc_client) =
+			setup_mocks_with_network(config.clone(), Some(&network));
+
+		let result = process_new_blocks(
+			&network,
+			&rpc_client,
+			block_storage,
+			block_handler.clone(),
+			trigger_handler.clone(),
+			Arc::new(block_tracker),
+		)
+		.await;
+		assert!(result.is_ok());
+		assert_eq!(stall_detector().is_stalled(&network.slug).await, run > 0);
+	}
+
+	assert!(stall_detector().is_stalled(&network.slug).await);
+	assert!(stall_detector()
+		.stalled_networks()
+		.await
+		.contains(&network.slug));
+}
+
+fn create_evm_block_with_hash(block_number: u64, hash: B256) -> BlockType {
+	BlockType::EVM(Box::new(EVMBlock::from(alloy::rpc::types::Block {
+		header: alloy::rpc::types::Header {
+			hash,
+			inner: alloy::consensus::Header {
+				number: block_number,
+				..Default::default()
+			},
+			..Default::default()
+		},
+		transactions: alloy::rpc::types::BlockTransactions::Full(vec![]),
+		uncles: vec![],
+		withdrawals: None,
+	})))
+}
+
+#[tokio::test]
+async fn test_reorg_detection_reprocesses_from_fork_point() {
+	let mut network = create_test_network("Reorg Network", "reorg-network", BlockChainType::EVM);
+	network.confirmation_blocks = 0;
+
+	let hash_101 = B256::repeat_byte(0x01);
+	let hash_102 = B256::repeat_byte(0x02);
+	let reorged_hash_102 = B256::repeat_byte(0x12);
+
+	let processed_blocks = Arc::new(std::sync::Mutex::new(Vec::new()));
+	let block_handler = Arc::new(|block: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: block.number().unwrap(),
+				network_slug: network.slug,
+				block_hash: None,
+				processing_results: vec![],
+				block: None,
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+	let trigger_handler = {
+		let processed_blocks = processed_blocks.clone();
+		Arc::new(move |block: &ProcessedBlock| {
+			processed_blocks.lock().unwrap().push(block.block_number);
+			tokio::spawn(async {})
+		})
+	};
+	let block_tracker = Arc::new(BlockTracker::new(10));
+
+	// First run processes blocks 101 and 102
+	let mut block_storage = MockBlockStorage::new();
+	block_storage
+		.expect_get_last_processed_block()
+		.returning(|_| Ok(Some(100)))
+		.times(1);
+	block_storage
+		.expect_save_last_processed_block()
+		.with(predicate::always(), predicate::eq(102))
+		.returning(|_, _| Ok(()))
+		.times(1);
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(102))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(101), predicate::eq(Some(102)))
+		.returning(move |_, _| {
+			Ok(vec![
+				create_evm_block_with_hash(101, hash_101),
+				create_evm_block_with_hash(102, hash_102),
+			])
+		})
+		.times(1);
+
+	let result = process_new_blocks(
+		&network,
+		&rpc_client,
+		Arc::new(block_storage),
+		block_handler.clone(),
+		trigger_handler.clone(),
+		block_tracker.clone(),
+	)
+	.await;
+	assert!(result.is_ok());
+	assert_eq!(*processed_blocks.lock().unwrap(), vec![101, 102]);
+
+	// Second run sees a different block at height 102
+	let mut block_storage = MockBlockStorage::new();
+	block_storage
+		.expect_get_last_processed_block()
+		.returning(|_| Ok(Some(102)))
+		.times(1);
+	block_storage
+		.expect_save_last_processed_block()
+		.with(predicate::always(), predicate::eq(102))
+		.returning(|_, _| Ok(()))
+		.times(1);
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(102))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(102), predicate::eq(None))
+		.returning(move |_, _| Ok(vec![create_evm_block_with_hash(102, reorged_hash_102)]))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(101), predicate::eq(Some(102)))
+		.returning(move |_, _| {
+			Ok(vec![
+				create_evm_block_with_hash(101, hash_101),
+				create_evm_block_with_hash(102, reorged_hash_102),
+			])
+		})
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(102), predicate::eq(Some(102)))
+		.returning(move |_, _| Ok(vec![create_evm_block_with_hash(102, reorged_hash_102)]))
+		.times(1);
+
+	let result = process_new_blocks(
+		&network,
+		&rpc_client,
+		Arc::new(block_storage),
+		block_handler,
+		trigger_handler,
+		block_tracker.clone(),
+	)
+	.await;
+	assert!(result.is_ok());
+
+	// Only the replaced block is processed again
+	assert_eq!(*processed_blocks.lock().unwrap(), vec![101, 102, 102]);
+	assert_eq!(
+		block_tracker.get_block_hashes(&network.slug).await,
+		vec![
+			(101, hash_101.to_string()),
+			(102, reorged_hash_102.to_string())
+		]
+	);
+	assert_eq!(
+		REORGS_DETECTED_TOTAL
+			.with_label_values(&[&network.slug])
+			.get(),
+		1
+	);
+}
+
+#[tokio::test]
+async fn test_interrupted_catchup_resumes_from_checkpoint() {
+	let mut network =
+		create_test_network("Catch-up Network", "catchup-network", BlockChainType::EVM);
+	network.max_past_blocks = Some(100);
+	network.catchup_checkpoint_blocks = Some(2);
+
+	let temp_dir = tempfile::TempDir::new().unwrap();
+	let block_storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+	block_storage
+		.save_last_processed_block(&network.slug, 100)
+		.await
+		.unwrap();
+
+	let processed_blocks = Arc::new(std::sync::Mutex::new(Vec::new()));
+	let block_handler = Arc::new(|block: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: block.number().unwrap(),
+				network_slug: network.slug,
+				block_hash: None,
+				processing_results: vec![],
+				block: None,
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+	let trigger_handler = {
+		let processed_blocks = processed_blocks.clone();
+		Arc::new(move |block: &ProcessedBlock| {
+			processed_blocks.lock().unwrap().push(block.block_number);
+			tokio::spawn(async {})
+		})
+	};
+	let create_blocks = |from: u64, to: u64| {
+		(from..=to)
+			.map(|number| create_test_block(BlockChainType::EVM, number))
+			.collect::<Vec<_>>()
+	};
+	let blocks_remaining = CATCHUP_BLOCKS_REMAINING.with_label_values(&[&network.slug]);
+
+	// The first run is interrupted after the first chunk of blocks 101 to 106
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(107))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(101), predicate::eq(Some(102)))
+		.returning(move |_, _| Ok(create_blocks(101, 102)))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(103), predicate::eq(Some(104)))
+		.returning(|_, _| Err(anyhow::anyhow!("Connection lost")))
+		.times(1);
+
+	let result = process_new_blocks(
+		&network,
+		&rpc_client,
+		block_storage.clone(),
+		block_handler.clone(),
+		trigger_handler.clone(),
+		Arc::new(BlockTracker::new(10)),
+	)
+	.await;
+	assert!(result.is_err());
+	assert_eq!(*processed_blocks.lock().unwrap(), vec![101, 102]);
+	assert_eq!(
+		block_storage
+			.get_last_processed_block(&network.slug)
+			.await
+			.unwrap(),
+		Some(102)
+	);
+	assert_eq!(blocks_remaining.get(), 4.0);
+
+	// After a restart, processing resumes from the checkpoint
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(107))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(103), predicate::eq(Some(104)))
+		.returning(move |_, _| Ok(create_blocks(103, 104)))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(105), predicate::eq(Some(106)))
+		.returning(move |_, _| Ok(create_blocks(105, 106)))
+		.times(1);
+
+	let result = process_new_blocks(
+		&network,
+		&rpc_client,
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		Arc::new(BlockTracker::new(10)),
+	)
+	.await;
+	assert!(result.is_ok());
+	assert_eq!(
+		*processed_blocks.lock().unwrap(),
+		vec![101, 102, 103, 104, 105, 106]
+	);
+	assert_eq!(
+		block_storage
+			.get_last_processed_block(&network.slug)
+			.await
+			.unwrap(),
+		Some(106)
+	);
+	assert_eq!(blocks_remaining.get(), 0.0);
+}
+
+#[tokio::test]
+async fn test_auto_past_blocks_after_downtime() {
+	let mut network = create_test_network("Auto Network", "auto-network", BlockChainType::EVM);
+	network.max_past_blocks = None;
+	network.auto_past_blocks = Some(true);
+	network.block_time_ms = 2000;
+	network.cron_schedule = "*/10 * * * * *".to_string();
+
+	// The recommended past blocks only cover one cron interval, (10s / 2s) + 1 + 1 blocks
+	assert_eq!(network.get_recommended_past_blocks(), 7);
+
+	// The service was down for 5 minutes after processing block 1000
+	let temp_dir = tempfile::TempDir::new().unwrap();
+	let block_storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+	let last_processed_time = chrono::Utc::now() - chrono::Duration::minutes(5);
+	block_storage
+		.save_last_processed_block(&network.slug, 1000)
+		.await
+		.unwrap();
+	block_storage
+		.save_last_processed_time(&network.slug, last_processed_time)
+		.await
+		.unwrap();
+
+	// 5 minutes of 2s blocks are 150 blocks, plus one, so the catch-up starts at
+	// max(1001, 1150 - 151) instead of 1150 - 7
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(1151))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(1001), predicate::eq(Some(1150)))
+		.returning(|_, _| {
+			Ok((1001..=1150)
+				.map(|number| create_test_block(BlockChainType::EVM, number))
+				.collect())
+		})
+		.times(1);
+
+	let processed_blocks = Arc::new(std::sync::Mutex::new(Vec::new()));
+	let block_handler = Arc::new(|block: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: block.number().unwrap(),
+				network_slug: network.slug,
+				block_hash: None,
+				processing_results: vec![],
+				block: None,
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+	let trigger_handler = {
+		let processed_blocks = processed_blocks.clone();
+		Arc::new(move |block: &ProcessedBlock| {
+			processed_blocks.lock().unwrap().push(block.block_number);
+			tokio::spawn(async {})
+		})
+	};
+
+	let result = process_new_blocks(
+		&network,
+		&rpc_client,
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		Arc::new(BlockTracker::new(10)),
+	)
+	.await;
+	assert!(result.is_ok());
+	assert_eq!(
+		*processed_blocks.lock().unwrap(),
+		(1001..=1150).collect::<Vec<_>>()
+	);
+	assert_eq!(
+		block_storage
+			.get_last_processed_block(&network.slug)
+			.await
+			.unwrap(),
+		Some(1150)
+	);
+	assert!(
+		block_storage
+			.get_last_processed_time(&network.slug)
+			.await
+			.unwrap()
+			.unwrap() > last_processed_time
+	);
+}
+
+/// Creates a block watcher service restarting watchers right away, for a network whose jobs
+/// never run during the test
+async fn create_supervised_service(
+	slug: &str,
+	max_attempts: u32,
+) -> (
+	Network,
+	Arc<
+		BlockWatcherService<
+			MockBlockStorage,
+			impl Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync,
+			impl Fn(&ProcessedBlock) -> tokio::task::JoinHandle<()> + Send + Sync,
+			JobScheduler,
+		>,
+	>,
+) {
+	let mut network = create_test_network("Test Network", slug, BlockChainType::EVM);
+	network.cron_schedule = "0 0 0 1 1 *".to_string();
+
+	let block_handler = Arc::new(|_: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: 0,
+				network_slug: network.slug,
+				block_hash: None,
+				processing_results: vec![],
+				block: None,
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+
+	let service = BlockWatcherService::<_, _, _, JobScheduler>::new(
+		Arc::new(MockBlockStorage::new()),
+		block_handler,
+		trigger_handler,
+		Arc::new(BlockTracker::new(10)),
+	)
+	.await
+	.unwrap()
+	.with_restart_policy(WatcherRestartPolicy {
+		max_attempts,
+		initial_backoff: Duration::from_millis(10),
+		max_backoff: Duration::from_millis(10),
+		..Default::default()
+	});
+
+	(network, Arc::new(service))
+}
+
+#[tokio::test]
+async fn test_supervised_watcher_recovers_from_client_failure() {
+	let (network, service) = create_supervised_service("test-network-supervised", 3).await;
+	let restarts = NETWORK_WATCHER_RESTARTS_TOTAL.with_label_values(&[&network.slug]);
+	let initial_restarts = restarts.get();
+	let client_fetches = Arc::new(AtomicUsize::new(0));
+
+	// The first client fetch fails, the next one succeeds
+	let supervisor = tokio::spawn({
+		let network = network.clone();
+		let service = service.clone();
+		let client_fetches = client_fetches.clone();
+		async move {
+			service
+				.supervise_network_watcher(
+					&network,
+					vec![network.cron_schedule.clone()],
+					|| async {
+						if client_fetches.fetch_add(1, Ordering::SeqCst) == 0 {
+							Err(anyhow::anyhow!("RPC unavailable"))
+						} else {
+							Ok(MockEvmClientTrait::<MockEVMTransportClient>::new())
+						}
+					},
+				)
+				.await
+		}
+	});
+
+	tokio::time::timeout(Duration::from_secs(5), async {
+		while !service
+			.active_watchers
+			.read()
+			.await
+			.contains_key(&network.slug)
+		{
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.expect("Watcher should be started after a restart");
+
+	assert_eq!(client_fetches.load(Ordering::SeqCst), 2);
+	assert_eq!(restarts.get() - initial_restarts, 1);
+
+	// Stopping the watcher ends its supervision
+	service.stop_network_watcher(&network.slug).await.unwrap();
+	let result = tokio::time::timeout(Duration::from_secs(5), supervisor)
+		.await
+		.expect("Supervision should end once the watcher is stopped")
+		.unwrap();
+	assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_supervised_watcher_gives_up_after_max_attempts() {
+	let (network, service) = create_supervised_service("test-network-given-up", 2).await;
+	let restarts = NETWORK_WATCHER_RESTARTS_TOTAL.with_label_values(&[&network.slug]);
+	let initial_restarts = restarts.get();
+	let client_fetches = AtomicUsize::new(0);
+
+	let result = service
+		.supervise_network_watcher(&network, vec![network.cron_schedule.clone()], || async {
+			client_fetches.fetch_add(1, Ordering::SeqCst);
+			Err::<MockEvmClientTrait<MockEVMTransportClient>, _>(anyhow::anyhow!("RPC unavailable"))
+		})
+		.await;
+
+	assert!(matches!(result, Err(BlockWatcherError::NetworkError(_))));
+	assert_eq!(client_fetches.load(Ordering::SeqCst), 3);
+	assert_eq!(restarts.get() - initial_restarts, 2);
+	assert!(service.active_watchers.read().await.is_empty());
+}
@@ -0,0 +1,158 @@
+//! Integration tests for the Redis block storage.
+//!
+//! These tests need a running Redis server and are skipped unless `REDIS_URL` is set, e.g.
+//! `REDIS_URL=redis://127.0.0.1:6379 cargo test redis`.
+
+use std::time::Duration;
+
+use crate::integration::mocks::create_test_block;
+use openzeppelin_monitor::{
+	models::BlockChainType,
+	services::blockwatcher::{BlockStorage, RedisBlockStorage},
+};
+
+/// Connects to the Redis server from `REDIS_URL` under a key prefix unique to the test run
+async fn create_storage(test_name: &str) -> Option<RedisBlockStorage> {
+	let Ok(redis_url) = std::env::var("REDIS_URL") else {
+		eprintln!("REDIS_URL not set, skipping {}", test_name);
+		return None;
+	};
+
+	let key_prefix = format!(
+		"oz_monitor_test:{}:{}:{}",
+		test_name,
+		std::process::id(),
+		chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+	);
+	Some(
+		RedisBlockStorage::new(&redis_url, &key_prefix)
+			.await
+			.expect("Failed to connect to Redis"),
+	)
+}
+
+#[tokio::test]
+async fn test_redis_last_processed_block() {
+	let Some(storage) = create_storage("last_processed_block").await else {
+		return;
+	};
+
+	assert_eq!(
+		storage.get_last_processed_block("ethereum").await.unwrap(),
+		None
+	);
+
+	storage
+		.save_last_processed_block("ethereum", 100)
+		.await
+		.unwrap();
+	storage
+		.save_last_processed_block("ethereum", 101)
+		.await
+		.unwrap();
+	storage
+		.save_last_processed_block("stellar", 5)
+		.await
+		.unwrap();
+
+	assert_eq!(
+		storage.get_last_processed_block("ethereum").await.unwrap(),
+		Some(101)
+	);
+	assert_eq!(
+		storage.get_last_processed_block("stellar").await.unwrap(),
+		Some(5)
+	);
+}
+
+#[tokio::test]
+async fn test_redis_last_processed_block_shared_between_replicas() {
+	let Some(replica_a) = create_storage("shared_between_replicas").await else {
+		return;
+	};
+	// A second storage with the same prefix behaves like another replica
+	let replica_b = replica_a.clone();
+
+	replica_a
+		.save_last_processed_block("ethereum", 200)
+		.await
+		.unwrap();
+
+	assert_eq!(
+		replica_b
+			.get_last_processed_block("ethereum")
+			.await
+			.unwrap(),
+		Some(200)
+	);
+}
+
+#[tokio::test]
+async fn test_redis_save_and_delete_blocks() {
+	let Some(storage) = create_storage("save_and_delete_blocks").await else {
+		return;
+	};
+
+	let blocks = vec![
+		create_test_block(BlockChainType::EVM, 1),
+		create_test_block(BlockChainType::EVM, 2),
+	];
+	storage.save_blocks("ethereum", &blocks).await.unwrap();
+	storage.delete_blocks("ethereum").await.unwrap();
+
+	// Deleting blocks that don't exist is not an error
+	storage.delete_blocks("ethereum").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_redis_missed_blocks() {
+	let Some(storage) = create_storage("missed_blocks").await else {
+		return;
+	};
+
+	storage.save_missed_blocks("ethereum", &[]).await.unwrap();
+	assert!(storage
+		.get_missed_blocks("ethereum")
+		.await
+		.unwrap()
+		.is_empty());
+
+	storage
+		.save_missed_blocks("ethereum", &[105, 101])
+		.await
+		.unwrap();
+	storage
+		.save_missed_blocks("ethereum", &[103, 101])
+		.await
+		.unwrap();
+
+	assert_eq!(
+		storage.get_missed_blocks("ethereum").await.unwrap(),
+		vec![101, 103, 105]
+	);
+}
+
+#[tokio::test]
+async fn test_redis_missed_blocks_ttl() {
+	let Some(storage) = create_storage("missed_blocks_ttl").await else {
+		return;
+	};
+	let storage = storage.with_missed_block_ttl(Duration::from_secs(1));
+
+	storage
+		.save_missed_blocks("ethereum", &[101, 102])
+		.await
+		.unwrap();
+	assert_eq!(
+		storage.get_missed_blocks("ethereum").await.unwrap(),
+		vec![101, 102]
+	);
+
+	tokio::time::sleep(Duration::from_millis(2500)).await;
+
+	assert!(storage
+		.get_missed_blocks("ethereum")
+		.await
+		.unwrap()
+		.is_empty());
+}
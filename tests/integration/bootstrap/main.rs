@@ -13,23 +13,34 @@ use crate::integration::{
 use openzeppelin_monitor::{
 	bootstrap::{
 		create_block_handler, create_trigger_handler, get_contract_specs, initialize_services,
-		process_block,
+		initialize_services_with_options, process_block, ActiveMonitors, AlertStates, InitOptions,
+		SharedActiveMonitors, TriggerTaskLimiter, MATCH_CHANNEL_CAPACITY,
 	},
 	models::{
-		AddressWithSpec, BlockChainType, ContractSpec, EVMContractSpec, EVMMonitorMatch,
-		EVMTransactionReceipt, MatchConditions, Monitor, MonitorMatch, ProcessedBlock,
-		ScriptLanguage, SecretString, SecretValue, StellarBlock, StellarContractSpec,
-		StellarFormattedContractSpec, StellarMonitorMatch, TransactionType, Trigger,
-		TriggerConditions,
+		AddressMatchMode, AddressWithSpec, BlockChainType, ContractSpec, EVMConfirmationState,
+		EVMContractSpec, EVMMonitorMatch, EVMTransactionReceipt, MatchConditions, Monitor,
+		MonitorMatch, ProcessedBlock, ScriptLanguage, SecretString, SecretValue, StellarBlock,
+		StellarContractSpec, StellarFormattedContractSpec, StellarMonitorMatch, TransactionType,
+		Trigger, TriggerConditions,
 	},
+	repositories::{MonitorRepository, NetworkRepository, TriggerRepository},
 	services::{
+		blockchain::StellarPage,
+		blockwatcher::{create_processed_block_guard, BlockStorage, FileBlockStorage},
 		filter::{stellar_helpers::are_same_address, FilterService},
 		notification::NotificationService,
-		trigger::{TriggerExecutionService, TriggerExecutionServiceTrait},
+		storage::{FileMatchStore, MatchQuery, MatchStore},
+		trigger::{
+			collapse_trigger_results, TriggerError, TriggerExecutionService,
+			TriggerExecutionServiceTrait, TriggerResult,
+		},
 	},
 	utils::{
+		logging::error::ErrorCode,
+		metrics::{DUPLICATE_BLOCKS_SKIPPED_TOTAL, TRIGGER_TASKS_DROPPED_TOTAL},
 		tests::{
 			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+			network::NetworkBuilder,
 			trigger::TriggerBuilder,
 		},
 		RetryConfig,
@@ -41,8 +52,15 @@ use stellar_xdr::curr::{
 };
 
 use serde_json::json;
-use std::{collections::HashMap, sync::Arc};
-use tokio::sync::watch;
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+use tokio::sync::{broadcast, watch, RwLock};
 
 fn create_test_monitor(
 	name: &str,
@@ -76,6 +94,9 @@ fn create_test_monitor_match(chain: BlockChainType) -> MonitorMatch {
 			logs: Some(vec![]),
 			matched_on: MatchConditions::default(),
 			matched_on_args: None,
+			confirmation_state: EVMConfirmationState::Confirmed,
+			severity: Default::default(),
+			sender_verified: None,
 		})),
 		BlockChainType::Stellar => MonitorMatch::Stellar(Box::new(StellarMonitorMatch {
 			monitor: create_test_monitor("test", vec!["stellar_mainnet"], false, vec![]),
@@ -87,6 +108,7 @@ fn create_test_monitor_match(chain: BlockChainType) -> MonitorMatch {
 			ledger: StellarBlock::default(),
 			matched_on: MatchConditions::default(),
 			matched_on_args: None,
+			severity: Default::default(),
 		})),
 		_ => panic!("Unsupported chain"),
 	}
@@ -138,6 +160,7 @@ async fn test_initialize_services() {
 		Some(mock_monitor_service),
 		Some(mock_network_service),
 		Some(mock_trigger_service),
+		None,
 	)
 	.await
 	.expect("Failed to initialize services");
@@ -164,6 +187,196 @@ async fn test_initialize_services() {
 	assert!(Arc::strong_count(&trigger_service) >= 1);
 }
 
+#[tokio::test]
+async fn test_initialize_services_with_options() {
+	let temp_dir = tempfile::tempdir().unwrap();
+	for dir in ["monitors", "networks", "triggers"] {
+		std::fs::create_dir_all(temp_dir.path().join(dir)).unwrap();
+	}
+	let network = NetworkBuilder::new()
+		.name("Ethereum Mainnet")
+		.slug("ethereum_mainnet")
+		.network_type(BlockChainType::EVM)
+		.chain_id(1)
+		.rpc_url("http://localhost:8545")
+		.block_time_ms(12000)
+		.confirmation_blocks(12)
+		.cron_schedule("0 */1 * * * *")
+		.store_blocks(false)
+		.monitor_concurrency(8)
+		.build();
+	std::fs::write(
+		temp_dir.path().join("networks/ethereum_mainnet.json"),
+		serde_json::to_string(&network).unwrap(),
+	)
+	.unwrap();
+	std::fs::write(
+		temp_dir.path().join("triggers/test_trigger.json"),
+		json!({ "test_trigger": create_test_trigger("test_trigger") }).to_string(),
+	)
+	.unwrap();
+	std::fs::write(
+		temp_dir.path().join("monitors/test.json"),
+		serde_json::to_string(&create_test_monitor(
+			"test",
+			vec!["ethereum_mainnet"],
+			false,
+			vec!["test_trigger"],
+		))
+		.unwrap(),
+	)
+	.unwrap();
+
+	let (_, trigger_execution_service, active_monitors, networks, _, network_service, _) =
+		initialize_services_with_options::<
+			MonitorRepository<NetworkRepository, TriggerRepository>,
+			NetworkRepository,
+			TriggerRepository,
+		>(
+			None,
+			None,
+			None,
+			InitOptions {
+				config_dir: Some(temp_dir.path().to_path_buf()),
+				confirmation_blocks: HashMap::from([("ethereum_mainnet".to_string(), 3)]),
+				dry_run: true,
+				max_monitor_concurrency: Some(2),
+				..Default::default()
+			},
+		)
+		.await
+		.expect("Failed to initialize services with options");
+
+	assert_eq!(active_monitors.len(), 1);
+	assert_eq!(active_monitors[0].name, "test");
+	assert_eq!(networks["ethereum_mainnet"].confirmation_blocks, 3);
+	assert_eq!(networks["ethereum_mainnet"].monitor_concurrency, Some(2));
+	assert_eq!(
+		network_service
+			.lock()
+			.await
+			.get("ethereum_mainnet")
+			.unwrap()
+			.confirmation_blocks,
+		3
+	);
+
+	// The Slack webhook isn't reachable, the trigger is skipped as it isn't executed
+	let results = trigger_execution_service
+		.execute_with_results(
+			&["test_trigger".to_string()],
+			HashMap::new(),
+			&create_test_monitor_match(BlockChainType::EVM),
+			&HashMap::new(),
+		)
+		.await;
+	assert!(results[0].outcome.is_skipped());
+}
+
+#[tokio::test]
+async fn test_initialize_services_with_options_unknown_network() {
+	let temp_dir = tempfile::tempdir().unwrap();
+	let bundle_path = write_config_bundle(temp_dir.path(), "ethereum_mainnet");
+
+	let result = initialize_services_with_options::<
+		MonitorRepository<NetworkRepository, TriggerRepository>,
+		NetworkRepository,
+		TriggerRepository,
+	>(
+		None,
+		None,
+		None,
+		InitOptions {
+			bundle_path: Some(bundle_path),
+			confirmation_blocks: HashMap::from([("missing_network".to_string(), 3)]),
+			..Default::default()
+		},
+	)
+	.await;
+
+	match result {
+		Err(e) => assert!(e.to_string().contains("unknown network missing_network")),
+		Ok(_) => panic!("Expected a confirmation override of a missing network to fail"),
+	}
+}
+
+fn write_config_bundle(dir: &std::path::Path, monitor_network: &str) -> std::path::PathBuf {
+	let network: serde_json::Value = serde_json::from_str(
+		&std::fs::read_to_string("examples/config/networks/ethereum_mainnet.json").unwrap(),
+	)
+	.unwrap();
+	let bundle = json!({
+		"networks": [network],
+		"triggers": [{
+			"name": "large_transfer_webhook",
+			"trigger_type": "webhook",
+			"config": {
+				"url": { "type": "plain", "value": "https://webhook.site/123-456-789" },
+				"method": "POST",
+				"message": { "title": "Alert", "body": "Large transfer" }
+			}
+		}],
+		"monitors": [{
+			"name": "Large Transfer",
+			"networks": [monitor_network],
+			"paused": false,
+			"addresses": [],
+			"match_conditions": { "functions": [], "events": [], "transactions": [] },
+			"trigger_conditions": [],
+			"triggers": ["large_transfer_webhook"]
+		}]
+	});
+
+	let path = dir.join("bundle.json");
+	std::fs::write(&path, bundle.to_string()).unwrap();
+	path
+}
+
+#[tokio::test]
+async fn test_initialize_services_from_config_bundle() {
+	let temp_dir = tempfile::tempdir().unwrap();
+	let bundle_path = write_config_bundle(temp_dir.path(), "ethereum_mainnet");
+
+	let (_, _, active_monitors, networks, _, _, trigger_service) =
+		initialize_services::<
+			MonitorRepository<NetworkRepository, TriggerRepository>,
+			NetworkRepository,
+			TriggerRepository,
+		>(None, None, None, Some(&bundle_path))
+		.await
+		.expect("Failed to initialize services from bundle");
+
+	assert_eq!(active_monitors.len(), 1);
+	assert_eq!(active_monitors[0].name, "Large Transfer");
+	assert_eq!(networks.len(), 1);
+	assert!(networks.contains_key("ethereum_mainnet"));
+	assert!(trigger_service
+		.lock()
+		.await
+		.get("large_transfer_webhook")
+		.is_some());
+}
+
+#[tokio::test]
+async fn test_initialize_services_from_config_bundle_invalid_reference() {
+	let temp_dir = tempfile::tempdir().unwrap();
+	let bundle_path = write_config_bundle(temp_dir.path(), "missing_network");
+
+	let result = initialize_services::<
+		MonitorRepository<NetworkRepository, TriggerRepository>,
+		NetworkRepository,
+		TriggerRepository,
+	>(None, None, None, Some(&bundle_path))
+	.await;
+
+	match result {
+		Err(e) => assert!(e
+			.to_string()
+			.contains("references non-existent network 'missing_network'")),
+		Ok(_) => panic!("Expected bundle with a missing network reference to fail"),
+	}
+}
+
 #[tokio::test]
 async fn test_create_block_handler_evm() {
 	let (shutdown_tx, _) = watch::channel(false);
@@ -197,9 +410,13 @@ async fn test_create_block_handler_evm() {
 	let block_handler = create_block_handler::<MockClientPool>(
 		shutdown_tx,
 		filter_service,
-		monitors,
+		Arc::new(RwLock::new(ActiveMonitors {
+			monitors,
+			contract_specs,
+			..Default::default()
+		})),
+		&HashMap::from([(network.slug.clone(), network.clone())]),
 		client_pool,
-		contract_specs,
 	);
 
 	let result = block_handler(block, network).await;
@@ -209,6 +426,47 @@ async fn test_create_block_handler_evm() {
 	assert!(result.processing_results.is_empty());
 }
 
+#[tokio::test]
+async fn test_create_block_handler_resumes_monitor_at_paused_until() {
+	let (shutdown_tx, _) = watch::channel(false);
+	let network = create_test_network("Ethereum", "ethereum_mainnet", BlockChainType::EVM);
+	let monitor = MonitorBuilder::new()
+		.name("scheduled")
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.paused(true)
+		.paused_until(chrono::Utc::now() + chrono::Duration::milliseconds(200))
+		.build();
+
+	let mut mock_client = MockEvmClientTrait::new();
+	mock_client
+		.expect_get_logs_for_blocks()
+		.return_once(|_, _, _| Ok(vec![]));
+	// The client is only needed once the monitor resumed
+	let mut mock_pool = MockClientPool::new();
+	mock_pool
+		.expect_get_evm_client()
+		.times(1)
+		.return_once(move |_| Ok(Arc::new(mock_client)));
+
+	let block_handler = create_block_handler::<MockClientPool>(
+		shutdown_tx,
+		Arc::new(FilterService::new()),
+		Arc::new(RwLock::new(ActiveMonitors {
+			monitors: vec![monitor],
+			..Default::default()
+		})),
+		&HashMap::from([(network.slug.clone(), network.clone())]),
+		Arc::new(mock_pool),
+	);
+
+	let result = block_handler(create_test_block(BlockChainType::EVM, 100), network.clone()).await;
+	assert!(result.processing_results.is_empty());
+
+	tokio::time::sleep(Duration::from_millis(250)).await;
+	let result = block_handler(create_test_block(BlockChainType::EVM, 101), network).await;
+	assert_eq!(result.block_number, 101);
+}
+
 #[tokio::test]
 async fn test_create_trigger_handler() {
 	// Set up expectation for the constructor first
@@ -229,10 +487,16 @@ async fn test_create_trigger_handler() {
 			.await;
 
 	let (shutdown_tx, _) = watch::channel(false);
+	let (match_tx, _) = broadcast::channel(MATCH_CHANNEL_CAPACITY);
 	let trigger_handler = create_trigger_handler(
 		shutdown_tx,
 		Arc::new(trigger_execution_service),
-		HashMap::new(),
+		SharedActiveMonitors::default(),
+		match_tx,
+		None,
+		TriggerTaskLimiter::default(),
+		None,
+		Arc::new(AlertStates::default()),
 	);
 
 	assert!(Arc::strong_count(&trigger_handler) == 1);
@@ -240,7 +504,9 @@ async fn test_create_trigger_handler() {
 	let processed_block = ProcessedBlock {
 		block_number: 100,
 		network_slug: "ethereum_mainnet".to_string(),
+		block_hash: None,
 		processing_results: vec![create_test_monitor_match(BlockChainType::EVM)],
+		block: None,
 	};
 
 	let handle = trigger_handler(&processed_block);
@@ -257,10 +523,16 @@ async fn test_create_trigger_handler_empty_matches() {
 			.await;
 
 	let (shutdown_tx, _) = watch::channel(false);
+	let (match_tx, _) = broadcast::channel(MATCH_CHANNEL_CAPACITY);
 	let trigger_handler = create_trigger_handler(
 		shutdown_tx,
 		Arc::new(trigger_execution_service),
-		HashMap::new(),
+		SharedActiveMonitors::default(),
+		match_tx,
+		None,
+		TriggerTaskLimiter::default(),
+		None,
+		Arc::new(AlertStates::default()),
 	);
 
 	assert!(Arc::strong_count(&trigger_handler) == 1);
@@ -268,7 +540,9 @@ async fn test_create_trigger_handler_empty_matches() {
 	let processed_block = ProcessedBlock {
 		block_number: 100,
 		network_slug: "ethereum_mainnet".to_string(),
+		block_hash: None,
 		processing_results: vec![],
+		block: None,
 	};
 
 	let handle = trigger_handler(&processed_block);
@@ -277,6 +551,115 @@ async fn test_create_trigger_handler_empty_matches() {
 		.expect("Trigger handler task should complete successfully");
 }
 
+#[tokio::test]
+async fn test_create_trigger_handler_records_matches() {
+	let trigger_execution_service =
+		setup_trigger_execution_service("tests/integration/fixtures/evm/triggers/trigger.json")
+			.await;
+	let temp_dir = tempfile::TempDir::new().unwrap();
+	let match_store = Arc::new(FileMatchStore::new(temp_dir.path().to_path_buf()));
+
+	let (shutdown_tx, _) = watch::channel(false);
+	let (match_tx, _) = broadcast::channel(MATCH_CHANNEL_CAPACITY);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		Arc::new(trigger_execution_service),
+		SharedActiveMonitors::default(),
+		match_tx,
+		Some(match_store.clone()),
+		TriggerTaskLimiter::default(),
+		None,
+		Arc::new(AlertStates::default()),
+	);
+
+	let processed_block = ProcessedBlock {
+		block_number: 100,
+		network_slug: "ethereum_mainnet".to_string(),
+		block_hash: None,
+		processing_results: vec![
+			create_test_monitor_match(BlockChainType::EVM),
+			create_test_monitor_match(BlockChainType::Stellar),
+		],
+		block: None,
+	};
+
+	trigger_handler(&processed_block)
+		.await
+		.expect("Trigger handler task should complete successfully");
+
+	let records = match_store.query(&MatchQuery::default()).await.unwrap();
+	assert_eq!(records.len(), 2);
+	assert_eq!(records[0].monitor_name, "test");
+	assert_eq!(records[0].network_slug, "ethereum_mainnet");
+	assert_eq!(records[0].block_number, 100);
+	assert_eq!(records[1].network_slug, "stellar_mainnet");
+}
+
+#[tokio::test]
+async fn test_create_trigger_handler_aggregates_matches_across_networks() {
+	let mut trigger_execution_service =
+		MockTriggerExecutionService::<MockTriggerRepository>::default();
+	trigger_execution_service
+		.expect_execute()
+		.withf(|triggers, variables, _, _| {
+			triggers.to_vec() == vec!["test_trigger".to_string()]
+				&& variables["aggregated.count"] == "2"
+				&& variables["aggregated.networks"] == "ethereum_mainnet, polygon_mainnet"
+		})
+		.times(1)
+		.returning(|_, _, _, _| Ok(()));
+
+	let (shutdown_tx, _) = watch::channel(false);
+	let (match_tx, _) = broadcast::channel(MATCH_CHANNEL_CAPACITY);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		Arc::new(trigger_execution_service),
+		SharedActiveMonitors::default(),
+		match_tx,
+		None,
+		TriggerTaskLimiter::default(),
+		None,
+		Arc::new(AlertStates::default()),
+	);
+
+	let monitor = MonitorBuilder::new()
+		.name("multichain")
+		.networks(vec![
+			"ethereum_mainnet".to_string(),
+			"polygon_mainnet".to_string(),
+		])
+		.triggers(vec!["test_trigger".to_string()])
+		.aggregate_across_networks(Some(200))
+		.build();
+	let processed_block = |network_slug: &str| ProcessedBlock {
+		block_number: 100,
+		network_slug: network_slug.to_string(),
+		block_hash: None,
+		processing_results: vec![MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: monitor.clone(),
+			transaction: TransactionBuilder::new().build(),
+			network_slug: network_slug.to_string(),
+			receipt: Some(EVMTransactionReceipt::default()),
+			logs: Some(vec![]),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			confirmation_state: EVMConfirmationState::Confirmed,
+			severity: Default::default(),
+			sender_verified: None,
+		}))],
+		block: None,
+	};
+
+	let first = trigger_handler(&processed_block("ethereum_mainnet"));
+	let second = trigger_handler(&processed_block("polygon_mainnet"));
+	second
+		.await
+		.expect("Trigger handler task should complete successfully");
+	first
+		.await
+		.expect("Trigger handler task should complete successfully");
+}
+
 #[tokio::test]
 async fn test_create_block_handler_stellar() {
 	let (shutdown_tx, _) = watch::channel(false);
@@ -338,9 +721,14 @@ async fn test_create_block_handler_stellar() {
 		.returning(move |_| {
 			let mut mock_client = MockStellarClientTrait::new();
 			mock_client
-				.expect_get_transactions()
+				.expect_get_transactions_page()
 				.times(1)
-				.returning(move |_, _| Ok(vec![]));
+				.returning(move |_, _, _, _| {
+					Ok(StellarPage {
+						items: vec![],
+						cursor: None,
+					})
+				});
 			Ok(Arc::new(mock_client))
 		});
 
@@ -350,9 +738,13 @@ async fn test_create_block_handler_stellar() {
 	let block_handler = create_block_handler::<MockClientPool>(
 		shutdown_tx,
 		filter_service,
-		monitors,
+		Arc::new(RwLock::new(ActiveMonitors {
+			monitors,
+			contract_specs,
+			..Default::default()
+		})),
+		&HashMap::from([(network.slug.clone(), network.clone())]),
 		Arc::new(handle_block_client_pool),
-		contract_specs,
 	);
 	let result = block_handler(block, network).await;
 
@@ -391,9 +783,13 @@ async fn test_create_block_handler_evm_client_error() {
 	let block_handler = create_block_handler::<MockClientPool>(
 		shutdown_tx,
 		filter_service,
-		monitors,
+		Arc::new(RwLock::new(ActiveMonitors {
+			monitors,
+			contract_specs,
+			..Default::default()
+		})),
+		&HashMap::from([(network.slug.clone(), network.clone())]),
 		client_pool,
-		contract_specs,
 	);
 	let result = block_handler(block, network).await;
 
@@ -430,9 +826,13 @@ async fn test_create_block_handler_stellar_client_error() {
 	let block_handler = create_block_handler::<MockClientPool>(
 		shutdown_tx,
 		filter_service,
-		monitors,
+		Arc::new(RwLock::new(ActiveMonitors {
+			monitors,
+			contract_specs,
+			..Default::default()
+		})),
+		&HashMap::from([(network.slug.clone(), network.clone())]),
 		client_pool,
-		contract_specs,
 	);
 
 	let result = block_handler(block, network).await;
@@ -479,10 +879,19 @@ print(True)  # Always return true for test
 	);
 
 	let (shutdown_tx, _) = watch::channel(false);
+	let (match_tx, _) = broadcast::channel(MATCH_CHANNEL_CAPACITY);
 	let trigger_handler = create_trigger_handler(
 		shutdown_tx,
 		Arc::new(trigger_execution_service),
-		trigger_scripts,
+		Arc::new(RwLock::new(ActiveMonitors {
+			trigger_scripts,
+			..Default::default()
+		})),
+		match_tx,
+		None,
+		TriggerTaskLimiter::default(),
+		None,
+		Arc::new(AlertStates::default()),
 	);
 
 	assert!(Arc::strong_count(&trigger_handler) == 1);
@@ -494,11 +903,15 @@ print(True)  # Always return true for test
 		language: ScriptLanguage::Python,
 		timeout_ms: 1000,
 		arguments: None,
+		max_memory_mb: None,
+		max_output_bytes: None,
+		include_block_context: false,
 	}];
 
 	let processed_block = ProcessedBlock {
 		block_number: 100,
 		network_slug: "ethereum_mainnet".to_string(),
+		block_hash: None,
 		processing_results: vec![MonitorMatch::EVM(Box::new(EVMMonitorMatch {
 			monitor,
 			transaction: TransactionBuilder::new().build(),
@@ -507,7 +920,11 @@ print(True)  # Always return true for test
 			network_slug: "ethereum_mainnet".to_string(),
 			matched_on: MatchConditions::default(),
 			matched_on_args: None,
+			confirmation_state: EVMConfirmationState::Confirmed,
+			severity: Default::default(),
+			sender_verified: None,
 		}))],
+		block: None,
 	};
 
 	let handle = trigger_handler(&processed_block);
@@ -852,7 +1269,7 @@ async fn test_load_scripts_for_custom_triggers_notifications_failed() {
 	assert!(result.is_err());
 	match result {
 		Err(e) => {
-			assert!(e.to_string().contains("Failed to get trigger"));
+			assert_eq!(e.code(), ErrorCode::TriggerConfiguration);
 		}
 		_ => panic!("Expected error"),
 	}
@@ -925,9 +1342,16 @@ async fn test_trigger_execution_service_execute_multiple_triggers_failed_retryab
 	variables.insert("value".to_string(), "42".to_string());
 	let monitor_match = create_test_monitor_match(BlockChainType::EVM);
 
-	let result = trigger_execution_service
-		.execute(&triggers, variables, &monitor_match, &HashMap::new())
+	let results = trigger_execution_service
+		.execute_with_results(&triggers, variables, &monitor_match, &HashMap::new())
 		.await;
+
+	// Each trigger reports its own failure
+	let trigger_names: Vec<_> = results.iter().map(|r| r.trigger_name.as_str()).collect();
+	assert_eq!(trigger_names, triggers);
+	assert!(results.iter().all(|r| r.outcome.is_failed()));
+
+	let result = collapse_trigger_results(results);
 	assert!(result.is_err());
 
 	match result {
@@ -1007,9 +1431,16 @@ async fn test_trigger_execution_service_execute_multiple_triggers_failed_non_ret
 	variables.insert("value".to_string(), "42".to_string());
 	let monitor_match = create_test_monitor_match(BlockChainType::EVM);
 
-	let result = trigger_execution_service
-		.execute(&triggers, variables, &monitor_match, &HashMap::new())
+	let results = trigger_execution_service
+		.execute_with_results(&triggers, variables, &monitor_match, &HashMap::new())
 		.await;
+
+	// Each trigger reports its own failure
+	let trigger_names: Vec<_> = results.iter().map(|r| r.trigger_name.as_str()).collect();
+	assert_eq!(trigger_names, triggers);
+	assert!(results.iter().all(|r| r.outcome.is_failed()));
+
+	let result = collapse_trigger_results(results);
 	assert!(result.is_err());
 
 	match result {
@@ -1176,11 +1607,18 @@ async fn test_trigger_execution_service_execute_multiple_triggers_partial_succes
 	variables.insert("value".to_string(), "42".to_string());
 	let monitor_match = create_test_monitor_match(BlockChainType::EVM);
 
-	let result = trigger_execution_service
-		.execute(&triggers, variables, &monitor_match, &HashMap::new())
+	let results = trigger_execution_service
+		.execute_with_results(&triggers, variables, &monitor_match, &HashMap::new())
 		.await;
 
-	// Assert all triggers executed successfully
+	// Only the Slack trigger failed
+	assert_eq!(results.len(), 2);
+	assert_eq!(results[0].trigger_name, "example_trigger_slack");
+	assert!(results[0].outcome.is_failed());
+	assert_eq!(results[1].trigger_name, "example_trigger_webhook");
+	assert!(results[1].outcome.is_sent());
+
+	let result = collapse_trigger_results(results);
 	assert!(result.is_err());
 
 	match result {
@@ -1239,11 +1677,13 @@ async fn test_get_contract_specs() {
 				"stateMutability": "nonpayable"
 			}]),
 		))),
+		match_mode: AddressMatchMode::Exact,
 	});
 
 	monitor.addresses.push(AddressWithSpec {
 		address: "0x1234567890123456789012345678901234567890".to_string(),
 		contract_spec: None,
+		match_mode: AddressMatchMode::Exact,
 	});
 
 	let monitors = vec![monitor];
@@ -1330,12 +1770,14 @@ async fn test_get_contract_specs() {
 				outputs: vec![ScSpecTypeDef::Bool].try_into().unwrap(),
 			}),
 		]) as StellarContractSpec)),
+		match_mode: AddressMatchMode::Exact,
 	});
 
 	// Add an address without a contract spec to test fetching from chain
 	stellar_monitor.addresses.push(AddressWithSpec {
 		address: "GZYXWVUTSRQPONMLKJIHGFEDCBA0987654321".to_string(),
 		contract_spec: None,
+		match_mode: AddressMatchMode::Exact,
 	});
 
 	let network_monitors = vec![(network, vec![stellar_monitor])];
@@ -1362,3 +1804,405 @@ async fn test_get_contract_specs() {
 		_ => panic!("Expected Stellar contract spec"),
 	}
 }
+
+/// Trigger execution service taking a while to execute, recording its concurrency
+#[derive(Default)]
+struct SlowTriggerExecutionService {
+	running: AtomicUsize,
+	max_running: AtomicUsize,
+	executed: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl TriggerExecutionServiceTrait for SlowTriggerExecutionService {
+	async fn execute_with_results(
+		&self,
+		_trigger_slugs: &[String],
+		_variables: HashMap<String, String>,
+		_monitor_match: &MonitorMatch,
+		_trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+	) -> Vec<TriggerResult> {
+		let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+		self.max_running.fetch_max(running, Ordering::SeqCst);
+		tokio::time::sleep(Duration::from_millis(20)).await;
+		self.running.fetch_sub(1, Ordering::SeqCst);
+		self.executed.fetch_add(1, Ordering::SeqCst);
+		vec![]
+	}
+
+	async fn load_scripts(
+		&self,
+		_monitors: &[Monitor],
+	) -> Result<HashMap<String, (ScriptLanguage, String)>, TriggerError> {
+		Ok(HashMap::new())
+	}
+}
+
+fn create_test_processed_block(block_number: u64) -> ProcessedBlock {
+	ProcessedBlock {
+		block_number,
+		network_slug: "ethereum_mainnet".to_string(),
+		block_hash: None,
+		processing_results: vec![create_test_monitor_match(BlockChainType::EVM)],
+		block: None,
+	}
+}
+
+#[tokio::test]
+async fn test_trigger_handler_limits_concurrent_blocks() {
+	let trigger_service = Arc::new(SlowTriggerExecutionService::default());
+	let (shutdown_tx, _) = watch::channel(false);
+	let (match_tx, _) = broadcast::channel(MATCH_CHANNEL_CAPACITY);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		trigger_service.clone(),
+		SharedActiveMonitors::default(),
+		match_tx,
+		None,
+		TriggerTaskLimiter::new(2, 100),
+		None,
+		Arc::new(AlertStates::default()),
+	);
+
+	let handles: Vec<_> = (0..20)
+		.map(|block_number| trigger_handler(&create_test_processed_block(block_number)))
+		.collect();
+	for handle in handles {
+		handle
+			.await
+			.expect("Trigger handler task should complete successfully");
+	}
+
+	assert_eq!(trigger_service.executed.load(Ordering::SeqCst), 20);
+	assert!(trigger_service.max_running.load(Ordering::SeqCst) <= 2);
+}
+
+#[tokio::test]
+async fn test_trigger_handler_drops_blocks_when_queue_is_full() {
+	let trigger_service = Arc::new(SlowTriggerExecutionService::default());
+	let (shutdown_tx, _) = watch::channel(false);
+	let (match_tx, _) = broadcast::channel(MATCH_CHANNEL_CAPACITY);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		trigger_service.clone(),
+		SharedActiveMonitors::default(),
+		match_tx,
+		None,
+		TriggerTaskLimiter::new(1, 2),
+		None,
+		Arc::new(AlertStates::default()),
+	);
+	let dropped_before = TRIGGER_TASKS_DROPPED_TOTAL.get();
+
+	// One block runs, two wait and the others are dropped
+	let handles: Vec<_> = (0..10)
+		.map(|block_number| trigger_handler(&create_test_processed_block(block_number)))
+		.collect();
+	for handle in handles {
+		handle
+			.await
+			.expect("Trigger handler task should complete successfully");
+	}
+
+	assert_eq!(trigger_service.executed.load(Ordering::SeqCst), 3);
+	assert!(TRIGGER_TASKS_DROPPED_TOTAL.get() - dropped_before >= 7);
+}
+
+#[tokio::test]
+async fn test_trigger_handler_skips_duplicate_blocks() {
+	let trigger_service = Arc::new(SlowTriggerExecutionService::default());
+	let temp_dir = tempfile::TempDir::new().unwrap();
+	let block_storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+	let (shutdown_tx, _) = watch::channel(false);
+	let (match_tx, _) = broadcast::channel(MATCH_CHANNEL_CAPACITY);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		trigger_service.clone(),
+		SharedActiveMonitors::default(),
+		match_tx,
+		None,
+		TriggerTaskLimiter::default(),
+		Some(create_processed_block_guard(block_storage)),
+		Arc::new(AlertStates::default()),
+	);
+	let skipped = DUPLICATE_BLOCKS_SKIPPED_TOTAL.with_label_values(&["ethereum_mainnet"]);
+	let skipped_before = skipped.get();
+
+	let block = ProcessedBlock {
+		block_hash: Some("0xabc".to_string()),
+		..create_test_processed_block(100)
+	};
+	// A block reusing the number after a reorg has another hash
+	let reorged_block = ProcessedBlock {
+		block_hash: Some("0xdef".to_string()),
+		..create_test_processed_block(100)
+	};
+	for block in [&block, &block, &reorged_block] {
+		trigger_handler(block)
+			.await
+			.expect("Trigger handler task should complete successfully");
+	}
+
+	assert_eq!(trigger_service.executed.load(Ordering::SeqCst), 2);
+	assert_eq!(skipped.get() - skipped_before, 1);
+}
+
+/// Trigger execution service recording the triggers executed and their variables
+#[derive(Default)]
+struct RecordingTriggerExecutionService {
+	executions: std::sync::Mutex<Vec<(Vec<String>, HashMap<String, String>)>>,
+}
+
+#[async_trait::async_trait]
+impl TriggerExecutionServiceTrait for RecordingTriggerExecutionService {
+	async fn execute_with_results(
+		&self,
+		trigger_slugs: &[String],
+		variables: HashMap<String, String>,
+		_monitor_match: &MonitorMatch,
+		_trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+	) -> Vec<TriggerResult> {
+		self.executions
+			.lock()
+			.unwrap()
+			.push((trigger_slugs.to_vec(), variables));
+		vec![]
+	}
+
+	async fn load_scripts(
+		&self,
+		_monitors: &[Monitor],
+	) -> Result<HashMap<String, (ScriptLanguage, String)>, TriggerError> {
+		Ok(HashMap::new())
+	}
+}
+
+#[tokio::test]
+async fn test_trigger_handler_notifies_resolved_alerts() {
+	let trigger_service = Arc::new(RecordingTriggerExecutionService::default());
+	let temp_dir = tempfile::TempDir::new().unwrap();
+	let block_storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+	let network_slugs = ["ethereum_mainnet".to_string()];
+	let (shutdown_tx, _) = watch::channel(false);
+	let (match_tx, _) = broadcast::channel(MATCH_CHANNEL_CAPACITY);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		trigger_service.clone(),
+		SharedActiveMonitors::default(),
+		match_tx,
+		None,
+		TriggerTaskLimiter::default(),
+		None,
+		Arc::new(
+			AlertStates::load(block_storage.clone(), &network_slugs)
+				.await
+				.unwrap(),
+		),
+	);
+
+	let monitor = MonitorBuilder::new()
+		.name("low_balance")
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.triggers(vec!["alert_trigger".to_string()])
+		.notify_on_resolve(Some("resolved_trigger"))
+		.build();
+	let monitor_match = MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor,
+		transaction: TransactionBuilder::new().build(),
+		network_slug: "ethereum_mainnet".to_string(),
+		receipt: Some(EVMTransactionReceipt::default()),
+		logs: Some(vec![]),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+		confirmation_state: EVMConfirmationState::Confirmed,
+		severity: Default::default(),
+		sender_verified: None,
+	}));
+	let processed_block =
+		|block_number: u64, processing_results: Vec<MonitorMatch>| ProcessedBlock {
+			block_number,
+			network_slug: "ethereum_mainnet".to_string(),
+			block_hash: Some(format!("0x{:x}", block_number)),
+			processing_results,
+			block: None,
+		};
+
+	// Fires, holds, resolves, stays resolved and fires again
+	let blocks = [
+		processed_block(100, vec![monitor_match.clone(), monitor_match.clone()]),
+		processed_block(101, vec![monitor_match.clone()]),
+		processed_block(102, vec![]),
+		processed_block(103, vec![]),
+		processed_block(104, vec![monitor_match.clone()]),
+	];
+	for block in &blocks[..3] {
+		trigger_handler(block)
+			.await
+			.expect("Trigger handler task should complete successfully");
+	}
+
+	// The alert firing at block 100 resolved at block 102
+	{
+		let executions = trigger_service.executions.lock().unwrap();
+		assert_eq!(executions.len(), 2);
+		assert_eq!(executions[0].0, vec!["alert_trigger".to_string()]);
+		assert!(!executions[0].1.contains_key("alert.state"));
+		assert_eq!(executions[1].0, vec!["resolved_trigger".to_string()]);
+		assert_eq!(executions[1].1["alert.state"], "resolved");
+		assert_eq!(executions[1].1["alert.resolved_block"], "102");
+		assert_eq!(executions[1].1["monitor.name"], "low_balance");
+	}
+	assert!(block_storage
+		.get_firing_alerts("ethereum_mainnet")
+		.await
+		.unwrap()
+		.is_empty());
+
+	for block in &blocks[3..] {
+		trigger_handler(block)
+			.await
+			.expect("Trigger handler task should complete successfully");
+	}
+
+	let executions = trigger_service.executions.lock().unwrap();
+	assert_eq!(executions.len(), 3);
+	assert_eq!(executions[2].0, vec!["alert_trigger".to_string()]);
+	// The firing alert is persisted, to resolve after a restart
+	drop(executions);
+	assert_eq!(
+		block_storage
+			.get_firing_alerts("ethereum_mainnet")
+			.await
+			.unwrap()
+			.len(),
+		1
+	);
+}
+
+fn create_alert_match(monitor: Monitor) -> MonitorMatch {
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor,
+		transaction: TransactionBuilder::new().build(),
+		network_slug: "ethereum_mainnet".to_string(),
+		receipt: Some(EVMTransactionReceipt::default()),
+		logs: Some(vec![]),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+		confirmation_state: EVMConfirmationState::Confirmed,
+		severity: Default::default(),
+		sender_verified: None,
+	}))
+}
+
+#[tokio::test]
+async fn test_trigger_handler_alerts_ignore_matches_rejected_by_trigger_conditions() {
+	let trigger_service = Arc::new(RecordingTriggerExecutionService::default());
+	let alert_states = Arc::new(AlertStates::default());
+	// The trigger condition filters out every match
+	let trigger_scripts = HashMap::from([(
+		"low_balance|filter.py".to_string(),
+		(ScriptLanguage::Python, "print(True)".to_string()),
+	)]);
+	let (shutdown_tx, _) = watch::channel(false);
+	let (match_tx, _) = broadcast::channel(MATCH_CHANNEL_CAPACITY);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		trigger_service.clone(),
+		Arc::new(RwLock::new(ActiveMonitors {
+			trigger_scripts,
+			..Default::default()
+		})),
+		match_tx,
+		None,
+		TriggerTaskLimiter::default(),
+		None,
+		alert_states.clone(),
+	);
+
+	let monitor = MonitorBuilder::new()
+		.name("low_balance")
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.triggers(vec!["alert_trigger".to_string()])
+		.notify_on_resolve(Some("resolved_trigger"))
+		.trigger_condition("filter.py", 1000, ScriptLanguage::Python, None)
+		.build();
+	for (block_number, processing_results) in
+		[(100, vec![create_alert_match(monitor)]), (101, vec![])]
+	{
+		trigger_handler(&ProcessedBlock {
+			block_number,
+			network_slug: "ethereum_mainnet".to_string(),
+			block_hash: Some(format!("0x{:x}", block_number)),
+			processing_results,
+			block: None,
+		})
+		.await
+		.expect("Trigger handler task should complete successfully");
+	}
+
+	// The rejected match neither fired the alert nor resolved it
+	assert!(alert_states.firing("ethereum_mainnet").is_empty());
+	assert!(trigger_service.executions.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_trigger_handler_scheduled_monitor_blocks_keep_alerts_firing() {
+	let trigger_service = Arc::new(RecordingTriggerExecutionService::default());
+	let alert_states = Arc::new(AlertStates::default());
+	let (shutdown_tx, _) = watch::channel(false);
+	let (match_tx, _) = broadcast::channel(MATCH_CHANNEL_CAPACITY);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		trigger_service.clone(),
+		SharedActiveMonitors::default(),
+		match_tx,
+		None,
+		TriggerTaskLimiter::default(),
+		None,
+		alert_states.clone(),
+	);
+
+	let low_balance = MonitorBuilder::new()
+		.name("low_balance")
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.triggers(vec!["alert_trigger".to_string()])
+		.notify_on_resolve(Some("resolved_trigger"))
+		.build();
+	let hourly = MonitorBuilder::new()
+		.name("hourly")
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.triggers(vec!["hourly_trigger".to_string()])
+		.cron_schedule("0 0 * * * *")
+		.build();
+	let blocks = [
+		(100, Some("0x64"), vec![create_alert_match(low_balance)]),
+		// Block of the job on the schedule of the hourly monitor, without the hash
+		(101, None, vec![create_alert_match(hourly)]),
+		(102, Some("0x66"), vec![]),
+	];
+	for (block_number, block_hash, processing_results) in blocks {
+		trigger_handler(&ProcessedBlock {
+			block_number,
+			network_slug: "ethereum_mainnet".to_string(),
+			block_hash: block_hash.map(str::to_string),
+			processing_results,
+			block: None,
+		})
+		.await
+		.expect("Trigger handler task should complete successfully");
+	}
+
+	// The scheduled monitor is notified without resolving the alert, which resolves at the
+	// next block of the network
+	let executions = trigger_service.executions.lock().unwrap();
+	let triggers: Vec<&str> = executions
+		.iter()
+		.map(|(triggers, _)| triggers[0].as_str())
+		.collect();
+	assert_eq!(
+		triggers,
+		vec!["alert_trigger", "hourly_trigger", "resolved_trigger"]
+	);
+	assert_eq!(executions[2].1["alert.resolved_block"], "102");
+	assert!(alert_states.firing("ethereum_mainnet").is_empty());
+}
@@ -3,20 +3,30 @@
 //! Tests the monitoring functionality for EVM-compatible blockchains,
 //! including event and transaction filtering.
 
-use alloy::primitives::Uint;
+use alloy::primitives::{keccak256, Address, Uint, U256, U64};
 use serde_json::json;
 use std::collections::HashMap;
 
 use openzeppelin_monitor::{
 	models::{
-		BlockType, ContractSpec, EVMReceiptLog, EVMTransactionReceipt, EventCondition,
-		FunctionCondition, Monitor, MonitorMatch, TransactionCondition, TransactionStatus,
+		AddressMatchMode, AddressWithSpec, BalanceCondition, BlockCondition, BlockType,
+		ChainConfiguration, ContractSpec, EVMMonitorConfig, EVMReceiptLog, EVMTraceMethod,
+		EVMTransactionReceipt, EventCondition, FunctionCondition, MatchConditions, MatchSeverity,
+		Monitor, MonitorMatch, RpcCall, StateCondition, TransactionCondition, TransactionStatus,
 	},
 	services::{
 		blockchain::{EvmClient, TransportError},
-		filter::{handle_match, FilterError, FilterService},
+		filter::{
+			evm_helpers::{b256_to_string, function_selector, h160_to_string},
+			handle_match, match_variables, FilterError, FilterService,
+		},
+	},
+	utils::{
+		metrics::EVM_FILTER_PATH_TOTAL,
+		tests::evm::{
+			monitor::MonitorBuilder, receipt::ReceiptBuilder, transaction::TransactionBuilder,
+		},
 	},
-	utils::tests::evm::{receipt::ReceiptBuilder, transaction::TransactionBuilder},
 };
 
 use crate::integration::{
@@ -69,6 +79,7 @@ fn make_monitor_with_events(mut monitor: Monitor, include_expression: bool) -> M
 		} else {
 			None
 		},
+		warn_threshold_expression: None,
 	});
 	monitor
 }
@@ -84,6 +95,8 @@ fn make_monitor_with_functions(mut monitor: Monitor, include_expression: bool) -
 		} else {
 			None
 		},
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	});
 	monitor
 }
@@ -102,6 +115,8 @@ fn make_monitor_with_transactions(mut monitor: Monitor, include_expression: bool
 			} else {
 				None
 			},
+			warn_threshold_expression: None,
+			is_contract_creation: false,
 		});
 	monitor
 }
@@ -115,6 +130,8 @@ fn make_monitor_with_tuples_expression_equality(mut monitor: Monitor) -> Monitor
 			"inputNestedStruct((bool,string,string,uint256,address,string[],(string,uint256)))"
 				.to_string(),
 		expression: Some("nestedStruct == '(true,\"The Book Title\",\"Author Name\",123,\"0x1234567890abcdef1234567890abcdef12345678\",[\"fiction\",\"bestseller\"],(\"The Sequel\",321))'".to_string()),
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	});
 	monitor
 }
@@ -442,6 +459,508 @@ async fn test_monitor_transactions_with_expressions() -> Result<(), Box<FilterEr
 	Ok(())
 }
 
+#[tokio::test]
+async fn test_monitor_transactions_with_warn_threshold_expression() -> Result<(), Box<FilterError>>
+{
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+
+	let monitor_with_threshold = |expression: &str| {
+		let mut monitor = make_monitor_with_transactions(test_data.monitor.clone(), false);
+		monitor.match_conditions.transactions[0].expression = Some(expression.to_string());
+		monitor.match_conditions.transactions[0].warn_threshold_expression =
+			Some("value == 0".to_string());
+		monitor.warn_triggers = vec!["warn_trigger".to_string()];
+		monitor
+	};
+
+	// The transaction passes the warn threshold but not the alert expression
+	let client = EvmClient::new_with_transport(setup_mock_transport(test_data.clone()));
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[monitor_with_threshold("value > 0")],
+			None,
+		)
+		.await?;
+
+	assert_eq!(matches.len(), 1, "Expected exactly one near miss");
+	assert_eq!(matches[0].severity(), MatchSeverity::Warning);
+	match &matches[0] {
+		MonitorMatch::EVM(evm_match) => {
+			assert_eq!(
+				evm_match.matched_on.transactions[0].expression,
+				Some("value == 0".to_string())
+			);
+			assert_eq!(
+				evm_match.monitor.match_conditions.transactions[0].expression,
+				Some("value > 0".to_string())
+			);
+		}
+		_ => panic!("Expected EVM match"),
+	}
+
+	// Near misses are notified to the warn triggers
+	let mut trigger_execution_service =
+		setup_trigger_execution_service("tests/integration/fixtures/evm/triggers/trigger.json")
+			.await;
+	trigger_execution_service
+		.expect_execute()
+		.withf(
+			|trigger_name, variables, _monitor_match, _trigger_scripts| {
+				trigger_name == ["warn_trigger"]
+					&& variables.get("match.severity") == Some(&"warning".to_string())
+			},
+		)
+		.once()
+		.returning(|_, _, _, _| Ok(()));
+	handle_match(
+		matches[0].clone(),
+		&trigger_execution_service,
+		&HashMap::new(),
+	)
+	.await?;
+
+	// A transaction passing the alert expression is only matched once, as an alert
+	let client = EvmClient::new_with_transport(setup_mock_transport(test_data.clone()));
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[monitor_with_threshold("value >= 0")],
+			None,
+		)
+		.await?;
+
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+	assert_eq!(matches[0].severity(), MatchSeverity::Alert);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_monitor_block_conditions() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+
+	let monitor_with_block_conditions = |expressions: &[&str]| {
+		let mut monitor = test_data.monitor.clone();
+		monitor.match_conditions = MatchConditions {
+			block_conditions: expressions
+				.iter()
+				.map(|expression| BlockCondition {
+					expression: expression.to_string(),
+				})
+				.collect(),
+			..Default::default()
+		};
+		monitor
+	};
+
+	// The block used 13890926 gas at timestamp 1733036783, and is matched once as a whole
+	let client = EvmClient::new_with_transport(setup_mock_transport(test_data.clone()));
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[monitor_with_block_conditions(&[
+				"gas_used > 13000000",
+				"timestamp >= 1733036783 AND tx_count > 0",
+				"gas_used > 20000000",
+			])],
+			None,
+		)
+		.await?;
+
+	assert_eq!(matches.len(), 1, "Expected exactly one block match");
+	match &matches[0] {
+		MonitorMatch::EVM(evm_match) => {
+			assert!(evm_match.receipt.is_none());
+			assert!(evm_match.matched_on.transactions.is_empty());
+			let matched_expressions = evm_match
+				.matched_on
+				.block_conditions
+				.iter()
+				.map(|condition| condition.expression.as_str())
+				.collect::<Vec<_>>();
+			assert_eq!(
+				matched_expressions,
+				vec![
+					"gas_used > 13000000",
+					"timestamp >= 1733036783 AND tx_count > 0"
+				]
+			);
+		}
+		_ => panic!("Expected EVM match"),
+	}
+
+	let variables = match_variables(&matches[0]);
+	assert_eq!(variables["block.gas_used"], "13890926");
+	assert_eq!(variables["block.timestamp"], "1733036783");
+	assert_eq!(variables["block.tx_count"], "3");
+	assert_eq!(
+		variables["block.miner"],
+		"0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5"
+	);
+
+	// A block not matching any block condition isn't matched, nor are its transactions
+	let client = EvmClient::new_with_transport(setup_mock_transport(test_data.clone()));
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[monitor_with_block_conditions(&[
+				"gas_used > 20000000",
+				"timestamp < 1733036783",
+			])],
+			None,
+		)
+		.await?;
+
+	assert!(matches.is_empty(), "Expected no match");
+
+	Ok(())
+}
+
+/// Creates a mock transport answering `eth_getBalance` with the balance at each block number
+fn setup_mock_transport_with_balances(
+	test_data: TestData,
+	balances: HashMap<u64, U256>,
+) -> MockEVMTransportClient {
+	let mut mock_transport = MockEVMTransportClient::new();
+	let counter = std::sync::atomic::AtomicUsize::new(0);
+	let receipts = test_data.receipts;
+
+	mock_transport
+		.expect_send_raw_request()
+		.returning(move |method, params| match method {
+			"eth_getBalance" => {
+				let params = params.unwrap();
+				assert_eq!(
+					params[0],
+					json!("0x00000000000000000000000000000000000000aa")
+				);
+				let block_number =
+					u64::from_str_radix(params[1].as_str().unwrap().trim_start_matches("0x"), 16)
+						.unwrap();
+				Ok(json!({ "result": format!("0x{:x}", balances[&block_number]) }))
+			}
+			"eth_getTransactionReceipt" => {
+				let current = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+				Ok(json!({ "result": &receipts[current % receipts.len()] }))
+			}
+			"eth_getLogs" => {
+				let current = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+				Ok(json!({ "result": &receipts[current % receipts.len()].logs }))
+			}
+			_ => Err(TransportError::http(
+				reqwest::StatusCode::METHOD_NOT_ALLOWED,
+				"random.url".to_string(),
+				"Unexpected method call".to_string(),
+				None,
+				None,
+			)),
+		});
+
+	mock_transport
+}
+
+#[tokio::test]
+async fn test_monitor_balance_conditions() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+
+	let mut monitor = test_data.monitor.clone();
+	monitor.addresses = vec![AddressWithSpec {
+		address: "0x00000000000000000000000000000000000000AA".to_string(),
+		contract_spec: None,
+		match_mode: AddressMatchMode::Exact,
+	}];
+	monitor.match_conditions = MatchConditions {
+		balance_conditions: vec![BalanceCondition {
+			expression: "balance < 1000000000000000000 AND balance_delta < 0".to_string(),
+		}],
+		..Default::default()
+	};
+
+	// The balance drops below 1 ETH at the next block
+	let block = test_data.blocks[0].clone();
+	let block_number = block.number().unwrap();
+	let mut next_block = block.clone();
+	if let BlockType::EVM(next_block) = &mut next_block {
+		next_block.0.number = Some(U64::from(block_number + 1));
+	}
+	let balances = HashMap::from([
+		(block_number, U256::from(2_000_000_000_000_000_000u128)),
+		(block_number + 1, U256::from(400_000_000_000_000_000u128)),
+	]);
+	let client = EvmClient::new_with_transport(setup_mock_transport_with_balances(
+		test_data.clone(),
+		balances,
+	));
+
+	// The first balance read has no previous balance to compare with
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&block,
+			&[monitor.clone()],
+			None,
+		)
+		.await?;
+	assert!(matches.is_empty(), "Expected no match");
+
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&next_block,
+			&[monitor.clone()],
+			None,
+		)
+		.await?;
+	assert_eq!(matches.len(), 1, "Expected exactly one balance match");
+	match &matches[0] {
+		MonitorMatch::EVM(evm_match) => {
+			assert!(evm_match.receipt.is_none());
+			assert_eq!(evm_match.matched_on.balance_conditions.len(), 1);
+		}
+		_ => panic!("Expected EVM match"),
+	}
+
+	let variables = match_variables(&matches[0]);
+	assert_eq!(
+		variables["balance.address"],
+		"0x00000000000000000000000000000000000000aa"
+	);
+	assert_eq!(variables["balance.balance"], "400000000000000000");
+	assert_eq!(variables["balance.previous_balance"], "2000000000000000000");
+	assert_eq!(variables["balance.balance_delta"], "-1600000000000000000");
+
+	// The same block processed again doesn't report the drop twice
+	let matches = filter_service
+		.filter_block(&client, &test_data.network, &next_block, &[monitor], None)
+		.await?;
+	assert!(matches.is_empty(), "Expected no match");
+
+	Ok(())
+}
+
+/// Creates a mock transport answering `custom_getThreshold` with the given response
+fn setup_mock_transport_with_custom_method(
+	test_data: TestData,
+	threshold_response: serde_json::Value,
+) -> MockEVMTransportClient {
+	let mut mock_transport = MockEVMTransportClient::new();
+	let counter = std::sync::atomic::AtomicUsize::new(0);
+	let receipts = test_data.receipts;
+
+	mock_transport
+		.expect_send_raw_request()
+		.returning(move |method, params| match method {
+			"custom_getThreshold" => {
+				assert_eq!(params, Some(vec![json!("latest")]));
+				Ok(threshold_response.clone())
+			}
+			"eth_getTransactionReceipt" => {
+				let current = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+				Ok(json!({ "result": &receipts[current] }))
+			}
+			"eth_getLogs" => {
+				let current = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+				Ok(json!({ "result": &receipts[current].logs }))
+			}
+			_ => Err(TransportError::http(
+				reqwest::StatusCode::METHOD_NOT_ALLOWED,
+				"random.url".to_string(),
+				"Unexpected method call".to_string(),
+				None,
+				None,
+			)),
+		});
+
+	mock_transport
+}
+
+/// Creates a mock transport answering `eth_call` of `paused()` with the given response
+fn setup_mock_transport_with_eth_call(
+	test_data: TestData,
+	call_response: serde_json::Value,
+) -> MockEVMTransportClient {
+	let mut mock_transport = MockEVMTransportClient::new();
+	let counter = std::sync::atomic::AtomicUsize::new(0);
+	let receipts = test_data.receipts;
+
+	mock_transport
+		.expect_send_raw_request()
+		.returning(move |method, params| match method {
+			"eth_call" => {
+				let params = params.unwrap();
+				assert_eq!(params[0]["data"], json!("0x5c975abb"));
+				assert!(params[1].as_str().unwrap().starts_with("0x"));
+				Ok(call_response.clone())
+			}
+			"eth_getTransactionReceipt" => {
+				let current = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+				Ok(json!({ "result": &receipts[current] }))
+			}
+			"eth_getLogs" => {
+				let current = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+				Ok(json!({ "result": &receipts[current].logs }))
+			}
+			_ => Err(TransportError::http(
+				reqwest::StatusCode::METHOD_NOT_ALLOWED,
+				"random.url".to_string(),
+				"Unexpected method call".to_string(),
+				None,
+				None,
+			)),
+		});
+
+	mock_transport
+}
+
+#[tokio::test]
+async fn test_monitor_transactions_with_state_conditions() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+
+	let mut monitor = make_monitor_with_transactions(test_data.monitor.clone(), true);
+	monitor.match_conditions.state_conditions = vec![StateCondition {
+		signature: "paused() returns (bool)".to_string(),
+		args: vec![],
+		expression: "result == false".to_string(),
+	}];
+
+	let filter_with_call_response = |call_response: serde_json::Value| {
+		let client = EvmClient::new_with_transport(setup_mock_transport_with_eth_call(
+			test_data.clone(),
+			call_response,
+		));
+		let filter_service = &filter_service;
+		let network = &test_data.network;
+		let block = &test_data.blocks[0];
+		let monitors = [monitor.clone()];
+		async move {
+			filter_service
+				.filter_block(&client, network, block, &monitors, None)
+				.await
+		}
+	};
+
+	// The contract isn't paused, the match is emitted
+	let matches = filter_with_call_response(json!({ "result": format!("0x{:064x}", 0) })).await?;
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+	match &matches[0] {
+		MonitorMatch::EVM(evm_match) => {
+			assert_eq!(evm_match.matched_on.transactions.len(), 1);
+			assert_eq!(
+				evm_match.matched_on.state_conditions,
+				monitor.match_conditions.state_conditions
+			);
+		}
+		_ => panic!("Expected EVM match"),
+	}
+
+	// The contract is paused, the match isn't emitted
+	let matches = filter_with_call_response(json!({ "result": format!("0x{:064x}", 1) })).await?;
+	assert!(matches.is_empty(), "Expected no match");
+
+	// The call reverts, the match isn't emitted and the block is still processed
+	let matches = filter_with_call_response(json!({
+		"error": { "code": 3, "message": "execution reverted: Pausable: paused" }
+	}))
+	.await?;
+	assert!(matches.is_empty(), "Expected no match");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_monitor_transactions_with_pre_filter_rpc_call() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+	let mock_transport =
+		setup_mock_transport_with_custom_method(test_data.clone(), json!({"result": "0"}));
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let mut monitor = make_monitor_with_transactions(test_data.monitor, false);
+	monitor.match_conditions.transactions[0].expression =
+		Some("value == ${rpc.threshold}".to_string());
+	monitor.pre_filter_rpc_calls = vec![RpcCall {
+		name: "threshold".to_string(),
+		method: "custom_getThreshold".to_string(),
+		params: Some(json!(["latest"])),
+	}];
+
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[monitor],
+			None,
+		)
+		.await?;
+
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+	match &matches[0] {
+		MonitorMatch::EVM(evm_match) => {
+			assert_eq!(
+				evm_match.matched_on.transactions[0].expression,
+				Some("value == ${rpc.threshold}".to_string())
+			);
+			assert_eq!(
+				evm_match.monitor.rpc_results.get("threshold"),
+				Some(&json!("0"))
+			);
+		}
+		_ => {
+			panic!("Expected EVM match");
+		}
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_monitor_skipped_when_pre_filter_rpc_call_fails() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+	let mock_transport = setup_mock_transport_with_custom_method(
+		test_data.clone(),
+		json!({"error": {"code": -32601, "message": "Method not found"}}),
+	);
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let mut monitor = make_monitor_with_transactions(test_data.monitor, false);
+	monitor.pre_filter_rpc_calls = vec![RpcCall {
+		name: "threshold".to_string(),
+		method: "custom_getThreshold".to_string(),
+		params: Some(json!(["latest"])),
+	}];
+
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[monitor],
+			None,
+		)
+		.await?;
+
+	assert!(matches.is_empty());
+
+	Ok(())
+}
+
 #[tokio::test]
 async fn test_monitor_with_multiple_conditions() -> Result<(), Box<FilterError>> {
 	// Load test data using common utility
@@ -623,6 +1142,8 @@ async fn test_handle_match_with_no_args() -> Result<(), Box<FilterError>> {
 	monitor.match_conditions.functions = vec![FunctionCondition {
 		signature: "increment()".to_string(),
 		expression: None,
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 	monitor.match_conditions.events = vec![];
 	monitor.match_conditions.transactions = vec![];
@@ -722,8 +1243,8 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 
 	// Create a monitor match with an argument named "signature"
 	use openzeppelin_monitor::models::{
-		EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch,
-		FunctionCondition, MatchConditions,
+		EVMConfirmationState, EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap,
+		EVMMonitorMatch, FunctionCondition, MatchConditions,
 	};
 
 	// Create test monitor with a function that has an argument called "signature"
@@ -731,6 +1252,8 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 	monitor.match_conditions.functions = vec![FunctionCondition {
 		signature: "dangerousFunc(bytes32 signature, uint256 value)".to_string(),
 		expression: None,
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 
 	fn create_test_evm_transaction_receipt() -> EVMTransactionReceipt {
@@ -752,9 +1275,15 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 			functions: vec![FunctionCondition {
 				signature: "dangerousFunc(bytes32 signature, uint256 value)".to_string(),
 				expression: None,
+				warn_threshold_expression: None,
+				eip712_schema: None,
 			}],
 			events: vec![],
 			transactions: vec![],
+			token_transfers: vec![],
+			block_conditions: vec![],
+			balance_conditions: vec![],
+			state_conditions: vec![],
 		},
 		matched_on_args: Some(EVMMatchArguments {
 			functions: Some(vec![EVMMatchParamsMap {
@@ -776,7 +1305,12 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 				hex_signature: Some("0xdeadbeef".to_string()),
 			}]),
 			events: None,
+			block: None,
+			balance: None,
 		}),
+		confirmation_state: EVMConfirmationState::Confirmed,
+		severity: Default::default(),
+		sender_verified: None,
 	};
 
 	let match_wrapper = MonitorMatch::EVM(Box::new(evm_match));
@@ -846,14 +1380,19 @@ async fn test_filter_block_with_receipt_and_logs() -> Result<(), Box<FilterError
 	monitor.match_conditions.events = vec![EventCondition {
 		signature: "Transfer(address,address,uint256)".to_string(),
 		expression: None,
+		warn_threshold_expression: None,
 	}];
 	monitor.match_conditions.functions = vec![FunctionCondition {
 		signature: "transfer(address,uint256)".to_string(),
 		expression: None,
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 	monitor.match_conditions.transactions = vec![TransactionCondition {
 		status: TransactionStatus::Any,
-		expression: Some("gas_used > 0".to_string()), // This is a test to ensure that the receipt is required
+		expression: Some("gas_used > 0".to_string()), // This is a test to ensure that the receipt is required,
+		warn_threshold_expression: None,
+		is_contract_creation: false,
 	}];
 
 	let contract_spec = test_data.contract_spec.unwrap();
@@ -934,6 +1473,59 @@ async fn test_filter_block_with_receipt_and_logs() -> Result<(), Box<FilterError
 	Ok(())
 }
 
+#[tokio::test]
+async fn test_filter_block_logs_path_matches_receipt_path() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+
+	let event_monitor = make_monitor_with_events(test_data.monitor.clone(), true);
+	// Monitoring functions in the same block sends it through the receipt path
+	let mut function_monitor = make_monitor_with_functions(test_data.monitor.clone(), false);
+	function_monitor.name = "Function Monitor".to_string();
+
+	let logs_path = EVM_FILTER_PATH_TOTAL.with_label_values(&[&test_data.network.slug, "logs"]);
+	let receipt_path =
+		EVM_FILTER_PATH_TOTAL.with_label_values(&[&test_data.network.slug, "receipt"]);
+	let logs_path_before = logs_path.get();
+	let receipt_path_before = receipt_path.get();
+
+	let client = EvmClient::new_with_transport(setup_mock_transport(test_data.clone()));
+	let logs_path_matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[event_monitor.clone()],
+			None,
+		)
+		.await?;
+	assert!(logs_path.get() > logs_path_before);
+
+	let client = EvmClient::new_with_transport(setup_mock_transport(test_data.clone()));
+	let receipt_path_matches: Vec<MonitorMatch> = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[event_monitor.clone(), function_monitor],
+			None,
+		)
+		.await?
+		.into_iter()
+		.filter(|m| matches!(m, MonitorMatch::EVM(m) if m.monitor.name == event_monitor.name))
+		.collect();
+	assert!(receipt_path.get() > receipt_path_before);
+
+	assert_eq!(logs_path_matches.len(), 1, "Expected exactly one match");
+	assert_eq!(
+		serde_json::to_value(&logs_path_matches).unwrap(),
+		serde_json::to_value(&receipt_path_matches).unwrap(),
+		"Both paths should produce the same matches"
+	);
+
+	Ok(())
+}
+
 #[tokio::test]
 async fn test_filter_block_with_only_logs() -> Result<(), Box<FilterError>> {
 	// Load test data using common utility
@@ -948,10 +1540,13 @@ async fn test_filter_block_with_only_logs() -> Result<(), Box<FilterError>> {
 	monitor.match_conditions.events = vec![EventCondition {
 		signature: "Transfer(address,address,uint256)".to_string(),
 		expression: None,
+		warn_threshold_expression: None,
 	}];
 	monitor.match_conditions.functions = vec![FunctionCondition {
 		signature: "transfer(address,uint256)".to_string(),
 		expression: None,
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 	monitor.match_conditions.transactions = vec![]; // This ensures we do not need a receipt
 
@@ -1058,10 +1653,14 @@ async fn test_filter_block_needs_receipt_for_status() -> Result<(), Box<FilterEr
 	monitor.match_conditions.functions = vec![FunctionCondition {
 		signature: "transfer(address,uint256)".to_string(),
 		expression: None,
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 	monitor.match_conditions.transactions = vec![TransactionCondition {
 		status: TransactionStatus::Success, // This is to ensure that the receipt is required
 		expression: None,
+		warn_threshold_expression: None,
+		is_contract_creation: false,
 	}];
 
 	let contract_spec = test_data.contract_spec.unwrap();
@@ -1133,6 +1732,275 @@ async fn test_filter_block_needs_receipt_for_status() -> Result<(), Box<FilterEr
 	Ok(())
 }
 
+#[tokio::test]
+async fn test_filter_block_with_internal_transactions() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+
+	// The second transaction of the block calls the USDC contract, which transfers value to the
+	// monitored address
+	let mut mock_transport = MockEVMTransportClient::new();
+	mock_transport
+		.expect_send_raw_request()
+		.returning(|method, _params| match method {
+			"eth_getLogs" => Ok(json!({ "result": [] })),
+			"debug_traceBlockByNumber" => Ok(json!({
+				"result": [
+					{ "result": { "type": "CALL", "calls": [] } },
+					{
+						"result": {
+							"type": "CALL",
+							"from": "0x58b704065b7aff3ed351052f8560019e05925023",
+							"to": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+							"value": "0x0",
+							"calls": [{
+								"type": "CALL",
+								"from": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+								"to": "0x1111111111111111111111111111111111111111",
+								"value": "0x1388"
+							}]
+						}
+					},
+					{ "result": { "type": "CALL" } }
+				]
+			})),
+			_ => Err(TransportError::http(
+				reqwest::StatusCode::METHOD_NOT_ALLOWED,
+				"random.url".to_string(),
+				"Unexpected method call".to_string(),
+				None,
+				None,
+			)),
+		});
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let mut monitor = make_monitor_with_transactions(test_data.monitor, false);
+	monitor.addresses = vec![AddressWithSpec {
+		address: "0x1111111111111111111111111111111111111111".to_string(),
+		match_mode: AddressMatchMode::Exact,
+		contract_spec: None,
+	}];
+	monitor.match_conditions.transactions = vec![TransactionCondition {
+		status: TransactionStatus::Any,
+		expression: Some("value == 5000".to_string()),
+		warn_threshold_expression: None,
+		is_contract_creation: false,
+	}];
+
+	let mut network = test_data.network;
+	network.trace_method = Some(EVMTraceMethod::DebugTraceBlockByNumber);
+
+	// Without opting in, the internal call is not traced and nothing matches
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&network,
+			&test_data.blocks[0],
+			&[monitor.clone()],
+			None,
+		)
+		.await?;
+	assert!(matches.is_empty());
+
+	monitor.chain_configurations = vec![ChainConfiguration {
+		evm: Some(EVMMonitorConfig {
+			include_internal_transactions: true,
+		}),
+		..Default::default()
+	}];
+	let matches = filter_service
+		.filter_block(&client, &network, &test_data.blocks[0], &[monitor], None)
+		.await?;
+
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+	match &matches[0] {
+		MonitorMatch::EVM(evm_match) => {
+			assert_eq!(
+				format!("{:?}", evm_match.transaction.hash),
+				"0xd5069b22a3a89a36d592d5a1f72a281bc5d11d6d0bac6f0a878c13abb764b6d8"
+			);
+			assert_eq!(
+				evm_match.matched_on.transactions[0].expression.as_deref(),
+				Some("value == 5000")
+			);
+		}
+		_ => panic!("Expected EVM match"),
+	}
+
+	// Networks whose endpoints don't support tracing fail the block with a clear error
+	let mut mock_transport = MockEVMTransportClient::new();
+	mock_transport
+		.expect_send_raw_request()
+		.returning(|method, _params| match method {
+			"eth_getLogs" => Ok(json!({ "result": [] })),
+			_ => Ok(json!({
+				"error": { "code": -32601, "message": "the method does not exist" }
+			})),
+		});
+	let client = EvmClient::new_with_transport(mock_transport);
+	let monitor = MonitorBuilder::new()
+		.include_internal_transactions(true)
+		.build();
+	let err = filter_service
+		.filter_block(&client, &network, &test_data.blocks[0], &[monitor], None)
+		.await
+		.unwrap_err();
+	assert!(err
+		.to_string()
+		.contains("Failed to get internal transactions of block"));
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_block_with_contract_creation() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+
+	let deployer = Address::repeat_byte(0x22);
+	let created_address = deployer.create(7);
+	let code = [0x60, 0x80, 0x60, 0x40];
+
+	let mut mock_transport = MockEVMTransportClient::new();
+	mock_transport
+		.expect_send_raw_request()
+		.returning(|method, _params| match method {
+			"eth_getLogs" => Ok(json!({ "result": [] })),
+			"eth_getCode" => Ok(json!({ "result": "0x60806040" })),
+			_ => Err(TransportError::http(
+				reqwest::StatusCode::METHOD_NOT_ALLOWED,
+				"random.url".to_string(),
+				"Unexpected method call".to_string(),
+				None,
+				None,
+			)),
+		});
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	// The deployer deploys a contract, then calls it
+	let mut block = test_data.blocks[0].clone();
+	if let BlockType::EVM(evm_block) = &mut block {
+		evm_block.0.transactions = vec![
+			TransactionBuilder::new()
+				.from(deployer)
+				.nonce(U256::from(7))
+				.build(),
+			TransactionBuilder::new()
+				.from(deployer)
+				.to(created_address)
+				.nonce(U256::from(8))
+				.build(),
+		];
+	}
+
+	let monitor = MonitorBuilder::new()
+		.address(&h160_to_string(deployer))
+		.contract_creation(Some(format!(
+			"code_hash == {}",
+			b256_to_string(keccak256(code))
+		)))
+		.build();
+
+	let matches = filter_service
+		.filter_block(&client, &test_data.network, &block, &[monitor], None)
+		.await?;
+
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+	match &matches[0] {
+		MonitorMatch::EVM(evm_match) => {
+			assert!(evm_match.transaction.to.is_none());
+			assert!(evm_match.matched_on.transactions[0].is_contract_creation);
+			assert_eq!(
+				match_variables(&matches[0])["transaction.created_contract_address"],
+				h160_to_string(created_address)
+			);
+		}
+		_ => panic!("Expected EVM match"),
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_block_with_revert_selector() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+
+	let contract = Address::repeat_byte(0x33);
+	let selector = format!(
+		"0x{}",
+		hex::encode(function_selector("InsufficientBalance(uint256,uint256)"))
+	);
+	let receipt = ReceiptBuilder::new().status(false).build();
+	let revert_data = format!("{}{:064x}{:064x}", selector, 100, 250);
+
+	let create_client = |traces: bool| {
+		let mut mock_transport = MockEVMTransportClient::new();
+		let receipt = receipt.clone();
+		let revert_data = revert_data.clone();
+		mock_transport
+			.expect_send_raw_request()
+			.returning(move |method, _params| match method {
+				"eth_getLogs" => Ok(json!({ "result": [] })),
+				"eth_getTransactionReceipt" => Ok(json!({ "result": receipt })),
+				"debug_traceTransaction" if traces => Ok(json!({
+					"result": {
+						"type": "CALL",
+						"error": "execution reverted",
+						"output": revert_data
+					}
+				})),
+				_ => Err(TransportError::http(
+					reqwest::StatusCode::METHOD_NOT_ALLOWED,
+					"random.url".to_string(),
+					"Unexpected method call".to_string(),
+					None,
+					None,
+				)),
+			});
+		EvmClient::new_with_transport(mock_transport)
+	};
+
+	let mut block = test_data.blocks[0].clone();
+	if let BlockType::EVM(evm_block) = &mut block {
+		evm_block.0.transactions = vec![TransactionBuilder::new().to(contract).build()];
+	}
+	let monitor = MonitorBuilder::new()
+		.address(&h160_to_string(contract))
+		.transaction(
+			TransactionStatus::Failure,
+			Some(format!("revert_selector == '{}'", selector)),
+		)
+		.build();
+
+	let mut network = test_data.network;
+	network.trace_method = Some(EVMTraceMethod::DebugTraceBlockByNumber);
+	let matches = filter_service
+		.filter_block(
+			&create_client(true),
+			&network,
+			&block,
+			&[monitor.clone()],
+			None,
+		)
+		.await?;
+
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+	let variables = match_variables(&matches[0]);
+	assert_eq!(variables["transaction.revert_selector"], selector);
+	// Custom errors aren't decoded
+	assert!(!variables.contains_key("transaction.revert_reason"));
+
+	// Networks without `debug_traceBlockByNumber` aren't traced, so the selector is unknown
+	network.trace_method = Some(EVMTraceMethod::TraceBlock);
+	let matches = filter_service
+		.filter_block(&create_client(false), &network, &block, &[monitor], None)
+		.await?;
+	assert!(matches.is_empty());
+
+	Ok(())
+}
+
 #[tokio::test]
 async fn test_filter_block_with_tuples_contains_expression() -> Result<(), Box<FilterError>> {
 	// Load test data using common utility
@@ -1154,6 +2022,8 @@ async fn test_filter_block_with_tuples_contains_expression() -> Result<(), Box<F
 			"inputNestedStruct((bool,string,string,uint256,address,string[],(string,uint256)))"
 				.to_string(),
 		expression: Some("nestedStruct contains 'The Book Title'".to_string()),
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 	monitor.match_conditions.transactions = vec![];
 
@@ -1232,6 +2102,8 @@ async fn test_filter_block_with_tuples_expression_equality() -> Result<(), Box<F
 			"inputNestedStruct((bool,string,string,uint256,address,string[],(string,uint256)))"
 				.to_string(),
 		expression: Some("nestedStruct == '(true,\"The Book Title\",\"Author Name\",123,\"0x1234567890abcdef1234567890abcdef12345678\",[\"fiction\",\"bestseller\"],(\"The Sequel\",321))'".to_string()),
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 	monitor.match_conditions.transactions = vec![];
 
@@ -7,13 +7,16 @@ use std::collections::HashMap;
 
 use openzeppelin_monitor::{
 	models::{
-		AddressWithSpec, BlockChainType, BlockType, ContractSpec, EventCondition,
+		AddressMatchMode, AddressWithSpec, BlockChainType, BlockType, ContractSpec, EventCondition,
 		FunctionCondition, MatchConditions, Monitor, MonitorMatch, StellarBlock,
 		StellarContractSpec, StellarEvent, StellarMatchArguments, StellarMatchParamEntry,
 		StellarMatchParamsMap, StellarMonitorMatch, StellarTransaction, StellarTransactionInfo,
 		TransactionCondition, TransactionStatus, TransactionType,
 	},
-	services::filter::{handle_match, FilterError, FilterService},
+	services::{
+		blockchain::StellarPage,
+		filter::{handle_match, FilterError, FilterService},
+	},
 };
 
 use crate::integration::{
@@ -40,6 +43,7 @@ fn make_monitor_with_events(mut monitor: Monitor, include_expression: bool) -> M
 		} else {
 			None
 		},
+		warn_threshold_expression: None,
 	});
 	monitor
 }
@@ -50,6 +54,7 @@ fn make_monitor_with_events_protocol_23(mut monitor: Monitor, include_expression
 	monitor.addresses = vec![AddressWithSpec {
 		address: "CBIELTK6YBZJU5UP2WWQEUCYKLPU6AUNZ2BQ4WWFEIE3USCIHMXQDAMC".to_string(),
 		contract_spec: None,
+		match_mode: AddressMatchMode::Exact,
 	}];
 	monitor.match_conditions.functions = vec![];
 	monitor.match_conditions.transactions = vec![];
@@ -61,6 +66,7 @@ fn make_monitor_with_events_protocol_23(mut monitor: Monitor, include_expression
 		} else {
 			None
 		},
+		warn_threshold_expression: None,
 	});
 	monitor
 }
@@ -75,6 +81,8 @@ fn make_monitor_with_functions(mut monitor: Monitor, include_expression: bool) -
 		} else {
 			None
 		},
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 	monitor
 }
@@ -93,6 +101,8 @@ fn make_monitor_with_transactions(mut monitor: Monitor, include_expression: bool
 			} else {
 				None
 			},
+			warn_threshold_expression: None,
+			is_contract_creation: false,
 		});
 	monitor
 }
@@ -118,14 +128,19 @@ async fn test_monitor_events_with_no_expressions() -> Result<(), Box<FilterError
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -169,6 +184,76 @@ async fn test_monitor_events_with_no_expressions() -> Result<(), Box<FilterError
 	Ok(())
 }
 
+#[tokio::test]
+async fn test_filter_block_with_paginated_transactions() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("stellar").build();
+	let filter_service = FilterService::new();
+
+	let monitor = make_monitor_with_events(test_data.monitor, false);
+	let mut network = test_data.network.clone();
+	network.page_size = Some(10);
+
+	let events: Vec<StellarEvent> =
+		read_and_parse_json("tests/integration/fixtures/stellar/events.json");
+	let transactions: Vec<StellarTransactionInfo> =
+		read_and_parse_json("tests/integration/fixtures/stellar/transactions.json");
+	let decoded_transactions: Vec<StellarTransaction> = transactions
+		.iter()
+		.map(|tx| StellarTransaction::from(tx.clone()))
+		.collect();
+
+	let mut mock_client = MockStellarClientTrait::<MockStellarTransportClient>::new();
+
+	// Transactions are returned in pages of 10, each page pointing to the next one
+	mock_client
+		.expect_get_transactions_page()
+		.withf(|_, end_sequence, _, page_size| end_sequence.is_none() && *page_size == Some(10))
+		.times(3)
+		.returning(move |_, _, cursor, _| {
+			let start = cursor.map_or(0, |cursor| cursor.parse::<usize>().unwrap());
+			let end = (start + 10).min(decoded_transactions.len());
+			Ok(StellarPage {
+				items: decoded_transactions[start..end].to_vec(),
+				cursor: (end < decoded_transactions.len()).then(|| end.to_string()),
+			})
+		});
+
+	mock_client
+		.expect_get_events()
+		.withf(|_, end_sequence, page_size| end_sequence.is_none() && *page_size == Some(10))
+		.times(1)
+		.returning(move |_, _, _| Ok(events.clone()));
+
+	mock_client
+		.expect_get_contract_spec()
+		.returning(move |_| Ok(test_data.contract_spec.clone().unwrap()));
+
+	let matches = filter_service
+		.filter_block(
+			&mock_client,
+			&network,
+			&test_data.blocks[0],
+			&[monitor],
+			None,
+		)
+		.await?;
+
+	// The match is found whichever page holds the transaction
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+	match &matches[0] {
+		MonitorMatch::Stellar(stellar_match) => {
+			assert_eq!(stellar_match.matched_on.events.len(), 1);
+			assert_eq!(
+				stellar_match.matched_on.events[0].signature,
+				"transfer(Address,Address,String,I128)"
+			);
+		}
+		_ => panic!("Expected Stellar match"),
+	}
+
+	Ok(())
+}
+
 #[tokio::test]
 async fn test_monitor_events_with_expressions() -> Result<(), Box<FilterError>> {
 	// Load test data using common utility
@@ -191,14 +276,19 @@ async fn test_monitor_events_with_expressions() -> Result<(), Box<FilterError>>
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -301,14 +391,19 @@ async fn test_monitor_functions_with_no_expressions() -> Result<(), Box<FilterEr
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -381,14 +476,19 @@ async fn test_monitor_functions_with_expressions() -> Result<(), Box<FilterError
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -475,14 +575,19 @@ async fn test_monitor_transactions_with_expressions() -> Result<(), Box<FilterEr
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -546,14 +651,19 @@ async fn test_monitor_transactions_with_no_expressions() -> Result<(), Box<Filte
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -621,14 +731,19 @@ async fn test_monitor_with_multiple_conditions() -> Result<(), Box<FilterError>>
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -760,14 +875,19 @@ async fn test_handle_match() -> Result<(), Box<FilterError>> {
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -858,6 +978,8 @@ async fn test_handle_match_with_no_args() -> Result<(), Box<FilterError>> {
 	monitor.match_conditions.functions = vec![FunctionCondition {
 		signature: "increment()".to_string(),
 		expression: None,
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 	monitor.match_conditions.events = vec![];
 	monitor.match_conditions.transactions = vec![];
@@ -881,14 +1003,19 @@ async fn test_handle_match_with_no_args() -> Result<(), Box<FilterError>> {
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -983,6 +1110,8 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 	monitor.match_conditions.functions = vec![FunctionCondition {
 		signature: "riskyFunction(String signature, I128 amount)".to_string(),
 		expression: None,
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 
 	fn create_test_stellar_transaction() -> StellarTransaction {
@@ -1009,9 +1138,15 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 			functions: vec![FunctionCondition {
 				signature: "riskyFunction(String signature, I128 amount)".to_string(),
 				expression: None,
+				warn_threshold_expression: None,
+				eip712_schema: None,
 			}],
 			events: vec![],
 			transactions: vec![],
+			token_transfers: vec![],
+			block_conditions: vec![],
+			balance_conditions: vec![],
+			state_conditions: vec![],
 		},
 		matched_on_args: Some(StellarMatchArguments {
 			functions: Some(vec![StellarMatchParamsMap {
@@ -1032,7 +1167,10 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 				]),
 			}]),
 			events: None,
+			block: None,
+			balance: None,
 		}),
+		severity: Default::default(),
 	};
 
 	let match_wrapper = MonitorMatch::Stellar(Box::new(stellar_match));
@@ -1115,14 +1253,19 @@ async fn test_filter_with_contract_spec() -> Result<(), Box<FilterError>> {
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	// Expect contract spec to be called
 	mock_client
@@ -1134,6 +1277,8 @@ async fn test_filter_with_contract_spec() -> Result<(), Box<FilterError>> {
 	monitor.match_conditions.functions = vec![FunctionCondition {
 		signature: "increment()".to_string(),
 		expression: None,
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 	monitor.match_conditions.events = vec![];
 	monitor.match_conditions.transactions = vec![];
@@ -1185,14 +1330,19 @@ async fn test_filter_with_invalid_contract_spec() -> Result<(), Box<FilterError>
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	// Setup mock to return error for contract spec
 	mock_client
@@ -1204,6 +1354,8 @@ async fn test_filter_with_invalid_contract_spec() -> Result<(), Box<FilterError>
 	monitor.match_conditions.functions = vec![FunctionCondition {
 		signature: "increment()".to_string(),
 		expression: None,
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 	monitor.match_conditions.events = vec![];
 	monitor.match_conditions.transactions = vec![];
@@ -1250,14 +1402,19 @@ async fn test_filter_with_abi_in_config() -> Result<(), Box<FilterError>> {
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	// get_contract_spec should NOT be called since we provide the ABI in config
 	mock_client.expect_get_contract_spec().times(0);
@@ -1267,6 +1424,8 @@ async fn test_filter_with_abi_in_config() -> Result<(), Box<FilterError>> {
 	monitor.match_conditions.functions = vec![FunctionCondition {
 		signature: "increment()".to_string(),
 		expression: None,
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 	monitor.match_conditions.events = vec![];
 	monitor.match_conditions.transactions = vec![];
@@ -1275,6 +1434,7 @@ async fn test_filter_with_abi_in_config() -> Result<(), Box<FilterError>> {
 	monitor.addresses = vec![AddressWithSpec {
 		address: contract_with_spec.0.clone(),
 		contract_spec: Some(contract_with_spec.1.clone()),
+		match_mode: AddressMatchMode::Exact,
 	}];
 
 	// Run filter_block with the test data
@@ -1372,14 +1532,19 @@ async fn test_filter_with_udt_expression() -> Result<(), Box<FilterError>> {
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	// get_contract_spec should NOT be called since we provide the ABI in config
 	mock_client.expect_get_contract_spec().times(0);
@@ -1392,6 +1557,8 @@ async fn test_filter_with_udt_expression() -> Result<(), Box<FilterError>> {
 			"requests contains CAS3J7GYLGXMF6TDJBBYYSE3HQ6BBSMLNUQ34T6TZMYMW2EVH34XOWMA"
 				.to_string(),
 		),
+		warn_threshold_expression: None,
+		eip712_schema: None,
 	}];
 	monitor.match_conditions.events = vec![];
 	monitor.match_conditions.transactions = vec![];
@@ -1400,6 +1567,7 @@ async fn test_filter_with_udt_expression() -> Result<(), Box<FilterError>> {
 	monitor.addresses = vec![AddressWithSpec {
 		address: contract_with_spec.0.clone(),
 		contract_spec: Some(contract_with_spec.1.clone()),
+		match_mode: AddressMatchMode::Exact,
 	}];
 
 	// Run filter_block with the test data
@@ -1467,14 +1635,19 @@ async fn test_handle_match_with_event_spec() -> Result<(), Box<FilterError>> {
 
 	// Setup mock expectations
 	mock_client
-		.expect_get_transactions()
+		.expect_get_transactions_page()
 		.times(1)
-		.returning(move |_, _| Ok(decoded_transactions.clone()));
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
 
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| {
+		.returning(move |_, _, _| {
 			Ok(vec![StellarEvent {
 				event_type: "contract".to_string(),
 				topic_xdr: Some(vec!["AAAADwAAAAR1c2Vy".to_string(), "AAAADwAAAANhZGQA".to_string(), "AAAADwAAAAExAAAA".to_string()]),
@@ -1575,3 +1748,81 @@ async fn test_handle_match_with_event_spec() -> Result<(), Box<FilterError>> {
 
 	Ok(())
 }
+
+#[tokio::test]
+async fn test_concurrent_monitor_evaluation_matches_sequential() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("stellar").build();
+	let filter_service = FilterService::new();
+
+	let mut event_monitor = make_monitor_with_events(test_data.monitor.clone(), false);
+	event_monitor.name = "Event monitor".to_string();
+	let mut function_monitor = make_monitor_with_functions(test_data.monitor.clone(), false);
+	function_monitor.name = "Function monitor".to_string();
+	let mut transaction_monitor = make_monitor_with_transactions(test_data.monitor.clone(), false);
+	transaction_monitor.name = "Transaction monitor".to_string();
+	let monitors = vec![event_monitor, function_monitor, transaction_monitor];
+
+	let events: Vec<StellarEvent> =
+		read_and_parse_json("tests/integration/fixtures/stellar/events.json");
+	let transactions: Vec<StellarTransactionInfo> =
+		read_and_parse_json("tests/integration/fixtures/stellar/transactions.json");
+	let decoded_transactions: Vec<StellarTransaction> = transactions
+		.iter()
+		.map(|tx| StellarTransaction::from(tx.clone()))
+		.collect();
+
+	let mut mock_client = MockStellarClientTrait::<MockStellarTransportClient>::new();
+	mock_client
+		.expect_get_transactions_page()
+		.times(2)
+		.returning(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: decoded_transactions.clone(),
+				cursor: None,
+			})
+		});
+	mock_client
+		.expect_get_events()
+		.times(2)
+		.returning(move |_, _, _| Ok(events.clone()));
+	let contract_spec = test_data.contract_spec.clone().unwrap();
+	mock_client
+		.expect_get_contract_spec()
+		.returning(move |_| Ok(contract_spec.clone()));
+
+	let sequential_network = test_data.network.clone();
+	let mut concurrent_network = test_data.network.clone();
+	concurrent_network.monitor_concurrency = Some(3);
+
+	let sequential_matches = filter_service
+		.filter_block(
+			&mock_client,
+			&sequential_network,
+			&test_data.blocks[0],
+			&monitors,
+			None,
+		)
+		.await?;
+	let concurrent_matches = filter_service
+		.filter_block(
+			&mock_client,
+			&concurrent_network,
+			&test_data.blocks[0],
+			&monitors,
+			None,
+		)
+		.await?;
+
+	assert_eq!(
+		sequential_matches.len(),
+		3,
+		"Expected one match per monitor"
+	);
+	assert_eq!(
+		serde_json::to_value(&sequential_matches).unwrap(),
+		serde_json::to_value(&concurrent_matches).unwrap(),
+		"Concurrent evaluation should produce the same matches in the same order"
+	);
+
+	Ok(())
+}
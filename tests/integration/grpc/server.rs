@@ -0,0 +1,112 @@
+use std::{sync::Arc, time::Duration};
+use tokio::{
+	net::TcpListener,
+	sync::{broadcast, watch},
+};
+
+use crate::integration::mocks::{MockTriggerExecutionService, MockTriggerRepository};
+use openzeppelin_monitor::{
+	bootstrap::{
+		create_trigger_handler, AlertStates, SharedActiveMonitors, TriggerTaskLimiter,
+		MATCH_CHANNEL_CAPACITY,
+	},
+	models::{
+		EVMConfirmationState, EVMMonitorMatch, EVMTransactionReceipt, MatchConditions,
+		MonitorMatch, ProcessedBlock,
+	},
+	services::grpc::{
+		proto::{
+			match_stream_client::MatchStreamClient, monitor_match::Match, SubscribeMatchesRequest,
+		},
+		serve,
+	},
+	utils::tests::evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+};
+
+fn create_test_monitor_match(monitor_name: &str) -> MonitorMatch {
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor: MonitorBuilder::new().name(monitor_name).build(),
+		transaction: TransactionBuilder::new().build(),
+		receipt: Some(EVMTransactionReceipt::default()),
+		logs: Some(vec![]),
+		network_slug: "ethereum_mainnet".to_string(),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+		confirmation_state: EVMConfirmationState::Confirmed,
+		severity: Default::default(),
+		sender_verified: None,
+	}))
+}
+
+#[tokio::test]
+async fn test_subscriber_receives_matches_from_trigger_handler() {
+	let (shutdown_tx, _) = watch::channel(false);
+	let (match_tx, _) = broadcast::channel(MATCH_CHANNEL_CAPACITY);
+
+	let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let address = listener.local_addr().unwrap();
+	let mut shutdown_rx = shutdown_tx.subscribe();
+	let server = tokio::spawn(serve(listener, match_tx.clone(), async move {
+		let _ = shutdown_rx.changed().await;
+	}));
+
+	let mut client = MatchStreamClient::connect(format!("http://{}", address))
+		.await
+		.unwrap();
+	let mut stream = client
+		.subscribe_matches(SubscribeMatchesRequest {
+			monitor_names: vec!["Large Transfer".to_string()],
+			network_slugs: vec![],
+		})
+		.await
+		.unwrap()
+		.into_inner();
+
+	let mut trigger_service = MockTriggerExecutionService::<MockTriggerRepository>::default();
+	trigger_service
+		.expect_execute()
+		.returning(|_, _, _, _| Ok(()));
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx.clone(),
+		Arc::new(trigger_service),
+		SharedActiveMonitors::default(),
+		match_tx,
+		None,
+		TriggerTaskLimiter::default(),
+		None,
+		Arc::new(AlertStates::default()),
+	);
+
+	// The match of the other monitor is filtered out by the subscription
+	let processed_block = ProcessedBlock {
+		block_number: 100,
+		network_slug: "ethereum_mainnet".to_string(),
+		block_hash: None,
+		processing_results: vec![
+			create_test_monitor_match("Approvals"),
+			create_test_monitor_match("Large Transfer"),
+		],
+		block: None,
+	};
+	trigger_handler(&processed_block).await.unwrap();
+
+	let message = tokio::time::timeout(Duration::from_secs(5), stream.message())
+		.await
+		.expect("Timed out waiting for a match")
+		.unwrap()
+		.expect("Stream ended before a match was received");
+	let Some(Match::Evm(evm_match)) = message.r#match else {
+		panic!("Expected an EVM match");
+	};
+	assert_eq!(evm_match.monitor_name, "Large Transfer");
+	assert_eq!(evm_match.network_slug, "ethereum_mainnet");
+
+	drop(stream);
+	drop(client);
+	let _ = shutdown_tx.send(true);
+	tokio::time::timeout(Duration::from_secs(5), server)
+		.await
+		.expect("Timed out waiting for the server to stop")
+		.unwrap()
+		.unwrap();
+}
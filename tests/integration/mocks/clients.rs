@@ -5,31 +5,39 @@
 //! - [`MockEvmClientTrait`] - Mock implementation of EVM blockchain client
 //! - [`MockStellarClientTrait`] - Mock implementation of Stellar blockchain client
 //! - [`MockMidnightClientTrait`] - Mock implementation of Midnight blockchain client
+//! - [`MockSolanaClientTrait`] - Mock implementation of Solana blockchain client
 //! - [`MockClientPool`] - Mock implementation of the client pool
 //! - [`MockSubstrateClient`] - Mock implementation of the Substrate client
 //!
 //! These mocks allow testing blockchain-related functionality without actual
 //! network connections.
 
-use super::{MockEVMTransportClient, MockMidnightWsTransportClient, MockStellarTransportClient};
+use super::{
+	MockEVMTransportClient, MockMidnightWsTransportClient, MockSolanaTransportClient,
+	MockStellarTransportClient,
+};
 
 use openzeppelin_monitor::{
 	models::{
-		BlockType, ContractSpec, EVMReceiptLog, EVMTransactionReceipt, MidnightEvent, Network,
+		BlockType, ContractSpec, EVMInternalCall, EVMReceiptLog, EVMTraceMethod,
+		EVMTransactionReceipt, MidnightEvent, Network, SolanaSignatureInfo, SolanaTransaction,
 		StellarEvent, StellarTransaction,
 	},
 	services::{
 		blockchain::{
-			BlockChainClient, BlockFilterFactory, ClientPoolTrait, EvmClientTrait,
-			MidnightClientTrait, MidnightSubstrateClientTrait, StellarClientTrait,
+			BlockChainClient, BlockFilterFactory, ClientPoolTrait, ContractCallResult,
+			EvmClientTrait, MidnightClientTrait, MidnightSubstrateClientTrait, SolanaClientTrait,
+			StellarClientTrait, StellarPage,
 		},
-		filter::{EVMBlockFilter, MidnightBlockFilter, StellarBlockFilter},
+		filter::{EVMBlockFilter, MidnightBlockFilter, SolanaBlockFilter, StellarBlockFilter},
 	},
 };
 
 use async_trait::async_trait;
 use mockall::{mock, predicate::*};
+use serde_json::Value;
 use std::{marker::PhantomData, sync::Arc};
+use stellar_xdr::curr::ScVal;
 
 mock! {
 	/// Mock implementation of the EVM client trait.
@@ -48,6 +56,11 @@ mock! {
 			start_block: u64,
 			end_block: Option<u64>,
 		) -> Result<Vec<BlockType>, anyhow::Error>;
+		async fn call_rpc(
+			&self,
+			method: &str,
+			params: Option<Value>,
+		) -> Result<Value, anyhow::Error>;
 	}
 
 	#[async_trait]
@@ -63,6 +76,30 @@ mock! {
 			to_block: u64,
 			addresses: Option<Vec<String>>,
 		) -> Result<Vec<EVMReceiptLog>,  anyhow::Error>;
+
+		async fn get_internal_calls(
+			&self,
+			block_number: u64,
+			trace_method: EVMTraceMethod,
+		) -> Result<Vec<EVMInternalCall>, anyhow::Error>;
+
+		async fn call_contract(
+			&self,
+			to: &str,
+			data: Vec<u8>,
+			block_number: u64,
+		) -> Result<ContractCallResult<Vec<u8>>, anyhow::Error>;
+
+		async fn get_code(
+			&self,
+			address: &str,
+			block_number: u64,
+		) -> Result<Vec<u8>, anyhow::Error>;
+
+		async fn get_revert_data(
+			&self,
+			transaction_hash: &str,
+		) -> Result<Option<Vec<u8>>, anyhow::Error>;
 	}
 
 	impl<T: Send + Sync + Clone + 'static> Clone for EvmClientTrait<T> {
@@ -101,15 +138,38 @@ mock! {
 			&self,
 			start_sequence: u32,
 			end_sequence: Option<u32>,
+			page_size: Option<u32>,
 		) -> Result<Vec<StellarTransaction>, anyhow::Error>;
 
+		async fn get_transactions_page(
+			&self,
+			start_sequence: u32,
+			end_sequence: Option<u32>,
+			cursor: Option<String>,
+			page_size: Option<u32>,
+		) -> Result<StellarPage<StellarTransaction>, anyhow::Error>;
+
 		async fn get_events(
 			&self,
 			start_sequence: u32,
 			end_sequence: Option<u32>,
+			page_size: Option<u32>,
 		) -> Result<Vec<StellarEvent>, anyhow::Error>;
 
+		async fn get_events_page(
+			&self,
+			start_sequence: u32,
+			end_sequence: Option<u32>,
+			cursor: Option<String>,
+			page_size: Option<u32>,
+		) -> Result<StellarPage<StellarEvent>, anyhow::Error>;
 
+		async fn simulate_contract_call(
+			&self,
+			contract_id: &str,
+			function_name: &str,
+			args: Vec<ScVal>,
+		) -> Result<ContractCallResult<ScVal>, anyhow::Error>;
 	}
 
 	impl<T: Send + Sync + Clone + 'static> Clone for StellarClientTrait<T> {
@@ -156,6 +216,57 @@ mock! {
 	}
 }
 
+mock! {
+	/// Mock implementation of the Solana client trait.
+	///
+	/// This mock allows testing Solana-specific functionality by simulating blockchain
+	/// responses without actual network calls.
+	pub SolanaClientTrait<T: Send + Sync + Clone + 'static> {
+		pub fn new_with_transport(transport: T, network: &Network) -> Self;
+	}
+
+	#[async_trait]
+	impl<T: Send + Sync + Clone + 'static> BlockChainClient for SolanaClientTrait<T> {
+		async fn get_latest_block_number(&self) -> Result<u64, anyhow::Error>;
+		async fn get_blocks(
+			&self,
+			start_block: u64,
+			end_block: Option<u64>,
+		) -> Result<Vec<BlockType>, anyhow::Error>;
+	}
+
+	#[async_trait]
+	impl<T: Send + Sync + Clone + 'static> SolanaClientTrait for SolanaClientTrait<T> {
+		async fn get_transaction(
+			&self,
+			signature: String,
+		) -> Result<SolanaTransaction, anyhow::Error>;
+
+		async fn get_signatures_for_address(
+			&self,
+			address: String,
+			limit: Option<u32>,
+		) -> Result<Vec<SolanaSignatureInfo>, anyhow::Error>;
+	}
+
+	impl<T: Send + Sync + Clone + 'static> Clone for SolanaClientTrait<T> {
+		fn clone(&self) -> Self {
+			Self{}
+		}
+	}
+}
+
+impl<T: Send + Sync + Clone + 'static> BlockFilterFactory<MockSolanaClientTrait<T>>
+	for MockSolanaClientTrait<T>
+{
+	type Filter = SolanaBlockFilter<MockSolanaClientTrait<T>>;
+	fn filter() -> Self::Filter {
+		SolanaBlockFilter {
+			_client: PhantomData,
+		}
+	}
+}
+
 impl<T: Send + Sync + Clone + 'static> BlockFilterFactory<MockMidnightClientTrait<T>>
 	for MockMidnightClientTrait<T>
 {
@@ -174,6 +285,7 @@ impl<T: Send + Sync + Clone + 'static> BlockFilterFactory<MockStellarClientTrait
 	fn filter() -> Self::Filter {
 		StellarBlockFilter {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		}
 	}
 }
@@ -185,6 +297,7 @@ impl<T: Send + Sync + Clone + 'static> BlockFilterFactory<MockEvmClientTrait<T>>
 	fn filter() -> Self::Filter {
 		EVMBlockFilter {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		}
 	}
 }
@@ -198,9 +311,11 @@ mock! {
 		type EvmClient = MockEvmClientTrait<MockEVMTransportClient>;
 		type StellarClient = MockStellarClientTrait<MockStellarTransportClient>;
 		type MidnightClient = MockMidnightClientTrait<MockMidnightWsTransportClient>;
+		type SolanaClient = MockSolanaClientTrait<MockSolanaTransportClient>;
 		async fn get_evm_client(&self, network: &Network) -> Result<Arc<MockEvmClientTrait<MockEVMTransportClient>>,  anyhow::Error>;
 		async fn get_stellar_client(&self, network: &Network) -> Result<Arc<MockStellarClientTrait<MockStellarTransportClient>>,  anyhow::Error>;
 		async fn get_midnight_client(&self, network: &Network) -> Result<Arc<MockMidnightClientTrait<MockMidnightWsTransportClient>>,  anyhow::Error>;
+		async fn get_solana_client(&self, network: &Network) -> Result<Arc<MockSolanaClientTrait<MockSolanaTransportClient>>,  anyhow::Error>;
 	}
 
 	impl Clone for ClientPool {
@@ -33,6 +33,9 @@ mock! {
 	impl TriggerRepositoryTrait for TriggerRepository {
 		#[mockall::concretize]
 		async fn new(path: Option<&Path>) -> Result<Self, RepositoryError>
+		where
+			Self: Sized;
+		fn new_with_triggers(triggers: HashMap<String, Trigger>) -> Self
 		where
 			Self: Sized;
 		#[mockall::concretize]
@@ -59,6 +62,9 @@ mock! {
 	impl NetworkRepositoryTrait for NetworkRepository {
 		#[mockall::concretize]
 		async fn new(path: Option<&Path>) -> Result<Self, RepositoryError>
+		where
+			Self: Sized;
+		fn new_with_networks(networks: HashMap<String, Network>) -> Self
 		where
 			Self: Sized;
 		#[mockall::concretize]
@@ -91,6 +97,9 @@ mock! {
 			network_service: Option<NetworkService<N>>,
 			trigger_service: Option<TriggerService<T>>,
 		) -> Result<Self, RepositoryError>
+		where
+			Self: Sized;
+		fn new_with_monitors(monitors: HashMap<String, Monitor>) -> Self
 		where
 			Self: Sized;
 		#[mockall::concretize]
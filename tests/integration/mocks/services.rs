@@ -6,11 +6,11 @@ use openzeppelin_monitor::{
 	models::{BlockType, ContractSpec, Monitor, MonitorMatch, Network, ScriptLanguage},
 	repositories::{TriggerRepositoryTrait, TriggerService},
 	services::{
-		blockchain::BlockFilterFactory,
+		blockchain::{BlockChainClient, BlockFilterFactory},
 		blockwatcher::{BlockCheckResult, BlockStorage, BlockTrackerTrait, JobSchedulerTrait},
 		filter::{FilterError, FilterServiceTrait},
 		notification::NotificationService,
-		trigger::{TriggerError, TriggerExecutionServiceTrait},
+		trigger::{TriggerError, TriggerExecutionServiceTrait, TriggerResult},
 	},
 };
 
@@ -28,6 +28,13 @@ mock! {
 			monitor_match: &MonitorMatch,
 			trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
 		) -> Result<(), TriggerError>;
+		async fn execute_with_results(
+			&self,
+			trigger_slugs: &[String],
+			variables: HashMap<String, String>,
+			monitor_match: &MonitorMatch,
+			trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+		) -> Vec<TriggerResult>;
 		async fn load_scripts(&self, monitors: &[Monitor]) -> Result<HashMap<String, (ScriptLanguage, String)>, TriggerError>;
 	}
 }
@@ -46,7 +53,7 @@ mock! {
 
 #[async_trait]
 impl FilterServiceTrait for MockFilterService {
-	async fn filter_block<T: BlockFilterFactory<T> + Send + Sync + 'static>(
+	async fn filter_block<T: BlockChainClient + BlockFilterFactory<T> + Send + Sync + 'static>(
 		&self,
 		client: &T,
 		network: &Network,
@@ -1,6 +1,7 @@
 use crate::integration::{
 	filters::common::{
-		setup_monitor_service, setup_network_service, setup_trigger_service, TestDataBuilder,
+		setup_monitor_service, setup_network_service, setup_trigger_service, TestData,
+		TestDataBuilder,
 	},
 	mocks::{
 		create_test_network, MockClientPool, MockEVMTransportClient, MockEvmClientTrait,
@@ -12,19 +13,27 @@ use crate::integration::{
 use mockall::predicate;
 use openzeppelin_monitor::{
 	models::{
-		BlockChainType, EVMTransactionReceipt, Monitor, ScriptLanguage, Trigger, TriggerConditions,
+		BlockChainType, EVMTransactionReceipt, Monitor, MonitorMatch, ScriptLanguage, Trigger,
+		TriggerConditions,
 	},
 	repositories::{
 		MonitorRepository, MonitorRepositoryTrait, NetworkRepository, NetworkService,
 		RepositoryError, TriggerRepository, TriggerService,
 	},
 	services::{
+		blockchain::StellarPage,
 		filter::{FilterError, FilterService},
 		notification::NotificationService,
 		trigger::TriggerExecutionService,
 	},
 	utils::{
-		monitor::execution::{execute_monitor, MonitorExecutionConfig},
+		monitor::{
+			execution::{
+				execute_monitor, execute_monitor_matches, write_matches, MatchOutputFormat,
+				MonitorExecutionConfig,
+			},
+			MonitorExecutionError,
+		},
 		tests::builders::{evm::monitor::MonitorBuilder, trigger::TriggerBuilder},
 	},
 };
@@ -154,6 +163,52 @@ fn create_test_monitor(
 #[tokio::test]
 async fn test_execute_monitor_evm() {
 	let test_data = TestDataBuilder::new("evm").build();
+	let result = execute_evm_fixture_monitor(test_data).await;
+	assert!(
+		result.is_ok(),
+		"Monitor execution failed: {:?}",
+		result.err()
+	);
+
+	// Parse the JSON result and add more specific assertions based on expected matches
+	let matches: Vec<serde_json::Value> = serde_json::from_str(&result.unwrap()).unwrap();
+	assert!(matches.len() == 1);
+}
+
+#[tokio::test]
+async fn test_write_matches_of_evm_monitor_execution() {
+	let test_data = TestDataBuilder::new("evm").build();
+	let monitor_name = test_data.monitor.name.clone();
+	let matches = execute_evm_fixture_monitor(test_data).await.unwrap();
+	let temp_dir = TempDir::new().unwrap();
+
+	let json_path = temp_dir.path().join("matches.json");
+	write_matches(&matches, &json_path, MatchOutputFormat::Json).unwrap();
+	let written: Vec<serde_json::Value> =
+		serde_json::from_str(&fs::read_to_string(&json_path).unwrap()).unwrap();
+	assert_eq!(
+		written,
+		serde_json::from_str::<Vec<serde_json::Value>>(&matches).unwrap()
+	);
+	assert_eq!(written.len(), 1);
+	assert_eq!(written[0]["EVM"]["monitor"]["name"], monitor_name);
+	assert_eq!(written[0]["EVM"]["network_slug"], "ethereum_mainnet");
+
+	let jsonl_path = temp_dir.path().join("matches.jsonl");
+	write_matches(&matches, &jsonl_path, MatchOutputFormat::Jsonl).unwrap();
+	let lines = fs::read_to_string(&jsonl_path)
+		.unwrap()
+		.lines()
+		.map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+		.collect::<Vec<_>>();
+	assert_eq!(lines, written);
+
+	// Matches that aren't a JSON array can't be written as lines
+	assert!(write_matches("{}", &jsonl_path, MatchOutputFormat::Jsonl).is_err());
+}
+
+/// Executes the monitor of the EVM fixtures against block 21305050 of the fixtures
+async fn execute_evm_fixture_monitor(test_data: TestData) -> Result<String, MonitorExecutionError> {
 	let receipts = test_data.receipts.clone();
 	let mut mocked_monitors = HashMap::new();
 	mocked_monitors.insert("monitor".to_string(), test_data.monitor.clone());
@@ -211,7 +266,7 @@ async fn test_execute_monitor_evm() {
 	let trigger_execution_service =
 		TriggerExecutionService::new(trigger_service, notification_service);
 
-	let result = execute_monitor(MonitorExecutionConfig {
+	execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("ethereum_mainnet".to_string()),
 		block_number: Some(block_number),
@@ -222,16 +277,113 @@ async fn test_execute_monitor_evm() {
 		active_monitors_trigger_scripts: HashMap::new(),
 		client_pool,
 	})
-	.await;
-	assert!(
-		result.is_ok(),
-		"Monitor execution failed: {:?}",
-		result.err()
+	.await
+}
+
+#[tokio::test]
+async fn test_execute_monitor_matches_evm() {
+	let test_data = TestDataBuilder::new("evm").build();
+	let receipts = test_data.receipts.clone();
+	let mut mocked_monitors = HashMap::new();
+	mocked_monitors.insert("monitor".to_string(), test_data.monitor.clone());
+	let mock_monitor_service = setup_monitor_service(mocked_monitors);
+	let mock_network_service =
+		setup_mocked_network_service("Ethereum", "ethereum_mainnet", BlockChainType::EVM);
+
+	let mut mock_pool = MockClientPool::new();
+	let mut mock_client = MockEvmClientTrait::new();
+
+	mock_client
+		.expect_get_blocks()
+		.with(predicate::eq(21305050u64), predicate::eq(None))
+		.return_once(move |_, _| Ok(test_data.blocks.clone()));
+
+	mock_client
+		.expect_get_logs_for_blocks()
+		.return_once(move |_, _, _| {
+			Ok(test_data
+				.receipts
+				.clone()
+				.into_iter()
+				.flat_map(|r| r.logs.clone())
+				.collect())
+		});
+
+	let receipt_map: Arc<HashMap<String, EVMTransactionReceipt>> = Arc::new(
+		receipts
+			.iter()
+			.map(|r| (format!("0x{:x}", r.transaction_hash), r.clone()))
+			.collect(),
 	);
+	mock_client
+		.expect_get_transaction_receipt()
+		.returning(move |hash| {
+			Ok(receipt_map
+				.get(&hash)
+				.cloned()
+				.unwrap_or_else(|| panic!("Receipt not found for hash: {}", hash)))
+		});
 
-	// Parse the JSON result and add more specific assertions based on expected matches
-	let matches: Vec<serde_json::Value> = serde_json::from_str(&result.unwrap()).unwrap();
-	assert!(matches.len() == 1);
+	let mock_client = Arc::new(mock_client);
+	mock_pool
+		.expect_get_evm_client()
+		.return_once(move |_| Ok(mock_client));
+
+	let trigger_service = setup_trigger_service(HashMap::new());
+	let trigger_execution_service =
+		TriggerExecutionService::new(trigger_service, NotificationService::new());
+
+	let matches = execute_monitor_matches(MonitorExecutionConfig {
+		path: test_data.monitor.name.clone(),
+		network_slug: Some("ethereum_mainnet".to_string()),
+		block_number: Some(21305050),
+		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
+		network_service: Arc::new(Mutex::new(mock_network_service)),
+		filter_service: Arc::new(FilterService::new()),
+		trigger_execution_service: Arc::new(trigger_execution_service),
+		active_monitors_trigger_scripts: HashMap::new(),
+		client_pool: Arc::new(mock_pool),
+	})
+	.await
+	.expect("Monitor execution failed");
+
+	assert_eq!(matches.len(), 1);
+	match &matches[0] {
+		MonitorMatch::EVM(evm_match) => {
+			assert_eq!(evm_match.monitor.name, test_data.monitor.name);
+			assert_eq!(evm_match.network_slug, "ethereum_mainnet");
+		}
+		_ => panic!("Expected an EVM match"),
+	}
+}
+
+#[tokio::test]
+async fn test_execute_monitor_matches_network_not_found() {
+	let test_data = TestDataBuilder::new("evm").build();
+	let mut mocked_monitors = HashMap::new();
+	mocked_monitors.insert("monitor".to_string(), test_data.monitor.clone());
+	let mock_monitor_service = setup_monitor_service(mocked_monitors);
+	let mock_network_service =
+		setup_mocked_network_service("Ethereum", "ethereum_mainnet", BlockChainType::EVM);
+
+	let trigger_service = setup_trigger_service(HashMap::new());
+	let trigger_execution_service =
+		TriggerExecutionService::new(trigger_service, NotificationService::new());
+
+	let result = execute_monitor_matches(MonitorExecutionConfig {
+		path: test_data.monitor.name.clone(),
+		network_slug: Some("ethereum_goerli".to_string()),
+		block_number: Some(22197425),
+		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
+		network_service: Arc::new(Mutex::new(mock_network_service)),
+		filter_service: Arc::new(FilterService::new()),
+		trigger_execution_service: Arc::new(trigger_execution_service),
+		active_monitors_trigger_scripts: HashMap::new(),
+		client_pool: Arc::new(MockClientPool::new()),
+	})
+	.await;
+
+	assert!(matches!(result, Err(MonitorExecutionError::NotFound(_))));
 }
 
 #[tokio::test]
@@ -409,11 +561,16 @@ async fn test_execute_monitor_stellar() {
 		.with(predicate::eq(172627u64), predicate::eq(None))
 		.return_once(move |_, _| Ok(test_data.blocks.clone()));
 	mock_client
-		.expect_get_transactions()
-		.return_once(move |_, _| Ok(test_data.stellar_transactions.clone()));
+		.expect_get_transactions_page()
+		.return_once(move |_, _, _, _| {
+			Ok(StellarPage {
+				items: test_data.stellar_transactions.clone(),
+				cursor: None,
+			})
+		});
 	mock_client
 		.expect_get_events()
-		.return_once(move |_, _| Ok(test_data.stellar_events.clone()));
+		.return_once(move |_, _, _| Ok(test_data.stellar_events.clone()));
 	mock_client
 		.expect_get_contract_spec()
 		.returning(move |_| Ok(test_data.contract_spec.clone().unwrap()));
@@ -912,6 +1069,9 @@ async fn test_execute_monitor_evm_with_trigger_scripts() {
 		language: ScriptLanguage::Python,
 		timeout_ms: 10000,
 		arguments: None,
+		max_memory_mb: None,
+		max_output_bytes: None,
+		include_block_context: false,
 	}];
 	mocked_monitors.insert("monitor".to_string(), test_data.monitor.clone());
 	let mock_monitor_service = setup_monitor_service(mocked_monitors);
@@ -0,0 +1,92 @@
+use mockito::{Matcher, Server};
+use openzeppelin_monitor::{
+	models::{DiscordEmbed, DiscordEmbedField},
+	services::notification::NotificationService,
+	utils::tests::trigger::TriggerBuilder,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn create_test_variables() -> HashMap<String, String> {
+	HashMap::from([
+		("monitor.name".to_string(), "usdc_transfers".to_string()),
+		("transaction.hash".to_string(), "0xabc".to_string()),
+		("events.0.args.value".to_string(), "1000".to_string()),
+	])
+}
+
+#[tokio::test]
+async fn test_discord_notification_with_embed() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Json(json!({
+			"embeds": [{
+				"title": "Large transfer on usdc_transfers",
+				"description": "Transaction 0xabc",
+				"color": 3447003,
+				"fields": [
+					{ "name": "Value", "value": "1000", "inline": true },
+					{ "name": "Monitor", "value": "usdc_transfers", "inline": false }
+				]
+			}]
+		})))
+		.with_status(204)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("discord_embed")
+		.discord(&server.url())
+		.message("Alert", "Transaction ${transaction.hash}")
+		.discord_embed(DiscordEmbed {
+			title: Some("Large transfer on ${monitor.name}".to_string()),
+			color: Some(3447003),
+			fields: vec![
+				DiscordEmbedField {
+					name: "Value".to_string(),
+					value: "${events.0.args.value}".to_string(),
+					inline: true,
+				},
+				DiscordEmbedField {
+					name: "Monitor".to_string(),
+					value: "${monitor.name}".to_string(),
+					inline: false,
+				},
+			],
+		})
+		.build();
+
+	let result = NotificationService::new()
+		.execute_alert(&trigger, &create_test_variables())
+		.await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_discord_notification_without_embed_sends_content() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Json(json!({
+			"content": "*Alert*\n\nTransaction 0xabc"
+		})))
+		.with_status(204)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("discord_content")
+		.discord(&server.url())
+		.message("Alert", "Transaction ${transaction.hash}")
+		.build();
+
+	let result = NotificationService::new()
+		.execute_alert(&trigger, &create_test_variables())
+		.await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
@@ -1,13 +1,21 @@
+use async_trait::async_trait;
 use mockall::mock;
 
 use email_address::EmailAddress;
-use lettre::{address::Envelope, transport::stub::AsyncStubTransport, Message, Transport};
-use std::collections::HashMap;
+use lettre::{
+	address::Envelope, transport::stub::AsyncStubTransport, AsyncTransport, Message, Transport,
+};
+use std::{
+	collections::HashMap,
+	io,
+	sync::atomic::{AtomicUsize, Ordering},
+	time::Duration,
+};
 
 use openzeppelin_monitor::{
 	models::{
-		EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch, NotificationMessage, SecretString,
-		SecretValue, TriggerType, TriggerTypeConfig,
+		EVMConfirmationState, EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch,
+		NotificationMessage, SecretString, SecretValue, TriggerType, TriggerTypeConfig,
 	},
 	services::notification::{
 		EmailContent, EmailNotifier, NotificationError, NotificationService, SmtpConfig,
@@ -43,6 +51,9 @@ fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
 		network_slug: "ethereum_mainnet".to_string(),
 		matched_on: MatchConditions::default(),
 		matched_on_args: None,
+		confirmation_state: EVMConfirmationState::Confirmed,
+		severity: Default::default(),
+		sender_verified: None,
 	}))
 }
 
@@ -71,6 +82,87 @@ mock! {
 	}
 }
 
+/// Transport whose connection to the server drops during its first sends
+struct DroppingTransport {
+	drops: AtomicUsize,
+	inner: AsyncStubTransport,
+}
+
+#[async_trait]
+impl AsyncTransport for DroppingTransport {
+	type Ok = ();
+	type Error = io::Error;
+
+	async fn send_raw(&self, envelope: &Envelope, email: &[u8]) -> Result<(), io::Error> {
+		let dropped = self
+			.drops
+			.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |drops| {
+				drops.checked_sub(1)
+			})
+			.is_ok();
+		if dropped {
+			return Err(io::Error::new(
+				io::ErrorKind::ConnectionReset,
+				"connection reset by peer",
+			));
+		}
+		self.inner
+			.send_raw(envelope, email)
+			.await
+			.map_err(io::Error::other)
+	}
+}
+
+fn create_test_email_content() -> EmailContent {
+	EmailContent {
+		subject: "Test".to_string(),
+		body_template: "Test message".to_string(),
+		sender: EmailAddress::new_unchecked("sender@test.com"),
+		recipients: vec![EmailAddress::new_unchecked("recipient@test.com")],
+		cc: vec![],
+		bcc: vec![],
+		is_html: false,
+	}
+}
+
+#[tokio::test]
+async fn test_email_notification_recovers_from_dropped_connection() {
+	let stub_transport = AsyncStubTransport::new_ok();
+	let retry_policy = RetryConfig {
+		initial_backoff: Duration::from_millis(10),
+		..RetryConfig::default()
+	};
+	let notifier = EmailNotifier::with_transport(
+		create_test_email_content(),
+		DroppingTransport {
+			drops: AtomicUsize::new(1),
+			inner: stub_transport.clone(),
+		},
+		retry_policy.clone(),
+	);
+
+	// The first send fails on the dropped connection, and its retry succeeds
+	notifier.notify("Test message").await.unwrap();
+	assert_eq!(stub_transport.messages().await.len(), 1);
+
+	// A connection dropping on every retry fails as a network error
+	let notifier = EmailNotifier::with_transport(
+		create_test_email_content(),
+		DroppingTransport {
+			drops: AtomicUsize::new(usize::MAX),
+			inner: stub_transport.clone(),
+		},
+		retry_policy,
+	);
+	match notifier.notify("Test message").await.unwrap_err() {
+		NotificationError::NetworkError(ctx) => {
+			assert!(ctx.message.contains("connection reset by peer"));
+		}
+		e => panic!("Expected NetworkError, got {:?}", e),
+	}
+	assert_eq!(stub_transport.messages().await.len(), 1);
+}
+
 #[tokio::test]
 async fn test_email_notification_success() {
 	let email_content = EmailContent {
@@ -78,6 +170,9 @@ async fn test_email_notification_success() {
 		body_template: "Test message".to_string(),
 		sender: EmailAddress::new_unchecked("sender@test.com"),
 		recipients: vec![EmailAddress::new_unchecked("recipient@test.com")],
+		cc: vec![],
+		bcc: vec![],
+		is_html: false,
 	};
 
 	let stub_transport = AsyncStubTransport::new_ok();
@@ -96,6 +191,9 @@ async fn test_email_notification_failure_after_retries() {
 		body_template: "Test message".to_string(),
 		sender: EmailAddress::new_unchecked("sender@test.com"),
 		recipients: vec![EmailAddress::new_unchecked("recipient@test.com")],
+		cc: vec![],
+		bcc: vec![],
+		is_html: false,
 	};
 
 	let stub_transport = AsyncStubTransport::new_error();
@@ -138,7 +236,11 @@ async fn test_notification_service_email_execution_failure() {
 		},
 		sender: "sender@example.com".parse().unwrap(),
 		recipients: vec!["recipient@example.com".parse().unwrap()],
+		cc: vec![],
+		bcc: vec![],
+		is_html: false,
 		retry_policy: RetryConfig::default(),
+		timeout_ms: None,
 	};
 
 	let trigger = TriggerBuilder::new()
@@ -160,10 +262,13 @@ async fn test_notification_service_email_execution_failure() {
 		"Expected email notification to fail due to dummy SMTP host"
 	);
 
+	// The connection to the server is refused
 	match result.unwrap_err() {
-		NotificationError::NotifyFailed(ctx) => {
-			assert!(ctx.message.contains("Failed to send email"));
+		NotificationError::NetworkError(ctx) => {
+			assert!(ctx
+				.message
+				.contains("Failed to send email over the SMTP connection"));
 		}
-		e => panic!("Expected NotifyFailed, got {:?}", e),
+		e => panic!("Expected NetworkError, got {:?}", e),
 	}
 }
@@ -0,0 +1,227 @@
+use openzeppelin_monitor::{
+	models::{
+		EVMConfirmationState, EVMMonitorMatch, FileFormat, MatchConditions, Monitor, MonitorMatch,
+	},
+	services::notification::{FailedNotification, FileRecord, NotificationService},
+	utils::tests::{
+		evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+		trigger::TriggerBuilder,
+	},
+};
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+};
+use tempfile::tempdir;
+
+use crate::integration::mocks::{create_test_evm_logs, create_test_evm_transaction_receipt};
+
+fn create_test_monitor(name: &str) -> Monitor {
+	MonitorBuilder::new()
+		.name(name)
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.paused(false)
+		.triggers(vec!["audit_file".to_string()])
+		.build()
+}
+
+fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
+	let transaction = TransactionBuilder::new().build();
+
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor,
+		transaction,
+		receipt: Some(create_test_evm_transaction_receipt()),
+		logs: Some(create_test_evm_logs()),
+		network_slug: "ethereum_mainnet".to_string(),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+		confirmation_state: EVMConfirmationState::Confirmed,
+		severity: Default::default(),
+		sender_verified: None,
+	}))
+}
+
+fn create_test_variables() -> HashMap<String, String> {
+	HashMap::from([
+		("monitor.name".to_string(), "usdc_transfers".to_string()),
+		("transaction.hash".to_string(), "0xabc".to_string()),
+	])
+}
+
+#[tokio::test]
+async fn test_notification_service_file_execution() {
+	let dir = tempdir().unwrap();
+	let path = dir.path().join("audit.jsonl");
+	let notification_service = NotificationService::new();
+
+	let trigger = TriggerBuilder::new()
+		.name("audit_file")
+		.file(path.to_str().unwrap())
+		.build();
+	let monitor_match = create_test_evm_match(create_test_monitor("usdc_transfers"));
+
+	let result = notification_service
+		.execute(
+			&trigger,
+			&create_test_variables(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+	assert!(result.is_ok());
+
+	let contents = std::fs::read_to_string(&path).unwrap();
+	let records: Vec<FileRecord> = contents
+		.lines()
+		.map(|line| serde_json::from_str(line).unwrap())
+		.collect();
+	assert_eq!(records.len(), 1);
+
+	let summary = records[0].monitor_match.as_ref().unwrap();
+	assert_eq!(summary.chain, "EVM");
+	assert_eq!(summary.monitor, "usdc_transfers");
+	assert_eq!(summary.network, "ethereum_mainnet");
+	assert_eq!(records[0].variables["transaction.hash"], "0xabc");
+}
+
+#[tokio::test]
+async fn test_notification_service_file_alert() {
+	let dir = tempdir().unwrap();
+	let path = dir.path().join("audit.csv");
+	let notification_service = NotificationService::new();
+
+	let trigger = TriggerBuilder::new()
+		.name("audit_file")
+		.file(path.to_str().unwrap())
+		.file_format(FileFormat::Csv)
+		.build();
+	let variables = HashMap::from([("network.slug".to_string(), "ethereum_mainnet".to_string())]);
+
+	let result = notification_service
+		.execute_alert(&trigger, &variables)
+		.await;
+	assert!(result.is_ok());
+
+	let contents = std::fs::read_to_string(&path).unwrap();
+	let lines: Vec<&str> = contents.lines().collect();
+	assert_eq!(lines.len(), 2);
+	assert_eq!(
+		lines[0],
+		"timestamp,chain,monitor,network,matched_on,variables"
+	);
+	assert!(lines[1].ends_with(",,,,,\"{\"\"network.slug\"\":\"\"ethereum_mainnet\"\"}\""));
+}
+
+#[tokio::test]
+async fn test_notification_service_file_digest() {
+	let dir = tempdir().unwrap();
+	let path = dir.path().join("digest.jsonl");
+	let notification_service = NotificationService::new();
+
+	let trigger = TriggerBuilder::new()
+		.name("audit_file")
+		.file(path.to_str().unwrap())
+		.batch_window_ms(200)
+		.build();
+	let monitor_match = create_test_evm_match(create_test_monitor("usdc_transfers"));
+
+	for index in 0..3 {
+		let mut variables = create_test_variables();
+		variables.insert("transaction.hash".to_string(), format!("0x{}", index));
+		let result = notification_service
+			.execute(&trigger, &variables, &monitor_match, &HashMap::new())
+			.await;
+		assert!(result.is_ok());
+	}
+	// Nothing is sent before the batch window elapses
+	assert!(!path.exists());
+
+	tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+	let contents = std::fs::read_to_string(&path).unwrap();
+	let records: Vec<FileRecord> = contents
+		.lines()
+		.map(|line| serde_json::from_str(line).unwrap())
+		.collect();
+	assert_eq!(records.len(), 1);
+
+	let variables = &records[0].variables;
+	assert_eq!(variables["digest.count"], "3");
+	assert_eq!(
+		variables["digest.matches"],
+		"- usdc_transfers: 0x0\n- usdc_transfers: 0x1\n- usdc_transfers: 0x2"
+	);
+	assert_eq!(variables["digest.2.summary"], "- usdc_transfers: 0x2");
+	assert_eq!(variables["transaction.hash"], "0x0");
+}
+
+#[tokio::test]
+async fn test_notification_service_flushes_open_digests() {
+	let dir = tempdir().unwrap();
+	let path = dir.path().join("digest.jsonl");
+	let notification_service = NotificationService::new();
+
+	let trigger = TriggerBuilder::new()
+		.name("audit_file")
+		.file(path.to_str().unwrap())
+		.batch_window_ms(600_000)
+		.build();
+	let monitor_match = create_test_evm_match(create_test_monitor("usdc_transfers"));
+	notification_service
+		.execute(
+			&trigger,
+			&create_test_variables(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await
+		.unwrap();
+	assert!(!path.exists());
+
+	// The digest is sent at shutdown without waiting for its window to elapse
+	notification_service.flush_digests().await;
+
+	let contents = std::fs::read_to_string(&path).unwrap();
+	let records: Vec<FileRecord> = contents
+		.lines()
+		.map(|line| serde_json::from_str(line).unwrap())
+		.collect();
+	assert_eq!(records.len(), 1);
+	assert_eq!(records[0].variables["digest.count"], "1");
+}
+
+#[tokio::test]
+async fn test_notification_service_hands_failed_digests_to_failure_handler() {
+	let dir = tempdir().unwrap();
+	let failed = Arc::new(Mutex::new(Vec::<FailedNotification>::new()));
+	let notification_service = NotificationService::new().with_failure_handler({
+		let failed = failed.clone();
+		Arc::new(move |notification| failed.lock().unwrap().push(notification))
+	});
+
+	// A directory can't be appended to
+	let trigger = TriggerBuilder::new()
+		.name("audit_file")
+		.file(dir.path().to_str().unwrap())
+		.batch_window_ms(600_000)
+		.build();
+	let monitor_match = create_test_evm_match(create_test_monitor("usdc_transfers"));
+	for _ in 0..2 {
+		notification_service
+			.execute(
+				&trigger,
+				&create_test_variables(),
+				&monitor_match,
+				&HashMap::new(),
+			)
+			.await
+			.unwrap();
+	}
+	notification_service.flush_digests().await;
+
+	let failed = failed.lock().unwrap();
+	assert_eq!(failed.len(), 1);
+	assert_eq!(failed[0].trigger.name, "audit_file");
+	assert_eq!(failed[0].variables["digest.count"], "2");
+}
@@ -0,0 +1,126 @@
+//! Integration tests for the Kafka notifier, built with the `kafka` feature.
+//!
+//! Producing to a broker needs a running Kafka cluster and is skipped unless `KAFKA_BROKERS` is
+//! set, e.g. `KAFKA_BROKERS=localhost:9092 cargo test --features kafka kafka`. The cluster must
+//! create topics on first use.
+
+use openzeppelin_monitor::{
+	models::{EVMConfirmationState, EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch},
+	services::notification::{KafkaRecord, NotificationService},
+	utils::{
+		metrics::NOTIFICATIONS_SENT_TOTAL,
+		tests::{
+			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+			trigger::TriggerBuilder,
+		},
+	},
+};
+use rdkafka::{
+	config::ClientConfig,
+	consumer::{Consumer, StreamConsumer},
+	Message,
+};
+use std::{collections::HashMap, time::Duration};
+
+use crate::integration::mocks::{create_test_evm_logs, create_test_evm_transaction_receipt};
+
+fn create_test_monitor(name: &str) -> Monitor {
+	MonitorBuilder::new()
+		.name(name)
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.paused(false)
+		.triggers(vec!["matches_kafka".to_string()])
+		.build()
+}
+
+fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
+	let transaction = TransactionBuilder::new().build();
+
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor,
+		transaction,
+		receipt: Some(create_test_evm_transaction_receipt()),
+		logs: Some(create_test_evm_logs()),
+		network_slug: "ethereum_mainnet".to_string(),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+		confirmation_state: EVMConfirmationState::Confirmed,
+		severity: Default::default(),
+		sender_verified: None,
+	}))
+}
+
+#[tokio::test]
+async fn test_notification_service_kafka_execution() {
+	let Ok(brokers) = std::env::var("KAFKA_BROKERS") else {
+		eprintln!("KAFKA_BROKERS not set, skipping test_notification_service_kafka_execution");
+		return;
+	};
+
+	let topic = format!(
+		"oz-monitor-test-{}-{}",
+		std::process::id(),
+		chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+	);
+	let trigger = TriggerBuilder::new()
+		.name("matches_kafka")
+		.kafka(brokers.split(',').collect(), &topic)
+		.timeout_ms(30_000)
+		.build();
+	let monitor_match = create_test_evm_match(create_test_monitor("usdc_transfers"));
+	let variables = HashMap::from([
+		("monitor.name".to_string(), "usdc_transfers".to_string()),
+		("transaction.hash".to_string(), "0xabc".to_string()),
+	]);
+
+	let result = NotificationService::new()
+		.execute(&trigger, &variables, &monitor_match, &HashMap::new())
+		.await;
+	assert!(result.is_ok(), "{:?}", result);
+
+	let consumer: StreamConsumer = ClientConfig::new()
+		.set("bootstrap.servers", &brokers)
+		.set("group.id", format!("{}-consumer", topic))
+		.set("auto.offset.reset", "earliest")
+		.create()
+		.unwrap();
+	consumer.subscribe(&[&topic]).unwrap();
+	let message = tokio::time::timeout(Duration::from_secs(30), consumer.recv())
+		.await
+		.expect("Record wasn't consumed")
+		.unwrap();
+
+	let record: KafkaRecord = serde_json::from_slice(message.payload().unwrap()).unwrap();
+	assert_eq!(message.key(), record.key().as_deref().map(str::as_bytes));
+	assert!(record.key().is_some());
+	assert_eq!(record.variables["monitor.name"], "usdc_transfers");
+	match record.monitor_match {
+		Some(MonitorMatch::EVM(evm_match)) => {
+			assert_eq!(evm_match.monitor.name, "usdc_transfers");
+			assert_eq!(evm_match.network_slug, "ethereum_mainnet");
+		}
+		_ => panic!("Expected EVM match"),
+	}
+}
+
+#[tokio::test]
+async fn test_notification_service_kafka_delivery_failure() {
+	// Nothing listens on the broker port, so the record is never acknowledged
+	let trigger = TriggerBuilder::new()
+		.name("matches_kafka")
+		.kafka(vec!["127.0.0.1:1"], "monitor-matches")
+		.timeout_ms(500)
+		.build();
+	let monitor_match = create_test_evm_match(create_test_monitor("usdc_transfers"));
+
+	let failures =
+		NOTIFICATIONS_SENT_TOTAL.with_label_values(&["kafka", "failure", "none", "", ""]);
+	let failures_before = failures.get();
+
+	let result = NotificationService::new()
+		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.await;
+
+	assert!(result.is_err());
+	assert_eq!(failures.get(), failures_before + 1);
+}
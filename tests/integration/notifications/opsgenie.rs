@@ -0,0 +1,109 @@
+use mockito::{Matcher, Server};
+use openzeppelin_monitor::{
+	models::OpsgeniePriority,
+	services::notification::{OpsgenieConfig, OpsgenieNotifier},
+	utils::tests::get_http_client_from_notification_pool,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn create_test_config(url: String) -> OpsgenieConfig {
+	OpsgenieConfig {
+		url,
+		api_key: "test-api-key".to_string(),
+		priority: OpsgeniePriority::P2,
+		alias: Some("${monitor.name}-${transaction.hash}".to_string()),
+		title: "Large transfer on ${monitor.name}".to_string(),
+		body_template: "Transaction ${transaction.hash} moved ${events.0.args.value}".to_string(),
+	}
+}
+
+fn create_test_variables() -> HashMap<String, String> {
+	HashMap::from([
+		("monitor.name".to_string(), "usdc_transfers".to_string()),
+		("transaction.hash".to_string(), "0xabc".to_string()),
+		("events.0.args.value".to_string(), "1000".to_string()),
+	])
+}
+
+#[tokio::test]
+async fn test_opsgenie_notification_success() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/v2/alerts")
+		.match_header("authorization", "GenieKey test-api-key")
+		.match_header("content-type", "application/json")
+		.match_body(Matcher::Json(json!({
+			"message": "Large transfer on usdc_transfers",
+			"description": "Transaction 0xabc moved 1000",
+			"priority": "P2",
+			"alias": "usdc_transfers-0xabc",
+			"source": "OpenZeppelin Monitor"
+		})))
+		.with_status(202)
+		.with_body(r#"{"result":"Request will be processed","took":0.1,"requestId":"1"}"#)
+		.create_async()
+		.await;
+
+	let http_client = get_http_client_from_notification_pool().await;
+	let notifier = OpsgenieNotifier::new(
+		create_test_config(format!("{}/v2/alerts", server.url())),
+		http_client,
+	)
+	.unwrap();
+
+	let result = notifier.notify(&create_test_variables()).await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_opsgenie_notification_same_alias_for_same_variables() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/v2/alerts")
+		.match_body(Matcher::PartialJson(json!({
+			"alias": "usdc_transfers-0xabc"
+		})))
+		.with_status(202)
+		.expect(2)
+		.create_async()
+		.await;
+
+	let http_client = get_http_client_from_notification_pool().await;
+	let notifier = OpsgenieNotifier::new(
+		create_test_config(format!("{}/v2/alerts", server.url())),
+		http_client,
+	)
+	.unwrap();
+
+	let variables = create_test_variables();
+	assert!(notifier.notify(&variables).await.is_ok());
+	assert!(notifier.notify(&variables).await.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_opsgenie_notification_unauthorized() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/v2/alerts")
+		.with_status(401)
+		.with_body(r#"{"message":"Could not authenticate","took":0.0,"requestId":"1"}"#)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let http_client = get_http_client_from_notification_pool().await;
+	let notifier = OpsgenieNotifier::new(
+		create_test_config(format!("{}/v2/alerts", server.url())),
+		http_client,
+	)
+	.unwrap();
+
+	let result = notifier.notify(&create_test_variables()).await;
+
+	assert!(result.is_err());
+	mock.assert();
+}
@@ -1,5 +1,8 @@
 use openzeppelin_monitor::{
-	models::{EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch, ScriptLanguage},
+	models::{
+		EVMConfirmationState, EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch,
+		ScriptLanguage,
+	},
 	services::notification::{NotificationError, NotificationService},
 	utils::tests::{
 		evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
@@ -30,6 +33,9 @@ fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
 		network_slug: "ethereum_mainnet".to_string(),
 		matched_on: MatchConditions::default(),
 		matched_on_args: None,
+		confirmation_state: EVMConfirmationState::Confirmed,
+		severity: Default::default(),
+		sender_verified: None,
 	}))
 }
 
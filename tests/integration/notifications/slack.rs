@@ -0,0 +1,97 @@
+use mockito::{Matcher, Server};
+use openzeppelin_monitor::{
+	services::notification::NotificationService, utils::tests::trigger::TriggerBuilder,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn create_test_variables() -> HashMap<String, String> {
+	HashMap::from([
+		("monitor.name".to_string(), "usdc_transfers".to_string()),
+		("transaction.hash".to_string(), "0xabc".to_string()),
+		("events.0.args.value".to_string(), "1000".to_string()),
+	])
+}
+
+#[tokio::test]
+async fn test_slack_notification_with_blocks() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Json(json!({
+			"text": "*Alert*\n\nTransaction 0xabc",
+			"blocks": [
+				{
+					"type": "header",
+					"text": { "type": "plain_text", "text": "Large transfer on usdc_transfers" }
+				},
+				{ "type": "divider" },
+				{
+					"type": "section",
+					"fields": [
+						{ "type": "mrkdwn", "text": "*Value*\n1000" },
+						{ "type": "mrkdwn", "text": "*Transaction*\n0xabc" }
+					]
+				}
+			]
+		})))
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("slack_blocks")
+		.slack(&server.url())
+		.message("Alert", "Transaction ${transaction.hash}")
+		.slack_blocks(vec![
+			json!({
+				"type": "header",
+				"text": { "type": "plain_text", "text": "Large transfer on ${monitor.name}" }
+			}),
+			json!({ "type": "divider" }),
+			json!({
+				"type": "section",
+				"fields": [
+					{ "type": "mrkdwn", "text": "*Value*\n${events.0.args.value}" },
+					{ "type": "mrkdwn", "text": "*Transaction*\n${transaction.hash}" }
+				]
+			}),
+		])
+		.build();
+
+	let result = NotificationService::new()
+		.execute_alert(&trigger, &create_test_variables())
+		.await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_slack_notification_without_blocks_sends_text_section() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Json(json!({
+			"blocks": [{
+				"type": "section",
+				"text": { "type": "mrkdwn", "text": "*Alert*\n\nTransaction 0xabc" }
+			}]
+		})))
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("slack_text")
+		.slack(&server.url())
+		.message("Alert", "Transaction ${transaction.hash}")
+		.build();
+
+	let result = NotificationService::new()
+		.execute_alert(&trigger, &create_test_variables())
+		.await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
@@ -0,0 +1,98 @@
+use mockito::{Matcher, Server};
+use openzeppelin_monitor::{
+	models::{TelegramParseMode, WebhookSignatureAlgorithm},
+	services::notification::{
+		TelegramPayloadBuilder, WebhookConfig, WebhookNotifier, WebhookPayloadBuilder,
+	},
+	utils::tests::get_http_client_from_notification_pool,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn create_test_config(url: String) -> WebhookConfig {
+	WebhookConfig {
+		url,
+		url_params: None,
+		title: "Alert".to_string(),
+		body_template: "Value: ${value}".to_string(),
+		method: Some("POST".to_string()),
+		secret: None,
+		headers: None,
+		payload_fields: None,
+		include_raw_match: false,
+		trim_raw_match: false,
+		signature_algorithm: WebhookSignatureAlgorithm::default(),
+		signature_header: None,
+		signature_algorithm_header: None,
+	}
+}
+
+#[tokio::test]
+async fn test_telegram_notification_with_thread_and_parse_mode() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(json!({
+			"chat_id": "12345",
+			"message_thread_id": 42,
+			"parse_mode": "HTML",
+			"text": "<b>Alert</b>\n\nValue: 1 &lt; 2"
+		})))
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let config = create_test_config(server.url());
+	let builder = TelegramPayloadBuilder {
+		chat_id: "12345".to_string(),
+		disable_web_preview: true,
+		message_thread_id: Some(42),
+		parse_mode: TelegramParseMode::Html,
+	};
+	let variables = HashMap::from([("value".to_string(), "1 < 2".to_string())]);
+	let payload = builder
+		.build_payload(&config.title, &config.body_template, &variables)
+		.unwrap();
+
+	let http_client = get_http_client_from_notification_pool().await;
+	let notifier = WebhookNotifier::new(config, http_client).unwrap();
+	let result = notifier.notify_json(&payload).await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_telegram_notification_defaults_to_markdown_v2_without_thread() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Json(json!({
+			"chat_id": "12345",
+			"text": "*Alert* \n\nValue: 1\\.5",
+			"parse_mode": "MarkdownV2",
+			"disable_web_page_preview": false
+		})))
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let config = create_test_config(server.url());
+	let builder = TelegramPayloadBuilder {
+		chat_id: "12345".to_string(),
+		disable_web_preview: false,
+		message_thread_id: None,
+		parse_mode: TelegramParseMode::default(),
+	};
+	let variables = HashMap::from([("value".to_string(), "1.5".to_string())]);
+	let payload = builder
+		.build_payload(&config.title, &config.body_template, &variables)
+		.unwrap();
+
+	let http_client = get_http_client_from_notification_pool().await;
+	let notifier = WebhookNotifier::new(config, http_client).unwrap();
+	let result = notifier.notify_json(&payload).await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
@@ -1,20 +1,41 @@
 use mockito::{Matcher, Server};
 use openzeppelin_monitor::{
-	models::{EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch, TriggerType},
-	services::notification::{
-		GenericWebhookPayloadBuilder, NotificationError, NotificationService, WebhookConfig,
-		WebhookNotifier, WebhookPayloadBuilder,
+	models::{
+		EVMConfirmationState, EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch,
+		NotificationSeverity, Trigger, TriggerType, WebhookSignatureAlgorithm,
+	},
+	repositories::{TriggerRepository, TriggerService},
+	services::{
+		blockwatcher::FileBlockStorage,
+		notification::{
+			GenericWebhookPayloadBuilder, NotificationError, NotificationService, WebhookConfig,
+			WebhookNotifier, WebhookPayloadBuilder,
+		},
+		trigger::{
+			DeadLetter, DeadLetterSink, FileDeadLetterSink, NotificationRetryConfig,
+			NotificationRetryQueue, NotificationThrottle, TriggerError, TriggerExecutionService,
+			TriggerExecutionServiceTrait, TriggerOutcome, THROTTLE_WINDOW,
+		},
 	},
 	utils::{
+		logging::error::ErrorCode,
+		metrics::{
+			NOTIFICATIONS_DEAD_LETTERED_TOTAL, NOTIFICATIONS_SENT_TOTAL,
+			NOTIFICATIONS_THROTTLED_TOTAL,
+		},
 		tests::{
 			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
 			get_http_client_from_notification_pool,
 			trigger::TriggerBuilder,
 		},
-		RetryConfig,
+		MockClock, RetryConfig,
 	},
 };
-use std::collections::HashMap;
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
 
 use crate::integration::mocks::{create_test_evm_logs, create_test_evm_transaction_receipt};
 
@@ -38,13 +59,18 @@ fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
 		network_slug: "ethereum_mainnet".to_string(),
 		matched_on: MatchConditions::default(),
 		matched_on_args: None,
+		confirmation_state: EVMConfirmationState::Confirmed,
+		severity: Default::default(),
+		sender_verified: None,
 	}))
 }
 fn create_test_payload() -> serde_json::Value {
 	let title = "Test Title";
 	let body_template = "Test message with value ${value}";
 	let variables = HashMap::from([("value".to_string(), "42".to_string())]);
-	GenericWebhookPayloadBuilder.build_payload(title, body_template, &variables)
+	GenericWebhookPayloadBuilder
+		.build_payload(title, body_template, &variables)
+		.unwrap()
 }
 
 #[tokio::test]
@@ -70,6 +96,11 @@ async fn test_webhook_notification_success() {
 		secret: None,
 		headers: None,
 		payload_fields: None,
+		include_raw_match: false,
+		trim_raw_match: false,
+		signature_algorithm: WebhookSignatureAlgorithm::default(),
+		signature_header: None,
+		signature_algorithm_header: None,
 	};
 	let http_client = get_http_client_from_notification_pool().await;
 	let notifier = WebhookNotifier::new(config, http_client).unwrap();
@@ -101,6 +132,11 @@ async fn test_webhook_notification_failure_retryable_error() {
 		secret: None,
 		headers: None,
 		payload_fields: None,
+		include_raw_match: false,
+		trim_raw_match: false,
+		signature_algorithm: WebhookSignatureAlgorithm::default(),
+		signature_header: None,
+		signature_algorithm_header: None,
 	};
 	let http_client = get_http_client_from_notification_pool().await;
 	let notifier = WebhookNotifier::new(config, http_client).unwrap();
@@ -133,6 +169,11 @@ async fn test_webhook_notification_failure_non_retryable_error() {
 		secret: None,
 		headers: None,
 		payload_fields: None,
+		include_raw_match: false,
+		trim_raw_match: false,
+		signature_algorithm: WebhookSignatureAlgorithm::default(),
+		signature_header: None,
+		signature_algorithm_header: None,
 	};
 	let http_client = get_http_client_from_notification_pool().await;
 	let notifier = WebhookNotifier::new(config, http_client).unwrap();
@@ -208,11 +249,50 @@ async fn test_notification_service_webhook_execution_failure() {
 	assert!(result.is_err());
 
 	let error = result.unwrap_err();
-	assert!(matches!(error, NotificationError::NotifyFailed(_)));
+	assert_eq!(error.code(), ErrorCode::NotificationFailed);
 
 	mock.assert();
 }
 
+#[tokio::test]
+async fn test_notification_service_webhook_execution_timeout() {
+	let notification_service = NotificationService::new();
+	let mut server = Server::new_async().await;
+
+	// Respond well after the trigger timeout has elapsed
+	let _mock = server
+		.mock("GET", "/")
+		.with_status(200)
+		.with_body_from_request(|_| {
+			std::thread::sleep(Duration::from_secs(2));
+			Vec::new()
+		})
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&server.url())
+		.webhook_method("GET")
+		.message("Test Alert", "Test message")
+		.timeout_ms(200)
+		.build();
+
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+	let failures =
+		NOTIFICATIONS_SENT_TOTAL.with_label_values(&["webhook", "failure", "none", "", ""]);
+	let failures_before = failures.get();
+
+	let started = std::time::Instant::now();
+	let result = notification_service
+		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.await;
+
+	assert!(started.elapsed() < Duration::from_secs(2));
+	assert_eq!(result.unwrap_err().code(), ErrorCode::NotificationTimeout);
+	assert!(failures.get() > failures_before);
+}
+
 #[tokio::test]
 async fn test_notification_service_webhook_execution_invalid_url() {
 	let notification_service = NotificationService::new();
@@ -264,6 +344,11 @@ async fn test_notify_json_with_url_params() {
 		secret: None,
 		headers: None,
 		payload_fields: None,
+		include_raw_match: false,
+		trim_raw_match: false,
+		signature_algorithm: WebhookSignatureAlgorithm::default(),
+		signature_header: None,
+		signature_algorithm_header: None,
 	};
 
 	let http_client = get_http_client_from_notification_pool().await;
@@ -274,3 +359,649 @@ async fn test_notify_json_with_url_params() {
 	assert!(result.is_ok());
 	mock.assert();
 }
+
+#[tokio::test]
+async fn test_notification_service_webhook_includes_raw_match() {
+	let notification_service = NotificationService::new();
+	let mut server = Server::new_async().await;
+
+	let captured = Arc::new(Mutex::new(None));
+	let captured_body = captured.clone();
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.with_body_from_request(move |request| {
+			let body: serde_json::Value = serde_json::from_slice(request.body().unwrap()).unwrap();
+			*captured_body.lock().unwrap() = Some(body);
+			Vec::new()
+		})
+		.expect(2)
+		.create_async()
+		.await;
+
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	for trim in [false, true] {
+		let trigger = TriggerBuilder::new()
+			.name("test_trigger")
+			.webhook(&server.url())
+			.message("Test Alert", "Test message ${value}")
+			.webhook_raw_match(true, trim)
+			.build();
+		let variables = HashMap::from([("value".to_string(), "42".to_string())]);
+
+		let result = notification_service
+			.execute(&trigger, &variables, &monitor_match, &HashMap::new())
+			.await;
+		assert!(result.is_ok());
+
+		let body = captured.lock().unwrap().take().unwrap();
+		assert_eq!(body["title"], "Test Alert");
+		assert_eq!(body["body"], "Test message 42");
+
+		let payload = body["payload"].as_object().unwrap();
+		let mut keys: Vec<&str> = payload.keys().map(String::as_str).collect();
+		keys.sort_unstable();
+		assert_eq!(
+			keys,
+			vec![
+				"chain",
+				"match",
+				"matched_on",
+				"matched_on_args",
+				"monitor",
+				"network",
+				"transaction_hash"
+			]
+		);
+		assert_eq!(payload["chain"], "EVM");
+		assert_eq!(payload["monitor"], "test_monitor");
+		assert_eq!(payload["network"], "ethereum_mainnet");
+		assert_eq!(
+			payload["matched_on"],
+			serde_json::json!(MatchConditions::default())
+		);
+		assert!(payload["matched_on_args"].is_null());
+
+		let details = payload["match"].as_object().unwrap();
+		assert!(details.contains_key("transaction"));
+		assert_eq!(details.contains_key("receipt"), !trim);
+		assert_eq!(details.contains_key("logs"), !trim);
+	}
+
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_notification_service_webhook_omits_raw_match_by_default() {
+	let notification_service = NotificationService::new();
+	let mut server = Server::new_async().await;
+
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Json(create_test_payload()))
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&server.url())
+		.message("Test Title", "Test message with value ${value}")
+		.build();
+	let variables = HashMap::from([("value".to_string(), "42".to_string())]);
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	let result = notification_service
+		.execute(&trigger, &variables, &monitor_match, &HashMap::new())
+		.await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_notification_service_webhook_severity_and_labels() {
+	let notification_service = NotificationService::new();
+	let mut server = Server::new_async().await;
+
+	let payload = GenericWebhookPayloadBuilder
+		.build_payload(
+			"[critical] Test Title",
+			"Routed to treasury in production",
+			&HashMap::new(),
+		)
+		.unwrap();
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Json(payload))
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&server.url())
+		.message(
+			"[${trigger.severity}] Test Title",
+			"Routed to ${trigger.labels.team} in ${trigger.labels.environment}",
+		)
+		.severity(NotificationSeverity::Critical)
+		.label("team", "treasury")
+		.label("environment", "production")
+		.label("runbook", "https://example.com/runbook")
+		.build();
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+	let successes = NOTIFICATIONS_SENT_TOTAL.with_label_values(&[
+		"webhook",
+		"success",
+		"critical",
+		"treasury",
+		"production",
+	]);
+	let successes_before = successes.get();
+
+	let result = notification_service
+		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.await;
+
+	assert!(result.is_ok());
+	assert_eq!(successes.get(), successes_before + 1);
+	mock.assert();
+}
+
+/// Creates a trigger execution service whose failed notifications are retried every 10ms
+fn create_retrying_trigger_execution_service(
+	trigger: Trigger,
+	max_retries: u32,
+	sink: Arc<dyn DeadLetterSink>,
+) -> TriggerExecutionService<TriggerRepository> {
+	let triggers = HashMap::from([(trigger.name.clone(), trigger)]);
+	let trigger_service =
+		TriggerService::new_with_repository(TriggerRepository { triggers }).unwrap();
+	let notification_service = NotificationService::new();
+	let retry_queue = NotificationRetryQueue::spawn(
+		notification_service.clone(),
+		NotificationRetryConfig {
+			max_retries,
+			initial_backoff: Duration::from_millis(10),
+			max_backoff: Duration::from_millis(10),
+		},
+		sink,
+	);
+
+	TriggerExecutionService::new(trigger_service, notification_service)
+		.with_retry_queue(retry_queue)
+}
+
+/// Creates a webhook trigger that doesn't retry failed requests itself
+fn create_non_retrying_webhook_trigger(url: &str) -> Trigger {
+	TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(url)
+		.webhook_method("GET")
+		.webhook_retry_policy(RetryConfig {
+			max_retries: 0,
+			..RetryConfig::default()
+		})
+		.build()
+}
+
+#[tokio::test]
+async fn test_failed_notification_is_retried_until_delivered() {
+	let mut server = Server::new_async().await;
+	let temp_dir = tempfile::TempDir::new().unwrap();
+	let dead_letter_path = temp_dir.path().join("dead_letters.jsonl");
+
+	// The endpoint fails twice, then accepts the notification
+	let failure_mock = server
+		.mock("GET", "/")
+		.with_status(500)
+		.expect(2)
+		.create_async()
+		.await;
+	let success_mock = server
+		.mock("GET", "/")
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let service = create_retrying_trigger_execution_service(
+		create_non_retrying_webhook_trigger(&server.url()),
+		3,
+		Arc::new(FileDeadLetterSink::new(dead_letter_path.clone())),
+	);
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	// The first attempt fails and is reported, the retries happen in the background
+	let result = service
+		.execute(
+			&["test_trigger".to_string()],
+			HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+	assert!(result.is_err());
+
+	for _ in 0..100 {
+		if success_mock.matched_async().await {
+			break;
+		}
+		tokio::time::sleep(Duration::from_millis(20)).await;
+	}
+
+	failure_mock.assert_async().await;
+	success_mock.assert_async().await;
+	assert!(!dead_letter_path.exists());
+}
+
+#[tokio::test]
+async fn test_failed_notification_is_dead_lettered_after_last_retry() {
+	let mut server = Server::new_async().await;
+	let temp_dir = tempfile::TempDir::new().unwrap();
+	let dead_letter_path = temp_dir.path().join("dead_letters.jsonl");
+
+	let mock = server
+		.mock("GET", "/")
+		.with_status(500)
+		.expect(3)
+		.create_async()
+		.await;
+
+	let dead_lettered = NOTIFICATIONS_DEAD_LETTERED_TOTAL.with_label_values(&["webhook"]);
+	let dead_lettered_before = dead_lettered.get();
+
+	let service = create_retrying_trigger_execution_service(
+		create_non_retrying_webhook_trigger(&server.url()),
+		2,
+		Arc::new(FileDeadLetterSink::new(dead_letter_path.clone())),
+	);
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	let result = service
+		.execute(
+			&["test_trigger".to_string()],
+			HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+	assert!(result.is_err());
+
+	for _ in 0..100 {
+		if dead_letter_path.exists() {
+			break;
+		}
+		tokio::time::sleep(Duration::from_millis(20)).await;
+	}
+
+	mock.assert_async().await;
+	let content = std::fs::read_to_string(&dead_letter_path).unwrap();
+	let dead_letter: DeadLetter = serde_json::from_str(content.trim()).unwrap();
+	assert_eq!(dead_letter.trigger_name, "test_trigger");
+	assert_eq!(dead_letter.attempts, 3);
+	assert!(dead_lettered.get() > dead_lettered_before);
+}
+
+/// Creates a trigger execution service without retry queue for a single trigger
+fn create_trigger_execution_service(
+	trigger: Trigger,
+) -> TriggerExecutionService<TriggerRepository> {
+	let triggers = HashMap::from([(trigger.name.clone(), trigger)]);
+	let trigger_service =
+		TriggerService::new_with_repository(TriggerRepository { triggers }).unwrap();
+
+	TriggerExecutionService::new(trigger_service, NotificationService::new())
+}
+
+#[tokio::test]
+async fn test_test_notification_is_sent_through_webhook() {
+	let mut server = Server::new_async().await;
+	let payload = GenericWebhookPayloadBuilder
+		.build_payload(
+			"Test Alert",
+			"Test Notification: transfer of 1000000000000000000",
+			&HashMap::new(),
+		)
+		.unwrap();
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Json(payload))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&server.url())
+		.message(
+			"Test Alert",
+			"${monitor.name}: transfer of ${functions.0.args.amount}",
+		)
+		.build();
+	let service = create_trigger_execution_service(trigger);
+
+	let result = service.execute_test_notification("test_trigger").await;
+
+	assert!(result.is_ok());
+	mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_test_notification_reports_webhook_failure() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("GET", "/")
+		.with_status(500)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let service =
+		create_trigger_execution_service(create_non_retrying_webhook_trigger(&server.url()));
+
+	let result = service.execute_test_notification("test_trigger").await;
+
+	assert!(result.is_err());
+	mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_test_notification_unknown_trigger() {
+	let service =
+		create_trigger_execution_service(create_non_retrying_webhook_trigger("http://localhost:1"));
+
+	let result = service.execute_test_notification("unknown_trigger").await;
+
+	assert!(matches!(result, Err(TriggerError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_check_triggers_with_healthy_webhook() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("HEAD", "/")
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let service =
+		create_trigger_execution_service(create_non_retrying_webhook_trigger(&server.url()));
+
+	let unhealthy = service.check_triggers().await;
+
+	assert!(unhealthy.is_empty());
+	mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_check_triggers_with_unhealthy_webhook() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("HEAD", "/")
+		.with_status(404)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let service =
+		create_trigger_execution_service(create_non_retrying_webhook_trigger(&server.url()));
+
+	let unhealthy = service.check_triggers().await;
+
+	assert_eq!(unhealthy.len(), 1);
+	assert_eq!(unhealthy[0].0, "test_trigger");
+	assert!(matches!(unhealthy[0].1, TriggerError::ExecutionError(_)));
+	mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_disabled_trigger_is_not_executed() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("GET", "/")
+		.with_status(200)
+		.expect(0)
+		.create_async()
+		.await;
+
+	let mut trigger = create_non_retrying_webhook_trigger(&server.url());
+	trigger.enabled = Some(false);
+	let skipped =
+		NOTIFICATIONS_SENT_TOTAL.with_label_values(&["webhook", "skipped", "none", "", ""]);
+	let skipped_before = skipped.get();
+
+	let service = create_trigger_execution_service(trigger);
+	let monitor = create_test_monitor("test_monitor");
+	let monitor_match = create_test_evm_match(monitor.clone());
+
+	// The monitor still references the trigger
+	assert_eq!(monitor.triggers, vec!["test_trigger".to_string()]);
+	let results = service
+		.execute_with_results(
+			&monitor.triggers,
+			HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+
+	assert_eq!(results.len(), 1);
+	assert!(matches!(results[0].outcome, TriggerOutcome::Skipped));
+	assert!(skipped.get() > skipped_before);
+	mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_throttled_monitor_drops_matches_over_the_cap() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("GET", "/")
+		.with_status(200)
+		.expect(2)
+		.create_async()
+		.await;
+
+	let service =
+		create_trigger_execution_service(create_non_retrying_webhook_trigger(&server.url()));
+	let monitor = MonitorBuilder::new()
+		.name("throttled_monitor")
+		.triggers(vec!["test_trigger".to_string()])
+		.max_notifications_per_hour(2, false)
+		.build();
+	let monitor_match = create_test_evm_match(monitor.clone());
+	let throttled = NOTIFICATIONS_THROTTLED_TOTAL.with_label_values(&["throttled_monitor"]);
+	let throttled_before = throttled.get();
+
+	for i in 0..5 {
+		let results = service
+			.execute_with_results(
+				&monitor.triggers,
+				HashMap::new(),
+				&monitor_match,
+				&HashMap::new(),
+			)
+			.await;
+		// Matches over the cap are skipped
+		assert_eq!(results[0].outcome.is_sent(), i < 2);
+		assert_eq!(results[0].outcome.is_skipped(), i >= 2);
+	}
+
+	assert_eq!(throttled.get(), throttled_before + 3);
+	mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_monitor_in_cooldown_drops_duplicates_across_restarts() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("GET", "/")
+		.with_status(200)
+		.expect(2)
+		.create_async()
+		.await;
+	let temp_dir = tempfile::tempdir().unwrap();
+	let storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+
+	let monitor = MonitorBuilder::new()
+		.name("cooldown_monitor")
+		.triggers(vec!["test_trigger".to_string()])
+		.cooldown(3_600_000, Some("${transaction.to}"), true)
+		.build();
+	let monitor_match = create_test_evm_match(monitor.clone());
+	let variables = |to: &str| HashMap::from([("transaction.to".to_string(), to.to_string())]);
+
+	let service =
+		create_trigger_execution_service(create_non_retrying_webhook_trigger(&server.url()));
+	service.restore_cooldown(storage.clone()).await.unwrap();
+	// The second match duplicates the first one
+	for (to, sent) in [("0xabc", true), ("0xabc", false), ("0xdef", true)] {
+		let results = service
+			.execute_with_results(
+				&monitor.triggers,
+				variables(to),
+				&monitor_match,
+				&HashMap::new(),
+			)
+			.await;
+		assert_eq!(results[0].outcome.is_sent(), sent);
+		assert_eq!(results[0].outcome.is_skipped(), !sent);
+	}
+	service.notification_cooldown().flush().await;
+
+	// After a restart within the cooldown, the duplicate is still dropped
+	let restarted =
+		create_trigger_execution_service(create_non_retrying_webhook_trigger(&server.url()));
+	restarted.restore_cooldown(storage).await.unwrap();
+	let results = restarted
+		.execute_with_results(
+			&monitor.triggers,
+			variables("0xabc"),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+	assert!(results[0].outcome.is_skipped());
+
+	mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_throttled_monitor_sends_summary_once_window_frees_up() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex("0 matches suppressed".to_string()))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+	let summary_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex("2 matches suppressed".to_string()))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&server.url())
+		.message(
+			"Alert",
+			"{{throttle.suppressed_count | default \"0\"}} matches suppressed",
+		)
+		.build();
+	let service = create_trigger_execution_service(trigger)
+		.with_notification_throttle(NotificationThrottle::new(Duration::from_millis(300)));
+	let monitor = MonitorBuilder::new()
+		.name("summarized_monitor")
+		.triggers(vec!["test_trigger".to_string()])
+		.max_notifications_per_hour(1, true)
+		.build();
+	let monitor_match = create_test_evm_match(monitor.clone());
+
+	for _ in 0..3 {
+		service
+			.execute(
+				&monitor.triggers,
+				HashMap::new(),
+				&monitor_match,
+				&HashMap::new(),
+			)
+			.await
+			.unwrap();
+	}
+	tokio::time::sleep(Duration::from_millis(600)).await;
+
+	mock.assert_async().await;
+	summary_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_throttle_summary_waits_for_window_on_injected_clock() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex("0 matches suppressed".to_string()))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+	let summary_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex("1 matches suppressed".to_string()))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&server.url())
+		.message(
+			"Alert",
+			"{{throttle.suppressed_count | default \"0\"}} matches suppressed",
+		)
+		.build();
+	let clock = Arc::new(MockClock::new());
+	let service = create_trigger_execution_service(trigger).with_clock(clock.clone());
+	let monitor = MonitorBuilder::new()
+		.name("clocked_summary_monitor")
+		.triggers(vec!["test_trigger".to_string()])
+		.max_notifications_per_hour(1, true)
+		.build();
+	let monitor_match = create_test_evm_match(monitor.clone());
+
+	for _ in 0..2 {
+		service
+			.execute(
+				&monitor.triggers,
+				HashMap::new(),
+				&monitor_match,
+				&HashMap::new(),
+			)
+			.await
+			.unwrap();
+	}
+
+	// The summary waits for the window of the clock, not for the time of the system
+	tokio::time::sleep(Duration::from_millis(100)).await;
+	assert!(!summary_mock.matched_async().await);
+
+	clock.advance(THROTTLE_WINDOW);
+	tokio::time::timeout(Duration::from_secs(5), async {
+		while !summary_mock.matched_async().await {
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.unwrap();
+
+	mock.assert_async().await;
+	summary_mock.assert_async().await;
+}
@@ -445,6 +445,7 @@ proptest! {
 
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -480,6 +481,7 @@ proptest! {
 
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -522,6 +524,7 @@ proptest! {
 
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
@@ -563,6 +566,7 @@ proptest! {
 
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -602,6 +606,7 @@ proptest! {
 
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -660,6 +665,7 @@ proptest! {
 
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 		let lhs_as_u128 = lhs_value_str.parse::<u128>().unwrap_or_default();
@@ -701,6 +707,7 @@ proptest! {
 
 			let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 					_client: PhantomData,
+					rpc_results: Default::default(),
 			};
 			let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -732,6 +739,7 @@ proptest! {
 
 			let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 					_client: PhantomData,
+					rpc_results: Default::default(),
 			};
 			let result = filter.evaluate_expression(&expr, &params).unwrap();
 			// Normalize the target for comparison
@@ -780,6 +788,7 @@ proptest! {
 
 			let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 					_client: PhantomData,
+					rpc_results: Default::default(),
 			};
 			let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -817,6 +826,7 @@ proptest! {
 
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -857,6 +867,7 @@ proptest! {
 
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -884,6 +895,7 @@ proptest! {
 
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -936,6 +948,7 @@ proptest! {
 
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -973,6 +986,7 @@ proptest! {
 
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 
 		// Test various invalid expression scenarios
@@ -1001,6 +1015,7 @@ proptest! {
 	) {
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 
 		// Test transaction matching across different status types
@@ -1010,6 +1025,8 @@ proptest! {
 				&status,
 				&tx,
 				&Some(ReceiptBuilder::new().build()),
+				None,
+				None,
 				&monitor,
 				&mut matched_transactions
 			);
@@ -1047,6 +1064,7 @@ proptest! {
 	) {
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let mut matched_transactions = Vec::new();
 
@@ -1057,6 +1075,8 @@ proptest! {
 			&TransactionStatus::Success,
 			&tx,
 			&Some(ReceiptBuilder::new().build()),
+			None,
+			None,
 			&monitor,
 			&mut matched_transactions
 		);
@@ -1077,11 +1097,14 @@ proptest! {
 	) {
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let mut matched_functions = Vec::new();
 		let mut matched_args = EVMMatchArguments {
 			events: None,
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		// Create transaction with specific function call data
@@ -1144,11 +1167,14 @@ proptest! {
 	) {
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let mut matched_events = Vec::new();
 		let mut matched_args = EVMMatchArguments {
 			events: Some(Vec::new()),
 			functions: None,
+			block: None,
+			balance: None,
 		};
 
 		// Create transaction with specific function call data
@@ -1185,6 +1211,7 @@ proptest! {
 	) {
 		let filter = EVMBlockFilter::<EvmClient<EVMTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 
 		// Decode the event
@@ -388,6 +388,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -420,6 +421,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -458,6 +460,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
@@ -503,6 +506,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -541,6 +545,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -579,6 +584,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -617,6 +623,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -655,6 +662,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -693,6 +701,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -734,6 +743,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -769,6 +779,7 @@ proptest! {
 
 			let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 					_client: PhantomData,
+					rpc_results: Default::default(),
 			};
 			let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -800,6 +811,7 @@ proptest! {
 
 			let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 					_client: PhantomData,
+					rpc_results: Default::default(),
 			};
 			let result = filter.evaluate_expression(&expr, &params).unwrap();
 			// Normalize the target for comparison
@@ -847,6 +859,7 @@ proptest! {
 
 			let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 					_client: PhantomData,
+					rpc_results: Default::default(),
 			};
 			let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -884,6 +897,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -919,6 +933,7 @@ proptest! {
 
 			let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 					_client: PhantomData,
+					rpc_results: Default::default(),
 			};
 			let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -959,6 +974,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -1002,6 +1018,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 		let lhs_json_val = serde_json::from_str::<JsonValue>(&lhs_json_map_str).unwrap();
@@ -1042,6 +1059,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -1068,6 +1086,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -1120,6 +1139,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -1153,6 +1173,7 @@ proptest! {
 
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 
 		// Test cases for expression validation:
@@ -1182,6 +1203,7 @@ proptest! {
 		let mut matched_transactions = Vec::new();
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 
 		filter.find_matching_transaction(&tx, &monitor, &mut matched_transactions);
@@ -1232,6 +1254,7 @@ proptest! {
 	) {
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let mut matched_transactions = Vec::new();
 
@@ -1280,6 +1303,7 @@ proptest! {
 	) {
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 
 		// Create the function signature
@@ -1390,6 +1414,8 @@ proptest! {
 		let mut matched_args = StellarMatchArguments {
 			events: None,
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		// Call the function under test
@@ -1441,6 +1467,7 @@ proptest! {
 	) {
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 
 		// Create array of JSON values with explicit types
@@ -1489,6 +1516,7 @@ proptest! {
 	) {
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let arguments = vec![json!(values)];
 
@@ -1513,6 +1541,7 @@ proptest! {
 	) {
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 
 		// Test regular object to parameter conversion
@@ -1554,6 +1583,7 @@ proptest! {
 	fn test_convert_empty_arguments(_ in prop::collection::vec(any::<i64>(), 0..1)) {
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 		let arguments = Vec::new();
 
@@ -1589,6 +1619,7 @@ proptest! {
 	) {
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 
 		// Create the event signature
@@ -1639,6 +1670,8 @@ proptest! {
 		let mut matched_args = StellarMatchArguments {
 			events: Some(Vec::new()),
 			functions: Some(Vec::new()),
+			block: None,
+			balance: None,
 		};
 
 		// Call the function under test
@@ -1694,6 +1727,7 @@ proptest! {
 	) {
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 
 		// Create a buffer for event name encoding (8 byte prefix + name)
@@ -1756,6 +1790,7 @@ proptest! {
 	) {
 		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
 			_client: PhantomData,
+			rpc_results: Default::default(),
 		};
 
 		let event_name = "Transfer";
@@ -5,7 +5,12 @@
 //! The tests ensure that the email notification system handles template variables correctly
 //! and produces consistent, well-formed output across various input combinations.
 
-use openzeppelin_monitor::services::notification::EmailNotifier;
+use email_address::EmailAddress;
+use lettre::transport::stub::AsyncStubTransport;
+use openzeppelin_monitor::{
+	services::notification::{EmailContent, EmailNotifier},
+	utils::RetryConfig,
+};
 use proptest::{prelude::*, test_runner::Config};
 use std::collections::HashMap;
 
@@ -16,6 +21,43 @@ fn template_variables_strategy() -> impl Strategy<Value = HashMap<String, String
 	prop::collection::hash_map("[a-zA-Z0-9_]{1,10}", "[a-zA-Z0-9 ]{1,20}", 1..5)
 }
 
+/// Generates a strategy for creating lists of distinct email addresses on the given domain.
+fn email_addresses_strategy(
+	domain: &'static str,
+	size: std::ops::Range<usize>,
+) -> impl Strategy<Value = Vec<EmailAddress>> {
+	prop::collection::hash_set("[a-z0-9]{1,10}", size).prop_map(move |local_parts| {
+		local_parts
+			.into_iter()
+			.map(|local| EmailAddress::new_unchecked(format!("{}@{}", local, domain)))
+			.collect()
+	})
+}
+
+/// Creates an email notifier with a stub transport for the given recipients.
+fn create_notifier(
+	recipients: Vec<EmailAddress>,
+	cc: Vec<EmailAddress>,
+	bcc: Vec<EmailAddress>,
+	is_html: bool,
+) -> EmailNotifier<AsyncStubTransport> {
+	let email_content = EmailContent {
+		subject: "Test".to_string(),
+		body_template: "Test message".to_string(),
+		sender: EmailAddress::new_unchecked("sender@test.com"),
+		recipients,
+		cc,
+		bcc,
+		is_html,
+	};
+
+	EmailNotifier::with_transport(
+		email_content,
+		AsyncStubTransport::new_ok(),
+		RetryConfig::default(),
+	)
+}
+
 proptest! {
 	#![proptest_config(Config {
 		failure_persistence: None,
@@ -33,8 +75,10 @@ proptest! {
 		template in "[a-zA-Z0-9 ${}_]{1,100}",
 		vars in template_variables_strategy()
 	) {
-		let first_pass = EmailNotifier::format_message(&template, &vars);
-		let second_pass = EmailNotifier::format_message(&template, &vars);
+		let first_pass =
+			EmailNotifier::format_message(&template, &vars).map_err(|e| e.to_string());
+		let second_pass =
+			EmailNotifier::format_message(&template, &vars).map_err(|e| e.to_string());
 
 		prop_assert_eq!(first_pass, second_pass);
 	}
@@ -50,7 +94,7 @@ proptest! {
 		template in "[a-zA-Z0-9 ]{0,50}\\$\\{[a-z_]+\\}[a-zA-Z0-9 ]{0,50}",
 		vars in template_variables_strategy()
 	) {
-		let formatted = EmailNotifier::format_message(&template, &vars);
+		let formatted = EmailNotifier::format_message(&template, &vars).unwrap();
 
 		// Verify no partial variable substitutions occurred
 		prop_assert!(!formatted.contains("${{"));
@@ -60,16 +104,71 @@ proptest! {
 	/// Tests that templates with no matching variables remain unchanged.
 	///
 	// # Properties tested
-	/// - Template remains identical when processed with an empty variables map
+	/// - Template without expressions remains identical when processed with an empty variables
+	///   map
 	/// - No unexpected substitutions occur with empty variable set
 	#[test]
 	fn test_notification_empty_variables(
 		template in "[a-zA-Z0-9 ${}_]{1,100}"
 	) {
+		// `{{` opens a template expression
+		prop_assume!(!template.contains("{{"));
+
 		let empty_vars = HashMap::new();
-		let formatted = EmailNotifier::format_message(&template, &empty_vars);
+		let formatted = EmailNotifier::format_message(&template, &empty_vars).unwrap();
 		let html_template = EmailNotifier::markdown_to_html(&template);
 		// Template should remain unchanged when no variables are provided
 		prop_assert_eq!(formatted, html_template);
 	}
+
+	/// Tests that every recipient, CC and BCC address ends up in the message envelope.
+	///
+	/// # Properties tested
+	/// - The envelope contains all recipients, CC and BCC addresses
+	/// - The CC header is only present when CC addresses are configured
+	/// - The BCC header is never part of the sent message
+	#[test]
+	fn test_email_message_includes_all_recipients(
+		recipients in email_addresses_strategy("to.com", 1..5),
+		cc in email_addresses_strategy("cc.com", 0..5),
+		bcc in email_addresses_strategy("bcc.com", 0..5),
+	) {
+		let notifier = create_notifier(recipients.clone(), cc.clone(), bcc.clone(), false);
+		let message = notifier.build_message("<p>Test message</p>").unwrap();
+
+		let envelope_recipients: Vec<String> = message
+			.envelope()
+			.to()
+			.iter()
+			.map(ToString::to_string)
+			.collect();
+		prop_assert_eq!(envelope_recipients.len(), recipients.len() + cc.len() + bcc.len());
+		for address in recipients.iter().chain(&cc).chain(&bcc) {
+			prop_assert!(envelope_recipients.contains(&address.to_string()));
+		}
+
+		let formatted = String::from_utf8(message.formatted()).unwrap();
+		prop_assert_eq!(formatted.contains("\r\nCc: "), !cc.is_empty());
+		prop_assert!(!formatted.contains("Bcc: "));
+	}
+
+	/// Tests that HTML bodies are sent as HTML without being rendered as Markdown.
+	///
+	/// # Properties tested
+	/// - HTML templates without variables remain unchanged
+	/// - The message is sent with the text/html content type
+	#[test]
+	fn test_email_html_body(
+		text in "[a-zA-Z0-9 ]{0,50}",
+		recipients in email_addresses_strategy("to.com", 1..3),
+	) {
+		let template = format!("<div><strong>{}</strong></div>", text);
+		let body = EmailNotifier::format_body(&template, &HashMap::new(), true).unwrap();
+		prop_assert_eq!(&body, &template);
+
+		let notifier = create_notifier(recipients, vec![], vec![], true);
+		let message = notifier.build_message(&body).unwrap();
+		let formatted = String::from_utf8(message.formatted()).unwrap();
+		prop_assert!(formatted.contains("Content-Type: text/html"));
+	}
 }
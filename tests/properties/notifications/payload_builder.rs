@@ -33,8 +33,10 @@ proptest! {
 		template in "[a-zA-Z0-9 ${}_]{1,100}",
 		vars in template_variables_strategy()
 	) {
-		let first_pass = payload_builder::format_template(&template, &vars);
-		let second_pass = payload_builder::format_template(&template, &vars);
+		let first_pass =
+			payload_builder::format_template(&template, &vars).map_err(|e| e.to_string());
+		let second_pass =
+			payload_builder::format_template(&template, &vars).map_err(|e| e.to_string());
 
 		prop_assert_eq!(first_pass, second_pass);
 	}
@@ -50,7 +52,7 @@ proptest! {
 		template in "[a-zA-Z0-9 ]{0,50}\\$\\{[a-z_]+\\}[a-zA-Z0-9 ]{0,50}",
 		vars in template_variables_strategy()
 	) {
-		let formatted = payload_builder::format_template(&template, &vars);
+		let formatted = payload_builder::format_template(&template, &vars).unwrap();
 
 		// Verify no partial variable substitutions occurred
 		prop_assert!(!formatted.contains("${{"));
@@ -60,14 +62,18 @@ proptest! {
 	/// Tests that templates with no matching variables remain unchanged.
 	///
 	/// # Properties tested
-	/// - Template remains identical when processed with an empty variables map
+	/// - Template without expressions remains identical when processed with an empty variables
+	///   map
 	/// - The formatted message follows the expected Webhook format: "{template}"
 	#[test]
 	fn test_notification_empty_variables(
 		template in "[a-zA-Z0-9 ${}_]{1,100}"
 	) {
+		// `{{` opens a template expression
+		prop_assume!(!template.contains("{{"));
+
 		let empty_vars = HashMap::new();
-		let formatted = payload_builder::format_template(&template, &empty_vars);
+		let formatted = payload_builder::format_template(&template, &empty_vars).unwrap();
 
 		// Template should remain unchanged when no variables are provided
 		prop_assert_eq!(formatted, format!("{}", template));
@@ -88,9 +88,9 @@ proptest! {
 			invalid_network.block_time_ms = 50; // Too low block time
 			prop_assert!(invalid_network.validate().is_err());
 
-			invalid_network = network.clone();
-			invalid_network.confirmation_blocks = 0; // Invalid confirmation blocks
-			prop_assert!(invalid_network.validate().is_err());
+			let mut unconfirmed_network = network.clone();
+			unconfirmed_network.confirmation_blocks = 0; // Processing unconfirmed blocks only warns
+			prop_assert!(unconfirmed_network.validate().is_ok());
 
 			invalid_network = network.clone();
 			invalid_network.rpc_urls[0].url = SecretValue::Plain(SecretString::new("invalid-url".to_string())); // Invalid RPC URL
@@ -93,7 +93,7 @@ proptest! {
 			// Test invalid cases
 			match &trigger.trigger_type {
 				TriggerType::Slack => {
-					if let TriggerTypeConfig::Slack { slack_url: _, message: _, retry_policy: _ } = &trigger.config {
+					if let TriggerTypeConfig::Slack { slack_url: _, message: _, blocks: _, retry_policy: _, timeout_ms: _ } = &trigger.config {
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Slack { slack_url, .. } = &mut invalid_trigger.config {
 							*slack_url = SecretValue::Plain(SecretString::new("not-a-url".to_string())); // Invalid URL format
@@ -123,7 +123,7 @@ proptest! {
 					}
 				}
 				TriggerType::Email => {
-					if let TriggerTypeConfig::Email { host: _, port: _, username: _, password: _, message: _, sender: _, recipients: _, retry_policy: _ } = &trigger.config {
+					if let TriggerTypeConfig::Email { host: _, port: _, username: _, password: _, message: _, sender: _, recipients: _, cc: _, bcc: _, is_html: _, retry_policy: _, timeout_ms: _ } = &trigger.config {
 						// Test empty recipients
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Email { recipients: r, .. } = &mut invalid_trigger.config {
@@ -150,7 +150,7 @@ proptest! {
 					}
 				}
 				TriggerType::Webhook => {
-					if let TriggerTypeConfig::Webhook { url: _, method: _, headers: _, secret: _, message: _, retry_policy: _ } = &trigger.config {
+					if let TriggerTypeConfig::Webhook { url: _, method: _, headers: _, secret: _, message: _, retry_policy: _, timeout_ms: _, include_raw_match: _, trim_raw_match: _, signature_algorithm: _, signature_header: _, signature_algorithm_header: _ } = &trigger.config {
 						// Test invalid method
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Webhook { method: m, .. } = &mut invalid_trigger.config {
@@ -187,7 +187,7 @@ proptest! {
 					}
 				}
 				TriggerType::Discord => {
-					if let TriggerTypeConfig::Discord { discord_url: _, message: _, retry_policy: _ } = &trigger.config {
+					if let TriggerTypeConfig::Discord { discord_url: _, message: _, embed: _, retry_policy: _, timeout_ms: _ } = &trigger.config {
 						// Test invalid URL
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Discord { discord_url: u, .. } = &mut invalid_trigger.config {
@@ -217,7 +217,7 @@ proptest! {
 					}
 				}
 				TriggerType::Telegram => {
-					if let TriggerTypeConfig::Telegram { token: _, chat_id: _, disable_web_preview: _, message: _, retry_policy: _ } = &trigger.config {
+					if let TriggerTypeConfig::Telegram { token: _, chat_id: _, disable_web_preview: _, message_thread_id: _, parse_mode: _, message: _, retry_policy: _, timeout_ms: _ } = &trigger.config {
 						// Test invalid token
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Telegram { token: t, .. } = &mut invalid_trigger.config {
@@ -253,8 +253,38 @@ proptest! {
 						prop_assert!(invalid_trigger.validate().is_err());
 					}
 				}
+				TriggerType::Opsgenie => {
+					if let TriggerTypeConfig::Opsgenie { .. } = &trigger.config {
+						// Test empty API key
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Opsgenie { api_key: k, .. } = &mut invalid_trigger.config {
+							*k = SecretValue::Plain(SecretString::new("".to_string()));
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+					}
+				}
+				TriggerType::File => {
+					if let TriggerTypeConfig::File { .. } = &trigger.config {
+						// Test empty path
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::File { path: p, .. } = &mut invalid_trigger.config {
+							*p = "".to_string();
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+					}
+				}
+				TriggerType::Kafka => {
+					if let TriggerTypeConfig::Kafka { .. } = &trigger.config {
+						// Test empty topic
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Kafka { topic: t, .. } = &mut invalid_trigger.config {
+							*t = "".to_string();
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+					}
+				}
 				TriggerType::Script => {
-					if let TriggerTypeConfig::Script { script_path: _, arguments: _, language: _, timeout_ms: _ } = &trigger.config {
+					if let TriggerTypeConfig::Script { script_path: _, arguments: _, language: _, timeout_ms: _, max_memory_mb: _, max_output_bytes: _ } = &trigger.config {
 						// Test invalid path
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Script { script_path: p, .. } = &mut invalid_trigger.config {
@@ -1,10 +1,10 @@
 use email_address::EmailAddress;
 use openzeppelin_monitor::{
 	models::{
-		AddressWithSpec, BlockChainType, EventCondition, FunctionCondition, MatchConditions,
-		Monitor, Network, NotificationMessage, RpcUrl, ScriptLanguage, SecretString, SecretValue,
-		TransactionCondition, TransactionStatus, Trigger, TriggerConditions, TriggerType,
-		TriggerTypeConfig,
+		AddressMatchMode, AddressWithSpec, BlockChainType, EventCondition, FunctionCondition,
+		MatchConditions, Monitor, Network, NotificationMessage, RpcUrl, ScriptLanguage,
+		SecretString, SecretValue, TransactionCondition, TransactionStatus, Trigger,
+		TriggerConditions, TriggerType, TriggerTypeConfig, WebhookSignatureAlgorithm,
 	},
 	utils::{
 		tests::{evm::monitor::MonitorBuilder, network::NetworkBuilder, trigger::TriggerBuilder},
@@ -38,6 +38,7 @@ pub fn monitor_strategy(
 				AddressWithSpec {
 					address,
 					contract_spec: None,
+					match_mode: AddressMatchMode::Exact,
 				}
 			}),
 			MIN_COLLECTION_SIZE..MAX_ADDRESSES,
@@ -97,7 +98,9 @@ pub fn trigger_strategy() -> impl Strategy<Value = Trigger> {
 				.prop_map(|(slack_url, message)| TriggerTypeConfig::Slack {
 					slack_url: SecretValue::Plain(SecretString::new(slack_url)),
 					message,
+					blocks: None,
 					retry_policy: RetryConfig::default(),
+					timeout_ms: None,
 				})
 		)
 			.prop_map(|(name, trigger_type, config)| TriggerBuilder::new()
@@ -131,7 +134,11 @@ pub fn trigger_strategy() -> impl Strategy<Value = Trigger> {
 							message,
 							sender,
 							recipients,
+							cc: vec![],
+							bcc: vec![],
+							is_html: false,
 							retry_policy: RetryConfig::default(),
+							timeout_ms: None,
 						}
 					}
 				)
@@ -164,6 +171,12 @@ pub fn trigger_strategy() -> impl Strategy<Value = Trigger> {
 						secret: secret.map(|s| SecretValue::Plain(SecretString::new(s))),
 						message,
 						retry_policy: RetryConfig::default(),
+						timeout_ms: None,
+						include_raw_match: false,
+						trim_raw_match: false,
+						signature_algorithm: WebhookSignatureAlgorithm::default(),
+						signature_header: None,
+						signature_algorithm_header: None,
 					}
 				})
 		)
@@ -243,6 +256,8 @@ pub fn match_conditions_strategy() -> impl Strategy<Value = MatchConditions> {
 		.prop_map(|(signature, expression)| FunctionCondition {
 			signature,
 			expression,
+			warn_threshold_expression: None,
+			eip712_schema: None,
 		});
 
 	let event_condition_strategy = (
@@ -252,6 +267,7 @@ pub fn match_conditions_strategy() -> impl Strategy<Value = MatchConditions> {
 		.prop_map(|(signature, expression)| EventCondition {
 			signature,
 			expression,
+			warn_threshold_expression: None,
 		});
 
 	let transaction_condition_strategy = (
@@ -262,7 +278,12 @@ pub fn match_conditions_strategy() -> impl Strategy<Value = MatchConditions> {
 		],
 		option::of("[0-9]+ [><=] [0-9]+".prop_map(|s| s.to_string())),
 	)
-		.prop_map(|(status, expression)| TransactionCondition { status, expression });
+		.prop_map(|(status, expression)| TransactionCondition {
+			status,
+			expression,
+			warn_threshold_expression: None,
+			is_contract_creation: false,
+		});
 
 	(
 		proptest::collection::vec(
@@ -282,6 +303,10 @@ pub fn match_conditions_strategy() -> impl Strategy<Value = MatchConditions> {
 			functions,
 			events,
 			transactions,
+			token_transfers: vec![],
+			block_conditions: vec![],
+			balance_conditions: vec![],
+			state_conditions: vec![],
 		})
 }
 
@@ -313,6 +338,9 @@ pub fn trigger_conditions_strategy() -> impl Strategy<Value = Vec<TriggerConditi
 				arguments: Some(arguments.split(',').map(|s| s.to_string()).collect()),
 				language,
 				timeout_ms,
+				max_memory_mb: None,
+				max_output_bytes: None,
+				include_block_context: false,
 			}]
 		})
 }